@@ -30,6 +30,30 @@
 //! assert!(verify_password("my-secret-password", &hash).unwrap());
 //! assert!(!verify_password("wrong-password", &hash).unwrap());
 //! ```
+//!
+//! ## Upgrading Cost Parameters
+//!
+//! Every hash records the parameters it was created with. After a successful
+//! login, [`needs_rehash`] tells you whether the stored hash predates your
+//! current parameters, so it can be replaced while the plaintext is at hand:
+//!
+//! ```rust
+//! use biscuit_hash::{PasswordParams, hash_password_with_params, needs_rehash, verify_password};
+//!
+//! let current = PasswordParams { memory_cost_kib: 8192, time_cost: 3, ..Default::default() };
+//! let stored = hash_password_with_params("secret", 4096, 1, 1).unwrap();
+//!
+//! if verify_password("secret", &stored).unwrap() && needs_rehash(&stored, &current).unwrap() {
+//!     let upgraded = hash_password_with_params(
+//!         "secret",
+//!         current.memory_cost_kib,
+//!         current.time_cost,
+//!         current.parallelism,
+//!     )
+//!     .unwrap();
+//!     assert!(!needs_rehash(&upgraded, &current).unwrap());
+//! }
+//! ```
 
 use argon2::{
     Argon2, Params,
@@ -70,6 +94,32 @@ pub const DEFAULT_PARALLELISM: u32 = 1;
 /// Default output length in bytes.
 pub const DEFAULT_OUTPUT_LEN: usize = 32;
 
+/// Argon2id cost parameters, as recorded in a PHC hash string.
+///
+/// The default is the OWASP-recommended set used by [`hash_password`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PasswordParams {
+    /// Memory cost in KiB.
+    pub memory_cost_kib: u32,
+    /// Number of iterations.
+    pub time_cost: u32,
+    /// Degree of parallelism (threads).
+    pub parallelism: u32,
+    /// Output length in bytes.
+    pub output_len: usize,
+}
+
+impl Default for PasswordParams {
+    fn default() -> Self {
+        Self {
+            memory_cost_kib: DEFAULT_MEMORY_COST_KIB,
+            time_cost: DEFAULT_TIME_COST,
+            parallelism: DEFAULT_PARALLELISM,
+            output_len: DEFAULT_OUTPUT_LEN,
+        }
+    }
+}
+
 /// Hashes a password using Argon2id with default parameters.
 ///
 /// Uses OWASP-recommended parameters:
@@ -175,6 +225,76 @@ pub fn verify_password(password: &str, hash: &str) -> Result<bool, Argon2idError
         .is_ok())
 }
 
+/// Reads the cost parameters a password hash was created with.
+///
+/// Accepts any Argon2 variant (`argon2id`, `argon2i`, `argon2d`) in PHC
+/// format.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{hash_password_with_params, parse_params};
+///
+/// let hash = hash_password_with_params("password", 4096, 3, 2).unwrap();
+/// let params = parse_params(&hash).unwrap();
+/// assert_eq!((params.memory_cost_kib, params.time_cost, params.parallelism), (4096, 3, 2));
+/// ```
+///
+/// ## Errors
+///
+/// Returns `Argon2idError::InvalidHash` if the hash is not an Argon2 PHC
+/// string or its parameters are invalid.
+pub fn parse_params(hash: &str) -> Result<PasswordParams, Argon2idError> {
+    let parsed = PasswordHash::new(hash).map_err(|e| Argon2idError::InvalidHash(e.to_string()))?;
+    params_of(&parsed)
+}
+
+/// Extracts the cost parameters of a parsed Argon2 hash.
+fn params_of(hash: &PasswordHash<'_>) -> Result<PasswordParams, Argon2idError> {
+    argon2::Algorithm::try_from(hash.algorithm)
+        .map_err(|e| Argon2idError::InvalidHash(e.to_string()))?;
+    let params = Params::try_from(hash).map_err(|e| Argon2idError::InvalidHash(e.to_string()))?;
+
+    Ok(PasswordParams {
+        memory_cost_kib: params.m_cost(),
+        time_cost: params.t_cost(),
+        parallelism: params.p_cost(),
+        output_len: hash.hash.map_or(DEFAULT_OUTPUT_LEN, |output| output.len()),
+    })
+}
+
+/// Checks whether a password hash should be replaced with one created with
+/// `desired` parameters.
+///
+/// Returns `true` if the hash is not Argon2id version 19 or any of its
+/// parameters differ from `desired`. Call it after [`verify_password`]
+/// succeeds, then store a fresh hash of the same password.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{PasswordParams, hash_password, needs_rehash};
+///
+/// let hash = hash_password("password").unwrap();
+/// assert!(!needs_rehash(&hash, &PasswordParams::default()).unwrap());
+///
+/// let stronger = PasswordParams { time_cost: 4, ..Default::default() };
+/// assert!(needs_rehash(&hash, &stronger).unwrap());
+/// ```
+///
+/// ## Errors
+///
+/// Returns `Argon2idError::InvalidHash` if the hash is not an Argon2 PHC
+/// string or its parameters are invalid.
+pub fn needs_rehash(hash: &str, desired: &PasswordParams) -> Result<bool, Argon2idError> {
+    let parsed = PasswordHash::new(hash).map_err(|e| Argon2idError::InvalidHash(e.to_string()))?;
+    let current = params_of(&parsed)?;
+    let outdated_algorithm = parsed.algorithm != argon2::Algorithm::Argon2id.ident()
+        || parsed.version != Some(argon2::Version::V0x13.into());
+
+    Ok(outdated_algorithm || current != *desired)
+}
+
 /// Hashes a password with a provided salt (for testing or deterministic needs).
 ///
 /// ## Warning
@@ -281,6 +401,74 @@ mod tests {
         assert!(!verify_password("wrong-password", &hash).unwrap());
     }
 
+    #[test]
+    fn test_parse_params() {
+        let hash = hash_password_with_params("password", 4096, 3, 2).unwrap();
+        assert_eq!(
+            parse_params(&hash).unwrap(),
+            PasswordParams {
+                memory_cost_kib: 4096,
+                time_cost: 3,
+                parallelism: 2,
+                output_len: DEFAULT_OUTPUT_LEN,
+            }
+        );
+
+        let hash = hash_password("password").unwrap();
+        assert_eq!(parse_params(&hash).unwrap(), PasswordParams::default());
+    }
+
+    #[test]
+    fn test_parse_params_invalid_hash() {
+        assert!(matches!(
+            parse_params("not-a-valid-hash"),
+            Err(Argon2idError::InvalidHash(_))
+        ));
+        assert!(matches!(
+            parse_params("$pbkdf2-sha256$i=1000$c2FsdHNhbHQ$aGFzaGhhc2hoYXNo"),
+            Err(Argon2idError::InvalidHash(_))
+        ));
+    }
+
+    #[test]
+    fn test_needs_rehash() {
+        let params = PasswordParams {
+            memory_cost_kib: 4096,
+            time_cost: 1,
+            parallelism: 1,
+            output_len: DEFAULT_OUTPUT_LEN,
+        };
+        let hash = hash_password_with_params("password", 4096, 1, 1).unwrap();
+        assert!(!needs_rehash(&hash, &params).unwrap());
+        assert!(needs_rehash(&hash, &PasswordParams::default()).unwrap());
+        assert!(
+            needs_rehash(
+                &hash,
+                &PasswordParams {
+                    time_cost: 2,
+                    ..params
+                }
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_needs_rehash_other_variant() {
+        // Argon2i with the same parameters is still replaced by Argon2id
+        let salt = SaltString::encode_b64(b"somesaltvalue123").unwrap();
+        let hash = Argon2::new(
+            argon2::Algorithm::Argon2i,
+            argon2::Version::V0x13,
+            Params::default(),
+        )
+        .hash_password(b"password", &salt)
+        .unwrap()
+        .to_string();
+        assert_eq!(parse_params(&hash).unwrap(), PasswordParams::default());
+        assert!(needs_rehash(&hash, &PasswordParams::default()).unwrap());
+    }
+
     #[test]
     fn test_hash_password_empty_string() {
         // Empty password should still hash
@@ -16,6 +16,8 @@
 //! assert_eq!(bytes.len(), 32);
 //! ```
 
+use std::io;
+
 /// Computes BLAKE3 hash of the input string and returns it as a hex string.
 ///
 /// ## Examples
@@ -61,10 +63,135 @@ pub fn blake3_hash_trimmed(data: &str) -> String {
     blake3::hash(data.trim().as_bytes()).to_hex().to_string()
 }
 
+/// Computes the keyed BLAKE3 hash (BLAKE3's built-in MAC mode) of `data` and
+/// returns it as a hex string.
+///
+/// Keyed hashing is a faster alternative to HMAC for signing data that only
+/// BLAKE3-aware parties need to verify. The key must be exactly 32 bytes;
+/// derive one from a password or shorter secret with a KDF first.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{blake3_hash, blake3_keyed_hash};
+///
+/// let key = [7u8; 32];
+/// let tag = blake3_keyed_hash(&key, b"Hello, World!");
+/// assert_eq!(tag.len(), 64);
+/// assert_ne!(tag, blake3_hash("Hello, World!"));
+/// ```
+#[inline]
+pub fn blake3_keyed_hash(key: &[u8; 32], data: &[u8]) -> String {
+    blake3::keyed_hash(key, data).to_hex().to_string()
+}
+
+/// Computes the keyed BLAKE3 hash of `data` and returns the raw 32-byte hash.
+#[inline]
+pub fn blake3_keyed_hash_bytes(key: &[u8; 32], data: &[u8]) -> [u8; 32] {
+    *blake3::keyed_hash(key, data).as_bytes()
+}
+
+/// Checks in constant time that `expected_hex` (either case) is the keyed
+/// BLAKE3 hash of `data`.
+///
+/// Returns `false` if `expected_hex` is not 64 hex characters.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{blake3_keyed_hash, verify_blake3_keyed_hash};
+///
+/// let key = [7u8; 32];
+/// let tag = blake3_keyed_hash(&key, b"payload");
+/// assert!(verify_blake3_keyed_hash(&key, b"payload", &tag));
+/// assert!(!verify_blake3_keyed_hash(&key, b"tampered", &tag));
+/// ```
+pub fn verify_blake3_keyed_hash(key: &[u8; 32], data: &[u8], expected_hex: &str) -> bool {
+    crate::verify::decode_hex(expected_hex).is_some_and(|expected| {
+        crate::constant_time_eq(&blake3_keyed_hash_bytes(key, data), &expected)
+    })
+}
+
+/// Incremental BLAKE3 hasher for content that arrives in chunks.
+///
+/// Produces the same hash as [`blake3_hash_bytes`] over the concatenation of
+/// everything written. Implements [`io::Write`], so it can be fed with
+/// [`io::copy`] or [`crate::hash_reader`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{Blake3Hasher, blake3_hash};
+///
+/// let mut hasher = Blake3Hasher::new();
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// assert_eq!(hasher.finish(), blake3_hash("Hello, World!"));
+/// ```
+#[derive(Clone, Default)]
+pub struct Blake3Hasher {
+    state: blake3::Hasher,
+}
+
+impl Blake3Hasher {
+    /// Creates a hasher with no data written.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `data` to the hash.
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+    }
+
+    /// Returns the hex-encoded hash of everything written so far.
+    ///
+    /// The hasher is left untouched, so more data can be added afterwards.
+    pub fn finish(&self) -> String {
+        self.state.finalize().to_hex().to_string()
+    }
+
+    /// Returns the raw 32-byte hash of everything written so far.
+    pub fn finish_bytes(&self) -> [u8; 32] {
+        *self.state.finalize().as_bytes()
+    }
+}
+
+impl io::Write for Blake3Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_blake3_keyed_hash() {
+        // BLAKE3 test vectors: empty input under the reference key
+        let key = b"whats the Elvish word for friend";
+        assert_eq!(
+            blake3_keyed_hash(key, b""),
+            "92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26"
+        );
+
+        let tag = blake3_keyed_hash(key, b"payload");
+        assert_ne!(tag, blake3_keyed_hash(&[0u8; 32], b"payload"));
+        assert!(verify_blake3_keyed_hash(
+            key,
+            b"payload",
+            &tag.to_uppercase()
+        ));
+        assert!(!verify_blake3_keyed_hash(key, b"payload", &tag[..62]));
+        assert!(!verify_blake3_keyed_hash(key, b"payload", "not hex"));
+    }
+
     #[test]
     fn test_blake3_hash_deterministic() {
         let content = "Hello, World!";
@@ -124,6 +251,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_blake3_hasher_matches_one_shot() {
+        let data = "The quick brown fox jumps over the lazy dog".repeat(1000);
+
+        let mut hasher = Blake3Hasher::new();
+        for chunk in data.as_bytes().chunks(17) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finish(), blake3_hash(&data));
+        assert_eq!(hasher.finish_bytes(), blake3_hash_bytes(data.as_bytes()));
+        assert_eq!(Blake3Hasher::new().finish(), blake3_hash(""));
+    }
+
+    #[test]
+    fn test_blake3_hasher_write() {
+        use std::io::Write;
+
+        let mut hasher = Blake3Hasher::new();
+        let name = "World";
+        write!(hasher, "Hello, {name}!").unwrap();
+        assert_eq!(hasher.finish(), blake3_hash("Hello, World!"));
+    }
+
     #[test]
     fn test_blake3_known_value() {
         // Known test vector: blake3("") should produce a specific hash
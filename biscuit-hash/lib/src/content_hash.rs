@@ -0,0 +1,409 @@
+//! Self-describing content hashes.
+//!
+//! A bare hex string stored in `metadata.json` or a manifest doesn't say
+//! which algorithm produced it, so a reader can't tell an XXH64 hash from the
+//! first half of a SHA-256 digest. [`ContentHash`] keeps the algorithm with
+//! the digest and renders as `<algorithm>:<hex>`:
+//!
+//! ```text
+//! xxh64:ef46db3751d8e999
+//! blake3:d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24
+//! ```
+//!
+//! It parses back with [`FromStr`], and with the `serde` feature it
+//! serializes as that same string. Constructors are `const`, so known hashes
+//! can live in constants.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use biscuit_hash::{ContentHash, HashAlgorithm};
+//!
+//! const EMPTY: ContentHash = ContentHash::xxh64(0xef46_db37_51d8_e999);
+//!
+//! let parsed: ContentHash = "xxh64:ef46db3751d8e999".parse().unwrap();
+//! assert_eq!(parsed, EMPTY);
+//! assert_eq!(parsed.algorithm(), HashAlgorithm::Xxh64);
+//! assert_eq!(EMPTY.to_string(), "xxh64:ef46db3751d8e999");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Longest digest any [`HashAlgorithm`] produces (SHA-512).
+const MAX_DIGEST_LEN: usize = 64;
+
+/// The algorithm a [`ContentHash`] was produced with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    /// XXH64 (`xx_hash`)
+    Xxh64,
+    /// XXH3, 64-bit (`xx_hash3`)
+    Xxh3,
+    /// XXH3, 128-bit (`xx_hash128`)
+    Xxh128,
+    /// BLAKE3, 256-bit
+    Blake3,
+    /// SHA-256
+    Sha256,
+    /// SHA-512
+    Sha512,
+}
+
+impl HashAlgorithm {
+    /// Every algorithm, in the order listed above.
+    pub const ALL: [HashAlgorithm; 6] = [
+        HashAlgorithm::Xxh64,
+        HashAlgorithm::Xxh3,
+        HashAlgorithm::Xxh128,
+        HashAlgorithm::Blake3,
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Sha512,
+    ];
+
+    /// The prefix used in the string form (`xxh64`, `blake3`, ...).
+    pub const fn name(self) -> &'static str {
+        match self {
+            HashAlgorithm::Xxh64 => "xxh64",
+            HashAlgorithm::Xxh3 => "xxh3",
+            HashAlgorithm::Xxh128 => "xxh128",
+            HashAlgorithm::Blake3 => "blake3",
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha512 => "sha512",
+        }
+    }
+
+    /// Length of the digest in bytes.
+    pub const fn digest_len(self) -> usize {
+        match self {
+            HashAlgorithm::Xxh64 | HashAlgorithm::Xxh3 => 8,
+            HashAlgorithm::Xxh128 => 16,
+            HashAlgorithm::Blake3 | HashAlgorithm::Sha256 => 32,
+            HashAlgorithm::Sha512 => 64,
+        }
+    }
+
+    /// Looks an algorithm up by its [`name`](Self::name), ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.name().eq_ignore_ascii_case(name))
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Why a string couldn't be parsed as a [`ContentHash`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentHashError {
+    /// No `<algorithm>:` prefix.
+    MissingAlgorithm,
+    /// The prefix isn't a known [`HashAlgorithm`].
+    UnknownAlgorithm(String),
+    /// The digest isn't valid hex.
+    InvalidHex,
+    /// The digest has the wrong length for its algorithm.
+    WrongLength {
+        algorithm: HashAlgorithm,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for ContentHashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingAlgorithm => {
+                f.write_str("content hash is missing its `<algorithm>:` prefix")
+            }
+            Self::UnknownAlgorithm(name) => write!(f, "unknown hash algorithm `{name}`"),
+            Self::InvalidHex => f.write_str("content hash digest is not valid hex"),
+            Self::WrongLength {
+                algorithm,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{algorithm} digests are {expected} bytes but {actual} were given"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ContentHashError {}
+
+/// A digest together with the algorithm that produced it.
+///
+/// Integer hashes (the xxHash family) are stored big-endian, so the hex
+/// matches the zero-padded `{:016x}`/`{:032x}` rendering used elsewhere.
+/// Two hashes are equal only when both the algorithm and digest match.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContentHash {
+    algorithm: HashAlgorithm,
+    /// The digest, zero-filled past `algorithm.digest_len()`
+    bytes: [u8; MAX_DIGEST_LEN],
+}
+
+impl ContentHash {
+    /// Wraps an XXH64 hash.
+    pub const fn xxh64(hash: u64) -> Self {
+        Self::from_digest(HashAlgorithm::Xxh64, &hash.to_be_bytes())
+    }
+
+    /// Wraps an XXH3 64-bit hash.
+    pub const fn xxh3(hash: u64) -> Self {
+        Self::from_digest(HashAlgorithm::Xxh3, &hash.to_be_bytes())
+    }
+
+    /// Wraps an XXH3 128-bit hash.
+    pub const fn xxh128(hash: u128) -> Self {
+        Self::from_digest(HashAlgorithm::Xxh128, &hash.to_be_bytes())
+    }
+
+    /// Wraps a BLAKE3 digest.
+    pub const fn blake3(digest: &[u8; 32]) -> Self {
+        Self::from_digest(HashAlgorithm::Blake3, digest)
+    }
+
+    /// Wraps a SHA-256 digest.
+    pub const fn sha256(digest: &[u8; 32]) -> Self {
+        Self::from_digest(HashAlgorithm::Sha256, digest)
+    }
+
+    /// Wraps a SHA-512 digest.
+    pub const fn sha512(digest: &[u8; 64]) -> Self {
+        Self::from_digest(HashAlgorithm::Sha512, digest)
+    }
+
+    /// Wraps `digest`, returning `None` if its length doesn't match `algorithm`.
+    pub const fn new(algorithm: HashAlgorithm, digest: &[u8]) -> Option<Self> {
+        if digest.len() != algorithm.digest_len() {
+            return None;
+        }
+        Some(Self::from_digest(algorithm, digest))
+    }
+
+    /// Copies `digest` (already the right length) into a new hash.
+    const fn from_digest(algorithm: HashAlgorithm, digest: &[u8]) -> Self {
+        let mut bytes = [0u8; MAX_DIGEST_LEN];
+        let mut i = 0;
+        while i < digest.len() {
+            bytes[i] = digest[i];
+            i += 1;
+        }
+        Self { algorithm, bytes }
+    }
+
+    /// The algorithm that produced this hash.
+    pub const fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// The raw digest bytes.
+    pub fn digest(&self) -> &[u8] {
+        &self.bytes[..self.algorithm.digest_len()]
+    }
+
+    /// The digest as lowercase hex, without the algorithm prefix.
+    pub fn to_hex(&self) -> String {
+        self.digest().iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    /// The xxHash value as an integer, or `None` for other algorithms.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self.algorithm {
+            HashAlgorithm::Xxh64 | HashAlgorithm::Xxh3 => {
+                let mut be = [0u8; 8];
+                be.copy_from_slice(self.digest());
+                Some(u64::from_be_bytes(be))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if `hex` (either case, no prefix) is this hash's digest.
+    ///
+    /// Useful when comparing against hashes stored as bare strings before
+    /// they were self-describing.
+    pub fn eq_hex(&self, hex: &str) -> bool {
+        decode_hex(hex).is_some_and(|digest| digest == self.digest())
+    }
+
+    /// Returns true if `digest` is this hash's digest, whatever its algorithm.
+    pub fn eq_digest(&self, digest: &[u8]) -> bool {
+        self.digest() == digest
+    }
+
+    /// Hashes `data` with `algorithm`.
+    ///
+    /// Returns `None` when the feature providing the algorithm is disabled
+    /// (`xx_hash`, `blake3` or `sha2`).
+    #[allow(unused_variables)]
+    pub fn compute(algorithm: HashAlgorithm, data: &[u8]) -> Option<Self> {
+        match algorithm {
+            #[cfg(feature = "xx_hash")]
+            HashAlgorithm::Xxh64 => Some(Self::xxh64(crate::xx::xx_hash_bytes(data))),
+            #[cfg(feature = "xx_hash")]
+            HashAlgorithm::Xxh3 => Some(Self::xxh3(crate::xx::xx_hash3_bytes(data))),
+            #[cfg(feature = "xx_hash")]
+            HashAlgorithm::Xxh128 => Some(Self::xxh128(crate::xx::xx_hash128_bytes(data))),
+            #[cfg(feature = "blake3")]
+            HashAlgorithm::Blake3 => Some(Self::blake3(&crate::blake::blake3_hash_bytes(data))),
+            #[cfg(feature = "sha2")]
+            HashAlgorithm::Sha256 => Some(Self::sha256(&crate::sha::sha256_hash_bytes(data))),
+            #[cfg(feature = "sha2")]
+            HashAlgorithm::Sha512 => Some(Self::sha512(&crate::sha::sha512_hash_bytes(data))),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
+    /// Returns `Some(true)` if hashing `data` with this hash's algorithm
+    /// reproduces it, or `None` when that algorithm's feature is disabled.
+    pub fn matches(&self, data: &[u8]) -> Option<bool> {
+        Self::compute(self.algorithm, data).map(|computed| computed == *self)
+    }
+}
+
+impl fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.algorithm, self.to_hex())
+    }
+}
+
+impl fmt::Debug for ContentHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ContentHash({self})")
+    }
+}
+
+impl FromStr for ContentHash {
+    type Err = ContentHashError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, hex) = s
+            .trim()
+            .split_once(':')
+            .ok_or(ContentHashError::MissingAlgorithm)?;
+        let algorithm = HashAlgorithm::from_name(name)
+            .ok_or_else(|| ContentHashError::UnknownAlgorithm(name.to_string()))?;
+        let digest = decode_hex(hex).ok_or(ContentHashError::InvalidHex)?;
+
+        Self::new(algorithm, &digest).ok_or(ContentHashError::WrongLength {
+            algorithm,
+            expected: algorithm.digest_len(),
+            actual: digest.len(),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ContentHash {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ContentHash {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Decodes a hex string (either case) into bytes.
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_and_parse_round_trip() {
+        let hash = ContentHash::xxh128(0x0123_4567_89ab_cdef_0000_0000_0000_0001);
+        let rendered = hash.to_string();
+
+        assert_eq!(rendered, "xxh128:0123456789abcdef0000000000000001");
+        assert_eq!(rendered.parse::<ContentHash>(), Ok(hash));
+        assert_eq!("XXH128:0123456789ABCDEF0000000000000001".parse(), Ok(hash));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "deadbeef".parse::<ContentHash>(),
+            Err(ContentHashError::MissingAlgorithm)
+        );
+        assert_eq!(
+            "md5:deadbeef".parse::<ContentHash>(),
+            Err(ContentHashError::UnknownAlgorithm("md5".to_string()))
+        );
+        assert_eq!(
+            "xxh64:zz".parse::<ContentHash>(),
+            Err(ContentHashError::InvalidHex)
+        );
+        assert_eq!(
+            "sha256:deadbeef".parse::<ContentHash>(),
+            Err(ContentHashError::WrongLength {
+                algorithm: HashAlgorithm::Sha256,
+                expected: 32,
+                actual: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn test_algorithm_is_part_of_equality() {
+        let a = ContentHash::xxh64(42);
+        let b = ContentHash::xxh3(42);
+
+        assert_ne!(a, b);
+        assert!(a.eq_digest(b.digest()));
+        assert!(a.eq_hex("000000000000002A"));
+        assert!(!a.eq_hex("2a"));
+        assert_eq!(a.as_u64(), Some(42));
+    }
+
+    #[test]
+    fn test_new_checks_length() {
+        assert!(ContentHash::new(HashAlgorithm::Blake3, &[0; 32]).is_some());
+        assert!(ContentHash::new(HashAlgorithm::Blake3, &[0; 31]).is_none());
+        for algorithm in HashAlgorithm::ALL {
+            assert_eq!(HashAlgorithm::from_name(algorithm.name()), Some(algorithm));
+        }
+    }
+
+    #[cfg(feature = "xx_hash")]
+    #[test]
+    fn test_compute_and_matches() {
+        let hash = ContentHash::compute(HashAlgorithm::Xxh64, b"hello").unwrap();
+
+        assert_eq!(hash, ContentHash::xxh64(crate::xx_hash("hello")));
+        assert_eq!(hash.matches(b"hello"), Some(true));
+        assert_eq!(hash.matches(b"hello!"), Some(false));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_uses_string_form() {
+        let hash = ContentHash::xxh3(7);
+        let json = serde_json::to_string(&hash).unwrap();
+
+        assert_eq!(json, "\"xxh3:0000000000000007\"");
+        assert_eq!(serde_json::from_str::<ContentHash>(&json).unwrap(), hash);
+        assert!(serde_json::from_str::<ContentHash>("\"xxh3:07\"").is_err());
+    }
+}
@@ -0,0 +1,356 @@
+//! Content hashes for files and directory trees.
+//!
+//! [`hash_file`] streams a file through XXH64. [`hash_dir`] hashes every file
+//! under a directory and combines them into a merkle-style tree hash: each
+//! directory's hash covers the sorted names, kinds and hashes of its entries,
+//! so the result depends only on paths and contents, never on walk order or
+//! timestamps. Comparing two [`DirHash`]es tells whether anything in the tree
+//! changed, and [`DirHash::files`] tells which files.
+//!
+//! Files ignored by `.gitignore`/`.ignore` rules, hidden files and anything
+//! matching [`HashDirOptions::with_ignore`] patterns are left out of the hash.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use biscuit_hash::{HashDirOptions, hash_dir, hash_file};
+//!
+//! let dir = std::env::temp_dir().join(format!("biscuit-files-{}", std::process::id()));
+//! std::fs::create_dir_all(dir.join("target")).unwrap();
+//! std::fs::write(dir.join("main.rs"), "fn main() {}").unwrap();
+//! std::fs::write(dir.join("target/out.bin"), [0u8; 16]).unwrap();
+//!
+//! let options = HashDirOptions::default().with_ignore("target");
+//! let tree = hash_dir(&dir, &options).unwrap();
+//! assert_eq!(tree.files.len(), 1);
+//! assert_eq!(tree.files[std::path::Path::new("main.rs")], hash_file(dir.join("main.rs")).unwrap());
+//! # std::fs::remove_dir_all(&dir).unwrap();
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ignore::WalkBuilder;
+use ignore::overrides::OverrideBuilder;
+use thiserror::Error;
+
+use crate::{XxHasher, hash_reader};
+
+/// Errors that can occur while hashing files or directories.
+#[derive(Debug, Error)]
+pub enum FileHashError {
+    /// A file or directory could not be read.
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// An ignore pattern is invalid, or the directory walk failed.
+    #[error(transparent)]
+    Walk(#[from] ignore::Error),
+}
+
+impl FileHashError {
+    fn io(path: &Path, source: io::Error) -> Self {
+        Self::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+/// Controls which entries [`hash_dir`] includes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashDirOptions {
+    /// Gitignore-style patterns to leave out, e.g. `target` or `*.log`
+    pub ignore: Vec<String>,
+    /// Honor `.gitignore`, `.ignore` and global git excludes (default `true`)
+    pub respect_gitignore: bool,
+    /// Include hidden files and directories (default `false`)
+    pub include_hidden: bool,
+    /// Hash the targets of symlinks instead of skipping them (default `false`)
+    pub follow_symlinks: bool,
+}
+
+impl Default for HashDirOptions {
+    fn default() -> Self {
+        Self {
+            ignore: Vec::new(),
+            respect_gitignore: true,
+            include_hidden: false,
+            follow_symlinks: false,
+        }
+    }
+}
+
+impl HashDirOptions {
+    /// Adds a gitignore-style pattern of entries to leave out.
+    pub fn with_ignore(mut self, pattern: impl Into<String>) -> Self {
+        self.ignore.push(pattern.into());
+        self
+    }
+
+    /// Sets whether `.gitignore` and `.ignore` files are honored.
+    pub fn with_gitignore(mut self, respect: bool) -> Self {
+        self.respect_gitignore = respect;
+        self
+    }
+
+    /// Sets whether hidden files and directories are hashed.
+    pub fn with_hidden(mut self, include: bool) -> Self {
+        self.include_hidden = include;
+        self
+    }
+
+    /// Sets whether symlinks are followed.
+    pub fn with_follow_symlinks(mut self, follow: bool) -> Self {
+        self.follow_symlinks = follow;
+        self
+    }
+}
+
+/// The hash of a directory tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirHash {
+    /// Merkle-style hash of the whole tree
+    pub hash: u64,
+    /// Content hash of every included file, keyed by path relative to the root
+    pub files: BTreeMap<PathBuf, u64>,
+}
+
+/// Computes the XXH64 hash of a file's contents without loading it into memory.
+///
+/// The result equals [`xx_hash_bytes`](crate::xx_hash_bytes) over the file's bytes.
+///
+/// ## Errors
+///
+/// Returns an error if the file cannot be opened or read.
+pub fn hash_file(path: impl AsRef<Path>) -> Result<u64, FileHashError> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| FileHashError::io(path, e))?;
+    hash_reader::<XxHasher>(file)
+        .map(|hasher| hasher.finish())
+        .map_err(|e| FileHashError::io(path, e))
+}
+
+/// Computes a deterministic, merkle-style hash of the directory tree at `root`.
+///
+/// Empty directories contribute to the hash, so adding or removing one
+/// changes it. Symlinks are skipped unless
+/// [`HashDirOptions::follow_symlinks`] is set.
+///
+/// ## Errors
+///
+/// Returns an error if `root` is not a directory, an ignore pattern is
+/// invalid, or an entry cannot be read.
+pub fn hash_dir(
+    root: impl AsRef<Path>,
+    options: &HashDirOptions,
+) -> Result<DirHash, FileHashError> {
+    let root = root.as_ref();
+    let metadata = fs::metadata(root).map_err(|e| FileHashError::io(root, e))?;
+    if !metadata.is_dir() {
+        return Err(FileHashError::io(
+            root,
+            io::Error::from(io::ErrorKind::NotADirectory),
+        ));
+    }
+
+    let mut overrides = OverrideBuilder::new(root);
+    for pattern in &options.ignore {
+        overrides.add(&format!("!{pattern}"))?;
+    }
+
+    let walker = WalkBuilder::new(root)
+        .standard_filters(options.respect_gitignore)
+        .require_git(false)
+        .hidden(!options.include_hidden)
+        .follow_links(options.follow_symlinks)
+        .overrides(overrides.build()?)
+        .build();
+
+    let mut tree = Node::default();
+    let mut files = BTreeMap::new();
+    for entry in walker {
+        let entry = entry?;
+        let Ok(relative) = entry.path().strip_prefix(root) else {
+            continue;
+        };
+        if relative.as_os_str().is_empty() {
+            continue;
+        }
+        let Some(file_type) = entry.file_type() else {
+            continue;
+        };
+
+        if file_type.is_dir() {
+            tree.insert(relative, None);
+        } else if file_type.is_file() {
+            let hash = hash_file(entry.path())?;
+            tree.insert(relative, Some(hash));
+            files.insert(relative.to_path_buf(), hash);
+        }
+    }
+
+    Ok(DirHash {
+        hash: tree.hash(),
+        files,
+    })
+}
+
+/// A directory in the tree being hashed.
+#[derive(Default)]
+struct Node {
+    dirs: BTreeMap<String, Node>,
+    files: BTreeMap<String, u64>,
+}
+
+impl Node {
+    /// Records a directory (`hash` is `None`) or a file at `relative`.
+    fn insert(&mut self, relative: &Path, hash: Option<u64>) {
+        let mut names: Vec<String> = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let Some(last) = names.pop() else {
+            return;
+        };
+
+        let mut node = self;
+        for name in names {
+            node = node.dirs.entry(name).or_default();
+        }
+        match hash {
+            Some(hash) => {
+                node.files.insert(last, hash);
+            }
+            None => {
+                node.dirs.entry(last).or_default();
+            }
+        }
+    }
+
+    /// Hashes the sorted `(kind, name, hash)` records of this directory's entries.
+    fn hash(&self) -> u64 {
+        let mut hasher = XxHasher::new();
+        for (name, dir) in &self.dirs {
+            hasher.update(b"d");
+            hasher.update(name.as_bytes());
+            hasher.update(&[0]);
+            hasher.update(&dir.hash().to_le_bytes());
+        }
+        for (name, hash) in &self.files {
+            hasher.update(b"f");
+            hasher.update(name.as_bytes());
+            hasher.update(&[0]);
+            hasher.update(&hash.to_le_bytes());
+        }
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xx_hash_bytes;
+
+    fn fixture() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("hello.txt"), "hello").unwrap();
+        fs::create_dir(dir.path().join("nested")).unwrap();
+        fs::write(dir.path().join("nested/data.bin"), [0u8, 1, 2, 3]).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_hash_file_matches_bytes() {
+        let dir = fixture();
+        assert_eq!(
+            hash_file(dir.path().join("hello.txt")).unwrap(),
+            xx_hash_bytes(b"hello")
+        );
+        assert!(matches!(
+            hash_file(dir.path().join("missing.txt")),
+            Err(FileHashError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_hash_dir_is_deterministic_across_locations() {
+        let a = fixture();
+        let b = fixture();
+        let options = HashDirOptions::default();
+
+        let first = hash_dir(a.path(), &options).unwrap();
+        assert_eq!(first, hash_dir(b.path(), &options).unwrap());
+        assert_eq!(
+            first.files.keys().collect::<Vec<_>>(),
+            [Path::new("hello.txt"), Path::new("nested/data.bin")]
+        );
+    }
+
+    #[test]
+    fn test_hash_dir_detects_changes() {
+        let dir = fixture();
+        let options = HashDirOptions::default();
+        let original = hash_dir(dir.path(), &options).unwrap().hash;
+
+        fs::write(dir.path().join("nested/data.bin"), [9u8]).unwrap();
+        let edited = hash_dir(dir.path(), &options).unwrap().hash;
+        assert_ne!(original, edited);
+
+        fs::create_dir(dir.path().join("empty")).unwrap();
+        assert_ne!(edited, hash_dir(dir.path(), &options).unwrap().hash);
+    }
+
+    #[test]
+    fn test_hash_dir_distinguishes_moves() {
+        let a = tempfile::tempdir().unwrap();
+        fs::create_dir(a.path().join("x")).unwrap();
+        fs::write(a.path().join("x/y"), "same").unwrap();
+
+        let b = tempfile::tempdir().unwrap();
+        fs::write(b.path().join("x"), "").unwrap();
+        fs::write(b.path().join("y"), "same").unwrap();
+
+        let options = HashDirOptions::default();
+        assert_ne!(
+            hash_dir(a.path(), &options).unwrap().hash,
+            hash_dir(b.path(), &options).unwrap().hash
+        );
+    }
+
+    #[test]
+    fn test_hash_dir_respects_ignore_patterns() {
+        let dir = fixture();
+        let baseline = hash_dir(dir.path(), &HashDirOptions::default()).unwrap();
+
+        fs::create_dir(dir.path().join("target")).unwrap();
+        fs::write(dir.path().join("target/out.o"), "build output").unwrap();
+        fs::write(dir.path().join("debug.log"), "noise").unwrap();
+        fs::write(dir.path().join(".env"), "SECRET=1").unwrap();
+        fs::write(dir.path().join(".gitignore"), "*.log\n").unwrap();
+
+        let options = HashDirOptions::default().with_ignore("target");
+        assert_eq!(hash_dir(dir.path(), &options).unwrap(), baseline);
+
+        let everything = HashDirOptions::default()
+            .with_gitignore(false)
+            .with_hidden(true);
+        let files = hash_dir(dir.path(), &everything).unwrap().files;
+        assert!(files.contains_key(Path::new("debug.log")));
+        assert!(files.contains_key(Path::new(".env")));
+        assert!(files.contains_key(Path::new("target/out.o")));
+    }
+
+    #[test]
+    fn test_hash_dir_rejects_files() {
+        let dir = fixture();
+        let err = hash_dir(dir.path().join("hello.txt"), &HashDirOptions::default()).unwrap_err();
+        assert!(matches!(err, FileHashError::Io { .. }));
+    }
+}
@@ -0,0 +1,120 @@
+//! HMAC-SHA256 for webhook signatures and signed tokens.
+//!
+//! GitHub, Stripe, Slack and most other webhook providers sign each delivery
+//! with HMAC-SHA256 over the raw request body and send the tag hex-encoded in
+//! a header. [`verify_hmac_sha256_hex`] checks such a signature in constant
+//! time; [`hmac_sha256`] produces tags for signing tokens or outgoing requests.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use biscuit_hash::{hmac_sha256_hex, verify_hmac_sha256_hex};
+//!
+//! let secret = b"webhook-secret";
+//! let body = br#"{"action":"opened"}"#;
+//!
+//! // e.g. the `X-Hub-Signature-256: sha256=<hex>` header
+//! let header = format!("sha256={}", hmac_sha256_hex(secret, body));
+//! let signature = header.strip_prefix("sha256=").unwrap();
+//! assert!(verify_hmac_sha256_hex(secret, body, signature));
+//! ```
+
+use ::hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::sha::to_hex;
+use crate::verify::decode_hex;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Creates the MAC for `key`; HMAC accepts keys of any length.
+fn mac(key: &[u8], data: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac
+}
+
+/// Computes the HMAC-SHA256 tag of `data` under `key`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{hmac_sha256, to_base64_url};
+///
+/// let tag = hmac_sha256(b"signing-key", b"user=42");
+/// let token = format!("user=42.{}", to_base64_url(&tag));
+/// ```
+#[inline]
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    mac(key, data).finalize().into_bytes().into()
+}
+
+/// Computes the HMAC-SHA256 tag of `data` under `key` as lowercase hex.
+#[inline]
+pub fn hmac_sha256_hex(key: &[u8], data: &[u8]) -> String {
+    to_hex(&hmac_sha256(key, data))
+}
+
+/// Checks in constant time that `tag` is the HMAC-SHA256 tag of `data` under
+/// `key`.
+#[inline]
+pub fn verify_hmac_sha256(key: &[u8], data: &[u8], tag: &[u8]) -> bool {
+    mac(key, data).verify_slice(tag).is_ok()
+}
+
+/// Checks in constant time that `tag_hex` (either case) is the hex-encoded
+/// HMAC-SHA256 tag of `data` under `key`.
+///
+/// Returns `false` if `tag_hex` is not valid hex. Strip any scheme prefix
+/// such as `sha256=` first.
+pub fn verify_hmac_sha256_hex(key: &[u8], data: &[u8], tag_hex: &str) -> bool {
+    decode_hex(tag_hex).is_some_and(|tag| verify_hmac_sha256(key, data, &tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_sha256_rfc4231() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            hmac_sha256_hex(b"Jefe", b"what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        // Test case 6: keys longer than the block size are hashed first
+        assert_eq!(
+            hmac_sha256_hex(
+                &[0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First"
+            ),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn test_verify_hmac_sha256() {
+        let tag = hmac_sha256(b"key", b"payload");
+        assert!(verify_hmac_sha256(b"key", b"payload", &tag));
+        assert!(!verify_hmac_sha256(b"key", b"payload!", &tag));
+        assert!(!verify_hmac_sha256(b"other", b"payload", &tag));
+        assert!(!verify_hmac_sha256(b"key", b"payload", &tag[..16]));
+    }
+
+    #[test]
+    fn test_verify_hmac_sha256_hex() {
+        let hex = hmac_sha256_hex(b"key", b"payload");
+        assert!(verify_hmac_sha256_hex(b"key", b"payload", &hex));
+        assert!(verify_hmac_sha256_hex(
+            b"key",
+            b"payload",
+            &hex.to_uppercase()
+        ));
+        assert!(!verify_hmac_sha256_hex(
+            b"key",
+            b"payload",
+            &format!("sha256={hex}")
+        ));
+        assert!(!verify_hmac_sha256_hex(b"key", b"payload", ""));
+    }
+}
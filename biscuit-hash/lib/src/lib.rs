@@ -3,17 +3,39 @@
 //! This crate provides various hashing algorithms with feature flags to control
 //! which implementations are included:
 //!
-//! - **`xx_hash`** (default): Fast non-cryptographic hashing using XXH64
-//! - **`blake3`**: Fast cryptographic hashing using BLAKE3
+//! - **`xx_hash`** (default): Fast non-cryptographic hashing using XXH64 and XXH3 (64 and 128-bit)
+//! - **`blake3`**: Fast cryptographic hashing using BLAKE3, including keyed hashing
+//! - **`sha2`**: SHA-256/SHA-512 with hex and base64 output, for external systems that require SHA-2, and HMAC-SHA256
 //! - **`argon2id`**: Secure password hashing using Argon2id
+//! - **`sketch`**: Bloom filter and HyperLogLog built on XXH64
+//! - **`ulid`**: Sortable unique identifiers (ULIDs) for request IDs and idempotency keys
+//! - **`manifest`**: `SHA256SUMS`/`B3SUMS` checksum manifests for directories (implies `blake3` and `sha2`)
+//! - **`fs`**: File content hashes and merkle-style directory tree hashes (implies `xx_hash`)
+//! - **`serde`**: `Serialize`/`Deserialize` for [`ContentHash`]
+//!
+//! [`XxHasher`] and [`Blake3Hasher`] hash content incrementally, and
+//! [`hash_reader`] streams any [`Read`](std::io::Read) source through them, so
+//! large files never need to be loaded into memory.
+//!
+//! [`ContentHash`] pairs a digest with its algorithm (`xxh64:ef46db37…`), so
+//! stored hashes say how they were made.
+//!
+//! Signatures and keyed hashes are checked with [`constant_time_eq`]
+//! (available with `blake3` or `sha2`), so verification doesn't leak timing.
 //!
 //! ## Feature Flags
 //!
 //! | Feature | Default | Description |
 //! |---------|---------|-------------|
-//! | `xx_hash` | Yes | XXH64 for content hashing, change detection |
-//! | `blake3` | No | BLAKE3 for cryptographic integrity |
+//! | `xx_hash` | Yes | XXH64 and XXH3 for content hashing, change detection |
+//! | `blake3` | No | BLAKE3 for cryptographic integrity and keyed hashes |
+//! | `sha2` | No | SHA-256/SHA-512 for registries and ETags, HMAC-SHA256 for webhook signatures |
 //! | `argon2id` | No | Argon2id for password storage |
+//! | `sketch` | No | Bloom filter and HyperLogLog for large-scale dedup (implies `xx_hash`) |
+//! | `ulid` | No | ULID generation for request IDs and idempotency keys |
+//! | `manifest` | No | Checksum manifest creation and verification (implies `blake3` and `sha2`) |
+//! | `fs` | No | `hash_file`/`hash_dir` for change detection (implies `xx_hash`) |
+//! | `serde` | No | `ContentHash` serializes as its `algorithm:hex` string |
 //!
 //! ## Examples
 //!
@@ -32,20 +54,80 @@ pub mod xx;
 #[cfg(feature = "blake3")]
 pub mod blake;
 
+#[cfg(feature = "sha2")]
+pub mod sha;
+
+#[cfg(feature = "sha2")]
+pub mod hmac;
+
+#[cfg(any(feature = "blake3", feature = "sha2"))]
+pub mod verify;
+
 #[cfg(feature = "argon2id")]
 pub mod argon;
 
+#[cfg(feature = "sketch")]
+pub mod sketch;
+
+#[cfg(feature = "ulid")]
+pub mod ulid;
+
+#[cfg(feature = "manifest")]
+pub mod manifest;
+
+#[cfg(feature = "fs")]
+pub mod files;
+
+pub mod content_hash;
+pub mod stream;
+
 // Re-exports for convenience
 
+pub use content_hash::{ContentHash, ContentHashError, HashAlgorithm};
+pub use stream::hash_reader;
+
 #[cfg(feature = "xx_hash")]
-pub use xx::{HashVariant, xx_hash, xx_hash_bytes, xx_hash_variant};
+pub use xx::{
+    HashOutput, HashVariant, XxHasher, xx_hash, xx_hash_bytes, xx_hash_variant, xx_hash3,
+    xx_hash3_bytes, xx_hash3_variant, xx_hash128, xx_hash128_bytes, xx_hash128_variant,
+};
 
 #[cfg(feature = "blake3")]
-pub use blake::{blake3_hash, blake3_hash_bytes, blake3_hash_trimmed};
+pub use blake::{
+    Blake3Hasher, blake3_hash, blake3_hash_bytes, blake3_hash_trimmed, blake3_keyed_hash,
+    blake3_keyed_hash_bytes, verify_blake3_keyed_hash,
+};
+
+#[cfg(feature = "sha2")]
+pub use sha::{
+    Sha256Hasher, Sha512Hasher, sha256_base64, sha256_hash, sha256_hash_bytes, sha512_base64,
+    sha512_hash, sha512_hash_bytes, to_base64, to_base64_url, to_hex,
+};
+
+#[cfg(feature = "sha2")]
+pub use hmac::{hmac_sha256, hmac_sha256_hex, verify_hmac_sha256, verify_hmac_sha256_hex};
+
+#[cfg(any(feature = "blake3", feature = "sha2"))]
+pub use verify::constant_time_eq;
 
 #[cfg(feature = "argon2id")]
 pub use argon::{
     Argon2idError, DEFAULT_MEMORY_COST_KIB, DEFAULT_OUTPUT_LEN, DEFAULT_PARALLELISM,
-    DEFAULT_TIME_COST, hash_password, hash_password_with_params, hash_password_with_salt,
-    verify_password,
+    DEFAULT_TIME_COST, PasswordParams, hash_password, hash_password_with_params,
+    hash_password_with_salt, needs_rehash, parse_params, verify_password,
 };
+
+#[cfg(feature = "sketch")]
+pub use sketch::{BloomFilter, HLL_MAX_PRECISION, HLL_MIN_PRECISION, HyperLogLog, SketchError};
+
+#[cfg(feature = "ulid")]
+pub use ulid::{ULID_LEN, ulid, ulid_from_parts};
+
+#[cfg(feature = "manifest")]
+pub use manifest::{
+    ManifestAlgorithm, ManifestEntry, ManifestError, VerificationReport, parse_manifest,
+    verify_manifest, verify_manifest_with, write_manifest,
+};
+
+#[cfg(feature = "fs")]
+pub use files::{DirHash, FileHashError, HashDirOptions, hash_dir, hash_file};
@@ -0,0 +1,445 @@
+//! Checksum manifests for directories of files.
+//!
+//! A manifest lists one digest per file in the format written by `sha256sum`
+//! and `b3sum`: the hex digest, two spaces and the file's path relative to
+//! the manifest, with `/` separators.
+//!
+//! ```text
+//! 2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824  report.html
+//! 486ea46224d1bb4fb680f34f7c9ad96a8f24ec88be73ea8e5a6c65260e9cb8a7  assets/logo.png
+//! ```
+//!
+//! [`write_manifest`] hashes every file under a directory and
+//! [`verify_manifest`] checks the files against the manifest, so a download
+//! or export can be integrity-checked with one call. Manifests can also be
+//! checked with `sha256sum -c SHA256SUMS` or `b3sum -c B3SUMS`.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use biscuit_hash::{ManifestAlgorithm, verify_manifest, write_manifest};
+//!
+//! let dir = std::env::temp_dir().join(format!("biscuit-manifest-{}", std::process::id()));
+//! std::fs::create_dir_all(&dir).unwrap();
+//! std::fs::write(dir.join("report.html"), "<h1>Report</h1>").unwrap();
+//!
+//! let manifest = dir.join(ManifestAlgorithm::Sha256.file_name());
+//! write_manifest(&dir, ManifestAlgorithm::Sha256, &manifest).unwrap();
+//!
+//! let report = verify_manifest(&manifest).unwrap();
+//! assert!(report.is_ok());
+//! # std::fs::remove_dir_all(&dir).unwrap();
+//! ```
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// Size of the buffer files are streamed through while hashing.
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Errors that can occur while writing or reading a manifest.
+#[derive(Debug, Error)]
+pub enum ManifestError {
+    /// A file or directory could not be read or written.
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// A manifest line is not `<digest>  <path>`.
+    #[error("malformed manifest line {line}: {content}")]
+    Malformed { line: usize, content: String },
+
+    /// A file path cannot be written to a manifest (not UTF-8, or contains a newline).
+    #[error("unsupported path in manifest: {0}")]
+    UnsupportedPath(PathBuf),
+}
+
+impl ManifestError {
+    fn io(path: &Path, source: io::Error) -> Self {
+        Self::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+/// The digest algorithm a manifest is written with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ManifestAlgorithm {
+    /// SHA-256, as written by `sha256sum`
+    Sha256,
+    /// BLAKE3, as written by `b3sum`
+    Blake3,
+}
+
+impl ManifestAlgorithm {
+    /// The conventional manifest file name: `SHA256SUMS` or `B3SUMS`.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            ManifestAlgorithm::Sha256 => "SHA256SUMS",
+            ManifestAlgorithm::Blake3 => "B3SUMS",
+        }
+    }
+
+    /// Guesses the algorithm from a manifest's file name.
+    ///
+    /// Names mentioning `b3` or `blake3` (`B3SUMS`, `export.blake3`) are
+    /// BLAKE3; anything else is SHA-256, since both produce 64 hex digits.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use biscuit_hash::ManifestAlgorithm;
+    /// use std::path::Path;
+    ///
+    /// assert_eq!(ManifestAlgorithm::from_path(Path::new("B3SUMS")), ManifestAlgorithm::Blake3);
+    /// assert_eq!(ManifestAlgorithm::from_path(Path::new("SHA256SUMS")), ManifestAlgorithm::Sha256);
+    /// ```
+    pub fn from_path(path: &Path) -> Self {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_ascii_lowercase())
+            .unwrap_or_default();
+        if name.contains("b3") || name.contains("blake3") {
+            ManifestAlgorithm::Blake3
+        } else {
+            ManifestAlgorithm::Sha256
+        }
+    }
+
+    /// Hashes everything `reader` yields and returns the lowercase hex digest.
+    fn digest_reader<R: Read>(self, mut reader: R) -> io::Result<String> {
+        let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+        match self {
+            ManifestAlgorithm::Sha256 => {
+                let mut hasher = Sha256::new();
+                loop {
+                    let read = reader.read(&mut buffer)?;
+                    if read == 0 {
+                        break;
+                    }
+                    hasher.update(&buffer[..read]);
+                }
+                Ok(format!("{:x}", hasher.finalize()))
+            }
+            ManifestAlgorithm::Blake3 => {
+                let mut hasher = blake3::Hasher::new();
+                loop {
+                    let read = reader.read(&mut buffer)?;
+                    if read == 0 {
+                        break;
+                    }
+                    hasher.update(&buffer[..read]);
+                }
+                Ok(hasher.finalize().to_hex().to_string())
+            }
+        }
+    }
+}
+
+impl fmt::Display for ManifestAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ManifestAlgorithm::Sha256 => "SHA-256",
+            ManifestAlgorithm::Blake3 => "BLAKE3",
+        })
+    }
+}
+
+/// One file listed in a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    /// Lowercase hex digest of the file's contents
+    pub digest: String,
+    /// Path relative to the manifest's directory, with `/` separators
+    pub path: String,
+}
+
+/// The outcome of checking files against a manifest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// Files whose digest matches the manifest
+    pub verified: Vec<String>,
+    /// Files whose digest differs from the manifest
+    pub mismatched: Vec<String>,
+    /// Files listed in the manifest that no longer exist
+    pub missing: Vec<String>,
+}
+
+impl VerificationReport {
+    /// Whether every listed file exists and matches its digest.
+    pub fn is_ok(&self) -> bool {
+        self.mismatched.is_empty() && self.missing.is_empty()
+    }
+}
+
+/// Hashes every file under `dir` and writes the manifest to `path`.
+///
+/// Files are listed in sorted order, relative to `path`'s directory, which
+/// must contain `dir`. The manifest itself is skipped if it lives under `dir`.
+/// Returns the entries written.
+///
+/// ## Errors
+///
+/// Returns an error if a file cannot be read, the manifest cannot be written,
+/// or a file path is not valid UTF-8, contains a newline, or lies outside the
+/// manifest's directory.
+pub fn write_manifest(
+    dir: &Path,
+    algo: ManifestAlgorithm,
+    path: &Path,
+) -> Result<Vec<ManifestEntry>, ManifestError> {
+    let base = manifest_dir(path);
+    let base = fs::canonicalize(&base).map_err(|e| ManifestError::io(&base, e))?;
+    let manifest = base.join(path.file_name().unwrap_or_default());
+
+    let dir = fs::canonicalize(dir).map_err(|e| ManifestError::io(dir, e))?;
+    let mut files = Vec::new();
+    collect_files(&dir, &mut files)?;
+
+    let mut entries = Vec::new();
+    for file in files {
+        if file == manifest {
+            continue;
+        }
+        let relative = file
+            .strip_prefix(&base)
+            .map_err(|_| ManifestError::UnsupportedPath(file.clone()))?;
+        let relative = relative
+            .components()
+            .map(|component| component.as_os_str().to_str())
+            .collect::<Option<Vec<_>>>()
+            .map(|parts| parts.join("/"))
+            .filter(|relative| !relative.contains('\n'))
+            .ok_or_else(|| ManifestError::UnsupportedPath(file.clone()))?;
+
+        let digest = hash_file(&file, algo)?;
+        entries.push(ManifestEntry {
+            digest,
+            path: relative,
+        });
+    }
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let content: String = entries
+        .iter()
+        .map(|entry| format!("{}  {}\n", entry.digest, entry.path))
+        .collect();
+    fs::write(path, content).map_err(|e| ManifestError::io(path, e))?;
+
+    Ok(entries)
+}
+
+/// Checks the files listed in the manifest at `path` against their digests.
+///
+/// The algorithm is chosen from the manifest's file name (see
+/// [`ManifestAlgorithm::from_path`]); use [`verify_manifest_with`] to set it.
+///
+/// ## Errors
+///
+/// Returns an error if the manifest cannot be read or parsed, or a listed
+/// file exists but cannot be read.
+pub fn verify_manifest(path: &Path) -> Result<VerificationReport, ManifestError> {
+    verify_manifest_with(path, ManifestAlgorithm::from_path(path))
+}
+
+/// Like [`verify_manifest`], with an explicit algorithm.
+///
+/// ## Errors
+///
+/// Returns an error if the manifest cannot be read or parsed, or a listed
+/// file exists but cannot be read.
+pub fn verify_manifest_with(
+    path: &Path,
+    algo: ManifestAlgorithm,
+) -> Result<VerificationReport, ManifestError> {
+    let content = fs::read_to_string(path).map_err(|e| ManifestError::io(path, e))?;
+    let base = manifest_dir(path);
+
+    let mut report = VerificationReport::default();
+    for entry in parse_manifest(&content)? {
+        let file = base.join(&entry.path);
+        match File::open(&file) {
+            Ok(reader) => {
+                let digest = algo
+                    .digest_reader(reader)
+                    .map_err(|e| ManifestError::io(&file, e))?;
+                if digest.eq_ignore_ascii_case(&entry.digest) {
+                    report.verified.push(entry.path);
+                } else {
+                    report.mismatched.push(entry.path);
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => report.missing.push(entry.path),
+            Err(e) => return Err(ManifestError::io(&file, e)),
+        }
+    }
+
+    Ok(report)
+}
+
+/// Parses `<digest>  <path>` lines; a `*` before the path (binary mode) is accepted.
+///
+/// Blank lines are skipped.
+///
+/// ## Errors
+///
+/// Returns [`ManifestError::Malformed`] for any other line.
+pub fn parse_manifest(content: &str) -> Result<Vec<ManifestEntry>, ManifestError> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let malformed = || ManifestError::Malformed {
+                line: index + 1,
+                content: line.to_string(),
+            };
+            let (digest, rest) = line.split_once(' ').ok_or_else(malformed)?;
+            let path = rest
+                .strip_prefix(' ')
+                .or_else(|| rest.strip_prefix('*'))
+                .ok_or_else(malformed)?;
+            if digest.is_empty()
+                || !digest.chars().all(|c| c.is_ascii_hexdigit())
+                || path.is_empty()
+            {
+                return Err(malformed());
+            }
+            Ok(ManifestEntry {
+                digest: digest.to_ascii_lowercase(),
+                path: path.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The directory manifest paths are relative to.
+fn manifest_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+fn hash_file(path: &Path, algo: ManifestAlgorithm) -> Result<String, ManifestError> {
+    let file = File::open(path).map_err(|e| ManifestError::io(path, e))?;
+    algo.digest_reader(file)
+        .map_err(|e| ManifestError::io(path, e))
+}
+
+/// Recursively collects the files under `dir`, following symlinks.
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), ManifestError> {
+    let entries = fs::read_dir(dir).map_err(|e| ManifestError::io(dir, e))?;
+    for entry in entries {
+        let path = entry.map_err(|e| ManifestError::io(dir, e))?.path();
+        let metadata = fs::metadata(&path).map_err(|e| ManifestError::io(&path, e))?;
+        if metadata.is_dir() {
+            collect_files(&path, files)?;
+        } else if metadata.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("hello.txt"), "hello").unwrap();
+        fs::create_dir(dir.path().join("nested")).unwrap();
+        fs::write(dir.path().join("nested/data.bin"), [0u8, 1, 2, 3]).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_write_manifest_matches_sha256sum_format() {
+        let dir = fixture();
+        let manifest = dir.path().join("SHA256SUMS");
+
+        let entries = write_manifest(dir.path(), ManifestAlgorithm::Sha256, &manifest).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        let content = fs::read_to_string(&manifest).unwrap();
+        assert_eq!(
+            content.lines().next().unwrap(),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824  hello.txt"
+        );
+        assert!(
+            content
+                .lines()
+                .nth(1)
+                .unwrap()
+                .ends_with("  nested/data.bin")
+        );
+        assert_eq!(content.lines().count(), 2, "manifest must not list itself");
+    }
+
+    #[test]
+    fn test_verify_manifest_reports_changes() {
+        let dir = fixture();
+        let manifest = dir.path().join("B3SUMS");
+        write_manifest(dir.path(), ManifestAlgorithm::Blake3, &manifest).unwrap();
+        assert!(
+            fs::read_to_string(&manifest)
+                .unwrap()
+                .starts_with(&crate::blake3_hash("hello"))
+        );
+
+        let report = verify_manifest(&manifest).unwrap();
+        assert!(report.is_ok());
+        assert_eq!(report.verified, vec!["hello.txt", "nested/data.bin"]);
+
+        fs::write(dir.path().join("hello.txt"), "goodbye").unwrap();
+        fs::remove_file(dir.path().join("nested/data.bin")).unwrap();
+        let report = verify_manifest(&manifest).unwrap();
+        assert!(!report.is_ok());
+        assert_eq!(report.mismatched, vec!["hello.txt"]);
+        assert_eq!(report.missing, vec!["nested/data.bin"]);
+    }
+
+    #[test]
+    fn test_manifest_outside_dir_uses_relative_paths() {
+        let root = tempfile::tempdir().unwrap();
+        let export = root.path().join("export");
+        fs::create_dir(&export).unwrap();
+        fs::write(export.join("report.html"), "<h1>Report</h1>").unwrap();
+        let manifest = root.path().join("export.sha256");
+
+        let entries = write_manifest(&export, ManifestAlgorithm::Sha256, &manifest).unwrap();
+        assert_eq!(entries[0].path, "export/report.html");
+        assert!(verify_manifest(&manifest).unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_parse_manifest_accepts_binary_marker() {
+        let entries = parse_manifest("ABC123 *dist/app.tar.gz\n\nabc123  notes.md\n").unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ManifestEntry {
+                    digest: "abc123".to_string(),
+                    path: "dist/app.tar.gz".to_string(),
+                },
+                ManifestEntry {
+                    digest: "abc123".to_string(),
+                    path: "notes.md".to_string(),
+                },
+            ]
+        );
+
+        let err = parse_manifest("abc123 notes.md").unwrap_err();
+        assert!(matches!(err, ManifestError::Malformed { line: 1, .. }));
+    }
+}
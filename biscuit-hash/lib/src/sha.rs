@@ -0,0 +1,288 @@
+//! SHA-256 and SHA-512 hashing for interoperability.
+//!
+//! BLAKE3 is the better choice for new integrity checks, but package
+//! registries, webhook signatures, `Digest`/ETag headers and Subresource
+//! Integrity all expect SHA-2. This module wraps the SHA-2 family with the
+//! same shape as the BLAKE3 helpers, plus hex and base64 encoders for the raw
+//! digests.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use biscuit_hash::{sha256_base64, sha256_hash, sha512_hash};
+//!
+//! let hash = sha256_hash("hello");
+//! assert_eq!(hash, "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824");
+//! assert_eq!(sha512_hash("hello").len(), 128); // 64 bytes = 128 hex chars
+//!
+//! // Subresource Integrity / `Digest: sha-256=...` style
+//! let integrity = format!("sha256-{}", sha256_base64(b"hello"));
+//! ```
+
+use std::io;
+
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use sha2::{Digest, Sha256, Sha512};
+
+/// Computes the SHA-256 hash of the input string and returns it as a hex string.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::sha256_hash;
+///
+/// let hash = sha256_hash("Hello, World!");
+/// assert_eq!(hash.len(), 64); // 32 bytes = 64 hex chars
+/// ```
+#[inline]
+pub fn sha256_hash(data: &str) -> String {
+    to_hex(&sha256_hash_bytes(data.as_bytes()))
+}
+
+/// Computes the SHA-256 hash of the input bytes and returns the raw 32-byte hash.
+#[inline]
+pub fn sha256_hash_bytes(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+/// Computes the SHA-256 hash of the input bytes and returns it base64-encoded
+/// (standard alphabet, padded), as used by SRI and `Digest` headers.
+#[inline]
+pub fn sha256_base64(data: &[u8]) -> String {
+    to_base64(&sha256_hash_bytes(data))
+}
+
+/// Computes the SHA-512 hash of the input string and returns it as a hex string.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::sha512_hash;
+///
+/// let hash = sha512_hash("Hello, World!");
+/// assert_eq!(hash.len(), 128); // 64 bytes = 128 hex chars
+/// ```
+#[inline]
+pub fn sha512_hash(data: &str) -> String {
+    to_hex(&sha512_hash_bytes(data.as_bytes()))
+}
+
+/// Computes the SHA-512 hash of the input bytes and returns the raw 64-byte hash.
+#[inline]
+pub fn sha512_hash_bytes(data: &[u8]) -> [u8; 64] {
+    Sha512::digest(data).into()
+}
+
+/// Computes the SHA-512 hash of the input bytes and returns it base64-encoded
+/// (standard alphabet, padded), as used by npm `integrity` fields.
+#[inline]
+pub fn sha512_base64(data: &[u8]) -> String {
+    to_base64(&sha512_hash_bytes(data))
+}
+
+/// Encodes bytes as lowercase hex.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::to_hex;
+///
+/// assert_eq!(to_hex(&[0x00, 0xab, 0xff]), "00abff");
+/// ```
+pub fn to_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        hex.push(DIGITS[usize::from(byte >> 4)] as char);
+        hex.push(DIGITS[usize::from(byte & 0x0f)] as char);
+    }
+    hex
+}
+
+/// Encodes bytes as base64 with the standard alphabet and padding.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::to_base64;
+///
+/// assert_eq!(to_base64(&[0xfb, 0xff]), "+/8=");
+/// ```
+pub fn to_base64(bytes: &[u8]) -> String {
+    STANDARD.encode(bytes)
+}
+
+/// Encodes bytes as URL-safe base64 without padding, for digests embedded in
+/// URLs, file names or JWT-style tokens.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::to_base64_url;
+///
+/// assert_eq!(to_base64_url(&[0xfb, 0xff]), "-_8");
+/// ```
+pub fn to_base64_url(bytes: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Incremental SHA-256 hasher for content that arrives in chunks.
+///
+/// Implements [`io::Write`], so it can be fed with [`io::copy`] or
+/// [`crate::hash_reader`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{Sha256Hasher, sha256_hash};
+///
+/// let mut hasher = Sha256Hasher::new();
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// assert_eq!(hasher.finish(), sha256_hash("Hello, World!"));
+/// ```
+#[derive(Clone, Default)]
+pub struct Sha256Hasher {
+    state: Sha256,
+}
+
+impl Sha256Hasher {
+    /// Creates a hasher with no data written.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `data` to the hash.
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+    }
+
+    /// Returns the hex-encoded hash of everything written so far.
+    pub fn finish(&self) -> String {
+        to_hex(&self.finish_bytes())
+    }
+
+    /// Returns the raw 32-byte hash of everything written so far.
+    pub fn finish_bytes(&self) -> [u8; 32] {
+        self.state.clone().finalize().into()
+    }
+}
+
+impl io::Write for Sha256Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Incremental SHA-512 hasher for content that arrives in chunks.
+///
+/// Implements [`io::Write`], so it can be fed with [`io::copy`] or
+/// [`crate::hash_reader`].
+#[derive(Clone, Default)]
+pub struct Sha512Hasher {
+    state: Sha512,
+}
+
+impl Sha512Hasher {
+    /// Creates a hasher with no data written.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `data` to the hash.
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+    }
+
+    /// Returns the hex-encoded hash of everything written so far.
+    pub fn finish(&self) -> String {
+        to_hex(&self.finish_bytes())
+    }
+
+    /// Returns the raw 64-byte hash of everything written so far.
+    pub fn finish_bytes(&self) -> [u8; 64] {
+        self.state.clone().finalize().into()
+    }
+}
+
+impl io::Write for Sha512Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_known_values() {
+        assert_eq!(
+            sha256_hash(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(to_hex(&sha256_hash_bytes(b"abc")), sha256_hash("abc"));
+    }
+
+    #[test]
+    fn test_sha512_known_values() {
+        assert_eq!(
+            sha512_hash("abc"),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        assert_eq!(sha512_hash_bytes(b"abc").len(), 64);
+    }
+
+    #[test]
+    fn test_base64_digests() {
+        // `echo -n hello | openssl dgst -sha256 -binary | base64`
+        assert_eq!(
+            sha256_base64(b"hello"),
+            "LPJNul+wow4m6DsqxbninhsWHlwfp0JecwQzYpOLmCQ="
+        );
+        assert_eq!(
+            to_base64_url(&sha256_hash_bytes(b"hello")),
+            "LPJNul-wow4m6DsqxbninhsWHlwfp0JecwQzYpOLmCQ"
+        );
+        assert_eq!(sha512_base64(b"hello").len(), 88);
+    }
+
+    #[test]
+    fn test_to_hex() {
+        assert_eq!(to_hex(&[]), "");
+        assert_eq!(to_hex(&[0x01, 0x23, 0xef]), "0123ef");
+    }
+
+    #[test]
+    fn test_streaming_hashers_match_one_shot() {
+        let data = "The quick brown fox jumps over the lazy dog".repeat(100);
+
+        let mut sha256 = Sha256Hasher::new();
+        let mut sha512 = Sha512Hasher::new();
+        for chunk in data.as_bytes().chunks(33) {
+            sha256.update(chunk);
+            sha512.update(chunk);
+        }
+        assert_eq!(sha256.finish(), sha256_hash(&data));
+        assert_eq!(sha512.finish(), sha512_hash(&data));
+
+        let hasher: Sha256Hasher = crate::hash_reader(data.as_bytes()).unwrap();
+        assert_eq!(hasher.finish_bytes(), sha256_hash_bytes(data.as_bytes()));
+    }
+}
@@ -0,0 +1,489 @@
+//! Probabilistic set sketches built on XXH64.
+//!
+//! This module provides two memory-efficient data structures for working
+//! with very large sets where storing every element is impractical:
+//!
+//! - [`BloomFilter`] - "have I seen this before?" membership tests with a
+//!   tunable false-positive rate and no false negatives
+//! - [`HyperLogLog`] - approximate count of distinct elements using a few
+//!   kilobytes of memory regardless of cardinality
+//!
+//! Both hash their input with [`xx_hash_bytes`], so any `AsRef<[u8]>`
+//! (strings, byte slices, paths converted to bytes) can be inserted.
+//!
+//! ## When to Use
+//!
+//! Use sketches for:
+//! - Deduplicating URLs while scraping
+//! - Skipping files already processed in a cache pass
+//! - Estimating how many unique items a stream contains
+//!
+//! Do NOT use when an exact answer is required; both structures trade
+//! accuracy for memory.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use biscuit_hash::{BloomFilter, HyperLogLog};
+//!
+//! let mut seen = BloomFilter::new(10_000, 0.01);
+//! assert!(seen.insert("https://example.com"));
+//! assert!(!seen.insert("https://example.com")); // already seen
+//!
+//! let mut unique = HyperLogLog::new(12);
+//! for i in 0..1000 {
+//!     unique.insert(format!("item-{}", i % 250));
+//! }
+//! assert!((unique.count() as i64 - 250).abs() < 25);
+//! ```
+
+use thiserror::Error;
+
+use crate::xx::xx_hash_bytes;
+
+/// Errors that can occur when combining sketches.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SketchError {
+    /// The two sketches were created with different parameters.
+    #[error("incompatible sketches: {0}")]
+    Incompatible(String),
+}
+
+/// Derives a second, independent-looking hash from the first (SplitMix64
+/// finalizer), used for double hashing.
+#[inline]
+fn mix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+/// A Bloom filter for approximate set membership.
+///
+/// A Bloom filter answers "possibly in the set" or "definitely not in the
+/// set". False positives occur at roughly the rate chosen at construction
+/// (as long as no more than the expected number of items are inserted);
+/// false negatives never occur.
+///
+/// Bit positions are derived from a single XXH64 hash using double hashing
+/// (Kirsch–Mitzenmacher), so each insert or lookup hashes the item once.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::BloomFilter;
+///
+/// let mut filter = BloomFilter::new(1_000, 0.01);
+/// filter.insert("src/main.rs");
+///
+/// assert!(filter.contains("src/main.rs"));
+/// assert!(!filter.contains("src/lib.rs")); // almost certainly
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+    len: usize,
+}
+
+impl BloomFilter {
+    /// Creates a filter sized for `expected_items` with the target
+    /// `false_positive_rate`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `false_positive_rate` is not strictly between 0 and 1.
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "false_positive_rate must be between 0 and 1 (exclusive)"
+        );
+
+        let n = expected_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        // Optimal bit count m = -n ln(p) / (ln 2)^2 and hash count k = (m/n) ln 2
+        let num_bits = (-n * false_positive_rate.ln() / (ln2 * ln2)).ceil() as u64;
+        let num_hashes = ((num_bits as f64 / n) * ln2).round() as u32;
+
+        Self::with_params(num_bits, num_hashes)
+    }
+
+    /// Creates a filter with an explicit bit count and number of hash
+    /// functions.
+    ///
+    /// Both values are clamped to a minimum of 1.
+    pub fn with_params(num_bits: u64, num_hashes: u32) -> Self {
+        let num_bits = num_bits.max(1);
+        let words = num_bits.div_ceil(64) as usize;
+        Self {
+            bits: vec![0; words],
+            num_bits,
+            num_hashes: num_hashes.max(1),
+            len: 0,
+        }
+    }
+
+    /// Returns the bit positions for an item.
+    fn positions<T: AsRef<[u8]>>(&self, item: T) -> impl Iterator<Item = u64> + use<T> {
+        let h1 = xx_hash_bytes(item.as_ref());
+        // An odd step guarantees distinct positions when num_bits is a power of two
+        let h2 = mix64(h1) | 1;
+        let num_bits = self.num_bits;
+        (0..self.num_hashes as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+    }
+
+    /// Inserts an item, returning `true` if it was not already present.
+    ///
+    /// A return value of `false` means the item was *probably* inserted
+    /// before, which makes this suitable for "process once" loops.
+    pub fn insert<T: AsRef<[u8]>>(&mut self, item: T) -> bool {
+        let mut added = false;
+        for pos in self.positions(item) {
+            let (word, mask) = ((pos / 64) as usize, 1u64 << (pos % 64));
+            if self.bits[word] & mask == 0 {
+                self.bits[word] |= mask;
+                added = true;
+            }
+        }
+        if added {
+            self.len += 1;
+        }
+        added
+    }
+
+    /// Returns `true` if the item is possibly in the set, `false` if it is
+    /// definitely not.
+    pub fn contains<T: AsRef<[u8]>>(&self, item: T) -> bool {
+        self.positions(item)
+            .all(|pos| self.bits[(pos / 64) as usize] & (1u64 << (pos % 64)) != 0)
+    }
+
+    /// Returns the number of distinct items inserted (items reported as
+    /// already present by [`insert`](Self::insert) are not counted).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been inserted.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the size of the filter in bits.
+    pub fn num_bits(&self) -> u64 {
+        self.num_bits
+    }
+
+    /// Returns the number of hash functions applied per item.
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// Estimates the current false-positive rate from the fraction of bits set.
+    pub fn false_positive_rate(&self) -> f64 {
+        let set: u64 = self.bits.iter().map(|w| w.count_ones() as u64).sum();
+        (set as f64 / self.num_bits as f64).powi(self.num_hashes as i32)
+    }
+
+    /// Removes all items from the filter.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+        self.len = 0;
+    }
+
+    /// Merges another filter into this one (set union).
+    ///
+    /// After merging, [`len`](Self::len) is an upper bound since items
+    /// present in both filters are counted twice.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SketchError::Incompatible`] if the filters have different
+    /// sizes or hash counts.
+    pub fn union(&mut self, other: &BloomFilter) -> Result<(), SketchError> {
+        if self.num_bits != other.num_bits || self.num_hashes != other.num_hashes {
+            return Err(SketchError::Incompatible(format!(
+                "bloom filter {}b/{}k vs {}b/{}k",
+                self.num_bits, self.num_hashes, other.num_bits, other.num_hashes
+            )));
+        }
+        for (word, other_word) in self.bits.iter_mut().zip(&other.bits) {
+            *word |= *other_word;
+        }
+        self.len += other.len;
+        Ok(())
+    }
+}
+
+/// Minimum supported HyperLogLog precision.
+pub const HLL_MIN_PRECISION: u8 = 4;
+/// Maximum supported HyperLogLog precision.
+pub const HLL_MAX_PRECISION: u8 = 18;
+
+/// A HyperLogLog sketch for estimating the number of distinct items.
+///
+/// Memory use is `2^precision` bytes and the standard error of the estimate
+/// is roughly `1.04 / sqrt(2^precision)`; precision 12 uses 4 KiB for about
+/// 1.6% error, precision 14 uses 16 KiB for about 0.8%.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::HyperLogLog;
+///
+/// let mut hll = HyperLogLog::new(14);
+/// for i in 0..10_000 {
+///     hll.insert(i.to_string());
+/// }
+///
+/// let estimate = hll.count() as f64;
+/// assert!((estimate - 10_000.0).abs() / 10_000.0 < 0.05);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Creates an empty sketch with `2^precision` registers.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `precision` is outside
+    /// [`HLL_MIN_PRECISION`]..=[`HLL_MAX_PRECISION`].
+    pub fn new(precision: u8) -> Self {
+        assert!(
+            (HLL_MIN_PRECISION..=HLL_MAX_PRECISION).contains(&precision),
+            "precision must be between {HLL_MIN_PRECISION} and {HLL_MAX_PRECISION}"
+        );
+        Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    /// Returns the precision (number of index bits).
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Adds an item to the sketch.
+    pub fn insert<T: AsRef<[u8]>>(&mut self, item: T) {
+        self.insert_hash(xx_hash_bytes(item.as_ref()));
+    }
+
+    /// Adds a pre-computed 64-bit hash to the sketch.
+    ///
+    /// Useful when items were already hashed with
+    /// [`xx_hash`](crate::xx_hash) for other purposes.
+    pub fn insert_hash(&mut self, hash: u64) {
+        let p = self.precision as u32;
+        let index = (hash >> (64 - p)) as usize;
+        // Rank = position of the first 1-bit in the remaining bits (1-based);
+        // the sentinel bit caps the rank when all remaining bits are zero
+        let remaining = (hash << p) | (1 << (p - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    /// Returns the estimated number of distinct items inserted.
+    pub fn count(&self) -> u64 {
+        self.estimate().round() as u64
+    }
+
+    /// Returns the raw cardinality estimate.
+    pub fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let raw = alpha * m * m / sum;
+
+        // Small-range correction: linear counting while registers are empty
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        if raw <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            raw
+        }
+    }
+
+    /// Returns the expected relative standard error of the estimate.
+    pub fn standard_error(&self) -> f64 {
+        1.04 / (self.registers.len() as f64).sqrt()
+    }
+
+    /// Returns `true` if nothing has been inserted.
+    pub fn is_empty(&self) -> bool {
+        self.registers.iter().all(|&r| r == 0)
+    }
+
+    /// Removes all items from the sketch.
+    pub fn clear(&mut self) {
+        self.registers.fill(0);
+    }
+
+    /// Merges another sketch into this one; the result estimates the
+    /// cardinality of the union.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SketchError::Incompatible`] if the sketches have different
+    /// precision.
+    pub fn merge(&mut self, other: &HyperLogLog) -> Result<(), SketchError> {
+        if self.precision != other.precision {
+            return Err(SketchError::Incompatible(format!(
+                "hyperloglog precision {} vs {}",
+                self.precision, other.precision
+            )));
+        }
+        for (reg, &other_reg) in self.registers.iter_mut().zip(&other.registers) {
+            *reg = (*reg).max(other_reg);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bloom_no_false_negatives() {
+        let mut filter = BloomFilter::new(1_000, 0.01);
+        for i in 0..1_000 {
+            filter.insert(format!("url-{i}"));
+        }
+        for i in 0..1_000 {
+            assert!(filter.contains(format!("url-{i}")));
+        }
+    }
+
+    #[test]
+    fn test_bloom_false_positive_rate_near_target() {
+        let mut filter = BloomFilter::new(5_000, 0.01);
+        for i in 0..5_000 {
+            filter.insert(format!("seen-{i}"));
+        }
+        let false_positives = (0..10_000)
+            .filter(|i| filter.contains(format!("unseen-{i}")))
+            .count();
+        // Target is 1%; allow generous slack for randomness
+        assert!(
+            false_positives < 250,
+            "too many false positives: {false_positives}"
+        );
+        assert!(filter.false_positive_rate() < 0.02);
+    }
+
+    #[test]
+    fn test_bloom_insert_reports_duplicates() {
+        let mut filter = BloomFilter::new(100, 0.001);
+        assert!(filter.insert("a"));
+        assert!(!filter.insert("a"));
+        assert!(filter.insert(b"b".as_slice()));
+        assert_eq!(filter.len(), 2);
+
+        filter.clear();
+        assert!(filter.is_empty());
+        assert!(!filter.contains("a"));
+    }
+
+    #[test]
+    fn test_bloom_sizing() {
+        let filter = BloomFilter::new(1_000, 0.01);
+        // ~9.6 bits per item and 7 hashes for a 1% target
+        assert_eq!(filter.num_bits(), 9_586);
+        assert_eq!(filter.num_hashes(), 7);
+    }
+
+    #[test]
+    fn test_bloom_union() {
+        let mut a = BloomFilter::new(100, 0.01);
+        let mut b = BloomFilter::new(100, 0.01);
+        a.insert("left");
+        b.insert("right");
+        a.union(&b).unwrap();
+        assert!(a.contains("left"));
+        assert!(a.contains("right"));
+
+        let c = BloomFilter::new(10, 0.1);
+        assert!(matches!(a.union(&c), Err(SketchError::Incompatible(_))));
+    }
+
+    #[test]
+    #[should_panic(expected = "false_positive_rate")]
+    fn test_bloom_rejects_invalid_rate() {
+        let _ = BloomFilter::new(10, 1.0);
+    }
+
+    #[test]
+    fn test_hll_empty() {
+        let hll = HyperLogLog::new(10);
+        assert!(hll.is_empty());
+        assert_eq!(hll.count(), 0);
+    }
+
+    #[test]
+    fn test_hll_small_cardinality_is_exact_enough() {
+        let mut hll = HyperLogLog::new(12);
+        for i in 0..100 {
+            hll.insert(format!("file-{i}"));
+            hll.insert(format!("file-{i}")); // duplicates don't count
+        }
+        let count = hll.count() as i64;
+        assert!((count - 100).abs() <= 3, "estimate {count}");
+    }
+
+    #[test]
+    fn test_hll_large_cardinality_within_error() {
+        let mut hll = HyperLogLog::new(14);
+        let n = 200_000;
+        for i in 0..n {
+            hll.insert(i.to_string());
+        }
+        let error = (hll.estimate() - n as f64).abs() / n as f64;
+        assert!(error < 3.0 * hll.standard_error(), "error {error}");
+    }
+
+    #[test]
+    fn test_hll_merge() {
+        let mut a = HyperLogLog::new(12);
+        let mut b = HyperLogLog::new(12);
+        for i in 0..5_000 {
+            a.insert(format!("a-{i}"));
+            b.insert(format!("b-{i}"));
+        }
+        a.merge(&b).unwrap();
+        let error = (a.estimate() - 10_000.0).abs() / 10_000.0;
+        assert!(error < 0.05, "error {error}");
+
+        let c = HyperLogLog::new(8);
+        assert!(a.merge(&c).is_err());
+    }
+
+    #[test]
+    fn test_hll_insert_hash_matches_insert() {
+        let mut a = HyperLogLog::new(8);
+        let mut b = HyperLogLog::new(8);
+        a.insert("hello");
+        b.insert_hash(crate::xx_hash("hello"));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "precision")]
+    fn test_hll_rejects_invalid_precision() {
+        let _ = HyperLogLog::new(2);
+    }
+}
@@ -0,0 +1,124 @@
+//! Streaming hashes of [`Read`] sources.
+//!
+//! The one-shot functions such as [`xx_hash`](crate::xx_hash) need the whole
+//! input in memory. [`hash_reader`] instead feeds a reader through any hasher
+//! implementing [`Write`], such as [`XxHasher`](crate::XxHasher) or
+//! [`Blake3Hasher`](crate::Blake3Hasher), so large files are hashed in
+//! fixed-size chunks.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use biscuit_hash::{XxHasher, hash_reader, xx_hash};
+//!
+//! let file: &[u8] = b"Hello, World!"; // any `Read`, e.g. `std::fs::File`
+//! let hasher: XxHasher = hash_reader(file).unwrap();
+//! assert_eq!(hasher.finish(), xx_hash("Hello, World!"));
+//! ```
+
+use std::io::{self, Read, Write};
+
+/// Hashes everything `reader` yields with a new `H` and returns the hasher.
+///
+/// Call the hasher's `finish` method for the digest.
+///
+/// ## Errors
+///
+/// Returns an error if reading from `reader` fails.
+///
+/// ## Examples
+///
+/// ```rust,no_run
+/// use biscuit_hash::{XxHasher, hash_reader};
+/// use std::fs::File;
+///
+/// let file = File::open("large.bin")?;
+/// let hash = hash_reader::<XxHasher>(file)?.finish();
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn hash_reader<H: Write + Default>(mut reader: impl Read) -> io::Result<H> {
+    let mut hasher = H::default();
+    io::copy(&mut reader, &mut hasher)?;
+    Ok(hasher)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A reader that hands out at most `chunk` bytes per call.
+    struct Trickle<'a> {
+        data: &'a [u8],
+        chunk: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.chunk.min(buf.len()).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk on fire"))
+        }
+    }
+
+    #[test]
+    fn reads_everything_in_chunks() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| i as u8).collect();
+        let reader = Trickle {
+            data: &data,
+            chunk: 7,
+        };
+        let copied: Vec<u8> = hash_reader(reader).unwrap();
+        assert_eq!(copied, data);
+    }
+
+    #[cfg(feature = "xx_hash")]
+    #[test]
+    fn xx_reader_matches_one_shot() {
+        let data = vec![7u8; 100_000];
+        let reader = Trickle {
+            data: &data,
+            chunk: 4093,
+        };
+        let hasher: crate::XxHasher = hash_reader(reader).unwrap();
+        assert_eq!(hasher.finish(), crate::xx_hash_bytes(&data));
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn blake3_reader_matches_one_shot() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| i as u8).collect();
+        let reader = Trickle {
+            data: &data,
+            chunk: 4093,
+        };
+        let hasher: crate::Blake3Hasher = hash_reader(reader).unwrap();
+        assert_eq!(hasher.finish_bytes(), crate::blake3_hash_bytes(&data));
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn hashes_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.txt");
+        std::fs::write(&path, "Hello, World!").unwrap();
+
+        let file = std::fs::File::open(&path).unwrap();
+        let hasher = hash_reader::<crate::Blake3Hasher>(file).unwrap();
+        assert_eq!(hasher.finish(), crate::blake3_hash("Hello, World!"));
+    }
+
+    #[test]
+    fn read_errors_are_returned() {
+        let err = hash_reader::<io::Sink>(Failing).unwrap_err();
+        assert_eq!(err.to_string(), "disk on fire");
+    }
+}
@@ -0,0 +1,112 @@
+//! ULID generation for sortable unique identifiers.
+//!
+//! A [ULID](https://github.com/ulid/spec) is a 128-bit identifier made of a
+//! 48-bit millisecond timestamp followed by 80 random bits, written as 26
+//! Crockford base32 characters. ULIDs sort lexicographically by creation time
+//! and are safe to use in URLs and HTTP headers, which makes them a good fit
+//! for request IDs and idempotency keys.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use biscuit_hash::{ulid, ulid_from_parts};
+//!
+//! let id = ulid();
+//! assert_eq!(id.len(), 26);
+//!
+//! // The timestamp prefix is deterministic
+//! let id = ulid_from_parts(1_469_918_176_385, 0);
+//! assert!(id.starts_with("01ARYZ6S41"));
+//! ```
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Crockford's base32 alphabet (no I, L, O or U).
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Number of characters in an encoded ULID.
+pub const ULID_LEN: usize = 26;
+
+/// Mask for the 80 random bits.
+const RANDOM_MASK: u128 = (1 << 80) - 1;
+
+/// Mask for the 48 timestamp bits.
+const TIMESTAMP_MASK: u64 = (1 << 48) - 1;
+
+/// Generates a new ULID from the current time and a random component.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::ulid;
+///
+/// let a = ulid();
+/// let b = ulid();
+/// assert_ne!(a, b);
+/// assert!(a.chars().all(|c| c.is_ascii_alphanumeric()));
+/// ```
+pub fn ulid() -> String {
+    let timestamp_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0);
+    ulid_from_parts(timestamp_ms, rand::random())
+}
+
+/// Encodes a ULID from a millisecond timestamp and random bits.
+///
+/// Only the low 48 bits of `timestamp_ms` and the low 80 bits of `random`
+/// are used.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::ulid_from_parts;
+///
+/// assert_eq!(ulid_from_parts(0, 0), "00000000000000000000000000");
+/// assert!(ulid_from_parts(1, 0) < ulid_from_parts(2, 0));
+/// ```
+pub fn ulid_from_parts(timestamp_ms: u64, random: u128) -> String {
+    let value = (u128::from(timestamp_ms & TIMESTAMP_MASK) << 80) | (random & RANDOM_MASK);
+
+    // 26 characters x 5 bits = 130 bits, so the first character holds only 3
+    (0..ULID_LEN)
+        .map(|index| {
+            let shift = 5 * (ULID_LEN - 1 - index);
+            CROCKFORD[((value >> shift) & 0x1f) as usize] as char
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_spec_timestamp_prefix() {
+        // Example from the ULID spec: 01ARYZ6S41TSV4RRFFQ69G5FAV
+        let id = ulid_from_parts(1_469_918_176_385, 0);
+        assert_eq!(&id[..10], "01ARYZ6S41");
+        assert_eq!(&id[10..], "0000000000000000");
+    }
+
+    #[test]
+    fn uses_full_width_of_both_parts() {
+        let id = ulid_from_parts(u64::MAX, u128::MAX);
+        assert_eq!(id, "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    }
+
+    #[test]
+    fn generated_ulids_are_unique_and_well_formed() {
+        let ids: Vec<String> = (0..100).map(|_| ulid()).collect();
+        for id in &ids {
+            assert_eq!(id.len(), ULID_LEN);
+            assert!(id.bytes().all(|b| CROCKFORD.contains(&b)));
+        }
+
+        let mut unique = ids.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), ids.len());
+    }
+}
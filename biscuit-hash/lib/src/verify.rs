@@ -0,0 +1,70 @@
+//! Constant-time comparison for signatures and tokens.
+//!
+//! Comparing a secret-derived value (an HMAC tag, a keyed hash, an API token)
+//! with `==` returns as soon as a byte differs, so response timing reveals how
+//! much of a forged value was correct. [`constant_time_eq`] takes the same
+//! time for every input of a given length.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use biscuit_hash::constant_time_eq;
+//!
+//! assert!(constant_time_eq(b"token-123", b"token-123"));
+//! assert!(!constant_time_eq(b"token-123", b"token-124"));
+//! ```
+
+use subtle::ConstantTimeEq;
+
+/// Compares two byte slices in constant time.
+///
+/// Slices of different lengths are unequal; the lengths themselves are not
+/// treated as secret.
+#[inline]
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.ct_eq(b).into()
+}
+
+/// Decodes a hex string (either case) into bytes.
+///
+/// Returns `None` for an odd length or a non-hex character.
+pub(crate) fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    fn nibble(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let bytes = hex.as_bytes();
+    if !bytes.len().is_multiple_of(2) {
+        return None;
+    }
+    bytes
+        .chunks_exact(2)
+        .map(|pair| Some(nibble(pair[0])? << 4 | nibble(pair[1])?))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"", b""));
+        assert!(constant_time_eq(b"abc", b"abc"));
+        assert!(!constant_time_eq(b"abc", b"abd"));
+        assert!(!constant_time_eq(b"abc", b"abcd"));
+    }
+
+    #[test]
+    fn test_decode_hex() {
+        assert_eq!(decode_hex("00abFF"), Some(vec![0x00, 0xab, 0xff]));
+        assert_eq!(decode_hex(""), Some(vec![]));
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+}
@@ -1,4 +1,4 @@
-//! XXH64 hashing utilities for fast, non-cryptographic hashing.
+//! xxHash utilities (XXH64, XXH3-64, XXH3-128) for fast, non-cryptographic hashing.
 //!
 //! This module provides wrappers around the xxHash algorithm for common
 //! use cases like content hashing, change detection, and caching.
@@ -7,7 +7,11 @@
 //!
 //! - [`xx_hash`] - Direct hash of a string
 //! - [`xx_hash_bytes`] - Direct hash of a byte slice
+//! - [`xx_hash3`] / [`xx_hash128`] - XXH3 64-bit and 128-bit hashes of a string
 //! - [`xx_hash_variant`] - Hash with configurable normalization via [`HashVariant`]
+//!   ([`xx_hash3_variant`] and [`xx_hash128_variant`] for XXH3)
+//! - [`HashOutput`] - Hex, integer and Base58 renderings of any of the above
+//! - [`XxHasher`] - Incremental hash of data written in chunks
 //!
 //! ## Examples
 //!
@@ -32,7 +36,9 @@
 //! ```
 
 use std::collections::HashMap;
-use xxhash_rust::xxh64::xxh64;
+use std::io;
+use xxhash_rust::xxh3::{xxh3_64, xxh3_128};
+use xxhash_rust::xxh64::{Xxh64, xxh64};
 
 /// The **HashVariant** enumeration lets you express characteristics about
 /// the content you're hashing which you want to remove from being a factor
@@ -105,6 +111,239 @@ pub fn xx_hash_bytes(data: &[u8]) -> u64 {
     xxh64(data, 0)
 }
 
+/// Incremental XXH64 hasher for content that arrives in chunks.
+///
+/// Produces the same hash as [`xx_hash_bytes`] over the concatenation of
+/// everything written. Implements [`io::Write`], so it can be fed with
+/// [`io::copy`] or [`crate::hash_reader`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{XxHasher, xx_hash};
+///
+/// let mut hasher = XxHasher::new();
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// assert_eq!(hasher.finish(), xx_hash("Hello, World!"));
+/// ```
+#[derive(Clone)]
+pub struct XxHasher {
+    state: Xxh64,
+}
+
+impl XxHasher {
+    /// Creates a hasher with no data written.
+    pub fn new() -> Self {
+        Self {
+            state: Xxh64::new(0),
+        }
+    }
+
+    /// Adds `data` to the hash.
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+    }
+
+    /// Returns the hash of everything written so far.
+    ///
+    /// The hasher is left untouched, so more data can be added afterwards.
+    pub fn finish(&self) -> u64 {
+        self.state.digest()
+    }
+}
+
+impl Default for XxHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl io::Write for XxHasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Computes the XXH3 64-bit hash of the input string.
+///
+/// XXH3 is considerably faster than XXH64 on short inputs and produces
+/// different values, so the two are not interchangeable.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{xx_hash, xx_hash3};
+///
+/// let hash = xx_hash3("Hello, World!");
+/// assert_eq!(hash, xx_hash3("Hello, World!"));
+/// assert_ne!(hash, xx_hash("Hello, World!"));
+/// ```
+#[inline]
+pub fn xx_hash3(data: &str) -> u64 {
+    xxh3_64(data.as_bytes())
+}
+
+/// Computes the XXH3 64-bit hash of the input bytes.
+#[inline]
+pub fn xx_hash3_bytes(data: &[u8]) -> u64 {
+    xxh3_64(data)
+}
+
+/// Computes the XXH3 128-bit hash of the input string.
+///
+/// Use this over [`xx_hash3`] when hashing enough items that 64-bit
+/// collisions become a concern (e.g. content-addressed keys).
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{HashOutput, xx_hash128};
+///
+/// let hash = HashOutput::from(xx_hash128("Hello, World!"));
+/// assert_eq!(hash.to_hex().len(), 32);
+/// ```
+#[inline]
+pub fn xx_hash128(data: &str) -> u128 {
+    xxh3_128(data.as_bytes())
+}
+
+/// Computes the XXH3 128-bit hash of the input bytes.
+#[inline]
+pub fn xx_hash128_bytes(data: &[u8]) -> u128 {
+    xxh3_128(data)
+}
+
+/// The result of one of the xxHash functions, rendered consistently
+/// regardless of its width.
+///
+/// Build one with `From<u64>` (XXH64, XXH3-64) or `From<u128>` (XXH3-128).
+/// Every rendering is big-endian and zero-padded to the full width, so
+/// equal hashes always produce equal strings.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{HashOutput, xx_hash3};
+///
+/// let hash = HashOutput::from(xx_hash3("hello"));
+/// assert_eq!(hash.to_hex().len(), 16);
+/// assert_eq!(hash.as_u64(), Some(xx_hash3("hello")));
+/// assert_eq!(hash.to_string(), hash.to_hex());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashOutput {
+    /// A 64-bit hash (XXH64 or XXH3-64).
+    U64(u64),
+    /// A 128-bit hash (XXH3-128).
+    U128(u128),
+}
+
+impl HashOutput {
+    /// The width of the hash in bits.
+    pub fn bits(&self) -> u32 {
+        match self {
+            Self::U64(_) => 64,
+            Self::U128(_) => 128,
+        }
+    }
+
+    /// Returns the hash as a `u64`, or `None` for a 128-bit hash.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::U64(v) => Some(*v),
+            Self::U128(_) => None,
+        }
+    }
+
+    /// Returns the hash as a `u128`; 64-bit hashes are zero-extended.
+    pub fn as_u128(&self) -> u128 {
+        match self {
+            Self::U64(v) => u128::from(*v),
+            Self::U128(v) => *v,
+        }
+    }
+
+    /// Big-endian bytes of the hash (8 or 16 of them).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::U64(v) => v.to_be_bytes().to_vec(),
+            Self::U128(v) => v.to_be_bytes().to_vec(),
+        }
+    }
+
+    /// Lowercase hex, zero-padded to 16 or 32 characters.
+    pub fn to_hex(&self) -> String {
+        match self {
+            Self::U64(v) => format!("{v:016x}"),
+            Self::U128(v) => format!("{v:032x}"),
+        }
+    }
+
+    /// Base58 (Bitcoin alphabet) of [`to_bytes`](Self::to_bytes).
+    ///
+    /// Shorter than hex and free of ambiguous characters (`0`, `O`, `I`, `l`),
+    /// which suits identifiers that people read or type.
+    pub fn to_base58(&self) -> String {
+        base58_encode(&self.to_bytes())
+    }
+}
+
+impl From<u64> for HashOutput {
+    fn from(value: u64) -> Self {
+        Self::U64(value)
+    }
+}
+
+impl From<u128> for HashOutput {
+    fn from(value: u128) -> Self {
+        Self::U128(value)
+    }
+}
+
+impl std::fmt::Display for HashOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encodes `bytes` as Base58; each leading zero byte becomes a `1`.
+fn base58_encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+
+    // Little-endian base-58 digits of the remaining big-endian number
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for &byte in &bytes[zeros..] {
+        let mut carry = u32::from(byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut out = String::with_capacity(zeros + digits.len());
+    out.extend(std::iter::repeat_n('1', zeros));
+    out.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&d| BASE58_ALPHABET[d as usize] as char),
+    );
+    out
+}
+
 /// Produces an xxHash of a _mutated_ version of the content/data passed in.
 ///
 /// The caller provides one or more `HashVariant` enum variants that describe
@@ -147,6 +386,36 @@ pub fn xx_hash_variant(data: &str, variants: Vec<HashVariant>) -> u64 {
         return xx_hash(data);
     }
 
+    xxh64(normalize(data, &variants).as_bytes(), 0)
+}
+
+/// Like [`xx_hash_variant`] but hashes the normalized content with XXH3-64.
+///
+/// ## Examples
+///
+/// ```rust
+/// use biscuit_hash::{xx_hash3, xx_hash3_variant, HashVariant};
+///
+/// assert_eq!(
+///     xx_hash3_variant("  hello  ", vec![HashVariant::BlockTrimming]),
+///     xx_hash3("hello")
+/// );
+/// ```
+pub fn xx_hash3_variant(data: &str, variants: Vec<HashVariant>) -> u64 {
+    xxh3_64(normalize(data, &variants).as_bytes())
+}
+
+/// Like [`xx_hash_variant`] but hashes the normalized content with XXH3-128.
+pub fn xx_hash128_variant(data: &str, variants: Vec<HashVariant>) -> u128 {
+    xxh3_128(normalize(data, &variants).as_bytes())
+}
+
+/// Applies `variants` to `data` in the order documented on [`xx_hash_variant`].
+fn normalize(data: &str, variants: &[HashVariant]) -> String {
+    if variants.is_empty() {
+        return data.to_string();
+    }
+
     // Check which variants are requested
     let has_block_trimming = variants
         .iter()
@@ -241,7 +510,7 @@ pub fn xx_hash_variant(data: &str, variants: Vec<HashVariant>) -> u64 {
         result = result.chars().filter(|c| !drop_chars.contains(c)).collect();
     }
 
-    xxh64(result.as_bytes(), 0)
+    result
 }
 
 /// Collapses runs of whitespace characters to single spaces.
@@ -599,4 +868,81 @@ mod tests {
             xx_hash_variant(data, variants)
         );
     }
+
+    #[test]
+    fn test_xx_hasher_matches_one_shot() {
+        let data = "The quick brown fox jumps over the lazy dog".repeat(1000);
+
+        let mut hasher = XxHasher::new();
+        for chunk in data.as_bytes().chunks(17) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finish(), xx_hash(&data));
+        assert_eq!(XxHasher::new().finish(), xx_hash(""));
+    }
+
+    #[test]
+    fn test_xx_hasher_write() {
+        use std::io::Write;
+
+        let mut hasher = XxHasher::default();
+        let name = "World";
+        write!(hasher, "Hello, {name}!").unwrap();
+        assert_eq!(hasher.finish(), xx_hash("Hello, World!"));
+    }
+
+    // ===========================================
+    // XXH3 and HashOutput tests
+    // ===========================================
+
+    #[test]
+    fn test_xx_hash3_known_values() {
+        // Reference values from the xxHash specification test vectors
+        assert_eq!(xx_hash3(""), 0x2D06_8005_38D3_94C2);
+        assert_eq!(xx_hash128(""), 0x99AA_06D3_0147_98D8_6001_C324_468D_497F);
+        assert_eq!(xx_hash3_bytes(b"abc"), xx_hash3("abc"));
+        assert_eq!(xx_hash128_bytes(b"abc"), xx_hash128("abc"));
+    }
+
+    #[test]
+    fn test_xx_hash3_variants_normalize() {
+        let variants = vec![HashVariant::BlockTrimming, HashVariant::BlankLine];
+        assert_eq!(
+            xx_hash3_variant("  a\n\nb  ", variants.clone()),
+            xx_hash3("a\nb")
+        );
+        assert_eq!(
+            xx_hash128_variant("  a\n\nb  ", variants),
+            xx_hash128("a\nb")
+        );
+        assert_eq!(xx_hash3_variant("hello", vec![]), xx_hash3("hello"));
+    }
+
+    #[test]
+    fn test_hash_output_renderings() {
+        let small = HashOutput::from(1u64);
+        assert_eq!(small.bits(), 64);
+        assert_eq!(small.to_hex(), "0000000000000001");
+        assert_eq!(small.as_u64(), Some(1));
+        assert_eq!(small.as_u128(), 1);
+        assert_eq!(small.to_base58(), "11111112");
+
+        let wide = HashOutput::from(u128::MAX);
+        assert_eq!(wide.bits(), 128);
+        assert_eq!(wide.to_hex(), "f".repeat(32));
+        assert_eq!(wide.as_u64(), None);
+        assert_eq!(wide.to_bytes(), vec![0xff; 16]);
+        assert_eq!(format!("{wide}"), wide.to_hex());
+    }
+
+    #[test]
+    fn test_base58_encode() {
+        assert_eq!(base58_encode(b""), "");
+        assert_eq!(base58_encode(&[0, 0]), "11");
+        assert_eq!(base58_encode(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+        assert_eq!(
+            base58_encode(&[0x00, 0x00, 0x28, 0x7f, 0xb4, 0xcd]),
+            "11233QC4"
+        );
+    }
 }
@@ -0,0 +1,573 @@
+//! Word timing and caption generation for synthesized speech.
+//!
+//! Providers that synthesize to an audio file report the time at which each
+//! word is spoken as a list of [`WordBoundary`] values. These drive two
+//! features:
+//!
+//! - **Progress callbacks**: a [`WordBoundaryCallback`] set on the
+//!   [`TtsConfig`](crate::types::TtsConfig) is invoked as each word starts
+//!   playing, so a UI can highlight the text being spoken.
+//! - **Caption files**: [`to_captions`] and [`write_captions`] render the
+//!   boundaries as SRT or WebVTT cues to store alongside the audio.
+//!
+//! ## Timing Sources
+//!
+//! Echogarden writes a word-level timeline next to its audio, which is used
+//! when present. For every other provider the timings are estimated by
+//! spreading the audio's duration across the words in proportion to their
+//! length; the duration is read from the WAV header, or derived from a
+//! typical speaking rate for compressed formats.
+//!
+//! ## Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use biscuit_speaks::captions::{estimate_word_boundaries, to_captions, CaptionFormat};
+//!
+//! let words = estimate_word_boundaries("Hello there, world.", Duration::from_secs(2));
+//! assert_eq!(words.len(), 3);
+//! assert_eq!(words[1].word, "there,");
+//!
+//! let vtt = to_captions(&words, CaptionFormat::WebVtt);
+//! assert!(vtt.starts_with("WEBVTT"));
+//! ```
+
+use std::fmt::Write as _;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::TtsError;
+use crate::types::{AudioFormat, SpeedLevel};
+
+/// Typical speaking rate used when an audio file's duration is unknown.
+const WORDS_PER_MINUTE: f64 = 150.0;
+
+/// Maximum number of words shown in a single caption cue.
+const MAX_CUE_WORDS: usize = 7;
+
+// ============================================================================
+// Word Boundaries
+// ============================================================================
+
+/// The time span during which a single word is spoken.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordBoundary {
+    /// The word as it appears in the source text (including punctuation).
+    pub word: String,
+    /// Byte offset of the word in the source text.
+    pub offset: usize,
+    /// When the word starts, relative to the start of the audio.
+    pub start: Duration,
+    /// When the word ends, relative to the start of the audio.
+    pub end: Duration,
+}
+
+impl WordBoundary {
+    /// The byte range of the word in the source text.
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.offset..self.offset + self.word.len()
+    }
+}
+
+/// Callback invoked as each word starts playing.
+///
+/// Cloning the callback is cheap; all clones share the same closure.
+///
+/// ## Examples
+///
+/// ```
+/// use biscuit_speaks::captions::WordBoundaryCallback;
+///
+/// let callback = WordBoundaryCallback::new(|boundary| {
+///     println!("{:?}: {}", boundary.start, boundary.word);
+/// });
+/// ```
+#[derive(Clone)]
+pub struct WordBoundaryCallback(Arc<dyn Fn(&WordBoundary) + Send + Sync>);
+
+impl WordBoundaryCallback {
+    /// Wrap a closure as a word boundary callback.
+    pub fn new(callback: impl Fn(&WordBoundary) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    /// Invoke the callback for `boundary`.
+    pub fn call(&self, boundary: &WordBoundary) {
+        (self.0)(boundary)
+    }
+}
+
+impl std::fmt::Debug for WordBoundaryCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("WordBoundaryCallback")
+    }
+}
+
+/// Split `text` into words with byte offsets.
+fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split_whitespace()
+        .map(move |word| (word.as_ptr() as usize - text.as_ptr() as usize, word))
+}
+
+/// Spread `duration` across the words of `text` in proportion to their length.
+///
+/// Each word is weighted by its character count plus one, so short words
+/// still get a share of the time and the gaps between words are accounted for.
+pub fn estimate_word_boundaries(text: &str, duration: Duration) -> Vec<WordBoundary> {
+    let weights: Vec<usize> = words(text)
+        .map(|(_, word)| word.chars().count() + 1)
+        .collect();
+    let total: usize = weights.iter().sum();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut elapsed = 0;
+    words(text)
+        .zip(weights)
+        .map(|((offset, word), weight)| {
+            let start = duration.mul_f64(elapsed as f64 / total as f64);
+            elapsed += weight;
+            let end = duration.mul_f64(elapsed as f64 / total as f64);
+            WordBoundary {
+                word: word.to_string(),
+                offset,
+                start,
+                end,
+            }
+        })
+        .collect()
+}
+
+/// Estimate how long `text` takes to speak at a typical rate.
+pub fn estimated_duration(text: &str, speed: SpeedLevel) -> Duration {
+    let words = words(text).count() as f64;
+    Duration::from_secs_f64(words * 60.0 / (WORDS_PER_MINUTE * f64::from(speed.value())))
+}
+
+/// Read the playback duration from a WAV file's header.
+///
+/// Returns `None` if `data` is not a RIFF/WAVE file with `fmt ` and `data`
+/// chunks.
+pub fn wav_duration(data: &[u8]) -> Option<Duration> {
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+        return None;
+    }
+
+    let mut byte_rate = None;
+    let mut pos = 12;
+    while pos + 8 <= data.len() {
+        let id = &data[pos..pos + 4];
+        let size = u32::from_le_bytes(data[pos + 4..pos + 8].try_into().ok()?) as usize;
+        let body = pos + 8;
+
+        match id {
+            b"fmt " if body + 12 <= data.len() => {
+                byte_rate = Some(u32::from_le_bytes(
+                    data[body + 8..body + 12].try_into().ok()?,
+                ));
+            }
+            b"data" => {
+                let rate = byte_rate.filter(|rate| *rate > 0)?;
+                // Streamed WAVs may leave the data size unset; use what is present.
+                let size = size.min(data.len() - body);
+                return Some(Duration::from_secs_f64(size as f64 / f64::from(rate)));
+            }
+            _ => {}
+        }
+
+        // Chunks are padded to an even number of bytes
+        pos = body + size + (size & 1);
+    }
+
+    None
+}
+
+// ============================================================================
+// Echogarden Timelines
+// ============================================================================
+
+/// Path of the timeline Echogarden writes alongside `audio_path`.
+///
+/// For `speech.wav` this is `speech.timeline.json`.
+pub fn timeline_path(audio_path: &Path) -> PathBuf {
+    audio_path.with_extension("timeline.json")
+}
+
+/// Parse the word entries of an Echogarden timeline.
+///
+/// Echogarden nests words inside segment and sentence entries; every entry
+/// with `"type": "word"` is returned in order. Offsets are found by matching
+/// each word against `text`.
+///
+/// ## Errors
+///
+/// Returns an error if `json` is not valid JSON.
+pub fn parse_echogarden_timeline(
+    text: &str,
+    json: &str,
+) -> Result<Vec<WordBoundary>, serde_json::Error> {
+    fn collect(entries: &[serde_json::Value], out: &mut Vec<(String, f64, f64)>) {
+        for entry in entries {
+            if entry["type"] == "word" {
+                let word = entry["text"].as_str().unwrap_or_default();
+                let start = entry["startTime"].as_f64().unwrap_or_default();
+                let end = entry["endTime"].as_f64().unwrap_or(start);
+                out.push((word.to_string(), start, end));
+            } else if let Some(children) = entry["timeline"].as_array() {
+                collect(children, out);
+            }
+        }
+    }
+
+    let entries: Vec<serde_json::Value> = serde_json::from_str(json)?;
+    let mut timed = Vec::new();
+    collect(&entries, &mut timed);
+
+    let mut cursor = 0;
+    Ok(timed
+        .into_iter()
+        .filter(|(word, ..)| !word.trim().is_empty())
+        .map(|(word, start, end)| {
+            let offset = text[cursor..]
+                .find(&word)
+                .map_or(cursor, |found| cursor + found);
+            cursor = (offset + word.len()).min(text.len());
+            WordBoundary {
+                word,
+                offset,
+                start: Duration::from_secs_f64(start.max(0.0)),
+                end: Duration::from_secs_f64(end.max(start).max(0.0)),
+            }
+        })
+        .collect())
+}
+
+/// Word boundaries for `text` spoken in the audio file at `path`.
+///
+/// Uses an Echogarden timeline at [`timeline_path`] when one exists,
+/// otherwise estimates the boundaries from the audio's duration.
+pub fn word_boundaries_for_audio(
+    text: &str,
+    path: &Path,
+    format: AudioFormat,
+    speed: SpeedLevel,
+) -> Vec<WordBoundary> {
+    if let Ok(json) = std::fs::read_to_string(timeline_path(path)) {
+        match parse_echogarden_timeline(text, &json) {
+            Ok(boundaries) if !boundaries.is_empty() => return boundaries,
+            Ok(_) => {}
+            Err(e) => tracing::debug!(error = %e, "Ignoring unreadable timeline"),
+        }
+    }
+
+    let duration = match format {
+        AudioFormat::Wav => std::fs::read(path)
+            .ok()
+            .and_then(|data| wav_duration(&data)),
+        _ => None,
+    };
+
+    estimate_word_boundaries(
+        text,
+        duration.unwrap_or_else(|| estimated_duration(text, speed)),
+    )
+}
+
+/// Await `playback`, invoking `callback` as each boundary's start time passes.
+///
+/// Boundaries not yet reached when playback finishes are not reported.
+#[cfg_attr(not(feature = "playa"), allow(dead_code))]
+pub(crate) async fn follow_playback<F: Future>(
+    boundaries: &[WordBoundary],
+    callback: Option<&WordBoundaryCallback>,
+    playback: F,
+) -> F::Output {
+    let Some(callback) = callback else {
+        return playback.await;
+    };
+
+    let started = tokio::time::Instant::now();
+    let ticker = async {
+        for boundary in boundaries {
+            tokio::time::sleep_until(started + boundary.start).await;
+            callback.call(boundary);
+        }
+    };
+
+    tokio::pin!(playback);
+    tokio::select! {
+        output = &mut playback => output,
+        () = ticker => playback.await,
+    }
+}
+
+// ============================================================================
+// Captions
+// ============================================================================
+
+/// Caption file formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaptionFormat {
+    /// SubRip (`.srt`).
+    #[default]
+    Srt,
+    /// WebVTT (`.vtt`).
+    WebVtt,
+}
+
+impl CaptionFormat {
+    /// File extension for this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CaptionFormat::Srt => "srt",
+            CaptionFormat::WebVtt => "vtt",
+        }
+    }
+
+    fn timestamp(&self, time: Duration) -> String {
+        let millis = time.as_millis();
+        let separator = match self {
+            CaptionFormat::Srt => ',',
+            CaptionFormat::WebVtt => '.',
+        };
+        format!(
+            "{:02}:{:02}:{:02}{separator}{:03}",
+            millis / 3_600_000,
+            millis / 60_000 % 60,
+            millis / 1000 % 60,
+            millis % 1000
+        )
+    }
+}
+
+/// Group word boundaries into caption cues.
+///
+/// A cue ends after [`MAX_CUE_WORDS`] words or at the end of a sentence.
+fn cues(boundaries: &[WordBoundary]) -> Vec<&[WordBoundary]> {
+    let mut cues = Vec::new();
+    let mut start = 0;
+    for (i, boundary) in boundaries.iter().enumerate() {
+        let sentence_end = boundary.word.ends_with(['.', '!', '?']);
+        if sentence_end || i + 1 - start == MAX_CUE_WORDS {
+            cues.push(&boundaries[start..=i]);
+            start = i + 1;
+        }
+    }
+    if start < boundaries.len() {
+        cues.push(&boundaries[start..]);
+    }
+    cues
+}
+
+/// Render word boundaries as a caption file.
+pub fn to_captions(boundaries: &[WordBoundary], format: CaptionFormat) -> String {
+    let mut out = String::new();
+    if format == CaptionFormat::WebVtt {
+        out.push_str("WEBVTT\n\n");
+    }
+
+    for (i, cue) in cues(boundaries).into_iter().enumerate() {
+        let (Some(first), Some(last)) = (cue.first(), cue.last()) else {
+            continue;
+        };
+        if format == CaptionFormat::Srt {
+            let _ = writeln!(out, "{}", i + 1);
+        }
+        let text: Vec<&str> = cue.iter().map(|b| b.word.as_str()).collect();
+        let _ = writeln!(
+            out,
+            "{} --> {}\n{}\n",
+            format.timestamp(first.start),
+            format.timestamp(last.end),
+            text.join(" ")
+        );
+    }
+
+    out
+}
+
+/// Write word boundaries to a caption file at `path`.
+///
+/// ## Errors
+///
+/// Returns `TtsError::IoError` if the file cannot be written.
+pub fn write_captions(
+    path: &Path,
+    boundaries: &[WordBoundary],
+    format: CaptionFormat,
+) -> Result<(), TtsError> {
+    std::fs::write(path, to_captions(boundaries, format))?;
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wav(byte_rate: u32, data_len: usize) -> Vec<u8> {
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len as u32).to_le_bytes());
+        wav.extend_from_slice(b"WAVE");
+        wav.extend_from_slice(b"fmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+        wav.extend_from_slice(&(byte_rate / 2).to_le_bytes()); // sample rate
+        wav.extend_from_slice(&byte_rate.to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes()); // block align
+        wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(data_len as u32).to_le_bytes());
+        wav.resize(wav.len() + data_len, 0);
+        wav
+    }
+
+    #[test]
+    fn test_estimate_word_boundaries_spans_duration() {
+        let text = "  Hi  there   everyone";
+        let words = estimate_word_boundaries(text, Duration::from_secs(3));
+
+        assert_eq!(words.len(), 3);
+        assert_eq!(&text[words[1].range()], "there");
+        assert_eq!(words[0].start, Duration::ZERO);
+        assert_eq!(words[2].end, Duration::from_secs(3));
+        for pair in words.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+        // "everyone" is longer than "Hi", so it gets more time
+        assert!(words[2].end - words[2].start > words[0].end - words[0].start);
+    }
+
+    #[test]
+    fn test_estimate_word_boundaries_empty_text() {
+        assert!(estimate_word_boundaries("   ", Duration::from_secs(1)).is_empty());
+    }
+
+    #[test]
+    fn test_estimated_duration_scales_with_speed() {
+        let text = "one two three four five";
+        assert_eq!(
+            estimated_duration(text, SpeedLevel::Normal),
+            Duration::from_secs(2)
+        );
+        assert!(estimated_duration(text, SpeedLevel::Fast) < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_wav_duration() {
+        assert_eq!(
+            wav_duration(&wav(48_000, 72_000)),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(wav_duration(b"ID3 not a wav file"), None);
+    }
+
+    #[test]
+    fn test_parse_echogarden_timeline() {
+        let text = "Hello, world. Bye";
+        let json = r#"[{"type": "segment", "text": "Hello, world. Bye", "startTime": 0, "endTime": 2,
+            "timeline": [
+                {"type": "sentence", "startTime": 0, "endTime": 1.2, "timeline": [
+                    {"type": "word", "text": "Hello", "startTime": 0.1, "endTime": 0.5,
+                     "timeline": [{"type": "phone", "text": "h", "startTime": 0.1, "endTime": 0.2}]},
+                    {"type": "word", "text": "world", "startTime": 0.6, "endTime": 1.1}
+                ]},
+                {"type": "sentence", "startTime": 1.2, "endTime": 2, "timeline": [
+                    {"type": "word", "text": "Bye", "startTime": 1.4, "endTime": 1.9}
+                ]}
+            ]}]"#;
+
+        let words = parse_echogarden_timeline(text, json).unwrap();
+        let summary: Vec<_> = words.iter().map(|w| (w.word.as_str(), w.offset)).collect();
+        assert_eq!(summary, vec![("Hello", 0), ("world", 7), ("Bye", 14)]);
+        assert_eq!(words[1].start, Duration::from_millis(600));
+        assert_eq!(words[2].end, Duration::from_millis(1900));
+
+        assert!(parse_echogarden_timeline(text, "not json").is_err());
+    }
+
+    #[test]
+    fn test_timeline_path() {
+        assert_eq!(
+            timeline_path(Path::new("/tmp/cache/abc.wav")),
+            PathBuf::from("/tmp/cache/abc.timeline.json")
+        );
+    }
+
+    #[test]
+    fn test_word_boundaries_for_audio_reads_wav_duration() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("speech.wav");
+        std::fs::write(&path, wav(32_000, 64_000)).unwrap();
+
+        let words =
+            word_boundaries_for_audio("one two", &path, AudioFormat::Wav, SpeedLevel::Normal);
+        assert_eq!(words.last().unwrap().end, Duration::from_secs(2));
+
+        std::fs::write(
+            timeline_path(&path),
+            r#"[{"type": "word", "text": "one", "startTime": 0.25, "endTime": 0.5}]"#,
+        )
+        .unwrap();
+        let words =
+            word_boundaries_for_audio("one two", &path, AudioFormat::Wav, SpeedLevel::Normal);
+        assert_eq!(words.len(), 1);
+        assert_eq!(words[0].start, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_to_captions_srt() {
+        let text = "The quick brown fox jumps over the lazy dog. It runs.";
+        let words = estimate_word_boundaries(text, Duration::from_secs(4));
+        let srt = to_captions(&words, CaptionFormat::Srt);
+
+        let blocks: Vec<&str> = srt.trim_end().split("\n\n").collect();
+        assert_eq!(blocks.len(), 3);
+        assert!(blocks[0].starts_with("1\n00:00:00,000 --> 00:00:0"));
+        assert!(blocks[0].ends_with("\nThe quick brown fox jumps over the"));
+        assert!(blocks[1].ends_with("\nlazy dog."));
+        assert!(blocks[2].ends_with(" --> 00:00:04,000\nIt runs."));
+    }
+
+    #[test]
+    fn test_to_captions_webvtt() {
+        let words = estimate_word_boundaries("Hello world", Duration::from_millis(61_250));
+        let vtt = to_captions(&words, CaptionFormat::WebVtt);
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n00:00:00.000 --> 00:01:01.250\nHello world\n\n"
+        );
+        assert_eq!(CaptionFormat::WebVtt.extension(), "vtt");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_follow_playback_reports_words_in_order() {
+        use std::sync::Mutex;
+
+        let words = estimate_word_boundaries("one two three", Duration::from_secs(3));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let callback = WordBoundaryCallback::new({
+            let seen = seen.clone();
+            move |boundary| seen.lock().unwrap().push(boundary.word.clone())
+        });
+
+        let output = follow_playback(&words, Some(&callback), async {
+            tokio::time::sleep(Duration::from_secs(3)).await;
+            42
+        })
+        .await;
+
+        assert_eq!(output, 42);
+        assert_eq!(*seen.lock().unwrap(), vec!["one", "two", "three"]);
+    }
+}
@@ -10,6 +10,7 @@
 //! - **OS-aware defaults**: Prioritizes native TTS on each platform
 //! - **Async-first**: Built on tokio for non-blocking TTS operations
 //! - **Builder pattern**: Ergonomic configuration via `TtsConfig`
+//! - **Word timing**: Per-word progress callbacks and SRT/WebVTT captions
 //!
 //! ## Quick Start
 //!
@@ -33,9 +34,11 @@
 //! - [`errors`] - Error types for TTS operations
 //! - [`traits`] - The `TtsExecutor` trait for provider implementations
 //! - [`speak`] - The main `Speak` struct for TTS operations
+//! - [`captions`] - Word timings, progress callbacks, and caption files
 
 pub mod audio_cache;
 pub mod cache;
+pub mod captions;
 pub mod detection;
 pub mod errors;
 pub mod gender_inference;
@@ -52,6 +55,7 @@ pub use cache::{
     bust_host_capability_cache, populate_cache_for_all_providers, populate_cache_for_provider,
     read_from_cache, update_provider_in_cache,
 };
+pub use captions::{CaptionFormat, WordBoundary, WordBoundaryCallback};
 pub use detection::{get_available_providers, get_providers_for_strategy, parse_provider_name};
 pub use gender_inference::infer_gender;
 pub use errors::{AllProvidersFailed, TtsError};
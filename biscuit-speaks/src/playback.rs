@@ -31,6 +31,8 @@
 //! play_audio_file(Path::new("/tmp/audio.mp3"), AudioFormat::Mp3, &config).await?;
 //! ```
 
+#[cfg(feature = "playa")]
+use crate::captions::{WordBoundary, follow_playback, word_boundaries_for_audio};
 #[cfg(feature = "playa")]
 use crate::errors::TtsError;
 #[cfg(feature = "playa")]
@@ -150,6 +152,32 @@ pub async fn play_audio_file(
         .map_err(TtsError::from)
 }
 
+/// Play an audio file of `text`, reporting word boundaries as they are spoken.
+///
+/// Boundaries come from [`word_boundaries_for_audio`]; if the config has a
+/// word boundary callback it is invoked as each word starts playing. The
+/// boundaries are returned so providers can include them in a `SpeakResult`.
+///
+/// ## Errors
+///
+/// Returns `TtsError` if playback fails.
+#[cfg(feature = "playa")]
+pub(crate) async fn play_spoken_audio_file(
+    text: &str,
+    path: &std::path::Path,
+    format: AudioFormat,
+    config: &crate::types::TtsConfig,
+) -> Result<Vec<WordBoundary>, TtsError> {
+    let boundaries = word_boundaries_for_audio(text, path, format, config.speed);
+    follow_playback(
+        &boundaries,
+        config.word_boundary_callback.as_ref(),
+        play_audio_file(path, format, config),
+    )
+    .await?;
+    Ok(boundaries)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
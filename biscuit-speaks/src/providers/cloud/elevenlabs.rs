@@ -689,7 +689,9 @@ impl TtsExecutor for ElevenLabsProvider {
         // Play the audio file
         #[cfg(feature = "playa")]
         {
-            crate::playback::play_audio_file(&audio_path, AudioFormat::Mp3, config).await
+            crate::playback::play_spoken_audio_file(text, &audio_path, AudioFormat::Mp3, config)
+                .await
+                .map(|_| ())
         }
         #[cfg(not(feature = "playa"))]
         {
@@ -740,7 +742,8 @@ impl TtsExecutor for ElevenLabsProvider {
 
         // Play the audio file (requires playa feature)
         #[cfg(feature = "playa")]
-        crate::playback::play_audio_file(&audio_path, AudioFormat::Mp3, config).await?;
+        let word_boundaries =
+            crate::playback::play_spoken_audio_file(text, &audio_path, AudioFormat::Mp3, config).await?;
 
         #[cfg(not(feature = "playa"))]
         {
@@ -789,7 +792,8 @@ impl TtsExecutor for ElevenLabsProvider {
             )
             .with_audio_file(audio_path)
             .with_codec("mp3")
-            .with_cache_hit(cache_hit))
+            .with_cache_hit(cache_hit)
+            .with_word_boundaries(word_boundaries))
         }
     }
 }
@@ -247,6 +247,8 @@ impl EchogardenProvider {
         cmd.arg("speak");
         cmd.arg(text);
         cmd.arg(&cache_path);
+        // Word-level timeline used for progress callbacks and captions
+        cmd.arg(crate::captions::timeline_path(&cache_path));
 
         // Engine selection
         cmd.arg(format!("--engine={}", self.engine.as_str()));
@@ -321,7 +323,9 @@ impl TtsExecutor for EchogardenProvider {
         // Play the audio file
         #[cfg(feature = "playa")]
         {
-            crate::playback::play_audio_file(&audio_path, crate::types::AudioFormat::Wav, config).await
+            crate::playback::play_spoken_audio_file(text, &audio_path, crate::types::AudioFormat::Wav, config)
+                .await
+                .map(|_| ())
         }
         #[cfg(not(feature = "playa"))]
         {
@@ -375,7 +379,8 @@ impl TtsExecutor for EchogardenProvider {
 
         // Play the audio file (requires playa feature)
         #[cfg(feature = "playa")]
-        crate::playback::play_audio_file(&audio_path, crate::types::AudioFormat::Wav, config).await?;
+        let word_boundaries =
+            crate::playback::play_spoken_audio_file(text, &audio_path, crate::types::AudioFormat::Wav, config).await?;
 
         #[cfg(not(feature = "playa"))]
         {
@@ -412,7 +417,8 @@ impl TtsExecutor for EchogardenProvider {
             )
             .with_audio_file(audio_path)
             .with_codec("wav")
-            .with_cache_hit(cache_hit))
+            .with_cache_hit(cache_hit)
+            .with_word_boundaries(word_boundaries))
         }
     }
 }
@@ -234,7 +234,9 @@ impl TtsExecutor for GttsProvider {
         // Play the audio file
         #[cfg(feature = "playa")]
         {
-            crate::playback::play_audio_file(&audio_path, AudioFormat::Mp3, config).await
+            crate::playback::play_spoken_audio_file(text, &audio_path, AudioFormat::Mp3, config)
+                .await
+                .map(|_| ())
         }
         #[cfg(not(feature = "playa"))]
         {
@@ -307,7 +309,8 @@ impl TtsExecutor for GttsProvider {
 
         // Play the audio file (requires playa feature)
         #[cfg(feature = "playa")]
-        crate::playback::play_audio_file(&audio_path, AudioFormat::Mp3, config).await?;
+        let word_boundaries =
+            crate::playback::play_spoken_audio_file(text, &audio_path, AudioFormat::Mp3, config).await?;
 
         #[cfg(not(feature = "playa"))]
         {
@@ -323,7 +326,8 @@ impl TtsExecutor for GttsProvider {
         )
         .with_audio_file(audio_path)
         .with_codec("mp3")
-        .with_cache_hit(cache_hit))
+        .with_cache_hit(cache_hit)
+        .with_word_boundaries(word_boundaries))
     }
 }
 
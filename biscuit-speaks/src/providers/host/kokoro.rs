@@ -305,7 +305,9 @@ impl TtsExecutor for KokoroTtsProvider {
         // Play the audio file
         #[cfg(feature = "playa")]
         {
-            crate::playback::play_audio_file(&audio_path, crate::types::AudioFormat::Wav, config).await
+            crate::playback::play_spoken_audio_file(text, &audio_path, crate::types::AudioFormat::Wav, config)
+                .await
+                .map(|_| ())
         }
         #[cfg(not(feature = "playa"))]
         {
@@ -353,7 +355,8 @@ impl TtsExecutor for KokoroTtsProvider {
 
         // Play the audio file (requires playa feature)
         #[cfg(feature = "playa")]
-        crate::playback::play_audio_file(&audio_path, crate::types::AudioFormat::Wav, config).await?;
+        let word_boundaries =
+            crate::playback::play_spoken_audio_file(text, &audio_path, crate::types::AudioFormat::Wav, config).await?;
 
         #[cfg(not(feature = "playa"))]
         {
@@ -376,7 +379,8 @@ impl TtsExecutor for KokoroTtsProvider {
             )
             .with_audio_file(audio_path)
             .with_codec("wav")
-            .with_cache_hit(cache_hit))
+            .with_cache_hit(cache_hit)
+            .with_word_boundaries(word_boundaries))
         }
     }
 }
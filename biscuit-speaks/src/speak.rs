@@ -2,6 +2,7 @@
 //!
 //! This module provides the primary API for text-to-speech functionality.
 
+use crate::captions::{WordBoundary, WordBoundaryCallback};
 use crate::detection::get_providers_for_strategy;
 use crate::errors::{AllProvidersFailed, TtsError};
 use crate::providers::cloud::ElevenLabsProvider;
@@ -118,6 +119,19 @@ impl Speak {
         self
     }
 
+    /// Set a callback invoked as each word starts playing.
+    ///
+    /// Useful for highlighting text as it is spoken. Only providers that
+    /// synthesize to an audio file report word boundaries.
+    #[must_use]
+    pub fn with_word_boundary_callback(
+        mut self,
+        callback: impl Fn(&WordBoundary) + Send + Sync + 'static,
+    ) -> Self {
+        self.config.word_boundary_callback = Some(WordBoundaryCallback::new(callback));
+        self
+    }
+
     /// Apply a complete TtsConfig.
     #[must_use]
     pub fn with_config(mut self, config: TtsConfig) -> Self {
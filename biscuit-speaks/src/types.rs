@@ -11,6 +11,8 @@ use std::sync::LazyLock;
 use serde::{Deserialize, Serialize};
 use sniff_lib::programs::InstalledTtsClients;
 
+use crate::captions::{WordBoundary, WordBoundaryCallback};
+
 // ============================================================================
 // Volume Level
 // ============================================================================
@@ -704,6 +706,8 @@ pub struct TtsConfig {
     pub speed: SpeedLevel,
     /// Failover strategy when providers fail.
     pub failover_strategy: TtsFailoverStrategy,
+    /// Called as each word starts playing (file-based providers only).
+    pub word_boundary_callback: Option<WordBoundaryCallback>,
 }
 
 impl TtsConfig {
@@ -763,6 +767,19 @@ impl TtsConfig {
         self.failover_strategy = strategy;
         self
     }
+
+    /// Set a callback invoked as each word starts playing.
+    ///
+    /// Only providers that synthesize to an audio file (Echogarden, Kokoro,
+    /// gTTS, ElevenLabs) report word boundaries; see [`crate::captions`].
+    #[must_use]
+    pub fn with_word_boundary_callback(
+        mut self,
+        callback: impl Fn(&WordBoundary) + Send + Sync + 'static,
+    ) -> Self {
+        self.word_boundary_callback = Some(WordBoundaryCallback::new(callback));
+        self
+    }
 }
 
 // ============================================================================
@@ -861,6 +878,11 @@ pub struct SpeakResult {
     /// Whether the audio was served from cache.
     #[serde(default)]
     pub cache_hit: bool,
+    /// When each word was spoken, for providers that synthesize to a file.
+    ///
+    /// Empty for providers that speak directly (say, eSpeak, SAPI).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub word_boundaries: Vec<WordBoundary>,
 }
 
 impl SpeakResult {
@@ -873,6 +895,7 @@ impl SpeakResult {
             audio_file_path: None,
             audio_codec: None,
             cache_hit: false,
+            word_boundaries: Vec::new(),
         }
     }
 
@@ -885,6 +908,7 @@ impl SpeakResult {
             audio_file_path: None,
             audio_codec: None,
             cache_hit: false,
+            word_boundaries: Vec::new(),
         }
     }
 
@@ -908,6 +932,13 @@ impl SpeakResult {
         self.cache_hit = hit;
         self
     }
+
+    /// Set the word boundaries of the spoken audio.
+    #[must_use]
+    pub fn with_word_boundaries(mut self, boundaries: Vec<WordBoundary>) -> Self {
+        self.word_boundaries = boundaries;
+        self
+    }
 }
 
 // ============================================================================
@@ -237,6 +237,9 @@ struct TerminalMetadata {
 
     /// Whether stdout is connected to a TTY
     is_tty: bool,
+    /// Windows console host (Windows Terminal, ConPTY or legacy console)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    console_host: Option<String>,
     /// Whether running in a CI environment
     is_ci: bool,
 
@@ -889,6 +892,7 @@ fn collect_metadata() -> TerminalMetadata {
         width: Terminal::width(),
         height: Terminal::height(),
         is_tty: terminal.is_tty,
+        console_host: terminal.console_host.map(|host| format!("{:?}", host)),
         is_ci: terminal.is_ci,
         color_depth: format!("{:?}", terminal.color_depth),
         color_mode: format!("{:?}", Terminal::color_mode()),
@@ -1037,6 +1041,9 @@ fn print_pretty(metadata: &TerminalMetadata, verbose: bool) {
             "no".to_string()
         }
     );
+    if let Some(host) = &metadata.console_host {
+        println!("  Console:    {}", host);
+    }
     println!(
         "  In CI:      {}",
         if metadata.is_ci {
@@ -0,0 +1,630 @@
+//! Key bindings and a help overlay that lists them.
+//!
+//! A [`Keymap`] maps key chords to caller-defined actions. Interactive
+//! components resolve key presses through it instead of matching
+//! [`KeyCode`]s by hand, and the same registrations produce a
+//! [`HelpOverlay`], so the keys a component accepts and the keys its help
+//! shows can't drift apart.
+//!
+//! Keys are written as chords such as `"q"`, `"?"`, `"ctrl-c"`,
+//! `"shift-tab"`, `"pgdn"` or `"f1"`. Several chords can be bound to one
+//! action by separating them with commas (the comma key itself is `"comma"`).
+//!
+//! ## Examples
+//!
+//! ```
+//! use biscuit_terminal::components::keymap::Keymap;
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! enum Action {
+//!     Up,
+//!     Down,
+//!     Quit,
+//! }
+//!
+//! let keymap = Keymap::new()
+//!     .section("Navigation")
+//!     .bind(Action::Up, "up, k", "Move up")
+//!     .bind(Action::Down, "down, j", "Move down")
+//!     .section("General")
+//!     .bind(Action::Quit, "q, ctrl-c", "Quit");
+//!
+//! let key = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+//! assert_eq!(keymap.action_for(&key), Some(&Action::Quit));
+//!
+//! for line in keymap.help_overlay().render(40) {
+//!     eprintln!("{line}");
+//! }
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use thiserror::Error;
+
+use crate::utils::block_constraint::TextAlignment;
+use crate::utils::text_width::{display_width, pad_to_width, truncate_with_ellipsis};
+
+/// Errors that can occur while registering key bindings.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum KeymapError {
+    /// A chord named a key that isn't recognized.
+    #[error("Unknown key '{0}'")]
+    UnknownKey(String),
+
+    /// A chord used a modifier other than `ctrl`, `alt` or `shift`.
+    #[error("Unknown modifier '{0}'")]
+    UnknownModifier(String),
+
+    /// A key spec, or one of its comma-separated chords, was empty.
+    #[error("Empty key binding")]
+    Empty,
+}
+
+/// A key together with the modifiers that must be held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    /// The key.
+    pub code: KeyCode,
+    /// The modifiers that must be held.
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    /// Creates a chord from a key and modifiers.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Returns `true` if `key` is this chord.
+    ///
+    /// `Shift` is ignored for characters and `BackTab`, since terminals
+    /// disagree on whether they report it (`?` may arrive with or without
+    /// `Shift` held).
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        if key.code != self.code {
+            return false;
+        }
+        match self.code {
+            KeyCode::Char(_) | KeyCode::BackTab => {
+                key.modifiers.difference(KeyModifiers::SHIFT)
+                    == self.modifiers.difference(KeyModifiers::SHIFT)
+            }
+            _ => key.modifiers == self.modifiers,
+        }
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = KeymapError;
+
+    /// Parses a chord such as `"ctrl-c"`, `"shift-tab"`, `"?"` or `"pgup"`.
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let spec = spec.trim();
+        if spec.is_empty() {
+            return Err(KeymapError::Empty);
+        }
+
+        // A trailing "-" after a separator is the minus key itself ("ctrl--")
+        let (prefix, key) = match spec.strip_suffix("--") {
+            Some(prefix) => (Some(prefix), "-"),
+            None if spec == "-" => (None, "-"),
+            None => match spec.rsplit_once('-') {
+                Some((prefix, key)) => (Some(prefix), key),
+                None => (None, spec),
+            },
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in prefix.into_iter().flat_map(|p| p.split('-')) {
+            modifiers |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" | "option" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(KeymapError::UnknownModifier(modifier.to_string())),
+            };
+        }
+
+        let code = parse_key(key).ok_or_else(|| KeymapError::UnknownKey(key.to_string()))?;
+
+        // Shift-Tab is reported as BackTab
+        if code == KeyCode::Tab && modifiers.contains(KeyModifiers::SHIFT) {
+            return Ok(Self::new(
+                KeyCode::BackTab,
+                modifiers.difference(KeyModifiers::SHIFT),
+            ));
+        }
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+/// Parses a key name or a single character.
+fn parse_key(key: &str) -> Option<KeyCode> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+
+    let code = match key.to_ascii_lowercase().as_str() {
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" | "return" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" | "bs" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "space" => KeyCode::Char(' '),
+        "comma" => KeyCode::Char(','),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pgup" | "pageup" => KeyCode::PageUp,
+        "pgdn" | "pgdown" | "pagedown" => KeyCode::PageDown,
+        name => {
+            let n = name.strip_prefix('f')?.parse::<u8>().ok()?;
+            if !(1..=24).contains(&n) {
+                return None;
+            }
+            KeyCode::F(n)
+        }
+    };
+    Some(code)
+}
+
+impl fmt::Display for KeyChord {
+    /// Formats the chord for display, e.g. `Ctrl-C`, `Shift-Tab` or `PageUp`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift-")?;
+        }
+
+        let modified = !self.modifiers.is_empty();
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) if modified => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::BackTab => write!(f, "Shift-Tab"),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Delete"),
+            KeyCode::Insert => write!(f, "Insert"),
+            KeyCode::Up => write!(f, "Up"),
+            KeyCode::Down => write!(f, "Down"),
+            KeyCode::Left => write!(f, "Left"),
+            KeyCode::Right => write!(f, "Right"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PageUp"),
+            KeyCode::PageDown => write!(f, "PageDown"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+/// One action and the chords bound to it.
+#[derive(Debug, Clone)]
+pub struct Binding<A> {
+    /// The action the chords trigger.
+    pub action: A,
+    /// The chords, in the order they were registered.
+    pub keys: Vec<KeyChord>,
+    /// What the action does, as shown in help. Empty hides the binding.
+    pub description: String,
+    /// The help section the binding was registered under.
+    pub section: Option<String>,
+}
+
+/// A set of key bindings for an interactive component.
+///
+/// When several bindings share a chord, the first one registered wins.
+#[derive(Debug, Clone)]
+pub struct Keymap<A> {
+    bindings: Vec<Binding<A>>,
+    section: Option<String>,
+}
+
+impl<A> Default for Keymap<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Keymap<A> {
+    /// Creates an empty keymap.
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            section: None,
+        }
+    }
+
+    /// Starts a help section; bindings registered after this are listed under `name`.
+    pub fn section<S: Into<String>>(mut self, name: S) -> Self {
+        self.section = Some(name.into());
+        self
+    }
+
+    /// Binds one or more comma-separated chords to `action`.
+    ///
+    /// An empty `description` keeps the binding out of the help overlay,
+    /// which suits aliases that would only clutter it.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `keys` isn't a valid key spec. Key specs are almost always
+    /// literals, so this is a programming error; use
+    /// [`try_bind`](Self::try_bind) for user-supplied keys.
+    pub fn bind<S: Into<String>>(self, action: A, keys: &str, description: S) -> Self {
+        match self.try_bind(action, keys, description) {
+            Ok(keymap) => keymap,
+            Err(e) => panic!("invalid key binding '{keys}': {e}"),
+        }
+    }
+
+    /// Binds one or more comma-separated chords to `action`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`KeymapError`] if any chord is empty or can't be parsed.
+    pub fn try_bind<S: Into<String>>(
+        mut self,
+        action: A,
+        keys: &str,
+        description: S,
+    ) -> Result<Self, KeymapError> {
+        let keys = parse_keys(keys)?;
+        self.bindings.push(Binding {
+            action,
+            keys,
+            description: description.into(),
+            section: self.section.clone(),
+        });
+        Ok(self)
+    }
+
+    /// Returns the action bound to `key`, if any.
+    pub fn action_for(&self, key: &KeyEvent) -> Option<&A> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.keys.iter().any(|chord| chord.matches(key)))
+            .map(|binding| &binding.action)
+    }
+
+    /// Returns the bindings in registration order.
+    pub fn bindings(&self) -> &[Binding<A>] {
+        &self.bindings
+    }
+
+    /// Builds a help overlay listing the described bindings by section.
+    pub fn help_overlay(&self) -> HelpOverlay {
+        let mut sections: Vec<HelpSection> = Vec::new();
+        for binding in self.bindings.iter().filter(|b| !b.description.is_empty()) {
+            let entry = HelpEntry {
+                keys: binding
+                    .keys
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                description: binding.description.clone(),
+            };
+            match sections.last_mut() {
+                Some(section) if section.title == binding.section => section.entries.push(entry),
+                _ => sections.push(HelpSection {
+                    title: binding.section.clone(),
+                    entries: vec![entry],
+                }),
+            }
+        }
+
+        HelpOverlay {
+            title: "Keys".to_string(),
+            sections,
+        }
+    }
+}
+
+/// Parses a comma-separated list of chords.
+fn parse_keys(keys: &str) -> Result<Vec<KeyChord>, KeymapError> {
+    keys.split(',').map(str::parse).collect()
+}
+
+/// One row of a help overlay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpEntry {
+    /// The chords, formatted for display (e.g. `Up, Ctrl-P`).
+    pub keys: String,
+    /// What the keys do.
+    pub description: String,
+}
+
+/// A titled group of help rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpSection {
+    /// The section heading, if the bindings were registered under one.
+    pub title: Option<String>,
+    /// The rows in the section.
+    pub entries: Vec<HelpEntry>,
+}
+
+/// A boxed list of key bindings, built with [`Keymap::help_overlay`].
+///
+/// [`render`](Self::render) draws the box as plain text lines for
+/// crossterm-style UIs; TUI frameworks can lay out [`sections`](Self::sections)
+/// themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpOverlay {
+    title: String,
+    sections: Vec<HelpSection>,
+}
+
+impl HelpOverlay {
+    /// Sets the title drawn in the top border (default `Keys`).
+    pub fn with_title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Returns the title.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the sections in registration order.
+    pub fn sections(&self) -> &[HelpSection] {
+        &self.sections
+    }
+
+    /// Returns the width of the widest key column.
+    pub fn keys_width(&self) -> usize {
+        self.sections
+            .iter()
+            .flat_map(|section| &section.entries)
+            .map(|entry| display_width(&entry.keys))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the overlay's content as unboxed lines: section headings,
+    /// then indented rows with the key column aligned.
+    pub fn lines(&self) -> Vec<String> {
+        let keys_width = self.keys_width();
+        let mut lines = Vec::new();
+        for (index, section) in self.sections.iter().enumerate() {
+            if index > 0 {
+                lines.push(String::new());
+            }
+            let indent = match &section.title {
+                Some(title) => {
+                    lines.push(title.clone());
+                    "  "
+                }
+                None => "",
+            };
+            for entry in &section.entries {
+                lines.push(format!(
+                    "{indent}{}  {}",
+                    pad_to_width(&entry.keys, keys_width, TextAlignment::Left),
+                    entry.description
+                ));
+            }
+        }
+        lines
+    }
+
+    /// Renders the overlay as a rounded box no wider than `max_width` columns.
+    ///
+    /// Lines that don't fit are truncated with an ellipsis. Returns no lines
+    /// if `max_width` is too narrow to draw a box.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biscuit_terminal::components::keymap::Keymap;
+    ///
+    /// let lines = Keymap::new().bind((), "q", "Quit").help_overlay().render(80);
+    /// assert_eq!(lines[0], "╭─ Keys ──╮");
+    /// assert_eq!(lines[1], "│ q  Quit │");
+    /// assert_eq!(lines[2], "╰─────────╯");
+    /// ```
+    pub fn render(&self, max_width: usize) -> Vec<String> {
+        if max_width < 5 {
+            return Vec::new();
+        }
+
+        let lines = self.lines();
+        let title_width = display_width(&self.title) + 3;
+        let inner = lines
+            .iter()
+            .map(|line| display_width(line))
+            .max()
+            .unwrap_or(0)
+            .max(title_width)
+            .min(max_width - 4);
+
+        let title = truncate_with_ellipsis(&self.title, inner.saturating_sub(1), "…");
+        let mut top = format!("╭─ {title} ");
+        let top_fill = inner.saturating_sub(display_width(&title) + 1);
+        top.push_str(&"─".repeat(top_fill));
+        top.push('╮');
+
+        let mut rendered = vec![top];
+        for line in &lines {
+            let line = truncate_with_ellipsis(line, inner, "…");
+            rendered.push(format!(
+                "│ {} │",
+                pad_to_width(&line, inner, TextAlignment::Left)
+            ));
+        }
+        rendered.push(format!("╰{}╯", "─".repeat(inner + 2)));
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Action {
+        Up,
+        Down,
+        Help,
+        Quit,
+    }
+
+    fn chord(spec: &str) -> KeyChord {
+        spec.parse().unwrap()
+    }
+
+    fn keymap() -> Keymap<Action> {
+        Keymap::new()
+            .section("Navigation")
+            .bind(Action::Up, "up, ctrl-p", "Move up")
+            .bind(Action::Down, "down, ctrl-n", "Move down")
+            .section("General")
+            .bind(Action::Help, "?", "Show help")
+            .bind(Action::Quit, "q, esc", "Quit")
+            .bind(Action::Quit, "ctrl-c", "")
+    }
+
+    #[test]
+    fn chords_parse_names_modifiers_and_characters() {
+        assert_eq!(
+            chord("ctrl-c"),
+            KeyChord::new(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            chord("Alt-Shift-x"),
+            KeyChord::new(KeyCode::Char('x'), KeyModifiers::ALT | KeyModifiers::SHIFT)
+        );
+        assert_eq!(chord("pgdn").code, KeyCode::PageDown);
+        assert_eq!(chord("F1").code, KeyCode::F(1));
+        assert_eq!(chord("space").code, KeyCode::Char(' '));
+        assert_eq!(chord("-").code, KeyCode::Char('-'));
+        assert_eq!(
+            chord("ctrl--"),
+            KeyChord::new(KeyCode::Char('-'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            chord("shift-tab"),
+            KeyChord::new(KeyCode::BackTab, KeyModifiers::NONE)
+        );
+
+        assert_eq!(
+            "hyper-x".parse::<KeyChord>(),
+            Err(KeymapError::UnknownModifier("hyper".to_string()))
+        );
+        assert_eq!(
+            "f99".parse::<KeyChord>(),
+            Err(KeymapError::UnknownKey("f99".to_string()))
+        );
+    }
+
+    #[test]
+    fn chords_display_like_key_caps() {
+        assert_eq!(chord("ctrl-u").to_string(), "Ctrl-U");
+        assert_eq!(chord("shift-tab").to_string(), "Shift-Tab");
+        assert_eq!(chord("pgup").to_string(), "PageUp");
+        assert_eq!(chord("?").to_string(), "?");
+        assert_eq!(chord("space").to_string(), "Space");
+    }
+
+    #[test]
+    fn chords_ignore_shift_for_characters() {
+        let question = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::SHIFT);
+        assert!(chord("?").matches(&question));
+
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert!(chord("ctrl-c").matches(&ctrl_c));
+        assert!(!chord("c").matches(&ctrl_c));
+
+        let shift_up = KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT);
+        assert!(!chord("up").matches(&shift_up));
+    }
+
+    #[test]
+    fn keymap_resolves_actions_in_registration_order() {
+        let keymap = keymap();
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        assert_eq!(keymap.action_for(&key(KeyCode::Up)), Some(&Action::Up));
+        assert_eq!(keymap.action_for(&key(KeyCode::Esc)), Some(&Action::Quit));
+        assert_eq!(
+            keymap.action_for(&KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(&Action::Quit)
+        );
+        assert_eq!(keymap.action_for(&key(KeyCode::Char('x'))), None);
+
+        let first = Keymap::new().bind(1, "x", "First").bind(2, "x", "Second");
+        assert_eq!(first.action_for(&key(KeyCode::Char('x'))), Some(&1));
+    }
+
+    #[test]
+    fn try_bind_reports_invalid_specs() {
+        assert!(matches!(
+            Keymap::new().try_bind((), "ctrl-nope", ""),
+            Err(KeymapError::UnknownKey(_))
+        ));
+        assert!(matches!(
+            Keymap::new().try_bind((), " ", ""),
+            Err(KeymapError::Empty)
+        ));
+
+        assert!(matches!(
+            Keymap::new().try_bind((), "a,,b", ""),
+            Err(KeymapError::Empty)
+        ));
+
+        let comma = Keymap::new().try_bind((), "comma, .", "").unwrap();
+        let keys: Vec<KeyCode> = comma.bindings()[0].keys.iter().map(|k| k.code).collect();
+        assert_eq!(keys, vec![KeyCode::Char(','), KeyCode::Char('.')]);
+    }
+
+    #[test]
+    fn help_overlay_groups_sections_and_hides_undescribed_bindings() {
+        let overlay = keymap().help_overlay();
+        assert_eq!(overlay.sections().len(), 2);
+        assert_eq!(overlay.sections()[0].title.as_deref(), Some("Navigation"));
+        assert_eq!(overlay.sections()[1].entries.len(), 2);
+
+        assert_eq!(
+            overlay.lines(),
+            vec![
+                "Navigation",
+                "  Up, Ctrl-P    Move up",
+                "  Down, Ctrl-N  Move down",
+                "",
+                "General",
+                "  ?             Show help",
+                "  q, Esc        Quit",
+            ]
+        );
+    }
+
+    #[test]
+    fn help_overlay_renders_a_box_within_the_width() {
+        let lines = keymap().help_overlay().with_title("Help").render(20);
+        assert_eq!(lines.len(), 9);
+        assert!(lines.iter().all(|line| display_width(line) == 20));
+        assert!(lines[0].starts_with("╭─ Help "));
+        assert_eq!(lines[2], "│   Up, Ctrl-P   … │");
+        assert!(lines[8].starts_with('╰'));
+
+        assert!(keymap().help_overlay().render(4).is_empty());
+    }
+}
@@ -1,6 +1,9 @@
 pub mod block_quote;
 pub mod image_options;
+pub mod keymap;
 pub mod mermaid;
+pub mod picker;
+pub mod progress_bar;
 pub mod prose;
 pub mod renderable;
 pub mod table;
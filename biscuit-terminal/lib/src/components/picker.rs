@@ -0,0 +1,844 @@
+//! Interactive fuzzy picker for choosing one item from a list.
+//!
+//! [`Picker`] draws a prompt and a filtered, scrollable list inline below the
+//! cursor and narrows the list as the user types. The UI is drawn on stderr so
+//! stdout stays free for whatever the caller prints with the selection (e.g.
+//! `vim $(my-tool pick)`).
+//!
+//! Matching is fzf-style (see [`fuzzy_match`]): query characters must appear in
+//! order, and matches at word boundaries, camelCase humps, and in consecutive
+//! runs rank higher. A query containing an uppercase letter is case-sensitive.
+//!
+//! ## Keys
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | typing / `Backspace` | Edit the query |
+//! | `Ctrl-U` / `Ctrl-W` | Clear the query / delete the last word |
+//! | `Up` / `Down`, `Ctrl-P` / `Ctrl-N`, `Tab` | Move the selection |
+//! | `PageUp` / `PageDown` | Move the selection by a page |
+//! | `Enter` | Accept the selection |
+//! | `Esc` / `Ctrl-C` | Cancel |
+//! | `F1` | Show these keys (any key closes the help) |
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use biscuit_terminal::components::picker::{Picker, PickerItem};
+//!
+//! let items = vec![
+//!     PickerItem::new("parse_config", 1).with_detail("function  src/config.rs:12"),
+//!     PickerItem::new("Config", 2).with_detail("type  src/config.rs:3"),
+//! ];
+//!
+//! match Picker::new(items).with_prompt("symbol> ").run()? {
+//!     Some(id) => println!("picked {id}"),
+//!     None => println!("cancelled"),
+//! }
+//! # Ok::<(), biscuit_terminal::components::picker::PickerError>(())
+//! ```
+
+use std::io::{IsTerminal, Write};
+
+use crossterm::cursor::{MoveToColumn, MoveUp};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, Clear, ClearType};
+use crossterm::{execute, queue};
+use thiserror::Error;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::keymap::Keymap;
+
+/// Default number of list rows shown below the prompt.
+const DEFAULT_MAX_VISIBLE: usize = 12;
+
+/// Errors that can occur while running a picker.
+#[derive(Error, Debug)]
+pub enum PickerError {
+    /// stdin or stderr is not a terminal, so the picker can't be interactive.
+    #[error("Interactive picker requires a terminal (stdin and stderr must be a TTY)")]
+    NotTty,
+
+    /// IO error while reading keys or drawing.
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+/// A fuzzy match of a query against a candidate string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Match quality; higher is better.
+    pub score: i64,
+    /// Char indices in the candidate that matched the query characters.
+    pub positions: Vec<usize>,
+}
+
+/// Fuzzy-matches `query` against `candidate`.
+///
+/// Every non-whitespace query character must appear in the candidate in
+/// order. Matching is case-insensitive unless the query contains an uppercase
+/// letter ("smart case").
+///
+/// ## Returns
+///
+/// Returns `None` if the candidate doesn't contain the query as a subsequence.
+/// An empty query matches everything with a score of zero.
+///
+/// ## Examples
+///
+/// ```
+/// use biscuit_terminal::components::picker::fuzzy_match;
+///
+/// let boundary = fuzzy_match("pc", "parse_config").unwrap();
+/// let buried = fuzzy_match("pc", "spec").unwrap();
+/// assert!(boundary.score > buried.score);
+/// assert_eq!(boundary.positions, vec![0, 6]);
+///
+/// assert!(fuzzy_match("xyz", "parse_config").is_none());
+/// ```
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    let case_sensitive = query.iter().any(|c| c.is_uppercase());
+    let chars_eq = |a: char, b: char| {
+        if case_sensitive {
+            a == b
+        } else {
+            a.to_lowercase().eq(b.to_lowercase())
+        }
+    };
+    let chars: Vec<char> = candidate.chars().collect();
+
+    // Forward pass: the earliest complete match
+    let mut forward = Vec::with_capacity(query.len());
+    for (index, &c) in chars.iter().enumerate() {
+        if chars_eq(c, query[forward.len()]) {
+            forward.push(index);
+            if forward.len() == query.len() {
+                break;
+            }
+        }
+    }
+    if forward.len() < query.len() {
+        return None;
+    }
+
+    // Backward pass: tighten the match to the shortest span ending at the same place
+    let mut tight = forward.clone();
+    let mut remaining = query.len();
+    for index in (0..=forward[query.len() - 1]).rev() {
+        if chars_eq(chars[index], query[remaining - 1]) {
+            remaining -= 1;
+            tight[remaining] = index;
+            if remaining == 0 {
+                break;
+            }
+        }
+    }
+
+    // The tight span usually wins, but not when it gives up word boundaries
+    [forward, tight]
+        .into_iter()
+        .map(|positions| FuzzyMatch {
+            score: score_positions(&chars, &positions),
+            positions,
+        })
+        .max_by_key(|m| m.score)
+}
+
+/// Scores matched positions: boundary bonuses, consecutive runs, and gap penalties.
+fn score_positions(chars: &[char], positions: &[usize]) -> i64 {
+    let mut score = 0i64;
+    for (n, &position) in positions.iter().enumerate() {
+        score += 16 + boundary_bonus(chars, position);
+        if n > 0 {
+            let gap = (position - positions[n - 1] - 1) as i64;
+            score += if gap == 0 { 8 } else { -(gap.min(8) + 2) };
+        }
+    }
+    score
+}
+
+/// Bonus for matching at the start of a "word" in the candidate.
+fn boundary_bonus(chars: &[char], position: usize) -> i64 {
+    let Some(&previous) = position.checked_sub(1).and_then(|p| chars.get(p)) else {
+        return 10;
+    };
+    let current = chars[position];
+
+    if !previous.is_alphanumeric() {
+        8
+    } else if previous.is_lowercase() && current.is_uppercase() {
+        7
+    } else if !previous.is_ascii_digit() && current.is_ascii_digit() {
+        4
+    } else {
+        0
+    }
+}
+
+/// An item that can be chosen in a [`Picker`].
+#[derive(Debug, Clone)]
+pub struct PickerItem<T> {
+    /// The primary text shown for the item.
+    pub label: String,
+    /// Secondary text shown dimmed after the label (also searched).
+    pub detail: Option<String>,
+    /// The value returned when the item is chosen.
+    pub value: T,
+}
+
+impl<T> PickerItem<T> {
+    /// Creates an item with a label and the value to return when chosen.
+    pub fn new<S: Into<String>>(label: S, value: T) -> Self {
+        Self {
+            label: label.into(),
+            detail: None,
+            value,
+        }
+    }
+
+    /// Sets the secondary text shown after the label.
+    pub fn with_detail<S: Into<String>>(mut self, detail: S) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// The text the query is matched against: the label, then the detail.
+    fn haystack(&self) -> String {
+        match &self.detail {
+            Some(detail) => format!("{} {}", self.label, detail),
+            None => self.label.clone(),
+        }
+    }
+}
+
+/// A ranked match of the current query against a picker item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PickerMatch {
+    /// Index of the item in the picker's item list.
+    pub index: usize,
+    /// Match quality; higher is better.
+    pub score: i64,
+    /// Matched char indices in the item's label followed by its detail
+    /// (separated by one space).
+    pub positions: Vec<usize>,
+}
+
+/// What the picker should do after a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PickerAction {
+    Continue,
+    Select,
+    Cancel,
+}
+
+/// A bound key's effect on a running picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PickerCommand {
+    Previous,
+    Next,
+    PreviousPage,
+    NextPage,
+    ClearQuery,
+    DeleteWord,
+    DeleteChar,
+    Accept,
+    Cancel,
+    Help,
+}
+
+/// The picker's key bindings; unbound characters edit the query.
+fn picker_keymap() -> Keymap<PickerCommand> {
+    Keymap::new()
+        .bind(PickerCommand::Previous, "up, ctrl-p", "Previous item")
+        .bind(PickerCommand::Previous, "shift-tab, ctrl-k", "")
+        .bind(PickerCommand::Next, "down, ctrl-n, tab", "Next item")
+        .bind(PickerCommand::Next, "ctrl-j", "")
+        .bind(PickerCommand::PreviousPage, "pgup", "Previous page")
+        .bind(PickerCommand::NextPage, "pgdn", "Next page")
+        .bind(PickerCommand::ClearQuery, "ctrl-u", "Clear the query")
+        .bind(PickerCommand::DeleteWord, "ctrl-w", "Delete the last word")
+        .bind(PickerCommand::DeleteChar, "backspace", "")
+        .bind(PickerCommand::Accept, "enter", "Accept the selection")
+        .bind(PickerCommand::Cancel, "esc, ctrl-c", "Cancel")
+        .bind(PickerCommand::Cancel, "ctrl-g", "")
+        .bind(PickerCommand::Help, "f1", "Show this help")
+}
+
+/// An interactive fuzzy finder over a list of items.
+///
+/// ## Examples
+///
+/// Non-interactive ranking, useful for piped output:
+///
+/// ```
+/// use biscuit_terminal::components::picker::{Picker, PickerItem};
+///
+/// let picker = Picker::new(vec![
+///     PickerItem::new("render_table", ()),
+///     PickerItem::new("sort_entries", ()),
+/// ]);
+///
+/// let ranked = picker.matches("rt");
+/// assert_eq!(picker.items()[ranked[0].index].label, "render_table");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Picker<T> {
+    items: Vec<PickerItem<T>>,
+    haystacks: Vec<String>,
+    prompt: String,
+    query: String,
+    max_visible: usize,
+    keymap: Keymap<PickerCommand>,
+}
+
+impl<T> Picker<T> {
+    /// Creates a picker over the given items.
+    ///
+    /// Uses default settings: a `> ` prompt, an empty initial query, and up to
+    /// 12 visible rows.
+    pub fn new(items: Vec<PickerItem<T>>) -> Self {
+        let haystacks = items.iter().map(PickerItem::haystack).collect();
+        Self {
+            items,
+            haystacks,
+            prompt: "> ".to_string(),
+            query: String::new(),
+            max_visible: DEFAULT_MAX_VISIBLE,
+            keymap: picker_keymap(),
+        }
+    }
+
+    /// Sets the prompt shown before the query.
+    pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Sets the initial query.
+    pub fn with_query<S: Into<String>>(mut self, query: S) -> Self {
+        self.query = query.into();
+        self
+    }
+
+    /// Sets the maximum number of list rows shown (minimum 1).
+    pub fn with_max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    /// Returns the picker's items.
+    pub fn items(&self) -> &[PickerItem<T>] {
+        &self.items
+    }
+
+    /// Ranks the items matching `query`, best first.
+    ///
+    /// Ties are broken by shorter label, then by original order.
+    pub fn matches(&self, query: &str) -> Vec<PickerMatch> {
+        let mut matches: Vec<PickerMatch> = self
+            .haystacks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, haystack)| {
+                fuzzy_match(query, haystack).map(|m| PickerMatch {
+                    index,
+                    score: m.score,
+                    positions: m.positions,
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| {
+                    self.items[a.index]
+                        .label
+                        .len()
+                        .cmp(&self.items[b.index].label.len())
+                })
+                .then_with(|| a.index.cmp(&b.index))
+        });
+        matches
+    }
+
+    /// Runs the picker and returns the chosen item's value.
+    ///
+    /// ## Returns
+    ///
+    /// Returns `None` if the user cancelled or there were no items.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `PickerError::NotTty` if stdin or stderr is not a terminal, or
+    /// `PickerError::IoError` if reading keys or drawing fails.
+    pub fn run(mut self) -> Result<Option<T>, PickerError> {
+        Ok(self
+            .run_index()?
+            .map(|index| self.items.swap_remove(index).value))
+    }
+
+    /// Runs the picker and returns the index of the chosen item.
+    ///
+    /// ## Errors
+    ///
+    /// See [`run`](Self::run).
+    pub fn run_index(&self) -> Result<Option<usize>, PickerError> {
+        if !std::io::stdin().is_terminal() || !std::io::stderr().is_terminal() {
+            return Err(PickerError::NotTty);
+        }
+        if self.items.is_empty() {
+            return Ok(None);
+        }
+
+        let mut out = std::io::stderr();
+        terminal::enable_raw_mode()?;
+        let _guard = RawModeGuard;
+
+        let result = self.event_loop(&mut out);
+        execute!(out, MoveToColumn(0), Clear(ClearType::FromCursorDown))?;
+        result
+    }
+
+    fn event_loop(&self, out: &mut impl Write) -> Result<Option<usize>, PickerError> {
+        let (_, height) = terminal::size()?;
+        let visible = self
+            .max_visible
+            .min(usize::from(height.saturating_sub(1)))
+            .max(1);
+        let mut state = PickerState::new(self, visible);
+
+        // Reserve rows below the prompt so redraws never scroll the screen
+        write!(out, "{}", "\r\n".repeat(visible))?;
+        queue!(out, MoveUp(visible as u16))?;
+
+        loop {
+            self.draw(out, &state)?;
+
+            if let Event::Key(key) = event::read()?
+                && key.kind != KeyEventKind::Release
+            {
+                match state.handle_key(self, key) {
+                    PickerAction::Continue => {}
+                    PickerAction::Select => return Ok(state.selected_index()),
+                    PickerAction::Cancel => return Ok(None),
+                }
+            }
+        }
+    }
+
+    fn draw(&self, out: &mut impl Write, state: &PickerState) -> Result<(), PickerError> {
+        let (width, _) = terminal::size()?;
+        let width = usize::from(width).saturating_sub(1);
+
+        queue!(out, MoveToColumn(0), Clear(ClearType::FromCursorDown))?;
+        write!(
+            out,
+            "{}{}\x1b[2m  {}/{}\x1b[0m",
+            self.prompt,
+            state.query,
+            state.matches.len(),
+            self.items.len()
+        )?;
+
+        let mut drawn = 0u16;
+        if state.show_help {
+            let overlay = self.keymap.help_overlay().render(width);
+            for line in overlay.iter().take(state.visible) {
+                write!(out, "\r\n{line}")?;
+                drawn += 1;
+            }
+        }
+
+        let rows = state
+            .matches
+            .iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(if state.show_help { 0 } else { state.visible });
+        for (position, picker_match) in rows {
+            let item = &self.items[picker_match.index];
+            let row = render_row(
+                item,
+                &picker_match.positions,
+                position == state.selected,
+                width,
+            );
+            write!(out, "\r\n{row}")?;
+            drawn += 1;
+        }
+
+        if drawn > 0 {
+            queue!(out, MoveUp(drawn))?;
+        }
+        let cursor = self.prompt.width() + state.query.width();
+        queue!(out, MoveToColumn(cursor.min(usize::from(u16::MAX)) as u16))?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// Restores cooked mode when the picker exits, even on error.
+struct RawModeGuard;
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Query, ranked matches, and selection for a running picker.
+struct PickerState {
+    query: String,
+    matches: Vec<PickerMatch>,
+    selected: usize,
+    offset: usize,
+    visible: usize,
+    show_help: bool,
+}
+
+impl PickerState {
+    fn new<T>(picker: &Picker<T>, visible: usize) -> Self {
+        Self {
+            query: picker.query.clone(),
+            matches: picker.matches(&picker.query),
+            selected: 0,
+            offset: 0,
+            visible,
+            show_help: false,
+        }
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        self.matches.get(self.selected).map(|m| m.index)
+    }
+
+    fn handle_key<T>(&mut self, picker: &Picker<T>, key: KeyEvent) -> PickerAction {
+        // Any key dismisses the help
+        if self.show_help {
+            self.show_help = false;
+            return PickerAction::Continue;
+        }
+
+        let page = self.visible as isize;
+        let Some(&command) = picker.keymap.action_for(&key) else {
+            let plain = !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+            if let KeyCode::Char(c) = key.code
+                && plain
+            {
+                let mut query = self.query.clone();
+                query.push(c);
+                self.set_query(picker, query);
+            }
+            return PickerAction::Continue;
+        };
+
+        match command {
+            PickerCommand::Cancel => return PickerAction::Cancel,
+            PickerCommand::Accept if !self.matches.is_empty() => return PickerAction::Select,
+            PickerCommand::Accept => {}
+            PickerCommand::Previous => self.move_selection(-1),
+            PickerCommand::Next => self.move_selection(1),
+            PickerCommand::PreviousPage => self.move_selection(-page),
+            PickerCommand::NextPage => self.move_selection(page),
+            PickerCommand::ClearQuery => self.set_query(picker, String::new()),
+            PickerCommand::DeleteWord => {
+                let trimmed = self.query.trim_end();
+                let word_start = trimmed.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+                let query = trimmed[..word_start].to_string();
+                self.set_query(picker, query);
+            }
+            PickerCommand::DeleteChar => {
+                let mut query = self.query.clone();
+                query.pop();
+                self.set_query(picker, query);
+            }
+            PickerCommand::Help => self.show_help = true,
+        }
+
+        PickerAction::Continue
+    }
+
+    fn set_query<T>(&mut self, picker: &Picker<T>, query: String) {
+        if query != self.query {
+            self.matches = picker.matches(&query);
+            self.query = query;
+            self.selected = 0;
+            self.offset = 0;
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let Some(last) = self.matches.len().checked_sub(1) else {
+            return;
+        };
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+
+        // Keep the selection inside the visible window
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + self.visible {
+            self.offset = self.selected + 1 - self.visible;
+        }
+    }
+}
+
+/// Renders one list row, highlighting matched characters and truncating to `width` columns.
+fn render_row<T>(
+    item: &PickerItem<T>,
+    positions: &[usize],
+    selected: bool,
+    width: usize,
+) -> String {
+    let (marker, label_on, label_off) = if selected {
+        ("\x1b[1;36m>\x1b[0m ", "\x1b[1m", "\x1b[22m")
+    } else {
+        ("  ", "", "")
+    };
+
+    let mut row = String::from(marker);
+    let mut columns = 2;
+    let mut positions = positions.iter().peekable();
+    let label_len = item.label.chars().count();
+
+    let segments = [
+        (item.label.as_str(), 0, label_on, label_off),
+        (
+            item.detail.as_deref().unwrap_or(""),
+            label_len + 1,
+            "\x1b[2m",
+            "\x1b[22m",
+        ),
+    ];
+
+    'segments: for (index, (text, start, on, off)) in segments.into_iter().enumerate() {
+        if text.is_empty() {
+            continue;
+        }
+        if index > 0 {
+            if columns + 2 > width {
+                break;
+            }
+            row.push_str("  ");
+            columns += 2;
+        }
+
+        row.push_str(on);
+        for (offset, c) in text.chars().enumerate() {
+            let c_width = c.width().unwrap_or(0);
+            if columns + c_width > width {
+                row.push_str(off);
+                break 'segments;
+            }
+            columns += c_width;
+
+            let matched = positions.next_if_eq(&&(start + offset)).is_some();
+            if matched {
+                row.push_str("\x1b[33m");
+                row.push(c);
+                row.push_str("\x1b[39m");
+            } else {
+                row.push(c);
+            }
+        }
+        row.push_str(off);
+    }
+
+    row.push_str("\x1b[0m");
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    fn picker(labels: &[&str]) -> Picker<usize> {
+        Picker::new(
+            labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| PickerItem::new(*label, i))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn fuzzy_match_requires_ordered_subsequence() {
+        assert!(fuzzy_match("abc", "a_b_c").is_some());
+        assert!(fuzzy_match("cba", "a_b_c").is_none());
+        assert!(fuzzy_match("abcd", "abc").is_none());
+    }
+
+    #[test]
+    fn fuzzy_match_empty_query_matches_everything() {
+        let m = fuzzy_match("  ", "anything").unwrap();
+        assert_eq!(m.score, 0);
+        assert!(m.positions.is_empty());
+    }
+
+    #[test]
+    fn fuzzy_match_smart_case() {
+        assert!(fuzzy_match("config", "ParseConfig").is_some());
+        assert!(fuzzy_match("Config", "ParseConfig").is_some());
+        assert!(fuzzy_match("Config", "parse_config").is_none());
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_tight_spans() {
+        // The backward pass should pick the later, consecutive "ab"
+        let m = fuzzy_match("ab", "a__ab").unwrap();
+        assert_eq!(m.positions, vec![3, 4]);
+    }
+
+    #[test]
+    fn fuzzy_match_rewards_boundaries_and_runs() {
+        let camel = fuzzy_match("pc", "parseConfig").unwrap();
+        let buried = fuzzy_match("pc", "epicycle").unwrap();
+        assert!(camel.score > buried.score);
+
+        let run = fuzzy_match("conf", "config").unwrap();
+        let scattered = fuzzy_match("conf", "c_o_n_f").unwrap();
+        assert!(run.score > scattered.score);
+    }
+
+    #[test]
+    fn matches_rank_best_first_and_break_ties_by_length() {
+        let picker = picker(&["spec", "parse_config", "pc"]);
+        let ranked: Vec<usize> = picker.matches("pc").iter().map(|m| m.index).collect();
+        assert_eq!(ranked, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn matches_search_detail_text() {
+        let picker = Picker::new(vec![
+            PickerItem::new("greet", 0).with_detail("src/lib.rs"),
+            PickerItem::new("greet", 1).with_detail("src/main.rs"),
+        ]);
+        let ranked = picker.matches("greet main");
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].index, 1);
+    }
+
+    #[test]
+    fn typing_refilters_and_resets_selection() {
+        let picker = picker(&["alpha", "beta", "gamma"]);
+        let mut state = PickerState::new(&picker, 10);
+        assert_eq!(state.matches.len(), 3);
+
+        state.handle_key(&picker, key(KeyCode::Down));
+        assert_eq!(state.selected, 1);
+
+        state.handle_key(&picker, key(KeyCode::Char('m')));
+        assert_eq!(state.query, "m");
+        assert_eq!(state.selected, 0);
+        assert_eq!(state.matches.len(), 1);
+        assert_eq!(state.selected_index(), Some(2));
+
+        state.handle_key(&picker, key(KeyCode::Backspace));
+        assert_eq!(state.matches.len(), 3);
+    }
+
+    #[test]
+    fn ctrl_w_deletes_last_word() {
+        let picker = picker(&["alpha"]).with_query("foo bar");
+        let mut state = PickerState::new(&picker, 10);
+        state.handle_key(&picker, ctrl('w'));
+        assert_eq!(state.query, "foo ");
+        state.handle_key(&picker, ctrl('u'));
+        assert_eq!(state.query, "");
+    }
+
+    #[test]
+    fn selection_clamps_and_scrolls() {
+        let picker = picker(&["a1", "a2", "a3", "a4", "a5"]);
+        let mut state = PickerState::new(&picker, 2);
+
+        state.handle_key(&picker, key(KeyCode::Up));
+        assert_eq!(state.selected, 0);
+
+        state.handle_key(&picker, key(KeyCode::PageDown));
+        state.handle_key(&picker, ctrl('n'));
+        assert_eq!(state.selected, 3);
+        assert_eq!(state.offset, 2);
+
+        state.handle_key(&picker, key(KeyCode::PageDown));
+        assert_eq!(state.selected, 4);
+
+        state.handle_key(&picker, ctrl('p'));
+        state.handle_key(&picker, ctrl('p'));
+        state.handle_key(&picker, ctrl('p'));
+        assert_eq!(state.selected, 1);
+        assert_eq!(state.offset, 1);
+    }
+
+    #[test]
+    fn enter_and_escape_end_the_picker() {
+        let picker = picker(&["alpha"]);
+        let mut state = PickerState::new(&picker, 10);
+        assert_eq!(
+            state.handle_key(&picker, key(KeyCode::Enter)),
+            PickerAction::Select
+        );
+        assert_eq!(
+            state.handle_key(&picker, key(KeyCode::Esc)),
+            PickerAction::Cancel
+        );
+        assert_eq!(state.handle_key(&picker, ctrl('c')), PickerAction::Cancel);
+
+        // Enter does nothing when nothing matches
+        state.handle_key(&picker, key(KeyCode::Char('z')));
+        assert_eq!(
+            state.handle_key(&picker, key(KeyCode::Enter)),
+            PickerAction::Continue
+        );
+    }
+
+    #[test]
+    fn f1_toggles_help_and_any_key_closes_it() {
+        let picker = picker(&["alpha"]);
+        let mut state = PickerState::new(&picker, 10);
+        state.handle_key(&picker, key(KeyCode::F(1)));
+        assert!(state.show_help);
+
+        // The closing key is swallowed rather than cancelling or typing
+        assert_eq!(
+            state.handle_key(&picker, key(KeyCode::Esc)),
+            PickerAction::Continue
+        );
+        assert!(!state.show_help);
+        assert_eq!(state.query, "");
+
+        let help = picker.keymap.help_overlay().lines().join("\n");
+        assert!(help.contains("Ctrl-W"));
+        assert!(!help.contains("Ctrl-G"));
+    }
+
+    #[test]
+    fn render_row_highlights_and_truncates() {
+        let item = PickerItem::new("greet", ()).with_detail("lib.rs");
+        let row = render_row(&item, &[0, 6], false, 80);
+        assert!(row.starts_with("  \x1b[33mg\x1b[39mreet"));
+        assert!(row.contains("\x1b[2m\x1b[33ml\x1b[39mib.rs"));
+
+        let truncated = render_row(&item, &[], true, 5);
+        assert_eq!(truncated, "\x1b[1;36m>\x1b[0m \x1b[1mgre\x1b[22m\x1b[0m");
+    }
+}
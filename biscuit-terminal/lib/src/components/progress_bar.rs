@@ -0,0 +1,174 @@
+//! Single-line progress bar for long-running work.
+//!
+//! [`ProgressBar`] redraws one line on stderr in place, so stdout stays free
+//! for the program's real output (e.g. JSON piped elsewhere). When stderr is
+//! not a terminal nothing is drawn.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use biscuit_terminal::components::progress_bar::ProgressBar;
+//!
+//! let files = ["a.rs", "b.rs", "c.rs"];
+//! let mut bar = ProgressBar::new(files.len()).with_label("Parsing");
+//! for (done, _file) in files.iter().enumerate() {
+//!     // ... work on the file ...
+//!     bar.update(done + 1)?;
+//! }
+//! bar.finish()?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::io::{IsTerminal, Write};
+
+use crossterm::cursor::MoveToColumn;
+use crossterm::queue;
+use crossterm::terminal::{self, Clear, ClearType};
+use unicode_width::UnicodeWidthStr;
+
+/// Default number of cells in the bar itself.
+const DEFAULT_BAR_WIDTH: usize = 30;
+
+/// A progress bar counting up to a known total.
+#[derive(Debug, Clone)]
+pub struct ProgressBar {
+    total: usize,
+    label: Option<String>,
+    bar_width: usize,
+    visible: bool,
+    /// The line last drawn, so unchanged updates skip the redraw.
+    drawn: Option<String>,
+}
+
+impl ProgressBar {
+    /// Creates a bar for `total` units of work.
+    pub fn new(total: usize) -> Self {
+        Self {
+            total,
+            label: None,
+            bar_width: DEFAULT_BAR_WIDTH,
+            visible: std::io::stderr().is_terminal(),
+            drawn: None,
+        }
+    }
+
+    /// Sets the text shown before the bar.
+    pub fn with_label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the number of cells in the bar (at least one).
+    pub fn with_bar_width(mut self, cells: usize) -> Self {
+        self.bar_width = cells.max(1);
+        self
+    }
+
+    /// Returns true if updates are drawn (stderr is a terminal).
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Renders the line for `completed` units, fitting the bar into `width`
+    /// columns.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biscuit_terminal::components::progress_bar::ProgressBar;
+    ///
+    /// let bar = ProgressBar::new(4).with_label("Parsing").with_bar_width(8);
+    /// assert_eq!(bar.render(1, 80), "Parsing ██░░░░░░ 1/4");
+    /// ```
+    pub fn render(&self, completed: usize, width: usize) -> String {
+        let completed = completed.min(self.total);
+        let count = format!("{completed}/{}", self.total);
+        let label = self
+            .label
+            .as_ref()
+            .map(|label| format!("{label} "))
+            .unwrap_or_default();
+
+        // Shrink the bar (never the label or count) when the line is too wide
+        let fixed = label.width() + 1 + count.width();
+        let cells = self.bar_width.min(width.saturating_sub(fixed)).max(1);
+        // Nothing to do counts as done
+        let filled = (cells * completed).checked_div(self.total).unwrap_or(cells);
+
+        format!(
+            "{label}{}{} {count}",
+            "█".repeat(filled),
+            "░".repeat(cells - filled)
+        )
+    }
+
+    /// Redraws the bar for `completed` units on stderr.
+    ///
+    /// Does nothing when stderr is not a terminal or the line hasn't changed.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if writing to stderr fails.
+    pub fn update(&mut self, completed: usize) -> std::io::Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+
+        let width = terminal::size()
+            .map(|(width, _)| usize::from(width).saturating_sub(1))
+            .unwrap_or(80);
+        let line = self.render(completed, width);
+        if self.drawn.as_ref() == Some(&line) {
+            return Ok(());
+        }
+
+        let mut out = std::io::stderr();
+        queue!(out, MoveToColumn(0), Clear(ClearType::CurrentLine))?;
+        write!(out, "{line}")?;
+        out.flush()?;
+        self.drawn = Some(line);
+        Ok(())
+    }
+
+    /// Clears the bar's line, leaving the cursor at its start.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if writing to stderr fails.
+    pub fn finish(&mut self) -> std::io::Result<()> {
+        if self.drawn.take().is_none() {
+            return Ok(());
+        }
+
+        let mut out = std::io::stderr();
+        queue!(out, MoveToColumn(0), Clear(ClearType::CurrentLine))?;
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_fills_in_proportion() {
+        let bar = ProgressBar::new(10).with_bar_width(10);
+        assert_eq!(bar.render(0, 80), "░░░░░░░░░░ 0/10");
+        assert_eq!(bar.render(5, 80), "█████░░░░░ 5/10");
+        assert_eq!(bar.render(12, 80), "██████████ 10/10");
+    }
+
+    #[test]
+    fn render_shrinks_the_bar_to_fit() {
+        let bar = ProgressBar::new(2).with_label("Parsing").with_bar_width(20);
+        let line = bar.render(1, 16);
+        assert_eq!(line, "Parsing ██░░ 1/2");
+        assert_eq!(line.width(), 16);
+    }
+
+    #[test]
+    fn render_handles_empty_totals() {
+        let bar = ProgressBar::new(0).with_bar_width(4);
+        assert_eq!(bar.render(0, 80), "████ 0/0");
+    }
+}
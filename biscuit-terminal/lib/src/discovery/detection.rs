@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use terminal_size::{Height, Width, terminal_size};
 use termini::{NumberCapability, StringCapability, TermInfo};
 
+use crate::discovery::windows_console::{ConsoleHost, console_host, windows_build};
+
 /// The type of image support (if any) of a terminal
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ImageSupport {
@@ -230,8 +232,10 @@ pub enum MultiplexSupport {
 ///
 /// Detection strategy:
 /// 1. Check `COLORTERM` environment variable for "truecolor" or "24bit"
-/// 2. Query terminfo `MaxColors` capability
-/// 3. Default to `ColorDepth::None` if detection fails
+/// 2. On a Windows console, use the console host's capabilities (Windows has
+///    no terminfo database)
+/// 3. Query terminfo `MaxColors` capability
+/// 4. Default to `ColorDepth::None` if detection fails
 ///
 /// ## Examples
 ///
@@ -261,6 +265,18 @@ pub fn color_depth() -> ColorDepth {
         }
     }
 
+    // Windows consoles have no terminfo entry; ask the console host instead
+    if let Some(host) = console_host() {
+        let color_depth = host.color_depth(windows_build());
+        tracing::info!(
+            ?color_depth,
+            source = "windows_console",
+            ?host,
+            "Detected color depth from Windows console host"
+        );
+        return color_depth;
+    }
+
     // Fallback to terminfo
     match TermInfo::from_env() {
         Ok(term_info) => {
@@ -567,6 +583,14 @@ pub fn image_support_with_reason() -> ImageSupportResult {
         };
     }
 
+    if console_host() == Some(ConsoleHost::Legacy) {
+        return ImageSupportResult {
+            support: ImageSupport::None,
+            reason: "legacy Windows console cannot process escape sequences".to_string(),
+            method: "windows_console".to_string(),
+        };
+    }
+
     // When viuer feature is enabled, use its runtime detection first
     // viuer actually queries the terminal, so it's more accurate than env heuristics
     #[cfg(feature = "viuer")]
@@ -733,7 +757,7 @@ fn image_support_from_env() -> ImageSupportResult {
 /// }
 /// ```
 pub fn osc8_link_support() -> bool {
-    if !is_tty() {
+    if !is_tty() || console_host() == Some(ConsoleHost::Legacy) {
         return false;
     }
 
@@ -919,9 +943,9 @@ pub fn underline_support() -> UnderlineSupport {
         };
     }
 
-    // Check for dumb terminal
+    // Check for dumb terminal or a legacy Windows console
     let term = env::var("TERM").unwrap_or_default();
-    if term == "dumb" {
+    if term == "dumb" || console_host() == Some(ConsoleHost::Legacy) {
         return none;
     }
 
@@ -1056,9 +1080,9 @@ pub fn italics_support() -> bool {
         return false;
     }
 
-    // Check for dumb terminal
+    // Check for dumb terminal or a legacy Windows console
     let term = env::var("TERM").unwrap_or_default();
-    if term == "dumb" {
+    if term == "dumb" || console_host() == Some(ConsoleHost::Legacy) {
         return false;
     }
 
@@ -12,14 +12,18 @@
 //! - [`osc_queries`] - OSC color queries (10/11/12) for background/foreground detection
 //! - [`mode_2027`] - Unicode grapheme cluster width support detection
 //! - [`clipboard`] - OSC52 clipboard support for terminal applications
+//! - [`notifications`] - Desktop notifications via OSC 9, OSC 777 and Kitty's OSC 99
 //! - [`fonts`] - Font detection utilities (font name, size, ligatures)
+//! - [`windows_console`] - Windows Terminal, ConPTY and legacy console detection
 
 pub mod clipboard;
 pub mod config_paths;
 pub mod detection;
 pub mod eval;
 pub mod mode_2027;
+pub mod notifications;
 pub mod osc_queries;
 pub mod os_detection;
 pub mod fonts;
 pub mod locale;
+pub mod windows_console;
@@ -0,0 +1,202 @@
+//! Desktop notifications through terminal escape sequences.
+//!
+//! Several terminals turn an OSC escape sequence into a native desktop
+//! notification, which lets a command-line program alert the user without a
+//! platform notification library. There are three competing protocols:
+//!
+//! | Protocol | Format | Terminals |
+//! |----------|--------|-----------|
+//! | OSC 9    | `ESC ] 9 ; <body> BEL` | iTerm2, WezTerm |
+//! | OSC 777  | `ESC ] 777 ; notify ; <title> ; <body> BEL` | Foot, Ghostty, Contour |
+//! | OSC 99   | `ESC ] 99 ; <metadata> ; <payload> ESC \` | Kitty |
+//!
+//! OSC 9 has no title, so the title and body are joined into a single line.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use biscuit_terminal::discovery::notifications::{notification_protocol, send_notification};
+//!
+//! if notification_protocol().is_some() {
+//!     send_notification("Build finished", "cargo build succeeded").ok();
+//! }
+//! ```
+
+use crate::discovery::detection::{TerminalApp, get_terminal_app, is_tty};
+use crate::discovery::os_detection::is_ci;
+
+/// Escape sequence protocol used to raise a desktop notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationProtocol {
+    /// `OSC 9`, the iTerm2 growl-style notification (body only).
+    Osc9,
+    /// `OSC 777`, the `notify` extension originating in urxvt.
+    Osc777,
+    /// `OSC 99`, Kitty's desktop notification protocol.
+    Kitty,
+}
+
+impl NotificationProtocol {
+    /// Returns the protocol understood by a terminal app, if any.
+    pub fn for_app(app: &TerminalApp) -> Option<Self> {
+        match app {
+            TerminalApp::ITerm2 | TerminalApp::Wezterm => Some(Self::Osc9),
+            TerminalApp::Foot | TerminalApp::Ghostty | TerminalApp::Contour => Some(Self::Osc777),
+            TerminalApp::Kitty => Some(Self::Kitty),
+            _ => None,
+        }
+    }
+}
+
+/// Detect which notification protocol the current terminal supports.
+///
+/// ## Returns
+///
+/// - `None` if not in a TTY, in CI, or the terminal has no known protocol
+/// - Otherwise the protocol to use with [`build_notification_sequence`]
+pub fn notification_protocol() -> Option<NotificationProtocol> {
+    if !is_tty() || is_ci() {
+        return None;
+    }
+    NotificationProtocol::for_app(&get_terminal_app())
+}
+
+/// Remove characters that would end the escape sequence early.
+fn sanitize(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect()
+}
+
+/// Build a notification escape sequence without writing it.
+///
+/// Control characters in `title` and `body` are replaced with spaces so the
+/// text can't terminate the sequence or inject other escape codes.
+///
+/// ## Examples
+///
+/// ```
+/// use biscuit_terminal::discovery::notifications::{
+///     build_notification_sequence, NotificationProtocol,
+/// };
+///
+/// let sequence = build_notification_sequence(NotificationProtocol::Osc777, "Done", "all good");
+/// assert_eq!(sequence, "\x1b]777;notify;Done;all good\x07");
+/// ```
+pub fn build_notification_sequence(
+    protocol: NotificationProtocol,
+    title: &str,
+    body: &str,
+) -> String {
+    let title = sanitize(title);
+    let body = sanitize(body);
+
+    match protocol {
+        NotificationProtocol::Osc9 => {
+            let text = match (title.is_empty(), body.is_empty()) {
+                (true, _) => body,
+                (false, true) => title,
+                (false, false) => format!("{title}: {body}"),
+            };
+            format!("\x1b]9;{text}\x07")
+        }
+        // The title is a `;`-separated field, the body runs to the terminator
+        NotificationProtocol::Osc777 => {
+            format!("\x1b]777;notify;{};{body}\x07", title.replace(';', ","))
+        }
+        // Title (p=title) is buffered until the body (p=body) arrives with d=1
+        NotificationProtocol::Kitty => {
+            format!("\x1b]99;i=1:d=0:p=title;{title}\x1b\\\x1b]99;i=1:d=1:p=body;{body}\x1b\\")
+        }
+    }
+}
+
+/// Raise a desktop notification through the terminal.
+///
+/// Writes the sequence for the detected [`NotificationProtocol`] to stdout.
+///
+/// ## Errors
+///
+/// Returns an error if:
+/// - Not in a TTY or in CI environment
+/// - The terminal has no known notification protocol
+/// - Writing to stdout fails
+pub fn send_notification(title: &str, body: &str) -> std::io::Result<()> {
+    let Some(protocol) = notification_protocol() else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "desktop notifications not supported in this terminal",
+        ));
+    };
+
+    use std::io::Write;
+    let mut stdout = std::io::stdout();
+    stdout.write_all(build_notification_sequence(protocol, title, body).as_bytes())?;
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protocol_for_known_apps() {
+        assert_eq!(
+            NotificationProtocol::for_app(&TerminalApp::Wezterm),
+            Some(NotificationProtocol::Osc9)
+        );
+        assert_eq!(
+            NotificationProtocol::for_app(&TerminalApp::Foot),
+            Some(NotificationProtocol::Osc777)
+        );
+        assert_eq!(
+            NotificationProtocol::for_app(&TerminalApp::Kitty),
+            Some(NotificationProtocol::Kitty)
+        );
+        assert_eq!(NotificationProtocol::for_app(&TerminalApp::Alacritty), None);
+    }
+
+    #[test]
+    fn test_osc9_joins_title_and_body() {
+        assert_eq!(
+            build_notification_sequence(NotificationProtocol::Osc9, "Done", "exit 0"),
+            "\x1b]9;Done: exit 0\x07"
+        );
+        assert_eq!(
+            build_notification_sequence(NotificationProtocol::Osc9, "Done", ""),
+            "\x1b]9;Done\x07"
+        );
+    }
+
+    #[test]
+    fn test_osc777_escapes_title_separator() {
+        assert_eq!(
+            build_notification_sequence(NotificationProtocol::Osc777, "a;b", "c;d"),
+            "\x1b]777;notify;a,b;c;d\x07"
+        );
+    }
+
+    #[test]
+    fn test_kitty_sends_title_then_body() {
+        let sequence = build_notification_sequence(NotificationProtocol::Kitty, "Done", "ok");
+        assert_eq!(
+            sequence,
+            "\x1b]99;i=1:d=0:p=title;Done\x1b\\\x1b]99;i=1:d=1:p=body;ok\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_control_characters_are_stripped() {
+        let sequence =
+            build_notification_sequence(NotificationProtocol::Osc9, "", "bad\x07\x1b]0;x\nline");
+        assert_eq!(sequence, "\x1b]9;bad  ]0;x line\x07");
+    }
+
+    #[test]
+    fn test_send_notification_behavior() {
+        // Either succeeds (real terminal) or fails as unsupported (non-TTY)
+        if let Err(e) = send_notification("title", "body") {
+            assert_eq!(e.kind(), std::io::ErrorKind::Unsupported);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! Windows console host detection and Virtual Terminal enablement.
+//!
+//! Programs on Windows write to one of three kinds of console host, and they
+//! differ a lot in what they can render:
+//!
+//! | Host | Escape sequences | Colors | Detected by |
+//! |------|------------------|--------|-------------|
+//! | Windows Terminal | Yes | 24-bit | `WT_SESSION` |
+//! | ConPTY / modern conhost | Yes, once enabled | 24-bit (256 before build 14931) | VT processing can be enabled |
+//! | Legacy console | No | 16 | VT processing cannot be enabled |
+//!
+//! ConPTY is the pseudo console used by VS Code, WezTerm, Alacritty and other
+//! emulators on Windows. Both ConPTY and `conhost.exe` only interpret escape
+//! sequences after the `ENABLE_VIRTUAL_TERMINAL_PROCESSING` flag is set on the
+//! output handle, which [`enable_virtual_terminal`] does once per process.
+//!
+//! None of this applies when stdout is not a Windows console (on other
+//! platforms, or when output is piped or goes to an MSYS/Cygwin pty); those
+//! cases return `None` so callers fall back to the usual environment checks.
+//!
+//! ## Examples
+//!
+//! ```
+//! use biscuit_terminal::discovery::windows_console::{console_host, ConsoleHost};
+//!
+//! match console_host() {
+//!     Some(ConsoleHost::Legacy) => println!("Plain text only"),
+//!     Some(host) => println!("Windows console: {:?}", host),
+//!     None => println!("Not a Windows console"),
+//! }
+//! ```
+
+use std::env;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::discovery::detection::ColorDepth;
+
+/// First Windows 10 build whose console renders 24-bit color.
+pub const TRUECOLOR_MIN_BUILD: u32 = 14931;
+
+/// The kind of Windows console host stdout is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConsoleHost {
+    /// Windows Terminal (`WT_SESSION` is set)
+    WindowsTerminal,
+    /// A pseudo console or modern `conhost.exe` with VT processing enabled
+    ConPty,
+    /// A console that cannot process escape sequences
+    Legacy,
+}
+
+impl ConsoleHost {
+    /// Whether the host interprets ANSI/VT escape sequences.
+    pub fn supports_vt(&self) -> bool {
+        !matches!(self, ConsoleHost::Legacy)
+    }
+
+    /// The color depth the host can render on the given Windows build.
+    ///
+    /// An unknown build is assumed to be recent.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biscuit_terminal::discovery::detection::ColorDepth;
+    /// use biscuit_terminal::discovery::windows_console::ConsoleHost;
+    ///
+    /// assert!(matches!(ConsoleHost::ConPty.color_depth(Some(10586)), ColorDepth::Enhanced));
+    /// assert!(matches!(ConsoleHost::Legacy.color_depth(None), ColorDepth::Basic));
+    /// ```
+    pub fn color_depth(&self, build: Option<u32>) -> ColorDepth {
+        match self {
+            ConsoleHost::WindowsTerminal => ColorDepth::TrueColor,
+            ConsoleHost::ConPty => match build {
+                Some(build) if build < TRUECOLOR_MIN_BUILD => ColorDepth::Enhanced,
+                _ => ColorDepth::TrueColor,
+            },
+            ConsoleHost::Legacy => ColorDepth::Basic,
+        }
+    }
+}
+
+/// Classify the console host from the `WT_SESSION` check and the VT probe.
+///
+/// `vt_enabled` is `None` when stdout is not a Windows console, `Some(false)`
+/// when VT processing was rejected and `Some(true)` when it is on.
+pub fn classify_console_host(wt_session: bool, vt_enabled: Option<bool>) -> Option<ConsoleHost> {
+    match vt_enabled? {
+        true if wt_session => Some(ConsoleHost::WindowsTerminal),
+        true => Some(ConsoleHost::ConPty),
+        false => Some(ConsoleHost::Legacy),
+    }
+}
+
+/// Detect the Windows console host stdout is attached to.
+///
+/// Enables VT processing as a side effect (see [`enable_virtual_terminal`]).
+/// Returns `None` on other platforms or when stdout is not a Windows console.
+pub fn console_host() -> Option<ConsoleHost> {
+    let host = classify_console_host(env::var("WT_SESSION").is_ok(), enable_virtual_terminal());
+    if let Some(host) = host {
+        tracing::debug!(?host, build = ?windows_build(), "Detected Windows console host");
+    }
+    host
+}
+
+/// Enable VT (ANSI escape sequence) processing on the stdout console.
+///
+/// The console mode is only changed on the first call; later calls return the
+/// cached outcome.
+///
+/// ## Returns
+///
+/// - `Some(true)` if VT processing is enabled
+/// - `Some(false)` if the console rejected it (a legacy console)
+/// - `None` if stdout is not a Windows console
+pub fn enable_virtual_terminal() -> Option<bool> {
+    static VT_ENABLED: OnceLock<Option<bool>> = OnceLock::new();
+    *VT_ENABLED.get_or_init(probe_virtual_terminal)
+}
+
+/// The Windows build number (e.g. `22631`), or `None` on other platforms.
+pub fn windows_build() -> Option<u32> {
+    static BUILD: OnceLock<Option<u32>> = OnceLock::new();
+    *BUILD.get_or_init(query_windows_build)
+}
+
+#[cfg(windows)]
+fn probe_virtual_terminal() -> Option<bool> {
+    use windows_sys::Win32::Foundation::INVALID_HANDLE_VALUE;
+    use windows_sys::Win32::System::Console::{
+        CONSOLE_MODE, ENABLE_VIRTUAL_TERMINAL_PROCESSING, GetConsoleMode, GetStdHandle,
+        STD_OUTPUT_HANDLE, SetConsoleMode,
+    };
+
+    // SAFETY: the handle comes from GetStdHandle and is checked before use;
+    // `mode` outlives the GetConsoleMode call.
+    unsafe {
+        let handle = GetStdHandle(STD_OUTPUT_HANDLE);
+        if handle.is_null() || handle == INVALID_HANDLE_VALUE {
+            return None;
+        }
+
+        let mut mode: CONSOLE_MODE = 0;
+        if GetConsoleMode(handle, &mut mode) == 0 {
+            // Not a console (pipe, file, or MSYS/Cygwin pty)
+            return None;
+        }
+        if mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0 {
+            return Some(true);
+        }
+
+        let enabled = SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) != 0;
+        tracing::debug!(enabled, "Requested VT processing on the Windows console");
+        Some(enabled)
+    }
+}
+
+#[cfg(not(windows))]
+fn probe_virtual_terminal() -> Option<bool> {
+    None
+}
+
+#[cfg(windows)]
+fn query_windows_build() -> Option<u32> {
+    use windows_sys::Wdk::System::SystemServices::RtlGetVersion;
+    use windows_sys::Win32::System::SystemInformation::OSVERSIONINFOW;
+
+    // RtlGetVersion, unlike GetVersionEx, reports the real version regardless
+    // of the application manifest.
+    // SAFETY: OSVERSIONINFOW is plain data and its size field is set as required.
+    unsafe {
+        let mut info: OSVERSIONINFOW = std::mem::zeroed();
+        info.dwOSVersionInfoSize = std::mem::size_of::<OSVERSIONINFOW>() as u32;
+        (RtlGetVersion(&mut info) == 0).then_some(info.dwBuildNumber)
+    }
+}
+
+#[cfg(not(windows))]
+fn query_windows_build() -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_not_a_console() {
+        assert_eq!(classify_console_host(false, None), None);
+        assert_eq!(classify_console_host(true, None), None);
+    }
+
+    #[test]
+    fn test_classify_console_hosts() {
+        assert_eq!(
+            classify_console_host(true, Some(true)),
+            Some(ConsoleHost::WindowsTerminal)
+        );
+        assert_eq!(
+            classify_console_host(false, Some(true)),
+            Some(ConsoleHost::ConPty)
+        );
+        // A rejected VT request means a legacy console, even with WT_SESSION leaked
+        // into the environment of a child conhost window
+        assert_eq!(
+            classify_console_host(true, Some(false)),
+            Some(ConsoleHost::Legacy)
+        );
+    }
+
+    #[test]
+    fn test_color_depth_by_host_and_build() {
+        assert!(matches!(
+            ConsoleHost::WindowsTerminal.color_depth(None),
+            ColorDepth::TrueColor
+        ));
+        assert!(matches!(
+            ConsoleHost::ConPty.color_depth(Some(22631)),
+            ColorDepth::TrueColor
+        ));
+        assert!(matches!(
+            ConsoleHost::ConPty.color_depth(Some(TRUECOLOR_MIN_BUILD - 1)),
+            ColorDepth::Enhanced
+        ));
+        assert!(matches!(
+            ConsoleHost::ConPty.color_depth(None),
+            ColorDepth::TrueColor
+        ));
+        assert!(matches!(
+            ConsoleHost::Legacy.color_depth(Some(22631)),
+            ColorDepth::Basic
+        ));
+    }
+
+    #[test]
+    fn test_supports_vt() {
+        assert!(ConsoleHost::WindowsTerminal.supports_vt());
+        assert!(ConsoleHost::ConPty.supports_vt());
+        assert!(!ConsoleHost::Legacy.supports_vt());
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_no_console_host_off_windows() {
+        assert_eq!(enable_virtual_terminal(), None);
+        assert_eq!(windows_build(), None);
+        assert_eq!(console_host(), None);
+    }
+}
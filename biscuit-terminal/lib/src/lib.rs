@@ -35,10 +35,11 @@
 //!   - [`discovery::config_paths`] - Terminal config file paths
 //!   - [`discovery::osc_queries`] - Terminal color queries
 //!   - [`discovery::clipboard`] - OSC52 clipboard support
+//!   - [`discovery::notifications`] - Desktop notifications via OSC escape sequences
 //!   - [`discovery::mode_2027`] - Unicode grapheme cluster support
 //!   - [`discovery::eval`] - Escape code analysis utilities
 //! - [`components`] - Renderable terminal components (sections, lists, tables)
-//! - [`utils`] - Utility functions (colors, styling, escape codes)
+//! - [`utils`] - Utility functions (colors, styling, escape codes, ANSI-to-HTML)
 
 pub mod components;
 pub mod discovery;
@@ -12,8 +12,12 @@ use crate::discovery::locale::{CharEncoding, TerminalLocale};
 use crate::discovery::os_detection::{
     LinuxDistro, OsType, detect_linux_distro, detect_os_type, is_ci,
 };
+use crate::discovery::windows_console::{ConsoleHost, console_host};
 
 fn new_terminal() -> Terminal {
+    // Detect the Windows console first: it enables VT processing, which the
+    // capability checks below (and any later output) rely on
+    let console_host = console_host();
     let app = get_terminal_app();
     let config_file = get_terminal_config_path(&app);
 
@@ -24,6 +28,7 @@ fn new_terminal() -> Terminal {
         underline_support: underline_support(),
         osc_link_support: osc8_link_support(),
         is_tty: is_tty(),
+        console_host,
         color_depth: color_depth(),
         os: detect_os_type(),
         distro: detect_linux_distro(),
@@ -75,6 +80,9 @@ pub struct Terminal {
 
     /// Whether stdout is connected to a TTY
     pub is_tty: bool,
+    /// The Windows console host stdout is attached to (`None` on other
+    /// platforms or when stdout is not a Windows console)
+    pub console_host: Option<ConsoleHost>,
     /// The color depth supported by the terminal
     pub color_depth: ColorDepth,
 
@@ -130,6 +138,9 @@ impl Terminal {
     /// - Feature support (italics, images, underlines, OSC8 links)
     /// - Configuration file path
     ///
+    /// On a Windows console this also enables VT processing so that escape
+    /// sequences written afterwards are rendered rather than printed.
+    ///
     /// ## Examples
     ///
     /// ```
@@ -249,6 +260,14 @@ mod tests {
         assert!(term.distro.is_none());
     }
 
+    #[test]
+    fn test_terminal_console_host_only_on_windows() {
+        let term = Terminal::new();
+        #[cfg(not(windows))]
+        assert!(term.console_host.is_none());
+        let _host = term.console_host;
+    }
+
     #[test]
     fn test_terminal_has_font_fields() {
         let term = Terminal::new();
@@ -0,0 +1,607 @@
+//! Conversion of ANSI-styled terminal output into HTML.
+//!
+//! Captured CLI output keeps its styling as escape codes. [`ansi_to_html`]
+//! turns those codes into inline-styled `<span>` elements so the output can
+//! be embedded in an HTML report:
+//!
+//! - SGR colors: the 16 basic colors, the 256-color palette and truecolor
+//!   (both `;` and `:` separated forms)
+//! - Bold, dim, italic, underline, strikethrough and inverse
+//! - OSC8 hyperlinks, rendered as `<a href>` for `http`, `https`, `mailto`
+//!   and `file` URIs (other schemes keep only the link text)
+//!
+//! Cursor movement, queries and other escape codes are dropped, as are
+//! control characters other than newline and tab. Text is HTML-escaped.
+//!
+//! The output contains no wrapper element; place it inside a `<pre>` (or use
+//! [`AnsiHtml::with_pre`]) so whitespace and line breaks are preserved.
+//!
+//! ## Examples
+//!
+//! ```
+//! use biscuit_terminal::utils::ansi_html::ansi_to_html;
+//!
+//! let html = ansi_to_html("\x1b[1;31merror\x1b[0m: <missing>");
+//! assert_eq!(
+//!     html,
+//!     "<span style=\"color:#cd3131;font-weight:bold\">error</span>: &lt;missing&gt;"
+//! );
+//! ```
+
+use std::fmt::Write;
+
+use crate::utils::color::{BasicColor, RgbColor};
+
+/// Default palette for the 16 basic ANSI colors (VS Code's dark terminal theme).
+pub const DEFAULT_PALETTE: [RgbColor; 16] = [
+    RgbColor::new(0x00, 0x00, 0x00, BasicColor::Black),
+    RgbColor::new(0xcd, 0x31, 0x31, BasicColor::Red),
+    RgbColor::new(0x0d, 0xbc, 0x79, BasicColor::Green),
+    RgbColor::new(0xe5, 0xe5, 0x10, BasicColor::Yellow),
+    RgbColor::new(0x24, 0x72, 0xc8, BasicColor::Blue),
+    RgbColor::new(0xbc, 0x3f, 0xbc, BasicColor::Magenta),
+    RgbColor::new(0x11, 0xa8, 0xcd, BasicColor::Cyan),
+    RgbColor::new(0xe5, 0xe5, 0xe5, BasicColor::White),
+    RgbColor::new(0x66, 0x66, 0x66, BasicColor::BrightBlack),
+    RgbColor::new(0xf1, 0x4c, 0x4c, BasicColor::BrightRed),
+    RgbColor::new(0x23, 0xd1, 0x8b, BasicColor::BrightGreen),
+    RgbColor::new(0xf5, 0xf5, 0x43, BasicColor::BrightYellow),
+    RgbColor::new(0x3b, 0x8e, 0xea, BasicColor::BrightBlue),
+    RgbColor::new(0xd6, 0x70, 0xd6, BasicColor::BrightMagenta),
+    RgbColor::new(0x29, 0xb8, 0xdb, BasicColor::BrightCyan),
+    RgbColor::new(0xff, 0xff, 0xff, BasicColor::BrightWhite),
+];
+
+/// URI schemes rendered as links; anything else (e.g. `javascript:`) is not.
+const LINK_SCHEMES: [&str; 4] = ["http://", "https://", "mailto:", "file://"];
+
+/// Converts ANSI-styled text to HTML using the default palette.
+///
+/// See the [module documentation](self) for what is supported.
+pub fn ansi_to_html<T: AsRef<str>>(content: T) -> String {
+    AnsiHtml::new().convert(content)
+}
+
+/// Configurable ANSI-to-HTML converter.
+///
+/// ## Examples
+///
+/// ```
+/// use biscuit_terminal::utils::ansi_html::{AnsiHtml, DEFAULT_PALETTE};
+/// use biscuit_terminal::utils::color::{BasicColor, RgbColor};
+///
+/// let mut palette = DEFAULT_PALETTE;
+/// palette[1] = RgbColor::new(0xff, 0x00, 0x00, BasicColor::Red);
+///
+/// let converter = AnsiHtml::new().with_palette(palette).with_pre(true);
+/// let html = converter.convert("\x1b[31mred\x1b[0m");
+/// assert_eq!(html, "<pre class=\"ansi\"><span style=\"color:#ff0000\">red</span></pre>");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnsiHtml {
+    palette: [RgbColor; 16],
+    foreground: RgbColor,
+    background: RgbColor,
+    pre: bool,
+}
+
+impl Default for AnsiHtml {
+    fn default() -> Self {
+        Self {
+            palette: DEFAULT_PALETTE,
+            foreground: DEFAULT_PALETTE[7],
+            background: DEFAULT_PALETTE[0],
+            pre: false,
+        }
+    }
+}
+
+impl AnsiHtml {
+    /// Creates a converter with the default palette.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the colors used for the 16 basic ANSI colors (and the first 16
+    /// entries of the 256-color palette).
+    pub fn with_palette(mut self, palette: [RgbColor; 16]) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets the default foreground and background colors, used when inverse
+    /// video swaps an unset color.
+    pub fn with_default_colors(mut self, foreground: RgbColor, background: RgbColor) -> Self {
+        self.foreground = foreground;
+        self.background = background;
+        self
+    }
+
+    /// Wraps the output in `<pre class="ansi">`.
+    pub fn with_pre(mut self, pre: bool) -> Self {
+        self.pre = pre;
+        self
+    }
+
+    /// Converts ANSI-styled text to HTML.
+    pub fn convert<T: AsRef<str>>(&self, content: T) -> String {
+        let content = content.as_ref();
+        let mut out = Writer::new(self);
+        if self.pre {
+            out.html.push_str("<pre class=\"ansi\">");
+        }
+
+        let mut chars = content.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            match c {
+                '\x1b' => match chars.peek().map(|&(_, next)| next) {
+                    Some('[') => {
+                        chars.next();
+                        let params_start = start + 2;
+                        let mut final_byte = None;
+                        for (i, c) in chars.by_ref() {
+                            if ('\x40'..='\x7e').contains(&c) {
+                                final_byte = Some((i, c));
+                                break;
+                            }
+                        }
+                        if let Some((end, 'm')) = final_byte {
+                            out.style.apply_sgr(&content[params_start..end], self);
+                        }
+                    }
+                    Some(']') => {
+                        chars.next();
+                        let body_start = start + 2;
+                        let mut body_end = content.len();
+                        while let Some((i, c)) = chars.next() {
+                            if c == '\x07' {
+                                body_end = i;
+                                break;
+                            }
+                            if c == '\x1b' && chars.peek().is_some_and(|&(_, c)| c == '\\') {
+                                chars.next();
+                                body_end = i;
+                                break;
+                            }
+                        }
+                        if let Some(osc8) = content[body_start..body_end].strip_prefix("8;") {
+                            // `8;params;uri` - an empty uri closes the link
+                            let uri = osc8.split_once(';').map_or("", |(_, uri)| uri);
+                            out.set_link(uri);
+                        }
+                    }
+                    Some(_) => {
+                        chars.next();
+                    }
+                    None => {}
+                },
+                '\n' | '\t' => out.text(c),
+                c if c.is_control() => {}
+                c => out.text(c),
+            }
+        }
+
+        out.finish();
+        if self.pre {
+            out.html.push_str("</pre>");
+        }
+        out.html
+    }
+}
+
+/// Text attributes set by SGR codes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Style {
+    foreground: Option<RgbColor>,
+    background: Option<RgbColor>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+    inverse: bool,
+}
+
+impl Style {
+    /// Applies the parameters of an SGR (`ESC [ ... m`) sequence.
+    fn apply_sgr(&mut self, params: &str, converter: &AnsiHtml) {
+        if params.is_empty() {
+            *self = Self::default();
+            return;
+        }
+
+        let params: Vec<&str> = params.split(';').collect();
+        let mut i = 0;
+        while i < params.len() {
+            let param = params[i];
+            i += 1;
+
+            // Colon-separated sub-parameters, e.g. `4:3` or `38:2::255:0:0`
+            if let Some((code, sub)) = param.split_once(':') {
+                let sub: Vec<&str> = sub.split(':').collect();
+                match code {
+                    "4" => self.underline = sub.first() != Some(&"0"),
+                    "38" => self.foreground = extended_color(&sub, converter),
+                    "48" => self.background = extended_color(&sub, converter),
+                    _ => {}
+                }
+                continue;
+            }
+
+            let Ok(code) = param.parse::<u8>() else {
+                continue;
+            };
+            match code {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 | 21 => self.underline = true,
+                7 => self.inverse = true,
+                9 => self.strikethrough = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                27 => self.inverse = false,
+                29 => self.strikethrough = false,
+                30..=37 => self.foreground = Some(converter.palette[(code - 30) as usize]),
+                39 => self.foreground = None,
+                40..=47 => self.background = Some(converter.palette[(code - 40) as usize]),
+                49 => self.background = None,
+                90..=97 => self.foreground = Some(converter.palette[(code - 90 + 8) as usize]),
+                100..=107 => {
+                    self.background = Some(converter.palette[(code - 100 + 8) as usize]);
+                }
+                38 | 48 => {
+                    let (color, used) = match params.get(i) {
+                        Some(&"5") => (
+                            params
+                                .get(i + 1)
+                                .and_then(|n| n.parse().ok())
+                                .map(|n| palette_color(n, converter)),
+                            2,
+                        ),
+                        Some(&"2") => (rgb(params.get(i + 1..i + 4).unwrap_or_default()), 4),
+                        _ => (None, 0),
+                    };
+                    i += used;
+                    if code == 38 {
+                        self.foreground = color;
+                    } else {
+                        self.background = color;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Inline CSS for this style, or `None` when it is the default.
+    fn css(&self, converter: &AnsiHtml) -> Option<String> {
+        let (foreground, background) = if self.inverse {
+            (
+                Some(self.background.unwrap_or(converter.background)),
+                Some(self.foreground.unwrap_or(converter.foreground)),
+            )
+        } else {
+            (self.foreground, self.background)
+        };
+
+        let mut css = Vec::new();
+        if let Some(color) = foreground {
+            css.push(format!("color:{}", hex(color)));
+        }
+        if let Some(color) = background {
+            css.push(format!("background-color:{}", hex(color)));
+        }
+        if self.bold {
+            css.push("font-weight:bold".to_string());
+        }
+        if self.dim {
+            css.push("opacity:0.7".to_string());
+        }
+        if self.italic {
+            css.push("font-style:italic".to_string());
+        }
+        match (self.underline, self.strikethrough) {
+            (true, true) => css.push("text-decoration:underline line-through".to_string()),
+            (true, false) => css.push("text-decoration:underline".to_string()),
+            (false, true) => css.push("text-decoration:line-through".to_string()),
+            (false, false) => {}
+        }
+
+        (!css.is_empty()).then(|| css.join(";"))
+    }
+}
+
+/// Parses the sub-parameters of a colon-form extended color (`5:n` or
+/// `2:[colorspace]:r:g:b`).
+fn extended_color(sub: &[&str], converter: &AnsiHtml) -> Option<RgbColor> {
+    match sub {
+        ["5", n, ..] => n.parse().ok().map(|n| palette_color(n, converter)),
+        ["2", _, r, g, b, ..] => rgb(&[*r, *g, *b]),
+        ["2", r, g, b] => rgb(&[*r, *g, *b]),
+        _ => None,
+    }
+}
+
+/// Builds a truecolor value from three decimal channel strings.
+fn rgb(channels: &[&str]) -> Option<RgbColor> {
+    let [r, g, b] = channels else {
+        return None;
+    };
+    let (r, g, b) = (r.parse().ok()?, g.parse().ok()?, b.parse().ok()?);
+    Some(RgbColor::new(r, g, b, nearest_basic(r, g, b)))
+}
+
+/// Resolves an entry of the 256-color palette.
+fn palette_color(index: u8, converter: &AnsiHtml) -> RgbColor {
+    match index {
+        0..=15 => converter.palette[index as usize],
+        16..=231 => {
+            const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+            let index = index - 16;
+            let (r, g, b) = (
+                LEVELS[(index / 36) as usize],
+                LEVELS[(index / 6 % 6) as usize],
+                LEVELS[(index % 6) as usize],
+            );
+            RgbColor::new(r, g, b, nearest_basic(r, g, b))
+        }
+        232..=255 => {
+            let level = 8 + (index - 232) * 10;
+            RgbColor::new(level, level, level, nearest_basic(level, level, level))
+        }
+    }
+}
+
+/// Picks a basic color fallback for an RGB value.
+fn nearest_basic(r: u8, g: u8, b: u8) -> BasicColor {
+    let bright = r.max(g).max(b) > 191;
+    match (r > 127, g > 127, b > 127, bright) {
+        (false, false, false, false) => BasicColor::Black,
+        (false, false, false, true) => BasicColor::BrightBlack,
+        (true, false, false, false) => BasicColor::Red,
+        (true, false, false, true) => BasicColor::BrightRed,
+        (false, true, false, false) => BasicColor::Green,
+        (false, true, false, true) => BasicColor::BrightGreen,
+        (true, true, false, false) => BasicColor::Yellow,
+        (true, true, false, true) => BasicColor::BrightYellow,
+        (false, false, true, false) => BasicColor::Blue,
+        (false, false, true, true) => BasicColor::BrightBlue,
+        (true, false, true, false) => BasicColor::Magenta,
+        (true, false, true, true) => BasicColor::BrightMagenta,
+        (false, true, true, false) => BasicColor::Cyan,
+        (false, true, true, true) => BasicColor::BrightCyan,
+        (true, true, true, false) => BasicColor::White,
+        (true, true, true, true) => BasicColor::BrightWhite,
+    }
+}
+
+fn hex(color: RgbColor) -> String {
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        color.red(),
+        color.green(),
+        color.blue()
+    )
+}
+
+/// Escapes text for use in HTML content and attribute values.
+fn escape_into(html: &mut String, c: char) {
+    match c {
+        '&' => html.push_str("&amp;"),
+        '<' => html.push_str("&lt;"),
+        '>' => html.push_str("&gt;"),
+        '"' => html.push_str("&quot;"),
+        '\'' => html.push_str("&#39;"),
+        c => html.push(c),
+    }
+}
+
+/// Accumulates HTML, opening and closing elements only around text so no
+/// empty spans are emitted and `<span>`s always nest inside `<a>`.
+struct Writer<'a> {
+    converter: &'a AnsiHtml,
+    html: String,
+    /// Style set by the escape codes seen so far
+    style: Style,
+    /// Style of the currently open `<span>`, if any
+    open_span: Option<Style>,
+    /// Link set by OSC8 codes seen so far
+    link: Option<String>,
+    /// Whether an `<a>` for `link` is open
+    open_link: bool,
+}
+
+impl<'a> Writer<'a> {
+    fn new(converter: &'a AnsiHtml) -> Self {
+        Self {
+            converter,
+            html: String::new(),
+            style: Style::default(),
+            open_span: None,
+            link: None,
+            open_link: false,
+        }
+    }
+
+    fn set_link(&mut self, uri: &str) {
+        let link = LINK_SCHEMES
+            .iter()
+            .any(|scheme| uri.starts_with(scheme))
+            .then(|| uri.to_string());
+        if link != self.link {
+            self.close_span();
+            if self.open_link {
+                self.html.push_str("</a>");
+                self.open_link = false;
+            }
+            self.link = link;
+        }
+    }
+
+    fn text(&mut self, c: char) {
+        if !self.open_link
+            && let Some(uri) = self.link.clone()
+        {
+            self.close_span();
+            self.html.push_str("<a href=\"");
+            for c in uri.chars() {
+                escape_into(&mut self.html, c);
+            }
+            self.html.push_str("\">");
+            self.open_link = true;
+        }
+
+        if self.open_span != Some(self.style) {
+            self.close_span();
+            if let Some(css) = self.style.css(self.converter) {
+                let _ = write!(self.html, "<span style=\"{}\">", css);
+                self.open_span = Some(self.style);
+            }
+        }
+
+        escape_into(&mut self.html, c);
+    }
+
+    fn close_span(&mut self) {
+        if self.open_span.take().is_some() {
+            self.html.push_str("</span>");
+        }
+    }
+
+    fn finish(&mut self) {
+        self.close_span();
+        if self.open_link {
+            self.html.push_str("</a>");
+            self.open_link = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_is_escaped() {
+        assert_eq!(
+            ansi_to_html("a < b && \"c\" > 'd'"),
+            "a &lt; b &amp;&amp; &quot;c&quot; &gt; &#39;d&#39;"
+        );
+    }
+
+    #[test]
+    fn basic_and_bright_colors() {
+        assert_eq!(
+            ansi_to_html("\x1b[32mok\x1b[0m \x1b[91;44mfail\x1b[m"),
+            "<span style=\"color:#0dbc79\">ok</span> \
+             <span style=\"color:#f14c4c;background-color:#2472c8\">fail</span>"
+        );
+    }
+
+    #[test]
+    fn palette_256_colors() {
+        // 196 is pure red in the 6x6x6 cube, 244 a mid gray, 3 a basic color
+        assert_eq!(
+            ansi_to_html("\x1b[38;5;196ma\x1b[38;5;244mb\x1b[48;5;3mc"),
+            "<span style=\"color:#ff0000\">a</span>\
+             <span style=\"color:#808080\">b</span>\
+             <span style=\"color:#808080;background-color:#e5e510\">c</span>"
+        );
+    }
+
+    #[test]
+    fn truecolor_semicolon_and_colon_forms() {
+        let expected = "<span style=\"color:#0a141e\">x</span>";
+        assert_eq!(ansi_to_html("\x1b[38;2;10;20;30mx"), expected);
+        assert_eq!(ansi_to_html("\x1b[38:2::10:20:30mx"), expected);
+        assert_eq!(ansi_to_html("\x1b[38:2:10:20:30mx"), expected);
+    }
+
+    #[test]
+    fn text_attributes_combine_and_reset() {
+        assert_eq!(
+            ansi_to_html("\x1b[1;3;4;9mall\x1b[22;23mdecorated\x1b[24;29mplain"),
+            "<span style=\"font-weight:bold;font-style:italic;\
+             text-decoration:underline line-through\">all</span>\
+             <span style=\"text-decoration:underline line-through\">decorated</span>plain"
+        );
+        assert_eq!(
+            ansi_to_html("\x1b[2mdim\x1b[4:0m"),
+            "<span style=\"opacity:0.7\">dim</span>"
+        );
+    }
+
+    #[test]
+    fn inverse_swaps_default_colors() {
+        assert_eq!(
+            ansi_to_html("\x1b[7mrev\x1b[27m"),
+            "<span style=\"color:#000000;background-color:#e5e5e5\">rev</span>"
+        );
+    }
+
+    #[test]
+    fn osc8_links_become_anchors() {
+        let link = |uri: &str, text: &str| format!("\x1b]8;;{}\x07{}\x1b]8;;\x07", uri, text);
+        assert_eq!(
+            ansi_to_html(format!(
+                "see {}",
+                link("https://example.com/?a=1&b=2", "docs")
+            )),
+            "see <a href=\"https://example.com/?a=1&amp;b=2\">docs</a>"
+        );
+        // ST-terminated form
+        assert_eq!(
+            ansi_to_html("\x1b]8;id=1;file:///tmp/x\x1b\\x\x1b]8;;\x1b\\"),
+            "<a href=\"file:///tmp/x\">x</a>"
+        );
+        // Unsafe schemes keep only the text
+        assert_eq!(ansi_to_html(link("javascript:alert(1)", "click")), "click");
+    }
+
+    #[test]
+    fn styled_text_inside_links_nests_spans_in_anchor() {
+        assert_eq!(
+            ansi_to_html("\x1b[1m\x1b]8;;https://a.b\x07bold link\x1b]8;;\x07 after\x1b[0m"),
+            "<a href=\"https://a.b\"><span style=\"font-weight:bold\">bold link</span></a>\
+             <span style=\"font-weight:bold\"> after</span>"
+        );
+    }
+
+    #[test]
+    fn non_sgr_codes_and_controls_are_dropped() {
+        assert_eq!(
+            ansi_to_html("\x1b[2K\x1b[1Aline\r\x1b7\x07\tnext\n"),
+            "line\tnext\n"
+        );
+    }
+
+    #[test]
+    fn unterminated_sequences_do_not_panic() {
+        assert_eq!(ansi_to_html("text\x1b["), "text");
+        assert_eq!(ansi_to_html("text\x1b]8;;https://x"), "text");
+        assert_eq!(ansi_to_html("text\x1b"), "text");
+        assert_eq!(ansi_to_html("\x1b[38;5m\x1b[38;2;1mok"), "ok");
+    }
+
+    #[test]
+    fn multibyte_text_is_preserved() {
+        assert_eq!(
+            ansi_to_html("\x1b[35m日本語 ✓\x1b[0m"),
+            "<span style=\"color:#bc3fbc\">日本語 ✓</span>"
+        );
+    }
+
+    #[test]
+    fn pre_wrapper() {
+        assert_eq!(
+            AnsiHtml::new().with_pre(true).convert("x"),
+            "<pre class=\"ansi\">x</pre>"
+        );
+    }
+}
@@ -4,6 +4,9 @@ use crate::{
     components::renderable::RenderableWrapper,
     terminal::Terminal,
     utils::color::{BasicColor, Color, RgbColor, WEB_COLOR_LOOKUP},
+    utils::text_width::{
+        display_width, escape_sequence_end, split_at_width, truncate_with_ellipsis,
+    },
 };
 
 pub enum MaxWidth {
@@ -12,6 +15,7 @@ pub enum MaxWidth {
     Percent(f32),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TextAlignment {
     Left,
     Center,
@@ -118,26 +122,10 @@ pub fn split_line<T: Into<String>>(content: T, width: &u32) -> (String, String)
 /// truncates the line with the `truncate_indicator` string used as the closing
 /// part of the string and leaving the resultant string length equal to the `width`.
 ///
-/// Note: this truncation must be smart and be aware of
+/// Escape codes are preserved and grapheme clusters are never split; see
+/// [`truncate_with_ellipsis`](crate::utils::text_width::truncate_with_ellipsis).
 pub fn truncate<T: Into<String>>(content: T, truncate_indicator: &String, width: &u32) -> String {
-    let content = content.into();
-    if *width == 0 {
-        return String::new();
-    }
-
-    let indicator_width = visible_width(truncate_indicator);
-    if *width <= indicator_width {
-        let (head, _) = split_at_visible_width(truncate_indicator, *width);
-        return head;
-    }
-
-    if visible_width(&content) <= *width {
-        return content;
-    }
-
-    let target_width = width.saturating_sub(indicator_width);
-    let (head, _) = split_at_visible_width(&content, target_width);
-    format!("{}{}", head, truncate_indicator)
+    truncate_with_ellipsis(&content.into(), *width as usize, truncate_indicator)
 }
 
 /// The **word_wrap** function follows the following logic:
@@ -493,122 +481,12 @@ fn color_wrapper(fg: &Color, bg: &Color, term: Option<&Terminal>) -> (String, St
     (prefix, suffix)
 }
 
-fn escape_sequence_end(content: &str, start: usize) -> usize {
-    let bytes = content.as_bytes();
-    if start >= bytes.len() {
-        return bytes.len();
-    }
-    if bytes[start] != 0x1b {
-        return (start + 1).min(bytes.len());
-    }
-    if start + 1 >= bytes.len() {
-        return bytes.len();
-    }
-
-    match bytes[start + 1] {
-        b'[' => {
-            let mut idx = start + 2;
-            while idx < bytes.len() {
-                let byte = bytes[idx];
-                idx += 1;
-                if (0x40..=0x7e).contains(&byte) {
-                    break;
-                }
-            }
-            idx
-        }
-        b']' => {
-            let mut idx = start + 2;
-            while idx < bytes.len() {
-                let byte = bytes[idx];
-                if byte == 0x07 {
-                    idx += 1;
-                    break;
-                }
-                if byte == 0x1b && idx + 1 < bytes.len() && bytes[idx + 1] == b'\\' {
-                    idx += 2;
-                    break;
-                }
-                idx += 1;
-            }
-            idx
-        }
-        _ => (start + 2).min(bytes.len()),
-    }
-}
-
 fn visible_width(content: &str) -> u32 {
-    let mut width = 0u32;
-    let mut idx = 0usize;
-    let bytes = content.as_bytes();
-
-    while idx < content.len() {
-        if bytes[idx] == 0x1b {
-            idx = escape_sequence_end(content, idx);
-            continue;
-        }
-
-        let ch = match content[idx..].chars().next() {
-            Some(ch) => ch,
-            None => break,
-        };
-        width = width.saturating_add(UnicodeWidthChar::width(ch).unwrap_or(0) as u32);
-        idx += ch.len_utf8();
-    }
-
-    width
+    display_width(content) as u32
 }
 
 fn split_at_visible_width(content: &str, width: u32) -> (String, String) {
-    if width == 0 {
-        return (String::new(), content.to_string());
-    }
-
-    let mut head = String::new();
-    let mut pending = String::new();
-    let mut visible = 0u32;
-    let mut idx = 0usize;
-    let bytes = content.as_bytes();
-
-    while idx < content.len() {
-        if bytes[idx] == 0x1b {
-            let end = escape_sequence_end(content, idx);
-            pending.push_str(&content[idx..end]);
-            idx = end;
-            continue;
-        }
-
-        let ch = match content[idx..].chars().next() {
-            Some(ch) => ch,
-            None => break,
-        };
-        let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0) as u32;
-        let ch_len = ch.len_utf8();
-
-        if visible.saturating_add(ch_width) > width {
-            if visible == 0 {
-                head.push_str(&pending);
-                pending.clear();
-                head.push(ch);
-                idx += ch_len;
-                return (head, content[idx..].to_string());
-            }
-
-            let mut tail = String::new();
-            tail.push_str(&pending);
-            tail.push_str(&content[idx..]);
-            return (head, tail);
-        }
-
-        head.push_str(&pending);
-        pending.clear();
-        head.push(ch);
-        visible = visible.saturating_add(ch_width);
-        idx += ch_len;
-    }
-
-    head.push_str(&pending);
-    (head, String::new())
+    split_at_width(content, width as usize)
 }
 
 fn trim_leading_whitespace_preserve_escapes(content: &str) -> String {
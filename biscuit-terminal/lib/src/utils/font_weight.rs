@@ -1,10 +1,20 @@
+use crate::discovery::detection::is_tty;
 use crate::terminal::Terminal;
 
+/// Whether font weight escapes should be written: the output is a TTY and
+/// `NO_COLOR` isn't set to a non-empty value.
+///
+/// Without a `terminal` only the TTY check runs, rather than the full
+/// detection `Terminal::default()` does.
+fn styled(terminal: Option<Terminal>) -> bool {
+    let is_tty = terminal.map_or_else(is_tty, |term| term.is_tty);
+    is_tty && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
 /// make the text passed in BOLD
 pub fn bold<T: Into<String>>(content: T, terminal: Option<Terminal>) -> String {
     let content = content.into();
-    let term = terminal.unwrap_or_default();
-    if term.is_tty {
+    if styled(terminal) {
         format!("\x1b[1m{}\x1b[22m", content)
     } else {
         content
@@ -14,8 +24,7 @@ pub fn bold<T: Into<String>>(content: T, terminal: Option<Terminal>) -> String {
 /// makes the text passed in DIM
 pub fn dim<T: Into<String>>(content: T, terminal: Option<Terminal>) -> String {
     let content = content.into();
-    let term = terminal.unwrap_or_default();
-    if term.is_tty {
+    if styled(terminal) {
         format!("\x1b[2m{}\x1b[22m", content)
     } else {
         content
@@ -24,8 +33,7 @@ pub fn dim<T: Into<String>>(content: T, terminal: Option<Terminal>) -> String {
 
 pub fn normal<T: Into<String>>(content: T, terminal: Option<Terminal>) -> String {
     let content = content.into();
-    let term = terminal.unwrap_or_default();
-    if term.is_tty {
+    if styled(terminal) {
         format!("\x1b[22m{}\x1b[22m", content) // Resets both bold and dim
     } else {
         content
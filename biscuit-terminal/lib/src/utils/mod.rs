@@ -1,7 +1,10 @@
+pub mod ansi_html;
 pub mod block_constraint;
 pub mod color;
 pub mod escape_codes;
 pub mod font_weight;
 pub mod multiplex;
 pub mod styling;
+pub mod symbols;
+pub mod text_width;
 pub mod todo_state;
@@ -0,0 +1,489 @@
+//! Semantic icon set with terminal fallbacks.
+//!
+//! Every [`Icon`] carries up to three renderings of the same concept:
+//!
+//! 1. a [Nerd Font](https://www.nerdfonts.com) glyph (private-use codepoints
+//!    which only render with a patched font),
+//! 2. an emoji or standard Unicode symbol, and
+//! 3. a plain ASCII label which renders everywhere.
+//!
+//! [`Icon::glyph`] walks that chain from the requested [`IconStyle`] downward
+//! so an icon always produces *something*, even when a tier is missing.
+//!
+//! ## Examples
+//!
+//! ```
+//! use biscuit_terminal::utils::symbols::{self, IconStyle};
+//!
+//! assert_eq!(symbols::SUCCESS.glyph(IconStyle::Emoji), "\u{2713}");
+//! assert_eq!(symbols::SUCCESS.glyph(IconStyle::Ascii), "[OK]");
+//!
+//! let icon = symbols::file_icon("src/main.rs");
+//! assert_eq!(icon.name, "file.rust");
+//! ```
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::discovery::fonts::detect_nerd_font;
+use crate::discovery::locale::{CharEncoding, detect_char_encoding};
+use crate::terminal::Terminal;
+
+/// Environment variable which forces an [`IconStyle`] (`nerd`, `emoji` or `ascii`).
+pub const ICON_STYLE_ENV: &str = "BISCUIT_ICONS";
+
+/// The rendering tier used when turning an [`Icon`] into text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconStyle {
+    /// Nerd Font glyphs, falling back to emoji and then ASCII.
+    NerdFont,
+    /// Emoji and standard Unicode symbols, falling back to ASCII.
+    Emoji,
+    /// Plain ASCII only.
+    Ascii,
+}
+
+impl IconStyle {
+    /// Detects the best icon style for the current terminal.
+    ///
+    /// Resolution order:
+    ///
+    /// 1. the `BISCUIT_ICONS` environment variable (`nerd`, `emoji` or `ascii`)
+    /// 2. Nerd Font detection (see [`detect_nerd_font`])
+    /// 3. the locale's character encoding: UTF-8 gets emoji, anything else ASCII
+    pub fn detect() -> Self {
+        if let Some(style) = std::env::var(ICON_STYLE_ENV)
+            .ok()
+            .and_then(|value| Self::parse(&value))
+        {
+            return style;
+        }
+
+        Self::resolve(detect_nerd_font(), &detect_char_encoding())
+    }
+
+    /// Picks the icon style for an already-discovered [`Terminal`].
+    ///
+    /// The `BISCUIT_ICONS` override still applies.
+    pub fn for_terminal(terminal: &Terminal) -> Self {
+        if let Some(style) = std::env::var(ICON_STYLE_ENV)
+            .ok()
+            .and_then(|value| Self::parse(&value))
+        {
+            return style;
+        }
+
+        Self::resolve(terminal.is_nerd_font, &terminal.char_encoding)
+    }
+
+    /// Returns the style detected for this process, caching the first result.
+    pub fn current() -> Self {
+        static DETECTED: OnceLock<IconStyle> = OnceLock::new();
+        *DETECTED.get_or_init(Self::detect)
+    }
+
+    /// Parses a style name as accepted by `BISCUIT_ICONS`.
+    ///
+    /// ## Returns
+    ///
+    /// `None` when the value is not a recognized style name.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "nerd" | "nerdfont" | "nerd-font" | "nerd_font" => Some(Self::NerdFont),
+            "emoji" | "unicode" | "utf8" | "utf-8" => Some(Self::Emoji),
+            "ascii" | "plain" | "none" => Some(Self::Ascii),
+            _ => None,
+        }
+    }
+
+    fn resolve(nerd_font: Option<bool>, encoding: &CharEncoding) -> Self {
+        match (nerd_font, encoding) {
+            (_, CharEncoding::Ascii) => Self::Ascii,
+            (Some(true), _) => Self::NerdFont,
+            (_, CharEncoding::Utf8 | CharEncoding::Utf16 | CharEncoding::Utf32) => Self::Emoji,
+            _ => Self::Ascii,
+        }
+    }
+}
+
+/// A semantic icon with Nerd Font, emoji and ASCII renderings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Icon {
+    /// Registry name, e.g. `"success"`, `"file.rust"` or `"provider.openai"`.
+    pub name: &'static str,
+    /// Nerd Font glyph, if the concept has one.
+    pub nerd: Option<&'static str>,
+    /// Emoji or Unicode symbol, if the concept has one.
+    pub emoji: Option<&'static str>,
+    /// ASCII rendering; always available.
+    pub ascii: &'static str,
+}
+
+impl Icon {
+    /// Renders the icon for `style`, falling back down the
+    /// nerd-font → emoji → ASCII chain when a tier is missing.
+    pub fn glyph(&self, style: IconStyle) -> &'static str {
+        match style {
+            IconStyle::NerdFont => self.nerd.or(self.emoji).unwrap_or(self.ascii),
+            IconStyle::Emoji => self.emoji.unwrap_or(self.ascii),
+            IconStyle::Ascii => self.ascii,
+        }
+    }
+
+    /// Renders the icon using the style detected for this process.
+    pub fn auto(&self) -> &'static str {
+        self.glyph(IconStyle::current())
+    }
+}
+
+const fn icon(
+    name: &'static str,
+    nerd: Option<&'static str>,
+    emoji: Option<&'static str>,
+    ascii: &'static str,
+) -> Icon {
+    Icon {
+        name,
+        nerd,
+        emoji,
+        ascii,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Status
+// ---------------------------------------------------------------------------
+
+pub const SUCCESS: Icon = icon("success", Some("\u{f00c}"), Some("\u{2713}"), "[OK]");
+pub const FAILURE: Icon = icon("failure", Some("\u{f00d}"), Some("\u{2717}"), "[FAIL]");
+pub const WARNING: Icon = icon("warning", Some("\u{f071}"), Some("\u{26a0}"), "[WARN]");
+pub const INFO: Icon = icon("info", Some("\u{f05a}"), Some("\u{2139}"), "[INFO]");
+pub const PENDING: Icon = icon("pending", Some("\u{f10c}"), Some("\u{25cb}"), "[..]");
+pub const RUNNING: Icon = icon("running", Some("\u{f04b}"), Some("\u{25b6}"), "[RUN]");
+pub const CANCELLED: Icon = icon("cancelled", Some("\u{f05e}"), Some("\u{00d7}"), "[--]");
+pub const SKIPPED: Icon = icon("skipped", Some("\u{f051}"), Some("\u{21b7}"), "[SKIP]");
+pub const INSTALLED: Icon = icon("installed", Some("\u{f058}"), Some("\u{2705}"), "yes");
+pub const MISSING: Icon = icon("missing", Some("\u{f057}"), Some("\u{274c}"), "no");
+
+// ---------------------------------------------------------------------------
+// File types
+// ---------------------------------------------------------------------------
+
+pub const FILE: Icon = icon("file", Some("\u{f15b}"), Some("\u{1f4c4}"), "-");
+pub const FOLDER: Icon = icon("folder", Some("\u{f07b}"), Some("\u{1f4c1}"), "d");
+pub const FILE_RUST: Icon = icon("file.rust", Some("\u{e7a8}"), Some("\u{1f980}"), "rs");
+pub const FILE_PYTHON: Icon = icon("file.python", Some("\u{e73c}"), Some("\u{1f40d}"), "py");
+pub const FILE_JAVASCRIPT: Icon = icon("file.javascript", Some("\u{e74e}"), None, "js");
+pub const FILE_TYPESCRIPT: Icon = icon("file.typescript", Some("\u{e628}"), None, "ts");
+pub const FILE_GO: Icon = icon("file.go", Some("\u{e627}"), Some("\u{1f439}"), "go");
+pub const FILE_JAVA: Icon = icon("file.java", Some("\u{e738}"), Some("\u{2615}"), "java");
+pub const FILE_C: Icon = icon("file.c", Some("\u{e61e}"), None, "c");
+pub const FILE_CPP: Icon = icon("file.cpp", Some("\u{e61d}"), None, "cpp");
+pub const FILE_CSHARP: Icon = icon("file.csharp", Some("\u{f031b}"), None, "cs");
+pub const FILE_RUBY: Icon = icon("file.ruby", Some("\u{e739}"), Some("\u{1f48e}"), "rb");
+pub const FILE_PHP: Icon = icon("file.php", Some("\u{e73d}"), Some("\u{1f418}"), "php");
+pub const FILE_SWIFT: Icon = icon("file.swift", Some("\u{e755}"), Some("\u{1f426}"), "swift");
+pub const FILE_LUA: Icon = icon("file.lua", Some("\u{e620}"), Some("\u{1f319}"), "lua");
+pub const FILE_SHELL: Icon = icon("file.shell", Some("\u{f489}"), Some("\u{1f41a}"), "sh");
+pub const FILE_MARKDOWN: Icon = icon("file.markdown", Some("\u{e73e}"), Some("\u{1f4dd}"), "md");
+pub const FILE_JSON: Icon = icon("file.json", Some("\u{e60b}"), None, "json");
+pub const FILE_CONFIG: Icon = icon("file.config", Some("\u{e615}"), Some("\u{1f527}"), "cfg");
+pub const FILE_HTML: Icon = icon("file.html", Some("\u{e736}"), Some("\u{1f310}"), "html");
+pub const FILE_CSS: Icon = icon("file.css", Some("\u{e749}"), Some("\u{1f3a8}"), "css");
+pub const FILE_IMAGE: Icon = icon("file.image", Some("\u{f1c5}"), Some("\u{1f4f7}"), "img");
+pub const FILE_ARCHIVE: Icon = icon("file.archive", Some("\u{f1c6}"), Some("\u{1f4e6}"), "zip");
+
+// ---------------------------------------------------------------------------
+// AI providers
+// ---------------------------------------------------------------------------
+
+pub const PROVIDER: Icon = icon("provider", Some("\u{f06a9}"), Some("\u{1f916}"), "ai");
+pub const PROVIDER_ANTHROPIC: Icon = icon(
+    "provider.anthropic",
+    Some("\u{f06a9}"),
+    Some("\u{1f916}"),
+    "anthropic",
+);
+pub const PROVIDER_DEEPSEEK: Icon = icon(
+    "provider.deepseek",
+    Some("\u{f06a9}"),
+    Some("\u{1f40b}"),
+    "deepseek",
+);
+pub const PROVIDER_GEMINI: Icon = icon(
+    "provider.gemini",
+    Some("\u{f1a0}"),
+    Some("\u{264a}"),
+    "gemini",
+);
+pub const PROVIDER_GROQ: Icon = icon("provider.groq", Some("\u{f06a9}"), Some("\u{26a1}"), "groq");
+pub const PROVIDER_HUGGINGFACE: Icon = icon(
+    "provider.huggingface",
+    Some("\u{f06a9}"),
+    Some("\u{1f917}"),
+    "huggingface",
+);
+pub const PROVIDER_MISTRAL: Icon = icon(
+    "provider.mistral",
+    Some("\u{f06a9}"),
+    Some("\u{1f300}"),
+    "mistral",
+);
+pub const PROVIDER_MOONSHOTAI: Icon = icon(
+    "provider.moonshotai",
+    Some("\u{f06a9}"),
+    Some("\u{1f319}"),
+    "moonshotai",
+);
+pub const PROVIDER_OLLAMA: Icon = icon(
+    "provider.ollama",
+    Some("\u{f06a9}"),
+    Some("\u{1f999}"),
+    "ollama",
+);
+pub const PROVIDER_OPENAI: Icon = icon(
+    "provider.openai",
+    Some("\u{f06a9}"),
+    Some("\u{1f916}"),
+    "openai",
+);
+pub const PROVIDER_OPENROUTER: Icon = icon(
+    "provider.openrouter",
+    Some("\u{f06a9}"),
+    Some("\u{1f500}"),
+    "openrouter",
+);
+pub const PROVIDER_XAI: Icon = icon("provider.xai", Some("\u{f06a9}"), Some("\u{1f916}"), "xai");
+pub const PROVIDER_ZAI: Icon = icon("provider.zai", Some("\u{f06a9}"), Some("\u{1f916}"), "zai");
+pub const PROVIDER_ZENMUX: Icon = icon(
+    "provider.zenmux",
+    Some("\u{f06a9}"),
+    Some("\u{1f916}"),
+    "zenmux",
+);
+
+/// Every icon in the registry, in declaration order.
+pub static ICONS: &[Icon] = &[
+    SUCCESS,
+    FAILURE,
+    WARNING,
+    INFO,
+    PENDING,
+    RUNNING,
+    CANCELLED,
+    SKIPPED,
+    INSTALLED,
+    MISSING,
+    FILE,
+    FOLDER,
+    FILE_RUST,
+    FILE_PYTHON,
+    FILE_JAVASCRIPT,
+    FILE_TYPESCRIPT,
+    FILE_GO,
+    FILE_JAVA,
+    FILE_C,
+    FILE_CPP,
+    FILE_CSHARP,
+    FILE_RUBY,
+    FILE_PHP,
+    FILE_SWIFT,
+    FILE_LUA,
+    FILE_SHELL,
+    FILE_MARKDOWN,
+    FILE_JSON,
+    FILE_CONFIG,
+    FILE_HTML,
+    FILE_CSS,
+    FILE_IMAGE,
+    FILE_ARCHIVE,
+    PROVIDER,
+    PROVIDER_ANTHROPIC,
+    PROVIDER_DEEPSEEK,
+    PROVIDER_GEMINI,
+    PROVIDER_GROQ,
+    PROVIDER_HUGGINGFACE,
+    PROVIDER_MISTRAL,
+    PROVIDER_MOONSHOTAI,
+    PROVIDER_OLLAMA,
+    PROVIDER_OPENAI,
+    PROVIDER_OPENROUTER,
+    PROVIDER_XAI,
+    PROVIDER_ZAI,
+    PROVIDER_ZENMUX,
+];
+
+/// Looks up an icon by its registry name (e.g. `"warning"`, `"file.rust"`).
+///
+/// ## Returns
+///
+/// `None` when no icon is registered under `name`.
+pub fn lookup(name: &str) -> Option<&'static Icon> {
+    ICONS.iter().find(|icon| icon.name == name)
+}
+
+/// Returns the icon for a file path based on its extension.
+///
+/// Paths ending in `/` return [`FOLDER`]; unknown or missing extensions
+/// return [`FILE`].
+pub fn file_icon<P: AsRef<Path>>(path: P) -> &'static Icon {
+    let path = path.as_ref();
+    if path.as_os_str().to_string_lossy().ends_with('/') {
+        return &FOLDER;
+    }
+
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "rs" => &FILE_RUST,
+        "py" | "pyi" => &FILE_PYTHON,
+        "js" | "mjs" | "cjs" | "jsx" => &FILE_JAVASCRIPT,
+        "ts" | "mts" | "cts" | "tsx" => &FILE_TYPESCRIPT,
+        "go" => &FILE_GO,
+        "java" => &FILE_JAVA,
+        "c" | "h" => &FILE_C,
+        "cc" | "cpp" | "cxx" | "hpp" | "hh" | "hxx" => &FILE_CPP,
+        "cs" => &FILE_CSHARP,
+        "rb" => &FILE_RUBY,
+        "php" => &FILE_PHP,
+        "swift" => &FILE_SWIFT,
+        "lua" => &FILE_LUA,
+        "sh" | "bash" | "zsh" | "fish" => &FILE_SHELL,
+        "md" | "markdown" | "mdx" => &FILE_MARKDOWN,
+        "json" | "jsonc" => &FILE_JSON,
+        "toml" | "yaml" | "yml" | "ini" | "conf" | "cfg" => &FILE_CONFIG,
+        "html" | "htm" => &FILE_HTML,
+        "css" | "scss" | "sass" | "less" => &FILE_CSS,
+        "png" | "jpg" | "jpeg" | "gif" | "webp" | "svg" | "bmp" | "ico" => &FILE_IMAGE,
+        "zip" | "tar" | "gz" | "tgz" | "xz" | "bz2" | "zst" | "7z" => &FILE_ARCHIVE,
+        _ => &FILE,
+    }
+}
+
+/// Returns the icon for an AI provider name.
+///
+/// Matching ignores case, `-` and `_`, so `"OpenAI"`, `"open_ai"` and
+/// `"openai"` are equivalent. Unknown providers return [`PROVIDER`].
+pub fn provider_icon(name: &str) -> &'static Icon {
+    let normalized: String = name
+        .chars()
+        .filter(|c| *c != '-' && *c != '_' && !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let normalized = match normalized.as_str() {
+        "google" => "gemini",
+        "moonshot" => "moonshotai",
+        "hf" => "huggingface",
+        other => other,
+    };
+
+    ICONS
+        .iter()
+        .find(|icon| icon.name.strip_prefix("provider.") == Some(normalized))
+        .unwrap_or(&PROVIDER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyph_falls_back_through_the_chain() {
+        assert_eq!(SUCCESS.glyph(IconStyle::NerdFont), "\u{f00c}");
+        assert_eq!(SUCCESS.glyph(IconStyle::Emoji), "\u{2713}");
+        assert_eq!(SUCCESS.glyph(IconStyle::Ascii), "[OK]");
+
+        // No emoji: nerd font still works, emoji falls straight to ASCII
+        assert_eq!(FILE_JSON.glyph(IconStyle::NerdFont), "\u{e60b}");
+        assert_eq!(FILE_JSON.glyph(IconStyle::Emoji), "json");
+
+        let emoji_only = icon("test", None, Some("\u{2728}"), "*");
+        assert_eq!(emoji_only.glyph(IconStyle::NerdFont), "\u{2728}");
+
+        let ascii_only = icon("test", None, None, "*");
+        assert_eq!(ascii_only.glyph(IconStyle::NerdFont), "*");
+    }
+
+    #[test]
+    fn ascii_renderings_are_ascii() {
+        for icon in ICONS {
+            assert!(
+                icon.ascii.is_ascii(),
+                "{} has a non-ASCII fallback",
+                icon.name
+            );
+            assert!(
+                !icon.ascii.is_empty(),
+                "{} has an empty fallback",
+                icon.name
+            );
+        }
+    }
+
+    #[test]
+    fn registry_names_are_unique_and_resolvable() {
+        for (i, icon) in ICONS.iter().enumerate() {
+            assert!(
+                ICONS[..i].iter().all(|other| other.name != icon.name),
+                "duplicate icon name {}",
+                icon.name
+            );
+            assert_eq!(lookup(icon.name), Some(icon));
+        }
+        assert_eq!(lookup("nope"), None);
+    }
+
+    #[test]
+    fn file_icon_matches_extensions() {
+        assert_eq!(file_icon("src/lib.rs").name, "file.rust");
+        assert_eq!(file_icon("App.TSX").name, "file.typescript");
+        assert_eq!(file_icon("Cargo.toml").name, "file.config");
+        assert_eq!(file_icon("README").name, "file");
+        assert_eq!(file_icon("src/").name, "folder");
+    }
+
+    #[test]
+    fn provider_icon_normalizes_names() {
+        assert_eq!(provider_icon("OpenAI").name, "provider.openai");
+        assert_eq!(provider_icon("open_ai").name, "provider.openai");
+        assert_eq!(provider_icon("google").name, "provider.gemini");
+        assert_eq!(provider_icon("Hugging-Face").name, "provider.huggingface");
+        assert_eq!(provider_icon("unknown").name, "provider");
+    }
+
+    #[test]
+    fn parse_accepts_style_names() {
+        assert_eq!(IconStyle::parse("Nerd"), Some(IconStyle::NerdFont));
+        assert_eq!(IconStyle::parse("emoji"), Some(IconStyle::Emoji));
+        assert_eq!(IconStyle::parse(" ascii "), Some(IconStyle::Ascii));
+        assert_eq!(IconStyle::parse("fancy"), None);
+    }
+
+    #[test]
+    fn resolve_prefers_nerd_font_then_encoding() {
+        assert_eq!(
+            IconStyle::resolve(Some(true), &CharEncoding::Utf8),
+            IconStyle::NerdFont
+        );
+        assert_eq!(
+            IconStyle::resolve(None, &CharEncoding::Utf8),
+            IconStyle::Emoji
+        );
+        assert_eq!(
+            IconStyle::resolve(Some(false), &CharEncoding::Utf8),
+            IconStyle::Emoji
+        );
+        assert_eq!(
+            IconStyle::resolve(Some(true), &CharEncoding::Ascii),
+            IconStyle::Ascii
+        );
+        assert_eq!(
+            IconStyle::resolve(None, &CharEncoding::Latin1),
+            IconStyle::Ascii
+        );
+    }
+}
@@ -0,0 +1,391 @@
+//! Display-width utilities for terminal text.
+//!
+//! Terminal text rarely has a display width equal to its byte or `char`
+//! length. These functions measure, split, truncate and pad strings by the
+//! number of terminal cells they occupy, taking into account:
+//!
+//! - escape codes (CSI, OSC and OSC8 links), which take up no space
+//! - grapheme clusters, so combining marks stay with their base character
+//! - East Asian wide characters, which occupy two cells
+//! - emoji sequences (ZWJ families, skin tones, flags, VS16), which render
+//!   as a single two-cell glyph
+//!
+//! Escape codes are always preserved in the output; only visible content
+//! is ever removed.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+use crate::utils::block_constraint::TextAlignment;
+
+/// Variation selector 16 (request emoji presentation).
+const VS16: char = '\u{FE0F}';
+
+/// Returns the byte index just past the escape sequence starting at `start`.
+///
+/// Handles CSI (`\x1b[...`), OSC (`\x1b]...` terminated by BEL or ST) and
+/// two-byte escape sequences. Unterminated sequences extend to the end of
+/// the content.
+pub(crate) fn escape_sequence_end(content: &str, start: usize) -> usize {
+    let bytes = content.as_bytes();
+    if start >= bytes.len() {
+        return bytes.len();
+    }
+    if bytes[start] != 0x1b {
+        return (start + 1).min(bytes.len());
+    }
+    if start + 1 >= bytes.len() {
+        return bytes.len();
+    }
+
+    match bytes[start + 1] {
+        b'[' => {
+            let mut idx = start + 2;
+            while idx < bytes.len() {
+                let byte = bytes[idx];
+                idx += 1;
+                if (0x40..=0x7e).contains(&byte) {
+                    break;
+                }
+            }
+            idx
+        }
+        b']' => {
+            let mut idx = start + 2;
+            while idx < bytes.len() {
+                let byte = bytes[idx];
+                if byte == 0x07 {
+                    idx += 1;
+                    break;
+                }
+                if byte == 0x1b && idx + 1 < bytes.len() && bytes[idx + 1] == b'\\' {
+                    idx += 2;
+                    break;
+                }
+                idx += 1;
+            }
+            idx
+        }
+        _ => {
+            // Two-byte sequence; step over a multi-byte character safely
+            let next = content[start + 1..]
+                .chars()
+                .next()
+                .map_or(1, char::len_utf8);
+            start + 1 + next
+        }
+    }
+}
+
+/// A piece of terminal text: either an escape sequence or a grapheme cluster.
+enum Segment<'a> {
+    Escape(&'a str),
+    Grapheme(&'a str),
+}
+
+/// Splits content into escape sequences and grapheme clusters, in order.
+fn segments(content: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut idx = 0usize;
+
+    while idx < content.len() {
+        let text_end = content[idx..]
+            .find('\x1b')
+            .map_or(content.len(), |offset| idx + offset);
+
+        if text_end > idx {
+            segments.extend(
+                content[idx..text_end]
+                    .graphemes(true)
+                    .map(Segment::Grapheme),
+            );
+            idx = text_end;
+        } else {
+            let end = escape_sequence_end(content, idx);
+            segments.push(Segment::Escape(&content[idx..end]));
+            idx = end;
+        }
+    }
+
+    segments
+}
+
+/// Returns true for regional indicator symbols (the halves of a flag emoji).
+fn is_regional_indicator(ch: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&ch)
+}
+
+/// Returns the number of terminal cells a single grapheme cluster occupies.
+///
+/// A grapheme never occupies more than two cells. Emoji sequences joined
+/// with ZWJ, skin-tone modifiers, flags and VS16 presentation sequences all
+/// render as one wide glyph.
+///
+/// ## Examples
+///
+/// ```
+/// use biscuit_terminal::utils::text_width::grapheme_width;
+///
+/// assert_eq!(grapheme_width("a"), 1);
+/// assert_eq!(grapheme_width("e\u{301}"), 1);
+/// assert_eq!(grapheme_width("你"), 2);
+/// assert_eq!(grapheme_width("👨‍👩‍👧"), 2);
+/// assert_eq!(grapheme_width("🇬🇧"), 2);
+/// ```
+pub fn grapheme_width(grapheme: &str) -> usize {
+    let Some(first) = grapheme.chars().next() else {
+        return 0;
+    };
+
+    if is_regional_indicator(first) || grapheme.contains(VS16) {
+        return 2;
+    }
+
+    let width: usize = grapheme
+        .chars()
+        .map(|ch| UnicodeWidthChar::width(ch).unwrap_or(0))
+        .sum();
+    width.min(2)
+}
+
+/// Returns the display width of the content in terminal cells.
+///
+/// Escape codes are ignored and the width of each grapheme cluster is
+/// measured with [`grapheme_width`]. Newlines are zero-width; for
+/// multi-line content see [`crate::discovery::eval::line_widths`].
+///
+/// ## Examples
+///
+/// ```
+/// use biscuit_terminal::utils::text_width::display_width;
+///
+/// assert_eq!(display_width("hello"), 5);
+/// assert_eq!(display_width("\x1b[31mred\x1b[0m"), 3);
+/// assert_eq!(display_width("你好"), 4);
+/// assert_eq!(display_width("hi 👋🏽"), 5);
+/// ```
+pub fn display_width(content: &str) -> usize {
+    segments(content)
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Escape(_) => 0,
+            Segment::Grapheme(g) => grapheme_width(g),
+        })
+        .sum()
+}
+
+/// Splits the content so the head occupies at most `width` cells.
+///
+/// Grapheme clusters are never split. Escape codes which precede the split
+/// point travel with the tail, so `head + tail` always equals the input.
+/// When the first grapheme is wider than `width` it is placed in the head
+/// anyway, guaranteeing progress when splitting repeatedly.
+///
+/// ## Examples
+///
+/// ```
+/// use biscuit_terminal::utils::text_width::split_at_width;
+///
+/// let (head, tail) = split_at_width("你好world", 5);
+/// assert_eq!(head, "你好w");
+/// assert_eq!(tail, "orld");
+/// ```
+pub fn split_at_width(content: &str, width: usize) -> (String, String) {
+    if width == 0 {
+        return (String::new(), content.to_string());
+    }
+
+    let mut head = String::new();
+    let mut pending = String::new();
+    let mut visible = 0usize;
+
+    for segment in segments(content) {
+        match segment {
+            Segment::Escape(seq) => pending.push_str(seq),
+            Segment::Grapheme(g) => {
+                let g_width = grapheme_width(g);
+                if visible + g_width > width && visible > 0 {
+                    let consumed = head.len();
+                    return (head, content[consumed..].to_string());
+                }
+                head.push_str(&pending);
+                pending.clear();
+                head.push_str(g);
+                visible += g_width;
+            }
+        }
+    }
+
+    // Everything fit; trailing escape codes (e.g. a closing reset) stay in the head
+    head.push_str(&pending);
+    (head, String::new())
+}
+
+/// Truncates the content to `width` cells, ending with `ellipsis` when
+/// anything was removed.
+///
+/// Content which already fits is returned unchanged. When `width` is too
+/// small to hold the ellipsis, the ellipsis itself is truncated. Escape
+/// codes before the cut are preserved.
+///
+/// ## Examples
+///
+/// ```
+/// use biscuit_terminal::utils::text_width::truncate_with_ellipsis;
+///
+/// assert_eq!(truncate_with_ellipsis("hello world", 8, "…"), "hello w…");
+/// assert_eq!(truncate_with_ellipsis("short", 8, "…"), "short");
+/// assert_eq!(truncate_with_ellipsis("你好世界", 5, "…"), "你好…");
+/// ```
+pub fn truncate_with_ellipsis(content: &str, width: usize, ellipsis: &str) -> String {
+    if width == 0 {
+        return String::new();
+    }
+    if display_width(content) <= width {
+        return content.to_string();
+    }
+
+    let ellipsis_width = display_width(ellipsis);
+    if width <= ellipsis_width {
+        let (head, _) = split_at_width(ellipsis, width);
+        return head;
+    }
+
+    let target = width - ellipsis_width;
+    let (mut head, _) = split_at_width(content, target);
+    // A wide first grapheme may overshoot a one-cell target
+    if display_width(&head) > target {
+        head.clear();
+    }
+    format!("{}{}", head, ellipsis)
+}
+
+/// Pads the content with spaces to exactly `width` cells.
+///
+/// Content wider than `width` is returned unchanged; combine with
+/// [`truncate_with_ellipsis`] when a hard limit is required. Centered
+/// content places any odd remaining cell on the right.
+///
+/// ## Examples
+///
+/// ```
+/// use biscuit_terminal::utils::block_constraint::TextAlignment;
+/// use biscuit_terminal::utils::text_width::pad_to_width;
+///
+/// assert_eq!(pad_to_width("ab", 5, TextAlignment::Left), "ab   ");
+/// assert_eq!(pad_to_width("ab", 5, TextAlignment::Right), "   ab");
+/// assert_eq!(pad_to_width("ab", 5, TextAlignment::Center), " ab  ");
+/// assert_eq!(pad_to_width("你", 4, TextAlignment::Left), "你  ");
+/// ```
+pub fn pad_to_width(content: &str, width: usize, alignment: TextAlignment) -> String {
+    let available = width.saturating_sub(display_width(content));
+    if available == 0 {
+        return content.to_string();
+    }
+
+    let left = match alignment {
+        TextAlignment::Left => 0,
+        TextAlignment::Center => available / 2,
+        TextAlignment::Right => available,
+    };
+    format!(
+        "{}{}{}",
+        " ".repeat(left),
+        content,
+        " ".repeat(available - left)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grapheme_width_handles_emoji_sequences() {
+        assert_eq!(grapheme_width("👍"), 2);
+        assert_eq!(grapheme_width("👍🏽"), 2);
+        assert_eq!(grapheme_width("👩‍💻"), 2);
+        assert_eq!(grapheme_width("❤\u{FE0F}"), 2);
+        assert_eq!(grapheme_width("1\u{FE0F}\u{20E3}"), 2);
+        assert_eq!(grapheme_width("🇯🇵"), 2);
+        assert_eq!(grapheme_width(""), 0);
+    }
+
+    #[test]
+    fn display_width_ignores_escape_codes_and_links() {
+        let content = "\x1b[1;31mbold\x1b[0m \x1b]8;;https://example.com\x07link\x1b]8;;\x07";
+        assert_eq!(display_width(content), 9);
+        assert_eq!(
+            display_width("\x1b]8;;https://x.io\x1b\\x\x1b]8;;\x1b\\"),
+            1
+        );
+    }
+
+    #[test]
+    fn display_width_counts_combining_marks_once() {
+        assert_eq!(display_width("cafe\u{301}"), 4);
+        assert_eq!(display_width("\u{1100}\u{1161}\u{11A8}"), 2);
+    }
+
+    #[test]
+    fn split_at_width_never_splits_graphemes() {
+        let (head, tail) = split_at_width("ab👨‍👩‍👧cd", 3);
+        assert_eq!(head, "ab");
+        assert_eq!(tail, "👨‍👩‍👧cd");
+
+        let (head, tail) = split_at_width("ab👨‍👩‍👧cd", 4);
+        assert_eq!(head, "ab👨‍👩‍👧");
+        assert_eq!(tail, "cd");
+    }
+
+    #[test]
+    fn split_at_width_keeps_escape_codes() {
+        let content = "\x1b[31mred\x1b[0m blue";
+        let (head, tail) = split_at_width(content, 3);
+        assert_eq!(head, "\x1b[31mred");
+        assert_eq!(tail, "\x1b[0m blue");
+        assert_eq!(format!("{head}{tail}"), content);
+
+        let (head, tail) = split_at_width("\x1b[31mred\x1b[0m", 3);
+        assert_eq!(head, "\x1b[31mred\x1b[0m");
+        assert_eq!(tail, "");
+    }
+
+    #[test]
+    fn split_at_width_makes_progress_on_wide_grapheme() {
+        let (head, tail) = split_at_width("你好", 1);
+        assert_eq!(head, "你");
+        assert_eq!(tail, "好");
+    }
+
+    #[test]
+    fn truncate_with_ellipsis_respects_width() {
+        let result = truncate_with_ellipsis("hello world", 8, "...");
+        assert_eq!(result, "hello...");
+        assert_eq!(display_width(&result), 8);
+
+        assert_eq!(truncate_with_ellipsis("abcdef", 2, "..."), "..");
+        assert_eq!(truncate_with_ellipsis("abcdef", 0, "..."), "");
+        assert_eq!(truncate_with_ellipsis("你好", 2, "…"), "…");
+    }
+
+    #[test]
+    fn truncate_with_ellipsis_keeps_emoji_whole() {
+        let result = truncate_with_ellipsis("go 👨‍👩‍👧 go", 5, "…");
+        assert_eq!(result, "go …");
+        assert!(display_width(&result) <= 5);
+    }
+
+    #[test]
+    fn pad_to_width_handles_wide_and_styled_content() {
+        assert_eq!(
+            pad_to_width("\x1b[1mhi\x1b[0m", 4, TextAlignment::Right),
+            "  \x1b[1mhi\x1b[0m"
+        );
+        assert_eq!(pad_to_width("toolong", 3, TextAlignment::Left), "toolong");
+        assert_eq!(
+            display_width(&pad_to_width("🇺🇸", 5, TextAlignment::Center)),
+            5
+        );
+    }
+}
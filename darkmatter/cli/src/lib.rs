@@ -53,6 +53,22 @@
 //! md README.md --toc
 //! md README.md --toc-filename  # Include filename in header
 //! md README.md --toc --json    # JSON format
+//!
+//! # Word count, reading time and structure statistics
+//! md README.md --stats
+//! md README.md --stats --json  # JSON format
+//! ```
+//!
+//! ### Data files
+//!
+//! ```bash
+//! # Preview CSV/TSV files as themed tables (first 100 rows by default)
+//! md data.csv
+//! md data.tsv --max-rows 20
+//! md data.csv --max-rows 0     # Show all rows
+//!
+//! # Convert to JSON with typed values
+//! md data.csv --json
 //! ```
 //!
 //! ### Markdown cleanup
@@ -99,6 +115,9 @@
 //! # Render mermaid diagrams as images
 //! md README.md --mermaid
 //!
+//! # Keep wide tables at full width and scroll sideways
+//! md wide.md --table-overflow scroll | less -RS
+//!
 //! # Verbose output for debugging
 //! md README.md -v      # INFO level
 //! md README.md -vv     # DEBUG level
@@ -116,6 +135,7 @@
 //! - **Markdown cleanup**: Normalize markdown formatting
 //! - **Document comparison**: Structural diff between markdown documents
 //! - **Table of contents**: Extract document structure as tree or JSON
+//! - **Data previews**: Render CSV/TSV files as tables or convert them to JSON
 //!
 //! ## Library Usage
 //!
@@ -132,6 +152,8 @@
 //! write_terminal(&mut stdout, &md, options)?;
 //! ```
 
+use darkmatter_lib::markdown::output::TableOverflow;
+
 // Re-export the CLI struct for programmatic access
 pub use cli::Cli;
 
@@ -140,6 +162,7 @@ mod cli {
     use clap_complete::Shell;
     use clap_complete::engine::{ArgValueCompleter, CompletionCandidate};
     use darkmatter_lib::markdown::highlighting::ThemePair;
+    use darkmatter_lib::markdown::output::TableOverflow;
     use std::path::{Path, PathBuf};
 
     /// Command-line interface for the darkmatter markdown renderer.
@@ -148,7 +171,7 @@ mod cli {
     #[derive(Parser)]
     #[command(name = "md", about = "Markdown Awesome Tool", version)]
     #[command(group = ArgGroup::new("output-mode")
-        .args(["html", "show_html", "ast", "clean", "clean_save", "toc", "toc_filename", "delta"])
+        .args(["html", "show_html", "ast", "clean", "clean_save", "toc", "toc_filename", "stats", "delta"])
         .multiple(false))]
     #[command(after_help = "\
 SHELL COMPLETIONS:
@@ -214,14 +237,22 @@ SHELL COMPLETIONS:
         #[arg(long, group = "output-mode")]
         pub toc_filename: bool,
 
+        /// Show word count, reading time, and code/link/heading statistics
+        #[arg(long, group = "output-mode")]
+        pub stats: bool,
+
         /// Compare with another markdown file and show differences
         #[arg(long, group = "output-mode", value_name = "FILE")]
         pub delta: Option<PathBuf>,
 
-        /// Output as JSON (for --toc and --delta modes)
+        /// Output as JSON (for --toc, --stats, --delta, and CSV/TSV input)
         #[arg(long)]
         pub json: bool,
 
+        /// Maximum rows to show when previewing CSV/TSV files (0 shows all)
+        #[arg(long, value_name = "N", default_value_t = 100)]
+        pub max_rows: usize,
+
         /// Merge JSON into frontmatter (JSON wins on conflicts)
         #[arg(long, value_name = "JSON")]
         pub fm_merge_with: Option<String>,
@@ -243,6 +274,11 @@ SHELL COMPLETIONS:
         #[arg(long)]
         pub mermaid: bool,
 
+        /// How to fit tables wider than the terminal: collapse (drop trailing
+        /// columns), wrap (keep every column), or scroll (full width, for `less -RS`)
+        #[arg(long, value_name = "MODE", value_parser = super::parse_table_overflow, default_value = "collapse")]
+        pub table_overflow: TableOverflow,
+
         /// Increase verbosity (-v INFO, -vv DEBUG, -vvv TRACE, -vvvv TRACE with file/line)
         #[arg(short = 'v', long = "verbose", action = clap::ArgAction::Count)]
         pub verbose: u8,
@@ -319,6 +355,19 @@ SHELL COMPLETIONS:
     }
 }
 
+/// Parses a `--table-overflow` mode.
+fn parse_table_overflow(s: &str) -> Result<TableOverflow, String> {
+    match s {
+        "collapse" => Ok(TableOverflow::Collapse),
+        "wrap" => Ok(TableOverflow::Wrap),
+        "scroll" => Ok(TableOverflow::Scroll),
+        _ => Err(format!(
+            "unknown table overflow mode '{}' (expected collapse, wrap or scroll)",
+            s
+        )),
+    }
+}
+
 /// Parses a theme name string into ThemePair.
 fn parse_theme_name(s: &str) -> Result<darkmatter_lib::markdown::highlighting::ThemePair, String> {
     darkmatter_lib::markdown::highlighting::ThemePair::try_from(s).map_err(|e| e.to_string())
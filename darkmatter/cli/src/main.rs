@@ -6,9 +6,12 @@ use darkmatter_lib::markdown::highlighting::{
     ColorMode, ThemePair, detect_code_theme, detect_color_mode, detect_prose_theme,
 };
 use darkmatter_lib::markdown::output::{HtmlOptions, MermaidMode, TerminalOptions, write_terminal};
-use darkmatter_lib::markdown::{Markdown, MarkdownDelta, MarkdownToc, MarkdownTocNode};
+use darkmatter_lib::markdown::{
+    DocumentStats, Markdown, MarkdownDelta, MarkdownToc, MarkdownTocNode,
+};
+use darkmatter_lib::tabular::{Delimiter, TabularData};
 use std::io::{self, IsTerminal, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing_subscriber::{filter::EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
 
 /// Initialize tracing subscriber based on verbosity level.
@@ -75,6 +78,13 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    // CSV/TSV files are previewed as tables (or converted to JSON)
+    if let Some(ref path) = cli.input
+        && let Some(delimiter) = Delimiter::from_path(path)
+    {
+        return render_tabular(&cli, path, delimiter);
+    }
+
     // Load markdown from input or stdin
     let mut md = load_markdown(cli.input.as_ref())?;
 
@@ -146,6 +156,17 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    // Handle --stats mode
+    if cli.stats {
+        let stats = md.stats();
+        if cli.json {
+            println!("{}", serde_json::to_string_pretty(&stats)?);
+        } else {
+            print_stats(&stats);
+        }
+        return Ok(());
+    }
+
     // Handle --delta mode
     if let Some(ref other_path) = cli.delta {
         let other_md = Markdown::try_from(other_path.as_path())
@@ -206,6 +227,7 @@ fn main() -> Result<()> {
     } else {
         MermaidMode::Off
     };
+    options.table_overflow = cli.table_overflow;
 
     // Derive base_path from input file for relative image resolution
     if let Some(ref path) = cli.input
@@ -223,6 +245,41 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Renders a CSV/TSV file as a themed terminal table, or as JSON with `--json`.
+///
+/// The data is converted to a markdown table so it shares the table layout
+/// and theming used for markdown documents.
+fn render_tabular(cli: &Cli, path: &Path, delimiter: Delimiter) -> Result<()> {
+    let content = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read file: {:?}", path))?;
+    let data = TabularData::parse(&content, delimiter)
+        .wrap_err_with(|| format!("Failed to parse {:?}", path))?;
+
+    if cli.json {
+        println!("{}", serde_json::to_string_pretty(&data.to_json())?);
+        return Ok(());
+    }
+
+    let max_rows = (cli.max_rows > 0).then_some(cli.max_rows);
+    let md: Markdown = data.to_markdown(max_rows).into();
+
+    let prose_theme = cli.theme.unwrap_or_else(detect_prose_theme);
+    let mut options = TerminalOptions::default();
+    options.prose_theme = prose_theme;
+    options.code_theme = cli
+        .code_theme
+        .unwrap_or_else(|| detect_code_theme(prose_theme));
+    options.color_mode = detect_color_mode();
+    options.color_depth = None; // Auto-detect
+    options.table_overflow = cli.table_overflow;
+
+    let stdout = io::stdout();
+    let mut handle = stdout.lock();
+    write_terminal(&mut handle, &md, options).context("Failed to render table for terminal")?;
+
+    Ok(())
+}
+
 /// Loads markdown from a file path or stdin.
 fn load_markdown(path: Option<&PathBuf>) -> Result<Markdown> {
     if let Some(p) = path {
@@ -414,6 +471,55 @@ fn print_toc_node<W: Write>(
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Stats Output
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Prints document statistics as aligned `label: value` lines.
+fn print_stats(stats: &DocumentStats) {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    writeln!(out, "Words:         {}", stats.words).ok();
+    writeln!(out, "Reading time:  {} min", stats.reading_time_minutes).ok();
+
+    let mut languages: Vec<String> = stats
+        .code_languages
+        .iter()
+        .map(|(language, count)| format!("{language} {count}"))
+        .collect();
+    if stats.untagged_code_blocks() > 0 {
+        languages.push(format!("untagged {}", stats.untagged_code_blocks()));
+    }
+    if languages.is_empty() {
+        writeln!(out, "Code blocks:   {}", stats.code_blocks).ok();
+    } else {
+        writeln!(
+            out,
+            "Code blocks:   {} ({})",
+            stats.code_blocks,
+            languages.join(", ")
+        )
+        .ok();
+    }
+
+    writeln!(out, "Links:         {}", stats.links).ok();
+    writeln!(out, "Images:        {}", stats.images).ok();
+
+    let h = &stats.headings;
+    let levels: Vec<String> = [h.h1, h.h2, h.h3, h.h4, h.h5, h.h6]
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count > 0)
+        .map(|(i, count)| format!("h{} {count}", i + 1))
+        .collect();
+    if levels.is_empty() {
+        writeln!(out, "Headings:      0").ok();
+    } else {
+        writeln!(out, "Headings:      {} ({})", h.total(), levels.join(", ")).ok();
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Delta Output
 // ─────────────────────────────────────────────────────────────────────────────
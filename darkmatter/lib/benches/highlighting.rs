@@ -0,0 +1,187 @@
+//! Benchmarks parallel code block highlighting against sequential rendering.
+//!
+//! Renders a large, code-heavy document to the terminal and to HTML with
+//! `parallel_highlighting` off and on, and reports the speedup. On machines
+//! with at least four cores the run fails if parallel rendering is not at
+//! least twice as fast.
+//!
+//! ```sh
+//! cargo bench -p darkmatter-lib --bench highlighting
+//! ```
+
+use std::hint::black_box;
+use std::process::ExitCode;
+use std::thread::available_parallelism;
+use std::time::{Duration, Instant};
+
+use darkmatter_lib::markdown::Markdown;
+use darkmatter_lib::markdown::output::{
+    ColorDepth, HtmlOptions, ItalicMode, TerminalOptions, as_html, for_terminal,
+};
+
+/// Number of code blocks per language in the generated document.
+const BLOCKS_PER_LANGUAGE: usize = 40;
+/// Timed iterations per configuration; the fastest run is reported.
+const ITERATIONS: usize = 5;
+/// Minimum speedup required when enough cores are available.
+const REQUIRED_SPEEDUP: f64 = 2.0;
+/// Cores needed before the speedup is enforced.
+const REQUIRED_CORES: usize = 4;
+
+const RUST: &str = r#"use std::collections::HashMap;
+
+/// Counts word frequencies in `text`.
+pub fn word_counts(text: &str) -> HashMap<&str, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    counts
+}
+
+impl<T: Clone + Default> Grid<T> {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { cells: vec![T::default(); width * height], width }
+    }
+}
+"#;
+
+const TYPESCRIPT: &str = r#"interface User {
+  id: number;
+  name: string;
+  tags?: string[];
+}
+
+export async function fetchUsers(url: string): Promise<User[]> {
+  const response = await fetch(url);
+  if (!response.ok) {
+    throw new Error(`request failed: ${response.status}`);
+  }
+  return (await response.json()) as User[];
+}
+"#;
+
+const PYTHON: &str = r#"from dataclasses import dataclass, field
+
+
+@dataclass
+class Inventory:
+    items: dict[str, int] = field(default_factory=dict)
+
+    def add(self, name: str, count: int = 1) -> None:
+        self.items[name] = self.items.get(name, 0) + count
+
+    def total(self) -> int:
+        return sum(count for count in self.items.values() if count > 0)
+"#;
+
+const BASH: &str = r#"#!/usr/bin/env bash
+set -euo pipefail
+
+for file in "$@"; do
+  if [[ -f "$file" ]]; then
+    lines=$(wc -l < "$file")
+    echo "${file}: ${lines} lines"
+  else
+    echo "missing: ${file}" >&2
+  fi
+done
+"#;
+
+/// Builds a document with many independent code blocks between prose.
+fn code_heavy_document() -> Markdown {
+    let languages = [
+        ("rust", RUST),
+        ("typescript", TYPESCRIPT),
+        ("python", PYTHON),
+        ("bash", BASH),
+    ];
+
+    let mut content = String::from("# Highlighting Benchmark\n\n");
+    for i in 0..BLOCKS_PER_LANGUAGE {
+        for (language, code) in languages {
+            content.push_str(&format!(
+                "## Example {i} ({language})\n\nSome **prose** around the block with `inline code`.\n\n```{language} title=\"example-{i}\"\n{code}```\n\n"
+            ));
+        }
+    }
+
+    content.into()
+}
+
+/// Returns the fastest of [`ITERATIONS`] runs of `render`.
+fn fastest(mut render: impl FnMut() -> usize) -> Duration {
+    (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(render());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn terminal_options(parallel: bool) -> TerminalOptions {
+    let mut options = TerminalOptions::default();
+    options.color_depth = Some(ColorDepth::TrueColor);
+    options.italic_mode = ItalicMode::Always;
+    options.max_width = Some(100);
+    options.render_images = false;
+    options.parallel_highlighting = parallel;
+    options
+}
+
+fn html_options(parallel: bool) -> HtmlOptions {
+    let mut options = HtmlOptions::default();
+    options.parallel_highlighting = parallel;
+    options
+}
+
+/// Times `render` sequentially and in parallel, printing the speedup.
+fn compare(name: &str, render: impl Fn(bool) -> usize) -> f64 {
+    // Warm up the shared syntax and theme caches before timing
+    render(false);
+
+    let sequential = fastest(|| render(false));
+    let parallel = fastest(|| render(true));
+    let speedup = sequential.as_secs_f64() / parallel.as_secs_f64();
+
+    println!(
+        "{name:<10} sequential {sequential:>9.2?}  parallel {parallel:>9.2?}  speedup {speedup:.2}x"
+    );
+    speedup
+}
+
+fn main() -> ExitCode {
+    let md = code_heavy_document();
+    let cores = available_parallelism().map_or(1, |n| n.get());
+    println!(
+        "{} code blocks, {} bytes, {cores} cores",
+        BLOCKS_PER_LANGUAGE * 4,
+        md.content().len()
+    );
+
+    let terminal = compare("terminal", |parallel| {
+        for_terminal(&md, terminal_options(parallel))
+            .expect("terminal rendering failed")
+            .len()
+    });
+    let html = compare("html", |parallel| {
+        as_html(&md, html_options(parallel))
+            .expect("HTML rendering failed")
+            .len()
+    });
+
+    if cores < REQUIRED_CORES {
+        println!("fewer than {REQUIRED_CORES} cores; speedup not enforced");
+        return ExitCode::SUCCESS;
+    }
+
+    let slowest = terminal.min(html);
+    if slowest < REQUIRED_SPEEDUP {
+        eprintln!("speedup {slowest:.2}x is below the required {REQUIRED_SPEEDUP:.1}x");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
@@ -7,13 +7,15 @@
 //!
 //! - [`markdown`] - Markdown document manipulation with frontmatter support
 //! - [`mermaid`] - Mermaid diagram theming and rendering
-//! - [`render`] - Hyperlink rendering utilities
+//! - [`render`] - Hyperlink rendering and link resolution utilities
+//! - [`tabular`] - CSV/TSV parsing with column type inference
 //! - [`terminal`] - Terminal color detection utilities
 //! - [`testing`] - Testing utilities for terminal output verification
 
 pub mod markdown;
 pub mod mermaid;
 pub mod render;
+pub mod tabular;
 pub mod terminal;
 
 pub mod testing;
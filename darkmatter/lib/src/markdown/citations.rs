@@ -0,0 +1,601 @@
+//! Bibliography and citation support.
+//!
+//! Documents can declare their sources in a `references` frontmatter list and
+//! cite them in prose with pandoc-style markers: `[@key]` for a single source
+//! or `[@a; @b]` for several. When rendered, each cited reference receives a
+//! number in order of first citation, markers become numbered links, and a
+//! references section listing the cited sources is appended to the output.
+//!
+//! Markers inside code spans and fenced code blocks are left untouched, as are
+//! markers whose keys are not declared in frontmatter.
+//!
+//! ## Examples
+//!
+//! ```
+//! use darkmatter_lib::markdown::Markdown;
+//! use darkmatter_lib::markdown::citations::CitationFormat;
+//!
+//! let content = r#"---
+//! references:
+//!   - key: rust-book
+//!     title: The Rust Programming Language
+//!     url: https://doc.rust-lang.org/book/
+//!     authors: [Steve Klabnik, Carol Nichols]
+//!     year: 2023
+//! ---
+//! Ownership is central to Rust [@rust-book].
+//! "#;
+//!
+//! let md: Markdown = content.into();
+//! let bibliography = md.bibliography().unwrap().unwrap();
+//! let resolved = bibliography.resolve(md.content(), CitationFormat::Terminal);
+//!
+//! assert_eq!(resolved.cited.len(), 1);
+//! assert!(resolved.content.contains("[1](https://doc.rust-lang.org/book/)"));
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::markdown::{Frontmatter, Markdown, MarkdownResult};
+
+/// The frontmatter key holding the reference list.
+pub const REFERENCES_KEY: &str = "references";
+
+/// A single bibliography entry declared in frontmatter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reference {
+    /// Key used by `[@key]` citation markers.
+    #[serde(alias = "id")]
+    pub key: String,
+    /// Title of the cited work.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Where the work can be found.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Authors, in citation order. Accepts a single string or a list.
+    #[serde(default, alias = "author", deserialize_with = "one_or_many")]
+    pub authors: Vec<String>,
+    /// Publication year or date. Accepts a string or a number.
+    #[serde(default, alias = "date", deserialize_with = "string_or_number")]
+    pub year: Option<String>,
+    /// Publisher, site, or journal name.
+    #[serde(default)]
+    pub publisher: Option<String>,
+    /// Free-form note appended after the entry.
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+impl Reference {
+    /// Formats the entry as plain text: `Authors (year). Title. Publisher. Note.`
+    ///
+    /// Missing parts are omitted; an entry with no title falls back to its key.
+    pub fn formatted(&self) -> String {
+        self.format_with(str::to_string, str::to_string)
+    }
+
+    /// Formats the entry, passing plain parts through `text` and the title
+    /// through `title_fmt` (e.g. for escaping or emphasis).
+    fn format_with(
+        &self,
+        text: impl Fn(&str) -> String,
+        title_fmt: impl Fn(&str) -> String,
+    ) -> String {
+        let mut parts = Vec::new();
+
+        let authors = text(&self.authors.join(", "));
+        match (authors.is_empty(), &self.year) {
+            (false, Some(year)) => parts.push(format!("{} ({})", authors, text(year))),
+            (false, None) => parts.push(authors),
+            (true, Some(year)) => parts.push(format!("({})", text(year))),
+            (true, None) => {}
+        }
+
+        parts.push(title_fmt(self.title.as_deref().unwrap_or(&self.key)));
+
+        if let Some(publisher) = &self.publisher {
+            parts.push(text(publisher));
+        }
+        if let Some(note) = &self.note {
+            parts.push(text(note));
+        }
+
+        parts
+            .into_iter()
+            .map(|part| {
+                let part = part.trim_end().to_string();
+                if part.ends_with(['.', '!', '?']) {
+                    part
+                } else {
+                    format!("{}.", part)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Output target for resolved citation markers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CitationFormat {
+    /// Numbers link to the reference's URL (when it has one).
+    Terminal,
+    /// Numbers link to the `#ref-N` anchor in the references section.
+    Html,
+}
+
+/// A reference that was cited in the document, with its assigned number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CitedReference<'a> {
+    /// 1-based citation number, assigned in order of first citation.
+    pub number: usize,
+    /// The cited entry.
+    pub reference: &'a Reference,
+}
+
+/// The result of resolving citation markers in a document body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedCitations<'a> {
+    /// Markdown content with citation markers replaced.
+    pub content: String,
+    /// Cited references in citation-number order.
+    pub cited: Vec<CitedReference<'a>>,
+    /// Keys cited in the document but not declared in frontmatter.
+    pub missing: Vec<String>,
+}
+
+impl ResolvedCitations<'_> {
+    /// Renders the references section as markdown (used for terminal output).
+    ///
+    /// ## Returns
+    ///
+    /// An empty string when nothing was cited.
+    pub fn references_markdown(&self) -> String {
+        if self.cited.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::from("## References\n\n");
+        for cited in &self.cited {
+            let entry = cited
+                .reference
+                .format_with(str::to_string, |title| format!("*{}*", title));
+            match &cited.reference.url {
+                Some(url) => out.push_str(&format!("{}. {} <{}>\n", cited.number, entry, url)),
+                None => out.push_str(&format!("{}. {}\n", cited.number, entry)),
+            }
+        }
+        out
+    }
+
+    /// Renders the references section as HTML with `ref-N` anchors.
+    ///
+    /// ## Returns
+    ///
+    /// An empty string when nothing was cited.
+    pub fn references_html(&self) -> String {
+        if self.cited.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::from("<section class=\"references\">\n<h2>References</h2>\n<ol>\n");
+        for cited in &self.cited {
+            let entry = cited.reference.format_with(
+                |text| html_escape::encode_text(text).into_owned(),
+                |title| format!("<em>{}</em>", html_escape::encode_text(title)),
+            );
+            out.push_str(&format!("<li id=\"ref-{}\">{}", cited.number, entry));
+            if let Some(url) = &cited.reference.url {
+                out.push_str(&format!(
+                    " <a href=\"{}\">{}</a>",
+                    html_escape::encode_double_quoted_attribute(url),
+                    html_escape::encode_text(url)
+                ));
+            }
+            out.push_str("</li>\n");
+        }
+        out.push_str("</ol>\n</section>\n");
+        out
+    }
+}
+
+/// The references declared in a document's frontmatter.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Bibliography {
+    references: Vec<Reference>,
+}
+
+impl Bibliography {
+    /// Creates a bibliography from a list of references.
+    pub fn new(references: Vec<Reference>) -> Self {
+        Self { references }
+    }
+
+    /// Reads the `references` list from frontmatter.
+    ///
+    /// ## Returns
+    ///
+    /// `None` when the frontmatter has no `references` key.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `references` is present but is not a list of
+    /// reference objects.
+    pub fn from_frontmatter(frontmatter: &Frontmatter) -> MarkdownResult<Option<Self>> {
+        Ok(frontmatter
+            .get::<Vec<Reference>>(REFERENCES_KEY)?
+            .map(Self::new))
+    }
+
+    /// Returns all declared references.
+    pub fn references(&self) -> &[Reference] {
+        &self.references
+    }
+
+    /// Looks up a reference by key.
+    pub fn get(&self, key: &str) -> Option<&Reference> {
+        self.references.iter().find(|r| r.key == key)
+    }
+
+    /// Replaces citation markers in `content` with numbered citations.
+    ///
+    /// Markers are replaced only when every key in them is declared; anything
+    /// else is left verbatim and unknown keys are reported in
+    /// [`ResolvedCitations::missing`].
+    pub fn resolve(&self, content: &str, format: CitationFormat) -> ResolvedCitations<'_> {
+        let mut numbers: HashMap<&str, usize> = HashMap::new();
+        let mut cited = Vec::new();
+        let mut missing: Vec<String> = Vec::new();
+        let mut out = String::with_capacity(content.len());
+        let mut fence: Option<String> = None;
+
+        for line in content.split_inclusive('\n') {
+            let trimmed = line.trim_start();
+
+            if let Some(open) = &fence {
+                if trimmed.starts_with(open.as_str()) {
+                    fence = None;
+                }
+                out.push_str(line);
+                continue;
+            }
+            if let Some(marker) = fence_marker(trimmed) {
+                fence = Some(marker);
+                out.push_str(line);
+                continue;
+            }
+
+            let mut rest = line;
+            while !rest.is_empty() {
+                // Copy inline code spans through untouched
+                if rest.starts_with('`') {
+                    let ticks = rest.len() - rest.trim_start_matches('`').len();
+                    let delimiter = &rest[..ticks];
+                    match rest[ticks..].find(delimiter) {
+                        Some(end) => {
+                            let span_end = ticks + end + ticks;
+                            out.push_str(&rest[..span_end]);
+                            rest = &rest[span_end..];
+                        }
+                        None => {
+                            out.push_str(delimiter);
+                            rest = &rest[ticks..];
+                        }
+                    }
+                    continue;
+                }
+
+                if rest.starts_with("\\[") {
+                    out.push_str("\\[");
+                    rest = &rest[2..];
+                    continue;
+                }
+
+                if rest.starts_with("[@")
+                    && let Some((keys, len)) = parse_marker(rest)
+                {
+                    let unknown: Vec<&str> = keys
+                        .iter()
+                        .copied()
+                        .filter(|key| self.get(key).is_none())
+                        .collect();
+
+                    if unknown.is_empty() {
+                        let mut cited_numbers = Vec::with_capacity(keys.len());
+                        for key in keys {
+                            let number = *numbers.entry(key).or_insert_with(|| {
+                                let reference = self.get(key).expect("key checked above");
+                                cited.push(CitedReference {
+                                    number: cited.len() + 1,
+                                    reference,
+                                });
+                                cited.len()
+                            });
+                            cited_numbers.push(number);
+                        }
+                        out.push_str(&self.render_marker(&cited_numbers, &cited, format));
+                    } else {
+                        for key in unknown {
+                            if !missing.iter().any(|m| m == key) {
+                                tracing::warn!(key, "citation references an undeclared key");
+                                missing.push(key.to_string());
+                            }
+                        }
+                        out.push_str(&rest[..len]);
+                    }
+                    rest = &rest[len..];
+                    continue;
+                }
+
+                let next = rest
+                    .char_indices()
+                    .skip(1)
+                    .find(|(_, c)| matches!(c, '`' | '[' | '\\'))
+                    .map(|(i, _)| i)
+                    .unwrap_or(rest.len());
+                out.push_str(&rest[..next]);
+                rest = &rest[next..];
+            }
+        }
+
+        ResolvedCitations {
+            content: out,
+            cited,
+            missing,
+        }
+    }
+
+    fn render_marker(
+        &self,
+        numbers: &[usize],
+        cited: &[CitedReference<'_>],
+        format: CitationFormat,
+    ) -> String {
+        let links: Vec<String> = numbers
+            .iter()
+            .map(|&number| {
+                let reference = cited[number - 1].reference;
+                match format {
+                    CitationFormat::Html => format!("[{}](#ref-{})", number, number),
+                    CitationFormat::Terminal => match &reference.url {
+                        Some(url) => format!("[{}]({})", number, url),
+                        None => number.to_string(),
+                    },
+                }
+            })
+            .collect();
+        format!("\\[{}\\]", links.join(", "))
+    }
+}
+
+impl Markdown {
+    /// Returns the bibliography declared in this document's frontmatter.
+    ///
+    /// ## Returns
+    ///
+    /// `None` when the frontmatter has no `references` key.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `references` is not a list of reference objects.
+    pub fn bibliography(&self) -> MarkdownResult<Option<Bibliography>> {
+        Bibliography::from_frontmatter(self.frontmatter())
+    }
+}
+
+/// Resolves citations for rendering, returning the rewritten body and the
+/// references section in the matching format.
+///
+/// Returns `None` when the document declares no references or cites none of
+/// them. A malformed `references` list is logged and ignored so that bad
+/// metadata never prevents a document from rendering.
+pub(crate) fn render_citations(md: &Markdown, format: CitationFormat) -> Option<(String, String)> {
+    let bibliography = match md.bibliography() {
+        Ok(Some(bibliography)) => bibliography,
+        Ok(None) => return None,
+        Err(e) => {
+            tracing::warn!(error = %e, "ignoring malformed `references` frontmatter");
+            return None;
+        }
+    };
+
+    let resolved = bibliography.resolve(md.content(), format);
+    if resolved.cited.is_empty() {
+        return None;
+    }
+
+    let section = match format {
+        CitationFormat::Terminal => resolved.references_markdown(),
+        CitationFormat::Html => resolved.references_html(),
+    };
+    Some((resolved.content, section))
+}
+
+/// Returns the fence delimiter (e.g. "```" or "~~~~") if the line opens a code fence.
+fn fence_marker(trimmed: &str) -> Option<String> {
+    for fence_char in ['`', '~'] {
+        let count = trimmed.len() - trimmed.trim_start_matches(fence_char).len();
+        if count >= 3 {
+            return Some(fence_char.to_string().repeat(count));
+        }
+    }
+    None
+}
+
+/// Parses a `[@key]` or `[@a; @b]` marker at the start of `text`.
+///
+/// Returns the keys and the byte length of the marker.
+fn parse_marker(text: &str) -> Option<(Vec<&str>, usize)> {
+    let close = text.find(']')?;
+    let inner = &text[1..close];
+    if inner.contains(['[', '\n']) {
+        return None;
+    }
+
+    let keys: Option<Vec<&str>> = inner
+        .split(';')
+        .map(|part| {
+            let key = part.trim().strip_prefix('@')?;
+            let valid = !key.is_empty()
+                && key
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | ':' | '.' | '/'));
+            valid.then_some(key)
+        })
+        .collect();
+
+    // `[@key](url)` is an ordinary link, not a citation
+    if text[close + 1..].starts_with('(') {
+        return None;
+    }
+
+    keys.map(|keys| (keys, close + 1))
+}
+
+fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        Some(OneOrMany::One(one)) => vec![one],
+        Some(OneOrMany::Many(many)) => many,
+        None => Vec::new(),
+    })
+}
+
+fn string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(i64),
+    }
+
+    Ok(
+        Option::<StringOrNumber>::deserialize(deserializer)?.map(|value| match value {
+            StringOrNumber::String(s) => s,
+            StringOrNumber::Number(n) => n.to_string(),
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = r#"---
+references:
+  - key: alpha
+    title: Alpha Paper
+    url: https://example.com/alpha
+    author: Ada Lovelace
+    year: 1843
+  - id: beta
+    title: Beta Book
+    authors: [Grace Hopper, Alan Turing]
+    publisher: Example Press
+---
+First [@beta], then [@alpha; @beta], and [@alpha] again.
+"#;
+
+    fn bibliography() -> (Markdown, Bibliography) {
+        let md: Markdown = DOC.into();
+        let bib = md.bibliography().unwrap().expect("references present");
+        (md, bib)
+    }
+
+    #[test]
+    fn parses_references_from_frontmatter() {
+        let (_, bib) = bibliography();
+        assert_eq!(bib.references().len(), 2);
+
+        let alpha = bib.get("alpha").unwrap();
+        assert_eq!(alpha.authors, vec!["Ada Lovelace".to_string()]);
+        assert_eq!(alpha.year.as_deref(), Some("1843"));
+        assert_eq!(bib.get("beta").unwrap().authors.len(), 2);
+    }
+
+    #[test]
+    fn missing_references_key_is_none() {
+        let md: Markdown = "---\ntitle: x\n---\nHello [@alpha]".into();
+        assert!(md.bibliography().unwrap().is_none());
+    }
+
+    #[test]
+    fn numbers_follow_first_citation_order() {
+        let (md, bib) = bibliography();
+        let resolved = bib.resolve(md.content(), CitationFormat::Html);
+
+        assert_eq!(resolved.cited.len(), 2);
+        assert_eq!(resolved.cited[0].reference.key, "beta");
+        assert_eq!(resolved.cited[1].reference.key, "alpha");
+        assert!(resolved.content.contains(r"First \[[1](#ref-1)\]"));
+        assert!(resolved.content.contains(r"\[[2](#ref-2), [1](#ref-1)\]"));
+        assert!(resolved.content.contains(r"and \[[2](#ref-2)\] again"));
+    }
+
+    #[test]
+    fn terminal_links_to_reference_url() {
+        let (md, bib) = bibliography();
+        let resolved = bib.resolve(md.content(), CitationFormat::Terminal);
+
+        // beta has no URL, alpha does
+        assert!(resolved.content.contains(r"First \[1\]"));
+        assert!(
+            resolved
+                .content
+                .contains(r"\[[2](https://example.com/alpha), 1\]")
+        );
+    }
+
+    #[test]
+    fn leaves_code_links_and_unknown_keys_alone() {
+        let (_, bib) = bibliography();
+        let content = "`[@alpha]` and\n```\n[@alpha]\n```\n[@nope] [@alpha](x) \\[@alpha]\n";
+        let resolved = bib.resolve(content, CitationFormat::Html);
+
+        assert_eq!(resolved.content, content);
+        assert!(resolved.cited.is_empty());
+        assert_eq!(resolved.missing, vec!["nope".to_string()]);
+    }
+
+    #[test]
+    fn renders_references_sections() {
+        let (md, bib) = bibliography();
+        let resolved = bib.resolve(md.content(), CitationFormat::Html);
+
+        let markdown = resolved.references_markdown();
+        assert!(markdown.starts_with("## References"));
+        assert!(markdown.contains("1. Grace Hopper, Alan Turing. *Beta Book*. Example Press.\n"));
+        assert!(
+            markdown
+                .contains("2. Ada Lovelace (1843). *Alpha Paper*. <https://example.com/alpha>\n")
+        );
+
+        let html = resolved.references_html();
+        assert!(html.contains(r#"<li id="ref-2">Ada Lovelace (1843). <em>Alpha Paper</em>."#));
+        assert!(html.contains(r#"<a href="https://example.com/alpha">"#));
+    }
+
+    #[test]
+    fn nothing_cited_renders_nothing() {
+        let (_, bib) = bibliography();
+        let resolved = bib.resolve("No citations here.", CitationFormat::Terminal);
+        assert_eq!(resolved.content, "No citations here.");
+        assert!(resolved.references_markdown().is_empty());
+        assert!(resolved.references_html().is_empty());
+    }
+}
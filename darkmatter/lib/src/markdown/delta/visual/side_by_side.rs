@@ -11,8 +11,9 @@
 
 use super::VisualDiffOptions;
 use super::diff::{DiffLine, InlineSpan};
+use biscuit_terminal::utils::text_width::{display_width, truncate_with_ellipsis};
 use textwrap::{Options as WrapOptions, wrap};
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use unicode_width::UnicodeWidthStr;
 
 // ANSI escape codes
 const RESET: &str = "\x1b[0m";
@@ -118,11 +119,12 @@ fn format_header(
     content_width: usize,
     _options: &VisualDiffOptions,
 ) -> String {
-    let left_label = truncate_label(label_left, content_width);
-    let right_label = truncate_label(label_right, content_width);
+    // Unlike content which wraps, labels are truncated with an ellipsis
+    let left_label = truncate_with_ellipsis(label_left, content_width, "…");
+    let right_label = truncate_with_ellipsis(label_right, content_width, "…");
 
-    let left_padding = content_width.saturating_sub(left_label.width());
-    let right_padding = content_width.saturating_sub(right_label.width());
+    let left_padding = content_width.saturating_sub(display_width(&left_label));
+    let right_padding = content_width.saturating_sub(display_width(&right_label));
 
     format!(
         "{DIM}     {BOLD}{}{RESET}{DIM}{} │      {BOLD}{}{RESET}{DIM}{}",
@@ -133,35 +135,6 @@ fn format_header(
     )
 }
 
-/// Truncate a label to fit within a maximum width (for headers only).
-///
-/// Unlike content which wraps, labels are truncated with an ellipsis.
-fn truncate_label(s: &str, max_width: usize) -> String {
-    if s.width() <= max_width {
-        return s.to_string();
-    }
-
-    // Need to truncate - reserve space for ellipsis
-    let target_width = max_width.saturating_sub(1);
-    let mut result = String::new();
-    let mut current_width = 0;
-
-    for ch in s.chars() {
-        let char_width = ch.width().unwrap_or(0);
-        if current_width + char_width > target_width {
-            break;
-        }
-        result.push(ch);
-        current_width += char_width;
-    }
-
-    if max_width > 0 {
-        result.push('…');
-    }
-
-    result
-}
-
 /// Format a context line (unchanged, shown on both sides).
 ///
 /// Returns one or more output lines if the content needs to wrap.
@@ -456,6 +429,21 @@ fn wrap_to_width(s: &str, max_width: usize) -> Vec<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_header_truncates_wide_labels() {
+        let header = format_header(
+            "日本語のファイル名.md",
+            "b.md",
+            8,
+            &VisualDiffOptions::default(),
+        );
+        let plain = crate::testing::strip_ansi_codes(&header);
+        assert!(plain.contains("日本語…  │"));
+        assert!(plain.ends_with("b.md    "));
+        // Both labels are padded to exactly the content width
+        assert_eq!(display_width(&plain), 5 + 8 + 8 + 8);
+    }
+
     #[test]
     fn test_wrap_to_width_short_string() {
         // String fits within width - single line returned
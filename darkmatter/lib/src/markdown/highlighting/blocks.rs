@@ -0,0 +1,190 @@
+//! Parallel highlighting of a document's code blocks.
+//!
+//! Renderers walk a document's events in order, but code blocks don't depend
+//! on each other or on the prose around them. Before rendering, the blocks are
+//! collected from a separate parse and highlighted on the rayon thread pool;
+//! the renderer then takes each result in document order as it reaches the
+//! block. Highlighting dominates render time for code-heavy documents, so this
+//! scales with the number of cores.
+
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use rayon::prelude::*;
+
+/// A code block's info string and contents, in document order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CodeBlockSource {
+    /// Whether the block is fenced rather than indented
+    pub fenced: bool,
+    /// The fenced block's info string (empty for indented blocks)
+    pub info: String,
+    /// The block's contents
+    pub code: String,
+}
+
+/// A code block together with its highlighted output, if it was highlighted.
+pub(crate) type HighlightedBlock<T> = (CodeBlockSource, Option<T>);
+
+/// Collects every code block in `content`, parsed with `options`.
+///
+/// `options` must match the renderer's so blocks line up with its events.
+pub(crate) fn collect_code_blocks(content: &str, options: Options) -> Vec<CodeBlockSource> {
+    let mut blocks = Vec::new();
+    let mut current: Option<CodeBlockSource> = None;
+
+    for event in Parser::new_ext(content, options) {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let (fenced, info) = match kind {
+                    CodeBlockKind::Fenced(info) => (true, info.to_string()),
+                    CodeBlockKind::Indented => (false, String::new()),
+                };
+                current = Some(CodeBlockSource {
+                    fenced,
+                    info,
+                    code: String::new(),
+                });
+            }
+            Event::Text(text) => {
+                if let Some(block) = current.as_mut() {
+                    block.code.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => blocks.extend(current.take()),
+            _ => {}
+        }
+    }
+
+    blocks
+}
+
+/// Highlights `blocks` with `highlight`, on multiple threads if `parallel`.
+///
+/// Results keep the order of `blocks`. `highlight` returns `None` for blocks
+/// the renderer handles another way (e.g. Mermaid diagrams rendered as images).
+pub(crate) fn highlight_blocks<T, F>(
+    blocks: Vec<CodeBlockSource>,
+    parallel: bool,
+    highlight: F,
+) -> Vec<HighlightedBlock<T>>
+where
+    T: Send,
+    F: Fn(&CodeBlockSource) -> Option<T> + Sync + Send,
+{
+    if parallel && blocks.len() > 1 {
+        blocks
+            .into_par_iter()
+            .map(|block| {
+                let highlighted = highlight(&block);
+                (block, highlighted)
+            })
+            .collect()
+    } else {
+        blocks
+            .into_iter()
+            .map(|block| {
+                let highlighted = highlight(&block);
+                (block, highlighted)
+            })
+            .collect()
+    }
+}
+
+/// Hands out pre-highlighted blocks as a renderer reaches them.
+pub(crate) struct HighlightedBlocks<T> {
+    blocks: std::vec::IntoIter<HighlightedBlock<T>>,
+}
+
+impl<T> HighlightedBlocks<T> {
+    pub(crate) fn new(blocks: Vec<HighlightedBlock<T>>) -> Self {
+        Self {
+            blocks: blocks.into_iter(),
+        }
+    }
+
+    /// Takes the next block's output, if it was highlighted from `code`.
+    ///
+    /// Call once per code block the renderer ends. A block whose contents
+    /// differ from `code` yields `None`, and the renderer highlights it itself.
+    pub(crate) fn next_for(&mut self, code: &str) -> Option<T> {
+        self.blocks
+            .next()
+            .filter(|(source, _)| source.code == code)
+            .and_then(|(_, highlighted)| highlighted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_code_blocks_in_document_order() {
+        let content = "# Title\n\n```rust title=\"main\"\nfn main() {}\n```\n\ntext\n\n    indented\n\n```\n```\n";
+        let blocks = collect_code_blocks(content, Options::empty());
+
+        assert_eq!(
+            blocks,
+            vec![
+                CodeBlockSource {
+                    fenced: true,
+                    info: "rust title=\"main\"".to_string(),
+                    code: "fn main() {}\n".to_string(),
+                },
+                CodeBlockSource {
+                    fenced: false,
+                    info: String::new(),
+                    code: "indented\n".to_string(),
+                },
+                CodeBlockSource {
+                    fenced: true,
+                    info: String::new(),
+                    code: String::new(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parallel_highlighting_keeps_order() {
+        let blocks: Vec<_> = (0..64)
+            .map(|i| CodeBlockSource {
+                fenced: true,
+                info: "text".to_string(),
+                code: i.to_string(),
+            })
+            .collect();
+
+        let sequential = highlight_blocks(blocks.clone(), false, |b| Some(b.code.clone()));
+        let parallel = highlight_blocks(blocks, true, |b| Some(b.code.clone()));
+
+        assert_eq!(sequential, parallel);
+        assert_eq!(parallel[42].1.as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn test_mismatched_block_is_not_used() {
+        let blocks = vec![
+            (
+                CodeBlockSource {
+                    fenced: true,
+                    info: String::new(),
+                    code: "a".to_string(),
+                },
+                Some("A"),
+            ),
+            (
+                CodeBlockSource {
+                    fenced: true,
+                    info: String::new(),
+                    code: "b".to_string(),
+                },
+                Some("B"),
+            ),
+        ];
+        let mut highlighted = HighlightedBlocks::new(blocks);
+
+        assert_eq!(highlighted.next_for("a"), Some("A"));
+        assert_eq!(highlighted.next_for("changed"), None);
+        assert_eq!(highlighted.next_for("c"), None);
+    }
+}
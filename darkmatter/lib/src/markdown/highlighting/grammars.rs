@@ -19,7 +19,8 @@ lazy_static! {
 ///
 /// This returns a reference to a lazily-loaded static syntax set that includes
 /// both syntect's default grammars and additional grammars from two-face (curated
-/// by the bat project).
+/// by the bat project). Every highlighter shares it, so grammars are linked once
+/// per process rather than once per render.
 ///
 /// ## Examples
 ///
@@ -30,8 +31,8 @@ lazy_static! {
 /// let rust_syntax = syntax_set.find_syntax_by_extension("rs");
 /// assert!(rust_syntax.is_some());
 /// ```
-pub(super) fn load_syntax_set() -> SyntaxSet {
-    SYNTAX_SET.clone()
+pub(super) fn load_syntax_set() -> &'static SyntaxSet {
+    &SYNTAX_SET
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@
 //! This module provides theme enumeration, theme pairing (light/dark),
 //! and grammar loading utilities for syntax highlighting using syntect.
 
+pub(crate) mod blocks;
 mod grammars;
 pub mod prose;
 pub(crate) mod scope_cache;
@@ -18,6 +19,9 @@ use syntect::parsing::SyntaxSet;
 
 /// Primary API for syntax highlighting with theme support.
 ///
+/// The syntax set and themes are loaded once per process and shared by every
+/// highlighter, so creating one per render is cheap.
+///
 /// ## Examples
 ///
 /// ```
@@ -25,10 +29,10 @@ use syntect::parsing::SyntaxSet;
 ///
 /// let highlighter = CodeHighlighter::new(ThemePair::Github, ColorMode::Dark);
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CodeHighlighter {
-    syntax_set: SyntaxSet,
-    theme: SyntectTheme,
+    syntax_set: &'static SyntaxSet,
+    theme: &'static SyntectTheme,
     theme_pair: ThemePair,
     color_mode: ColorMode,
 }
@@ -45,7 +49,7 @@ impl CodeHighlighter {
     /// ```
     pub fn new(theme_pair: ThemePair, color_mode: ColorMode) -> Self {
         let syntax_set = grammars::load_syntax_set();
-        let theme = themes::load_theme(theme_pair, color_mode);
+        let theme = themes::cached_theme(theme_pair, color_mode);
 
         Self {
             syntax_set,
@@ -57,12 +61,12 @@ impl CodeHighlighter {
 
     /// Returns a reference to the syntax set.
     pub fn syntax_set(&self) -> &SyntaxSet {
-        &self.syntax_set
+        self.syntax_set
     }
 
     /// Returns a reference to the current theme.
     pub fn theme(&self) -> &SyntectTheme {
-        &self.theme
+        self.theme
     }
 
     /// Returns the current theme pair.
@@ -88,7 +92,7 @@ impl CodeHighlighter {
     pub fn set_color_mode(&mut self, color_mode: ColorMode) {
         if self.color_mode != color_mode {
             self.color_mode = color_mode;
-            self.theme = themes::load_theme(self.theme_pair, color_mode);
+            self.theme = themes::cached_theme(self.theme_pair, color_mode);
         }
     }
 
@@ -105,7 +109,7 @@ impl CodeHighlighter {
     pub fn set_theme_pair(&mut self, theme_pair: ThemePair) {
         if self.theme_pair != theme_pair {
             self.theme_pair = theme_pair;
-            self.theme = themes::load_theme(theme_pair, self.color_mode);
+            self.theme = themes::cached_theme(theme_pair, self.color_mode);
         }
     }
 }
@@ -155,6 +159,14 @@ mod tests {
         assert!(syntax_set.find_syntax_by_extension("rs").is_some());
     }
 
+    #[test]
+    fn test_highlighters_share_syntax_set_and_theme() {
+        let a = CodeHighlighter::new(ThemePair::Github, ColorMode::Dark);
+        let b = CodeHighlighter::new(ThemePair::Github, ColorMode::Dark);
+        assert!(std::ptr::eq(a.syntax_set(), b.syntax_set()));
+        assert!(std::ptr::eq(a.theme(), b.theme()));
+    }
+
     #[test]
     fn test_theme_available() {
         let highlighter = CodeHighlighter::new(ThemePair::Github, ColorMode::Dark);
@@ -105,21 +105,21 @@ mod tests {
     use crate::markdown::highlighting::{ColorMode, ThemePair};
     use pulldown_cmark::HeadingLevel;
 
-    fn load_test_theme() -> SyntectTheme {
-        crate::markdown::highlighting::themes::load_theme(ThemePair::Github, ColorMode::Dark)
+    fn load_test_theme() -> &'static SyntectTheme {
+        crate::markdown::highlighting::themes::cached_theme(ThemePair::Github, ColorMode::Dark)
     }
 
     #[test]
     fn test_prose_highlighter_new() {
         let theme = load_test_theme();
-        let _highlighter = ProseHighlighter::new(&theme);
+        let _highlighter = ProseHighlighter::new(theme);
         // Just verify it doesn't panic
     }
 
     #[test]
     fn test_base_style() {
         let theme = load_test_theme();
-        let highlighter = ProseHighlighter::new(&theme);
+        let highlighter = ProseHighlighter::new(theme);
 
         let style = highlighter.base_style();
         // Style should have foreground color
@@ -129,7 +129,7 @@ mod tests {
     #[test]
     fn test_base_scope() {
         let theme = load_test_theme();
-        let highlighter = ProseHighlighter::new(&theme);
+        let highlighter = ProseHighlighter::new(theme);
 
         let scope = highlighter.base_scope();
         assert_eq!(scope.to_string(), "text.html.markdown");
@@ -138,7 +138,7 @@ mod tests {
     #[test]
     fn test_style_for_tag_heading() {
         let theme = load_test_theme();
-        let highlighter = ProseHighlighter::new(&theme);
+        let highlighter = ProseHighlighter::new(theme);
 
         let tag = Tag::Heading {
             level: HeadingLevel::H1,
@@ -155,7 +155,7 @@ mod tests {
     #[test]
     fn test_style_for_tag_strong() {
         let theme = load_test_theme();
-        let highlighter = ProseHighlighter::new(&theme);
+        let highlighter = ProseHighlighter::new(theme);
 
         let tag = Tag::Strong;
         let base = highlighter.base_scope();
@@ -169,7 +169,7 @@ mod tests {
     #[test]
     fn test_style_for_tag_emphasis() {
         let theme = load_test_theme();
-        let highlighter = ProseHighlighter::new(&theme);
+        let highlighter = ProseHighlighter::new(theme);
 
         let tag = Tag::Emphasis;
         let style = highlighter.style_for_tag(&tag, &[highlighter.base_scope()]);
@@ -180,7 +180,7 @@ mod tests {
     #[test]
     fn test_style_for_tag_nested_scopes() {
         let theme = load_test_theme();
-        let highlighter = ProseHighlighter::new(&theme);
+        let highlighter = ProseHighlighter::new(theme);
 
         // Simulate nested context: base -> heading -> strong
         let base = highlighter.base_scope();
@@ -201,7 +201,7 @@ mod tests {
     #[test]
     fn test_style_for_inline_code() {
         let theme = load_test_theme();
-        let highlighter = ProseHighlighter::new(&theme);
+        let highlighter = ProseHighlighter::new(theme);
 
         let style = highlighter.style_for_inline_code(&[highlighter.base_scope()]);
         assert!(style.foreground.a > 0);
@@ -210,7 +210,7 @@ mod tests {
     #[test]
     fn test_code_inline_scope() {
         let theme = load_test_theme();
-        let highlighter = ProseHighlighter::new(&theme);
+        let highlighter = ProseHighlighter::new(theme);
 
         let scope = highlighter.code_inline_scope();
         assert_eq!(scope.to_string(), "markup.raw.inline.markdown");
@@ -219,7 +219,7 @@ mod tests {
     #[test]
     fn test_functional_style_no_mutation() {
         let theme = load_test_theme();
-        let highlighter = ProseHighlighter::new(&theme);
+        let highlighter = ProseHighlighter::new(theme);
 
         // Call style_for_tag multiple times with same inputs
         let tag = Tag::Strong;
@@ -241,7 +241,7 @@ mod tests {
         let theme = load_test_theme();
 
         let start = Instant::now();
-        let _highlighter = ProseHighlighter::new(&theme);
+        let _highlighter = ProseHighlighter::new(theme);
         let duration = start.elapsed();
 
         // Initialization should be fast (< 1ms)
@@ -258,7 +258,7 @@ mod tests {
         // Note: Whether the theme provides a distinct link color depends on the theme.
         // Github theme has a distinct link color, OneHalf doesn't.
         let theme = load_test_theme(); // Github theme
-        let highlighter = ProseHighlighter::new(&theme);
+        let highlighter = ProseHighlighter::new(theme);
 
         let link_tag = Tag::Link {
             link_type: pulldown_cmark::LinkType::Inline,
@@ -282,8 +282,8 @@ mod tests {
 
         // Github theme has a distinct link color
         let github_theme =
-            crate::markdown::highlighting::themes::load_theme(ThemePair::Github, ColorMode::Dark);
-        let github_highlighter = ProseHighlighter::new(&github_theme);
+            crate::markdown::highlighting::themes::cached_theme(ThemePair::Github, ColorMode::Dark);
+        let github_highlighter = ProseHighlighter::new(github_theme);
         let github_base = github_highlighter.base_style();
         let link_tag = Tag::Link {
             link_type: pulldown_cmark::LinkType::Inline,
@@ -301,9 +301,11 @@ mod tests {
         );
 
         // OneHalf theme does NOT have a distinct link color
-        let onehalf_theme =
-            crate::markdown::highlighting::themes::load_theme(ThemePair::OneHalf, ColorMode::Dark);
-        let onehalf_highlighter = ProseHighlighter::new(&onehalf_theme);
+        let onehalf_theme = crate::markdown::highlighting::themes::cached_theme(
+            ThemePair::OneHalf,
+            ColorMode::Dark,
+        );
+        let onehalf_highlighter = ProseHighlighter::new(onehalf_theme);
         let onehalf_base = onehalf_highlighter.base_style();
         let onehalf_link =
             onehalf_highlighter.style_for_tag(&link_tag, &[onehalf_highlighter.base_scope()]);
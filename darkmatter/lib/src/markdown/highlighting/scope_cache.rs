@@ -22,6 +22,7 @@ pub struct ScopeCache {
     pub link: Scope,
     pub code_inline: Scope,
     pub list: Scope,
+    pub definition_term: Scope,
     pub base: Scope,
     pub mark: Scope,
 }
@@ -52,6 +53,8 @@ impl ScopeCache {
                 .expect("Invalid hardcoded scope: markup.raw.inline.markdown"),
             list: Scope::new("markup.list.markdown")
                 .expect("Invalid hardcoded scope: markup.list.markdown"),
+            definition_term: Scope::new("markup.heading.definition.markdown")
+                .expect("Invalid hardcoded scope: markup.heading.definition.markdown"),
             mark: Scope::new("markup.mark.markdown")
                 .expect("Invalid hardcoded scope: markup.mark.markdown"),
         }
@@ -74,6 +77,7 @@ impl ScopeCache {
             Tag::BlockQuote(_) => Some(self.quote),
             Tag::Link { .. } => Some(self.link),
             Tag::List(_) => Some(self.list),
+            Tag::DefinitionListTitle => Some(self.definition_term),
             _ => None,
         }
     }
@@ -186,6 +190,24 @@ mod tests {
         assert_eq!(scope.unwrap().to_string(), "markup.strikethrough.markdown");
     }
 
+    #[test]
+    fn test_scope_for_tag_definition_term() {
+        let cache = ScopeCache::global();
+        let scope = cache.scope_for_tag(&Tag::DefinitionListTitle);
+        assert_eq!(scope, Some(cache.definition_term));
+        // Nested under markup.heading so themes style terms like headings
+        assert!(
+            Scope::new("markup.heading")
+                .unwrap()
+                .is_prefix_of(cache.definition_term)
+        );
+        assert!(
+            cache
+                .scope_for_tag(&Tag::DefinitionListDefinition)
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_scope_for_inline_tag_mark() {
         let cache = ScopeCache::global();
@@ -446,16 +446,18 @@ pub fn detect_color_mode() -> ColorMode {
     ColorMode::Dark
 }
 
-/// Loads a syntect theme for the given theme pair and color mode.
+/// Returns the shared syntect theme for the given theme pair and color mode.
+///
+/// Themes are deserialized on first use and shared for the rest of the process.
 ///
 /// ## Panics
 ///
 /// Panics if the theme cannot be loaded (should never happen with valid Theme variants).
-pub(crate) fn load_theme(theme_pair: ThemePair, color_mode: ColorMode) -> SyntectTheme {
+pub(crate) fn cached_theme(theme_pair: ThemePair, color_mode: ColorMode) -> &'static SyntectTheme {
     let theme = theme_pair.resolve(color_mode);
     let embedded_name = theme.to_embedded_name();
 
-    THEME_SET.get(embedded_name).clone()
+    THEME_SET.get(embedded_name)
 }
 
 #[cfg(test)]
@@ -511,7 +513,7 @@ mod tests {
 
     #[test]
     fn test_load_theme_github_dark() {
-        let theme = load_theme(ThemePair::Github, ColorMode::Dark);
+        let theme = cached_theme(ThemePair::Github, ColorMode::Dark);
         assert!(theme.settings.background.is_some());
 
         // Dark themes should have dark backgrounds (r,g,b all < 100 typically)
@@ -527,13 +529,13 @@ mod tests {
 
     #[test]
     fn test_load_theme_solarized_light() {
-        let theme = load_theme(ThemePair::Solarized, ColorMode::Light);
+        let theme = cached_theme(ThemePair::Solarized, ColorMode::Light);
         assert!(theme.settings.background.is_some());
     }
 
     #[test]
     fn test_github_light_has_light_background() {
-        let theme = load_theme(ThemePair::Github, ColorMode::Light);
+        let theme = cached_theme(ThemePair::Github, ColorMode::Light);
         assert!(theme.settings.background.is_some());
 
         // Light themes should have light backgrounds (r,g,b all > 200 typically)
@@ -551,10 +553,10 @@ mod tests {
     fn test_all_themes_load() {
         // Verify all theme pairs can load in both modes
         for theme_pair in ThemePair::all() {
-            let dark_theme = load_theme(*theme_pair, ColorMode::Dark);
+            let dark_theme = cached_theme(*theme_pair, ColorMode::Dark);
             assert!(dark_theme.settings.background.is_some());
 
-            let light_theme = load_theme(*theme_pair, ColorMode::Light);
+            let light_theme = cached_theme(*theme_pair, ColorMode::Light);
             assert!(light_theme.settings.background.is_some());
         }
     }
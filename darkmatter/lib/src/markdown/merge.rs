@@ -0,0 +1,392 @@
+//! Section-by-section merging of regenerated documents.
+//!
+//! Generated documents (research write-ups, skill files, reports) are often
+//! edited by hand and later regenerated. [`merge`] combines the two versions
+//! so the edits survive: a section of the existing document containing the
+//! [`KEEP_DIRECTIVE`] is kept, together with its subsections, and every other
+//! section comes from the regenerated document.
+//!
+//! Sections are matched by their heading path (the titles of the heading and
+//! its ancestors), so a kept `## Usage` under `# Overview` replaces the
+//! regenerated `## Usage` under `# Overview`. Kept sections the regenerated
+//! document no longer has are carried over after the section they followed.
+//! A directive before the first heading keeps the preamble, including any
+//! frontmatter.
+//!
+//! ## Examples
+//!
+//! ```
+//! use darkmatter_lib::markdown::merge;
+//!
+//! let edited = "# Guide\n\n## Setup\n\n<!-- keep -->\nOur internal mirror is at https://mirror.local.\n\n## Usage\n\nOld usage.\n";
+//! let regenerated = "# Guide\n\n## Setup\n\nRun `cargo add clap`.\n\n## Usage\n\nNew usage.\n";
+//!
+//! let merged = merge(edited, regenerated);
+//! assert!(merged.content.contains("internal mirror"));
+//! assert!(merged.content.contains("New usage."));
+//! assert_eq!(merged.kept, vec![vec!["Guide".to_string(), "Setup".to_string()]]);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+use super::delta::SectionPath;
+
+/// Marks a section to keep when its document is regenerated.
+///
+/// Place it anywhere in the section's own content, usually on the line after
+/// the heading. Whitespace and case inside the comment don't matter.
+pub const KEEP_DIRECTIVE: &str = "<!-- keep -->";
+
+/// The result of [`merge`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergedDocument {
+    /// The merged document.
+    pub content: String,
+    /// Heading paths of the sections kept from the existing document, in
+    /// document order. The preamble's path is empty.
+    pub kept: Vec<SectionPath>,
+}
+
+/// A heading's content up to the next heading of any level.
+#[derive(Debug)]
+struct Section {
+    /// Heading titles from the top-level ancestor to this heading
+    path: SectionPath,
+    /// How many earlier sections have the same path
+    occurrence: usize,
+    /// Heading level, or 0 for the preamble
+    level: u8,
+    /// Byte range in the document
+    range: Range<usize>,
+    /// Whether the section's own content has the keep directive
+    keep: bool,
+}
+
+type SectionKey<'a> = (&'a [String], usize);
+
+impl Section {
+    fn key(&self) -> SectionKey<'_> {
+        (&self.path, self.occurrence)
+    }
+}
+
+/// Merges a regenerated version of a document into the existing one.
+///
+/// Sections of `existing` marked with [`KEEP_DIRECTIVE`] (and their
+/// subsections) are kept; everything else is taken from `regenerated`. When
+/// `existing` has no keep directives the result is `regenerated` unchanged.
+pub fn merge(existing: &str, regenerated: &str) -> MergedDocument {
+    let old = split_sections(existing);
+    let new = split_sections(regenerated);
+
+    // Outermost kept sections, each with its subsections
+    let mut blocks: Vec<Range<usize>> = Vec::new();
+    let mut i = 0;
+    while i < old.len() {
+        if old[i].keep {
+            let end = subtree_end(&old, i);
+            blocks.push(i..end);
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    if blocks.is_empty() {
+        return MergedDocument {
+            content: regenerated.to_string(),
+            kept: Vec::new(),
+        };
+    }
+
+    let new_keys: HashSet<SectionKey> = new.iter().map(Section::key).collect();
+    let mut replacements: HashMap<SectionKey, &Range<usize>> = HashMap::new();
+    let mut carried: HashMap<SectionKey, Vec<&Range<usize>>> = HashMap::new();
+    for block in &blocks {
+        let root = old[block.start].key();
+        if new_keys.contains(&root) {
+            replacements.insert(root, block);
+            continue;
+        }
+        // Follow the closest earlier section that is still there; inside
+        // another kept block, that block's root stands in for it
+        let anchor = (0..block.start)
+            .rev()
+            .find(|&p| new_keys.contains(&old[p].key()))
+            .map(|p| {
+                blocks
+                    .iter()
+                    .find(|b| b.contains(&p))
+                    .map_or(p, |b| b.start)
+            })
+            .unwrap_or(0);
+        carried.entry(old[anchor].key()).or_default().push(block);
+    }
+
+    let mut merged = MergedDocument::default();
+    let mut emitted: HashSet<usize> = HashSet::new();
+    let mut keep_block = |merged: &mut MergedDocument, block: &Range<usize>| {
+        let text = &existing[old[block.start].range.start..old[block.end - 1].range.end];
+        push_section(&mut merged.content, text, true);
+        merged.kept.push(old[block.start].path.clone());
+        emitted.insert(block.start);
+    };
+
+    // Carried-over sections wait until their anchor's subsections are done
+    let mut pending: Vec<(u8, &Vec<&Range<usize>>)> = Vec::new();
+    let mut r = 0;
+    while r < new.len() {
+        let section = &new[r];
+        while let Some((_, blocks)) = pending.pop_if(|(level, _)| *level >= section.level) {
+            for block in blocks {
+                keep_block(&mut merged, block);
+            }
+        }
+
+        let next = match replacements.get(&section.key()) {
+            Some(block) => {
+                keep_block(&mut merged, block);
+                subtree_end(&new, r)
+            }
+            None => {
+                push_section(
+                    &mut merged.content,
+                    &regenerated[section.range.clone()],
+                    false,
+                );
+                r + 1
+            }
+        };
+        if let Some(blocks) = carried.get(&section.key()) {
+            pending.push((section.level, blocks));
+        }
+        r = next;
+    }
+    for (_, blocks) in pending.into_iter().rev() {
+        for block in blocks {
+            keep_block(&mut merged, block);
+        }
+    }
+
+    // Sections whose anchor was skipped along with a replaced section's
+    // subsections
+    for block in blocks.iter().filter(|b| !emitted.contains(&b.start)) {
+        let text = &existing[old[block.start].range.start..old[block.end - 1].range.end];
+        push_section(&mut merged.content, text, true);
+        merged.kept.push(old[block.start].path.clone());
+    }
+
+    merged
+}
+
+/// Appends a section, starting it on a new line (after a blank line for
+/// kept sections).
+fn push_section(out: &mut String, text: &str, kept: bool) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+    if kept && !out.is_empty() && !out.ends_with("\n\n") && !text.is_empty() {
+        out.push('\n');
+    }
+    out.push_str(text);
+}
+
+/// Returns the index after the last subsection of `sections[index]`.
+fn subtree_end(sections: &[Section], index: usize) -> usize {
+    let level = sections[index].level;
+    if level == 0 {
+        return index + 1;
+    }
+    sections[index + 1..]
+        .iter()
+        .position(|s| s.level <= level)
+        .map_or(sections.len(), |offset| index + 1 + offset)
+}
+
+/// Whether an HTML snippet is the keep directive.
+fn is_keep_directive(html: &str) -> bool {
+    let normalized: String = html
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    normalized == "<!--keep-->"
+}
+
+/// Splits `content` into its preamble and one section per heading.
+///
+/// Headings inside code blocks and frontmatter aren't sections.
+fn split_sections(content: &str) -> Vec<Section> {
+    let mut headings: Vec<(u8, usize, String)> = Vec::new();
+    let mut keep_offsets = Vec::new();
+    let mut current: Option<(u8, usize, String)> = None;
+
+    for (event, range) in Parser::new_ext(content, Options::all()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                current = Some((level as u8, range.start, String::new()));
+            }
+            Event::End(TagEnd::Heading(_)) => headings.extend(current.take()),
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, _, title)) = &mut current {
+                    title.push_str(&text);
+                }
+            }
+            Event::Html(html) | Event::InlineHtml(html) if is_keep_directive(&html) => {
+                keep_offsets.push(range.start);
+            }
+            _ => {}
+        }
+    }
+
+    let mut sections = Vec::with_capacity(headings.len() + 1);
+    sections.push(Section {
+        path: Vec::new(),
+        occurrence: 0,
+        level: 0,
+        range: 0..headings.first().map_or(content.len(), |h| h.1),
+        keep: false,
+    });
+
+    let mut ancestors: Vec<(u8, String)> = Vec::new();
+    let mut occurrences: HashMap<SectionPath, usize> = HashMap::new();
+    for (i, (level, start, title)) in headings.iter().enumerate() {
+        let end = headings.get(i + 1).map_or(content.len(), |h| h.1);
+        while ancestors.last().is_some_and(|(l, _)| l >= level) {
+            ancestors.pop();
+        }
+        ancestors.push((*level, title.trim().to_string()));
+
+        let path: SectionPath = ancestors.iter().map(|(_, title)| title.clone()).collect();
+        let count = occurrences.entry(path.clone()).or_default();
+        sections.push(Section {
+            path,
+            occurrence: *count,
+            level: *level,
+            range: *start..end,
+            keep: false,
+        });
+        *count += 1;
+    }
+
+    for offset in keep_offsets {
+        if let Some(section) = sections.iter_mut().rev().find(|s| s.range.start <= offset) {
+            section.keep = true;
+        }
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(titles: &[&str]) -> SectionPath {
+        titles.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn without_directives_the_regenerated_document_wins() {
+        let merged = merge("# Doc\n\nEdited.\n", "# Doc\n\nRegenerated.\n");
+        assert_eq!(merged.content, "# Doc\n\nRegenerated.\n");
+        assert!(merged.kept.is_empty());
+    }
+
+    #[test]
+    fn kept_sections_replace_their_regenerated_versions() {
+        let existing = "# Doc\n\nIntro.\n\n## A\n\n<!-- keep -->\nMy A.\n\n## B\n\nOld B.\n";
+        let regenerated = "# Doc\n\nNew intro.\n\n## A\n\nNew A.\n\n## B\n\nNew B.\n";
+
+        let merged = merge(existing, regenerated);
+
+        assert_eq!(
+            merged.content,
+            "# Doc\n\nNew intro.\n\n## A\n\n<!-- keep -->\nMy A.\n\n## B\n\nNew B.\n"
+        );
+        assert_eq!(merged.kept, vec![path(&["Doc", "A"])]);
+    }
+
+    #[test]
+    fn subsections_are_kept_with_their_section() {
+        let existing = "## A\n<!-- KEEP -->\n\n### A1\n\nMine.\n\n## B\n\nOld.\n";
+        let regenerated =
+            "## A\n\nNew A.\n\n### A1\n\nNew A1.\n\n### A2\n\nNew A2.\n\n## B\n\nNew.\n";
+
+        let merged = merge(existing, regenerated);
+
+        assert_eq!(
+            merged.content,
+            "## A\n<!-- KEEP -->\n\n### A1\n\nMine.\n\n## B\n\nNew.\n"
+        );
+    }
+
+    #[test]
+    fn kept_sections_missing_from_the_regenerated_document_stay_in_place() {
+        let existing = "# Doc\n\n## A\n\nOld A.\n\n### A1\n\nOld A1.\n\n## Notes\n\n<!-- keep -->\nMy notes.\n\n## B\n\nOld B.\n";
+        let regenerated = "# Doc\n\n## A\n\nNew A.\n\n### A1\n\nNew A1.\n\n## B\n\nNew B.\n";
+
+        let merged = merge(existing, regenerated);
+
+        assert_eq!(
+            merged.content,
+            "# Doc\n\n## A\n\nNew A.\n\n### A1\n\nNew A1.\n\n## Notes\n\n<!-- keep -->\nMy notes.\n\n## B\n\nNew B.\n"
+        );
+        assert_eq!(merged.kept, vec![path(&["Doc", "Notes"])]);
+    }
+
+    #[test]
+    fn kept_sections_at_the_end_are_appended() {
+        let existing = "## A\n\nOld A.\n\n## Notes\n<!-- keep -->\nMy notes.";
+        let regenerated = "## A\n\nNew A.";
+
+        let merged = merge(existing, regenerated);
+
+        assert_eq!(
+            merged.content,
+            "## A\n\nNew A.\n\n## Notes\n<!-- keep -->\nMy notes."
+        );
+    }
+
+    #[test]
+    fn preamble_and_frontmatter_can_be_kept() {
+        let existing = "---\ntitle: Mine\n# not a heading\n---\n<!-- keep -->\n\n# Doc\n\nOld.\n";
+        let regenerated = "---\ntitle: Generated\n---\n\n# Doc\n\nNew.\n";
+
+        let merged = merge(existing, regenerated);
+
+        assert_eq!(
+            merged.content,
+            "---\ntitle: Mine\n# not a heading\n---\n<!-- keep -->\n\n# Doc\n\nNew.\n"
+        );
+        assert_eq!(merged.kept, vec![SectionPath::new()]);
+    }
+
+    #[test]
+    fn headings_in_code_blocks_are_not_sections() {
+        let existing =
+            "## A\n<!-- keep -->\n\n```sh\n# install\ncargo add clap\n```\n\n## B\n\nOld.\n";
+        let regenerated = "## A\n\nNew A.\n\n## B\n\nNew.\n";
+
+        let merged = merge(existing, regenerated);
+
+        assert_eq!(
+            merged.content,
+            "## A\n<!-- keep -->\n\n```sh\n# install\ncargo add clap\n```\n\n## B\n\nNew.\n"
+        );
+    }
+
+    #[test]
+    fn repeated_headings_match_by_occurrence() {
+        let existing = "## Example\n\nOld 1.\n\n## Example\n<!-- keep -->\nMine.\n";
+        let regenerated = "## Example\n\nNew 1.\n\n## Example\n\nNew 2.\n";
+
+        let merged = merge(existing, regenerated);
+
+        assert_eq!(
+            merged.content,
+            "## Example\n\nNew 1.\n\n## Example\n<!-- keep -->\nMine.\n"
+        );
+    }
+}
@@ -7,6 +7,9 @@
 //! - Loading from strings, files, and URLs
 //! - Typed frontmatter accessors
 //! - Frontmatter merging with conflict resolution strategies
+//! - Bibliographies and `[@key]` citations (see [`citations`])
+//! - Merging regenerated documents while keeping hand-edited sections (see [`merge()`])
+//! - Word counts, reading time and structural statistics (see [`stats`])
 //!
 //! ## Examples
 //!
@@ -27,14 +30,17 @@
 //! assert_eq!(title, Some("Hello World".to_string()));
 //! ```
 
+pub mod citations;
 pub mod cleanup;
 pub mod delta;
 pub mod dsl;
 mod frontmatter;
 pub mod highlighting;
 pub mod inline;
+pub mod merge;
 pub mod normalize;
 pub mod output;
+pub mod stats;
 pub mod toc;
 mod types;
 
@@ -43,10 +49,12 @@ pub use delta::{
     FrontmatterChange, MarkdownDelta, MovedSection, SectionId, SectionPath,
 };
 pub use frontmatter::{Frontmatter, MergeStrategy};
+pub use merge::{KEEP_DIRECTIVE, MergedDocument, merge};
 pub use normalize::{
     HeadingAdjustment, HeadingLevel, NormalizationError, NormalizationReport, StructureIssue,
     StructureIssueKind, StructureValidation, ViolationCorrection,
 };
+pub use stats::{DEFAULT_WORDS_PER_MINUTE, DocumentStats, HeadingCounts};
 pub use toc::{CodeBlockInfo, InternalLinkInfo, MarkdownToc, MarkdownTocNode};
 pub use types::{FrontmatterMap, MarkdownError, MarkdownResult};
 
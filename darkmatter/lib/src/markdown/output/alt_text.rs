@@ -0,0 +1,168 @@
+//! Pluggable alt-text generation for HTML export.
+//!
+//! Images without alt text are an accessibility gap in exported reports. When
+//! an [`AltTextHook`] is set on [`HtmlOptions`](super::HtmlOptions), the HTML
+//! renderer asks it to describe every image whose alt text is empty. The hook
+//! wraps any [`AltTextGenerator`] — typically a call to a vision model — so
+//! darkmatter itself never talks to a provider.
+//!
+//! This module is only compiled with the `alt-text` feature, and no hook is
+//! configured by default.
+//!
+//! ## Examples
+//!
+//! ```
+//! use darkmatter_lib::markdown::Markdown;
+//! use darkmatter_lib::markdown::output::alt_text::{AltTextHook, ImageRef};
+//! use darkmatter_lib::markdown::output::{HtmlOptions, as_html};
+//!
+//! let hook = AltTextHook::new(|image: &ImageRef<'_>| {
+//!     Ok(Some(format!("Diagram loaded from {}", image.src)))
+//! });
+//!
+//! let mut options = HtmlOptions::default();
+//! options.alt_text = Some(hook);
+//!
+//! let md: Markdown = "![](arch.png)".into();
+//! let html = as_html(&md, options).unwrap();
+//! assert!(html.contains(r#"alt="Diagram loaded from arch.png""#));
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use thiserror::Error;
+
+/// Errors returned by an [`AltTextGenerator`].
+#[derive(Error, Debug)]
+pub enum AltTextError {
+    /// The image could not be read or fetched.
+    #[error("Failed to load image '{src}': {message}")]
+    ImageLoad { src: String, message: String },
+
+    /// The generator (e.g. a vision model) failed to describe the image.
+    #[error("Alt text generation failed: {0}")]
+    Generation(String),
+}
+
+/// An image that needs alt text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageRef<'a> {
+    /// Image destination exactly as written in the markdown.
+    pub src: &'a str,
+    /// Image title attribute (empty when absent).
+    pub title: &'a str,
+}
+
+/// Generates alt text for images that have none.
+///
+/// Implementations are called synchronously during HTML export, once per
+/// distinct image source.
+pub trait AltTextGenerator: Send + Sync {
+    /// Describes `image`.
+    ///
+    /// ## Returns
+    ///
+    /// `Ok(None)` to leave the alt text empty (e.g. for decorative images).
+    ///
+    /// ## Errors
+    ///
+    /// Errors are logged and the image is exported with empty alt text; they
+    /// never fail the export.
+    fn generate(&self, image: &ImageRef<'_>) -> Result<Option<String>, AltTextError>;
+}
+
+impl<F> AltTextGenerator for F
+where
+    F: Fn(&ImageRef<'_>) -> Result<Option<String>, AltTextError> + Send + Sync,
+{
+    fn generate(&self, image: &ImageRef<'_>) -> Result<Option<String>, AltTextError> {
+        self(image)
+    }
+}
+
+/// A shareable [`AltTextGenerator`] configured on `HtmlOptions::alt_text`.
+#[derive(Clone)]
+pub struct AltTextHook(Arc<dyn AltTextGenerator>);
+
+impl AltTextHook {
+    /// Wraps a generator for use in [`HtmlOptions`](super::HtmlOptions).
+    pub fn new<G: AltTextGenerator + 'static>(generator: G) -> Self {
+        Self(Arc::new(generator))
+    }
+}
+
+impl fmt::Debug for AltTextHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AltTextHook(..)")
+    }
+}
+
+/// Per-export cache so repeated images only hit the generator once.
+#[derive(Default)]
+pub(crate) struct AltTextCache {
+    generated: HashMap<String, Option<String>>,
+}
+
+impl AltTextCache {
+    /// Returns generated alt text for `image`, calling `hook` on a cache miss.
+    pub(crate) fn get(&mut self, hook: &AltTextHook, image: &ImageRef<'_>) -> Option<String> {
+        if let Some(cached) = self.generated.get(image.src) {
+            return cached.clone();
+        }
+
+        let generated = match hook.0.generate(image) {
+            Ok(alt) => alt
+                .map(|alt| alt.trim().to_string())
+                .filter(|alt| !alt.is_empty()),
+            Err(e) => {
+                tracing::warn!(src = image.src, error = %e, "alt text generation failed");
+                None
+            }
+        };
+        self.generated
+            .insert(image.src.to_string(), generated.clone());
+        generated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn cache_calls_generator_once_per_source() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let hook = AltTextHook::new(move |image: &ImageRef<'_>| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(Some(format!("  {}  ", image.src)))
+        });
+
+        let mut cache = AltTextCache::default();
+        let image = ImageRef {
+            src: "a.png",
+            title: "",
+        };
+        assert_eq!(cache.get(&hook, &image), Some("a.png".to_string()));
+        assert_eq!(cache.get(&hook, &image), Some("a.png".to_string()));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn errors_and_blank_results_yield_none() {
+        let failing = AltTextHook::new(|_: &ImageRef<'_>| {
+            Err(AltTextError::Generation("model offline".to_string()))
+        });
+        let blank = AltTextHook::new(|_: &ImageRef<'_>| Ok(Some("   ".to_string())));
+        let image = ImageRef {
+            src: "a.png",
+            title: "",
+        };
+
+        assert_eq!(AltTextCache::default().get(&failing, &image), None);
+        assert_eq!(AltTextCache::default().get(&blank, &image), None);
+    }
+}
@@ -24,13 +24,21 @@
 //! assert!(html.contains("<code"));
 //! ```
 
+use crate::markdown::citations::{CitationFormat, render_citations};
 use crate::markdown::dsl::parse_code_info;
+use crate::markdown::highlighting::blocks::{
+    HighlightedBlocks, collect_code_blocks, highlight_blocks,
+};
+use crate::markdown::highlighting::prose::ProseHighlighter;
 use crate::markdown::highlighting::{CodeHighlighter, ColorMode, ThemePair};
 use crate::markdown::inline::{InlineEvent, InlineTag, MarkProcessor};
+#[cfg(feature = "alt-text")]
+use crate::markdown::output::alt_text::{AltTextCache, AltTextHook, ImageRef};
 use crate::markdown::output::terminal::MermaidMode;
 use crate::markdown::{Markdown, MarkdownResult};
 use crate::mermaid::Mermaid;
 use crate::render::link::Link;
+use biscuit_terminal::utils::ansi_html::ansi_to_html;
 use html_escape;
 use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
 use syntect::easy::HighlightLines;
@@ -70,6 +78,17 @@ pub struct HtmlOptions {
     /// - `Image`: Render as interactive mermaid diagrams (includes mermaid.js)
     /// - `Text`: Show as fenced code blocks (fallback format)
     pub mermaid_mode: MermaidMode,
+    /// Resolve `[@key]` citations against the frontmatter `references` list
+    /// and append a numbered references section. Default: `true`.
+    pub render_citations: bool,
+    /// Highlight independent code blocks on multiple threads before
+    /// rendering. Default: `true`.
+    pub parallel_highlighting: bool,
+    /// Generates alt text for images that have none.
+    ///
+    /// `None` (default) exports such images with an empty `alt` attribute.
+    #[cfg(feature = "alt-text")]
+    pub alt_text: Option<AltTextHook>,
 }
 
 impl Default for HtmlOptions {
@@ -81,6 +100,10 @@ impl Default for HtmlOptions {
             include_line_numbers: false,
             include_styles: true,
             mermaid_mode: MermaidMode::default(),
+            render_citations: true,
+            parallel_highlighting: true,
+            #[cfg(feature = "alt-text")]
+            alt_text: None,
         }
     }
 }
@@ -116,8 +139,45 @@ pub fn as_html(md: &Markdown, options: HtmlOptions) -> MarkdownResult<String> {
         output.push_str(&generate_styles(&code_highlighter, &options));
     }
 
-    // Parse markdown content with GFM strikethrough extension and wrap with MarkProcessor
-    let parser = Parser::new_ext(md.content(), Options::ENABLE_STRIKETHROUGH);
+    // Resolve citations: markers link to `#ref-N` anchors in the references section
+    let citations = if options.render_citations {
+        render_citations(md, CitationFormat::Html)
+    } else {
+        None
+    };
+    let content = citations
+        .as_ref()
+        .map_or(md.content(), |(content, _)| content.as_str());
+
+    let parser_options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_DEFINITION_LIST;
+
+    // Highlight code blocks up front; rendered Mermaid diagrams are left alone
+    let mut highlighted_blocks = HighlightedBlocks::new(highlight_blocks(
+        collect_code_blocks(content, parser_options),
+        options.parallel_highlighting,
+        |block| {
+            if !block.fenced {
+                return None;
+            }
+            let meta = parse_code_info(&block.info).ok()?;
+            if meta.language.eq_ignore_ascii_case("mermaid")
+                && options.mermaid_mode != MermaidMode::Off
+            {
+                return None;
+            }
+            Some(highlight_code_block(
+                &block.code,
+                &meta.language,
+                &meta,
+                &code_highlighter,
+                &options,
+            ))
+        },
+    ));
+
+    // Parse markdown content with GFM strikethrough and definition list extensions
+    // and wrap with MarkProcessor
+    let parser = Parser::new_ext(content, parser_options);
     let events = MarkProcessor::new(parser);
 
     // Track state for code blocks
@@ -127,6 +187,12 @@ pub fn as_html(md: &Markdown, options: HtmlOptions) -> MarkdownResult<String> {
     let mut code_info = String::new();
     let mut has_mermaid = false;
 
+    // Track images: alt text arrives as text events between start and end
+    let mut current_image: Option<(String, String)> = None;
+    let mut image_alt = String::new();
+    #[cfg(feature = "alt-text")]
+    let mut alt_cache = AltTextCache::default();
+
     for event in events {
         match event {
             // Handle custom inline tags (highlight/mark)
@@ -144,6 +210,7 @@ pub fn as_html(md: &Markdown, options: HtmlOptions) -> MarkdownResult<String> {
                 code_lang.clear();
             }
             InlineEvent::Standard(Event::End(TagEnd::CodeBlock)) => {
+                let precomputed = highlighted_blocks.next_for(&code_buffer);
                 if in_code_block {
                     // Parse DSL metadata
                     let meta = parse_code_info(&code_info)?;
@@ -179,13 +246,16 @@ pub fn as_html(md: &Markdown, options: HtmlOptions) -> MarkdownResult<String> {
                         }
                     } else {
                         // Render code block with highlighting
-                        let highlighted = highlight_code_block(
-                            &code_buffer,
-                            &code_lang,
-                            &meta,
-                            &code_highlighter,
-                            &options,
-                        )?;
+                        let highlighted = match precomputed {
+                            Some(highlighted) => highlighted?,
+                            None => highlight_code_block(
+                                &code_buffer,
+                                &code_lang,
+                                &meta,
+                                &code_highlighter,
+                                &options,
+                            )?,
+                        };
                         output.push_str(&highlighted);
                     }
 
@@ -259,6 +329,24 @@ pub fn as_html(md: &Markdown, options: HtmlOptions) -> MarkdownResult<String> {
             InlineEvent::Standard(Event::End(TagEnd::Item)) => {
                 output.push_str("</li>\n");
             }
+            InlineEvent::Standard(Event::Start(Tag::DefinitionList)) => {
+                output.push_str("<dl>\n");
+            }
+            InlineEvent::Standard(Event::End(TagEnd::DefinitionList)) => {
+                output.push_str("</dl>\n");
+            }
+            InlineEvent::Standard(Event::Start(Tag::DefinitionListTitle)) => {
+                output.push_str("<dt>");
+            }
+            InlineEvent::Standard(Event::End(TagEnd::DefinitionListTitle)) => {
+                output.push_str("</dt>\n");
+            }
+            InlineEvent::Standard(Event::Start(Tag::DefinitionListDefinition)) => {
+                output.push_str("<dd>");
+            }
+            InlineEvent::Standard(Event::End(TagEnd::DefinitionListDefinition)) => {
+                output.push_str("</dd>\n");
+            }
             InlineEvent::Standard(Event::Start(Tag::BlockQuote(_))) => {
                 output.push_str("<blockquote>\n");
             }
@@ -311,6 +399,47 @@ pub fn as_html(md: &Markdown, options: HtmlOptions) -> MarkdownResult<String> {
             InlineEvent::Standard(Event::End(TagEnd::Link)) => {
                 output.push_str("</a>");
             }
+            InlineEvent::Standard(Event::Start(Tag::Image {
+                dest_url, title, ..
+            })) => {
+                current_image = Some((dest_url.to_string(), title.to_string()));
+                image_alt.clear();
+            }
+            InlineEvent::Standard(Event::End(TagEnd::Image)) => {
+                if let Some((src, title)) = current_image.take() {
+                    #[cfg(feature = "alt-text")]
+                    if image_alt.trim().is_empty()
+                        && let Some(hook) = &options.alt_text
+                        && let Some(generated) = alt_cache.get(
+                            hook,
+                            &ImageRef {
+                                src: &src,
+                                title: &title,
+                            },
+                        )
+                    {
+                        image_alt = generated;
+                    }
+
+                    output.push_str(&format!(
+                        r#"<img src="{}" alt="{}""#,
+                        html_escape::encode_double_quoted_attribute(&src),
+                        html_escape::encode_double_quoted_attribute(&image_alt)
+                    ));
+                    if !title.is_empty() {
+                        output.push_str(&format!(
+                            r#" title="{}""#,
+                            html_escape::encode_double_quoted_attribute(&title)
+                        ));
+                    }
+                    output.push('>');
+                }
+            }
+            InlineEvent::Standard(Event::Text(text) | Event::Code(text))
+                if current_image.is_some() =>
+            {
+                image_alt.push_str(&text);
+            }
             InlineEvent::Standard(Event::Code(text)) => {
                 output.push_str(&format!("<code>{}</code>", html_escape::encode_text(&text)));
             }
@@ -331,6 +460,10 @@ pub fn as_html(md: &Markdown, options: HtmlOptions) -> MarkdownResult<String> {
         }
     }
 
+    if let Some((_, references)) = &citations {
+        output.push_str(references);
+    }
+
     // Add mermaid.js script if we rendered any mermaid diagrams
     if has_mermaid {
         output.push_str(r#"<script type="module">
@@ -350,6 +483,9 @@ pub fn as_html(md: &Markdown, options: HtmlOptions) -> MarkdownResult<String> {
 }
 
 /// Highlights a code block with syntax highlighting and optional line numbers.
+///
+/// `ansi` blocks hold captured terminal output and keep their own escape-code
+/// styling instead of being syntax highlighted.
 fn highlight_code_block(
     code: &str,
     language: &str,
@@ -368,6 +504,15 @@ fn highlight_code_block(
         output.push('\n');
     }
 
+    if language.eq_ignore_ascii_case("ansi") {
+        output.push_str(r#"<div class="code-block">"#);
+        output.push('\n');
+        output.push_str(r#"<pre><code class="language-ansi">"#);
+        output.push_str(&ansi_to_html(code));
+        output.push_str("</code></pre>\n</div>\n");
+        return Ok(output);
+    }
+
     // Determine if we should show line numbers
     let show_line_numbers = meta.line_numbering || options.include_line_numbers;
 
@@ -472,7 +617,18 @@ fn highlight_code_block(
 }
 
 /// Generates CSS styles for syntax highlighting.
-fn generate_styles(highlighter: &CodeHighlighter, _options: &HtmlOptions) -> String {
+///
+/// Definition terms take their color from the prose theme's heading style.
+fn generate_styles(highlighter: &CodeHighlighter, options: &HtmlOptions) -> String {
+    let prose_theme = crate::markdown::highlighting::themes::cached_theme(
+        options.prose_theme,
+        options.color_mode,
+    );
+    let prose_highlighter = ProseHighlighter::new(prose_theme);
+    let term = prose_highlighter
+        .style_for_tag(&Tag::DefinitionListTitle, &[prose_highlighter.base_scope()])
+        .foreground;
+
     let bg = highlighter
         .theme()
         .settings
@@ -539,6 +695,16 @@ mark {{
     padding: 0.1em 0.2em;
     border-radius: 2px;
 }}
+
+dt {{
+    color: #{:02x}{:02x}{:02x};
+    font-weight: bold;
+    margin-top: 0.75em;
+}}
+
+dd {{
+    margin: 0.25em 0 0 2em;
+}}
 </style>
 "#,
         bg.r,
@@ -546,7 +712,10 @@ mark {{
         bg.b,
         bg.r.saturating_sub(10),
         bg.g.saturating_sub(10),
-        bg.b.saturating_sub(10)
+        bg.b.saturating_sub(10),
+        term.r,
+        term.g,
+        term.b
     )
 }
 
@@ -595,6 +764,18 @@ fn main() {}
         assert!(html.contains("fn") && html.contains("main"));
     }
 
+    #[test]
+    fn test_as_html_ansi_code_block_keeps_terminal_styling() {
+        let content = "```ansi\n\x1b[1;31merror\x1b[0m: <missing>\n```";
+        let md: Markdown = content.into();
+        let html = as_html(&md, HtmlOptions::default()).unwrap();
+        assert!(html.contains(r#"<code class="language-ansi">"#));
+        assert!(html.contains(
+            r#"<span style="color:#cd3131;font-weight:bold">error</span>: &lt;missing&gt;"#
+        ));
+        assert!(!html.contains('\x1b'));
+    }
+
     #[test]
     fn test_as_html_code_block_with_title() {
         let content = r#"```rust title="Main function"
@@ -719,6 +900,24 @@ fn main() {
         assert!(html.contains("</blockquote>"));
     }
 
+    #[test]
+    fn test_as_html_definition_list() {
+        let md: Markdown = "Term\n: The *first* definition\n: Another definition".into();
+        let html = as_html(&md, HtmlOptions::default()).unwrap();
+        assert!(html.contains("<dl>\n<dt>Term</dt>\n"));
+        assert!(html.contains("<dd>The <em>first</em> definition</dd>\n"));
+        assert!(html.contains("<dd>Another definition</dd>\n</dl>"));
+        assert!(!html.contains("<p>Term"));
+    }
+
+    #[test]
+    fn test_as_html_definition_list_styles() {
+        let md: Markdown = "Term\n: Definition".into();
+        let html = as_html(&md, HtmlOptions::default()).unwrap();
+        assert!(html.contains("dt {"));
+        assert!(html.contains("dd {"));
+    }
+
     #[test]
     fn test_as_html_xss_prevention() {
         let md: Markdown = "<script>alert('xss')</script>".into();
@@ -1229,4 +1428,55 @@ flowchart LR
             "registerIconPacks should come before initialize"
         );
     }
+
+    #[test]
+    fn test_as_html_renders_citations() {
+        let content = r#"---
+references:
+  - key: alpha
+    title: Alpha <Paper>
+    url: https://example.com/alpha
+---
+See [@alpha].
+"#;
+        let md: Markdown = content.into();
+        let html = as_html(&md, HtmlOptions::default()).unwrap();
+
+        assert!(html.contains(r##"[<a href="#ref-1">1</a>]"##));
+        assert!(html.contains(r#"<section class="references">"#));
+        assert!(html.contains(r#"<li id="ref-1"><em>Alpha &lt;Paper&gt;</em>."#));
+
+        let options = HtmlOptions {
+            render_citations: false,
+            ..Default::default()
+        };
+        let html = as_html(&md, options).unwrap();
+        assert!(html.contains("[@alpha]"));
+        assert!(!html.contains("references"));
+    }
+
+    #[test]
+    fn test_as_html_renders_images() {
+        let md: Markdown = r#"![A "chart"](chart.png "Q3") and ![](blank.png)"#.into();
+        let html = as_html(&md, HtmlOptions::default()).unwrap();
+
+        assert!(html.contains(r#"<img src="chart.png" alt="A &quot;chart&quot;" title="Q3">"#));
+        assert!(html.contains(r#"<img src="blank.png" alt="">"#));
+    }
+
+    #[cfg(feature = "alt-text")]
+    #[test]
+    fn test_as_html_alt_text_hook_fills_missing_alt() {
+        use crate::markdown::output::alt_text::{AltTextHook, ImageRef};
+
+        let md: Markdown = "![](a.png) ![Given](b.png)".into();
+        let mut options = HtmlOptions::default();
+        options.alt_text = Some(AltTextHook::new(|image: &ImageRef<'_>| {
+            Ok(Some(format!("generated for {}", image.src)))
+        }));
+        let html = as_html(&md, options).unwrap();
+
+        assert!(html.contains(r#"<img src="a.png" alt="generated for a.png">"#));
+        assert!(html.contains(r#"<img src="b.png" alt="Given">"#));
+    }
 }
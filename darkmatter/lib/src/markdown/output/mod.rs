@@ -6,6 +6,9 @@
 //! - HTML with syntax highlighting
 //! - MDAST (Markdown Abstract Syntax Tree) for programmatic manipulation
 //!
+//! With the `alt-text` feature, HTML export can also generate missing image
+//! alt text through a pluggable hook (see `alt_text`).
+//!
 //! ## Examples
 //!
 //! ```
@@ -22,6 +25,8 @@
 //! assert!(output.contains("title: Hello"));
 //! ```
 
+#[cfg(feature = "alt-text")]
+pub mod alt_text;
 mod ast;
 pub mod html;
 mod string;
@@ -31,8 +36,8 @@ pub use ast::as_ast;
 pub use html::{HtmlOptions, as_html};
 pub use string::as_string;
 pub use terminal::{
-    ColorDepth, ImageRenderer, ItalicMode, MermaidMode, TerminalOptions, for_terminal,
-    write_terminal,
+    ColorDepth, ImageRenderer, ItalicMode, MermaidMode, TableOverflow, TerminalOptions,
+    for_terminal, write_terminal,
 };
 
 #[cfg(test)]
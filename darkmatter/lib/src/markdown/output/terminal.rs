@@ -29,13 +29,18 @@
 
 use crate::markdown::{
     Markdown, MarkdownError,
+    citations::{CitationFormat, render_citations},
     dsl::parse_code_info,
     highlighting::{
-        CodeHighlighter, ColorMode, ThemePair, prose::ProseHighlighter, scope_cache::ScopeCache,
+        CodeHighlighter, ColorMode, ThemePair,
+        blocks::{HighlightedBlocks, collect_code_blocks, highlight_blocks},
+        prose::ProseHighlighter,
+        scope_cache::ScopeCache,
     },
     inline::{InlineEvent, InlineTag, MarkProcessor},
 };
 use crate::render::link::Link;
+use crate::render::resolver::{LinkContext, LinkResolverChain};
 use comfy_table::{Attribute, Cell, CellAlignment, ContentArrangement, Table, presets};
 use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
 use std::path::{Path, PathBuf};
@@ -48,6 +53,7 @@ use biscuit_terminal::components::image_options::TerminalImageOptions;
 use biscuit_terminal::components::terminal_image::{ImageWidth, TerminalImage};
 use biscuit_terminal::discovery::detection::ImageSupport;
 use biscuit_terminal::terminal::Terminal;
+use biscuit_terminal::utils::text_width::display_width;
 
 /// Color depth capability for terminal.
 ///
@@ -193,6 +199,42 @@ pub enum MermaidMode {
     Text,
 }
 
+/// How tables wider than the terminal are rendered.
+///
+/// ## Examples
+///
+/// ```
+/// use darkmatter_lib::markdown::output::terminal::{TableOverflow, TerminalOptions};
+///
+/// // Drop trailing columns that don't fit (default)
+/// let mut options = TerminalOptions::default();
+/// assert!(matches!(options.table_overflow, TableOverflow::Collapse));
+///
+/// // Render at full width for a pager that scrolls sideways (`less -RS`)
+/// options.table_overflow = TableOverflow::Scroll;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableOverflow {
+    /// Wrap cell text to fit the terminal, dropping columns that can't fit
+    /// without breaking words (default).
+    ///
+    /// Columns on the left take priority: columns are dropped from the right
+    /// until the rest fit, always keeping the first. A note below the table
+    /// names the hidden columns.
+    #[default]
+    Collapse,
+
+    /// Wrap cell text to fit the terminal, keeping every column even when
+    /// that breaks words.
+    Wrap,
+
+    /// Render tables at their natural width without wrapping.
+    ///
+    /// Wide tables overflow the terminal, so use this when output goes to a
+    /// pager that scrolls horizontally, such as `less -RS`.
+    Scroll,
+}
+
 /// Maximum image file size (10MB).
 const MAX_IMAGE_FILE_SIZE: u64 = 10 * 1024 * 1024;
 
@@ -420,6 +462,18 @@ impl ImageRenderer {
 /// options.base_path = Some(PathBuf::from("/docs"));
 /// ```
 ///
+/// ## Link Resolution
+///
+/// ```
+/// use darkmatter_lib::markdown::output::terminal::TerminalOptions;
+/// use darkmatter_lib::render::resolver::SchemeResolver;
+///
+/// let mut options = TerminalOptions::default();
+/// options
+///     .link_resolvers
+///     .register(SchemeResolver::new("research", "file:///home/me/.research/library/{path}/"));
+/// ```
+///
 /// ## Italic Mode
 ///
 /// ```
@@ -471,6 +525,25 @@ pub struct TerminalOptions {
     /// - `Image`: Render as images via mermaid.ink service
     /// - `Text`: Show as fenced code blocks (fallback format)
     pub mermaid_mode: MermaidMode,
+    /// Resolvers that rewrite link destinations before they are emitted as
+    /// OSC8 hyperlinks.
+    ///
+    /// The default chain maps Rust paths (`serde::Deserialize`) to docs and
+    /// relative paths to `file://` URLs (relative to `base_path`). Register
+    /// additional resolvers for custom schemes such as `research://`.
+    pub link_resolvers: LinkResolverChain,
+    /// Resolve `[@key]` citations against the frontmatter `references` list
+    /// and append a numbered references section. Default: `true`.
+    pub render_citations: bool,
+    /// Highlight independent code blocks on multiple threads before rendering.
+    /// Default: `true`
+    pub parallel_highlighting: bool,
+    /// Controls how tables wider than the terminal are rendered.
+    ///
+    /// - `Collapse` (default): Wrap cells and drop trailing columns that don't fit
+    /// - `Wrap`: Wrap cells, keeping every column
+    /// - `Scroll`: Render at natural width for horizontal scrolling in a pager
+    pub table_overflow: TableOverflow,
 }
 
 impl Default for TerminalOptions {
@@ -494,6 +567,10 @@ impl Default for TerminalOptions {
             italic_mode: ItalicMode::default(),
             max_width: None,
             mermaid_mode: MermaidMode::default(),
+            link_resolvers: LinkResolverChain::default(),
+            render_citations: true,
+            parallel_highlighting: true,
+            table_overflow: TableOverflow::default(),
         }
     }
 }
@@ -565,6 +642,23 @@ pub fn write_terminal<W: std::io::Write>(
     md: &Markdown,
     options: TerminalOptions,
 ) -> Result<(), MarkdownError> {
+    // Resolve citations into numbered links plus an appended references section
+    let cited;
+    let md = match options
+        .render_citations
+        .then(|| render_citations(md, CitationFormat::Terminal))
+        .flatten()
+    {
+        Some((content, references)) => {
+            cited = Markdown::with_frontmatter(
+                md.frontmatter().clone(),
+                format!("{}\n\n{}", content.trim_end(), references),
+            );
+            &cited
+        }
+        None => md,
+    };
+
     let color_depth = options.color_depth.unwrap_or_else(ColorDepth::auto_detect);
 
     // Early return if no color support
@@ -588,9 +682,11 @@ pub fn write_terminal<W: std::io::Write>(
     let code_highlighter = CodeHighlighter::new(options.code_theme, options.color_mode);
 
     // Load prose theme for ProseHighlighter
-    let prose_syntect_theme =
-        crate::markdown::highlighting::themes::load_theme(options.prose_theme, options.color_mode);
-    let prose_highlighter = ProseHighlighter::new(&prose_syntect_theme);
+    let prose_syntect_theme = crate::markdown::highlighting::themes::cached_theme(
+        options.prose_theme,
+        options.color_mode,
+    );
+    let prose_highlighter = ProseHighlighter::new(prose_syntect_theme);
 
     // Use LineWrapper for proper word wrapping at terminal width
     let mut wrapper = LineWrapper::new(terminal_width as usize);
@@ -598,11 +694,39 @@ pub fn write_terminal<W: std::io::Write>(
     // Track scope stack for prose highlighting (functional style)
     let mut scope_stack: Vec<Scope> = vec![prose_highlighter.base_scope()];
 
-    // Enable table parsing extension and wrap with MarkProcessor for ==highlight== support
-    let parser = Parser::new_ext(
-        md.content(),
-        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH,
-    );
+    // Enable table and definition list extensions
+    let parser_options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_DEFINITION_LIST;
+
+    // Highlight every code block up front (in parallel) and take the results in order below.
+    // Mermaid diagrams rendered as images are only highlighted if rendering fails.
+    let code_blocks = collect_code_blocks(md.content(), parser_options);
+    let mut highlighted_blocks = HighlightedBlocks::new(highlight_blocks(
+        code_blocks,
+        options.parallel_highlighting,
+        |block| {
+            let meta = parse_code_info(&block.info).unwrap_or_default();
+            let mut language = meta.language.as_str();
+            if language.eq_ignore_ascii_case("mermaid") && options.mermaid_mode != MermaidMode::Off
+            {
+                if options.mermaid_mode == MermaidMode::Image {
+                    return None;
+                }
+                language = "mermaid";
+            }
+            Some(highlight_code(
+                &block.code,
+                language,
+                &code_highlighter,
+                &options,
+                &meta,
+                options.color_mode,
+            ))
+        },
+    ));
+
+    // Wrap with MarkProcessor for ==highlight== support
+    let parser = Parser::new_ext(md.content(), parser_options);
     let events = MarkProcessor::new(parser);
     let mut in_code_block = false;
     let mut code_buffer = String::new();
@@ -612,6 +736,10 @@ pub fn write_terminal<W: std::io::Write>(
     // List tracking
     let mut list_stack: Vec<Option<u64>> = Vec::new(); // None = unordered, Some(start) = ordered
 
+    // Definition list tracking (terms render bold, definitions indented below them)
+    let mut in_definition_term = false;
+    let mut in_definition = false;
+
     // Table tracking - buffer entire table for proper rendering
     let mut in_table = false;
     let mut table_rows: Vec<Vec<String>> = Vec::new(); // All rows including header
@@ -638,6 +766,7 @@ pub fn write_terminal<W: std::io::Write>(
     let mut in_mark = false;
 
     // Track hyperlinks for OSC8 terminal escape sequences
+    let link_context = LinkContext::new(options.base_path.as_deref());
     let mut in_link = false;
     let mut current_link_url = String::new();
     let mut current_link_text = String::new();
@@ -690,6 +819,7 @@ pub fn write_terminal<W: std::io::Write>(
             }
             InlineEvent::Standard(Event::End(TagEnd::CodeBlock)) => {
                 in_code_block = false;
+                let precomputed = highlighted_blocks.next_for(&code_buffer);
 
                 // Check for mermaid code blocks
                 let is_mermaid = code_language.eq_ignore_ascii_case("mermaid");
@@ -719,14 +849,17 @@ pub fn write_terminal<W: std::io::Write>(
                             wrapper.push_with_newlines(&header);
                             wrapper.newline();
                             // Render with syntax highlighting (same as regular code blocks)
-                            let highlighted = highlight_code(
-                                &code_buffer,
-                                "mermaid",
-                                &code_highlighter,
-                                &options,
-                                &meta,
-                                options.color_mode,
-                            )?;
+                            let highlighted = match precomputed {
+                                Some(highlighted) => highlighted?,
+                                None => highlight_code(
+                                    &code_buffer,
+                                    "mermaid",
+                                    &code_highlighter,
+                                    &options,
+                                    &meta,
+                                    options.color_mode,
+                                )?,
+                            };
                             wrapper.push_with_newlines(&highlighted);
                             wrapper.push_with_newlines("\n\n");
                         }
@@ -818,15 +951,18 @@ pub fn write_terminal<W: std::io::Write>(
                     wrapper.push_with_newlines(&header);
                     wrapper.newline();
 
-                    // Highlight and render code
-                    let highlighted = highlight_code(
-                        &code_buffer,
-                        &code_language,
-                        &code_highlighter,
-                        &options,
-                        &meta,
-                        options.color_mode,
-                    )?;
+                    // Highlight and render code (usually already highlighted up front)
+                    let highlighted = match precomputed {
+                        Some(highlighted) => highlighted?,
+                        None => highlight_code(
+                            &code_buffer,
+                            &code_language,
+                            &code_highlighter,
+                            &options,
+                            &meta,
+                            options.color_mode,
+                        )?,
+                    };
                     wrapper.push_with_newlines(&highlighted);
                     // highlight_code ends with a bottom padding row, add newline after it
                     // then add blank line for separation from following content
@@ -908,9 +1044,12 @@ pub fn write_terminal<W: std::io::Write>(
                 }
             }
             InlineEvent::Standard(Event::End(TagEnd::Link)) => {
+                let link_url = options
+                    .link_resolvers
+                    .resolve(&current_link_url, &link_context);
                 if in_table {
                     scope_stack.pop();
-                    let link = Link::new(current_link_text.clone(), current_link_url.clone());
+                    let link = Link::new(current_link_text.clone(), link_url);
                     current_cell.push_str(&link.to_terminal_unchecked());
                 } else {
                     // Pop link scope to get parent scopes, then query theme for link styling
@@ -947,7 +1086,7 @@ pub fn write_terminal<W: std::io::Write>(
                     // The styled text appears between the OSC8 open and close sequences.
                     wrapper.emit_styled_hyperlink(
                         &current_link_text,
-                        &current_link_url,
+                        &link_url,
                         style,
                         emit_italic,
                     );
@@ -1009,6 +1148,44 @@ pub fn write_terminal<W: std::io::Write>(
                 wrapper.newline();
             }
 
+            // Definition list handling - term on its own line, definitions indented below
+            InlineEvent::Standard(Event::Start(Tag::DefinitionList)) => {
+                // Add blank line before definition list if needed
+                if !wrapper.output().is_empty() && !wrapper.output().ends_with("\n\n") {
+                    if wrapper.output().ends_with('\n') {
+                        wrapper.newline();
+                    } else {
+                        wrapper.push_with_newlines("\n\n");
+                    }
+                }
+            }
+            InlineEvent::Standard(Event::End(TagEnd::DefinitionList)) => {
+                // Add blank line after definition list ends
+                wrapper.newline();
+            }
+            InlineEvent::Standard(Event::Start(ref tag @ Tag::DefinitionListTitle)) => {
+                in_definition_term = true;
+                if let Some(scope) = ScopeCache::global().scope_for_tag(tag) {
+                    scope_stack.push(scope);
+                }
+            }
+            InlineEvent::Standard(Event::End(TagEnd::DefinitionListTitle)) => {
+                in_definition_term = false;
+                scope_stack.pop();
+                wrapper.newline();
+            }
+            InlineEvent::Standard(Event::Start(Tag::DefinitionListDefinition)) => {
+                in_definition = true;
+                let style = prose_highlighter.base_style();
+                wrapper.emit_styled_marker("    ", style, emit_italic);
+            }
+            InlineEvent::Standard(Event::End(TagEnd::DefinitionListDefinition)) => {
+                in_definition = false;
+                if wrapper.current_col() > 0 {
+                    wrapper.newline();
+                }
+            }
+
             InlineEvent::Standard(Event::Start(Tag::Paragraph)) => {
                 // Add spacing before paragraphs inside blockquotes (except first)
                 if blockquote_depth > 0 && blockquote_has_content {
@@ -1018,6 +1195,12 @@ pub fn write_terminal<W: std::io::Write>(
                     wrapper.emit_newline_with_prefix();
                     wrapper.emit_newline_with_prefix();
                 }
+                // Later paragraphs of a definition get a blank line and the definition indent
+                if in_definition && wrapper.current_col() == 0 {
+                    wrapper.newline();
+                    let style = prose_highlighter.base_style();
+                    wrapper.emit_styled_marker("    ", style, emit_italic);
+                }
                 // Don't add extra spacing inside list items
             }
             InlineEvent::Standard(Event::End(TagEnd::Paragraph)) => {
@@ -1025,6 +1208,9 @@ pub fn write_terminal<W: std::io::Write>(
                 if just_rendered_image {
                     just_rendered_image = false;
                     // viuer positions cursor after image; no extra spacing needed
+                } else if in_definition {
+                    // Next paragraph of a loose definition re-emits the indent
+                    wrapper.newline();
                 } else if list_stack.is_empty() && blockquote_depth == 0 {
                     // Only add double newline for paragraphs outside of lists and blockquotes
                     wrapper.push_with_newlines("\n\n");
@@ -1072,7 +1258,7 @@ pub fn write_terminal<W: std::io::Write>(
                     if in_emphasis {
                         style.font_style |= FontStyle::ITALIC;
                     }
-                    if in_strong {
+                    if in_strong || in_definition_term {
                         style.font_style |= FontStyle::BOLD;
                     }
 
@@ -1136,6 +1322,7 @@ pub fn write_terminal<W: std::io::Write>(
                     &table_rows,
                     &table_alignments,
                     terminal_width,
+                    options.table_overflow,
                 ));
                 // Add blank line after table for spacing from following content
                 wrapper.push_with_newlines("\n\n");
@@ -1424,7 +1611,7 @@ fn calculate_min_column_width(rows: &[Vec<String>], col_index: usize) -> usize {
 
             // Find the longest word (space-separated)
             for word in plain_content.split_whitespace() {
-                let word_width = UnicodeWidthStr::width(word);
+                let word_width = display_width(word);
                 max_word_len = max_word_len.max(word_width);
             }
         }
@@ -1480,11 +1667,17 @@ fn strip_osc8_sequences(input: &str) -> String {
 ///   First row is treated as header.
 /// * `alignments` - Column alignment settings
 /// * `terminal_width` - Terminal width for wrapping
+/// * `overflow` - What to do when the table is wider than the terminal
 #[tracing::instrument(
     skip(rows, alignments),
     fields(row_count = rows.len(), col_count, terminal_width)
 )]
-fn render_table(rows: &[Vec<String>], alignments: &[CellAlignment], terminal_width: u16) -> String {
+fn render_table(
+    rows: &[Vec<String>],
+    alignments: &[CellAlignment],
+    terminal_width: u16,
+    overflow: TableOverflow,
+) -> String {
     use comfy_table::{Color as ComfyColor, ColumnConstraint, Width};
 
     if rows.is_empty() {
@@ -1501,18 +1694,49 @@ fn render_table(rows: &[Vec<String>], alignments: &[CellAlignment], terminal_wid
         })
         .collect();
 
-    let col_count = processed_rows.iter().map(|r| r.len()).max().unwrap_or(0);
-    tracing::Span::current().record("col_count", col_count);
+    let total_cols = processed_rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    tracing::Span::current().record("col_count", total_cols);
 
-    let mut table = Table::new();
+    // Calculate minimum column widths based on longest words to prevent mid-word breaks.
+    // This ensures that identifiers like "tool.duration_ms" won't be split.
+    let all_min_widths: Vec<usize> = (0..total_cols)
+        .map(|i| calculate_min_column_width(&processed_rows, i) + 2) // +2 for padding
+        .collect();
 
-    // Set width constraint (use pre-queried width)
-    table.set_width(terminal_width);
-    table.set_content_arrangement(ContentArrangement::Dynamic);
+    // In collapse mode, keep only the leading columns that fit unbroken
+    let col_count = match overflow {
+        TableOverflow::Collapse => fitting_column_count(&all_min_widths, terminal_width as usize),
+        TableOverflow::Wrap | TableOverflow::Scroll => total_cols,
+    };
+    let processed_rows: Vec<Vec<String>> = processed_rows
+        .into_iter()
+        .map(|row| row.into_iter().take(col_count).collect())
+        .collect();
+    let hidden_columns: Vec<String> = rows
+        .first()
+        .map(|header| {
+            header
+                .iter()
+                .skip(col_count)
+                .map(|cell| strip_control_sequences(cell).trim().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut table = Table::new();
 
     // Minimalist preset
     table.load_preset(presets::UTF8_BORDERS_ONLY);
 
+    if overflow == TableOverflow::Scroll {
+        // Natural width: no wrapping, so each row stays on one line
+        table.set_content_arrangement(ContentArrangement::Disabled);
+    } else {
+        // Set width constraint (use pre-queried width)
+        table.set_width(terminal_width);
+        table.set_content_arrangement(ContentArrangement::Dynamic);
+    }
+
     // Add header row with bold styling
     // Note: We strip inline code markers but don't convert to ANSI - comfy-table
     // doesn't handle raw ANSI injection well during content wrapping, causing
@@ -1539,18 +1763,20 @@ fn render_table(rows: &[Vec<String>], alignments: &[CellAlignment], terminal_wid
         }));
     }
 
-    // Calculate minimum column widths based on longest words to prevent mid-word breaks.
-    // This ensures that identifiers like "tool.duration_ms" won't be split.
-    // We apply LowerBoundary constraints after adding content so columns exist.
+    if overflow == TableOverflow::Scroll {
+        let rendered = table.to_string();
+        return reinsert_osc8_links(&rendered, &link_replacements);
+    }
+
+    // Apply the minimum column widths as LowerBoundary constraints after adding
+    // content so columns exist.
     //
     // Strategy:
-    // 1. Calculate the natural minimum width for each column (longest word)
+    // 1. Use the natural minimum width for each column (longest word)
     // 2. If total fits within terminal, apply as LowerBoundary constraints
     // 3. If total exceeds terminal, apply scaled-down constraints that still
     //    provide reasonable minimums to avoid the worst word breaks
-    let min_widths: Vec<usize> = (0..col_count)
-        .map(|i| calculate_min_column_width(&processed_rows, i) + 2) // +2 for padding
-        .collect();
+    let min_widths: Vec<usize> = all_min_widths[..col_count].to_vec();
 
     // Calculate total minimum width including borders and column separators
     // comfy-table uses: │ col1 │ col2 │ col3 │ which adds 1 char per column + 1 for final border
@@ -1588,8 +1814,40 @@ fn render_table(rows: &[Vec<String>], alignments: &[CellAlignment], terminal_wid
         table.set_constraints(constraints);
     }
 
-    let rendered = table.to_string();
-    reinsert_osc8_links(&rendered, &link_replacements)
+    let rendered = reinsert_osc8_links(&table.to_string(), &link_replacements);
+    if hidden_columns.is_empty() {
+        return rendered;
+    }
+
+    let note = format!(
+        "{} {} hidden to fit: {}",
+        hidden_columns.len(),
+        if hidden_columns.len() == 1 {
+            "column"
+        } else {
+            "columns"
+        },
+        hidden_columns.join(", ")
+    );
+    let note = textwrap::fill(&note, terminal_width.max(1) as usize);
+    format!("{}\n\x1b[2m{}\x1b[22m", rendered, note)
+}
+
+/// Returns how many leading columns fit in `width` without breaking words,
+/// given each column's minimum width (padding included). At least one column
+/// is always kept.
+fn fitting_column_count(min_widths: &[usize], width: usize) -> usize {
+    // Each column adds its width plus a border; the table adds one more
+    let mut used = 1;
+    let mut count = 0;
+    for min_width in min_widths {
+        used += min_width + 1;
+        if used > width {
+            break;
+        }
+        count += 1;
+    }
+    count.max(1).min(min_widths.len())
 }
 
 fn replace_osc8_with_markers(content: &str, links: &mut Vec<String>) -> String {
@@ -2368,6 +2626,26 @@ mod tests {
         assert!(plain.contains("█ Hello World"));
     }
 
+    #[test]
+    fn test_for_terminal_renders_citations() {
+        let content = r#"---
+references:
+  - key: alpha
+    title: Alpha Paper
+    author: Ada Lovelace
+---
+See [@alpha].
+"#;
+        let md: Markdown = content.into();
+        let output = for_terminal(&md, TerminalOptions::default()).unwrap();
+
+        let plain = strip_ansi_codes(&output);
+        assert!(plain.contains("See [1]."));
+        assert!(plain.contains("References"));
+        assert!(plain.contains("Ada Lovelace. Alpha Paper."));
+        assert!(!plain.contains("[@alpha]"));
+    }
+
     #[test]
     fn test_for_terminal_code_block() {
         let content = r#"# Test
@@ -3964,6 +4242,35 @@ fn main() {}
         assert!(plain.contains("- Second parent\n"));
     }
 
+    #[test]
+    fn test_for_terminal_definition_list() {
+        let md: Markdown = "Intro\n\nTerm\n: First definition\n: Second definition\n\nAfter".into();
+        let output = for_terminal(&md, TerminalOptions::default()).unwrap();
+
+        let plain = strip_ansi_codes(&output);
+        // Term on its own line, each definition indented below it, not merged into a paragraph
+        assert!(
+            plain.contains("Intro\n\nTerm\n    First definition\n    Second definition\n\nAfter"),
+            "Unexpected definition list layout:\n{}",
+            plain
+        );
+        // Term is bold
+        assert!(output.contains("\x1b[1m"));
+    }
+
+    #[test]
+    fn test_for_terminal_loose_definition_list() {
+        let md: Markdown = "Term\n\n: First paragraph\n\n  Second paragraph".into();
+        let output = for_terminal(&md, TerminalOptions::default()).unwrap();
+
+        let plain = strip_ansi_codes(&output);
+        assert!(
+            plain.contains("Term\n    First paragraph\n\n    Second paragraph\n"),
+            "Unexpected loose definition layout:\n{}",
+            plain
+        );
+    }
+
     #[test]
     fn test_for_terminal_list_with_inline_code() {
         let md: Markdown = "- Use `cargo build`\n- Run `cargo test`".into();
@@ -4649,7 +4956,7 @@ fn main() {}
         // Use width that can fit the content without mid-word breaks
         let adequate_width: u16 = 60;
 
-        let output = render_table(&rows, &alignments, adequate_width);
+        let output = render_table(&rows, &alignments, adequate_width, TableOverflow::Wrap);
         let plain = strip_ansi_codes(&output);
 
         // Every line should be <= adequate_width in display width
@@ -4733,7 +5040,7 @@ fn main() {}
         // Test with narrow width (60 chars) like the screenshot showed
         let narrow_width: u16 = 60;
 
-        let output = render_table(&rows, &alignments, narrow_width);
+        let output = render_table(&rows, &alignments, narrow_width, TableOverflow::Wrap);
         let plain = strip_ansi_codes(&output);
 
         // Check for mid-word splits by looking at line breaks
@@ -4802,7 +5109,7 @@ fn main() {}
         // Very narrow width to force issues
         let narrow_width: u16 = 40;
 
-        let output = render_table(&rows, &alignments, narrow_width);
+        let output = render_table(&rows, &alignments, narrow_width, TableOverflow::Wrap);
         let plain = strip_ansi_codes(&output);
 
         eprintln!("Table at width {}:\n{}", narrow_width, plain);
@@ -4856,7 +5163,7 @@ fn main() {}
 
         // With adequate width, markers should not cause misalignment
         let width: u16 = 70;
-        let output = render_table(&rows, &alignments, width);
+        let output = render_table(&rows, &alignments, width, TableOverflow::Wrap);
         let plain = strip_ansi_codes(&output);
 
         // The markers should be converted to ANSI (and stripped), not visible
@@ -4961,7 +5268,7 @@ fn main() {}
                 "=".repeat(60)
             );
 
-            let output = render_table(&rows, &alignments, width);
+            let output = render_table(&rows, &alignments, width, TableOverflow::Wrap);
             let plain = strip_ansi_codes(&output);
 
             for (i, line) in plain.lines().enumerate() {
@@ -5013,6 +5320,87 @@ fn main() {}
         }
     }
 
+    fn overflow_rows() -> Vec<Vec<String>> {
+        [
+            ["Field", "Description", "Example"],
+            ["tool.name", "Tool being called", "\"brave_search\""],
+            ["tool.duration_ms", "Execution time", "1234"],
+        ]
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+        .collect()
+    }
+
+    /// Collapse mode drops trailing columns that can't fit unbroken and names them
+    #[test]
+    fn test_table_collapse_drops_trailing_columns() {
+        let alignments = vec![CellAlignment::Left; 3];
+        let output = render_table(&overflow_rows(), &alignments, 40, TableOverflow::Collapse);
+        let plain = strip_ansi_codes(&output);
+
+        assert!(plain.contains("tool.duration_ms"), "{}", plain);
+        assert!(plain.contains("Description"), "{}", plain);
+        assert!(!plain.contains("brave_search"), "{}", plain);
+        assert!(
+            plain.ends_with("1 column hidden to fit: Example"),
+            "{}",
+            plain
+        );
+        for line in plain.lines() {
+            assert!(UnicodeWidthStr::width(line) <= 40, "{}", plain);
+        }
+
+        // Nothing is hidden when every column fits
+        let output = render_table(&overflow_rows(), &alignments, 60, TableOverflow::Collapse);
+        assert!(!output.contains("hidden to fit"));
+        assert!(output.contains("brave_search"));
+    }
+
+    /// Scroll mode keeps each row on one line, however narrow the terminal
+    #[test]
+    fn test_table_scroll_renders_natural_width() {
+        let alignments = vec![CellAlignment::Left; 3];
+        let output = render_table(&overflow_rows(), &alignments, 20, TableOverflow::Scroll);
+        let plain = strip_ansi_codes(&output);
+
+        assert_eq!(plain.lines().count(), 6, "{}", plain);
+        assert!(
+            plain
+                .lines()
+                .any(|line| line.contains("tool.duration_ms") && line.contains("Execution time")),
+            "{}",
+            plain
+        );
+        assert!(!plain.contains("hidden to fit"));
+    }
+
+    /// GFM alignment markers align both the header and the cells
+    #[test]
+    fn test_table_honors_alignment_markers() {
+        let md: Markdown = "| Name | Count |\n|:-----|------:|\n| a | 7 |".into();
+        let options = TerminalOptions {
+            max_width: Some(40),
+            ..Default::default()
+        };
+        let output = for_terminal(&md, options).unwrap();
+        let plain = strip_ansi_codes(&output);
+
+        let row = plain
+            .lines()
+            .find(|line| line.contains('7'))
+            .expect("data row");
+        assert!(row.trim_end().ends_with("    7 │"), "{}", plain);
+        assert!(row.starts_with("│ a "), "{}", plain);
+    }
+
+    #[test]
+    fn test_fitting_column_count() {
+        assert_eq!(fitting_column_count(&[10, 10, 10], 34), 3);
+        assert_eq!(fitting_column_count(&[10, 10, 10], 33), 2);
+        assert_eq!(fitting_column_count(&[50, 10], 20), 1);
+        assert_eq!(fitting_column_count(&[], 80), 0);
+    }
+
     // ---- Edge Case Tests ----
 
     /// Test that empty table returns empty string
@@ -7178,6 +7566,65 @@ flowchart LR
         );
     }
 
+    #[test]
+    fn test_terminal_link_resolvers_rewrite_destinations() {
+        use crate::render::resolver::SchemeResolver;
+
+        let content = "[Guide](../guide.md#setup) [Tokio](research://tokio) [Web](https://test.com)";
+        let md: Markdown = content.into();
+        let mut options = TerminalOptions {
+            base_path: Some(PathBuf::from("/docs/api")),
+            ..Default::default()
+        };
+        options
+            .link_resolvers
+            .register(SchemeResolver::new("research", "file:///library/{path}/"));
+        let output = for_terminal(&md, options).unwrap();
+
+        assert!(
+            output.contains("\x1b]8;;file:///docs/guide.md#setup\x07"),
+            "Relative link should resolve against base_path. Output: {:?}",
+            output
+        );
+        assert!(
+            output.contains("\x1b]8;;file:///library/tokio/\x07"),
+            "Custom scheme should resolve via registered resolver. Output: {:?}",
+            output
+        );
+        assert!(
+            output.contains("\x1b]8;;https://test.com\x07"),
+            "Absolute URLs should pass through unchanged. Output: {:?}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_terminal_link_resolvers_in_table() {
+        let content = "| Item |\n|------|\n| [HashMap](std::collections::HashMap) |";
+        let md: Markdown = content.into();
+        let output = for_terminal(&md, TerminalOptions::default()).unwrap();
+
+        assert!(
+            output.contains("\x1b]8;;https://doc.rust-lang.org/std/?search=collections::HashMap\x07"),
+            "Rust paths in tables should resolve to docs. Output: {:?}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_terminal_link_resolvers_empty_chain_keeps_links() {
+        use crate::render::resolver::LinkResolverChain;
+
+        let md: Markdown = "[Guide](guide.md)".into();
+        let options = TerminalOptions {
+            link_resolvers: LinkResolverChain::new(),
+            ..Default::default()
+        };
+        let output = for_terminal(&md, options).unwrap();
+
+        assert!(output.contains("\x1b]8;;guide.md\x07"));
+    }
+
     #[test]
     fn test_terminal_link_styling_preserved_in_list() {
         // Regression test: links in lists should also have styling inside OSC8
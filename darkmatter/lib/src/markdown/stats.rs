@@ -0,0 +1,261 @@
+//! Document statistics: word counts, reading time and a structural census.
+//!
+//! [`Markdown::stats`] walks the document body once and reports how much prose
+//! it contains, an estimated reading time, which languages its code blocks use,
+//! how many links and images it references, and how its headings are spread
+//! across levels. Frontmatter is not counted.
+//!
+//! Words are counted in prose only: headings, paragraphs, lists, tables and
+//! inline code. Fenced and indented code blocks and image alt text are
+//! excluded, and a word must contain at least one letter or digit, so stray
+//! punctuation such as `—` is not counted.
+//!
+//! ## Examples
+//!
+//! ```
+//! use darkmatter_lib::markdown::Markdown;
+//!
+//! let md: Markdown = "# Intro\n\nSee [the docs](https://docs.rs).\n\n```rust\nfn main() {}\n```".into();
+//! let stats = md.stats();
+//!
+//! assert_eq!(stats.words, 4);
+//! assert_eq!(stats.reading_time_minutes, 1);
+//! assert_eq!(stats.code_languages.get("rust"), Some(&1));
+//! assert_eq!(stats.links, 1);
+//! assert_eq!(stats.headings.h1, 1);
+//! ```
+
+use std::collections::BTreeMap;
+
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+use crate::markdown::Markdown;
+
+/// Reading speed used for [`DocumentStats::reading_time_minutes`].
+pub const DEFAULT_WORDS_PER_MINUTE: usize = 200;
+
+/// Number of headings at each level.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeadingCounts {
+    pub h1: usize,
+    pub h2: usize,
+    pub h3: usize,
+    pub h4: usize,
+    pub h5: usize,
+    pub h6: usize,
+}
+
+impl HeadingCounts {
+    /// Returns the number of headings across all levels.
+    pub fn total(&self) -> usize {
+        self.h1 + self.h2 + self.h3 + self.h4 + self.h5 + self.h6
+    }
+
+    fn record(&mut self, level: HeadingLevel) {
+        match level {
+            HeadingLevel::H1 => self.h1 += 1,
+            HeadingLevel::H2 => self.h2 += 1,
+            HeadingLevel::H3 => self.h3 += 1,
+            HeadingLevel::H4 => self.h4 += 1,
+            HeadingLevel::H5 => self.h5 += 1,
+            HeadingLevel::H6 => self.h6 += 1,
+        }
+    }
+}
+
+/// Statistics about a markdown document's body.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentStats {
+    /// Words of prose, excluding code blocks and image alt text.
+    pub words: usize,
+    /// Estimated reading time at [`DEFAULT_WORDS_PER_MINUTE`], rounded up.
+    ///
+    /// Zero only for a document without prose.
+    pub reading_time_minutes: usize,
+    /// Number of fenced and indented code blocks.
+    pub code_blocks: usize,
+    /// Lines of code across all code blocks.
+    pub code_lines: usize,
+    /// Code blocks per language, keyed by the lowercased first word of the
+    /// fence's info string. Blocks without a language are not listed.
+    pub code_languages: BTreeMap<String, usize>,
+    /// Number of links, including autolinks.
+    pub links: usize,
+    /// Number of images.
+    pub images: usize,
+    /// Number of headings at each level.
+    pub headings: HeadingCounts,
+}
+
+impl DocumentStats {
+    /// Returns the estimated reading time at `words_per_minute`, rounded up.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use darkmatter_lib::markdown::Markdown;
+    ///
+    /// let md: Markdown = "word ".repeat(450).as_str().into();
+    /// let stats = md.stats();
+    ///
+    /// assert_eq!(stats.reading_time_minutes, 3);
+    /// assert_eq!(stats.reading_time(300), 2);
+    /// ```
+    pub fn reading_time(&self, words_per_minute: usize) -> usize {
+        self.words.div_ceil(words_per_minute.max(1))
+    }
+
+    /// Returns the number of code blocks without a language.
+    pub fn untagged_code_blocks(&self) -> usize {
+        self.code_blocks - self.code_languages.values().sum::<usize>()
+    }
+}
+
+impl Markdown {
+    /// Computes word, reading time, code, link, image and heading statistics
+    /// for the document body.
+    ///
+    /// See the [`stats`](crate::markdown::stats) module for what is counted.
+    pub fn stats(&self) -> DocumentStats {
+        compute_stats(self.content())
+    }
+}
+
+/// Counts everything reported in [`DocumentStats`] in a single pass.
+fn compute_stats(content: &str) -> DocumentStats {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut stats = DocumentStats::default();
+    // Prose is collected with a separator at every block boundary so words in
+    // adjacent blocks are never joined, while inline markup (`**a**b`) is.
+    let mut prose = String::new();
+    let mut in_code_block = false;
+    let mut image_depth = 0usize;
+
+    for event in Parser::new_ext(content, options) {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                in_code_block = true;
+                stats.code_blocks += 1;
+                if let CodeBlockKind::Fenced(info) = kind
+                    && let Some(language) = info.split_whitespace().next()
+                {
+                    *stats
+                        .code_languages
+                        .entry(language.to_lowercase())
+                        .or_default() += 1;
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Text(text) if in_code_block => {
+                stats.code_lines += text.lines().count();
+            }
+            Event::Start(Tag::Heading { level, .. }) => stats.headings.record(level),
+            Event::Start(Tag::Link { .. }) => stats.links += 1,
+            Event::Start(Tag::Image { .. }) => {
+                stats.images += 1;
+                image_depth += 1;
+            }
+            Event::End(TagEnd::Image) => image_depth = image_depth.saturating_sub(1),
+            Event::Text(text) | Event::Code(text) if image_depth == 0 => prose.push_str(&text),
+            Event::End(
+                TagEnd::Paragraph
+                | TagEnd::Heading(_)
+                | TagEnd::Item
+                | TagEnd::TableCell
+                | TagEnd::BlockQuote(_),
+            )
+            | Event::SoftBreak
+            | Event::HardBreak => prose.push(' '),
+            _ => {}
+        }
+    }
+
+    stats.words = prose
+        .split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count();
+    stats.reading_time_minutes = stats.reading_time(DEFAULT_WORDS_PER_MINUTE);
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_prose_words_only() {
+        let content = "---\ntitle: ignored words here\n---\n# A Title\n\n\
+                       Some **bold**text and `inline code` — done.\n\n\
+                       - one\n- two\n\n\
+                       ![alt text words](cat.png)\n\n\
+                       ```sh\necho not counted\n```\n";
+        let stats = Markdown::from(content).stats();
+
+        // "A Title" + "Some boldtext and inline code done." + "one two"
+        assert_eq!(stats.words, 10);
+        assert_eq!(stats.images, 1);
+        assert_eq!(stats.code_lines, 1);
+    }
+
+    #[test]
+    fn block_boundaries_separate_words() {
+        let stats = Markdown::from("| a | b |\n|---|---|\n| c | d |\n\nend\nline").stats();
+        assert_eq!(stats.words, 6);
+    }
+
+    #[test]
+    fn reading_time_rounds_up() {
+        assert_eq!(Markdown::from("").stats().reading_time_minutes, 0);
+        assert_eq!(Markdown::from("hello").stats().reading_time_minutes, 1);
+
+        let stats = Markdown::from("word ".repeat(401).as_str()).stats();
+        assert_eq!(stats.reading_time_minutes, 3);
+        assert_eq!(stats.reading_time(0), 401);
+    }
+
+    #[test]
+    fn code_block_census() {
+        let content = "```Rust\nfn a() {}\nfn b() {}\n```\n\n\
+                       ```rust title=\"x\"\nlet x = 1;\n```\n\n\
+                       ```\nplain\n```\n\n    indented\n";
+        let stats = Markdown::from(content).stats();
+
+        assert_eq!(stats.code_blocks, 4);
+        assert_eq!(stats.code_lines, 5);
+        assert_eq!(stats.code_languages.len(), 1);
+        assert_eq!(stats.code_languages["rust"], 2);
+        assert_eq!(stats.untagged_code_blocks(), 2);
+        assert_eq!(stats.words, 0);
+    }
+
+    #[test]
+    fn links_images_and_headings() {
+        let content = "# One\n## Two\n## Three\n#### Four\n\n\
+                       [a](https://a.example) <https://b.example> \
+                       [![badge](b.svg)](https://c.example)\n";
+        let stats = Markdown::from(content).stats();
+
+        assert_eq!(stats.links, 3);
+        assert_eq!(stats.images, 1);
+        assert_eq!(
+            stats.headings,
+            HeadingCounts {
+                h1: 1,
+                h2: 2,
+                h4: 1,
+                ..HeadingCounts::default()
+            }
+        );
+        assert_eq!(stats.headings.total(), 4);
+    }
+
+    #[test]
+    fn serializes_for_json_output() {
+        let json = serde_json::to_value(Markdown::from("## Hi there").stats()).unwrap();
+        assert_eq!(json["words"], 2);
+        assert_eq!(json["headings"]["h2"], 1);
+        assert_eq!(json["code_languages"], serde_json::json!({}));
+    }
+}
@@ -2,8 +2,14 @@
 //!
 //! This module provides types that can render content to different
 //! output formats including terminal (with ANSI escape sequences),
-//! HTML, and Markdown.
+//! HTML, and Markdown, plus pluggable resolvers that rewrite link
+//! destinations before they become terminal hyperlinks.
 
 pub mod link;
+pub mod resolver;
 
 pub use link::{Link, LinkType};
+pub use resolver::{
+    LinkContext, LinkResolver, LinkResolverChain, RelativePathResolver, RustPathResolver,
+    SchemeResolver,
+};
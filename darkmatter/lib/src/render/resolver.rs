@@ -0,0 +1,529 @@
+//! Pluggable link resolution for terminal hyperlinks.
+//!
+//! Markdown links often point somewhere a terminal can't open directly:
+//! relative file paths, Rust item paths like `crate::render::Link`, or custom
+//! schemes such as `research://tokio`. A [`LinkResolver`] rewrites such a
+//! destination into a URL at render time, before it is wrapped in an OSC 8
+//! hyperlink.
+//!
+//! Resolvers are grouped into a [`LinkResolverChain`]; the first resolver
+//! that returns `Some` wins, and unresolved links are emitted unchanged.
+//!
+//! ## Examples
+//!
+//! ```
+//! use darkmatter_lib::render::resolver::{LinkContext, LinkResolverChain, SchemeResolver};
+//! use std::path::Path;
+//!
+//! let mut chain = LinkResolverChain::default();
+//! chain.register(SchemeResolver::new(
+//!     "research",
+//!     "file:///home/me/.research/library/{path}/deep_dive.md",
+//! ));
+//!
+//! let ctx = LinkContext::new(Some(Path::new("/docs")));
+//! assert_eq!(chain.resolve("https://example.com", &ctx), "https://example.com");
+//! assert_eq!(chain.resolve("guide.md#setup", &ctx), "file:///docs/guide.md#setup");
+//! assert_eq!(
+//!     chain.resolve("research://tokio", &ctx),
+//!     "file:///home/me/.research/library/tokio/deep_dive.md"
+//! );
+//! assert_eq!(
+//!     chain.resolve("serde::Deserialize", &ctx),
+//!     "https://docs.rs/serde/latest/serde/?search=Deserialize"
+//! );
+//! ```
+
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+/// Context available to resolvers while rendering a document.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinkContext<'a> {
+    /// Directory relative links are resolved against.
+    ///
+    /// If `None`, the current working directory is used.
+    pub base_path: Option<&'a Path>,
+}
+
+impl<'a> LinkContext<'a> {
+    /// Creates a context with the given base directory.
+    pub fn new(base_path: Option<&'a Path>) -> Self {
+        Self { base_path }
+    }
+}
+
+/// Rewrites a link destination into a URL a terminal can open.
+///
+/// Return `None` to leave the link to the next resolver in the chain.
+///
+/// Closures with a matching signature implement this trait, so simple
+/// resolvers don't need a dedicated type:
+///
+/// ```
+/// use darkmatter_lib::render::resolver::{LinkContext, LinkResolverChain};
+///
+/// let mut chain = LinkResolverChain::new();
+/// chain.register(|link: &str, _ctx: &LinkContext<'_>| {
+///     link.strip_prefix("issue:")
+///         .map(|id| format!("https://github.com/org/repo/issues/{id}"))
+/// });
+///
+/// let ctx = LinkContext::default();
+/// assert_eq!(chain.resolve("issue:42", &ctx), "https://github.com/org/repo/issues/42");
+/// ```
+pub trait LinkResolver: Send + Sync {
+    /// Resolves `link` into a URL, or returns `None` if this resolver doesn't apply.
+    fn resolve(&self, link: &str, ctx: &LinkContext<'_>) -> Option<String>;
+
+    /// Name used when debug-printing a chain.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+}
+
+impl<F> LinkResolver for F
+where
+    F: Fn(&str, &LinkContext<'_>) -> Option<String> + Send + Sync,
+{
+    fn resolve(&self, link: &str, ctx: &LinkContext<'_>) -> Option<String> {
+        self(link, ctx)
+    }
+}
+
+/// An ordered list of resolvers.
+///
+/// [`LinkResolverChain::default`] contains the built-in resolvers
+/// ([`RustPathResolver`] then [`RelativePathResolver`]); use
+/// [`LinkResolverChain::new`] to start from an empty chain.
+#[derive(Clone)]
+pub struct LinkResolverChain {
+    resolvers: Vec<Arc<dyn LinkResolver>>,
+}
+
+impl LinkResolverChain {
+    /// Creates an empty chain that leaves every link unchanged.
+    pub fn new() -> Self {
+        Self {
+            resolvers: Vec::new(),
+        }
+    }
+
+    /// Appends a resolver to the end of the chain.
+    ///
+    /// Resolvers are tried in registration order, so resolvers registered
+    /// after the defaults only see links the defaults didn't handle.
+    pub fn register(&mut self, resolver: impl LinkResolver + 'static) -> &mut Self {
+        self.resolvers.push(Arc::new(resolver));
+        self
+    }
+
+    /// Builder-style variant of [`register`](Self::register).
+    pub fn with_resolver(mut self, resolver: impl LinkResolver + 'static) -> Self {
+        self.register(resolver);
+        self
+    }
+
+    /// Returns the number of registered resolvers.
+    pub fn len(&self) -> usize {
+        self.resolvers.len()
+    }
+
+    /// Returns true if no resolvers are registered.
+    pub fn is_empty(&self) -> bool {
+        self.resolvers.is_empty()
+    }
+
+    /// Resolves a link with the first resolver that applies.
+    ///
+    /// Returns the link unchanged if no resolver applies.
+    pub fn resolve(&self, link: &str, ctx: &LinkContext<'_>) -> String {
+        self.resolvers
+            .iter()
+            .find_map(|resolver| resolver.resolve(link, ctx))
+            .unwrap_or_else(|| link.to_string())
+    }
+}
+
+impl Default for LinkResolverChain {
+    fn default() -> Self {
+        Self::new()
+            .with_resolver(RustPathResolver::default())
+            .with_resolver(RelativePathResolver)
+    }
+}
+
+impl fmt::Debug for LinkResolverChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.resolvers.iter().map(|r| r.name()))
+            .finish()
+    }
+}
+
+/// Returns true if the link starts with a URI scheme (`https:`, `mailto:`, ...).
+fn has_scheme(link: &str) -> bool {
+    let Some((scheme, _)) = link.split_once(':') else {
+        return false;
+    };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Resolves relative file paths into absolute `file://` URLs.
+///
+/// Paths are joined onto [`LinkContext::base_path`] (or the current working
+/// directory) and `.`/`..` components are normalized. Fragments such as
+/// `#section` are preserved. Links with a scheme, in-page anchors (`#top`),
+/// and Rust paths are ignored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RelativePathResolver;
+
+impl LinkResolver for RelativePathResolver {
+    fn resolve(&self, link: &str, ctx: &LinkContext<'_>) -> Option<String> {
+        if link.is_empty() || link.starts_with('#') || link.starts_with("//") || has_scheme(link) {
+            return None;
+        }
+
+        let (path, fragment) = match link.split_once('#') {
+            Some((path, fragment)) => (path, Some(fragment)),
+            None => (link, None),
+        };
+
+        // Joining an absolute path replaces the base; a relative base
+        // (e.g. the parent of `README.md` is "") is anchored at the cwd
+        let mut absolute = match ctx.base_path {
+            Some(base) => base.join(path),
+            None => PathBuf::from(path),
+        };
+        if !absolute.is_absolute() {
+            absolute = std::env::current_dir().ok()?.join(absolute);
+        }
+
+        let mut url = url::Url::from_file_path(normalize_path(&absolute)).ok()?;
+        url.set_fragment(fragment);
+        Some(url.to_string())
+    }
+
+    fn name(&self) -> &str {
+        "RelativePathResolver"
+    }
+}
+
+/// Removes `.` components and applies `..` components lexically.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Crates documented on doc.rust-lang.org rather than docs.rs.
+const STD_CRATES: &[&str] = &["std", "core", "alloc", "proc_macro", "test"];
+
+/// Resolves Rust item paths into documentation search URLs.
+///
+/// - `std::`, `core::`, `alloc::` paths link to doc.rust-lang.org
+/// - `crate::` paths link to docs.rs for the configured crate (if any)
+/// - `other_crate::` paths link to docs.rs for that crate
+///
+/// A trailing `()` or `!` (functions and macros) is ignored. Links use the
+/// rustdoc search page since the item kind can't be known from the path.
+///
+/// ## Examples
+///
+/// ```
+/// use darkmatter_lib::render::resolver::{LinkContext, LinkResolver, RustPathResolver};
+///
+/// let resolver = RustPathResolver::for_crate("darkmatter_lib");
+/// let ctx = LinkContext::default();
+///
+/// assert_eq!(
+///     resolver.resolve("crate::render::Link", &ctx).as_deref(),
+///     Some("https://docs.rs/darkmatter_lib/latest/darkmatter_lib/?search=render::Link")
+/// );
+/// assert_eq!(
+///     resolver.resolve("std::collections::HashMap", &ctx).as_deref(),
+///     Some("https://doc.rust-lang.org/std/?search=collections::HashMap")
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RustPathResolver {
+    crate_name: Option<String>,
+}
+
+impl RustPathResolver {
+    /// Creates a resolver that maps `crate::` paths to the given crate.
+    pub fn for_crate(crate_name: impl Into<String>) -> Self {
+        Self {
+            crate_name: Some(crate_name.into().replace('-', "_")),
+        }
+    }
+}
+
+impl LinkResolver for RustPathResolver {
+    fn resolve(&self, link: &str, _ctx: &LinkContext<'_>) -> Option<String> {
+        let path = link
+            .strip_suffix("()")
+            .or_else(|| link.strip_suffix('!'))
+            .unwrap_or(link);
+
+        let (root, rest) = path.split_once("::")?;
+        let is_ident = |s: &str| {
+            let mut chars = s.chars();
+            chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        };
+        if !is_ident(root) || !rest.split("::").all(is_ident) {
+            return None;
+        }
+
+        let crate_name = match root {
+            "self" | "super" => return None,
+            "crate" => self.crate_name.as_deref()?,
+            root => root,
+        };
+
+        if STD_CRATES.contains(&crate_name) {
+            Some(format!(
+                "https://doc.rust-lang.org/{crate_name}/?search={rest}"
+            ))
+        } else {
+            Some(format!(
+                "https://docs.rs/{crate_name}/latest/{crate_name}/?search={rest}"
+            ))
+        }
+    }
+
+    fn name(&self) -> &str {
+        "RustPathResolver"
+    }
+}
+
+/// Resolves a custom URI scheme using a URL template.
+///
+/// The template's `{path}` placeholder is replaced with everything after
+/// `scheme://` (or `scheme:`), so `research://tokio` with the template
+/// `file:///home/me/.research/library/{path}/` becomes
+/// `file:///home/me/.research/library/tokio/`.
+#[derive(Debug, Clone)]
+pub struct SchemeResolver {
+    scheme: String,
+    template: String,
+}
+
+impl SchemeResolver {
+    /// Creates a resolver for `scheme` (without the trailing `:`).
+    pub fn new(scheme: impl Into<String>, template: impl Into<String>) -> Self {
+        Self {
+            scheme: scheme.into(),
+            template: template.into(),
+        }
+    }
+
+    /// The scheme this resolver handles.
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+}
+
+impl LinkResolver for SchemeResolver {
+    fn resolve(&self, link: &str, _ctx: &LinkContext<'_>) -> Option<String> {
+        let (scheme, rest) = link.split_once(':')?;
+        if !scheme.eq_ignore_ascii_case(&self.scheme) {
+            return None;
+        }
+        let path = rest.strip_prefix("//").unwrap_or(rest);
+        Some(self.template.replace("{path}", path))
+    }
+
+    fn name(&self) -> &str {
+        &self.scheme
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx(base: &Path) -> LinkContext<'_> {
+        LinkContext::new(Some(base))
+    }
+
+    #[test]
+    fn test_has_scheme() {
+        assert!(has_scheme("https://example.com"));
+        assert!(has_scheme("mailto:me@example.com"));
+        assert!(has_scheme("git+ssh://host/repo"));
+        assert!(!has_scheme("docs/guide.md"));
+        assert!(!has_scheme("1abc:foo"));
+        assert!(!has_scheme("no scheme: here"));
+    }
+
+    #[test]
+    fn test_relative_path_resolver() {
+        let base = Path::new("/docs/guide");
+        let resolver = RelativePathResolver;
+
+        assert_eq!(
+            resolver.resolve("intro.md", &ctx(base)).as_deref(),
+            Some("file:///docs/guide/intro.md")
+        );
+        assert_eq!(
+            resolver
+                .resolve("./../api/ref.md#types", &ctx(base))
+                .as_deref(),
+            Some("file:///docs/api/ref.md#types")
+        );
+        assert_eq!(
+            resolver.resolve("/etc/hosts", &ctx(base)).as_deref(),
+            Some("file:///etc/hosts")
+        );
+        assert_eq!(
+            resolver.resolve("my notes.md", &ctx(base)).as_deref(),
+            Some("file:///docs/guide/my%20notes.md")
+        );
+    }
+
+    #[test]
+    fn test_relative_path_resolver_relative_base_uses_cwd() {
+        let cwd = std::env::current_dir().unwrap();
+        let expected = url::Url::from_file_path(cwd.join("notes.md"))
+            .unwrap()
+            .to_string();
+
+        assert_eq!(
+            RelativePathResolver.resolve("notes.md", &ctx(Path::new(""))),
+            Some(expected.clone())
+        );
+        assert_eq!(
+            RelativePathResolver.resolve("notes.md", &LinkContext::default()),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_relative_path_resolver_skips_non_paths() {
+        let base = Path::new("/docs");
+        let resolver = RelativePathResolver;
+
+        for link in [
+            "",
+            "#heading",
+            "https://example.com",
+            "mailto:me@example.com",
+            "research://tokio",
+            "crate::render::Link",
+            "//cdn.example.com/lib.js",
+        ] {
+            assert_eq!(resolver.resolve(link, &ctx(base)), None, "link: {link}");
+        }
+    }
+
+    #[test]
+    fn test_rust_path_resolver() {
+        let resolver = RustPathResolver::default();
+        let ctx = LinkContext::default();
+
+        assert_eq!(
+            resolver.resolve("serde::de::Deserialize", &ctx).as_deref(),
+            Some("https://docs.rs/serde/latest/serde/?search=de::Deserialize")
+        );
+        assert_eq!(
+            resolver.resolve("core::mem::swap()", &ctx).as_deref(),
+            Some("https://doc.rust-lang.org/core/?search=mem::swap")
+        );
+        assert_eq!(
+            resolver.resolve("std::println!", &ctx).as_deref(),
+            Some("https://doc.rust-lang.org/std/?search=println")
+        );
+
+        // `crate::` needs a configured crate name
+        assert_eq!(resolver.resolve("crate::render::Link", &ctx), None);
+        assert_eq!(
+            RustPathResolver::for_crate("darkmatter-lib")
+                .resolve("crate::render::Link", &ctx)
+                .as_deref(),
+            Some("https://docs.rs/darkmatter_lib/latest/darkmatter_lib/?search=render::Link")
+        );
+    }
+
+    #[test]
+    fn test_rust_path_resolver_skips_non_paths() {
+        let resolver = RustPathResolver::default();
+        let ctx = LinkContext::default();
+
+        for link in [
+            "https://example.com",
+            "docs/guide.md",
+            "self::Link",
+            "super::Link",
+            "serde::",
+            "::serde",
+            "a::b c",
+            "C::\\Windows",
+        ] {
+            assert_eq!(resolver.resolve(link, &ctx), None, "link: {link}");
+        }
+    }
+
+    #[test]
+    fn test_scheme_resolver() {
+        let resolver = SchemeResolver::new("research", "file:///lib/{path}/deep_dive.md");
+        let ctx = LinkContext::default();
+
+        assert_eq!(
+            resolver.resolve("research://tokio", &ctx).as_deref(),
+            Some("file:///lib/tokio/deep_dive.md")
+        );
+        assert_eq!(
+            resolver.resolve("Research:serde", &ctx).as_deref(),
+            Some("file:///lib/serde/deep_dive.md")
+        );
+        assert_eq!(resolver.resolve("https://example.com", &ctx), None);
+        assert_eq!(resolver.scheme(), "research");
+    }
+
+    #[test]
+    fn test_chain_order_and_fallback() {
+        let ctx = LinkContext::new(Some(Path::new("/docs")));
+
+        let empty = LinkResolverChain::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.resolve("guide.md", &ctx), "guide.md");
+
+        let chain = LinkResolverChain::new()
+            .with_resolver(|link: &str, _: &LinkContext<'_>| {
+                (link == "guide.md").then(|| "first".to_string())
+            })
+            .with_resolver(RelativePathResolver);
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain.resolve("guide.md", &ctx), "first");
+        assert_eq!(chain.resolve("other.md", &ctx), "file:///docs/other.md");
+        assert_eq!(
+            chain.resolve("https://example.com", &ctx),
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    fn test_default_chain_debug() {
+        let chain = LinkResolverChain::default()
+            .with_resolver(SchemeResolver::new("research", "file:///{path}"));
+        assert_eq!(
+            format!("{chain:?}"),
+            r#"["RustPathResolver", "RelativePathResolver", "research"]"#
+        );
+    }
+}
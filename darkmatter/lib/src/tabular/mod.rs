@@ -0,0 +1,513 @@
+//! CSV/TSV parsing and preview rendering.
+//!
+//! This module parses delimited data files, infers a type for each column,
+//! and converts the result into either a GitHub Flavored Markdown table (so
+//! it can be rendered with the same themed table layout as markdown
+//! documents) or JSON.
+//!
+//! ## Examples
+//!
+//! ```
+//! use darkmatter_lib::tabular::{ColumnType, Delimiter, TabularData};
+//!
+//! let data = TabularData::parse("name,age\nAlice,30\nBob,25\n", Delimiter::Comma).unwrap();
+//! assert_eq!(data.headers, vec!["name", "age"]);
+//! assert_eq!(data.column_types, vec![ColumnType::Text, ColumnType::Integer]);
+//!
+//! let table = data.to_markdown(None);
+//! assert!(table.starts_with("| name | age |\n| --- | ---: |\n"));
+//! ```
+
+use serde::Serialize;
+use std::path::Path;
+use thiserror::Error;
+
+/// Errors that can occur when parsing delimited data.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum TabularError {
+    /// The input contains no header row.
+    #[error("No data found (expected a header row)")]
+    Empty,
+
+    /// A quoted field was not closed before the end of input.
+    #[error("Unterminated quoted field starting on line {line}")]
+    UnterminatedQuote {
+        /// 1-based line number where the quoted field starts.
+        line: usize,
+    },
+}
+
+/// Field delimiter of a data file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Comma-separated values (`.csv`).
+    Comma,
+    /// Tab-separated values (`.tsv`, `.tab`).
+    Tab,
+}
+
+impl Delimiter {
+    /// Returns the delimiter character.
+    pub fn as_char(self) -> char {
+        match self {
+            Delimiter::Comma => ',',
+            Delimiter::Tab => '\t',
+        }
+    }
+
+    /// Detects the delimiter from a file extension (case-insensitive).
+    ///
+    /// Returns `None` for paths that are not CSV/TSV files.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use darkmatter_lib::tabular::Delimiter;
+    /// use std::path::Path;
+    ///
+    /// assert_eq!(Delimiter::from_path(Path::new("data.CSV")), Some(Delimiter::Comma));
+    /// assert_eq!(Delimiter::from_path(Path::new("data.tsv")), Some(Delimiter::Tab));
+    /// assert_eq!(Delimiter::from_path(Path::new("README.md")), None);
+    /// ```
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "csv" => Some(Delimiter::Comma),
+            "tsv" | "tab" => Some(Delimiter::Tab),
+            _ => None,
+        }
+    }
+}
+
+/// Inferred type of a column.
+///
+/// Inference only considers non-empty cells; a column is only numeric or
+/// boolean if every non-empty cell parses as such.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnType {
+    /// Every cell is empty.
+    Empty,
+    /// `true`/`false`/`yes`/`no` (case-insensitive).
+    Boolean,
+    /// Whole numbers.
+    Integer,
+    /// Decimal numbers (integers mixed with decimals also infer as float).
+    Float,
+    /// Anything else.
+    Text,
+}
+
+impl ColumnType {
+    /// Returns true for integer and float columns.
+    pub fn is_numeric(self) -> bool {
+        matches!(self, ColumnType::Integer | ColumnType::Float)
+    }
+
+    /// Infers the type of a column from its cells.
+    pub fn infer<'a>(cells: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut inferred = ColumnType::Empty;
+        for cell in cells.into_iter().map(str::trim).filter(|c| !c.is_empty()) {
+            let cell_type = if parse_bool(cell).is_some() {
+                ColumnType::Boolean
+            } else if cell.parse::<i64>().is_ok() {
+                ColumnType::Integer
+            } else if parse_float(cell).is_some() {
+                ColumnType::Float
+            } else {
+                return ColumnType::Text;
+            };
+
+            inferred = match (inferred, cell_type) {
+                (ColumnType::Empty, t) => t,
+                (a, b) if a == b => a,
+                (ColumnType::Integer, ColumnType::Float)
+                | (ColumnType::Float, ColumnType::Integer) => ColumnType::Float,
+                _ => return ColumnType::Text,
+            };
+        }
+        inferred
+    }
+}
+
+fn parse_bool(cell: &str) -> Option<bool> {
+    match cell.to_ascii_lowercase().as_str() {
+        "true" | "yes" => Some(true),
+        "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parses a float, rejecting the textual `inf`/`nan` forms `f64` accepts.
+fn parse_float(cell: &str) -> Option<f64> {
+    if !cell.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    cell.parse::<f64>().ok().filter(|f| f.is_finite())
+}
+
+/// Parsed delimited data with inferred column types.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabularData {
+    /// Column names from the first row.
+    ///
+    /// Rows wider than the header get generated names (`column_4`, ...).
+    pub headers: Vec<String>,
+    /// Data rows, each padded to the number of headers.
+    pub rows: Vec<Vec<String>>,
+    /// Inferred type of each column.
+    pub column_types: Vec<ColumnType>,
+}
+
+impl TabularData {
+    /// Parses delimited content.
+    ///
+    /// Supports RFC 4180 quoting: quoted fields may contain delimiters,
+    /// newlines, and doubled quotes (`""`). A leading UTF-8 byte order mark
+    /// and blank lines are ignored.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`TabularError::Empty`] if there is no header row, or
+    /// [`TabularError::UnterminatedQuote`] if a quoted field is not closed.
+    pub fn parse(content: &str, delimiter: Delimiter) -> Result<Self, TabularError> {
+        let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+        let mut records = parse_records(content, delimiter.as_char())?.into_iter();
+
+        let mut headers = records.next().ok_or(TabularError::Empty)?;
+        let mut rows: Vec<Vec<String>> = records.collect();
+
+        let width = rows
+            .iter()
+            .map(Vec::len)
+            .chain(std::iter::once(headers.len()))
+            .max()
+            .unwrap_or(0);
+        for i in headers.len()..width {
+            headers.push(format!("column_{}", i + 1));
+        }
+        for row in &mut rows {
+            row.resize(width, String::new());
+        }
+
+        let column_types = (0..width)
+            .map(|i| ColumnType::infer(rows.iter().map(|row| row[i].as_str())))
+            .collect();
+
+        Ok(Self {
+            headers,
+            rows,
+            column_types,
+        })
+    }
+
+    /// Number of data rows (excluding the header).
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Converts the data to a GitHub Flavored Markdown table.
+    ///
+    /// Numeric columns are right-aligned. When `max_rows` truncates the data,
+    /// a note with the total row count follows the table. Cell content is
+    /// escaped so it renders literally.
+    pub fn to_markdown(&self, max_rows: Option<usize>) -> String {
+        let shown = max_rows.map_or(self.rows.len(), |max| max.min(self.rows.len()));
+
+        let mut out = String::new();
+        push_markdown_row(&mut out, &self.headers);
+
+        let separators: Vec<String> = self
+            .column_types
+            .iter()
+            .map(|t| if t.is_numeric() { "---:" } else { "---" }.to_string())
+            .collect();
+        out.push_str(&format!("| {} |\n", separators.join(" | ")));
+
+        for row in &self.rows[..shown] {
+            push_markdown_row(&mut out, row);
+        }
+
+        if shown < self.rows.len() {
+            out.push_str(&format!(
+                "\n_Showing {} of {} rows._\n",
+                shown,
+                self.rows.len()
+            ));
+        }
+
+        out
+    }
+
+    /// Converts the data to a JSON array of objects keyed by header.
+    ///
+    /// Values are typed by their column: numbers and booleans are emitted as
+    /// JSON numbers and booleans, empty cells as `null`, and everything else
+    /// as strings.
+    pub fn to_json(&self) -> serde_json::Value {
+        let records = self
+            .rows
+            .iter()
+            .map(|row| {
+                let object = self
+                    .headers
+                    .iter()
+                    .zip(row)
+                    .zip(&self.column_types)
+                    .map(|((header, cell), column_type)| {
+                        (header.clone(), typed_value(cell, *column_type))
+                    })
+                    .collect::<serde_json::Map<_, _>>();
+                serde_json::Value::Object(object)
+            })
+            .collect();
+        serde_json::Value::Array(records)
+    }
+}
+
+/// Converts a cell into a JSON value according to its column type.
+fn typed_value(cell: &str, column_type: ColumnType) -> serde_json::Value {
+    let trimmed = cell.trim();
+    if trimmed.is_empty() {
+        return serde_json::Value::Null;
+    }
+
+    let typed = match column_type {
+        ColumnType::Boolean => parse_bool(trimmed).map(serde_json::Value::Bool),
+        ColumnType::Integer => trimmed.parse::<i64>().ok().map(Into::into),
+        ColumnType::Float => parse_float(trimmed)
+            .and_then(serde_json::Number::from_f64)
+            .map(serde_json::Value::Number),
+        ColumnType::Empty | ColumnType::Text => None,
+    };
+    typed.unwrap_or_else(|| serde_json::Value::String(cell.to_string()))
+}
+
+/// Appends one escaped markdown table row.
+fn push_markdown_row(out: &mut String, cells: &[String]) {
+    let escaped: Vec<String> = cells.iter().map(|c| escape_markdown_cell(c)).collect();
+    out.push_str(&format!("| {} |\n", escaped.join(" | ")));
+}
+
+/// Escapes a cell so markdown renders it literally on a single line.
+fn escape_markdown_cell(cell: &str) -> String {
+    let mut escaped = String::with_capacity(cell.len());
+    for c in cell.trim().chars() {
+        match c {
+            '\r' => {}
+            '\n' => escaped.push(' '),
+            '\\' | '|' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '~' | '#' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Splits content into records of fields, honoring quotes.
+fn parse_records(content: &str, delimiter: char) -> Result<Vec<Vec<String>>, TabularError> {
+    let mut records = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut quote_start_line = 0;
+    let mut line = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => {
+                in_quotes = true;
+                quote_start_line = line;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                line += 1;
+                record.push(std::mem::take(&mut field));
+                // Skip blank lines
+                if !(record.len() == 1 && record[0].is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                } else {
+                    record.clear();
+                }
+            }
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(TabularError::UnterminatedQuote {
+            line: quote_start_line,
+        });
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_delimiter_from_path() {
+        assert_eq!(
+            Delimiter::from_path(Path::new("a/b/data.csv")),
+            Some(Delimiter::Comma)
+        );
+        assert_eq!(
+            Delimiter::from_path(Path::new("data.TSV")),
+            Some(Delimiter::Tab)
+        );
+        assert_eq!(
+            Delimiter::from_path(Path::new("data.tab")),
+            Some(Delimiter::Tab)
+        );
+        assert_eq!(Delimiter::from_path(Path::new("data.md")), None);
+        assert_eq!(Delimiter::from_path(Path::new("csv")), None);
+    }
+
+    #[test]
+    fn test_parse_simple_csv() {
+        let data = TabularData::parse("a,b\n1,2\n3,4\n", Delimiter::Comma).unwrap();
+        assert_eq!(data.headers, vec!["a", "b"]);
+        assert_eq!(data.rows, vec![vec!["1", "2"], vec!["3", "4"]]);
+        assert_eq!(data.row_count(), 2);
+    }
+
+    #[test]
+    fn test_parse_tsv() {
+        let data = TabularData::parse("a\tb\nx,y\tz\n", Delimiter::Tab).unwrap();
+        assert_eq!(data.headers, vec!["a", "b"]);
+        assert_eq!(data.rows, vec![vec!["x,y", "z"]]);
+    }
+
+    #[test]
+    fn test_parse_quoted_fields() {
+        let content = "name,quote\r\n\"Smith, J\",\"He said \"\"hi\"\"\nthen left\"\r\n";
+        let data = TabularData::parse(content, Delimiter::Comma).unwrap();
+        assert_eq!(
+            data.rows,
+            vec![vec!["Smith, J", "He said \"hi\"\nthen left"]]
+        );
+    }
+
+    #[test]
+    fn test_parse_strips_bom_and_blank_lines() {
+        let data = TabularData::parse("\u{feff}a,b\n\n1,2\n\n", Delimiter::Comma).unwrap();
+        assert_eq!(data.headers, vec!["a", "b"]);
+        assert_eq!(data.rows, vec![vec!["1", "2"]]);
+    }
+
+    #[test]
+    fn test_parse_ragged_rows() {
+        let data = TabularData::parse("a,b\n1\n1,2,3\n", Delimiter::Comma).unwrap();
+        assert_eq!(data.headers, vec!["a", "b", "column_3"]);
+        assert_eq!(data.rows, vec![vec!["1", "", ""], vec!["1", "2", "3"]]);
+    }
+
+    #[test]
+    fn test_parse_no_trailing_newline() {
+        let data = TabularData::parse("a,b\n1,", Delimiter::Comma).unwrap();
+        assert_eq!(data.rows, vec![vec!["1", ""]]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            TabularData::parse("", Delimiter::Comma),
+            Err(TabularError::Empty)
+        );
+        assert_eq!(
+            TabularData::parse("a,b\n1,\"open\n", Delimiter::Comma),
+            Err(TabularError::UnterminatedQuote { line: 2 })
+        );
+    }
+
+    #[test]
+    fn test_column_type_inference() {
+        assert_eq!(ColumnType::infer(["1", " 2 ", "-3"]), ColumnType::Integer);
+        assert_eq!(ColumnType::infer(["1", "2.5"]), ColumnType::Float);
+        assert_eq!(ColumnType::infer(["1e3", ""]), ColumnType::Float);
+        assert_eq!(ColumnType::infer(["true", "No"]), ColumnType::Boolean);
+        assert_eq!(ColumnType::infer(["1", "abc"]), ColumnType::Text);
+        assert_eq!(ColumnType::infer(["inf", "NaN"]), ColumnType::Text);
+        assert_eq!(ColumnType::infer(["true", "1"]), ColumnType::Text);
+        assert_eq!(ColumnType::infer(["", " "]), ColumnType::Empty);
+    }
+
+    #[test]
+    fn test_to_markdown_aligns_numeric_columns() {
+        let data =
+            TabularData::parse("item,qty,price\npen,2,1.50\nink,1,3\n", Delimiter::Comma).unwrap();
+        assert_eq!(
+            data.to_markdown(None),
+            "| item | qty | price |\n\
+             | --- | ---: | ---: |\n\
+             | pen | 2 | 1.50 |\n\
+             | ink | 1 | 3 |\n"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_truncates_rows() {
+        let data = TabularData::parse("n\n1\n2\n3\n", Delimiter::Comma).unwrap();
+        let md = data.to_markdown(Some(2));
+        assert!(md.contains("| 2 |"));
+        assert!(!md.contains("| 3 |"));
+        assert!(md.ends_with("\n_Showing 2 of 3 rows._\n"));
+
+        // A limit above the row count shows everything without a note
+        assert!(!data.to_markdown(Some(10)).contains("Showing"));
+    }
+
+    #[test]
+    fn test_to_markdown_escapes_cells() {
+        let data = TabularData::parse("expr\n\"a|b *c* `d`\nnext\"\n", Delimiter::Comma).unwrap();
+        assert!(
+            data.to_markdown(None)
+                .contains("| a\\|b \\*c\\* \\`d\\` next |")
+        );
+    }
+
+    #[test]
+    fn test_to_json_typed_values() {
+        let data = TabularData::parse(
+            "name,age,score,active\nAlice,30,9.5,yes\nBob,,7,false\n",
+            Delimiter::Comma,
+        )
+        .unwrap();
+        assert_eq!(
+            data.to_json(),
+            json!([
+                {"name": "Alice", "age": 30, "score": 9.5, "active": true},
+                {"name": "Bob", "age": null, "score": 7.0, "active": false}
+            ])
+        );
+    }
+}
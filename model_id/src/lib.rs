@@ -22,8 +22,12 @@ use syn::{Data, DeriveInput, Fields, LitStr, parse_macro_input};
 /// This macro generates:
 /// - `model_id(&self) -> &str` - Returns the wire-format model ID
 /// - `FromStr` implementation - Parses wire IDs back to variants
+/// - `parse_fuzzy(s)` - Tolerant parsing (case, separators, provider prefix)
 /// - `ALL: &'static [Self]` - Array of all unit variants (excludes Bespoke)
 ///
+/// Parse failures return `UnknownModelIdError` with ranked "did you mean"
+/// suggestions drawn from `ALL`.
+///
 /// ## Optional Metadata Lookup
 ///
 /// Add a `#[model_id_metadata(...)]` attribute to generate a `metadata()` method:
@@ -62,6 +66,7 @@ pub fn derive_model_id(input: TokenStream) -> TokenStream {
 
     let mut model_id_arms = Vec::new();
     let mut from_str_arms = Vec::new();
+    let mut exact_arms = Vec::new();
     let mut all_variants = Vec::new();
 
     for v in &data_enum.variants {
@@ -105,6 +110,7 @@ pub fn derive_model_id(input: TokenStream) -> TokenStream {
 
         model_id_arms.push(quote! { Self::#v_ident => #canonical });
         from_str_arms.push(quote! { #canonical => Ok(Self::#v_ident) });
+        exact_arms.push(quote! { #canonical => Some(Self::#v_ident) });
         all_variants.push(quote! { Self::#v_ident });
     }
 
@@ -116,12 +122,7 @@ pub fn derive_model_id(input: TokenStream) -> TokenStream {
     let from_str_fallback = if has_bespoke {
         quote! { _ => Ok(Self::Bespoke(s.to_string())) }
     } else {
-        quote! {
-            _ => Err(UnknownModelIdError {
-                model_id: s.to_string(),
-                enum_name: stringify!(#enum_ident).to_string(),
-            })
-        }
+        quote! { _ => Err(Self::unknown_model_id(s)) }
     };
 
     // Generate optional metadata method if configured
@@ -152,11 +153,21 @@ pub fn derive_model_id(input: TokenStream) -> TokenStream {
             pub model_id: String,
             /// The enum type that failed to parse.
             pub enum_name: String,
+            /// Known model IDs similar to `model_id`, best match first.
+            pub suggestions: Vec<String>,
         }
 
         impl std::fmt::Display for UnknownModelIdError {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "unknown model ID '{}' for {}", self.model_id, self.enum_name)
+                write!(f, "unknown model ID '{}' for {}", self.model_id, self.enum_name)?;
+                match self.suggestions.as_slice() {
+                    [] => Ok(()),
+                    [only] => write!(f, "; did you mean '{}'?", only),
+                    many => {
+                        let quoted: Vec<String> = many.iter().map(|s| format!("'{}'", s)).collect();
+                        write!(f, "; did you mean one of {}?", quoted.join(", "))
+                    }
+                }
             }
         }
 
@@ -181,6 +192,133 @@ pub fn derive_model_id(input: TokenStream) -> TokenStream {
                 }
             }
 
+            /// Parses a model ID, tolerating common spelling differences.
+            ///
+            /// Tries, in order:
+            /// 1. The exact canonical ID
+            /// 2. A case- and separator-insensitive match (`GPT_4o` finds `gpt-4o`)
+            /// 3. A match ignoring the provider prefix (`openai/gpt-4o` finds
+            ///    `gpt-4o` and vice versa)
+            ///
+            /// Unlike `FromStr`, this never falls back to `Bespoke`.
+            ///
+            /// ## Errors
+            ///
+            /// Returns `UnknownModelIdError` if no known model matches, or if a
+            /// relaxed match is ambiguous. The error's `suggestions` lists the
+            /// closest known IDs (or the ambiguous candidates).
+            pub fn parse_fuzzy(s: &str) -> Result<Self, UnknownModelIdError> {
+                let input = s.trim();
+                if let Some(model) = Self::from_exact_model_id(input) {
+                    return Ok(model);
+                }
+
+                let ids: Vec<&'static str> = Self::ALL.iter().map(|m| m.model_id()).collect();
+
+                let key = Self::model_id_fuzzy_key(input);
+                let mut matches: Vec<&'static str> = ids
+                    .iter()
+                    .copied()
+                    .filter(|id| Self::model_id_fuzzy_key(id) == key)
+                    .collect();
+
+                if matches.is_empty() {
+                    let model_key = Self::model_id_fuzzy_key(Self::model_id_without_provider(input));
+                    matches = ids
+                        .iter()
+                        .copied()
+                        .filter(|id| {
+                            Self::model_id_fuzzy_key(Self::model_id_without_provider(id)) == model_key
+                        })
+                        .collect();
+                }
+
+                match matches.as_slice() {
+                    [id] => Self::from_exact_model_id(id).ok_or_else(|| Self::unknown_model_id(input)),
+                    [] => Err(Self::unknown_model_id(input)),
+                    ambiguous => Err(UnknownModelIdError {
+                        model_id: input.to_string(),
+                        enum_name: stringify!(#enum_ident).to_string(),
+                        suggestions: ambiguous.iter().map(|id| id.to_string()).collect(),
+                    }),
+                }
+            }
+
+            /// Maps a canonical model ID to its unit variant.
+            fn from_exact_model_id(id: &str) -> Option<Self> {
+                match id {
+                    #(#exact_arms,)*
+                    _ => None,
+                }
+            }
+
+            /// Builds an `UnknownModelIdError` with ranked suggestions.
+            fn unknown_model_id(s: &str) -> UnknownModelIdError {
+                /// Maximum number of suggestions to include.
+                const MAX_SUGGESTIONS: usize = 3;
+
+                let key = Self::model_id_fuzzy_key(Self::model_id_without_provider(s));
+                let threshold = (key.chars().count() / 3).max(2);
+
+                // Rank by (prefix match first, edit distance, id)
+                let mut ranked: Vec<(bool, usize, &'static str)> = Self::ALL
+                    .iter()
+                    .map(|m| m.model_id())
+                    .filter_map(|id| {
+                        let candidate = Self::model_id_fuzzy_key(Self::model_id_without_provider(id));
+                        let is_prefix = !key.is_empty()
+                            && (candidate.starts_with(&key) || key.starts_with(&candidate));
+                        let distance = Self::model_id_edit_distance(&key, &candidate);
+                        (is_prefix || distance <= threshold).then_some((!is_prefix, distance, id))
+                    })
+                    .collect();
+                ranked.sort();
+
+                UnknownModelIdError {
+                    model_id: s.to_string(),
+                    enum_name: stringify!(#enum_ident).to_string(),
+                    suggestions: ranked
+                        .into_iter()
+                        .take(MAX_SUGGESTIONS)
+                        .map(|(_, _, id)| id.to_string())
+                        .collect(),
+                }
+            }
+
+            /// Lowercases and collapses separators (`.`, `_`, `-`, `:`, spaces) to `-`.
+            fn model_id_fuzzy_key(s: &str) -> String {
+                let mut key = String::with_capacity(s.len());
+                for c in s.trim().chars() {
+                    if c.is_alphanumeric() || c == '/' {
+                        key.extend(c.to_lowercase());
+                    } else if !key.is_empty() && !key.ends_with('-') {
+                        key.push('-');
+                    }
+                }
+                key.trim_end_matches('-').to_string()
+            }
+
+            /// Strips a leading `provider/` or `provider:` prefix.
+            fn model_id_without_provider(s: &str) -> &str {
+                s.split_once(['/', ':']).map_or(s, |(_, model)| model)
+            }
+
+            /// Levenshtein distance between two strings.
+            fn model_id_edit_distance(a: &str, b: &str) -> usize {
+                let b: Vec<char> = b.chars().collect();
+                let mut prev: Vec<usize> = (0..=b.len()).collect();
+                for (i, ca) in a.chars().enumerate() {
+                    let mut curr = Vec::with_capacity(b.len() + 1);
+                    curr.push(i + 1);
+                    for (j, cb) in b.iter().enumerate() {
+                        let substitution = prev[j] + usize::from(ca != *cb);
+                        curr.push(substitution.min(prev[j + 1] + 1).min(curr[j] + 1));
+                    }
+                    prev = curr;
+                }
+                prev[b.len()]
+            }
+
             #metadata_method
         }
 
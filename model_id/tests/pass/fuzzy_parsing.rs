@@ -0,0 +1,92 @@
+use model_id::ModelId;
+
+#[derive(ModelId, Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum ProviderOpenAi {
+    #[model_id("gpt-4o")]
+    Gpt4o,
+    #[model_id("gpt-4o-mini")]
+    Gpt4oMini,
+    #[model_id("o3-mini")]
+    O3Mini,
+    Bespoke(String),
+}
+
+// Each derive emits its own `UnknownModelIdError`, so keep enums in separate modules
+mod strict {
+    use model_id::ModelId;
+
+    #[derive(ModelId, Debug, Clone, PartialEq, Eq)]
+    pub enum StrictProvider {
+        ModelA,
+        ModelB,
+        Other,
+    }
+}
+
+use strict::StrictProvider;
+
+fn main() {
+    // Exact match
+    assert_eq!(
+        ProviderOpenAi::parse_fuzzy("gpt-4o").unwrap(),
+        ProviderOpenAi::Gpt4o
+    );
+
+    // Case, whitespace, and separator differences
+    assert_eq!(
+        ProviderOpenAi::parse_fuzzy("  GPT-4o  ").unwrap(),
+        ProviderOpenAi::Gpt4o
+    );
+    assert_eq!(
+        ProviderOpenAi::parse_fuzzy("gpt_4o_mini").unwrap(),
+        ProviderOpenAi::Gpt4oMini
+    );
+    assert_eq!(
+        ProviderOpenAi::parse_fuzzy("O3 Mini").unwrap(),
+        ProviderOpenAi::O3Mini
+    );
+
+    // Provider prefix is ignored for primary providers
+    assert_eq!(
+        ProviderOpenAi::parse_fuzzy("openai/gpt-4o").unwrap(),
+        ProviderOpenAi::Gpt4o
+    );
+    assert_eq!(
+        ProviderOpenAi::parse_fuzzy("OpenAI:o3-mini").unwrap(),
+        ProviderOpenAi::O3Mini
+    );
+
+    // parse_fuzzy never falls back to Bespoke, and suggests close matches
+    let err = ProviderOpenAi::parse_fuzzy("gpt-4").unwrap_err();
+    assert_eq!(err.model_id, "gpt-4");
+    assert_eq!(err.enum_name, "ProviderOpenAi");
+    assert_eq!(err.suggestions, vec!["gpt-4o", "gpt-4o-mini"]);
+    assert_eq!(
+        err.to_string(),
+        "unknown model ID 'gpt-4' for ProviderOpenAi; did you mean one of 'gpt-4o', 'gpt-4o-mini'?"
+    );
+
+    // FromStr still falls back to Bespoke
+    let bespoke: ProviderOpenAi = "gpt-4".parse().unwrap();
+    assert_eq!(bespoke, ProviderOpenAi::Bespoke("gpt-4".to_string()));
+
+    // FromStr errors include suggestions when there is no Bespoke variant
+    let err = "modelc".parse::<StrictProvider>().unwrap_err();
+    assert_eq!(err.suggestions, vec!["modela", "modelb"]);
+
+    let err = StrictProvider::parse_fuzzy("othr").unwrap_err();
+    assert_eq!(err.suggestions, vec!["other"]);
+    assert_eq!(
+        err.to_string(),
+        "unknown model ID 'othr' for StrictProvider; did you mean 'other'?"
+    );
+
+    // Nothing similar yields no suggestions
+    let err = StrictProvider::parse_fuzzy("completely-different").unwrap_err();
+    assert!(err.suggestions.is_empty());
+    assert_eq!(
+        err.to_string(),
+        "unknown model ID 'completely-different' for StrictProvider"
+    );
+}
@@ -6,10 +6,11 @@ use url::Url;
 
 use crate::detection::{
     detect_audio_format_from_bytes, detect_audio_format_from_path, detect_audio_format_from_url,
+    detect_audio_format_from_url_with_headers,
 };
 use crate::error::{DetectionError, InvalidAudio, PlaybackError};
-use crate::playback::playa_explicit_with_options;
-use crate::types::{AudioFormat, PlaybackOptions};
+use crate::playback::{playa_explicit_with_options, playa_explicit_with_stream_options};
+use crate::types::{AudioFormat, PlaybackOptions, StreamOptions};
 
 /// Audio source data.
 #[derive(Debug, Clone)]
@@ -64,6 +65,18 @@ impl Audio {
         Ok(Self::new(AudioData::Url(url), format))
     }
 
+    /// Create an Audio instance from a URL, sending the stream's HTTP headers
+    /// with the format detection request.
+    pub async fn from_url_with_stream_options(
+        url: impl AsRef<str>,
+        stream: &StreamOptions,
+    ) -> Result<Self, InvalidAudio> {
+        let format =
+            detect_audio_format_from_url_with_headers(url.as_ref(), &stream.headers).await?;
+        let url = Url::parse(url.as_ref()).map_err(DetectionError::Url)?;
+        Ok(Self::new(AudioData::Url(url), format))
+    }
+
     /// Create an Audio instance from in-memory bytes.
     pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Result<Self, InvalidAudio> {
         let bytes = bytes.into();
@@ -99,6 +112,19 @@ impl Audio {
         Ok(())
     }
 
+    /// Play the audio with playback options and network streaming options.
+    ///
+    /// Stream options only apply to URL sources; see [`StreamOptions`].
+    pub fn play_with_stream_options(
+        &self,
+        options: PlaybackOptions,
+        stream: &StreamOptions,
+    ) -> Result<(), PlaybackError> {
+        playa_explicit_with_stream_options(self.format, self.data.clone(), options, stream)?;
+        self.mark_playing()?;
+        Ok(())
+    }
+
     /// Mark the audio as paused and track the pause position.
     pub fn pause(&self) -> Result<(), PlaybackError> {
         let mut state = self.state.lock().map_err(|_| PlaybackError::StateLock)?;
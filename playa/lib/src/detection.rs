@@ -51,11 +51,22 @@ pub fn detect_audio_format_from_path(path: &Path) -> Result<AudioFormat, Detecti
 
 /// Detect audio format from a URL using an HTTP range request.
 pub async fn detect_audio_format_from_url(url: &str) -> Result<AudioFormat, DetectionError> {
+    detect_audio_format_from_url_with_headers(url, &[]).await
+}
+
+/// Detect audio format from a URL, sending extra HTTP headers (e.g., auth tokens)
+/// with the range request.
+pub async fn detect_audio_format_from_url_with_headers(
+    url: &str,
+    headers: &[(String, String)],
+) -> Result<AudioFormat, DetectionError> {
     let parsed = Url::parse(url)?;
     let client = Client::new();
-    let response = client
-        .get(parsed.as_str())
-        .header(RANGE, URL_RANGE_BYTES)
+    let mut request = client.get(parsed.as_str()).header(RANGE, URL_RANGE_BYTES);
+    for (name, value) in headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+    let response = request
         .send()
         .await?
         .error_for_status()?;
@@ -1,7 +1,7 @@
 use thiserror::Error;
 
 use crate::player::AudioPlayer;
-use crate::types::AudioFormat;
+use crate::types::{AudioFormat, ResourceUsage};
 
 /// Errors returned by audio format detection.
 #[derive(Debug, Error)]
@@ -56,6 +56,20 @@ pub enum PlaybackError {
         /// Whether volume control was required.
         needs_volume: bool,
     },
+    /// No installed player fits within the requested resource usage.
+    #[error("no player for {format:?} within {max:?} resource usage")]
+    NoPlayerWithinResourceLimit {
+        /// The requested audio format.
+        format: AudioFormat,
+        /// The resource usage limit.
+        max: ResourceUsage,
+    },
+    /// No installed player can inject the HTTP headers requested for a URL source.
+    #[error("no player for {format:?} can send custom HTTP headers")]
+    NoPlayerWithHeaderSupport {
+        /// The requested audio format.
+        format: AudioFormat,
+    },
     /// Player metadata could not be found in the lookup table.
     #[error("player metadata missing for {player:?}")]
     MissingPlayerMetadata {
@@ -76,6 +90,12 @@ pub enum PlaybackError {
         /// The source label.
         source_kind: &'static str,
     },
+    /// The player cannot inject the HTTP headers requested for a URL source.
+    #[error("player {player:?} cannot send custom HTTP headers")]
+    UnsupportedStreamHeaders {
+        /// The player being used.
+        player: AudioPlayer,
+    },
     /// Failed to spawn the player process.
     #[error("failed to spawn player {player:?}: {source}")]
     Spawn {
@@ -92,6 +112,16 @@ pub enum PlaybackError {
         /// The exit code, if available.
         exit_code: Option<i32>,
     },
+    /// The player was stopped for exceeding its memory limit.
+    #[error("player {player:?} used {used_bytes} bytes, exceeding the {limit_bytes} byte limit")]
+    MemoryLimitExceeded {
+        /// The player that was stopped.
+        player: AudioPlayer,
+        /// The configured limit.
+        limit_bytes: u64,
+        /// Resident memory observed when the player was stopped.
+        used_bytes: u64,
+    },
     /// The audio state lock was poisoned.
     #[error("audio state lock poisoned")]
     StateLock,
@@ -1,9 +1,11 @@
 mod audio;
 mod detection;
 mod error;
+mod meter;
 mod playa;
 mod playback;
 mod player;
+mod resources;
 mod types;
 
 #[cfg(any(
@@ -31,20 +33,26 @@ pub use crate::effects::SoundEffect;
 pub use crate::audio::{Audio, AudioData, AudioSourceKind};
 pub use crate::detection::{
     detect_audio_format_from_bytes, detect_audio_format_from_path, detect_audio_format_from_url,
+    detect_audio_format_from_url_with_headers,
 };
 pub use crate::error::{DetectionError, InvalidAudio, PlaybackError};
+pub use crate::meter::{Level, LevelMeter, METER_RATE_HZ};
 pub use crate::playa::Playa;
 pub use crate::playback::{
-    playa, playa_explicit, playa_explicit_with_options, playa_with_player,
-    playa_with_player_and_options,
+    playa, playa_explicit, playa_explicit_with_options, playa_explicit_with_stream_options,
+    playa_with_player, playa_with_player_and_options, playa_with_player_and_stream_options,
 };
 
 #[cfg(feature = "async")]
 pub use crate::playback::{
-    playa_async, playa_explicit_async, playa_explicit_with_options_async, playa_with_player_async,
-    playa_with_player_and_options_async,
+    playa_async, playa_explicit_async, playa_explicit_with_options_async,
+    playa_explicit_with_stream_options_async, playa_with_player_and_options_async,
+    playa_with_player_and_stream_options_async, playa_with_player_async,
 };
 pub use crate::player::{
     all_players, match_available_players, match_players, AudioPlayer, Player, PLAYER_LOOKUP,
 };
-pub use crate::types::{AudioFileFormat, AudioFormat, Codec, PlaybackOptions, ResourceUsage};
+pub use crate::types::{
+    AudioFileFormat, AudioFormat, Codec, PlaybackOptions, ProcessPriority, ResourceUsage,
+    StreamOptions,
+};
@@ -0,0 +1,562 @@
+//! Level metering while audio plays.
+//!
+//! A [`LevelMeter`] reports the RMS and peak level of the audio being played,
+//! updated about [`METER_RATE_HZ`] times a second, so a TUI can draw a VU
+//! meter by polling [`LevelMeter::level`] from its render loop.
+//!
+//! Players run as separate processes, so the levels are computed alongside
+//! them: PCM WAV files are decoded natively, and anything else is analyzed by
+//! an `ffmpeg` process reading the same source in real time. When `ffmpeg` is
+//! not installed, other formats are not metered and the meter stays silent.
+
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use std::process::{Child, ChildStderr, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::types::PlaybackOptions;
+
+/// How many times per second the meter is updated.
+pub const METER_RATE_HZ: u32 = 30;
+
+/// Samples per channel that `ffmpeg` measures at a time; 30Hz at 48kHz.
+const FFMPEG_WINDOW_SAMPLES: u32 = 1600;
+
+const RMS_KEY: &str = "lavfi.astats.Overall.RMS_level=";
+const PEAK_KEY: &str = "lavfi.astats.Overall.Peak_level=";
+
+/// The level of a short window of audio, as linear amplitudes.
+///
+/// `0.0` is silence and `1.0` is full scale. Levels can exceed `1.0` when the
+/// playback volume amplifies the audio.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Level {
+    /// Root mean square amplitude across all channels.
+    pub rms: f32,
+    /// Highest absolute sample amplitude across all channels.
+    pub peak: f32,
+}
+
+impl Level {
+    /// No signal.
+    pub const SILENT: Level = Level {
+        rms: 0.0,
+        peak: 0.0,
+    };
+
+    /// The RMS level in dBFS (negative infinity for silence).
+    pub fn rms_db(&self) -> f32 {
+        to_db(self.rms)
+    }
+
+    /// The peak level in dBFS (negative infinity for silence).
+    pub fn peak_db(&self) -> f32 {
+        to_db(self.peak)
+    }
+
+    fn scaled(self, gain: f32) -> Self {
+        Self {
+            rms: self.rms * gain,
+            peak: self.peak * gain,
+        }
+    }
+}
+
+/// A shared handle to the level of the audio currently playing.
+///
+/// Clones share the same level, so keep one clone to read from while another
+/// is handed to [`Playa::meter`](crate::Playa::meter).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use playa::{LevelMeter, Playa};
+///
+/// let meter = LevelMeter::new();
+/// let playback = Playa::from_path("song.wav")?.meter(meter.clone());
+/// let handle = std::thread::spawn(move || playback.play());
+///
+/// while !handle.is_finished() {
+///     let level = meter.level();
+///     println!("rms {:>6.1} dB  peak {:>6.1} dB", level.rms_db(), level.peak_db());
+///     std::thread::sleep(std::time::Duration::from_millis(33));
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LevelMeter {
+    state: Arc<MeterState>,
+}
+
+#[derive(Debug, Default)]
+struct MeterState {
+    level: Mutex<Level>,
+    active: AtomicBool,
+}
+
+impl LevelMeter {
+    /// Create a silent, inactive meter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The most recent level, or [`Level::SILENT`] when nothing is playing.
+    pub fn level(&self) -> Level {
+        *self
+            .state
+            .level
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Whether audio is being metered right now.
+    pub fn is_active(&self) -> bool {
+        self.state.active.load(Ordering::SeqCst)
+    }
+
+    fn set(&self, level: Level) {
+        *self
+            .state
+            .level
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = level;
+    }
+
+    /// Start metering `input` (a file path or URL) as it is played with `options`.
+    ///
+    /// Metering stops when the returned run is dropped.
+    pub(crate) fn start(&self, input: &OsStr, options: &PlaybackOptions) -> MeterRun {
+        let stop = Arc::new(AtomicBool::new(false));
+        let gain = options.volume.unwrap_or(1.0);
+        let speed = options.speed.filter(|speed| *speed > 0.0).unwrap_or(1.0);
+        let mut run = MeterRun {
+            meter: self.clone(),
+            stop: stop.clone(),
+            analyzer: None,
+        };
+
+        if let Some((reader, format)) = open_wav(Path::new(input)) {
+            let meter = self.clone();
+            thread::spawn(move || meter_wav(reader, format, &meter, &stop, gain, speed));
+        } else if let Some(mut child) = spawn_ffmpeg(input, speed) {
+            if let Some(stderr) = child.stderr.take() {
+                let meter = self.clone();
+                thread::spawn(move || meter_ffmpeg(stderr, &meter, &stop, gain));
+            }
+            run.analyzer = Some(child);
+        } else {
+            return run;
+        }
+
+        self.state.active.store(true, Ordering::SeqCst);
+        run
+    }
+}
+
+/// Metering for one playback attempt; stops the analysis when dropped.
+pub(crate) struct MeterRun {
+    meter: LevelMeter,
+    stop: Arc<AtomicBool>,
+    analyzer: Option<Child>,
+}
+
+impl Drop for MeterRun {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(mut child) = self.analyzer.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+        self.meter.state.active.store(false, Ordering::SeqCst);
+        self.meter.set(Level::SILENT);
+    }
+}
+
+/// Sample encoding of a PCM WAV file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WavFormat {
+    channels: u16,
+    sample_rate: u32,
+    bits_per_sample: u16,
+    float: bool,
+}
+
+impl WavFormat {
+    fn bytes_per_sample(&self) -> usize {
+        usize::from(self.bits_per_sample / 8)
+    }
+
+    fn frames_per_window(&self) -> usize {
+        (self.sample_rate / METER_RATE_HZ).max(1) as usize
+    }
+
+    /// Decode one sample to the range `-1.0..=1.0`.
+    fn sample(&self, bytes: &[u8]) -> f32 {
+        match (self.bits_per_sample, self.float) {
+            (8, false) => (f32::from(bytes[0]) - 128.0) / 128.0,
+            (16, false) => f32::from(i16::from_le_bytes([bytes[0], bytes[1]])) / 32_768.0,
+            (24, false) => {
+                let value = i32::from_le_bytes([0, bytes[0], bytes[1], bytes[2]]) >> 8;
+                value as f32 / 8_388_608.0
+            }
+            (32, false) => {
+                i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32
+                    / 2_147_483_648.0
+            }
+            (32, true) => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            (64, true) => f64::from_le_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ]) as f32,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Open `path` for native metering if it is a PCM WAV file.
+fn open_wav(path: &Path) -> Option<(BufReader<File>, WavFormat)> {
+    if !path.is_file() {
+        return None;
+    }
+    let mut reader = BufReader::new(File::open(path).ok()?);
+    let format = read_wav_format(&mut reader)?;
+    Some((reader, format))
+}
+
+/// Read a WAV header up to the start of its sample data.
+///
+/// Returns `None` for anything but uncompressed integer or float PCM.
+fn read_wav_format(reader: &mut impl Read) -> Option<WavFormat> {
+    const WAVE_FORMAT_PCM: u16 = 1;
+    const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+    const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+    let mut riff = [0u8; 12];
+    reader.read_exact(&mut riff).ok()?;
+    if &riff[0..4] != b"RIFF" || &riff[8..12] != b"WAVE" {
+        return None;
+    }
+
+    let mut format = None;
+    loop {
+        let mut header = [0u8; 8];
+        reader.read_exact(&mut header).ok()?;
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        // Chunks are padded to an even length
+        let padded = size + size % 2;
+
+        match &header[0..4] {
+            b"fmt " => {
+                let mut body = vec![0u8; padded];
+                reader.read_exact(&mut body).ok()?;
+                if size < 16 {
+                    return None;
+                }
+                let mut tag = u16::from_le_bytes([body[0], body[1]]);
+                if tag == WAVE_FORMAT_EXTENSIBLE && size >= 26 {
+                    tag = u16::from_le_bytes([body[24], body[25]]);
+                }
+                let float = match tag {
+                    WAVE_FORMAT_PCM => false,
+                    WAVE_FORMAT_IEEE_FLOAT => true,
+                    _ => return None,
+                };
+                format = Some(WavFormat {
+                    channels: u16::from_le_bytes([body[2], body[3]]),
+                    sample_rate: u32::from_le_bytes([body[4], body[5], body[6], body[7]]),
+                    bits_per_sample: u16::from_le_bytes([body[14], body[15]]),
+                    float,
+                });
+            }
+            b"data" => {
+                let format = format?;
+                let supported = matches!(
+                    (format.bits_per_sample, format.float),
+                    (8 | 16 | 24 | 32, false) | (32 | 64, true)
+                );
+                return (supported && format.channels > 0 && format.sample_rate > 0)
+                    .then_some(format);
+            }
+            _ => {
+                std::io::copy(
+                    &mut (&mut *reader).take(padded as u64),
+                    &mut std::io::sink(),
+                )
+                .ok()?;
+            }
+        }
+    }
+}
+
+/// The level of interleaved samples in `bytes`.
+fn window_level(bytes: &[u8], format: &WavFormat) -> Level {
+    let width = format.bytes_per_sample();
+    let mut sum_squares = 0.0f64;
+    let mut peak = 0.0f32;
+    let mut count = 0usize;
+    for sample in bytes.chunks_exact(width) {
+        let value = format.sample(sample);
+        sum_squares += f64::from(value) * f64::from(value);
+        peak = peak.max(value.abs());
+        count += 1;
+    }
+    if count == 0 {
+        return Level::SILENT;
+    }
+    Level {
+        rms: (sum_squares / count as f64).sqrt() as f32,
+        peak,
+    }
+}
+
+/// Meter a WAV file's samples in real time until it ends or `stop` is set.
+fn meter_wav(
+    mut reader: impl Read,
+    format: WavFormat,
+    meter: &LevelMeter,
+    stop: &AtomicBool,
+    gain: f32,
+    speed: f32,
+) {
+    let frame_bytes = format.bytes_per_sample() * usize::from(format.channels);
+    let mut window = vec![0u8; format.frames_per_window() * frame_bytes];
+    let window_duration = Duration::from_secs_f32(
+        format.frames_per_window() as f32 / format.sample_rate as f32 / speed,
+    );
+    let started = Instant::now();
+
+    for elapsed_windows in 1u32.. {
+        let read = read_up_to(&mut reader, &mut window);
+        if read == 0 || stop.load(Ordering::SeqCst) {
+            break;
+        }
+        meter.set(window_level(&window[..read - read % frame_bytes], &format).scaled(gain));
+
+        let due = started + window_duration * elapsed_windows;
+        if let Some(wait) = due.checked_duration_since(Instant::now()) {
+            thread::sleep(wait);
+        }
+    }
+    if !stop.load(Ordering::SeqCst) {
+        meter.set(Level::SILENT);
+    }
+}
+
+/// Fill `buffer` as far as the reader allows, returning the bytes read.
+fn read_up_to(reader: &mut impl Read, buffer: &mut [u8]) -> usize {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => filled += read,
+        }
+    }
+    filled
+}
+
+/// Start `ffmpeg` decoding `input` in real time and logging each window's levels.
+fn spawn_ffmpeg(input: &OsStr, speed: f32) -> Option<Child> {
+    let tempo = if (speed - 1.0).abs() > f32::EPSILON {
+        format!("atempo={},", speed.clamp(0.5, 100.0))
+    } else {
+        String::new()
+    };
+    let filter = format!(
+        "{tempo}asetnsamples=n={FFMPEG_WINDOW_SAMPLES},astats=metadata=1:reset=1,\
+         ametadata=print:key=lavfi.astats.Overall.RMS_level,\
+         ametadata=print:key=lavfi.astats.Overall.Peak_level"
+    );
+
+    Command::new("ffmpeg")
+        .args(["-hide_banner", "-nostats", "-loglevel", "info", "-re", "-i"])
+        .arg(input)
+        .args(["-vn", "-af"])
+        .arg(filter)
+        .args(["-f", "null", "-"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .ok()
+}
+
+/// Update `meter` from the levels `ffmpeg` logs until it exits or `stop` is set.
+fn meter_ffmpeg(stderr: ChildStderr, meter: &LevelMeter, stop: &AtomicBool, gain: f32) {
+    let mut rms = 0.0;
+    for line in BufReader::new(stderr).lines() {
+        let Ok(line) = line else { break };
+        if stop.load(Ordering::SeqCst) {
+            break;
+        }
+        if let Some(value) = parse_db_value(&line, RMS_KEY) {
+            rms = value;
+        } else if let Some(peak) = parse_db_value(&line, PEAK_KEY) {
+            meter.set(Level { rms, peak }.scaled(gain));
+        }
+    }
+}
+
+/// Parse the dBFS value logged after `key`, as a linear amplitude.
+fn parse_db_value(line: &str, key: &str) -> Option<f32> {
+    let (_, value) = line.split_once(key)?;
+    let value = value.trim();
+    if value.ends_with("inf") && value.starts_with('-') {
+        return Some(0.0);
+    }
+    value.parse::<f32>().ok().map(from_db)
+}
+
+fn to_db(amplitude: f32) -> f32 {
+    20.0 * amplitude.log10()
+}
+
+fn from_db(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wav_bytes(format_tag: u16, channels: u16, bits: u16, samples: &[u8]) -> Vec<u8> {
+        let sample_rate = 3000u32;
+        let block_align = channels * bits / 8;
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + samples.len() as u32 + 10).to_le_bytes());
+        bytes.extend_from_slice(b"WAVE");
+        bytes.extend_from_slice(b"fmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&format_tag.to_le_bytes());
+        bytes.extend_from_slice(&channels.to_le_bytes());
+        bytes.extend_from_slice(&sample_rate.to_le_bytes());
+        bytes.extend_from_slice(&(sample_rate * u32::from(block_align)).to_le_bytes());
+        bytes.extend_from_slice(&block_align.to_le_bytes());
+        bytes.extend_from_slice(&bits.to_le_bytes());
+        // An odd-sized chunk before the data, to exercise padding
+        bytes.extend_from_slice(b"LIST");
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&[0, 0]);
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&(samples.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(samples);
+        bytes
+    }
+
+    #[test]
+    fn reads_pcm_wav_header_up_to_the_samples() {
+        let samples: Vec<u8> = [16_384i16, -16_384]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        let bytes = wav_bytes(1, 2, 16, &samples);
+        let mut reader = bytes.as_slice();
+
+        let format = read_wav_format(&mut reader).unwrap();
+        assert_eq!(
+            format,
+            WavFormat {
+                channels: 2,
+                sample_rate: 3000,
+                bits_per_sample: 16,
+                float: false,
+            }
+        );
+        assert_eq!(format.frames_per_window(), 100);
+        assert_eq!(reader, samples.as_slice());
+    }
+
+    #[test]
+    fn rejects_compressed_and_non_wav_audio() {
+        let adpcm = wav_bytes(2, 1, 4, &[0; 8]);
+        assert_eq!(read_wav_format(&mut adpcm.as_slice()), None);
+        assert_eq!(read_wav_format(&mut &b"ID3\x04\x00 not a wav"[..]), None);
+    }
+
+    #[test]
+    fn window_level_measures_rms_and_peak() {
+        let format = WavFormat {
+            channels: 1,
+            sample_rate: 3000,
+            bits_per_sample: 16,
+            float: false,
+        };
+        let samples: Vec<u8> = [16_384i16, -16_384, 16_384, -32_768]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+
+        let level = window_level(&samples, &format);
+        assert!((level.peak - 1.0).abs() < 1e-6);
+        assert!((level.rms - (1.75f32 / 4.0).sqrt()).abs() < 1e-6);
+        assert_eq!(window_level(&[], &format), Level::SILENT);
+    }
+
+    #[test]
+    fn decodes_float_and_24_bit_samples() {
+        let float = WavFormat {
+            channels: 1,
+            sample_rate: 3000,
+            bits_per_sample: 32,
+            float: true,
+        };
+        assert_eq!(float.sample(&0.5f32.to_le_bytes()), 0.5);
+
+        let pcm24 = WavFormat {
+            bits_per_sample: 24,
+            float: false,
+            ..float
+        };
+        assert_eq!(pcm24.sample(&[0x00, 0x00, 0xC0]), -0.5);
+        assert_eq!(pcm24.sample(&[0x00, 0x00, 0x40]), 0.5);
+    }
+
+    #[test]
+    fn parses_ffmpeg_level_lines() {
+        let rms = "[Parsed_ametadata_3 @ 0x600] lavfi.astats.Overall.RMS_level=-20.000000";
+        let peak = "[Parsed_ametadata_4 @ 0x600] lavfi.astats.Overall.Peak_level=-inf";
+
+        assert!((parse_db_value(rms, RMS_KEY).unwrap() - 0.1).abs() < 1e-6);
+        assert_eq!(parse_db_value(peak, PEAK_KEY), Some(0.0));
+        assert_eq!(parse_db_value(rms, PEAK_KEY), None);
+        assert_eq!(
+            parse_db_value("frame:3 pts:4800 pts_time:0.1", RMS_KEY),
+            None
+        );
+    }
+
+    #[test]
+    fn levels_convert_to_dbfs() {
+        let level = Level {
+            rms: 0.1,
+            peak: 1.0,
+        }
+        .scaled(0.5);
+        assert!((level.rms_db() - -26.0206).abs() < 1e-3);
+        assert!((level.peak_db() - -6.0206).abs() < 1e-3);
+        assert_eq!(Level::SILENT.rms_db(), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn meter_is_silent_and_inactive_until_started() {
+        let meter = LevelMeter::new();
+        let copy = meter.clone();
+        copy.set(Level {
+            rms: 0.2,
+            peak: 0.4,
+        });
+
+        assert_eq!(
+            meter.level(),
+            Level {
+                rms: 0.2,
+                peak: 0.4
+            }
+        );
+        assert!(!meter.is_active());
+    }
+}
@@ -2,9 +2,11 @@ use std::path::PathBuf;
 
 use crate::audio::Audio;
 use crate::error::{InvalidAudio, PlaybackError};
-use crate::playback::playa_with_player_and_options;
+use crate::audio::AudioSourceKind;
+use crate::meter::LevelMeter;
+use crate::playback::play_metered;
 use crate::player::{match_available_players, AudioPlayer, Player, PLAYER_LOOKUP};
-use crate::types::{AudioFormat, PlaybackOptions};
+use crate::types::{AudioFormat, PlaybackOptions, ProcessPriority, StreamOptions};
 
 /// Builder for audio playback with optional metadata display.
 ///
@@ -32,7 +34,9 @@ use crate::types::{AudioFormat, PlaybackOptions};
 pub struct Playa {
     audio: Audio,
     options: PlaybackOptions,
+    stream: StreamOptions,
     show_meta: bool,
+    meter: Option<LevelMeter>,
 }
 
 impl Playa {
@@ -41,7 +45,9 @@ impl Playa {
         Self {
             audio,
             options: PlaybackOptions::default(),
+            stream: StreamOptions::default(),
             show_meta: false,
+            meter: None,
         }
     }
 
@@ -51,6 +57,16 @@ impl Playa {
         Ok(Self::new(audio))
     }
 
+    /// Create a `Playa` from a URL, using the stream options for both format
+    /// detection and playback.
+    pub async fn from_url(
+        url: impl AsRef<str>,
+        stream: StreamOptions,
+    ) -> Result<Self, InvalidAudio> {
+        let audio = Audio::from_url_with_stream_options(url, &stream).await?;
+        Ok(Self::new(audio).stream_options(stream))
+    }
+
     /// Create a `Playa` from raw audio bytes.
     pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Result<Self, InvalidAudio> {
         let audio = Audio::from_bytes(bytes)?;
@@ -82,12 +98,39 @@ impl Playa {
         self
     }
 
+    /// Set the CPU and I/O priority of the player process.
+    pub fn priority(mut self, priority: ProcessPriority) -> Self {
+        self.options = self.options.with_priority(priority);
+        self
+    }
+
+    /// Abort playback if the player uses more than `bytes` of resident memory.
+    pub fn max_memory(mut self, bytes: u64) -> Self {
+        self.options = self.options.with_max_memory(bytes);
+        self
+    }
+
     /// Set playback options directly.
     pub fn with_options(mut self, options: PlaybackOptions) -> Self {
         self.options = options;
         self
     }
 
+    /// Set network streaming options (buffering, headers, retries) for URL sources.
+    pub fn stream_options(mut self, stream: StreamOptions) -> Self {
+        self.stream = stream;
+        self
+    }
+
+    /// Report the level of the audio to `meter` while it plays.
+    ///
+    /// Keep a clone of the meter and poll [`LevelMeter::level`] to draw a VU
+    /// meter; it reads [`Level::SILENT`](crate::Level::SILENT) once playback ends.
+    pub fn meter(mut self, meter: LevelMeter) -> Self {
+        self.meter = Some(meter);
+        self
+    }
+
     /// Return the detected audio format.
     pub fn format(&self) -> AudioFormat {
         self.audio.format()
@@ -105,11 +148,19 @@ impl Playa {
             self.print_meta(player, format);
         }
 
-        playa_with_player_and_options(player, self.audio.into_data(), self.options)
+        play_metered(
+            player,
+            self.audio.into_data(),
+            self.options,
+            &self.stream,
+            self.meter.as_ref(),
+        )
     }
 
     /// Select the best available player for the audio format and options.
     fn select_player(&self, format: AudioFormat) -> Result<AudioPlayer, PlaybackError> {
+        let needs_headers = self.audio.source_kind() == AudioSourceKind::Url
+            && self.stream.requires_header_support();
         let players = match_available_players(format);
         let selected = players.into_iter().find(|candidate| {
             let Some(metadata) = PLAYER_LOOKUP.get(candidate) else {
@@ -121,7 +172,10 @@ impl Playa {
             if self.options.requires_volume_control() && !metadata.supports_volume_control {
                 return false;
             }
-            true
+            if needs_headers && !metadata.supports_http_headers() {
+                return false;
+            }
+            self.options.allows_resource_usage(metadata.resource_usage)
         });
 
         selected.ok_or_else(|| {
@@ -131,6 +185,10 @@ impl Playa {
                     needs_speed: self.options.requires_speed_control(),
                     needs_volume: self.options.requires_volume_control(),
                 }
+            } else if let Some(max) = self.options.max_resource_usage {
+                PlaybackError::NoPlayerWithinResourceLimit { format, max }
+            } else if needs_headers {
+                PlaybackError::NoPlayerWithHeaderSupport { format }
             } else {
                 PlaybackError::NoCompatiblePlayer { format }
             }
@@ -1,3 +1,4 @@
+use std::ffi::OsStr;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -10,8 +11,14 @@ use crate::detection::{
     detect_audio_format_from_bytes, detect_audio_format_from_path, detect_audio_format_from_url,
 };
 use crate::error::PlaybackError;
+use crate::meter::LevelMeter;
 use crate::player::{match_available_players, AudioPlayer, PLAYER_LOOKUP};
-use crate::types::{AudioFormat, PlaybackOptions};
+#[cfg(windows)]
+use crate::resources::priority_creation_flags;
+#[cfg(feature = "async")]
+use crate::resources::wait_with_watchdog_async;
+use crate::resources::{wait_with_watchdog, with_priority};
+use crate::types::{AudioFormat, PlaybackOptions, ProcessPriority, StreamOptions};
 
 /// Detect the format and play audio with the best available player.
 pub async fn playa(audio: AudioData) -> Result<(), PlaybackError> {
@@ -35,8 +42,22 @@ pub fn playa_explicit_with_options(
     audio: AudioData,
     options: PlaybackOptions,
 ) -> Result<(), PlaybackError> {
-    let player = select_player(format, &audio, &options)?;
-    playa_with_player_and_options(player, audio, options)
+    playa_explicit_with_stream_options(format, audio, options, &StreamOptions::default())
+}
+
+/// Play audio using an explicitly provided audio format with playback and
+/// streaming options.
+///
+/// Stream options only affect URL sources. When headers are requested, only
+/// players that can inject HTTP headers are considered.
+pub fn playa_explicit_with_stream_options(
+    format: AudioFormat,
+    audio: AudioData,
+    options: PlaybackOptions,
+    stream: &StreamOptions,
+) -> Result<(), PlaybackError> {
+    let player = select_player(format, &audio, &options, stream)?;
+    playa_with_player_and_stream_options(player, audio, options, stream)
 }
 
 /// Play audio using a specific player.
@@ -49,37 +70,71 @@ pub fn playa_with_player_and_options(
     player: AudioPlayer,
     audio: AudioData,
     options: PlaybackOptions,
+) -> Result<(), PlaybackError> {
+    playa_with_player_and_stream_options(player, audio, options, &StreamOptions::default())
+}
+
+/// Play audio using a specific player with playback and streaming options.
+///
+/// For URL sources, a player that exits with a failure status is retried up
+/// to [`StreamOptions::retries`] times, waiting [`StreamOptions::retry_delay`]
+/// between attempts, as long as the stream itself is failing: unreachable or
+/// answering with a `5xx` status.
+pub fn playa_with_player_and_stream_options(
+    player: AudioPlayer,
+    audio: AudioData,
+    options: PlaybackOptions,
+    stream: &StreamOptions,
+) -> Result<(), PlaybackError> {
+    play_metered(player, audio, options, stream, None)
+}
+
+/// Play audio using a specific player, reporting its level to `meter` while
+/// the player runs.
+pub(crate) fn play_metered(
+    player: AudioPlayer,
+    audio: AudioData,
+    options: PlaybackOptions,
+    stream: &StreamOptions,
+    meter: Option<&LevelMeter>,
 ) -> Result<(), PlaybackError> {
     let metadata = PLAYER_LOOKUP
         .get(&player)
         .ok_or(PlaybackError::MissingPlayerMetadata { player })?;
 
-    if matches!(audio, AudioData::Url(_)) && !metadata.takes_stream_input {
-        return Err(PlaybackError::UnsupportedSource {
-            player,
-            source_kind: "url",
-        });
-    }
+    check_stream_support(player, metadata, &audio, stream)?;
 
     let source = resolve_source(&audio)?;
-    let mut command = build_player_command(player, metadata, &source, &options)?;
-    command
-        .stdin(Stdio::null())
-        .stdout(Stdio::null())
-        .stderr(Stdio::null());
-
-    let status = command
-        .status()
-        .map_err(|source| PlaybackError::Spawn { player, source })?;
-
-    if !status.success() {
-        return Err(PlaybackError::PlayerFailed {
-            player,
-            exit_code: status.code(),
-        });
-    }
+    let mut attempt = 0;
+    loop {
+        let command = build_player_command(player, metadata, &source, &options, stream)?;
+        let mut command = prioritized_command(&command, options.priority);
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+
+        let child = command
+            .spawn()
+            .map_err(|source| PlaybackError::Spawn { player, source })?;
+        let metering = meter.map(|meter| meter.start(source.as_os_str(), &options));
+        let status = wait_with_watchdog(player, child, options.max_memory_bytes)?;
+        drop(metering);
+
+        if status.success() {
+            return Ok(());
+        }
 
-    Ok(())
+        if !(should_retry(&source, stream, attempt) && is_transient_failure(&source, stream)) {
+            return Err(PlaybackError::PlayerFailed {
+                player,
+                exit_code: status.code(),
+            });
+        }
+
+        attempt += 1;
+        std::thread::sleep(stream.retry_delay);
+    }
 }
 
 // ============================================================================
@@ -121,8 +176,23 @@ pub async fn playa_explicit_with_options_async(
     audio: AudioData,
     options: PlaybackOptions,
 ) -> Result<(), PlaybackError> {
-    let player = select_player(format, &audio, &options)?;
-    playa_with_player_and_options_async(player, audio, options).await
+    playa_explicit_with_stream_options_async(format, audio, options, &StreamOptions::default())
+        .await
+}
+
+/// Play audio using an explicitly provided audio format with playback and
+/// streaming options (async).
+///
+/// This is the async version of [`playa_explicit_with_stream_options`].
+#[cfg(feature = "async")]
+pub async fn playa_explicit_with_stream_options_async(
+    format: AudioFormat,
+    audio: AudioData,
+    options: PlaybackOptions,
+    stream: &StreamOptions,
+) -> Result<(), PlaybackError> {
+    let player = select_player(format, &audio, &options, stream)?;
+    playa_with_player_and_stream_options_async(player, audio, options, stream).await
 }
 
 /// Play audio using a specific player (async).
@@ -146,55 +216,188 @@ pub async fn playa_with_player_and_options_async(
     player: AudioPlayer,
     audio: AudioData,
     options: PlaybackOptions,
+) -> Result<(), PlaybackError> {
+    playa_with_player_and_stream_options_async(player, audio, options, &StreamOptions::default())
+        .await
+}
+
+/// Play audio using a specific player with playback and streaming options (async).
+///
+/// This is the async version of [`playa_with_player_and_stream_options`].
+#[cfg(feature = "async")]
+pub async fn playa_with_player_and_stream_options_async(
+    player: AudioPlayer,
+    audio: AudioData,
+    options: PlaybackOptions,
+    stream: &StreamOptions,
 ) -> Result<(), PlaybackError> {
     let metadata = PLAYER_LOOKUP
         .get(&player)
         .ok_or(PlaybackError::MissingPlayerMetadata { player })?;
 
-    if matches!(audio, AudioData::Url(_)) && !metadata.takes_stream_input {
+    check_stream_support(player, metadata, &audio, stream)?;
+
+    let source = resolve_source_async(&audio).await?;
+    let mut attempt = 0;
+    loop {
+        let (binary, args) = build_player_args(player, metadata, &source, &options, stream)?;
+        let (program, args) = with_priority(options.priority, OsStr::new(binary), args);
+
+        let mut command = tokio::process::Command::new(program);
+        command
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        #[cfg(windows)]
+        command.creation_flags(priority_creation_flags(options.priority));
+
+        let child = command
+            .spawn()
+            .map_err(|source| PlaybackError::Spawn { player, source })?;
+        let status = wait_with_watchdog_async(player, child, options.max_memory_bytes).await?;
+
+        if status.success() {
+            return Ok(());
+        }
+
+        if !(should_retry(&source, stream, attempt)
+            && is_transient_failure_async(&source, stream).await)
+        {
+            return Err(PlaybackError::PlayerFailed {
+                player,
+                exit_code: status.code(),
+            });
+        }
+
+        attempt += 1;
+        tokio::time::sleep(stream.retry_delay).await;
+    }
+}
+
+/// Rebuild `command` so the player runs at `priority`.
+fn prioritized_command(command: &Command, priority: ProcessPriority) -> Command {
+    let args = command.get_args().map(OsStr::to_owned).collect();
+    let (program, args) = with_priority(priority, command.get_program(), args);
+
+    let mut prioritized = Command::new(program);
+    prioritized.args(args);
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        prioritized.creation_flags(priority_creation_flags(priority));
+    }
+    prioritized
+}
+
+/// Verify the player can handle the source kind and requested stream options.
+fn check_stream_support(
+    player: AudioPlayer,
+    metadata: &crate::player::Player,
+    audio: &AudioData,
+    stream: &StreamOptions,
+) -> Result<(), PlaybackError> {
+    if !matches!(audio, AudioData::Url(_)) {
+        return Ok(());
+    }
+    if !metadata.takes_stream_input {
         return Err(PlaybackError::UnsupportedSource {
             player,
             source_kind: "url",
         });
     }
+    if stream.requires_header_support() && !metadata.supports_http_headers() {
+        return Err(PlaybackError::UnsupportedStreamHeaders { player });
+    }
+    Ok(())
+}
 
-    let source = resolve_source_async(&audio).await?;
-    let (binary, args) = build_player_args(player, metadata, &source, &options)?;
+/// Whether a failed playback attempt may be retried.
+///
+/// Only URL sources are retried since local failures are not transient, and
+/// only while [`StreamOptions::retries`] allows another attempt.
+fn should_retry(source: &ResolvedSource, stream: &StreamOptions, attempt: u32) -> bool {
+    matches!(source, ResolvedSource::Url(_)) && attempt < stream.retries
+}
 
-    let mut command = tokio::process::Command::new(binary);
-    command
-        .args(args)
-        .stdin(Stdio::null())
-        .stdout(Stdio::null())
-        .stderr(Stdio::null());
+/// How long probing a failed stream may take.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
 
-    let status = command
-        .status()
-        .await
-        .map_err(|source| PlaybackError::Spawn { player, source })?;
+/// Whether a failure is worth retrying, given the stream's probe status.
+///
+/// `None` means the stream couldn't be reached at all. Network errors and
+/// `5xx` responses may clear up; a `4xx` won't, and a stream that answers
+/// fine means the player failed on the audio itself.
+fn is_transient_status(status: Option<reqwest::StatusCode>) -> bool {
+    status.is_none_or(|status| status.is_server_error())
+}
 
-    if !status.success() {
-        return Err(PlaybackError::PlayerFailed {
-            player,
-            exit_code: status.code(),
-        });
+/// Whether a failed URL stream looks transient.
+///
+/// The player's exit status doesn't say why it failed, so the first byte of
+/// the stream is requested again with the same headers.
+fn is_transient_failure(source: &ResolvedSource, stream: &StreamOptions) -> bool {
+    let ResolvedSource::Url(url) = source else {
+        return false;
+    };
+    let url = url.clone();
+    let headers = stream.headers.clone();
+
+    // The blocking client can't run on an async runtime's thread
+    let status = std::thread::spawn(move || {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(PROBE_TIMEOUT)
+            .build()
+            .ok()?;
+        let mut request = client.get(url).header(reqwest::header::RANGE, "bytes=0-0");
+        for (name, value) in &headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        request.send().ok().map(|response| response.status())
+    })
+    .join()
+    .unwrap_or_default();
+
+    is_transient_status(status)
+}
+
+/// Whether a failed URL stream looks transient (async).
+///
+/// See [`is_transient_failure`].
+#[cfg(feature = "async")]
+async fn is_transient_failure_async(source: &ResolvedSource, stream: &StreamOptions) -> bool {
+    let ResolvedSource::Url(url) = source else {
+        return false;
+    };
+    let Ok(client) = reqwest::Client::builder().timeout(PROBE_TIMEOUT).build() else {
+        return is_transient_status(None);
+    };
+    let mut request = client.get(url).header(reqwest::header::RANGE, "bytes=0-0");
+    for (name, value) in &stream.headers {
+        request = request.header(name.as_str(), value.as_str());
     }
+    let status = request.send().await.ok().map(|response| response.status());
 
-    Ok(())
+    is_transient_status(status)
 }
 
 fn select_player(
     format: AudioFormat,
     audio: &AudioData,
     options: &PlaybackOptions,
+    stream: &StreamOptions,
 ) -> Result<AudioPlayer, PlaybackError> {
+    let is_url = matches!(audio, AudioData::Url(_));
     let players = match_available_players(format);
     let selected = players.into_iter().find(|candidate| {
         let Some(metadata) = PLAYER_LOOKUP.get(candidate) else {
             return false;
         };
         // Filter by URL capability
-        if matches!(audio, AudioData::Url(_)) && !metadata.takes_stream_input {
+        if is_url && !metadata.takes_stream_input {
+            return false;
+        }
+        if is_url && stream.requires_header_support() && !metadata.supports_http_headers() {
             return false;
         }
         // Filter by required capabilities
@@ -204,7 +407,7 @@ fn select_player(
         if options.requires_volume_control() && !metadata.supports_volume_control {
             return false;
         }
-        true
+        options.allows_resource_usage(metadata.resource_usage)
     });
 
     selected.ok_or_else(|| {
@@ -214,20 +417,102 @@ fn select_player(
                 needs_speed: options.requires_speed_control(),
                 needs_volume: options.requires_volume_control(),
             }
+        } else if let Some(max) = options.max_resource_usage {
+            PlaybackError::NoPlayerWithinResourceLimit { format, max }
+        } else if is_url && stream.requires_header_support() {
+            PlaybackError::NoPlayerWithHeaderSupport { format }
         } else {
             PlaybackError::NoCompatiblePlayer { format }
         }
     })
 }
 
+/// Build player-specific flags for streaming a URL source.
+///
+/// Options the player has no flag for are ignored. Headers become plain
+/// arguments, visible in the process list (see [`StreamOptions`]).
+fn stream_args(player: AudioPlayer, stream: &StreamOptions) -> Vec<String> {
+    let mut args = Vec::new();
+
+    match player {
+        AudioPlayer::Mpv => {
+            if stream.buffer_size_kb.is_some() || stream.latency.is_some() {
+                args.push("--cache=yes".to_string());
+            }
+            if let Some(kb) = stream.buffer_size_kb {
+                args.push(format!("--demuxer-max-bytes={}KiB", kb));
+            }
+            if let Some(latency) = stream.latency {
+                args.push(format!("--cache-secs={}", latency.as_secs_f32()));
+            }
+            for (name, value) in &stream.headers {
+                args.push(format!("--http-header-fields-append={}: {}", name, value));
+            }
+        }
+        AudioPlayer::FfPlay => {
+            if !stream.headers.is_empty() {
+                // FFmpeg expects CRLF-terminated header lines in a single argument
+                let headers: String = stream
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}\r\n", name, value))
+                    .collect();
+                args.push("-headers".to_string());
+                args.push(headers);
+            }
+        }
+        AudioPlayer::Vlc => {
+            if let Some(latency) = stream.latency {
+                args.push(format!("--network-caching={}", latency.as_millis()));
+            }
+        }
+        AudioPlayer::MPlayer => {
+            if let Some(kb) = stream.buffer_size_kb {
+                args.push("-cache".to_string());
+                args.push(kb.to_string());
+            }
+            if !stream.headers.is_empty() {
+                let headers: Vec<String> = stream
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect();
+                args.push("-http-header-fields".to_string());
+                args.push(headers.join(","));
+            }
+        }
+        AudioPlayer::Mpg123 => {
+            if let Some(kb) = stream.buffer_size_kb {
+                args.push("--buffer".to_string());
+                args.push(kb.to_string());
+            }
+        }
+        AudioPlayer::GstreamerGstPlay
+        | AudioPlayer::Sox
+        | AudioPlayer::Ogg123
+        | AudioPlayer::AlsaAplay
+        | AudioPlayer::MacOsAfplay
+        | AudioPlayer::PulseaudioPaplay
+        | AudioPlayer::PulseaudioPacat
+        | AudioPlayer::Pipewire => {}
+    }
+
+    args
+}
+
 fn build_player_command(
     player: AudioPlayer,
     metadata: &crate::player::Player,
     source: &ResolvedSource,
     options: &PlaybackOptions,
+    stream: &StreamOptions,
 ) -> Result<Command, PlaybackError> {
     let mut command = Command::new(metadata.binary_name());
 
+    if matches!(source, ResolvedSource::Url(_)) {
+        command.args(stream_args(player, stream));
+    }
+
     match player {
         // Tier 1: Full controllability (speed + volume + stream)
         AudioPlayer::Mpv => {
@@ -447,9 +732,14 @@ fn build_player_args(
     metadata: &crate::player::Player,
     source: &ResolvedSource,
     options: &PlaybackOptions,
+    stream: &StreamOptions,
 ) -> Result<(&'static str, Vec<OsString>), PlaybackError> {
     let mut args: Vec<OsString> = Vec::new();
 
+    if matches!(source, ResolvedSource::Url(_)) {
+        args.extend(stream_args(player, stream).into_iter().map(OsString::from));
+    }
+
     match player {
         // Tier 1: Full controllability (speed + volume + stream)
         AudioPlayer::Mpv => {
@@ -579,6 +869,13 @@ enum ResolvedSource {
 }
 
 impl ResolvedSource {
+    fn as_os_str(&self) -> &OsStr {
+        match self {
+            ResolvedSource::Path(path) => path.as_os_str(),
+            ResolvedSource::Url(url) => OsStr::new(url),
+        }
+    }
+
     fn apply(&self, command: &mut Command) {
         match self {
             ResolvedSource::Path(path) => {
@@ -623,7 +920,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::Mpv);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command = build_player_command(AudioPlayer::Mpv, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::Mpv,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("--no-video")));
@@ -636,7 +940,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::Mpv);
         let source = mock_source();
         let options = PlaybackOptions::new().with_volume(0.5).with_speed(1.25);
-        let command = build_player_command(AudioPlayer::Mpv, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::Mpv,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("--volume=50")));
@@ -648,8 +959,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::FfPlay);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command =
-            build_player_command(AudioPlayer::FfPlay, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::FfPlay,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-nodisp")));
@@ -663,8 +980,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::FfPlay);
         let source = mock_source();
         let options = PlaybackOptions::new().with_volume(0.75).with_speed(1.5);
-        let command =
-            build_player_command(AudioPlayer::FfPlay, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::FfPlay,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-volume")));
@@ -678,7 +1001,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::Sox);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command = build_player_command(AudioPlayer::Sox, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::Sox,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-q")));
@@ -689,7 +1019,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::Sox);
         let source = mock_source();
         let options = PlaybackOptions::new().with_volume(0.8).with_speed(1.2);
-        let command = build_player_command(AudioPlayer::Sox, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::Sox,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-v")));
@@ -710,7 +1047,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::Vlc);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command = build_player_command(AudioPlayer::Vlc, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::Vlc,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("--quiet")));
@@ -722,7 +1066,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::Vlc);
         let source = mock_source();
         let options = PlaybackOptions::new().with_volume(0.5);
-        let command = build_player_command(AudioPlayer::Vlc, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::Vlc,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("--gain=1")));
@@ -733,8 +1084,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::MPlayer);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command =
-            build_player_command(AudioPlayer::MPlayer, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::MPlayer,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-really-quiet")));
@@ -745,8 +1102,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::MPlayer);
         let source = mock_source();
         let options = PlaybackOptions::new().with_volume(0.8);
-        let command =
-            build_player_command(AudioPlayer::MPlayer, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::MPlayer,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-softvol")));
@@ -759,9 +1122,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::GstreamerGstPlay);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command =
-            build_player_command(AudioPlayer::GstreamerGstPlay, metadata, &source, &options)
-                .unwrap();
+        let command = build_player_command(
+            AudioPlayer::GstreamerGstPlay,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("--quiet")));
@@ -772,9 +1140,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::GstreamerGstPlay);
         let source = mock_source();
         let options = PlaybackOptions::new().with_volume(0.5);
-        let command =
-            build_player_command(AudioPlayer::GstreamerGstPlay, metadata, &source, &options)
-                .unwrap();
+        let command = build_player_command(
+            AudioPlayer::GstreamerGstPlay,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("--volume=0.5")));
@@ -785,9 +1158,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::PulseaudioPaplay);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command =
-            build_player_command(AudioPlayer::PulseaudioPaplay, metadata, &source, &options)
-                .unwrap();
+        let command = build_player_command(
+            AudioPlayer::PulseaudioPaplay,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("/tmp/test.wav")));
@@ -798,9 +1176,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::PulseaudioPaplay);
         let source = mock_source();
         let options = PlaybackOptions::new().with_volume(0.5);
-        let command =
-            build_player_command(AudioPlayer::PulseaudioPaplay, metadata, &source, &options)
-                .unwrap();
+        let command = build_player_command(
+            AudioPlayer::PulseaudioPaplay,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         // 0.5 * 65536 = 32768
@@ -812,8 +1195,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::Pipewire);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command =
-            build_player_command(AudioPlayer::Pipewire, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::Pipewire,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("/tmp/test.wav")));
@@ -824,8 +1213,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::Pipewire);
         let source = mock_source();
         let options = PlaybackOptions::new().with_volume(0.75);
-        let command =
-            build_player_command(AudioPlayer::Pipewire, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::Pipewire,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("--volume=0.75")));
@@ -836,8 +1231,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::Mpg123);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command =
-            build_player_command(AudioPlayer::Mpg123, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::Mpg123,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-q")));
@@ -848,8 +1249,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::Ogg123);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command =
-            build_player_command(AudioPlayer::Ogg123, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::Ogg123,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-q")));
@@ -860,8 +1267,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::AlsaAplay);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command =
-            build_player_command(AudioPlayer::AlsaAplay, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::AlsaAplay,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-q")));
@@ -872,8 +1285,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::MacOsAfplay);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command =
-            build_player_command(AudioPlayer::MacOsAfplay, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::MacOsAfplay,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("/tmp/test.wav")));
@@ -884,8 +1303,14 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::MacOsAfplay);
         let source = mock_source();
         let options = PlaybackOptions::new().with_volume(0.5).with_speed(1.5);
-        let command =
-            build_player_command(AudioPlayer::MacOsAfplay, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::MacOsAfplay,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-v")));
@@ -900,8 +1325,14 @@ mod tests {
         let source = mock_source();
         // Speed outside 0.4-3.0 range should be clamped
         let options = PlaybackOptions::new().with_speed(5.0);
-        let command =
-            build_player_command(AudioPlayer::MacOsAfplay, metadata, &source, &options).unwrap();
+        let command = build_player_command(
+            AudioPlayer::MacOsAfplay,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("-r")));
@@ -913,15 +1344,148 @@ mod tests {
         let metadata = get_metadata(AudioPlayer::PulseaudioPacat);
         let source = mock_source();
         let options = PlaybackOptions::default();
-        let command =
-            build_player_command(AudioPlayer::PulseaudioPacat, metadata, &source, &options)
-                .unwrap();
+        let command = build_player_command(
+            AudioPlayer::PulseaudioPacat,
+            metadata,
+            &source,
+            &options,
+            &StreamOptions::default(),
+        )
+        .unwrap();
 
         // pacat takes file path directly, no special flags
         let args: Vec<_> = command.get_args().collect();
         assert!(args.contains(&OsStr::new("/tmp/test.wav")));
     }
 
+    fn url_source() -> ResolvedSource {
+        ResolvedSource::Url("https://example.com/stream.mp3".to_string())
+    }
+
+    #[test]
+    fn build_command_mpv_with_stream_options() {
+        let metadata = get_metadata(AudioPlayer::Mpv);
+        let stream = StreamOptions::new()
+            .with_buffer_size_kb(512)
+            .with_latency(Duration::from_secs(2))
+            .with_header("Authorization", "Bearer abc");
+        let command = build_player_command(
+            AudioPlayer::Mpv,
+            metadata,
+            &url_source(),
+            &PlaybackOptions::default(),
+            &stream,
+        )
+        .unwrap();
+
+        let args: Vec<_> = command.get_args().collect();
+        assert!(args.contains(&OsStr::new("--cache=yes")));
+        assert!(args.contains(&OsStr::new("--demuxer-max-bytes=512KiB")));
+        assert!(args.contains(&OsStr::new("--cache-secs=2")));
+        assert!(args.contains(&OsStr::new(
+            "--http-header-fields-append=Authorization: Bearer abc"
+        )));
+    }
+
+    #[test]
+    fn build_command_ffplay_joins_headers_with_crlf() {
+        let metadata = get_metadata(AudioPlayer::FfPlay);
+        let stream = StreamOptions::new()
+            .with_header("Authorization", "Bearer abc")
+            .with_header("X-Trace", "1");
+        let command = build_player_command(
+            AudioPlayer::FfPlay,
+            metadata,
+            &url_source(),
+            &PlaybackOptions::default(),
+            &stream,
+        )
+        .unwrap();
+
+        let args: Vec<_> = command.get_args().collect();
+        assert!(args.contains(&OsStr::new("-headers")));
+        assert!(args.contains(&OsStr::new("Authorization: Bearer abc\r\nX-Trace: 1\r\n")));
+    }
+
+    #[test]
+    fn build_command_vlc_network_caching_in_ms() {
+        let metadata = get_metadata(AudioPlayer::Vlc);
+        let stream = StreamOptions::new().with_latency(Duration::from_millis(1500));
+        let command = build_player_command(
+            AudioPlayer::Vlc,
+            metadata,
+            &url_source(),
+            &PlaybackOptions::default(),
+            &stream,
+        )
+        .unwrap();
+
+        let args: Vec<_> = command.get_args().collect();
+        assert!(args.contains(&OsStr::new("--network-caching=1500")));
+    }
+
+    #[test]
+    fn stream_options_ignored_for_file_sources() {
+        let metadata = get_metadata(AudioPlayer::Mpv);
+        let stream = StreamOptions::new()
+            .with_buffer_size_kb(512)
+            .with_header("Authorization", "Bearer abc");
+        let command = build_player_command(
+            AudioPlayer::Mpv,
+            metadata,
+            &mock_source(),
+            &PlaybackOptions::default(),
+            &stream,
+        )
+        .unwrap();
+
+        let args: Vec<_> = command.get_args().collect();
+        assert!(!args.contains(&OsStr::new("--cache=yes")));
+        assert!(!args.iter().any(|a| a.to_string_lossy().contains("Authorization")));
+    }
+
+    #[test]
+    fn headers_rejected_for_players_without_support() {
+        let url = url::Url::parse("https://example.com/stream.mp3").unwrap();
+        let stream = StreamOptions::new().with_header("Authorization", "Bearer abc");
+        let result = check_stream_support(
+            AudioPlayer::Vlc,
+            get_metadata(AudioPlayer::Vlc),
+            &AudioData::Url(url),
+            &stream,
+        );
+
+        assert!(matches!(
+            result,
+            Err(PlaybackError::UnsupportedStreamHeaders {
+                player: AudioPlayer::Vlc
+            })
+        ));
+    }
+
+    #[test]
+    fn only_network_errors_and_server_errors_are_transient() {
+        use reqwest::StatusCode;
+
+        assert!(is_transient_status(None));
+        assert!(is_transient_status(Some(StatusCode::BAD_GATEWAY)));
+        assert!(is_transient_status(Some(StatusCode::SERVICE_UNAVAILABLE)));
+        assert!(!is_transient_status(Some(StatusCode::UNAUTHORIZED)));
+        assert!(!is_transient_status(Some(StatusCode::NOT_FOUND)));
+        assert!(!is_transient_status(Some(StatusCode::PARTIAL_CONTENT)));
+        assert!(!is_transient_failure(&mock_source(), &StreamOptions::new()));
+    }
+
+    #[test]
+    fn retries_only_apply_to_url_sources() {
+        let stream = StreamOptions::new().with_retries(2, Duration::ZERO);
+
+        assert!(should_retry(&url_source(), &stream, 0));
+        assert!(should_retry(&url_source(), &stream, 1));
+        assert!(!should_retry(&url_source(), &stream, 2));
+        assert!(!should_retry(&mock_source(), &stream, 0));
+    }
+
     // Async variant tests (feature-gated)
     #[cfg(feature = "async")]
     mod async_tests {
@@ -932,8 +1496,14 @@ mod tests {
             let metadata = get_metadata(AudioPlayer::Mpv);
             let source = mock_source();
             let options = PlaybackOptions::default();
-            let (binary, args) =
-                build_player_args(AudioPlayer::Mpv, metadata, &source, &options).unwrap();
+            let (binary, args) = build_player_args(
+                AudioPlayer::Mpv,
+                metadata,
+                &source,
+                &options,
+                &StreamOptions::default(),
+            )
+            .unwrap();
 
             assert_eq!(binary, "mpv");
             assert!(args.contains(&OsString::from("--no-video")));
@@ -946,8 +1516,14 @@ mod tests {
             let metadata = get_metadata(AudioPlayer::Mpv);
             let source = mock_source();
             let options = PlaybackOptions::new().with_volume(0.5).with_speed(1.25);
-            let (binary, args) =
-                build_player_args(AudioPlayer::Mpv, metadata, &source, &options).unwrap();
+            let (binary, args) = build_player_args(
+                AudioPlayer::Mpv,
+                metadata,
+                &source,
+                &options,
+                &StreamOptions::default(),
+            )
+            .unwrap();
 
             assert_eq!(binary, "mpv");
             assert!(args.contains(&OsString::from("--volume=50")));
@@ -959,8 +1535,14 @@ mod tests {
             let metadata = get_metadata(AudioPlayer::FfPlay);
             let source = mock_source();
             let options = PlaybackOptions::new().with_volume(0.75).with_speed(1.5);
-            let (binary, args) =
-                build_player_args(AudioPlayer::FfPlay, metadata, &source, &options).unwrap();
+            let (binary, args) = build_player_args(
+                AudioPlayer::FfPlay,
+                metadata,
+                &source,
+                &options,
+                &StreamOptions::default(),
+            )
+            .unwrap();
 
             assert_eq!(binary, "ffplay");
             assert!(args.contains(&OsString::from("-nodisp")));
@@ -975,8 +1557,14 @@ mod tests {
             let metadata = get_metadata(AudioPlayer::Sox);
             let source = mock_source();
             let options = PlaybackOptions::new().with_volume(0.8).with_speed(1.2);
-            let (binary, args) =
-                build_player_args(AudioPlayer::Sox, metadata, &source, &options).unwrap();
+            let (binary, args) = build_player_args(
+                AudioPlayer::Sox,
+                metadata,
+                &source,
+                &options,
+                &StreamOptions::default(),
+            )
+            .unwrap();
 
             assert_eq!(binary, "play");
             assert!(args.contains(&OsString::from("-q")));
@@ -123,6 +123,14 @@ impl Player {
         self.supports_volume_control
     }
 
+    /// Check if the player can send custom HTTP headers when streaming a URL.
+    pub fn supports_http_headers(&self) -> bool {
+        matches!(
+            self.id,
+            AudioPlayer::Mpv | AudioPlayer::FfPlay | AudioPlayer::MPlayer
+        )
+    }
+
     /// Calculate capability score for ranking.
     ///
     /// Higher scores indicate more capable players. Scoring:
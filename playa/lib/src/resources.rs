@@ -0,0 +1,238 @@
+//! Resource constraints for spawned player processes.
+//!
+//! Priorities are applied when the player is launched; the memory limit is
+//! enforced by polling the player's resident memory while it runs.
+
+use std::ffi::{OsStr, OsString};
+use std::process::{Child, ExitStatus};
+use std::time::Duration;
+
+use crate::error::PlaybackError;
+use crate::player::AudioPlayer;
+use crate::types::ProcessPriority;
+
+/// How often the memory watchdog samples the player process.
+const WATCHDOG_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Program and arguments that run a command at `priority`.
+///
+/// The player binary and its arguments follow the returned prefix. Returns
+/// an empty prefix for [`ProcessPriority::Normal`], on non-Unix platforms, and
+/// when `nice` is not installed.
+#[cfg(unix)]
+pub(crate) fn priority_prefix(priority: ProcessPriority) -> Vec<OsString> {
+    let (niceness, io_class, io_level) = match priority {
+        ProcessPriority::Normal => return Vec::new(),
+        ProcessPriority::Low => ("10", "2", Some("7")),
+        ProcessPriority::Idle => ("19", "3", None),
+    };
+
+    let mut prefix: Vec<OsString> = Vec::new();
+    if is_installed("nice") {
+        prefix.extend(["nice", "-n", niceness].map(OsString::from));
+    }
+    if cfg!(target_os = "linux") && is_installed("ionice") {
+        prefix.extend(["ionice", "-c", io_class].map(OsString::from));
+        if let Some(level) = io_level {
+            prefix.extend(["-n", level].map(OsString::from));
+        }
+    }
+    prefix
+}
+
+#[cfg(not(unix))]
+pub(crate) fn priority_prefix(_priority: ProcessPriority) -> Vec<OsString> {
+    Vec::new()
+}
+
+/// Windows process creation flags for `priority`.
+#[cfg(windows)]
+pub(crate) fn priority_creation_flags(priority: ProcessPriority) -> u32 {
+    const BELOW_NORMAL_PRIORITY_CLASS: u32 = 0x0000_4000;
+    const IDLE_PRIORITY_CLASS: u32 = 0x0000_0040;
+
+    match priority {
+        ProcessPriority::Normal => 0,
+        ProcessPriority::Low => BELOW_NORMAL_PRIORITY_CLASS,
+        ProcessPriority::Idle => IDLE_PRIORITY_CLASS,
+    }
+}
+
+/// Prepend the priority wrapper to a player invocation.
+pub(crate) fn with_priority(
+    priority: ProcessPriority,
+    binary: &OsStr,
+    args: Vec<OsString>,
+) -> (OsString, Vec<OsString>) {
+    let mut prefix = priority_prefix(priority).into_iter();
+    match prefix.next() {
+        Some(program) => {
+            let mut wrapped: Vec<OsString> = prefix.collect();
+            wrapped.push(binary.to_owned());
+            wrapped.extend(args);
+            (program, wrapped)
+        }
+        None => (binary.to_owned(), args),
+    }
+}
+
+#[cfg(unix)]
+fn is_installed(binary: &str) -> bool {
+    std::env::var_os("PATH")
+        .is_some_and(|path| std::env::split_paths(&path).any(|dir| dir.join(binary).is_file()))
+}
+
+/// Resident memory of the process `pid` in bytes.
+///
+/// Reads `/proc` on Linux and asks `ps` on other Unix systems. Returns `None`
+/// if the process has exited or the platform is unsupported.
+#[cfg(target_os = "linux")]
+pub(crate) fn resident_memory(pid: u32) -> Option<u64> {
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+pub(crate) fn resident_memory(pid: u32) -> Option<u64> {
+    let output = std::process::Command::new("ps")
+        .args(["-o", "rss=", "-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    let kb: u64 = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(not(unix))]
+pub(crate) fn resident_memory(_pid: u32) -> Option<u64> {
+    None
+}
+
+/// The memory used by `pid` if it exceeds `limit`.
+fn over_limit(pid: Option<u32>, limit: u64) -> Option<u64> {
+    resident_memory(pid?).filter(|used| *used > limit)
+}
+
+/// Wait for `child`, killing it if its memory exceeds `max_memory_bytes`.
+pub(crate) fn wait_with_watchdog(
+    player: AudioPlayer,
+    mut child: Child,
+    max_memory_bytes: Option<u64>,
+) -> Result<ExitStatus, PlaybackError> {
+    let Some(limit) = max_memory_bytes else {
+        return Ok(child.wait()?);
+    };
+
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+        if let Some(used) = over_limit(Some(child.id()), limit) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(PlaybackError::MemoryLimitExceeded {
+                player,
+                limit_bytes: limit,
+                used_bytes: used,
+            });
+        }
+        std::thread::sleep(WATCHDOG_INTERVAL);
+    }
+}
+
+/// Wait for `child`, killing it if its memory exceeds `max_memory_bytes` (async).
+#[cfg(feature = "async")]
+pub(crate) async fn wait_with_watchdog_async(
+    player: AudioPlayer,
+    mut child: tokio::process::Child,
+    max_memory_bytes: Option<u64>,
+) -> Result<ExitStatus, PlaybackError> {
+    let Some(limit) = max_memory_bytes else {
+        return Ok(child.wait().await?);
+    };
+
+    loop {
+        if let Ok(status) = tokio::time::timeout(WATCHDOG_INTERVAL, child.wait()).await {
+            return Ok(status?);
+        }
+        if let Some(used) = over_limit(child.id(), limit) {
+            let _ = child.kill().await;
+            return Err(PlaybackError::MemoryLimitExceeded {
+                player,
+                limit_bytes: limit,
+                used_bytes: used,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::{Command, Stdio};
+
+    #[test]
+    fn normal_priority_runs_player_directly() {
+        let (program, args) = with_priority(
+            ProcessPriority::Normal,
+            OsStr::new("mpv"),
+            vec![OsString::from("a.wav")],
+        );
+        assert_eq!(program, "mpv");
+        assert_eq!(args, vec![OsString::from("a.wav")]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn low_priority_wraps_player_with_nice() {
+        if !is_installed("nice") {
+            return;
+        }
+        let (program, args) = with_priority(
+            ProcessPriority::Low,
+            OsStr::new("mpv"),
+            vec![OsString::from("a.wav")],
+        );
+        assert_eq!(program, "nice");
+        assert_eq!(args[..2], [OsString::from("-n"), OsString::from("10")]);
+        assert_eq!(
+            args[args.len() - 2..],
+            [OsString::from("mpv"), OsString::from("a.wav")]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resident_memory_of_current_process() {
+        let used = resident_memory(std::process::id()).unwrap();
+        assert!(used > 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn watchdog_kills_process_over_limit() {
+        let child = Command::new("sleep")
+            .arg("5")
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap();
+
+        let err = wait_with_watchdog(AudioPlayer::Mpv, child, Some(1)).unwrap_err();
+        assert!(matches!(
+            err,
+            PlaybackError::MemoryLimitExceeded { limit_bytes: 1, used_bytes, .. } if used_bytes > 1
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn watchdog_returns_status_within_limit() {
+        let child = Command::new("true").spawn().unwrap();
+        let status = wait_with_watchdog(AudioPlayer::Mpv, child, Some(u64::MAX)).unwrap();
+        assert!(status.success());
+    }
+}
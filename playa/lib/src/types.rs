@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 /// Audio codecs (compression algorithms).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Codec {
@@ -53,7 +55,11 @@ impl AudioFormat {
 }
 
 /// CPU and memory usage classification for players.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Variants are ordered from least to most demanding, so a player fits within
+/// [`PlaybackOptions::max_resource_usage`] when its classification is `<=` the
+/// limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ResourceUsage {
     /// Minimal resource usage.
     Low,
@@ -63,6 +69,21 @@ pub enum ResourceUsage {
     High,
 }
 
+/// Scheduling priority for spawned player processes.
+///
+/// On Unix the player is launched through `nice` (and `ionice` on Linux when
+/// installed); on Windows the matching process priority class is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProcessPriority {
+    /// Inherit the caller's priority.
+    #[default]
+    Normal,
+    /// Below-normal CPU priority (`nice -n 10`, best-effort I/O at the lowest level).
+    Low,
+    /// Only run when the system is otherwise idle (`nice -n 19`, idle I/O class).
+    Idle,
+}
+
 /// Options for controlling audio playback.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct PlaybackOptions {
@@ -73,6 +94,16 @@ pub struct PlaybackOptions {
     /// Playback speed multiplier (1.0 = normal, <1.0 = slower, >1.0 = faster).
     /// Only applied if selected player supports speed control.
     pub speed: Option<f32>,
+
+    /// CPU and I/O priority of the player process.
+    pub priority: ProcessPriority,
+
+    /// Only consider players whose [`ResourceUsage`] is at or below this level.
+    pub max_resource_usage: Option<ResourceUsage>,
+
+    /// Abort playback if the player's resident memory exceeds this many bytes.
+    /// Not enforced on Windows.
+    pub max_memory_bytes: Option<u64>,
 }
 
 impl PlaybackOptions {
@@ -81,6 +112,9 @@ impl PlaybackOptions {
         Self {
             volume: None,
             speed: None,
+            priority: ProcessPriority::Normal,
+            max_resource_usage: None,
+            max_memory_bytes: None,
         }
     }
 
@@ -96,6 +130,29 @@ impl PlaybackOptions {
         self
     }
 
+    /// Set the player process priority.
+    pub const fn with_priority(mut self, priority: ProcessPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Only use players whose resource usage is at or below `usage`.
+    pub const fn with_max_resource_usage(mut self, usage: ResourceUsage) -> Self {
+        self.max_resource_usage = Some(usage);
+        self
+    }
+
+    /// Abort playback if the player uses more than `bytes` of resident memory.
+    pub const fn with_max_memory(mut self, bytes: u64) -> Self {
+        self.max_memory_bytes = Some(bytes);
+        self
+    }
+
+    /// Check if a player with the given resource usage fits within these options.
+    pub fn allows_resource_usage(&self, usage: ResourceUsage) -> bool {
+        self.max_resource_usage.is_none_or(|max| usage <= max)
+    }
+
     /// Check if any options require speed control capability.
     pub const fn requires_speed_control(&self) -> bool {
         self.speed.is_some()
@@ -106,3 +163,79 @@ impl PlaybackOptions {
         self.volume.is_some()
     }
 }
+
+/// Options for controlling network streaming of URL sources.
+///
+/// These settings only apply when the audio source is a URL. Buffer size and
+/// latency hints are translated into player-specific flags where the player
+/// supports them and silently ignored otherwise. HTTP headers are only passed
+/// to players that can inject them (see [`Player::supports_http_headers`]).
+///
+/// ## Security
+///
+/// Players take headers as command-line arguments, so header values
+/// (including `Authorization` tokens) are visible to other local users
+/// through the process list (e.g. `ps`) while the player runs. Prefer
+/// short-lived credentials, or a URL that doesn't need them, on shared
+/// machines.
+///
+/// [`Player::supports_http_headers`]: crate::Player::supports_http_headers
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StreamOptions {
+    /// Network buffer size in kilobytes.
+    pub buffer_size_kb: Option<u32>,
+
+    /// Target amount of audio to buffer before playback starts.
+    pub latency: Option<Duration>,
+
+    /// Extra HTTP headers sent with the request (e.g., `Authorization`).
+    ///
+    /// These end up on the player's command line; see the security note above.
+    pub headers: Vec<(String, String)>,
+
+    /// Number of additional attempts when playback fails for a URL source.
+    pub retries: u32,
+
+    /// Delay between retry attempts.
+    pub retry_delay: Duration,
+}
+
+impl StreamOptions {
+    /// Create stream options with default values (no buffering hints, no retries).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the network buffer size in kilobytes.
+    pub fn with_buffer_size_kb(mut self, kb: u32) -> Self {
+        self.buffer_size_kb = Some(kb);
+        self
+    }
+
+    /// Set the target buffering latency.
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    /// Add an HTTP header to send with the stream request.
+    ///
+    /// The header is passed to the player as a command-line argument, so
+    /// other local users can read it from the process list.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Retry playback up to `retries` additional times, waiting `delay` between attempts.
+    pub fn with_retries(mut self, retries: u32, delay: Duration) -> Self {
+        self.retries = retries;
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Check if these options require the player to inject HTTP headers.
+    pub fn requires_header_support(&self) -> bool {
+        !self.headers.is_empty()
+    }
+}
@@ -1,35 +1,55 @@
+mod report;
+mod status;
 mod tui;
 
+use std::io::{BufRead, IsTerminal, Write};
 use std::process::{Command, Stdio};
 
 use chrono::{Duration as ChronoDuration, Local, NaiveTime, TimeZone, Utc};
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 use crossterm::terminal;
 use queue_lib::{
     parse_at_time,
     parse_delay,
+    parse_memory_size,
+    ExecutionJournal,
     ExecutionTarget,
+    HistoryError,
+    InterruptedTask,
     JsonFileStore,
+    Notifications,
+    NotifyOn,
+    OutputLog,
+    RecoveryPolicy,
+    ResourceLimits,
     ScheduledTask,
     TerminalDetector,
 };
 use thiserror::Error;
 
+use crate::report::{run_report, ReportOptions};
+use crate::status::{run_status, StatusOptions};
 use crate::tui::{run_app, App};
 
 /// Queue commands for later execution with an interactive TUI.
 ///
-/// All invocations open the TUI. Use --at or --in to pre-schedule a task.
+/// Invocations without a subcommand open the TUI. Use --at or --in to
+/// pre-schedule a task.
 ///
 /// Examples:
 ///   queue                                    # Open TUI
 ///   queue --at 7:00am "echo 'good morning'"  # Open TUI with pre-scheduled task
 ///   queue --in 15m "echo 'reminder'"         # Open TUI with task in 15 minutes
+///   queue status --follow                    # Watch running and upcoming tasks
 #[derive(Debug, Parser)]
 #[command(name = "queue")]
 #[command(version)]
 #[command(about = "Queue commands for later execution with an interactive TUI")]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    subcommand: Option<Commands>,
+
     /// Schedule the command for the next occurrence of a time.
     #[arg(long, value_parser = parse_at_time, value_name = "TIME", conflicts_with = "in_delay")]
     at: Option<NaiveTime>,
@@ -38,6 +58,39 @@ struct Cli {
     #[arg(long = "in", value_parser = parse_delay, value_name = "DELAY", conflicts_with = "at")]
     in_delay: Option<ChronoDuration>,
 
+    /// Run the command with a CPU niceness from -20 (highest priority) to 19.
+    #[arg(
+        long,
+        value_name = "N",
+        allow_negative_numbers = true,
+        value_parser = clap::value_parser!(i32).range(-20..=19)
+    )]
+    nice: Option<i32>,
+
+    /// Limit the command's memory (e.g., 512M, 2G).
+    #[arg(long, value_parser = parse_memory_size, value_name = "SIZE")]
+    max_memory: Option<u64>,
+
+    /// Kill the command if it runs longer than this (e.g., 30s, 2h).
+    #[arg(long, value_parser = parse_delay, value_name = "DURATION")]
+    timeout: Option<ChronoDuration>,
+
+    /// Notify when the command finishes: terminal, speak (comma-separated).
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "CHANNELS")]
+    notify: Vec<NotifyChannel>,
+
+    /// POST the task result as JSON to this URL when the command finishes.
+    #[arg(long, value_name = "URL")]
+    webhook: Option<String>,
+
+    /// Which outcomes trigger notifications: finished, success, or failure.
+    #[arg(long, value_name = "WHEN", default_value_t = NotifyOn::Finished)]
+    notify_on: NotifyOn,
+
+    /// What to do with tasks interrupted by a crash: rerun, mark-failed, or ask.
+    #[arg(long, value_name = "POLICY", default_value_t = RecoveryPolicy::Ask)]
+    recover: RecoveryPolicy,
+
     /// Enable debug logging to ~/.queue-debug.log.
     #[arg(long)]
     debug: bool,
@@ -51,6 +104,40 @@ struct Cli {
     command: Option<String>,
 }
 
+/// A notification channel selectable with `--notify`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum NotifyChannel {
+    /// Desktop notification through the terminal.
+    Terminal,
+    /// Spoken alert via text-to-speech.
+    Speak,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Show running tasks and the next scheduled runs.
+    Status {
+        /// Keep refreshing until interrupted (Ctrl+C).
+        #[arg(long, short)]
+        follow: bool,
+
+        /// Number of captured output lines to show per running task.
+        #[arg(long, value_name = "N", default_value_t = 5)]
+        lines: usize,
+    },
+
+    /// Summarize recent runs: success rate, average duration, and failure times.
+    Report {
+        /// How far back to look (e.g., 24h, 7d).
+        #[arg(long, value_parser = parse_delay, value_name = "DURATION", default_value = "7d")]
+        since: ChronoDuration,
+
+        /// Print the report as JSON.
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 #[derive(Debug, Error)]
 enum QueueError {
     #[error("TUI error: {0}")]
@@ -61,6 +148,15 @@ enum QueueError {
 
     #[error("failed to spawn TUI pane: {0}")]
     SpawnPane(String),
+
+    #[error("history error: {0}")]
+    History(HistoryError),
+
+    #[error("failed to write status: {0}")]
+    Status(std::io::Error),
+
+    #[error("failed to write report: {0}")]
+    Report(std::io::Error),
 }
 
 fn main() -> Result<(), QueueError> {
@@ -71,6 +167,19 @@ fn main() -> Result<(), QueueError> {
         init_debug_logging()?;
     }
 
+    match cli.subcommand {
+        Some(Commands::Status { follow, lines }) => {
+            return run_status(StatusOptions {
+                follow,
+                tail_lines: lines,
+            });
+        }
+        Some(Commands::Report { since, json }) => {
+            return run_report(ReportOptions { since, json });
+        }
+        None => {}
+    }
+
     // In Wezterm, split the pane and spawn the TUI in the bottom pane,
     // unless we're already running in the TUI pane (--tui-pane flag).
     if TerminalDetector::is_wezterm() && !cli.tui_pane {
@@ -80,7 +189,7 @@ fn main() -> Result<(), QueueError> {
     // Build the initial task if --at or --in was provided
     let initial_task = build_initial_task(&cli);
 
-    run_tui(initial_task)
+    run_tui(initial_task, cli.recover)
 }
 
 /// Spawns the TUI in a new bottom pane and exits.
@@ -99,6 +208,8 @@ fn spawn_tui_in_split_pane(cli: &Cli) -> Result<(), QueueError> {
         args.push("--debug".to_string());
     }
 
+    args.push(format!("--recover={}", cli.recover));
+
     if let Some(ref time) = cli.at {
         args.push("--at".to_string());
         args.push(time.format("%H:%M").to_string());
@@ -119,6 +230,33 @@ fn spawn_tui_in_split_pane(cli: &Cli) -> Result<(), QueueError> {
         }
     }
 
+    if let Some(nice) = cli.nice {
+        args.push(format!("--nice={nice}"));
+    }
+
+    if let Some(bytes) = cli.max_memory {
+        args.push("--max-memory".to_string());
+        args.push(bytes.to_string());
+    }
+
+    if let Some(ref timeout) = cli.timeout {
+        args.push("--timeout".to_string());
+        args.push(format!("{}s", timeout.num_seconds()));
+    }
+
+    for channel in &cli.notify {
+        if let Some(value) = channel.to_possible_value() {
+            args.push(format!("--notify={}", value.get_name()));
+        }
+    }
+
+    if let Some(ref url) = cli.webhook {
+        args.push("--webhook".to_string());
+        args.push(url.clone());
+    }
+
+    args.push(format!("--notify-on={}", cli.notify_on));
+
     if let Some(ref cmd) = cli.command {
         args.push(cmd.clone());
     }
@@ -214,12 +352,49 @@ fn build_initial_task(cli: &Cli) -> Option<ScheduledTask> {
         _ => None,
     }?;
 
-    Some(ScheduledTask::new(
-        1,
-        command.clone(),
-        scheduled_at,
-        ExecutionTarget::default(),
-    ))
+    let limits = build_limits(cli);
+    // Limits are only enforced for background tasks
+    let target = if limits.is_unlimited() {
+        ExecutionTarget::default()
+    } else {
+        ExecutionTarget::Background
+    };
+
+    Some(
+        ScheduledTask::new(1, command.clone(), scheduled_at, target)
+            .with_limits(limits)
+            .with_notifications(build_notifications(cli)),
+    )
+}
+
+/// Collects the `--notify`, `--webhook` and `--notify-on` flags into notifications.
+fn build_notifications(cli: &Cli) -> Notifications {
+    let mut notify = Notifications::default().on(cli.notify_on);
+    if cli.notify.contains(&NotifyChannel::Terminal) {
+        notify = notify.with_terminal();
+    }
+    if cli.notify.contains(&NotifyChannel::Speak) {
+        notify = notify.with_speech();
+    }
+    if let Some(ref url) = cli.webhook {
+        notify = notify.with_webhook(url.clone());
+    }
+    notify
+}
+
+/// Collects the `--nice`, `--max-memory` and `--timeout` flags into resource limits.
+fn build_limits(cli: &Cli) -> ResourceLimits {
+    let mut limits = ResourceLimits::default();
+    if let Some(nice) = cli.nice {
+        limits = limits.with_nice(nice);
+    }
+    if let Some(bytes) = cli.max_memory {
+        limits = limits.with_max_memory(bytes);
+    }
+    if let Some(timeout) = cli.timeout.and_then(|t| t.to_std().ok()) {
+        limits = limits.with_max_runtime(timeout);
+    }
+    limits
 }
 
 /// Initializes debug logging to ~/.queue-debug.log.
@@ -245,8 +420,81 @@ fn init_debug_logging() -> Result<(), QueueError> {
     Ok(())
 }
 
+/// Opens the history store at its default path, encrypting commands and
+/// error messages when `QUEUE_HISTORY_KEY` holds a key.
+fn history_store() -> Result<JsonFileStore, QueueError> {
+    JsonFileStore::default_path()
+        .with_env_key()
+        .map_err(QueueError::History)
+}
+
+/// Opens the execution journal at its default path, encrypting commands and
+/// error messages when `QUEUE_HISTORY_KEY` holds a key.
+fn execution_journal() -> Result<ExecutionJournal, QueueError> {
+    ExecutionJournal::default_path()
+        .with_env_key()
+        .map_err(QueueError::History)
+}
+
+/// Opens the output log at its default location, encrypting captured output
+/// when `QUEUE_HISTORY_KEY` holds a key.
+fn output_log() -> Result<OutputLog, QueueError> {
+    OutputLog::default_path()
+        .with_env_key()
+        .map_err(QueueError::History)
+}
+
+/// Resolves tasks interrupted by a previous crash, returning those to rerun.
+///
+/// Recovery problems are logged rather than fatal so the TUI still opens.
+fn recover_interrupted(
+    journal: &ExecutionJournal,
+    store: &JsonFileStore,
+    policy: RecoveryPolicy,
+) -> Vec<ScheduledTask> {
+    match journal.recover(store, policy, confirm_rerun) {
+        Ok(tasks) => tasks,
+        Err(err) => {
+            tracing::warn!(error = %err, "Failed to recover interrupted tasks");
+            Vec::new()
+        }
+    }
+}
+
+/// Asks on the terminal whether an interrupted task should run again.
+///
+/// Without an interactive terminal the task is not rerun.
+fn confirm_rerun(interrupted: &InterruptedTask) -> bool {
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        return false;
+    }
+
+    let state = if interrupted.started_at.is_some() {
+        "while it was running"
+    } else {
+        "before it started"
+    };
+    let mut stderr = std::io::stderr();
+    let _ = write!(
+        stderr,
+        "Task {} ({}) was interrupted {state}. Run it again? [y/N] ",
+        interrupted.task.id, interrupted.task.command
+    );
+    let _ = stderr.flush();
+
+    let mut answer = String::new();
+    if stdin.lock().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
 /// Runs the TUI application with proper terminal setup and cleanup.
-fn run_tui(initial_task: Option<ScheduledTask>) -> Result<(), QueueError> {
+fn run_tui(
+    initial_task: Option<ScheduledTask>,
+    recover: RecoveryPolicy,
+) -> Result<(), QueueError> {
     // Build a tokio runtime for the executor
     let runtime = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
@@ -256,6 +504,12 @@ fn run_tui(initial_task: Option<ScheduledTask>) -> Result<(), QueueError> {
     // Enter the runtime context for the executor to spawn tasks
     let _guard = runtime.enter();
 
+    // Resolve tasks a previous crash left unfinished (may prompt, so before the TUI takes over)
+    let journal = execution_journal()?;
+    let history = history_store()?;
+    let output_log = output_log()?;
+    let recovered = recover_interrupted(&journal, &history, recover);
+
     // Set up panic hook for terminal cleanup
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
@@ -269,7 +523,9 @@ fn run_tui(initial_task: Option<ScheduledTask>) -> Result<(), QueueError> {
     // Create app with executor
     let mut app = App::new()
         .with_executor()
-        .with_history_store(JsonFileStore::default_path());
+        .with_output_log(output_log)
+        .with_journal(journal)
+        .with_history_store(history);
 
     // In Wezterm, get the current pane ID so tasks can create panes relative to the TUI.
     // When running with --tui-pane, we're in the bottom pane. Tasks with NewPane target
@@ -283,6 +539,14 @@ fn run_tui(initial_task: Option<ScheduledTask>) -> Result<(), QueueError> {
         executor.set_task_pane_id_sync(task_pane);
     }
 
+    // Rerun recovered tasks before anything newly scheduled
+    for task in recovered {
+        app.schedule_task(task);
+    }
+
+    // Pick up recurring tasks registered while no TUI was running
+    app.schedule_recurring_from_history();
+
     // Add initial task if provided
     if let Some(mut task) = initial_task {
         task.id = app.alloc_task_id();
@@ -365,6 +629,44 @@ mod tests {
         assert_eq!(cli.command, Some("echo hello".to_string()));
     }
 
+    #[test]
+    fn clap_accepts_status_subcommand() {
+        let cli = Cli::try_parse_from(["queue", "status", "--follow", "--lines", "3"]).unwrap();
+        match cli.subcommand {
+            Some(Commands::Status { follow, lines }) => {
+                assert!(follow);
+                assert_eq!(lines, 3);
+            }
+            _ => panic!("expected status subcommand"),
+        }
+    }
+
+    #[test]
+    fn clap_accepts_report_subcommand() {
+        let cli = Cli::try_parse_from(["queue", "report", "--since", "24h", "--json"]).unwrap();
+        match cli.subcommand {
+            Some(Commands::Report { since, json }) => {
+                assert_eq!(since, ChronoDuration::hours(24));
+                assert!(json);
+            }
+            _ => panic!("expected report subcommand"),
+        }
+
+        let cli = Cli::try_parse_from(["queue", "report"]).unwrap();
+        assert!(matches!(
+            cli.subcommand,
+            Some(Commands::Report { since, json: false }) if since == ChronoDuration::days(7)
+        ));
+    }
+
+    #[test]
+    fn clap_treats_status_after_schedule_args_as_command() {
+        // Once scheduling args are given, `status` is the command to schedule
+        let cli = Cli::try_parse_from(["queue", "--in", "15m", "status"]).unwrap();
+        assert!(cli.subcommand.is_none());
+        assert_eq!(cli.command, Some("status".to_string()));
+    }
+
     #[test]
     fn build_initial_task_returns_none_without_schedule() {
         let cli = Cli::try_parse_from(["queue"]).unwrap();
@@ -389,6 +691,58 @@ mod tests {
         assert_eq!(task.command, "echo hello");
     }
 
+    #[test]
+    fn build_initial_task_applies_resource_limits() {
+        let cli = Cli::try_parse_from([
+            "queue",
+            "--in",
+            "15m",
+            "--nice",
+            "-5",
+            "--max-memory",
+            "512M",
+            "--timeout",
+            "2h",
+            "make build",
+        ])
+        .unwrap();
+        let task = build_initial_task(&cli).unwrap();
+        assert_eq!(task.target, ExecutionTarget::Background);
+        assert_eq!(task.limits.nice, Some(-5));
+        assert_eq!(task.limits.max_memory_bytes, Some(512 * 1024 * 1024));
+        assert_eq!(task.limits.max_runtime_secs, Some(7200));
+    }
+
+    #[test]
+    fn build_initial_task_applies_notifications() {
+        let cli = Cli::try_parse_from([
+            "queue",
+            "--in",
+            "15m",
+            "--notify",
+            "terminal,speak",
+            "--webhook",
+            "https://example.com/hook",
+            "--notify-on",
+            "failure",
+            "make build",
+        ])
+        .unwrap();
+        let task = build_initial_task(&cli).unwrap();
+        assert!(task.notify.terminal);
+        assert!(task.notify.speak);
+        assert_eq!(task.notify.webhook.as_deref(), Some("https://example.com/hook"));
+        assert_eq!(task.notify.on, NotifyOn::Failure);
+
+        let cli = Cli::try_parse_from(["queue", "--in", "15m", "make build"]).unwrap();
+        assert!(build_initial_task(&cli).unwrap().notify.is_empty());
+    }
+
+    #[test]
+    fn nice_flag_rejects_out_of_range_values() {
+        assert!(Cli::try_parse_from(["queue", "--nice", "20", "echo hi"]).is_err());
+    }
+
     // =========================================================================
     // Regression tests for Bug 1: TUI should split and move to bottom pane
     // =========================================================================
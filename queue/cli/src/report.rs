@@ -0,0 +1,196 @@
+//! The `queue report` command.
+//!
+//! Summarizes recorded runs over a time window: success rate and average
+//! duration per command, plus the hours of the day when failures happen.
+//! The data comes from the run log the TUI appends to whenever a task
+//! finishes. With `--json` the raw [`HistoryReport`] is printed instead of a
+//! table, for use in scripts.
+
+use std::io::{self, Write};
+
+use biscuit_terminal::discovery::detection::is_tty;
+use biscuit_terminal::terminal::Terminal;
+use biscuit_terminal::utils::block_constraint::truncate;
+use chrono::{DateTime, Local, TimeDelta, Utc};
+use queue_lib::{HistoryReport, HistoryStore};
+
+use crate::QueueError;
+use crate::status::{dim, format_duration, heading};
+
+/// Maximum width of the command column.
+const MAX_COMMAND_WIDTH: usize = 40;
+
+/// Longest bar drawn in the failures-by-hour chart.
+const MAX_BAR_WIDTH: usize = 30;
+
+/// Indicator appended to commands that are cut to the column width.
+const TRUNCATION_INDICATOR: &str = "…";
+
+/// Options for the report command.
+#[derive(Debug, Clone, Copy)]
+pub struct ReportOptions {
+    /// How far back to look.
+    pub since: TimeDelta,
+    /// Print JSON instead of a table.
+    pub json: bool,
+}
+
+/// Runs `queue report`.
+pub fn run_report(options: ReportOptions) -> Result<(), QueueError> {
+    let store = crate::history_store()?;
+    let report = store
+        .report(Utc::now() - options.since)
+        .map_err(QueueError::History)?;
+    let mut stdout = io::stdout();
+
+    if options.json {
+        serde_json::to_writer_pretty(&mut stdout, &report)
+            .map_err(io::Error::from)
+            .and_then(|()| writeln!(stdout))
+            .map_err(QueueError::Report)?;
+    } else {
+        let styled = is_tty();
+        let width = if styled { Terminal::width() } else { u32::MAX };
+        writeln!(stdout, "{}", render(&report, styled, width)).map_err(QueueError::Report)?;
+    }
+    stdout.flush().map_err(QueueError::Report)
+}
+
+/// Renders the report as a table, `width` columns wide.
+///
+/// When `styled` is false the output contains no escape codes.
+fn render(report: &HistoryReport, styled: bool, width: u32) -> String {
+    let indicator = TRUNCATION_INDICATOR.to_string();
+    let mut lines = Vec::new();
+
+    lines.push(heading(
+        &format!(
+            "Runs since {} ({} runs, {} failed)",
+            format_timestamp(report.since),
+            report.total_runs,
+            report.total_failures
+        ),
+        styled,
+    ));
+    if report.commands.is_empty() {
+        lines.push(dim("  no runs recorded", styled));
+        return lines.join("\n");
+    }
+
+    let command_width = report
+        .commands
+        .iter()
+        .map(|stats| stats.command.chars().count())
+        .max()
+        .unwrap_or(0)
+        .clamp("COMMAND".len(), MAX_COMMAND_WIDTH);
+
+    lines.push(String::new());
+    lines.push(dim(
+        &format!(
+            "  {:<command_width$}  {:>5}  {:>7}  {:>8}  LAST RUN",
+            "COMMAND", "RUNS", "SUCCESS", "AVG TIME"
+        ),
+        styled,
+    ));
+    for stats in &report.commands {
+        let command = truncate(stats.command.clone(), &indicator, &(command_width as u32));
+        let average = stats
+            .average_duration_secs
+            .map(|secs| format_duration(TimeDelta::milliseconds((secs * 1000.0) as i64)))
+            .unwrap_or_else(|| "-".to_string());
+        let line = format!(
+            "  {command:<command_width$}  {:>5}  {:>6.0}%  {average:>8}  {}",
+            stats.runs,
+            stats.success_rate * 100.0,
+            format_timestamp(stats.last_run)
+        );
+        lines.push(truncate(line, &indicator, &width));
+    }
+
+    lines.push(String::new());
+    lines.push(heading("Failures by hour", styled));
+    let busiest = report.failures_by_hour.iter().copied().max().unwrap_or(0);
+    if busiest == 0 {
+        lines.push(dim("  no failures", styled));
+    }
+    for (hour, &count) in report.failures_by_hour.iter().enumerate() {
+        if count == 0 {
+            continue;
+        }
+        let bar = "█".repeat((count * MAX_BAR_WIDTH).div_ceil(busiest));
+        lines.push(truncate(
+            format!("  {hour:02}:00  {bar} {count}"),
+            &indicator,
+            &width,
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Formats a timestamp in local time, e.g. `03-10 14:02`.
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp
+        .with_timezone(&Local)
+        .format("%m-%d %H:%M")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use queue_lib::{RunRecord, TaskStatus};
+
+    fn run(command: &str, status: TaskStatus, finished_at: DateTime<Utc>) -> RunRecord {
+        RunRecord {
+            task_id: 1,
+            command: command.to_string(),
+            status,
+            started_at: Some(finished_at - TimeDelta::seconds(90)),
+            finished_at,
+        }
+    }
+
+    #[test]
+    fn render_lists_commands_and_failure_hours() {
+        let now = Utc::now();
+        let runs = vec![
+            run("cargo test", TaskStatus::Completed, now),
+            run(
+                "cargo test",
+                TaskStatus::Failed {
+                    error: "exit code 101".to_string(),
+                },
+                now,
+            ),
+        ];
+        let report = HistoryReport::from_runs(&runs, now - TimeDelta::days(7), &Local);
+
+        let text = render(&report, false, u32::MAX);
+
+        assert!(
+            !text.contains('\x1b'),
+            "plain output contained escapes: {text:?}"
+        );
+        assert!(text.contains("(2 runs, 1 failed)"));
+        assert!(text.contains("cargo test"));
+        assert!(text.contains("50%"));
+        assert!(text.contains("1m 30s"));
+        let hour = now.with_timezone(&Local).format("%H:00").to_string();
+        assert!(
+            text.contains(&format!("{hour}  ")),
+            "missing failure hour: {text}"
+        );
+    }
+
+    #[test]
+    fn render_handles_empty_report() {
+        let report = HistoryReport::from_runs(&[], Utc::now() - TimeDelta::days(7), &Local);
+
+        let text = render(&report, false, u32::MAX);
+
+        assert!(text.contains("no runs recorded"));
+        assert!(!text.contains("Failures by hour"));
+    }
+}
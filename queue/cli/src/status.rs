@@ -0,0 +1,398 @@
+//! The `queue status` command.
+//!
+//! Shows tasks that are currently running (with elapsed time and the tail of
+//! their captured output) and the next scheduled runs. The data comes from
+//! the history file, which the TUI updates as the executor reports status
+//! changes, and from the per-task [`OutputLog`].
+//!
+//! With `--follow` the view refreshes once a second. On a TTY the screen is
+//! redrawn in place with bold/dim styling (dropped when `NO_COLOR` is set);
+//! otherwise plain text is printed and a new snapshot is only written when
+//! something changes.
+
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use biscuit_terminal::discovery::detection::is_tty;
+use biscuit_terminal::terminal::Terminal;
+use biscuit_terminal::utils::block_constraint::truncate;
+use biscuit_terminal::utils::escape_codes::strip_escape_codes;
+use biscuit_terminal::utils::font_weight;
+use chrono::{DateTime, Local, TimeDelta, Utc};
+use queue_lib::{ExecutionTarget, HistoryError, HistoryStore, OutputLog, ScheduledTask};
+
+use crate::QueueError;
+
+/// How often `--follow` refreshes the view.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Maximum number of upcoming runs to list.
+const MAX_UPCOMING: usize = 5;
+
+/// Indicator appended to lines that are cut to the terminal width.
+const TRUNCATION_INDICATOR: &str = "…";
+
+/// Options for the status command.
+#[derive(Debug, Clone, Copy)]
+pub struct StatusOptions {
+    /// Keep refreshing until interrupted.
+    pub follow: bool,
+    /// Number of captured output lines to show per running task.
+    pub tail_lines: usize,
+}
+
+/// A running task together with the tail of its captured output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RunningTask {
+    task: ScheduledTask,
+    output: Vec<String>,
+}
+
+/// Everything shown by a single render of the status view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct StatusSnapshot {
+    running: Vec<RunningTask>,
+    upcoming: Vec<ScheduledTask>,
+}
+
+impl StatusSnapshot {
+    /// Builds a snapshot from the task history and captured output.
+    fn collect(
+        store: &impl HistoryStore,
+        output_log: &OutputLog,
+        tail_lines: usize,
+    ) -> Result<Self, HistoryError> {
+        let tasks = store.load_all()?;
+
+        let mut running: Vec<RunningTask> = tasks
+            .iter()
+            .filter(|task| task.is_running())
+            .map(|task| RunningTask {
+                task: task.clone(),
+                // Output is best-effort; a missing or unreadable log just shows nothing
+                output: output_log.tail(task.id, tail_lines).unwrap_or_default(),
+            })
+            .collect();
+        running.sort_by_key(|running| (running.task.started_at, running.task.id));
+
+        let mut upcoming: Vec<ScheduledTask> = tasks
+            .into_iter()
+            .filter(ScheduledTask::is_pending)
+            .collect();
+        upcoming.sort_by_key(|task| (task.scheduled_at, task.id));
+        upcoming.truncate(MAX_UPCOMING);
+
+        Ok(Self { running, upcoming })
+    }
+
+    /// Renders the snapshot as text, `width` columns wide.
+    ///
+    /// When `styled` is false the output contains no escape codes.
+    fn render(&self, now: DateTime<Utc>, styled: bool, width: u32) -> String {
+        let indicator = TRUNCATION_INDICATOR.to_string();
+        let fit = |line: String| truncate(line, &indicator, &width);
+        let mut lines = Vec::new();
+
+        lines.push(heading(
+            &format!("Running ({})", self.running.len()),
+            styled,
+        ));
+        if self.running.is_empty() {
+            lines.push(dim("  nothing running", styled));
+        }
+        for running in &self.running {
+            let task = &running.task;
+            let elapsed = task
+                .elapsed(now)
+                .map(format_duration)
+                .unwrap_or_else(|| "-".to_string());
+            lines.push(fit(format!(
+                "  #{:<4} {:>8}  {}",
+                task.id, elapsed, task.command
+            )));
+
+            if task.target != ExecutionTarget::Background {
+                let location = match task.target {
+                    ExecutionTarget::NewPane => "pane",
+                    _ => "window",
+                };
+                lines.push(dim(
+                    &format!("         output shown in its {location}"),
+                    styled,
+                ));
+            } else if running.output.is_empty() {
+                lines.push(dim("         (no output yet)", styled));
+            }
+            for output in &running.output {
+                let output = fit(format!(
+                    "         │ {}",
+                    strip_escape_codes(output.as_str())
+                ));
+                lines.push(dim(&output, styled));
+            }
+        }
+
+        lines.push(String::new());
+        lines.push(heading(&format!("Next ({})", self.upcoming.len()), styled));
+        if self.upcoming.is_empty() {
+            lines.push(dim("  nothing scheduled", styled));
+        }
+        for task in &self.upcoming {
+            lines.push(fit(format!(
+                "  #{:<4} {:>8}  {}  {}",
+                task.id,
+                format_countdown(task.scheduled_at, now),
+                task.scheduled_at.with_timezone(&Local).format("%H:%M"),
+                task.command
+            )));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Runs `queue status`, optionally following until interrupted.
+pub fn run_status(options: StatusOptions) -> Result<(), QueueError> {
+    let store = crate::history_store()?;
+    let output_log = crate::output_log()?;
+    let styled = is_tty();
+    let mut stdout = io::stdout();
+    let mut previous: Option<StatusSnapshot> = None;
+
+    loop {
+        let snapshot = StatusSnapshot::collect(&store, &output_log, options.tail_lines)
+            .map_err(QueueError::History)?;
+        let now = Utc::now();
+
+        if styled {
+            let frame = snapshot.render(now, true, Terminal::width());
+            if options.follow {
+                // Redraw in place: home the cursor, then clear the screen
+                write!(stdout, "\x1b[H\x1b[2J{frame}\n\n")
+                    .and_then(|()| writeln!(stdout, "{}", dim("Ctrl+C to exit", true)))
+                    .map_err(QueueError::Status)?;
+            } else {
+                writeln!(stdout, "{frame}").map_err(QueueError::Status)?;
+            }
+        } else if previous.as_ref() != Some(&snapshot) {
+            // Without a TTY there is no width to fit and no screen to redraw;
+            // only emit a new snapshot when the underlying data changes.
+            if previous.is_some() {
+                writeln!(stdout).map_err(QueueError::Status)?;
+            }
+            let frame = snapshot.render(now, false, u32::MAX);
+            writeln!(stdout, "{frame}").map_err(QueueError::Status)?;
+        }
+        stdout.flush().map_err(QueueError::Status)?;
+
+        if !options.follow {
+            return Ok(());
+        }
+        previous = Some(snapshot);
+        thread::sleep(REFRESH_INTERVAL);
+    }
+}
+
+/// Bolds `text` when `styled`, unless `NO_COLOR` is set.
+pub(crate) fn heading(text: &str, styled: bool) -> String {
+    if styled {
+        font_weight::bold(text, None)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Dims `text` when `styled`, unless `NO_COLOR` is set.
+pub(crate) fn dim(text: &str, styled: bool) -> String {
+    if styled {
+        font_weight::dim(text, None)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Formats a duration compactly, e.g. `45s`, `3m 12s`, `1h 05m`.
+pub(crate) fn format_duration(duration: TimeDelta) -> String {
+    let total = duration.num_seconds().max(0);
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+/// Formats the time until a scheduled run, e.g. `in 5m 00s` or `overdue`.
+fn format_countdown(scheduled_at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    if scheduled_at <= now {
+        "overdue".to_string()
+    } else {
+        format!("in {}", format_duration(scheduled_at - now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+    use queue_lib::JsonFileStore;
+    use tempfile::TempDir;
+
+    fn make_task(id: u64, command: &str, scheduled_at: DateTime<Utc>) -> ScheduledTask {
+        ScheduledTask::new(
+            id,
+            command.to_string(),
+            scheduled_at,
+            ExecutionTarget::Background,
+        )
+    }
+
+    fn create_test_env() -> (JsonFileStore, OutputLog, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let store = JsonFileStore::new(temp_dir.path().join("history.jsonl"));
+        let log = OutputLog::new(temp_dir.path().join("output"));
+        (store, log, temp_dir)
+    }
+
+    #[test]
+    fn format_duration_picks_largest_units() {
+        assert_eq!(format_duration(TimeDelta::seconds(45)), "45s");
+        assert_eq!(format_duration(TimeDelta::seconds(192)), "3m 12s");
+        assert_eq!(format_duration(TimeDelta::seconds(3900)), "1h 05m");
+        assert_eq!(format_duration(TimeDelta::seconds(-5)), "0s");
+    }
+
+    #[test]
+    fn format_countdown_marks_past_runs_overdue() {
+        let now = Utc::now();
+        assert_eq!(
+            format_countdown(now - ChronoDuration::minutes(1), now),
+            "overdue"
+        );
+        assert_eq!(
+            format_countdown(now + ChronoDuration::minutes(5), now),
+            "in 5m 00s"
+        );
+    }
+
+    #[test]
+    fn collect_splits_running_and_upcoming() {
+        let (store, log, _temp_dir) = create_test_env();
+        let now = Utc::now();
+
+        let mut running = make_task(1, "cargo build", now);
+        running.mark_running();
+        store.save(&running).unwrap();
+        store
+            .save(&make_task(2, "later", now + ChronoDuration::hours(2)))
+            .unwrap();
+        store
+            .save(&make_task(3, "sooner", now + ChronoDuration::hours(1)))
+            .unwrap();
+        let mut done = make_task(4, "done", now);
+        done.mark_completed();
+        store.save(&done).unwrap();
+
+        let mut file = log.create(1).unwrap();
+        writeln!(file, "Compiling a\nCompiling b\nCompiling c").unwrap();
+
+        let snapshot = StatusSnapshot::collect(&store, &log, 2).unwrap();
+
+        assert_eq!(snapshot.running.len(), 1);
+        assert_eq!(snapshot.running[0].task.id, 1);
+        assert_eq!(
+            snapshot.running[0].output,
+            vec!["Compiling b", "Compiling c"]
+        );
+        let upcoming: Vec<u64> = snapshot.upcoming.iter().map(|task| task.id).collect();
+        assert_eq!(upcoming, vec![3, 2]);
+    }
+
+    #[test]
+    fn collect_limits_upcoming_runs() {
+        let (store, log, _temp_dir) = create_test_env();
+        let now = Utc::now();
+        for id in 1..=(MAX_UPCOMING as u64 + 3) {
+            store
+                .save(&make_task(
+                    id,
+                    &format!("echo {id}"),
+                    now + ChronoDuration::minutes(id as i64),
+                ))
+                .unwrap();
+        }
+
+        let snapshot = StatusSnapshot::collect(&store, &log, 0).unwrap();
+
+        assert_eq!(snapshot.upcoming.len(), MAX_UPCOMING);
+    }
+
+    #[test]
+    fn plain_render_has_no_escape_codes() {
+        let now = Utc::now();
+        let mut task = make_task(1, "cargo build", now);
+        task.mark_running();
+        let snapshot = StatusSnapshot {
+            running: vec![RunningTask {
+                task,
+                output: vec!["\x1b[32mCompiling\x1b[0m queue".to_string()],
+            }],
+            upcoming: vec![make_task(2, "echo later", now + ChronoDuration::minutes(5))],
+        };
+
+        let text = snapshot.render(now, false, u32::MAX);
+
+        assert!(
+            !text.contains('\x1b'),
+            "plain output contained escapes: {text:?}"
+        );
+        assert!(text.contains("Running (1)"));
+        assert!(text.contains("cargo build"));
+        assert!(text.contains("│ Compiling queue"));
+        assert!(text.contains("Next (1)"));
+        assert!(text.contains("echo later"));
+    }
+
+    #[test]
+    fn styled_render_fits_width() {
+        let now = Utc::now();
+        let mut task = make_task(1, &"x".repeat(200), now);
+        task.mark_running();
+        let snapshot = StatusSnapshot {
+            running: vec![RunningTask {
+                task,
+                output: vec!["y".repeat(200)],
+            }],
+            upcoming: Vec::new(),
+        };
+
+        let text = snapshot.render(now, true, 40);
+
+        for line in strip_escape_codes(text).lines() {
+            assert!(line.chars().count() <= 40, "line too wide: {line:?}");
+        }
+    }
+
+    #[test]
+    fn render_notes_where_pane_output_lives() {
+        let now = Utc::now();
+        let mut task = ScheduledTask::new(1, "htop".to_string(), now, ExecutionTarget::NewPane);
+        task.mark_running();
+        let snapshot = StatusSnapshot {
+            running: vec![RunningTask {
+                task,
+                output: Vec::new(),
+            }],
+            upcoming: Vec::new(),
+        };
+
+        let text = snapshot.render(now, false, u32::MAX);
+
+        assert!(text.contains("output shown in its pane"));
+        assert!(text.contains("nothing scheduled"));
+    }
+}
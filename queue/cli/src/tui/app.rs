@@ -1,17 +1,24 @@
 //! Application state for the TUI.
 
 use queue_lib::{
+    ExecutionJournal,
     HistoryStore,
     JsonFileStore,
+    OutputLog,
+    RunRecord,
     ScheduledTask,
     TaskEvent,
     TaskExecutor,
+    TaskStatus,
     TerminalCapabilities,
     TerminalDetector,
 };
+use biscuit_terminal::components::keymap::Keymap;
+use chrono::Utc;
 use ratatui::widgets::TableState;
 use tokio::sync::mpsc;
 
+use super::help_modal::{normal_keymap, NormalAction};
 use super::history_modal::HistoryModal;
 use super::input_modal::InputModal;
 
@@ -30,6 +37,8 @@ pub enum AppMode {
     InputModal,
     /// History modal - viewing completed tasks.
     HistoryModal,
+    /// Help modal - listing key bindings.
+    HelpModal,
     /// Confirm quit - awaiting Y/N to exit.
     ConfirmQuit,
 }
@@ -54,6 +63,8 @@ pub struct App {
     pub input_modal: Option<InputModal>,
     /// History modal state (present when HistoryModal mode is active).
     pub history_modal: Option<HistoryModal>,
+    /// Normal-mode key bindings (also listed by the help modal).
+    pub keymap: Keymap<NormalAction>,
     /// Terminal capabilities - determines available execution targets.
     pub capabilities: TerminalCapabilities,
     /// History store for persisting tasks (optional for test isolation).
@@ -87,6 +98,7 @@ impl App {
             table_state,
             input_modal: None,
             history_modal: None,
+            keymap: normal_keymap(),
             capabilities: TerminalDetector::detect(),
             history_store: None,
             next_task_id: 1,
@@ -104,6 +116,22 @@ impl App {
         self
     }
 
+    /// Captures the output of background tasks into the given log.
+    ///
+    /// Has no effect unless [`with_executor`](Self::with_executor) was called first.
+    pub fn with_output_log(mut self, log: OutputLog) -> Self {
+        self.executor = self.executor.take().map(|executor| executor.with_output_log(log));
+        self
+    }
+
+    /// Journals task execution so tasks interrupted by a crash can be recovered.
+    ///
+    /// Has no effect unless [`with_executor`](Self::with_executor) was called first.
+    pub fn with_journal(mut self, journal: ExecutionJournal) -> Self {
+        self.executor = self.executor.take().map(|executor| executor.with_journal(journal));
+        self
+    }
+
     /// Adds a history store for persisting tasks.
     pub fn with_history_store(mut self, store: JsonFileStore) -> Self {
         let next_task_id = match store.load_all() {
@@ -228,7 +256,12 @@ impl App {
             TaskEvent::StatusChanged { id, status } => {
                 let updated_task = if let Some(task) = self.tasks.iter_mut().find(|t| t.id == id)
                 {
-                    task.status = status;
+                    // Record the start time so `queue status` can show elapsed time
+                    if status == TaskStatus::Running {
+                        task.mark_running();
+                    } else {
+                        task.status = status;
+                    }
                     Some(task.clone())
                 } else {
                     None
@@ -236,11 +269,57 @@ impl App {
 
                 if let Some(task) = updated_task {
                     self.update_history(&task);
+                    if let Some(run) = RunRecord::from_task(&task, Utc::now()) {
+                        self.record_run(&run);
+                        self.schedule_next_occurrence(&task);
+                    }
                 }
             }
         }
     }
 
+    /// Schedules the next run of a finished recurring task.
+    fn schedule_next_occurrence(&mut self, task: &ScheduledTask) {
+        if task.recurrence.is_none() {
+            return;
+        }
+        let id = self.alloc_task_id();
+        if let Some(next) = task.next_occurrence(id, Utc::now()) {
+            self.schedule_task(next);
+        }
+    }
+
+    /// Schedules pending recurring tasks saved to the history by other programs.
+    ///
+    /// Recurring tasks can be registered without a running TUI (for example by
+    /// `research schedule`); they run once a TUI opens, immediately if their
+    /// time has already passed. Tasks already in the list are skipped.
+    pub fn schedule_recurring_from_history(&mut self) {
+        let Some(ref store) = self.history_store else {
+            return;
+        };
+        let pending = match store.load_all() {
+            Ok(tasks) => tasks
+                .into_iter()
+                .filter(|task| task.is_pending() && task.recurrence.is_some())
+                .collect::<Vec<_>>(),
+            Err(err) => {
+                tracing::warn!(error = %err, "Failed to load recurring tasks");
+                return;
+            }
+        };
+
+        for task in pending {
+            if self.tasks.iter().any(|t| t.id == task.id) {
+                continue;
+            }
+            if let Some(ref executor) = self.executor {
+                executor.schedule(task.clone());
+            }
+            self.tasks.push(task);
+        }
+    }
+
     fn save_history(&self, task: &ScheduledTask) {
         if let Some(ref store) = self.history_store
             && let Err(err) = store.save(task)
@@ -256,13 +335,21 @@ impl App {
             tracing::warn!(error = %err, "Failed to update task history");
         }
     }
+
+    fn record_run(&self, run: &RunRecord) {
+        if let Some(ref store) = self.history_store
+            && let Err(err) = store.record_run(run)
+        {
+            tracing::warn!(error = %err, "Failed to record task run");
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::Utc;
-    use queue_lib::{ExecutionTarget, TaskStatus};
+    use queue_lib::{ExecutionTarget, Recurrence, TaskStatus};
 
     fn make_task(id: u64, command: &str) -> ScheduledTask {
         ScheduledTask::new(
@@ -358,6 +445,76 @@ mod tests {
         });
 
         assert!(app.tasks[0].is_running());
+        assert!(app.tasks[0].started_at.is_some());
+    }
+
+    #[test]
+    fn finished_tasks_are_recorded_as_runs() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = JsonFileStore::new(temp_dir.path().join("history.jsonl"));
+        let mut app = App::new().with_history_store(store.clone());
+        app.schedule_task(make_task(7, "backup"));
+
+        app.handle_task_event(TaskEvent::StatusChanged {
+            id: 7,
+            status: TaskStatus::Running,
+        });
+        assert!(store.load_runs().unwrap().is_empty());
+
+        app.handle_task_event(TaskEvent::StatusChanged {
+            id: 7,
+            status: TaskStatus::Completed,
+        });
+        let runs = store.load_runs().unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].command, "backup");
+        assert!(runs[0].duration().is_some());
+    }
+
+    #[test]
+    fn finished_recurring_tasks_schedule_their_next_run() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = JsonFileStore::new(temp_dir.path().join("history.jsonl"));
+        let mut app = App::new().with_history_store(store.clone());
+        app.schedule_task(make_task(7, "backup").with_recurrence(Recurrence::daily()));
+
+        app.handle_task_event(TaskEvent::StatusChanged {
+            id: 7,
+            status: TaskStatus::Failed {
+                error: "disk full".to_string(),
+            },
+        });
+
+        assert_eq!(app.tasks.len(), 2);
+        let next = &app.tasks[1];
+        assert_eq!(next.id, 8);
+        assert_eq!(next.command, "backup");
+        assert!(next.is_pending());
+        assert!(next.scheduled_at > Utc::now());
+
+        // History keeps the latest task per command: the pending next run
+        let saved = store.load_all().unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].id, 8);
+        assert!(saved[0].is_pending());
+    }
+
+    #[test]
+    fn recurring_tasks_are_loaded_from_history() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = JsonFileStore::new(temp_dir.path().join("history.jsonl"));
+        store
+            .save(&make_task(3, "research refresh tokio").with_recurrence(Recurrence::weekly()))
+            .unwrap();
+        store.save(&make_task(4, "one-off")).unwrap();
+
+        let mut app = App::new().with_history_store(store.clone());
+        app.schedule_recurring_from_history();
+        app.schedule_recurring_from_history();
+
+        assert_eq!(app.tasks.len(), 1);
+        assert_eq!(app.tasks[0].id, 3);
+        assert_eq!(store.load_all().unwrap().len(), 2);
     }
 
     #[test]
@@ -13,7 +13,12 @@
 //! - `NO_COLOR="1"`: color disabled
 //! - `NO_COLOR="0"`: color disabled (any non-empty value disables)
 //! - `NO_COLOR="yes"`: color disabled
+//!
+//! Status symbols come from the shared `biscuit_terminal::utils::symbols`
+//! registry, so a Nerd Font terminal gets Nerd Font glyphs while `NO_COLOR`
+//! always falls back to the ASCII labels.
 
+use biscuit_terminal::utils::symbols::{self, IconStyle};
 use queue_lib::TaskStatus;
 use std::env;
 
@@ -24,6 +29,7 @@ use std::env;
 #[derive(Debug, Clone, Copy)]
 pub struct ColorContext {
     color_enabled: bool,
+    icon_style: IconStyle,
 }
 
 impl ColorContext {
@@ -40,6 +46,7 @@ impl ColorContext {
     pub fn new() -> Self {
         Self {
             color_enabled: Self::detect_color_enabled(),
+            icon_style: IconStyle::current(),
         }
     }
 
@@ -50,6 +57,7 @@ impl ColorContext {
     pub fn with_color() -> Self {
         Self {
             color_enabled: true,
+            icon_style: IconStyle::Emoji,
         }
     }
 
@@ -60,6 +68,7 @@ impl ColorContext {
     pub fn without_color() -> Self {
         Self {
             color_enabled: false,
+            icon_style: IconStyle::Ascii,
         }
     }
 
@@ -74,36 +83,32 @@ impl ColorContext {
 
     /// Returns the appropriate status symbol for the given task status.
     ///
-    /// When color is enabled, returns Unicode symbols:
+    /// When color is enabled, returns the detected icon style's glyph
+    /// (Nerd Font when available), otherwise Unicode symbols:
     /// - Completed: "✓"
     /// - Cancelled: "×"
-    /// - Failed: "✗"
+    /// - Failed / LimitExceeded: "✗"
     /// - Running: "▶"
     /// - Pending: "○"
     ///
     /// When NO_COLOR is set, returns ASCII fallbacks:
     /// - Completed: "[OK]"
     /// - Cancelled: "[--]"
-    /// - Failed: "[FAIL]"
+    /// - Failed / LimitExceeded: "[FAIL]"
     /// - Running: "[RUN]"
     /// - Pending: "[..]"
     pub fn status_symbol(&self, status: &TaskStatus) -> &'static str {
+        let icon = match status {
+            TaskStatus::Completed => &symbols::SUCCESS,
+            TaskStatus::Cancelled => &symbols::CANCELLED,
+            TaskStatus::Failed { .. } | TaskStatus::LimitExceeded { .. } => &symbols::FAILURE,
+            TaskStatus::Running => &symbols::RUNNING,
+            TaskStatus::Pending => &symbols::PENDING,
+        };
         if self.color_enabled {
-            match status {
-                TaskStatus::Completed => "\u{2713}",   // ✓
-                TaskStatus::Cancelled => "\u{00d7}",   // ×
-                TaskStatus::Failed { .. } => "\u{2717}", // ✗
-                TaskStatus::Running => "\u{25b6}",     // ▶
-                TaskStatus::Pending => "\u{25cb}",     // ○
-            }
+            icon.glyph(self.icon_style)
         } else {
-            match status {
-                TaskStatus::Completed => "[OK]",
-                TaskStatus::Cancelled => "[--]",
-                TaskStatus::Failed { .. } => "[FAIL]",
-                TaskStatus::Running => "[RUN]",
-                TaskStatus::Pending => "[..]",
-            }
+            icon.glyph(IconStyle::Ascii)
         }
     }
 
@@ -3,10 +3,11 @@
 use std::io;
 use std::time::Duration;
 
-use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::DefaultTerminal;
 
 use super::app::{App, AppMode};
+use super::help_modal::NormalAction;
 use super::history_modal::HistoryModal;
 use super::input_modal::{InputField, InputModal, ScheduleType};
 use super::render;
@@ -59,20 +60,27 @@ pub fn run_app(terminal: &mut DefaultTerminal, app: &mut App) -> io::Result<()>
 /// Routes keyboard input to the appropriate mode handler.
 fn handle_input(app: &mut App, key: KeyCode, modifiers: KeyModifiers) {
     match app.mode {
-        AppMode::Normal => handle_normal_mode(app, key),
+        AppMode::Normal => handle_normal_mode(app, key, modifiers),
         AppMode::ConfirmQuit => handle_confirm_quit(app, key),
         AppMode::EditMode => handle_edit_mode(app, key),
         AppMode::RemoveMode => handle_remove_mode(app, key),
         AppMode::InputModal => handle_input_modal(app, key, modifiers),
         AppMode::HistoryModal => handle_history_modal(app, key),
+        AppMode::HelpModal => handle_help_modal(app),
     }
 }
 
 /// Handles keyboard input in normal mode.
-fn handle_normal_mode(app: &mut App, key: KeyCode) {
-    match key {
-        // Quit commands
-        KeyCode::Char('q') | KeyCode::Char('Q') => {
+///
+/// Keys are resolved through the app's keymap, which the help modal also lists.
+fn handle_normal_mode(app: &mut App, key: KeyCode, modifiers: KeyModifiers) {
+    let key = KeyEvent::new(key, modifiers);
+    let Some(action) = app.keymap.action_for(&key).copied() else {
+        return;
+    };
+
+    match action {
+        NormalAction::Quit => {
             let has_active_tasks = app
                 .tasks
                 .iter()
@@ -83,40 +91,43 @@ fn handle_normal_mode(app: &mut App, key: KeyCode) {
                 app.should_quit = true;
             }
         }
-        KeyCode::Esc => {
+        NormalAction::ForceQuit => {
             // Immediate exit without confirmation
             app.should_quit = true;
         }
 
         // Navigation
-        KeyCode::Down | KeyCode::Char('j') => {
+        NormalAction::Next => {
             app.select_next();
         }
-        KeyCode::Up | KeyCode::Char('k') => {
+        NormalAction::Previous => {
             app.select_previous();
         }
 
         // Mode switching
-        KeyCode::Char('n') | KeyCode::Char('N') => {
+        NormalAction::New => {
             app.input_modal = Some(InputModal::new(app.capabilities.clone()));
             app.mode = AppMode::InputModal;
         }
-        KeyCode::Char('e') | KeyCode::Char('E') => {
+        NormalAction::Edit => {
             if let Some(task) = app.selected_task().cloned() {
                 app.input_modal = Some(InputModal::for_edit(&task, app.capabilities.clone()));
                 app.mode = AppMode::InputModal;
             }
         }
-        KeyCode::Char('r') | KeyCode::Char('R') => {
+        NormalAction::Remove => {
             app.mode = AppMode::RemoveMode;
         }
-        KeyCode::Char('h') | KeyCode::Char('H') => {
+        NormalAction::History => {
             app.history_modal = Some(HistoryModal::new());
             app.mode = AppMode::HistoryModal;
         }
+        NormalAction::Help => {
+            app.mode = AppMode::HelpModal;
+        }
 
         // Cancel selected pending task
-        KeyCode::Char('x') | KeyCode::Char('X') => {
+        NormalAction::Cancel => {
             if let Some(task) = app.selected_task() {
                 let task_id = task.id;
                 if task.is_pending() {
@@ -124,11 +135,14 @@ fn handle_normal_mode(app: &mut App, key: KeyCode) {
                 }
             }
         }
-
-        _ => {}
     }
 }
 
+/// Handles keyboard input in the help modal: any key closes it.
+fn handle_help_modal(app: &mut App) {
+    app.mode = AppMode::Normal;
+}
+
 /// Handles keyboard input in the quit confirmation dialog.
 fn handle_confirm_quit(app: &mut App, key: KeyCode) {
     match key {
@@ -520,6 +534,18 @@ mod tests {
         assert!(app.history_modal.is_some());
     }
 
+    #[test]
+    fn normal_mode_question_mark_toggles_help() {
+        let mut app = App::new();
+        input_with_modifiers(&mut app, KeyCode::Char('?'), KeyModifiers::SHIFT);
+        assert_eq!(app.mode, AppMode::HelpModal);
+
+        // Any key closes the help without acting on it
+        input(&mut app, KeyCode::Char('q'));
+        assert_eq!(app.mode, AppMode::Normal);
+        assert!(!app.should_quit);
+    }
+
     #[test]
     fn input_modal_escape_clears_modal() {
         let mut app = App::new();
@@ -618,7 +644,9 @@ mod tests {
     #[test]
     fn history_modal_navigation() {
         use chrono::Utc;
-        use queue_lib::{ExecutionTarget, ScheduledTask, TaskStatus};
+        use queue_lib::{
+            ExecutionTarget, Notifications, ResourceLimits, ScheduledTask, TaskStatus,
+        };
         use ratatui::widgets::ListState;
 
         let mut app = App::new();
@@ -634,6 +662,10 @@ mod tests {
                     status: TaskStatus::Completed,
                     created_at: Utc::now(),
                     schedule_kind: None,
+                    started_at: None,
+                    limits: ResourceLimits::default(),
+                    recurrence: None,
+                    notify: Notifications::default(),
                 },
                 ScheduledTask {
                     id: 2,
@@ -643,6 +675,10 @@ mod tests {
                     status: TaskStatus::Completed,
                     created_at: Utc::now(),
                     schedule_kind: None,
+                    started_at: None,
+                    limits: ResourceLimits::default(),
+                    recurrence: None,
+                    notify: Notifications::default(),
                 },
             ],
             list_state: state,
@@ -664,7 +700,9 @@ mod tests {
     #[test]
     fn history_modal_enter_opens_input_modal() {
         use chrono::Utc;
-        use queue_lib::{ExecutionTarget, ScheduledTask, TaskStatus};
+        use queue_lib::{
+            ExecutionTarget, Notifications, ResourceLimits, ScheduledTask, TaskStatus,
+        };
         use ratatui::widgets::ListState;
 
         let mut app = App::new();
@@ -679,6 +717,10 @@ mod tests {
                 status: TaskStatus::Completed,
                 created_at: Utc::now(),
                 schedule_kind: None,
+                started_at: None,
+                limits: ResourceLimits::default(),
+                recurrence: None,
+                notify: Notifications::default(),
             }],
             list_state: state,
             filter: String::new(),
@@ -0,0 +1,163 @@
+//! Normal-mode key bindings and the help modal that lists them.
+//!
+//! Normal-mode keys are registered once in [`normal_keymap`]; the event
+//! handler resolves key presses through it and [`HelpModal`] renders the
+//! same bindings, so the help can't go stale.
+
+use biscuit_terminal::components::keymap::{HelpOverlay, Keymap};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::color_context::ColorContext;
+use super::modal::Modal;
+
+/// An action triggered by a key in normal mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalAction {
+    /// Select the next task.
+    Next,
+    /// Select the previous task.
+    Previous,
+    /// Open the input modal for a new task.
+    New,
+    /// Open the input modal for the selected task.
+    Edit,
+    /// Enter remove mode.
+    Remove,
+    /// Cancel the selected pending task.
+    Cancel,
+    /// Open the history modal.
+    History,
+    /// Show the help modal.
+    Help,
+    /// Quit, confirming first if tasks are pending or running.
+    Quit,
+    /// Quit immediately.
+    ForceQuit,
+}
+
+/// Returns the key bindings for normal mode.
+pub fn normal_keymap() -> Keymap<NormalAction> {
+    Keymap::new()
+        .section("Navigation")
+        .bind(NormalAction::Next, "down, j", "Next task")
+        .bind(NormalAction::Previous, "up, k", "Previous task")
+        .section("Tasks")
+        .bind(NormalAction::New, "n", "New task")
+        .bind(NormalAction::Edit, "e", "Edit selected task")
+        .bind(NormalAction::Remove, "r", "Remove a task")
+        .bind(NormalAction::Cancel, "x", "Cancel selected pending task")
+        .bind(NormalAction::History, "h", "Browse history")
+        .section("General")
+        .bind(NormalAction::Help, "?", "Show this help")
+        .bind(NormalAction::Quit, "q", "Quit (asks if tasks are active)")
+        .bind(NormalAction::ForceQuit, "esc", "Quit immediately")
+        // Uppercase aliases for users with caps lock on
+        .bind(NormalAction::New, "N", "")
+        .bind(NormalAction::Edit, "E", "")
+        .bind(NormalAction::Remove, "R", "")
+        .bind(NormalAction::Cancel, "X", "")
+        .bind(NormalAction::History, "H", "")
+        .bind(NormalAction::Quit, "Q", "")
+}
+
+/// Help modal listing the normal-mode key bindings.
+pub struct HelpModal {
+    overlay: HelpOverlay,
+}
+
+impl HelpModal {
+    /// Creates a help modal for the given keymap.
+    pub fn new(keymap: &Keymap<NormalAction>) -> Self {
+        Self {
+            overlay: keymap.help_overlay().with_title("Keys"),
+        }
+    }
+
+    /// Builds the modal's lines: bold section headings and aligned key columns.
+    fn lines(&self) -> Vec<Line<'static>> {
+        let keys_width = self.overlay.keys_width();
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+
+        let mut lines = Vec::new();
+        for (index, section) in self.overlay.sections().iter().enumerate() {
+            if index > 0 {
+                lines.push(Line::from(""));
+            }
+            if let Some(title) = &section.title {
+                lines.push(Line::from(Span::styled(title.clone(), bold)));
+            }
+            for entry in &section.entries {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {:<keys_width$}  ", entry.keys), bold),
+                    Span::raw(entry.description.clone()),
+                ]));
+            }
+        }
+        lines
+    }
+}
+
+impl Modal for HelpModal {
+    fn title(&self) -> &str {
+        self.overlay.title()
+    }
+
+    fn width_percent(&self) -> u16 {
+        50
+    }
+
+    fn height_percent(&self) -> u16 {
+        60
+    }
+
+    fn min_width(&self) -> u16 {
+        48
+    }
+
+    fn min_height(&self) -> u16 {
+        // Content plus the border
+        self.lines().len() as u16 + 2
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, _color_context: &ColorContext) {
+        frame.render_widget(Paragraph::new(self.lines()), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[test]
+    fn normal_keymap_accepts_both_cases() {
+        let keymap = normal_keymap();
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        assert_eq!(keymap.action_for(&key('n')), Some(&NormalAction::New));
+        assert_eq!(keymap.action_for(&key('N')), Some(&NormalAction::New));
+        assert_eq!(keymap.action_for(&key('?')), Some(&NormalAction::Help));
+        assert_eq!(keymap.action_for(&key('z')), None);
+    }
+
+    #[test]
+    fn help_modal_lists_described_bindings_only() {
+        let modal = HelpModal::new(&normal_keymap());
+        let text: Vec<String> = modal.lines().iter().map(|line| line.to_string()).collect();
+
+        assert_eq!(text[0], "Navigation");
+        assert!(text.iter().any(|line| line.contains("Down, j")));
+        assert!(
+            text.iter()
+                .any(|line| line.contains("Cancel selected pending task"))
+        );
+        // Uppercase aliases are not listed
+        assert!(!text.iter().any(|line| line.contains("n, N")));
+        assert_eq!(modal.title(), "Keys");
+    }
+}
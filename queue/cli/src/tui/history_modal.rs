@@ -1,6 +1,6 @@
 //! History modal for viewing previously queued commands.
 
-use queue_lib::{HistoryStore, JsonFileStore, ScheduledTask, TaskStatus};
+use queue_lib::{HistoryStore, ScheduledTask, TaskStatus};
 use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -33,8 +33,10 @@ pub struct HistoryModal {
 
 impl HistoryModal {
     pub fn new() -> Self {
-        let store = JsonFileStore::default_path();
-        let items = store.load_all().unwrap_or_default();
+        let items = crate::history_store()
+            .ok()
+            .and_then(|store| store.load_all().ok())
+            .unwrap_or_default();
 
         let mut state = ListState::default();
         if !items.is_empty() {
@@ -292,7 +294,9 @@ impl HistoryModal {
                             TaskStatus::Cancelled => Style::default()
                                 .fg(Color::DarkGray)
                                 .add_modifier(Modifier::DIM),
-                            TaskStatus::Failed { .. } => Style::default().fg(Color::Red),
+                            TaskStatus::Failed { .. } | TaskStatus::LimitExceeded { .. } => {
+                                Style::default().fg(Color::Red)
+                            }
                             TaskStatus::Running => Style::default().fg(Color::Yellow),
                             TaskStatus::Pending => Style::default().fg(Color::DarkGray),
                         },
@@ -371,7 +375,7 @@ impl HistoryModal {
 mod tests {
     use super::*;
     use chrono::Utc;
-    use queue_lib::ExecutionTarget;
+    use queue_lib::{ExecutionTarget, Notifications, ResourceLimits};
 
     fn create_test_task(id: u64, command: &str) -> ScheduledTask {
         ScheduledTask {
@@ -382,6 +386,10 @@ mod tests {
             status: TaskStatus::Completed,
             created_at: Utc::now(),
             schedule_kind: None,
+            started_at: None,
+            limits: ResourceLimits::default(),
+            recurrence: None,
+            notify: Notifications::default(),
         }
     }
 
@@ -7,6 +7,7 @@ use ratatui::style::Color;
 mod app;
 mod color_context;
 mod event;
+mod help_modal;
 mod history_modal;
 mod input_modal;
 mod modal;
@@ -14,6 +14,7 @@ use ratatui::{
 
 use super::app::{App, AppMode};
 use super::color_context::ColorContext;
+use super::help_modal::HelpModal;
 use super::modal::{render_modal, ConfirmQuitDialog};
 use super::PANEL_BG;
 
@@ -53,6 +54,10 @@ pub fn render(app: &mut App, frame: &mut Frame) {
                 render_modal(frame, history_modal, frame.area(), &color_context);
             }
         }
+        AppMode::HelpModal => {
+            let help_modal = HelpModal::new(&app.keymap);
+            render_modal(frame, &help_modal, frame.area(), &color_context);
+        }
         _ => {}
     }
 }
@@ -227,6 +232,7 @@ fn render_footer(app: &App, frame: &mut Frame, area: Rect) {
         ),
         AppMode::ConfirmQuit => (PANEL_BG, vec![("Y", "Yes, Quit"), ("N", "No, Stay")]),
         AppMode::InputModal | AppMode::HistoryModal => (PANEL_BG, vec![("Esc", "Back")]),
+        AppMode::HelpModal => (PANEL_BG, vec![("Any key", "Close")]),
     };
 
     let spans: Vec<Span> = shortcuts
@@ -262,7 +268,9 @@ fn task_style(task: &ScheduledTask) -> Style {
         TaskStatus::Running => Style::default()
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD),
-        TaskStatus::Failed { .. } => Style::default().fg(Color::Red),
+        TaskStatus::Failed { .. } | TaskStatus::LimitExceeded { .. } => {
+            Style::default().fg(Color::Red)
+        }
         TaskStatus::Pending => Style::default(),
     }
 }
@@ -343,6 +351,7 @@ fn format_status(status: &TaskStatus) -> &'static str {
         TaskStatus::Completed => "done",
         TaskStatus::Cancelled => "cancelled",
         TaskStatus::Failed { .. } => "failed",
+        TaskStatus::LimitExceeded { .. } => "limit",
     }
 }
 
@@ -688,6 +697,26 @@ mod tests {
         assert!(content.contains("Quit"));
     }
 
+    #[test]
+    fn render_shows_help_modal() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = App::new();
+        app.mode = AppMode::HelpModal;
+
+        terminal
+            .draw(|frame| render(&mut app, frame))
+            .expect("render should not fail");
+
+        let buffer = terminal.backend().buffer();
+        let content: String = buffer.content.iter().map(|c| c.symbol()).collect();
+        assert!(content.contains("Keys"));
+        assert!(content.contains("Browse history"));
+    }
+
     // =========================================================================
     // Column configuration tests
     // =========================================================================
@@ -69,3 +69,18 @@ fn cli_short_version_works() {
         .success()
         .stdout(predicate::str::contains("0.1.0"));
 }
+
+#[test]
+#[cfg(unix)]
+fn cli_status_prints_plain_text_without_tty() {
+    let home = tempfile::TempDir::new().unwrap();
+
+    queue_cmd()
+        .env("HOME", home.path())
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Running (0)"))
+        .stdout(predicate::str::contains("Next (0)"))
+        .stdout(predicate::str::contains("\x1b").not());
+}
@@ -0,0 +1,308 @@
+//! Encryption at rest for task history.
+//!
+//! Commands can carry secrets (tokens passed as arguments, credentials in
+//! URLs), and the error messages of failed tasks often quote them. With a
+//! [`HistoryKey`], [`JsonFileStore`](crate::JsonFileStore) encrypts these
+//! fields with ChaCha20-Poly1305 before writing them and decrypts them when
+//! reading. Ids, timestamps, status, targets and limits stay plain JSON, so
+//! history can still be filtered and reported on without the key.
+//!
+//! Encrypted fields are stored as `enc:v1:<base64 of nonce and ciphertext>`.
+//! Plain values written before encryption was enabled are still read; the
+//! task history is re-encrypted on its next write, while runs already in the
+//! append-only run log stay as they were written.
+//!
+//! Keys are 32 random bytes, either taken from the `QUEUE_HISTORY_KEY`
+//! environment variable (base64) or kept in a key file readable only by its
+//! owner (see [`HistoryKey::load_or_create`]).
+
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::error::HistoryError;
+use crate::history::SEALED_PREFIX;
+
+/// Environment variable holding a base64-encoded history key.
+pub const HISTORY_KEY_ENV: &str = "QUEUE_HISTORY_KEY";
+
+/// Default key file name (in the home directory on Unix).
+#[cfg(not(windows))]
+const DEFAULT_KEY_FILE: &str = ".queue-history.key";
+
+/// Default key file name (in `%LOCALAPPDATA%\queue` on Windows).
+#[cfg(windows)]
+const DEFAULT_KEY_FILE: &str = "queue-history.key";
+
+/// Length of a history key in bytes.
+const KEY_LEN: usize = 32;
+
+/// A key for encrypting sensitive history fields.
+///
+/// The key bytes are never printed; its `Debug` output is redacted.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use queue_lib::{HistoryKey, JsonFileStore};
+///
+/// let key = HistoryKey::load_or_create(&HistoryKey::default_key_path()).unwrap();
+/// let store = JsonFileStore::default_path().with_key(key);
+/// ```
+#[derive(Clone)]
+pub struct HistoryKey {
+    bytes: [u8; KEY_LEN],
+}
+
+impl HistoryKey {
+    /// Generates a new random key.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`HistoryError::Key`] if the system has no secure random source.
+    pub fn generate() -> Result<Self, HistoryError> {
+        let mut bytes = [0u8; KEY_LEN];
+        SystemRandom::new()
+            .fill(&mut bytes)
+            .map_err(|_| HistoryError::Key("no secure random source".to_string()))?;
+        Ok(Self { bytes })
+    }
+
+    /// Decodes a key from base64, ignoring surrounding whitespace.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`HistoryError::Key`] if the input isn't base64 or doesn't
+    /// decode to 32 bytes.
+    pub fn from_base64(encoded: &str) -> Result<Self, HistoryError> {
+        let decoded = STANDARD
+            .decode(encoded.trim())
+            .map_err(|e| HistoryError::Key(format!("invalid base64: {e}")))?;
+        let bytes = <[u8; KEY_LEN]>::try_from(decoded.as_slice()).map_err(|_| {
+            HistoryError::Key(format!("expected {KEY_LEN} bytes, got {}", decoded.len()))
+        })?;
+        Ok(Self { bytes })
+    }
+
+    /// Encodes the key as base64, the format of [`HISTORY_KEY_ENV`] and key files.
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.bytes)
+    }
+
+    /// Reads the key from [`HISTORY_KEY_ENV`], returning `None` when it's
+    /// unset or empty.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`HistoryError::Key`] if the variable holds an invalid key.
+    pub fn from_env() -> Result<Option<Self>, HistoryError> {
+        match std::env::var(HISTORY_KEY_ENV) {
+            Ok(encoded) if !encoded.trim().is_empty() => Self::from_base64(&encoded).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Loads the key stored at `path`, generating and storing a new one if
+    /// the file doesn't exist yet.
+    ///
+    /// New key files are created readable and writable by their owner only
+    /// (on Unix). Losing the key file makes encrypted history unreadable.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`HistoryError::Read`] if the file can't be read or created,
+    /// and [`HistoryError::Key`] if it holds an invalid key.
+    pub fn load_or_create(path: &Path) -> Result<Self, HistoryError> {
+        match std::fs::read_to_string(path) {
+            Ok(encoded) => return Self::from_base64(&encoded),
+            Err(e) if e.kind() != ErrorKind::NotFound => return Err(e.into()),
+            Err(_) => {}
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let key = Self::generate()?;
+        match options.open(path) {
+            Ok(mut file) => {
+                writeln!(file, "{}", key.to_base64())?;
+                file.sync_all()?;
+                Ok(key)
+            }
+            // Another process created the key first; use theirs.
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                Self::from_base64(&std::fs::read_to_string(path)?)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the default key file path.
+    ///
+    /// This is `~/.queue-history.key` on Unix and
+    /// `%LOCALAPPDATA%\queue\queue-history.key` on Windows.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the home (or local app data) directory cannot be determined.
+    #[cfg(not(windows))]
+    pub fn default_key_path() -> PathBuf {
+        let home = dirs::home_dir().expect("could not determine home directory");
+        home.join(DEFAULT_KEY_FILE)
+    }
+
+    /// Returns the default key file path.
+    ///
+    /// This is `~/.queue-history.key` on Unix and
+    /// `%LOCALAPPDATA%\queue\queue-history.key` on Windows.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the home (or local app data) directory cannot be determined.
+    #[cfg(windows)]
+    pub fn default_key_path() -> PathBuf {
+        let data_dir =
+            dirs::data_local_dir().expect("could not determine local app data directory");
+        data_dir.join("queue").join(DEFAULT_KEY_FILE)
+    }
+
+    /// Encrypts the value of `field`, binding the ciphertext to the field
+    /// name so it can't be moved to another field.
+    pub(crate) fn seal(&self, field: &str, plaintext: &str) -> Result<String, HistoryError> {
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| HistoryError::Key("no secure random source".to_string()))?;
+
+        let mut in_out = plaintext.as_bytes().to_vec();
+        self.aead_key()
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(field.as_bytes()),
+                &mut in_out,
+            )
+            .map_err(|_| HistoryError::Key("encryption failed".to_string()))?;
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&in_out);
+        Ok(format!("{SEALED_PREFIX}{}", STANDARD.encode(sealed)))
+    }
+
+    /// Decrypts a value sealed by [`seal`](Self::seal) for `field`.
+    pub(crate) fn open(&self, field: &str, value: &str) -> Result<String, HistoryError> {
+        let encoded = value
+            .strip_prefix(SEALED_PREFIX)
+            .ok_or(HistoryError::Decrypt)?;
+        let mut sealed = STANDARD
+            .decode(encoded)
+            .map_err(|_| HistoryError::Decrypt)?;
+        if sealed.len() < NONCE_LEN {
+            return Err(HistoryError::Decrypt);
+        }
+
+        let mut in_out = sealed.split_off(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(&sealed).map_err(|_| HistoryError::Decrypt)?;
+        let plaintext = self
+            .aead_key()
+            .open_in_place(nonce, Aad::from(field.as_bytes()), &mut in_out)
+            .map_err(|_| HistoryError::Decrypt)?;
+        String::from_utf8(plaintext.to_vec()).map_err(|_| HistoryError::Decrypt)
+    }
+
+    fn aead_key(&self) -> LessSafeKey {
+        let key = UnboundKey::new(&CHACHA20_POLY1305, &self.bytes)
+            .expect("history keys have the cipher's key length");
+        LessSafeKey::new(key)
+    }
+}
+
+impl fmt::Debug for HistoryKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HistoryKey(<redacted>)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn sealed_values_round_trip() {
+        let key = HistoryKey::generate().unwrap();
+        let sealed = key.seal("command", "curl -H 'token: s3cret'").unwrap();
+
+        assert!(sealed.starts_with(SEALED_PREFIX));
+        assert!(!sealed.contains("s3cret"));
+        assert_eq!(
+            key.open("command", &sealed).unwrap(),
+            "curl -H 'token: s3cret'"
+        );
+    }
+
+    #[test]
+    fn open_rejects_wrong_key_and_field() {
+        let key = HistoryKey::generate().unwrap();
+        let sealed = key.seal("command", "secret").unwrap();
+
+        let other = HistoryKey::generate().unwrap();
+        assert!(matches!(
+            other.open("command", &sealed),
+            Err(HistoryError::Decrypt)
+        ));
+        assert!(matches!(
+            key.open("error", &sealed),
+            Err(HistoryError::Decrypt)
+        ));
+    }
+
+    #[test]
+    fn base64_round_trip_and_validation() {
+        let key = HistoryKey::generate().unwrap();
+        let decoded = HistoryKey::from_base64(&format!(" {}\n", key.to_base64())).unwrap();
+        assert_eq!(decoded.bytes, key.bytes);
+
+        assert!(matches!(
+            HistoryKey::from_base64("not base64!"),
+            Err(HistoryError::Key(_))
+        ));
+        assert!(matches!(
+            HistoryKey::from_base64("c2hvcnQ="),
+            Err(HistoryError::Key(_))
+        ));
+    }
+
+    #[test]
+    fn load_or_create_persists_the_key() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("keys").join("history.key");
+
+        let created = HistoryKey::load_or_create(&path).unwrap();
+        let loaded = HistoryKey::load_or_create(&path).unwrap();
+        assert_eq!(created.bytes, loaded.bytes);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn debug_output_is_redacted() {
+        let key = HistoryKey::generate().unwrap();
+        assert_eq!(format!("{key:?}"), "HistoryKey(<redacted>)");
+    }
+}
@@ -16,4 +16,16 @@ pub enum HistoryError {
     /// Failed to acquire a file lock.
     #[error("failed to acquire lock")]
     Lock,
+
+    /// A history key could not be created, loaded, or used.
+    #[error("invalid history key: {0}")]
+    Key(String),
+
+    /// An encrypted history field could not be decrypted with the store's key.
+    #[error("failed to decrypt history (wrong key?)")]
+    Decrypt,
+
+    /// The history contains encrypted fields but the store has no key.
+    #[error("history is encrypted but no key was provided")]
+    MissingKey,
 }
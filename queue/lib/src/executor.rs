@@ -2,8 +2,8 @@
 //!
 //! This module provides the core execution infrastructure for running scheduled
 //! tasks at their designated times. It supports multiple execution targets:
-//! - Wezterm panes for integrated terminal workflows
-//! - Native terminal windows for various terminal emulators
+//! - Wezterm and Windows Terminal panes for integrated terminal workflows
+//! - Native terminal windows (or Windows Terminal tabs) for various emulators
 //! - Background processes for detached execution
 //!
 //! ## Examples
@@ -38,15 +38,31 @@
 //! ```
 
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::Write;
 use std::process::Stdio;
 use std::sync::{Arc, Mutex};
 
 use chrono::Utc;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::process::Command;
 use tokio::sync::{mpsc, RwLock};
 use tokio::time::{sleep_until, Instant};
 
-use crate::{ExecutionTarget, ScheduledTask, TaskStatus, TerminalDetector, TerminalKind};
+use crate::shell::{RUN_FLAG, SHELL};
+#[cfg(windows)]
+use crate::shell::KEEP_OPEN_FLAG;
+use crate::limits;
+use crate::notify::{self, FinishedRun};
+use crate::{
+    ExecutionJournal, ExecutionTarget, JournalEvent, LimitKind, OutputLog, ResourceLimits,
+    ScheduledTask, TaskStatus, TerminalDetector, TerminalKind,
+};
+
+/// Windows process creation flag that puts the child in its own process group
+/// so Ctrl+C in the parent console does not propagate to background tasks.
+#[cfg(windows)]
+const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
 
 /// Event emitted when a task's status changes.
 ///
@@ -63,6 +79,21 @@ pub enum TaskEvent {
     },
 }
 
+/// Why a task execution failed.
+#[derive(Debug)]
+enum ExecFailure {
+    /// The command could not be run or exited unsuccessfully.
+    Error(String),
+    /// The command exceeded one of the task's resource limits.
+    LimitExceeded { limit: LimitKind, error: String },
+}
+
+impl From<String> for ExecFailure {
+    fn from(error: String) -> Self {
+        Self::Error(error)
+    }
+}
+
 /// Executes scheduled tasks at their designated times.
 ///
 /// The executor spawns a tokio task for each scheduled task that waits until
@@ -82,6 +113,26 @@ pub enum TaskEvent {
 /// When running in Wezterm, the executor can be configured with a target pane ID
 /// for task execution. Tasks with `NewPane` target will create new panes within
 /// that target area, keeping the TUI pane separate.
+///
+/// ## Output Capture
+///
+/// When configured with an [`OutputLog`] via
+/// [`with_output_log`](TaskExecutor::with_output_log), background tasks write
+/// their stdout and stderr to a per-task log file and are reported as
+/// completed (or failed) only once the process exits.
+///
+/// ## Journaling
+///
+/// When configured with an [`ExecutionJournal`] via
+/// [`with_journal`](TaskExecutor::with_journal), each task is journaled as
+/// claimed, started, and finished, so tasks interrupted by a crash can be
+/// found with [`ExecutionJournal::recover`] on the next startup.
+///
+/// ## Notifications
+///
+/// Tasks with [`Notifications`](crate::Notifications) are announced once their
+/// final status has been reported: through the terminal, by speech, or by a
+/// webhook `POST` (each behind its own `notify-*` feature).
 pub struct TaskExecutor {
     event_tx: mpsc::Sender<TaskEvent>,
     /// The pane ID where tasks should be executed (for Wezterm pane support).
@@ -89,6 +140,10 @@ pub struct TaskExecutor {
     task_pane_id: Arc<RwLock<Option<String>>>,
     /// Handles to scheduled task futures for cancellation.
     task_handles: Arc<Mutex<HashMap<u64, tokio::task::JoinHandle<()>>>>,
+    /// Where background task output is captured, if anywhere.
+    output_log: Option<OutputLog>,
+    /// Where task execution is journaled, if anywhere.
+    journal: Option<ExecutionJournal>,
 }
 
 impl TaskExecutor {
@@ -113,9 +168,48 @@ impl TaskExecutor {
             event_tx,
             task_pane_id: Arc::new(RwLock::new(None)),
             task_handles: Arc::new(Mutex::new(HashMap::new())),
+            output_log: None,
+            journal: None,
         }
     }
 
+    /// Captures background task output into the given log.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use queue_lib::{OutputLog, TaskExecutor};
+    /// use tokio::sync::mpsc;
+    ///
+    /// let (tx, _rx) = mpsc::channel(100);
+    /// let executor = TaskExecutor::new(tx).with_output_log(OutputLog::new("/tmp/queue-output"));
+    /// ```
+    #[must_use]
+    pub fn with_output_log(mut self, log: OutputLog) -> Self {
+        self.output_log = Some(log);
+        self
+    }
+
+    /// Journals task execution so interrupted tasks can be recovered.
+    ///
+    /// Journal write failures do not stop a task from running.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use queue_lib::{ExecutionJournal, TaskExecutor};
+    /// use tokio::sync::mpsc;
+    ///
+    /// let (tx, _rx) = mpsc::channel(100);
+    /// let executor = TaskExecutor::new(tx)
+    ///     .with_journal(ExecutionJournal::new("/tmp/queue-journal.jsonl".into()));
+    /// ```
+    #[must_use]
+    pub fn with_journal(mut self, journal: ExecutionJournal) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
     /// Sets the target pane ID for task execution.
     ///
     /// When set, tasks with `NewPane` target will create new panes within
@@ -171,9 +265,12 @@ impl TaskExecutor {
         let tx = self.event_tx.clone();
         let task_pane_id = self.task_pane_id.clone();
         let task_handles = self.task_handles.clone();
+        let output_log = self.output_log.clone();
+        let journal = self.journal.clone();
         let task_id = task.id;
         let handle = tokio::spawn(async move {
-            Self::execute_task(task, tx, task_pane_id, task_handles.clone()).await;
+            Self::execute_task(task, tx, task_pane_id, task_handles.clone(), output_log, journal)
+                .await;
         });
         if let Ok(mut handles) = self.task_handles.lock()
             && !handle.is_finished()
@@ -200,6 +297,8 @@ impl TaskExecutor {
         tx: mpsc::Sender<TaskEvent>,
         task_pane_id: Arc<RwLock<Option<String>>>,
         task_handles: Arc<Mutex<HashMap<u64, tokio::task::JoinHandle<()>>>>,
+        output_log: Option<OutputLog>,
+        journal: Option<ExecutionJournal>,
     ) {
         // Wait until scheduled time
         let now = Utc::now();
@@ -209,6 +308,18 @@ impl TaskExecutor {
             sleep_until(deadline).await;
         }
 
+        let journal_event = |event: JournalEvent| {
+            if let Some(ref journal) = journal {
+                let _ = journal.record(task.id, event);
+            }
+        };
+
+        // Claim the task before anything runs, so a crash from here on is recoverable
+        journal_event(JournalEvent::Claimed {
+            task: task.clone(),
+            pid: std::process::id(),
+        });
+
         // Mark as running
         let _ = tx
             .send(TaskEvent::StatusChanged {
@@ -220,24 +331,53 @@ impl TaskExecutor {
         // Get the target pane ID for task execution
         let pane_id = task_pane_id.read().await.clone();
 
+        journal_event(JournalEvent::Started);
+        let started_at = Utc::now();
+
         // Execute based on target
         let result = match task.target {
-            ExecutionTarget::NewPane => Self::execute_in_pane(&task.command, pane_id.as_deref()).await,
-            ExecutionTarget::NewWindow => Self::execute_in_window(&task.command).await,
-            ExecutionTarget::Background => Self::execute_background(&task.command).await,
+            ExecutionTarget::NewPane => Self::execute_in_pane(&task.command, pane_id.as_deref())
+                .await
+                .map_err(ExecFailure::from),
+            ExecutionTarget::NewWindow => Self::execute_in_window(&task.command)
+                .await
+                .map_err(ExecFailure::from),
+            ExecutionTarget::Background => {
+                Self::execute_background(&task.command, task.id, &task.limits, output_log.as_ref())
+                    .await
+            }
         };
 
         // Report completion status
         let status = match result {
             Ok(()) => TaskStatus::Completed,
-            Err(e) => TaskStatus::Failed { error: e },
+            Err(ExecFailure::Error(error)) => TaskStatus::Failed { error },
+            Err(ExecFailure::LimitExceeded { limit, error }) => {
+                TaskStatus::LimitExceeded { limit, error }
+            }
         };
 
-        let _ = tx.send(TaskEvent::StatusChanged { id: task.id, status }).await;
+        journal_event(JournalEvent::Finished {
+            status: status.clone(),
+        });
+        let _ = tx
+            .send(TaskEvent::StatusChanged {
+                id: task.id,
+                status: status.clone(),
+            })
+            .await;
 
         if let Ok(mut handles) = task_handles.lock() {
             handles.remove(&task.id);
         }
+
+        let run = FinishedRun {
+            task: &task,
+            status: &status,
+            started_at,
+            finished_at: Utc::now(),
+        };
+        notify::deliver(&run, output_log.as_ref()).await;
     }
 
     /// Executes a command in a new Wezterm pane.
@@ -250,7 +390,8 @@ impl TaskExecutor {
     /// interactive programs. After the command completes, an interactive shell
     /// takes over so the pane stays open for user interaction.
     ///
-    /// If not running in Wezterm, falls back to opening a new window.
+    /// In Windows Terminal (outside Wezterm), the current window is split with
+    /// `wt split-pane`. Otherwise falls back to opening a new window.
     ///
     /// ## Arguments
     ///
@@ -259,6 +400,9 @@ impl TaskExecutor {
     async fn execute_in_pane(command: &str, task_pane_id: Option<&str>) -> Result<(), String> {
         // Check if we're in Wezterm
         if !TerminalDetector::is_wezterm() {
+            if TerminalDetector::is_windows_terminal() {
+                return Self::execute_in_windows_terminal("split-pane", command).await;
+            }
             return Self::execute_in_window(command).await;
         }
 
@@ -274,9 +418,10 @@ impl TaskExecutor {
         // Wrap command to keep the shell alive after execution.
         // This runs the user's command, then replaces the process with an
         // interactive shell so the pane stays open for user interaction.
-        let wrapped_command = Self::wrap_command_for_interactive_shell(command);
+        let shell_argv = Self::interactive_shell_argv(command);
 
-        args.extend(["--", "/bin/sh", "-c", &wrapped_command]);
+        args.push("--");
+        args.extend(shell_argv.iter().map(String::as_str));
 
         let status = Command::new("wezterm")
             .args(&args)
@@ -308,6 +453,7 @@ impl TaskExecutor {
     /// - GNOME Terminal
     /// - Konsole
     /// - Xfce4 Terminal
+    /// - Windows Terminal (new tab)
     /// - XTerm (fallback)
     async fn execute_in_window(command: &str) -> Result<(), String> {
         let caps = TerminalDetector::detect();
@@ -319,7 +465,8 @@ impl TaskExecutor {
             TerminalKind::Wezterm => {
                 // Wezterm: open new tab
                 Command::new("wezterm")
-                    .args(["cli", "spawn", "--", "/bin/sh", "-c", &wrapped_command])
+                    .args(["cli", "spawn", "--"])
+                    .args(Self::interactive_shell_argv(command))
                     .stdin(Stdio::null())
                     .stdout(Stdio::null())
                     .stderr(Stdio::null())
@@ -405,6 +552,9 @@ impl TaskExecutor {
                     .status()
                     .await
             }
+            TerminalKind::WindowsTerminal => {
+                return Self::execute_in_windows_terminal("new-tab", command).await;
+            }
             TerminalKind::Xterm => {
                 Command::new("xterm")
                     .args(["-e", "/bin/sh", "-c", &wrapped_command])
@@ -421,7 +571,18 @@ impl TaskExecutor {
                     // macOS: use Terminal.app via osascript
                     Self::execute_in_terminal_app_status(command).await
                 }
-                #[cfg(not(target_os = "macos"))]
+                #[cfg(windows)]
+                {
+                    // Windows: `start` opens a new console window for the command
+                    Command::new(SHELL)
+                        .args([RUN_FLAG, "start", "", SHELL, KEEP_OPEN_FLAG, command])
+                        .stdin(Stdio::null())
+                        .stdout(Stdio::null())
+                        .stderr(Stdio::null())
+                        .status()
+                        .await
+                }
+                #[cfg(not(any(target_os = "macos", windows)))]
                 {
                     // Linux/other: try common terminal emulators in order
                     let xterm_result = Command::new("xterm")
@@ -458,17 +619,156 @@ impl TaskExecutor {
     /// Executes a command as a detached background process.
     ///
     /// The process runs independently and continues even after the executor
-    /// is dropped. Output is discarded.
-    async fn execute_background(command: &str) -> Result<(), String> {
-        Command::new("/bin/sh")
-            .args(["-c", command])
+    /// is dropped. On Windows the process is started in a new process group
+    /// (`CREATE_NEW_PROCESS_GROUP`) so console signals sent to the TUI do not
+    /// terminate it.
+    ///
+    /// Without an output log, output is discarded and the task is complete as
+    /// soon as the process spawns. With one, stdout and stderr are written to
+    /// the task's log file and the result reflects the process exit status.
+    ///
+    /// Tasks with [`ResourceLimits`] are always waited on so that a runtime
+    /// overrun can be killed and a memory-limit crash reported as
+    /// [`TaskStatus::LimitExceeded`].
+    async fn execute_background(
+        command: &str,
+        task_id: u64,
+        limits: &ResourceLimits,
+        output_log: Option<&OutputLog>,
+    ) -> Result<(), ExecFailure> {
+        let mut process = Command::new(SHELL);
+        process.args([RUN_FLAG, command]).stdin(Stdio::null());
+
+        #[cfg(windows)]
+        process.creation_flags(CREATE_NEW_PROCESS_GROUP);
+
+        limits::apply(&mut process, limits);
+
+        let mut sealed_log = None;
+        match output_log {
+            Some(output_log) => {
+                let stdout = output_log
+                    .create(task_id)
+                    .map_err(|e| format!("failed to create output log: {e}"))?;
+                if output_log.is_sealed() {
+                    // Output passes through us so each line can be encrypted
+                    process.stdout(Stdio::piped()).stderr(Stdio::piped());
+                    sealed_log = Some((output_log, Arc::new(Mutex::new(stdout))));
+                } else {
+                    let stderr = stdout.try_clone().map_err(|e| e.to_string())?;
+                    process.stdout(stdout).stderr(stderr);
+                }
+            }
+            None => {
+                process.stdout(Stdio::null()).stderr(Stdio::null());
+            }
+        }
+
+        let mut child = process.spawn().map_err(|e| e.to_string())?;
+        let mut copies = Vec::new();
+        if let Some((log, file)) = sealed_log {
+            if let Some(stdout) = child.stdout.take() {
+                copies.push(Self::copy_sealed(stdout, log, file.clone()));
+            }
+            if let Some(stderr) = child.stderr.take() {
+                copies.push(Self::copy_sealed(stderr, log, file));
+            }
+        }
+
+        // Without captured output or limits to enforce there is nothing to wait for
+        if output_log.is_none() && limits.is_unlimited() {
+            return Ok(());
+        }
+
+        let status = match limits.max_runtime() {
+            Some(max_runtime) => match tokio::time::timeout(max_runtime, child.wait()).await {
+                Ok(status) => status,
+                Err(_) => {
+                    limits::kill(&mut child).await;
+                    return Err(ExecFailure::LimitExceeded {
+                        limit: LimitKind::MaxRuntime,
+                        error: format!("exceeded max runtime of {}s", max_runtime.as_secs()),
+                    });
+                }
+            },
+            None => child.wait().await,
+        }
+        .map_err(|e| e.to_string())?;
+
+        // Let the log catch up before the result (and any notification) goes out
+        for copy in copies {
+            let _ = copy.await;
+        }
+
+        if status.success() {
+            Ok(())
+        } else if let Some((limit, error)) = limits::violation(&status, limits) {
+            Err(ExecFailure::LimitExceeded { limit, error })
+        } else {
+            Err(format!("command exited with status {status}").into())
+        }
+    }
+
+    /// Copies a child's piped output into its log file, sealing each line.
+    fn copy_sealed(
+        reader: impl AsyncRead + Unpin + Send + 'static,
+        log: &OutputLog,
+        file: Arc<Mutex<File>>,
+    ) -> tokio::task::JoinHandle<()> {
+        let log = log.clone();
+        tokio::spawn(async move {
+            let mut reader = BufReader::new(reader);
+            let mut line = Vec::new();
+            loop {
+                line.clear();
+                match reader.read_until(b'\n', &mut line).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(_) => {}
+                }
+                let text = String::from_utf8_lossy(&line);
+                let Ok(sealed) = log.seal_line(text.trim_end_matches(['\n', '\r'])) else {
+                    break;
+                };
+                if let Ok(mut file) = file.lock() {
+                    let _ = writeln!(file, "{sealed}");
+                }
+            }
+        })
+    }
+
+    /// Executes a command in Windows Terminal via the `wt` CLI.
+    ///
+    /// `action` is either `new-tab` or `split-pane`; both target the current
+    /// window (`-w 0`) so tasks open next to the TUI.
+    async fn execute_in_windows_terminal(action: &str, command: &str) -> Result<(), String> {
+        let status = Command::new("wt")
+            .args(Self::windows_terminal_args(action, command))
             .stdin(Stdio::null())
             .stdout(Stdio::null())
             .stderr(Stdio::null())
-            .spawn()
+            .status()
+            .await
             .map_err(|e| e.to_string())?;
 
-        Ok(())
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("wt exited with status {status}"))
+        }
+    }
+
+    /// Builds the `wt` argument list for running a command in a tab or pane.
+    ///
+    /// `wt` treats `;` as a separator between its own subcommands, so any
+    /// semicolons in the task command are escaped.
+    fn windows_terminal_args(action: &str, command: &str) -> Vec<String> {
+        let mut args = vec!["-w".to_string(), "0".to_string(), action.to_string()];
+        args.extend(
+            Self::interactive_shell_argv(command)
+                .into_iter()
+                .map(|arg| arg.replace(';', "\\;")),
+        );
+        args
     }
 
     async fn execute_in_terminal_app_status(
@@ -501,6 +801,30 @@ impl TaskExecutor {
         let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/sh".to_string());
         format!("{}; exec {}", command, shell)
     }
+
+    /// Builds the shell argv for running a command in an interactive target:
+    /// `/bin/sh -c "<command>; exec $SHELL"`, which replaces the shell with
+    /// the user's interactive `$SHELL` once the command completes.
+    #[cfg(not(windows))]
+    fn interactive_shell_argv(command: &str) -> Vec<String> {
+        vec![
+            SHELL.to_string(),
+            RUN_FLAG.to_string(),
+            Self::wrap_command_for_interactive_shell(command),
+        ]
+    }
+
+    /// Builds the `cmd /K <command>` argv for running a command in an
+    /// interactive target; `/K` keeps the console open at a prompt after the
+    /// command completes.
+    #[cfg(windows)]
+    fn interactive_shell_argv(command: &str) -> Vec<String> {
+        vec![
+            SHELL.to_string(),
+            KEEP_OPEN_FLAG.to_string(),
+            command.to_string(),
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -593,6 +917,207 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn task_background_output_is_captured_in_log() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let log = OutputLog::new(temp_dir.path());
+        let (tx, mut rx) = mpsc::channel::<TaskEvent>(100);
+        let executor = TaskExecutor::new(tx).with_output_log(log.clone());
+
+        let task = ScheduledTask::new(
+            5,
+            "echo captured".to_string(),
+            Utc::now(),
+            ExecutionTarget::Background,
+        );
+
+        executor.schedule(task);
+
+        // Skip Running status
+        let _ = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("closed");
+
+        // Completion is only reported once the process has exited
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timeout waiting for completion")
+            .expect("channel closed");
+
+        match event {
+            TaskEvent::StatusChanged { id, status } => {
+                assert_eq!(id, 5);
+                assert_eq!(status, TaskStatus::Completed);
+            }
+        }
+        assert_eq!(log.tail(5, 1).unwrap(), vec!["captured"]);
+    }
+
+    #[cfg(feature = "encryption")]
+    #[tokio::test]
+    async fn task_background_output_is_sealed_with_a_key() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let log = OutputLog::new(temp_dir.path()).with_key(crate::HistoryKey::generate().unwrap());
+        let (tx, mut rx) = mpsc::channel::<TaskEvent>(100);
+        let executor = TaskExecutor::new(tx).with_output_log(log.clone());
+
+        let task = ScheduledTask::new(
+            6,
+            "echo hunter2; echo oops >&2".to_string(),
+            Utc::now(),
+            ExecutionTarget::Background,
+        );
+
+        executor.schedule(task);
+
+        for _ in 0..2 {
+            let _ = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+                .await
+                .expect("timeout")
+                .expect("closed");
+        }
+
+        let raw = std::fs::read_to_string(log.path_for(6)).unwrap();
+        assert!(!raw.contains("hunter2"));
+        let mut tail = log.tail(6, 10).unwrap();
+        tail.sort();
+        assert_eq!(tail, vec!["hunter2", "oops"]);
+    }
+
+    #[tokio::test]
+    async fn task_execution_is_journaled() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let journal = ExecutionJournal::new(temp_dir.path().join("journal.jsonl"));
+        let (tx, mut rx) = mpsc::channel::<TaskEvent>(100);
+        let executor = TaskExecutor::new(tx).with_journal(journal.clone());
+
+        let task = ScheduledTask::new(7, "true".to_string(), Utc::now(), ExecutionTarget::Background);
+
+        executor.schedule(task);
+
+        // Running, then Completed
+        for _ in 0..2 {
+            let _ = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+                .await
+                .expect("timeout")
+                .expect("closed");
+        }
+
+        let events: Vec<JournalEvent> =
+            journal.entries().unwrap().into_iter().map(|entry| entry.event).collect();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], JournalEvent::Claimed { ref task, .. } if task.id == 7));
+        assert_eq!(events[1], JournalEvent::Started);
+        assert_eq!(events[2], JournalEvent::Finished {
+            status: TaskStatus::Completed
+        });
+        assert!(journal.interrupted().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn task_background_nonzero_exit_fails_with_output_log() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (tx, mut rx) = mpsc::channel::<TaskEvent>(100);
+        let executor = TaskExecutor::new(tx).with_output_log(OutputLog::new(temp_dir.path()));
+
+        let task = ScheduledTask::new(6, "exit 3".to_string(), Utc::now(), ExecutionTarget::Background);
+
+        executor.schedule(task);
+
+        // Skip Running status
+        let _ = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("closed");
+
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timeout waiting for completion")
+            .expect("channel closed");
+
+        match event {
+            TaskEvent::StatusChanged { id, status } => {
+                assert_eq!(id, 6);
+                assert!(matches!(status, TaskStatus::Failed { .. }), "got {status:?}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn task_background_exceeding_max_runtime_is_killed() {
+        let (tx, mut rx) = mpsc::channel::<TaskEvent>(100);
+        let executor = TaskExecutor::new(tx);
+
+        let task = ScheduledTask::new(7, "sleep 30".to_string(), Utc::now(), ExecutionTarget::Background)
+            .with_limits(ResourceLimits::default().with_max_runtime(std::time::Duration::from_secs(1)));
+
+        executor.schedule(task);
+
+        // Skip Running status
+        let _ = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("closed");
+
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .expect("task should be killed well before it finishes")
+            .expect("channel closed");
+
+        match event {
+            TaskEvent::StatusChanged { id, status } => {
+                assert_eq!(id, 7);
+                assert!(
+                    matches!(
+                        status,
+                        TaskStatus::LimitExceeded {
+                            limit: LimitKind::MaxRuntime,
+                            ..
+                        }
+                    ),
+                    "got {status:?}"
+                );
+            }
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn task_background_applies_nice_and_memory_limit() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let log = OutputLog::new(temp_dir.path());
+        let (tx, mut rx) = mpsc::channel::<TaskEvent>(100);
+        let executor = TaskExecutor::new(tx).with_output_log(log.clone());
+
+        let task = ScheduledTask::new(8, "nice; ulimit -v".to_string(), Utc::now(), ExecutionTarget::Background)
+            .with_limits(
+                ResourceLimits::default()
+                    .with_nice(10)
+                    .with_max_memory(512 * 1024 * 1024),
+            );
+
+        executor.schedule(task);
+
+        // Skip Running status
+        let _ = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("closed");
+
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timeout waiting for completion")
+            .expect("channel closed");
+
+        match event {
+            TaskEvent::StatusChanged { status, .. } => {
+                assert_eq!(status, TaskStatus::Completed);
+            }
+        }
+        assert_eq!(log.tail(8, 2).unwrap(), vec!["10", "524288"]);
+    }
+
     #[tokio::test]
     async fn task_waits_for_scheduled_time() {
         let (tx, mut rx) = mpsc::channel::<TaskEvent>(100);
@@ -817,6 +1342,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn windows_terminal_args_target_current_window() {
+        let args = TaskExecutor::windows_terminal_args("split-pane", "cargo build");
+
+        assert_eq!(&args[..3], ["-w", "0", "split-pane"]);
+        assert!(
+            args.iter().any(|arg| arg.contains("cargo build")),
+            "wt args should contain the command: {args:?}"
+        );
+    }
+
+    #[test]
+    fn windows_terminal_args_escape_semicolons() {
+        // wt uses `;` to separate its own subcommands, so a literal semicolon
+        // in the task command must be escaped to stay in the same tab/pane.
+        let args = TaskExecutor::windows_terminal_args("new-tab", "echo a; echo b");
+
+        assert!(
+            args.iter().any(|arg| arg.contains("echo a\\; echo b")),
+            "semicolons should be escaped: {args:?}"
+        );
+        assert!(
+            args.iter().all(|arg| !arg.replace("\\;", "").contains(';')),
+            "no unescaped semicolons should remain: {args:?}"
+        );
+    }
+
     // =========================================================================
     // Regression tests for Bug: Ghostty shows error after command completes
     // =========================================================================
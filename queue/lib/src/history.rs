@@ -8,15 +8,29 @@ use std::collections::HashMap;
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader, BufWriter, Seek, SeekFrom, Write};
 use std::path::PathBuf;
+#[cfg(feature = "encryption")]
+use std::sync::Arc;
 
+use chrono::{DateTime, Local, Utc};
 use fs2::FileExt;
 
+#[cfg(feature = "encryption")]
+use crate::encryption::HistoryKey;
 use crate::error::HistoryError;
-use crate::types::ScheduledTask;
+use crate::report::{self, CommandStats, HistoryReport, RunRecord};
+use crate::types::{ScheduledTask, TaskStatus};
 
-/// Default history file name.
+/// Default history file name (in the home directory on Unix).
+#[cfg(not(windows))]
 const DEFAULT_HISTORY_FILE: &str = ".queue-history.jsonl";
 
+/// Default history file name (in `%LOCALAPPDATA%\queue` on Windows).
+#[cfg(windows)]
+const DEFAULT_HISTORY_FILE: &str = "queue-history.jsonl";
+
+/// Prefix marking a field value encrypted at rest.
+pub(crate) const SEALED_PREFIX: &str = "enc:v1:";
+
 /// Trait for history storage backends.
 ///
 /// Implementations must handle concurrent access safely.
@@ -43,6 +57,49 @@ pub trait HistoryStore {
     ///
     /// Returns an error if reading, writing, or parsing fails.
     fn update(&self, task: &ScheduledTask) -> Result<(), HistoryError>;
+
+    /// Records a finished run for analytics.
+    ///
+    /// Unlike [`save`](Self::save), runs are never de-duplicated.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if writing fails.
+    fn record_run(&self, run: &RunRecord) -> Result<(), HistoryError>;
+
+    /// Loads all recorded runs, oldest first.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if reading or parsing fails.
+    fn load_runs(&self) -> Result<Vec<RunRecord>, HistoryError>;
+
+    /// Returns per-command statistics for runs finished since `since`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the runs cannot be loaded.
+    fn command_stats(&self, since: DateTime<Utc>) -> Result<Vec<CommandStats>, HistoryError> {
+        Ok(report::command_stats(&self.load_runs()?, since))
+    }
+
+    /// Counts failed runs since `since` by local hour of day.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the runs cannot be loaded.
+    fn failures_by_hour(&self, since: DateTime<Utc>) -> Result<[usize; 24], HistoryError> {
+        Ok(report::failures_by_hour(&self.load_runs()?, since, &Local))
+    }
+
+    /// Summarizes runs finished since `since`, bucketing failures by local hour.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the runs cannot be loaded.
+    fn report(&self, since: DateTime<Utc>) -> Result<HistoryReport, HistoryError> {
+        Ok(HistoryReport::from_runs(&self.load_runs()?, since, &Local))
+    }
 }
 
 /// JSONL file-based history storage.
@@ -63,25 +120,69 @@ pub trait HistoryStore {
 /// let tasks = store.load_all().unwrap();
 /// assert_eq!(tasks.len(), 1);
 /// ```
+///
+/// With the `encryption` feature, [`with_key`](Self::with_key) encrypts
+/// commands and error messages at rest; see [`HistoryKey`](crate::HistoryKey).
 #[derive(Debug, Clone)]
 pub struct JsonFileStore {
     path: PathBuf,
+    sealer: Sealer,
 }
 
 impl JsonFileStore {
     /// Creates a new JSON file store at the given path.
     pub fn new(path: PathBuf) -> Self {
-        Self { path }
+        Self {
+            path,
+            sealer: Sealer::default(),
+        }
+    }
+
+    /// Encrypts commands and error messages with `key` when writing, and
+    /// decrypts them when reading.
+    ///
+    /// Timestamps, status, and the other fields stay readable without the
+    /// key. Plain entries from before encryption was enabled still load.
+    #[cfg(feature = "encryption")]
+    pub fn with_key(mut self, key: HistoryKey) -> Self {
+        self.sealer = Sealer::new(key);
+        self
     }
 
-    /// Creates a new JSON file store at the default path (`~/.queue-history.jsonl`).
+    /// Encrypts with the key in [`HISTORY_KEY_ENV`](crate::HISTORY_KEY_ENV)
+    /// when it's set, and leaves the store unencrypted otherwise.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`HistoryError::Key`] if the variable holds an invalid key.
+    #[cfg(feature = "encryption")]
+    pub fn with_env_key(mut self) -> Result<Self, HistoryError> {
+        self.sealer = Sealer::from_env()?;
+        Ok(self)
+    }
+
+    /// Creates a new JSON file store at the default path.
+    ///
+    /// This is `~/.queue-history.jsonl` on Unix and
+    /// `%LOCALAPPDATA%\queue\queue-history.jsonl` on Windows.
     ///
     /// ## Panics
     ///
-    /// Panics if the home directory cannot be determined.
+    /// Panics if the home (or local app data) directory cannot be determined.
     pub fn default_path() -> Self {
+        Self::new(Self::default_history_path())
+    }
+
+    #[cfg(not(windows))]
+    fn default_history_path() -> PathBuf {
         let home = dirs::home_dir().expect("could not determine home directory");
-        Self::new(home.join(DEFAULT_HISTORY_FILE))
+        home.join(DEFAULT_HISTORY_FILE)
+    }
+
+    #[cfg(windows)]
+    fn default_history_path() -> PathBuf {
+        let data_dir = dirs::data_local_dir().expect("could not determine local app data directory");
+        data_dir.join("queue").join(DEFAULT_HISTORY_FILE)
     }
 
     /// Returns the path to the history file.
@@ -89,6 +190,13 @@ impl JsonFileStore {
         &self.path
     }
 
+    /// Returns the path to the run log, stored next to the history file.
+    ///
+    /// For `~/.queue-history.jsonl` this is `~/.queue-history.runs.jsonl`.
+    pub fn runs_path(&self) -> PathBuf {
+        self.path.with_extension("runs.jsonl")
+    }
+
     /// Ensures the history file exists, creating it if necessary.
     fn ensure_file_exists(&self) -> Result<(), HistoryError> {
         if !self.path.exists() {
@@ -100,9 +208,11 @@ impl JsonFileStore {
         Ok(())
     }
 
-    fn load_tasks(file: &mut File) -> Result<Vec<ScheduledTask>, HistoryError> {
+    fn load_tasks(&self, mut file: &File) -> Result<Vec<ScheduledTask>, HistoryError> {
         file.seek(SeekFrom::Start(0))?;
-        let reader = BufReader::new(file.try_clone()?);
+        // Read through the locked handle itself rather than a cloned handle;
+        // Windows byte-range locks are enforced per handle.
+        let reader = BufReader::new(file);
         let mut tasks = Vec::new();
 
         for line in reader.lines() {
@@ -110,19 +220,26 @@ impl JsonFileStore {
             if line.trim().is_empty() {
                 continue;
             }
-            let task: ScheduledTask = serde_json::from_str(&line)?;
+            let mut task: ScheduledTask = serde_json::from_str(&line)?;
+            self.sealer.open_fields(&mut task.command, &mut task.status)?;
             tasks.push(task);
         }
 
         Ok(tasks)
     }
 
-    fn rewrite_tasks(file: &mut File, tasks: &[ScheduledTask]) -> Result<(), HistoryError> {
+    fn rewrite_tasks(&self, mut file: &File, tasks: &[ScheduledTask]) -> Result<(), HistoryError> {
+        // Seal everything before truncating so a failure leaves the file intact
+        let mut sealed = tasks.to_vec();
+        for task in &mut sealed {
+            self.sealer.seal_fields(&mut task.command, &mut task.status)?;
+        }
+
         file.set_len(0)?;
         file.seek(SeekFrom::Start(0))?;
 
         let mut writer = BufWriter::new(file);
-        for task in tasks {
+        for task in &sealed {
             let json = serde_json::to_string(task)?;
             writeln!(writer, "{json}")?;
         }
@@ -153,47 +270,204 @@ impl JsonFileStore {
     }
 }
 
+/// Seals and opens values kept encrypted at rest.
+///
+/// Shared by the history store, the execution journal and output logs.
+/// Without a key (or without the `encryption` feature) values are written
+/// as they are; plain values are always read back unchanged.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Sealer {
+    #[cfg(feature = "encryption")]
+    key: Option<Arc<HistoryKey>>,
+}
+
+impl Sealer {
+    /// Creates a sealer that encrypts with `key`.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn new(key: HistoryKey) -> Self {
+        Self {
+            key: Some(Arc::new(key)),
+        }
+    }
+
+    /// Creates a sealer with the key in [`HISTORY_KEY_ENV`](crate::HISTORY_KEY_ENV),
+    /// or one that leaves values plain when it's unset.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn from_env() -> Result<Self, HistoryError> {
+        Ok(Self {
+            key: HistoryKey::from_env()?.map(Arc::new),
+        })
+    }
+
+    /// Returns true if values are encrypted when written.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.key.is_some()
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    pub(crate) fn is_enabled(&self) -> bool {
+        false
+    }
+
+    /// Encrypts `value` for `field`, or returns it unchanged without a key.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn seal(&self, field: &str, value: &str) -> Result<String, HistoryError> {
+        match &self.key {
+            Some(key) => key.seal(field, value),
+            None => Ok(value.to_string()),
+        }
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    pub(crate) fn seal(&self, _field: &str, value: &str) -> Result<String, HistoryError> {
+        Ok(value.to_string())
+    }
+
+    /// Decrypts a value sealed for `field`, leaving plain values as they are.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`HistoryError::MissingKey`] for a sealed value without a key,
+    /// and [`HistoryError::Decrypt`] if the key doesn't match.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn open(&self, field: &str, value: &str) -> Result<String, HistoryError> {
+        match &self.key {
+            _ if !value.starts_with(SEALED_PREFIX) => Ok(value.to_string()),
+            Some(key) => key.open(field, value),
+            None => Err(HistoryError::MissingKey),
+        }
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    pub(crate) fn open(&self, _field: &str, value: &str) -> Result<String, HistoryError> {
+        if value.starts_with(SEALED_PREFIX) {
+            Err(HistoryError::MissingKey)
+        } else {
+            Ok(value.to_string())
+        }
+    }
+
+    /// Encrypts the command and any error message of an entry about to be written.
+    pub(crate) fn seal_fields(
+        &self,
+        command: &mut String,
+        status: &mut TaskStatus,
+    ) -> Result<(), HistoryError> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        map_sensitive_fields(command, status, |field, value| self.seal(field, value))
+    }
+
+    /// Encrypts the error message of a failed status about to be written.
+    pub(crate) fn seal_status(&self, status: &mut TaskStatus) -> Result<(), HistoryError> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        map_error(status, |field, value| self.seal(field, value))
+    }
+
+    /// Decrypts the error message of a failed status read from disk.
+    pub(crate) fn open_status(&self, status: &mut TaskStatus) -> Result<(), HistoryError> {
+        map_error(status, |field, value| self.open(field, value))
+    }
+
+    /// Decrypts the command and any error message of an entry read from disk.
+    pub(crate) fn open_fields(
+        &self,
+        command: &mut String,
+        status: &mut TaskStatus,
+    ) -> Result<(), HistoryError> {
+        map_sensitive_fields(command, status, |field, value| self.open(field, value))
+    }
+}
+
+/// Replaces the fields kept encrypted at rest, the command and any error
+/// message, with `f(field_name, value)`.
+fn map_sensitive_fields(
+    command: &mut String,
+    status: &mut TaskStatus,
+    f: impl Fn(&str, &str) -> Result<String, HistoryError>,
+) -> Result<(), HistoryError> {
+    *command = f("command", command)?;
+    map_error(status, f)
+}
+
+/// Replaces the error message of a failed status with `f("error", message)`.
+fn map_error(
+    status: &mut TaskStatus,
+    f: impl Fn(&str, &str) -> Result<String, HistoryError>,
+) -> Result<(), HistoryError> {
+    if let TaskStatus::Failed { error } | TaskStatus::LimitExceeded { error, .. } = status {
+        *error = f("error", error)?;
+    }
+    Ok(())
+}
+
+/// An acquired lock on a history or journal file, released when dropped.
+///
+/// Windows may defer releasing a lock until some time after its handle is
+/// closed, which can make the next access fail with [`HistoryError::Lock`].
+/// Unlocking explicitly on drop releases the lock promptly on every path,
+/// including early returns on errors.
+pub(crate) struct FileLock<'a> {
+    file: &'a File,
+}
+
+impl<'a> FileLock<'a> {
+    pub(crate) fn shared(file: &'a File) -> Result<Self, HistoryError> {
+        file.lock_shared().map_err(|_| HistoryError::Lock)?;
+        Ok(Self { file })
+    }
+
+    pub(crate) fn exclusive(file: &'a File) -> Result<Self, HistoryError> {
+        file.lock_exclusive().map_err(|_| HistoryError::Lock)?;
+        Ok(Self { file })
+    }
+}
+
+impl Drop for FileLock<'_> {
+    fn drop(&mut self) {
+        let _ = FileExt::unlock(self.file);
+    }
+}
+
 impl HistoryStore for JsonFileStore {
     fn load_all(&self) -> Result<Vec<ScheduledTask>, HistoryError> {
         if !self.path.exists() {
             return Ok(Vec::new());
         }
 
-        let mut file = File::open(&self.path)?;
-        file.lock_shared().map_err(|_| HistoryError::Lock)?;
-
-        let tasks = Self::load_tasks(&mut file)?;
+        let file = File::open(&self.path)?;
+        let _lock = FileLock::shared(&file)?;
 
-        file.unlock().map_err(|_| HistoryError::Lock)?;
-        Ok(tasks)
+        self.load_tasks(&file)
     }
 
     fn save(&self, task: &ScheduledTask) -> Result<(), HistoryError> {
         self.ensure_file_exists()?;
-        let mut file = OpenOptions::new().read(true).write(true).open(&self.path)?;
+        let file = OpenOptions::new().read(true).write(true).open(&self.path)?;
 
-        file.lock_exclusive().map_err(|_| HistoryError::Lock)?;
+        let _lock = FileLock::exclusive(&file)?;
 
-        let mut tasks = Self::load_tasks(&mut file)?;
+        let mut tasks = self.load_tasks(&file)?;
         tasks.push(task.clone());
         let tasks = Self::dedupe_tasks(tasks);
-        Self::rewrite_tasks(&mut file, &tasks)?;
-
-        file.unlock().map_err(|_| HistoryError::Lock)?;
-        Ok(())
+        self.rewrite_tasks(&file, &tasks)
     }
 
     fn update(&self, task: &ScheduledTask) -> Result<(), HistoryError> {
         self.ensure_file_exists()?;
 
-        let mut file = OpenOptions::new()
+        let file = OpenOptions::new()
             .read(true)
             .write(true)
             .open(&self.path)?;
 
-        file.lock_exclusive().map_err(|_| HistoryError::Lock)?;
+        let _lock = FileLock::exclusive(&file)?;
 
-        let mut tasks = Self::load_tasks(&mut file)?;
+        let mut tasks = self.load_tasks(&file)?;
         let mut updated = false;
 
         for existing in &mut tasks {
@@ -209,11 +483,47 @@ impl HistoryStore for JsonFileStore {
         }
 
         let tasks = Self::dedupe_tasks(tasks);
-        Self::rewrite_tasks(&mut file, &tasks)?;
+        self.rewrite_tasks(&file, &tasks)
+    }
 
-        file.unlock().map_err(|_| HistoryError::Lock)?;
+    fn record_run(&self, run: &RunRecord) -> Result<(), HistoryError> {
+        let path = self.runs_path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let _lock = FileLock::exclusive(&file)?;
+
+        let mut run = run.clone();
+        self.sealer.seal_fields(&mut run.command, &mut run.status)?;
+        let json = serde_json::to_string(&run)?;
+        let mut writer = BufWriter::new(&file);
+        writeln!(writer, "{json}")?;
+        writer.flush()?;
         Ok(())
     }
+
+    fn load_runs(&self) -> Result<Vec<RunRecord>, HistoryError> {
+        let path = self.runs_path();
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let file = File::open(&path)?;
+        let _lock = FileLock::shared(&file)?;
+
+        let mut runs = Vec::new();
+        for line in BufReader::new(&file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut run: RunRecord = serde_json::from_str(&line)?;
+            self.sealer.open_fields(&mut run.command, &mut run.status)?;
+            runs.push(run);
+        }
+        Ok(runs)
+    }
 }
 
 #[cfg(test)]
@@ -378,12 +688,36 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(windows))]
     fn default_path_points_to_home_directory() {
         let store = JsonFileStore::default_path();
         let home = dirs::home_dir().unwrap();
         assert_eq!(store.path(), &home.join(".queue-history.jsonl"));
     }
 
+    #[test]
+    #[cfg(windows)]
+    fn default_path_points_to_local_app_data() {
+        let store = JsonFileStore::default_path();
+        let data_dir = dirs::data_local_dir().unwrap();
+        assert_eq!(
+            store.path(),
+            &data_dir.join("queue").join("queue-history.jsonl")
+        );
+    }
+
+    #[test]
+    fn lock_is_released_after_failed_parse() {
+        let (store, _temp_dir) = create_test_store();
+        std::fs::write(store.path(), "not json\n").unwrap();
+
+        assert!(matches!(store.load_all(), Err(HistoryError::Parse(_))));
+
+        // An exclusive lock must be obtainable once the failed read returns.
+        let file = File::open(store.path()).unwrap();
+        assert!(file.try_lock_exclusive().is_ok());
+    }
+
     #[test]
     fn file_locking_allows_sequential_access() {
         let (store, _temp_dir) = create_test_store();
@@ -405,4 +739,125 @@ mod tests {
         let tasks = store.load_all().unwrap();
         assert_eq!(tasks.len(), 5);
     }
+
+    #[test]
+    fn recorded_runs_are_not_deduplicated() {
+        let (store, _temp_dir) = create_test_store();
+
+        for id in 1..=3 {
+            let mut task = ScheduledTask::new(
+                id,
+                "cargo test".to_string(),
+                Utc::now(),
+                ExecutionTarget::Background,
+            );
+            store.save(&task).unwrap();
+            task.mark_running();
+            task.mark_completed();
+            store.update(&task).unwrap();
+            store
+                .record_run(&RunRecord::from_task(&task, Utc::now()).unwrap())
+                .unwrap();
+        }
+
+        assert_eq!(store.load_all().unwrap().len(), 1);
+        assert_eq!(store.load_runs().unwrap().len(), 3);
+
+        let stats = store.command_stats(Utc::now() - Duration::days(7)).unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].runs, 3);
+        assert_eq!(stats[0].success_rate, 1.0);
+    }
+
+    #[test]
+    fn runs_path_sits_next_to_history_file() {
+        let store = JsonFileStore::new(PathBuf::from("/tmp/.queue-history.jsonl"));
+        assert_eq!(
+            store.runs_path(),
+            PathBuf::from("/tmp/.queue-history.runs.jsonl")
+        );
+    }
+
+    #[test]
+    fn encrypted_entries_need_a_key() {
+        let (store, _temp_dir) = create_test_store();
+        let task = ScheduledTask::new(
+            1,
+            "enc:v1:AAAA".to_string(),
+            Utc::now(),
+            ExecutionTarget::Background,
+        );
+        store.save(&task).unwrap();
+
+        assert!(matches!(store.load_all(), Err(HistoryError::MissingKey)));
+    }
+
+    #[cfg(feature = "encryption")]
+    mod encryption {
+        use super::*;
+
+        fn failed_task() -> ScheduledTask {
+            let mut task = ScheduledTask::new(
+                1,
+                "deploy --token s3cret".to_string(),
+                Utc::now(),
+                ExecutionTarget::Background,
+            );
+            task.mark_running();
+            task.mark_failed("deploy --token s3cret: exit status 1".to_string());
+            task
+        }
+
+        #[test]
+        fn sensitive_fields_are_encrypted_on_disk() {
+            let (store, _temp_dir) = create_test_store();
+            let store = store.with_key(HistoryKey::generate().unwrap());
+            let task = failed_task();
+
+            store.save(&task).unwrap();
+            store
+                .record_run(&RunRecord::from_task(&task, Utc::now()).unwrap())
+                .unwrap();
+
+            let history = std::fs::read_to_string(store.path()).unwrap();
+            let runs = std::fs::read_to_string(store.runs_path()).unwrap();
+            for contents in [&history, &runs] {
+                assert!(!contents.contains("s3cret"));
+                assert!(contents.contains(SEALED_PREFIX));
+                assert!(contents.contains(r#""status":"failed""#));
+            }
+
+            assert_eq!(store.load_all().unwrap(), vec![task.clone()]);
+            assert_eq!(store.load_runs().unwrap()[0].command, task.command);
+        }
+
+        #[test]
+        fn wrong_or_missing_key_fails_to_load() {
+            let (store, _temp_dir) = create_test_store();
+            store
+                .clone()
+                .with_key(HistoryKey::generate().unwrap())
+                .save(&failed_task())
+                .unwrap();
+
+            let wrong_key = store.clone().with_key(HistoryKey::generate().unwrap());
+            assert!(matches!(wrong_key.load_all(), Err(HistoryError::Decrypt)));
+            assert!(matches!(store.load_all(), Err(HistoryError::MissingKey)));
+        }
+
+        #[test]
+        fn plain_history_is_encrypted_on_next_write() {
+            let (store, _temp_dir) = create_test_store();
+            let task = failed_task();
+            store.save(&task).unwrap();
+
+            let store = store.with_key(HistoryKey::generate().unwrap());
+            assert_eq!(store.load_all().unwrap(), vec![task.clone()]);
+
+            store.update(&task).unwrap();
+            let history = std::fs::read_to_string(store.path()).unwrap();
+            assert!(!history.contains("s3cret"));
+            assert_eq!(store.load_all().unwrap(), vec![task]);
+        }
+    }
 }
@@ -0,0 +1,695 @@
+//! Write-ahead journal of task execution.
+//!
+//! The executor records each task as it is claimed (its scheduled time has
+//! arrived), started (its command was launched) and finished. If queue exits
+//! while a task is between claim and finish, the journal still shows that task
+//! as unfinished, and [`ExecutionJournal::recover`] resolves it at the next
+//! startup according to a [`RecoveryPolicy`].
+//!
+//! A task that is rerun during recovery may already have run, fully or in
+//! part, before the crash, so execution is at-least-once.
+//!
+//! Journaled tasks carry their commands and error messages. With the
+//! `encryption` feature and a [`HistoryKey`](crate::HistoryKey), these are
+//! sealed the same way as in [`JsonFileStore`](crate::JsonFileStore).
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "encryption")]
+use crate::encryption::HistoryKey;
+use crate::error::HistoryError;
+use crate::history::{FileLock, HistoryStore, Sealer};
+use crate::report::RunRecord;
+use crate::types::{ScheduledTask, TaskStatus};
+
+/// Default journal file name (in the home directory on Unix).
+#[cfg(not(windows))]
+const DEFAULT_JOURNAL_FILE: &str = ".queue-journal.jsonl";
+
+/// Default journal file name (in `%LOCALAPPDATA%\queue` on Windows).
+#[cfg(windows)]
+const DEFAULT_JOURNAL_FILE: &str = "queue-journal.jsonl";
+
+/// Error recorded for interrupted tasks resolved with [`RecoveryPolicy::MarkFailed`].
+pub const INTERRUPTED_ERROR: &str = "interrupted: queue exited before the task finished";
+
+/// A step in a task's execution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "event")]
+pub enum JournalEvent {
+    /// The task's scheduled time arrived and an executor took it.
+    Claimed {
+        /// The task as it was when claimed.
+        task: ScheduledTask,
+        /// Process ID of the executor that claimed the task.
+        pid: u32,
+    },
+    /// The task's command was launched.
+    Started,
+    /// The task reached a final status.
+    Finished {
+        /// The task's final status.
+        status: TaskStatus,
+    },
+    /// The task was interrupted and resolved by [`ExecutionJournal::recover`].
+    Recovered {
+        /// Whether the task was rescheduled rather than marked failed.
+        rerun: bool,
+    },
+}
+
+/// One line of the journal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// The task this entry is about.
+    pub task_id: u64,
+    /// When the entry was written.
+    pub at: DateTime<Utc>,
+    /// What happened.
+    #[serde(flatten)]
+    pub event: JournalEvent,
+}
+
+/// How to resolve tasks that were interrupted by a crash.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RecoveryPolicy {
+    /// Schedule every interrupted task to run again immediately.
+    Rerun,
+    /// Record every interrupted task as failed.
+    MarkFailed,
+    /// Ask about each interrupted task.
+    #[default]
+    Ask,
+}
+
+impl std::fmt::Display for RecoveryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecoveryPolicy::Rerun => write!(f, "rerun"),
+            RecoveryPolicy::MarkFailed => write!(f, "mark-failed"),
+            RecoveryPolicy::Ask => write!(f, "ask"),
+        }
+    }
+}
+
+impl std::str::FromStr for RecoveryPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "rerun" => Ok(RecoveryPolicy::Rerun),
+            "mark-failed" | "fail" => Ok(RecoveryPolicy::MarkFailed),
+            "ask" => Ok(RecoveryPolicy::Ask),
+            other => Err(format!(
+                "invalid recovery policy '{other}' (expected rerun, mark-failed or ask)"
+            )),
+        }
+    }
+}
+
+/// A task the journal shows as claimed but never finished.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterruptedTask {
+    /// The task as it was when claimed.
+    pub task: ScheduledTask,
+    /// When the task was claimed.
+    pub claimed_at: DateTime<Utc>,
+    /// When the task's command was launched, if it got that far.
+    pub started_at: Option<DateTime<Utc>>,
+}
+
+/// JSONL write-ahead journal of task execution.
+///
+/// Entries are appended under an exclusive file lock, so several queue
+/// processes can share one journal. Tasks claimed by a process that is still
+/// alive are never reported as interrupted.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use queue_lib::{ExecutionJournal, JsonFileStore, RecoveryPolicy};
+///
+/// let journal = ExecutionJournal::default_path();
+/// let store = JsonFileStore::default_path();
+/// let rerun = journal.recover(&store, RecoveryPolicy::Rerun, |_| true).unwrap();
+/// println!("{} task(s) to run again", rerun.len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExecutionJournal {
+    path: PathBuf,
+    sealer: Sealer,
+}
+
+impl ExecutionJournal {
+    /// Creates a journal stored at the given path.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            sealer: Sealer::default(),
+        }
+    }
+
+    /// Encrypts journaled commands and error messages with `key`, and
+    /// decrypts them when reading.
+    #[cfg(feature = "encryption")]
+    pub fn with_key(mut self, key: HistoryKey) -> Self {
+        self.sealer = Sealer::new(key);
+        self
+    }
+
+    /// Encrypts with the key in [`HISTORY_KEY_ENV`](crate::HISTORY_KEY_ENV)
+    /// when it's set, and leaves the journal unencrypted otherwise.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`HistoryError::Key`] if the variable holds an invalid key.
+    #[cfg(feature = "encryption")]
+    pub fn with_env_key(mut self) -> Result<Self, HistoryError> {
+        self.sealer = Sealer::from_env()?;
+        Ok(self)
+    }
+
+    /// Creates a journal at the default path.
+    ///
+    /// This is `~/.queue-journal.jsonl` on Unix and
+    /// `%LOCALAPPDATA%\queue\queue-journal.jsonl` on Windows.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the home (or local app data) directory cannot be determined.
+    pub fn default_path() -> Self {
+        Self::new(Self::default_journal_path())
+    }
+
+    #[cfg(not(windows))]
+    fn default_journal_path() -> PathBuf {
+        let home = dirs::home_dir().expect("could not determine home directory");
+        home.join(DEFAULT_JOURNAL_FILE)
+    }
+
+    #[cfg(windows)]
+    fn default_journal_path() -> PathBuf {
+        let data_dir =
+            dirs::data_local_dir().expect("could not determine local app data directory");
+        data_dir.join("queue").join(DEFAULT_JOURNAL_FILE)
+    }
+
+    /// Returns the path to the journal file.
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Appends an event for a task.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the journal cannot be written.
+    pub fn record(&self, task_id: u64, event: JournalEvent) -> Result<(), HistoryError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let _lock = FileLock::exclusive(&file)?;
+
+        let entry = JournalEntry {
+            task_id,
+            at: Utc::now(),
+            event: self.seal_event(event)?,
+        };
+        let json = serde_json::to_string(&entry)?;
+        let mut writer = BufWriter::new(&file);
+        writeln!(writer, "{json}")?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Loads all entries, oldest first.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if reading or parsing fails.
+    pub fn entries(&self) -> Result<Vec<JournalEntry>, HistoryError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let file = File::open(&self.path)?;
+        let _lock = FileLock::shared(&file)?;
+        Self::load_entries(&file)?
+            .into_iter()
+            .map(|entry| {
+                Ok(JournalEntry {
+                    event: self.open_event(entry.event)?,
+                    ..entry
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the tasks that were claimed but never finished, in claim order.
+    ///
+    /// Tasks claimed by a process that is still running (including this one)
+    /// are in progress rather than interrupted, and are left out.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the journal cannot be read.
+    pub fn interrupted(&self) -> Result<Vec<InterruptedTask>, HistoryError> {
+        let mut open: HashMap<u64, (InterruptedTask, u32)> = HashMap::new();
+
+        for entry in self.entries()? {
+            match entry.event {
+                JournalEvent::Claimed { task, pid } => {
+                    let interrupted = InterruptedTask {
+                        task,
+                        claimed_at: entry.at,
+                        started_at: None,
+                    };
+                    open.insert(entry.task_id, (interrupted, pid));
+                }
+                JournalEvent::Started => {
+                    if let Some((interrupted, _)) = open.get_mut(&entry.task_id) {
+                        interrupted.started_at = Some(entry.at);
+                    }
+                }
+                JournalEvent::Finished { .. } | JournalEvent::Recovered { .. } => {
+                    open.remove(&entry.task_id);
+                }
+            }
+        }
+
+        let mut interrupted: Vec<InterruptedTask> = open
+            .into_values()
+            .filter(|(_, pid)| !process_alive(*pid))
+            .map(|(interrupted, _)| interrupted)
+            .collect();
+        interrupted.sort_by(|a, b| {
+            a.claimed_at
+                .cmp(&b.claimed_at)
+                .then_with(|| a.task.id.cmp(&b.task.id))
+        });
+        Ok(interrupted)
+    }
+
+    /// Resolves interrupted tasks and returns the ones to run again.
+    ///
+    /// Each interrupted task is either rerun or marked failed, depending on
+    /// `policy`; with [`RecoveryPolicy::Ask`], `confirm_rerun` decides. Failed
+    /// tasks are updated in `store` and recorded as failed runs. Rerun tasks
+    /// are returned pending and due now, ready to be scheduled again.
+    ///
+    /// Every interrupted task is then marked recovered in the journal and the
+    /// journal is compacted, so each crash is only recovered once.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the journal or history cannot be read or written.
+    pub fn recover<S: HistoryStore>(
+        &self,
+        store: &S,
+        policy: RecoveryPolicy,
+        mut confirm_rerun: impl FnMut(&InterruptedTask) -> bool,
+    ) -> Result<Vec<ScheduledTask>, HistoryError> {
+        let now = Utc::now();
+        let mut rerun = Vec::new();
+
+        for interrupted in self.interrupted()? {
+            let should_rerun = match policy {
+                RecoveryPolicy::Rerun => true,
+                RecoveryPolicy::MarkFailed => false,
+                RecoveryPolicy::Ask => confirm_rerun(&interrupted),
+            };
+
+            let task_id = interrupted.task.id;
+            let mut task = interrupted.task;
+            if should_rerun {
+                task.status = TaskStatus::Pending;
+                task.started_at = None;
+                task.scheduled_at = now;
+                rerun.push(task);
+            } else {
+                task.started_at = interrupted.started_at;
+                task.mark_failed(INTERRUPTED_ERROR);
+                store.update(&task)?;
+                if let Some(run) = RunRecord::from_task(&task, now) {
+                    store.record_run(&run)?;
+                }
+            }
+            self.record(
+                task_id,
+                JournalEvent::Recovered {
+                    rerun: should_rerun,
+                },
+            )?;
+        }
+
+        self.compact()?;
+        Ok(rerun)
+    }
+
+    /// Drops the entries of finished tasks.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the journal cannot be read or rewritten.
+    pub fn compact(&self) -> Result<(), HistoryError> {
+        if !self.path.exists() {
+            return Ok(());
+        }
+
+        let file = OpenOptions::new().read(true).write(true).open(&self.path)?;
+        let _lock = FileLock::exclusive(&file)?;
+
+        let entries = Self::load_entries(&file)?;
+        let mut open: HashMap<u64, bool> = HashMap::new();
+        for entry in &entries {
+            match entry.event {
+                JournalEvent::Claimed { .. } => {
+                    open.insert(entry.task_id, true);
+                }
+                JournalEvent::Finished { .. } | JournalEvent::Recovered { .. } => {
+                    open.insert(entry.task_id, false);
+                }
+                JournalEvent::Started => {}
+            }
+        }
+
+        let unfinished: Vec<&JournalEntry> = entries
+            .iter()
+            .filter(|entry| open.get(&entry.task_id).copied().unwrap_or(false))
+            .collect();
+        Self::rewrite_entries(&file, &unfinished)
+    }
+
+    fn seal_event(&self, event: JournalEvent) -> Result<JournalEvent, HistoryError> {
+        Ok(match event {
+            JournalEvent::Claimed { mut task, pid } => {
+                self.sealer
+                    .seal_fields(&mut task.command, &mut task.status)?;
+                JournalEvent::Claimed { task, pid }
+            }
+            JournalEvent::Finished { mut status } => {
+                self.sealer.seal_status(&mut status)?;
+                JournalEvent::Finished { status }
+            }
+            event => event,
+        })
+    }
+
+    fn open_event(&self, event: JournalEvent) -> Result<JournalEvent, HistoryError> {
+        Ok(match event {
+            JournalEvent::Claimed { mut task, pid } => {
+                self.sealer
+                    .open_fields(&mut task.command, &mut task.status)?;
+                JournalEvent::Claimed { task, pid }
+            }
+            JournalEvent::Finished { mut status } => {
+                self.sealer.open_status(&mut status)?;
+                JournalEvent::Finished { status }
+            }
+            event => event,
+        })
+    }
+
+    /// Rewrites the journal with already-sealed entries.
+    fn rewrite_entries(mut file: &File, entries: &[&JournalEntry]) -> Result<(), HistoryError> {
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+
+        let mut writer = BufWriter::new(file);
+        for entry in entries {
+            let json = serde_json::to_string(entry)?;
+            writeln!(writer, "{json}")?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn load_entries(mut file: &File) -> Result<Vec<JournalEntry>, HistoryError> {
+        file.seek(SeekFrom::Start(0))?;
+        let mut entries = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            entries.push(serde_json::from_str(&line)?);
+        }
+        Ok(entries)
+    }
+}
+
+/// Returns true if a process with the given ID is running.
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    if pid == std::process::id() {
+        return true;
+    }
+    let Ok(pid) = i32::try_from(pid) else {
+        return false;
+    };
+    // SAFETY: kill(2) with signal 0 only checks for the process; it sends nothing.
+    let result = unsafe { libc::kill(pid, 0) };
+    result == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Other processes cannot be checked on this platform, so only this one counts as alive.
+#[cfg(not(unix))]
+fn process_alive(pid: u32) -> bool {
+    pid == std::process::id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::JsonFileStore;
+    use crate::types::ExecutionTarget;
+    use tempfile::TempDir;
+
+    /// A process ID that is not running.
+    const DEAD_PID: u32 = u32::MAX;
+
+    fn create_test_journal() -> (ExecutionJournal, JsonFileStore, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let journal = ExecutionJournal::new(temp_dir.path().join("journal.jsonl"));
+        let store = JsonFileStore::new(temp_dir.path().join("history.jsonl"));
+        (journal, store, temp_dir)
+    }
+
+    fn task(id: u64, command: &str) -> ScheduledTask {
+        ScheduledTask::new(
+            id,
+            command.to_string(),
+            Utc::now(),
+            ExecutionTarget::Background,
+        )
+    }
+
+    fn claim(journal: &ExecutionJournal, task: &ScheduledTask, pid: u32) {
+        journal
+            .record(
+                task.id,
+                JournalEvent::Claimed {
+                    task: task.clone(),
+                    pid,
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn entries_round_trip() {
+        let (journal, _store, _temp_dir) = create_test_journal();
+        let task = task(1, "make");
+
+        claim(&journal, &task, DEAD_PID);
+        journal.record(1, JournalEvent::Started).unwrap();
+
+        let entries = journal.entries().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].task_id, 1);
+        assert_eq!(entries[1].event, JournalEvent::Started);
+    }
+
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn sealed_entries_hide_commands_and_errors() {
+        let (journal, _store, _temp_dir) = create_test_journal();
+        let journal = journal.with_key(crate::HistoryKey::generate().unwrap());
+        let task = task(1, "deploy --token hunter2");
+
+        claim(&journal, &task, DEAD_PID);
+        journal
+            .record(
+                1,
+                JournalEvent::Finished {
+                    status: TaskStatus::Failed {
+                        error: "bad token hunter2".to_string(),
+                    },
+                },
+            )
+            .unwrap();
+
+        let raw = std::fs::read_to_string(journal.path()).unwrap();
+        assert!(!raw.contains("hunter2"));
+
+        let entries = journal.entries().unwrap();
+        assert!(matches!(
+            &entries[0].event,
+            JournalEvent::Claimed { task, .. } if task.command == "deploy --token hunter2"
+        ));
+        assert_eq!(
+            entries[1].event,
+            JournalEvent::Finished {
+                status: TaskStatus::Failed {
+                    error: "bad token hunter2".to_string(),
+                },
+            }
+        );
+
+        let without_key = ExecutionJournal::new(journal.path().clone());
+        assert!(matches!(
+            without_key.entries(),
+            Err(HistoryError::MissingKey)
+        ));
+    }
+
+    #[test]
+    fn interrupted_skips_finished_and_live_tasks() {
+        let (journal, _store, _temp_dir) = create_test_journal();
+        let finished = task(1, "finished");
+        let crashed = task(2, "crashed");
+        let live = task(3, "live");
+
+        claim(&journal, &finished, DEAD_PID);
+        journal
+            .record(
+                1,
+                JournalEvent::Finished {
+                    status: TaskStatus::Completed,
+                },
+            )
+            .unwrap();
+        claim(&journal, &crashed, DEAD_PID);
+        journal.record(2, JournalEvent::Started).unwrap();
+        claim(&journal, &live, std::process::id());
+
+        let interrupted = journal.interrupted().unwrap();
+        assert_eq!(interrupted.len(), 1);
+        assert_eq!(interrupted[0].task.id, 2);
+        assert!(interrupted[0].started_at.is_some());
+    }
+
+    #[test]
+    fn recover_mark_failed_updates_history() {
+        let (journal, store, _temp_dir) = create_test_journal();
+        let task = task(1, "backup");
+        store.save(&task).unwrap();
+        claim(&journal, &task, DEAD_PID);
+        journal.record(1, JournalEvent::Started).unwrap();
+
+        let rerun = journal
+            .recover(&store, RecoveryPolicy::MarkFailed, |_| true)
+            .unwrap();
+
+        assert!(rerun.is_empty());
+        let tasks = store.load_all().unwrap();
+        assert_eq!(
+            tasks[0].status,
+            TaskStatus::Failed {
+                error: INTERRUPTED_ERROR.to_string()
+            }
+        );
+        assert_eq!(store.load_runs().unwrap().len(), 1);
+        assert!(journal.interrupted().unwrap().is_empty());
+        assert!(journal.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn recover_rerun_returns_pending_tasks() {
+        let (journal, store, _temp_dir) = create_test_journal();
+        let mut task = task(1, "sync");
+        task.mark_running();
+        claim(&journal, &task, DEAD_PID);
+
+        let rerun = journal
+            .recover(&store, RecoveryPolicy::Rerun, |_| false)
+            .unwrap();
+
+        assert_eq!(rerun.len(), 1);
+        assert!(rerun[0].is_pending());
+        assert!(rerun[0].started_at.is_none());
+        assert!(journal.interrupted().unwrap().is_empty());
+    }
+
+    #[test]
+    fn recover_ask_consults_callback() {
+        let (journal, store, _temp_dir) = create_test_journal();
+        claim(&journal, &task(1, "yes"), DEAD_PID);
+        claim(&journal, &task(2, "no"), DEAD_PID);
+
+        let rerun = journal
+            .recover(&store, RecoveryPolicy::Ask, |interrupted| {
+                interrupted.task.command == "yes"
+            })
+            .unwrap();
+
+        assert_eq!(rerun.len(), 1);
+        assert_eq!(rerun[0].id, 1);
+        assert!(
+            store
+                .load_all()
+                .unwrap()
+                .iter()
+                .any(|t| t.id == 2 && t.is_failed())
+        );
+    }
+
+    #[test]
+    fn compact_keeps_unfinished_tasks() {
+        let (journal, _store, _temp_dir) = create_test_journal();
+        claim(&journal, &task(1, "done"), DEAD_PID);
+        journal
+            .record(
+                1,
+                JournalEvent::Finished {
+                    status: TaskStatus::Completed,
+                },
+            )
+            .unwrap();
+        claim(&journal, &task(2, "running"), std::process::id());
+
+        journal.compact().unwrap();
+
+        let entries = journal.entries().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].task_id, 2);
+    }
+
+    #[test]
+    fn recovery_policy_parses_names() {
+        assert_eq!(
+            "rerun".parse::<RecoveryPolicy>().unwrap(),
+            RecoveryPolicy::Rerun
+        );
+        assert_eq!(
+            "mark-failed".parse::<RecoveryPolicy>().unwrap(),
+            RecoveryPolicy::MarkFailed
+        );
+        assert_eq!(
+            "ASK".parse::<RecoveryPolicy>().unwrap(),
+            RecoveryPolicy::Ask
+        );
+        assert!("later".parse::<RecoveryPolicy>().is_err());
+        assert_eq!(RecoveryPolicy::MarkFailed.to_string(), "mark-failed");
+    }
+}
@@ -9,21 +9,40 @@
 //! - [`ScheduledTask`] - A task scheduled for future execution
 //! - [`ExecutionTarget`] - Where to run the task (pane, window, background)
 //! - [`TaskStatus`] - Current status of a task (pending, running, completed, cancelled, failed)
+//! - [`ResourceLimits`] - Optional niceness, memory, and runtime limits for background tasks
+//! - [`Recurrence`] - How often a recurring task runs again
+//! - [`Notifications`] - Terminal, spoken, and webhook alerts when a task finishes
 //!
 //! ## Task Execution
 //!
 //! - [`TaskExecutor`] - Executes scheduled tasks at their designated times
 //! - [`TaskEvent`] - Events emitted during task execution
 //!
+//! Notification channels are behind optional features: `notify-terminal`
+//! (OSC desktop notifications via `biscuit-terminal`), `notify-speech`
+//! (text-to-speech via `biscuit-speaks`) and `notify-webhook` (HTTP `POST`
+//! via `reqwest`).
+//!
 //! ## History Storage
 //!
 //! - [`HistoryStore`] - Trait for history storage backends
 //! - [`JsonFileStore`] - JSONL file-based storage with file locking
+//! - [`OutputLog`] - Per-task log files capturing background task output
+//! - [`HistoryReport`] - Success rates, durations, and failure times from recorded runs
+//! - `HistoryKey` - Key for encrypting commands and error messages at rest
+//!   (requires the `encryption` feature)
+//!
+//! ## Crash Recovery
+//!
+//! - [`ExecutionJournal`] - Write-ahead journal of claimed, started, and finished tasks
+//! - [`RecoveryPolicy`] - Whether interrupted tasks are rerun, marked failed, or asked about
 //!
 //! ## Parsing Utilities
 //!
 //! - [`parse_at_time`] - Parse time strings like "7:00am" or "19:30"
 //! - [`parse_delay`] - Parse delay strings like "15m" or "2h"
+//! - [`parse_memory_size`] - Parse memory sizes like "512M" or "2G"
+//! - [`parse_recurrence`] - Parse recurrences like "daily" or "12h"
 //!
 //! ## Terminal Detection
 //!
@@ -31,16 +50,35 @@
 //! - [`TerminalCapabilities`] - Available features for the detected terminal
 //! - [`TerminalKind`] - Known terminal emulator types
 
+#[cfg(feature = "encryption")]
+mod encryption;
 mod error;
 mod executor;
 mod history;
+mod journal;
+mod limits;
+mod notify;
+mod output;
 mod parse;
+mod report;
+mod shell;
 pub mod terminal;
 mod types;
 
+#[cfg(feature = "encryption")]
+pub use encryption::{HISTORY_KEY_ENV, HistoryKey};
 pub use error::HistoryError;
 pub use executor::{TaskEvent, TaskExecutor};
 pub use history::{HistoryStore, JsonFileStore};
-pub use parse::{parse_at_time, parse_delay};
+pub use journal::{
+    ExecutionJournal, INTERRUPTED_ERROR, InterruptedTask, JournalEntry, JournalEvent,
+    RecoveryPolicy,
+};
+pub use output::OutputLog;
+pub use parse::{parse_at_time, parse_delay, parse_memory_size, parse_recurrence};
+pub use report::{CommandStats, HistoryReport, RunRecord, command_stats, failures_by_hour};
 pub use terminal::{TerminalCapabilities, TerminalDetector, TerminalKind, TuiLayoutResult};
-pub use types::{ExecutionTarget, ScheduleKind, ScheduledTask, TaskStatus};
+pub use types::{
+    ExecutionTarget, LimitKind, Notifications, NotifyOn, Recurrence, ResourceLimits, ScheduleKind,
+    ScheduledTask, TaskStatus,
+};
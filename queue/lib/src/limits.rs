@@ -0,0 +1,171 @@
+//! Per-task resource limit enforcement.
+//!
+//! Niceness and memory limits are applied in the forked child before `exec`
+//! (Unix only), so they cover the task's shell and everything it starts. When
+//! a maximum runtime is set the child also becomes a process group leader, so
+//! a timeout can kill the whole process tree rather than just the shell.
+
+use std::process::ExitStatus;
+
+use tokio::process::{Child, Command};
+
+use crate::{LimitKind, ResourceLimits};
+
+/// Configures `command` to start with the niceness and memory limit from `limits`.
+#[cfg(unix)]
+pub(crate) fn apply(command: &mut Command, limits: &ResourceLimits) {
+    if limits.is_unlimited() {
+        return;
+    }
+    let limits = *limits;
+
+    // SAFETY: the closure runs in the forked child before exec and only calls
+    // async-signal-safe libc functions; it does not allocate.
+    unsafe {
+        command.pre_exec(move || {
+            if limits.max_runtime_secs.is_some() && libc::setpgid(0, 0) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            if let Some(nice) = limits.nice
+                && libc::setpriority(libc::PRIO_PROCESS, 0, nice) != 0
+            {
+                return Err(std::io::Error::last_os_error());
+            }
+            if let Some(bytes) = limits.max_memory_bytes {
+                let mut current = libc::rlimit {
+                    rlim_cur: 0,
+                    rlim_max: 0,
+                };
+                if libc::getrlimit(libc::RLIMIT_AS, &mut current) != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                let wanted = libc::rlim_t::try_from(bytes).unwrap_or(libc::RLIM_INFINITY);
+                let limit = libc::rlimit {
+                    rlim_cur: wanted.min(current.rlim_max),
+                    rlim_max: current.rlim_max,
+                };
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        });
+    }
+}
+
+/// Niceness and memory limits are not supported on this platform.
+#[cfg(not(unix))]
+pub(crate) fn apply(_command: &mut Command, _limits: &ResourceLimits) {}
+
+/// Kills a task that exceeded its maximum runtime, including its children.
+pub(crate) async fn kill(child: &mut Child) {
+    #[cfg(unix)]
+    if let Some(pid) = child.id().and_then(|pid| i32::try_from(pid).ok()) {
+        // The child leads its own process group (see `apply`), so signal the group.
+        // SAFETY: kill(2) has no memory-safety preconditions.
+        unsafe {
+            libc::kill(-pid, libc::SIGKILL);
+        }
+    }
+    let _ = child.kill().await;
+}
+
+/// Classifies an abnormal exit as a possible resource limit violation.
+///
+/// A process that hits `RLIMIT_AS` sees its allocations fail and usually dies
+/// from `SIGSEGV`, `SIGABRT` or `SIGBUS`. Those signals have other causes too,
+/// and the exit status can't tell them apart, so when a memory limit is set
+/// they're reported as a *possible* memory violation. `SIGKILL` is never
+/// attributed: the address-space limit doesn't send it, so it came from
+/// someone else (e.g. the system OOM killer or a user).
+pub(crate) fn violation(
+    status: &ExitStatus,
+    limits: &ResourceLimits,
+) -> Option<(LimitKind, String)> {
+    let bytes = limits.max_memory_bytes?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        let signal = status.signal()?;
+        if [libc::SIGSEGV, libc::SIGABRT, libc::SIGBUS].contains(&signal) {
+            return Some((
+                LimitKind::Memory,
+                format!(
+                    "terminated by signal {signal}, possibly from exceeding the memory limit of {}",
+                    format_bytes(bytes)
+                ),
+            ));
+        }
+        None
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (status, bytes);
+        None
+    }
+}
+
+/// Formats a byte count using binary units (e.g. "512 MiB").
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    if value.fract() == 0.0 {
+        format!("{value:.0} {unit}")
+    } else {
+        format!("{value:.1} {unit}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_bytes_uses_binary_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(512 * 1024 * 1024), "512 MiB");
+        assert_eq!(format_bytes(1536 * 1024 * 1024), "1.5 GiB");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn violation_requires_memory_limit_and_fatal_signal() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let aborted = ExitStatus::from_raw(libc::SIGABRT);
+        let exited = ExitStatus::from_raw(1 << 8);
+        let limits = ResourceLimits::default().with_max_memory(1024 * 1024);
+
+        let (kind, error) = violation(&aborted, &limits).expect("possible violation");
+        assert_eq!(kind, LimitKind::Memory);
+        assert!(error.contains("possibly"), "{error}");
+        assert_eq!(violation(&exited, &limits), None);
+        assert_eq!(violation(&aborted, &ResourceLimits::default()), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn sigkill_is_not_attributed_to_the_memory_limit() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let killed = ExitStatus::from_raw(libc::SIGKILL);
+        let limits = ResourceLimits::default().with_max_memory(1024 * 1024);
+
+        assert_eq!(violation(&killed, &limits), None);
+    }
+}
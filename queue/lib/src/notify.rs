@@ -0,0 +1,242 @@
+//! Delivery of per-task [`Notifications`] when a task finishes.
+//!
+//! Each channel is backed by an optional dependency: terminal notifications
+//! use `biscuit-terminal` (`notify-terminal`), spoken alerts use
+//! `biscuit-speaks` (`notify-speech`) and webhooks use `reqwest`
+//! (`notify-webhook`). Channels whose feature is disabled are skipped, and
+//! delivery is best effort: failures are ignored so they can never change a
+//! task's recorded status.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{Notifications, OutputLog, ScheduledTask, TaskStatus};
+
+/// Number of captured output lines included in a webhook payload.
+const WEBHOOK_OUTPUT_LINES: usize = 20;
+
+/// Longest command text included in a notification title or spoken alert.
+const MAX_COMMAND_CHARS: usize = 60;
+
+/// The JSON body posted to a task's webhook.
+#[derive(Debug, Serialize)]
+pub(crate) struct WebhookPayload<'a> {
+    /// The task that finished.
+    pub task_id: u64,
+    /// The command that ran.
+    pub command: &'a str,
+    /// How the run ended.
+    pub status: &'a TaskStatus,
+    /// When the task was scheduled to run.
+    pub scheduled_at: DateTime<Utc>,
+    /// When the run started.
+    pub started_at: DateTime<Utc>,
+    /// When the run finished.
+    pub finished_at: DateTime<Utc>,
+    /// The last lines of captured output, for background tasks with an output log.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub output: Vec<String>,
+}
+
+/// A finished task run to notify about.
+pub(crate) struct FinishedRun<'a> {
+    pub task: &'a ScheduledTask,
+    pub status: &'a TaskStatus,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+}
+
+impl FinishedRun<'_> {
+    /// Returns the command shortened for display, on a single line.
+    fn short_command(&self) -> String {
+        let command = self
+            .task
+            .command
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if command.chars().count() <= MAX_COMMAND_CHARS {
+            return command;
+        }
+        let truncated: String = command.chars().take(MAX_COMMAND_CHARS - 1).collect();
+        format!("{truncated}…")
+    }
+
+    /// Returns the notification title, e.g. `queue: task 3 failed`.
+    pub fn title(&self) -> String {
+        let outcome = match self.status {
+            TaskStatus::Completed => "completed".to_string(),
+            TaskStatus::LimitExceeded { limit, .. } => format!("exceeded its {limit}"),
+            _ => "failed".to_string(),
+        };
+        format!("queue: task {} {outcome}", self.task.id)
+    }
+
+    /// Returns the notification body: the command, plus the error on failure.
+    pub fn body(&self) -> String {
+        match self.status {
+            TaskStatus::Failed { error } | TaskStatus::LimitExceeded { error, .. } => {
+                format!("{}\n{error}", self.short_command())
+            }
+            _ => self.short_command(),
+        }
+    }
+
+    /// Returns the sentence spoken for the run.
+    pub fn spoken(&self) -> String {
+        let outcome = match self.status {
+            TaskStatus::Completed => "finished",
+            TaskStatus::LimitExceeded { .. } => "was stopped at its limit",
+            _ => "failed",
+        };
+        format!("Queued task {outcome}: {}", self.short_command())
+    }
+
+    /// Builds the webhook body, including recent output when it was captured.
+    pub fn webhook_payload(&self, output_log: Option<&OutputLog>) -> WebhookPayload<'_> {
+        WebhookPayload {
+            task_id: self.task.id,
+            command: &self.task.command,
+            status: self.status,
+            scheduled_at: self.task.scheduled_at,
+            started_at: self.started_at,
+            finished_at: self.finished_at,
+            output: output_log
+                .and_then(|log| log.tail(self.task.id, WEBHOOK_OUTPUT_LINES).ok())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Sends every notification enabled for the run's task.
+///
+/// Does nothing unless [`Notifications::should_notify`] accepts the status.
+pub(crate) async fn deliver(run: &FinishedRun<'_>, output_log: Option<&OutputLog>) {
+    let notify: &Notifications = &run.task.notify;
+    if !notify.should_notify(run.status) {
+        return;
+    }
+
+    if notify.terminal {
+        terminal(&run.title(), &run.body());
+    }
+    if let Some(ref url) = notify.webhook {
+        webhook(url, &run.webhook_payload(output_log)).await;
+    }
+    if notify.speak {
+        speak(&run.spoken()).await;
+    }
+}
+
+#[cfg(feature = "notify-terminal")]
+fn terminal(title: &str, body: &str) {
+    let _ = biscuit_terminal::discovery::notifications::send_notification(title, body);
+}
+
+#[cfg(not(feature = "notify-terminal"))]
+fn terminal(_title: &str, _body: &str) {}
+
+#[cfg(feature = "notify-speech")]
+async fn speak(text: &str) {
+    biscuit_speaks::speak_when_able(text, &biscuit_speaks::TtsConfig::default()).await;
+}
+
+#[cfg(not(feature = "notify-speech"))]
+async fn speak(_text: &str) {}
+
+#[cfg(feature = "notify-webhook")]
+async fn webhook(url: &str, payload: &WebhookPayload<'_>) {
+    let _ = reqwest::Client::new()
+        .post(url)
+        .timeout(std::time::Duration::from_secs(10))
+        .json(payload)
+        .send()
+        .await;
+}
+
+#[cfg(not(feature = "notify-webhook"))]
+async fn webhook(_url: &str, _payload: &WebhookPayload<'_>) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ExecutionTarget, LimitKind};
+
+    fn run<'a>(task: &'a ScheduledTask, status: &'a TaskStatus) -> FinishedRun<'a> {
+        FinishedRun {
+            task,
+            status,
+            started_at: task.scheduled_at,
+            finished_at: task.scheduled_at + chrono::Duration::seconds(5),
+        }
+    }
+
+    fn task(command: &str) -> ScheduledTask {
+        ScheduledTask::new(
+            7,
+            command.to_string(),
+            Utc::now(),
+            ExecutionTarget::Background,
+        )
+    }
+
+    #[test]
+    fn messages_describe_the_outcome() {
+        let task = task("cargo   build\n--release");
+        let completed = TaskStatus::Completed;
+        let failed = TaskStatus::Failed {
+            error: "exit status 101".to_string(),
+        };
+        let limited = TaskStatus::LimitExceeded {
+            limit: LimitKind::MaxRuntime,
+            error: "killed".to_string(),
+        };
+
+        assert_eq!(run(&task, &completed).title(), "queue: task 7 completed");
+        assert_eq!(run(&task, &completed).body(), "cargo build --release");
+        assert_eq!(run(&task, &failed).title(), "queue: task 7 failed");
+        assert_eq!(
+            run(&task, &failed).body(),
+            "cargo build --release\nexit status 101"
+        );
+        assert_eq!(
+            run(&task, &limited).title(),
+            "queue: task 7 exceeded its max runtime"
+        );
+        assert_eq!(
+            run(&task, &failed).spoken(),
+            "Queued task failed: cargo build --release"
+        );
+    }
+
+    #[test]
+    fn long_commands_are_truncated() {
+        let task = task(&"x".repeat(100));
+        let status = TaskStatus::Completed;
+        let body = run(&task, &status).body();
+        assert_eq!(body.chars().count(), MAX_COMMAND_CHARS);
+        assert!(body.ends_with('…'));
+    }
+
+    #[test]
+    fn webhook_payload_includes_status_and_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = OutputLog::new(dir.path());
+        std::fs::write(log.path_for(7), "one\ntwo\n").unwrap();
+
+        let task = task("make");
+        let status = TaskStatus::Failed {
+            error: "boom".to_string(),
+        };
+        let json = serde_json::to_value(run(&task, &status).webhook_payload(Some(&log))).unwrap();
+
+        assert_eq!(json["task_id"], 7);
+        assert_eq!(json["command"], "make");
+        assert_eq!(json["status"]["status"], "failed");
+        assert_eq!(json["status"]["error"], "boom");
+        assert_eq!(json["output"], serde_json::json!(["one", "two"]));
+
+        let json = serde_json::to_value(run(&task, &status).webhook_payload(None)).unwrap();
+        assert!(json.get("output").is_none());
+    }
+}
@@ -0,0 +1,254 @@
+//! Captured output for background tasks.
+//!
+//! Background tasks have no terminal of their own, so when the executor is
+//! configured with an [`OutputLog`] their stdout and stderr are written to a
+//! per-task log file. Other processes (e.g. `queue status`) read the tail of
+//! these files to show what a running task is doing.
+//!
+//! Output can echo secrets just like commands can. With the `encryption`
+//! feature and a [`HistoryKey`](crate::HistoryKey), each captured line is
+//! sealed before it's written and opened again by [`OutputLog::tail`].
+
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "encryption")]
+use crate::encryption::HistoryKey;
+use crate::error::HistoryError;
+use crate::history::Sealer;
+
+/// Default output directory name (in the home directory on Unix).
+#[cfg(not(windows))]
+const DEFAULT_OUTPUT_DIR: &str = ".queue-output";
+
+/// Default output directory name (in `%LOCALAPPDATA%\queue` on Windows).
+#[cfg(windows)]
+const DEFAULT_OUTPUT_DIR: &str = "output";
+
+/// A directory of per-task output logs.
+///
+/// Each task's output is stored in `<dir>/<task id>.log`.
+///
+/// ## Examples
+///
+/// ```
+/// use queue_lib::OutputLog;
+///
+/// let log = OutputLog::new("/tmp/queue-output");
+/// assert!(log.path_for(7).ends_with("7.log"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct OutputLog {
+    dir: PathBuf,
+    sealer: Sealer,
+}
+
+impl OutputLog {
+    /// Creates an output log rooted at the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            sealer: Sealer::default(),
+        }
+    }
+
+    /// Encrypts each captured line with `key`, and decrypts lines when
+    /// reading the tail.
+    #[cfg(feature = "encryption")]
+    pub fn with_key(mut self, key: HistoryKey) -> Self {
+        self.sealer = Sealer::new(key);
+        self
+    }
+
+    /// Encrypts with the key in [`HISTORY_KEY_ENV`](crate::HISTORY_KEY_ENV)
+    /// when it's set, and leaves output unencrypted otherwise.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`HistoryError::Key`] if the variable holds an invalid key.
+    #[cfg(feature = "encryption")]
+    pub fn with_env_key(mut self) -> Result<Self, HistoryError> {
+        self.sealer = Sealer::from_env()?;
+        Ok(self)
+    }
+
+    /// Returns true if captured lines are encrypted, in which case output
+    /// must be written through [`seal_line`](Self::seal_line).
+    pub(crate) fn is_sealed(&self) -> bool {
+        self.sealer.is_enabled()
+    }
+
+    /// Encrypts one line of output for writing to a log file.
+    pub(crate) fn seal_line(&self, line: &str) -> Result<String, HistoryError> {
+        self.sealer.seal("output", line)
+    }
+
+    /// Creates an output log at the default location.
+    ///
+    /// This is `~/.queue-output` on Unix and
+    /// `%LOCALAPPDATA%\queue\output` on Windows.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the home (or local app data) directory cannot be determined.
+    pub fn default_path() -> Self {
+        Self::new(Self::default_output_dir())
+    }
+
+    #[cfg(not(windows))]
+    fn default_output_dir() -> PathBuf {
+        let home = dirs::home_dir().expect("could not determine home directory");
+        home.join(DEFAULT_OUTPUT_DIR)
+    }
+
+    #[cfg(windows)]
+    fn default_output_dir() -> PathBuf {
+        let data_dir =
+            dirs::data_local_dir().expect("could not determine local app data directory");
+        data_dir.join("queue").join(DEFAULT_OUTPUT_DIR)
+    }
+
+    /// Returns the directory holding the log files.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the log file path for a task.
+    pub fn path_for(&self, task_id: u64) -> PathBuf {
+        self.dir.join(format!("{task_id}.log"))
+    }
+
+    /// Creates (or truncates) the log file for a task.
+    ///
+    /// The parent directory is created if it does not exist.
+    pub fn create(&self, task_id: u64) -> io::Result<File> {
+        fs::create_dir_all(&self.dir)?;
+        File::create(self.path_for(task_id))
+    }
+
+    /// Returns the last `lines` lines of a task's output.
+    ///
+    /// A task with no log file yet has no output, so this returns an empty
+    /// list rather than an error. Invalid UTF-8 is replaced rather than
+    /// rejected since task output is arbitrary.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the log can't be read, or if it holds encrypted
+    /// lines that can't be decrypted with this log's key.
+    pub fn tail(&self, task_id: u64, lines: usize) -> io::Result<Vec<String>> {
+        if lines == 0 {
+            return Ok(Vec::new());
+        }
+
+        let file = match File::open(self.path_for(task_id)) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut tail = VecDeque::with_capacity(lines);
+
+        for line in BufReader::new(file).split(b'\n') {
+            let line = line?;
+            let line = String::from_utf8_lossy(&line)
+                .trim_end_matches('\r')
+                .to_string();
+            if tail.len() == lines {
+                tail.pop_front();
+            }
+            tail.push_back(line);
+        }
+
+        // Only the kept lines need decrypting
+        tail.into_iter()
+            .map(|line| {
+                self.sealer
+                    .open("output", &line)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_test_log() -> (OutputLog, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let log = OutputLog::new(temp_dir.path().join("output"));
+        (log, temp_dir)
+    }
+
+    #[test]
+    fn path_for_uses_task_id() {
+        let log = OutputLog::new("/tmp/queue-output");
+        assert_eq!(
+            log.path_for(42),
+            Path::new("/tmp/queue-output").join("42.log")
+        );
+    }
+
+    #[test]
+    fn tail_of_missing_log_is_empty() {
+        let (log, _temp_dir) = create_test_log();
+        assert!(log.tail(1, 5).unwrap().is_empty());
+    }
+
+    #[test]
+    fn create_makes_parent_directory() {
+        let (log, _temp_dir) = create_test_log();
+        assert!(!log.dir().exists());
+
+        log.create(1).unwrap();
+
+        assert!(log.path_for(1).exists());
+    }
+
+    #[test]
+    fn tail_returns_last_lines() {
+        let (log, _temp_dir) = create_test_log();
+        log.create(1).unwrap();
+        fs::write(log.path_for(1), "one\ntwo\r\nthree\nfour\n").unwrap();
+
+        assert_eq!(log.tail(1, 2).unwrap(), vec!["three", "four"]);
+        assert_eq!(
+            log.tail(1, 10).unwrap(),
+            vec!["one", "two", "three", "four"]
+        );
+        assert!(log.tail(1, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn tail_tolerates_invalid_utf8() {
+        let (log, _temp_dir) = create_test_log();
+        log.create(1).unwrap();
+        fs::write(log.path_for(1), b"ok\n\xff\xfe\n").unwrap();
+
+        let tail = log.tail(1, 2).unwrap();
+        assert_eq!(tail[0], "ok");
+        assert!(tail[1].contains('\u{FFFD}'));
+    }
+
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn sealed_lines_need_the_key() {
+        let (log, _temp_dir) = create_test_log();
+        let log = log.with_key(crate::HistoryKey::generate().unwrap());
+        log.create(1).unwrap();
+        let sealed = format!(
+            "{}\n{}\n",
+            log.seal_line("one").unwrap(),
+            log.seal_line("hunter2").unwrap()
+        );
+        fs::write(log.path_for(1), sealed).unwrap();
+
+        assert_eq!(log.tail(1, 1).unwrap(), vec!["hunter2"]);
+        let without_key = OutputLog::new(log.dir());
+        assert!(without_key.tail(1, 1).is_err());
+    }
+}
@@ -2,6 +2,8 @@
 
 use chrono::{Duration as ChronoDuration, NaiveTime};
 
+use crate::types::Recurrence;
+
 /// Parses a time string into a `NaiveTime`.
 ///
 /// ## Supported Formats
@@ -106,6 +108,105 @@ pub fn parse_delay(value: &str) -> Result<ChronoDuration, String> {
     Ok(duration)
 }
 
+/// Parses how often a recurring task runs.
+///
+/// Accepts `hourly`, `daily` or `weekly`, or an interval in the
+/// [`parse_delay`] format such as `12h` or `3d`.
+///
+/// ## Errors
+///
+/// Returns an error string if the value is neither a named recurrence nor a
+/// valid interval.
+///
+/// ## Examples
+///
+/// ```
+/// use queue_lib::{Recurrence, parse_recurrence};
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_recurrence("weekly").unwrap(), Recurrence::weekly());
+/// assert_eq!(
+///     parse_recurrence("12h").unwrap(),
+///     Recurrence::every(Duration::from_secs(12 * 60 * 60))
+/// );
+/// ```
+pub fn parse_recurrence(value: &str) -> Result<Recurrence, String> {
+    match value.trim().to_lowercase().as_str() {
+        "hourly" => Ok(Recurrence::hourly()),
+        "daily" => Ok(Recurrence::daily()),
+        "weekly" => Ok(Recurrence::weekly()),
+        _ => {
+            let interval = parse_delay(value).map_err(|_| {
+                "recurrence must be hourly, daily, weekly, or an interval like 12h".to_string()
+            })?;
+            let secs = u64::try_from(interval.num_seconds()).unwrap_or(u64::MAX);
+            Ok(Recurrence::every(std::time::Duration::from_secs(secs)))
+        }
+    }
+}
+
+/// Parses a memory size like "512M", "2G", or "1048576".
+///
+/// Units are binary (K = 1024 bytes) and case-insensitive; an optional
+/// trailing "B" or "iB" is accepted ("512MB", "512MiB"). A bare number is a
+/// byte count.
+///
+/// ## Errors
+///
+/// Returns an error string if the size cannot be parsed or is zero.
+///
+/// ## Examples
+///
+/// ```
+/// use queue_lib::parse_memory_size;
+///
+/// assert_eq!(parse_memory_size("512M").unwrap(), 512 * 1024 * 1024);
+/// assert_eq!(parse_memory_size("2GiB").unwrap(), 2 * 1024 * 1024 * 1024);
+/// ```
+pub fn parse_memory_size(value: &str) -> Result<u64, String> {
+    let normalized = value.trim().to_lowercase().replace(' ', "");
+
+    if normalized.is_empty() {
+        return Err("memory size cannot be empty".to_string());
+    }
+
+    let split_index = normalized
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(normalized.len());
+    let (amount, unit) = normalized.split_at(split_index);
+
+    if amount.is_empty() {
+        return Err("memory size must start with a number".to_string());
+    }
+
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| "memory size must be a number".to_string())?;
+
+    if amount == 0 {
+        return Err("memory size must be greater than zero".to_string());
+    }
+
+    let unit = unit
+        .strip_suffix("ib")
+        .or_else(|| unit.strip_suffix('b'))
+        .unwrap_or(unit);
+    let multiplier: u64 = match unit {
+        "" => 1,
+        "k" => 1024,
+        "m" => 1024 * 1024,
+        "g" => 1024 * 1024 * 1024,
+        "t" => 1024 * 1024 * 1024 * 1024,
+        _ => {
+            return Err("memory size units must be K, M, G, or T".to_string());
+        }
+    };
+
+    amount
+        .checked_mul(multiplier)
+        .ok_or_else(|| "memory size is too large".to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,4 +341,31 @@ mod tests {
         assert!(parse_delay("m").is_err());
         assert!(parse_delay("h").is_err());
     }
+
+    #[test]
+    fn parse_recurrence_accepts_names_and_intervals() {
+        assert_eq!(parse_recurrence("Daily"), Ok(Recurrence::daily()));
+        assert_eq!(parse_recurrence(" hourly "), Ok(Recurrence::hourly()));
+        assert_eq!(parse_recurrence("2d").unwrap().every_secs, 2 * 24 * 60 * 60);
+        assert_eq!(parse_recurrence("90s").unwrap().every_secs, 90);
+        assert!(parse_recurrence("fortnightly").is_err());
+        assert!(parse_recurrence("0h").is_err());
+    }
+
+    #[test]
+    fn parse_memory_size_supports_binary_units() {
+        assert_eq!(parse_memory_size("4096"), Ok(4096));
+        assert_eq!(parse_memory_size("512k"), Ok(512 * 1024));
+        assert_eq!(parse_memory_size("512MB"), Ok(512 * 1024 * 1024));
+        assert_eq!(parse_memory_size("1 GiB"), Ok(1024 * 1024 * 1024));
+    }
+
+    #[test]
+    fn parse_memory_size_rejects_invalid_input() {
+        assert!(parse_memory_size("").is_err());
+        assert!(parse_memory_size("0M").is_err());
+        assert!(parse_memory_size("M").is_err());
+        assert!(parse_memory_size("12X").is_err());
+        assert!(parse_memory_size("99999999999T").is_err());
+    }
 }
@@ -0,0 +1,295 @@
+//! Run analytics over the task history.
+//!
+//! The task history keeps only the latest entry per command, so every finished
+//! run is also recorded as a [`RunRecord`] in an append-only run log. The
+//! aggregation queries here (success rate and average duration per command,
+//! failures by hour of day) work on those records.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, TimeZone, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ScheduledTask, TaskStatus};
+
+/// A single finished run of a task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// The task that ran.
+    pub task_id: u64,
+    /// The command that ran.
+    pub command: String,
+    /// How the run ended.
+    pub status: TaskStatus,
+    /// When the run started, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the run finished.
+    pub finished_at: DateTime<Utc>,
+}
+
+impl RunRecord {
+    /// Creates a record for `task`, which finished at `finished_at`.
+    ///
+    /// ## Returns
+    ///
+    /// `None` if the task has not finished (pending or running), or if it was
+    /// cancelled before it started.
+    pub fn from_task(task: &ScheduledTask, finished_at: DateTime<Utc>) -> Option<Self> {
+        let finished = match task.status {
+            TaskStatus::Pending | TaskStatus::Running => false,
+            TaskStatus::Cancelled => task.started_at.is_some(),
+            _ => true,
+        };
+        finished.then(|| Self {
+            task_id: task.id,
+            command: task.command.clone(),
+            status: task.status.clone(),
+            started_at: task.started_at,
+            finished_at,
+        })
+    }
+
+    /// Returns true if the run completed successfully.
+    pub fn succeeded(&self) -> bool {
+        self.status == TaskStatus::Completed
+    }
+
+    /// Returns true if the run failed or exceeded a resource limit.
+    pub fn failed(&self) -> bool {
+        matches!(
+            self.status,
+            TaskStatus::Failed { .. } | TaskStatus::LimitExceeded { .. }
+        )
+    }
+
+    /// Returns how long the run took, if its start time is known.
+    pub fn duration(&self) -> Option<chrono::Duration> {
+        self.started_at
+            .map(|started| (self.finished_at - started).max(chrono::Duration::zero()))
+    }
+}
+
+/// Aggregated run statistics for one command.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommandStats {
+    /// The command.
+    pub command: String,
+    /// Number of finished runs.
+    pub runs: usize,
+    /// Runs that completed successfully.
+    pub succeeded: usize,
+    /// Runs that failed or exceeded a resource limit.
+    pub failed: usize,
+    /// Runs cancelled while running.
+    pub cancelled: usize,
+    /// Fraction of runs that succeeded, from 0.0 to 1.0.
+    pub success_rate: f64,
+    /// Average run duration in seconds, over runs with a known start time.
+    pub average_duration_secs: Option<f64>,
+    /// When the command last finished.
+    pub last_run: DateTime<Utc>,
+}
+
+/// A summary of runs over a time window.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistoryReport {
+    /// Start of the window; runs that finished earlier are excluded.
+    pub since: DateTime<Utc>,
+    /// Total number of runs in the window.
+    pub total_runs: usize,
+    /// Total number of failed runs in the window.
+    pub total_failures: usize,
+    /// Per-command statistics, most frequently run first.
+    pub commands: Vec<CommandStats>,
+    /// Failed runs bucketed by the hour of day (0-23) they finished in.
+    pub failures_by_hour: [usize; 24],
+}
+
+/// Computes per-command statistics for runs that finished at or after `since`.
+///
+/// Commands are ordered by run count (descending), then by command.
+pub fn command_stats(runs: &[RunRecord], since: DateTime<Utc>) -> Vec<CommandStats> {
+    let mut grouped: BTreeMap<&str, Vec<&RunRecord>> = BTreeMap::new();
+    for run in runs.iter().filter(|run| run.finished_at >= since) {
+        grouped.entry(run.command.as_str()).or_default().push(run);
+    }
+
+    let mut stats: Vec<CommandStats> = grouped
+        .into_iter()
+        .map(|(command, runs)| {
+            let succeeded = runs.iter().filter(|run| run.succeeded()).count();
+            let failed = runs.iter().filter(|run| run.failed()).count();
+            let durations: Vec<f64> = runs
+                .iter()
+                .filter_map(|run| run.duration())
+                .map(|duration| duration.num_milliseconds() as f64 / 1000.0)
+                .collect();
+
+            CommandStats {
+                command: command.to_string(),
+                runs: runs.len(),
+                succeeded,
+                failed,
+                cancelled: runs.len() - succeeded - failed,
+                success_rate: succeeded as f64 / runs.len() as f64,
+                average_duration_secs: (!durations.is_empty())
+                    .then(|| durations.iter().sum::<f64>() / durations.len() as f64),
+                last_run: runs
+                    .iter()
+                    .map(|run| run.finished_at)
+                    .max()
+                    .unwrap_or(since),
+            }
+        })
+        .collect();
+
+    stats.sort_by(|a, b| b.runs.cmp(&a.runs).then_with(|| a.command.cmp(&b.command)));
+    stats
+}
+
+/// Counts failed runs that finished at or after `since` by hour of day in `tz`.
+pub fn failures_by_hour<Tz: TimeZone>(
+    runs: &[RunRecord],
+    since: DateTime<Utc>,
+    tz: &Tz,
+) -> [usize; 24] {
+    let mut hours = [0; 24];
+    for run in runs
+        .iter()
+        .filter(|run| run.finished_at >= since && run.failed())
+    {
+        hours[run.finished_at.with_timezone(tz).hour() as usize] += 1;
+    }
+    hours
+}
+
+impl HistoryReport {
+    /// Builds a report from `runs`, bucketing failures by hour in `tz`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use chrono::{Duration, Utc};
+    /// use queue_lib::{HistoryReport, RunRecord, TaskStatus};
+    ///
+    /// let now = Utc::now();
+    /// let run = RunRecord {
+    ///     task_id: 1,
+    ///     command: "make".to_string(),
+    ///     status: TaskStatus::Completed,
+    ///     started_at: Some(now - Duration::seconds(30)),
+    ///     finished_at: now,
+    /// };
+    ///
+    /// let report = HistoryReport::from_runs(&[run], now - Duration::days(7), &Utc);
+    /// assert_eq!(report.total_runs, 1);
+    /// assert_eq!(report.commands[0].success_rate, 1.0);
+    /// ```
+    pub fn from_runs<Tz: TimeZone>(runs: &[RunRecord], since: DateTime<Utc>, tz: &Tz) -> Self {
+        let commands = command_stats(runs, since);
+        Self {
+            since,
+            total_runs: commands.iter().map(|stats| stats.runs).sum(),
+            total_failures: commands.iter().map(|stats| stats.failed).sum(),
+            commands,
+            failures_by_hour: failures_by_hour(runs, since, tz),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ExecutionTarget, LimitKind};
+    use chrono::Duration;
+
+    fn run(command: &str, status: TaskStatus, finished_at: DateTime<Utc>, secs: i64) -> RunRecord {
+        RunRecord {
+            task_id: 1,
+            command: command.to_string(),
+            status,
+            started_at: Some(finished_at - Duration::seconds(secs)),
+            finished_at,
+        }
+    }
+
+    fn at_hour(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 10, hour, 15, 0).unwrap()
+    }
+
+    #[test]
+    fn from_task_skips_unfinished_and_unstarted_tasks() {
+        let now = Utc::now();
+        let mut task = ScheduledTask::new(1, "make".to_string(), now, ExecutionTarget::Background);
+        assert!(RunRecord::from_task(&task, now).is_none());
+
+        task.mark_cancelled();
+        assert!(RunRecord::from_task(&task, now).is_none());
+
+        task.mark_running();
+        task.mark_failed("exit code 2");
+        let record = RunRecord::from_task(&task, now).unwrap();
+        assert!(record.failed());
+        assert_eq!(record.started_at, task.started_at);
+    }
+
+    #[test]
+    fn command_stats_aggregate_success_rate_and_duration() {
+        let failed = TaskStatus::Failed {
+            error: "boom".to_string(),
+        };
+        let runs = vec![
+            run("backup", TaskStatus::Completed, at_hour(2), 10),
+            run("backup", TaskStatus::Completed, at_hour(3), 20),
+            run("backup", failed.clone(), at_hour(4), 30),
+            run("backup", TaskStatus::Cancelled, at_hour(5), 40),
+            run("sync", failed, at_hour(6), 5),
+        ];
+
+        let stats = command_stats(&runs, at_hour(0));
+        assert_eq!(stats.len(), 2);
+
+        let backup = &stats[0];
+        assert_eq!(backup.command, "backup");
+        assert_eq!(
+            (
+                backup.runs,
+                backup.succeeded,
+                backup.failed,
+                backup.cancelled
+            ),
+            (4, 2, 1, 1)
+        );
+        assert_eq!(backup.success_rate, 0.5);
+        assert_eq!(backup.average_duration_secs, Some(25.0));
+        assert_eq!(backup.last_run, at_hour(5));
+
+        assert_eq!(stats[1].command, "sync");
+        assert_eq!(stats[1].success_rate, 0.0);
+    }
+
+    #[test]
+    fn report_excludes_runs_before_since_and_buckets_failures() {
+        let limit = TaskStatus::LimitExceeded {
+            limit: LimitKind::MaxRuntime,
+            error: "timed out".to_string(),
+        };
+        let runs = vec![
+            run("old", limit.clone(), at_hour(1), 1),
+            run("nightly", limit.clone(), at_hour(3), 1),
+            run("nightly", limit, at_hour(3), 1),
+            run("nightly", TaskStatus::Completed, at_hour(4), 1),
+        ];
+
+        let report = HistoryReport::from_runs(&runs, at_hour(2), &Utc);
+        assert_eq!(report.total_runs, 3);
+        assert_eq!(report.total_failures, 2);
+        assert_eq!(report.failures_by_hour[3], 2);
+        assert_eq!(report.failures_by_hour[1], 0);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["commands"][0]["command"], "nightly");
+        assert_eq!(json["failures_by_hour"].as_array().unwrap().len(), 24);
+    }
+}
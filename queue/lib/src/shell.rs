@@ -0,0 +1,23 @@
+//! Platform-specific shell invocation.
+//!
+//! Unix systems run commands through `/bin/sh -c`, while Windows uses
+//! `cmd /C`. The `KEEP_OPEN_FLAG` variant (`cmd /K`) is used on Windows for
+//! interactive targets so the console stays open after the command finishes.
+
+/// The shell used to interpret task commands: the POSIX shell.
+#[cfg(not(windows))]
+pub(crate) const SHELL: &str = "/bin/sh";
+/// The shell used to interpret task commands: the Windows command processor.
+#[cfg(windows)]
+pub(crate) const SHELL: &str = "cmd";
+
+/// The flag that makes `sh` run a single command string and exit.
+#[cfg(not(windows))]
+pub(crate) const RUN_FLAG: &str = "-c";
+/// The flag that makes `cmd` run a single command and exit.
+#[cfg(windows)]
+pub(crate) const RUN_FLAG: &str = "/C";
+
+/// The flag that makes `cmd` run a command and then stay interactive.
+#[cfg(windows)]
+pub(crate) const KEEP_OPEN_FLAG: &str = "/K";
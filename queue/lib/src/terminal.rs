@@ -20,6 +20,8 @@
 use std::env;
 use std::process::{Command, Stdio};
 
+use crate::shell::{RUN_FLAG, SHELL};
+
 /// Detected terminal emulator type.
 ///
 /// Identifies the terminal emulator running the application, enabling
@@ -40,6 +42,8 @@ pub enum TerminalKind {
     Konsole,
     /// Xfce4 Terminal
     Xfce4Terminal,
+    /// Windows Terminal - tabbed terminal with pane support
+    WindowsTerminal,
     /// XTerm or xterm-compatible
     Xterm,
     /// Unknown or undetected terminal
@@ -58,6 +62,7 @@ impl TerminalKind {
             Self::GnomeTerminal => "GNOME Terminal",
             Self::Konsole => "Konsole",
             Self::Xfce4Terminal => "Xfce4 Terminal",
+            Self::WindowsTerminal => "Windows Terminal",
             Self::Xterm => "XTerm",
             Self::Unknown => "Unknown",
         }
@@ -90,6 +95,7 @@ impl TerminalCapabilities {
             TerminalKind::GnomeTerminal => (false, true),
             TerminalKind::Konsole => (false, true),
             TerminalKind::Xfce4Terminal => (false, true),
+            TerminalKind::WindowsTerminal => (true, true),
             TerminalKind::Xterm => (false, true),
             TerminalKind::Unknown => (false, false),
         };
@@ -143,8 +149,9 @@ impl TerminalDetector {
     /// 4. Konsole (`KONSOLE_VERSION`)
     /// 5. Xfce4 Terminal (`COLORTERM` == "xfce4-terminal")
     /// 6. Terminal.app (`TERM_PROGRAM` == "Apple_Terminal")
-    /// 7. XTerm (`TERM` starts with "xterm")
-    /// 8. Unknown (fallback)
+    /// 7. Windows Terminal (`WT_SESSION`)
+    /// 8. XTerm (`TERM` starts with "xterm")
+    /// 9. Unknown (fallback)
     #[must_use]
     pub fn detect() -> TerminalCapabilities {
         let kind = Self::detect_kind();
@@ -191,6 +198,11 @@ impl TerminalDetector {
             return TerminalKind::TerminalApp;
         }
 
+        // Windows Terminal sets WT_SESSION
+        if Self::is_windows_terminal() {
+            return TerminalKind::WindowsTerminal;
+        }
+
         // XTerm or xterm-compatible terminals
         if Self::is_xterm() {
             return TerminalKind::Xterm;
@@ -261,6 +273,14 @@ impl TerminalDetector {
             .unwrap_or(false)
     }
 
+    /// Checks if running in Windows Terminal.
+    ///
+    /// Windows Terminal sets the `WT_SESSION` environment variable.
+    #[must_use]
+    pub fn is_windows_terminal() -> bool {
+        env::var("WT_SESSION").is_ok()
+    }
+
     /// Checks if running in XTerm or xterm-compatible terminal.
     ///
     /// Checks if `TERM` starts with "xterm" when no other terminal is detected.
@@ -399,7 +419,7 @@ impl TerminalDetector {
             args.extend(["--pane-id", &pane_id_str]);
         }
 
-        args.extend(["--", "/bin/sh", "-c", command]);
+        args.extend(["--", SHELL, RUN_FLAG, command]);
 
         let output = Command::new("wezterm")
             .args(&args)
@@ -493,6 +513,7 @@ mod tests {
             "KONSOLE_VERSION",
             "COLORTERM",
             "TERM_PROGRAM",
+            "WT_SESSION",
             "TERM",
         ];
         for var in vars {
@@ -556,6 +577,17 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_windows_terminal_detection() {
+        with_env("WT_SESSION", "0b9d1c3e-5f2a-4e6b-9c7d-1a2b3c4d5e6f", || {
+            assert!(TerminalDetector::is_windows_terminal());
+            let caps = TerminalDetector::detect();
+            assert_eq!(caps.kind, TerminalKind::WindowsTerminal);
+            assert!(caps.supports_panes);
+            assert!(caps.supports_new_window);
+        });
+    }
+
     #[test]
     fn test_xfce4_terminal_detection() {
         with_env("COLORTERM", "xfce4-terminal", || {
@@ -657,6 +689,7 @@ mod tests {
         assert_eq!(TerminalKind::GnomeTerminal.display_name(), "GNOME Terminal");
         assert_eq!(TerminalKind::Konsole.display_name(), "Konsole");
         assert_eq!(TerminalKind::Xfce4Terminal.display_name(), "Xfce4 Terminal");
+        assert_eq!(TerminalKind::WindowsTerminal.display_name(), "Windows Terminal");
         assert_eq!(TerminalKind::Xterm.display_name(), "XTerm");
         assert_eq!(TerminalKind::Unknown.display_name(), "Unknown");
     }
@@ -49,6 +49,320 @@ pub enum TaskStatus {
         /// The error message describing why the task failed.
         error: String,
     },
+    /// Task failed because it exceeded one of its [`ResourceLimits`].
+    LimitExceeded {
+        /// Which limit was exceeded.
+        limit: LimitKind,
+        /// Details about the violation.
+        error: String,
+    },
+}
+
+/// A resource limit that a task can exceed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitKind {
+    /// The process exceeded its memory limit.
+    Memory,
+    /// The process ran longer than its maximum runtime and was killed.
+    MaxRuntime,
+}
+
+impl std::fmt::Display for LimitKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LimitKind::Memory => write!(f, "memory limit"),
+            LimitKind::MaxRuntime => write!(f, "max runtime"),
+        }
+    }
+}
+
+/// Optional per-task resource limits.
+///
+/// Limits are enforced for [`ExecutionTarget::Background`] tasks, which run
+/// unattended as children of the executor. Interactive targets (panes and
+/// windows) run inside another terminal process and are not constrained.
+///
+/// - `nice`: CPU scheduling priority (-20 to 19) set on Unix via `setpriority`
+/// - `max_memory_bytes`: address-space limit set on Unix via `setrlimit(RLIMIT_AS)`
+/// - `max_runtime_secs`: wall-clock limit; the process group is killed when reached
+///
+/// ## Examples
+///
+/// ```
+/// use queue_lib::ResourceLimits;
+/// use std::time::Duration;
+///
+/// let limits = ResourceLimits::default()
+///     .with_nice(10)
+///     .with_max_memory(512 * 1024 * 1024)
+///     .with_max_runtime(Duration::from_secs(3600));
+/// assert!(!limits.is_unlimited());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    /// CPU niceness for the task process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+    /// Maximum address space for the task process, in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_memory_bytes: Option<u64>,
+    /// Maximum wall-clock runtime, in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_runtime_secs: Option<u64>,
+}
+
+impl ResourceLimits {
+    /// Sets the CPU niceness, clamped to the valid range of -20 to 19.
+    #[must_use]
+    pub fn with_nice(mut self, nice: i32) -> Self {
+        self.nice = Some(nice.clamp(-20, 19));
+        self
+    }
+
+    /// Sets the maximum memory (address space) in bytes.
+    #[must_use]
+    pub fn with_max_memory(mut self, bytes: u64) -> Self {
+        self.max_memory_bytes = Some(bytes);
+        self
+    }
+
+    /// Sets the maximum wall-clock runtime (whole seconds, at least one).
+    #[must_use]
+    pub fn with_max_runtime(mut self, max: std::time::Duration) -> Self {
+        self.max_runtime_secs = Some(max.as_secs().max(1));
+        self
+    }
+
+    /// Returns the maximum runtime as a [`std::time::Duration`].
+    pub fn max_runtime(&self) -> Option<std::time::Duration> {
+        self.max_runtime_secs.map(std::time::Duration::from_secs)
+    }
+
+    /// Returns true if no limits are set.
+    pub fn is_unlimited(&self) -> bool {
+        self.nice.is_none() && self.max_memory_bytes.is_none() && self.max_runtime_secs.is_none()
+    }
+}
+
+/// Which task outcomes trigger a notification.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotifyOn {
+    /// Notify whenever the task finishes, successfully or not.
+    #[default]
+    Finished,
+    /// Notify only when the task completes successfully.
+    Success,
+    /// Notify only when the task fails or exceeds a limit.
+    Failure,
+}
+
+impl NotifyOn {
+    /// Returns true if a task that finished with `status` should notify.
+    ///
+    /// Pending, running, and cancelled tasks never notify.
+    pub fn matches(&self, status: &TaskStatus) -> bool {
+        let failed = match status {
+            TaskStatus::Completed => false,
+            TaskStatus::Failed { .. } | TaskStatus::LimitExceeded { .. } => true,
+            TaskStatus::Pending | TaskStatus::Running | TaskStatus::Cancelled => return false,
+        };
+        match self {
+            NotifyOn::Finished => true,
+            NotifyOn::Success => !failed,
+            NotifyOn::Failure => failed,
+        }
+    }
+}
+
+impl std::fmt::Display for NotifyOn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotifyOn::Finished => write!(f, "finished"),
+            NotifyOn::Success => write!(f, "success"),
+            NotifyOn::Failure => write!(f, "failure"),
+        }
+    }
+}
+
+impl std::str::FromStr for NotifyOn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "finished" | "always" => Ok(NotifyOn::Finished),
+            "success" | "completed" => Ok(NotifyOn::Success),
+            "failure" | "failed" => Ok(NotifyOn::Failure),
+            other => Err(format!(
+                "unknown notify condition '{other}' (expected finished, success, or failure)"
+            )),
+        }
+    }
+}
+
+/// Optional per-task notifications sent when the task finishes.
+///
+/// The [`TaskExecutor`](crate::TaskExecutor) delivers each enabled channel
+/// once the task's final status is known:
+///
+/// - `terminal`: a desktop notification via the terminal's OSC 9/777/99
+///   sequence (requires the `notify-terminal` feature)
+/// - `speak`: a spoken summary via text-to-speech (requires the
+///   `notify-speech` feature)
+/// - `webhook`: a JSON `POST` of the finished task (requires the
+///   `notify-webhook` feature)
+///
+/// Channels whose feature is disabled are skipped, and delivery failures
+/// never change the task's status.
+///
+/// ## Examples
+///
+/// ```
+/// use queue_lib::{Notifications, NotifyOn};
+///
+/// let notify = Notifications::default()
+///     .with_terminal()
+///     .with_webhook("https://example.com/hooks/queue")
+///     .on(NotifyOn::Failure);
+/// assert!(!notify.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notifications {
+    /// Raise a desktop notification through the terminal.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub terminal: bool,
+    /// Speak a summary of the result aloud.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub speak: bool,
+    /// URL that receives the finished task as a JSON `POST`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<String>,
+    /// Which outcomes trigger the notifications.
+    #[serde(default)]
+    pub on: NotifyOn,
+}
+
+impl Notifications {
+    /// Enables terminal desktop notifications.
+    #[must_use]
+    pub fn with_terminal(mut self) -> Self {
+        self.terminal = true;
+        self
+    }
+
+    /// Enables spoken notifications.
+    #[must_use]
+    pub fn with_speech(mut self) -> Self {
+        self.speak = true;
+        self
+    }
+
+    /// Posts the finished task to `url`.
+    #[must_use]
+    pub fn with_webhook(mut self, url: impl Into<String>) -> Self {
+        self.webhook = Some(url.into());
+        self
+    }
+
+    /// Sets which outcomes trigger the notifications.
+    #[must_use]
+    pub fn on(mut self, on: NotifyOn) -> Self {
+        self.on = on;
+        self
+    }
+
+    /// Returns true if no notification channel is enabled.
+    pub fn is_empty(&self) -> bool {
+        !self.terminal && !self.speak && self.webhook.is_none()
+    }
+
+    /// Returns true if a task that finished with `status` should notify.
+    pub fn should_notify(&self, status: &TaskStatus) -> bool {
+        !self.is_empty() && self.on.matches(status)
+    }
+}
+
+/// How often a recurring task runs.
+///
+/// When a recurring task finishes, its next run is scheduled a whole number
+/// of intervals after the previous scheduled time, skipping occurrences that
+/// passed while no queue was running.
+///
+/// ## Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use queue_lib::Recurrence;
+///
+/// let previous = Utc.with_ymd_and_hms(2026, 3, 1, 7, 0, 0).unwrap();
+/// let now = Utc.with_ymd_and_hms(2026, 3, 3, 12, 0, 0).unwrap();
+/// assert_eq!(
+///     Recurrence::daily().next_after(previous, now),
+///     Utc.with_ymd_and_hms(2026, 3, 4, 7, 0, 0).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recurrence {
+    /// Interval between runs, in seconds.
+    pub every_secs: u64,
+}
+
+impl Recurrence {
+    /// Repeats every `interval` (whole seconds, at least one).
+    pub fn every(interval: std::time::Duration) -> Self {
+        Self {
+            every_secs: interval.as_secs().max(1),
+        }
+    }
+
+    /// Repeats every hour.
+    pub fn hourly() -> Self {
+        Self {
+            every_secs: 60 * 60,
+        }
+    }
+
+    /// Repeats every day.
+    pub fn daily() -> Self {
+        Self {
+            every_secs: 24 * 60 * 60,
+        }
+    }
+
+    /// Repeats every week.
+    pub fn weekly() -> Self {
+        Self {
+            every_secs: 7 * 24 * 60 * 60,
+        }
+    }
+
+    /// Returns the interval between runs.
+    pub fn interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.every_secs.max(1))
+    }
+
+    /// Returns the first occurrence after both `previous` and `now`.
+    pub fn next_after(&self, previous: DateTime<Utc>, now: DateTime<Utc>) -> DateTime<Utc> {
+        let every = i64::try_from(self.every_secs.max(1)).unwrap_or(i64::MAX);
+        let elapsed = (now - previous).num_seconds().max(0);
+        let steps = elapsed / every + 1;
+        previous + chrono::Duration::seconds(steps.saturating_mul(every))
+    }
+}
+
+impl std::fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.every_secs {
+            3_600 => write!(f, "hourly"),
+            86_400 => write!(f, "daily"),
+            604_800 => write!(f, "weekly"),
+            secs if secs % 86_400 == 0 => write!(f, "every {}d", secs / 86_400),
+            secs if secs % 3_600 == 0 => write!(f, "every {}h", secs / 3_600),
+            secs if secs % 60 == 0 => write!(f, "every {}m", secs / 60),
+            secs => write!(f, "every {secs}s"),
+        }
+    }
 }
 
 /// A scheduled task in the queue.
@@ -76,6 +390,24 @@ pub struct ScheduledTask {
     /// existed - treated as `AfterDelay` (countdown display).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schedule_kind: Option<ScheduleKind>,
+    /// When the task started running.
+    ///
+    /// Set when the task transitions to [`TaskStatus::Running`] and used to
+    /// report elapsed time. `None` for tasks that have not started yet (and
+    /// for history entries recorded before this field existed).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    /// Resource limits applied when the task runs.
+    #[serde(default, skip_serializing_if = "ResourceLimits::is_unlimited")]
+    pub limits: ResourceLimits,
+    /// How often the task repeats; `None` for one-off tasks.
+    ///
+    /// Finishing a recurring task schedules its next occurrence as a new task.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<Recurrence>,
+    /// Notifications sent when the task finishes.
+    #[serde(default, skip_serializing_if = "Notifications::is_empty")]
+    pub notify: Notifications,
 }
 
 impl ScheduledTask {
@@ -109,6 +441,10 @@ impl ScheduledTask {
             status: TaskStatus::Pending,
             created_at: Utc::now(),
             schedule_kind: None,
+            started_at: None,
+            limits: ResourceLimits::default(),
+            recurrence: None,
+            notify: Notifications::default(),
         }
     }
 
@@ -148,12 +484,95 @@ impl ScheduledTask {
             status: TaskStatus::Pending,
             created_at: Utc::now(),
             schedule_kind: Some(schedule_kind),
+            started_at: None,
+            limits: ResourceLimits::default(),
+            recurrence: None,
+            notify: Notifications::default(),
         }
     }
 
-    /// Marks the task as running.
+    /// Sets the resource limits for this task.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use queue_lib::{ExecutionTarget, ResourceLimits, ScheduledTask};
+    /// use chrono::Utc;
+    ///
+    /// let task = ScheduledTask::new(1, "make".to_string(), Utc::now(), ExecutionTarget::Background)
+    ///     .with_limits(ResourceLimits::default().with_nice(10));
+    /// assert_eq!(task.limits.nice, Some(10));
+    /// ```
+    #[must_use]
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Makes the task repeat with the given recurrence.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use queue_lib::{ExecutionTarget, Recurrence, ScheduledTask};
+    /// use chrono::Utc;
+    ///
+    /// let task = ScheduledTask::new(1, "backup".to_string(), Utc::now(), ExecutionTarget::Background)
+    ///     .with_recurrence(Recurrence::daily());
+    /// assert_eq!(task.recurrence, Some(Recurrence::daily()));
+    /// ```
+    #[must_use]
+    pub fn with_recurrence(mut self, recurrence: Recurrence) -> Self {
+        self.recurrence = Some(recurrence);
+        self
+    }
+
+    /// Sets the notifications sent when this task finishes.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use queue_lib::{ExecutionTarget, Notifications, ScheduledTask};
+    /// use chrono::Utc;
+    ///
+    /// let task = ScheduledTask::new(1, "make".to_string(), Utc::now(), ExecutionTarget::Background)
+    ///     .with_notifications(Notifications::default().with_speech());
+    /// assert!(task.notify.speak);
+    /// ```
+    #[must_use]
+    pub fn with_notifications(mut self, notify: Notifications) -> Self {
+        self.notify = notify;
+        self
+    }
+
+    /// Returns the next run of a recurring task as a new pending task.
+    ///
+    /// The copy gets `id` and is scheduled by [`Recurrence::next_after`].
+    /// Returns `None` for one-off tasks.
+    pub fn next_occurrence(&self, id: u64, now: DateTime<Utc>) -> Option<ScheduledTask> {
+        let recurrence = self.recurrence?;
+        Some(Self {
+            id,
+            scheduled_at: recurrence.next_after(self.scheduled_at, now),
+            status: TaskStatus::Pending,
+            created_at: now,
+            started_at: None,
+            ..self.clone()
+        })
+    }
+
+    /// Marks the task as running and records the start time.
     pub fn mark_running(&mut self) {
         self.status = TaskStatus::Running;
+        self.started_at = Some(Utc::now());
+    }
+
+    /// Returns how long the task has been running, if it has started.
+    ///
+    /// Negative durations (clock skew between processes) are clamped to zero.
+    pub fn elapsed(&self, now: DateTime<Utc>) -> Option<chrono::Duration> {
+        self.started_at
+            .map(|started| (now - started).max(chrono::Duration::zero()))
     }
 
     /// Marks the task as completed.
@@ -188,9 +607,20 @@ impl ScheduledTask {
         matches!(self.status, TaskStatus::Completed)
     }
 
-    /// Returns true if the task has failed.
+    /// Marks the task as failed because it exceeded a resource limit.
+    pub fn mark_limit_exceeded(&mut self, limit: LimitKind, error: impl Into<String>) {
+        self.status = TaskStatus::LimitExceeded {
+            limit,
+            error: error.into(),
+        };
+    }
+
+    /// Returns true if the task has failed, including resource limit violations.
     pub fn is_failed(&self) -> bool {
-        matches!(self.status, TaskStatus::Failed { .. })
+        matches!(
+            self.status,
+            TaskStatus::Failed { .. } | TaskStatus::LimitExceeded { .. }
+        )
     }
 
     /// Returns true if the task was cancelled.
@@ -311,6 +741,7 @@ mod tests {
         task.mark_running();
         assert!(!task.is_pending());
         assert!(task.is_running());
+        assert!(task.started_at.is_some());
 
         task.mark_completed();
         assert!(task.is_completed());
@@ -328,6 +759,185 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scheduled_task_without_started_at_deserializes() {
+        // History entries written before `started_at` existed must still load
+        let task = ScheduledTask::new(1, "ls".to_string(), Utc::now(), ExecutionTarget::Background);
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(!json.contains("started_at"));
+
+        let task: ScheduledTask = serde_json::from_str(&json).unwrap();
+        assert!(task.started_at.is_none());
+        assert!(task.elapsed(Utc::now()).is_none());
+    }
+
+    #[test]
+    fn elapsed_is_clamped_to_zero() {
+        let mut task = ScheduledTask::new(
+            1,
+            "echo test".to_string(),
+            Utc::now(),
+            ExecutionTarget::Background,
+        );
+        task.started_at = Some(Utc::now() + Duration::minutes(5));
+        assert_eq!(task.elapsed(Utc::now()), Some(Duration::zero()));
+    }
+
+    #[test]
+    fn limit_exceeded_status_round_trips() {
+        let status = TaskStatus::LimitExceeded {
+            limit: LimitKind::MaxRuntime,
+            error: "exceeded max runtime of 60s".to_string(),
+        };
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(
+            json,
+            r#"{"status":"limit_exceeded","limit":"max_runtime","error":"exceeded max runtime of 60s"}"#
+        );
+        assert_eq!(serde_json::from_str::<TaskStatus>(&json).unwrap(), status);
+
+        let mut task =
+            ScheduledTask::new(1, "ls".to_string(), Utc::now(), ExecutionTarget::Background);
+        task.mark_limit_exceeded(LimitKind::Memory, "killed");
+        assert!(task.is_failed());
+    }
+
+    #[test]
+    fn resource_limits_are_omitted_when_unlimited() {
+        let task = ScheduledTask::new(1, "ls".to_string(), Utc::now(), ExecutionTarget::Background);
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(!json.contains("limits"));
+        let restored: ScheduledTask = serde_json::from_str(&json).unwrap();
+        assert!(restored.limits.is_unlimited());
+
+        let task = task.with_limits(
+            ResourceLimits::default()
+                .with_nice(42)
+                .with_max_runtime(std::time::Duration::from_millis(10)),
+        );
+        assert_eq!(task.limits.nice, Some(19));
+        assert_eq!(task.limits.max_runtime_secs, Some(1));
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains(r#""limits":{"nice":19,"max_runtime_secs":1}"#));
+        let restored: ScheduledTask = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.limits, task.limits);
+    }
+
+    #[test]
+    fn recurrence_skips_missed_occurrences() {
+        let previous = Utc::now();
+        let daily = Recurrence::daily();
+
+        assert_eq!(
+            daily.next_after(previous, previous - Duration::hours(1)),
+            previous + Duration::days(1)
+        );
+        assert_eq!(
+            daily.next_after(previous, previous + Duration::days(1)),
+            previous + Duration::days(2)
+        );
+        assert_eq!(
+            daily.next_after(previous, previous + Duration::hours(75)),
+            previous + Duration::days(4)
+        );
+    }
+
+    #[test]
+    fn recurrence_displays_named_and_custom_intervals() {
+        assert_eq!(Recurrence::weekly().to_string(), "weekly");
+        assert_eq!(
+            Recurrence::every(std::time::Duration::from_secs(3 * 86_400)).to_string(),
+            "every 3d"
+        );
+        assert_eq!(
+            Recurrence::every(std::time::Duration::from_secs(90)).to_string(),
+            "every 90s"
+        );
+    }
+
+    #[test]
+    fn next_occurrence_copies_recurring_tasks() {
+        let now = Utc::now();
+        let mut task =
+            ScheduledTask::new(1, "backup".to_string(), now, ExecutionTarget::Background)
+                .with_limits(ResourceLimits::default().with_nice(5));
+        assert!(task.next_occurrence(2, now).is_none());
+
+        task = task.with_recurrence(Recurrence::hourly());
+        task.mark_running();
+        task.mark_completed();
+
+        let next = task.next_occurrence(2, now + Duration::minutes(5)).unwrap();
+        assert_eq!(next.id, 2);
+        assert_eq!(next.command, "backup");
+        assert_eq!(next.scheduled_at, now + Duration::hours(1));
+        assert!(next.is_pending());
+        assert!(next.started_at.is_none());
+        assert_eq!(next.limits.nice, Some(5));
+        assert_eq!(next.recurrence, Some(Recurrence::hourly()));
+    }
+
+    #[test]
+    fn recurrence_round_trips_and_is_omitted_for_one_off_tasks() {
+        let task = ScheduledTask::new(1, "ls".to_string(), Utc::now(), ExecutionTarget::Background);
+        assert!(!serde_json::to_string(&task).unwrap().contains("recurrence"));
+
+        let task = task.with_recurrence(Recurrence::daily());
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains(r#""recurrence":{"every_secs":86400}"#));
+        let restored: ScheduledTask = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.recurrence, Some(Recurrence::daily()));
+    }
+
+    #[test]
+    fn notify_on_matches_final_statuses() {
+        let failed = TaskStatus::Failed {
+            error: "boom".to_string(),
+        };
+        let limited = TaskStatus::LimitExceeded {
+            limit: LimitKind::MaxRuntime,
+            error: "killed".to_string(),
+        };
+
+        assert!(NotifyOn::Finished.matches(&TaskStatus::Completed));
+        assert!(NotifyOn::Finished.matches(&failed));
+        assert!(NotifyOn::Success.matches(&TaskStatus::Completed));
+        assert!(!NotifyOn::Success.matches(&failed));
+        assert!(NotifyOn::Failure.matches(&limited));
+        assert!(!NotifyOn::Failure.matches(&TaskStatus::Completed));
+        assert!(!NotifyOn::Finished.matches(&TaskStatus::Cancelled));
+        assert!(!NotifyOn::Finished.matches(&TaskStatus::Running));
+    }
+
+    #[test]
+    fn notify_on_parses_and_displays() {
+        assert_eq!("Failed".parse::<NotifyOn>(), Ok(NotifyOn::Failure));
+        assert_eq!("success".parse::<NotifyOn>(), Ok(NotifyOn::Success));
+        assert_eq!(NotifyOn::Finished.to_string(), "finished");
+        assert!("sometimes".parse::<NotifyOn>().is_err());
+    }
+
+    #[test]
+    fn notifications_round_trip_and_are_omitted_when_empty() {
+        let task = ScheduledTask::new(1, "ls".to_string(), Utc::now(), ExecutionTarget::Background);
+        assert!(!serde_json::to_string(&task).unwrap().contains("notify"));
+        assert!(!task.notify.should_notify(&TaskStatus::Completed));
+
+        let task = task.with_notifications(
+            Notifications::default()
+                .with_terminal()
+                .with_webhook("http://localhost/hook")
+                .on(NotifyOn::Failure),
+        );
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains(
+            r#""notify":{"terminal":true,"webhook":"http://localhost/hook","on":"failure"}"#
+        ));
+        let restored: ScheduledTask = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.notify, task.notify);
+        assert!(!restored.notify.should_notify(&TaskStatus::Completed));
+    }
+
     #[test]
     fn execution_target_default_is_new_pane() {
         assert_eq!(ExecutionTarget::default(), ExecutionTarget::NewPane);
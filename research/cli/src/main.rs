@@ -1,10 +1,24 @@
 //! Research CLI - Automated research tool for software libraries
 
-use clap::{Parser, Subcommand};
-use research_lib::research;
+use clap::{Args, Parser, Subcommand};
+use research_lib::ecosystem::Ecosystem;
+use research_lib::export::ExportFormat;
+use research_lib::hooks::CompletionHooks;
+use research_lib::models::{ModelConfig, ModelOverride};
+use research_lib::paths::ResearchPaths;
+use research_lib::question::Question;
+use research_lib::repository::RepositorySource;
+use research_lib::{ResearchKind, ResearchOptions, SelectionPolicy, research_with_options};
 use std::io::{self, BufRead};
 use std::path::PathBuf;
-use tracing_subscriber::{filter::EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+use tracing_subscriber::{
+    Layer, Registry, filter::EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt,
+};
+
+/// OTLP export, set up from the `OTEL_*` environment variables
+#[cfg(feature = "otel")]
+static TELEMETRY: std::sync::OnceLock<research_lib::telemetry::Telemetry> =
+    std::sync::OnceLock::new();
 
 #[derive(Parser)]
 #[command(name = "research")]
@@ -22,37 +36,144 @@ struct Cli {
     command: Commands,
 }
 
+/// Arguments shared by the topic research commands (library, software, standard)
+#[derive(Args)]
+struct TopicArgs {
+    /// The topic to research (use "-" to read from stdin)
+    #[arg(value_name = "TOPIC")]
+    topic: String,
+
+    /// Additional questions to research in parallel
+    #[arg(value_name = "QUESTIONS")]
+    questions: Vec<String>,
+
+    /// Additional question to research (repeatable, same as positional QUESTIONS)
+    #[arg(short = 'q', long = "question", value_name = "QUESTION")]
+    question: Vec<String>,
+
+    /// Tag the additional questions (repeatable: --tag reliability --tag async)
+    ///
+    /// Tags are stored in metadata.json and collected into cross-topic
+    /// indexes at ~/.research/tags/<tag>.md.
+    #[arg(long = "tag", value_name = "TAG")]
+    tags: Vec<String>,
+
+    /// Output directory for research files [default: research/<TOPIC>]
+    #[arg(short, long, value_name = "DIR")]
+    output: Option<PathBuf>,
+
+    /// Regenerate skill files from existing research
+    ///
+    /// Requires all underlying research documents (overview, similar_libraries, etc.)
+    /// to exist. Removes skill/* contents and regenerates SKILL.md.
+    #[arg(long)]
+    skill: bool,
+
+    /// Force recreation of all research output documents
+    ///
+    /// Bypasses incremental mode and regenerates all ResearchOutput documents
+    /// (overview, similar_libraries, etc.) even if they already exist.
+    #[arg(long)]
+    force: bool,
+
+    /// POST a JSON summary to this URL when research finishes
+    #[arg(long, value_name = "URL")]
+    webhook: Option<String>,
+
+    /// Run a shell command when research finishes
+    ///
+    /// The command receives the JSON summary on stdin and RESEARCH_TOPIC,
+    /// RESEARCH_STATUS, RESEARCH_OUTPUT_DIR (and friends) in its environment.
+    #[arg(long, value_name = "COMMAND")]
+    on_complete: Option<String>,
+
+    /// Don't speak an announcement when research finishes
+    #[arg(long)]
+    no_announce: bool,
+
+    /// Stream prompt output to disk as tokens arrive
+    ///
+    /// Output files grow while models are still generating, so long prompts
+    /// like the deep dive can be followed (e.g. with `tail -f`) and partial
+    /// results survive a crash.
+    #[arg(long)]
+    stream: bool,
+
+    /// Print the execution plan without calling any model
+    ///
+    /// Shows each task with its model, output file and estimated input
+    /// tokens, followed by every fully templated prompt. Nothing is written.
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Use this ecosystem's library prompts (rust, javascript, python, go, php or generic)
+    ///
+    /// By default the prompts are picked from the library's language. Only
+    /// library research has ecosystem variants.
+    #[arg(long, value_name = "ECOSYSTEM")]
+    ecosystem: Option<Ecosystem>,
+
+    /// Run a task on another model, e.g. `overview=zai:glm-4.7@0.3` (repeatable)
+    ///
+    /// The optional `@<temperature>` sets the sampling temperature (0-2), and
+    /// `fallback=...` replaces the shared fallback model. Applied on top of
+    /// the models file and RESEARCH_MODEL_* variables; unknown models are
+    /// rejected with suggestions.
+    #[arg(long = "model", value_name = "TASK=PROVIDER:MODEL[@TEMP]")]
+    models: Vec<ModelOverride>,
+
+    /// How to pick a library found on several package managers
+    ///
+    /// `interactive` (the default) asks, `first` takes the first registry's
+    /// match, `fail` stops with an error, and a language such as `rust` takes
+    /// the match in that language. Use one of the others when there's no
+    /// terminal, e.g. in CI.
+    #[arg(long, value_name = "POLICY")]
+    select: Option<SelectionPolicy>,
+
+    /// Continue an interrupted run from its checkpoint
+    ///
+    /// Keeps the Phase 1 documents the earlier run completed, re-runs the
+    /// rest with the same library, ecosystem and questions, then runs
+    /// Phase 2.
+    #[arg(long, conflicts_with_all = ["skill", "force", "dry_run"])]
+    resume: bool,
+
+    /// Regenerate only brief.md from the existing deep dive
+    ///
+    /// Re-runs the brief prompt and updates the brief and summary in
+    /// metadata.json, without repeating the rest of Phase 2.
+    #[arg(long, conflicts_with_all = ["skill", "force", "dry_run", "resume", "questions", "question"])]
+    brief: bool,
+
+    /// Ground the research in the library's source code
+    ///
+    /// Takes a local checkout, a git URL to clone, or `registry` to clone the
+    /// repository the package registry lists. Its exported symbols and
+    /// examples are added to the Phase 1 prompts of a full run.
+    #[arg(long, value_name = "PATH|URL|registry")]
+    repo: Option<RepositorySource>,
+
+    /// Show a live table of task progress instead of log lines
+    ///
+    /// Each task's status, elapsed time, token counts and tool calls are
+    /// redrawn in place; the final table is printed when the run ends.
+    /// Needs the `tui` feature.
+    #[arg(long, conflicts_with = "dry_run")]
+    tui: bool,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Research a software library
     #[command(alias = "lib")]
-    Library {
-        /// The library/topic to research (use "-" to read from stdin)
-        #[arg(value_name = "TOPIC")]
-        topic: String,
-
-        /// Additional questions to research in parallel
-        #[arg(value_name = "QUESTIONS")]
-        questions: Vec<String>,
+    Library(TopicArgs),
 
-        /// Output directory for research files [default: research/<TOPIC>]
-        #[arg(short, long, value_name = "DIR")]
-        output: Option<PathBuf>,
-
-        /// Regenerate skill files from existing research
-        ///
-        /// Requires all underlying research documents (overview, similar_libraries, etc.)
-        /// to exist. Removes skill/* contents and regenerates SKILL.md.
-        #[arg(long)]
-        skill: bool,
+    /// Research software such as a CLI tool, application or service
+    Software(TopicArgs),
 
-        /// Force recreation of all research output documents
-        ///
-        /// Bypasses incremental mode and regenerates all ResearchOutput documents
-        /// (overview, similar_libraries, etc.) even if they already exist.
-        #[arg(long)]
-        force: bool,
-    },
+    /// Research a standard such as a specification, protocol or file format
+    Standard(TopicArgs),
 
     /// List all research topics
     List {
@@ -64,6 +185,14 @@ enum Commands {
         #[arg(short = 't', long = "type", value_name = "TYPE")]
         types: Vec<String>,
 
+        /// Filter by topic or question tag (repeatable: --tag async --tag networking)
+        #[arg(long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+
+        /// Filter by category (repeatable: --category web-frameworks)
+        #[arg(long = "category", value_name = "CATEGORY")]
+        categories: Vec<String>,
+
         /// Show detailed metadata for each topic (sub-bullets with issues)
         #[arg(long)]
         verbose: bool,
@@ -75,6 +204,10 @@ enum Commands {
         /// Migrate all v0 metadata files to v1 schema
         #[arg(long)]
         migrate: bool,
+
+        /// Include archived topics
+        #[arg(long)]
+        archived: bool,
     },
 
     /// Create symbolic links from research skills to Claude Code and OpenCode
@@ -90,6 +223,10 @@ enum Commands {
         /// Output as JSON instead of terminal format
         #[arg(long)]
         json: bool,
+
+        /// Prune links to deleted topics and repair links whose target changed
+        #[arg(long)]
+        sync: bool,
     },
 
     /// Show a research topic's deep dive document
@@ -99,6 +236,32 @@ enum Commands {
         topic: String,
     },
 
+    /// Add or remove a topic's tags and set its category
+    ///
+    /// Topic tags and categories are stored in the topic's metadata.json and
+    /// can be filtered on with `research list --tag` and `--category`.
+    Tag {
+        /// The topic to tag (directory name under ~/.research/<kind>/) or a research directory
+        #[arg(required = true, value_name = "TOPIC")]
+        topic: String,
+
+        /// Tags to add
+        #[arg(value_name = "TAG")]
+        add: Vec<String>,
+
+        /// Tags to remove (repeatable: --remove async --remove networking)
+        #[arg(long = "remove", value_name = "TAG")]
+        remove: Vec<String>,
+
+        /// File the topic under a category
+        #[arg(long, value_name = "CATEGORY", conflicts_with = "no_category")]
+        category: Option<String>,
+
+        /// Clear the topic's category
+        #[arg(long)]
+        no_category: bool,
+    },
+
     /// Research a public API
     Api {
         /// The API name (e.g., "stripe", "github", "openai")
@@ -109,7 +272,7 @@ enum Commands {
         #[arg(value_name = "QUESTIONS")]
         questions: Vec<String>,
 
-        /// Output directory [default: $RESEARCH_DIR/.research/api/<api-name>]
+        /// Output directory [default: <research library>/api/<api-name>]
         #[arg(short, long, value_name = "DIR")]
         output: Option<PathBuf>,
 
@@ -133,6 +296,165 @@ enum Commands {
         #[arg(long)]
         local: bool,
     },
+
+    /// Find near-duplicate topics and optionally merge them
+    ///
+    /// Compares topic names and their brief/summary text. Without --apply or
+    /// --merge this only prints the proposed merges.
+    Dedupe {
+        /// Minimum similarity score (0.0-1.0) for proposing a merge
+        #[arg(long, value_name = "SCORE", default_value_t = research_lib::dedupe::DEFAULT_THRESHOLD)]
+        threshold: f32,
+
+        /// Merge every proposed pair into the topic with more research
+        #[arg(long)]
+        apply: bool,
+
+        /// Merge the topic MERGE into KEEP, regardless of similarity
+        #[arg(long, num_args = 2, value_names = ["KEEP", "MERGE"], conflicts_with = "apply")]
+        merge: Option<Vec<String>>,
+
+        /// Show what merging would change without modifying anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Output as JSON instead of terminal format
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Move a topic into the research archive
+    ///
+    /// Archived topics are kept in ~/.research/archive/ and hidden from
+    /// `list` and `link`; their skill and doc symlinks are removed.
+    Archive {
+        /// The topic to archive
+        #[arg(required = true, value_name = "TOPIC")]
+        topic: String,
+
+        /// Output as JSON instead of terminal format
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Restore an archived topic to the research library
+    Unarchive {
+        /// The archived topic to restore
+        #[arg(required = true, value_name = "TOPIC")]
+        topic: String,
+
+        /// Output as JSON instead of terminal format
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Generate or refresh the glossary of domain terms across all topics
+    ///
+    /// Extracts terms from each topic's brief and deep dive and writes
+    /// ~/.research/library/glossary.md with backlinks to the source topics.
+    /// Only topics whose sources changed since the last run are re-extracted.
+    Glossary {
+        /// Re-extract terms for every topic, ignoring the refresh interval and cache
+        #[arg(long)]
+        force: bool,
+
+        /// Skip the run if the glossary was refreshed within this many hours (0 disables)
+        #[arg(long, value_name = "HOURS", default_value_t = 24)]
+        interval: u64,
+    },
+
+    /// Search every topic's documents for a concept
+    ///
+    /// Ranks the markdown files under ~/.research/library/ by how well they
+    /// match the query and shows the best-matching line of each. The index
+    /// at ~/.research/library/.search-index.json is updated as needed.
+    Search {
+        /// Words to search for (documents with more of them rank higher)
+        #[arg(required = true, value_name = "QUERY")]
+        query: Vec<String>,
+
+        /// Maximum number of results
+        #[arg(long, value_name = "N", default_value_t = 10)]
+        limit: usize,
+
+        /// Output as JSON instead of terminal format
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Export a topic's research as a single HTML or PDF report
+    ///
+    /// Bundles the overview, use cases, changelog, deep dive and additional
+    /// questions into one document with a table of contents.
+    Export {
+        /// The topic to export (directory name under ~/.research/<kind>/) or a research directory
+        #[arg(required = true, value_name = "TOPIC")]
+        topic: String,
+
+        /// Report format (html or pdf)
+        #[arg(long, value_name = "FORMAT", default_value_t = ExportFormat::Html)]
+        format: ExportFormat,
+
+        /// Where to write the report [default: <topic dir>/report.<format>]
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+
+    /// Show which sections changed between an earlier snapshot and a topic's research
+    ///
+    /// Compares the overview, changelog and deep dive section by section, e.g.
+    /// against a copy taken before refreshing stale research.
+    Diff {
+        /// The topic to compare (directory name under ~/.research/<kind>/) or a research directory
+        #[arg(required = true, value_name = "TOPIC")]
+        topic: String,
+
+        /// The earlier research directory to compare against
+        #[arg(required = true, value_name = "OLD_DIR")]
+        old: PathBuf,
+
+        /// Output as JSON instead of terminal format
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Refresh library topics whose research is older than a maximum age
+    ///
+    /// Re-runs the changelog prompt and the Phase 2 synthesis (skill, deep
+    /// dive and brief) for each stale topic, keeping its other documents.
+    /// Suitable for running from cron.
+    Refresh {
+        /// Refresh these topics whatever their age, instead of the stale ones
+        #[arg(value_name = "TOPICS")]
+        topics: Vec<String>,
+
+        /// Refresh topics last updated more than this many days ago
+        #[arg(long, value_name = "DAYS", default_value_t = research_lib::refresh::DEFAULT_MAX_AGE_DAYS)]
+        max_age: u64,
+
+        /// Refresh all stale topics concurrently, sharing request limits
+        #[arg(long)]
+        batch: bool,
+
+        /// List the stale topics without refreshing them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Refresh a library topic on a recurring schedule through `queue`
+    ///
+    /// Registers a background task in the queue history that runs
+    /// `research refresh <TOPIC>` on the given cadence while a `queue` TUI
+    /// is open. Scheduling a topic again replaces its cadence.
+    Schedule {
+        /// The library topic to keep current
+        #[arg(value_name = "TOPIC")]
+        topic: String,
+
+        /// How often to refresh: hourly, daily, weekly or an interval like 3d
+        #[arg(long, value_name = "RECURRENCE", default_value = "weekly", value_parser = research_lib::refresh::parse_recurrence)]
+        every: research_lib::refresh::Recurrence,
+    },
 }
 
 fn read_topic_from_stdin() -> io::Result<String> {
@@ -142,29 +464,182 @@ fn read_topic_from_stdin() -> io::Result<String> {
     Ok(line.trim().to_string())
 }
 
+/// Print the outcome of archiving or restoring a topic.
+fn print_archive_report(report: &research_lib::archive::ArchiveReport, json: bool) {
+    if json {
+        match serde_json::to_string_pretty(report) {
+            Ok(output) => println!("{}", output),
+            Err(e) => {
+                eprintln!("Failed to format JSON: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        print!("{}", report.render());
+    }
+}
+
+/// Report near-duplicate topics and merge them when requested.
+///
+/// Merges run in order of similarity; a topic that was already merged away is
+/// resolved to the topic it was merged into, so chains like `a <- b <- c`
+/// collapse into `a`.
+async fn run_dedupe(
+    threshold: f32,
+    apply: bool,
+    merge: Option<Vec<String>>,
+    dry_run: bool,
+    json: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use research_lib::dedupe::{dedupe, default_link_dirs, merge_topics};
+    use std::collections::HashMap;
+
+    let library_dir = research_lib::tags::default_research_root().join("library");
+
+    let pairs: Vec<(String, String)> = match merge {
+        Some(names) => vec![(names[0].clone(), names[1].clone())],
+        None => {
+            let report = dedupe(&library_dir, threshold)?;
+            if !apply {
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&report)?);
+                } else {
+                    print!("{}", report.render());
+                    if !report.candidates.is_empty() {
+                        println!("\nRun with --apply to merge (add --dry-run to preview).");
+                    }
+                }
+                return Ok(());
+            }
+            report
+                .candidates
+                .into_iter()
+                .map(|c| (c.keep, c.merge))
+                .collect()
+        }
+    };
+
+    let link_dirs = default_link_dirs();
+    let mut merged_into: HashMap<String, String> = HashMap::new();
+    let mut plans = Vec::new();
+
+    for (keep, merge) in pairs {
+        let mut keep = keep;
+        while let Some(target) = merged_into.get(&keep) {
+            keep = target.clone();
+        }
+        if keep == merge || merged_into.contains_key(&merge) {
+            continue;
+        }
+
+        let plan = merge_topics(&library_dir, &keep, &merge, &link_dirs, dry_run)?;
+        if !json {
+            print!("{}", plan.render());
+        }
+        merged_into.insert(merge, keep);
+        plans.push(plan);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&plans)?);
+    } else if plans.is_empty() {
+        println!("Nothing to merge");
+    } else if !dry_run {
+        println!("\nRun `research link` to link the kept topics.");
+    }
+
+    Ok(())
+}
+
+/// Refresh the library topics last updated more than `max_age_days` ago.
+async fn run_refresh(
+    topics: &[String],
+    max_age_days: u64,
+    batch: bool,
+    dry_run: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use research_lib::refresh::{find_stale_topics, find_topics, refresh_stale};
+
+    let library_dir = research_lib::tags::default_research_root().join("library");
+    let stale = if topics.is_empty() {
+        let max_age = std::time::Duration::from_secs(max_age_days * 24 * 60 * 60);
+        let stale = find_stale_topics(&library_dir, max_age).await?;
+        if stale.is_empty() {
+            println!("No topics older than {} days", max_age_days);
+            return Ok(());
+        }
+        println!("{} topic(s) older than {} days:", stale.len(), max_age_days);
+        stale
+    } else {
+        let found = find_topics(&library_dir, topics).await?;
+        println!("{} topic(s):", found.len());
+        found
+    };
+    for topic in &stale {
+        println!(
+            "  {} (updated {}, {} days ago)",
+            topic.topic,
+            topic.updated_at.format("%Y-%m-%d"),
+            topic.age().num_days()
+        );
+    }
+    if dry_run {
+        return Ok(());
+    }
+    println!();
+
+    let options = with_telemetry(ResearchOptions::default());
+    let results = refresh_stale(&stale, batch, &options).await;
+    flush_telemetry();
+
+    println!("\n{}", "=".repeat(60));
+    for topic in &results.topics {
+        match &topic.result {
+            Ok(result) => println!(
+                "✓ {}: {} succeeded, {} failed in {:.1}s",
+                topic.topic, result.succeeded, result.failed, result.total_time_secs
+            ),
+            Err(e) => println!("✗ {}: {}", topic.topic, e),
+        }
+    }
+    println!(
+        "Refreshed {}/{} topic(s) in {:.1}s, {} tokens",
+        results.succeeded().count(),
+        results.topics.len(),
+        results.total_time_secs,
+        results.total_tokens()
+    );
+    println!("{}", "=".repeat(60));
+
+    match results.failed().count() {
+        0 => Ok(()),
+        failed => Err(format!("{} topic(s) failed to refresh", failed).into()),
+    }
+}
+
 /// Show a research topic's deep dive document in the system's default application.
 ///
-/// Discovers topics by globbing for `{RESEARCH_DIR}/.research/library/*/deep_dive.md`.
+/// Discovers topics by globbing for `{root}/{kind}/*/deep_dive.md` across the
+/// library, software and standard directories of the research library.
 fn show_topic(topic: &str) -> Result<(), Box<dyn std::error::Error>> {
-    // Match the pattern used in research_lib (respects RESEARCH_DIR env var)
-    let base = std::env::var("RESEARCH_DIR")
-        .map(std::path::PathBuf::from)
-        .unwrap_or_else(|_| dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from(".")));
-
-    let library_dir = base.join(".research").join("library");
-
-    // Glob only immediate children, matching discover_topics() behavior
-    let pattern = format!("{}/*/deep_dive.md", library_dir.display());
-
-    for entry in glob::glob(&pattern)? {
-        let path = entry?;
-        // Get parent (should be the topic directory)
-        if let Some(parent) = path.parent()
-            && let Some(name) = parent.file_name()
-            && name.to_string_lossy() == topic
-        {
-            open::that(&path)?;
-            return Ok(());
+    let paths = ResearchPaths::from_env();
+
+    for kind in ResearchKind::TOPIC_KINDS {
+        let kind_dir = paths.kind_dir(kind);
+
+        // Glob only immediate children, matching discover_topics() behavior
+        let pattern = format!("{}/*/deep_dive.md", kind_dir.display());
+
+        for entry in glob::glob(&pattern)? {
+            let path = entry?;
+            // Get parent (should be the topic directory)
+            if let Some(parent) = path.parent()
+                && let Some(name) = parent.file_name()
+                && name.to_string_lossy() == topic
+            {
+                open::that(&path)?;
+                return Ok(());
+            }
         }
     }
 
@@ -175,6 +650,21 @@ fn show_topic(topic: &str) -> Result<(), Box<dyn std::error::Error>> {
     .into())
 }
 
+/// Finds the research directory for `topic`, which may also be a path to one.
+fn topic_dir(topic: &str) -> Option<PathBuf> {
+    let path = PathBuf::from(topic);
+    if path.is_dir() {
+        return Some(path);
+    }
+
+    let paths = ResearchPaths::from_env();
+    ResearchKind::TOPIC_KINDS
+        .into_iter()
+        .chain([ResearchKind::Api])
+        .map(|kind| paths.topic_dir(kind, topic))
+        .find(|dir| dir.is_dir())
+}
+
 /// Initialize tracing subscriber based on verbosity and output format
 fn init_tracing(verbose: u8, json: bool) {
     // Determine base filter from RUST_LOG or verbosity flags
@@ -196,16 +686,22 @@ fn init_tracing(verbose: u8, json: bool) {
 
     let filter = EnvFilter::try_new(&base_filter).unwrap_or_else(|_| EnvFilter::new("warn"));
 
+    // The filter only applies to console output; exported spans keep INFO
     if json {
         // JSON output for structured log processing
         tracing_subscriber::registry()
-            .with(filter)
-            .with(fmt::layer().json().with_writer(std::io::stderr))
+            .with(telemetry_layer())
+            .with(
+                fmt::layer()
+                    .json()
+                    .with_writer(std::io::stderr)
+                    .with_filter(filter),
+            )
             .init();
     } else {
         // Human-readable console output to stderr
         tracing_subscriber::registry()
-            .with(filter)
+            .with(telemetry_layer())
             .with(
                 fmt::layer()
                     .with_target(true)
@@ -214,91 +710,307 @@ fn init_tracing(verbose: u8, json: bool) {
                     .with_file(verbose >= 3)
                     .with_line_number(verbose >= 3)
                     .with_writer(std::io::stderr)
-                    .compact(),
+                    .compact()
+                    .with_filter(filter),
             )
             .init();
     }
 }
 
-#[tokio::main]
-async fn main() {
-    // Load environment variables from .env file
-    dotenvy::dotenv().ok();
+/// Set up OTLP export when an endpoint is configured, returning its span layer
+#[cfg(feature = "otel")]
+fn telemetry_layer() -> Option<Box<dyn Layer<Registry> + Send + Sync>> {
+    match research_lib::telemetry::Telemetry::from_env() {
+        Ok(Some(telemetry)) => {
+            let layer = telemetry
+                .tracing_layer()
+                .with_filter(EnvFilter::new("info"))
+                .boxed();
+            TELEMETRY.set(telemetry).ok();
+            Some(layer)
+        }
+        Ok(None) => None,
+        Err(e) => {
+            eprintln!("Warning: Telemetry export disabled: {}", e);
+            None
+        }
+    }
+}
 
-    let cli = Cli::parse();
-    init_tracing(cli.log_verbosity, cli.json);
+/// Telemetry export needs the `otel` feature
+#[cfg(not(feature = "otel"))]
+fn telemetry_layer() -> Option<Box<dyn Layer<Registry> + Send + Sync>> {
+    None
+}
 
-    tracing::info!("Research CLI starting");
+/// Record research metrics when telemetry export is enabled
+#[cfg(feature = "otel")]
+fn with_telemetry(options: ResearchOptions) -> ResearchOptions {
+    use research_lib::observer::ConsoleObserver;
+    use std::sync::Arc;
 
-    match cli.command {
-        Commands::Library {
-            topic,
-            questions,
+    let Some(telemetry) = TELEMETRY.get() else {
+        return options;
+    };
+    let inner = options
+        .observer
+        .clone()
+        .unwrap_or_else(|| Arc::new(ConsoleObserver));
+    let mut observer = match telemetry.observer(inner).with_prices_from_env() {
+        Ok(observer) => observer,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Some(models) = options.models.clone().or_else(|| ModelConfig::load().ok()) {
+        observer = observer.with_models(&models);
+    }
+    options.with_observer(Arc::new(observer))
+}
+
+#[cfg(not(feature = "otel"))]
+fn with_telemetry(options: ResearchOptions) -> ResearchOptions {
+    options
+}
+
+/// Start the `--tui` dashboard, exiting if it can't be started
+#[cfg(feature = "tui")]
+fn start_dashboard(topic: &str) -> research_lib::dashboard::Dashboard {
+    match research_lib::dashboard::Dashboard::start(topic) {
+        Ok(dashboard) => dashboard,
+        Err(e) => {
+            eprintln!("Error: Failed to start the dashboard: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Send any spans and metrics still waiting to be exported
+fn flush_telemetry() {
+    #[cfg(feature = "otel")]
+    if let Some(telemetry) = TELEMETRY.get()
+        && let Err(e) = telemetry.shutdown()
+    {
+        eprintln!("Warning: {}", e);
+    }
+}
+
+/// Run research for a topic of the given kind and print a summary
+async fn research_topic(kind: ResearchKind, args: TopicArgs) {
+    let TopicArgs {
+        topic,
+        mut questions,
+        question,
+        tags,
+        output,
+        skill,
+        force,
+        webhook,
+        on_complete,
+        no_announce,
+        stream,
+        dry_run,
+        ecosystem,
+        models,
+        select,
+        resume,
+        brief,
+        repo,
+        tui,
+    } = args;
+
+    questions.extend(question);
+
+    if !tags.is_empty() {
+        if questions.is_empty() {
+            eprintln!("Error: --tag requires at least one additional question");
+            std::process::exit(1);
+        }
+        if let Some(invalid) = tags
+            .iter()
+            .find(|t| research_lib::tags::normalize_tag(t).is_none())
+        {
+            eprintln!("Error: Invalid tag '{}'", invalid);
+            std::process::exit(1);
+        }
+    }
+
+    // Read topic from stdin if "-" is provided
+    let topic = if topic == "-" {
+        match read_topic_from_stdin() {
+            Ok(t) if !t.is_empty() => t,
+            Ok(_) => {
+                eprintln!("Error: No topic provided on stdin");
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Error reading from stdin: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        topic
+    };
+
+    let mut hooks = CompletionHooks {
+        webhook_url: webhook,
+        command: on_complete,
+        ..Default::default()
+    };
+    if !no_announce {
+        hooks = hooks.with_announcement();
+    }
+    let mut options = ResearchOptions::default().with_kind(kind).with_hooks(hooks);
+    if stream {
+        options = options.with_streaming();
+    }
+    if let Some(ecosystem) = ecosystem {
+        options = options.with_ecosystem(ecosystem);
+    }
+    if let Some(repo) = repo {
+        options = options.with_repository(repo);
+    }
+    if let Some(policy) = select {
+        options = options.with_selection(policy);
+    }
+    if !models.is_empty() {
+        let config = match ModelConfig::load() {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        };
+        options = options.with_models(models.into_iter().fold(config, ModelConfig::with_override));
+    }
+    #[cfg(feature = "tui")]
+    let dashboard = tui.then(|| start_dashboard(&topic));
+    #[cfg(feature = "tui")]
+    if let Some(ref dashboard) = dashboard {
+        options = options.with_observer(dashboard.observer());
+    }
+    #[cfg(not(feature = "tui"))]
+    if tui {
+        eprintln!("Error: --tui needs the research CLI built with the `tui` feature");
+        std::process::exit(1);
+    }
+    let options = with_telemetry(options);
+    let research_questions: Vec<Question> = questions
+        .iter()
+        .map(|question| Question::from_prompt(question))
+        .collect();
+
+    if dry_run {
+        match research_lib::plan::plan_research(
+            &topic,
             output,
+            &research_questions,
             skill,
             force,
-        } => {
-            // Read topic from stdin if "-" is provided
-            let topic = if topic == "-" {
-                match read_topic_from_stdin() {
-                    Ok(t) if !t.is_empty() => t,
-                    Ok(_) => {
-                        eprintln!("Error: No topic provided on stdin");
-                        std::process::exit(1);
-                    }
-                    Err(e) => {
-                        eprintln!("Error reading from stdin: {}", e);
-                        std::process::exit(1);
-                    }
-                }
-            } else {
-                topic
-            };
+            &options,
+        )
+        .await
+        {
+            Ok(plan) => print!("{:#}", plan),
+            Err(e) => {
+                eprintln!("Research plan failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
 
-            match research(&topic, output, &questions, skill, force).await {
-                Ok(result) => {
-                    println!("\n{}", "=".repeat(60));
-                    if result.cancelled {
-                        println!(
-                            "Cancelled: {} succeeded, {} failed in {:.1}s",
-                            result.succeeded, result.failed, result.total_time_secs
-                        );
-                    } else {
-                        println!(
-                            "Complete: {} succeeded, {} failed in {:.1}s",
-                            result.succeeded, result.failed, result.total_time_secs
-                        );
-                    }
-                    println!(
-                        "Total tokens: {} in, {} out, {} total",
-                        result.total_input_tokens, result.total_output_tokens, result.total_tokens
-                    );
-                    println!("Output: {:?}", result.output_dir);
-                    println!("{}", "=".repeat(60));
+    let result = if resume {
+        let output_dir =
+            output.unwrap_or_else(|| research_lib::default_output_dir_for(kind, &topic));
+        research_lib::resume(&output_dir, &options).await
+    } else if brief {
+        let output_dir =
+            output.unwrap_or_else(|| research_lib::default_output_dir_for(kind, &topic));
+        research_lib::regenerate_brief_in(&topic, &output_dir, &options).await
+    } else {
+        research_with_options(&topic, output, &research_questions, skill, force, &options).await
+    };
+    #[cfg(feature = "tui")]
+    if let Some(dashboard) = dashboard
+        && let Err(e) = dashboard.finish()
+    {
+        eprintln!("Warning: Failed to restore the terminal: {}", e);
+    }
+    flush_telemetry();
 
-                    // Only announce if not cancelled
-                    if !result.cancelled {
-                        use biscuit_speaks::{TtsConfig, speak_when_able};
-                        let message =
-                            format!("Research for the {} library has completed", result.topic);
-                        speak_when_able(&message, &TtsConfig::default()).await;
+    match result {
+        Ok(result) => {
+            println!("\n{}", "=".repeat(60));
+            if result.cancelled {
+                println!(
+                    "Cancelled: {} succeeded, {} failed in {:.1}s",
+                    result.succeeded, result.failed, result.total_time_secs
+                );
+            } else {
+                println!(
+                    "Complete: {} succeeded, {} failed in {:.1}s",
+                    result.succeeded, result.failed, result.total_time_secs
+                );
+            }
+            println!(
+                "Total tokens: {} in, {} out, {} total",
+                result.total_input_tokens, result.total_output_tokens, result.total_tokens
+            );
+            println!("Output: {:?}", result.output_dir);
+            println!("{}", "=".repeat(60));
+
+            if !tags.is_empty() {
+                let texts: Vec<String> = research_questions
+                    .iter()
+                    .map(|question| question.text.clone())
+                    .collect();
+                match research_lib::tags::tag_questions(&result.output_dir, &texts, &tags).await {
+                    Ok(count) => {
+                        println!("Tagged {} question(s): {}", count, tags.join(", "))
                     }
-                }
-                Err(e) => {
-                    eprintln!("Research failed: {}", e);
-                    std::process::exit(1);
+                    Err(e) => eprintln!("Warning: Failed to tag questions: {}", e),
                 }
             }
         }
+        Err(e) => {
+            eprintln!("Research failed: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    // Load environment variables from .env file
+    dotenvy::dotenv().ok();
+
+    let cli = Cli::parse();
+    init_tracing(cli.log_verbosity, cli.json);
+
+    tracing::info!("Research CLI starting");
+
+    match cli.command {
+        Commands::Library(args) => research_topic(ResearchKind::Library, args).await,
+        Commands::Software(args) => research_topic(ResearchKind::Software, args).await,
+        Commands::Standard(args) => research_topic(ResearchKind::Standard, args).await,
 
         Commands::List {
             filters,
             types,
+            tags,
+            categories,
             verbose,
             json,
             migrate,
+            archived,
         } => {
-            match research_lib::list_with_migrate(filters, types, verbose, json, migrate).await {
+            match research_lib::list_with_migrate(
+                filters, types, tags, categories, verbose, json, migrate, archived,
+            )
+            .await
+            {
                 Ok(()) => {
                     // Success - output already written to stdout
                 }
@@ -313,8 +1025,9 @@ async fn main() {
             filters,
             types,
             json,
+            sync,
         } => {
-            match research_lib::link(filters, types, json).await {
+            match research_lib::link_with_sync(filters, types, json, sync).await {
                 Ok(_) => {
                     // Output already printed by library
                 }
@@ -332,12 +1045,52 @@ async fn main() {
             }
         }
 
+        Commands::Tag {
+            topic,
+            add,
+            remove,
+            category,
+            no_category,
+        } => {
+            let Some(dir) = topic_dir(&topic) else {
+                eprintln!(
+                    "Error: Topic '{}' not found. Run 'research list' to see available topics.",
+                    topic
+                );
+                std::process::exit(1);
+            };
+
+            if category.is_some() || no_category {
+                match research_lib::tags::set_topic_category(&dir, category.as_deref()).await {
+                    Ok(Some(category)) => println!("Category: {}", category),
+                    Ok(None) => println!("Category: (none)"),
+                    Err(e) => {
+                        eprintln!("Tagging failed: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+
+            match research_lib::tags::update_topic_tags(&dir, &add, &remove).await {
+                Ok(tags) if tags.is_empty() => println!("Tags: (none)"),
+                Ok(tags) => println!("Tags: {}", tags.join(", ")),
+                Err(e) => {
+                    eprintln!("Tagging failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
         Commands::Api {
             api_name,
             questions,
             output,
             force,
         } => {
+            let questions: Vec<Question> = questions
+                .iter()
+                .map(|question| Question::from_prompt(question))
+                .collect();
             match research_lib::research_api(&api_name, output, &questions, force).await {
                 Ok(result) => {
                     println!("\n{}", "=".repeat(60));
@@ -408,5 +1161,176 @@ async fn main() {
                 }
             }
         }
+
+        Commands::Dedupe {
+            threshold,
+            apply,
+            merge,
+            dry_run,
+            json,
+        } => {
+            if let Err(e) = run_dedupe(threshold, apply, merge, dry_run, json).await {
+                eprintln!("Dedupe failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Archive { topic, json } => match research_lib::archive::archive(&topic) {
+            Ok(report) => print_archive_report(&report, json),
+            Err(e) => {
+                eprintln!("Archive failed: {}", e);
+                std::process::exit(1);
+            }
+        },
+
+        Commands::Unarchive { topic, json } => match research_lib::archive::unarchive(&topic) {
+            Ok(report) => {
+                print_archive_report(&report, json);
+                if !json {
+                    println!("\nRun `research link` to link the restored topic.");
+                }
+            }
+            Err(e) => {
+                eprintln!("Unarchive failed: {}", e);
+                std::process::exit(1);
+            }
+        },
+
+        Commands::Glossary { force, interval } => {
+            use research_lib::glossary::{GlossaryConfig, glossary};
+
+            let refresh_interval =
+                (interval > 0).then(|| std::time::Duration::from_secs(interval * 60 * 60));
+            let config = GlossaryConfig::default()
+                .force(force)
+                .refresh_interval(refresh_interval);
+
+            match glossary(&config).await {
+                Ok(result) if result.skipped => {
+                    println!(
+                        "Glossary is up to date (refreshed within {}h): {}",
+                        interval,
+                        result.path.display()
+                    );
+                }
+                Ok(result) => {
+                    println!(
+                        "Glossary: {} terms from {} topics ({} extracted, {} failed)",
+                        result.terms, result.topics, result.extracted, result.failed
+                    );
+                    println!("  {}", result.path.display());
+                }
+                Err(e) => {
+                    eprintln!("Glossary failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::Search { query, limit, json } => {
+            use research_lib::search::{SearchOptions, search_with};
+
+            let query = query.join(" ");
+            match search_with(&SearchOptions::default().limit(limit), &query) {
+                Ok(hits) if json => match serde_json::to_string_pretty(&hits) {
+                    Ok(output) => println!("{}", output),
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                },
+                Ok(hits) if hits.is_empty() => println!("No matches for '{}'", query),
+                Ok(hits) => {
+                    for hit in hits {
+                        println!("{}/{} ({:.2})", hit.topic, hit.file.display(), hit.score);
+                        if !hit.snippet.is_empty() {
+                            println!("    {}", hit.snippet);
+                        }
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Search failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::Export {
+            topic,
+            format,
+            output,
+        } => {
+            let Some(dir) = topic_dir(&topic) else {
+                eprintln!(
+                    "Error: Topic '{}' not found. Run 'research list' to see available topics.",
+                    topic
+                );
+                std::process::exit(1);
+            };
+
+            match research_lib::export::export(&dir, format, output.as_deref()).await {
+                Ok(path) => println!("Report: {}", path.display()),
+                Err(e) => {
+                    eprintln!("Export failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::Diff { topic, old, json } => {
+            let Some(dir) = topic_dir(&topic) else {
+                eprintln!(
+                    "Error: Topic '{}' not found. Run 'research list' to see available topics.",
+                    topic
+                );
+                std::process::exit(1);
+            };
+            let name = dir
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or(topic);
+
+            match research_lib::diff::research_diff(&name, &old, &dir).await {
+                Ok(diff) if json => match serde_json::to_string_pretty(&diff) {
+                    Ok(output) => println!("{}", output),
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                },
+                Ok(diff) => print!("{}", diff),
+                Err(e) => {
+                    eprintln!("Diff failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::Refresh {
+            topics,
+            max_age,
+            batch,
+            dry_run,
+        } => {
+            if let Err(e) = run_refresh(&topics, max_age, batch, dry_run).await {
+                eprintln!("Refresh failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Schedule { topic, every } => {
+            match research_lib::refresh::schedule_refresh(&topic, every) {
+                Ok(task) => println!(
+                    "Scheduled '{}' ({}, task {}); first refresh at {}",
+                    topic,
+                    every,
+                    task.id,
+                    task.scheduled_at.format("%Y-%m-%d %H:%M UTC")
+                ),
+                Err(e) => {
+                    eprintln!("Schedule failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,446 @@
+//! Archiving of research topics that are no longer actively used.
+//!
+//! [`archive`] moves a topic from its kind directory (e.g. `library/`) into
+//! `.research/archive/`, records when and where from in `metadata.json`, and
+//! removes the skill/doc symlinks pointing at it. Archived topics are left
+//! out of `research list` and `research link`, but nothing is deleted:
+//! [`unarchive`] moves the topic back to where it came from.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::ResearchKind;
+
+/// Name of the directory under `.research` that holds archived topics.
+pub const ARCHIVE_DIR: &str = "archive";
+
+/// Errors that can occur while archiving or restoring a topic.
+#[derive(Error, Debug)]
+pub enum ArchiveError {
+    #[error("Topic not found: {0}")]
+    TopicNotFound(String),
+
+    #[error("Topic '{0}' is already archived")]
+    AlreadyArchived(String),
+
+    #[error("Topic '{0}' is not archived")]
+    NotArchived(String),
+
+    #[error("Cannot move topic, destination already exists: {0}")]
+    DestinationExists(PathBuf),
+
+    #[error("Invalid metadata.json for '{topic}': {message}")]
+    InvalidMetadata { topic: String, message: String },
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// What archiving or restoring a topic did.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArchiveReport {
+    /// The topic that was moved
+    pub topic: String,
+    /// The kind directory the topic belongs to (e.g. `library`)
+    pub kind: String,
+    /// Where the topic was before the move
+    pub from: PathBuf,
+    /// Where the topic is now
+    pub to: PathBuf,
+    /// When the topic was archived (`None` once restored)
+    pub archived_at: Option<DateTime<Utc>>,
+    /// Skill/doc symlinks pointing into the topic that were removed
+    pub removed_links: Vec<PathBuf>,
+}
+
+impl ArchiveReport {
+    /// Renders the report for the terminal.
+    pub fn render(&self) -> String {
+        let mut out = if self.archived_at.is_some() {
+            format!(
+                "Archived '{}' ({}/ -> {}/)\n",
+                self.topic, self.kind, ARCHIVE_DIR
+            )
+        } else {
+            format!(
+                "Restored '{}' ({}/ -> {}/)\n",
+                self.topic, ARCHIVE_DIR, self.kind
+            )
+        };
+        for link in &self.removed_links {
+            out.push_str(&format!("  - unlink {}\n", link.display()));
+        }
+        out
+    }
+}
+
+/// Archives a topic in the default research library.
+///
+/// Uses [`default_research_root`](crate::tags::default_research_root) and
+/// removes symlinks from the skill and doc directories managed by
+/// `research link`.
+///
+/// ## Errors
+///
+/// See [`archive_in`].
+pub fn archive(topic: &str) -> Result<ArchiveReport, ArchiveError> {
+    archive_in(
+        &crate::tags::default_research_root(),
+        topic,
+        &crate::dedupe::default_link_dirs(),
+    )
+}
+
+/// Restores an archived topic in the default research library.
+///
+/// ## Errors
+///
+/// See [`unarchive_in`].
+pub fn unarchive(topic: &str) -> Result<ArchiveReport, ArchiveError> {
+    unarchive_in(&crate::tags::default_research_root(), topic)
+}
+
+/// Moves `topic` from its kind directory under `research_root` into
+/// `research_root/archive/`.
+///
+/// - Symlinks in `link_dirs` that point into the topic are removed.
+/// - `archived_at` and `archived_from` are set in `metadata.json`.
+/// - Tag indexes are refreshed so the topic's questions drop out of them.
+///
+/// ## Errors
+///
+/// Returns an error if the topic doesn't exist, is already archived, an
+/// archived topic of the same name exists, its `metadata.json` is invalid,
+/// or a filesystem operation fails.
+pub fn archive_in(
+    research_root: &Path,
+    topic: &str,
+    link_dirs: &[PathBuf],
+) -> Result<ArchiveReport, ArchiveError> {
+    let archive_dir = research_root.join(ARCHIVE_DIR);
+    let Some((kind, from)) = find_active_topic(research_root, topic) else {
+        if is_topic_name(topic) && archive_dir.join(topic).is_dir() {
+            return Err(ArchiveError::AlreadyArchived(topic.to_string()));
+        }
+        return Err(ArchiveError::TopicNotFound(topic.to_string()));
+    };
+
+    let to = archive_dir.join(topic);
+    if to.exists() {
+        return Err(ArchiveError::DestinationExists(to));
+    }
+
+    let mut metadata = read_metadata(topic, &from)?;
+    let archived_at = Utc::now();
+    if let Some(obj) = metadata.as_object_mut() {
+        obj.insert(
+            "archived_at".to_string(),
+            Value::String(archived_at.to_rfc3339()),
+        );
+        obj.insert(
+            "archived_from".to_string(),
+            Value::String(kind.dir_name().to_string()),
+        );
+    }
+
+    // Links must be found before the move, while their targets still resolve
+    let removed_links = crate::dedupe::links_into(&from, link_dirs);
+    for link in &removed_links {
+        std::fs::remove_file(link)?;
+    }
+
+    std::fs::create_dir_all(&archive_dir)?;
+    std::fs::rename(&from, &to)?;
+    write_metadata(topic, &to, &metadata)?;
+    info!("Archived topic '{}' from {}/", topic, kind.dir_name());
+
+    refresh_tag_indexes(research_root);
+
+    Ok(ArchiveReport {
+        topic: topic.to_string(),
+        kind: kind.dir_name().to_string(),
+        from,
+        to,
+        archived_at: Some(archived_at),
+        removed_links,
+    })
+}
+
+/// Moves `topic` from `research_root/archive/` back to the kind directory it
+/// was archived from.
+///
+/// The kind comes from `archived_from` in `metadata.json`, falling back to
+/// the topic's `kind` and then to `library`. `archived_at` and
+/// `archived_from` are removed from the metadata. Skill symlinks are not
+/// recreated; run `research link` afterwards.
+///
+/// ## Errors
+///
+/// Returns an error if the topic isn't archived, a topic of the same name
+/// already exists in the kind directory, its `metadata.json` is invalid, or
+/// a filesystem operation fails.
+pub fn unarchive_in(research_root: &Path, topic: &str) -> Result<ArchiveReport, ArchiveError> {
+    let from = research_root.join(ARCHIVE_DIR).join(topic);
+    if !is_topic_name(topic) || !from.is_dir() {
+        if find_active_topic(research_root, topic).is_some() {
+            return Err(ArchiveError::NotArchived(topic.to_string()));
+        }
+        return Err(ArchiveError::TopicNotFound(topic.to_string()));
+    }
+
+    let mut metadata = read_metadata(topic, &from)?;
+    let kind = ["archived_from", "kind"]
+        .into_iter()
+        .filter_map(|field| metadata.get(field).and_then(Value::as_str))
+        .find_map(topic_kind)
+        .unwrap_or(ResearchKind::Library);
+
+    let kind_dir = research_root.join(kind.dir_name());
+    let to = kind_dir.join(topic);
+    if to.exists() {
+        return Err(ArchiveError::DestinationExists(to));
+    }
+
+    if let Some(obj) = metadata.as_object_mut() {
+        obj.remove("archived_at");
+        obj.remove("archived_from");
+    }
+
+    std::fs::create_dir_all(&kind_dir)?;
+    std::fs::rename(&from, &to)?;
+    write_metadata(topic, &to, &metadata)?;
+    info!("Restored topic '{}' to {}/", topic, kind.dir_name());
+
+    refresh_tag_indexes(research_root);
+
+    Ok(ArchiveReport {
+        topic: topic.to_string(),
+        kind: kind.dir_name().to_string(),
+        from,
+        to,
+        archived_at: None,
+        removed_links: Vec::new(),
+    })
+}
+
+/// Finds a non-archived topic, returning its kind and directory.
+fn find_active_topic(research_root: &Path, topic: &str) -> Option<(ResearchKind, PathBuf)> {
+    if !is_topic_name(topic) {
+        return None;
+    }
+    ResearchKind::TOPIC_KINDS.into_iter().find_map(|kind| {
+        let dir = research_root.join(kind.dir_name()).join(topic);
+        dir.is_dir().then_some((kind, dir))
+    })
+}
+
+/// Whether `topic` is a plain directory name (no separators or `..`).
+fn is_topic_name(topic: &str) -> bool {
+    !topic.is_empty() && topic != "." && topic != ".." && !topic.contains(['/', '\\'])
+}
+
+/// Maps a kind directory name to a topic kind.
+fn topic_kind(name: &str) -> Option<ResearchKind> {
+    ResearchKind::TOPIC_KINDS
+        .into_iter()
+        .find(|kind| kind.dir_name() == name)
+}
+
+/// Reads a topic's `metadata.json` as raw JSON so that unknown fields and
+/// older schema versions are preserved.
+fn read_metadata(topic: &str, dir: &Path) -> Result<Value, ArchiveError> {
+    let path = dir.join("metadata.json");
+    if !path.exists() {
+        return Ok(Value::Object(Default::default()));
+    }
+
+    let content = std::fs::read_to_string(&path)?;
+    let value: Value =
+        serde_json::from_str(&content).map_err(|e| ArchiveError::InvalidMetadata {
+            topic: topic.to_string(),
+            message: e.to_string(),
+        })?;
+    if !value.is_object() {
+        return Err(ArchiveError::InvalidMetadata {
+            topic: topic.to_string(),
+            message: "expected a JSON object".to_string(),
+        });
+    }
+    Ok(value)
+}
+
+/// Writes a topic's `metadata.json`.
+fn write_metadata(topic: &str, dir: &Path, metadata: &Value) -> Result<(), ArchiveError> {
+    let content =
+        serde_json::to_string_pretty(metadata).map_err(|e| ArchiveError::InvalidMetadata {
+            topic: topic.to_string(),
+            message: e.to_string(),
+        })?;
+    std::fs::write(dir.join("metadata.json"), content)?;
+    Ok(())
+}
+
+/// Rewrites the tag indexes, logging rather than failing on errors.
+fn refresh_tag_indexes(research_root: &Path) {
+    if let Err(e) = crate::tags::write_tag_indexes(research_root) {
+        warn!("Failed to refresh tag indexes: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_topic(research_root: &Path, kind: &str, topic: &str, metadata: &str) -> PathBuf {
+        let dir = research_root.join(kind).join(topic);
+        std::fs::create_dir_all(dir.join("skill")).unwrap();
+        std::fs::write(dir.join("metadata.json"), metadata).unwrap();
+        std::fs::write(dir.join("skill/SKILL.md"), "# Skill\n").unwrap();
+        dir
+    }
+
+    fn read_json(path: &Path) -> Value {
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_archive_moves_topic_and_stamps_metadata() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write_topic(
+            root,
+            "software",
+            "htop",
+            r#"{"kind": "software", "brief": "Top"}"#,
+        );
+
+        let report = archive_in(root, "htop", &[]).unwrap();
+
+        assert_eq!(report.kind, "software");
+        assert_eq!(report.to, root.join("archive/htop"));
+        assert!(!root.join("software/htop").exists());
+        assert!(root.join("archive/htop/skill/SKILL.md").is_file());
+
+        let metadata = read_json(&root.join("archive/htop/metadata.json"));
+        assert_eq!(metadata["brief"], "Top");
+        assert_eq!(metadata["archived_from"], "software");
+        let archived_at = metadata["archived_at"].as_str().unwrap();
+        assert_eq!(
+            DateTime::parse_from_rfc3339(archived_at).unwrap(),
+            report.archived_at.unwrap()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_archive_removes_skill_links() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().join(".research");
+        let skills = temp.path().join("skills");
+        std::fs::create_dir_all(&skills).unwrap();
+        let tokio = write_topic(&root, "library", "tokio", "{}");
+        let serde = write_topic(&root, "library", "serde", "{}");
+        std::os::unix::fs::symlink(tokio.join("skill"), skills.join("tokio")).unwrap();
+        std::os::unix::fs::symlink(serde.join("skill"), skills.join("serde")).unwrap();
+
+        let report = archive_in(&root, "tokio", std::slice::from_ref(&skills)).unwrap();
+
+        assert_eq!(report.removed_links, vec![skills.join("tokio")]);
+        assert!(skills.join("tokio").symlink_metadata().is_err());
+        assert!(skills.join("serde").exists());
+    }
+
+    #[test]
+    fn test_unarchive_restores_original_kind() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write_topic(root, "standard", "oauth2", r#"{"kind": "standard"}"#);
+        archive_in(root, "oauth2", &[]).unwrap();
+
+        let report = unarchive_in(root, "oauth2").unwrap();
+
+        assert_eq!(report.kind, "standard");
+        assert_eq!(report.archived_at, None);
+        assert!(root.join("standard/oauth2").is_dir());
+        assert!(!root.join("archive/oauth2").exists());
+
+        let metadata = read_json(&root.join("standard/oauth2/metadata.json"));
+        assert_eq!(metadata.get("archived_at"), None);
+        assert_eq!(metadata.get("archived_from"), None);
+        assert_eq!(metadata["kind"], "standard");
+    }
+
+    #[test]
+    fn test_unarchive_falls_back_to_kind_then_library() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write_topic(root, "archive", "nginx", r#"{"kind": "software"}"#);
+        write_topic(root, "archive", "clap", "{}");
+
+        assert_eq!(unarchive_in(root, "nginx").unwrap().kind, "software");
+        assert_eq!(unarchive_in(root, "clap").unwrap().kind, "library");
+        assert!(root.join("library/clap").is_dir());
+    }
+
+    #[test]
+    fn test_archive_errors() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write_topic(root, "library", "tokio", "{}");
+        write_topic(root, "library", "serde", "{}");
+        write_topic(root, "archive", "serde", "{}");
+        write_topic(root, "library", "broken", "[1, 2]");
+
+        assert!(matches!(
+            archive_in(root, "missing", &[]),
+            Err(ArchiveError::TopicNotFound(_))
+        ));
+        assert!(matches!(
+            archive_in(root, "../library/tokio", &[]),
+            Err(ArchiveError::TopicNotFound(_))
+        ));
+        assert!(matches!(
+            archive_in(root, "serde", &[]),
+            Err(ArchiveError::DestinationExists(_))
+        ));
+        assert!(matches!(
+            archive_in(root, "broken", &[]),
+            Err(ArchiveError::InvalidMetadata { .. })
+        ));
+        assert!(root.join("library/broken").is_dir());
+
+        archive_in(root, "tokio", &[]).unwrap();
+        assert!(matches!(
+            archive_in(root, "tokio", &[]),
+            Err(ArchiveError::AlreadyArchived(_))
+        ));
+    }
+
+    #[test]
+    fn test_unarchive_errors() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write_topic(root, "library", "tokio", "{}");
+        write_topic(root, "archive", "tokio", "{}");
+        write_topic(root, "library", "serde", "{}");
+
+        assert!(matches!(
+            unarchive_in(root, "missing"),
+            Err(ArchiveError::TopicNotFound(_))
+        ));
+        assert!(matches!(
+            unarchive_in(root, "serde"),
+            Err(ArchiveError::NotArchived(_))
+        ));
+        assert!(matches!(
+            unarchive_in(root, "tokio"),
+            Err(ArchiveError::DestinationExists(_))
+        ));
+    }
+}
@@ -0,0 +1,391 @@
+//! Checkpoints for resuming interrupted research runs.
+//!
+//! A full research run exits as soon as it receives SIGINT, and `metadata.json`
+//! is only written once Phase 2 finishes, so running the topic again would
+//! start over. While it runs, a full research run keeps a [`Checkpoint`] in
+//! `.research-state.json` in the output directory. The checkpoint records the
+//! decisions made before the first prompt (the selected library, the
+//! ecosystem, the questions and any repository context) and the outcome and token usage of each task
+//! as it finishes. It is deleted once the run completes.
+//!
+//! [`resume`](crate::resume) reads the checkpoint and continues the run:
+//! Phase 1 tasks that completed are kept, the rest run again, and Phase 2
+//! runs from the full set of Phase 1 documents.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::ecosystem::Ecosystem;
+use crate::observer::{PhaseSummary, ResearchObserver, TaskOutcome, TaskReport, TaskStage};
+use crate::question::Question;
+use crate::{LibraryInfo, PromptMetrics, ResearchKind};
+
+/// Name of the checkpoint file in a topic's output directory.
+pub const CHECKPOINT_FILE: &str = ".research-state.json";
+
+/// How a checkpointed task ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskState {
+    Completed,
+    Failed,
+}
+
+/// The recorded outcome of one task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskCheckpoint {
+    pub state: TaskState,
+    /// Why the task failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub input_tokens: u64,
+    #[serde(default)]
+    pub output_tokens: u64,
+    #[serde(default)]
+    pub total_tokens: u64,
+}
+
+/// The state of an interrupted research run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub topic: String,
+    pub kind: ResearchKind,
+    /// The package selected for the topic, so resuming doesn't ask again
+    pub library: Option<LibraryInfo>,
+    /// Whose library prompt variants the run uses
+    #[serde(default)]
+    pub ecosystem: Ecosystem,
+    /// Additional questions; question N is written to `question_N.md`
+    /// unless it names its own file. Checkpoints from before questions had
+    /// settings list them as plain strings, which still load.
+    #[serde(default)]
+    pub questions: Vec<Question>,
+    /// The repository context added to the prompts, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository_context: Option<String>,
+    /// Finished tasks by name (`overview`, `question_2`, ...); tasks that
+    /// haven't finished are absent
+    #[serde(default)]
+    pub tasks: BTreeMap<String, TaskCheckpoint>,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Checkpoint {
+    /// Starts a checkpoint for a run with no finished tasks.
+    pub fn new(
+        topic: &str,
+        kind: ResearchKind,
+        library: Option<LibraryInfo>,
+        ecosystem: Ecosystem,
+        questions: Vec<Question>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            topic: topic.to_string(),
+            kind,
+            library,
+            ecosystem,
+            questions,
+            repository_context: None,
+            tasks: BTreeMap::new(),
+            started_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Returns the checkpoint file's path in `output_dir`.
+    pub fn path(output_dir: &Path) -> PathBuf {
+        output_dir.join(CHECKPOINT_FILE)
+    }
+
+    /// Loads the checkpoint in `output_dir`, if there is a readable one.
+    pub fn load(output_dir: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(Self::path(output_dir)).ok()?;
+        serde_json::from_str(&content)
+            .inspect_err(|e| warn!(error = %e, "Ignoring unreadable research checkpoint"))
+            .ok()
+    }
+
+    /// Writes the checkpoint to `output_dir`.
+    ///
+    /// The file is small and written from observer callbacks, so this is
+    /// synchronous.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, output_dir: &Path) -> std::io::Result<()> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        // Write then rename, so a run killed mid-write leaves the old checkpoint
+        let path = Self::path(output_dir);
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, content)?;
+        std::fs::rename(&tmp, &path)
+    }
+
+    /// Deletes the checkpoint in `output_dir`, if any.
+    pub fn remove(output_dir: &Path) {
+        let path = Self::path(output_dir);
+        if let Err(e) = std::fs::remove_file(&path)
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            warn!(error = %e, path = %path.display(), "Failed to remove research checkpoint");
+        }
+    }
+
+    /// Returns true if `task` completed.
+    pub fn is_completed(&self, task: &str) -> bool {
+        self.tasks
+            .get(task)
+            .is_some_and(|t| t.state == TaskState::Completed)
+    }
+
+    /// Returns the token usage of `task`, if it completed.
+    pub fn metrics(&self, task: &str) -> Option<PromptMetrics> {
+        self.tasks
+            .get(task)
+            .filter(|t| t.state == TaskState::Completed)
+            .map(|t| PromptMetrics {
+                input_tokens: t.input_tokens,
+                output_tokens: t.output_tokens,
+                total_tokens: t.total_tokens,
+                elapsed_secs: 0.0,
+            })
+    }
+
+    /// Records how `task` ended; cancelled tasks are left unfinished.
+    pub fn record(&mut self, task: &str, outcome: &TaskOutcome) {
+        let entry = match outcome {
+            TaskOutcome::Succeeded { metrics } => {
+                let metrics = metrics.clone().unwrap_or_default();
+                TaskCheckpoint {
+                    state: TaskState::Completed,
+                    error: None,
+                    input_tokens: metrics.input_tokens,
+                    output_tokens: metrics.output_tokens,
+                    total_tokens: metrics.total_tokens,
+                }
+            }
+            TaskOutcome::Recovered => TaskCheckpoint {
+                state: TaskState::Completed,
+                error: None,
+                input_tokens: 0,
+                output_tokens: 0,
+                total_tokens: 0,
+            },
+            TaskOutcome::Failed { error } => TaskCheckpoint {
+                state: TaskState::Failed,
+                error: Some(error.clone()),
+                input_tokens: 0,
+                output_tokens: 0,
+                total_tokens: 0,
+            },
+            TaskOutcome::Cancelled { .. } => return,
+        };
+        self.tasks.insert(task.to_string(), entry);
+        self.updated_at = Utc::now();
+    }
+}
+
+/// Forwards events to another observer, recording finished tasks in a
+/// checkpoint that is saved after every task.
+pub(crate) struct CheckpointRecorder {
+    inner: Arc<dyn ResearchObserver>,
+    checkpoint: Mutex<Checkpoint>,
+    output_dir: PathBuf,
+}
+
+impl CheckpointRecorder {
+    /// Saves `checkpoint` to `output_dir` and starts recording into it.
+    pub(crate) fn start(
+        inner: Arc<dyn ResearchObserver>,
+        checkpoint: Checkpoint,
+        output_dir: &Path,
+    ) -> Self {
+        let recorder = Self {
+            inner,
+            checkpoint: Mutex::new(checkpoint),
+            output_dir: output_dir.to_path_buf(),
+        };
+        {
+            let checkpoint = recorder
+                .checkpoint
+                .lock()
+                .expect("checkpoint lock poisoned");
+            recorder.save(&checkpoint);
+        }
+        recorder
+    }
+
+    /// Returns the token usage of `task` if it completed in an earlier run
+    /// and its output file is still there.
+    pub(crate) fn restored(&self, task: &str, filename: &str) -> Option<PromptMetrics> {
+        if !self.output_dir.join(filename).exists() {
+            return None;
+        }
+        self.checkpoint
+            .lock()
+            .expect("checkpoint lock poisoned")
+            .metrics(task)
+    }
+
+    /// Deletes the checkpoint once the run has finished.
+    pub(crate) fn finish(&self) {
+        Checkpoint::remove(&self.output_dir);
+    }
+
+    fn save(&self, checkpoint: &Checkpoint) {
+        if let Err(e) = checkpoint.save(&self.output_dir) {
+            self.inner
+                .on_warning(&format!("Failed to write research checkpoint: {}", e));
+        }
+    }
+}
+
+impl ResearchObserver for CheckpointRecorder {
+    fn on_task_start(&self, task: &str, stage: &TaskStage) {
+        self.inner.on_task_start(task, stage);
+    }
+
+    fn on_task_complete(&self, report: &TaskReport) {
+        {
+            let mut checkpoint = self.checkpoint.lock().expect("checkpoint lock poisoned");
+            checkpoint.record(&report.task, &report.outcome);
+            self.save(&checkpoint);
+        }
+        self.inner.on_task_complete(report);
+    }
+
+    fn on_tool_call(&self, task: &str, tool: &str) {
+        self.inner.on_tool_call(task, tool);
+    }
+
+    fn on_token_usage(&self, task: &str, usage: &PromptMetrics) {
+        self.inner.on_token_usage(task, usage);
+    }
+
+    fn on_phase_complete(&self, summary: &PhaseSummary) {
+        self.inner.on_phase_complete(summary);
+    }
+
+    fn on_warning(&self, message: &str) {
+        self.inner.on_warning(message);
+    }
+
+    fn on_info(&self, message: &str) {
+        self.inner.on_info(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn succeeded(tokens: u64) -> TaskOutcome {
+        TaskOutcome::Succeeded {
+            metrics: Some(PromptMetrics {
+                input_tokens: tokens,
+                output_tokens: tokens,
+                total_tokens: tokens * 2,
+                elapsed_secs: 1.0,
+            }),
+        }
+    }
+
+    #[test]
+    fn records_outcomes_and_skips_cancelled_tasks() {
+        let mut checkpoint =
+            Checkpoint::new("clap", ResearchKind::Library, None, Ecosystem::Rust, vec![]);
+        checkpoint.record("overview", &succeeded(10));
+        checkpoint.record(
+            "changelog",
+            &TaskOutcome::Failed {
+                error: "timeout".to_string(),
+            },
+        );
+        checkpoint.record(
+            "use_cases",
+            &TaskOutcome::Cancelled {
+                partial_output_kept: false,
+            },
+        );
+
+        assert!(checkpoint.is_completed("overview"));
+        assert_eq!(checkpoint.metrics("overview").unwrap().total_tokens, 20);
+        assert!(!checkpoint.is_completed("changelog"));
+        assert!(checkpoint.metrics("changelog").is_none());
+        assert!(!checkpoint.tasks.contains_key("use_cases"));
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir = tempdir().unwrap();
+        let mut checkpoint = Checkpoint::new(
+            "requests",
+            ResearchKind::Library,
+            None,
+            Ecosystem::Python,
+            vec![
+                Question::from("How do sessions work?"),
+                Question::new("Is there async support?")
+                    .with_tools(false)
+                    .with_filename("async"),
+            ],
+        );
+        checkpoint.record("question_1", &succeeded(5));
+        checkpoint.save(dir.path()).unwrap();
+
+        let loaded = Checkpoint::load(dir.path()).unwrap();
+        assert_eq!(loaded.topic, "requests");
+        assert_eq!(loaded.ecosystem, Ecosystem::Python);
+        assert_eq!(loaded.questions, checkpoint.questions);
+        assert_eq!(loaded.tasks, checkpoint.tasks);
+
+        Checkpoint::remove(dir.path());
+        assert!(Checkpoint::load(dir.path()).is_none());
+    }
+
+    #[test]
+    fn recorder_saves_after_every_task_and_restores_only_existing_outputs() {
+        let dir = tempdir().unwrap();
+        let checkpoint =
+            Checkpoint::new("clap", ResearchKind::Library, None, Ecosystem::Rust, vec![]);
+        let recorder = CheckpointRecorder::start(
+            Arc::new(crate::observer::ConsoleObserver),
+            checkpoint,
+            dir.path(),
+        );
+        assert!(Checkpoint::load(dir.path()).is_some());
+
+        for task in ["overview", "use_cases"] {
+            recorder.on_task_complete(&TaskReport {
+                task: task.to_string(),
+                completed: 1,
+                total: 2,
+                elapsed_secs: 1.0,
+                outcome: succeeded(3),
+            });
+        }
+        assert!(
+            Checkpoint::load(dir.path())
+                .unwrap()
+                .is_completed("use_cases")
+        );
+
+        std::fs::write(dir.path().join("overview.md"), "# clap").unwrap();
+        assert!(recorder.restored("overview", "overview.md").is_some());
+        assert!(recorder.restored("use_cases", "use_cases.md").is_none());
+
+        recorder.finish();
+        assert!(Checkpoint::load(dir.path()).is_none());
+    }
+}
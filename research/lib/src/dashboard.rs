@@ -0,0 +1,634 @@
+//! Live terminal dashboard for research runs (requires the `tui` feature).
+//!
+//! With eight or more tasks running in parallel, the line-per-event output of
+//! [`ConsoleObserver`](crate::observer::ConsoleObserver) interleaves into an
+//! unreadable log. [`Dashboard`] instead redraws a table with one row per
+//! Phase 1 and Phase 2 task, showing its status, elapsed time, token counts
+//! and tool calls as they happen. Agent tasks report tokens and tool calls
+//! after every turn through their [`TracingPromptHook`](crate::TracingPromptHook);
+//! other tasks report their tokens when they finish.
+//!
+//! The dashboard draws on the alternate screen once the first task starts, so
+//! interactive prompts before that (e.g. picking a package manager) still
+//! work. Raw mode is left off, so Ctrl-C cancels the run as usual. When the
+//! run ends, [`Dashboard::finish`] restores the screen and prints the final
+//! table to stdout.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use research_lib::dashboard::Dashboard;
+//! use research_lib::{ResearchOptions, research_with_options};
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let dashboard = Dashboard::start("clap")?;
+//! let options = ResearchOptions::default().with_observer(dashboard.observer());
+//! let result = research_with_options("clap", None, &[], false, false, &options).await;
+//! dashboard.finish()?;
+//! println!("{} tasks succeeded", result?.succeeded);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::io::{self, Stdout};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crossterm::{cursor, execute, terminal};
+use ratatui::Frame;
+use ratatui::Terminal;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table};
+
+use crate::PromptMetrics;
+use crate::observer::{
+    PhaseSummary, ResearchObserver, ResearchPhase, TaskOutcome, TaskReport, TaskStage,
+};
+
+/// How often the dashboard is redrawn.
+const REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Status and warning messages kept below the table.
+const MAX_MESSAGES: usize = 5;
+
+/// Where a task on the dashboard is.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskStatus {
+    /// Running; `stage` describes what it is doing.
+    Running { stage: String },
+    /// The output file was written.
+    Succeeded,
+    /// The agent ran out of tool calls and its output was synthesized.
+    Recovered,
+    /// The task failed.
+    Failed { error: String },
+    /// The run was cancelled while the task was in progress.
+    Cancelled,
+}
+
+impl TaskStatus {
+    fn label(&self) -> String {
+        match self {
+            Self::Running { stage } => format!("⋯ {}", stage),
+            Self::Succeeded => "✓ done".to_string(),
+            Self::Recovered => "✓ recovered".to_string(),
+            Self::Failed { error } => format!("✗ {}", error),
+            Self::Cancelled => "– cancelled".to_string(),
+        }
+    }
+
+    fn style(&self) -> Style {
+        match self {
+            Self::Running { .. } => Style::default().fg(Color::Yellow),
+            Self::Succeeded | Self::Recovered => Style::default().fg(Color::Green),
+            Self::Failed { .. } => Style::default().fg(Color::Red),
+            Self::Cancelled => Style::default().fg(Color::DarkGray),
+        }
+    }
+}
+
+/// One task's row on the dashboard.
+#[derive(Debug, Clone)]
+pub struct TaskRow {
+    /// Task name (e.g. `overview`, `question_2`).
+    pub task: String,
+    /// The phase the task runs in.
+    pub phase: ResearchPhase,
+    /// Where the task is.
+    pub status: TaskStatus,
+    /// When the task first reported a start.
+    pub started: Instant,
+    /// How long the task took, once it finished.
+    pub finished_after: Option<Duration>,
+    /// Tokens used so far.
+    pub usage: PromptMetrics,
+    /// Tools the task has called.
+    pub tool_calls: usize,
+}
+
+impl TaskRow {
+    /// Time since the task started, or its total run time once finished.
+    pub fn elapsed(&self) -> Duration {
+        self.finished_after
+            .unwrap_or_else(|| self.started.elapsed())
+    }
+}
+
+/// Everything the dashboard shows, built up from observer events.
+#[derive(Debug, Clone)]
+pub struct DashboardState {
+    title: String,
+    phase: ResearchPhase,
+    rows: Vec<TaskRow>,
+    phases: Vec<PhaseSummary>,
+    messages: VecDeque<String>,
+}
+
+impl DashboardState {
+    /// Creates an empty dashboard titled with the research topic.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            phase: ResearchPhase::Research,
+            rows: Vec::new(),
+            phases: Vec::new(),
+            messages: VecDeque::new(),
+        }
+    }
+
+    /// The tasks in the order they started.
+    pub fn rows(&self) -> &[TaskRow] {
+        &self.rows
+    }
+
+    /// The phases that have finished.
+    pub fn phases(&self) -> &[PhaseSummary] {
+        &self.phases
+    }
+
+    /// The most recent status and warning messages, oldest first.
+    pub fn messages(&self) -> impl Iterator<Item = &str> {
+        self.messages.iter().map(String::as_str)
+    }
+
+    fn row_mut(&mut self, task: &str) -> Option<&mut TaskRow> {
+        // A task name can repeat across phases; the latest row is the live one
+        self.rows.iter_mut().rev().find(|row| row.task == task)
+    }
+
+    /// Records that `task` started or moved on to a new stage.
+    pub fn task_started(&mut self, task: &str, stage: &TaskStage) {
+        let stage = match stage {
+            TaskStage::Prompt => "prompting".to_string(),
+            TaskStage::AgentPrompt => "researching".to_string(),
+            TaskStage::Streaming { .. } => "streaming".to_string(),
+            TaskStage::AggregatingHistory => "collecting history".to_string(),
+            TaskStage::Generating => "generating".to_string(),
+        };
+        let phase = self.phase;
+        match self.row_mut(task) {
+            Some(row) if row.phase == phase && row.finished_after.is_none() => {
+                row.status = TaskStatus::Running { stage };
+            }
+            _ => self.rows.push(TaskRow {
+                task: task.to_string(),
+                phase,
+                status: TaskStatus::Running { stage },
+                started: Instant::now(),
+                finished_after: None,
+                usage: PromptMetrics::default(),
+                tool_calls: 0,
+            }),
+        }
+    }
+
+    /// Records a finished task.
+    pub fn task_completed(&mut self, report: &TaskReport) {
+        let Some(row) = self.row_mut(&report.task) else {
+            return;
+        };
+        row.finished_after = Some(row.started.elapsed());
+        row.status = match &report.outcome {
+            TaskOutcome::Succeeded { metrics } => {
+                if let Some(metrics) = metrics {
+                    row.usage = metrics.clone();
+                }
+                TaskStatus::Succeeded
+            }
+            TaskOutcome::Recovered => TaskStatus::Recovered,
+            TaskOutcome::Failed { error } => TaskStatus::Failed {
+                error: error.clone(),
+            },
+            TaskOutcome::Cancelled { .. } => TaskStatus::Cancelled,
+        };
+    }
+
+    /// Counts a tool call made by `task`.
+    pub fn tool_called(&mut self, task: &str) {
+        if let Some(row) = self.row_mut(task) {
+            row.tool_calls += 1;
+        }
+    }
+
+    /// Updates the running token totals of `task`.
+    pub fn usage_updated(&mut self, task: &str, usage: &PromptMetrics) {
+        if let Some(row) = self.row_mut(task) {
+            row.usage = usage.clone();
+        }
+    }
+
+    /// Records a finished phase; later tasks belong to the next phase.
+    pub fn phase_completed(&mut self, summary: &PhaseSummary) {
+        if summary.phase == ResearchPhase::Research {
+            self.phase = ResearchPhase::Synthesis;
+        }
+        self.phases.push(summary.clone());
+    }
+
+    /// Adds a status or warning message, dropping the oldest beyond five.
+    pub fn message(&mut self, message: &str) {
+        let message = message.trim();
+        if message.is_empty() {
+            return;
+        }
+        if self.messages.len() == MAX_MESSAGES {
+            self.messages.pop_front();
+        }
+        self.messages.push_back(message.to_string());
+    }
+
+    fn header(&self) -> String {
+        let in_phase = self.rows.iter().filter(|row| row.phase == self.phase);
+        let (done, total) = in_phase.fold((0, 0), |(done, total), row| {
+            (done + usize::from(row.finished_after.is_some()), total + 1)
+        });
+        let tokens: u64 = self.rows.iter().map(|row| row.usage.total_tokens).sum();
+        format!(
+            "Researching {} | Phase {}: {}/{} done | {} tokens",
+            self.title,
+            self.phase.number(),
+            done,
+            total,
+            tokens
+        )
+    }
+
+    fn table(&self) -> Table<'_> {
+        let header = Row::new(["Phase", "Task", "Status", "Elapsed", "In", "Out", "Tools"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let rows = self.rows.iter().map(|row| {
+            Row::new([
+                row.phase.number().to_string(),
+                row.task.clone(),
+                row.status.label(),
+                format!("{:.1}s", row.elapsed().as_secs_f32()),
+                row.usage.input_tokens.to_string(),
+                row.usage.output_tokens.to_string(),
+                row.tool_calls.to_string(),
+            ])
+            .style(row.status.style())
+        });
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(5),
+                Constraint::Length(22),
+                Constraint::Min(20),
+                Constraint::Length(8),
+                Constraint::Length(8),
+                Constraint::Length(8),
+                Constraint::Length(5),
+            ],
+        )
+        .header(header)
+    }
+
+    /// Draws the dashboard into `frame`.
+    pub fn render(&self, frame: &mut Frame) {
+        let [header, table, messages] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(MAX_MESSAGES as u16 + 2),
+        ])
+        .areas(frame.area());
+
+        frame.render_widget(
+            Paragraph::new(self.header()).style(Style::default().add_modifier(Modifier::BOLD)),
+            header,
+        );
+        frame.render_widget(self.table().block(Block::bordered()), table);
+        frame.render_widget(
+            Paragraph::new(self.messages().map(Line::raw).collect::<Vec<_>>())
+                .block(Block::bordered().title("Messages")),
+            messages,
+        );
+    }
+
+    /// Formats the final table as plain text, one task per line.
+    pub fn summary(&self) -> String {
+        let mut out = format!("{}\n", self.header());
+        for row in &self.rows {
+            out.push_str(&format!(
+                "  [{}] {:<22} {:<14} {:>7.1}s  tokens: {} in, {} out  tools: {}\n",
+                row.phase.number(),
+                row.task,
+                row.status.label(),
+                row.elapsed().as_secs_f32(),
+                row.usage.input_tokens,
+                row.usage.output_tokens,
+                row.tool_calls,
+            ));
+        }
+        out
+    }
+}
+
+/// Feeds observer events into a shared [`DashboardState`].
+#[derive(Debug, Clone)]
+pub struct DashboardObserver {
+    state: Arc<Mutex<DashboardState>>,
+}
+
+impl DashboardObserver {
+    fn update(&self, f: impl FnOnce(&mut DashboardState)) {
+        f(&mut self.state.lock().unwrap_or_else(PoisonError::into_inner));
+    }
+}
+
+impl ResearchObserver for DashboardObserver {
+    fn on_task_start(&self, task: &str, stage: &TaskStage) {
+        self.update(|state| state.task_started(task, stage));
+    }
+
+    fn on_task_complete(&self, report: &TaskReport) {
+        self.update(|state| state.task_completed(report));
+    }
+
+    fn on_tool_call(&self, task: &str, _tool: &str) {
+        self.update(|state| state.tool_called(task));
+    }
+
+    fn on_token_usage(&self, task: &str, usage: &PromptMetrics) {
+        self.update(|state| state.usage_updated(task, usage));
+    }
+
+    fn on_phase_complete(&self, summary: &PhaseSummary) {
+        self.update(|state| state.phase_completed(summary));
+    }
+
+    fn on_warning(&self, message: &str) {
+        self.update(|state| state.message(&format!("⚠ {}", message)));
+    }
+
+    fn on_info(&self, message: &str) {
+        self.update(|state| state.message(message));
+    }
+}
+
+/// A live dashboard drawn on the terminal while research runs.
+///
+/// Dropping the dashboard without calling [`finish`](Self::finish) still
+/// restores the terminal, but doesn't print the summary.
+pub struct Dashboard {
+    state: Arc<Mutex<DashboardState>>,
+    stop: Arc<AtomicBool>,
+    render: Option<JoinHandle<io::Result<()>>>,
+}
+
+impl Dashboard {
+    /// Starts redrawing the dashboard for a run researching `title`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the drawing thread can't be spawned.
+    pub fn start(title: &str) -> io::Result<Self> {
+        let state = Arc::new(Mutex::new(DashboardState::new(title)));
+        let stop = Arc::new(AtomicBool::new(false));
+        let render = std::thread::Builder::new()
+            .name("research-dashboard".to_string())
+            .spawn({
+                let state = Arc::clone(&state);
+                let stop = Arc::clone(&stop);
+                move || render_loop(&state, &stop)
+            })?;
+
+        Ok(Self {
+            state,
+            stop,
+            render: Some(render),
+        })
+    }
+
+    /// Returns an observer that updates this dashboard.
+    ///
+    /// Pass it to [`ResearchOptions::with_observer`](crate::ResearchOptions::with_observer).
+    pub fn observer(&self) -> Arc<dyn ResearchObserver> {
+        Arc::new(DashboardObserver {
+            state: Arc::clone(&self.state),
+        })
+    }
+
+    /// Stops drawing, restores the terminal and prints the final table.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the first error from drawing or restoring the terminal.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.stop_rendering()?;
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if !state.rows().is_empty() {
+            print!("{}", state.summary());
+        }
+        Ok(())
+    }
+
+    fn stop_rendering(&mut self) -> io::Result<()> {
+        self.stop.store(true, Ordering::Relaxed);
+        match self.render.take().map(JoinHandle::join) {
+            Some(Ok(result)) => result,
+            Some(Err(_)) => Err(io::Error::other("dashboard thread panicked")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Dashboard {
+    fn drop(&mut self) {
+        let _ = self.stop_rendering();
+    }
+}
+
+/// Redraws the dashboard until `stop` is set, entering the alternate screen
+/// once the first task starts.
+fn render_loop(state: &Mutex<DashboardState>, stop: &AtomicBool) -> io::Result<()> {
+    let mut terminal: Option<Terminal<CrosstermBackend<Stdout>>> = None;
+    let result = (|| {
+        while !stop.load(Ordering::Relaxed) {
+            let state = state.lock().unwrap_or_else(PoisonError::into_inner).clone();
+            if !state.rows().is_empty() {
+                let terminal = match terminal {
+                    Some(ref mut terminal) => terminal,
+                    None => terminal.insert(enter()?),
+                };
+                terminal.draw(|frame| state.render(frame))?;
+            }
+            std::thread::sleep(REFRESH_INTERVAL);
+        }
+        Ok(())
+    })();
+
+    match terminal {
+        Some(_) => result.and(leave()),
+        None => result,
+    }
+}
+
+fn enter() -> io::Result<Terminal<CrosstermBackend<Stdout>>> {
+    let mut stdout = io::stdout();
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+    terminal.clear()?;
+    Ok(terminal)
+}
+
+fn leave() -> io::Result<()> {
+    execute!(io::stdout(), terminal::LeaveAlternateScreen, cursor::Show)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+
+    fn report(task: &str, outcome: TaskOutcome) -> TaskReport {
+        TaskReport {
+            task: task.to_string(),
+            completed: 1,
+            total: 2,
+            elapsed_secs: 1.0,
+            outcome,
+        }
+    }
+
+    fn metrics(input: u64, output: u64) -> PromptMetrics {
+        PromptMetrics {
+            input_tokens: input,
+            output_tokens: output,
+            total_tokens: input + output,
+            elapsed_secs: 0.0,
+        }
+    }
+
+    #[test]
+    fn tracks_task_progress() {
+        let mut state = DashboardState::new("clap");
+        state.task_started("overview", &TaskStage::AgentPrompt);
+        state.task_started("use_cases", &TaskStage::Prompt);
+        state.tool_called("overview");
+        state.tool_called("overview");
+        state.usage_updated("overview", &metrics(100, 20));
+        state.task_completed(&report(
+            "use_cases",
+            TaskOutcome::Failed {
+                error: "timeout".to_string(),
+            },
+        ));
+
+        let rows = state.rows();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].tool_calls, 2);
+        assert_eq!(rows[0].usage.total_tokens, 120);
+        assert!(matches!(rows[0].status, TaskStatus::Running { .. }));
+        assert!(rows[0].finished_after.is_none());
+        assert_eq!(
+            rows[1].status,
+            TaskStatus::Failed {
+                error: "timeout".to_string()
+            }
+        );
+        assert!(rows[1].finished_after.is_some());
+    }
+
+    #[test]
+    fn stage_changes_update_the_same_row() {
+        let mut state = DashboardState::new("clap");
+        state.task_started("changelog", &TaskStage::AggregatingHistory);
+        state.task_started("changelog", &TaskStage::Generating);
+
+        assert_eq!(state.rows().len(), 1);
+        assert_eq!(
+            state.rows()[0].status,
+            TaskStatus::Running {
+                stage: "generating".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn tasks_after_phase_one_belong_to_phase_two() {
+        let mut state = DashboardState::new("clap");
+        state.task_started("overview", &TaskStage::Prompt);
+        state.task_completed(&report(
+            "overview",
+            TaskOutcome::Succeeded {
+                metrics: Some(metrics(10, 5)),
+            },
+        ));
+        state.phase_completed(&PhaseSummary {
+            phase: ResearchPhase::Research,
+            succeeded: 1,
+            total: 1,
+            cancelled: false,
+        });
+        state.task_started("skill", &TaskStage::Prompt);
+
+        assert_eq!(state.rows()[0].usage.total_tokens, 15);
+        assert_eq!(state.rows()[1].phase, ResearchPhase::Synthesis);
+        assert!(state.header().contains("Phase 2: 0/1 done"));
+    }
+
+    #[test]
+    fn keeps_the_latest_messages() {
+        let mut state = DashboardState::new("clap");
+        for i in 0..7 {
+            state.message(&format!("message {}", i));
+        }
+        state.message("  \n");
+
+        let messages: Vec<&str> = state.messages().collect();
+        assert_eq!(messages.len(), MAX_MESSAGES);
+        assert_eq!(messages[0], "message 2");
+        assert_eq!(messages[4], "message 6");
+    }
+
+    #[test]
+    fn observer_updates_shared_state() {
+        let dashboard = DashboardState::new("clap");
+        let observer = DashboardObserver {
+            state: Arc::new(Mutex::new(dashboard)),
+        };
+        observer.on_task_start("overview", &TaskStage::AgentPrompt);
+        observer.on_tool_call("overview", "brave_search");
+        observer.on_warning("brief failed");
+
+        let state = observer.state.lock().unwrap();
+        assert_eq!(state.rows()[0].tool_calls, 1);
+        assert_eq!(state.messages().collect::<Vec<_>>(), vec!["⚠ brief failed"]);
+    }
+
+    #[test]
+    fn renders_rows_and_summary() {
+        let mut state = DashboardState::new("clap");
+        state.task_started("overview", &TaskStage::AgentPrompt);
+        state.usage_updated("overview", &metrics(1200, 300));
+        state.message("Phase 1: Running research prompts...");
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 14)).unwrap();
+        terminal.draw(|frame| state.render(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(screen.contains("Researching clap"));
+        assert!(screen.contains("overview"));
+        assert!(screen.contains("researching"));
+        assert!(screen.contains("1200"));
+        assert!(screen.contains("Phase 1: Running research prompts..."));
+
+        let summary = state.summary();
+        assert!(summary.contains("overview"));
+        assert!(summary.contains("tokens: 1200 in, 300 out"));
+    }
+}
@@ -0,0 +1,982 @@
+//! Library-wide detection and merging of near-duplicate research topics.
+//!
+//! Topics are often researched twice under slightly different names (e.g.
+//! `tokio` and `tokio-runtime`). [`dedupe`] compares every pair of topics in
+//! the library using text similarity of their names and of the `brief` and
+//! `summary` stored in `metadata.json`, and proposes merges for pairs above a
+//! threshold. [`merge_topics`] folds one topic directory into another,
+//! consolidating metadata, additional questions, and skill/doc symlinks.
+//!
+//! Both steps support a dry run: [`dedupe`] never modifies anything, and
+//! [`merge_topics`] with `dry_run` returns the [`MergePlan`] without touching
+//! the filesystem.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use tracing::{debug, info, warn};
+
+/// Default similarity score at or above which a merge is proposed.
+pub const DEFAULT_THRESHOLD: f32 = 0.65;
+
+/// Standard research documents copied into the kept topic when it lacks them.
+const UNDERLYING_DOCS: &[&str] = &[
+    "overview.md",
+    "similar_libraries.md",
+    "integration_partners.md",
+    "use_cases.md",
+    "changelog.md",
+];
+
+/// Words ignored when comparing briefs and summaries.
+const STOP_WORDS: &[&str] = &[
+    "and", "are", "for", "from", "into", "its", "library", "that", "the", "this", "with", "which",
+    "you", "your", "can", "has", "have", "provides", "rust", "crate", "package", "using", "use",
+];
+
+/// Errors that can occur while deduplicating topics.
+#[derive(Error, Debug)]
+pub enum DedupeError {
+    #[error("Research library directory not found: {0}")]
+    LibraryNotFound(PathBuf),
+
+    #[error("Topic not found: {0}")]
+    TopicNotFound(String),
+
+    #[error("Cannot merge topic '{0}' into itself")]
+    SameTopic(String),
+
+    #[error("Invalid metadata.json for '{topic}': {message}")]
+    InvalidMetadata { topic: String, message: String },
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// The parts of a topic used for similarity scoring.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TopicProfile {
+    /// The topic (directory name under `library/`)
+    pub name: String,
+    /// The topic directory
+    pub location: PathBuf,
+    /// Single-sentence summary from `metadata.json`
+    pub brief: Option<String>,
+    /// Paragraph summary from `metadata.json`
+    pub summary: Option<String>,
+    /// Number of markdown documents in the topic directory
+    pub document_count: usize,
+}
+
+/// The subset of `metadata.json` needed to build a [`TopicProfile`].
+#[derive(Debug, Default, Deserialize)]
+struct ProfileMetadata {
+    brief: Option<String>,
+    summary: Option<String>,
+}
+
+/// A proposed merge of two similar topics.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DuplicateCandidate {
+    /// The topic to keep (the one with more research)
+    pub keep: String,
+    /// The topic to fold into `keep`
+    pub merge: String,
+    /// Combined similarity score in `0.0..=1.0`
+    pub score: f32,
+    /// Similarity of the topic names
+    pub name_score: f32,
+    /// Similarity of the briefs/summaries, if both topics have one
+    pub text_score: Option<f32>,
+}
+
+/// Result of a [`dedupe`] analysis.
+#[derive(Debug, Clone, Serialize)]
+pub struct DedupeReport {
+    /// Number of topics compared
+    pub topics_scanned: usize,
+    /// The threshold used for proposals
+    pub threshold: f32,
+    /// Proposed merges, most similar first
+    pub candidates: Vec<DuplicateCandidate>,
+}
+
+impl DedupeReport {
+    /// Renders the report for the terminal.
+    pub fn render(&self) -> String {
+        if self.candidates.is_empty() {
+            return format!(
+                "No duplicate topics found ({} topics scanned, threshold {:.2})\n",
+                self.topics_scanned, self.threshold
+            );
+        }
+
+        let mut out = format!(
+            "Found {} possible duplicate(s) among {} topics (threshold {:.2}):\n\n",
+            self.candidates.len(),
+            self.topics_scanned,
+            self.threshold
+        );
+        for candidate in &self.candidates {
+            let text = candidate
+                .text_score
+                .map(|s| format!("{:.2}", s))
+                .unwrap_or_else(|| "n/a".to_string());
+            out.push_str(&format!(
+                "  {:.2}  merge '{}' into '{}'  (name {:.2}, text {})\n",
+                candidate.score, candidate.merge, candidate.keep, candidate.name_score, text
+            ));
+        }
+        out
+    }
+}
+
+/// What a merge did (or, for a dry run, would do).
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MergePlan {
+    /// The topic that is kept
+    pub keep: String,
+    /// The topic folded into `keep` and removed
+    pub merge: String,
+    /// Whether this plan was only computed, not applied
+    pub dry_run: bool,
+    /// Standard documents copied because `keep` lacked them
+    pub copied_docs: Vec<String>,
+    /// Additional question files moved as `(from, to)` filenames
+    pub moved_questions: Vec<(String, String)>,
+    /// Additional question files dropped because `keep` already asks the same question
+    pub skipped_questions: Vec<String>,
+    /// Metadata fields (`brief`, `summary`, `when_to_use`) filled from `merge`
+    pub filled_fields: Vec<String>,
+    /// Skill/doc symlinks pointing into `merge` that are removed
+    pub removed_links: Vec<PathBuf>,
+}
+
+impl MergePlan {
+    /// Renders the plan for the terminal.
+    pub fn render(&self) -> String {
+        let verb = if self.dry_run {
+            "Would merge"
+        } else {
+            "Merged"
+        };
+        let mut out = format!("{} '{}' into '{}'\n", verb, self.merge, self.keep);
+
+        for doc in &self.copied_docs {
+            out.push_str(&format!("  + copy {}\n", doc));
+        }
+        for (from, to) in &self.moved_questions {
+            if from == to {
+                out.push_str(&format!("  + move {}\n", from));
+            } else {
+                out.push_str(&format!("  + move {} -> {}\n", from, to));
+            }
+        }
+        for question in &self.skipped_questions {
+            out.push_str(&format!("  - skip {} (already asked)\n", question));
+        }
+        for field in &self.filled_fields {
+            out.push_str(&format!("  + fill metadata {}\n", field));
+        }
+        for link in &self.removed_links {
+            out.push_str(&format!("  - unlink {}\n", link.display()));
+        }
+        out.push_str(&format!("  - remove {}/\n", self.merge));
+        out
+    }
+}
+
+/// Returns the skill and doc directories that may hold symlinks into the library.
+///
+/// These are the Claude Code, OpenCode, and Roo Code locations managed by
+/// `research link`. Directories that cannot be determined are omitted.
+pub fn default_link_dirs() -> Vec<PathBuf> {
+    use crate::link::detection;
+
+    [
+        detection::get_claude_skills_dir(),
+        detection::get_opencode_skills_dir(),
+        detection::get_roo_skills_dir(),
+        detection::get_claude_docs_dir(),
+        detection::get_opencode_docs_dir(),
+        detection::get_roo_docs_dir(),
+    ]
+    .into_iter()
+    .filter_map(Result::ok)
+    .collect()
+}
+
+/// Loads the similarity profile of every topic under `library_dir`.
+///
+/// Topics are sorted by name. A missing or unreadable `metadata.json` yields
+/// a profile without brief or summary.
+///
+/// ## Errors
+///
+/// Returns an error if `library_dir` does not exist or cannot be read.
+pub fn load_profiles(library_dir: &Path) -> Result<Vec<TopicProfile>, DedupeError> {
+    if !library_dir.is_dir() {
+        return Err(DedupeError::LibraryNotFound(library_dir.to_path_buf()));
+    }
+
+    let mut profiles = Vec::new();
+    for entry in std::fs::read_dir(library_dir)?.flatten() {
+        let location = entry.path();
+        // Skip symlinks and files; only real topic directories are compared
+        if !entry.file_type().is_ok_and(|t| t.is_dir()) {
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy().to_string();
+        let metadata: ProfileMetadata = std::fs::read_to_string(location.join("metadata.json"))
+            .ok()
+            .and_then(|content| match serde_json::from_str(&content) {
+                Ok(metadata) => Some(metadata),
+                Err(e) => {
+                    warn!("Ignoring invalid metadata.json for '{}': {}", name, e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        profiles.push(TopicProfile {
+            name,
+            document_count: count_documents(&location),
+            location,
+            brief: metadata.brief.filter(|s| !s.trim().is_empty()),
+            summary: metadata.summary.filter(|s| !s.trim().is_empty()),
+        });
+    }
+
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(profiles)
+}
+
+/// Detects near-duplicate topics in the research library.
+///
+/// Every pair of topics is scored with [`topic_similarity`]; pairs scoring at
+/// least `threshold` are proposed for merging, keeping the topic with more
+/// research documents. This is a read-only analysis.
+///
+/// ## Errors
+///
+/// Returns an error if `library_dir` does not exist or cannot be read.
+pub fn dedupe(library_dir: &Path, threshold: f32) -> Result<DedupeReport, DedupeError> {
+    let profiles = load_profiles(library_dir)?;
+    let mut candidates = Vec::new();
+
+    for (i, a) in profiles.iter().enumerate() {
+        for b in &profiles[i + 1..] {
+            let (score, name_score, text_score) = topic_similarity(a, b);
+            if score < threshold {
+                continue;
+            }
+
+            let (keep, merge) = if prefer_keep(a, b) { (a, b) } else { (b, a) };
+            debug!(keep = %keep.name, merge = %merge.name, score, "Duplicate candidate");
+            candidates.push(DuplicateCandidate {
+                keep: keep.name.clone(),
+                merge: merge.name.clone(),
+                score,
+                name_score,
+                text_score,
+            });
+        }
+    }
+
+    candidates.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.keep.cmp(&b.keep))
+            .then_with(|| a.merge.cmp(&b.merge))
+    });
+
+    Ok(DedupeReport {
+        topics_scanned: profiles.len(),
+        threshold,
+        candidates,
+    })
+}
+
+/// Scores how likely two topics are duplicates.
+///
+/// When both topics have a brief or summary, the score is the average of
+/// [`name_similarity`] and [`text_similarity`]. Otherwise only the names are
+/// compared, discounted by 10% for the missing evidence.
+///
+/// ## Returns
+///
+/// `(score, name_score, text_score)`, all in `0.0..=1.0`.
+pub fn topic_similarity(a: &TopicProfile, b: &TopicProfile) -> (f32, f32, Option<f32>) {
+    let name_score = name_similarity(&a.name, &b.name);
+
+    let text_a = profile_text(a);
+    let text_b = profile_text(b);
+    if text_a.is_empty() || text_b.is_empty() {
+        return (name_score * 0.9, name_score, None);
+    }
+
+    let text_score = text_similarity(&text_a, &text_b);
+    (
+        (name_score + text_score) / 2.0,
+        name_score,
+        Some(text_score),
+    )
+}
+
+/// Scores the similarity of two topic names.
+///
+/// Names are split into lowercase alphanumeric words. The score is the higher
+/// of the word overlap (mean of containment and Jaccard index) and the
+/// normalized edit distance of the joined words, so `tokio`/`Tokio runtime`,
+/// `serde_json`/`serde-json`, and `reqwest`/`request` all score highly.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::dedupe::name_similarity;
+///
+/// assert_eq!(name_similarity("serde_json", "serde-json"), 1.0);
+/// assert!(name_similarity("tokio", "Tokio runtime") >= 0.75);
+/// assert!(name_similarity("tokio", "clap") < 0.3);
+/// ```
+pub fn name_similarity(a: &str, b: &str) -> f32 {
+    let words_a = words(a);
+    let words_b = words(b);
+    if words_a.is_empty() || words_b.is_empty() {
+        return 0.0;
+    }
+
+    let joined_a = words_a.concat();
+    let joined_b = words_b.concat();
+    if joined_a == joined_b {
+        return 1.0;
+    }
+
+    let set_a: HashSet<&String> = words_a.iter().collect();
+    let set_b: HashSet<&String> = words_b.iter().collect();
+    let shared = set_a.intersection(&set_b).count() as f32;
+    let containment = shared / set_a.len().min(set_b.len()) as f32;
+    let jaccard = shared / set_a.union(&set_b).count() as f32;
+    let overlap = (containment + jaccard) / 2.0;
+
+    let max_len = joined_a.chars().count().max(joined_b.chars().count());
+    let edit = 1.0 - levenshtein(&joined_a, &joined_b) as f32 / max_len as f32;
+
+    overlap.max(edit)
+}
+
+/// Scores the similarity of two descriptions as the cosine similarity of
+/// their word counts, ignoring short words and common filler words.
+pub fn text_similarity(a: &str, b: &str) -> f32 {
+    let counts_a = word_counts(a);
+    let counts_b = word_counts(b);
+    if counts_a.is_empty() || counts_b.is_empty() {
+        return 0.0;
+    }
+
+    let dot: f32 = counts_a
+        .iter()
+        .filter_map(|(word, n)| counts_b.get(word).map(|m| n * m))
+        .sum();
+    let norm = |counts: &HashMap<String, f32>| counts.values().map(|n| n * n).sum::<f32>().sqrt();
+
+    dot / (norm(&counts_a) * norm(&counts_b))
+}
+
+/// Folds the topic `merge` into the topic `keep`.
+///
+/// - Standard research documents missing from `keep` are copied over.
+/// - Additional questions are moved, renumbering `question_N.md` files and
+///   suffixing clashing custom names; questions whose prompt `keep` already
+///   asks are dropped. Their tags move with them.
+/// - `brief`, `summary`, and `when_to_use` are filled in `keep` if missing.
+/// - Symlinks in `link_dirs` that point into `merge` are removed (run
+///   `research link` afterwards to link the kept topic).
+/// - The `merge` directory is deleted and tag indexes are refreshed.
+///
+/// With `dry_run`, the returned plan describes these steps without
+/// modifying anything.
+///
+/// ## Errors
+///
+/// Returns an error if either topic is missing, both names are the same, a
+/// `metadata.json` is invalid, or a filesystem operation fails.
+pub fn merge_topics(
+    library_dir: &Path,
+    keep: &str,
+    merge: &str,
+    link_dirs: &[PathBuf],
+    dry_run: bool,
+) -> Result<MergePlan, DedupeError> {
+    if keep == merge {
+        return Err(DedupeError::SameTopic(keep.to_string()));
+    }
+
+    let keep_dir = library_dir.join(keep);
+    let merge_dir = library_dir.join(merge);
+    for (name, dir) in [(keep, &keep_dir), (merge, &merge_dir)] {
+        if !dir.is_dir() {
+            return Err(DedupeError::TopicNotFound(name.to_string()));
+        }
+    }
+
+    let mut keep_meta = read_metadata(keep, &keep_dir)?;
+    let merge_meta = read_metadata(merge, &merge_dir)?;
+
+    let mut plan = MergePlan {
+        keep: keep.to_string(),
+        merge: merge.to_string(),
+        dry_run,
+        ..Default::default()
+    };
+
+    for doc in UNDERLYING_DOCS {
+        if merge_dir.join(doc).is_file() && !keep_dir.join(doc).exists() {
+            plan.copied_docs.push(doc.to_string());
+        }
+    }
+
+    // Additional questions: filename -> prompt
+    let keep_files = string_map(&keep_meta, "additional_files");
+    let merge_files = string_map(&merge_meta, "additional_files");
+    let merge_tags = merge_meta
+        .get("question_tags")
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut known_prompts: HashSet<String> =
+        keep_files.values().map(|p| normalize_prompt(p)).collect();
+    let mut taken: HashSet<String> = keep_files.keys().cloned().collect();
+    let mut next_question = next_question_number(taken.iter());
+
+    let mut merge_names: Vec<&String> = merge_files.keys().collect();
+    merge_names.sort_by_key(|name| (question_number(name).unwrap_or(usize::MAX), *name));
+
+    let mut new_files = Vec::new();
+    for filename in merge_names {
+        let prompt = &merge_files[filename];
+        if !known_prompts.insert(normalize_prompt(prompt)) {
+            plan.skipped_questions.push(filename.clone());
+            continue;
+        }
+
+        let target = if question_number(filename).is_some() {
+            let target = format!("question_{}.md", next_question);
+            next_question += 1;
+            target
+        } else if taken.contains(filename) {
+            let stem = filename.strip_suffix(".md").unwrap_or(filename);
+            format!("{}-{}.md", stem, merge)
+        } else {
+            filename.clone()
+        };
+
+        taken.insert(target.clone());
+        plan.moved_questions
+            .push((filename.clone(), target.clone()));
+        new_files.push((target, prompt.clone(), merge_tags.get(filename).cloned()));
+    }
+
+    for field in ["brief", "summary", "when_to_use"] {
+        let missing = keep_meta
+            .get(field)
+            .and_then(Value::as_str)
+            .is_none_or(|s| s.trim().is_empty());
+        if missing && let Some(value) = merge_meta.get(field).filter(|v| v.is_string()) {
+            plan.filled_fields.push(field.to_string());
+            if let Some(obj) = keep_meta.as_object_mut() {
+                obj.insert(field.to_string(), value.clone());
+            }
+        }
+    }
+
+    plan.removed_links = links_into(&merge_dir, link_dirs);
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    for doc in &plan.copied_docs {
+        std::fs::copy(merge_dir.join(doc), keep_dir.join(doc))?;
+    }
+
+    for ((from, to), (_, prompt, tags)) in plan.moved_questions.iter().zip(&new_files) {
+        let source = merge_dir.join(from);
+        if source.is_file() {
+            std::fs::copy(&source, keep_dir.join(to))?;
+        } else {
+            warn!("Question file {} is missing in '{}'", from, merge);
+        }
+
+        if let Some(files) = object_field(&mut keep_meta, "additional_files") {
+            files.insert(to.clone(), Value::String(prompt.clone()));
+        }
+        if let Some(tags) = tags
+            && let Some(all) = object_field(&mut keep_meta, "question_tags")
+        {
+            all.insert(to.clone(), tags.clone());
+        }
+    }
+
+    if let Some(obj) = keep_meta.as_object_mut() {
+        obj.insert(
+            "updated_at".to_string(),
+            Value::String(chrono::Utc::now().to_rfc3339()),
+        );
+    }
+    let content =
+        serde_json::to_string_pretty(&keep_meta).map_err(|e| DedupeError::InvalidMetadata {
+            topic: keep.to_string(),
+            message: e.to_string(),
+        })?;
+    std::fs::write(keep_dir.join("metadata.json"), content)?;
+
+    for link in &plan.removed_links {
+        std::fs::remove_file(link)?;
+    }
+
+    std::fs::remove_dir_all(&merge_dir)?;
+    info!("Merged topic '{}' into '{}'", merge, keep);
+
+    if library_dir
+        .file_name()
+        .is_some_and(|name| name == "library")
+        && let Some(research_root) = library_dir.parent()
+        && let Err(e) = crate::tags::write_tag_indexes(research_root)
+    {
+        warn!("Failed to refresh tag indexes: {}", e);
+    }
+
+    Ok(plan)
+}
+
+/// Decides whether `a` should be kept over `b`.
+///
+/// The topic with more documents wins; ties go to the shorter, then
+/// alphabetically first, name.
+fn prefer_keep(a: &TopicProfile, b: &TopicProfile) -> bool {
+    let key = |p: &TopicProfile| {
+        (
+            std::cmp::Reverse(p.document_count),
+            p.name.len(),
+            p.name.clone(),
+        )
+    };
+    key(a) <= key(b)
+}
+
+/// Counts the markdown files directly inside a topic directory.
+fn count_documents(dir: &Path) -> usize {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// Joins a profile's brief and summary.
+fn profile_text(profile: &TopicProfile) -> String {
+    [profile.brief.as_deref(), profile.summary.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits text into lowercase alphanumeric words.
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Counts the significant words of a description.
+fn word_counts(text: &str) -> HashMap<String, f32> {
+    let mut counts = HashMap::new();
+    for word in words(text) {
+        if word.chars().count() < 3 || STOP_WORDS.contains(&word.as_str()) {
+            continue;
+        }
+        *counts.entry(word).or_insert(0.0) += 1.0;
+    }
+    counts
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Reads a topic's `metadata.json` as raw JSON so that unknown fields and
+/// older schema versions survive the merge untouched.
+fn read_metadata(topic: &str, dir: &Path) -> Result<Value, DedupeError> {
+    let path = dir.join("metadata.json");
+    if !path.exists() {
+        return Ok(Value::Object(Default::default()));
+    }
+
+    let content = std::fs::read_to_string(&path)?;
+    let value: Value =
+        serde_json::from_str(&content).map_err(|e| DedupeError::InvalidMetadata {
+            topic: topic.to_string(),
+            message: e.to_string(),
+        })?;
+    if !value.is_object() {
+        return Err(DedupeError::InvalidMetadata {
+            topic: topic.to_string(),
+            message: "expected a JSON object".to_string(),
+        });
+    }
+    Ok(value)
+}
+
+/// Reads a `{string: string}` object field from metadata.
+fn string_map(metadata: &Value, field: &str) -> HashMap<String, String> {
+    metadata
+        .get(field)
+        .and_then(Value::as_object)
+        .map(|obj| {
+            obj.iter()
+                .filter_map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns a mutable object field of metadata, creating it if missing.
+fn object_field<'a>(
+    metadata: &'a mut Value,
+    field: &str,
+) -> Option<&'a mut serde_json::Map<String, Value>> {
+    metadata
+        .as_object_mut()?
+        .entry(field)
+        .or_insert_with(|| Value::Object(Default::default()))
+        .as_object_mut()
+}
+
+/// Normalizes a prompt for duplicate detection.
+fn normalize_prompt(prompt: &str) -> String {
+    words(prompt).join(" ")
+}
+
+/// Returns `N` for a `question_N.md` filename.
+fn question_number(filename: &str) -> Option<usize> {
+    filename
+        .strip_prefix("question_")
+        .and_then(|s| s.strip_suffix(".md"))
+        .and_then(|n| n.parse().ok())
+}
+
+/// Returns the next free question number after the given filenames.
+fn next_question_number<'a>(filenames: impl Iterator<Item = &'a String>) -> usize {
+    filenames
+        .filter_map(|name| question_number(name))
+        .max()
+        .map_or(1, |n| n + 1)
+}
+
+/// Finds symlinks in `link_dirs` whose target lies inside `topic_dir`.
+pub(crate) fn links_into(topic_dir: &Path, link_dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let canonical = topic_dir.canonicalize().ok();
+    let mut links = BTreeSet::new();
+
+    for dir in link_dirs {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(target) = std::fs::read_link(&path) else {
+                continue;
+            };
+            let target = if target.is_relative() {
+                dir.join(target)
+            } else {
+                target
+            };
+            let points_into = target.starts_with(topic_dir)
+                || canonical.as_ref().is_some_and(|c| target.starts_with(c));
+            if points_into {
+                links.insert(path);
+            }
+        }
+    }
+
+    links.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_topic(library: &Path, topic: &str, metadata: &str, files: &[&str]) -> PathBuf {
+        let dir = library.join(topic);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("metadata.json"), metadata).unwrap();
+        for file in files {
+            std::fs::write(dir.join(file), format!("# {}\n", file)).unwrap();
+        }
+        dir
+    }
+
+    fn read_json(path: &Path) -> Value {
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_name_similarity() {
+        assert_eq!(name_similarity("serde_json", "serde-json"), 1.0);
+        assert_eq!(name_similarity("Tokio", "tokio"), 1.0);
+        assert_eq!(name_similarity("tokio", "tokio runtime"), 0.75);
+        assert!(name_similarity("reqwest", "request") > 0.8);
+        assert!(name_similarity("tokio", "clap") < 0.3);
+        assert_eq!(name_similarity("", "clap"), 0.0);
+    }
+
+    #[test]
+    fn test_text_similarity() {
+        let a = "Asynchronous runtime for writing reliable network applications";
+        let b = "An asynchronous runtime for reliable network applications and services";
+        assert!(text_similarity(a, b) > 0.7);
+        assert!(text_similarity(a, "Command line argument parser") < 0.1);
+        assert_eq!(text_similarity(a, ""), 0.0);
+    }
+
+    #[test]
+    fn test_dedupe_proposes_similar_topics() {
+        let temp = TempDir::new().unwrap();
+        let library = temp.path();
+        write_topic(
+            library,
+            "tokio",
+            r#"{"brief": "Asynchronous runtime for reliable network applications"}"#,
+            &["overview.md", "use_cases.md"],
+        );
+        write_topic(
+            library,
+            "tokio-runtime",
+            r#"{"brief": "An asynchronous runtime for network applications"}"#,
+            &["overview.md"],
+        );
+        write_topic(
+            library,
+            "serde_json",
+            r#"{"brief": "JSON serialization and deserialization"}"#,
+            &["overview.md"],
+        );
+        write_topic(
+            library,
+            "serde",
+            r#"{"brief": "Generic serialization framework for data structures"}"#,
+            &["overview.md"],
+        );
+
+        let report = dedupe(library, DEFAULT_THRESHOLD).unwrap();
+
+        assert_eq!(report.topics_scanned, 4);
+        assert_eq!(report.candidates.len(), 1, "{:?}", report.candidates);
+        let candidate = &report.candidates[0];
+        assert_eq!(candidate.keep, "tokio");
+        assert_eq!(candidate.merge, "tokio-runtime");
+        assert!(candidate.text_score.is_some());
+        assert!(
+            report
+                .render()
+                .contains("merge 'tokio-runtime' into 'tokio'")
+        );
+    }
+
+    #[test]
+    fn test_dedupe_missing_library() {
+        let temp = TempDir::new().unwrap();
+        let result = dedupe(&temp.path().join("missing"), DEFAULT_THRESHOLD);
+        assert!(matches!(result, Err(DedupeError::LibraryNotFound(_))));
+    }
+
+    #[test]
+    fn test_merge_dry_run_changes_nothing() {
+        let temp = TempDir::new().unwrap();
+        let library = temp.path().join("library");
+        write_topic(&library, "tokio", r#"{"additional_files": {}}"#, &[]);
+        let merge_dir = write_topic(
+            &library,
+            "tokio-runtime",
+            r#"{"brief": "Async runtime", "additional_files": {"question_1.md": "Retries?"}}"#,
+            &["overview.md", "question_1.md"],
+        );
+
+        let plan = merge_topics(&library, "tokio", "tokio-runtime", &[], true).unwrap();
+
+        assert!(plan.dry_run);
+        assert_eq!(plan.copied_docs, vec!["overview.md"]);
+        assert_eq!(
+            plan.moved_questions,
+            vec![("question_1.md".to_string(), "question_1.md".to_string())]
+        );
+        assert_eq!(plan.filled_fields, vec!["brief"]);
+        assert!(
+            plan.render()
+                .starts_with("Would merge 'tokio-runtime' into 'tokio'")
+        );
+        assert!(merge_dir.exists());
+        assert!(!library.join("tokio/overview.md").exists());
+    }
+
+    #[test]
+    fn test_merge_consolidates_questions_and_metadata() {
+        let temp = TempDir::new().unwrap();
+        let library = temp.path().join("library");
+        let keep_dir = write_topic(
+            &library,
+            "tokio",
+            r#"{
+                "schema_version": 1,
+                "brief": "Async runtime for Rust",
+                "additional_files": {
+                    "question_1.md": "How does tokio handle retries?",
+                    "channels.md": "What channel types are available?"
+                },
+                "question_tags": {"question_1.md": ["reliability"]}
+            }"#,
+            &["overview.md", "question_1.md", "channels.md"],
+        );
+        write_topic(
+            &library,
+            "tokio-runtime",
+            r#"{
+                "brief": "Another brief",
+                "summary": "Tokio runtime summary",
+                "additional_files": {
+                    "question_1.md": "How does Tokio handle retries",
+                    "question_2.md": "How do I spawn blocking tasks?",
+                    "channels.md": "Which channels exist?"
+                },
+                "question_tags": {"question_2.md": ["async"]}
+            }"#,
+            &[
+                "overview.md",
+                "use_cases.md",
+                "question_1.md",
+                "question_2.md",
+                "channels.md",
+            ],
+        );
+
+        let plan = merge_topics(&library, "tokio", "tokio-runtime", &[], false).unwrap();
+
+        assert_eq!(plan.copied_docs, vec!["use_cases.md"]);
+        assert_eq!(plan.skipped_questions, vec!["question_1.md"]);
+        assert_eq!(
+            plan.moved_questions,
+            vec![
+                ("question_2.md".to_string(), "question_2.md".to_string()),
+                (
+                    "channels.md".to_string(),
+                    "channels-tokio-runtime.md".to_string()
+                ),
+            ]
+        );
+        assert_eq!(plan.filled_fields, vec!["summary"]);
+
+        assert!(!library.join("tokio-runtime").exists());
+        assert!(keep_dir.join("use_cases.md").exists());
+        assert!(keep_dir.join("question_2.md").exists());
+        assert!(keep_dir.join("channels-tokio-runtime.md").exists());
+
+        let metadata = read_json(&keep_dir.join("metadata.json"));
+        assert_eq!(metadata["schema_version"], 1);
+        assert_eq!(metadata["brief"], "Async runtime for Rust");
+        assert_eq!(metadata["summary"], "Tokio runtime summary");
+        assert_eq!(
+            metadata["additional_files"]["question_2.md"],
+            "How do I spawn blocking tasks?"
+        );
+        assert_eq!(
+            metadata["additional_files"]["channels-tokio-runtime.md"],
+            "Which channels exist?"
+        );
+        assert_eq!(metadata["question_tags"]["question_1.md"][0], "reliability");
+        assert_eq!(metadata["question_tags"]["question_2.md"][0], "async");
+
+        // Tag indexes are refreshed from the merged library
+        let index = std::fs::read_to_string(temp.path().join("tags/async.md")).unwrap();
+        assert!(index.contains("../library/tokio/question_2.md"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_merge_removes_links_into_merged_topic() {
+        let temp = TempDir::new().unwrap();
+        let library = temp.path().join("library");
+        write_topic(&library, "tokio", "{}", &[]);
+        let merge_dir = write_topic(&library, "tokio-runtime", "{}", &[]);
+        std::fs::create_dir_all(merge_dir.join("skill")).unwrap();
+
+        let skills = temp.path().join("skills");
+        std::fs::create_dir_all(&skills).unwrap();
+        std::os::unix::fs::symlink(merge_dir.join("skill"), skills.join("tokio-runtime")).unwrap();
+        std::os::unix::fs::symlink(library.join("tokio"), skills.join("tokio")).unwrap();
+
+        let plan = merge_topics(
+            &library,
+            "tokio",
+            "tokio-runtime",
+            std::slice::from_ref(&skills),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(plan.removed_links, vec![skills.join("tokio-runtime")]);
+        assert!(std::fs::symlink_metadata(skills.join("tokio-runtime")).is_err());
+        assert!(std::fs::symlink_metadata(skills.join("tokio")).is_ok());
+    }
+
+    #[test]
+    fn test_merge_rejects_invalid_requests() {
+        let temp = TempDir::new().unwrap();
+        let library = temp.path();
+        write_topic(library, "tokio", "{}", &[]);
+        write_topic(library, "broken", "not json", &[]);
+
+        assert!(matches!(
+            merge_topics(library, "tokio", "tokio", &[], true),
+            Err(DedupeError::SameTopic(_))
+        ));
+        assert!(matches!(
+            merge_topics(library, "tokio", "missing", &[], true),
+            Err(DedupeError::TopicNotFound(_))
+        ));
+        assert!(matches!(
+            merge_topics(library, "tokio", "broken", &[], true),
+            Err(DedupeError::InvalidMetadata { .. })
+        ));
+    }
+}
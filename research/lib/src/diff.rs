@@ -0,0 +1,311 @@
+//! Section-level comparison of two research snapshots.
+//!
+//! Refreshing stale research rewrites whole documents, which makes it hard to
+//! see what actually changed. [`research_diff()`] compares the overview,
+//! changelog and deep dive of an earlier run with the current ones using
+//! darkmatter's structural markdown delta, and reports which sections were
+//! added, removed, modified or moved in each.
+//!
+//! ```no_run
+//! use research_lib::diff::research_diff;
+//!
+//! # async fn example() -> Result<(), research_lib::diff::DiffError> {
+//! let diff = research_diff(
+//!     "clap",
+//!     "backup/library/clap".as_ref(),
+//!     "research/library/clap".as_ref(),
+//! )
+//! .await?;
+//! print!("{}", diff);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use darkmatter_lib::markdown::{Markdown, MarkdownDelta, SectionPath};
+use serde::Serialize;
+use thiserror::Error;
+use tokio::fs;
+use tracing::instrument;
+
+/// Errors that can occur while comparing research snapshots.
+#[derive(Debug, Error)]
+pub enum DiffError {
+    /// One of the snapshot directories does not exist
+    #[error("Research directory not found: {0}")]
+    NotFound(PathBuf),
+
+    /// Failed to read a document
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// How one document differs between two snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "status", content = "delta", rename_all = "snake_case")]
+pub enum DocumentStatus {
+    /// Only the new snapshot has the document
+    Added,
+    /// Only the old snapshot has the document
+    Removed,
+    /// Both snapshots have the document with the same content
+    Unchanged,
+    /// Both snapshots have the document and its content differs
+    Changed(Box<MarkdownDelta>),
+}
+
+/// The comparison of one research document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DocumentDiff {
+    /// The document: `overview`, `changelog` or `deep_dive`
+    pub document: &'static str,
+    /// What changed
+    #[serde(flatten)]
+    pub status: DocumentStatus,
+}
+
+/// The comparison of two research snapshots of a topic.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResearchDiff {
+    /// The topic both snapshots research
+    pub topic: String,
+    /// The earlier snapshot
+    pub old_dir: PathBuf,
+    /// The later snapshot
+    pub new_dir: PathBuf,
+    /// One entry per document found in either snapshot, in reading order
+    pub documents: Vec<DocumentDiff>,
+}
+
+impl ResearchDiff {
+    /// Returns true if no document was added, removed or changed.
+    pub fn is_unchanged(&self) -> bool {
+        self.documents
+            .iter()
+            .all(|doc| doc.status == DocumentStatus::Unchanged)
+    }
+
+    /// The documents that were added, removed or changed.
+    pub fn changed(&self) -> impl Iterator<Item = &DocumentDiff> {
+        self.documents
+            .iter()
+            .filter(|doc| doc.status != DocumentStatus::Unchanged)
+    }
+}
+
+impl fmt::Display for ResearchDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.documents.is_empty() {
+            return writeln!(f, "No research documents found for {}", self.topic);
+        }
+
+        for doc in &self.documents {
+            match &doc.status {
+                DocumentStatus::Added => writeln!(f, "{}: added", doc.document)?,
+                DocumentStatus::Removed => writeln!(f, "{}: removed", doc.document)?,
+                DocumentStatus::Unchanged => writeln!(f, "{}: unchanged", doc.document)?,
+                DocumentStatus::Changed(delta) => {
+                    writeln!(f, "{}: {}", doc.document, delta.summary())?;
+                    write_sections(f, delta)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Lists the sections a delta touches, one per line.
+fn write_sections(f: &mut fmt::Formatter<'_>, delta: &MarkdownDelta) -> fmt::Result {
+    for change in &delta.added {
+        writeln!(f, "  + {}", section(change.new_path.as_ref()))?;
+    }
+    for change in &delta.removed {
+        writeln!(f, "  - {}", section(change.original_path.as_ref()))?;
+    }
+    for change in &delta.modified {
+        writeln!(f, "  ~ {}", section(change.new_path.as_ref()))?;
+    }
+    for moved in &delta.moved {
+        writeln!(
+            f,
+            "  > {} (moved from {})",
+            section(Some(&moved.new_path)),
+            section(Some(&moved.original_path))
+        )?;
+    }
+    Ok(())
+}
+
+/// A section's headings, outermost first.
+fn section(path: Option<&SectionPath>) -> String {
+    match path {
+        Some(path) if !path.is_empty() => path.join(" > "),
+        _ => "(preamble)".to_string(),
+    }
+}
+
+/// The documents compared, with the files each may be stored in.
+fn document_files(topic: &str) -> [(&'static str, Vec<String>); 3] {
+    [
+        ("overview", vec!["overview.md".to_string()]),
+        ("changelog", vec!["changelog.md".to_string()]),
+        (
+            "deep_dive",
+            vec![
+                format!("deep-dive/{}.md", topic),
+                "deep_dive.md".to_string(),
+            ],
+        ),
+    ]
+}
+
+/// Reads the first of `candidates` that exists in `dir`.
+async fn read_document(dir: &Path, candidates: &[String]) -> Result<Option<String>, DiffError> {
+    for filename in candidates {
+        let path = dir.join(filename);
+        match fs::read_to_string(&path).await {
+            Ok(content) => return Ok(Some(content)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(source) => return Err(DiffError::Io { path, source }),
+        }
+    }
+    Ok(None)
+}
+
+/// Compares the overview, changelog and deep dive of two snapshots of `topic`.
+///
+/// The deep dive is read from `deep-dive/{topic}.md`, or the legacy
+/// `deep_dive.md`. Documents missing from both snapshots are left out.
+///
+/// ## Errors
+///
+/// Returns [`DiffError::NotFound`] if either directory does not exist, or
+/// [`DiffError::Io`] if a document can't be read.
+#[instrument(skip(old_dir, new_dir))]
+pub async fn research_diff(
+    topic: &str,
+    old_dir: &Path,
+    new_dir: &Path,
+) -> Result<ResearchDiff, DiffError> {
+    for dir in [old_dir, new_dir] {
+        if !dir.is_dir() {
+            return Err(DiffError::NotFound(dir.to_path_buf()));
+        }
+    }
+
+    let mut documents = Vec::new();
+    for (document, candidates) in document_files(topic) {
+        let old = read_document(old_dir, &candidates).await?;
+        let new = read_document(new_dir, &candidates).await?;
+        let status = match (old, new) {
+            (None, None) => continue,
+            (None, Some(_)) => DocumentStatus::Added,
+            (Some(_), None) => DocumentStatus::Removed,
+            (Some(old), Some(new)) => {
+                let delta = Markdown::from(old).delta(&Markdown::from(new));
+                if delta.is_unchanged() {
+                    DocumentStatus::Unchanged
+                } else {
+                    DocumentStatus::Changed(Box::new(delta))
+                }
+            }
+        };
+        documents.push(DocumentDiff { document, status });
+    }
+
+    Ok(ResearchDiff {
+        topic: topic.to_string(),
+        old_dir: old_dir.to_path_buf(),
+        new_dir: new_dir.to_path_buf(),
+        documents,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn snapshot(files: &[(&str, &str)]) -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for (name, content) in files {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[tokio::test]
+    async fn reports_changed_sections_per_document() {
+        let overview =
+            "# clap\n\n## Features\n\nDerive API.\n\n## Installation\n\ncargo add clap\n";
+        let old = snapshot(&[
+            ("overview.md", overview),
+            ("changelog.md", "# Changelog\n\n## 4.5\n\nStyling.\n"),
+            ("deep_dive.md", "# Deep Dive\n\nOld notes.\n"),
+        ]);
+        let new = snapshot(&[
+            ("overview.md", overview),
+            (
+                "changelog.md",
+                "# Changelog\n\n## 4.6\n\nNew parser.\n\n## 4.5\n\nStyling.\n",
+            ),
+            ("deep-dive/clap.md", "# Deep Dive\n\nOld notes.\n"),
+        ]);
+
+        let diff = research_diff("clap", old.path(), new.path()).await.unwrap();
+
+        let statuses: Vec<_> = diff.documents.iter().map(|doc| doc.document).collect();
+        assert_eq!(statuses, ["overview", "changelog", "deep_dive"]);
+        assert_eq!(diff.documents[0].status, DocumentStatus::Unchanged);
+        assert_eq!(diff.documents[2].status, DocumentStatus::Unchanged);
+
+        let DocumentStatus::Changed(delta) = &diff.documents[1].status else {
+            panic!("changelog should have changed: {:?}", diff.documents[1]);
+        };
+        assert_eq!(delta.added.len(), 1);
+        assert!(!diff.is_unchanged());
+        assert_eq!(diff.changed().count(), 1);
+        assert!(diff.to_string().contains("  + Changelog > 4.6"), "{}", diff);
+    }
+
+    #[tokio::test]
+    async fn documents_only_in_one_snapshot_are_added_or_removed() {
+        let old = snapshot(&[("changelog.md", "# Changelog\n")]);
+        let new = snapshot(&[("overview.md", "# clap\n")]);
+
+        let diff = research_diff("clap", old.path(), new.path()).await.unwrap();
+
+        assert_eq!(
+            diff.documents,
+            [
+                DocumentDiff {
+                    document: "overview",
+                    status: DocumentStatus::Added,
+                },
+                DocumentDiff {
+                    document: "changelog",
+                    status: DocumentStatus::Removed,
+                },
+            ]
+        );
+        assert_eq!(diff.to_string(), "overview: added\nchangelog: removed\n");
+    }
+
+    #[tokio::test]
+    async fn missing_snapshot_is_an_error() {
+        let new = snapshot(&[]);
+        let missing = new.path().join("missing");
+
+        let result = research_diff("clap", &missing, new.path()).await;
+
+        assert!(matches!(result, Err(DiffError::NotFound(dir)) if dir == missing));
+    }
+}
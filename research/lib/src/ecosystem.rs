@@ -0,0 +1,150 @@
+//! Package ecosystems with their own library prompt variants.
+//!
+//! The standard library prompts are language-neutral, which leaves models to
+//! guess at idioms and install commands. Libraries from a known [`Ecosystem`]
+//! are researched with prompt variants written for it instead (`cargo add`
+//! and feature flags for Rust, ESM and bundled types for JavaScript, extras
+//! and type hints for Python, ...).
+//!
+//! The ecosystem is picked from the library's language, e.g. `"Rust"` for a
+//! crates.io match, and can be overridden with
+//! [`ResearchOptions::with_ecosystem`](crate::ResearchOptions::with_ecosystem).
+//! Software and standard research always use their own prompts.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A package ecosystem whose libraries get their own Phase 1 prompts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Ecosystem {
+    /// The language-neutral library prompts
+    #[default]
+    Generic,
+    Rust,
+    /// JavaScript and TypeScript
+    JavaScript,
+    Python,
+    Go,
+    Php,
+}
+
+/// Error returned when parsing an unknown ecosystem name.
+#[derive(Debug, Error)]
+#[error("Unknown ecosystem '{0}' (expected rust, javascript, python, go, php or generic)")]
+pub struct UnknownEcosystem(pub String);
+
+impl Ecosystem {
+    /// All ecosystems, the generic prompts first.
+    pub const ALL: [Ecosystem; 6] = [
+        Ecosystem::Generic,
+        Ecosystem::Rust,
+        Ecosystem::JavaScript,
+        Ecosystem::Python,
+        Ecosystem::Go,
+        Ecosystem::Php,
+    ];
+
+    /// The ecosystem's name, as accepted by `--ecosystem`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Ecosystem::Generic => "generic",
+            Ecosystem::Rust => "rust",
+            Ecosystem::JavaScript => "javascript",
+            Ecosystem::Python => "python",
+            Ecosystem::Go => "go",
+            Ecosystem::Php => "php",
+        }
+    }
+
+    /// Picks the ecosystem for a library written in `language`.
+    ///
+    /// Languages without their own prompts map to [`Ecosystem::Generic`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use research_lib::ecosystem::Ecosystem;
+    ///
+    /// assert_eq!(Ecosystem::from_language("Rust"), Ecosystem::Rust);
+    /// assert_eq!(Ecosystem::from_language("JavaScript/TypeScript"), Ecosystem::JavaScript);
+    /// assert_eq!(Ecosystem::from_language("Lua"), Ecosystem::Generic);
+    /// ```
+    pub fn from_language(language: &str) -> Self {
+        language
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .find_map(|word| word.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Returns `choice` if set, otherwise the ecosystem for `language`.
+    pub fn resolve(choice: Option<Ecosystem>, language: Option<&str>) -> Self {
+        choice.unwrap_or_else(|| language.map(Self::from_language).unwrap_or_default())
+    }
+}
+
+impl fmt::Display for Ecosystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Ecosystem {
+    type Err = UnknownEcosystem;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "generic" | "none" => Ok(Ecosystem::Generic),
+            "rust" | "rs" => Ok(Ecosystem::Rust),
+            "javascript" | "js" | "typescript" | "ts" | "node" => Ok(Ecosystem::JavaScript),
+            "python" | "py" => Ok(Ecosystem::Python),
+            "go" | "golang" => Ok(Ecosystem::Go),
+            "php" => Ok(Ecosystem::Php),
+            _ => Err(UnknownEcosystem(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn package_manager_languages_map_to_their_ecosystem() {
+        let cases = [
+            ("Rust", Ecosystem::Rust),
+            ("JavaScript/TypeScript", Ecosystem::JavaScript),
+            ("TypeScript", Ecosystem::JavaScript),
+            ("Python", Ecosystem::Python),
+            ("PHP", Ecosystem::Php),
+            ("Go", Ecosystem::Go),
+            ("Lua", Ecosystem::Generic),
+            ("unknown", Ecosystem::Generic),
+        ];
+        for (language, ecosystem) in cases {
+            assert_eq!(Ecosystem::from_language(language), ecosystem, "{language}");
+        }
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for ecosystem in Ecosystem::ALL {
+            assert_eq!(ecosystem.name().parse::<Ecosystem>().unwrap(), ecosystem);
+        }
+        assert_eq!("TS".parse::<Ecosystem>().unwrap(), Ecosystem::JavaScript);
+        assert!("cobol".parse::<Ecosystem>().is_err());
+    }
+
+    #[test]
+    fn an_explicit_choice_overrides_the_language() {
+        assert_eq!(
+            Ecosystem::resolve(Some(Ecosystem::Generic), Some("Rust")),
+            Ecosystem::Generic
+        );
+        assert_eq!(Ecosystem::resolve(None, Some("Python")), Ecosystem::Python);
+        assert_eq!(Ecosystem::resolve(None, None), Ecosystem::Generic);
+    }
+}
@@ -0,0 +1,474 @@
+//! Bundled HTML and PDF reports of a topic's research.
+//!
+//! A research run leaves a topic's findings spread over several markdown
+//! files. [`export()`] bundles the overview, use cases, changelog, deep dive
+//! and additional questions into a single navigable document: an HTML page
+//! rendered with darkmatter, opening with a table of contents that links to
+//! each section, or a PDF printed from that page.
+//!
+//! PDFs are produced by the first converter found on the `PATH`: WeasyPrint,
+//! wkhtmltopdf or a headless Chromium/Chrome.
+//!
+//! ```no_run
+//! use research_lib::export::{ExportFormat, export};
+//!
+//! # async fn example() -> Result<(), research_lib::export::ExportError> {
+//! let report = export("research/library/clap".as_ref(), ExportFormat::Html, None).await?;
+//! println!("Report written to {}", report.display());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use darkmatter_lib::markdown::highlighting::ColorMode;
+use darkmatter_lib::markdown::output::HtmlOptions;
+use darkmatter_lib::markdown::{HeadingLevel, Markdown, MarkdownError};
+use thiserror::Error;
+use tokio::fs;
+use tokio::process::Command;
+use tracing::{debug, instrument};
+
+use crate::ResearchMetadata;
+
+/// File stem of the report written into the topic directory by default.
+pub const REPORT_STEM: &str = "report";
+
+/// PDF converters tried in order.
+const PDF_CONVERTERS: [&str; 5] = [
+    "weasyprint",
+    "wkhtmltopdf",
+    "chromium",
+    "chromium-browser",
+    "google-chrome",
+];
+
+/// Layout of the bundled report; darkmatter supplies the code and prose styles.
+const REPORT_CSS: &str = "\
+body { max-width: 52em; margin: 2em auto; padding: 0 1em; font-family: system-ui, sans-serif; line-height: 1.5; }
+nav.toc { border-bottom: 1px solid #ddd; margin-bottom: 2em; }
+nav.toc ol { padding-left: 1.5em; }
+nav.toc a { text-decoration: none; }
+section.report-section { break-before: page; }
+";
+
+/// The document format of an exported report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    Html,
+    Pdf,
+}
+
+/// Error returned when parsing an unknown export format.
+#[derive(Debug, Error)]
+#[error("Unknown export format '{0}' (expected html or pdf)")]
+pub struct UnknownExportFormat(pub String);
+
+impl ExportFormat {
+    /// The file extension of reports in this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Html => "html",
+            ExportFormat::Pdf => "pdf",
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = UnknownExportFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "html" | "htm" => Ok(ExportFormat::Html),
+            "pdf" => Ok(ExportFormat::Pdf),
+            _ => Err(UnknownExportFormat(s.to_string())),
+        }
+    }
+}
+
+/// Errors that can occur while exporting a report.
+#[derive(Debug, Error)]
+pub enum ExportError {
+    /// The topic's research directory was not found
+    #[error("Research directory not found: {0}")]
+    NotFound(PathBuf),
+
+    /// The directory holds none of the documents a report is made of
+    #[error("No research documents to export in {0}")]
+    Empty(PathBuf),
+
+    /// Failed to read a document or write the report
+    #[error("Export I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// darkmatter failed to render a section
+    #[error("Failed to render {section}: {source}")]
+    Render {
+        section: String,
+        #[source]
+        source: MarkdownError,
+    },
+
+    /// None of the PDF converters is installed
+    #[error("No PDF converter found (install weasyprint, wkhtmltopdf or Chromium)")]
+    NoPdfConverter,
+
+    /// A PDF converter ran but failed
+    #[error("{tool} failed: {message}")]
+    Converter { tool: &'static str, message: String },
+}
+
+/// A section of the report, with its entry in the table of contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportSection {
+    /// Anchor the table of contents links to
+    pub id: String,
+    /// Title shown in the table of contents and above the section
+    pub title: String,
+    /// The section's markdown; empty for sections that only group others
+    pub markdown: String,
+    /// Nested sections, e.g. one per additional question
+    pub children: Vec<ReportSection>,
+}
+
+impl ReportSection {
+    /// Creates a section without children.
+    pub fn new(id: impl Into<String>, title: impl Into<String>, markdown: String) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            markdown,
+            children: Vec::new(),
+        }
+    }
+}
+
+/// Reads the documents of the topic in `output_dir` into report sections.
+///
+/// Sections come in reading order: overview, use cases, changelog, deep dive
+/// (`deep-dive/{topic}.md`, or the legacy `deep_dive.md`) and the additional
+/// questions recorded in the topic's metadata. Missing documents are skipped.
+pub async fn collect_sections(output_dir: &Path) -> Vec<ReportSection> {
+    let topic = output_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut sections = Vec::new();
+
+    let documents = [
+        ("overview", "Overview", vec!["overview.md".to_string()]),
+        ("use-cases", "Use Cases", vec!["use_cases.md".to_string()]),
+        ("changelog", "Changelog", vec!["changelog.md".to_string()]),
+        (
+            "deep-dive",
+            "Deep Dive",
+            vec![
+                format!("deep-dive/{}.md", topic),
+                "deep_dive.md".to_string(),
+            ],
+        ),
+    ];
+    for (id, title, candidates) in documents {
+        for filename in candidates {
+            if let Ok(markdown) = fs::read_to_string(output_dir.join(&filename)).await {
+                sections.push(ReportSection::new(id, title, markdown));
+                break;
+            }
+        }
+    }
+
+    if let Some(metadata) = ResearchMetadata::load(output_dir).await {
+        let mut questions: Vec<_> = metadata.additional_files.iter().collect();
+        questions.sort_by_key(|(filename, _)| (filename.len(), filename.as_str()));
+
+        let mut children = Vec::new();
+        for (filename, prompt) in questions {
+            match fs::read_to_string(output_dir.join(filename)).await {
+                Ok(markdown) => {
+                    let id = filename.trim_end_matches(".md").replace('_', "-");
+                    children.push(ReportSection::new(id, prompt.as_str(), markdown));
+                }
+                Err(e) => debug!(filename, error = %e, "Skipping unreadable question"),
+            }
+        }
+        if !children.is_empty() {
+            let mut section = ReportSection::new("questions", "Questions", String::new());
+            section.children = children;
+            sections.push(section);
+        }
+    }
+
+    sections
+}
+
+/// Renders `sections` as a standalone HTML page titled `title`.
+///
+/// Each section's headings are shifted below the section title, and the page
+/// opens with a table of contents linking to every section.
+///
+/// ## Errors
+///
+/// Returns an error if darkmatter fails to render a section.
+pub fn render_html(
+    title: &str,
+    sections: &[ReportSection],
+    options: &HtmlOptions,
+) -> Result<String, ExportError> {
+    let mut section_options = options.clone();
+    section_options.include_styles = false;
+
+    // darkmatter emits its styles ahead of the content, so an empty document
+    // renders just the styles
+    let styles = if options.include_styles {
+        Markdown::from("")
+            .as_html(options.clone())
+            .map_err(|source| ExportError::Render {
+                section: "styles".to_string(),
+                source,
+            })?
+    } else {
+        String::new()
+    };
+
+    let mut body = String::new();
+    for section in sections {
+        render_section(section, 1, &section_options, &mut body)?;
+    }
+
+    Ok(format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>\n{REPORT_CSS}</style>\n{styles}</head>\n<body>\n<h1>{title}</h1>\n\
+         {toc}{body}</body>\n</html>\n",
+        title = escape(title),
+        toc = table_of_contents(sections),
+    ))
+}
+
+/// Renders `section` and its children, with the section title at `level`.
+fn render_section(
+    section: &ReportSection,
+    level: u8,
+    options: &HtmlOptions,
+    output: &mut String,
+) -> Result<(), ExportError> {
+    output.push_str(&format!(
+        "<section class=\"report-section\" id=\"{id}\">\n<h{level}>{title}</h{level}>\n",
+        id = escape(&section.id),
+        title = escape(&section.title),
+    ));
+
+    if !section.markdown.trim().is_empty() {
+        let md = Markdown::from(section.markdown.as_str());
+        let md = match heading_level(level + 1) {
+            Some(target) => md.relevel(target).map_or(md, |(releveled, _)| releveled),
+            None => md,
+        };
+        let html = md
+            .as_html(options.clone())
+            .map_err(|source| ExportError::Render {
+                section: section.title.clone(),
+                source,
+            })?;
+        output.push_str(&html);
+    }
+
+    for child in &section.children {
+        render_section(child, level + 1, options, output)?;
+    }
+    output.push_str("</section>\n");
+    Ok(())
+}
+
+fn heading_level(level: u8) -> Option<HeadingLevel> {
+    match level {
+        1 => Some(HeadingLevel::H1),
+        2 => Some(HeadingLevel::H2),
+        3 => Some(HeadingLevel::H3),
+        4 => Some(HeadingLevel::H4),
+        5 => Some(HeadingLevel::H5),
+        6 => Some(HeadingLevel::H6),
+        _ => None,
+    }
+}
+
+/// Builds the table of contents for `sections` as nested ordered lists.
+fn table_of_contents(sections: &[ReportSection]) -> String {
+    fn entries(sections: &[ReportSection], output: &mut String) {
+        output.push_str("<ol>\n");
+        for section in sections {
+            output.push_str(&format!(
+                "<li><a href=\"#{}\">{}</a>",
+                escape(&section.id),
+                escape(&section.title)
+            ));
+            if !section.children.is_empty() {
+                output.push('\n');
+                entries(&section.children, output);
+            }
+            output.push_str("</li>\n");
+        }
+        output.push_str("</ol>\n");
+    }
+
+    let mut output = String::from("<nav class=\"toc\">\n<h2>Contents</h2>\n");
+    entries(sections, &mut output);
+    output.push_str("</nav>\n");
+    output
+}
+
+/// Escapes text for use in HTML content and attribute values.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Exports the research in `output_dir` as a single report.
+///
+/// The report is written to `destination`, or to `report.html`/`report.pdf`
+/// in `output_dir`, and its path returned.
+///
+/// ## Errors
+///
+/// Returns an error if the directory holds no research documents, rendering
+/// or writing fails, or no PDF converter is available.
+#[instrument]
+pub async fn export(
+    output_dir: &Path,
+    format: ExportFormat,
+    destination: Option<&Path>,
+) -> Result<PathBuf, ExportError> {
+    if !output_dir.is_dir() {
+        return Err(ExportError::NotFound(output_dir.to_path_buf()));
+    }
+    let sections = collect_sections(output_dir).await;
+    if sections.is_empty() {
+        return Err(ExportError::Empty(output_dir.to_path_buf()));
+    }
+
+    let title = output_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "Research".to_string());
+    let mut options = HtmlOptions::default();
+    // Reports are read on a page, not in a dark terminal
+    options.color_mode = ColorMode::Light;
+    let html = render_html(&title, &sections, &options)?;
+
+    let destination = destination.map(Path::to_path_buf).unwrap_or_else(|| {
+        output_dir
+            .join(REPORT_STEM)
+            .with_extension(format.extension())
+    });
+    if let Some(parent) = destination.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).await?;
+    }
+
+    match format {
+        ExportFormat::Html => fs::write(&destination, html).await?,
+        ExportFormat::Pdf => {
+            let page = destination.with_extension("export.html");
+            fs::write(&page, html).await?;
+            let converted = html_to_pdf(&page, &destination).await;
+            let _ = fs::remove_file(&page).await;
+            converted?;
+        }
+    }
+    Ok(destination)
+}
+
+/// Prints the HTML page at `html` to `pdf` with the first available converter.
+async fn html_to_pdf(html: &Path, pdf: &Path) -> Result<(), ExportError> {
+    for tool in PDF_CONVERTERS {
+        let mut command = Command::new(tool);
+        match tool {
+            "weasyprint" | "wkhtmltopdf" => {
+                command.arg(html).arg(pdf);
+            }
+            _ => {
+                command
+                    .args(["--headless", "--disable-gpu", "--no-pdf-header-footer"])
+                    .arg(format!("--print-to-pdf={}", pdf.display()))
+                    .arg(html);
+            }
+        }
+
+        let output = match command.output().await {
+            Ok(output) => output,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => {
+                return Err(ExportError::Converter {
+                    tool,
+                    message: e.to_string(),
+                });
+            }
+        };
+        if !output.status.success() {
+            return Err(ExportError::Converter {
+                tool,
+                message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+        debug!(tool, "Converted report to PDF");
+        return Ok(());
+    }
+    Err(ExportError::NoPdfConverter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn sections_follow_reading_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let topic = dir.path().join("clap");
+        std::fs::create_dir_all(topic.join("deep-dive")).unwrap();
+        std::fs::write(topic.join("changelog.md"), "# Changelog").unwrap();
+        std::fs::write(topic.join("overview.md"), "# Overview").unwrap();
+        std::fs::write(topic.join("deep-dive/clap.md"), "# Deep Dive").unwrap();
+        std::fs::write(topic.join("deep_dive.md"), "# Legacy").unwrap();
+
+        let sections = collect_sections(&topic).await;
+
+        let ids: Vec<_> = sections.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["overview", "changelog", "deep-dive"]);
+        assert_eq!(sections[2].markdown, "# Deep Dive");
+    }
+
+    #[test]
+    fn report_opens_with_a_linked_table_of_contents() {
+        let mut questions = ReportSection::new("questions", "Questions", String::new());
+        questions.children = vec![ReportSection::new(
+            "question-1",
+            "How do <T> errors work?",
+            "# Errors\n\nReturned as `Result`.".to_string(),
+        )];
+        let sections = [
+            ReportSection::new("overview", "Overview", "# Overview\n\nA parser.".into()),
+            questions,
+        ];
+
+        let html = render_html("clap", &sections, &HtmlOptions::default()).unwrap();
+
+        let toc = html.find("<nav class=\"toc\">").unwrap();
+        let body = html.find("<section").unwrap();
+        assert!(toc < body);
+        assert!(html.contains("<a href=\"#overview\">Overview</a>"));
+        assert!(html.contains("<a href=\"#question-1\">How do &lt;T&gt; errors work?</a>"));
+        assert!(html.contains("id=\"question-1\""));
+        // Section headings sit below the section title
+        assert!(html.contains("<h2>Overview</h2>"));
+        assert!(html.contains("<h3>Errors</h3>"));
+    }
+}
@@ -0,0 +1,716 @@
+//! Glossary generation across the research library.
+//!
+//! Scans every topic's brief and deep dive, asks a model to extract domain
+//! terms with definitions, and maintains `glossary.md` at the library root
+//! with backlinks to the topics each term came from.
+//!
+//! Extracted terms are cached per topic in `.glossary-cache.json`, so a run
+//! only sends topics whose sources changed since the last extraction. Runs
+//! within the configured refresh interval are skipped entirely, which makes
+//! [`glossary()`] cheap to call periodically (e.g. after each research run or
+//! from a scheduled job).
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use research_lib::glossary::{GlossaryConfig, glossary};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let result = glossary(&GlossaryConfig::default().force(true)).await?;
+//!     println!("{} terms written to {:?}", result.terms, result.path);
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use rig::client::{CompletionClient, ProviderClient};
+use rig::completion::{AssistantContent, CompletionModel};
+use rig::providers::gemini;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{debug, info, instrument, warn};
+
+use crate::list::types::ResearchOutput;
+
+/// Filename of the generated glossary within the library directory.
+pub const GLOSSARY_FILENAME: &str = "glossary.md";
+
+/// Filename of the per-topic term cache within the library directory.
+const CACHE_FILENAME: &str = ".glossary-cache.json";
+
+/// Errors that can occur while generating the glossary.
+#[derive(Debug, Error)]
+pub enum GlossaryError {
+    /// The research library directory was not found
+    #[error("Research library directory not found: {0}")]
+    LibraryNotFound(PathBuf),
+
+    /// Failed to read sources or write the glossary
+    #[error("Glossary I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Failed to serialize the term cache
+    #[error("Failed to serialize glossary cache: {0}")]
+    Cache(#[from] serde_json::Error),
+}
+
+/// Configuration for [`glossary()`].
+#[derive(Debug, Clone)]
+pub struct GlossaryConfig {
+    /// The research library directory (see [`ResearchPaths::library_dir`](crate::paths::ResearchPaths::library_dir))
+    pub library_dir: PathBuf,
+    /// Skip the run when `glossary.md` was written more recently than this.
+    /// `None` always checks sources for changes.
+    pub refresh_interval: Option<Duration>,
+    /// Re-extract terms for every topic, ignoring the interval and the cache
+    pub force: bool,
+    /// Model used for term extraction
+    pub model: String,
+    /// Maximum number of terms requested per topic
+    pub max_terms_per_topic: usize,
+    /// Maximum characters of source material sent per topic
+    pub max_source_chars: usize,
+    /// Number of topics extracted concurrently
+    pub concurrency: usize,
+}
+
+impl Default for GlossaryConfig {
+    fn default() -> Self {
+        Self {
+            library_dir: crate::paths::ResearchPaths::from_env().library_dir(),
+            refresh_interval: Some(Duration::from_secs(24 * 60 * 60)),
+            force: false,
+            model: "gemini-3-flash-preview".to_string(),
+            max_terms_per_topic: 15,
+            max_source_chars: 40_000,
+            concurrency: 4,
+        }
+    }
+}
+
+impl GlossaryConfig {
+    /// Use a different research library directory.
+    pub fn library_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.library_dir = dir.into();
+        self
+    }
+
+    /// Set the minimum time between glossary runs (`None` disables the check).
+    pub fn refresh_interval(mut self, interval: Option<Duration>) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+
+    /// Re-extract terms for every topic regardless of interval and cache.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Use a different extraction model.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Path of the generated glossary.
+    pub fn glossary_path(&self) -> PathBuf {
+        self.library_dir.join(GLOSSARY_FILENAME)
+    }
+}
+
+/// Outcome of a [`glossary()`] run.
+#[derive(Debug, Clone)]
+pub struct GlossaryResult {
+    /// Path of the glossary file
+    pub path: PathBuf,
+    /// True when the run was skipped because the glossary is within its refresh interval
+    pub skipped: bool,
+    /// Number of topics with a brief or deep dive
+    pub topics: usize,
+    /// Number of topics whose terms were (re-)extracted in this run
+    pub extracted: usize,
+    /// Number of topics whose extraction failed (previous terms are kept)
+    pub failed: usize,
+    /// Number of distinct terms in the glossary
+    pub terms: usize,
+}
+
+/// A single term extracted from a topic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TermDefinition {
+    pub term: String,
+    pub definition: String,
+}
+
+/// A merged glossary entry with backlinks to its source topics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlossaryEntry {
+    pub term: String,
+    pub definition: String,
+    /// Topics the term was extracted from (sorted)
+    pub sources: Vec<String>,
+}
+
+/// Cached terms for one topic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedTopic {
+    /// Latest modification time of the topic's sources when terms were extracted
+    sources_modified: DateTime<Utc>,
+    terms: Vec<TermDefinition>,
+}
+
+/// Per-topic term cache stored next to the glossary.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GlossaryCache {
+    #[serde(default)]
+    topics: BTreeMap<String, CachedTopic>,
+}
+
+impl GlossaryCache {
+    fn load(library_dir: &Path) -> Self {
+        std::fs::read_to_string(library_dir.join(CACHE_FILENAME))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, library_dir: &Path) -> Result<(), GlossaryError> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(library_dir.join(CACHE_FILENAME), content)?;
+        Ok(())
+    }
+}
+
+/// The brief and deep dive of a topic.
+#[derive(Debug, Clone)]
+pub struct TopicSources {
+    pub topic: String,
+    /// Source files, relative to the library directory
+    pub files: Vec<PathBuf>,
+    /// Latest modification time across `files`
+    pub modified: DateTime<Utc>,
+}
+
+/// Finds the brief and deep dive of every topic under `library_dir`.
+///
+/// Topics without either file are skipped. Topics are returned sorted by name.
+pub fn collect_topic_sources(library_dir: &Path) -> Result<Vec<TopicSources>, GlossaryError> {
+    if !library_dir.is_dir() {
+        return Err(GlossaryError::LibraryNotFound(library_dir.to_path_buf()));
+    }
+
+    let mut sources = Vec::new();
+    for entry in std::fs::read_dir(library_dir)?.flatten() {
+        if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            continue;
+        }
+        let topic = entry.file_name().to_string_lossy().to_string();
+
+        let candidates = [
+            PathBuf::from(&topic).join(ResearchOutput::Brief.path_for(&topic)),
+            PathBuf::from(&topic).join(ResearchOutput::DeepDive.path_for(&topic)),
+            // Legacy deep dive location
+            PathBuf::from(&topic).join("deep_dive.md"),
+        ];
+
+        let mut files = Vec::new();
+        let mut modified: Option<SystemTime> = None;
+        for candidate in candidates {
+            if let Ok(meta) = std::fs::metadata(library_dir.join(&candidate))
+                && meta.is_file()
+            {
+                if let Ok(time) = meta.modified() {
+                    modified = Some(modified.map_or(time, |m| m.max(time)));
+                }
+                files.push(candidate);
+            }
+        }
+
+        if !files.is_empty() {
+            sources.push(TopicSources {
+                topic,
+                files,
+                modified: modified.map(DateTime::<Utc>::from).unwrap_or_else(Utc::now),
+            });
+        }
+    }
+
+    sources.sort_by(|a, b| a.topic.cmp(&b.topic));
+    Ok(sources)
+}
+
+/// Returns true if the glossary at `path` was written within `interval`.
+pub fn is_within_refresh_interval(path: &Path, interval: Duration) -> bool {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age < interval)
+}
+
+/// Parses `TERM: ... | DEFINITION: ...` lines from a model response.
+///
+/// Lines that don't follow the format are ignored, as are leading list
+/// markers and surrounding bold markers on the term.
+pub fn parse_glossary_response(response: &str) -> Vec<TermDefinition> {
+    response
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim().trim_start_matches(['-', '*']).trim_start();
+            let rest = line.strip_prefix("TERM:")?;
+            let (term, definition) = rest.split_once("| DEFINITION:")?;
+            let term = term.trim().trim_matches('*').trim();
+            let definition = definition.trim();
+            if term.is_empty() || definition.is_empty() {
+                return None;
+            }
+            Some(TermDefinition {
+                term: term.to_string(),
+                definition: definition.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Merges per-topic terms into glossary entries.
+///
+/// Terms are matched case-insensitively. The definition comes from the first
+/// topic (by name) that defined the term; every defining topic is listed as a
+/// source. Entries are sorted alphabetically by term.
+pub fn merge_terms<'a>(
+    topics: impl IntoIterator<Item = (&'a str, &'a [TermDefinition])>,
+) -> Vec<GlossaryEntry> {
+    let mut topics: Vec<_> = topics.into_iter().collect();
+    topics.sort_by_key(|(topic, _)| *topic);
+
+    let mut entries: BTreeMap<String, GlossaryEntry> = BTreeMap::new();
+    for (topic, terms) in topics {
+        for term in terms {
+            let entry = entries
+                .entry(term.term.to_lowercase())
+                .or_insert_with(|| GlossaryEntry {
+                    term: term.term.clone(),
+                    definition: term.definition.clone(),
+                    sources: Vec::new(),
+                });
+            if !entry.sources.iter().any(|s| s == topic) {
+                entry.sources.push(topic.to_string());
+            }
+        }
+    }
+
+    entries.into_values().collect()
+}
+
+/// Renders glossary entries as markdown, grouped by initial letter.
+///
+/// `links` maps each topic to the file its backlinks should point at,
+/// relative to the library directory. Topics without a link are listed by
+/// name only.
+pub fn render_glossary(entries: &[GlossaryEntry], links: &BTreeMap<String, PathBuf>) -> String {
+    let topic_count = entries
+        .iter()
+        .flat_map(|e| e.sources.iter())
+        .collect::<std::collections::BTreeSet<_>>()
+        .len();
+
+    let mut out = String::from("# Glossary\n\n");
+    out.push_str(&format!(
+        "_{} terms from {} research topics. Generated by `research glossary`; edits will be overwritten._\n",
+        entries.len(),
+        topic_count
+    ));
+
+    let mut current_section: Option<char> = None;
+    for entry in entries {
+        let initial = entry
+            .term
+            .chars()
+            .next()
+            .map(|c| c.to_ascii_uppercase())
+            .filter(|c| c.is_ascii_alphabetic())
+            .unwrap_or('#');
+        if current_section != Some(initial) {
+            out.push_str(&format!("\n## {}\n", initial));
+            current_section = Some(initial);
+        }
+
+        let sources = entry
+            .sources
+            .iter()
+            .map(|topic| match links.get(topic) {
+                Some(path) => format!("[{}]({})", topic, path.display()),
+                None => topic.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        out.push_str(&format!(
+            "\n### {}\n\n{}\n\nSources: {}\n",
+            entry.term, entry.definition, sources
+        ));
+    }
+
+    out
+}
+
+/// Reads a topic's sources, truncated to `max_chars`.
+fn read_topic_content(library_dir: &Path, sources: &TopicSources, max_chars: usize) -> String {
+    let mut content = String::new();
+    for file in &sources.files {
+        if let Ok(text) = std::fs::read_to_string(library_dir.join(file)) {
+            content.push_str(&text);
+            content.push_str("\n\n");
+        }
+    }
+    match content.char_indices().nth(max_chars) {
+        Some((idx, _)) => content[..idx].to_string(),
+        None => content,
+    }
+}
+
+/// Extracts terms for one topic with the given model.
+async fn extract_terms<M>(model: &M, prompt: String) -> Result<Vec<TermDefinition>, String>
+where
+    M: CompletionModel,
+{
+    let response = model
+        .completion_request(&prompt)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let content: String = response
+        .choice
+        .into_iter()
+        .filter_map(|c| match c {
+            AssistantContent::Text(text) => Some(text.text),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let terms = parse_glossary_response(&content);
+    if terms.is_empty() {
+        return Err("response contained no terms".to_string());
+    }
+    Ok(terms)
+}
+
+/// Generates or refreshes `glossary.md` at the root of the research library.
+///
+/// Topics whose brief or deep dive changed since their terms were last
+/// extracted (or all topics, with [`GlossaryConfig::force`]) are sent to the
+/// extraction model; other topics reuse cached terms. Topics that no longer
+/// exist are dropped. When extraction fails for a topic, its previous terms
+/// are kept.
+///
+/// Requires `GEMINI_API_KEY` when any topic needs extraction.
+///
+/// ## Errors
+///
+/// Returns an error if the library directory does not exist, or if the
+/// glossary or its cache cannot be written.
+#[instrument(
+    name = "glossary",
+    skip_all,
+    fields(library_dir = ?config.library_dir, force = config.force)
+)]
+pub async fn glossary(config: &GlossaryConfig) -> Result<GlossaryResult, GlossaryError> {
+    let path = config.glossary_path();
+    let sources = collect_topic_sources(&config.library_dir)?;
+
+    if !config.force
+        && let Some(interval) = config.refresh_interval
+        && is_within_refresh_interval(&path, interval)
+    {
+        debug!("Glossary is within its refresh interval; skipping");
+        return Ok(GlossaryResult {
+            path,
+            skipped: true,
+            topics: sources.len(),
+            extracted: 0,
+            failed: 0,
+            terms: 0,
+        });
+    }
+
+    let mut cache = if config.force {
+        GlossaryCache::default()
+    } else {
+        GlossaryCache::load(&config.library_dir)
+    };
+    cache
+        .topics
+        .retain(|topic, _| sources.iter().any(|s| &s.topic == topic));
+
+    let pending: Vec<&TopicSources> = sources
+        .iter()
+        .filter(|s| {
+            cache
+                .topics
+                .get(&s.topic)
+                .is_none_or(|cached| cached.sources_modified < s.modified)
+        })
+        .collect();
+
+    let mut extracted = 0;
+    let mut failed = 0;
+    if !pending.is_empty() {
+        info!("Extracting glossary terms from {} topic(s)", pending.len());
+        let gemini = gemini::Client::from_env();
+        let model = gemini.completion_model(&config.model);
+
+        let results: Vec<_> = stream::iter(pending)
+            .map(|s| {
+                let prompt = crate::prompts::GLOSSARY
+                    .get()
+                    .replace("{{topic}}", &s.topic)
+                    .replace("{{max_terms}}", &config.max_terms_per_topic.to_string())
+                    .replace(
+                        "{{content}}",
+                        &read_topic_content(&config.library_dir, s, config.max_source_chars),
+                    );
+                let model = &model;
+                async move { (s, extract_terms(model, prompt).await) }
+            })
+            .buffer_unordered(config.concurrency.max(1))
+            .collect()
+            .await;
+
+        for (s, result) in results {
+            match result {
+                Ok(terms) => {
+                    debug!("Extracted {} terms from '{}'", terms.len(), s.topic);
+                    cache.topics.insert(
+                        s.topic.clone(),
+                        CachedTopic {
+                            sources_modified: s.modified,
+                            terms,
+                        },
+                    );
+                    extracted += 1;
+                }
+                Err(e) => {
+                    warn!("Failed to extract glossary terms from '{}': {}", s.topic, e);
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    let entries = merge_terms(
+        cache
+            .topics
+            .iter()
+            .map(|(topic, cached)| (topic.as_str(), cached.terms.as_slice())),
+    );
+
+    // Backlink to the deep dive when available, otherwise the first source
+    let links: BTreeMap<String, PathBuf> = sources
+        .iter()
+        .filter_map(|s| {
+            let deep_dive =
+                PathBuf::from(&s.topic).join(ResearchOutput::DeepDive.path_for(&s.topic));
+            let link = if s.files.contains(&deep_dive) {
+                deep_dive
+            } else {
+                s.files.first()?.clone()
+            };
+            Some((s.topic.clone(), link))
+        })
+        .collect();
+
+    std::fs::write(&path, render_glossary(&entries, &links))?;
+    cache.save(&config.library_dir)?;
+
+    Ok(GlossaryResult {
+        path,
+        skipped: false,
+        topics: sources.len(),
+        extracted,
+        failed,
+        terms: entries.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn term(term: &str, definition: &str) -> TermDefinition {
+        TermDefinition {
+            term: term.to_string(),
+            definition: definition.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_glossary_response() {
+        let response = "Here are the terms:\n\
+            TERM: Backpressure | DEFINITION: Slowing producers when consumers fall behind.\n\
+            - TERM: **Work stealing** | DEFINITION: Idle threads take tasks from busy ones.\n\
+            TERM: | DEFINITION: missing term\n\
+            not a term line";
+
+        let terms = parse_glossary_response(response);
+
+        assert_eq!(
+            terms,
+            vec![
+                term(
+                    "Backpressure",
+                    "Slowing producers when consumers fall behind."
+                ),
+                term("Work stealing", "Idle threads take tasks from busy ones."),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_terms_deduplicates_case_insensitively() {
+        let tokio = vec![
+            term("Backpressure", "From tokio."),
+            term("Reactor", "Event loop."),
+        ];
+        let futures = vec![term("backpressure", "From futures.")];
+
+        let entries = merge_terms([("tokio", tokio.as_slice()), ("futures", futures.as_slice())]);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].term, "backpressure");
+        // First topic by name wins the definition
+        assert_eq!(entries[0].definition, "From futures.");
+        assert_eq!(entries[0].sources, vec!["futures", "tokio"]);
+        assert_eq!(entries[1].term, "Reactor");
+        assert_eq!(entries[1].sources, vec!["tokio"]);
+    }
+
+    #[test]
+    fn test_render_glossary_groups_and_backlinks() {
+        let entries = vec![
+            GlossaryEntry {
+                term: "Backpressure".to_string(),
+                definition: "Slowing producers.".to_string(),
+                sources: vec!["futures".to_string(), "tokio".to_string()],
+            },
+            GlossaryEntry {
+                term: "Reactor".to_string(),
+                definition: "Event loop.".to_string(),
+                sources: vec!["tokio".to_string()],
+            },
+        ];
+        let links = BTreeMap::from([(
+            "tokio".to_string(),
+            PathBuf::from("tokio/deep-dive/tokio.md"),
+        )]);
+
+        let md = render_glossary(&entries, &links);
+
+        assert!(md.starts_with("# Glossary\n"));
+        assert!(md.contains("_2 terms from 2 research topics."));
+        assert!(md.contains("\n## B\n"));
+        assert!(md.contains("\n## R\n"));
+        assert!(md.contains("### Backpressure\n\nSlowing producers.\n"));
+        assert!(md.contains("Sources: futures, [tokio](tokio/deep-dive/tokio.md)\n"));
+    }
+
+    #[test]
+    fn test_collect_topic_sources() {
+        let temp = TempDir::new().unwrap();
+        let lib = temp.path();
+        std::fs::create_dir_all(lib.join("tokio/deep-dive")).unwrap();
+        std::fs::write(lib.join("tokio/brief.md"), "brief").unwrap();
+        std::fs::write(lib.join("tokio/deep-dive/tokio.md"), "deep dive").unwrap();
+        std::fs::create_dir_all(lib.join("empty")).unwrap();
+        std::fs::write(lib.join(GLOSSARY_FILENAME), "# Glossary").unwrap();
+
+        let sources = collect_topic_sources(lib).unwrap();
+
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].topic, "tokio");
+        assert_eq!(
+            sources[0].files,
+            vec![
+                PathBuf::from("tokio/brief.md"),
+                PathBuf::from("tokio/deep-dive/tokio.md")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_topic_sources_missing_library() {
+        let result = collect_topic_sources(Path::new("/nonexistent/library"));
+        assert!(matches!(result, Err(GlossaryError::LibraryNotFound(_))));
+    }
+
+    #[test]
+    fn test_is_within_refresh_interval() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(GLOSSARY_FILENAME);
+
+        assert!(!is_within_refresh_interval(&path, Duration::from_secs(60)));
+
+        std::fs::write(&path, "# Glossary").unwrap();
+        assert!(is_within_refresh_interval(&path, Duration::from_secs(60)));
+        assert!(!is_within_refresh_interval(&path, Duration::ZERO));
+    }
+
+    #[tokio::test]
+    async fn test_glossary_uses_cache_without_model() {
+        let temp = TempDir::new().unwrap();
+        let lib = temp.path();
+        std::fs::create_dir_all(lib.join("tokio")).unwrap();
+        std::fs::write(lib.join("tokio/brief.md"), "brief").unwrap();
+
+        // Cache is newer than the sources, so no extraction is needed
+        let cache = GlossaryCache {
+            topics: BTreeMap::from([(
+                "tokio".to_string(),
+                CachedTopic {
+                    sources_modified: Utc::now() + chrono::Duration::hours(1),
+                    terms: vec![term("Reactor", "Event loop.")],
+                },
+            )]),
+        };
+        cache.save(lib).unwrap();
+
+        let config = GlossaryConfig::default()
+            .library_dir(lib)
+            .refresh_interval(None);
+        let result = glossary(&config).await.unwrap();
+
+        assert!(!result.skipped);
+        assert_eq!(result.topics, 1);
+        assert_eq!(result.extracted, 0);
+        assert_eq!(result.terms, 1);
+        let md = std::fs::read_to_string(lib.join(GLOSSARY_FILENAME)).unwrap();
+        assert!(md.contains("Sources: [tokio](tokio/brief.md)"));
+    }
+
+    #[tokio::test]
+    async fn test_glossary_skips_within_refresh_interval() {
+        let temp = TempDir::new().unwrap();
+        let lib = temp.path();
+        std::fs::write(lib.join(GLOSSARY_FILENAME), "# Glossary").unwrap();
+
+        let config = GlossaryConfig::default()
+            .library_dir(lib)
+            .refresh_interval(Some(Duration::from_secs(3600)));
+        let result = glossary(&config).await.unwrap();
+
+        assert!(result.skipped);
+        assert_eq!(
+            std::fs::read_to_string(lib.join(GLOSSARY_FILENAME)).unwrap(),
+            "# Glossary"
+        );
+    }
+}
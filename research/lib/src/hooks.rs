@@ -0,0 +1,459 @@
+//! Completion hooks for research runs.
+//!
+//! Long research runs can take several minutes, so callers may want to be
+//! notified when they finish. A [`CompletionHooks`] configuration (usually
+//! supplied through [`ResearchOptions`](crate::ResearchOptions)) can:
+//!
+//! - POST a JSON [`CompletionSummary`] to a webhook URL (e.g. a chat channel)
+//! - run a shell command with the summary in its environment and on stdin
+//! - speak an announcement via `biscuit-speaks`
+//!
+//! Hooks never fail the research run itself; each failure is reported back
+//! as a [`HookError`] so the caller can decide how loudly to warn.
+
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+
+use reqwest::Client as HttpClient;
+use serde::Serialize;
+use thiserror::Error;
+use tokio::io::AsyncWriteExt;
+use tracing::{debug, info};
+
+use crate::ResearchResult;
+
+/// How long a webhook may take before it is abandoned.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Errors raised by an individual completion hook.
+#[derive(Error, Debug)]
+pub enum HookError {
+    #[error("Webhook to {url} failed: {source}")]
+    Webhook {
+        url: String,
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("Webhook to {url} returned HTTP {status}")]
+    WebhookStatus { url: String, status: u16 },
+
+    #[error("Failed to run completion command `{command}`: {source}")]
+    CommandSpawn {
+        command: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Completion command `{command}` exited with {status}")]
+    CommandFailed { command: String, status: String },
+}
+
+/// Hooks to run once a research run has finished.
+///
+/// All hooks are optional and disabled by default.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::hooks::CompletionHooks;
+///
+/// let hooks = CompletionHooks::default()
+///     .with_webhook("https://hooks.example.com/research")
+///     .with_command("notify-send \"$RESEARCH_TOPIC\" done")
+///     .with_announcement();
+///
+/// assert!(hooks.is_enabled());
+/// assert!(!CompletionHooks::default().is_enabled());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompletionHooks {
+    /// URL to POST a JSON [`CompletionSummary`] to
+    pub webhook_url: Option<String>,
+    /// Shell command to run (via `sh -c`, or `cmd /C` on Windows)
+    pub command: Option<String>,
+    /// Speak an announcement when research completes (skipped when cancelled)
+    pub announce: bool,
+}
+
+impl CompletionHooks {
+    /// Sets the webhook URL that receives the JSON summary.
+    pub fn with_webhook(mut self, url: impl Into<String>) -> Self {
+        self.webhook_url = Some(url.into());
+        self
+    }
+
+    /// Sets the shell command to run on completion.
+    ///
+    /// The command receives the summary as JSON on stdin and as `RESEARCH_*`
+    /// environment variables (see [`CompletionSummary::env_vars`]).
+    pub fn with_command(mut self, command: impl Into<String>) -> Self {
+        self.command = Some(command.into());
+        self
+    }
+
+    /// Enables the spoken completion announcement.
+    pub fn with_announcement(mut self) -> Self {
+        self.announce = true;
+        self
+    }
+
+    /// Returns true if at least one hook is configured.
+    pub fn is_enabled(&self) -> bool {
+        self.webhook_url.is_some() || self.command.is_some() || self.announce
+    }
+}
+
+/// Outcome of a research run as reported to completion hooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompletionStatus {
+    /// Every prompt succeeded
+    Completed,
+    /// Some prompts failed but enough succeeded to produce output
+    Partial,
+    /// The user cancelled the run
+    Cancelled,
+}
+
+impl CompletionStatus {
+    /// Returns the status as used in JSON and environment variables.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompletionStatus::Completed => "completed",
+            CompletionStatus::Partial => "partial",
+            CompletionStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// JSON summary of a finished research run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CompletionSummary {
+    pub topic: String,
+    pub status: CompletionStatus,
+    pub output_dir: PathBuf,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub total_time_secs: f32,
+    pub total_input_tokens: u64,
+    pub total_output_tokens: u64,
+    pub total_tokens: u64,
+    /// Human-readable one-line message, suitable for chat channels
+    pub message: String,
+}
+
+impl CompletionSummary {
+    /// Builds a summary from a research result.
+    pub fn from_result(result: &ResearchResult) -> Self {
+        let status = if result.cancelled {
+            CompletionStatus::Cancelled
+        } else if result.failed > 0 {
+            CompletionStatus::Partial
+        } else {
+            CompletionStatus::Completed
+        };
+
+        let message = match status {
+            CompletionStatus::Cancelled => format!(
+                "Research for '{}' was cancelled ({} succeeded, {} failed)",
+                result.topic, result.succeeded, result.failed
+            ),
+            _ => format!(
+                "Research for '{}' {}: {} succeeded, {} failed in {:.1}s",
+                result.topic,
+                status.as_str(),
+                result.succeeded,
+                result.failed,
+                result.total_time_secs
+            ),
+        };
+
+        Self {
+            topic: result.topic.clone(),
+            status,
+            output_dir: result.output_dir.clone(),
+            succeeded: result.succeeded,
+            failed: result.failed,
+            total_time_secs: result.total_time_secs,
+            total_input_tokens: result.total_input_tokens,
+            total_output_tokens: result.total_output_tokens,
+            total_tokens: result.total_tokens,
+            message,
+        }
+    }
+
+    /// Returns the `RESEARCH_*` environment variables passed to completion commands.
+    pub fn env_vars(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("RESEARCH_TOPIC", self.topic.clone()),
+            ("RESEARCH_STATUS", self.status.as_str().to_string()),
+            (
+                "RESEARCH_OUTPUT_DIR",
+                self.output_dir.to_string_lossy().to_string(),
+            ),
+            ("RESEARCH_SUCCEEDED", self.succeeded.to_string()),
+            ("RESEARCH_FAILED", self.failed.to_string()),
+            ("RESEARCH_TOTAL_TOKENS", self.total_tokens.to_string()),
+            ("RESEARCH_MESSAGE", self.message.clone()),
+        ]
+    }
+}
+
+/// Runs every configured hook for a finished research run.
+///
+/// Hooks run in order: webhook, command, announcement. A failing hook does
+/// not prevent the others from running.
+///
+/// ## Returns
+///
+/// The errors of any hooks that failed; empty when all hooks succeeded.
+pub async fn run_completion_hooks(
+    hooks: &CompletionHooks,
+    result: &ResearchResult,
+) -> Vec<HookError> {
+    let mut errors = Vec::new();
+    if !hooks.is_enabled() {
+        return errors;
+    }
+
+    let summary = CompletionSummary::from_result(result);
+
+    if let Some(url) = &hooks.webhook_url
+        && let Err(e) = post_webhook(url, &summary).await
+    {
+        errors.push(e);
+    }
+
+    if let Some(command) = &hooks.command
+        && let Err(e) = run_command(command, &summary).await
+    {
+        errors.push(e);
+    }
+
+    if hooks.announce && summary.status != CompletionStatus::Cancelled {
+        let message = format!("Research for the {} library has completed", summary.topic);
+        biscuit_speaks::speak_when_able(&message, &biscuit_speaks::TtsConfig::default()).await;
+    }
+
+    errors
+}
+
+/// POSTs the summary as JSON to `url`.
+///
+/// ## Errors
+///
+/// Returns an error if the request fails or the server responds with a
+/// non-success status.
+pub async fn post_webhook(url: &str, summary: &CompletionSummary) -> Result<(), HookError> {
+    debug!(url, "Posting research completion webhook");
+
+    let client = HttpClient::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .map_err(|source| HookError::Webhook {
+            url: url.to_string(),
+            source,
+        })?;
+
+    let response = client
+        .post(url)
+        .json(summary)
+        .send()
+        .await
+        .map_err(|source| HookError::Webhook {
+            url: url.to_string(),
+            source,
+        })?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(HookError::WebhookStatus {
+            url: url.to_string(),
+            status: status.as_u16(),
+        });
+    }
+
+    info!(url, "Research completion webhook delivered");
+    Ok(())
+}
+
+/// Runs `command` through the platform shell with the summary in its
+/// environment and as JSON on stdin.
+///
+/// ## Errors
+///
+/// Returns an error if the command cannot be spawned or exits unsuccessfully.
+pub async fn run_command(command: &str, summary: &CompletionSummary) -> Result<(), HookError> {
+    debug!(command, "Running research completion command");
+
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = tokio::process::Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+
+    cmd.arg(command)
+        .envs(summary.env_vars())
+        .stdin(Stdio::piped())
+        .kill_on_drop(true);
+
+    let spawn_error = |source| HookError::CommandSpawn {
+        command: command.to_string(),
+        source,
+    };
+
+    let mut child = cmd.spawn().map_err(spawn_error)?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        let json = serde_json::to_vec(summary).unwrap_or_default();
+        // The command may not read stdin at all; a broken pipe is not an error
+        let _ = stdin.write_all(&json).await;
+    }
+
+    let status = child.wait().await.map_err(spawn_error)?;
+    if !status.success() {
+        return Err(HookError::CommandFailed {
+            command: command.to_string(),
+            status: status.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn result(failed: usize, cancelled: bool) -> ResearchResult {
+        ResearchResult {
+            topic: "tokio".to_string(),
+            output_dir: PathBuf::from("/tmp/research/tokio"),
+            succeeded: 5,
+            failed,
+            cancelled,
+            total_time_secs: 12.5,
+            total_input_tokens: 100,
+            total_output_tokens: 200,
+            total_tokens: 300,
+        }
+    }
+
+    #[test]
+    fn test_summary_status() {
+        assert_eq!(
+            CompletionSummary::from_result(&result(0, false)).status,
+            CompletionStatus::Completed
+        );
+        assert_eq!(
+            CompletionSummary::from_result(&result(2, false)).status,
+            CompletionStatus::Partial
+        );
+        assert_eq!(
+            CompletionSummary::from_result(&result(2, true)).status,
+            CompletionStatus::Cancelled
+        );
+    }
+
+    #[test]
+    fn test_summary_json() {
+        let summary = CompletionSummary::from_result(&result(0, false));
+        let json = serde_json::to_value(&summary).unwrap();
+
+        assert_eq!(json["topic"], "tokio");
+        assert_eq!(json["status"], "completed");
+        assert_eq!(json["total_tokens"], 300);
+        assert_eq!(
+            json["message"],
+            "Research for 'tokio' completed: 5 succeeded, 0 failed in 12.5s"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_webhook_posts_summary() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/hook"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let hooks = CompletionHooks::default().with_webhook(format!("{}/hook", mock_server.uri()));
+        let errors = run_completion_hooks(&hooks, &result(1, false)).await;
+        assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["topic"], "tokio");
+        assert_eq!(body["status"], "partial");
+        assert_eq!(body["failed"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_error_status() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+
+        let hooks = CompletionHooks::default().with_webhook(mock_server.uri());
+        let errors = run_completion_hooks(&hooks, &result(0, false)).await;
+
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            HookError::WebhookStatus { status: 500, .. }
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_command_receives_summary() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("out.txt");
+        let command = format!(
+            "printf '%s %s ' \"$RESEARCH_TOPIC\" \"$RESEARCH_STATUS\" > '{0}' && cat >> '{0}'",
+            out.display()
+        );
+
+        let hooks = CompletionHooks::default().with_command(command);
+        let errors = run_completion_hooks(&hooks, &result(0, true)).await;
+        assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+
+        let written = std::fs::read_to_string(&out).unwrap();
+        assert!(written.starts_with("tokio cancelled {"));
+        assert!(written.contains("\"output_dir\":\"/tmp/research/tokio\""));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_failing_command_does_not_stop_other_hooks() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let hooks = CompletionHooks::default()
+            .with_command("exit 3")
+            .with_webhook(mock_server.uri());
+        let errors = run_completion_hooks(&hooks, &result(0, false)).await;
+
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], HookError::CommandFailed { .. }));
+    }
+}
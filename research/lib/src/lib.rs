@@ -9,13 +9,50 @@
 //! - [`BraveSearchTool`](unchained_ai::rigging::tools::BraveSearchTool) - Web search via Brave Search API
 //! - [`ScreenScrapeTool`](unchained_ai::rigging::tools::ScreenScrapeTool) - Web page content extraction
 //!
+//! When the library's repository is on GitHub, the overview and changelog
+//! prompts also get [`GitHubRepoTool`](unchained_ai::rigging::tools::GitHubRepoTool)
+//! for first-party README, release, star and issue data. For crates.io
+//! libraries the use cases prompt gets
+//! [`DocsRsTool`](unchained_ai::rigging::tools::DocsRsTool) so code examples
+//! use the crate's documented API.
+//!
 //! Phase 2 prompts (synthesis) run without tools as they consolidate existing content.
 
+pub mod archive;
 pub mod changelog;
+pub mod checkpoint;
+#[cfg(feature = "tui")]
+pub mod dashboard;
+pub mod dedupe;
+pub mod diff;
+pub mod ecosystem;
+pub mod export;
+pub mod glossary;
+pub mod hooks;
 pub mod link;
 pub mod list;
 pub mod metadata;
+pub mod models;
+pub mod observer;
+#[cfg(feature = "embeddings")]
+pub mod overlap;
+pub mod paths;
+pub mod plan;
+pub mod protected;
+pub mod providers;
 pub mod pull;
+pub mod question;
+pub mod refresh;
+pub mod registry;
+pub mod repository;
+pub mod run_log;
+pub mod scheduler;
+pub mod search;
+pub mod streaming;
+pub mod tags;
+#[cfg(feature = "otel")]
+pub mod telemetry;
+pub mod templates;
 pub mod utils;
 pub mod validation;
 
@@ -26,31 +63,78 @@ use pulldown_cmark::{Options, Parser};
 use pulldown_cmark_to_cmark::cmark;
 use reqwest::Client as HttpClient;
 use rig::agent::{Agent, CancelSignal, PromptHook};
-use rig::client::{CompletionClient, ProviderClient};
+use rig::client::CompletionClient;
 use rig::completion::{AssistantContent, CompletionModel, Message, Prompt, PromptError};
 use rig::message::{ToolResultContent, UserContent};
-use rig::providers::{gemini, openai};
 use serde::{Deserialize, Serialize};
-use unchained_ai::rigging::providers::client_adaptors::zai;
-use unchained_ai::rigging::tools::{BravePlan, BraveSearchTool, ScreenScrapeTool};
+use unchained_ai::rigging::tools::{
+    BravePlan, BraveSearchTool, DocsRsTool, GitHubRepoTool, ScreenScrapeTool, ToolCache,
+    parse_github_repository,
+};
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
 use std::fmt;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
-use std::time::Instant;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::fs;
 use tracing::{Span, debug, info, info_span, instrument, warn};
 
-use crate::validation::{parse_and_validate_frontmatter, repair_skill_frontmatter};
+use crate::checkpoint::{Checkpoint, CheckpointRecorder};
+use crate::ecosystem::Ecosystem;
+use crate::models::{
+    ModelClients, ModelConfig, ModelConfigError, ModelSpec, ResearchTask, ToolBudget, TunedModel,
+    with_fallback, with_model,
+};
+use crate::observer::{
+    ConsoleObserver, PhaseSummary, ResearchObserver, ResearchPhase, TaskOutcome, TaskReport,
+    TaskStage,
+};
+use crate::paths::ResearchPaths;
+use crate::question::{Question, QuestionError, answer_label, validate_questions};
+use crate::registry::Registry;
+use crate::repository::RepositorySource;
+use crate::run_log::RunLogRecorder;
+use crate::scheduler::ResearchScheduler;
+use crate::streaming::{PartialOutput, ProgressHandler, PromptProgress};
+use crate::templates::PromptTemplate;
+use crate::validation::{
+    FrontmatterError, SkillFrontmatter, join_skill_frontmatter, parse_and_validate_frontmatter,
+    repair_skill_frontmatter, split_skill_frontmatter,
+};
 
 /// A PromptHook that emits tracing events for agent interactions.
 ///
 /// This hook is used to trace all tool calls made by agents during research tasks,
 /// providing visibility into the agent's decision-making process.
+///
+/// It also sums the token usage of every completion the agent makes, since the
+/// Agent API only returns the final text. Clones share the same totals, so keep
+/// a clone of the hook passed to `with_hook` and read [`usage`](Self::usage)
+/// once the prompt finishes.
+///
+/// With a [`ToolBudget`], the hook also counts searches and scrapes and cancels
+/// the prompt at the first call over budget; [`exhausted_tool`](Self::exhausted_tool)
+/// then names the tool that ran out.
 #[derive(Clone)]
 pub struct TracingPromptHook {
     span: Span,
+    task: String,
+    observer: Option<Arc<dyn ResearchObserver>>,
+    usage: Arc<Mutex<PromptMetrics>>,
+    budget: Option<ToolBudget>,
+    tool_calls: Arc<Mutex<ToolCalls>>,
+}
+
+/// Tool calls counted against a [`TracingPromptHook`]'s budget.
+#[derive(Debug, Default)]
+struct ToolCalls {
+    counts: HashMap<String, usize>,
+    exhausted: Option<String>,
 }
 
 impl TracingPromptHook {
@@ -58,6 +142,70 @@ impl TracingPromptHook {
     pub fn new(task_name: &str) -> Self {
         Self {
             span: info_span!("agent_task", task = %task_name),
+            task: task_name.to_string(),
+            observer: None,
+            usage: Arc::default(),
+            budget: None,
+            tool_calls: Arc::default(),
+        }
+    }
+
+    /// Limit the searches and scrapes the agent may make to `budget`.
+    pub fn with_budget(mut self, budget: ToolBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Count a call to `tool_name`, returning `false` if it goes over budget.
+    ///
+    /// The first tool to go over is remembered as the
+    /// [`exhausted_tool`](Self::exhausted_tool).
+    pub fn record_tool_call(&self, tool_name: &str) -> bool {
+        let mut calls = self
+            .tool_calls
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let count = calls.counts.entry(tool_name.to_string()).or_default();
+        *count += 1;
+        let count = *count;
+
+        let limit = self.budget.and_then(|budget| budget.limit_for(tool_name));
+        if limit.is_some_and(|limit| count > limit) {
+            calls.exhausted.get_or_insert_with(|| tool_name.to_string());
+            return false;
+        }
+        true
+    }
+
+    /// The tool whose budget ran out, if one did.
+    pub fn exhausted_tool(&self) -> Option<String> {
+        self.tool_calls
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .exhausted
+            .clone()
+    }
+
+    /// Also report each tool call and the running token usage to `observer`.
+    pub fn with_observer(mut self, observer: Arc<dyn ResearchObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Add one completion's token counts to the running totals.
+    pub fn record_usage(&self, input_tokens: u64, output_tokens: u64, total_tokens: u64) {
+        let mut usage = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+        usage.input_tokens += input_tokens;
+        usage.output_tokens += output_tokens;
+        usage.total_tokens += total_tokens;
+    }
+
+    /// The tokens used so far, as metrics for a task that took `elapsed_secs`.
+    pub fn usage(&self, elapsed_secs: f32) -> PromptMetrics {
+        let usage = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+        PromptMetrics {
+            elapsed_secs,
+            ..usage.clone()
         }
     }
 }
@@ -91,10 +239,18 @@ where
             .filter(|c| matches!(c, AssistantContent::ToolCall(_)))
             .count();
 
+        let usage = &response.usage;
+        self.record_usage(usage.input_tokens, usage.output_tokens, usage.total_tokens);
+        if let Some(observer) = &self.observer {
+            observer.on_token_usage(&self.task, &self.usage(0.0));
+        }
+
         debug!(
             parent: &self.span,
             has_tool_calls = tool_call_count > 0,
             tool_call_count,
+            input_tokens = usage.input_tokens,
+            output_tokens = usage.output_tokens,
             "Received model response"
         );
     }
@@ -104,8 +260,18 @@ where
         tool_name: &str,
         tool_call_id: Option<String>,
         args: &str,
-        _cancel_sig: CancelSignal,
+        cancel_sig: CancelSignal,
     ) {
+        if !self.record_tool_call(tool_name) {
+            warn!(
+                parent: &self.span,
+                tool.name = %tool_name,
+                "Tool budget exhausted, stopping the agent"
+            );
+            cancel_sig.cancel();
+            return;
+        }
+
         info!(
             parent: &self.span,
             tool.name = %tool_name,
@@ -113,6 +279,9 @@ where
             tool.args = %args,
             "Invoking tool"
         );
+        if let Some(observer) = &self.observer {
+            observer.on_tool_call(&self.task, tool_name);
+        }
     }
 
     async fn on_tool_result(
@@ -139,23 +308,200 @@ where
     }
 }
 
+/// Declares an embedded prompt template, overridable from the same path
+/// under `RESEARCH_PROMPTS_DIR` (see [`templates`]).
+macro_rules! prompt {
+    ($name:ident = $path:literal) => {
+        pub const $name: $crate::templates::PromptTemplate =
+            $crate::templates::PromptTemplate::new(
+                $path,
+                include_str!(concat!("../prompts/", $path)),
+            );
+    };
+}
+
 /// Embedded prompt templates
 mod prompts {
-    pub const OVERVIEW: &str = include_str!("../prompts/overview.md");
-    pub const SIMILAR_LIBRARIES: &str = include_str!("../prompts/similar_libraries.md");
-    pub const INTEGRATION_PARTNERS: &str = include_str!("../prompts/integration_partners.md");
-    pub const USE_CASES: &str = include_str!("../prompts/use_cases.md");
-    pub const CHANGELOG: &str = include_str!("../prompts/changelog.md");
-    pub const ADDITIONAL_QUESTION: &str = include_str!("../prompts/additional_question.md");
-    pub const CONTEXT: &str = include_str!("../prompts/context.md");
-    pub const SKILL: &str = include_str!("../prompts/skill.md");
-    pub const DEEP_DIVE: &str = include_str!("../prompts/deep_dive.md");
-    pub const BRIEF: &str = include_str!("../prompts/brief.md");
+    prompt!(OVERVIEW = "overview.md");
+    prompt!(SIMILAR_LIBRARIES = "similar_libraries.md");
+    prompt!(INTEGRATION_PARTNERS = "integration_partners.md");
+    prompt!(USE_CASES = "use_cases.md");
+    prompt!(CHANGELOG = "changelog.md");
+    prompt!(ADDITIONAL_QUESTION = "additional_question.md");
+    prompt!(CONTEXT = "context.md");
+    prompt!(SKILL = "skill.md");
+    prompt!(DEEP_DIVE = "deep_dive.md");
+    prompt!(BRIEF = "brief.md");
+    prompt!(SKILL_FRONTMATTER = "skill_frontmatter.md");
+    prompt!(GLOSSARY = "glossary.md");
+
+    /// Phase 1 prompts for software (applications, CLI tools, services)
+    pub mod software {
+        prompt!(OVERVIEW = "software/overview.md");
+        prompt!(ALTERNATIVES = "software/alternatives.md");
+        prompt!(INTEGRATIONS = "software/integrations.md");
+        prompt!(USE_CASES = "software/use_cases.md");
+        prompt!(CHANGELOG = "software/changelog.md");
+        prompt!(ADDITIONAL_QUESTION = "software/additional_question.md");
+    }
+
+    /// Phase 1 prompts for standards (specifications, protocols, formats)
+    pub mod standard {
+        prompt!(OVERVIEW = "standard/overview.md");
+        prompt!(ALTERNATIVES = "standard/alternatives.md");
+        prompt!(INTEGRATIONS = "standard/integrations.md");
+        prompt!(USE_CASES = "standard/use_cases.md");
+        prompt!(CHANGELOG = "standard/changelog.md");
+        prompt!(ADDITIONAL_QUESTION = "standard/additional_question.md");
+    }
+
+    /// Library prompt variants written for one package ecosystem.
+    ///
+    /// Similar libraries and the changelog use the generic prompts.
+    pub mod ecosystem {
+        pub mod rust {
+            prompt!(OVERVIEW = "ecosystem/rust/overview.md");
+            prompt!(INTEGRATION_PARTNERS = "ecosystem/rust/integration_partners.md");
+            prompt!(USE_CASES = "ecosystem/rust/use_cases.md");
+            prompt!(ADDITIONAL_QUESTION = "ecosystem/rust/additional_question.md");
+        }
+
+        pub mod javascript {
+            prompt!(OVERVIEW = "ecosystem/javascript/overview.md");
+            prompt!(INTEGRATION_PARTNERS = "ecosystem/javascript/integration_partners.md");
+            prompt!(USE_CASES = "ecosystem/javascript/use_cases.md");
+            prompt!(ADDITIONAL_QUESTION = "ecosystem/javascript/additional_question.md");
+        }
+
+        pub mod python {
+            prompt!(OVERVIEW = "ecosystem/python/overview.md");
+            prompt!(INTEGRATION_PARTNERS = "ecosystem/python/integration_partners.md");
+            prompt!(USE_CASES = "ecosystem/python/use_cases.md");
+            prompt!(ADDITIONAL_QUESTION = "ecosystem/python/additional_question.md");
+        }
+
+        pub mod go {
+            prompt!(OVERVIEW = "ecosystem/go/overview.md");
+            prompt!(INTEGRATION_PARTNERS = "ecosystem/go/integration_partners.md");
+            prompt!(USE_CASES = "ecosystem/go/use_cases.md");
+            prompt!(ADDITIONAL_QUESTION = "ecosystem/go/additional_question.md");
+        }
+
+        pub mod php {
+            prompt!(OVERVIEW = "ecosystem/php/overview.md");
+            prompt!(INTEGRATION_PARTNERS = "ecosystem/php/integration_partners.md");
+            prompt!(USE_CASES = "ecosystem/php/use_cases.md");
+            prompt!(ADDITIONAL_QUESTION = "ecosystem/php/additional_question.md");
+        }
+    }
+}
+
+/// Phase 1 prompt templates for one [`ResearchKind`].
+///
+/// Every kind writes the same Phase 1 files (`overview.md`,
+/// `similar_libraries.md`, ...), so Phase 2, incremental research and
+/// `research list` handle all topics alike; only the prompts differ.
+struct PromptSet {
+    overview: PromptTemplate,
+    similar_libraries: PromptTemplate,
+    integration_partners: PromptTemplate,
+    use_cases: PromptTemplate,
+    changelog: PromptTemplate,
+    additional_question: PromptTemplate,
+}
+
+const LIBRARY_PROMPT_SET: PromptSet = PromptSet {
+    overview: prompts::OVERVIEW,
+    similar_libraries: prompts::SIMILAR_LIBRARIES,
+    integration_partners: prompts::INTEGRATION_PARTNERS,
+    use_cases: prompts::USE_CASES,
+    changelog: prompts::CHANGELOG,
+    additional_question: prompts::ADDITIONAL_QUESTION,
+};
+
+const SOFTWARE_PROMPT_SET: PromptSet = PromptSet {
+    overview: prompts::software::OVERVIEW,
+    similar_libraries: prompts::software::ALTERNATIVES,
+    integration_partners: prompts::software::INTEGRATIONS,
+    use_cases: prompts::software::USE_CASES,
+    changelog: prompts::software::CHANGELOG,
+    additional_question: prompts::software::ADDITIONAL_QUESTION,
+};
+
+const STANDARD_PROMPT_SET: PromptSet = PromptSet {
+    overview: prompts::standard::OVERVIEW,
+    similar_libraries: prompts::standard::ALTERNATIVES,
+    integration_partners: prompts::standard::INTEGRATIONS,
+    use_cases: prompts::standard::USE_CASES,
+    changelog: prompts::standard::CHANGELOG,
+    additional_question: prompts::standard::ADDITIONAL_QUESTION,
+};
+
+/// Library prompt sets for the ecosystems with their own variants.
+macro_rules! ecosystem_prompt_set {
+    ($ecosystem:ident) => {
+        PromptSet {
+            overview: prompts::ecosystem::$ecosystem::OVERVIEW,
+            similar_libraries: prompts::SIMILAR_LIBRARIES,
+            integration_partners: prompts::ecosystem::$ecosystem::INTEGRATION_PARTNERS,
+            use_cases: prompts::ecosystem::$ecosystem::USE_CASES,
+            changelog: prompts::CHANGELOG,
+            additional_question: prompts::ecosystem::$ecosystem::ADDITIONAL_QUESTION,
+        }
+    };
+}
+
+const RUST_PROMPT_SET: PromptSet = ecosystem_prompt_set!(rust);
+const JAVASCRIPT_PROMPT_SET: PromptSet = ecosystem_prompt_set!(javascript);
+const PYTHON_PROMPT_SET: PromptSet = ecosystem_prompt_set!(python);
+const GO_PROMPT_SET: PromptSet = ecosystem_prompt_set!(go);
+const PHP_PROMPT_SET: PromptSet = ecosystem_prompt_set!(php);
+
+impl PromptSet {
+    /// Returns the prompt set used to research topics of `kind`, with
+    /// `ecosystem`'s variants for libraries.
+    fn for_topic(kind: ResearchKind, ecosystem: Ecosystem) -> &'static PromptSet {
+        if kind != ResearchKind::Library {
+            return Self::for_kind(kind);
+        }
+        match ecosystem {
+            Ecosystem::Generic => &LIBRARY_PROMPT_SET,
+            Ecosystem::Rust => &RUST_PROMPT_SET,
+            Ecosystem::JavaScript => &JAVASCRIPT_PROMPT_SET,
+            Ecosystem::Python => &PYTHON_PROMPT_SET,
+            Ecosystem::Go => &GO_PROMPT_SET,
+            Ecosystem::Php => &PHP_PROMPT_SET,
+        }
+    }
+
+    /// Returns the prompt set used to research topics of `kind`.
+    fn for_kind(kind: ResearchKind) -> &'static PromptSet {
+        match kind {
+            // API research doesn't run prompts yet
+            ResearchKind::Library | ResearchKind::Api => &LIBRARY_PROMPT_SET,
+            ResearchKind::Software => &SOFTWARE_PROMPT_SET,
+            ResearchKind::Standard => &STANDARD_PROMPT_SET,
+        }
+    }
+
+    /// Returns the template for a standard Phase 1 prompt by name, with any
+    /// override from `RESEARCH_PROMPTS_DIR` applied.
+    fn template(&self, name: &str) -> Option<&'static str> {
+        let template = match name {
+            "overview" => self.overview,
+            "similar_libraries" => self.similar_libraries,
+            "integration_partners" => self.integration_partners,
+            "use_cases" => self.use_cases,
+            "changelog" => self.changelog,
+            _ => return None,
+        };
+        Some(template.get())
+    }
 }
 
 /// Standard Phase 1 prompts that should be present for complete research.
 /// Each entry is (name, filename, prompt_template).
-const STANDARD_PROMPTS: [(&str, &str, &str); 5] = [
+const STANDARD_PROMPTS: [(&str, &str, PromptTemplate); 5] = [
     ("overview", "overview.md", prompts::OVERVIEW),
     (
         "similar_libraries",
@@ -194,7 +540,7 @@ pub async fn check_missing_standard_prompts(output_dir: &std::path::Path) -> Vec
             missing.push(MissingPrompt {
                 name,
                 filename,
-                template,
+                template: template.get(),
             });
         }
     }
@@ -244,7 +590,7 @@ pub async fn check_missing_outputs(output_dir: &std::path::Path) -> Vec<MissingO
 }
 
 /// Information about a library found in a package manager
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LibraryInfo {
     pub package_manager: String,
     pub language: String,
@@ -270,14 +616,41 @@ impl fmt::Display for LibraryInfo {
 }
 
 /// The kind of research being performed
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum ResearchKind {
     /// Research about software libraries (packages, crates, modules)
+    #[default]
     Library,
     /// Research about public APIs (REST, GraphQL, RPC)
     Api,
-    // Future: Software, Standard, Company, etc.
+    /// Research about software (applications, CLI tools, services)
+    Software,
+    /// Research about standards (specifications, protocols, formats)
+    Standard,
+    // Future: Company, etc.
+}
+
+impl ResearchKind {
+    /// Kinds researched with the topic workflow, whose topics live side by
+    /// side under `.research/<kind>/`.
+    pub const TOPIC_KINDS: [ResearchKind; 3] = [
+        ResearchKind::Library,
+        ResearchKind::Software,
+        ResearchKind::Standard,
+    ];
+
+    /// Directory name under `.research/` holding topics of this kind.
+    ///
+    /// Matches the serialized `kind` in `metadata.json`.
+    pub fn dir_name(self) -> &'static str {
+        match self {
+            ResearchKind::Library => "library",
+            ResearchKind::Api => "api",
+            ResearchKind::Software => "software",
+            ResearchKind::Standard => "standard",
+        }
+    }
 }
 
 /// Metadata for a research output
@@ -293,6 +666,15 @@ pub struct ResearchMetadata {
     /// Additional files created from user prompts (filename -> prompt)
     #[serde(default)]
     pub additional_files: std::collections::HashMap<String, String>,
+    /// Tags attached to additional files (filename -> tags)
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub question_tags: std::collections::HashMap<String, Vec<String>>,
+    /// Tags attached to the topic as a whole (sorted, deduplicated)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Category the topic is filed under (e.g., `web-frameworks`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
     /// When the research was first created
     pub created_at: DateTime<Utc>,
     /// When the research was last updated
@@ -306,6 +688,16 @@ pub struct ResearchMetadata {
     /// Guidance on when to use this research (e.g., "Use when working with X library")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub when_to_use: Option<String>,
+    /// When the topic was moved into `.research/archive/` (see [`archive`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime<Utc>>,
+    /// The kind directory an archived topic is restored to (e.g. `library`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_from: Option<String>,
+    /// Model that produced each generated file (path relative to the topic
+    /// directory -> `provider:model`), after any failover
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub generated_by: std::collections::BTreeMap<String, String>,
 }
 
 fn default_schema_version() -> u32 {
@@ -334,31 +726,55 @@ impl From<&LibraryInfo> for LibraryInfoMetadata {
 }
 
 impl ResearchMetadata {
-    /// Create new metadata for library research
-    pub fn new_library(library_info: Option<&LibraryInfo>) -> Self {
+    /// Create new metadata with the given kind and details
+    pub fn new(kind: ResearchKind, details: metadata::ResearchDetails) -> Self {
         let now = Utc::now();
-        let details = match library_info {
-            Some(info) => metadata::ResearchDetails::Library(metadata::LibraryDetails {
-                package_manager: Some(info.package_manager.clone()),
-                language: Some(info.language.clone()),
-                url: Some(info.url.clone()),
-                repository: info.repository.clone(),
-            }),
-            None => metadata::ResearchDetails::Library(metadata::LibraryDetails::default()),
-        };
         Self {
             schema_version: 1,
-            kind: ResearchKind::Library,
+            kind,
             details,
             additional_files: std::collections::HashMap::new(),
+            question_tags: std::collections::HashMap::new(),
+            tags: Vec::new(),
+            category: None,
             created_at: now,
             updated_at: now,
             brief: None,
             summary: None,
             when_to_use: None,
+            archived_at: None,
+            archived_from: None,
+            generated_by: std::collections::BTreeMap::new(),
         }
     }
 
+    /// Create new metadata for a topic of the given kind.
+    ///
+    /// `library_info` is only used for library research.
+    pub fn new_for_kind(kind: ResearchKind, library_info: Option<&LibraryInfo>) -> Self {
+        let details = match kind {
+            ResearchKind::Library => return Self::new_library(library_info),
+            ResearchKind::Api => metadata::ResearchDetails::Api(Default::default()),
+            ResearchKind::Software => metadata::ResearchDetails::Software(Default::default()),
+            ResearchKind::Standard => metadata::ResearchDetails::Standard(Default::default()),
+        };
+        Self::new(kind, details)
+    }
+
+    /// Create new metadata for library research
+    pub fn new_library(library_info: Option<&LibraryInfo>) -> Self {
+        let details = match library_info {
+            Some(info) => metadata::ResearchDetails::Library(metadata::LibraryDetails {
+                package_manager: Some(info.package_manager.clone()),
+                language: Some(info.language.clone()),
+                url: Some(info.url.clone()),
+                repository: info.repository.clone(),
+            }),
+            None => metadata::ResearchDetails::Library(metadata::LibraryDetails::default()),
+        };
+        Self::new(ResearchKind::Library, details)
+    }
+
     /// Get library details if this is library research
     pub fn library_details(&self) -> Option<&metadata::LibraryDetails> {
         match &self.details {
@@ -367,6 +783,25 @@ impl ResearchMetadata {
         }
     }
 
+    /// Library info for prompt building, if this is library research.
+    ///
+    /// Details missing from the metadata are filled with placeholders.
+    pub fn library_info(&self) -> Option<LibraryInfo> {
+        self.library_details().map(|details| LibraryInfo {
+            package_manager: details
+                .package_manager
+                .clone()
+                .unwrap_or_else(|| "unknown".to_string()),
+            language: details
+                .language
+                .clone()
+                .unwrap_or_else(|| "unknown".to_string()),
+            url: details.url.clone().unwrap_or_else(|| "N/A".to_string()),
+            repository: details.repository.clone(),
+            description: None,
+        })
+    }
+
     /// Load metadata from a directory.
     ///
     /// Automatically migrates v0 metadata to v1 format if needed. When migration
@@ -469,12 +904,92 @@ impl ResearchMetadata {
         fs::write(&path, content).await
     }
 
+    /// Record that `model` produced the generated file `filename`.
+    pub fn record_generated_by(&mut self, filename: impl Into<String>, model: &ModelSpec) {
+        self.generated_by.insert(
+            filename.into(),
+            format!("{}:{}", model.provider, model.model),
+        );
+    }
+
     /// Add an additional file to the metadata
     pub fn add_additional_file(&mut self, filename: String, prompt: String) {
         self.additional_files.insert(filename, prompt);
         self.updated_at = Utc::now();
     }
 
+    /// Replace the tags of an additional file.
+    ///
+    /// Tags are normalized with [`crate::tags::normalize_tag`]; invalid tags are dropped
+    /// and an empty tag list removes the entry.
+    pub fn set_question_tags(&mut self, filename: &str, tags: &[String]) {
+        let normalized: std::collections::BTreeSet<String> =
+            tags.iter().filter_map(|t| crate::tags::normalize_tag(t)).collect();
+        if normalized.is_empty() {
+            self.question_tags.remove(filename);
+        } else {
+            self.question_tags
+                .insert(filename.to_string(), normalized.into_iter().collect());
+        }
+        self.updated_at = Utc::now();
+    }
+
+    /// Add tags to the topic, returning the tags that weren't already present.
+    ///
+    /// Tags are normalized with [`crate::tags::normalize_tag`]; invalid tags are dropped.
+    pub fn add_tags(&mut self, tags: &[String]) -> Vec<String> {
+        let mut current: std::collections::BTreeSet<String> = self.tags.drain(..).collect();
+        let added: Vec<String> = tags
+            .iter()
+            .filter_map(|t| crate::tags::normalize_tag(t))
+            .filter(|t| current.insert(t.clone()))
+            .collect();
+        self.tags = current.into_iter().collect();
+        if !added.is_empty() {
+            self.updated_at = Utc::now();
+        }
+        added
+    }
+
+    /// Remove tags from the topic, returning the tags that were present.
+    ///
+    /// Tags are normalized like [`add_tags`](Self::add_tags) before matching.
+    /// Question tags are left untouched.
+    pub fn remove_tags(&mut self, tags: &[String]) -> Vec<String> {
+        let unwanted: std::collections::BTreeSet<String> = tags
+            .iter()
+            .filter_map(|t| crate::tags::normalize_tag(t))
+            .collect();
+        let (removed, kept): (Vec<String>, Vec<String>) =
+            self.tags.drain(..).partition(|t| unwanted.contains(t));
+        self.tags = kept;
+        if !removed.is_empty() {
+            self.updated_at = Utc::now();
+        }
+        removed
+    }
+
+    /// Set or clear the topic's category.
+    ///
+    /// Categories are normalized like tags; an invalid category clears it.
+    pub fn set_category(&mut self, category: Option<&str>) {
+        let category = category.and_then(crate::tags::normalize_tag);
+        if category != self.category {
+            self.category = category;
+            self.updated_at = Utc::now();
+        }
+    }
+
+    /// Get all tags of the topic and its additional files (sorted, deduplicated)
+    pub fn tags(&self) -> Vec<String> {
+        let tags: std::collections::BTreeSet<&String> = self
+            .tags
+            .iter()
+            .chain(self.question_tags.values().flatten())
+            .collect();
+        tags.into_iter().cloned().collect()
+    }
+
     /// Check if a prompt is similar to an existing one (simple text-based comparison)
     pub fn check_overlap(&self, prompt: &str) -> Option<String> {
         let prompt_lower = prompt.to_lowercase();
@@ -513,6 +1028,22 @@ impl ResearchMetadata {
     }
 }
 
+/// Finds the existing additional file `question` overlaps with.
+///
+/// With the `embeddings` feature and `OPENAI_API_KEY` set, questions are
+/// compared by meaning (see the `overlap` module); otherwise, or if the
+/// embedding request fails, by [`ResearchMetadata::check_overlap`].
+async fn find_overlap(metadata: &ResearchMetadata, question: &str) -> Option<String> {
+    #[cfg(feature = "embeddings")]
+    if let Some(checker) = overlap::checker_from_env() {
+        match checker.conflicts(metadata, question).await {
+            Ok(conflicts) => return conflicts.into_iter().next().map(|c| c.filename),
+            Err(e) => warn!(error = %e, "Semantic overlap check failed; comparing words"),
+        }
+    }
+    metadata.check_overlap(question)
+}
+
 /// Result of overlap detection for a prompt
 #[derive(Debug, Clone)]
 pub struct PromptOverlap {
@@ -535,311 +1066,202 @@ pub enum OverlapVerdict {
     Conflict,
 }
 
-/// Response from crates.io API
-#[derive(Debug, Deserialize)]
-struct CratesIoResponse {
-    #[serde(rename = "crate")]
-    krate: Option<CratesIoCrate>,
-}
-
-#[derive(Debug, Deserialize)]
-struct CratesIoCrate {
-    description: Option<String>,
-    repository: Option<String>,
-}
-
-/// Response from npm registry API
-#[derive(Debug, Deserialize)]
-struct NpmResponse {
-    description: Option<String>,
-    repository: Option<NpmRepository>,
-}
-
-#[derive(Debug, Deserialize)]
-struct NpmRepository {
-    url: Option<String>,
-}
-
-/// Response from PyPI API
-#[derive(Debug, Deserialize)]
-struct PyPIResponse {
-    info: Option<PyPIInfo>,
-}
-
-#[derive(Debug, Deserialize)]
-struct PyPIInfo {
-    summary: Option<String>,
-    project_urls: Option<std::collections::HashMap<String, String>>,
-}
-
-/// Response from Packagist API
-#[derive(Debug, Deserialize)]
-struct PackagistSearchResponse {
-    results: Option<Vec<PackagistResult>>,
-}
-
-#[derive(Debug, Deserialize)]
-struct PackagistResult {
-    name: String,
-    description: Option<String>,
-    url: Option<String>,
-}
-
-/// Find a library across multiple package managers concurrently.
+/// Find a library across the configured package registries concurrently.
 ///
-/// Checks the following package managers:
+/// Checks the private registries configured in `registries.toml` (see
+/// [`registry::custom`]), then every registry in [`registry::default_registries`]:
 /// - crates.io (Rust)
 /// - npm (JavaScript/TypeScript)
 /// - PyPI (Python)
 /// - Packagist (PHP)
 /// - LuaRocks (Lua)
 /// - pkg.go.dev (Go)
+/// - RubyGems (Ruby)
+/// - Maven Central (Java)
+/// - NuGet (.NET)
+/// - Homebrew (native libraries)
 ///
 /// Returns a list of `LibraryInfo` for each package manager where the library was found.
 pub async fn find_library(name: &str) -> Vec<LibraryInfo> {
+    find_library_in(&registry::configured_registries(), name).await
+}
+
+/// Find a library in the given registries concurrently.
+///
+/// Results follow the order of `registries`; registries without a match are
+/// left out.
+pub async fn find_library_in(registries: &[Box<dyn Registry>], name: &str) -> Vec<LibraryInfo> {
     let client = HttpClient::builder()
         .user_agent("research-lib/0.1.0")
         .build()
         .unwrap_or_default();
 
-    let name = name.to_string();
-
-    // Check all package managers concurrently
-    let (crates_io, npm, pypi, packagist, luarocks, go) = tokio::join!(
-        check_crates_io(&client, &name),
-        check_npm(&client, &name),
-        check_pypi(&client, &name),
-        check_packagist(&client, &name),
-        check_luarocks(&client, &name),
-        check_go(&client, &name),
-    );
+    // Check all registries concurrently
+    let found = join_all(
+        registries
+            .iter()
+            .map(|registry| registry.lookup(&client, name)),
+    )
+    .await;
 
     // Collect all found libraries (no printing here - select_library handles display)
-    [crates_io, npm, pypi, packagist, luarocks, go]
-        .into_iter()
-        .flatten()
-        .collect()
+    found.into_iter().flatten().collect()
 }
 
-async fn check_crates_io(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
-    let url = format!("https://crates.io/api/v1/crates/{}", name);
-    let response = client.get(&url).send().await.ok()?;
-
-    if !response.status().is_success() {
-        return None;
-    }
-
-    let data: CratesIoResponse = response.json().await.ok()?;
-    let description = data.krate.as_ref().and_then(|c| c.description.clone());
-    let repository = data.krate.as_ref().and_then(|c| c.repository.clone());
-
-    Some(LibraryInfo {
-        package_manager: "crates.io".to_string(),
-        language: "Rust".to_string(),
-        url: format!("https://crates.io/crates/{}", name),
-        repository,
-        description,
-    })
+/// Result of library selection
+#[derive(Debug)]
+pub enum LibrarySelection {
+    /// User selected a specific library
+    Selected(LibraryInfo),
+    /// Only one library was found (no selection needed)
+    Single(LibraryInfo),
+    /// No libraries were found
+    NotFound,
+    /// User cancelled the selection (pressed ESC)
+    Cancelled,
+    /// Several libraries matched and [`SelectionPolicy::Fail`] was in effect
+    Ambiguous(Vec<LibraryInfo>),
 }
 
-async fn check_npm(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
-    let url = format!("https://registry.npmjs.org/{}", name);
-    let response = client.get(&url).send().await.ok()?;
-
-    if !response.status().is_success() {
-        return None;
-    }
-
-    let data: NpmResponse = response.json().await.ok()?;
-
-    // Extract repository URL and clean git+ prefix
-    let repository = data
-        .repository
-        .as_ref()
-        .and_then(|r| r.url.as_ref())
-        .map(|url| {
-            // Clean git+https:// prefix to just https://
-            url.strip_prefix("git+").unwrap_or(url).to_string()
-        });
-
-    Some(LibraryInfo {
-        package_manager: "npm".to_string(),
-        language: "JavaScript/TypeScript".to_string(),
-        url: format!("https://www.npmjs.com/package/{}", name),
-        repository,
-        description: data.description,
-    })
+/// How to pick a library when several package managers match a topic.
+///
+/// Scripted runs have no terminal to prompt on, so they pick a policy that
+/// resolves the choice on its own.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::SelectionPolicy;
+///
+/// assert_eq!("first".parse(), Ok(SelectionPolicy::First));
+/// assert_eq!(
+///     "rust".parse(),
+///     Ok(SelectionPolicy::PreferLanguage("rust".to_string()))
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SelectionPolicy {
+    /// Ask on the terminal (the default)
+    #[default]
+    Interactive,
+    /// Take the first match in this language (case-insensitive, e.g. `rust`
+    /// or `typescript`), or the first match if none is in it
+    PreferLanguage(String),
+    /// Take the first match, in registry order
+    First,
+    /// Don't pick; research fails with [`ResearchError::AmbiguousLibrary`]
+    Fail,
 }
 
-async fn check_pypi(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
-    let url = format!("https://pypi.org/pypi/{}/json", name);
-    let response = client.get(&url).send().await.ok()?;
-
-    if !response.status().is_success() {
-        return None;
+impl SelectionPolicy {
+    /// Whether `library`'s language is `language`, or one of the languages
+    /// it lists (`JavaScript/TypeScript`).
+    fn is_language(library: &LibraryInfo, language: &str) -> bool {
+        let language = language.trim();
+        library.language.eq_ignore_ascii_case(language)
+            || library
+                .language
+                .split('/')
+                .any(|part| part.trim().eq_ignore_ascii_case(language))
     }
-
-    let data: PyPIResponse = response.json().await.ok()?;
-    let description = data.info.as_ref().and_then(|i| i.summary.clone());
-
-    // Extract repository URL from project_urls (try "Repository" first, then "Source")
-    let repository = data
-        .info
-        .as_ref()
-        .and_then(|i| i.project_urls.as_ref())
-        .and_then(|urls| {
-            urls.get("Repository")
-                .or_else(|| urls.get("Source"))
-                .cloned()
-        });
-
-    Some(LibraryInfo {
-        package_manager: "PyPI".to_string(),
-        language: "Python".to_string(),
-        url: format!("https://pypi.org/project/{}", name),
-        repository,
-        description,
-    })
 }
 
-async fn check_packagist(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
-    // Packagist requires vendor/package format, so we search instead
-    let url = format!("https://packagist.org/search.json?q={}", name);
-    let response = client.get(&url).send().await.ok()?;
+impl FromStr for SelectionPolicy {
+    type Err = Infallible;
 
-    if !response.status().is_success() {
-        return None;
+    /// Parses `interactive`, `first` or `fail`; anything else is a language to prefer.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "interactive" | "ask" => SelectionPolicy::Interactive,
+            "first" => SelectionPolicy::First,
+            "fail" | "error" => SelectionPolicy::Fail,
+            _ => SelectionPolicy::PreferLanguage(s.to_string()),
+        })
     }
-
-    let data: PackagistSearchResponse = response.json().await.ok()?;
-    let results = data.results?;
-
-    // Look for an exact match in the package name
-    let matching = results.into_iter().find(|r| {
-        let package_name = r.name.split('/').next_back().unwrap_or(&r.name);
-        package_name == name
-    })?;
-
-    Some(LibraryInfo {
-        package_manager: "Packagist".to_string(),
-        language: "PHP".to_string(),
-        url: matching
-            .url
-            .unwrap_or_else(|| format!("https://packagist.org/packages/{}", matching.name)),
-        repository: None,
-        description: matching.description,
-    })
 }
 
-async fn check_luarocks(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
-    // LuaRocks doesn't have a formal API, but we can check if the package page exists
-    let url = format!("https://luarocks.org/modules/{}", name);
-    let response = client.head(&url).send().await.ok()?;
-
-    if response.status().is_success() {
-        return Some(LibraryInfo {
-            package_manager: "LuaRocks".to_string(),
-            language: "Lua".to_string(),
-            url,
-            repository: None,
-            description: None,
-        });
-    }
-
-    // Try searching
-    let search_url = format!("https://luarocks.org/search?q={}", name);
-    let response = client.get(&search_url).send().await.ok()?;
-
-    if response.status().is_success() {
-        let body = response.text().await.ok()?;
-        // Simple check if the package name appears in search results
-        if body.contains(&format!("\"/{}/", name)) || body.contains(&format!(">{}<", name)) {
-            return Some(LibraryInfo {
-                package_manager: "LuaRocks".to_string(),
-                language: "Lua".to_string(),
-                url: format!("https://luarocks.org/modules/{}", name),
-                repository: None,
-                description: None,
-            });
+impl fmt::Display for SelectionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelectionPolicy::Interactive => f.write_str("interactive"),
+            SelectionPolicy::PreferLanguage(language) => f.write_str(language),
+            SelectionPolicy::First => f.write_str("first"),
+            SelectionPolicy::Fail => f.write_str("fail"),
         }
     }
-
-    None
 }
 
-async fn check_go(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
-    // pkg.go.dev requires full module path, but we can search
-    // First try as a potential GitHub path
-    let common_prefixes = ["github.com/", "golang.org/x/", ""];
-
-    for prefix in common_prefixes {
-        let module = if prefix.is_empty() {
-            name.to_string()
-        } else {
-            format!("{}{}", prefix, name)
-        };
-
-        let url = format!("https://pkg.go.dev/{}", module);
-        let response = client.head(&url).send().await.ok();
-
-        if let Some(resp) = response
-            && resp.status().is_success()
-        {
-            return Some(LibraryInfo {
-                package_manager: "pkg.go.dev".to_string(),
-                language: "Go".to_string(),
-                url,
-                repository: None,
-                description: None,
-            });
-        }
-    }
-
-    None
+/// Interactively select a library if multiple package managers match.
+///
+/// Shorthand for [`select_library_with`] and [`SelectionPolicy::Interactive`],
+/// reporting to the console.
+pub fn select_library(libraries: Vec<LibraryInfo>, topic: &str) -> LibrarySelection {
+    select_library_with(
+        libraries,
+        topic,
+        &SelectionPolicy::Interactive,
+        &ConsoleObserver,
+    )
 }
 
-/// Result of library selection
-#[derive(Debug)]
-pub enum LibrarySelection {
-    /// User selected a specific library
-    Selected(LibraryInfo),
-    /// Only one library was found (no selection needed)
-    Single(LibraryInfo),
-    /// No libraries were found
-    NotFound,
-    /// User cancelled the selection (pressed ESC)
-    Cancelled,
-}
-
-/// Interactively select a library if multiple package managers match.
+/// Select a library, resolving multiple matches with `policy`.
 ///
 /// - If no matches: returns `LibrarySelection::NotFound`
 /// - If one match: returns `LibrarySelection::Single` with info message
-/// - If multiple matches: prompts user to select one
+/// - If multiple matches: picks one as `policy` says, prompting the user
+///   only for [`SelectionPolicy::Interactive`]
 /// - If user cancels: returns `LibrarySelection::Cancelled`
-pub fn select_library(libraries: Vec<LibraryInfo>, topic: &str) -> LibrarySelection {
+/// - If `policy` is [`SelectionPolicy::Fail`]: returns
+///   `LibrarySelection::Ambiguous` with every match
+///
+/// What was found (or chosen) is reported to `observer`.
+pub fn select_library_with(
+    libraries: Vec<LibraryInfo>,
+    topic: &str,
+    policy: &SelectionPolicy,
+    observer: &dyn ResearchObserver,
+) -> LibrarySelection {
     match libraries.len() {
         0 => {
-            println!(
-                "  ⚠ '{}' not found on any package manager (may be a general topic)\n",
+            observer.on_warning(&format!(
+                "'{}' not found on any package manager (may be a general topic)",
                 topic
-            );
+            ));
             LibrarySelection::NotFound
         }
         1 => {
             let lib = libraries.into_iter().next().unwrap();
-            println!(
+            observer.on_info(&format!(
                 "  ✓ Found '{}' on {} ({})\n",
                 topic, lib.package_manager, lib.language
-            );
+            ));
             LibrarySelection::Single(lib)
         }
+        _ if *policy == SelectionPolicy::Fail => LibrarySelection::Ambiguous(libraries),
+        _ if *policy != SelectionPolicy::Interactive => {
+            let preferred = match policy {
+                SelectionPolicy::PreferLanguage(language) => libraries
+                    .iter()
+                    .position(|lib| SelectionPolicy::is_language(lib, language)),
+                _ => None,
+            };
+            let lib = libraries
+                .into_iter()
+                .nth(preferred.unwrap_or(0))
+                .expect("several libraries matched");
+            observer.on_info(&format!(
+                "  ✓ Found '{}' on several package managers; using {} ({})\n",
+                topic, lib.package_manager, lib.language
+            ));
+            LibrarySelection::Selected(lib)
+        }
         _ => {
-            println!(
+            observer.on_info(&format!(
                 "\n  Found '{}' on {} package managers. Please select the intended one:\n",
                 topic,
                 libraries.len()
-            );
+            ));
 
             let selection = Select::new("Which package manager?", libraries)
                 .with_help_message("↑↓ to move, enter to select, ESC to skip")
@@ -847,18 +1269,18 @@ pub fn select_library(libraries: Vec<LibraryInfo>, topic: &str) -> LibrarySelect
 
             match selection {
                 Ok(lib) => {
-                    println!(
+                    observer.on_info(&format!(
                         "\n  → Selected: {} ({})\n",
                         lib.package_manager, lib.language
-                    );
+                    ));
                     LibrarySelection::Selected(lib)
                 }
                 Err(InquireError::OperationCanceled | InquireError::OperationInterrupted) => {
-                    println!("\n  ⚠ Selection skipped, continuing as general topic\n");
+                    observer.on_warning("Selection skipped, continuing as general topic");
                     LibrarySelection::Cancelled
                 }
                 Err(_) => {
-                    println!("\n  ⚠ Selection error, continuing as general topic\n");
+                    observer.on_warning("Selection error, continuing as general topic");
                     LibrarySelection::Cancelled
                 }
             }
@@ -884,12 +1306,50 @@ pub enum ResearchError {
     #[error("Skill regeneration failed: {0}")]
     SkillRegenerationFailed(String),
 
+    #[error("Brief regeneration failed: {0}")]
+    BriefRegenerationFailed(String),
+
     #[error("Invalid flag combination: {0}")]
     InvalidFlagCombination(String),
+
+    #[error("Tagging failed: {0}")]
+    TaggingFailed(String),
+
+    #[error("Invalid model configuration: {0}")]
+    ModelConfig(#[from] ModelConfigError),
+
+    #[error("No interrupted research run to resume in {}", .0.display())]
+    NoCheckpoint(PathBuf),
+
+    #[error("No research metadata found in {}", .0.display())]
+    NoMetadata(PathBuf),
+
+    #[error(
+        "'{topic}' matches several libraries ({candidates}); choose one with a selection policy"
+    )]
+    AmbiguousLibrary { topic: String, candidates: String },
+
+    #[error("Invalid question: {0}")]
+    InvalidQuestion(#[from] QuestionError),
+}
+
+impl ResearchError {
+    /// The error for a topic that several `libraries` matched.
+    fn ambiguous_library(topic: &str, libraries: &[LibraryInfo]) -> Self {
+        let candidates = libraries
+            .iter()
+            .map(|lib| format!("{} ({})", lib.package_manager, lib.language))
+            .collect::<Vec<_>>()
+            .join(", ");
+        ResearchError::AmbiguousLibrary {
+            topic: topic.to_string(),
+            candidates,
+        }
+    }
 }
 
 /// Metrics from a completed prompt
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PromptMetrics {
     pub input_tokens: u64,
     pub output_tokens: u64,
@@ -898,7 +1358,9 @@ pub struct PromptMetrics {
 }
 
 /// Result of a research operation
-#[derive(Debug)]
+///
+/// Serializes as the `result` of the run's [`RunLog`](run_log::RunLog).
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResearchResult {
     pub topic: String,
     pub output_dir: PathBuf,
@@ -911,6 +1373,132 @@ pub struct ResearchResult {
     pub total_tokens: u64,
 }
 
+/// Optional behaviour for [`research_with_options`].
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::ResearchOptions;
+/// use research_lib::hooks::CompletionHooks;
+///
+/// let options = ResearchOptions::default()
+///     .with_hooks(CompletionHooks::default().with_webhook("https://hooks.example.com/research"));
+/// assert!(options.hooks.is_enabled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ResearchOptions {
+    /// Hooks to run once the research run has finished
+    pub hooks: hooks::CompletionHooks,
+    /// Stream prompt output to disk as it arrives, reporting progress here
+    pub streaming: Option<ProgressHandler>,
+    /// Models to use instead of the ones loaded by [`ModelConfig::load`]
+    pub models: Option<ModelConfig>,
+    /// Receives progress events; progress is printed to the console when unset
+    pub observer: Option<Arc<dyn ResearchObserver>>,
+    /// What kind of topic is being researched (defaults to a library)
+    pub kind: ResearchKind,
+    /// Limits on model requests shared with other runs
+    pub scheduler: Option<Arc<ResearchScheduler>>,
+    /// Library prompt variants to use instead of the ones picked from the
+    /// library's language
+    pub ecosystem: Option<Ecosystem>,
+    /// Source repository to analyze and add to the Phase 1 prompts
+    pub repository: Option<RepositorySource>,
+    /// How to pick between libraries found on several package managers
+    pub selection: SelectionPolicy,
+    /// Library to write to instead of the one found by [`ResearchPaths::from_env`]
+    pub paths: Option<ResearchPaths>,
+}
+
+impl ResearchOptions {
+    /// Sets the completion hooks.
+    pub fn with_hooks(mut self, hooks: hooks::CompletionHooks) -> Self {
+        self.hooks = hooks;
+        self
+    }
+
+    /// Streams prompt output to disk, printing progress to the console.
+    ///
+    /// See the [`streaming`] module for which prompts stream.
+    pub fn with_streaming(self) -> Self {
+        self.with_progress(ProgressHandler::console())
+    }
+
+    /// Streams prompt output to disk, reporting progress to `handler`.
+    pub fn with_progress(mut self, handler: ProgressHandler) -> Self {
+        self.streaming = Some(handler);
+        self
+    }
+
+    /// Uses `models` for every prompt task.
+    ///
+    /// Without this, models come from the config file and `RESEARCH_MODEL_*`
+    /// variables (see the [`models`] module).
+    pub fn with_models(mut self, models: ModelConfig) -> Self {
+        self.models = Some(models);
+        self
+    }
+
+    /// Reports progress to `observer` instead of printing it.
+    ///
+    /// See the [`observer`] module for the events a run reports.
+    pub fn with_observer(mut self, observer: Arc<dyn ResearchObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Researches the topic as `kind`, selecting its prompts and output directory.
+    ///
+    /// [`ResearchKind::Api`] is not supported here; use [`research_api`].
+    pub fn with_kind(mut self, kind: ResearchKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Runs model requests through `scheduler`, sharing its limits with every
+    /// other run given the same scheduler.
+    ///
+    /// See the [`scheduler`] module for the limits it applies.
+    pub fn with_scheduler(mut self, scheduler: Arc<ResearchScheduler>) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
+    /// Researches libraries with `ecosystem`'s prompt variants, whatever
+    /// their language.
+    ///
+    /// See the [`ecosystem`] module for how the variants are otherwise picked.
+    pub fn with_ecosystem(mut self, ecosystem: Ecosystem) -> Self {
+        self.ecosystem = Some(ecosystem);
+        self
+    }
+
+    /// Analyzes the repository at `source` and grounds the Phase 1 prompts of
+    /// full research runs in its public API and examples.
+    ///
+    /// See the [`repository`] module for what the context contains.
+    pub fn with_repository(mut self, source: RepositorySource) -> Self {
+        self.repository = Some(source);
+        self
+    }
+
+    /// Picks between libraries found on several package managers with
+    /// `policy` instead of asking on the terminal.
+    pub fn with_selection(mut self, policy: SelectionPolicy) -> Self {
+        self.selection = policy;
+        self
+    }
+
+    /// Writes topics without an explicit output directory to the library
+    /// at `paths`.
+    ///
+    /// See the [`paths`] module for how the library is otherwise found.
+    pub fn with_paths(mut self, paths: ResearchPaths) -> Self {
+        self.paths = Some(paths);
+        self
+    }
+}
+
 /// Split multi-file LLM output into separate files.
 /// Handles the implicit first file (SKILL.md) that doesn't have a separator before it.
 ///
@@ -955,6 +1543,66 @@ fn split_into_files(content: &str) -> Vec<(String, String)> {
     files
 }
 
+/// Write a generated document to `path`, carrying over the protected
+/// sections (see [`protected`]) of the version it replaces.
+///
+/// Sections that could not be carried over as written are reported to the
+/// observer as warnings.
+async fn write_generated(
+    path: &Path,
+    content: &str,
+    observer: &dyn ResearchObserver,
+) -> std::io::Result<()> {
+    let unpreserved = protected::write_preserving(path, content).await?;
+    report_unpreserved(path, &unpreserved, observer);
+    Ok(())
+}
+
+/// Re-insert the protected sections of `existing`, the version of `path` read
+/// before a task replaced it, into the file the task wrote.
+///
+/// Used by streaming tasks, which truncate their output file before the
+/// first token arrives.
+async fn reapply_protected(path: &Path, existing: &str, observer: &dyn ResearchObserver) {
+    if existing.is_empty() {
+        return;
+    }
+    let result = match protected::read_existing(path).await {
+        Ok(content) => protected::reapply(path, existing, &content).await,
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(unpreserved) => report_unpreserved(path, &unpreserved, observer),
+        Err(e) => {
+            let message = format!(
+                "{}: could not restore protected sections: {}",
+                path.display(),
+                e
+            );
+            warn!("{}", message);
+            observer.on_warning(&message);
+        }
+    }
+}
+
+/// Warn about protected sections that could not be carried over as written.
+fn report_unpreserved(
+    path: &Path,
+    unpreserved: &[protected::UnpreservedBlock],
+    observer: &dyn ResearchObserver,
+) {
+    for block in unpreserved {
+        let message = format!(
+            "{}: protected section at line {} {}",
+            path.display(),
+            block.line,
+            block.reason
+        );
+        warn!("{}", message);
+        observer.on_warning(&message);
+    }
+}
+
 /// Normalize markdown by parsing and re-serializing it.
 /// This produces consistent formatting regardless of LLM output style.
 /// Also strips empty HTML anchor tags that LLMs sometimes generate for navigation.
@@ -1011,6 +1659,134 @@ fn parse_brief_response(response: &str) -> (Option<String>, Option<String>) {
     (brief, summary)
 }
 
+/// Ask the brief model to condense a deep dive into `BRIEF:`/`SUMMARY:` lines.
+///
+/// Returns the raw response text for [`parse_brief_response`].
+async fn request_brief(
+    topic: &str,
+    deep_dive: &str,
+    clients: &ModelClients,
+) -> Result<String, String> {
+    let brief_prompt = prompts::BRIEF
+        .get()
+        .replace("{{topic}}", topic)
+        .replace("{{deep_dive}}", deep_dive);
+
+    let brief_prompt = &brief_prompt;
+    let choice = with_fallback!(clients, ResearchTask::Brief, |client, model, temp| {
+        client
+            .completion_model(model)
+            .completion_request(brief_prompt)
+            .temperature_opt(temp)
+            .send()
+            .await
+            .map(|response| response.choice)
+    })
+    .map_err(|e| e.to_string())?;
+
+    Ok(choice
+        .into_iter()
+        .filter_map(|c| match c {
+            AssistantContent::Text(text) => Some(text.text),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Ask the skill model to fix SKILL.md frontmatter that failed validation.
+///
+/// Only the frontmatter and `error` are sent; the model's corrected
+/// frontmatter is joined back onto the original body. Returns the request
+/// error as a string when every model fails.
+async fn request_frontmatter_fix(
+    topic: &str,
+    content: &str,
+    error: &FrontmatterError,
+    clients: &ModelClients,
+) -> Result<String, String> {
+    let (frontmatter, body) = split_skill_frontmatter(content);
+    let fix_prompt = prompts::SKILL_FRONTMATTER
+        .get()
+        .replace("{{topic}}", topic)
+        .replace("{{error}}", &error.to_string())
+        .replace("{{frontmatter}}", &frontmatter);
+
+    let fix_prompt = &fix_prompt;
+    let choice = with_fallback!(clients, ResearchTask::Skill, |client, model, temp| {
+        client
+            .completion_model(model)
+            .completion_request(fix_prompt)
+            .temperature_opt(temp)
+            .send()
+            .await
+            .map(|response| response.choice)
+    })
+    .map_err(|e| e.to_string())?;
+
+    let fixed = choice
+        .into_iter()
+        .filter_map(|c| match c {
+            AssistantContent::Text(text) => Some(text.text),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(join_skill_frontmatter(&fixed, &body))
+}
+
+/// Validate SKILL.md at `path`, asking a model to fix its frontmatter once if
+/// it is invalid.
+///
+/// A repaired file is written back to `path`. Returns the validation error of
+/// the repaired version, or the original error if the repair request failed.
+async fn validate_skill_with_repair(
+    topic: &str,
+    path: &Path,
+    content: &str,
+    clients: &ModelClients,
+    observer: &dyn ResearchObserver,
+) -> Result<SkillFrontmatter, FrontmatterError> {
+    let error = match parse_and_validate_frontmatter(content) {
+        Ok((frontmatter, _body)) => return Ok(frontmatter),
+        Err(e) => e,
+    };
+
+    tracing::warn!(
+        "SKILL.md frontmatter is invalid ({}), asking the model to fix it",
+        error
+    );
+    observer.on_info(&format!(
+        "SKILL.md frontmatter is invalid ({}), attempting repair...",
+        error
+    ));
+
+    let repaired = match request_frontmatter_fix(topic, content, &error, clients).await {
+        Ok(repaired) => repaired,
+        Err(e) => {
+            tracing::warn!("SKILL.md frontmatter repair request failed: {}", e);
+            return Err(error);
+        }
+    };
+    let (frontmatter, _body) = parse_and_validate_frontmatter(&repaired)?;
+
+    if let Err(e) = write_generated(path, &repaired, observer).await {
+        tracing::warn!("Failed to save repaired SKILL.md: {}", e);
+    } else {
+        tracing::info!("✓ Repaired SKILL.md frontmatter");
+    }
+    Ok(frontmatter)
+}
+
+/// Render `brief.md`: the one-line brief and repository as frontmatter,
+/// followed by the summary.
+fn brief_document(brief: &str, summary: &str, repository: Option<&str>) -> String {
+    let repo_url = repository
+        .map(|r| format!("repo: {}\n", r))
+        .unwrap_or_default();
+    format!("---\nsummary: {}\n{}---\n\n{}", brief, repo_url, summary)
+}
+
 /// Library context for building prompts
 struct LibraryContext<'a> {
     package_manager: &'a str,
@@ -1193,8 +1969,90 @@ fn build_changelog_prompt(
 }
 
 /// Result of a single prompt task
+#[derive(Default)]
 struct PromptTaskResult {
     metrics: Option<PromptMetrics>,
+    /// The model the task ran on, set by [`failover_task`]
+    model: Option<ModelSpec>,
+}
+
+/// A boxed research task, as built for one model by the closures passed to
+/// [`failover_task`].
+type TaskFuture<'a> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = PromptTaskResult> + Send + 'a>>;
+
+/// Run a research task on the first of `specs`, failing over to the next
+/// model when its provider rate limits or errors (see [`providers::failover`]).
+///
+/// `attempt` builds the task for a model, reporting to the observer it is
+/// given. The returned result records the model the task ran on last.
+async fn failover_task<'a, F>(
+    name: &str,
+    specs: Vec<ModelSpec>,
+    counter: Arc<AtomicUsize>,
+    observer: Arc<dyn ResearchObserver>,
+    attempt: F,
+) -> PromptTaskResult
+where
+    F: FnMut(&ModelSpec, Arc<dyn ResearchObserver>) -> TaskFuture<'a>,
+{
+    let (mut result, spec) =
+        providers::failover::run_with_failover(name, &specs, &counter, &observer, attempt).await;
+    result.model = Some(spec);
+    result
+}
+
+/// Record the model behind each document produced by `results`, where
+/// `filenames[i]` is the document `results[i]` wrote.
+fn record_generated_by(
+    metadata: &mut ResearchMetadata,
+    filenames: &[String],
+    results: &[PromptTaskResult],
+) {
+    for (filename, result) in filenames.iter().zip(results) {
+        if let (Some(_), Some(model)) = (&result.metrics, &result.model) {
+            metadata.record_generated_by(filename, model);
+        }
+    }
+}
+
+/// Reports a finished task to the observer.
+fn report_task(
+    observer: &dyn ResearchObserver,
+    task: &str,
+    completed: usize,
+    total: usize,
+    elapsed_secs: f32,
+    outcome: TaskOutcome,
+) {
+    observer.on_task_complete(&TaskReport {
+        task: task.to_string(),
+        completed,
+        total,
+        elapsed_secs,
+        outcome,
+    });
+}
+
+/// Reports a task stopped by cancellation; it doesn't count as completed.
+fn report_cancelled(
+    observer: &dyn ResearchObserver,
+    task: &str,
+    counter: &AtomicUsize,
+    total: usize,
+    start_time: Instant,
+    partial_output_kept: bool,
+) {
+    report_task(
+        observer,
+        task,
+        counter.load(Ordering::SeqCst),
+        total,
+        start_time.elapsed().as_secs_f32(),
+        TaskOutcome::Cancelled {
+            partial_output_kept,
+        },
+    );
 }
 
 /// Run a prompt task and save result, printing progress as it completes
@@ -1203,29 +2061,30 @@ async fn run_prompt_task<M>(
     name: &'static str,
     filename: &str,
     output_dir: PathBuf,
-    model: M,
+    model: TunedModel<M>,
     prompt: String,
     counter: Arc<AtomicUsize>,
     total: usize,
     start_time: Instant,
     cancelled: Arc<AtomicBool>,
+    observer: Arc<dyn ResearchObserver>,
 ) -> PromptTaskResult
 where
     M: CompletionModel,
 {
     // Check if already cancelled before starting
     if cancelled.load(Ordering::SeqCst) {
-        return PromptTaskResult { metrics: None };
+        return PromptTaskResult::default();
     }
 
-    println!("  [{}] Starting...", name);
+    observer.on_task_start(name, &TaskStage::Prompt);
 
     let result = model.completion_request(&prompt).send().await;
 
     // Check if cancelled after the request completed
     if cancelled.load(Ordering::SeqCst) {
-        println!("  [{}] Cancelled (response discarded)", name);
-        return PromptTaskResult { metrics: None };
+        report_cancelled(observer.as_ref(), name, &counter, total, start_time, false);
+        return PromptTaskResult::default();
     }
 
     let elapsed = start_time.elapsed().as_secs_f32();
@@ -1259,45 +2118,245 @@ where
                 && !parent.exists()
                 && let Err(e) = fs::create_dir_all(parent).await
             {
-                eprintln!(
-                    "  [{}/{}] ✗ {} failed to create directory: {} ({:.1}s)",
-                    completed, total, name, e, elapsed
+                report_task(
+                    observer.as_ref(),
+                    name,
+                    completed,
+                    total,
+                    elapsed,
+                    TaskOutcome::Failed {
+                        error: format!("could not create directory: {}", e),
+                    },
                 );
-                return PromptTaskResult { metrics: None };
+                return PromptTaskResult::default();
             }
-            match fs::write(&path, &content).await {
+            match write_generated(&path, &content, observer.as_ref()).await {
                 Ok(_) => {
-                    println!(
-                        "  [{}/{}] ✓ {} ({:.1}s) | tokens: {} in, {} out, {} total",
+                    report_task(
+                        observer.as_ref(),
+                        name,
                         completed,
                         total,
-                        name,
                         elapsed,
-                        metrics.input_tokens,
-                        metrics.output_tokens,
-                        metrics.total_tokens,
+                        TaskOutcome::Succeeded {
+                            metrics: Some(metrics.clone()),
+                        },
                     );
                     Some(metrics)
                 }
                 Err(e) => {
-                    eprintln!(
-                        "  [{}/{}] ✗ {} write failed: {} ({:.1}s)",
-                        completed, total, name, e, elapsed
+                    report_task(
+                        observer.as_ref(),
+                        name,
+                        completed,
+                        total,
+                        elapsed,
+                        TaskOutcome::Failed {
+                            error: format!("write failed: {}", e),
+                        },
                     );
                     None
                 }
             }
         }
         Err(e) => {
-            eprintln!(
-                "  [{}/{}] ✗ {} failed: {} ({:.1}s)",
-                completed, total, name, e, elapsed
+            report_task(
+                observer.as_ref(),
+                name,
+                completed,
+                total,
+                elapsed,
+                TaskOutcome::Failed {
+                    error: e.to_string(),
+                },
             );
             None
         }
     };
 
-    PromptTaskResult { metrics }
+    PromptTaskResult {
+        metrics,
+        model: None,
+    }
+}
+
+/// Run a prompt task, streaming its output when a progress handler is given
+#[allow(clippy::too_many_arguments)]
+async fn dispatch_prompt_task<M>(
+    streaming: Option<ProgressHandler>,
+    name: &'static str,
+    filename: &str,
+    output_dir: PathBuf,
+    model: TunedModel<M>,
+    prompt: String,
+    counter: Arc<AtomicUsize>,
+    total: usize,
+    start_time: Instant,
+    cancelled: Arc<AtomicBool>,
+    observer: Arc<dyn ResearchObserver>,
+) -> PromptTaskResult
+where
+    M: CompletionModel,
+{
+    match streaming {
+        Some(progress) => {
+            run_prompt_task_streaming(
+                name, filename, output_dir, model, prompt, counter, total, start_time, cancelled,
+                observer, progress,
+            )
+            .await
+        }
+        None => {
+            run_prompt_task(
+                name, filename, output_dir, model, prompt, counter, total, start_time, cancelled,
+                observer,
+            )
+            .await
+        }
+    }
+}
+
+/// Run a prompt task, appending tokens to the output file as they arrive.
+///
+/// Unlike [`run_prompt_task`], the output file exists (and grows) while the
+/// model is still generating. If the stream fails or the run is cancelled,
+/// the partial content is left on disk and the task counts as failed.
+#[allow(clippy::too_many_arguments)]
+async fn run_prompt_task_streaming<M>(
+    name: &'static str,
+    filename: &str,
+    output_dir: PathBuf,
+    model: TunedModel<M>,
+    prompt: String,
+    counter: Arc<AtomicUsize>,
+    total: usize,
+    start_time: Instant,
+    cancelled: Arc<AtomicBool>,
+    observer: Arc<dyn ResearchObserver>,
+    progress: ProgressHandler,
+) -> PromptTaskResult
+where
+    M: CompletionModel,
+{
+    use futures::StreamExt;
+    use rig::completion::GetTokenUsage;
+    use rig::streaming::StreamedAssistantContent;
+
+    if cancelled.load(Ordering::SeqCst) {
+        return PromptTaskResult::default();
+    }
+
+    let path = output_dir.join(filename);
+    let fail = |error: String, chars: usize| {
+        let elapsed = start_time.elapsed().as_secs_f32();
+        let completed = counter.fetch_add(1, Ordering::SeqCst) + 1;
+        report_task(
+            observer.as_ref(),
+            name,
+            completed,
+            total,
+            elapsed,
+            TaskOutcome::Failed {
+                error: error.clone(),
+            },
+        );
+        progress.emit(PromptProgress::Failed {
+            task: name.to_string(),
+            error,
+            chars,
+        });
+        PromptTaskResult::default()
+    };
+
+    // Creating the output truncates it, so keep the previous version to
+    // restore its protected sections from
+    let existing = protected::read_existing(&path).await.unwrap_or_default();
+    let mut output = match PartialOutput::create(&path).await {
+        Ok(output) => output,
+        Err(e) => return fail(format!("could not create {}: {}", path.display(), e), 0),
+    };
+
+    observer.on_task_start(name, &TaskStage::Streaming { path: path.clone() });
+    progress.emit(PromptProgress::Started {
+        task: name.to_string(),
+        path: path.clone(),
+    });
+
+    let mut stream = match model.completion_request(&prompt).stream().await {
+        Ok(stream) => stream,
+        Err(e) => {
+            reapply_protected(&path, &existing, observer.as_ref()).await;
+            return fail(e.to_string(), 0);
+        }
+    };
+
+    let mut usage = None;
+    while let Some(chunk) = stream.next().await {
+        if cancelled.load(Ordering::SeqCst) {
+            reapply_protected(&path, &existing, observer.as_ref()).await;
+            report_cancelled(observer.as_ref(), name, &counter, total, start_time, true);
+            progress.emit(PromptProgress::Failed {
+                task: name.to_string(),
+                error: "cancelled".to_string(),
+                chars: output.chars(),
+            });
+            return PromptTaskResult::default();
+        }
+
+        match chunk {
+            Ok(StreamedAssistantContent::Text(text)) => match output.push(&text.text).await {
+                Ok(delta) => progress.emit(PromptProgress::Chunk {
+                    task: name.to_string(),
+                    delta,
+                    chars: output.chars(),
+                }),
+                Err(e) => {
+                    reapply_protected(&path, &existing, observer.as_ref()).await;
+                    return fail(format!("write failed: {}", e), output.chars());
+                }
+            },
+            Ok(StreamedAssistantContent::Final(response)) => usage = response.token_usage(),
+            Ok(_) => {}
+            Err(e) => {
+                reapply_protected(&path, &existing, observer.as_ref()).await;
+                let chars = output.chars();
+                return fail(format!("{} (partial output kept)", e), chars);
+            }
+        }
+    }
+
+    reapply_protected(&path, &existing, observer.as_ref()).await;
+
+    let elapsed = start_time.elapsed().as_secs_f32();
+    let completed = counter.fetch_add(1, Ordering::SeqCst) + 1;
+    // Providers that don't report usage for streams leave the counts at zero
+    let metrics = PromptMetrics {
+        input_tokens: usage.as_ref().map_or(0, |u| u.input_tokens),
+        output_tokens: usage.as_ref().map_or(0, |u| u.output_tokens),
+        total_tokens: usage.as_ref().map_or(0, |u| u.total_tokens),
+        elapsed_secs: elapsed,
+    };
+
+    report_task(
+        observer.as_ref(),
+        name,
+        completed,
+        total,
+        elapsed,
+        TaskOutcome::Succeeded {
+            metrics: Some(metrics.clone()),
+        },
+    );
+    progress.emit(PromptProgress::Completed {
+        task: name.to_string(),
+        chars: output.chars(),
+        metrics: metrics.clone(),
+    });
+
+    PromptTaskResult {
+        metrics: Some(metrics),
+        model: None,
+    }
 }
 
 /// Check if web research tools are available (BRAVE_API_KEY is set).
@@ -1336,31 +2395,190 @@ fn extract_tool_results_from_history(chat_history: &[Message]) -> Vec<String> {
     results
 }
 
-/// Run a prompt task using an agent with tools, printing progress as it completes.
+/// Write a response synthesized from the tool results an agent gathered before
+/// it was stopped, by its turn limit or its [`ToolBudget`].
 ///
-/// This function is used for Phase 1 prompts that benefit from web search
-/// and scraping capabilities. If tools are not available (no BRAVE_API_KEY),
-/// it falls back to a standard completion request without tools.
-#[instrument(
-    name = "prompt_task",
-    skip(output_dir, agent, prompt, counter, cancelled),
-    fields(
-        task = name,
-        filename = filename,
-        prompt_len = prompt.len()
-    )
-)]
+/// The agent's model is called once more without tools, with the results and
+/// the original prompt. `reason` describes why the agent stopped, for the
+/// failure reported when it gathered nothing.
 #[allow(clippy::too_many_arguments)]
-async fn run_agent_prompt_task<M>(
+async fn synthesize_tool_results<M>(
     name: &'static str,
     filename: &'static str,
-    output_dir: PathBuf,
-    agent: Agent<M>,
-    prompt: String,
+    output_dir: &Path,
+    agent: &Agent<M>,
+    prompt: &str,
+    chat_history: &[Message],
+    hook: &TracingPromptHook,
+    reason: &str,
+    completed: usize,
+    total: usize,
+    start_time: Instant,
+    elapsed: f32,
+    observer: &dyn ResearchObserver,
+) -> Option<PromptMetrics>
+where
+    M: CompletionModel,
+{
+    // Extract all tool results from the chat history
+    let tool_results = extract_tool_results_from_history(chat_history);
+
+    if tool_results.is_empty() {
+        // No tool results gathered, can't recover
+        warn!(
+            task = name,
+            elapsed_secs = elapsed,
+            "Recovery failed: no tool results found in chat history"
+        );
+        report_task(
+            observer,
+            name,
+            completed,
+            total,
+            elapsed,
+            TaskOutcome::Failed {
+                error: format!("{} with no results", reason),
+            },
+        );
+        None
+    } else {
+        // Build a synthesis prompt with the gathered tool results
+        let gathered_context = tool_results.join("\n\n---\n\n");
+        let synthesis_prompt = format!(
+            "Based on the following research results gathered from web searches and page scraping, \
+            please synthesize a comprehensive response to the original request.\n\n\
+            If the information is limited or inconclusive, acknowledge that and provide \
+            whatever relevant information was found.\n\n\
+            # Research Results\n\n{}\n\n# Original Request\n\n{}",
+            gathered_context, prompt
+        );
+
+        // Use the underlying model directly (bypassing tools) for final synthesis
+        let mut request_builder = agent
+            .model
+            .completion_request(&synthesis_prompt)
+            .temperature_opt(agent.temperature);
+
+        // Add preamble if the agent had one
+        if let Some(preamble) = &agent.preamble {
+            request_builder = request_builder.preamble(preamble.clone());
+        }
+
+        let synthesis_result = request_builder.send().await;
+
+        match synthesis_result {
+            Ok(response) => {
+                let usage = &response.usage;
+                hook.record_usage(usage.input_tokens, usage.output_tokens, usage.total_tokens);
+
+                // Extract text from the response
+                let content: String = response
+                    .choice
+                    .iter()
+                    .filter_map(|c| {
+                        if let AssistantContent::Text(text) = c {
+                            Some(text.text.clone())
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                // Includes the turns before the agent was stopped
+                let metrics = hook.usage(start_time.elapsed().as_secs_f32());
+
+                let normalized = normalize_markdown(&content);
+
+                let path = output_dir.join(filename);
+                match write_generated(&path, &normalized, observer).await {
+                    Ok(_) => {
+                        let final_elapsed = start_time.elapsed().as_secs_f32();
+                        info!(
+                            task = name,
+                            elapsed_secs = final_elapsed,
+                            content_len = normalized.len(),
+                            "Task completed via tool result recovery"
+                        );
+                        report_task(
+                            observer,
+                            name,
+                            completed,
+                            total,
+                            final_elapsed,
+                            TaskOutcome::Recovered,
+                        );
+                        Some(metrics)
+                    }
+                    Err(e) => {
+                        warn!(
+                            task = name,
+                            error = %e,
+                            "Failed to write recovered output file"
+                        );
+                        report_task(
+                            observer,
+                            name,
+                            completed,
+                            total,
+                            start_time.elapsed().as_secs_f32(),
+                            TaskOutcome::Failed {
+                                error: format!("write failed: {}", e),
+                            },
+                        );
+                        None
+                    }
+                }
+            }
+            Err(e) => {
+                warn!(
+                    task = name,
+                    error = %e,
+                    "Recovery synthesis failed"
+                );
+                report_task(
+                    observer,
+                    name,
+                    completed,
+                    total,
+                    start_time.elapsed().as_secs_f32(),
+                    TaskOutcome::Failed {
+                        error: format!("recovery failed: {}", e),
+                    },
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Run a prompt task using an agent with tools, printing progress as it completes.
+///
+/// This function is used for Phase 1 prompts that benefit from web search
+/// and scraping capabilities. If tools are not available (no BRAVE_API_KEY),
+/// it falls back to a standard completion request without tools.
+#[instrument(
+    name = "prompt_task",
+    skip(output_dir, agent, prompt, counter, cancelled),
+    fields(
+        task = name,
+        filename = filename,
+        prompt_len = prompt.len()
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+async fn run_agent_prompt_task<M>(
+    name: &'static str,
+    filename: &'static str,
+    output_dir: PathBuf,
+    agent: Agent<M>,
+    prompt: String,
     counter: Arc<AtomicUsize>,
     total: usize,
     start_time: Instant,
     cancelled: Arc<AtomicBool>,
+    observer: Arc<dyn ResearchObserver>,
+    budget: ToolBudget,
 ) -> PromptTaskResult
 where
     M: CompletionModel,
@@ -1368,24 +2586,30 @@ where
     // Check if already cancelled before starting
     if cancelled.load(Ordering::SeqCst) {
         debug!(task = name, "Task cancelled before starting");
-        return PromptTaskResult { metrics: None };
+        return PromptTaskResult::default();
     }
 
     info!(task = name, "Starting prompt task with tools");
-    println!("  [{}] Starting (with tools)...", name);
-
-    // Create a tracing hook for this task to emit tool call events
-    let hook = TracingPromptHook::new(name);
-
-    // Use multi_turn(15) to allow up to 15 rounds of tool calls before final response
-    // Higher limit needed as research tasks may require multiple search + scrape operations
-    // If this still hits the limit, the preamble should guide the agent to synthesize earlier
-    let result = agent.prompt(&prompt).multi_turn(15).with_hook(hook).await;
+    observer.on_task_start(name, &TaskStage::AgentPrompt);
+
+    // Create a tracing hook for this task to emit tool call events and
+    // enforce its search and scrape budgets
+    let hook = TracingPromptHook::new(name)
+        .with_observer(observer.clone())
+        .with_budget(budget);
+
+    // The turn limit bounds rounds of tool calls before the final response;
+    // if it is still hit, the preamble should guide the agent to synthesize earlier
+    let result = agent
+        .prompt(&prompt)
+        .multi_turn(budget.max_turns)
+        .with_hook(hook.clone())
+        .await;
 
     // Check if cancelled after the request completed
     if cancelled.load(Ordering::SeqCst) {
-        println!("  [{}] Cancelled (response discarded)", name);
-        return PromptTaskResult { metrics: None };
+        report_cancelled(observer.as_ref(), name, &counter, total, start_time, false);
+        return PromptTaskResult::default();
     }
 
     let elapsed = start_time.elapsed().as_secs_f32();
@@ -1399,18 +2623,13 @@ where
                 "Agent returned content"
             );
 
-            // Agent returns the content directly as a string
-            let metrics = PromptMetrics {
-                input_tokens: 0, // Agent API doesn't expose token counts
-                output_tokens: 0,
-                total_tokens: 0,
-                elapsed_secs: elapsed,
-            };
+            // Agent returns the content directly as a string; usage comes from the hook
+            let metrics = hook.usage(elapsed);
 
             let normalized = normalize_markdown(&content);
 
             let path = output_dir.join(filename);
-            match fs::write(&path, &normalized).await {
+            match write_generated(&path, &normalized, observer.as_ref()).await {
                 Ok(_) => {
                     info!(
                         task = name,
@@ -1418,7 +2637,16 @@ where
                         content_len = normalized.len(),
                         "Task completed successfully"
                     );
-                    println!("  [{}/{}] ✓ {} ({:.1}s)", completed, total, name, elapsed,);
+                    report_task(
+                        observer.as_ref(),
+                        name,
+                        completed,
+                        total,
+                        elapsed,
+                        TaskOutcome::Succeeded {
+                            metrics: Some(metrics.clone()),
+                        },
+                    );
                     Some(metrics)
                 }
                 Err(e) => {
@@ -1427,9 +2655,15 @@ where
                         error = %e,
                         "Failed to write output file"
                     );
-                    eprintln!(
-                        "  [{}/{}] ✗ {} write failed: {} ({:.1}s)",
-                        completed, total, name, e, elapsed
+                    report_task(
+                        observer.as_ref(),
+                        name,
+                        completed,
+                        total,
+                        elapsed,
+                        TaskOutcome::Failed {
+                            error: format!("write failed: {}", e),
+                        },
                     );
                     None
                 }
@@ -1448,125 +2682,58 @@ where
                 max_depth = max_depth,
                 "MaxDepthError: attempting recovery by synthesizing gathered tool results"
             );
-            println!(
-                "  [{}] Max tool calls reached, synthesizing gathered results...",
+            observer.on_warning(&format!(
+                "[{}] Max tool calls reached, synthesizing gathered results...",
                 name
-            );
-
-            // Extract all tool results from the chat history
-            let tool_results = extract_tool_results_from_history(&chat_history);
-
-            if tool_results.is_empty() {
-                // No tool results gathered, can't recover
-                warn!(
-                    task = name,
-                    elapsed_secs = elapsed,
-                    "MaxDepthError recovery failed: no tool results found in chat history"
-                );
-                eprintln!(
-                    "  [{}/{}] ✗ {} failed: max tool calls with no results ({:.1}s)",
-                    completed, total, name, elapsed
-                );
-                None
-            } else {
-                // Build a synthesis prompt with the gathered tool results
-                let gathered_context = tool_results.join("\n\n---\n\n");
-                let synthesis_prompt = format!(
-                    "Based on the following research results gathered from web searches and page scraping, \
-                    please synthesize a comprehensive response to the original request.\n\n\
-                    If the information is limited or inconclusive, acknowledge that and provide \
-                    whatever relevant information was found.\n\n\
-                    # Research Results\n\n{}\n\n# Original Request\n\n{}",
-                    gathered_context, prompt
-                );
-
-                // Use the underlying model directly (bypassing tools) for final synthesis
-                let mut request_builder = agent.model.completion_request(&synthesis_prompt);
-
-                // Add preamble if the agent had one
-                if let Some(preamble) = &agent.preamble {
-                    request_builder = request_builder.preamble(preamble.clone());
-                }
+            ));
 
-                let synthesis_result = request_builder.send().await;
-
-                match synthesis_result {
-                    Ok(response) => {
-                        // Extract text from the response
-                        let content: String = response
-                            .choice
-                            .iter()
-                            .filter_map(|c| {
-                                if let AssistantContent::Text(text) = c {
-                                    Some(text.text.clone())
-                                } else {
-                                    None
-                                }
-                            })
-                            .collect::<Vec<_>>()
-                            .join("\n");
-
-                        let metrics = PromptMetrics {
-                            input_tokens: 0,
-                            output_tokens: 0,
-                            total_tokens: 0,
-                            elapsed_secs: start_time.elapsed().as_secs_f32(),
-                        };
-
-                        let normalized = normalize_markdown(&content);
-
-                        let path = output_dir.join(filename);
-                        match fs::write(&path, &normalized).await {
-                            Ok(_) => {
-                                let final_elapsed = start_time.elapsed().as_secs_f32();
-                                info!(
-                                    task = name,
-                                    elapsed_secs = final_elapsed,
-                                    content_len = normalized.len(),
-                                    "Task completed via MaxDepthError recovery"
-                                );
-                                println!(
-                                    "  [{}/{}] ✓ {} (recovered, {:.1}s)",
-                                    completed, total, name, final_elapsed
-                                );
-                                Some(metrics)
-                            }
-                            Err(e) => {
-                                warn!(
-                                    task = name,
-                                    error = %e,
-                                    "Failed to write recovered output file"
-                                );
-                                eprintln!(
-                                    "  [{}/{}] ✗ {} write failed: {} ({:.1}s)",
-                                    completed,
-                                    total,
-                                    name,
-                                    e,
-                                    start_time.elapsed().as_secs_f32()
-                                );
-                                None
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        warn!(
-                            task = name,
-                            error = %e,
-                            "MaxDepthError recovery synthesis failed"
-                        );
-                        eprintln!(
-                            "  [{}/{}] ✗ {} recovery failed: {} ({:.1}s)",
-                            completed,
-                            total,
-                            name,
-                            e,
-                            start_time.elapsed().as_secs_f32()
-                        );
-                        None
-                    }
-                }
-            }
+            synthesize_tool_results(
+                name,
+                filename,
+                &output_dir,
+                &agent,
+                &prompt,
+                &chat_history,
+                &hook,
+                "max tool calls",
+                completed,
+                total,
+                start_time,
+                elapsed,
+                observer.as_ref(),
+            )
+            .await
+        }
+        Err(PromptError::PromptCancelled { chat_history, .. })
+            if hook.exhausted_tool().is_some() =>
+        {
+            // The hook stopped the agent at its first tool call over budget
+            let tool = hook.exhausted_tool().unwrap_or_default();
+            info!(
+                task = name,
+                tool = %tool,
+                "Tool budget exhausted: attempting recovery by synthesizing gathered tool results"
+            );
+            observer.on_warning(&format!(
+                "[{}] {} budget exhausted, synthesizing gathered results...",
+                name, tool
+            ));
+            synthesize_tool_results(
+                name,
+                filename,
+                &output_dir,
+                &agent,
+                &prompt,
+                &chat_history,
+                &hook,
+                &format!("{} budget exhausted", tool),
+                completed,
+                total,
+                start_time,
+                elapsed,
+                observer.as_ref(),
+            )
+            .await
         }
         Err(e) => {
             warn!(
@@ -1575,69 +2742,194 @@ where
                 elapsed_secs = elapsed,
                 "Task failed"
             );
-            eprintln!(
-                "  [{}/{}] ✗ {} failed: {} ({:.1}s)",
-                completed, total, name, e, elapsed
+            report_task(
+                observer.as_ref(),
+                name,
+                completed,
+                total,
+                elapsed,
+                TaskOutcome::Failed {
+                    error: e.to_string(),
+                },
             );
             None
         }
     };
 
-    PromptTaskResult { metrics }
+    PromptTaskResult {
+        metrics,
+        model: None,
+    }
 }
 
 /// Returns the default output directory for a given topic.
 ///
-/// Uses the `RESEARCH_DIR` environment variable if set, otherwise falls back to `$HOME`.
-/// The full path is: `${RESEARCH_DIR:-$HOME}/.research/library/{topic}`
+/// The full path is `{root}/library/{topic}`, where `{root}` comes from
+/// [`ResearchPaths::from_env`].
 pub fn default_output_dir(topic: &str) -> PathBuf {
-    let base = std::env::var("RESEARCH_DIR")
-        .map(PathBuf::from)
-        .unwrap_or_else(|_| dirs::home_dir().unwrap_or_else(|| PathBuf::from(".")));
-    base.join(".research").join("library").join(topic)
+    default_output_dir_for(ResearchKind::Library, topic)
+}
+
+/// Returns the default output directory for a topic of the given kind.
+///
+/// The full path is `{root}/{kind}/{topic}`, where `{root}` comes from
+/// [`ResearchPaths::from_env`] and `{kind}` is [`ResearchKind::dir_name`].
+pub fn default_output_dir_for(kind: ResearchKind, topic: &str) -> PathBuf {
+    ResearchPaths::from_env().topic_dir(kind, topic)
+}
+
+/// Returns the on-disk cache shared by the web search, scrape, GitHub and
+/// docs.rs tools.
+///
+/// Entries live in the library's [`cache_dir`](ResearchPaths::cache_dir) and stay fresh for
+/// `RESEARCH_CACHE_TTL_HOURS` hours (default 24). Returns `None` when the TTL
+/// is `0`, which disables caching.
+pub fn tool_cache() -> Option<ToolCache> {
+    let ttl_hours = std::env::var("RESEARCH_CACHE_TTL_HOURS")
+        .ok()
+        .and_then(|hours| hours.trim().parse::<u64>().ok())
+        .unwrap_or(24);
+    if ttl_hours == 0 {
+        return None;
+    }
+
+    Some(
+        ToolCache::new(ResearchPaths::from_env().cache_dir())
+            .with_ttl(Duration::from_secs(ttl_hours * 60 * 60)),
+    )
+}
+
+/// Creates the web search and scrape tools handed to research agents.
+///
+/// Both tools share [`tool_cache`], so re-running research reuses earlier
+/// searches and scrapes instead of fetching them again. With a `scheduler`,
+/// searches draw from its [`search_limiter`](ResearchScheduler::search_limiter)
+/// so concurrent runs share one Brave budget.
+fn web_tools(scheduler: Option<&ResearchScheduler>) -> (BraveSearchTool, ScreenScrapeTool) {
+    let search_tool = match scheduler {
+        Some(scheduler) => {
+            BraveSearchTool::from_env().with_rate_limiter(scheduler.search_limiter().clone())
+        }
+        None => BraveSearchTool::from_env(),
+    };
+    let scrape_tool = ScreenScrapeTool::new();
+    match tool_cache() {
+        Some(cache) => (
+            search_tool.with_cache(cache.clone()),
+            scrape_tool.with_cache(cache),
+        ),
+        None => (search_tool, scrape_tool),
+    }
+}
+
+/// Creates the GitHub repository tool handed to overview and changelog agents.
+///
+/// Authenticates with `GITHUB_TOKEN` when set and shares [`tool_cache`].
+fn github_tool() -> GitHubRepoTool {
+    let tool = GitHubRepoTool::from_env();
+    match tool_cache() {
+        Some(cache) => tool.with_cache(cache),
+        None => tool,
+    }
+}
+
+/// Returns `owner/repo` when the library's repository is hosted on GitHub.
+fn github_repository(library_info: Option<&LibraryInfo>) -> Option<String> {
+    let repository = library_info?.repository.as_deref()?;
+    parse_github_repository(repository).map(|(owner, name)| format!("{owner}/{name}"))
+}
+
+/// Appends a pointer to the `github_repo` tool to an agent preamble.
+fn with_github_hint(preamble: &str, repository: &str) -> String {
+    format!(
+        "{preamble} The project's source is the GitHub repository {repository}; call the github_repo tool once for its README, stars, open issues and release notes, and prefer that first-party data over search results."
+    )
+}
+
+/// Creates the docs.rs tool handed to use cases agents for Rust crates.
+///
+/// Shares [`tool_cache`].
+fn docs_rs_tool() -> DocsRsTool {
+    let tool = DocsRsTool::new();
+    match tool_cache() {
+        Some(cache) => tool.with_cache(cache),
+        None => tool,
+    }
+}
+
+/// Returns the crate name when the library is published on crates.io.
+fn docs_rs_crate(library_info: Option<&LibraryInfo>) -> Option<String> {
+    let library_info = library_info?;
+    if library_info.package_manager != "crates.io" {
+        return None;
+    }
+    library_info
+        .url
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+}
+
+/// Appends a pointer to the `docs_rs` tool to an agent preamble.
+fn with_docs_rs_hint(preamble: &str, crate_name: &str) -> String {
+    format!(
+        "{preamble} The library is the Rust crate {crate_name}; before writing code examples, call the docs_rs tool for the crate root and the items you use, and only use types, functions and signatures that appear in its documentation."
+    )
 }
 
 /// Run a dynamic question task and save result
+///
+/// The answer is written to the question's file for `question_num`.
+/// `repository_context` is appended to the prompt when set.
 #[allow(clippy::too_many_arguments)]
 async fn run_question_task<M>(
     question_num: usize,
+    template: &'static str,
     topic: String,
-    question: String,
+    question: Question,
+    repository_context: Option<String>,
     package_manager: String,
     language: String,
     url: String,
     output_dir: PathBuf,
-    model: M,
+    model: TunedModel<M>,
     counter: Arc<AtomicUsize>,
     total: usize,
     start_time: Instant,
     cancelled: Arc<AtomicBool>,
+    observer: Arc<dyn ResearchObserver>,
 ) -> PromptTaskResult
 where
     M: CompletionModel,
 {
     // Check if already cancelled before starting
     if cancelled.load(Ordering::SeqCst) {
-        return PromptTaskResult { metrics: None };
+        return PromptTaskResult::default();
     }
 
-    let name = format!("question_{}", question_num);
-    println!("  [{}] Starting...", name);
+    let name = &format!("question_{}", question_num);
+    observer.on_task_start(name, &TaskStage::Prompt);
 
     let ctx = LibraryContext {
         package_manager: &package_manager,
         language: &language,
         url: &url,
     };
-    let prompt = build_prompt_with_context(prompts::ADDITIONAL_QUESTION, &topic, Some(&ctx))
-        .replace("{{question}}", &question);
+    let prompt = repository::ground_prompt(
+        build_prompt_with_context(template, &topic, Some(&ctx))
+            .replace("{{question}}", &question.text),
+        name,
+        repository_context.as_deref(),
+    );
 
     let result = model.completion_request(&prompt).send().await;
 
     // Check if cancelled after the request completed
     if cancelled.load(Ordering::SeqCst) {
-        println!("  [{}] Cancelled (response discarded)", name);
-        return PromptTaskResult { metrics: None };
+        report_cancelled(observer.as_ref(), name, &counter, total, start_time, false);
+        return PromptTaskResult::default();
     }
 
     let elapsed = start_time.elapsed().as_secs_f32();
@@ -1665,41 +2957,56 @@ where
 
             let normalized = normalize_markdown(&content);
 
-            let filename = format!("question_{}.md", question_num);
+            let filename = question.filename(question_num);
             let path = output_dir.join(&filename);
-            match fs::write(&path, &normalized).await {
+            match write_generated(&path, &normalized, observer.as_ref()).await {
                 Ok(_) => {
-                    println!(
-                        "  [{}/{}] ✓ {} ({:.1}s) | tokens: {} in, {} out, {} total",
+                    report_task(
+                        observer.as_ref(),
+                        name,
                         completed,
                         total,
-                        name,
                         elapsed,
-                        metrics.input_tokens,
-                        metrics.output_tokens,
-                        metrics.total_tokens,
+                        TaskOutcome::Succeeded {
+                            metrics: Some(metrics.clone()),
+                        },
                     );
                     Some(metrics)
                 }
                 Err(e) => {
-                    eprintln!(
-                        "  [{}/{}] ✗ {} write failed: {} ({:.1}s)",
-                        completed, total, name, e, elapsed
+                    report_task(
+                        observer.as_ref(),
+                        name,
+                        completed,
+                        total,
+                        elapsed,
+                        TaskOutcome::Failed {
+                            error: format!("write failed: {}", e),
+                        },
                     );
                     None
                 }
             }
         }
         Err(e) => {
-            eprintln!(
-                "  [{}/{}] ✗ {} failed: {} ({:.1}s)",
-                completed, total, name, e, elapsed
+            report_task(
+                observer.as_ref(),
+                name,
+                completed,
+                total,
+                elapsed,
+                TaskOutcome::Failed {
+                    error: e.to_string(),
+                },
             );
             None
         }
     };
 
-    PromptTaskResult { metrics }
+    PromptTaskResult {
+        metrics,
+        model: None,
+    }
 }
 
 /// Run changelog task with version history aggregation (agent version with tools).
@@ -1713,6 +3020,7 @@ where
 async fn run_changelog_agent_task<M>(
     name: &'static str,
     filename: &'static str,
+    template: &'static str,
     output_dir: PathBuf,
     agent: Agent<M>,
     topic: String,
@@ -1722,6 +3030,8 @@ async fn run_changelog_agent_task<M>(
     total: usize,
     start_time: Instant,
     cancelled: Arc<AtomicBool>,
+    observer: Arc<dyn ResearchObserver>,
+    budget: ToolBudget,
 ) -> PromptTaskResult
 where
     M: CompletionModel,
@@ -1729,11 +3039,11 @@ where
     // Check if already cancelled before starting
     if cancelled.load(Ordering::SeqCst) {
         debug!(task = name, "Task cancelled before starting");
-        return PromptTaskResult { metrics: None };
+        return PromptTaskResult::default();
     }
 
     info!(task = name, "Starting changelog task with aggregator");
-    println!("  [{}] Aggregating version history...", name);
+    observer.on_task_start(name, &TaskStage::AggregatingHistory);
 
     // 1. Aggregate version history from structured sources
     let version_history = if let Some(ref lib_info) = library_info {
@@ -1773,24 +3083,30 @@ where
 
     // 2. Build prompt with version history injected
     let prompt = build_changelog_prompt(
-        prompts::CHANGELOG,
+        template,
         &topic,
         library_info.as_ref(),
         version_history.as_ref(),
     );
 
-    println!("  [{}] Starting LLM generation...", name);
+    observer.on_task_start(name, &TaskStage::Generating);
 
-    // 3. Create a tracing hook for this task
-    let hook = TracingPromptHook::new(name);
+    // 3. Create a tracing hook for this task, enforcing its tool budget
+    let hook = TracingPromptHook::new(name)
+        .with_observer(observer.clone())
+        .with_budget(budget);
 
     // 4. Call LLM agent with tools
-    let result = agent.prompt(&prompt).multi_turn(15).with_hook(hook).await;
+    let result = agent
+        .prompt(&prompt)
+        .multi_turn(budget.max_turns)
+        .with_hook(hook.clone())
+        .await;
 
     // Check if cancelled after the request completed
     if cancelled.load(Ordering::SeqCst) {
-        println!("  [{}] Cancelled (response discarded)", name);
-        return PromptTaskResult { metrics: None };
+        report_cancelled(observer.as_ref(), name, &counter, total, start_time, false);
+        return PromptTaskResult::default();
     }
 
     let elapsed = start_time.elapsed().as_secs_f32();
@@ -1804,43 +3120,84 @@ where
                 "Agent returned content"
             );
 
-            let metrics = PromptMetrics {
-                input_tokens: 0,
-                output_tokens: 0,
-                total_tokens: 0,
-                elapsed_secs: elapsed,
-            };
+            let metrics = hook.usage(elapsed);
 
             let normalized = normalize_markdown(&content);
 
             let path = output_dir.join(filename);
-            match fs::write(&path, &normalized).await {
+            match write_generated(&path, &normalized, observer.as_ref()).await {
                 Ok(_) => {
-                    println!(
-                        "  [{}/{}] ✓ {} ({:.1}s) | with version aggregation",
-                        completed, total, name, elapsed
+                    report_task(
+                        observer.as_ref(),
+                        name,
+                        completed,
+                        total,
+                        elapsed,
+                        TaskOutcome::Succeeded {
+                            metrics: Some(metrics.clone()),
+                        },
                     );
                     Some(metrics)
                 }
                 Err(e) => {
-                    eprintln!(
-                        "  [{}/{}] ✗ {} write failed: {} ({:.1}s)",
-                        completed, total, name, e, elapsed
+                    report_task(
+                        observer.as_ref(),
+                        name,
+                        completed,
+                        total,
+                        elapsed,
+                        TaskOutcome::Failed {
+                            error: format!("write failed: {}", e),
+                        },
                     );
                     None
                 }
             }
         }
+        Err(PromptError::PromptCancelled { chat_history, .. })
+            if hook.exhausted_tool().is_some() =>
+        {
+            let tool = hook.exhausted_tool().unwrap_or_default();
+            observer.on_warning(&format!(
+                "[{}] {} budget exhausted, synthesizing gathered results...",
+                name, tool
+            ));
+            synthesize_tool_results(
+                name,
+                filename,
+                &output_dir,
+                &agent,
+                &prompt,
+                &chat_history,
+                &hook,
+                &format!("{} budget exhausted", tool),
+                completed,
+                total,
+                start_time,
+                elapsed,
+                observer.as_ref(),
+            )
+            .await
+        }
         Err(e) => {
-            eprintln!(
-                "  [{}/{}] ✗ {} failed: {} ({:.1}s)",
-                completed, total, name, e, elapsed
+            report_task(
+                observer.as_ref(),
+                name,
+                completed,
+                total,
+                elapsed,
+                TaskOutcome::Failed {
+                    error: e.to_string(),
+                },
             );
             None
         }
     };
 
-    PromptTaskResult { metrics }
+    PromptTaskResult {
+        metrics,
+        model: None,
+    }
 }
 
 /// Run changelog task with version history aggregation (non-agent version without tools).
@@ -1854,8 +3211,9 @@ where
 async fn run_changelog_completion_task<M>(
     name: &'static str,
     filename: &'static str,
+    template: &'static str,
     output_dir: PathBuf,
-    model: M,
+    model: TunedModel<M>,
     topic: String,
     library_info: Option<LibraryInfo>,
     client: HttpClient,
@@ -1863,16 +3221,17 @@ async fn run_changelog_completion_task<M>(
     total: usize,
     start_time: Instant,
     cancelled: Arc<AtomicBool>,
+    observer: Arc<dyn ResearchObserver>,
 ) -> PromptTaskResult
 where
     M: CompletionModel,
 {
     // Check if already cancelled before starting
     if cancelled.load(Ordering::SeqCst) {
-        return PromptTaskResult { metrics: None };
+        return PromptTaskResult::default();
     }
 
-    println!("  [{}] Aggregating version history...", name);
+    observer.on_task_start(name, &TaskStage::AggregatingHistory);
 
     // 1. Aggregate version history from structured sources
     let version_history = if let Some(ref lib_info) = library_info {
@@ -1911,21 +3270,21 @@ where
 
     // 2. Build prompt with version history injected
     let prompt = build_changelog_prompt(
-        prompts::CHANGELOG,
+        template,
         &topic,
         library_info.as_ref(),
         version_history.as_ref(),
     );
 
-    println!("  [{}] Starting LLM generation...", name);
+    observer.on_task_start(name, &TaskStage::Generating);
 
     // 3. Call LLM completion model
     let result = model.completion_request(&prompt).send().await;
 
     // Check if cancelled after the request completed
     if cancelled.load(Ordering::SeqCst) {
-        println!("  [{}] Cancelled (response discarded)", name);
-        return PromptTaskResult { metrics: None };
+        report_cancelled(observer.as_ref(), name, &counter, total, start_time, false);
+        return PromptTaskResult::default();
     }
 
     let elapsed = start_time.elapsed().as_secs_f32();
@@ -1952,39 +3311,54 @@ where
             let normalized = normalize_markdown(&content);
 
             let path = output_dir.join(filename);
-            match fs::write(&path, &normalized).await {
+            match write_generated(&path, &normalized, observer.as_ref()).await {
                 Ok(_) => {
-                    println!(
-                        "  [{}/{}] ✓ {} ({:.1}s) | tokens: {} in, {} out, {} total | with version aggregation",
+                    report_task(
+                        observer.as_ref(),
+                        name,
                         completed,
                         total,
-                        name,
                         elapsed,
-                        metrics.input_tokens,
-                        metrics.output_tokens,
-                        metrics.total_tokens,
+                        TaskOutcome::Succeeded {
+                            metrics: Some(metrics.clone()),
+                        },
                     );
                     Some(metrics)
                 }
                 Err(e) => {
-                    eprintln!(
-                        "  [{}/{}] ✗ {} write failed: {} ({:.1}s)",
-                        completed, total, name, e, elapsed
+                    report_task(
+                        observer.as_ref(),
+                        name,
+                        completed,
+                        total,
+                        elapsed,
+                        TaskOutcome::Failed {
+                            error: format!("write failed: {}", e),
+                        },
                     );
                     None
                 }
             }
         }
         Err(e) => {
-            eprintln!(
-                "  [{}/{}] ✗ {} failed: {} ({:.1}s)",
-                completed, total, name, e, elapsed
+            report_task(
+                observer.as_ref(),
+                name,
+                completed,
+                total,
+                elapsed,
+                TaskOutcome::Failed {
+                    error: e.to_string(),
+                },
             );
             None
         }
     };
 
-    PromptTaskResult { metrics }
+    PromptTaskResult {
+        metrics,
+        model: None,
+    }
 }
 
 /// Generate skill files (SKILL.md and supporting docs) from research
@@ -1997,8 +3371,9 @@ where
 /// * `topic` - The library/package name
 /// * `output_dir` - Base output directory (skill/ will be created inside this)
 /// * `combined_context` - Combined research context from all Phase 1 documents
-/// * `openai` - OpenAI client for LLM calls
+/// * `clients` - Provider clients and the model configured for the skill task
 /// * `cancelled` - Cancellation flag
+/// * `observer` - Receives task progress and warnings
 /// * `metadata` - Mutable reference to metadata (will update when_to_use field)
 ///
 /// ## Returns
@@ -2013,12 +3388,14 @@ async fn generate_skill_files(
     topic: &str,
     output_dir: &std::path::Path,
     combined_context: &str,
-    openai: &openai::Client,
+    clients: &ModelClients,
     cancelled: Arc<AtomicBool>,
+    observer: Arc<dyn ResearchObserver>,
     metadata: &mut ResearchMetadata,
 ) -> Result<Option<PromptMetrics>, ResearchError> {
     // Build skill prompt
     let skill_prompt = prompts::SKILL
+        .get()
         .replace("{{topic}}", topic)
         .replace("{{context}}", combined_context);
 
@@ -2026,25 +3403,43 @@ async fn generate_skill_files(
     let skill_dir = output_dir.join("skill");
     fs::create_dir_all(&skill_dir).await?;
 
-    // Get model for skill generation
-    let skill_gen = openai.completion_model("gpt-5.2");
-
     let phase2_counter = Arc::new(AtomicUsize::new(0));
     let phase2_start = Instant::now();
 
     // Run skill generation task
-    let skill_result = run_prompt_task(
+    let skill_result = failover_task(
         "skill",
-        "SKILL.md",
-        skill_dir.clone(),
-        skill_gen,
-        skill_prompt,
-        phase2_counter,
-        1,
-        phase2_start,
-        cancelled,
+        clients.failover_specs(ResearchTask::Skill),
+        phase2_counter.clone(),
+        observer.clone(),
+        |spec, observer| {
+            let future: TaskFuture<'_> =
+                with_model!(clients, spec = spec, |client, model, temp| {
+                    Box::pin(clients.schedule_on(
+                        spec.provider,
+                        run_prompt_task(
+                            "skill",
+                            "SKILL.md",
+                            skill_dir.clone(),
+                            TunedModel::new(client.completion_model(model), temp),
+                            skill_prompt.clone(),
+                            phase2_counter.clone(),
+                            1,
+                            phase2_start,
+                            cancelled.clone(),
+                            observer,
+                        ),
+                    ))
+                });
+            future
+        },
     )
     .await;
+    if skill_result.metrics.is_some()
+        && let Some(model) = &skill_result.model
+    {
+        metadata.record_generated_by("skill/SKILL.md", model);
+    }
 
     // Parse skill output and split into multiple files if needed
     if skill_result.metrics.is_some()
@@ -2071,16 +3466,25 @@ async fn generate_skill_files(
             };
 
             let file_path = skill_dir.join(&filename);
-            if let Err(e) = fs::write(&file_path, final_content).await {
+            if let Err(e) = write_generated(&file_path, &final_content, observer.as_ref()).await {
                 tracing::error!("Failed to write {}: {}", filename, e);
             }
         }
 
-        // Validate SKILL.md frontmatter and extract when_to_use
+        // Validate SKILL.md frontmatter (repairing it with the model if
+        // needed) and extract when_to_use
         let skill_md_path = skill_dir.join("SKILL.md");
         if let Ok(skill_content) = fs::read_to_string(&skill_md_path).await {
-            match parse_and_validate_frontmatter(&skill_content) {
-                Ok((frontmatter, _body)) => {
+            match validate_skill_with_repair(
+                topic,
+                &skill_md_path,
+                &skill_content,
+                clients,
+                observer.as_ref(),
+            )
+            .await
+            {
+                Ok(frontmatter) => {
                     tracing::info!("✓ SKILL.md frontmatter is valid");
 
                     // Update metadata with when_to_use
@@ -2094,10 +3498,11 @@ async fn generate_skill_files(
                     tracing::error!("  File: {}", skill_md_path.display());
                     tracing::error!("  Please manually fix the frontmatter in SKILL.md");
 
-                    eprintln!("\n⚠️  Warning: SKILL.md frontmatter is invalid");
-                    eprintln!("   {}", e);
-                    eprintln!("   File: {}", skill_md_path.display());
-                    eprintln!("   The skill may not activate correctly until this is fixed.\n");
+                    observer.on_warning(&format!(
+                        "SKILL.md frontmatter is invalid: {}\n   File: {}\n   The skill may not activate correctly until this is fixed.",
+                        e,
+                        skill_md_path.display()
+                    ));
                 }
             }
         }
@@ -2113,13 +3518,21 @@ async fn generate_skill_files(
 /// - New questions are provided
 ///
 /// It runs the missing prompt tasks and question tasks in parallel, then re-synthesizes Phase 2.
+/// Library prompts use `ecosystem`'s variants when set, otherwise the ones
+/// for the language recorded in the metadata.
+#[allow(clippy::too_many_arguments)]
 async fn run_incremental_research(
     topic: &str,
     output_dir: PathBuf,
     mut existing_metadata: ResearchMetadata,
-    questions: Vec<(usize, String)>,
+    questions: Vec<(usize, Question)>,
     missing_prompts: Vec<MissingPrompt>,
     missing_outputs: Vec<MissingOutput>,
+    ecosystem: Option<Ecosystem>,
+    streaming: Option<ProgressHandler>,
+    models: ModelConfig,
+    scheduler: Option<Arc<ResearchScheduler>>,
+    observer: Arc<dyn ResearchObserver>,
 ) -> Result<ResearchResult, ResearchError> {
     // Load environment variables from .env file
     dotenvy::dotenv().ok();
@@ -2161,7 +3574,10 @@ async fn run_incremental_research(
     if has_questions {
         parts.push(format!("Adding {} new question(s)", questions.len()));
     }
-    println!("\nIncremental research: {}...\n", parts.join(" and "));
+    observer.on_info(&format!(
+        "\nIncremental research: {}...\n",
+        parts.join(" and ")
+    ));
 
     // Set up cancellation flag for SIGINT handling
     let cancelled = Arc::new(AtomicBool::new(false));
@@ -2175,9 +3591,7 @@ async fn run_incremental_research(
     });
 
     // Initialize providers
-    let gemini = gemini::Client::from_env();
-    let openai = openai::Client::from_env();
-    let zai = zai::Client::from_env().ok();
+    let clients = ModelClients::from_env(models)?.with_scheduler(scheduler);
 
     // Check if research tools are available
     let use_tools = tools_available();
@@ -2185,12 +3599,12 @@ async fn run_incremental_research(
         let plan = std::env::var("BRAVE_PLAN")
             .map(|s| BravePlan::from_string(&s))
             .unwrap_or_default();
-        println!(
+        observer.on_info(&format!(
             "  ✓ Web research tools enabled (BRAVE_API_KEY found, {:?} plan)\n",
             plan
-        );
+        ));
     } else {
-        println!("  ⚠ Web research tools disabled (set BRAVE_API_KEY to enable)\n");
+        observer.on_warning("Web research tools disabled (set BRAVE_API_KEY to enable)");
     }
 
     // Extract library context from metadata (clone to owned strings for futures)
@@ -2214,22 +3628,10 @@ async fn run_incremental_research(
     };
 
     // Build library info for prompt building
-    let library_info = existing_metadata
-        .library_details()
-        .map(|details| LibraryInfo {
-            package_manager: details
-                .package_manager
-                .clone()
-                .unwrap_or_else(|| "unknown".to_string()),
-            language: details
-                .language
-                .clone()
-                .unwrap_or_else(|| "unknown".to_string()),
-            url: details.url.clone().unwrap_or_else(|| "N/A".to_string()),
-            repository: details.repository.clone(),
-            description: None,
-        });
+    let library_info = existing_metadata.library_info();
     let lib_info_ref = library_info.as_ref();
+    let ecosystem = Ecosystem::resolve(ecosystem, lib_info_ref.map(|li| li.language.as_str()));
+    let prompt_set = PromptSet::for_topic(existing_metadata.kind, ecosystem);
 
     // Clone topic for use in futures
     let topic_owned = topic.to_string();
@@ -2239,222 +3641,240 @@ async fn run_incremental_research(
     let total = missing_prompts.len() + questions.len();
 
     // Create tasks for missing standard prompts - with or without tools
-    type BoxedFuture =
-        std::pin::Pin<Box<dyn std::future::Future<Output = PromptTaskResult> + Send>>;
-    let mut phase1_futures: Vec<BoxedFuture> = Vec::new();
+    let mut phase1_futures: Vec<TaskFuture<'_>> = Vec::new();
+    let mut phase1_files: Vec<String> = Vec::new();
 
     if use_tools {
         // Create agents with web research tools
-        let search_tool = BraveSearchTool::from_env();
-        let scrape_tool = ScreenScrapeTool::new();
+        let (search_tool, scrape_tool) = web_tools(clients.scheduler.as_deref());
+        let github_repo = github_repository(lib_info_ref);
+        let github_tool = github_tool();
+        let docs_crate = docs_rs_crate(lib_info_ref);
+        let docs_rs_tool = docs_rs_tool();
 
         for mp in &missing_prompts {
-            let prompt = build_prompt(mp.template, topic, lib_info_ref);
+            let template = prompt_set.template(mp.name).unwrap_or(mp.template);
+            let prompt = build_prompt(template, topic, lib_info_ref);
             let task_name = mp.name;
             let filename = mp.filename;
+            // Standard prompts all have a task; anything else runs like a question
+            let task = ResearchTask::from_name(task_name).unwrap_or(ResearchTask::Questions);
+            let preamble = if task == ResearchTask::Changelog {
+                "You are a research assistant with web search and scraping tools. Search for recent releases, changelogs, and version history. Use 1-3 targeted searches, then synthesize your findings. Do not make excessive tool calls - write your final answer after gathering sufficient information."
+            } else {
+                "You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to gather key information, then synthesize your findings into a comprehensive response. Do not make excessive tool calls - gather what you need efficiently and write your final answer."
+            };
 
-            match mp.name {
-                "overview" => {
-                    // Use GLM-4.7 if available, otherwise fall back to Gemini
-                    if let Some(ref z) = zai {
-                        let agent = z
-                            .agent(zai::GLM_4_7)
-                            .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to gather key information, then synthesize your findings into a comprehensive response. Do not make excessive tool calls - gather what you need efficiently and write your final answer.")
-                            .tool(search_tool.clone())
-                            .tool(scrape_tool.clone())
-                            .build();
-                        phase1_futures.push(Box::pin(run_agent_prompt_task(
-                            task_name,
-                            filename,
-                            output_dir.clone(),
-                            agent,
-                            prompt,
-                            counter.clone(),
-                            total,
-                            start_time,
-                            cancelled.clone(),
-                        )));
-                    } else {
-                        let agent = gemini
-                            .agent("gemini-3-flash-preview")
-                            .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to gather key information, then synthesize your findings into a comprehensive response. Do not make excessive tool calls - gather what you need efficiently and write your final answer.")
-                            .tool(search_tool.clone())
-                            .tool(scrape_tool.clone())
-                            .build();
-                        phase1_futures.push(Box::pin(run_agent_prompt_task(
-                            task_name,
-                            filename,
-                            output_dir.clone(),
-                            agent,
-                            prompt,
-                            counter.clone(),
-                            total,
-                            start_time,
-                            cancelled.clone(),
-                        )));
-                    }
-                }
-                "changelog" => {
-                    let agent = openai
-                        .agent("gpt-5.2")
-                        .preamble("You are a research assistant with web search and scraping tools. Search for recent releases, changelogs, and version history. Use 1-3 targeted searches, then synthesize your findings. Do not make excessive tool calls - write your final answer after gathering sufficient information.")
-                        .tool(search_tool.clone())
-                        .tool(scrape_tool.clone())
-                        .build();
-                    phase1_futures.push(Box::pin(run_agent_prompt_task(
-                        task_name,
-                        filename,
-                        output_dir.clone(),
-                        agent,
-                        prompt,
-                        counter.clone(),
-                        total,
-                        start_time,
-                        cancelled.clone(),
-                    )));
-                }
-                _ => {
-                    let agent = gemini
-                        .agent("gemini-3-flash-preview")
-                        .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to gather key information, then synthesize your findings into a comprehensive response. Do not make excessive tool calls - gather what you need efficiently and write your final answer.")
-                        .tool(search_tool.clone())
-                        .tool(scrape_tool.clone())
-                        .build();
-                    phase1_futures.push(Box::pin(run_agent_prompt_task(
-                        task_name,
-                        filename,
-                        output_dir.clone(),
-                        agent,
-                        prompt,
-                        counter.clone(),
-                        total,
-                        start_time,
-                        cancelled.clone(),
-                    )));
-                }
-            }
-        }
-
-        // Create question tasks with tools
-        for (num, question) in questions.iter() {
-            let question_agent = gemini
-                .agent("gemini-3-flash-preview")
-                .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to find relevant information, then provide a comprehensive answer. Do not make excessive tool calls - synthesize your findings efficiently.")
-                .tool(search_tool.clone())
-                .tool(scrape_tool.clone())
-                .build();
-
-            let prompt = prompts::ADDITIONAL_QUESTION
-                .replace("{{topic}}", &topic_owned)
-                .replace("{{package_manager}}", &package_manager)
-                .replace("{{language}}", &language)
-                .replace("{{url}}", &url)
-                .replace("{{question}}", question);
-
-            let filename: &'static str = Box::leak(format!("question_{}.md", num).into_boxed_str());
-            let name: &'static str = Box::leak(format!("question_{}", num).into_boxed_str());
-
-            phase1_futures.push(Box::pin(run_agent_prompt_task(
-                name,
-                filename,
-                output_dir.clone(),
-                question_agent,
-                prompt,
+            // Overview and changelog get first-party repository data when available,
+            // use cases get the crate's documented API
+            let repo = github_repo
+                .clone()
+                .filter(|_| matches!(task, ResearchTask::Overview | ResearchTask::Changelog));
+            let docs = docs_crate
+                .clone()
+                .filter(|_| task == ResearchTask::UseCases);
+            let (search_tool, scrape_tool) = (search_tool.clone(), scrape_tool.clone());
+            let (github_tool, docs_rs_tool) = (github_tool.clone(), docs_rs_tool.clone());
+            let (clients, output_dir) = (&clients, &output_dir);
+            let (counter, cancelled) = (&counter, &cancelled);
+
+            phase1_files.push(filename.to_string());
+            phase1_futures.push(Box::pin(failover_task(
+                task_name,
+                clients.failover_specs(task),
                 counter.clone(),
-                total,
-                start_time,
-                cancelled.clone(),
+                observer.clone(),
+                move |spec, observer| {
+                    let future: TaskFuture<'_> =
+                        with_model!(clients, spec = spec, |client, model, temp| {
+                            let mut agent = match (repo.as_deref(), docs.as_deref()) {
+                                (Some(repo), _) => client
+                                    .agent(model)
+                                    .preamble(&with_github_hint(preamble, repo))
+                                    .tool(search_tool.clone())
+                                    .tool(scrape_tool.clone())
+                                    .tool(github_tool.clone())
+                                    .build(),
+                                (None, Some(crate_name)) => client
+                                    .agent(model)
+                                    .preamble(&with_docs_rs_hint(preamble, crate_name))
+                                    .tool(search_tool.clone())
+                                    .tool(scrape_tool.clone())
+                                    .tool(docs_rs_tool.clone())
+                                    .build(),
+                                (None, None) => client
+                                    .agent(model)
+                                    .preamble(preamble)
+                                    .tool(search_tool.clone())
+                                    .tool(scrape_tool.clone())
+                                    .build(),
+                            };
+                            agent.temperature = temp;
+                            Box::pin(clients.schedule_on(
+                                spec.provider,
+                                run_agent_prompt_task(
+                                    task_name,
+                                    filename,
+                                    output_dir.clone(),
+                                    agent,
+                                    prompt.clone(),
+                                    counter.clone(),
+                                    total,
+                                    start_time,
+                                    cancelled.clone(),
+                                    observer,
+                                    clients.config.tool_budget(task),
+                                ),
+                            ))
+                        });
+                    future
+                },
             )));
         }
     } else {
         // Fallback: Use raw completion models without tools
         for mp in &missing_prompts {
-            let prompt = build_prompt(mp.template, topic, lib_info_ref);
+            let template = prompt_set.template(mp.name).unwrap_or(mp.template);
+            let prompt = build_prompt(template, topic, lib_info_ref);
             let task_name = mp.name;
             let filename = mp.filename;
-
-            match mp.name {
-                "overview" => {
-                    // Use GLM-4.7 if available, otherwise fall back to Gemini
-                    if let Some(ref z) = zai {
-                        let model = z.completion_model(zai::GLM_4_7);
-                        phase1_futures.push(Box::pin(run_prompt_task(
-                            task_name,
-                            filename,
-                            output_dir.clone(),
-                            model,
-                            prompt,
-                            counter.clone(),
-                            total,
-                            start_time,
-                            cancelled.clone(),
-                        )));
-                    } else {
-                        let model = gemini.completion_model("gemini-3-flash-preview");
-                        phase1_futures.push(Box::pin(run_prompt_task(
-                            task_name,
-                            filename,
-                            output_dir.clone(),
-                            model,
-                            prompt,
-                            counter.clone(),
-                            total,
-                            start_time,
-                            cancelled.clone(),
-                        )));
-                    }
-                }
-                "changelog" => {
-                    let model = openai.completion_model("gpt-5.2");
-                    phase1_futures.push(Box::pin(run_prompt_task(
-                        task_name,
-                        filename,
-                        output_dir.clone(),
-                        model,
-                        prompt,
-                        counter.clone(),
-                        total,
-                        start_time,
-                        cancelled.clone(),
-                    )));
-                }
-                _ => {
-                    let model = gemini.completion_model("gemini-3-flash-preview");
-                    phase1_futures.push(Box::pin(run_prompt_task(
-                        task_name,
-                        filename,
-                        output_dir.clone(),
-                        model,
-                        prompt,
-                        counter.clone(),
-                        total,
-                        start_time,
-                        cancelled.clone(),
-                    )));
-                }
-            }
-        }
-
-        // Create question tasks without tools
-        for (num, question) in questions.iter() {
-            let question_model = gemini.completion_model("gemini-3-flash-preview");
-            phase1_futures.push(Box::pin(run_question_task(
-                *num,
-                topic_owned.clone(),
-                question.clone(),
-                package_manager.clone(),
-                language.clone(),
-                url.clone(),
-                output_dir.clone(),
-                question_model,
+            let task = ResearchTask::from_name(task_name).unwrap_or(ResearchTask::Questions);
+            let streaming = streaming.clone();
+            let (clients, output_dir) = (&clients, &output_dir);
+            let (counter, cancelled) = (&counter, &cancelled);
+
+            phase1_files.push(filename.to_string());
+            phase1_futures.push(Box::pin(failover_task(
+                task_name,
+                clients.failover_specs(task),
                 counter.clone(),
-                total,
-                start_time,
-                cancelled.clone(),
+                observer.clone(),
+                move |spec, observer| {
+                    let future: TaskFuture<'_> =
+                        with_model!(clients, spec = spec, |client, model, temp| {
+                            Box::pin(clients.schedule_on(
+                                spec.provider,
+                                dispatch_prompt_task(
+                                    streaming.clone(),
+                                    task_name,
+                                    filename,
+                                    output_dir.clone(),
+                                    TunedModel::new(client.completion_model(model), temp),
+                                    prompt.clone(),
+                                    counter.clone(),
+                                    total,
+                                    start_time,
+                                    cancelled.clone(),
+                                    observer,
+                                ),
+                            ))
+                        });
+                    future
+                },
             )));
         }
     }
 
+    // Create question tasks on each question's model, with tools unless the
+    // question opts out. Either way they fail over along the questions chain.
+    let question_tools = use_tools.then(|| web_tools(clients.scheduler.as_deref()));
+    for (num, question) in questions.iter() {
+        let filename: &'static str = Box::leak(question.filename(*num).into_boxed_str());
+        let name: &'static str = Box::leak(format!("question_{}", num).into_boxed_str());
+        let specs = clients.question_failover_specs(question);
+        let (clients, output_dir) = (&clients, &output_dir);
+        let (counter, cancelled) = (&counter, &cancelled);
+        phase1_files.push(filename.to_string());
+
+        match &question_tools {
+            Some((search_tool, scrape_tool)) if question.uses_tools(true) => {
+                let prompt = prompt_set
+                    .additional_question
+                    .get()
+                    .replace("{{topic}}", &topic_owned)
+                    .replace("{{package_manager}}", &package_manager)
+                    .replace("{{language}}", &language)
+                    .replace("{{url}}", &url)
+                    .replace("{{question}}", &question.text);
+                let (search_tool, scrape_tool) = (search_tool.clone(), scrape_tool.clone());
+
+                phase1_futures.push(Box::pin(failover_task(
+                    name,
+                    specs,
+                    counter.clone(),
+                    observer.clone(),
+                    move |spec, observer| {
+                        let future: TaskFuture<'_> =
+                            with_model!(clients, spec = spec, |client, model, temp| {
+                                let mut question_agent = client
+                                    .agent(model)
+                                    .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to find relevant information, then provide a comprehensive answer. Do not make excessive tool calls - synthesize your findings efficiently.")
+                                    .tool(search_tool.clone())
+                                    .tool(scrape_tool.clone())
+                                    .build();
+                                question_agent.temperature = temp;
+                                Box::pin(clients.schedule_on(
+                                    spec.provider,
+                                    run_agent_prompt_task(
+                                        name,
+                                        filename,
+                                        output_dir.clone(),
+                                        question_agent,
+                                        prompt.clone(),
+                                        counter.clone(),
+                                        total,
+                                        start_time,
+                                        cancelled.clone(),
+                                        observer,
+                                        clients.config.tool_budget(ResearchTask::Questions),
+                                    ),
+                                ))
+                            });
+                        future
+                    },
+                )));
+            }
+            _ => {
+                let (topic_owned, package_manager) = (&topic_owned, &package_manager);
+                let (language, url) = (&language, &url);
+                let template = prompt_set.additional_question.get();
+
+                phase1_futures.push(Box::pin(failover_task(
+                    name,
+                    specs,
+                    counter.clone(),
+                    observer.clone(),
+                    move |spec, observer| {
+                        let future: TaskFuture<'_> =
+                            with_model!(clients, spec = spec, |client, model, temp| {
+                                Box::pin(clients.schedule_on(
+                                    spec.provider,
+                                    run_question_task(
+                                        *num,
+                                        template,
+                                        topic_owned.clone(),
+                                        question.clone(),
+                                        None,
+                                        package_manager.clone(),
+                                        language.clone(),
+                                        url.clone(),
+                                        output_dir.clone(),
+                                        TunedModel::new(client.completion_model(model), temp),
+                                        counter.clone(),
+                                        total,
+                                        start_time,
+                                        cancelled.clone(),
+                                        observer,
+                                    ),
+                                ))
+                            });
+                        future
+                    },
+                )));
+            }
+        }
+    }
+
     // Run all Phase 1 tasks in parallel
     let all_results = join_all(phase1_futures).await;
 
@@ -2466,12 +3886,12 @@ async fn run_incremental_research(
 
     let was_cancelled = cancelled.load(Ordering::SeqCst);
 
-    println!(
-        "\nPhase 1 complete: {}/{} succeeded{}\n",
-        succeeded.len(),
-        all_results.len(),
-        if was_cancelled { " (cancelled)" } else { "" }
-    );
+    observer.on_phase_complete(&PhaseSummary {
+        phase: ResearchPhase::Research,
+        succeeded: succeeded.len(),
+        total: all_results.len(),
+        cancelled: was_cancelled,
+    });
 
     // If cancelled, return early with partial results
     if was_cancelled {
@@ -2501,11 +3921,11 @@ async fn run_incremental_research(
     // Check if too many prompts failed (require at least 50% success for incremental)
     let min_required = (all_results.len() / 2).max(1);
     if succeeded.len() < min_required && all_results.len() > 1 {
-        println!(
-            "⚠ Too many prompts failed ({}/{}). Stopping before Phase 2.",
+        observer.on_warning(&format!(
+            "Too many prompts failed ({}/{}). Stopping before Phase 2.",
             failed,
             all_results.len()
-        );
+        ));
         return Err(ResearchError::TooManyPromptsFailed {
             succeeded: succeeded.len(),
             total: all_results.len(),
@@ -2514,12 +3934,12 @@ async fn run_incremental_research(
 
     // Update metadata with new questions
     for (num, question) in &questions {
-        let filename = format!("question_{}.md", num);
-        existing_metadata.add_additional_file(filename, question.clone());
+        existing_metadata.add_additional_file(question.filename(*num), question.text.clone());
     }
+    record_generated_by(&mut existing_metadata, &phase1_files, &all_results);
 
     // === Phase 2: Re-synthesize with expanded corpus ===
-    println!("Phase 2: Re-generating consolidated outputs with new content...\n");
+    observer.on_info("Phase 2: Re-generating consolidated outputs with new content...\n");
 
     // Read back all documents
     let overview_content = fs::read_to_string(output_dir.join("overview.md"))
@@ -2545,19 +3965,17 @@ async fn run_incremental_research(
         if let Ok(content) = fs::read_to_string(output_dir.join(filename)).await
             && !content.is_empty()
         {
-            let num = filename
-                .strip_prefix("question_")
-                .and_then(|s| s.strip_suffix(".md"))
-                .unwrap_or("?");
             additional_content.push_str(&format!(
                 "\n## Additional Research: Question {}\n\n{}\n",
-                num, content
+                answer_label(filename),
+                content
             ));
         }
     }
 
     // Build context from all phase 1 results
     let combined_context = prompts::CONTEXT
+        .get()
         .replace("{{topic}}", topic)
         .replace("{{overview}}", &overview_content)
         .replace("{{similar_libraries}}", &similar_libraries_content)
@@ -2568,15 +3986,41 @@ async fn run_incremental_research(
 
     // Build prompts for phase 2
     let deep_dive_prompt = prompts::DEEP_DIVE
+        .get()
         .replace("{{topic}}", topic)
         .replace("{{context}}", &combined_context);
 
-    // Get model for deep_dive
-    let deep_dive_gen = openai.completion_model("gpt-5.2");
-
     let phase2_counter = Arc::new(AtomicUsize::new(0));
     let phase2_start = Instant::now();
     let deep_dive_filename = format!("deep-dive/{}.md", topic);
+    let deep_dive_task = failover_task(
+        "deep_dive",
+        clients.failover_specs(ResearchTask::DeepDive),
+        phase2_counter.clone(),
+        observer.clone(),
+        |spec, observer| {
+            let future: TaskFuture<'_> =
+                with_model!(clients, spec = spec, |client, model, temp| {
+                    Box::pin(clients.schedule_on(
+                        spec.provider,
+                        dispatch_prompt_task(
+                            streaming.clone(),
+                            "deep_dive",
+                            &deep_dive_filename,
+                            output_dir.clone(),
+                            TunedModel::new(client.completion_model(model), temp),
+                            deep_dive_prompt.clone(),
+                            phase2_counter.clone(),
+                            2,
+                            phase2_start,
+                            cancelled.clone(),
+                            observer,
+                        ),
+                    ))
+                });
+            future
+        },
+    );
 
     // Run phase 2 prompts in parallel
     let (skill_metrics_result, deep_dive_result) = tokio::join!(
@@ -2584,21 +4028,17 @@ async fn run_incremental_research(
             topic,
             &output_dir,
             &combined_context,
-            &openai,
+            &clients,
             cancelled.clone(),
+            observer.clone(),
             &mut existing_metadata,
         ),
-        run_prompt_task(
-            "deep_dive",
-            &deep_dive_filename,
-            output_dir.clone(),
-            deep_dive_gen,
-            deep_dive_prompt,
-            phase2_counter.clone(),
-            2,
-            phase2_start,
-            cancelled.clone(),
-        ),
+        deep_dive_task,
+    );
+    record_generated_by(
+        &mut existing_metadata,
+        std::slice::from_ref(&deep_dive_filename),
+        std::slice::from_ref(&deep_dive_result),
     );
 
     // Save metadata after skill generation (which updated when_to_use)
@@ -2616,57 +4056,45 @@ async fn run_incremental_research(
         && let Ok(content) = fs::read_to_string(&deep_dive_path).await
     {
         let normalized = normalize_markdown(&content);
-        if let Err(e) = fs::write(&deep_dive_path, normalized).await {
+        if let Err(e) = write_generated(&deep_dive_path, &normalized, observer.as_ref()).await {
             tracing::error!("Failed to normalize deep-dive/{}.md: {}", topic, e);
         }
     }
 
     // === Phase 2b: Generate brief from deep_dive (if successful) ===
     let (brief_text, summary_text) = if deep_dive_result.metrics.is_some() {
-        println!("Generating brief summary...\n");
+        observer.on_info("Generating brief summary...\n");
 
         // Read the deep_dive content
         let deep_dive_content = fs::read_to_string(&deep_dive_path)
             .await
             .unwrap_or_default();
 
-        let brief_prompt = prompts::BRIEF
-            .replace("{{topic}}", topic)
-            .replace("{{deep_dive}}", &deep_dive_content);
-
-        let brief_model = gemini.completion_model("gemini-3-flash-preview");
-
-        match brief_model.completion_request(&brief_prompt).send().await {
-            Ok(response) => {
-                let content: String = response
-                    .choice
-                    .into_iter()
-                    .filter_map(|c| match c {
-                        AssistantContent::Text(text) => Some(text.text),
-                        _ => None,
-                    })
-                    .collect::<Vec<_>>()
-                    .join("\n");
-
+        match request_brief(topic, &deep_dive_content, &clients).await {
+            Ok(content) => {
                 let (brief, summary) = parse_brief_response(&content);
 
                 // Write brief.md file
                 if let (Some(b), Some(s)) = (&brief, &summary) {
-                    let repo_url = library_info
+                    let repository = library_info
                         .as_ref()
-                        .and_then(|li| li.repository.as_ref())
-                        .map(|r| format!("repo: {}\n", r))
-                        .unwrap_or_default();
-
-                    let brief_content = format!("---\nsummary: {}\n{}---\n\n{}", b, repo_url, s);
+                        .and_then(|li| li.repository.as_deref());
+                    let brief_content = brief_document(b, s, repository);
                     let _ = fs::write(output_dir.join("brief.md"), brief_content).await;
-                    println!("[3/3] brief ✓");
+                    report_task(
+                        observer.as_ref(),
+                        "brief",
+                        3,
+                        3,
+                        phase2_start.elapsed().as_secs_f32(),
+                        TaskOutcome::Succeeded { metrics: None },
+                    );
                 }
 
                 (brief, summary)
             }
             Err(e) => {
-                eprintln!("Warning: Failed to generate brief: {}", e);
+                observer.on_warning(&format!("Failed to generate brief: {}", e));
                 (None, None)
             }
         }
@@ -2677,6 +4105,7 @@ async fn run_incremental_research(
     // Convert skill_metrics_result to PromptTaskResult for metrics aggregation
     let skill_result = PromptTaskResult {
         metrics: skill_metrics_result.ok().flatten(),
+        model: None,
     };
 
     let phase2_results = [skill_result, deep_dive_result];
@@ -2686,11 +4115,12 @@ async fn run_incremental_research(
         .collect();
     let phase2_failed = phase2_results.len() - phase2_succeeded.len();
 
-    println!(
-        "\nPhase 2 complete: {}/{} succeeded",
-        phase2_succeeded.len(),
-        phase2_results.len()
-    );
+    observer.on_phase_complete(&PhaseSummary {
+        phase: ResearchPhase::Synthesis,
+        succeeded: phase2_succeeded.len(),
+        total: phase2_results.len(),
+        cancelled: false,
+    });
 
     // Update metadata with brief/summary
     existing_metadata.brief = brief_text;
@@ -2698,7 +4128,7 @@ async fn run_incremental_research(
 
     // Save updated metadata
     if let Err(e) = existing_metadata.save(&output_dir).await {
-        eprintln!("Warning: Failed to update metadata.json: {}", e);
+        observer.on_warning(&format!("Failed to update metadata.json: {}", e));
     }
 
     // Aggregate all metrics
@@ -2747,15 +4177,16 @@ async fn run_incremental_research(
 /// * `verbose` - If true, show detailed sub-bullets with metadata issues
 /// * `json` - If true, output as JSON; otherwise use terminal format
 ///
-/// ## Environment Variables
-/// * `RESEARCH_DIR` - Base directory for research library (default: `$HOME`)
+/// ## Library Location
+/// Topics are read from the library found by [`ResearchPaths::from_env`]
+/// (`RESEARCH_DIR`, `~/.research` or the platform data directory); use
+/// [`list_in`] to list another library.
 ///
 /// ## Returns
 /// Returns `Ok(())` on success, or an error if discovery/formatting fails.
 ///
 /// ## Errors
 /// Returns an error if:
-/// - Neither `RESEARCH_DIR` nor `HOME` environment variable is set
 /// - The library directory cannot be read
 /// - JSON serialization fails (when `json` is true)
 #[instrument(
@@ -2774,7 +4205,17 @@ pub async fn list(
     verbose: bool,
     json: bool,
 ) -> Result<(), String> {
-    list_with_migrate(filters, types, verbose, json, false).await
+    list_with_migrate(
+        filters,
+        types,
+        Vec::new(),
+        Vec::new(),
+        verbose,
+        json,
+        false,
+        false,
+    )
+    .await
 }
 
 /// Check if a metadata.json file has the when_to_use field set.
@@ -2794,37 +4235,74 @@ fn check_has_when_to_use(metadata_path: &std::path::Path) -> bool {
 ///
 /// When `migrate` is true, this function will load each topic's metadata using
 /// `ResearchMetadata::load()`, which triggers automatic v0 → v1 migration.
+/// When `tags` is non-empty, only topics tagged with one of the tags (on the
+/// topic itself or one of its questions) are listed, and when `categories` is
+/// non-empty, only topics filed under one of the categories. Archived topics
+/// (see [`archive`]) are left out unless `archived` is true.
+#[allow(clippy::too_many_arguments)]
 #[tracing::instrument(
     name = "list_with_migrate",
     skip_all,
     fields(
         filter_count = filters.len(),
         type_count = types.len(),
+        tag_count = tags.len(),
+        category_count = categories.len(),
         verbose = verbose,
         json = json,
-        migrate = migrate
+        migrate = migrate,
+        archived = archived
     )
 )]
 pub async fn list_with_migrate(
     filters: Vec<String>,
     types: Vec<String>,
+    tags: Vec<String>,
+    categories: Vec<String>,
     verbose: bool,
     json: bool,
     migrate: bool,
+    archived: bool,
 ) -> Result<(), String> {
-    use list::{apply_filters, discover_topics, format_json, format_terminal};
+    list_in(
+        &ResearchPaths::from_env(),
+        filters,
+        types,
+        tags,
+        categories,
+        verbose,
+        json,
+        migrate,
+        archived,
+    )
+    .await
+}
 
-    // Get RESEARCH_DIR from env (default to HOME)
-    let research_dir = std::env::var("RESEARCH_DIR").unwrap_or_else(|_| {
-        std::env::var("HOME").expect("Neither RESEARCH_DIR nor HOME environment variable is set")
-    });
+/// Lists the research topics of the library at `paths`.
+///
+/// Takes the same options as [`list_with_migrate`], which lists the library
+/// found by [`ResearchPaths::from_env`].
+#[allow(clippy::too_many_arguments)]
+pub async fn list_in(
+    paths: &ResearchPaths,
+    filters: Vec<String>,
+    types: Vec<String>,
+    tags: Vec<String>,
+    categories: Vec<String>,
+    verbose: bool,
+    json: bool,
+    migrate: bool,
+    archived: bool,
+) -> Result<(), String> {
+    use list::{
+        apply_filters, discover_all_topics, discover_archived_topics, filter_by_category,
+        filter_by_tags, format_json, format_terminal,
+    };
 
-    // Construct library path: $RESEARCH_DIR/.research/library/
-    let library_path = PathBuf::from(&research_dir)
-        .join(".research")
-        .join("library");
+    // Topics live in one directory per kind: {root}/{kind}/
+    let research_root = paths.root().to_path_buf();
 
-    debug!("Searching for topics in: {:?}", library_path);
+    debug!("Searching for topics in: {:?}", research_root);
 
     // If migrate flag is set, trigger migration for all topics
     if migrate {
@@ -2835,15 +4313,18 @@ pub async fn list_with_migrate(
         let mut needs_manual_fix: Vec<String> = Vec::new();
         let mut errors = 0;
 
-        // Also check the api directory
-        let api_path = PathBuf::from(&research_dir).join(".research").join("api");
+        // Check every kind directory, including api
+        let kind_paths = ResearchKind::TOPIC_KINDS
+            .into_iter()
+            .chain([ResearchKind::Api])
+            .map(|kind| research_root.join(kind.dir_name()));
 
-        for base_path in [&library_path, &api_path] {
+        for base_path in kind_paths {
             if !base_path.exists() {
                 continue;
             }
 
-            if let Ok(entries) = std::fs::read_dir(base_path) {
+            if let Ok(entries) = std::fs::read_dir(&base_path) {
                 for entry in entries.flatten() {
                     let path = entry.path();
                     if path.is_dir() {
@@ -2932,14 +4413,22 @@ pub async fn list_with_migrate(
     }
 
     // Discover topics
-    let topics =
-        discover_topics(library_path).map_err(|e| format!("Failed to discover topics: {}", e))?;
+    let mut topics = discover_all_topics(&research_root)
+        .map_err(|e| format!("Failed to discover topics: {}", e))?;
+    if archived {
+        topics.extend(
+            discover_archived_topics(&research_root)
+                .map_err(|e| format!("Failed to discover archived topics: {}", e))?,
+        );
+    }
 
     debug!("Found {} topics before filtering", topics.len());
 
     // Apply filters
     let filtered_topics = apply_filters(topics, &filters, &types)
         .map_err(|e| format!("Failed to apply filters: {}", e))?;
+    let filtered_topics = filter_by_tags(filtered_topics, &tags);
+    let filtered_topics = filter_by_category(filtered_topics, &categories);
 
     debug!("Found {} topics after filtering", filtered_topics.len());
 
@@ -2996,17 +4485,42 @@ pub async fn list_with_migrate(
     )
 )]
 pub async fn link(filters: Vec<String>, types: Vec<String>, json: bool) -> Result<(), String> {
+    link_with_sync(filters, types, json, false).await
+}
+
+/// Link research topics, optionally syncing existing links with the library.
+///
+/// With `sync` set, symlinks into the research library that no topic accounts
+/// for are pruned and symlinks whose target has drifted are re-pointed at the
+/// topic's current skill directory or deep dive. See
+/// [`link::link_with_sync`] for details.
+#[instrument(
+    skip(filters, types, json),
+    fields(
+        filter_count = filters.len(),
+        type_count = types.len(),
+        json = json,
+        sync = sync
+    )
+)]
+pub async fn link_with_sync(
+    filters: Vec<String>,
+    types: Vec<String>,
+    json: bool,
+    sync: bool,
+) -> Result<(), String> {
     // Delegate to the link module implementation
-    let result = link::link(filters, types, json)
+    let result = link::link_with_sync(filters, types, json, sync)
         .await
         .map_err(|e| format!("Link operation failed: {}", e))?;
 
     // TODO: Phase 5 - Format and output results
     // For now, just acknowledge success
     debug!(
-        "Link completed: {} processed, {} created, {} failed",
+        "Link completed: {} processed, {} created, {} repaired, {} failed",
         result.total_processed(),
         result.total_created(),
+        result.total_repaired(),
         result.total_failed()
     );
 
@@ -3023,12 +4537,15 @@ pub async fn link(filters: Vec<String>, types: Vec<String>, json: bool) -> Resul
 ///
 /// Returns `ResearchError::SkillRegenerationFailed` if:
 /// - Any underlying research documents are missing
-/// - Research type is not `ResearchKind::Library`
+/// - Research type is `ResearchKind::Api`
 /// - LLM call to generate skill fails
 /// - File I/O operations fail
 async fn regenerate_skill_from_existing_research(
     topic: &str,
     output_dir: &Path,
+    models: ModelConfig,
+    scheduler: Option<Arc<ResearchScheduler>>,
+    observer: Arc<dyn ResearchObserver>,
 ) -> Result<ResearchResult, ResearchError> {
     let start_time = std::time::Instant::now();
 
@@ -3040,9 +4557,9 @@ async fn regenerate_skill_from_existing_research(
     })?;
 
     // 2. Validate research type
-    if metadata.kind != ResearchKind::Library {
+    if metadata.kind == ResearchKind::Api {
         return Err(ResearchError::SkillRegenerationFailed(format!(
-            "Cannot regenerate skill for non-library research (found: {:?})",
+            "Cannot regenerate skill for API research (found: {:?})",
             metadata.kind
         )));
     }
@@ -3120,7 +4637,7 @@ async fn regenerate_skill_from_existing_research(
         fs::create_dir(&skill_dir).await?;
     }
 
-    println!("🔄 Regenerating skill files from existing research...");
+    observer.on_info("🔄 Regenerating skill files from existing research...");
 
     // 6. Read all underlying research documents
     let overview_content = fs::read_to_string(output_dir.join("overview.md")).await?;
@@ -3140,6 +4657,7 @@ async fn regenerate_skill_from_existing_research(
 
     // 7. Build combined context (same format as normal research workflow)
     let combined_context = prompts::CONTEXT
+        .get()
         .replace("{{topic}}", topic)
         .replace("{{overview}}", &overview_content)
         .replace("{{similar_libraries}}", &similar_libraries_content)
@@ -3148,8 +4666,8 @@ async fn regenerate_skill_from_existing_research(
         .replace("{{changelog}}", &changelog_content)
         .replace("{{additional_content}}", &additional_content);
 
-    // 8. Get OpenAI client
-    let openai = openai::Client::from_env();
+    // 8. Initialize providers
+    let clients = ModelClients::from_env(models)?.with_scheduler(scheduler);
     let cancelled = Arc::new(AtomicBool::new(false));
 
     // 9. Call generate_skill_files to regenerate SKILL.md
@@ -3157,8 +4675,9 @@ async fn regenerate_skill_from_existing_research(
         topic,
         output_dir,
         &combined_context,
-        &openai,
+        &clients,
         cancelled,
+        observer.clone(),
         &mut metadata,
     )
     .await?;
@@ -3184,7 +4703,7 @@ async fn regenerate_skill_from_existing_research(
         }
     }
 
-    println!("✓ Skill files regenerated successfully");
+    observer.on_info("✓ Skill files regenerated successfully");
 
     // 11. Return ResearchResult with metrics
     let (input_tokens, output_tokens, total_tokens) = if let Some(metrics) = skill_metrics {
@@ -3210,6 +4729,135 @@ async fn regenerate_skill_from_existing_research(
     })
 }
 
+/// Read a topic's deep dive, preferring `deep-dive/{topic}.md` over the
+/// legacy `deep_dive.md`. Returns `None` when neither exists or is non-empty.
+async fn read_deep_dive(output_dir: &Path, topic: &str) -> Option<String> {
+    for path in [
+        output_dir.join(format!("deep-dive/{}.md", topic)),
+        output_dir.join("deep_dive.md"),
+    ] {
+        if let Ok(content) = fs::read_to_string(&path).await
+            && !content.trim().is_empty()
+        {
+            return Some(content);
+        }
+    }
+    None
+}
+
+/// Regenerate a library topic's brief from its existing deep dive.
+///
+/// Re-runs only the brief prompt, rewriting `brief.md` and the `brief` and
+/// `summary` fields of `metadata.json`. Fixing a poor brief this way avoids
+/// repeating the whole Phase 2 synthesis.
+///
+/// The topic is read from [`default_output_dir`]; use [`regenerate_brief_in`]
+/// for research stored elsewhere.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use research_lib::regenerate_brief;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     regenerate_brief("clap").await?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// See [`regenerate_brief_in`].
+pub async fn regenerate_brief(topic: &str) -> Result<ResearchResult, ResearchError> {
+    regenerate_brief_in(
+        topic,
+        &default_output_dir(topic),
+        &ResearchOptions::default(),
+    )
+    .await
+}
+
+/// Regenerate the brief for the topic researched in `output_dir`.
+///
+/// Models, scheduler and observer come from `options`. The existing
+/// `brief.md` and metadata are only replaced once the model returns both a
+/// brief and a summary.
+///
+/// ## Errors
+///
+/// Returns `ResearchError::NoMetadata` if `output_dir` has no `metadata.json`,
+/// and `ResearchError::BriefRegenerationFailed` if:
+/// - The deep dive is missing or empty
+/// - The LLM call fails
+/// - The response lacks a `BRIEF:` or `SUMMARY:` line
+#[instrument(name = "regenerate_brief", skip(options), fields(output_dir = %output_dir.display()))]
+pub async fn regenerate_brief_in(
+    topic: &str,
+    output_dir: &Path,
+    options: &ResearchOptions,
+) -> Result<ResearchResult, ResearchError> {
+    let start_time = Instant::now();
+    dotenvy::dotenv().ok();
+
+    let mut metadata = ResearchMetadata::load(output_dir)
+        .await
+        .ok_or_else(|| ResearchError::NoMetadata(output_dir.to_path_buf()))?;
+
+    let deep_dive = read_deep_dive(output_dir, topic).await.ok_or_else(|| {
+        ResearchError::BriefRegenerationFailed(format!(
+            "No deep dive found for '{}'. Run research first.",
+            topic
+        ))
+    })?;
+
+    let models = match options.models.clone() {
+        Some(models) => models,
+        None => ModelConfig::load()?,
+    };
+    let observer = options
+        .observer
+        .clone()
+        .unwrap_or_else(|| Arc::new(ConsoleObserver));
+    observer.on_info("🔄 Regenerating brief from existing deep dive...");
+
+    let clients = ModelClients::from_env(models)?.with_scheduler(options.scheduler.clone());
+    let content = request_brief(topic, &deep_dive, &clients)
+        .await
+        .map_err(ResearchError::BriefRegenerationFailed)?;
+
+    let (Some(brief), Some(summary)) = parse_brief_response(&content) else {
+        return Err(ResearchError::BriefRegenerationFailed(
+            "Response did not contain both BRIEF: and SUMMARY: lines".to_string(),
+        ));
+    };
+
+    let repository = metadata.library_info().and_then(|li| li.repository);
+    fs::write(
+        output_dir.join("brief.md"),
+        brief_document(&brief, &summary, repository.as_deref()),
+    )
+    .await?;
+
+    metadata.brief = Some(brief);
+    metadata.summary = Some(summary);
+    metadata.save(output_dir).await?;
+
+    observer.on_info("✓ Brief regenerated successfully");
+
+    Ok(ResearchResult {
+        topic: topic.to_string(),
+        output_dir: output_dir.to_path_buf(),
+        succeeded: 1, // Only the brief regenerated
+        failed: 0,
+        cancelled: false,
+        total_time_secs: start_time.elapsed().as_secs_f32(),
+        total_input_tokens: 0,
+        total_output_tokens: 0,
+        total_tokens: 0,
+    })
+}
+
 /// Delete existing ResearchOutput documents (overview, similar_libraries, etc.)
 ///
 /// This is called when the user provides the `--force` flag. It removes all
@@ -3273,8 +4921,9 @@ async fn delete_research_output_documents(
 /// ## Arguments
 ///
 /// * `topic` - The library/package name to research
-/// * `output_dir` - Optional output directory (defaults to `$RESEARCH_DIR/library/{topic}`)
-/// * `questions` - Additional research questions beyond standard prompts
+/// * `output_dir` - Optional output directory (defaults to [`default_output_dir`])
+/// * `questions` - Additional research questions beyond standard prompts, each
+///   with optional model, tool and output file settings (see [`Question`])
 /// * `skill_regenerate` - If true, regenerate skill/* files from existing research
 /// * `force_recreation` - If true, force recreation of all ResearchOutput documents
 ///
@@ -3282,8 +4931,8 @@ async fn delete_research_output_documents(
 /// A `ResearchResult` containing metrics about the operation
 ///
 /// ## Errors
-/// Returns `ResearchError` if the output directory cannot be created
-/// or if all prompts fail.
+/// Returns `ResearchError` if the output directory cannot be created,
+/// if a question is invalid (see [`validate_questions`]) or if all prompts fail.
 ///
 /// ## Examples
 ///
@@ -3324,29 +4973,120 @@ async fn delete_research_output_documents(
 ///     Ok(())
 /// }
 /// ```
+///
+/// Additional questions with their own settings:
+/// ```no_run
+/// use research_lib::models::{ModelProvider, ModelSpec};
+/// use research_lib::question::Question;
+/// use research_lib::research;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let questions = [
+///         // A quick lookup: the default model, no web searches
+///         Question::from("Which Rust version does clap require?").with_tools(false),
+///         // A hard question: a stronger model, answered in value_parsers.md
+///         Question::new("How do I write a custom value parser?")
+///             .with_model(ModelSpec::new(ModelProvider::OpenAi, "gpt-5.2"))
+///             .with_filename("value_parsers"),
+///     ];
+///     let result = research("clap", None, &questions, false, false).await?;
+///     println!("Research complete: {} documents generated", result.succeeded);
+///     Ok(())
+/// }
+/// ```
+pub async fn research(
+    topic: &str,
+    output_dir: Option<PathBuf>,
+    questions: &[Question],
+    skill_regenerate: bool,
+    force_recreation: bool,
+) -> Result<ResearchResult, ResearchError> {
+    run_research(
+        topic,
+        output_dir,
+        questions,
+        skill_regenerate,
+        force_recreation,
+        ResearchKind::Library,
+        &SelectionPolicy::Interactive,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Arc::new(ConsoleObserver),
+    )
+    .await
+}
+
+/// Runs the research workflow behind [`research`] and [`research_with_options`].
+///
+/// `kind` selects the Phase 1 prompts and default output directory; package
+/// managers are only searched for [`ResearchKind::Library`], and `selection`
+/// picks between their matches. When `streaming`
+/// is set, plain completion prompts stream their output to disk and report
+/// progress to the handler. Without `models`, the model configuration is
+/// loaded with [`ModelConfig::load`]. Library prompts use `ecosystem`'s
+/// variants when set, otherwise the ones for the library's language. Full
+/// runs analyze `repository` when set and add its context to the Phase 1
+/// prompts. Model requests wait for `scheduler`'s limits when one is set.
+/// Task, phase and warning events go to `observer`.
+#[allow(clippy::too_many_arguments)]
 #[instrument(
     name = "research",
-    skip(output_dir, questions, skill_regenerate, force_recreation),
+    skip(
+        output_dir,
+        questions,
+        skill_regenerate,
+        force_recreation,
+        selection,
+        ecosystem,
+        repository,
+        streaming,
+        models,
+        scheduler,
+        observer
+    ),
     fields(
         topic = %topic,
         question_count = questions.len(),
         skill_regenerate = skill_regenerate,
         force_recreation = force_recreation,
+        kind = ?kind,
+        streaming = streaming.is_some(),
+        scheduled = scheduler.is_some(),
         tools_enabled = tracing::field::Empty
     )
 )]
-pub async fn research(
+async fn run_research(
     topic: &str,
     output_dir: Option<PathBuf>,
-    questions: &[String],
+    questions: &[Question],
     skill_regenerate: bool,
     force_recreation: bool,
+    kind: ResearchKind,
+    selection: &SelectionPolicy,
+    ecosystem: Option<Ecosystem>,
+    repository: Option<RepositorySource>,
+    streaming: Option<ProgressHandler>,
+    models: Option<ModelConfig>,
+    scheduler: Option<Arc<ResearchScheduler>>,
+    observer: Arc<dyn ResearchObserver>,
 ) -> Result<ResearchResult, ResearchError> {
     info!("Starting research session");
 
     // Load environment variables from .env file
     dotenvy::dotenv().ok();
 
+    // Resolve models after .env so RESEARCH_MODEL_* overrides there apply
+    let models = match models {
+        Some(models) => models,
+        None => ModelConfig::load()?,
+    };
+
+    validate_questions(questions)?;
+
     // Validate flag combinations
     if skill_regenerate && force_recreation {
         return Err(ResearchError::InvalidFlagCombination(
@@ -3354,20 +5094,30 @@ pub async fn research(
         ));
     }
 
-    // Use provided output_dir or default to research/{topic}
-    let output_dir = output_dir.unwrap_or_else(|| default_output_dir(topic));
+    // Use provided output_dir or default to .research/{kind}/{topic}
+    let output_dir = output_dir.unwrap_or_else(|| default_output_dir_for(kind, topic));
 
     // Create output directory
     fs::create_dir_all(&output_dir).await?;
 
     // Handle --skill flag (regenerate skill from existing research)
     if skill_regenerate {
-        return regenerate_skill_from_existing_research(topic, &output_dir).await;
+        let run_log = Arc::new(RunLogRecorder::new(observer, &models));
+        let result = regenerate_skill_from_existing_research(
+            topic,
+            &output_dir,
+            models,
+            scheduler,
+            run_log.clone(),
+        )
+        .await?;
+        run_log.finish(&result);
+        return Ok(result);
     }
 
     // Handle --force flag (force recreation of all documents)
     if force_recreation {
-        println!("🔄 Force recreation mode: Regenerating all research documents...");
+        observer.on_info("🔄 Force recreation mode: Regenerating all research documents...");
         delete_research_output_documents(&output_dir).await?;
         // Continue to normal research workflow (will regenerate everything)
         // Skip incremental mode check by not entering the if block below
@@ -3376,7 +5126,7 @@ pub async fn research(
     // Check for existing metadata (incremental mode) - skip if force_recreation is true
     if !force_recreation && let Some(existing_metadata) = ResearchMetadata::load(&output_dir).await
     {
-        println!("Found existing research for '{}'", topic);
+        observer.on_info(&format!("Found existing research for '{}'", topic));
 
         // Check for missing standard prompts
         // NOTE: Using deprecated function because research() accepts custom output_dir
@@ -3385,10 +5135,15 @@ pub async fn research(
         #[allow(deprecated)]
         let missing_prompts = check_missing_standard_prompts(&output_dir).await;
         if !missing_prompts.is_empty() {
-            println!("  ⚠ Missing {} standard prompt(s):", missing_prompts.len());
-            for mp in &missing_prompts {
-                println!("    - {}", mp.filename);
-            }
+            let filenames: Vec<_> = missing_prompts
+                .iter()
+                .map(|mp| mp.filename)
+                .collect();
+            observer.on_warning(&format!(
+                "Missing {} standard prompt(s): {}",
+                missing_prompts.len(),
+                filenames.join(", ")
+            ));
         }
 
         // Check for missing output files
@@ -3396,28 +5151,35 @@ pub async fn research(
         #[allow(deprecated)]
         let missing_outputs = check_missing_outputs(&output_dir).await;
         if !missing_outputs.is_empty() {
-            println!("  ⚠ Missing {} output file(s):", missing_outputs.len());
-            for mo in &missing_outputs {
-                println!("    - {}", mo.filename);
-            }
+            let filenames: Vec<_> = missing_outputs
+                .iter()
+                .map(|mo| mo.filename)
+                .collect();
+            observer.on_warning(&format!(
+                "Missing {} output file(s): {}",
+                missing_outputs.len(),
+                filenames.join(", ")
+            ));
         }
 
         // Check for overlaps and filter questions
-        let mut questions_to_run: Vec<(usize, String)> = Vec::new();
+        let mut questions_to_run: Vec<(usize, Question)> = Vec::new();
         let mut next_num = existing_metadata.next_question_number();
 
         for question in questions {
-            if let Some(conflict_file) = existing_metadata.check_overlap(question) {
-                println!(
-                    "  ⚠ Question overlaps with existing {}: \"{}\"",
+            if let Some(conflict_file) = find_overlap(&existing_metadata, &question.text).await {
+                observer.on_warning(&format!(
+                    "Question overlaps with existing {}: \"{}\"",
                     conflict_file, question
-                );
+                ));
 
                 // Ask user if they want to include anyway
-                let confirm =
-                    inquire::Confirm::new(&format!("Include anyway as question_{}?", next_num))
-                        .with_default(false)
-                        .prompt();
+                let confirm = inquire::Confirm::new(&format!(
+                    "Include anyway as {}?",
+                    question.filename(next_num)
+                ))
+                .with_default(false)
+                .prompt();
 
                 match confirm {
                     Ok(true) => {
@@ -3425,10 +5187,10 @@ pub async fn research(
                         next_num += 1;
                     }
                     Ok(false) => {
-                        println!("    Skipping overlapping question");
+                        observer.on_info("    Skipping overlapping question");
                     }
                     Err(_) => {
-                        println!("    Skipping (cancelled)");
+                        observer.on_info("    Skipping (cancelled)");
                     }
                 }
             } else {
@@ -3439,7 +5201,7 @@ pub async fn research(
 
         // If nothing to do (no missing prompts, no missing outputs, and no new questions), return early
         if missing_prompts.is_empty() && missing_outputs.is_empty() && questions_to_run.is_empty() {
-            println!("  Research is complete. Use additional prompts to expand research.");
+            observer.on_info("  Research is complete. Use additional prompts to expand research.");
             return Ok(ResearchResult {
                 topic: topic.to_string(),
                 output_dir,
@@ -3453,29 +5215,169 @@ pub async fn research(
             });
         }
 
+        if repository.is_some() {
+            observer.on_warning(
+                "Repository context is only added to full research runs (use --force to rerun everything)",
+            );
+        }
+
         // Run incremental research with missing prompts, missing outputs, and/or new questions
-        return run_incremental_research(
+        let run_log = Arc::new(
+            RunLogRecorder::new(observer, &models).with_questions(
+                questions_to_run
+                    .iter()
+                    .map(|(num, question)| (*num, question)),
+            ),
+        );
+        let result = run_incremental_research(
             topic,
             output_dir,
             existing_metadata,
             questions_to_run,
             missing_prompts,
             missing_outputs,
+            ecosystem,
+            streaming,
+            models,
+            scheduler,
+            run_log.clone(),
         )
-        .await;
+        .await?;
+        run_log.finish(&result);
+        return Ok(result);
+    }
+
+    if Checkpoint::load(&output_dir).is_some() {
+        observer.on_warning(
+            "Found an interrupted research run; starting over (use --resume to continue it instead)",
+        );
     }
 
-    // Find the library across package managers and let user select if multiple
-    println!("Checking package managers for '{}'...", topic);
-    let library_matches = find_library(topic).await;
-    let selected = select_library(library_matches, topic);
+    // Find the library across package managers and let user select if multiple.
+    // Software and standards aren't packages, so they skip the lookup.
+    let library_info = if kind == ResearchKind::Library {
+        observer.on_info(&format!("Checking package managers for '{}'...", topic));
+        let library_matches = find_library(topic).await;
+        match select_library_with(library_matches, topic, selection, observer.as_ref()) {
+            LibrarySelection::Selected(info) | LibrarySelection::Single(info) => Some(info),
+            LibrarySelection::Ambiguous(libraries) => {
+                return Err(ResearchError::ambiguous_library(topic, &libraries));
+            }
+            _ => None,
+        }
+    } else {
+        None
+    };
 
-    // Extract library info for metadata
-    let library_info = match &selected {
-        LibrarySelection::Selected(info) | LibrarySelection::Single(info) => Some(info.clone()),
-        _ => None,
+    let ecosystem = Ecosystem::resolve(
+        ecosystem,
+        library_info.as_ref().map(|li| li.language.as_str()),
+    );
+    let repository_context = match &repository {
+        Some(source) => {
+            observer.on_info(&format!("Analyzing source repository ({})...", source));
+            match repository::repository_context(source, library_info.as_ref()).await {
+                Ok(context) => {
+                    observer.on_info(&format!(
+                        "  ✓ Found {} exported symbol(s) and {} example file(s)\n",
+                        context.api_len(),
+                        context.examples.len()
+                    ));
+                    Some(context.to_prompt())
+                }
+                Err(e) => {
+                    warn!(error = %e, "Repository analysis failed");
+                    observer.on_warning(&format!("Researching without repository context: {}", e));
+                    None
+                }
+            }
+        }
+        None => None,
     };
 
+    let mut checkpoint = Checkpoint::new(topic, kind, library_info, ecosystem, questions.to_vec());
+    checkpoint.repository_context = repository_context;
+    run_full_research_logged(
+        checkpoint, output_dir, streaming, models, scheduler, observer,
+    )
+    .await
+}
+
+/// Runs [`run_full_research`], writing the run log once the run finishes.
+async fn run_full_research_logged(
+    checkpoint: Checkpoint,
+    output_dir: PathBuf,
+    streaming: Option<ProgressHandler>,
+    models: ModelConfig,
+    scheduler: Option<Arc<ResearchScheduler>>,
+    observer: Arc<dyn ResearchObserver>,
+) -> Result<ResearchResult, ResearchError> {
+    let run_log = Arc::new(
+        RunLogRecorder::new(observer, &models).with_questions(
+            checkpoint
+                .questions
+                .iter()
+                .enumerate()
+                .map(|(i, question)| (i + 1, question)),
+        ),
+    );
+    let result = run_full_research(
+        checkpoint,
+        output_dir,
+        streaming,
+        models,
+        scheduler,
+        run_log.clone(),
+    )
+    .await?;
+    run_log.finish(&result);
+    Ok(result)
+}
+
+/// Runs every Phase 1 prompt of `checkpoint`'s topic, then Phase 2.
+///
+/// Phase 1 tasks the checkpoint records as completed (with their output file
+/// still present) are kept rather than run again. The checkpoint is updated
+/// in `output_dir` as tasks finish and deleted once the run completes, so an
+/// interrupted run can be picked up by [`resume`].
+async fn run_full_research(
+    checkpoint: Checkpoint,
+    output_dir: PathBuf,
+    streaming: Option<ProgressHandler>,
+    models: ModelConfig,
+    scheduler: Option<Arc<ResearchScheduler>>,
+    observer: Arc<dyn ResearchObserver>,
+) -> Result<ResearchResult, ResearchError> {
+    let topic = checkpoint.topic.clone();
+    let topic = topic.as_str();
+    let kind = checkpoint.kind;
+    let library_info = checkpoint.library.clone();
+    let ecosystem = checkpoint.ecosystem;
+    let questions = checkpoint.questions.clone();
+    let repository_context = checkpoint.repository_context.clone();
+    let recorder = Arc::new(CheckpointRecorder::start(observer, checkpoint, &output_dir));
+    let observer: Arc<dyn ResearchObserver> = recorder.clone();
+
+    // Phase 1 tasks finished by an earlier, interrupted run
+    let restored: Vec<(String, PromptMetrics)> = STANDARD_PROMPTS
+        .iter()
+        .map(|(name, filename, _)| (name.to_string(), filename.to_string()))
+        .chain(
+            questions
+                .iter()
+                .enumerate()
+                .map(|(i, question)| (format!("question_{}", i + 1), question.filename(i + 1))),
+        )
+        .filter_map(|(name, filename)| recorder.restored(&name, &filename).map(|m| (name, m)))
+        .collect();
+    let is_restored = |name: &str| restored.iter().any(|(done, _)| done == name);
+    if !restored.is_empty() {
+        observer.on_info(&format!(
+            "Resuming: keeping {} completed research prompt(s)\n",
+            restored.len()
+        ));
+    }
+
     // Set up cancellation flag for SIGINT handling
     let cancelled = Arc::new(AtomicBool::new(false));
 
@@ -3488,9 +5390,7 @@ pub async fn research(
     });
 
     // Initialize providers
-    let openai = openai::Client::from_env();
-    let gemini = gemini::Client::from_env();
-    let zai = zai::Client::from_env().ok();
+    let clients = ModelClients::from_env(models)?.with_scheduler(scheduler);
 
     // Create HTTP client for changelog aggregation
     let http_client = HttpClient::builder()
@@ -3506,22 +5406,41 @@ pub async fn research(
             .map(|s| BravePlan::from_string(&s))
             .unwrap_or_default();
         info!(?plan, "Web research tools enabled");
-        println!(
+        observer.on_info(&format!(
             "  ✓ Web research tools enabled (BRAVE_API_KEY found, {:?} plan)\n",
             plan
-        );
+        ));
     } else {
         warn!("Web research tools disabled - set BRAVE_API_KEY to enable");
-        println!("  ⚠ Web research tools disabled (set BRAVE_API_KEY to enable)\n");
+        observer.on_warning("Web research tools disabled (set BRAVE_API_KEY to enable)");
     }
 
-    // Build prompts from templates with library context
+    // Build prompts from the kind's templates with library context, using the
+    // library ecosystem's variants
+    if kind == ResearchKind::Library && ecosystem != Ecosystem::Generic {
+        info!(%ecosystem, "Using ecosystem prompt variants");
+        observer.on_info(&format!("  ✓ Using {} prompt variants\n", ecosystem));
+    }
+    let prompt_set = PromptSet::for_topic(kind, ecosystem);
     let lib_info_ref = library_info.as_ref();
-    let overview_prompt = build_prompt(prompts::OVERVIEW, topic, lib_info_ref);
-    let similar_libraries_prompt = build_prompt(prompts::SIMILAR_LIBRARIES, topic, lib_info_ref);
-    let integration_partners_prompt =
-        build_prompt(prompts::INTEGRATION_PARTNERS, topic, lib_info_ref);
-    let use_cases_prompt = build_prompt(prompts::USE_CASES, topic, lib_info_ref);
+    // Grounded in the source repository's public API when it was analyzed
+    let grounded = |prompt: String, task: &str| {
+        repository::ground_prompt(prompt, task, repository_context.as_deref())
+    };
+    let overview_prompt = grounded(
+        build_prompt(prompt_set.overview.get(), topic, lib_info_ref),
+        "overview",
+    );
+    let similar_libraries_prompt =
+        build_prompt(prompt_set.similar_libraries.get(), topic, lib_info_ref);
+    let integration_partners_prompt = grounded(
+        build_prompt(prompt_set.integration_partners.get(), topic, lib_info_ref),
+        "integration_partners",
+    );
+    let use_cases_prompt = grounded(
+        build_prompt(prompt_set.use_cases.get(), topic, lib_info_ref),
+        "use_cases",
+    );
     // Note: changelog_prompt is built inside run_changelog_*_task with version history
 
     // Extract library context strings for question tasks (owned for boxed futures)
@@ -3540,294 +5459,390 @@ pub async fn research(
     let topic_owned = topic.to_string();
 
     let num_questions = questions.len();
-    let total = 5 + num_questions; // 5 default prompts + user questions
+    // 5 default prompts + user questions, less those already done
+    let total = 5 + num_questions - restored.len();
 
     // Phase 1 span
     let _phase1_guard =
         info_span!("phase_1", prompt_count = total, tools_enabled = use_tools).entered();
 
     info!(prompt_count = total, "Beginning parallel prompt execution");
-    println!(
+    observer.on_info(&format!(
         "Phase 1: Running {} research prompts in parallel to {:?}...\n",
         total, output_dir
-    );
-    println!("  (Press Ctrl+C to cancel and save completed results)\n");
+    ));
+    observer.on_info("  (Press Ctrl+C to cancel and save completed results)\n");
 
     let start_time = Instant::now();
     let counter = Arc::new(AtomicUsize::new(0));
 
     // Create Phase 1 tasks - with or without tools
-    type BoxedFuture =
-        std::pin::Pin<Box<dyn std::future::Future<Output = PromptTaskResult> + Send>>;
-    let mut phase1_futures: Vec<BoxedFuture> = Vec::new();
+    let mut phase1_futures: Vec<TaskFuture<'_>> = Vec::new();
+    let mut phase1_files: Vec<String> = Vec::new();
 
     if use_tools {
         // Create agents with web research tools
-        let search_tool = BraveSearchTool::from_env();
-        let scrape_tool = ScreenScrapeTool::new();
-
-        // Overview agent (using zai GLM if available, otherwise Gemini)
-        if let Some(ref z) = zai {
-            let overview_agent = z
-                .agent(zai::GLM_4_7)
-                .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to gather key information, then synthesize your findings into a comprehensive response. Do not make excessive tool calls - gather what you need efficiently and write your final answer.")
-                .tool(search_tool.clone())
-                .tool(scrape_tool.clone())
-                .build();
-            phase1_futures.push(Box::pin(run_agent_prompt_task(
-                "overview",
-                "overview.md",
-                output_dir.clone(),
-                overview_agent,
-                overview_prompt,
-                counter.clone(),
-                total,
-                start_time,
-                cancelled.clone(),
-            )));
-        } else {
-            let overview_agent = gemini
-                .agent("gemini-3-flash-preview")
-                .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to gather key information, then synthesize your findings into a comprehensive response. Do not make excessive tool calls - gather what you need efficiently and write your final answer.")
-                .tool(search_tool.clone())
-                .tool(scrape_tool.clone())
-                .build();
-            phase1_futures.push(Box::pin(run_agent_prompt_task(
-                "overview",
-                "overview.md",
-                output_dir.clone(),
-                overview_agent,
-                overview_prompt,
+        let (search_tool, scrape_tool) = web_tools(clients.scheduler.as_deref());
+        let github_repo = github_repository(lib_info_ref);
+        let github_tool = github_tool();
+        let docs_crate = docs_rs_crate(lib_info_ref);
+        let docs_rs_tool = docs_rs_tool();
+
+        // Overview, similar libraries, integration partners and use cases agents
+        let research_tasks = [
+            (ResearchTask::Overview, "overview.md", overview_prompt),
+            (
+                ResearchTask::SimilarLibraries,
+                "similar_libraries.md",
+                similar_libraries_prompt,
+            ),
+            (
+                ResearchTask::IntegrationPartners,
+                "integration_partners.md",
+                integration_partners_prompt,
+            ),
+            (ResearchTask::UseCases, "use_cases.md", use_cases_prompt),
+        ];
+        for (task, filename, prompt) in research_tasks
+            .into_iter()
+            .filter(|(task, _, _)| !is_restored(task.name()))
+        {
+            let preamble = "You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to gather key information, then synthesize your findings into a comprehensive response. Do not make excessive tool calls - gather what you need efficiently and write your final answer.";
+            let repo = github_repo
+                .clone()
+                .filter(|_| task == ResearchTask::Overview);
+            let docs = docs_crate
+                .clone()
+                .filter(|_| task == ResearchTask::UseCases);
+            let (search_tool, scrape_tool) = (search_tool.clone(), scrape_tool.clone());
+            let (github_tool, docs_rs_tool) = (github_tool.clone(), docs_rs_tool.clone());
+            let (clients, output_dir) = (&clients, &output_dir);
+            let (counter, cancelled) = (&counter, &cancelled);
+
+            phase1_files.push(filename.to_string());
+            phase1_futures.push(Box::pin(failover_task(
+                task.name(),
+                clients.failover_specs(task),
                 counter.clone(),
-                total,
-                start_time,
-                cancelled.clone(),
+                observer.clone(),
+                move |spec, observer| {
+                    let future: TaskFuture<'_> =
+                        with_model!(clients, spec = spec, |client, model, temp| {
+                            let mut agent = match (repo.as_deref(), docs.as_deref()) {
+                                (Some(repo), _) => client
+                                    .agent(model)
+                                    .preamble(&with_github_hint(preamble, repo))
+                                    .tool(search_tool.clone())
+                                    .tool(scrape_tool.clone())
+                                    .tool(github_tool.clone())
+                                    .build(),
+                                (None, Some(crate_name)) => client
+                                    .agent(model)
+                                    .preamble(&with_docs_rs_hint(preamble, crate_name))
+                                    .tool(search_tool.clone())
+                                    .tool(scrape_tool.clone())
+                                    .tool(docs_rs_tool.clone())
+                                    .build(),
+                                (None, None) => client
+                                    .agent(model)
+                                    .preamble(preamble)
+                                    .tool(search_tool.clone())
+                                    .tool(scrape_tool.clone())
+                                    .build(),
+                            };
+                            agent.temperature = temp;
+                            Box::pin(clients.schedule_on(
+                                spec.provider,
+                                run_agent_prompt_task(
+                                    task.name(),
+                                    filename,
+                                    output_dir.clone(),
+                                    agent,
+                                    prompt.clone(),
+                                    counter.clone(),
+                                    total,
+                                    start_time,
+                                    cancelled.clone(),
+                                    observer,
+                                    clients.config.tool_budget(task),
+                                ),
+                            ))
+                        });
+                    future
+                },
             )));
         }
 
-        // Similar libraries agent (using Gemini)
-        let similar_agent = gemini
-            .agent("gemini-3-flash-preview")
-            .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to gather key information, then synthesize your findings into a comprehensive response. Do not make excessive tool calls - gather what you need efficiently and write your final answer.")
-            .tool(search_tool.clone())
-            .tool(scrape_tool.clone())
-            .build();
-        phase1_futures.push(Box::pin(run_agent_prompt_task(
-            "similar_libraries",
-            "similar_libraries.md",
-            output_dir.clone(),
-            similar_agent,
-            similar_libraries_prompt,
-            counter.clone(),
-            total,
-            start_time,
-            cancelled.clone(),
-        )));
-
-        // Integration partners agent (using Gemini)
-        let integration_agent = gemini
-            .agent("gemini-3-flash-preview")
-            .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to gather key information, then synthesize your findings into a comprehensive response. Do not make excessive tool calls - gather what you need efficiently and write your final answer.")
-            .tool(search_tool.clone())
-            .tool(scrape_tool.clone())
-            .build();
-        phase1_futures.push(Box::pin(run_agent_prompt_task(
-            "integration_partners",
-            "integration_partners.md",
-            output_dir.clone(),
-            integration_agent,
-            integration_partners_prompt,
-            counter.clone(),
-            total,
-            start_time,
-            cancelled.clone(),
-        )));
-
-        // Use cases agent (using Gemini)
-        let use_cases_agent = gemini
-            .agent("gemini-3-flash-preview")
-            .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to gather key information, then synthesize your findings into a comprehensive response. Do not make excessive tool calls - gather what you need efficiently and write your final answer.")
-            .tool(search_tool.clone())
-            .tool(scrape_tool.clone())
-            .build();
-        phase1_futures.push(Box::pin(run_agent_prompt_task(
-            "use_cases",
-            "use_cases.md",
-            output_dir.clone(),
-            use_cases_agent,
-            use_cases_prompt,
-            counter.clone(),
-            total,
-            start_time,
-            cancelled.clone(),
-        )));
-
-        // Changelog agent (using OpenAI GPT) with version history aggregation
-        let changelog_agent = openai
-            .agent("gpt-5.2")
-            .preamble("You are a research assistant with web search and scraping tools. You have been provided with pre-gathered version data from structured sources. Synthesize this data into a readable changelog, enriching with context where helpful. Use tools only if you need additional information beyond the provided data.")
-            .tool(search_tool.clone())
-            .tool(scrape_tool.clone())
-            .build();
-        phase1_futures.push(Box::pin(run_changelog_agent_task(
-            "changelog",
-            "changelog.md",
-            output_dir.clone(),
-            changelog_agent,
-            topic.to_string(),
-            library_info.clone(),
-            http_client.clone(),
-            counter.clone(),
-            total,
-            start_time,
-            cancelled.clone(),
-        )));
-
-        // Question agents (using Gemini)
-        for (i, question) in questions.iter().enumerate() {
-            let question_agent = gemini
-                .agent("gemini-3-flash-preview")
-                .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to find relevant information, then provide a comprehensive answer. Do not make excessive tool calls - synthesize your findings efficiently.")
-                .tool(search_tool.clone())
-                .tool(scrape_tool.clone())
-                .build();
-
-            let ctx = LibraryContext {
-                package_manager: &pkg_mgr,
-                language: &lang,
-                url: &pkg_url,
-            };
-            let prompt = build_prompt_with_context(prompts::ADDITIONAL_QUESTION, topic, Some(&ctx))
-                .replace("{{question}}", question);
-
-            let question_num = i + 1;
-            let filename: &'static str =
-                Box::leak(format!("question_{}.md", question_num).into_boxed_str());
-            let name: &'static str =
-                Box::leak(format!("question_{}", question_num).into_boxed_str());
-
-            phase1_futures.push(Box::pin(run_agent_prompt_task(
-                name,
-                filename,
-                output_dir.clone(),
-                question_agent,
-                prompt,
+        // Changelog agent with version history aggregation
+        if !is_restored("changelog") {
+            let preamble = "You are a research assistant with web search and scraping tools. You have been provided with pre-gathered version data from structured sources. Synthesize this data into a readable changelog, enriching with context where helpful. Use tools only if you need additional information beyond the provided data.";
+            let (library_info, http_client) = (&library_info, &http_client);
+            let (clients, output_dir) = (&clients, &output_dir);
+            let (counter, cancelled) = (&counter, &cancelled);
+
+            phase1_files.push("changelog.md".to_string());
+            phase1_futures.push(Box::pin(failover_task(
+                "changelog",
+                clients.failover_specs(ResearchTask::Changelog),
                 counter.clone(),
-                total,
-                start_time,
-                cancelled.clone(),
+                observer.clone(),
+                move |spec, observer| {
+                    let future: TaskFuture<'_> =
+                        with_model!(clients, spec = spec, |client, model, temp| {
+                            let mut changelog_agent = match github_repo.as_deref() {
+                                Some(repo) => client
+                                    .agent(model)
+                                    .preamble(&with_github_hint(preamble, repo))
+                                    .tool(search_tool.clone())
+                                    .tool(scrape_tool.clone())
+                                    .tool(github_tool.clone())
+                                    .build(),
+                                None => client
+                                    .agent(model)
+                                    .preamble(preamble)
+                                    .tool(search_tool.clone())
+                                    .tool(scrape_tool.clone())
+                                    .build(),
+                            };
+                            changelog_agent.temperature = temp;
+                            Box::pin(clients.schedule_on(
+                                spec.provider,
+                                run_changelog_agent_task(
+                                    "changelog",
+                                    "changelog.md",
+                                    prompt_set.changelog.get(),
+                                    output_dir.clone(),
+                                    changelog_agent,
+                                    topic.to_string(),
+                                    library_info.clone(),
+                                    http_client.clone(),
+                                    counter.clone(),
+                                    total,
+                                    start_time,
+                                    cancelled.clone(),
+                                    observer,
+                                    clients.config.tool_budget(ResearchTask::Changelog),
+                                ),
+                            ))
+                        });
+                    future
+                },
             )));
         }
     } else {
         // Fallback: Use raw completion models without tools
-        let gemini1 = gemini.completion_model("gemini-3-flash-preview");
-        let gemini2 = gemini.completion_model("gemini-3-flash-preview");
-        let gemini3 = gemini.completion_model("gemini-3-flash-preview");
-        let changelog_model = openai.completion_model("gpt-5.2");
-
-        // Use GLM-4.7 if available, otherwise fall back to Gemini
-        if let Some(ref z) = zai {
-            let overview_model = z.completion_model(zai::GLM_4_7);
-            phase1_futures.push(Box::pin(run_prompt_task(
-                "overview",
-                "overview.md",
-                output_dir.clone(),
-                overview_model,
-                overview_prompt,
+        let research_tasks = [
+            (ResearchTask::Overview, "overview.md", overview_prompt),
+            (
+                ResearchTask::SimilarLibraries,
+                "similar_libraries.md",
+                similar_libraries_prompt,
+            ),
+            (
+                ResearchTask::IntegrationPartners,
+                "integration_partners.md",
+                integration_partners_prompt,
+            ),
+            (ResearchTask::UseCases, "use_cases.md", use_cases_prompt),
+        ];
+        for (task, filename, prompt) in research_tasks
+            .into_iter()
+            .filter(|(task, _, _)| !is_restored(task.name()))
+        {
+            let streaming = streaming.clone();
+            let (clients, output_dir) = (&clients, &output_dir);
+            let (counter, cancelled) = (&counter, &cancelled);
+
+            phase1_files.push(filename.to_string());
+            phase1_futures.push(Box::pin(failover_task(
+                task.name(),
+                clients.failover_specs(task),
                 counter.clone(),
-                total,
-                start_time,
-                cancelled.clone(),
+                observer.clone(),
+                move |spec, observer| {
+                    let future: TaskFuture<'_> =
+                        with_model!(clients, spec = spec, |client, model, temp| {
+                            Box::pin(clients.schedule_on(
+                                spec.provider,
+                                dispatch_prompt_task(
+                                    streaming.clone(),
+                                    task.name(),
+                                    filename,
+                                    output_dir.clone(),
+                                    TunedModel::new(client.completion_model(model), temp),
+                                    prompt.clone(),
+                                    counter.clone(),
+                                    total,
+                                    start_time,
+                                    cancelled.clone(),
+                                    observer,
+                                ),
+                            ))
+                        });
+                    future
+                },
             )));
-        } else {
-            let overview_model = gemini.completion_model("gemini-3-flash-preview");
-            phase1_futures.push(Box::pin(run_prompt_task(
-                "overview",
-                "overview.md",
-                output_dir.clone(),
-                overview_model,
-                overview_prompt,
+        }
+        if !is_restored("changelog") {
+            let (library_info, http_client) = (&library_info, &http_client);
+            let (clients, output_dir) = (&clients, &output_dir);
+            let (counter, cancelled) = (&counter, &cancelled);
+
+            phase1_files.push("changelog.md".to_string());
+            phase1_futures.push(Box::pin(failover_task(
+                "changelog",
+                clients.failover_specs(ResearchTask::Changelog),
                 counter.clone(),
-                total,
-                start_time,
-                cancelled.clone(),
+                observer.clone(),
+                move |spec, observer| {
+                    let future: TaskFuture<'_> =
+                        with_model!(clients, spec = spec, |client, model, temp| {
+                            Box::pin(clients.schedule_on(
+                                spec.provider,
+                                run_changelog_completion_task(
+                                    "changelog",
+                                    "changelog.md",
+                                    prompt_set.changelog.get(),
+                                    output_dir.clone(),
+                                    TunedModel::new(client.completion_model(model), temp),
+                                    topic.to_string(),
+                                    library_info.clone(),
+                                    http_client.clone(),
+                                    counter.clone(),
+                                    total,
+                                    start_time,
+                                    cancelled.clone(),
+                                    observer,
+                                ),
+                            ))
+                        });
+                    future
+                },
             )));
         }
-        phase1_futures.push(Box::pin(run_prompt_task(
-            "similar_libraries",
-            "similar_libraries.md",
-            output_dir.clone(),
-            gemini1,
-            similar_libraries_prompt,
-            counter.clone(),
-            total,
-            start_time,
-            cancelled.clone(),
-        )));
-        phase1_futures.push(Box::pin(run_prompt_task(
-            "integration_partners",
-            "integration_partners.md",
-            output_dir.clone(),
-            gemini2,
-            integration_partners_prompt,
-            counter.clone(),
-            total,
-            start_time,
-            cancelled.clone(),
-        )));
-        phase1_futures.push(Box::pin(run_prompt_task(
-            "use_cases",
-            "use_cases.md",
-            output_dir.clone(),
-            gemini3,
-            use_cases_prompt,
-            counter.clone(),
-            total,
-            start_time,
-            cancelled.clone(),
-        )));
-        phase1_futures.push(Box::pin(run_changelog_completion_task(
-            "changelog",
-            "changelog.md",
-            output_dir.clone(),
-            changelog_model,
-            topic.to_string(),
-            library_info.clone(),
-            http_client.clone(),
-            counter.clone(),
-            total,
-            start_time,
-            cancelled.clone(),
-        )));
+    }
 
-        // Question tasks without tools
-        for (i, question) in questions.iter().enumerate() {
-            let question_model = gemini.completion_model("gemini-3-flash-preview");
-            phase1_futures.push(Box::pin(run_question_task(
-                i + 1,
-                topic_owned.clone(),
-                question.clone(),
-                pkg_mgr.clone(),
-                lang.clone(),
-                pkg_url.clone(),
-                output_dir.clone(),
-                question_model,
-                counter.clone(),
-                total,
-                start_time,
-                cancelled.clone(),
-            )));
+    // Question tasks on each question's model, with tools unless the
+    // question opts out. Either way they fail over along the questions chain.
+    let question_tools = use_tools.then(|| web_tools(clients.scheduler.as_deref()));
+    for (i, question) in questions
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !is_restored(&format!("question_{}", i + 1)))
+    {
+        let question_num = i + 1;
+        let filename: &'static str = Box::leak(question.filename(question_num).into_boxed_str());
+        let name: &'static str = Box::leak(format!("question_{}", question_num).into_boxed_str());
+        let specs = clients.question_failover_specs(question);
+        let (clients, output_dir) = (&clients, &output_dir);
+        let (counter, cancelled) = (&counter, &cancelled);
+        phase1_files.push(filename.to_string());
+
+        match &question_tools {
+            Some((search_tool, scrape_tool)) if question.uses_tools(true) => {
+                let ctx = LibraryContext {
+                    package_manager: &pkg_mgr,
+                    language: &lang,
+                    url: &pkg_url,
+                };
+                let prompt = grounded(
+                    build_prompt_with_context(
+                        prompt_set.additional_question.get(),
+                        topic,
+                        Some(&ctx),
+                    )
+                    .replace("{{question}}", &question.text),
+                    name,
+                );
+                let (search_tool, scrape_tool) = (search_tool.clone(), scrape_tool.clone());
+
+                phase1_futures.push(Box::pin(failover_task(
+                    name,
+                    specs,
+                    counter.clone(),
+                    observer.clone(),
+                    move |spec, observer| {
+                        let future: TaskFuture<'_> =
+                            with_model!(clients, spec = spec, |client, model, temp| {
+                                let mut question_agent = client
+                                    .agent(model)
+                                    .preamble("You are a research assistant with web search and scraping tools. Use 1-3 targeted searches to find relevant information, then provide a comprehensive answer. Do not make excessive tool calls - synthesize your findings efficiently.")
+                                    .tool(search_tool.clone())
+                                    .tool(scrape_tool.clone())
+                                    .build();
+                                question_agent.temperature = temp;
+                                Box::pin(clients.schedule_on(
+                                    spec.provider,
+                                    run_agent_prompt_task(
+                                        name,
+                                        filename,
+                                        output_dir.clone(),
+                                        question_agent,
+                                        prompt.clone(),
+                                        counter.clone(),
+                                        total,
+                                        start_time,
+                                        cancelled.clone(),
+                                        observer,
+                                        clients.config.tool_budget(ResearchTask::Questions),
+                                    ),
+                                ))
+                            });
+                        future
+                    },
+                )));
+            }
+            _ => {
+                let (topic_owned, repository_context) = (&topic_owned, &repository_context);
+                let (pkg_mgr, lang, pkg_url) = (&pkg_mgr, &lang, &pkg_url);
+                let template = prompt_set.additional_question.get();
+
+                phase1_futures.push(Box::pin(failover_task(
+                    name,
+                    specs,
+                    counter.clone(),
+                    observer.clone(),
+                    move |spec, observer| {
+                        let future: TaskFuture<'_> =
+                            with_model!(clients, spec = spec, |client, model, temp| {
+                                Box::pin(clients.schedule_on(
+                                    spec.provider,
+                                    run_question_task(
+                                        question_num,
+                                        template,
+                                        topic_owned.clone(),
+                                        question.clone(),
+                                        repository_context.clone(),
+                                        pkg_mgr.clone(),
+                                        lang.clone(),
+                                        pkg_url.clone(),
+                                        output_dir.clone(),
+                                        TunedModel::new(client.completion_model(model), temp),
+                                        counter.clone(),
+                                        total,
+                                        start_time,
+                                        cancelled.clone(),
+                                        observer,
+                                    ),
+                                ))
+                            });
+                        future
+                    },
+                )));
+            }
         }
     }
 
     // Run all Phase 1 tasks in parallel
     let phase1_results = join_all(phase1_futures).await;
 
-    let phase1_succeeded: Vec<_> = phase1_results
+    let phase1_succeeded: Vec<_> = restored
         .iter()
-        .filter_map(|r| r.metrics.as_ref())
+        .map(|(_, metrics)| metrics)
+        .chain(phase1_results.iter().filter_map(|r| r.metrics.as_ref()))
         .collect();
-    let phase1_failed = phase1_results.len() - phase1_succeeded.len();
+    let phase1_total = restored.len() + phase1_results.len();
+    let phase1_failed = phase1_total - phase1_succeeded.len();
 
     // Check if cancelled
     let was_cancelled = cancelled.load(Ordering::SeqCst);
@@ -3842,12 +5857,12 @@ pub async fn research(
     // Exit the phase 1 span
     drop(_phase1_guard);
 
-    println!(
-        "\nPhase 1 complete: {}/{} succeeded{}\n",
-        phase1_succeeded.len(),
-        phase1_results.len(),
-        if was_cancelled { " (cancelled)" } else { "" }
-    );
+    observer.on_phase_complete(&PhaseSummary {
+        phase: ResearchPhase::Research,
+        succeeded: phase1_succeeded.len(),
+        total: phase1_total,
+        cancelled: was_cancelled,
+    });
 
     if phase1_succeeded.is_empty() {
         return Err(ResearchError::AllPromptsFailed);
@@ -3855,16 +5870,15 @@ pub async fn research(
 
     // Check if too many Phase 1 prompts failed (require at least 50% success or all 5 core prompts)
     let core_prompts = 5; // overview, similar_libraries, integration_partners, use_cases, changelog
-    let min_required = core_prompts.min(phase1_results.len() / 2 + 1);
+    let min_required = core_prompts.min(phase1_total / 2 + 1);
     if phase1_succeeded.len() < min_required {
-        println!(
-            "⚠ Too many Phase 1 prompts failed ({}/{}). Stopping before Phase 2.",
-            phase1_failed,
-            phase1_results.len()
-        );
+        observer.on_warning(&format!(
+            "Too many Phase 1 prompts failed ({}/{}). Stopping before Phase 2.",
+            phase1_failed, phase1_total
+        ));
         return Err(ResearchError::TooManyPromptsFailed {
             succeeded: phase1_succeeded.len(),
-            total: phase1_results.len(),
+            total: phase1_total,
         });
     }
 
@@ -3891,7 +5905,7 @@ pub async fn research(
     // === Phase 2: Read initial documents and generate consolidated outputs ===
     let _phase2_guard = info_span!("phase_2").entered();
     info!("Generating consolidated outputs");
-    println!("Phase 2: Generating consolidated outputs...\n");
+    observer.on_info("Phase 2: Generating consolidated outputs...\n");
 
     // Read back the initial documents
     let overview_content = fs::read_to_string(output_dir.join("overview.md"))
@@ -3913,20 +5927,22 @@ pub async fn research(
 
     // Read additional question files and build additional content
     let mut additional_content = String::new();
-    for i in 1..=num_questions {
-        let filename = format!("question_{}.md", i);
+    for (i, question) in questions.iter().enumerate() {
+        let filename = question.filename(i + 1);
         if let Ok(content) = fs::read_to_string(output_dir.join(&filename)).await
             && !content.is_empty()
         {
             additional_content.push_str(&format!(
                 "\n## Additional Research: Question {}\n\n{}\n",
-                i, content
+                answer_label(&filename),
+                content
             ));
         }
     }
 
     // Build context from phase 1 results
     let combined_context = prompts::CONTEXT
+        .get()
         .replace("{{topic}}", topic)
         .replace("{{overview}}", &overview_content)
         .replace("{{similar_libraries}}", &similar_libraries_content)
@@ -3937,18 +5953,44 @@ pub async fn research(
 
     // Build prompts for phase 2 from templates
     let deep_dive_prompt = prompts::DEEP_DIVE
+        .get()
         .replace("{{topic}}", topic)
         .replace("{{context}}", &combined_context);
 
-    // Get fresh model instance for deep_dive
-    let deep_dive_gen = openai.completion_model("gpt-5.2");
-
     let phase2_counter = Arc::new(AtomicUsize::new(0));
     let phase2_start = Instant::now();
     let deep_dive_filename = format!("deep-dive/{}.md", topic);
+    let deep_dive_task = failover_task(
+        "deep_dive",
+        clients.failover_specs(ResearchTask::DeepDive),
+        phase2_counter.clone(),
+        observer.clone(),
+        |spec, observer| {
+            let future: TaskFuture<'_> =
+                with_model!(clients, spec = spec, |client, model, temp| {
+                    Box::pin(clients.schedule_on(
+                        spec.provider,
+                        dispatch_prompt_task(
+                            streaming.clone(),
+                            "deep_dive",
+                            &deep_dive_filename,
+                            output_dir.clone(),
+                            TunedModel::new(client.completion_model(model), temp),
+                            deep_dive_prompt.clone(),
+                            phase2_counter.clone(),
+                            2,
+                            phase2_start,
+                            cancelled.clone(),
+                            observer,
+                        ),
+                    ))
+                });
+            future
+        },
+    );
 
     // Create a temporary metadata struct for skill generation to update
-    let mut temp_metadata = ResearchMetadata::new_library(library_info.as_ref());
+    let mut temp_metadata = ResearchMetadata::new_for_kind(kind, library_info.as_ref());
 
     // Run phase 2 prompts in parallel
     let (skill_metrics_result, deep_dive_result) = tokio::join!(
@@ -3956,25 +5998,17 @@ pub async fn research(
             topic,
             &output_dir,
             &combined_context,
-            &openai,
+            &clients,
             cancelled.clone(),
+            observer.clone(),
             &mut temp_metadata,
         ),
-        run_prompt_task(
-            "deep_dive",
-            &deep_dive_filename,
-            output_dir.clone(),
-            deep_dive_gen,
-            deep_dive_prompt,
-            phase2_counter.clone(),
-            2,
-            phase2_start,
-            cancelled.clone(),
-        ),
+        deep_dive_task,
     );
 
-    // Extract when_to_use from temporary metadata
+    // Extract when_to_use and the skill's model from temporary metadata
     let when_to_use = temp_metadata.when_to_use;
+    let skill_generated_by = temp_metadata.generated_by;
 
     // Normalize deep-dive/{topic}.md if it was generated
     let deep_dive_path = output_dir.join(format!("deep-dive/{}.md", topic));
@@ -3982,57 +6016,45 @@ pub async fn research(
         && let Ok(content) = fs::read_to_string(&deep_dive_path).await
     {
         let normalized = normalize_markdown(&content);
-        if let Err(e) = fs::write(&deep_dive_path, normalized).await {
+        if let Err(e) = write_generated(&deep_dive_path, &normalized, observer.as_ref()).await {
             tracing::error!("Failed to normalize deep-dive/{}.md: {}", topic, e);
         }
     }
 
     // === Phase 2b: Generate brief from deep_dive (if successful) ===
     let (brief_text, summary_text) = if deep_dive_result.metrics.is_some() {
-        println!("Generating brief summary...\n");
+        observer.on_info("Generating brief summary...\n");
 
         // Read the deep_dive content
         let deep_dive_content = fs::read_to_string(&deep_dive_path)
             .await
             .unwrap_or_default();
 
-        let brief_prompt = prompts::BRIEF
-            .replace("{{topic}}", topic)
-            .replace("{{deep_dive}}", &deep_dive_content);
-
-        let brief_model = gemini.completion_model("gemini-3-flash-preview");
-
-        match brief_model.completion_request(&brief_prompt).send().await {
-            Ok(response) => {
-                let content: String = response
-                    .choice
-                    .into_iter()
-                    .filter_map(|c| match c {
-                        AssistantContent::Text(text) => Some(text.text),
-                        _ => None,
-                    })
-                    .collect::<Vec<_>>()
-                    .join("\n");
-
+        match request_brief(topic, &deep_dive_content, &clients).await {
+            Ok(content) => {
                 let (brief, summary) = parse_brief_response(&content);
 
                 // Write brief.md file
                 if let (Some(b), Some(s)) = (&brief, &summary) {
-                    let repo_url = library_info
+                    let repository = library_info
                         .as_ref()
-                        .and_then(|li| li.repository.as_ref())
-                        .map(|r| format!("repo: {}\n", r))
-                        .unwrap_or_default();
-
-                    let brief_content = format!("---\nsummary: {}\n{}---\n\n{}", b, repo_url, s);
+                        .and_then(|li| li.repository.as_deref());
+                    let brief_content = brief_document(b, s, repository);
                     let _ = fs::write(output_dir.join("brief.md"), brief_content).await;
-                    println!("[3/3] brief ✓");
+                    report_task(
+                        observer.as_ref(),
+                        "brief",
+                        3,
+                        3,
+                        phase2_start.elapsed().as_secs_f32(),
+                        TaskOutcome::Succeeded { metrics: None },
+                    );
                 }
 
                 (brief, summary)
             }
             Err(e) => {
-                eprintln!("Warning: Failed to generate brief: {}", e);
+                observer.on_warning(&format!("Failed to generate brief: {}", e));
                 (None, None)
             }
         }
@@ -4043,6 +6065,7 @@ pub async fn research(
     // Convert skill_metrics_result to PromptTaskResult for metrics aggregation
     let skill_result = PromptTaskResult {
         metrics: skill_metrics_result.ok().flatten(),
+        model: None,
     };
 
     let phase2_results = [skill_result, deep_dive_result];
@@ -4055,12 +6078,12 @@ pub async fn research(
     // Check if cancelled during phase 2
     let was_cancelled = cancelled.load(Ordering::SeqCst);
 
-    println!(
-        "\nPhase 2 complete: {}/{} succeeded{}",
-        phase2_succeeded.len(),
-        phase2_results.len(),
-        if was_cancelled { " (cancelled)" } else { "" }
-    );
+    observer.on_phase_complete(&PhaseSummary {
+        phase: ResearchPhase::Synthesis,
+        succeeded: phase2_succeeded.len(),
+        total: phase2_results.len(),
+        cancelled: was_cancelled,
+    });
 
     // Aggregate all metrics
     let total_time = start_time.elapsed().as_secs_f32();
@@ -4073,53 +6096,324 @@ pub async fn research(
     let total_tokens: u64 = all_metrics.iter().map(|m| m.total_tokens).sum();
 
     // Write metadata.json
-    let mut metadata = ResearchMetadata::new_library(library_info.as_ref());
+    let mut metadata = ResearchMetadata::new_for_kind(kind, library_info.as_ref());
     metadata.brief = brief_text;
     metadata.summary = summary_text;
     metadata.when_to_use = when_to_use;
     for (i, question) in questions.iter().enumerate() {
-        let filename = format!("question_{}.md", i + 1);
-        metadata.add_additional_file(filename, question.clone());
+        metadata.add_additional_file(question.filename(i + 1), question.text.clone());
     }
+    metadata.generated_by = skill_generated_by;
+    record_generated_by(&mut metadata, &phase1_files, &phase1_results);
+    record_generated_by(
+        &mut metadata,
+        std::slice::from_ref(&deep_dive_filename),
+        &phase2_results[1..],
+    );
     if let Err(e) = metadata.save(&output_dir).await {
-        eprintln!("Warning: Failed to write metadata.json: {}", e);
+        observer.on_warning(&format!("Failed to write metadata.json: {}", e));
     } else if metadata.when_to_use.is_some() {
         tracing::info!("✓ Updated metadata.when_to_use");
     }
+    if !was_cancelled {
+        recorder.finish();
+    }
 
     // Exit the phase 2 span
     drop(_phase2_guard);
 
-    info!(
-        total_time_secs = total_time,
-        total_tokens,
-        succeeded = phase1_succeeded.len() + phase2_succeeded.len(),
-        failed = phase1_failed + phase2_failed,
-        "Research complete"
-    );
+    info!(
+        total_time_secs = total_time,
+        total_tokens,
+        succeeded = phase1_succeeded.len() + phase2_succeeded.len(),
+        failed = phase1_failed + phase2_failed,
+        "Research complete"
+    );
+
+    Ok(ResearchResult {
+        topic: topic.to_string(),
+        output_dir,
+        succeeded: phase1_succeeded.len() + phase2_succeeded.len(),
+        failed: phase1_failed + phase2_failed,
+        cancelled: was_cancelled,
+        total_time_secs: total_time,
+        total_input_tokens: total_input,
+        total_output_tokens: total_output,
+        total_tokens,
+    })
+}
+
+/// Research a library and run completion hooks when it finishes.
+///
+/// Behaves exactly like [`research`], then runs the hooks configured in
+/// `options` (webhook, shell command, spoken announcement) against the
+/// result. Hooks only run when research returns a result (including a
+/// cancelled one); hook failures are logged as warnings and never turn a
+/// successful run into an error.
+///
+/// Progress is reported to `options.observer`, or printed to the console
+/// by [`observer::ConsoleObserver`] when none is set.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use research_lib::{ResearchOptions, hooks::CompletionHooks, research_with_options};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let options = ResearchOptions::default().with_hooks(
+///         CompletionHooks::default()
+///             .with_webhook("https://hooks.slack.com/services/T000/B000/XXXX")
+///             .with_announcement(),
+///     );
+///     let result = research_with_options("clap", None, &[], false, false, &options).await?;
+///     println!("Research saved to {:?}", result.output_dir);
+///     Ok(())
+/// }
+/// ```
+pub async fn research_with_options(
+    topic: &str,
+    output_dir: Option<PathBuf>,
+    questions: &[Question],
+    skill_regenerate: bool,
+    force_recreation: bool,
+    options: &ResearchOptions,
+) -> Result<ResearchResult, ResearchError> {
+    let observer = options
+        .observer
+        .clone()
+        .unwrap_or_else(|| Arc::new(ConsoleObserver));
+    let output_dir = output_dir.or_else(|| {
+        options
+            .paths
+            .as_ref()
+            .map(|paths| paths.topic_dir(options.kind, topic))
+    });
+    let result = run_research(
+        topic,
+        output_dir,
+        questions,
+        skill_regenerate,
+        force_recreation,
+        options.kind,
+        &options.selection,
+        options.ecosystem,
+        options.repository.clone(),
+        options.streaming.clone(),
+        options.models.clone(),
+        options.scheduler.clone(),
+        observer.clone(),
+    )
+    .await?;
+
+    for error in hooks::run_completion_hooks(&options.hooks, &result).await {
+        warn!(error = %error, "Completion hook failed");
+        observer.on_warning(&format!("Completion hook failed: {}", error));
+    }
+
+    Ok(result)
+}
+
+/// Continues a research run that was interrupted before it finished.
+///
+/// Reads the checkpoint a full research run keeps in `output_dir` (see
+/// [`checkpoint`]) and picks up where the run stopped: the library, ecosystem
+/// and questions chosen for it are reused, Phase 1 tasks that already
+/// completed are kept, the rest run again, and Phase 2 runs from the full set
+/// of Phase 1 documents. Completion hooks in `options` run as for
+/// [`research_with_options`]; its `kind` and `ecosystem` are ignored in favour
+/// of the checkpoint's.
+///
+/// ## Errors
+///
+/// Returns [`ResearchError::NoCheckpoint`] if `output_dir` has no checkpoint
+/// to resume, or any error [`research_with_options`] can return.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use research_lib::{ResearchOptions, default_output_dir, resume};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let result = resume(&default_output_dir("clap"), &ResearchOptions::default()).await?;
+///     println!("{} task(s) succeeded", result.succeeded);
+///     Ok(())
+/// }
+/// ```
+#[instrument(name = "resume", skip(options), fields(output_dir = %output_dir.display()))]
+pub async fn resume(
+    output_dir: &Path,
+    options: &ResearchOptions,
+) -> Result<ResearchResult, ResearchError> {
+    dotenvy::dotenv().ok();
+
+    let checkpoint = Checkpoint::load(output_dir)
+        .ok_or_else(|| ResearchError::NoCheckpoint(output_dir.to_path_buf()))?;
+    let models = match options.models.clone() {
+        Some(models) => models,
+        None => ModelConfig::load()?,
+    };
+    let observer = options
+        .observer
+        .clone()
+        .unwrap_or_else(|| Arc::new(ConsoleObserver));
+    observer.on_info(&format!(
+        "Resuming research for '{}' started {}",
+        checkpoint.topic,
+        checkpoint.started_at.format("%Y-%m-%d %H:%M UTC")
+    ));
+
+    let result = run_full_research_logged(
+        checkpoint,
+        output_dir.to_path_buf(),
+        options.streaming.clone(),
+        models,
+        options.scheduler.clone(),
+        observer.clone(),
+    )
+    .await?;
+
+    for error in hooks::run_completion_hooks(&options.hooks, &result).await {
+        warn!(error = %error, "Completion hook failed");
+        observer.on_warning(&format!("Completion hook failed: {}", error));
+    }
+
+    Ok(result)
+}
+
+/// The outcome of researching one topic in [`research_many`].
+#[derive(Debug)]
+pub struct TopicResearch {
+    pub topic: String,
+    pub result: Result<ResearchResult, ResearchError>,
+}
+
+/// Per-topic outcomes of [`research_many`], in the order the topics were given.
+#[derive(Debug, Default)]
+pub struct ManyResearchResult {
+    pub topics: Vec<TopicResearch>,
+    pub total_time_secs: f32,
+}
+
+impl ManyResearchResult {
+    /// Returns the results of the topics that were researched.
+    pub fn succeeded(&self) -> impl Iterator<Item = &ResearchResult> {
+        self.topics
+            .iter()
+            .filter_map(|topic| topic.result.as_ref().ok())
+    }
+
+    /// Returns the topics that failed, with their errors.
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &ResearchError)> {
+        self.topics.iter().filter_map(|topic| {
+            topic
+                .result
+                .as_ref()
+                .err()
+                .map(|error| (topic.topic.as_str(), error))
+        })
+    }
+
+    /// Returns the tokens used across all successful topics.
+    pub fn total_tokens(&self) -> u64 {
+        self.succeeded().map(|result| result.total_tokens).sum()
+    }
+}
+
+/// Researches several topics at once, sharing one [`ResearchScheduler`].
+///
+/// Every topic runs concurrently through [`research_with_options`], writing
+/// to its default output directory, and asks the same `questions`. Model
+/// requests from all topics share the scheduler's global and per-provider
+/// limits: the one in `options`, or [`ResearchScheduler::default`] when unset.
+///
+/// A topic that fails doesn't stop the others; its error is kept in the
+/// returned [`ManyResearchResult`]. Repeated topics are researched once,
+/// at the position they first appear.
+///
+/// Concurrent topics can't share the terminal, so
+/// [`SelectionPolicy::Interactive`] (the default) is replaced with
+/// [`SelectionPolicy::Fail`]: a topic found on several package managers
+/// fails with [`ResearchError::AmbiguousLibrary`] instead of prompting. Set
+/// [`SelectionPolicy::First`] or [`SelectionPolicy::PreferLanguage`] on
+/// `options` to have such topics picked automatically.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::sync::Arc;
+///
+/// use research_lib::scheduler::ResearchScheduler;
+/// use research_lib::{ResearchOptions, research_many};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let options =
+///         ResearchOptions::default().with_scheduler(Arc::new(ResearchScheduler::new(6)));
+///     let results = research_many(&["clap", "serde", "tokio"], &[], false, false, &options).await;
+///     for (topic, error) in results.failed() {
+///         eprintln!("{topic}: {error}");
+///     }
+/// }
+/// ```
+pub async fn research_many(
+    topics: &[&str],
+    questions: &[Question],
+    skill_regenerate: bool,
+    force_recreation: bool,
+    options: &ResearchOptions,
+) -> ManyResearchResult {
+    let start_time = Instant::now();
+    let options = &many_topic_options(options);
+
+    let mut seen = HashSet::new();
+    let topics = topics.iter().copied().filter(|&topic| seen.insert(topic));
+    let topics = join_all(topics.map(|topic| async move {
+        let result = research_with_options(
+            topic,
+            None,
+            questions,
+            skill_regenerate,
+            force_recreation,
+            options,
+        )
+        .await;
+        if let Err(error) = &result {
+            warn!(topic, error = %error, "Topic research failed");
+        }
+        TopicResearch {
+            topic: topic.to_string(),
+            result,
+        }
+    }))
+    .await;
+
+    ManyResearchResult {
+        topics,
+        total_time_secs: start_time.elapsed().as_secs_f32(),
+    }
+}
 
-    Ok(ResearchResult {
-        topic: topic.to_string(),
-        output_dir,
-        succeeded: phase1_succeeded.len() + phase2_succeeded.len(),
-        failed: phase1_failed + phase2_failed,
-        cancelled: was_cancelled,
-        total_time_secs: total_time,
-        total_input_tokens: total_input,
-        total_output_tokens: total_output,
-        total_tokens,
-    })
+/// Returns the options [`research_many`] runs every topic with: a shared
+/// default scheduler when none is set, and no interactive prompts.
+fn many_topic_options(options: &ResearchOptions) -> ResearchOptions {
+    let mut options = options.clone();
+    if options.scheduler.is_none() {
+        options.scheduler = Some(Arc::new(ResearchScheduler::default()));
+    }
+    if options.selection == SelectionPolicy::Interactive {
+        options.selection = SelectionPolicy::Fail;
+    }
+    options
 }
 
 /// Returns the default output directory for API research.
 ///
-/// Uses the `RESEARCH_DIR` environment variable if set, otherwise falls back to `$HOME`.
-/// The full path is: `${RESEARCH_DIR:-$HOME}/.research/api/{api_name}`
+/// The full path is `{root}/api/{api_name}`, where `{root}` comes from
+/// [`ResearchPaths::from_env`].
 pub fn default_api_output_dir(api_name: &str) -> PathBuf {
-    let base = std::env::var("RESEARCH_DIR")
-        .map(PathBuf::from)
-        .unwrap_or_else(|_| dirs::home_dir().unwrap_or_else(|| PathBuf::from(".")));
-    base.join(".research").join("api").join(api_name)
+    default_output_dir_for(ResearchKind::Api, api_name)
 }
 
 /// Research a public API.
@@ -4157,7 +6451,7 @@ pub fn default_api_output_dir(api_name: &str) -> PathBuf {
 pub async fn research_api(
     api_name: &str,
     output_dir: Option<PathBuf>,
-    questions: &[String],
+    questions: &[Question],
     force_recreation: bool,
 ) -> Result<ResearchResult, ResearchError> {
     info!("Starting API research session");
@@ -4181,18 +6475,7 @@ pub async fn research_api(
     }
 
     // Create initial metadata with Api kind
-    let now = Utc::now();
-    let metadata = ResearchMetadata {
-        schema_version: 1,
-        kind: ResearchKind::Api,
-        details: metadata::ResearchDetails::Api(metadata::ApiDetails::default()),
-        additional_files: std::collections::HashMap::new(),
-        created_at: now,
-        updated_at: now,
-        brief: None,
-        summary: None,
-        when_to_use: None,
-    };
+    let metadata = ResearchMetadata::new_for_kind(ResearchKind::Api, None);
 
     // Save initial metadata
     metadata.save(&output_dir).await?;
@@ -4231,6 +6514,74 @@ mod tests {
     use super::*;
     use tempfile::tempdir;
 
+    // ===========================================
+    // Tests for brief regeneration
+    // ===========================================
+
+    #[test]
+    fn test_brief_document_includes_repository() {
+        let doc = brief_document(
+            "Async runtime",
+            "Tokio runs futures.",
+            Some("https://github.com/tokio-rs/tokio"),
+        );
+        assert_eq!(
+            doc,
+            "---\nsummary: Async runtime\nrepo: https://github.com/tokio-rs/tokio\n---\n\nTokio runs futures."
+        );
+
+        let doc = brief_document("Async runtime", "Tokio runs futures.", None);
+        assert_eq!(
+            doc,
+            "---\nsummary: Async runtime\n---\n\nTokio runs futures."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_deep_dive_prefers_new_layout() {
+        let temp = tempdir().unwrap();
+        assert_eq!(read_deep_dive(temp.path(), "tokio").await, None);
+
+        std::fs::write(temp.path().join("deep_dive.md"), "legacy").unwrap();
+        assert_eq!(
+            read_deep_dive(temp.path(), "tokio").await.as_deref(),
+            Some("legacy")
+        );
+
+        std::fs::create_dir(temp.path().join("deep-dive")).unwrap();
+        std::fs::write(temp.path().join("deep-dive/tokio.md"), "  \n").unwrap();
+        assert_eq!(
+            read_deep_dive(temp.path(), "tokio").await.as_deref(),
+            Some("legacy")
+        );
+
+        std::fs::write(temp.path().join("deep-dive/tokio.md"), "current").unwrap();
+        assert_eq!(
+            read_deep_dive(temp.path(), "tokio").await.as_deref(),
+            Some("current")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_regenerate_brief_requires_metadata_and_deep_dive() {
+        let temp = tempdir().unwrap();
+        let options = ResearchOptions::default().with_models(ModelConfig::default());
+
+        let result = regenerate_brief_in("tokio", temp.path(), &options).await;
+        assert!(matches!(result, Err(ResearchError::NoMetadata(_))));
+
+        ResearchMetadata::new_library(None)
+            .save(temp.path())
+            .await
+            .unwrap();
+        let result = regenerate_brief_in("tokio", temp.path(), &options).await;
+        assert!(matches!(
+            result,
+            Err(ResearchError::BriefRegenerationFailed(message)) if message.contains("No deep dive")
+        ));
+        assert!(!temp.path().join("brief.md").exists());
+    }
+
     // ===========================================
     // Tests for ResearchMetadata
     // ===========================================
@@ -4287,6 +6638,99 @@ mod tests {
         assert!(metadata.updated_at >= original_updated);
     }
 
+    #[test]
+    fn test_metadata_set_question_tags_normalizes() {
+        let mut metadata = ResearchMetadata::new_library(None);
+        metadata.add_additional_file("question_1.md".to_string(), "Q1".to_string());
+        metadata.add_additional_file("question_2.md".to_string(), "Q2".to_string());
+
+        metadata.set_question_tags(
+            "question_1.md",
+            &[
+                "Reliability".to_string(),
+                "error handling".to_string(),
+                "reliability".to_string(),
+                "../bad".to_string(),
+            ],
+        );
+        metadata.set_question_tags("question_2.md", &["async".to_string()]);
+
+        assert_eq!(
+            metadata.question_tags["question_1.md"],
+            vec!["error-handling".to_string(), "reliability".to_string()]
+        );
+        assert_eq!(
+            metadata.tags(),
+            vec![
+                "async".to_string(),
+                "error-handling".to_string(),
+                "reliability".to_string()
+            ]
+        );
+
+        // An empty tag list removes the entry
+        metadata.set_question_tags("question_2.md", &[]);
+        assert!(!metadata.question_tags.contains_key("question_2.md"));
+    }
+
+    #[test]
+    fn test_metadata_add_and_remove_topic_tags() {
+        let mut metadata = ResearchMetadata::new_library(None);
+        metadata.set_question_tags("question_1.md", &["reliability".to_string()]);
+
+        let added = metadata.add_tags(&[
+            "Networking".to_string(),
+            "async".to_string(),
+            "../bad".to_string(),
+        ]);
+        assert_eq!(added, vec!["networking".to_string(), "async".to_string()]);
+        assert_eq!(
+            metadata.tags,
+            vec!["async".to_string(), "networking".to_string()]
+        );
+
+        // Re-adding an existing tag adds nothing
+        assert!(metadata.add_tags(&["ASYNC".to_string()]).is_empty());
+
+        let removed = metadata.remove_tags(&["networking".to_string(), "missing".to_string()]);
+        assert_eq!(removed, vec!["networking".to_string()]);
+        assert_eq!(metadata.tags, vec!["async".to_string()]);
+
+        // tags() covers the topic and its questions
+        assert_eq!(
+            metadata.tags(),
+            vec!["async".to_string(), "reliability".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_metadata_category_round_trip() {
+        let mut metadata = ResearchMetadata::new_library(None);
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert!(!json.contains("category"));
+        assert!(!json.contains("\"tags\""));
+
+        metadata.set_category(Some("Web Frameworks"));
+        metadata.add_tags(&["async".to_string()]);
+        let json = serde_json::to_string(&metadata).unwrap();
+        let loaded: ResearchMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.category.as_deref(), Some("web-frameworks"));
+        assert_eq!(loaded.tags, vec!["async".to_string()]);
+
+        metadata.set_category(None);
+        assert!(metadata.category.is_none());
+    }
+
+    #[test]
+    fn test_metadata_question_tags_omitted_when_empty() {
+        let metadata = ResearchMetadata::new_library(None);
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert!(!json.contains("question_tags"));
+
+        let parsed: ResearchMetadata = serde_json::from_str(&json).unwrap();
+        assert!(parsed.question_tags.is_empty());
+    }
+
     #[test]
     fn test_metadata_next_question_number_empty() {
         let metadata = ResearchMetadata::new_library(None);
@@ -4519,6 +6963,7 @@ mod tests {
     #[test]
     fn test_standard_prompts_all_have_content() {
         for (name, filename, template) in STANDARD_PROMPTS {
+            let template = template.embedded();
             assert!(!name.is_empty(), "Name should not be empty");
             assert!(filename.ends_with(".md"), "Filename should end with .md");
             assert!(!template.is_empty(), "Template should not be empty");
@@ -4604,11 +7049,315 @@ mod tests {
     #[test]
     fn test_default_output_dir_structure() {
         let dir = default_output_dir("tokio");
-        let path_str = dir.to_string_lossy();
 
-        assert!(path_str.contains(".research"));
-        assert!(path_str.contains("library"));
-        assert!(path_str.contains("tokio"));
+        assert!(dir.starts_with(ResearchPaths::from_env().root()));
+        assert!(dir.ends_with("library/tokio"));
+    }
+
+    #[test]
+    fn test_default_output_dir_for_kind() {
+        let paths = ResearchPaths::from_env();
+
+        let dir = default_output_dir_for(ResearchKind::Software, "ripgrep");
+        assert_eq!(dir, paths.root().join("software").join("ripgrep"));
+
+        let dir = default_output_dir_for(ResearchKind::Standard, "oauth2");
+        assert_eq!(dir, paths.root().join("standard").join("oauth2"));
+
+        assert_eq!(
+            default_api_output_dir("stripe"),
+            default_output_dir_for(ResearchKind::Api, "stripe")
+        );
+    }
+
+    #[test]
+    fn test_research_options_with_paths() {
+        let options = ResearchOptions::default().with_paths(ResearchPaths::new("/srv/research"));
+        assert_eq!(
+            options.paths.map(|paths| paths.topic_dir(ResearchKind::Library, "clap")),
+            Some(PathBuf::from("/srv/research/library/clap"))
+        );
+    }
+
+    #[test]
+    fn test_tool_cache_location() {
+        if std::env::var("RESEARCH_CACHE_TTL_HOURS").is_ok() {
+            return;
+        }
+        let cache = tool_cache().unwrap();
+        assert_eq!(cache.dir(), ResearchPaths::from_env().cache_dir());
+        assert_eq!(cache.ttl(), Duration::from_secs(24 * 60 * 60));
+    }
+
+    /// Registry that finds every name, without touching the network.
+    struct FakeRegistry(&'static str);
+
+    impl Registry for FakeRegistry {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        fn lookup<'a>(
+            &'a self,
+            _client: &'a HttpClient,
+            name: &'a str,
+        ) -> futures::future::BoxFuture<'a, Option<LibraryInfo>> {
+            Box::pin(async move {
+                (self.0 != "empty").then(|| LibraryInfo {
+                    package_manager: self.0.to_string(),
+                    language: "Test".to_string(),
+                    url: format!("https://{}/{}", self.0, name),
+                    repository: None,
+                    description: None,
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_library_in_keeps_registry_order() {
+        let registries: Vec<Box<dyn Registry>> = vec![
+            Box::new(FakeRegistry("second.example")),
+            Box::new(FakeRegistry("empty")),
+            Box::new(FakeRegistry("first.example")),
+        ];
+
+        let found = find_library_in(&registries, "widget").await;
+
+        let urls: Vec<&str> = found.iter().map(|lib| lib.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://second.example/widget",
+                "https://first.example/widget"
+            ]
+        );
+        assert!(find_library_in(&[], "widget").await.is_empty());
+    }
+
+    fn ambiguous_matches() -> Vec<LibraryInfo> {
+        [("npm", "JavaScript/TypeScript"), ("crates.io", "Rust")]
+            .into_iter()
+            .map(|(package_manager, language)| LibraryInfo {
+                package_manager: package_manager.to_string(),
+                language: language.to_string(),
+                url: format!("https://{}/widget", package_manager),
+                repository: None,
+                description: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_selection_policy_resolves_multiple_matches() {
+        let selected = |policy: SelectionPolicy| {
+            let selection =
+                select_library_with(ambiguous_matches(), "widget", &policy, &ConsoleObserver);
+            match selection {
+                LibrarySelection::Selected(lib) => lib.package_manager,
+                other => panic!("expected a selection, got {:?}", other),
+            }
+        };
+
+        assert_eq!(selected(SelectionPolicy::First), "npm");
+        assert_eq!(selected("RUST".parse().unwrap()), "crates.io");
+        assert_eq!(selected("typescript".parse().unwrap()), "npm");
+        // No match in the preferred language falls back to the first
+        assert_eq!(selected("python".parse().unwrap()), "npm");
+    }
+
+    #[test]
+    fn test_selection_reports_to_the_observer() {
+        #[derive(Default)]
+        struct Recorder(std::sync::Mutex<Vec<String>>);
+
+        impl ResearchObserver for Recorder {
+            fn on_warning(&self, message: &str) {
+                self.0.lock().unwrap().push(format!("warning {}", message));
+            }
+
+            fn on_info(&self, message: &str) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("info {}", message.trim()));
+            }
+        }
+
+        let recorder = Recorder::default();
+        select_library_with(Vec::new(), "widget", &SelectionPolicy::First, &recorder);
+        select_library_with(
+            ambiguous_matches(),
+            "widget",
+            &SelectionPolicy::First,
+            &recorder,
+        );
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            vec![
+                "warning 'widget' not found on any package manager (may be a general topic)",
+                "info ✓ Found 'widget' on several package managers; using npm (JavaScript/TypeScript)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_selection_policy_fail_reports_candidates() {
+        let selection = select_library_with(
+            ambiguous_matches(),
+            "widget",
+            &SelectionPolicy::Fail,
+            &ConsoleObserver,
+        );
+        let LibrarySelection::Ambiguous(libraries) = selection else {
+            panic!("expected Ambiguous, got {:?}", selection);
+        };
+
+        let err = ResearchError::ambiguous_library("widget", &libraries);
+        assert_eq!(
+            err.to_string(),
+            "'widget' matches several libraries (npm (JavaScript/TypeScript), crates.io (Rust)); \
+             choose one with a selection policy"
+        );
+
+        // A single match never needs the policy
+        let single = ambiguous_matches().into_iter().take(1).collect();
+        assert!(matches!(
+            select_library_with(single, "widget", &SelectionPolicy::Fail, &ConsoleObserver),
+            LibrarySelection::Single(_)
+        ));
+    }
+
+    #[test]
+    fn test_selection_policy_round_trips_through_strings() {
+        for policy in [
+            SelectionPolicy::Interactive,
+            SelectionPolicy::First,
+            SelectionPolicy::Fail,
+            SelectionPolicy::PreferLanguage("go".to_string()),
+        ] {
+            assert_eq!(policy.to_string().parse(), Ok(policy));
+        }
+    }
+
+    #[test]
+    fn test_github_repository_from_library_info() {
+        let info = |repository: Option<&str>| LibraryInfo {
+            package_manager: "crates.io".to_string(),
+            language: "Rust".to_string(),
+            url: "https://crates.io/crates/tokio".to_string(),
+            repository: repository.map(str::to_string),
+            description: None,
+        };
+
+        assert_eq!(
+            github_repository(Some(&info(Some("https://github.com/tokio-rs/tokio.git")))),
+            Some("tokio-rs/tokio".to_string())
+        );
+        assert_eq!(
+            github_repository(Some(&info(Some("https://gitlab.com/owner/repo")))),
+            None
+        );
+        assert_eq!(github_repository(Some(&info(None))), None);
+        assert_eq!(github_repository(None), None);
+    }
+
+    #[test]
+    fn test_docs_rs_crate_from_library_info() {
+        let info = |package_manager: &str, url: &str| LibraryInfo {
+            package_manager: package_manager.to_string(),
+            language: "Rust".to_string(),
+            url: url.to_string(),
+            repository: None,
+            description: None,
+        };
+
+        assert_eq!(
+            docs_rs_crate(Some(&info(
+                "crates.io",
+                "https://crates.io/crates/serde_json"
+            ))),
+            Some("serde_json".to_string())
+        );
+        assert_eq!(
+            docs_rs_crate(Some(&info("npm", "https://www.npmjs.com/package/serde"))),
+            None
+        );
+        assert_eq!(docs_rs_crate(None), None);
+    }
+
+    // ===========================================
+    // Tests for research kinds and prompt sets
+    // ===========================================
+
+    #[test]
+    fn test_research_kind_serializes_lowercase() {
+        for (kind, name) in [
+            (ResearchKind::Software, "\"software\""),
+            (ResearchKind::Standard, "\"standard\""),
+        ] {
+            assert_eq!(serde_json::to_string(&kind).unwrap(), name);
+            assert_eq!(serde_json::from_str::<ResearchKind>(name).unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn test_metadata_new_for_kind_details() {
+        use crate::metadata::ResearchDetails;
+
+        let metadata = ResearchMetadata::new_for_kind(ResearchKind::Software, None);
+        assert_eq!(metadata.kind, ResearchKind::Software);
+        assert!(matches!(metadata.details, ResearchDetails::Software(_)));
+        assert!(metadata.library_details().is_none());
+
+        let metadata = ResearchMetadata::new_for_kind(ResearchKind::Standard, None);
+        assert!(matches!(metadata.details, ResearchDetails::Standard(_)));
+    }
+
+    #[test]
+    fn test_prompt_sets_for_non_library_kinds_skip_package_context() {
+        for kind in [ResearchKind::Software, ResearchKind::Standard] {
+            let set = PromptSet::for_kind(kind);
+            for (name, _, _) in STANDARD_PROMPTS {
+                let template = set.template(name).unwrap();
+                assert!(template.contains("{{topic}}"), "{kind:?} {name}");
+                assert!(!template.contains("{{package_manager}}"), "{kind:?} {name}");
+            }
+            assert!(set.additional_question.embedded().contains("{{question}}"));
+        }
+    }
+
+    #[test]
+    fn test_library_prompt_set_matches_standard_prompts() {
+        let set = PromptSet::for_kind(ResearchKind::Library);
+        for (name, _, template) in STANDARD_PROMPTS {
+            assert_eq!(set.template(name), Some(template.get()));
+        }
+    }
+
+    #[test]
+    fn test_ecosystem_prompt_sets_keep_library_placeholders() {
+        for ecosystem in Ecosystem::ALL {
+            let set = PromptSet::for_topic(ResearchKind::Library, ecosystem);
+            for (name, _, _) in STANDARD_PROMPTS {
+                let template = set.template(name).unwrap();
+                assert!(template.contains("{{topic}}"), "{ecosystem} {name}");
+                assert!(template.contains("{{package_manager}}"), "{ecosystem} {name}");
+            }
+            assert!(set.additional_question.embedded().contains("{{question}}"));
+        }
+
+        let rust = PromptSet::for_topic(ResearchKind::Library, Ecosystem::Rust);
+        assert!(rust.overview.embedded().contains("cargo add"));
+        let generic = PromptSet::for_topic(ResearchKind::Library, Ecosystem::Generic);
+        assert_eq!(generic.overview, prompts::OVERVIEW);
+    }
+
+    #[test]
+    fn test_ecosystem_is_ignored_for_non_library_kinds() {
+        let set = PromptSet::for_topic(ResearchKind::Software, Ecosystem::Python);
+        assert_eq!(set.overview, prompts::software::OVERVIEW);
     }
 
     // ===========================================
@@ -4650,6 +7399,44 @@ mod tests {
         assert!(loaded.is_none());
     }
 
+    #[tokio::test]
+    async fn test_research_many_keeps_every_topic_outcome_in_order() {
+        // --skill with --force fails every topic before any request is made
+        let options = ResearchOptions::default().with_models(ModelConfig::default());
+        let results = research_many(&["clap", "serde"], &[], true, true, &options).await;
+
+        let topics: Vec<_> = results.topics.iter().map(|t| t.topic.as_str()).collect();
+        assert_eq!(topics, vec!["clap", "serde"]);
+        assert_eq!(results.failed().count(), 2);
+        assert!(
+            results
+                .failed()
+                .all(|(_, error)| matches!(error, ResearchError::InvalidFlagCombination(_)))
+        );
+        assert_eq!(results.succeeded().count(), 0);
+        assert_eq!(results.total_tokens(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_research_many_researches_repeated_topics_once() {
+        let options = ResearchOptions::default().with_models(ModelConfig::default());
+        let results = research_many(&["clap", "serde", "clap"], &[], true, true, &options).await;
+
+        let topics: Vec<_> = results.topics.iter().map(|t| t.topic.as_str()).collect();
+        assert_eq!(topics, vec!["clap", "serde"]);
+    }
+
+    #[test]
+    fn test_research_many_never_prompts_for_a_library() {
+        let options = many_topic_options(&ResearchOptions::default());
+        assert_eq!(options.selection, SelectionPolicy::Fail);
+        assert!(options.scheduler.is_some());
+
+        let options =
+            many_topic_options(&ResearchOptions::default().with_selection(SelectionPolicy::First));
+        assert_eq!(options.selection, SelectionPolicy::First);
+    }
+
     // ===========================================
     // Tests for PromptMetrics
     // ===========================================
@@ -4663,6 +7450,53 @@ mod tests {
         assert_eq!(metrics.elapsed_secs, 0.0);
     }
 
+    #[test]
+    fn test_tracing_hook_sums_usage_across_clones() {
+        let hook = TracingPromptHook::new("overview");
+        let agent_copy = hook.clone();
+
+        agent_copy.record_usage(100, 20, 120);
+        agent_copy.record_usage(300, 50, 350);
+        hook.record_usage(10, 5, 15);
+
+        let metrics = hook.usage(2.5);
+        assert_eq!(metrics.input_tokens, 410);
+        assert_eq!(metrics.output_tokens, 75);
+        assert_eq!(metrics.total_tokens, 485);
+        assert_eq!(metrics.elapsed_secs, 2.5);
+        assert_eq!(agent_copy.usage(2.5), metrics);
+    }
+
+    #[test]
+    fn test_tracing_hook_enforces_tool_budget_per_tool() {
+        let budget = ToolBudget {
+            max_turns: 15,
+            max_searches: 2,
+            max_scrapes: 1,
+        };
+        let hook = TracingPromptHook::new("use_cases").with_budget(budget);
+        let agent_copy = hook.clone();
+
+        assert!(agent_copy.record_tool_call("brave_search"));
+        assert!(agent_copy.record_tool_call("screen_scrape"));
+        assert!(agent_copy.record_tool_call("brave_search"));
+        assert!(agent_copy.record_tool_call("github_repo"));
+        assert_eq!(hook.exhausted_tool(), None);
+
+        assert!(!agent_copy.record_tool_call("screen_scrape"));
+        assert!(!agent_copy.record_tool_call("brave_search"));
+        assert_eq!(hook.exhausted_tool().as_deref(), Some("screen_scrape"));
+    }
+
+    #[test]
+    fn test_tracing_hook_without_budget_allows_every_call() {
+        let hook = TracingPromptHook::new("overview");
+        for _ in 0..50 {
+            assert!(hook.record_tool_call("brave_search"));
+        }
+        assert_eq!(hook.exhausted_tool(), None);
+    }
+
     // ===========================================
     // Tests for ResearchResult
     // ===========================================
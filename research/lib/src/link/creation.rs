@@ -22,6 +22,10 @@ pub enum CreationError {
     /// Failed to create the symlink itself.
     #[error("Failed to create symlink: {0}")]
     SymlinkCreation(#[source] std::io::Error),
+
+    /// Failed to remove a drifted symlink before re-creating it.
+    #[error("Failed to remove symlink: {0}")]
+    SymlinkRemoval(#[source] std::io::Error),
 }
 
 /// Validates that a skill directory exists and contains a SKILL.md file.
@@ -289,6 +293,55 @@ pub fn create_deep_dive_symlink(
     Ok(())
 }
 
+/// Re-points an existing symlink at `source`.
+///
+/// Used by sync mode to repair links whose target has drifted. The existing
+/// path must be a symlink; real files and directories are never removed.
+///
+/// # Arguments
+///
+/// * `source` - The skill directory or deep dive file the link should point to
+/// * `symlink_location` - The existing symlink to replace
+///
+/// # Errors
+///
+/// - `CreationError::InvalidSource` - Source missing, or the location is not a symlink
+/// - `CreationError::SymlinkRemoval` - Failed to remove the old symlink
+/// - `CreationError::SymlinkCreation` - Failed to create the new symlink
+#[instrument]
+pub fn repair_symlink(source: &Path, symlink_location: &Path) -> Result<(), CreationError> {
+    debug!("Repairing symlink: {:?} -> {:?}", symlink_location, source);
+
+    let is_symlink = symlink_location
+        .symlink_metadata()
+        .map(|m| m.is_symlink())
+        .unwrap_or(false);
+    if !is_symlink {
+        return Err(CreationError::InvalidSource(symlink_location.to_path_buf()));
+    }
+
+    let absolute_source = source
+        .canonicalize()
+        .map_err(|_| CreationError::InvalidSource(source.to_path_buf()))?;
+
+    fs::remove_file(symlink_location).map_err(CreationError::SymlinkRemoval)?;
+
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(&absolute_source, symlink_location)
+        .map_err(CreationError::SymlinkCreation)?;
+
+    #[cfg(not(unix))]
+    {
+        return Err(CreationError::SymlinkCreation(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "Symlink creation is only supported on Unix-like systems",
+        )));
+    }
+
+    debug!("Symlink repaired successfully");
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -669,4 +722,31 @@ mod tests {
         let expected = deep_dive.canonicalize().unwrap();
         assert_eq!(target, expected);
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_repair_symlink_repoints_link() {
+        let temp = TempDir::new().unwrap();
+        let old = create_valid_skill_dir(temp.path(), "old");
+        let new = create_valid_skill_dir(temp.path(), "new");
+        let link = temp.path().join("link");
+        std::os::unix::fs::symlink(&old, &link).unwrap();
+
+        repair_symlink(&new, &link).unwrap();
+
+        assert_eq!(fs::read_link(&link).unwrap(), new.canonicalize().unwrap());
+    }
+
+    #[test]
+    fn test_repair_symlink_refuses_real_directory() {
+        let temp = TempDir::new().unwrap();
+        let source = create_valid_skill_dir(temp.path(), "source");
+        let local = temp.path().join("local");
+        fs::create_dir(&local).unwrap();
+
+        let result = repair_symlink(&source, &local);
+
+        assert!(matches!(result, Err(CreationError::InvalidSource(_))));
+        assert!(local.is_dir());
+    }
 }
@@ -15,6 +15,7 @@
 //! - Paths are within expected directories (no arbitrary filesystem access)
 //! - Symlink targets are absolute paths (for robustness)
 
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::{debug, warn};
@@ -216,6 +217,115 @@ pub fn scan_and_remove_stale_symlinks(dir: &Path) -> StaleSymlinkScanResult {
     result
 }
 
+/// Scan a directory for symlinks into the research library whose targets are
+/// no longer linkable, and remove them.
+///
+/// A symlink is orphaned when it resolves to a path inside `research_root` that
+/// is not in `live_sources` - for example a skill directory left behind after
+/// its topic's metadata was deleted, or a deep dive whose topic was renamed.
+/// Symlinks pointing outside `research_root` and broken symlinks are left for
+/// the caller (see [`scan_and_remove_stale_symlinks`]).
+///
+/// # Arguments
+///
+/// * `dir` - The skill or docs directory to scan
+/// * `research_root` - The `$RESEARCH_DIR/.research` directory
+/// * `live_sources` - Canonical paths of every skill directory and deep dive
+///   that should stay linked
+pub fn scan_and_remove_orphaned_symlinks(
+    dir: &Path,
+    research_root: &Path,
+    live_sources: &HashSet<PathBuf>,
+) -> StaleSymlinkScanResult {
+    let mut result = StaleSymlinkScanResult::default();
+
+    let Ok(research_root) = research_root.canonicalize() else {
+        debug!(
+            "Research root does not exist, skipping orphan scan: {}",
+            research_root.display()
+        );
+        return result;
+    };
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            debug!(
+                "Failed to read directory for orphan scan: {}: {}",
+                dir.display(),
+                e
+            );
+            return result;
+        }
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !check_is_symlink(&path) {
+            continue;
+        }
+
+        // Broken symlinks are handled by the stale scan
+        let Ok(target) = path.canonicalize() else {
+            continue;
+        };
+
+        if !target.starts_with(&research_root) || live_sources.contains(&target) {
+            continue;
+        }
+
+        debug!(
+            "Found orphaned symlink: {} -> {}",
+            path.display(),
+            target.display()
+        );
+        match std::fs::remove_file(&path) {
+            Ok(()) => result.removed.push(path),
+            Err(e) => {
+                warn!(
+                    "Failed to remove orphaned symlink {}: {}",
+                    path.display(),
+                    e
+                );
+                result.failed.push((path, e.to_string()));
+            }
+        }
+    }
+
+    result
+}
+
+/// Check whether a working symlink points somewhere other than `source_path`.
+///
+/// Both sides are canonicalized before comparing, so relative and absolute
+/// links to the same place are not considered drift.
+///
+/// # Returns
+///
+/// The symlink's current (canonical) target if it differs from `source_path`,
+/// or `None` if the path is not a working symlink or already points at the
+/// source.
+pub fn check_symlink_drift(target_path: &Path, source_path: &Path) -> Option<PathBuf> {
+    if !check_is_symlink(target_path) {
+        return None;
+    }
+
+    let current = target_path.canonicalize().ok()?;
+    let expected = source_path.canonicalize().ok()?;
+
+    if current == expected {
+        None
+    } else {
+        debug!(
+            "Symlink {} drifted: points to {}, expected {}",
+            target_path.display(),
+            current.display(),
+            expected.display()
+        );
+        Some(current)
+    }
+}
+
 /// Check if a path exists (file, directory, or symlink).
 ///
 /// This function uses `symlink_metadata()` to check existence without
@@ -1136,4 +1246,59 @@ mod tests {
             assert!(working_link.exists());
         }
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn check_symlink_drift_detects_changed_target() {
+        let temp = TempDir::new().unwrap();
+        let current = create_skill_dir(temp.path(), "current");
+        let old = create_skill_dir(temp.path(), "old");
+        let link = temp.path().join("link");
+        std::os::unix::fs::symlink(&old, &link).unwrap();
+
+        assert_eq!(
+            check_symlink_drift(&link, &current),
+            Some(old.canonicalize().unwrap())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn check_symlink_drift_ignores_matching_and_non_symlinks() {
+        let temp = TempDir::new().unwrap();
+        let source = create_skill_dir(temp.path(), "topic");
+        let link = temp.path().join("link");
+        std::os::unix::fs::symlink(&source, &link).unwrap();
+
+        assert_eq!(check_symlink_drift(&link, &source), None);
+        assert_eq!(check_symlink_drift(&source, temp.path()), None);
+        assert_eq!(
+            check_symlink_drift(&temp.path().join("missing"), &source),
+            None
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn scan_and_remove_orphaned_symlinks_keeps_live_and_external_links() {
+        let temp = TempDir::new().unwrap();
+        let research_root = temp.path().join(".research");
+        let live = create_skill_dir(&research_root.join("library"), "live");
+        let orphan = create_skill_dir(&research_root.join("library"), "orphan");
+        let external = create_skill_dir(&temp.path().join("elsewhere"), "mine");
+
+        let skills = temp.path().join("skills");
+        fs::create_dir(&skills).unwrap();
+        std::os::unix::fs::symlink(&live, skills.join("live")).unwrap();
+        std::os::unix::fs::symlink(&orphan, skills.join("orphan")).unwrap();
+        std::os::unix::fs::symlink(&external, skills.join("mine")).unwrap();
+
+        let live_sources = HashSet::from([live.canonicalize().unwrap()]);
+        let result = scan_and_remove_orphaned_symlinks(&skills, &research_root, &live_sources);
+
+        assert_eq!(result.removed, vec![skills.join("orphan")]);
+        assert!(result.failed.is_empty());
+        assert!(check_is_symlink(&skills.join("live")));
+        assert!(check_is_symlink(&skills.join("mine")));
+    }
 }
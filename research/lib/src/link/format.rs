@@ -14,6 +14,7 @@ use owo_colors::OwoColorize;
 /// Uses owo-colors to provide visual feedback about the status of each skill link:
 /// - Topic names are **bold**
 /// - Successful link creation is shown in **green**
+/// - Repaired (re-pointed) links are shown in **cyan**
 /// - Already linked skills are shown **dim + italic**
 /// - Local definitions are shown in **yellow** (warning)
 /// - Invalid skill directories are shown in **yellow**
@@ -58,6 +59,37 @@ pub fn format_terminal(result: &LinkResult) -> String {
         output.push('\n');
     }
 
+    // Report drifted symlinks, either repaired or left for `--sync`
+    if !result.repaired.is_empty() {
+        output.push_str(&format!(
+            "{}\n",
+            format!("Repaired {} drifted symlink(s)", result.repaired.len())
+                .cyan()
+                .bold()
+        ));
+        output.push('\n');
+    }
+
+    if !result.drifted.is_empty() {
+        output.push_str(&format!(
+            "{}\n",
+            format!(
+                "Found {} symlink(s) pointing elsewhere (run with --sync to repair)",
+                result.drifted.len()
+            )
+            .yellow()
+            .bold()
+        ));
+        for change in &result.drifted {
+            output.push_str(&format!(
+                "  {} -> {}\n",
+                change.path,
+                change.previous_target.as_deref().unwrap_or("?").dimmed()
+            ));
+        }
+        output.push('\n');
+    }
+
     if result.links.is_empty() {
         output.push_str(&"No topics processed\n".dimmed().to_string());
         return output;
@@ -132,6 +164,11 @@ fn format_skill_actions(
             return "skills already linked".dimmed().italic().to_string();
         }
 
+        // All repaired
+        (SkillAction::RepairedLink, SkillAction::RepairedLink, SkillAction::RepairedLink) => {
+            return "skills repaired".cyan().to_string();
+        }
+
         // All have local definitions
         (
             SkillAction::NoneLocalDefinition,
@@ -206,12 +243,13 @@ fn format_doc_actions(claude: &SkillAction, opencode: &SkillAction, roo: &SkillA
         | (_, SkillAction::FailedOther(_), _)
         | (_, _, SkillAction::FailedOther(_)) => "doc linking failed".red().to_string(),
 
-        // Mixed success states (some created, some already linked)
-        (c, o, r)
-            if matches!(c, SkillAction::CreatedLink | SkillAction::NoneAlreadyLinked)
-                && matches!(o, SkillAction::CreatedLink | SkillAction::NoneAlreadyLinked)
-                && matches!(r, SkillAction::CreatedLink | SkillAction::NoneAlreadyLinked) =>
-        {
+        // All repaired
+        (SkillAction::RepairedLink, SkillAction::RepairedLink, SkillAction::RepairedLink) => {
+            "docs repaired".cyan().to_string()
+        }
+
+        // Mixed success states (some created or repaired, some already linked)
+        (c, o, r) if c.is_success() && o.is_success() && r.is_success() => {
             "docs linked (partial)".green().to_string()
         }
 
@@ -224,6 +262,7 @@ fn format_doc_actions(claude: &SkillAction, opencode: &SkillAction, roo: &SkillA
 fn format_action_status(action: &SkillAction, service: &str) -> String {
     match action {
         SkillAction::CreatedLink => format!("{}: {}", service.italic(), "created".green()),
+        SkillAction::RepairedLink => format!("{}: {}", service.italic(), "repaired".cyan()),
         SkillAction::NoneAlreadyLinked => {
             format!("{}: {}", service.italic(), "already linked".dimmed())
         }
@@ -639,6 +678,35 @@ mod tests {
         assert!(output.contains("Cleaned up 2 stale symlink"));
     }
 
+    #[test]
+    fn test_format_terminal_reports_repaired_and_drifted() {
+        use crate::link::types::LinkChange;
+
+        let mut result = LinkResult::new();
+        result.links.push(SkillLink::new(
+            "clap".to_string(),
+            SkillAction::RepairedLink,
+            SkillAction::NoneAlreadyLinked,
+            SkillAction::CreatedLink,
+        ));
+        result.repaired.push(LinkChange {
+            path: "/home/user/.claude/skills/clap".to_string(),
+            target: "/research/library/clap/skill".to_string(),
+            previous_target: Some("/old/clap/skill".to_string()),
+        });
+        result.drifted.push(LinkChange {
+            path: "/home/user/.roo/skills/serde".to_string(),
+            target: "/research/library/serde/skill".to_string(),
+            previous_target: Some("/elsewhere/serde".to_string()),
+        });
+
+        let output = format_terminal(&result);
+        assert!(output.contains("Repaired 1 drifted symlink"));
+        assert!(output.contains("run with --sync to repair"));
+        assert!(output.contains("/home/user/.roo/skills/serde"));
+        assert!(output.contains("repaired"));
+    }
+
     #[test]
     fn test_format_terminal_no_stale_symlinks() {
         let result = LinkResult::new();
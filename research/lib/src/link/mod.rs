@@ -26,9 +26,12 @@ pub mod format;
 pub mod types;
 
 // Re-export main types for convenience
-pub use types::{LinkError, LinkResult, SkillAction, SkillLink};
+pub use types::{LinkChange, LinkError, LinkResult, SkillAction, SkillLink};
 
-use tracing::instrument;
+use std::path::Path;
+use tracing::{error, info, instrument, warn};
+
+use crate::paths::ResearchPaths;
 
 /// Create symbolic links from research topic skill directories to Claude Code
 /// and OpenCode user-scoped skill locations.
@@ -72,14 +75,77 @@ use tracing::instrument;
 /// # Ok(())
 /// # }
 /// ```
-#[instrument(skip(filters, types), fields(filter_count = filters.len(), type_count = types.len(), json = json))]
 pub async fn link(
     filters: Vec<String>,
     types: Vec<String>,
     json: bool,
 ) -> Result<LinkResult, LinkError> {
+    link_with_sync(filters, types, json, false).await
+}
+
+/// Link research topics like [`link`], optionally synchronizing existing links.
+///
+/// Broken symlinks are always removed. With `sync` set, the link directories
+/// are also brought back in line with the research library:
+///
+/// - Symlinks into the research library whose topic (or its skill directory
+///   or deep dive) no longer exists are pruned and reported in `stale_removed`
+/// - Symlinks for a topic that point somewhere other than the topic's current
+///   source are re-pointed and reported in `repaired`
+///
+/// Without `sync`, drifted symlinks are left alone and reported in `drifted`.
+/// Newly created symlinks are reported in `created` either way.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use research_lib::link::link_with_sync;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let result = link_with_sync(vec![], vec![], false, true).await?;
+/// for change in &result.repaired {
+///     println!("{} now points to {}", change.path, change.target);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn link_with_sync(
+    filters: Vec<String>,
+    types: Vec<String>,
+    json: bool,
+    sync: bool,
+) -> Result<LinkResult, LinkError> {
+    link_in(&ResearchPaths::from_env(), filters, types, json, sync).await
+}
+
+/// Link the topics of the research library at `paths`, like [`link_with_sync`].
+///
+/// [`link`] and [`link_with_sync`] use the library found by
+/// [`ResearchPaths::from_env`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use research_lib::link::link_in;
+/// use research_lib::paths::ResearchPaths;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let paths = ResearchPaths::new("/srv/research");
+/// let result = link_in(&paths, vec![], vec![], false, false).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[instrument(skip(paths, filters, types), fields(root = %paths.root().display(), filter_count = filters.len(), type_count = types.len(), json = json, sync = sync))]
+pub async fn link_in(
+    paths: &ResearchPaths,
+    filters: Vec<String>,
+    types: Vec<String>,
+    json: bool,
+    sync: bool,
+) -> Result<LinkResult, LinkError> {
+    use std::collections::HashSet;
     use std::path::PathBuf;
-    use tracing::{debug, error, info, warn};
+    use tracing::debug;
 
     info!(
         "Starting link command with {} filters and {} type filters",
@@ -87,17 +153,10 @@ pub async fn link(
         types.len()
     );
 
-    // Get RESEARCH_DIR from env (default to HOME)
-    let research_dir = std::env::var("RESEARCH_DIR").unwrap_or_else(|_| {
-        std::env::var("HOME").expect("Neither RESEARCH_DIR nor HOME environment variable is set")
-    });
+    // Topics live in one directory per kind: {root}/{kind}/
+    let research_root = paths.root().to_path_buf();
 
-    // Construct library path: $RESEARCH_DIR/.research/library/
-    let library_path = PathBuf::from(research_dir)
-        .join(".research")
-        .join("library");
-
-    debug!("Searching for topics in: {:?}", library_path);
+    debug!("Searching for topics in: {:?}", research_root);
 
     // 1. Get all target directories for skills and docs
     let claude_skills_dir =
@@ -154,11 +213,53 @@ pub async fn link(
     }
 
     // 3. Discover topics
-    let all_topics =
-        crate::list::discovery::discover_topics(library_path).map_err(LinkError::Discovery)?;
+    let all_topics = crate::list::discovery::discover_all_topics(&research_root)
+        .map_err(LinkError::Discovery)?;
 
     info!("Discovered {} topics", all_topics.len());
 
+    // In sync mode, prune links into the library that no topic accounts for
+    if sync {
+        let live_sources: HashSet<PathBuf> = all_topics
+            .iter()
+            .flat_map(|topic| {
+                let skill = topic.location.join("skill");
+                let deep_dive = topic.location.join(format!("deep-dive/{}.md", topic.name));
+                [
+                    detection::validate_skill_source(&skill).then_some(skill),
+                    deep_dive.is_file().then_some(deep_dive),
+                ]
+            })
+            .flatten()
+            .filter_map(|path| path.canonicalize().ok())
+            .collect();
+
+        for (dir, dir_name) in [
+            (&claude_skills_dir, "Claude Code skills"),
+            (&opencode_skills_dir, "OpenCode skills"),
+            (&roo_skills_dir, "Roo Code skills"),
+            (&claude_docs_dir, "Claude Code docs"),
+            (&opencode_docs_dir, "OpenCode docs"),
+            (&roo_docs_dir, "Roo Code docs"),
+        ] {
+            let scan_result =
+                detection::scan_and_remove_orphaned_symlinks(dir, &research_root, &live_sources);
+            for removed in scan_result.removed {
+                let display_path = removed.display().to_string();
+                info!("Pruned orphaned symlink in {}: {}", dir_name, display_path);
+                stale_removed.push(display_path);
+            }
+            for (path, err) in scan_result.failed {
+                let display_path = path.display().to_string();
+                error!(
+                    "Failed to prune orphaned symlink in {}: {}: {}",
+                    dir_name, display_path, err
+                );
+                stale_failed.push((display_path, err));
+            }
+        }
+    }
+
     // 4. Filter topics
     let filtered_topics = crate::list::filter::apply_filters(all_topics, &filters, &types)
         .map_err(LinkError::Filter)?;
@@ -166,14 +267,15 @@ pub async fn link(
     info!("Filtered to {} topics", filtered_topics.len());
 
     // 5. Process each topic
-    let mut links = Vec::new();
-    let mut errors = Vec::new();
+    let mut result = LinkResult {
+        stale_removed,
+        stale_failed,
+        ..LinkResult::new()
+    };
 
     for topic in filtered_topics {
         let source_path = topic.location.join("skill");
-        let deep_dive_path = topic
-            .location
-            .join(format!("deep-dive/{}.md", topic.name));
+        let deep_dive_path = topic.location.join(format!("deep-dive/{}.md", topic.name));
 
         // Validate skill source (early filtering)
         let skill_source_valid = detection::validate_skill_source(&source_path);
@@ -214,12 +316,16 @@ pub async fn link(
                                 "Permission denied creating skill symlink for {}: {}",
                                 topic.name, e
                             );
-                            errors.push((topic.name.clone(), format!("Claude Code skill: {}", e)));
+                            result
+                                .errors
+                                .push((topic.name.clone(), format!("Claude Code skill: {}", e)));
                             SkillAction::FailedPermissionDenied(e.to_string())
                         }
                         Err(e) => {
                             error!("Failed to create skill symlink for {}: {}", topic.name, e);
-                            errors.push((topic.name.clone(), format!("Claude Code skill: {}", e)));
+                            result
+                                .errors
+                                .push((topic.name.clone(), format!("Claude Code skill: {}", e)));
                             SkillAction::FailedOther(e.to_string())
                         }
                     }
@@ -244,12 +350,16 @@ pub async fn link(
                                 "Permission denied creating skill symlink for {}: {}",
                                 topic.name, e
                             );
-                            errors.push((topic.name.clone(), format!("OpenCode skill: {}", e)));
+                            result
+                                .errors
+                                .push((topic.name.clone(), format!("OpenCode skill: {}", e)));
                             SkillAction::FailedPermissionDenied(e.to_string())
                         }
                         Err(e) => {
                             error!("Failed to create skill symlink for {}: {}", topic.name, e);
-                            errors.push((topic.name.clone(), format!("OpenCode skill: {}", e)));
+                            result
+                                .errors
+                                .push((topic.name.clone(), format!("OpenCode skill: {}", e)));
                             SkillAction::FailedOther(e.to_string())
                         }
                     }
@@ -274,12 +384,16 @@ pub async fn link(
                                 "Permission denied creating skill symlink for {}: {}",
                                 topic.name, e
                             );
-                            errors.push((topic.name.clone(), format!("Roo Code skill: {}", e)));
+                            result
+                                .errors
+                                .push((topic.name.clone(), format!("Roo Code skill: {}", e)));
                             SkillAction::FailedPermissionDenied(e.to_string())
                         }
                         Err(e) => {
                             error!("Failed to create skill symlink for {}: {}", topic.name, e);
-                            errors.push((topic.name.clone(), format!("Roo Code skill: {}", e)));
+                            result
+                                .errors
+                                .push((topic.name.clone(), format!("Roo Code skill: {}", e)));
                             SkillAction::FailedOther(e.to_string())
                         }
                     }
@@ -287,6 +401,34 @@ pub async fn link(
                 other => other,
             };
 
+            let final_claude_action = reconcile_link(
+                &mut result,
+                final_claude_action,
+                &claude_target,
+                &source_path,
+                sync,
+                &topic.name,
+                "Claude Code skill",
+            );
+            let final_opencode_action = reconcile_link(
+                &mut result,
+                final_opencode_action,
+                &opencode_target,
+                &source_path,
+                sync,
+                &topic.name,
+                "OpenCode skill",
+            );
+            let final_roo_action = reconcile_link(
+                &mut result,
+                final_roo_action,
+                &roo_target,
+                &source_path,
+                sync,
+                &topic.name,
+                "Roo Code skill",
+            );
+
             (final_claude_action, final_opencode_action, final_roo_action)
         } else {
             (
@@ -323,7 +465,9 @@ pub async fn link(
                             "Permission denied creating deep dive symlink for {}: {}",
                             topic.name, e
                         );
-                        errors.push((topic.name.clone(), format!("Claude Code doc: {}", e)));
+                        result
+                            .errors
+                            .push((topic.name.clone(), format!("Claude Code doc: {}", e)));
                         SkillAction::FailedPermissionDenied(e.to_string())
                     }
                     Err(e) => {
@@ -331,7 +475,9 @@ pub async fn link(
                             "Failed to create deep dive symlink for {}: {}",
                             topic.name, e
                         );
-                        errors.push((topic.name.clone(), format!("Claude Code doc: {}", e)));
+                        result
+                            .errors
+                            .push((topic.name.clone(), format!("Claude Code doc: {}", e)));
                         SkillAction::FailedOther(e.to_string())
                     }
                 }
@@ -355,7 +501,9 @@ pub async fn link(
                             "Permission denied creating deep dive symlink for {}: {}",
                             topic.name, e
                         );
-                        errors.push((topic.name.clone(), format!("OpenCode doc: {}", e)));
+                        result
+                            .errors
+                            .push((topic.name.clone(), format!("OpenCode doc: {}", e)));
                         SkillAction::FailedPermissionDenied(e.to_string())
                     }
                     Err(e) => {
@@ -363,7 +511,9 @@ pub async fn link(
                             "Failed to create deep dive symlink for {}: {}",
                             topic.name, e
                         );
-                        errors.push((topic.name.clone(), format!("OpenCode doc: {}", e)));
+                        result
+                            .errors
+                            .push((topic.name.clone(), format!("OpenCode doc: {}", e)));
                         SkillAction::FailedOther(e.to_string())
                     }
                 }
@@ -387,7 +537,9 @@ pub async fn link(
                             "Permission denied creating deep dive symlink for {}: {}",
                             topic.name, e
                         );
-                        errors.push((topic.name.clone(), format!("Roo Code doc: {}", e)));
+                        result
+                            .errors
+                            .push((topic.name.clone(), format!("Roo Code doc: {}", e)));
                         SkillAction::FailedPermissionDenied(e.to_string())
                     }
                     Err(e) => {
@@ -395,12 +547,42 @@ pub async fn link(
                             "Failed to create deep dive symlink for {}: {}",
                             topic.name, e
                         );
-                        errors.push((topic.name.clone(), format!("Roo Code doc: {}", e)));
+                        result
+                            .errors
+                            .push((topic.name.clone(), format!("Roo Code doc: {}", e)));
                         SkillAction::FailedOther(e.to_string())
                     }
                 }
             };
 
+            let claude_doc_action = reconcile_link(
+                &mut result,
+                claude_doc_action,
+                &claude_doc_target,
+                &deep_dive_path,
+                sync,
+                &topic.name,
+                "Claude Code doc",
+            );
+            let opencode_doc_action = reconcile_link(
+                &mut result,
+                opencode_doc_action,
+                &opencode_doc_target,
+                &deep_dive_path,
+                sync,
+                &topic.name,
+                "OpenCode doc",
+            );
+            let roo_doc_action = reconcile_link(
+                &mut result,
+                roo_doc_action,
+                &roo_doc_target,
+                &deep_dive_path,
+                sync,
+                &topic.name,
+                "Roo Code doc",
+            );
+
             (
                 Some(claude_doc_action),
                 Some(opencode_doc_action),
@@ -416,7 +598,7 @@ pub async fn link(
             (None, None, None)
         };
 
-        links.push(SkillLink::new_with_docs(
+        result.links.push(SkillLink::new_with_docs(
             topic.name,
             final_claude_action,
             final_opencode_action,
@@ -427,17 +609,11 @@ pub async fn link(
         ));
     }
 
-    let result = LinkResult {
-        links,
-        errors,
-        stale_removed,
-        stale_failed,
-    };
-
     info!(
-        "Link command completed: {} processed, {} created, {} failed, {} stale removed",
+        "Link command completed: {} processed, {} created, {} repaired, {} failed, {} stale removed",
         result.total_processed(),
         result.total_created(),
+        result.total_repaired(),
         result.total_failed(),
         result.stale_removed.len()
     );
@@ -455,6 +631,91 @@ pub async fn link(
     Ok(result)
 }
 
+/// Record the outcome of a single link in `result`.
+///
+/// Created links are added to `result.created`. Existing links are checked for
+/// drift: with `sync` set they are re-pointed at `source` and recorded in
+/// `result.repaired`, otherwise they are recorded in `result.drifted`.
+fn reconcile_link(
+    result: &mut LinkResult,
+    action: SkillAction,
+    target: &Path,
+    source: &Path,
+    sync: bool,
+    topic: &str,
+    label: &str,
+) -> SkillAction {
+    let canonical_source = || {
+        source
+            .canonicalize()
+            .unwrap_or_else(|_| source.to_path_buf())
+            .display()
+            .to_string()
+    };
+
+    match action {
+        SkillAction::CreatedLink => {
+            result.created.push(LinkChange {
+                path: target.display().to_string(),
+                target: canonical_source(),
+                previous_target: None,
+            });
+            SkillAction::CreatedLink
+        }
+        SkillAction::NoneAlreadyLinked => {
+            let Some(previous) = detection::check_symlink_drift(target, source) else {
+                return SkillAction::NoneAlreadyLinked;
+            };
+            let change = LinkChange {
+                path: target.display().to_string(),
+                target: canonical_source(),
+                previous_target: Some(previous.display().to_string()),
+            };
+
+            if !sync {
+                warn!(
+                    "{} symlink for {} points to {} instead of {}",
+                    label,
+                    topic,
+                    previous.display(),
+                    change.target
+                );
+                result.drifted.push(change);
+                return SkillAction::NoneAlreadyLinked;
+            }
+
+            match creation::repair_symlink(source, target) {
+                Ok(()) => {
+                    info!("Repaired {} symlink for {}", label, topic);
+                    result.repaired.push(change);
+                    SkillAction::RepairedLink
+                }
+                Err(
+                    creation::CreationError::SymlinkRemoval(e)
+                    | creation::CreationError::SymlinkCreation(e),
+                ) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+                    error!(
+                        "Permission denied repairing {} symlink for {}: {}",
+                        label, topic, e
+                    );
+                    result
+                        .errors
+                        .push((topic.to_string(), format!("{}: {}", label, e)));
+                    SkillAction::FailedPermissionDenied(e.to_string())
+                }
+                Err(e) => {
+                    error!("Failed to repair {} symlink for {}: {}", label, topic, e);
+                    result
+                        .errors
+                        .push((topic.to_string(), format!("{}: {}", label, e)));
+                    SkillAction::FailedOther(e.to_string())
+                }
+            }
+        }
+        other => other,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -486,4 +747,94 @@ mod tests {
         // Should succeed or fail gracefully
         assert!(result.is_ok() || matches!(result, Err(LinkError::Discovery(_))));
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_reconcile_link_records_created() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skill");
+        std::fs::create_dir(&source).unwrap();
+        let target = temp.path().join("link");
+
+        let mut result = LinkResult::new();
+        let action = reconcile_link(
+            &mut result,
+            SkillAction::CreatedLink,
+            &target,
+            &source,
+            false,
+            "topic",
+            "Claude Code skill",
+        );
+
+        assert_eq!(action, SkillAction::CreatedLink);
+        assert_eq!(result.created.len(), 1);
+        assert_eq!(result.created[0].path, target.display().to_string());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_reconcile_link_reports_drift_without_sync() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skill");
+        let old = temp.path().join("old");
+        std::fs::create_dir(&source).unwrap();
+        std::fs::create_dir(&old).unwrap();
+        let target = temp.path().join("link");
+        std::os::unix::fs::symlink(&old, &target).unwrap();
+
+        let mut result = LinkResult::new();
+        let action = reconcile_link(
+            &mut result,
+            SkillAction::NoneAlreadyLinked,
+            &target,
+            &source,
+            false,
+            "topic",
+            "Claude Code skill",
+        );
+
+        assert_eq!(action, SkillAction::NoneAlreadyLinked);
+        assert_eq!(result.drifted.len(), 1);
+        assert!(result.repaired.is_empty());
+        assert_eq!(
+            std::fs::read_link(&target).unwrap(),
+            old,
+            "drifted link must be left alone without sync"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_reconcile_link_repairs_drift_with_sync() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skill");
+        let old = temp.path().join("old");
+        std::fs::create_dir(&source).unwrap();
+        std::fs::create_dir(&old).unwrap();
+        let target = temp.path().join("link");
+        std::os::unix::fs::symlink(&old, &target).unwrap();
+
+        let mut result = LinkResult::new();
+        let action = reconcile_link(
+            &mut result,
+            SkillAction::NoneAlreadyLinked,
+            &target,
+            &source,
+            true,
+            "topic",
+            "Claude Code skill",
+        );
+
+        assert_eq!(action, SkillAction::RepairedLink);
+        assert_eq!(result.repaired.len(), 1);
+        assert_eq!(
+            result.repaired[0].previous_target.as_deref(),
+            Some(old.canonicalize().unwrap().display().to_string().as_str())
+        );
+        assert_eq!(
+            target.canonicalize().unwrap(),
+            source.canonicalize().unwrap()
+        );
+    }
 }
@@ -20,6 +20,10 @@ pub enum SkillAction {
     /// Successfully created a new symlink
     CreatedLink,
 
+    /// Replaced an existing symlink that pointed somewhere other than the
+    /// topic's current source (sync mode only)
+    RepairedLink,
+
     /// No action taken - symlink already exists
     NoneAlreadyLinked,
 
@@ -46,7 +50,7 @@ impl SkillAction {
     pub fn is_success(&self) -> bool {
         matches!(
             self,
-            SkillAction::CreatedLink | SkillAction::NoneAlreadyLinked
+            SkillAction::CreatedLink | SkillAction::RepairedLink | SkillAction::NoneAlreadyLinked
         )
     }
 
@@ -154,6 +158,15 @@ impl SkillLink {
             || self.roo_action.is_failure()
     }
 
+    /// Iterates over every skill and doc action recorded for this link
+    pub fn actions(&self) -> impl Iterator<Item = &SkillAction> {
+        [&self.claude_action, &self.opencode_action, &self.roo_action]
+            .into_iter()
+            .chain(self.claude_doc_action.as_ref())
+            .chain(self.opencode_doc_action.as_ref())
+            .chain(self.roo_doc_action.as_ref())
+    }
+
     /// Returns true if all three service actions were skipped (no changes made)
     pub fn all_skipped(&self) -> bool {
         self.claude_action.is_skipped()
@@ -162,6 +175,21 @@ impl SkillLink {
     }
 }
 
+/// A single symlink that was created, repaired, or found to have drifted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinkChange {
+    /// Path of the symlink (e.g., `~/.claude/skills/clap`)
+    pub path: String,
+
+    /// What the symlink points to after the operation
+    pub target: String,
+
+    /// What the symlink pointed to before it was repaired (or, for drifted
+    /// links, what it still points to)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_target: Option<String>,
+}
+
 /// Result of the link command execution.
 ///
 /// Contains all skill link operations and any errors encountered during processing.
@@ -181,6 +209,19 @@ pub struct LinkResult {
     /// Stale symlinks that could not be removed (path, error message)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub stale_failed: Vec<(String, String)>,
+
+    /// Symlinks created during this run
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub created: Vec<LinkChange>,
+
+    /// Drifted symlinks that were re-pointed at the topic's source (sync mode)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repaired: Vec<LinkChange>,
+
+    /// Symlinks pointing somewhere other than the topic's source that were
+    /// left untouched because sync mode was off
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub drifted: Vec<LinkChange>,
 }
 
 impl LinkResult {
@@ -191,6 +232,9 @@ impl LinkResult {
             errors: Vec::new(),
             stale_removed: Vec::new(),
             stale_failed: Vec::new(),
+            created: Vec::new(),
+            repaired: Vec::new(),
+            drifted: Vec::new(),
         }
     }
 
@@ -212,6 +256,17 @@ impl LinkResult {
             .count()
     }
 
+    /// Returns the number of skills where at least one drifted link was repaired
+    pub fn total_repaired(&self) -> usize {
+        self.links
+            .iter()
+            .filter(|link| {
+                link.actions()
+                    .any(|action| matches!(action, SkillAction::RepairedLink))
+            })
+            .count()
+    }
+
     /// Returns the number of skills with failures
     pub fn total_failed(&self) -> usize {
         self.links.iter().filter(|link| link.has_failure()).count()
@@ -431,6 +486,53 @@ mod tests {
         assert_eq!(deserialized.errors[0].0, "error-topic");
     }
 
+    #[test]
+    fn test_repaired_link_is_success() {
+        assert!(SkillAction::RepairedLink.is_success());
+        assert!(!SkillAction::RepairedLink.is_failure());
+        assert!(!SkillAction::RepairedLink.is_skipped());
+    }
+
+    #[test]
+    fn test_link_result_total_repaired_counts_doc_actions() {
+        let mut result = LinkResult::new();
+        result.links.push(SkillLink::new_with_docs(
+            "doc-only".to_string(),
+            SkillAction::NoneAlreadyLinked,
+            SkillAction::NoneAlreadyLinked,
+            SkillAction::NoneAlreadyLinked,
+            Some(SkillAction::RepairedLink),
+            None,
+            None,
+        ));
+        result.links.push(SkillLink::new(
+            "untouched".to_string(),
+            SkillAction::NoneAlreadyLinked,
+            SkillAction::NoneAlreadyLinked,
+            SkillAction::NoneAlreadyLinked,
+        ));
+
+        assert_eq!(result.total_repaired(), 1);
+    }
+
+    #[test]
+    fn test_link_result_changes_serialization() {
+        let mut result = LinkResult::new();
+        result.repaired.push(LinkChange {
+            path: "/home/user/.claude/skills/clap".to_string(),
+            target: "/research/library/clap/skill".to_string(),
+            previous_target: Some("/old/clap/skill".to_string()),
+        });
+
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"repaired\""));
+        assert!(!json.contains("\"created\""));
+        assert!(!json.contains("\"drifted\""));
+
+        let deserialized: LinkResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.repaired, result.repaired);
+    }
+
     #[test]
     fn test_skill_link_all_skipped() {
         let link1 = SkillLink::new(
@@ -8,6 +8,7 @@ use super::types::{ResearchOutput, TopicInfo};
 use crate::metadata::inventory::ResearchInventory;
 use crate::metadata::KindCategory;
 use serde::Deserialize;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::{debug, warn};
@@ -77,6 +78,22 @@ struct Metadata {
 
     /// Guidance on when to use this research (required for v1 schema)
     when_to_use: Option<String>,
+
+    /// Tags attached to additional questions (filename -> tags)
+    #[serde(default)]
+    question_tags: HashMap<String, Vec<String>>,
+
+    /// Tags attached to the topic as a whole
+    #[serde(default)]
+    tags: Vec<String>,
+
+    /// Category the topic is filed under
+    #[serde(default)]
+    category: Option<String>,
+
+    /// When the topic was archived (RFC 3339)
+    #[serde(default)]
+    archived_at: Option<String>,
 }
 
 impl Metadata {
@@ -107,6 +124,17 @@ impl Metadata {
 
         None
     }
+
+    /// Collect the topic's tags and those of its additional questions
+    /// (sorted, deduplicated).
+    fn tags(&self) -> Vec<String> {
+        let tags: BTreeSet<&String> = self
+            .tags
+            .iter()
+            .chain(self.question_tags.values().flatten())
+            .collect();
+        tags.into_iter().cloned().collect()
+    }
 }
 
 /// Expected underlying research document filenames.
@@ -197,10 +225,58 @@ pub fn discover_topics(base_dir: PathBuf) -> Result<Vec<TopicInfo>, DiscoveryErr
     Ok(topics)
 }
 
+/// Discovers research topics of every kind under a `.research` directory.
+///
+/// Each topic kind lives in its own subdirectory (`library/`, `software/`,
+/// `standard/`); kinds whose directory doesn't exist yet are skipped.
+///
+/// # Errors
+///
+/// Returns `DirectoryNotFound` (naming the library directory) if none of the
+/// kind directories exist, or any error from [`discover_topics`].
+pub fn discover_all_topics(research_root: &Path) -> Result<Vec<TopicInfo>, DiscoveryError> {
+    let mut topics = Vec::new();
+    let mut found_any = false;
+
+    for kind in crate::ResearchKind::TOPIC_KINDS {
+        let base_dir = research_root.join(kind.dir_name());
+        if !base_dir.exists() {
+            continue;
+        }
+        found_any = true;
+        topics.extend(discover_topics(base_dir)?);
+    }
+
+    if !found_any {
+        return Err(DiscoveryError::DirectoryNotFound(
+            research_root.join(crate::ResearchKind::Library.dir_name()),
+        ));
+    }
+
+    Ok(topics)
+}
+
+/// Discovers the archived research topics under a `.research` directory.
+///
+/// Archived topics live in `archive/` (see [`crate::archive`]) and are not
+/// returned by [`discover_all_topics`]. Returns an empty list if nothing has
+/// been archived yet.
+///
+/// # Errors
+///
+/// Returns any error from [`discover_topics`].
+pub fn discover_archived_topics(research_root: &Path) -> Result<Vec<TopicInfo>, DiscoveryError> {
+    let archive_dir = research_root.join(crate::archive::ARCHIVE_DIR);
+    if !archive_dir.exists() {
+        return Ok(Vec::new());
+    }
+    discover_topics(archive_dir)
+}
+
 /// Analyzes a single topic directory to determine its status and metadata.
 ///
 /// This function:
-/// 1. Reads and parses metadata.json (if present)
+/// 1. Reads and parses metadata.json (if present), including tags and category
 /// 2. Checks for the presence of expected output files
 /// 3. Checks for the presence of underlying research documents
 /// 4. Identifies any additional custom prompt files
@@ -211,23 +287,33 @@ fn analyze_topic(
 ) -> TopicInfo {
     let mut topic = TopicInfo::new(name.clone(), location.clone());
 
+    // Tags and categories only live in metadata.json, so read it even when
+    // the inventory provides the rest of the topic's metadata.
+    let legacy_metadata = read_metadata(&location.join("metadata.json")).ok();
+    if let Some(ref metadata) = legacy_metadata {
+        topic.tags = metadata.tags();
+        topic.category = metadata.category.clone();
+        topic.archived_at = metadata
+            .archived_at
+            .as_deref()
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.with_timezone(&chrono::Utc));
+    }
+
     if let Some(inventory) = inventory
         && let Some(metadata) = inventory.get(&name)
     {
         apply_inventory_metadata(&mut topic, metadata);
+    } else if let Some(metadata) = legacy_metadata {
+        // Fall back to metadata.json for legacy topics.
+        // Extract values that borrow BEFORE moving any fields
+        topic.needs_migration = metadata.needs_migration();
+        topic.language = metadata.language();
+        // Now move the owned fields
+        topic.topic_type = metadata.kind.unwrap_or_else(|| "library".to_string());
+        topic.description = metadata.brief;
     } else {
-        // Try to read and parse metadata.json for legacy topics.
-        let metadata_path = location.join("metadata.json");
-        if let Ok(metadata) = read_metadata(&metadata_path) {
-            // Extract values that borrow BEFORE moving any fields
-            topic.needs_migration = metadata.needs_migration();
-            topic.language = metadata.language();
-            // Now move the owned fields
-            topic.topic_type = metadata.kind.unwrap_or_else(|| "library".to_string());
-            topic.description = metadata.brief;
-        } else {
-            debug!("No inventory entry or legacy metadata for topic '{}'.", name);
-        }
+        debug!("No inventory entry or legacy metadata for topic '{}'.", name);
     }
 
     // Check for missing output deliverables
@@ -470,9 +556,82 @@ mod tests {
         assert!(topic.missing_output.is_empty());
         assert!(topic.missing_underlying.is_empty());
         assert!(topic.additional_files.is_empty());
+        assert!(topic.tags.is_empty());
         assert!(!topic.has_issues());
     }
 
+    #[test]
+    fn test_discover_topic_collects_question_tags() {
+        let temp_dir = TempDir::new().unwrap();
+        let metadata = r#"{
+            "schema_version": 1,
+            "kind": "library",
+            "when_to_use": "Use for testing",
+            "additional_files": {
+                "question_1.md": "How are retries handled?",
+                "question_2.md": "Is it async?"
+            },
+            "question_tags": {
+                "question_1.md": ["reliability", "networking"],
+                "question_2.md": ["async", "reliability"]
+            }
+        }"#;
+
+        create_test_topic(
+            temp_dir.path(),
+            "tagged-lib",
+            Some(metadata),
+            &[],
+            &[],
+            &["question_1", "question_2"],
+        );
+
+        let topics = discover_topics(temp_dir.path().to_path_buf()).unwrap();
+        assert_eq!(topics.len(), 1);
+        assert_eq!(
+            topics[0].tags,
+            vec![
+                "async".to_string(),
+                "networking".to_string(),
+                "reliability".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_discover_topic_merges_topic_tags_and_category() {
+        let temp_dir = TempDir::new().unwrap();
+        let metadata = r#"{
+            "schema_version": 1,
+            "kind": "library",
+            "when_to_use": "Use for testing",
+            "additional_files": {"question_1.md": "How are retries handled?"},
+            "question_tags": {"question_1.md": ["reliability"]},
+            "tags": ["async", "networking"],
+            "category": "http-clients"
+        }"#;
+
+        create_test_topic(
+            temp_dir.path(),
+            "reqwest",
+            Some(metadata),
+            &[],
+            &[],
+            &["question_1"],
+        );
+
+        let topics = discover_topics(temp_dir.path().to_path_buf()).unwrap();
+        assert_eq!(
+            topics[0].tags,
+            vec![
+                "async".to_string(),
+                "networking".to_string(),
+                "reliability".to_string()
+            ]
+        );
+        assert_eq!(topics[0].category.as_deref(), Some("http-clients"));
+    }
+
     #[test]
     fn test_discover_topic_missing_metadata_is_not_an_issue() {
         let temp_dir = TempDir::new().unwrap();
@@ -669,6 +828,88 @@ mod tests {
         assert!(topics[2].has_critical_issues()); // Missing everything
     }
 
+    #[test]
+    fn test_discover_all_topics_spans_kind_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let library_dir = temp_dir.path().join("library");
+        let software_dir = temp_dir.path().join("software");
+        fs::create_dir_all(&library_dir).unwrap();
+        fs::create_dir_all(&software_dir).unwrap();
+
+        create_test_topic(
+            &library_dir,
+            "clap",
+            Some(r#"{"kind": "library"}"#),
+            &[],
+            &[],
+            &[],
+        );
+        create_test_topic(
+            &software_dir,
+            "ripgrep",
+            Some(r#"{"kind": "software"}"#),
+            &[],
+            &[],
+            &[],
+        );
+
+        let mut topics = discover_all_topics(temp_dir.path()).unwrap();
+        topics.sort_by(|a, b| a.name.cmp(&b.name));
+
+        assert_eq!(topics.len(), 2);
+        assert_eq!(topics[0].topic_type, "library");
+        assert_eq!(topics[1].topic_type, "software");
+    }
+
+    #[test]
+    fn test_discover_all_topics_without_kind_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = discover_all_topics(temp_dir.path());
+        assert!(matches!(
+            result.unwrap_err(),
+            DiscoveryError::DirectoryNotFound(path) if path.ends_with("library")
+        ));
+    }
+
+    #[test]
+    fn test_archived_topics_are_discovered_separately() {
+        let temp_dir = TempDir::new().unwrap();
+        let library_dir = temp_dir.path().join("library");
+        let archive_dir = temp_dir.path().join("archive");
+        fs::create_dir_all(&library_dir).unwrap();
+        fs::create_dir_all(&archive_dir).unwrap();
+
+        create_test_topic(&library_dir, "clap", Some("{}"), &[], &[], &[]);
+        create_test_topic(
+            &archive_dir,
+            "structopt",
+            Some(r#"{"kind": "library", "archived_at": "2026-01-02T03:04:05Z"}"#),
+            &[],
+            &[],
+            &[],
+        );
+
+        let active = discover_all_topics(temp_dir.path()).unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].name, "clap");
+        assert_eq!(active[0].archived_at, None);
+
+        let archived = discover_archived_topics(temp_dir.path()).unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].name, "structopt");
+        assert_eq!(
+            archived[0].archived_at.unwrap().to_rfc3339(),
+            "2026-01-02T03:04:05+00:00"
+        );
+
+        fs::remove_dir_all(&archive_dir).unwrap();
+        assert!(
+            discover_archived_topics(temp_dir.path())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     // =========================================================================
     // Regression Tests: needs_migration detection for when_to_use
     // =========================================================================
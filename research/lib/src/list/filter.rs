@@ -4,6 +4,7 @@
 //! supporting glob pattern matching and type filtering.
 
 use crate::list::types::TopicInfo;
+use crate::tags::normalize_tag;
 use globset::{Glob, GlobSetBuilder};
 use thiserror::Error;
 
@@ -97,6 +98,79 @@ pub fn apply_filters(
     Ok(filtered)
 }
 
+/// Keeps only topics tagged with any of `tags`, either on the topic itself or
+/// on one of its questions.
+///
+/// Tags are normalized the same way as when they are stored, so
+/// `"Error Handling"` matches a topic tagged `error-handling`. An empty
+/// `tags` slice returns all topics.
+///
+/// # Examples
+///
+/// ```
+/// use research_lib::list::filter::filter_by_tags;
+/// use research_lib::list::types::TopicInfo;
+/// use std::path::PathBuf;
+///
+/// let mut tokio = TopicInfo::new("tokio".to_string(), PathBuf::from("/test/tokio"));
+/// tokio.tags = vec!["reliability".to_string()];
+/// let serde = TopicInfo::new("serde".to_string(), PathBuf::from("/test/serde"));
+///
+/// let filtered = filter_by_tags(vec![tokio, serde], &["Reliability".to_string()]);
+/// assert_eq!(filtered.len(), 1);
+/// assert_eq!(filtered[0].name, "tokio");
+/// ```
+pub fn filter_by_tags(topics: Vec<TopicInfo>, tags: &[String]) -> Vec<TopicInfo> {
+    if tags.is_empty() {
+        return topics;
+    }
+
+    let wanted: Vec<String> = tags.iter().filter_map(|t| normalize_tag(t)).collect();
+
+    topics
+        .into_iter()
+        .filter(|topic| topic.tags.iter().any(|tag| wanted.contains(tag)))
+        .collect()
+}
+
+/// Keeps only topics filed under any of `categories`.
+///
+/// Categories are normalized like tags. Topics without a category never
+/// match, and an empty `categories` slice returns all topics.
+///
+/// # Examples
+///
+/// ```
+/// use research_lib::list::filter::filter_by_category;
+/// use research_lib::list::types::TopicInfo;
+/// use std::path::PathBuf;
+///
+/// let mut axum = TopicInfo::new("axum".to_string(), PathBuf::from("/test/axum"));
+/// axum.category = Some("web-frameworks".to_string());
+/// let serde = TopicInfo::new("serde".to_string(), PathBuf::from("/test/serde"));
+///
+/// let filtered = filter_by_category(vec![axum, serde], &["Web Frameworks".to_string()]);
+/// assert_eq!(filtered.len(), 1);
+/// assert_eq!(filtered[0].name, "axum");
+/// ```
+pub fn filter_by_category(topics: Vec<TopicInfo>, categories: &[String]) -> Vec<TopicInfo> {
+    if categories.is_empty() {
+        return topics;
+    }
+
+    let wanted: Vec<String> = categories.iter().filter_map(|c| normalize_tag(c)).collect();
+
+    topics
+        .into_iter()
+        .filter(|topic| {
+            topic
+                .category
+                .as_ref()
+                .is_some_and(|category| wanted.contains(category))
+        })
+        .collect()
+}
+
 /// Builds a case-insensitive glob matcher from a list of patterns.
 ///
 /// Patterns without glob metacharacters (`*`, `?`, `[`, `]`, `{`, `}`) are
@@ -167,6 +241,9 @@ mod tests {
                 description: Some("A foo library".to_string()),
                 language: None,
                 additional_files: vec![],
+                tags: vec![],
+                category: None,
+                archived_at: None,
                 missing_underlying: vec![],
                 missing_output: vec![],
                 needs_migration: false,
@@ -178,6 +255,9 @@ mod tests {
                 description: Some("A bar framework".to_string()),
                 language: None,
                 additional_files: vec![],
+                tags: vec![],
+                category: None,
+                archived_at: None,
                 missing_underlying: vec![],
                 missing_output: vec![],
                 needs_migration: false,
@@ -189,6 +269,9 @@ mod tests {
                 description: Some("Baz software".to_string()),
                 language: None,
                 additional_files: vec![],
+                tags: vec![],
+                category: None,
+                archived_at: None,
                 missing_underlying: vec![],
                 missing_output: vec![],
                 needs_migration: false,
@@ -200,6 +283,9 @@ mod tests {
                 description: Some("Foobar library".to_string()),
                 language: None,
                 additional_files: vec![],
+                tags: vec![],
+                category: None,
+                archived_at: None,
                 missing_underlying: vec![],
                 missing_output: vec![],
                 needs_migration: false,
@@ -211,6 +297,9 @@ mod tests {
                 description: Some("Rust library".to_string()),
                 language: None,
                 additional_files: vec![],
+                tags: vec![],
+                category: None,
+                archived_at: None,
                 missing_underlying: vec![],
                 missing_output: vec![],
                 needs_migration: false,
@@ -431,4 +520,44 @@ mod tests {
         assert!(has_glob_metacharacters("{wire}"));
         assert!(has_glob_metacharacters("wire[123]"));
     }
+
+    #[test]
+    fn test_filter_by_tags() {
+        let mut topics = create_test_topics();
+        topics[0].tags = vec!["reliability".to_string()];
+        topics[2].tags = vec!["async".to_string(), "error-handling".to_string()];
+
+        let filtered = filter_by_tags(topics.clone(), &["reliability".to_string()]);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "foo-library");
+
+        // OR logic across tags, normalized like stored tags
+        let filtered = filter_by_tags(
+            topics.clone(),
+            &["RELIABILITY".to_string(), "Error Handling".to_string()],
+        );
+        assert_eq!(filtered.len(), 2);
+
+        // No tags means no filtering
+        assert_eq!(filter_by_tags(topics, &[]).len(), 5);
+    }
+
+    #[test]
+    fn test_filter_by_category() {
+        let mut topics = create_test_topics();
+        topics[1].category = Some("web-frameworks".to_string());
+        topics[3].category = Some("serialization".to_string());
+
+        let filtered = filter_by_category(topics.clone(), &["Web Frameworks".to_string()]);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "bar-framework");
+
+        let filtered = filter_by_category(
+            topics.clone(),
+            &["web-frameworks".to_string(), "serialization".to_string()],
+        );
+        assert_eq!(filtered.len(), 2);
+
+        assert_eq!(filter_by_category(topics, &[]).len(), 5);
+    }
 }
@@ -65,6 +65,7 @@ pub fn format_json(topics: &[TopicInfo]) -> Result<String, serde_json::Error> {
 ///     - 🐞 missing underlying research docs: ...
 ///     - 🐞 missing final output deliverables: ...
 ///     - 💡 {#} additional prompts used in research: ...
+///     - 🏷️ tagged: ...
 /// ```
 ///
 /// In non-verbose mode:
@@ -139,6 +140,14 @@ fn format_topic(topic: &TopicInfo, hide_type_badge: bool, verbose: bool) -> Stri
         if let Some(additional_line) = format_additional_prompts(topic) {
             lines.push(additional_line);
         }
+
+        if let Some(category_line) = format_category(topic) {
+            lines.push(category_line);
+        }
+
+        if let Some(tags_line) = format_tags(topic) {
+            lines.push(tags_line);
+        }
     }
 
     lines.join("\n")
@@ -177,6 +186,16 @@ fn format_main_line(topic: &TopicInfo, hide_type_badge: bool, verbose: bool) ->
     // Language icon after type badge (in all modes)
     parts.push(format_language_icon(topic.language.as_ref()));
 
+    // Archived topics only appear with `--archived`; mark them in all modes
+    if let Some(archived_at) = topic.archived_at {
+        parts.push(" ".to_string());
+        parts.push(
+            format!("(archived {})", archived_at.format("%Y-%m-%d"))
+                .dimmed()
+                .to_string(),
+        );
+    }
+
     // Description (if present and in verbose mode)
     if verbose && let Some(ref desc) = topic.description {
         parts.push(" : ".to_string());
@@ -375,6 +394,23 @@ fn format_additional_prompts(topic: &TopicInfo) -> Option<String> {
     ))
 }
 
+/// Formats the topic's category if present.
+fn format_category(topic: &TopicInfo) -> Option<String> {
+    topic
+        .category
+        .as_ref()
+        .map(|category| format!("    - 📂 category: {}", category))
+}
+
+/// Formats topic and question tags if present.
+fn format_tags(topic: &TopicInfo) -> Option<String> {
+    if topic.tags.is_empty() {
+        return None;
+    }
+
+    Some(format!("    - 🏷️ tagged: {}", topic.tags.join(", ")))
+}
+
 /// Formats migration needed indicator if present.
 fn format_migration_issue(topic: &TopicInfo) -> Option<String> {
     if !topic.needs_migration {
@@ -416,6 +452,9 @@ mod tests {
             description: Some("A test library for testing".to_string()),
             language: None,
             additional_files: vec!["custom_prompt".to_string()],
+            tags: vec![],
+            category: None,
+            archived_at: None,
             missing_underlying: vec!["overview.md".to_string()],
             missing_output: vec![ResearchOutput::Brief],
             needs_migration: false,
@@ -456,6 +495,9 @@ mod tests {
             description: Some("First library".to_string()),
             language: None,
             additional_files: vec![],
+            tags: vec![],
+            category: None,
+            archived_at: None,
             missing_underlying: vec![],
             missing_output: vec![],
             needs_migration: false,
@@ -468,6 +510,9 @@ mod tests {
             description: Some("Second framework".to_string()),
             language: None,
             additional_files: vec!["question_1".to_string(), "question_2".to_string()],
+            tags: vec![],
+            category: None,
+            archived_at: None,
             missing_underlying: vec!["overview.md".to_string()],
             missing_output: vec![ResearchOutput::DeepDive, ResearchOutput::Skill],
             needs_migration: false,
@@ -480,6 +525,9 @@ mod tests {
             description: None,
             language: None,
             additional_files: vec![],
+            tags: vec![],
+            category: None,
+            archived_at: None,
             missing_underlying: vec!["use_cases.md".to_string(), "best_practices.md".to_string()],
             missing_output: vec![ResearchOutput::Brief],
             needs_migration: false,
@@ -531,6 +579,9 @@ mod tests {
             description: Some("Complete topic".to_string()),
             language: None,
             additional_files: vec!["file1".to_string()],
+            tags: vec![],
+            category: None,
+            archived_at: None,
             missing_underlying: vec!["doc1.md".to_string()],
             missing_output: vec![ResearchOutput::Brief],
             needs_migration: false,
@@ -645,6 +696,44 @@ mod tests {
         assert!(output.contains("custom_analysis"));
     }
 
+    #[test]
+    fn test_tags_shown_in_verbose_mode_only() {
+        let mut topic = create_test_topic("tagged-lib");
+        topic.additional_files.push("question_1".to_string());
+        topic.tags = vec!["async".to_string(), "reliability".to_string()];
+
+        let verbose = format_terminal(std::slice::from_ref(&topic), false, true);
+        assert!(verbose.contains("🏷️ tagged: async, reliability"));
+
+        let compact = format_terminal(&[topic], false, false);
+        assert!(!compact.contains("tagged:"));
+    }
+
+    #[test]
+    fn test_category_shown_in_verbose_mode_only() {
+        let mut topic = create_test_topic("categorized-lib");
+        topic.category = Some("web-frameworks".to_string());
+
+        let verbose = format_terminal(std::slice::from_ref(&topic), false, true);
+        assert!(verbose.contains("📂 category: web-frameworks"));
+
+        let compact = format_terminal(&[topic], false, false);
+        assert!(!compact.contains("category:"));
+    }
+
+    #[test]
+    fn test_archived_marker_shown() {
+        let mut topic = create_test_topic("old-lib");
+        let active = format_terminal(std::slice::from_ref(&topic), false, false);
+        assert!(!active.contains("archived"));
+
+        topic.archived_at = chrono::DateTime::parse_from_rfc3339("2026-03-04T05:06:07Z")
+            .ok()
+            .map(|dt| dt.with_timezone(&chrono::Utc));
+        let archived = format_terminal(&[topic], false, false);
+        assert!(archived.contains("(archived 2026-03-04)"));
+    }
+
     #[test]
     fn test_type_badge_shown_when_not_filtered() {
         let topic = create_test_topic("test-lib");
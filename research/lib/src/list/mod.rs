@@ -9,7 +9,9 @@ pub mod format;
 pub mod types;
 
 // Re-export main types and functions for convenience
-pub use discovery::{DiscoveryError, discover_topics};
-pub use filter::{FilterError, apply_filters};
+pub use discovery::{
+    DiscoveryError, discover_all_topics, discover_archived_topics, discover_topics,
+};
+pub use filter::{FilterError, apply_filters, filter_by_category, filter_by_tags};
 pub use format::{format_json, format_terminal};
 pub use types::{ResearchOutput, TopicInfo};
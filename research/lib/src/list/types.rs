@@ -3,6 +3,7 @@
 //! This module defines the core data structures used to represent research topics
 //! and their associated metadata.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -77,6 +78,18 @@ pub struct TopicInfo {
     /// (e.g., question_*.md files without the .md extension)
     pub additional_files: Vec<String>,
 
+    /// Tags attached to the topic and its additional questions (sorted, deduplicated)
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Category from metadata.json `category` property
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// When the topic was archived, for topics listed from `.research/archive/`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime<Utc>>,
+
     /// Missing underlying research documents (overview.md, use_cases.md, etc.)
     pub missing_underlying: Vec<String>,
 
@@ -103,6 +116,9 @@ impl TopicInfo {
             description: None,
             language: None,
             additional_files: Vec::new(),
+            tags: Vec::new(),
+            category: None,
+            archived_at: None,
             missing_underlying: Vec::new(),
             missing_output: Vec::new(),
             needs_migration: false,
@@ -235,6 +251,9 @@ mod tests {
             description: Some("A test library".to_string()),
             language: None,
             additional_files: vec!["custom_prompt".to_string()],
+            tags: vec!["reliability".to_string()],
+            category: Some("web-frameworks".to_string()),
+            archived_at: None,
             missing_underlying: vec!["overview.md".to_string()],
             missing_output: vec![ResearchOutput::Brief],
             needs_migration: false,
@@ -248,6 +267,8 @@ mod tests {
         assert_eq!(deserialized.topic_type, topic.topic_type);
         assert_eq!(deserialized.description, topic.description);
         assert_eq!(deserialized.additional_files, topic.additional_files);
+        assert_eq!(deserialized.tags, topic.tags);
+        assert_eq!(deserialized.category, topic.category);
         assert_eq!(deserialized.missing_underlying, topic.missing_underlying);
         assert_eq!(deserialized.missing_output, topic.missing_output);
         assert_eq!(deserialized.needs_migration, topic.needs_migration);
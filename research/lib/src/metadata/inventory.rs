@@ -2,7 +2,7 @@
 //!
 //! This module provides the [`ResearchInventory`] type for managing a centralized
 //! registry of all research topics. The inventory is stored as a JSON file at
-//! `$RESEARCH_DIR/.research/research-inventory.json`.
+//! `research-inventory.json` in the library root (see [`crate::paths`]).
 //!
 //! ## Migration
 //!
@@ -89,20 +89,10 @@ impl ResearchInventory {
 
     /// Get the default inventory path.
     ///
-    /// Returns `$RESEARCH_DIR/.research/research-inventory.json` if `RESEARCH_DIR`
-    /// is set, otherwise `$HOME/.research/research-inventory.json`.
+    /// Returns `research-inventory.json` in the library root found by
+    /// [`ResearchPaths::from_env`](crate::paths::ResearchPaths::from_env).
     pub fn default_path() -> Result<PathBuf> {
-        let base = std::env::var("RESEARCH_DIR").unwrap_or_else(|_| {
-            std::env::var("HOME").unwrap_or_else(|_| String::new())
-        });
-
-        if base.is_empty() {
-            return Err(InventoryError::NoResearchDir);
-        }
-
-        Ok(PathBuf::from(base)
-            .join(".research")
-            .join("research-inventory.json"))
+        Ok(crate::paths::ResearchPaths::from_env().inventory_path())
     }
 
     /// Load the inventory from the default path.
@@ -22,7 +22,7 @@
 use chrono::Utc;
 use thiserror::Error;
 
-use super::types::{ApiDetails, LibraryDetails, ResearchDetails};
+use super::types::{ApiDetails, LibraryDetails, ResearchDetails, SoftwareDetails, StandardDetails};
 use super::v0::MetadataV0;
 use crate::{ResearchKind, ResearchMetadata};
 
@@ -101,7 +101,11 @@ pub fn migrate_v0_to_v1(v0: MetadataV0) -> ResearchMetadata {
         ResearchKind::Api => {
             // Api is a new kind in v1, so any v0 Api data would be minimal
             ResearchDetails::Api(ApiDetails::default())
-        } // Future kinds will have their own migration logic
+        }
+        // Software and Standard are new kinds in v1 and carry no v0 details
+        ResearchKind::Software => ResearchDetails::Software(SoftwareDetails::default()),
+        ResearchKind::Standard => ResearchDetails::Standard(StandardDetails::default()),
+        // Future kinds will have their own migration logic
     };
 
     ResearchMetadata {
@@ -109,11 +113,17 @@ pub fn migrate_v0_to_v1(v0: MetadataV0) -> ResearchMetadata {
         kind: v0.kind,
         details,
         additional_files: v0.additional_files,
+        question_tags: std::collections::HashMap::new(),
+        tags: Vec::new(),
+        category: None,
         created_at: v0.created_at, // Preserve original creation time
         updated_at: Utc::now(),    // Migration counts as an update
         brief: v0.brief,
         summary: v0.summary,
         when_to_use: v0.when_to_use,
+        archived_at: None,
+        archived_from: None,
+        generated_by: std::collections::BTreeMap::new(),
     }
 }
 
@@ -332,7 +342,7 @@ mod tests {
         let v0_a = make_v0_with_library_info();
         let v0_b = MetadataV0 {
             schema_version: v0_a.schema_version,
-            kind: v0_a.kind.clone(),
+            kind: v0_a.kind,
             library_info: v0_a.library_info.clone(),
             additional_files: v0_a.additional_files.clone(),
             created_at: v0_a.created_at,
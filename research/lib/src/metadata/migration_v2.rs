@@ -37,7 +37,7 @@ use xxhash_rust::xxh3::xxh3_64;
 use super::inventory::ResearchInventory;
 use sniff_lib::package::LanguagePackageManager;
 
-use super::topic::{ContentType, Document, KindCategory, Library, Topic};
+use super::topic::{ContentType, Document, KindCategory, Library, Software, Topic};
 use crate::ResearchMetadata;
 
 /// Errors that can occur during v2 migration.
@@ -202,6 +202,10 @@ fn convert_kind(v1: &ResearchMetadata, topic_name: &str) -> KindCategory {
                 String::new(),
             ))
         }
+        ResearchKind::Software => KindCategory::Software(Software::new(topic_name.to_string())),
+        // The v2 inventory has no category for standards yet; they describe a
+        // problem space, which is the closest existing fit.
+        ResearchKind::Standard => KindCategory::SolutionArea,
     }
 }
 
@@ -219,6 +223,8 @@ fn parse_package_manager(pm: &str) -> LanguagePackageManager {
         "go" | "go.mod" | "pkg.go.dev" => LanguagePackageManager::GoModules,
         "composer" | "packagist" => LanguagePackageManager::Composer,
         "luarocks" => LanguagePackageManager::Luarocks,
+        "rubygems" | "gem" => LanguagePackageManager::Gem,
+        "maven central" | "maven" => LanguagePackageManager::Maven,
         "hex" => LanguagePackageManager::Hex,
         "nuget" => LanguagePackageManager::Nuget,
         "cpan" => LanguagePackageManager::Cpan,
@@ -481,11 +487,17 @@ mod tests {
                 repository: None,
             }),
             additional_files: HashMap::new(),
+            question_tags: HashMap::new(),
+            tags: Vec::new(),
+            category: None,
             created_at: Utc::now() - chrono::Duration::days(30),
             updated_at: Utc::now(),
             brief: Some(format!("A test library: {}", name)),
             summary: Some(format!("Summary for {}", name)),
             when_to_use: Some(format!("Use {} when testing", name)),
+            archived_at: None,
+            archived_from: None,
+            generated_by: std::collections::BTreeMap::new(),
         };
 
         let content = serde_json::to_string_pretty(&metadata).unwrap();
@@ -25,7 +25,8 @@ pub mod v0;
 pub use types::{
     ApiDetails, AppDetails, CliDetails, CloudProviderDetails, CompanyCategoryDetails,
     CompanyDetails, LibraryDetails, NewsDetails, PeopleDetails, PersonDetails, PlaceDetails,
-    ProductDetails, ResearchDetails, SkillSetDetails, SolutionSpaceDetails, StandardDetails,
+    ProductDetails, ResearchDetails, SkillSetDetails, SoftwareDetails, SolutionSpaceDetails,
+    StandardDetails,
 };
 pub use v0::MetadataV0;
 
@@ -43,6 +43,8 @@ pub enum ResearchDetails {
     SolutionSpace(SolutionSpaceDetails),
     /// Details for CLI tool research
     Cli(CliDetails),
+    /// Details for software research (applications, CLI tools, services)
+    Software(SoftwareDetails),
     /// Details for application research
     App(AppDetails),
     /// Details for cloud provider service research
@@ -77,6 +79,7 @@ impl ResearchDetails {
             Self::Library(_) => "Library",
             Self::SolutionSpace(_) => "SolutionSpace",
             Self::Cli(_) => "Cli",
+            Self::Software(_) => "Software",
             Self::App(_) => "App",
             Self::CloudProvider(_) => "CloudProvider",
             Self::Standard(_) => "Standard",
@@ -127,6 +130,14 @@ pub struct SolutionSpaceDetails {}
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct CliDetails {}
 
+/// Details for software research.
+///
+/// Used by [`ResearchKind::Software`](crate::ResearchKind::Software) for
+/// applications, CLI tools, and services researched by name rather than
+/// through a package manager.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct SoftwareDetails {}
+
 /// Details for application research.
 ///
 /// Used when researching desktop, mobile, or web applications.
@@ -289,6 +300,10 @@ mod tests {
             "SolutionSpace"
         );
         assert_eq!(ResearchDetails::Cli(Default::default()).type_name(), "Cli");
+        assert_eq!(
+            ResearchDetails::Software(Default::default()).type_name(),
+            "Software"
+        );
         assert_eq!(ResearchDetails::App(Default::default()).type_name(), "App");
         assert_eq!(
             ResearchDetails::CloudProvider(Default::default()).type_name(),
@@ -338,6 +353,7 @@ mod tests {
             ResearchDetails::Library(Default::default()),
             ResearchDetails::SolutionSpace(Default::default()),
             ResearchDetails::Cli(Default::default()),
+            ResearchDetails::Software(Default::default()),
             ResearchDetails::App(Default::default()),
             ResearchDetails::CloudProvider(Default::default()),
             ResearchDetails::Standard(Default::default()),
@@ -0,0 +1,1427 @@
+//! Model selection for the research pipeline.
+//!
+//! Every prompt task (overview, changelog, skill, deep dive, brief, questions,
+//! ...) runs against a provider and model chosen by a [`ModelConfig`]. The
+//! defaults match the models the pipeline was tuned with; they can be changed
+//! without recompiling in three ways, later sources winning:
+//!
+//! 1. A TOML or JSON file at `models.toml` in the library root (see the
+//!    [`paths`](crate::paths) module), or wherever `RESEARCH_MODELS` points
+//! 2. `RESEARCH_MODEL_<TASK>` environment variables such as
+//!    `RESEARCH_MODEL_DEEP_DIVE=gemini:gemini-3-pro-preview`
+//! 3. [`ResearchOptions::with_models`](crate::ResearchOptions::with_models)
+//!    for library callers
+//!
+//! Any model can carry a sampling temperature as an `@<temperature>` suffix
+//! (`zai:glm-4.7@0.3`) or a `temperature` key in the file. For one-off runs,
+//! [`ModelOverride`] parses `task=provider:model[@temperature]` strings such as
+//! `overview=zai:glm-4.7@0.3`, checking the model against the provider's known
+//! models so a typo fails fast with suggestions:
+//!
+//! ```
+//! use research_lib::models::{ModelConfig, ResearchTask};
+//!
+//! let config = ModelConfig::default()
+//!     .with_overrides(["overview=zai:glm-4.7@0.3", "deep-dive=gemini:gemini-3-pro-preview"])
+//!     .unwrap();
+//! assert_eq!(config.get(ResearchTask::Overview).temperature, Some(0.3));
+//!
+//! let err = ModelConfig::default()
+//!     .with_overrides(["overview=openai:gpt-5.3"])
+//!     .unwrap_err();
+//! assert!(err.to_string().contains("did you mean"));
+//! ```
+//!
+//! A config file only needs the tasks it changes:
+//!
+//! ```toml
+//! [deep_dive]
+//! provider = "gemini"
+//! model = "gemini-3-pro-preview"
+//!
+//! [questions]
+//! provider = "openai"
+//! model = "gpt-5.2"
+//! ```
+//!
+//! Providers are `gemini`, `openai`, `zai`, `anthropic` (Claude) and `ollama`.
+//! Ollama serves local models and needs no key; point `OLLAMA_API_BASE_URL` at
+//! the server (e.g. `http://localhost:11434`) to enable it. With every task and
+//! the `fallback` set to `ollama:<model>`, the pipeline runs entirely locally.
+//!
+//! Each task runs on a [`FallbackChain`]: its configured model, then any
+//! models listed for it under `fallbacks`, then the shared `fallback` model.
+//! Providers without an API key are skipped, and requests that can be retried
+//! move on to the next pair when one fails:
+//!
+//! ```toml
+//! [[fallbacks.overview]]
+//! provider = "openai"
+//! model = "gpt-5.2"
+//! ```
+//!
+//! Tasks that run as agents with web tools are also bounded by a
+//! [`ToolBudget`]: a limit on model turns plus separate limits on searches and
+//! page scrapes. Each task has a default (the changelog may search more than
+//! the other tasks); `tool_budgets` overrides any of the limits:
+//!
+//! ```toml
+//! [tool_budgets.changelog]
+//! max_searches = 20
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use rig::client::ProviderClient;
+use rig::completion::{CompletionModel, CompletionRequestBuilder, Message};
+use rig::providers::{anthropic, gemini, ollama, openai};
+use rig::tool::Tool;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::warn;
+use unchained_ai::rigging::providers::client_adaptors::zai;
+use unchained_ai::rigging::providers::models as registry;
+use unchained_ai::rigging::tools::{BraveSearchTool, ScreenScrapeTool};
+
+use crate::providers::FallbackChain;
+use crate::question::Question;
+use crate::scheduler::ResearchScheduler;
+
+/// Environment variable holding the path of a model config file.
+pub const MODELS_FILE_ENV: &str = "RESEARCH_MODELS";
+
+/// Prefix of the per-task override variables, e.g. `RESEARCH_MODEL_SKILL`.
+pub const MODEL_ENV_PREFIX: &str = "RESEARCH_MODEL_";
+
+const DEFAULT_GEMINI_MODEL: &str = "gemini-3-flash-preview";
+const DEFAULT_OPENAI_MODEL: &str = "gpt-5.2";
+
+/// The highest sampling temperature any supported provider accepts.
+const MAX_TEMPERATURE: f64 = 2.0;
+
+/// Errors raised while loading a [`ModelConfig`].
+#[derive(Error, Debug)]
+pub enum ModelConfigError {
+    #[error("Failed to read model config {path}: {source}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid TOML in model config {path}: {source}")]
+    Toml {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+
+    #[error("Invalid JSON in model config {path}: {source}")]
+    Json {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("Unknown model provider '{0}' (expected anthropic, gemini, ollama, openai or zai)")]
+    UnknownProvider(String),
+
+    #[error(
+        "Invalid model '{0}' (expected <provider>:<model>[@<temperature>], e.g. openai:gpt-5.2@0.7)"
+    )]
+    InvalidSpec(String),
+
+    #[error("Invalid temperature '{0}' (expected a number from 0 to 2)")]
+    InvalidTemperature(String),
+
+    #[error("Unknown {provider} model '{model}'{}", did_you_mean(.suggestions))]
+    UnknownModel {
+        provider: ModelProvider,
+        model: String,
+        /// Known models with similar names, best match first
+        suggestions: Vec<String>,
+    },
+
+    #[error(
+        "Unknown research task '{0}' (expected overview, similar_libraries, integration_partners, \
+         use_cases, changelog, skill, deep_dive, brief, questions or fallback)"
+    )]
+    UnknownTask(String),
+
+    #[error("Invalid model override '{0}' (expected <task>=<provider>:<model>[@<temperature>])")]
+    InvalidOverride(String),
+
+    #[error("Invalid value for {var}: {source}")]
+    Env {
+        var: String,
+        #[source]
+        source: Box<ModelConfigError>,
+    },
+
+    #[error(
+        "No configured provider for the {task} task (tried {chain}); set one of their API keys"
+    )]
+    Unavailable { task: &'static str, chain: String },
+}
+
+/// Formats the suggestions of [`ModelConfigError::UnknownModel`].
+fn did_you_mean(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [only] => format!("; did you mean '{}'?", only),
+        many => {
+            let quoted: Vec<String> = many.iter().map(|s| format!("'{}'", s)).collect();
+            format!("; did you mean one of {}?", quoted.join(", "))
+        }
+    }
+}
+
+/// An LLM provider the research pipeline can call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelProvider {
+    /// Google Gemini (`GEMINI_API_KEY`)
+    Gemini,
+    /// OpenAI (`OPENAI_API_KEY`)
+    #[serde(rename = "openai")]
+    OpenAi,
+    /// Z.ai GLM models (`ZAI_API_KEY`)
+    Zai,
+    /// Anthropic Claude models (`ANTHROPIC_API_KEY`)
+    Anthropic,
+    /// Local models served by Ollama (`OLLAMA_API_BASE_URL`, e.g. `http://localhost:11434`)
+    Ollama,
+}
+
+impl ModelProvider {
+    /// All providers.
+    pub const ALL: [ModelProvider; 5] = [
+        ModelProvider::Gemini,
+        ModelProvider::OpenAi,
+        ModelProvider::Zai,
+        ModelProvider::Anthropic,
+        ModelProvider::Ollama,
+    ];
+
+    /// The environment variable holding the provider's API key; for Ollama,
+    /// which needs no key, the URL of the server.
+    pub fn api_key_var(self) -> &'static str {
+        match self {
+            ModelProvider::Gemini => "GEMINI_API_KEY",
+            ModelProvider::OpenAi => "OPENAI_API_KEY",
+            ModelProvider::Zai => "ZAI_API_KEY",
+            ModelProvider::Anthropic => "ANTHROPIC_API_KEY",
+            ModelProvider::Ollama => "OLLAMA_API_BASE_URL",
+        }
+    }
+
+    /// Whether the provider's [`api_key_var`](Self::api_key_var) is set.
+    pub fn is_configured(self) -> bool {
+        std::env::var(self.api_key_var()).is_ok_and(|key| !key.trim().is_empty())
+    }
+}
+
+impl fmt::Display for ModelProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ModelProvider::Gemini => "gemini",
+            ModelProvider::OpenAi => "openai",
+            ModelProvider::Zai => "zai",
+            ModelProvider::Anthropic => "anthropic",
+            ModelProvider::Ollama => "ollama",
+        })
+    }
+}
+
+impl FromStr for ModelProvider {
+    type Err = ModelConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "gemini" | "google" => Ok(ModelProvider::Gemini),
+            "openai" => Ok(ModelProvider::OpenAi),
+            "zai" | "z.ai" => Ok(ModelProvider::Zai),
+            "anthropic" | "claude" => Ok(ModelProvider::Anthropic),
+            "ollama" => Ok(ModelProvider::Ollama),
+            _ => Err(ModelConfigError::UnknownProvider(s.to_string())),
+        }
+    }
+}
+
+/// A provider and model name, written as `provider:model` in env vars, with
+/// an optional `@<temperature>` suffix.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::models::{ModelProvider, ModelSpec};
+///
+/// let spec: ModelSpec = "openai:gpt-5.2".parse().unwrap();
+/// assert_eq!(spec.provider, ModelProvider::OpenAi);
+/// assert_eq!(spec.model, "gpt-5.2");
+/// assert_eq!(spec.to_string(), "openai:gpt-5.2");
+///
+/// let spec: ModelSpec = "zai:glm-4.7@0.3".parse().unwrap();
+/// assert_eq!(spec.temperature, Some(0.3));
+/// assert_eq!(spec.to_string(), "zai:glm-4.7@0.3");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelSpec {
+    /// The provider serving the model
+    pub provider: ModelProvider,
+    /// The provider's model name
+    pub model: String,
+    /// Sampling temperature; the provider's default when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+}
+
+impl ModelSpec {
+    /// Creates a spec for `model` on `provider`.
+    pub fn new(provider: ModelProvider, model: impl Into<String>) -> Self {
+        Self {
+            provider,
+            model: model.into(),
+            temperature: None,
+        }
+    }
+
+    /// Samples the model at `temperature` instead of the provider's default.
+    pub fn with_temperature(mut self, temperature: f64) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Checks the model against the provider's known models, replacing it
+    /// with the canonical ID (`GLM-4.7` becomes `glm-4.7`).
+    ///
+    /// Ollama serves whatever models were pulled locally, so its models are
+    /// accepted as given.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`ModelConfigError::UnknownModel`], with the closest known
+    /// models as suggestions, if the provider has no such model.
+    pub fn validated(mut self) -> Result<Self, ModelConfigError> {
+        macro_rules! canonical {
+            ($registry:ty, $model:expr) => {
+                <$registry>::parse_fuzzy($model)
+                    .map(|model| model.model_id().to_string())
+                    .map_err(|e| e.suggestions)
+            };
+        }
+
+        let model = self.model.as_str();
+        let canonical = match self.provider {
+            ModelProvider::Gemini => canonical!(registry::gemini::ProviderModelGemini, model),
+            ModelProvider::OpenAi => canonical!(registry::openai::ProviderModelOpenAi, model),
+            ModelProvider::Zai => canonical!(registry::zai::ProviderModelZai, model),
+            ModelProvider::Anthropic => {
+                canonical!(registry::anthropic::ProviderModelAnthropic, model)
+            }
+            ModelProvider::Ollama => return Ok(self),
+        };
+        match canonical {
+            Ok(model) => {
+                self.model = model;
+                Ok(self)
+            }
+            Err(suggestions) => Err(ModelConfigError::UnknownModel {
+                provider: self.provider,
+                model: self.model,
+                suggestions,
+            }),
+        }
+    }
+
+    /// A Gemini model.
+    pub fn gemini(model: impl Into<String>) -> Self {
+        Self::new(ModelProvider::Gemini, model)
+    }
+
+    /// An OpenAI model.
+    pub fn openai(model: impl Into<String>) -> Self {
+        Self::new(ModelProvider::OpenAi, model)
+    }
+
+    /// A Z.ai model.
+    pub fn zai(model: impl Into<String>) -> Self {
+        Self::new(ModelProvider::Zai, model)
+    }
+
+    /// An Anthropic Claude model.
+    pub fn anthropic(model: impl Into<String>) -> Self {
+        Self::new(ModelProvider::Anthropic, model)
+    }
+
+    /// A model served by a local Ollama server.
+    pub fn ollama(model: impl Into<String>) -> Self {
+        Self::new(ModelProvider::Ollama, model)
+    }
+}
+
+impl fmt::Display for ModelSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.provider, self.model)?;
+        match self.temperature {
+            Some(temperature) => write!(f, "@{}", temperature),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for ModelSpec {
+    type Err = ModelConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (provider, model) = s
+            .split_once(':')
+            .ok_or_else(|| ModelConfigError::InvalidSpec(s.to_string()))?;
+        let (model, temperature) = match model.rsplit_once('@') {
+            Some((model, temperature)) => (model, Some(parse_temperature(temperature)?)),
+            None => (model, None),
+        };
+        let model = model.trim();
+        if model.is_empty() {
+            return Err(ModelConfigError::InvalidSpec(s.to_string()));
+        }
+        Ok(Self {
+            temperature,
+            ..Self::new(provider.parse()?, model)
+        })
+    }
+}
+
+/// Parses a sampling temperature between 0 and [`MAX_TEMPERATURE`].
+fn parse_temperature(s: &str) -> Result<f64, ModelConfigError> {
+    s.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|temperature| (0.0..=MAX_TEMPERATURE).contains(temperature))
+        .ok_or_else(|| ModelConfigError::InvalidTemperature(s.to_string()))
+}
+
+/// A one-off model choice for a task, written `task=provider:model[@temperature]`.
+///
+/// Task names are the [`ResearchTask`] names, with `-` accepted for `_`;
+/// `fallback` targets the shared fallback model. Unlike config files and
+/// `RESEARCH_MODEL_*` variables, the model is checked against the provider's
+/// known models (see [`ModelSpec::validated`]).
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::models::{ModelOverride, ModelSpec, ResearchTask};
+///
+/// let ov: ModelOverride = "deep-dive=openai:GPT-5.2@0.2".parse().unwrap();
+/// assert_eq!(ov.task, Some(ResearchTask::DeepDive));
+/// assert_eq!(ov.spec, ModelSpec::openai("gpt-5.2").with_temperature(0.2));
+///
+/// assert!("overview=zai:glm-9".parse::<ModelOverride>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelOverride {
+    /// The task to change, or `None` for the shared fallback
+    pub task: Option<ResearchTask>,
+    /// The model the task runs on
+    pub spec: ModelSpec,
+}
+
+impl fmt::Display for ModelOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let task = self.task.map_or("fallback", ResearchTask::name);
+        write!(f, "{}={}", task, self.spec)
+    }
+}
+
+impl FromStr for ModelOverride {
+    type Err = ModelConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, spec) = s
+            .split_once('=')
+            .ok_or_else(|| ModelConfigError::InvalidOverride(s.to_string()))?;
+        let key = name.trim().to_ascii_lowercase().replace('-', "_");
+        let task = match key.as_str() {
+            "fallback" => None,
+            key => Some(
+                ResearchTask::ALL
+                    .into_iter()
+                    .find(|task| task.name() == key)
+                    .ok_or_else(|| ModelConfigError::UnknownTask(name.trim().to_string()))?,
+            ),
+        };
+        Ok(Self {
+            task,
+            spec: spec.trim().parse::<ModelSpec>()?.validated()?,
+        })
+    }
+}
+
+/// A prompt task in the research pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResearchTask {
+    Overview,
+    SimilarLibraries,
+    IntegrationPartners,
+    UseCases,
+    Changelog,
+    Skill,
+    DeepDive,
+    Brief,
+    /// Additional user questions (`question_N`)
+    Questions,
+}
+
+impl ResearchTask {
+    /// All tasks, in pipeline order.
+    pub const ALL: [ResearchTask; 9] = [
+        ResearchTask::Overview,
+        ResearchTask::SimilarLibraries,
+        ResearchTask::IntegrationPartners,
+        ResearchTask::UseCases,
+        ResearchTask::Changelog,
+        ResearchTask::Skill,
+        ResearchTask::DeepDive,
+        ResearchTask::Brief,
+        ResearchTask::Questions,
+    ];
+
+    /// The task's name, as used for config keys and output files.
+    pub fn name(self) -> &'static str {
+        match self {
+            ResearchTask::Overview => "overview",
+            ResearchTask::SimilarLibraries => "similar_libraries",
+            ResearchTask::IntegrationPartners => "integration_partners",
+            ResearchTask::UseCases => "use_cases",
+            ResearchTask::Changelog => "changelog",
+            ResearchTask::Skill => "skill",
+            ResearchTask::DeepDive => "deep_dive",
+            ResearchTask::Brief => "brief",
+            ResearchTask::Questions => "questions",
+        }
+    }
+
+    /// Looks up a task by prompt name; `question_N` maps to [`ResearchTask::Questions`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name.starts_with("question_") {
+            return Some(ResearchTask::Questions);
+        }
+        Self::ALL.into_iter().find(|task| task.name() == name)
+    }
+
+    /// The environment variable that overrides this task's model.
+    pub fn env_var(self) -> String {
+        format!("{}{}", MODEL_ENV_PREFIX, self.name().to_ascii_uppercase())
+    }
+}
+
+/// Limits on the tool calls an agent task may make before it has to answer.
+///
+/// `max_turns` bounds the rounds of tool calls; `max_searches` and
+/// `max_scrapes` bound web searches and page scrapes separately, so a task
+/// stuck re-scraping pages stops even while it has turns left. A task that
+/// runs out of either synthesizes its answer from the results gathered so far.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::models::{ResearchTask, ToolBudget};
+///
+/// let changelog = ToolBudget::for_task(ResearchTask::Changelog);
+/// assert!(changelog.max_searches > ToolBudget::for_task(ResearchTask::UseCases).max_searches);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToolBudget {
+    /// Rounds of tool calls before the agent must give its final answer
+    pub max_turns: usize,
+    /// Web searches the agent may run
+    pub max_searches: usize,
+    /// Pages the agent may scrape
+    pub max_scrapes: usize,
+}
+
+impl Default for ToolBudget {
+    fn default() -> Self {
+        Self {
+            max_turns: 15,
+            max_searches: 6,
+            max_scrapes: 6,
+        }
+    }
+}
+
+impl ToolBudget {
+    /// The default budget for `task`.
+    ///
+    /// The changelog gets more turns and searches to chase release notes
+    /// across versions; additional questions get fewer.
+    pub fn for_task(task: ResearchTask) -> Self {
+        match task {
+            ResearchTask::Changelog => Self {
+                max_turns: 20,
+                max_searches: 12,
+                max_scrapes: 10,
+            },
+            ResearchTask::Questions => Self {
+                max_searches: 4,
+                max_scrapes: 4,
+                ..Self::default()
+            },
+            _ => Self::default(),
+        }
+    }
+
+    /// The limit on calls to the tool named `tool_name`, if it has one.
+    ///
+    /// Tools other than web search and scraping are bounded only by `max_turns`.
+    pub fn limit_for(&self, tool_name: &str) -> Option<usize> {
+        if tool_name == BraveSearchTool::NAME {
+            Some(self.max_searches)
+        } else if tool_name == ScreenScrapeTool::NAME {
+            Some(self.max_scrapes)
+        } else {
+            None
+        }
+    }
+}
+
+/// The provider and model used for each research task.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::models::{ModelConfig, ModelSpec, ResearchTask};
+///
+/// let config = ModelConfig::default()
+///     .with_model(ResearchTask::DeepDive, ModelSpec::gemini("gemini-3-pro-preview"));
+/// assert_eq!(config.get(ResearchTask::DeepDive).model, "gemini-3-pro-preview");
+/// assert_eq!(config.get(ResearchTask::Skill).model, "gpt-5.2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModelConfig {
+    pub overview: ModelSpec,
+    pub similar_libraries: ModelSpec,
+    pub integration_partners: ModelSpec,
+    pub use_cases: ModelSpec,
+    pub changelog: ModelSpec,
+    pub skill: ModelSpec,
+    pub deep_dive: ModelSpec,
+    pub brief: ModelSpec,
+    pub questions: ModelSpec,
+    /// Tried after every task's own fallbacks, as the last link of its chain
+    pub fallback: ModelSpec,
+    /// Models tried, in order, when a task's model fails or its provider is not configured
+    pub fallbacks: BTreeMap<ResearchTask, Vec<ModelSpec>>,
+    /// Tool budgets replacing [`ToolBudget::for_task`] for the tasks listed
+    pub tool_budgets: BTreeMap<ResearchTask, ToolBudget>,
+}
+
+impl Default for ModelConfig {
+    fn default() -> Self {
+        Self {
+            overview: ModelSpec::zai(zai::GLM_4_7),
+            similar_libraries: ModelSpec::gemini(DEFAULT_GEMINI_MODEL),
+            integration_partners: ModelSpec::gemini(DEFAULT_GEMINI_MODEL),
+            use_cases: ModelSpec::gemini(DEFAULT_GEMINI_MODEL),
+            changelog: ModelSpec::openai(DEFAULT_OPENAI_MODEL),
+            skill: ModelSpec::openai(DEFAULT_OPENAI_MODEL),
+            deep_dive: ModelSpec::openai(DEFAULT_OPENAI_MODEL),
+            brief: ModelSpec::gemini(DEFAULT_GEMINI_MODEL),
+            questions: ModelSpec::gemini(DEFAULT_GEMINI_MODEL),
+            fallback: ModelSpec::gemini(DEFAULT_GEMINI_MODEL),
+            fallbacks: BTreeMap::new(),
+            tool_budgets: BTreeMap::new(),
+        }
+    }
+}
+
+impl ModelConfig {
+    /// Loads the config from the default file (if present) and `RESEARCH_MODEL_*` overrides.
+    ///
+    /// The file is read from `$RESEARCH_MODELS` if set, otherwise from
+    /// `models.toml` (or `models.json`) in the library root (see the
+    /// [`paths`](crate::paths) module). Without a file, the defaults are used.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or an override
+    /// variable is not a valid `provider:model`.
+    pub fn load() -> Result<Self, ModelConfigError> {
+        let explicit = std::env::var_os(MODELS_FILE_ENV).map(PathBuf::from);
+        let config = match explicit.or_else(Self::default_path) {
+            Some(path) => Self::from_file(&path)?,
+            None => Self::default(),
+        };
+        config.with_env_overrides(|var| std::env::var(var).ok())
+    }
+
+    /// Returns the first of `models.toml` / `models.json` that exists in the research directory.
+    pub fn default_path() -> Option<PathBuf> {
+        crate::paths::ResearchPaths::from_env().config_file("models")
+    }
+
+    /// Reads a config file; `.json` files are parsed as JSON, anything else as TOML.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn from_file(path: &Path) -> Result<Self, ModelConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|source| ModelConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        if is_json {
+            serde_json::from_str(&content).map_err(|source| ModelConfigError::Json {
+                path: path.to_path_buf(),
+                source,
+            })
+        } else {
+            toml::from_str(&content).map_err(|source| ModelConfigError::Toml {
+                path: path.to_path_buf(),
+                source,
+            })
+        }
+    }
+
+    /// Applies `RESEARCH_MODEL_<TASK>` (and `RESEARCH_MODEL_FALLBACK`) overrides read through `lookup`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`ModelConfigError::Env`] if a variable is not a valid `provider:model`.
+    pub fn with_env_overrides<F>(mut self, lookup: F) -> Result<Self, ModelConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let parse = |var: String| -> Result<Option<ModelSpec>, ModelConfigError> {
+            lookup(&var)
+                .filter(|value| !value.trim().is_empty())
+                .map(|value| {
+                    value.parse().map_err(|source| ModelConfigError::Env {
+                        var: var.clone(),
+                        source: Box::new(source),
+                    })
+                })
+                .transpose()
+        };
+
+        for task in ResearchTask::ALL {
+            if let Some(spec) = parse(task.env_var())? {
+                *self.get_mut(task) = spec;
+            }
+        }
+        if let Some(spec) = parse(format!("{}FALLBACK", MODEL_ENV_PREFIX))? {
+            self.fallback = spec;
+        }
+        Ok(self)
+    }
+
+    /// Sets the model for `task`.
+    pub fn with_model(mut self, task: ResearchTask, spec: ModelSpec) -> Self {
+        *self.get_mut(task) = spec;
+        self
+    }
+
+    /// Applies a [`ModelOverride`].
+    pub fn with_override(mut self, model: ModelOverride) -> Self {
+        match model.task {
+            Some(task) => *self.get_mut(task) = model.spec,
+            None => self.fallback = model.spec,
+        }
+        self
+    }
+
+    /// Parses and applies `task=provider:model[@temperature]` overrides, in order.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the first override that is malformed, names an unknown task,
+    /// or names a model its provider doesn't know.
+    pub fn with_overrides<I>(self, overrides: I) -> Result<Self, ModelConfigError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        overrides.into_iter().try_fold(self, |config, value| {
+            Ok(config.with_override(value.as_ref().parse()?))
+        })
+    }
+
+    /// Sets the models tried after `task`'s own model, before the shared fallback.
+    pub fn with_fallbacks(mut self, task: ResearchTask, specs: Vec<ModelSpec>) -> Self {
+        self.fallbacks.insert(task, specs);
+        self
+    }
+
+    /// Sets the tool budget for `task`.
+    pub fn with_tool_budget(mut self, task: ResearchTask, budget: ToolBudget) -> Self {
+        self.tool_budgets.insert(task, budget);
+        self
+    }
+
+    /// Returns the tool budget for `task`: the configured one, or its default.
+    pub fn tool_budget(&self, task: ResearchTask) -> ToolBudget {
+        self.tool_budgets
+            .get(&task)
+            .copied()
+            .unwrap_or_else(|| ToolBudget::for_task(task))
+    }
+
+    /// Returns the model configured for `task`.
+    pub fn get(&self, task: ResearchTask) -> &ModelSpec {
+        match task {
+            ResearchTask::Overview => &self.overview,
+            ResearchTask::SimilarLibraries => &self.similar_libraries,
+            ResearchTask::IntegrationPartners => &self.integration_partners,
+            ResearchTask::UseCases => &self.use_cases,
+            ResearchTask::Changelog => &self.changelog,
+            ResearchTask::Skill => &self.skill,
+            ResearchTask::DeepDive => &self.deep_dive,
+            ResearchTask::Brief => &self.brief,
+            ResearchTask::Questions => &self.questions,
+        }
+    }
+
+    /// Returns the (provider, model) pairs `task` tries, in order: its own
+    /// model, its `fallbacks`, then the shared `fallback`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use research_lib::models::{ModelConfig, ModelProvider, ModelSpec, ResearchTask};
+    ///
+    /// let config = ModelConfig::default()
+    ///     .with_fallbacks(ResearchTask::Overview, vec![ModelSpec::openai("gpt-5.2")]);
+    /// let providers: Vec<_> = config
+    ///     .chain(ResearchTask::Overview)
+    ///     .iter()
+    ///     .map(|(provider, _)| *provider)
+    ///     .collect();
+    /// assert_eq!(
+    ///     providers,
+    ///     [ModelProvider::Zai, ModelProvider::OpenAi, ModelProvider::Gemini]
+    /// );
+    /// ```
+    pub fn chain(&self, task: ResearchTask) -> FallbackChain<ModelProvider> {
+        let primary = self.get(task);
+        self.candidates(task).skip(1).fold(
+            FallbackChain::new(primary.provider, &primary.model),
+            |chain, spec| chain.with_fallback(spec.provider, &spec.model),
+        )
+    }
+
+    /// Returns the model `task` starts on: the first model in its
+    /// [`chain`](Self::chain) whose provider passes `is_available`, or its
+    /// configured model if none does.
+    pub fn resolve<F>(&self, task: ResearchTask, is_available: F) -> &ModelSpec
+    where
+        F: Fn(ModelProvider) -> bool,
+    {
+        self.candidates(task)
+            .find(|spec| is_available(spec.provider))
+            .unwrap_or_else(|| self.get(task))
+    }
+
+    /// Returns the temperature set on `model` in `task`'s chain, if any.
+    pub(crate) fn temperature(
+        &self,
+        task: ResearchTask,
+        provider: ModelProvider,
+        model: &str,
+    ) -> Option<f64> {
+        self.candidates(task)
+            .find(|spec| spec.provider == provider && spec.model == model)
+            .and_then(|spec| spec.temperature)
+    }
+
+    fn candidates(&self, task: ResearchTask) -> impl Iterator<Item = &ModelSpec> {
+        std::iter::once(self.get(task))
+            .chain(self.fallbacks.get(&task).into_iter().flatten())
+            .chain(std::iter::once(&self.fallback))
+    }
+
+    fn get_mut(&mut self, task: ResearchTask) -> &mut ModelSpec {
+        match task {
+            ResearchTask::Overview => &mut self.overview,
+            ResearchTask::SimilarLibraries => &mut self.similar_libraries,
+            ResearchTask::IntegrationPartners => &mut self.integration_partners,
+            ResearchTask::UseCases => &mut self.use_cases,
+            ResearchTask::Changelog => &mut self.changelog,
+            ResearchTask::Skill => &mut self.skill,
+            ResearchTask::DeepDive => &mut self.deep_dive,
+            ResearchTask::Brief => &mut self.brief,
+            ResearchTask::Questions => &mut self.questions,
+        }
+    }
+}
+
+/// Provider clients plus the [`ModelConfig`] that picks between them.
+///
+/// Only providers whose API key is set get a client.
+pub(crate) struct ModelClients {
+    pub(crate) config: ModelConfig,
+    pub(crate) gemini: Option<gemini::Client>,
+    pub(crate) openai: Option<openai::Client>,
+    pub(crate) zai: Option<zai::Client>,
+    pub(crate) anthropic: Option<anthropic::Client>,
+    pub(crate) ollama: Option<ollama::Client>,
+    /// Limits shared with other research runs, if any
+    pub(crate) scheduler: Option<Arc<ResearchScheduler>>,
+}
+
+impl ModelClients {
+    /// Creates clients for every provider whose API key variable is set.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`ModelConfigError::Unavailable`] if no provider in some task's
+    /// chain is configured.
+    pub(crate) fn from_env(config: ModelConfig) -> Result<Self, ModelConfigError> {
+        let clients = Self {
+            config,
+            gemini: ModelProvider::Gemini
+                .is_configured()
+                .then(gemini::Client::from_env),
+            openai: ModelProvider::OpenAi
+                .is_configured()
+                .then(openai::Client::from_env),
+            zai: zai::Client::from_env().ok(),
+            anthropic: ModelProvider::Anthropic
+                .is_configured()
+                .then(anthropic::Client::from_env),
+            ollama: ModelProvider::Ollama
+                .is_configured()
+                .then(ollama::Client::from_env),
+            scheduler: None,
+        };
+
+        for task in ResearchTask::ALL {
+            let chain = clients.config.chain(task);
+            if chain
+                .first_available(|provider| clients.is_available(*provider))
+                .is_none()
+            {
+                let tried = chain
+                    .iter()
+                    .map(|(provider, model)| format!("{}:{}", provider, model))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(ModelConfigError::Unavailable {
+                    task: task.name(),
+                    chain: tried,
+                });
+            }
+        }
+        Ok(clients)
+    }
+
+    /// Whether a client exists for `provider`.
+    pub(crate) fn is_available(&self, provider: ModelProvider) -> bool {
+        match provider {
+            ModelProvider::Gemini => self.gemini.is_some(),
+            ModelProvider::OpenAi => self.openai.is_some(),
+            ModelProvider::Zai => self.zai.is_some(),
+            ModelProvider::Anthropic => self.anthropic.is_some(),
+            ModelProvider::Ollama => self.ollama.is_some(),
+        }
+    }
+
+    /// Runs every request passed to [`schedule_on`](Self::schedule_on) through `scheduler`.
+    pub(crate) fn with_scheduler(mut self, scheduler: Option<Arc<ResearchScheduler>>) -> Self {
+        self.scheduler = scheduler;
+        self
+    }
+
+    /// Wraps the request future for a request sent to `provider` so it waits
+    /// for the scheduler's permits before running; without a scheduler it
+    /// runs unchanged.
+    pub(crate) fn schedule_on<F: Future>(
+        &self,
+        provider: ModelProvider,
+        request: F,
+    ) -> impl Future<Output = F::Output> + use<F> {
+        let scheduler = self.scheduler.clone();
+        async move {
+            match scheduler {
+                Some(scheduler) => scheduler.run(provider, request).await,
+                None => request.await,
+            }
+        }
+    }
+
+    /// Returns the model to use for `task`: the first in its chain with a client.
+    pub(crate) fn spec(&self, task: ResearchTask) -> &ModelSpec {
+        self.config
+            .resolve(task, |provider| self.is_available(provider))
+    }
+
+    /// Returns the model to answer `question` with: its own when its provider
+    /// has a client, otherwise the [`ResearchTask::Questions`] model.
+    pub(crate) fn question_spec<'a>(&'a self, question: &'a Question) -> &'a ModelSpec {
+        match &question.model {
+            Some(spec) if self.is_available(spec.provider) => spec,
+            Some(spec) => {
+                warn!(model = %spec, "Question model isn't configured; using the default");
+                self.spec(ResearchTask::Questions)
+            }
+            None => self.spec(ResearchTask::Questions),
+        }
+    }
+
+    /// Returns the models `task` fails over between, in order: every model
+    /// in its chain whose provider has a client. The first is [`spec`](Self::spec).
+    pub(crate) fn failover_specs(&self, task: ResearchTask) -> Vec<ModelSpec> {
+        let mut specs: Vec<ModelSpec> = Vec::new();
+        for spec in self.config.candidates(task) {
+            if self.is_available(spec.provider)
+                && !specs
+                    .iter()
+                    .any(|s| s.provider == spec.provider && s.model == spec.model)
+            {
+                specs.push(spec.clone());
+            }
+        }
+        specs
+    }
+
+    /// Like [`failover_specs`](Self::failover_specs) for `question`: its
+    /// [`question_spec`](Self::question_spec) first, then the rest of the
+    /// [`ResearchTask::Questions`] chain.
+    pub(crate) fn question_failover_specs(&self, question: &Question) -> Vec<ModelSpec> {
+        let first = self.question_spec(question).clone();
+        let mut specs = self.failover_specs(ResearchTask::Questions);
+        specs.retain(|spec| spec.provider != first.provider || spec.model != first.model);
+        specs.insert(0, first);
+        specs
+    }
+}
+
+/// A completion model that sends its task's configured temperature with
+/// every request.
+///
+/// Prompt tasks take one of these in place of the bare model, so the
+/// temperature follows the model without another argument.
+#[derive(Clone)]
+pub(crate) struct TunedModel<M> {
+    model: M,
+    temperature: Option<f64>,
+}
+
+impl<M: CompletionModel> TunedModel<M> {
+    /// Wraps `model`, sampling at `temperature` (the provider's default if `None`).
+    pub(crate) fn new(model: M, temperature: Option<f64>) -> Self {
+        Self { model, temperature }
+    }
+
+    /// Starts a request for `prompt`, like [`CompletionModel::completion_request`].
+    pub(crate) fn completion_request(
+        &self,
+        prompt: impl Into<Message>,
+    ) -> CompletionRequestBuilder<M> {
+        self.model
+            .completion_request(prompt)
+            .temperature_opt(self.temperature)
+    }
+}
+
+/// Runs `$body` with `$client` bound to the provider client for `$task`,
+/// `$model` to its model name and `$temperature` to its sampling temperature
+/// (an `Option<f64>`).
+///
+/// rig's completion models and agents are generic over the provider, so each
+/// provider gets its own copy of `$body`; every copy must evaluate to the same
+/// type.
+///
+/// With `spec = $spec` in place of `$task`, `$body` runs with that
+/// [`ModelSpec`] instead, which must be one [`ModelClients`] has a client for.
+macro_rules! with_model {
+    ($clients:expr, spec = $spec:expr, |$client:ident, $model:ident, $temperature:ident| $body:expr) => {{
+        let clients: &$crate::models::ModelClients = &$clients;
+        let spec: &$crate::models::ModelSpec = $spec;
+        let $model: &str = spec.model.as_str();
+        let $temperature: Option<f64> = spec.temperature;
+        const UNCONFIGURED: &str = "specs are only picked for configured providers";
+        match spec.provider {
+            $crate::models::ModelProvider::Gemini => {
+                let $client = clients.gemini.as_ref().expect(UNCONFIGURED);
+                $body
+            }
+            $crate::models::ModelProvider::OpenAi => {
+                let $client = clients.openai.as_ref().expect(UNCONFIGURED);
+                $body
+            }
+            $crate::models::ModelProvider::Zai => {
+                let $client = clients.zai.as_ref().expect(UNCONFIGURED);
+                $body
+            }
+            $crate::models::ModelProvider::Anthropic => {
+                let $client = clients.anthropic.as_ref().expect(UNCONFIGURED);
+                $body
+            }
+            $crate::models::ModelProvider::Ollama => {
+                let $client = clients.ollama.as_ref().expect(UNCONFIGURED);
+                $body
+            }
+        }
+    }};
+    ($clients:expr, $task:expr, |$client:ident, $model:ident, $temperature:ident| $body:expr) => {{
+        let clients: &$crate::models::ModelClients = &$clients;
+        $crate::models::with_model!(
+            clients,
+            spec = clients.spec($task),
+            |$client, $model, $temperature| $body
+        )
+    }};
+}
+
+/// Like [`with_model!`], but on an error retries `$body` with the next
+/// configured pair in the task's [`FallbackChain`].
+///
+/// `$body` runs inside an `async move` block once per attempt, so it may
+/// `.await` but must only capture references and other `Copy` values. Every
+/// copy must evaluate to the same `Result<T, E>`; the macro evaluates to
+/// `Result<T, FallbackError<ModelProvider, E>>`.
+macro_rules! with_fallback {
+    ($clients:expr, $task:expr, |$client:ident, $model:ident, $temperature:ident| $body:expr) => {{
+        let clients: &$crate::models::ModelClients = &$clients;
+        let task: $crate::models::ResearchTask = $task;
+        clients
+            .config
+            .chain(task)
+            .run(
+                |provider| clients.is_available(*provider),
+                |provider, model| async move {
+                    let $temperature: Option<f64> =
+                        clients.config.temperature(task, provider, &model);
+                    let $model: &str = model.as_str();
+                    let request = async {
+                        const UNCONFIGURED: &str = "the chain skips unconfigured providers";
+                        match provider {
+                            $crate::models::ModelProvider::Gemini => {
+                                let $client = clients.gemini.as_ref().expect(UNCONFIGURED);
+                                $body
+                            }
+                            $crate::models::ModelProvider::OpenAi => {
+                                let $client = clients.openai.as_ref().expect(UNCONFIGURED);
+                                $body
+                            }
+                            $crate::models::ModelProvider::Zai => {
+                                let $client = clients.zai.as_ref().expect(UNCONFIGURED);
+                                $body
+                            }
+                            $crate::models::ModelProvider::Anthropic => {
+                                let $client = clients.anthropic.as_ref().expect(UNCONFIGURED);
+                                $body
+                            }
+                            $crate::models::ModelProvider::Ollama => {
+                                let $client = clients.ollama.as_ref().expect(UNCONFIGURED);
+                                $body
+                            }
+                        }
+                    };
+                    clients.schedule_on(provider, request).await
+                },
+            )
+            .await
+    }};
+}
+
+pub(crate) use {with_fallback, with_model};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn spec_parses_provider_and_model() {
+        let spec: ModelSpec = "Gemini:gemini-3-pro-preview".parse().unwrap();
+        assert_eq!(spec, ModelSpec::gemini("gemini-3-pro-preview"));
+        assert_eq!(
+            "zai:glm-4.7".parse::<ModelSpec>().unwrap(),
+            ModelSpec::zai("glm-4.7")
+        );
+
+        assert!(matches!(
+            "gpt-5.2".parse::<ModelSpec>(),
+            Err(ModelConfigError::InvalidSpec(_))
+        ));
+        assert!(matches!(
+            "mistral:large".parse::<ModelSpec>(),
+            Err(ModelConfigError::UnknownProvider(_))
+        ));
+        assert_eq!(
+            "claude:claude-sonnet-4-5".parse::<ModelSpec>().unwrap(),
+            ModelSpec::anthropic("claude-sonnet-4-5")
+        );
+        assert_eq!(
+            "ollama:qwen3:32b".parse::<ModelSpec>().unwrap(),
+            ModelSpec::ollama("qwen3:32b")
+        );
+    }
+
+    #[test]
+    fn spec_parses_temperature_suffix() {
+        let spec: ModelSpec = "zai:glm-4.7@0.3".parse().unwrap();
+        assert_eq!(spec, ModelSpec::zai("glm-4.7").with_temperature(0.3));
+        assert_eq!(spec.to_string(), "zai:glm-4.7@0.3");
+        assert_eq!(
+            "ollama:qwen3:32b@1".parse::<ModelSpec>().unwrap(),
+            ModelSpec::ollama("qwen3:32b").with_temperature(1.0)
+        );
+
+        for bad in [
+            "openai:gpt-5.2@hot",
+            "openai:gpt-5.2@2.5",
+            "openai:gpt-5.2@-0.1",
+        ] {
+            assert!(
+                matches!(
+                    bad.parse::<ModelSpec>(),
+                    Err(ModelConfigError::InvalidTemperature(_))
+                ),
+                "{bad}"
+            );
+        }
+        assert!(matches!(
+            "openai:@0.5".parse::<ModelSpec>(),
+            Err(ModelConfigError::InvalidSpec(_))
+        ));
+    }
+
+    #[test]
+    fn config_file_temperature_is_optional() {
+        let config: ModelConfig = toml::from_str(
+            "[overview]\nprovider = \"zai\"\nmodel = \"glm-4.7\"\ntemperature = 0.3\n",
+        )
+        .unwrap();
+        assert_eq!(config.overview.temperature, Some(0.3));
+        assert_eq!(config.skill.temperature, None);
+        let skill = serde_json::to_value(&config.skill).unwrap();
+        assert!(skill.get("temperature").is_none());
+    }
+
+    #[test]
+    fn overrides_canonicalize_known_models() {
+        let config = ModelConfig::default()
+            .with_overrides([
+                "overview=zai:GLM-4.7@0.3",
+                "Deep-Dive=openai:gpt_5.2",
+                "fallback=ollama:qwen3:32b",
+            ])
+            .unwrap();
+        assert_eq!(
+            config.overview,
+            ModelSpec::zai("glm-4.7").with_temperature(0.3)
+        );
+        assert_eq!(config.deep_dive, ModelSpec::openai("gpt-5.2"));
+        assert_eq!(config.fallback, ModelSpec::ollama("qwen3:32b"));
+        assert_eq!(config.skill, ModelConfig::default().skill);
+
+        let ov: ModelOverride = "use_cases=gemini:gemini-3-pro-preview@0.7".parse().unwrap();
+        assert_eq!(ov.to_string(), "use_cases=gemini:gemini-3-pro-preview@0.7");
+    }
+
+    #[test]
+    fn overrides_report_unknown_models_and_tasks() {
+        let err = "overview=zai:glm-4.8".parse::<ModelOverride>().unwrap_err();
+        match &err {
+            ModelConfigError::UnknownModel {
+                provider,
+                model,
+                suggestions,
+            } => {
+                assert_eq!(*provider, ModelProvider::Zai);
+                assert_eq!(model, "glm-4.8");
+                assert!(suggestions.iter().any(|s| s == "glm-4.7"));
+            }
+            other => panic!("expected UnknownModel, got {other:?}"),
+        }
+        assert!(err.to_string().contains("did you mean"));
+
+        assert!(matches!(
+            "summary=zai:glm-4.7".parse::<ModelOverride>(),
+            Err(ModelConfigError::UnknownTask(task)) if task == "summary"
+        ));
+        assert!(matches!(
+            "zai:glm-4.7".parse::<ModelOverride>(),
+            Err(ModelConfigError::InvalidOverride(_))
+        ));
+        assert!(matches!(
+            "brief=mistral:large".parse::<ModelOverride>(),
+            Err(ModelConfigError::UnknownProvider(_))
+        ));
+    }
+
+    #[test]
+    fn temperature_follows_the_chain_model() {
+        let config = ModelConfig::default()
+            .with_model(
+                ResearchTask::Brief,
+                ModelSpec::gemini(DEFAULT_GEMINI_MODEL).with_temperature(0.2),
+            )
+            .with_fallbacks(
+                ResearchTask::Brief,
+                vec![ModelSpec::openai("gpt-5.2").with_temperature(0.9)],
+            );
+
+        let temperature =
+            |provider, model| config.temperature(ResearchTask::Brief, provider, model);
+        assert_eq!(
+            temperature(ModelProvider::Gemini, DEFAULT_GEMINI_MODEL),
+            Some(0.2)
+        );
+        assert_eq!(temperature(ModelProvider::OpenAi, "gpt-5.2"), Some(0.9));
+        assert_eq!(temperature(ModelProvider::Zai, "glm-4.7"), None);
+    }
+
+    #[test]
+    fn task_names_round_trip() {
+        for task in ResearchTask::ALL {
+            assert_eq!(ResearchTask::from_name(task.name()), Some(task));
+        }
+        assert_eq!(
+            ResearchTask::from_name("question_3"),
+            Some(ResearchTask::Questions)
+        );
+        assert_eq!(ResearchTask::DeepDive.env_var(), "RESEARCH_MODEL_DEEP_DIVE");
+    }
+
+    #[test]
+    fn tool_budgets_default_per_task_and_can_be_overridden() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("models.toml");
+        std::fs::write(&path, "[tool_budgets.use_cases]\nmax_scrapes = 2\n").unwrap();
+
+        let config = ModelConfig::from_file(&path).unwrap();
+        let use_cases = config.tool_budget(ResearchTask::UseCases);
+        assert_eq!(use_cases.max_scrapes, 2);
+        assert_eq!(use_cases.max_searches, ToolBudget::default().max_searches);
+        assert_eq!(
+            config.tool_budget(ResearchTask::Changelog),
+            ToolBudget::for_task(ResearchTask::Changelog)
+        );
+
+        assert_eq!(use_cases.limit_for(BraveSearchTool::NAME), Some(6));
+        assert_eq!(use_cases.limit_for(ScreenScrapeTool::NAME), Some(2));
+        assert_eq!(use_cases.limit_for("github_repo"), None);
+    }
+
+    #[test]
+    fn partial_toml_file_keeps_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("models.toml");
+        std::fs::write(
+            &path,
+            "[deep_dive]\nprovider = \"gemini\"\nmodel = \"gemini-3-pro-preview\"\n",
+        )
+        .unwrap();
+
+        let config = ModelConfig::from_file(&path).unwrap();
+        assert_eq!(config.deep_dive, ModelSpec::gemini("gemini-3-pro-preview"));
+        assert_eq!(config.skill, ModelConfig::default().skill);
+    }
+
+    #[test]
+    fn json_file_is_parsed_by_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("models.json");
+        std::fs::write(
+            &path,
+            r#"{"brief": {"provider": "openai", "model": "gpt-5.2-mini"}}"#,
+        )
+        .unwrap();
+
+        let config = ModelConfig::from_file(&path).unwrap();
+        assert_eq!(config.brief, ModelSpec::openai("gpt-5.2-mini"));
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(matches!(
+            ModelConfig::from_file(&path),
+            Err(ModelConfigError::Json { .. })
+        ));
+    }
+
+    #[test]
+    fn env_overrides_replace_task_models() {
+        let env: HashMap<&str, &str> = HashMap::from([
+            ("RESEARCH_MODEL_QUESTIONS", "openai:gpt-5.2"),
+            ("RESEARCH_MODEL_FALLBACK", "openai:gpt-5.2-mini"),
+            ("RESEARCH_MODEL_SKILL", ""),
+        ]);
+        let lookup = |var: &str| env.get(var).map(|value| value.to_string());
+
+        let config = ModelConfig::default().with_env_overrides(lookup).unwrap();
+        assert_eq!(config.questions, ModelSpec::openai("gpt-5.2"));
+        assert_eq!(config.fallback, ModelSpec::openai("gpt-5.2-mini"));
+        assert_eq!(config.skill, ModelConfig::default().skill);
+
+        let bad = |var: &str| (var == "RESEARCH_MODEL_BRIEF").then(|| "gemini".to_string());
+        let err = ModelConfig::default().with_env_overrides(bad).unwrap_err();
+        assert!(err.to_string().contains("RESEARCH_MODEL_BRIEF"));
+    }
+
+    #[test]
+    fn fallbacks_extend_each_task_chain() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("models.toml");
+        std::fs::write(
+            &path,
+            "[[fallbacks.deep_dive]]\nprovider = \"zai\"\nmodel = \"glm-4.7\"\n\n\
+             [[fallbacks.deep_dive]]\nprovider = \"gemini\"\nmodel = \"gemini-3-flash-preview\"\n",
+        )
+        .unwrap();
+        let config = ModelConfig::from_file(&path).unwrap();
+
+        // The shared fallback is already in the chain, so it isn't repeated
+        let chain: Vec<_> = config
+            .chain(ResearchTask::DeepDive)
+            .iter()
+            .map(|(provider, model)| ModelSpec::new(*provider, model))
+            .collect();
+        assert_eq!(
+            chain,
+            vec![
+                ModelSpec::openai("gpt-5.2"),
+                ModelSpec::zai("glm-4.7"),
+                ModelSpec::gemini("gemini-3-flash-preview"),
+            ]
+        );
+        assert_eq!(config.chain(ResearchTask::Skill).len(), 2);
+    }
+
+    #[test]
+    fn resolve_skips_unconfigured_providers() {
+        let config = ModelConfig::default()
+            .with_fallbacks(ResearchTask::DeepDive, vec![ModelSpec::zai("glm-4.7")]);
+
+        let without_openai = |provider| provider != ModelProvider::OpenAi;
+        assert_eq!(
+            config.resolve(ResearchTask::DeepDive, without_openai),
+            &ModelSpec::zai("glm-4.7")
+        );
+
+        let gemini_only = |provider| provider == ModelProvider::Gemini;
+        assert_eq!(
+            config.resolve(ResearchTask::DeepDive, gemini_only),
+            &config.fallback
+        );
+
+        // With nothing configured the task keeps its own model
+        assert_eq!(
+            config.resolve(ResearchTask::DeepDive, |_| false),
+            &config.deep_dive
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Structured progress reporting for research runs.
+//!
+//! The research workflow reports what it is doing through a
+//! [`ResearchObserver`] rather than printing to stdout, so GUIs and servers
+//! can embed the library and present progress their own way. When no
+//! observer is configured (see
+//! [`ResearchOptions::with_observer`](crate::ResearchOptions::with_observer)),
+//! [`ConsoleObserver`] prints the same output the CLI has always shown.
+//!
+//! Streaming token progress is reported separately through
+//! [`ProgressHandler`](crate::streaming::ProgressHandler).
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::PromptMetrics;
+
+/// What a research task is doing when it reports a start.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskStage {
+    /// Sending a plain completion request.
+    Prompt,
+    /// Running an agent that can call web research tools.
+    AgentPrompt,
+    /// Streaming a completion into the output file at `path`.
+    Streaming { path: PathBuf },
+    /// Collecting version history from package registries and repositories.
+    AggregatingHistory,
+    /// Generating the document from previously gathered data.
+    Generating,
+}
+
+/// How a research task ended.
+#[derive(Debug, Clone)]
+pub enum TaskOutcome {
+    /// The output file was written.
+    ///
+    /// `metrics` is `None` when the task doesn't track token usage. Agent
+    /// tasks report usage summed over all of their turns.
+    Succeeded { metrics: Option<PromptMetrics> },
+    /// The agent ran out of tool calls, and its output was synthesized from
+    /// the results gathered so far.
+    Recovered,
+    /// The task failed; `error` says why.
+    Failed { error: String },
+    /// The run was cancelled while the task was in progress.
+    Cancelled { partial_output_kept: bool },
+}
+
+/// A research task that finished, successfully or not.
+#[derive(Debug, Clone)]
+pub struct TaskReport {
+    /// Task name (e.g. `overview`, `question_2`).
+    pub task: String,
+    /// Tasks finished so far in this phase, including this one.
+    pub completed: usize,
+    /// Tasks in this phase.
+    pub total: usize,
+    /// Seconds since the phase started.
+    pub elapsed_secs: f32,
+    /// How the task ended.
+    pub outcome: TaskOutcome,
+}
+
+/// The phases of a research run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResearchPhase {
+    /// Phase 1: the research prompts, run in parallel.
+    Research,
+    /// Phase 2: the skill files and deep dive synthesized from Phase 1.
+    Synthesis,
+}
+
+impl ResearchPhase {
+    /// The phase number shown to users.
+    pub fn number(self) -> u8 {
+        match self {
+            Self::Research => 1,
+            Self::Synthesis => 2,
+        }
+    }
+}
+
+/// Outcome of a research phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseSummary {
+    /// Which phase finished.
+    pub phase: ResearchPhase,
+    /// Tasks that succeeded.
+    pub succeeded: usize,
+    /// Tasks that ran.
+    pub total: usize,
+    /// Whether the run was cancelled during the phase.
+    pub cancelled: bool,
+}
+
+/// Receives progress events from a research run.
+///
+/// Every method has an empty default, so implementors only override the
+/// events they care about. Tasks run concurrently and call the observer from
+/// their futures, so methods should return quickly.
+///
+/// ## Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use research_lib::ResearchOptions;
+/// use research_lib::observer::{ResearchObserver, TaskOutcome, TaskReport};
+///
+/// #[derive(Default)]
+/// struct FailureCounter(AtomicUsize);
+///
+/// impl ResearchObserver for FailureCounter {
+///     fn on_task_complete(&self, report: &TaskReport) {
+///         if matches!(report.outcome, TaskOutcome::Failed { .. }) {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// let options = ResearchOptions::default().with_observer(Arc::new(FailureCounter::default()));
+/// # let _ = options;
+/// ```
+pub trait ResearchObserver: Send + Sync {
+    /// A task started, or moved on to a new stage.
+    fn on_task_start(&self, _task: &str, _stage: &TaskStage) {}
+
+    /// A task finished.
+    fn on_task_complete(&self, _report: &TaskReport) {}
+
+    /// An agent task called one of its tools.
+    fn on_tool_call(&self, _task: &str, _tool: &str) {}
+
+    /// An agent task received a model response; `usage` is the task's
+    /// running total over all of its turns so far.
+    fn on_token_usage(&self, _task: &str, _usage: &PromptMetrics) {}
+
+    /// A phase finished.
+    fn on_phase_complete(&self, _summary: &PhaseSummary) {}
+
+    /// Something went wrong that doesn't stop the run.
+    fn on_warning(&self, _message: &str) {}
+
+    /// A general status update (e.g. which phase is starting).
+    fn on_info(&self, _message: &str) {}
+}
+
+impl fmt::Debug for dyn ResearchObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ResearchObserver(..)")
+    }
+}
+
+/// Prints research progress to the console, as the CLI does.
+///
+/// Task results and status go to stdout; failures and warnings go to stderr.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConsoleObserver;
+
+impl ResearchObserver for ConsoleObserver {
+    fn on_task_start(&self, task: &str, stage: &TaskStage) {
+        match stage {
+            TaskStage::Prompt => println!("  [{}] Starting...", task),
+            TaskStage::AgentPrompt => println!("  [{}] Starting (with tools)...", task),
+            TaskStage::Streaming { path } => {
+                println!("  [{}] Streaming to {}...", task, path.display())
+            }
+            TaskStage::AggregatingHistory => {
+                println!("  [{}] Aggregating version history...", task)
+            }
+            TaskStage::Generating => println!("  [{}] Starting LLM generation...", task),
+        }
+    }
+
+    fn on_task_complete(&self, report: &TaskReport) {
+        let TaskReport {
+            task,
+            completed,
+            total,
+            elapsed_secs,
+            outcome,
+        } = report;
+
+        match outcome {
+            TaskOutcome::Succeeded {
+                metrics: Some(metrics),
+            } => println!(
+                "  [{}/{}] ✓ {} ({:.1}s) | tokens: {} in, {} out, {} total",
+                completed,
+                total,
+                task,
+                elapsed_secs,
+                metrics.input_tokens,
+                metrics.output_tokens,
+                metrics.total_tokens,
+            ),
+            TaskOutcome::Succeeded { metrics: None } => {
+                println!(
+                    "  [{}/{}] ✓ {} ({:.1}s)",
+                    completed, total, task, elapsed_secs
+                )
+            }
+            TaskOutcome::Recovered => println!(
+                "  [{}/{}] ✓ {} (recovered, {:.1}s)",
+                completed, total, task, elapsed_secs
+            ),
+            TaskOutcome::Failed { error } => eprintln!(
+                "  [{}/{}] ✗ {} failed: {} ({:.1}s)",
+                completed, total, task, error, elapsed_secs
+            ),
+            TaskOutcome::Cancelled {
+                partial_output_kept,
+            } => println!(
+                "  [{}] Cancelled ({})",
+                task,
+                if *partial_output_kept {
+                    "partial output kept"
+                } else {
+                    "response discarded"
+                }
+            ),
+        }
+    }
+
+    fn on_phase_complete(&self, summary: &PhaseSummary) {
+        println!(
+            "\nPhase {} complete: {}/{} succeeded{}\n",
+            summary.phase.number(),
+            summary.succeeded,
+            summary.total,
+            if summary.cancelled {
+                " (cancelled)"
+            } else {
+                ""
+            }
+        );
+    }
+
+    fn on_warning(&self, message: &str) {
+        eprintln!("⚠ {}", message);
+    }
+
+    fn on_info(&self, message: &str) {
+        println!("{}", message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl ResearchObserver for Recorder {
+        fn on_task_start(&self, task: &str, _stage: &TaskStage) {
+            self.0.lock().unwrap().push(format!("start {}", task));
+        }
+
+        fn on_warning(&self, message: &str) {
+            self.0.lock().unwrap().push(format!("warning {}", message));
+        }
+    }
+
+    #[test]
+    fn default_methods_ignore_events() {
+        let recorder = Recorder::default();
+        let observer: &dyn ResearchObserver = &recorder;
+
+        observer.on_task_start("overview", &TaskStage::Prompt);
+        observer.on_phase_complete(&PhaseSummary {
+            phase: ResearchPhase::Research,
+            succeeded: 5,
+            total: 5,
+            cancelled: false,
+        });
+        observer.on_info("Phase 2: Generating consolidated outputs...");
+        observer.on_warning("brief failed");
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            vec!["start overview", "warning brief failed"]
+        );
+    }
+
+    #[test]
+    fn phase_numbers_match_console_output() {
+        assert_eq!(ResearchPhase::Research.number(), 1);
+        assert_eq!(ResearchPhase::Synthesis.number(), 2);
+    }
+
+    #[test]
+    fn observer_trait_objects_are_debug() {
+        let observer: std::sync::Arc<dyn ResearchObserver> = std::sync::Arc::new(ConsoleObserver);
+        assert_eq!(format!("{:?}", observer), "ResearchObserver(..)");
+    }
+}
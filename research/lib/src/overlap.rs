@@ -0,0 +1,220 @@
+//! Semantic overlap detection for additional questions.
+//!
+//! [`ResearchMetadata::check_overlap`] compares the words of a new question
+//! with those of the questions already researched, so paraphrases ("How do I
+//! handle errors?" and "What does failure handling look like?") slip
+//! through. An [`OverlapChecker`] embeds the new question and every earlier
+//! one with an embedding model instead, and ranks the earlier questions by
+//! cosine similarity.
+//!
+//! This module requires the `embeddings` feature. With it enabled,
+//! incremental research checks new questions with [`checker_from_env`] when
+//! `OPENAI_API_KEY` is set, and falls back to word overlap when it isn't or
+//! the embedding request fails.
+//!
+//! ```no_run
+//! use research_lib::ResearchMetadata;
+//! use research_lib::overlap::checker_from_env;
+//!
+//! # async fn example(metadata: &ResearchMetadata) -> Result<(), Box<dyn std::error::Error>> {
+//! if let Some(checker) = checker_from_env() {
+//!     for conflict in checker.conflicts(metadata, "How are errors reported?").await? {
+//!         println!("{} ({:.2})", conflict.filename, conflict.similarity);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cmp::Ordering;
+
+use rig::client::{EmbeddingsClient, ProviderClient};
+use rig::embeddings::{EmbeddingError, EmbeddingModel};
+use rig::providers::openai;
+use thiserror::Error;
+
+use crate::ResearchMetadata;
+
+/// Similarity at or above which an earlier question counts as a conflict.
+pub const DEFAULT_OVERLAP_THRESHOLD: f32 = 0.8;
+
+/// Errors that can occur while checking for overlap.
+#[derive(Debug, Error)]
+pub enum OverlapError {
+    #[error("Embedding request failed: {0}")]
+    Embedding(#[from] EmbeddingError),
+
+    #[error("Expected {expected} embeddings, got {actual}")]
+    MissingEmbeddings { expected: usize, actual: usize },
+}
+
+/// An earlier question that may overlap with a new one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlapCandidate {
+    /// The additional file the earlier question was researched into
+    pub filename: String,
+    /// The earlier question
+    pub prompt: String,
+    /// Cosine similarity of the two questions' embeddings
+    pub similarity: f32,
+}
+
+/// Ranks earlier questions by their semantic similarity to a new one.
+#[derive(Debug, Clone)]
+pub struct OverlapChecker<M> {
+    model: M,
+    threshold: f32,
+}
+
+/// Returns a checker using OpenAI's `text-embedding-3-small`, if
+/// `OPENAI_API_KEY` is set.
+pub fn checker_from_env() -> Option<OverlapChecker<impl EmbeddingModel>> {
+    std::env::var_os("OPENAI_API_KEY")?;
+    let client = openai::Client::from_env();
+    Some(OverlapChecker::new(
+        client.embedding_model(openai::TEXT_EMBEDDING_3_SMALL),
+    ))
+}
+
+impl<M: EmbeddingModel> OverlapChecker<M> {
+    /// Creates a checker embedding questions with `model`.
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            threshold: DEFAULT_OVERLAP_THRESHOLD,
+        }
+    }
+
+    /// Sets the similarity at or above which questions conflict.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Returns the earlier questions in `metadata` that overlap with
+    /// `question`, most similar first.
+    ///
+    /// All questions are embedded in a single request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the embedding request fails.
+    pub async fn conflicts(
+        &self,
+        metadata: &ResearchMetadata,
+        question: &str,
+    ) -> Result<Vec<OverlapCandidate>, OverlapError> {
+        if metadata.additional_files.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let existing: Vec<_> = metadata.additional_files.iter().collect();
+        let texts = std::iter::once(question.to_string())
+            .chain(existing.iter().map(|(_, prompt)| prompt.to_string()))
+            .collect::<Vec<_>>();
+        let embeddings = self.model.embed_texts(texts).await?;
+
+        let Some((query, rest)) = embeddings
+            .split_first()
+            .filter(|(_, rest)| rest.len() == existing.len())
+        else {
+            return Err(OverlapError::MissingEmbeddings {
+                expected: existing.len() + 1,
+                actual: embeddings.len(),
+            });
+        };
+        Ok(rank_conflicts(
+            &query.vec,
+            existing
+                .iter()
+                .zip(rest)
+                .map(|((filename, prompt), embedding)| {
+                    (filename.as_str(), prompt.as_str(), embedding.vec.as_slice())
+                }),
+            self.threshold,
+        ))
+    }
+}
+
+/// Returns the cosine similarity of two vectors, or 0 if either is zero.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::overlap::cosine_similarity;
+///
+/// assert_eq!(cosine_similarity(&[1.0, 0.0], &[2.0, 0.0]), 1.0);
+/// assert_eq!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]), 0.0);
+/// ```
+pub fn cosine_similarity(a: &[f64], b: &[f64]) -> f32 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norms = norm(a) * norm(b);
+    if norms == 0.0 {
+        return 0.0;
+    }
+    (dot / norms) as f32
+}
+
+/// Scores each `(filename, prompt, embedding)` against `query`, keeping those
+/// at or above `threshold`, most similar first.
+pub fn rank_conflicts<'a>(
+    query: &[f64],
+    existing: impl IntoIterator<Item = (&'a str, &'a str, &'a [f64])>,
+    threshold: f32,
+) -> Vec<OverlapCandidate> {
+    let mut candidates: Vec<_> = existing
+        .into_iter()
+        .map(|(filename, prompt, embedding)| OverlapCandidate {
+            filename: filename.to_string(),
+            prompt: prompt.to_string(),
+            similarity: cosine_similarity(query, embedding),
+        })
+        .filter(|candidate| candidate.similarity >= threshold)
+        .collect();
+    candidates.sort_by(|a, b| {
+        b.similarity
+            .partial_cmp(&a.similarity)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.filename.cmp(&b.filename))
+    });
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conflicts_are_ranked_by_similarity() {
+        let existing = [
+            ("question_1.md", "How do I handle errors?", &[0.9, 0.1][..]),
+            (
+                "question_2.md",
+                "Which runtimes are supported?",
+                &[0.0, 1.0][..],
+            ),
+            (
+                "question_3.md",
+                "What does failure handling look like?",
+                &[1.0, 0.0][..],
+            ),
+        ];
+        let ranked = rank_conflicts(&[1.0, 0.0], existing, 0.8);
+
+        let files: Vec<_> = ranked.iter().map(|c| c.filename.as_str()).collect();
+        assert_eq!(files, ["question_3.md", "question_1.md"]);
+        assert_eq!(ranked[0].similarity, 1.0);
+        assert!(ranked[1].similarity > 0.99);
+    }
+
+    #[test]
+    fn zero_vectors_never_conflict() {
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+        assert!(
+            rank_conflicts(&[0.0, 0.0], [("question_1.md", "q", &[1.0, 0.0][..])], 0.0)
+                .iter()
+                .all(|c| c.similarity == 0.0)
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Where the research library lives on disk.
+//!
+//! Every topic, cache entry, clone and config file the library writes lives
+//! under a single root directory, described by [`ResearchPaths`]. The root is
+//! resolved from the environment with [`ResearchPaths::from_env`], in order:
+//!
+//! 1. `$RESEARCH_DIR/.research` when `RESEARCH_DIR` is set
+//! 2. `~/.research` when that directory already exists, so existing
+//!    libraries keep working
+//! 3. The platform data directory: `$XDG_DATA_HOME/research` (default
+//!    `~/.local/share/research`) on Linux, `~/Library/Application
+//!    Support/research` on macOS and `%APPDATA%\research` on Windows
+//! 4. `~/.research` (or `./.research` without a home directory)
+//!
+//! Callers that manage their own library pass a root to
+//! [`ResearchPaths::new`] instead, e.g. to [`crate::list_in`],
+//! [`crate::link::link_in`] or [`crate::ResearchOptions::with_paths`].
+//!
+//! ## Examples
+//!
+//! ```
+//! use research_lib::ResearchKind;
+//! use research_lib::paths::ResearchPaths;
+//!
+//! let paths = ResearchPaths::new("/srv/research");
+//! assert_eq!(
+//!     paths.topic_dir(ResearchKind::Software, "ripgrep"),
+//!     std::path::Path::new("/srv/research/software/ripgrep")
+//! );
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use crate::ResearchKind;
+
+/// Environment variable naming the directory that holds `.research/`.
+pub const RESEARCH_DIR_ENV: &str = "RESEARCH_DIR";
+
+/// The directories and files that make up a research library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResearchPaths {
+    root: PathBuf,
+}
+
+impl ResearchPaths {
+    /// Uses `root` as the library root (the directory holding `library/`,
+    /// `software/`, `cache/` and so on).
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Resolves the library root from the environment.
+    ///
+    /// See the [module documentation](self) for the lookup order.
+    pub fn from_env() -> Self {
+        Self::new(resolve_root(
+            std::env::var_os(RESEARCH_DIR_ENV).map(PathBuf::from),
+            dirs::home_dir(),
+            dirs::data_dir(),
+        ))
+    }
+
+    /// Returns the library root.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the directory holding topics of `kind`.
+    pub fn kind_dir(&self, kind: ResearchKind) -> PathBuf {
+        self.root.join(kind.dir_name())
+    }
+
+    /// Returns the directory of `topic`, researched as `kind`.
+    pub fn topic_dir(&self, kind: ResearchKind, topic: &str) -> PathBuf {
+        self.kind_dir(kind).join(topic)
+    }
+
+    /// Returns the directory holding library topics.
+    pub fn library_dir(&self) -> PathBuf {
+        self.kind_dir(ResearchKind::Library)
+    }
+
+    /// Returns the directory of the shared web tool cache.
+    pub fn cache_dir(&self) -> PathBuf {
+        self.root.join("cache")
+    }
+
+    /// Returns the directory holding cloned source repositories.
+    pub fn repositories_dir(&self) -> PathBuf {
+        self.root.join("repositories")
+    }
+
+    /// Returns the path of the topic inventory.
+    pub fn inventory_path(&self) -> PathBuf {
+        self.root.join("research-inventory.json")
+    }
+
+    /// Returns the first of `{stem}.toml` / `{stem}.json` that exists in the root.
+    pub fn config_file(&self, stem: &str) -> Option<PathBuf> {
+        ["toml", "json"]
+            .into_iter()
+            .map(|ext| self.root.join(format!("{stem}.{ext}")))
+            .find(|path| path.is_file())
+    }
+}
+
+impl Default for ResearchPaths {
+    fn default() -> Self {
+        Self::from_env()
+    }
+}
+
+/// Picks the library root from `RESEARCH_DIR`, the home directory and the
+/// platform data directory.
+fn resolve_root(
+    research_dir: Option<PathBuf>,
+    home: Option<PathBuf>,
+    data_dir: Option<PathBuf>,
+) -> PathBuf {
+    if let Some(dir) = research_dir.filter(|dir| !dir.as_os_str().is_empty()) {
+        return dir.join(".research");
+    }
+
+    let legacy = home.unwrap_or_else(|| PathBuf::from(".")).join(".research");
+    if legacy.is_dir() {
+        return legacy;
+    }
+
+    match data_dir {
+        Some(data_dir) => data_dir.join("research"),
+        None => legacy,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn research_dir_takes_precedence() {
+        let home = TempDir::new().unwrap();
+        std::fs::create_dir(home.path().join(".research")).unwrap();
+
+        let root = resolve_root(
+            Some(PathBuf::from("/srv/research")),
+            Some(home.path().to_path_buf()),
+            Some(PathBuf::from("/data")),
+        );
+        assert_eq!(root, Path::new("/srv/research/.research"));
+    }
+
+    #[test]
+    fn existing_home_library_is_kept() {
+        let home = TempDir::new().unwrap();
+        std::fs::create_dir(home.path().join(".research")).unwrap();
+
+        let root = resolve_root(
+            None,
+            Some(home.path().to_path_buf()),
+            Some(PathBuf::from("/data")),
+        );
+        assert_eq!(root, home.path().join(".research"));
+    }
+
+    #[test]
+    fn new_libraries_use_the_data_dir() {
+        let home = TempDir::new().unwrap();
+
+        let root = resolve_root(
+            Some(PathBuf::new()),
+            Some(home.path().to_path_buf()),
+            Some(PathBuf::from("/data")),
+        );
+        assert_eq!(root, Path::new("/data/research"));
+
+        let root = resolve_root(None, Some(home.path().to_path_buf()), None);
+        assert_eq!(root, home.path().join(".research"));
+    }
+
+    #[test]
+    fn paths_are_derived_from_the_root() {
+        let temp = TempDir::new().unwrap();
+        let paths = ResearchPaths::new(temp.path());
+
+        assert_eq!(paths.library_dir(), temp.path().join("library"));
+        assert_eq!(
+            paths.topic_dir(ResearchKind::Api, "stripe"),
+            temp.path().join("api").join("stripe")
+        );
+        assert_eq!(paths.cache_dir(), temp.path().join("cache"));
+        assert_eq!(
+            paths.inventory_path(),
+            temp.path().join("research-inventory.json")
+        );
+
+        assert_eq!(paths.config_file("models"), None);
+        std::fs::write(temp.path().join("models.json"), "{}").unwrap();
+        assert_eq!(
+            paths.config_file("models"),
+            Some(temp.path().join("models.json"))
+        );
+        std::fs::write(temp.path().join("models.toml"), "").unwrap();
+        assert_eq!(
+            paths.config_file("models"),
+            Some(temp.path().join("models.toml"))
+        );
+    }
+}
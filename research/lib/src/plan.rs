@@ -0,0 +1,859 @@
+//! Dry runs: the execution plan of a research run, built without calling a model.
+//!
+//! [`plan_research`] walks the same decisions as
+//! [`research_with_options`](crate::research_with_options) — full, incremental
+//! or skill-only mode, library lookup, tool availability, model resolution and
+//! prompt templating — and returns them as a [`ResearchPlan`] instead of
+//! running the prompts. Nothing is written to the output directory.
+//!
+//! Package registries are still queried for [`ResearchKind::Library`] topics,
+//! so the library context substituted into the prompts matches a real run.
+//!
+//! Phase 2 prompts are built from the Phase 1 documents already on disk.
+//! Documents the run would (re)generate first appear as `<file from Phase 1>`
+//! markers, and their size is not part of the token estimates.
+//!
+//! A local [`RepositorySource::Path`] is analyzed so the planned prompts
+//! include its context; repositories a real run would clone are not.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::ecosystem::Ecosystem;
+use crate::models::{ModelConfig, ModelProvider, ModelSpec, ResearchTask};
+use crate::observer::{ConsoleObserver, ResearchPhase};
+use crate::question::{Question, answer_label, validate_questions};
+use crate::repository::{self, RepositorySource};
+use crate::templates::PromptTemplate;
+use crate::{
+    EXPECTED_OUTPUTS, LibraryInfo, LibrarySelection, PromptSet, ResearchError, ResearchKind,
+    ResearchMetadata, ResearchOptions, STANDARD_PROMPTS, build_changelog_prompt, build_prompt,
+    default_output_dir_for, find_library, prompts, select_library_with, tools_available,
+};
+
+/// How a research run would treat the topic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanMode {
+    /// No research yet (or `--force`): every prompt runs.
+    Full,
+    /// Existing research: only missing documents and new questions run, then
+    /// Phase 2 is regenerated.
+    Incremental,
+    /// `--skill`: only the skill files are regenerated.
+    SkillRegeneration,
+    /// Existing research with nothing missing and no new questions.
+    UpToDate,
+}
+
+impl fmt::Display for PlanMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PlanMode::Full => "full research",
+            PlanMode::Incremental => "incremental research",
+            PlanMode::SkillRegeneration => "skill regeneration",
+            PlanMode::UpToDate => "up to date (nothing to run)",
+        })
+    }
+}
+
+/// One model request a research run would make.
+#[derive(Debug, Clone)]
+pub struct PlannedTask {
+    /// The task's name, e.g. `overview` or `question_3`.
+    pub name: String,
+    /// The task whose model runs the prompt.
+    pub task: ResearchTask,
+    /// The phase the task runs in.
+    pub phase: ResearchPhase,
+    /// The file written, relative to the output directory.
+    pub output: String,
+    /// The model the prompt is sent to: the first in the task's fallback chain
+    /// whose provider has an API key, or a question's own model when its
+    /// provider has one.
+    pub model: ModelSpec,
+    /// Whether the prompt runs as an agent with web search and scraping tools.
+    pub uses_tools: bool,
+    /// The fully templated prompt.
+    pub prompt: String,
+    /// Estimated input tokens of `prompt`.
+    pub estimated_input_tokens: u64,
+    /// Anything about the task decided only at run time.
+    pub note: Option<String>,
+}
+
+/// Everything a research run would do, in the order it would do it.
+#[derive(Debug, Clone)]
+pub struct ResearchPlan {
+    pub topic: String,
+    pub kind: ResearchKind,
+    pub output_dir: PathBuf,
+    pub mode: PlanMode,
+    /// The package the prompts are templated with, if one was found.
+    pub library: Option<LibraryInfo>,
+    /// Whose library prompt variants are used; generic for other kinds.
+    pub ecosystem: Ecosystem,
+    /// Whether Phase 1 prompts get web tools (`BRAVE_API_KEY` is set).
+    pub tools_enabled: bool,
+    pub tasks: Vec<PlannedTask>,
+    /// Things a real run would warn about.
+    pub warnings: Vec<String>,
+}
+
+impl ResearchPlan {
+    /// Returns the planned tasks of `phase`.
+    pub fn phase(&self, phase: ResearchPhase) -> impl Iterator<Item = &PlannedTask> {
+        self.tasks.iter().filter(move |task| task.phase == phase)
+    }
+
+    /// Returns the estimated input tokens of every planned prompt.
+    pub fn estimated_input_tokens(&self) -> u64 {
+        self.tasks
+            .iter()
+            .map(|task| task.estimated_input_tokens)
+            .sum()
+    }
+}
+
+/// Prints a summary of the plan; the alternate form (`{:#}`) adds every prompt.
+impl fmt::Display for ResearchPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Research plan for '{}' ({})", self.topic, self.mode)?;
+        writeln!(f, "  Output:  {}", self.output_dir.display())?;
+        match &self.library {
+            Some(library) => writeln!(f, "  Library: {} <{}>", library, library.url)?,
+            None => writeln!(f, "  Library: none")?,
+        }
+        writeln!(
+            f,
+            "  Prompts: {} ({})",
+            self.kind.dir_name(),
+            self.ecosystem
+        )?;
+        writeln!(
+            f,
+            "  Tools:   {}",
+            if self.tools_enabled {
+                "web search and scraping"
+            } else {
+                "disabled (set BRAVE_API_KEY to enable)"
+            }
+        )?;
+        for warning in &self.warnings {
+            writeln!(f, "  ⚠ {}", warning)?;
+        }
+
+        for phase in [ResearchPhase::Research, ResearchPhase::Synthesis] {
+            let tasks: Vec<_> = self.phase(phase).collect();
+            if tasks.is_empty() {
+                continue;
+            }
+            writeln!(f, "\nPhase {}:", phase.number())?;
+            for task in tasks {
+                writeln!(
+                    f,
+                    "  {:<22} {:<34} ~{:>6} tokens  {}{}",
+                    task.name,
+                    task.model.to_string(),
+                    task.estimated_input_tokens,
+                    task.output,
+                    if task.uses_tools { "  [tools]" } else { "" }
+                )?;
+                if let Some(note) = &task.note {
+                    writeln!(f, "  {:<22} {}", "", note)?;
+                }
+            }
+        }
+        writeln!(
+            f,
+            "\n{} prompt(s), ~{} input tokens (excluding Phase 1 output)",
+            self.tasks.len(),
+            self.estimated_input_tokens()
+        )?;
+
+        if f.alternate() {
+            for task in &self.tasks {
+                writeln!(f, "\n===== {} ({}) =====\n", task.name, task.output)?;
+                writeln!(f, "{}", task.prompt.trim_end())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Estimates the tokens in `text`, at roughly four characters per token.
+pub fn estimate_tokens(text: &str) -> u64 {
+    (text.chars().count() as u64).div_ceil(4)
+}
+
+/// Builds the plan for researching `topic` with the same arguments as
+/// [`research_with_options`](crate::research_with_options), without calling
+/// any model provider or touching the output directory.
+///
+/// Overlapping questions are planned with a note, since a real run asks
+/// whether to include them.
+///
+/// ## Errors
+///
+/// Returns the errors a real run would fail with before its first prompt:
+/// invalid questions, conflicting flags, an invalid model configuration, or
+/// (with `skill_regenerate`) missing research to regenerate the skill from.
+pub async fn plan_research(
+    topic: &str,
+    output_dir: Option<PathBuf>,
+    questions: &[Question],
+    skill_regenerate: bool,
+    force_recreation: bool,
+    options: &ResearchOptions,
+) -> Result<ResearchPlan, ResearchError> {
+    dotenvy::dotenv().ok();
+
+    let models = match &options.models {
+        Some(models) => models.clone(),
+        None => ModelConfig::load()?,
+    };
+    validate_questions(questions)?;
+
+    if skill_regenerate && force_recreation {
+        return Err(ResearchError::InvalidFlagCombination(
+            "Cannot use --skill and --force together. Use --force alone to regenerate everything, or --skill to regenerate only skill files.".to_string()
+        ));
+    }
+
+    let kind = options.kind;
+    let output_dir = output_dir.unwrap_or_else(|| default_output_dir_for(kind, topic));
+    let mut planner = Planner {
+        topic,
+        output_dir: &output_dir,
+        models,
+        ecosystem: options.ecosystem,
+        repository_context: None,
+        configured: ModelProvider::ALL
+            .into_iter()
+            .filter(|provider| provider.is_configured())
+            .collect(),
+        tools_enabled: tools_available(),
+        tasks: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    let existing = if force_recreation {
+        planner
+            .warnings
+            .push("--force deletes the existing research documents first".to_string());
+        None
+    } else {
+        ResearchMetadata::load(&output_dir).await
+    };
+
+    let (mode, kind, library) = if skill_regenerate {
+        let metadata = existing.ok_or_else(|| {
+            ResearchError::SkillRegenerationFailed(
+                "No metadata.json found. Run research without --skill first.".to_string(),
+            )
+        })?;
+        planner.plan_skill_regeneration(&metadata)?;
+        (
+            PlanMode::SkillRegeneration,
+            metadata.kind,
+            library_from_metadata(&metadata),
+        )
+    } else if let Some(metadata) = existing {
+        if options.repository.is_some() {
+            planner
+                .warnings
+                .push("Repository context is only added to full research runs".to_string());
+        }
+        let library = library_from_metadata(&metadata);
+        let mode = planner.plan_incremental(&metadata, library.as_ref(), questions);
+        (mode, metadata.kind, library)
+    } else {
+        // Software and standards aren't packages, so they skip the lookup
+        let library = if kind == ResearchKind::Library {
+            let observer = options
+                .observer
+                .clone()
+                .unwrap_or_else(|| Arc::new(ConsoleObserver));
+            let matches = find_library(topic).await;
+            match select_library_with(matches, topic, &options.selection, observer.as_ref()) {
+                LibrarySelection::Selected(info) | LibrarySelection::Single(info) => Some(info),
+                LibrarySelection::Ambiguous(libraries) => {
+                    return Err(ResearchError::ambiguous_library(topic, &libraries));
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+        planner.repository_context = match &options.repository {
+            Some(source @ RepositorySource::Path(_)) => {
+                match repository::repository_context(source, library.as_ref()).await {
+                    Ok(context) => Some(context.to_prompt()),
+                    Err(e) => {
+                        planner
+                            .warnings
+                            .push(format!("Researching without repository context: {}", e));
+                        None
+                    }
+                }
+            }
+            Some(source) => {
+                planner.warnings.push(format!(
+                    "The repository ({}) is cloned at run time, so its context isn't shown",
+                    source
+                ));
+                None
+            }
+            None => None,
+        };
+        planner.plan_full(kind, library.as_ref(), questions);
+        (PlanMode::Full, kind, library)
+    };
+    let ecosystem = match kind {
+        ResearchKind::Library => planner.ecosystem(library.as_ref()),
+        _ => Ecosystem::Generic,
+    };
+
+    let Planner {
+        tools_enabled,
+        tasks,
+        warnings,
+        ..
+    } = planner;
+    Ok(ResearchPlan {
+        topic: topic.to_string(),
+        kind,
+        output_dir,
+        mode,
+        library,
+        ecosystem,
+        tools_enabled,
+        tasks,
+        warnings,
+    })
+}
+
+/// Builds the library context an incremental run templates its prompts with.
+fn library_from_metadata(metadata: &ResearchMetadata) -> Option<LibraryInfo> {
+    metadata.library_details().map(|details| LibraryInfo {
+        package_manager: details
+            .package_manager
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string()),
+        language: details
+            .language
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string()),
+        url: details.url.clone().unwrap_or_else(|| "N/A".to_string()),
+        repository: details.repository.clone(),
+        description: None,
+    })
+}
+
+/// Collects the tasks of a plan.
+struct Planner<'a> {
+    topic: &'a str,
+    output_dir: &'a Path,
+    /// The `--ecosystem` override, if any
+    ecosystem: Option<Ecosystem>,
+    /// Rendered repository context for the grounded Phase 1 prompts
+    repository_context: Option<String>,
+    models: ModelConfig,
+    /// Providers whose API key is set
+    configured: Vec<ModelProvider>,
+    tools_enabled: bool,
+    tasks: Vec<PlannedTask>,
+    warnings: Vec<String>,
+}
+
+impl Planner<'_> {
+    fn ecosystem(&self, library: Option<&LibraryInfo>) -> Ecosystem {
+        Ecosystem::resolve(self.ecosystem, library.map(|li| li.language.as_str()))
+    }
+
+    fn push(
+        &mut self,
+        name: impl Into<String>,
+        task: ResearchTask,
+        phase: ResearchPhase,
+        output: impl Into<String>,
+        prompt: String,
+        note: Option<String>,
+    ) {
+        self.tasks.push(PlannedTask {
+            name: name.into(),
+            task,
+            phase,
+            output: output.into(),
+            model: self
+                .models
+                .resolve(task, |provider| self.configured.contains(&provider))
+                .clone(),
+            uses_tools: phase == ResearchPhase::Research && self.tools_enabled,
+            estimated_input_tokens: estimate_tokens(&prompt),
+            prompt,
+            note,
+        });
+    }
+
+    /// Plans a Phase 1 prompt from the kind's templates.
+    fn push_standard(
+        &mut self,
+        prompt_set: &PromptSet,
+        (name, filename, template): (&'static str, &'static str, PromptTemplate),
+        library: Option<&LibraryInfo>,
+    ) {
+        let task = ResearchTask::from_name(name).unwrap_or(ResearchTask::Questions);
+        let template = prompt_set.template(name).unwrap_or_else(|| template.get());
+        let (prompt, note) = if task == ResearchTask::Changelog {
+            (
+                build_changelog_prompt(template, self.topic, library, None),
+                Some(
+                    "version history is gathered from GitHub and the registry at run time"
+                        .to_string(),
+                ),
+            )
+        } else {
+            (build_prompt(template, self.topic, library), None)
+        };
+        let prompt = repository::ground_prompt(prompt, name, self.repository_context.as_deref());
+        self.push(name, task, ResearchPhase::Research, filename, prompt, note);
+    }
+
+    /// Plans question `num`, on its own model and tool choice when it has them.
+    fn push_question(
+        &mut self,
+        prompt_set: &PromptSet,
+        num: usize,
+        question: &Question,
+        library: Option<&LibraryInfo>,
+        note: Option<String>,
+    ) {
+        let name = format!("question_{}", num);
+        let prompt = repository::ground_prompt(
+            build_prompt(prompt_set.additional_question.get(), self.topic, library)
+                .replace("{{question}}", &question.text),
+            &name,
+            self.repository_context.as_deref(),
+        );
+        let model = question
+            .model
+            .as_ref()
+            .filter(|model| self.configured.contains(&model.provider))
+            .cloned();
+        let uses_tools = question.uses_tools(self.tools_enabled);
+        self.push(
+            name,
+            ResearchTask::Questions,
+            ResearchPhase::Research,
+            question.filename(num),
+            prompt,
+            note,
+        );
+        if let Some(planned) = self.tasks.last_mut() {
+            if let Some(model) = model {
+                planned.model = model;
+            }
+            planned.uses_tools = uses_tools;
+        }
+    }
+
+    fn plan_full(
+        &mut self,
+        kind: ResearchKind,
+        library: Option<&LibraryInfo>,
+        questions: &[Question],
+    ) {
+        let prompt_set = PromptSet::for_topic(kind, self.ecosystem(library));
+        for prompt in STANDARD_PROMPTS {
+            self.push_standard(prompt_set, prompt, library);
+        }
+        for (i, question) in questions.iter().enumerate() {
+            self.push_question(prompt_set, i + 1, question, library, None);
+        }
+
+        let question_files: Vec<_> = questions
+            .iter()
+            .enumerate()
+            .map(|(i, question)| question.filename(i + 1))
+            .collect();
+        self.plan_synthesis(&question_files);
+    }
+
+    fn plan_incremental(
+        &mut self,
+        metadata: &ResearchMetadata,
+        library: Option<&LibraryInfo>,
+        questions: &[Question],
+    ) -> PlanMode {
+        let prompt_set = PromptSet::for_topic(metadata.kind, self.ecosystem(library));
+        let missing_prompts: Vec<_> = STANDARD_PROMPTS
+            .into_iter()
+            .filter(|(_, filename, _)| !self.output_dir.join(filename).exists())
+            .collect();
+        for &prompt in &missing_prompts {
+            self.push_standard(prompt_set, prompt, library);
+        }
+        if !missing_prompts.is_empty() {
+            self.warnings.push(format!(
+                "Missing {} standard prompt(s)",
+                missing_prompts.len()
+            ));
+        }
+
+        let mut question_files: Vec<_> = metadata.additional_files.keys().cloned().collect();
+        for (num, question) in (metadata.next_question_number()..).zip(questions) {
+            let note = metadata.check_overlap(&question.text).map(|conflict| {
+                format!(
+                    "overlaps with existing {}; a real run asks before including it",
+                    conflict
+                )
+            });
+            self.push_question(prompt_set, num, question, library, note);
+            question_files.push(question.filename(num));
+        }
+
+        let missing_outputs = EXPECTED_OUTPUTS
+            .iter()
+            .filter(|(_, filename)| !self.output_dir.join(filename).exists())
+            .count();
+        if missing_outputs > 0 {
+            self.warnings
+                .push(format!("Missing {} output file(s)", missing_outputs));
+        }
+        if self.tasks.is_empty() && missing_outputs == 0 {
+            return PlanMode::UpToDate;
+        }
+
+        question_files.sort();
+        question_files.dedup();
+        self.plan_synthesis(&question_files);
+        PlanMode::Incremental
+    }
+
+    fn plan_skill_regeneration(
+        &mut self,
+        metadata: &ResearchMetadata,
+    ) -> Result<(), ResearchError> {
+        if metadata.kind == ResearchKind::Api {
+            return Err(ResearchError::SkillRegenerationFailed(format!(
+                "Cannot regenerate skill for API research (found: {:?})",
+                metadata.kind
+            )));
+        }
+
+        let mut question_files: Vec<_> = metadata.additional_files.keys().cloned().collect();
+        question_files.sort();
+        let missing_docs: Vec<_> = STANDARD_PROMPTS
+            .iter()
+            .map(|(_, filename, _)| filename.to_string())
+            .chain(question_files.iter().cloned())
+            .filter(|doc| !self.output_dir.join(doc).exists())
+            .collect();
+        if !missing_docs.is_empty() {
+            return Err(ResearchError::SkillRegenerationFailed(format!(
+                "Cannot regenerate skill: Missing underlying research documents: {}",
+                missing_docs.join(", ")
+            )));
+        }
+
+        let context = self.context(&question_files);
+        self.push_skill(&context);
+        Ok(())
+    }
+
+    /// Plans the skill, deep dive and brief from the Phase 1 documents.
+    fn plan_synthesis(&mut self, question_files: &[String]) {
+        let context = self.context(question_files);
+        self.push_skill(&context);
+
+        let deep_dive = format!("deep-dive/{}.md", self.topic);
+        let prompt = prompts::DEEP_DIVE
+            .get()
+            .replace("{{topic}}", self.topic)
+            .replace("{{context}}", &context);
+        self.push(
+            "deep_dive",
+            ResearchTask::DeepDive,
+            ResearchPhase::Synthesis,
+            deep_dive.clone(),
+            prompt,
+            None,
+        );
+
+        let prompt = prompts::BRIEF
+            .get()
+            .replace("{{topic}}", self.topic)
+            .replace("{{deep_dive}}", &format!("<{} from Phase 2>", deep_dive));
+        self.push(
+            "brief",
+            ResearchTask::Brief,
+            ResearchPhase::Synthesis,
+            "brief.md",
+            prompt,
+            Some("runs once the deep dive succeeds".to_string()),
+        );
+    }
+
+    fn push_skill(&mut self, context: &str) {
+        let prompt = prompts::SKILL
+            .get()
+            .replace("{{topic}}", self.topic)
+            .replace("{{context}}", context);
+        self.push(
+            "skill",
+            ResearchTask::Skill,
+            ResearchPhase::Synthesis,
+            "skill/SKILL.md",
+            prompt,
+            None,
+        );
+    }
+
+    /// Builds the Phase 2 context from the Phase 1 documents, marking the
+    /// ones this run would write first.
+    fn context(&self, question_files: &[String]) -> String {
+        let document = |filename: &str| {
+            let pending = self
+                .tasks
+                .iter()
+                .any(|task| task.phase == ResearchPhase::Research && task.output == filename);
+            if pending {
+                format!("<{} from Phase 1>", filename)
+            } else {
+                std::fs::read_to_string(self.output_dir.join(filename)).unwrap_or_default()
+            }
+        };
+
+        let mut additional_content = String::new();
+        for filename in question_files {
+            let content = document(filename.as_str());
+            if content.is_empty() {
+                continue;
+            }
+            additional_content.push_str(&format!(
+                "\n## Additional Research: Question {}\n\n{}\n",
+                answer_label(filename),
+                content
+            ));
+        }
+
+        prompts::CONTEXT
+            .get()
+            .replace("{{topic}}", self.topic)
+            .replace("{{overview}}", &document("overview.md"))
+            .replace("{{similar_libraries}}", &document("similar_libraries.md"))
+            .replace(
+                "{{integration_partners}}",
+                &document("integration_partners.md"),
+            )
+            .replace("{{use_cases}}", &document("use_cases.md"))
+            .replace("{{changelog}}", &document("changelog.md"))
+            .replace("{{additional_content}}", &additional_content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unchained_ai::rigging::providers::client_adaptors::zai;
+
+    fn planner(output_dir: &Path) -> Planner<'_> {
+        Planner {
+            topic: "clap",
+            output_dir,
+            ecosystem: None,
+            repository_context: None,
+            models: ModelConfig::default(),
+            configured: vec![ModelProvider::Gemini, ModelProvider::OpenAi],
+            tools_enabled: false,
+            tasks: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    fn library() -> LibraryInfo {
+        LibraryInfo {
+            package_manager: "crates.io".to_string(),
+            language: "Rust".to_string(),
+            url: "https://crates.io/crates/clap".to_string(),
+            repository: None,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn estimate_rounds_up_to_whole_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+    }
+
+    #[test]
+    fn full_plan_templates_every_prompt_with_the_library() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut planner = planner(dir.path());
+        let library = library();
+        planner.plan_full(
+            ResearchKind::Library,
+            Some(&library),
+            &[Question::from("How do derive macros work?")],
+        );
+
+        let names: Vec<_> = planner.tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "overview",
+                "similar_libraries",
+                "integration_partners",
+                "use_cases",
+                "changelog",
+                "question_1",
+                "skill",
+                "deep_dive",
+                "brief"
+            ]
+        );
+        for task in &planner.tasks {
+            assert!(!task.prompt.contains("{{topic}}"), "{}", task.name);
+            assert!(
+                !task.prompt.contains("{{package_manager}}"),
+                "{}",
+                task.name
+            );
+            assert!(task.estimated_input_tokens > 0);
+        }
+        assert!(
+            planner.tasks[5]
+                .prompt
+                .contains("How do derive macros work?")
+        );
+        assert!(planner.tasks[0].prompt.contains("crates.io"));
+        assert!(
+            planner.tasks[7]
+                .prompt
+                .contains("<overview.md from Phase 1>")
+        );
+        assert!(
+            planner.tasks[7]
+                .prompt
+                .contains("<question_1.md from Phase 1>")
+        );
+    }
+
+    #[test]
+    fn library_prompts_follow_the_library_language() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut rust = planner(dir.path());
+        rust.plan_full(ResearchKind::Library, Some(&library()), &[]);
+        assert!(rust.tasks[0].prompt.contains("`cargo add`"));
+
+        let mut generic = planner(dir.path());
+        generic.ecosystem = Some(Ecosystem::Generic);
+        generic.plan_full(ResearchKind::Library, Some(&library()), &[]);
+        assert!(!generic.tasks[0].prompt.contains("`cargo add`"));
+    }
+
+    #[test]
+    fn repository_context_grounds_api_prompts_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut planner = planner(dir.path());
+        planner.repository_context = Some("## Source Repository".to_string());
+        planner.plan_full(
+            ResearchKind::Library,
+            Some(&library()),
+            &[Question::from("How do derive macros work?")],
+        );
+
+        let grounded: Vec<_> = planner
+            .tasks
+            .iter()
+            .filter(|t| t.prompt.contains("## Source Repository"))
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(
+            grounded,
+            [
+                "overview",
+                "integration_partners",
+                "use_cases",
+                "question_1"
+            ]
+        );
+    }
+
+    #[test]
+    fn zai_tasks_resolve_to_the_fallback_without_a_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut planner = planner(dir.path());
+        planner.plan_full(ResearchKind::Library, None, &[]);
+
+        let overview = &planner.tasks[0];
+        assert_eq!(
+            ModelConfig::default().overview,
+            ModelSpec::zai(zai::GLM_4_7)
+        );
+        assert_eq!(overview.model, ModelConfig::default().fallback);
+    }
+
+    #[test]
+    fn questions_use_their_own_model_tools_and_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut planner = planner(dir.path());
+        planner.tools_enabled = true;
+        let strong = ModelSpec::new(ModelProvider::OpenAi, "o3");
+        planner.plan_full(
+            ResearchKind::Library,
+            None,
+            &[
+                Question::new("Hard?")
+                    .with_model(strong.clone())
+                    .with_filename("hard"),
+                Question::new("Simple?").with_tools(false),
+                Question::new("Unconfigured?")
+                    .with_model(ModelSpec::new(ModelProvider::Anthropic, "claude-opus-4-5")),
+            ],
+        );
+
+        let questions: Vec<_> = planner
+            .tasks
+            .iter()
+            .filter(|t| t.task == ResearchTask::Questions)
+            .collect();
+        assert_eq!(questions[0].model, strong);
+        assert_eq!(questions[0].output, "hard.md");
+        assert!(questions[0].uses_tools);
+        assert_eq!(questions[1].output, "question_2.md");
+        assert!(!questions[1].uses_tools);
+        let config = ModelConfig::default();
+        let default = config.resolve(ResearchTask::Questions, |provider| {
+            planner.configured.contains(&provider)
+        });
+        assert_eq!(&questions[2].model, default);
+        let deep_dive = planner
+            .tasks
+            .iter()
+            .find(|t| t.name == "deep_dive")
+            .unwrap();
+        assert!(
+            deep_dive
+                .prompt
+                .contains("## Additional Research: Question hard")
+        );
+        assert!(deep_dive.prompt.contains("<hard.md from Phase 1>"));
+    }
+
+    #[test]
+    fn synthesis_reads_documents_already_on_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("overview.md"), "Clap parses arguments.").unwrap();
+        let mut planner = planner(dir.path());
+        planner.plan_synthesis(&[]);
+
+        let skill = &planner.tasks[0];
+        assert_eq!(skill.name, "skill");
+        assert!(skill.prompt.contains("Clap parses arguments."));
+        assert!(!skill.prompt.contains("from Phase 1>"));
+    }
+}
@@ -0,0 +1,460 @@
+//! Hand-written sections that survive regeneration.
+//!
+//! Research documents are rewritten whenever a topic is researched again, so
+//! edits to them are normally lost. Wrapping an edit in keep markers protects
+//! it:
+//!
+//! ```markdown
+//! ## Installation
+//!
+//! Generated text...
+//!
+//! <!-- biscuit:keep -->
+//! Our fork pins `tokio` to 1.38 until the 1.39 regression is fixed.
+//! <!-- /biscuit:keep -->
+//! ```
+//!
+//! When a file is regenerated, every protected section in the previous version
+//! is carried over verbatim. A section is anchored to the heading line it
+//! follows and is re-inserted at the end of that heading's section in the new
+//! version (before the next heading). Sections that come before any heading
+//! are placed at the start of the body, after the frontmatter.
+//!
+//! Sections that can't be carried over as written are reported as
+//! [`UnpreservedBlock`]s:
+//!
+//! - when the anchor heading no longer exists, the section is moved to the end
+//!   of the file
+//! - an opening marker without a closing marker is dropped along with the text
+//!   after it, since its extent is unknown
+//! - a closing marker without an opening marker is ignored
+//!
+//! Protected sections only ever come from the file on disk: markers in the
+//! regenerated content are removed before the previous sections are
+//! re-inserted. Markers inside fenced code blocks are not directives.
+//!
+//! ## Examples
+//!
+//! ```
+//! use research_lib::protected::preserve;
+//!
+//! let existing = "# Tokio\n\nOld text.\n\n<!-- biscuit:keep -->\nMine.\n<!-- /biscuit:keep -->\n";
+//! let regenerated = "# Tokio\n\nNew text.\n";
+//!
+//! let preserved = preserve(existing, regenerated);
+//! assert_eq!(
+//!     preserved.content,
+//!     "# Tokio\n\nNew text.\n\n<!-- biscuit:keep -->\nMine.\n<!-- /biscuit:keep -->\n"
+//! );
+//! assert!(preserved.unpreserved.is_empty());
+//! ```
+
+use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+
+use tokio::fs;
+
+/// Marker that opens a protected section.
+pub const KEEP_START: &str = "<!-- biscuit:keep -->";
+
+/// Marker that closes a protected section.
+pub const KEEP_END: &str = "<!-- /biscuit:keep -->";
+
+/// Why a protected section was not carried over as written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnpreservedReason {
+    /// The heading the section followed is missing from the regenerated
+    /// file; the section was moved to the end of the file.
+    MissingAnchor(String),
+    /// The opening marker has no closing marker; the section was dropped.
+    Unclosed,
+    /// A closing marker without an opening marker; it was ignored.
+    Unopened,
+}
+
+impl fmt::Display for UnpreservedReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingAnchor(heading) => write!(
+                f,
+                "was moved to the end of the file: heading '{}' no longer exists",
+                heading
+            ),
+            Self::Unclosed => write!(f, "has no closing marker and was dropped"),
+            Self::Unopened => write!(f, "is a closing marker without an opening marker"),
+        }
+    }
+}
+
+/// A protected section that could not be carried over as written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnpreservedBlock {
+    /// 1-based line of the marker in the previous version of the file
+    pub line: usize,
+    /// What happened to the section
+    pub reason: UnpreservedReason,
+}
+
+/// The result of [`preserve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preserved {
+    /// The regenerated content with the protected sections re-inserted
+    pub content: String,
+    /// Sections that could not be carried over as written
+    pub unpreserved: Vec<UnpreservedBlock>,
+}
+
+/// A protected section found in a document.
+#[derive(Debug)]
+struct Block {
+    /// The heading line the section follows, if any
+    anchor: Option<String>,
+    /// Byte range of the section, from the opening marker to the end of the
+    /// closing marker's line
+    span: Range<usize>,
+    /// 1-based line of the opening marker
+    line: usize,
+}
+
+/// The protected sections and structure of a document.
+#[derive(Debug, Default)]
+struct Scan {
+    blocks: Vec<Block>,
+    problems: Vec<UnpreservedBlock>,
+    /// Byte offset of each heading line, with the line trimmed
+    headings: Vec<(usize, String)>,
+    /// Byte offset where the body starts, after any frontmatter
+    body_start: usize,
+}
+
+/// Re-inserts the protected sections of `existing` into `regenerated`.
+///
+/// See the [module documentation](self) for where sections are placed.
+pub fn preserve(existing: &str, regenerated: &str) -> Preserved {
+    let previous = scan(existing);
+    let stripped = strip_blocks(regenerated);
+    let target = scan(&stripped);
+
+    let body = target.body_start + blank_prefix_len(&stripped[target.body_start..]);
+
+    let mut unpreserved = previous.problems;
+    // (byte offset in `stripped`, section text); `usize::MAX` is end of file
+    let mut inserts: Vec<(usize, &str)> = Vec::new();
+    for block in &previous.blocks {
+        let text = existing[block.span.clone()].trim_end_matches(['\r', '\n']);
+        let offset = match &block.anchor {
+            None => body,
+            Some(anchor) => match target.headings.iter().position(|(_, h)| h == anchor) {
+                Some(i) => target
+                    .headings
+                    .get(i + 1)
+                    .map_or(usize::MAX, |(offset, _)| *offset),
+                None => {
+                    unpreserved.push(UnpreservedBlock {
+                        line: block.line,
+                        reason: UnpreservedReason::MissingAnchor(anchor.clone()),
+                    });
+                    usize::MAX
+                }
+            },
+        };
+        inserts.push((offset, text));
+    }
+    // Stable, so sections sharing an anchor keep their order
+    inserts.sort_by_key(|(offset, _)| *offset);
+    unpreserved.sort_by_key(|block| block.line);
+
+    let mut content = String::with_capacity(stripped.len() + existing.len());
+    let mut copied = 0;
+    for (offset, text) in inserts {
+        let at_end = offset == usize::MAX;
+        let offset = offset.min(stripped.len());
+        content.push_str(&stripped[copied..offset]);
+        copied = offset;
+
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        if offset != body && !content.is_empty() && !content.ends_with("\n\n") {
+            content.push('\n');
+        }
+        content.push_str(text);
+        content.push('\n');
+        if !at_end && offset < stripped.len() {
+            content.push('\n');
+        }
+    }
+    content.push_str(&stripped[copied..]);
+
+    Preserved {
+        content,
+        unpreserved,
+    }
+}
+
+/// Writes regenerated `content` to `path`, carrying over the protected
+/// sections of the file it replaces.
+///
+/// ## Returns
+///
+/// The sections that could not be carried over as written.
+pub async fn write_preserving(path: &Path, content: &str) -> io::Result<Vec<UnpreservedBlock>> {
+    let existing = read_existing(path).await?;
+    reapply(path, &existing, content).await
+}
+
+/// Reads the current version of a file that is about to be regenerated, for
+/// use with [`reapply`] once the new version has been written.
+///
+/// A missing file reads as empty.
+pub async fn read_existing(path: &Path) -> io::Result<String> {
+    match fs::read(path).await {
+        Ok(bytes) => Ok(String::from_utf8_lossy(&bytes).into_owned()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Writes `content` to `path` with the protected sections of `existing`, a
+/// previous version read with [`read_existing`].
+///
+/// ## Returns
+///
+/// The sections that could not be carried over as written.
+pub async fn reapply(
+    path: &Path,
+    existing: &str,
+    content: &str,
+) -> io::Result<Vec<UnpreservedBlock>> {
+    let preserved = preserve(existing, content);
+    fs::write(path, &preserved.content).await?;
+    Ok(preserved.unpreserved)
+}
+
+/// Returns `true` if `line` is the marker `marker`, ignoring whitespace.
+fn is_marker(line: &str, marker: &str) -> bool {
+    let mut chars = line.chars().filter(|c| !c.is_whitespace());
+    let mut expected = marker.chars().filter(|c| !c.is_whitespace());
+    loop {
+        match (chars.next(), expected.next()) {
+            (None, None) => return true,
+            (Some(a), Some(b)) if a == b => {}
+            _ => return false,
+        }
+    }
+}
+
+/// Returns `true` for ATX heading lines (`#` to `######` followed by a space).
+fn is_heading(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    (1..=6).contains(&level)
+        && trimmed[level..]
+            .chars()
+            .next()
+            .is_none_or(char::is_whitespace)
+}
+
+/// Returns `true` for lines that open or close a fenced code block.
+fn is_fence(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+/// Finds the protected sections, headings and body start of `content`.
+fn scan(content: &str) -> Scan {
+    let mut result = Scan::default();
+    let mut lines = content.split_inclusive('\n').enumerate().peekable();
+    let mut offset = 0;
+
+    // Skip YAML frontmatter
+    if lines
+        .peek()
+        .is_some_and(|(_, line)| line.trim_end() == "---")
+    {
+        let closing = content
+            .split_inclusive('\n')
+            .skip(1)
+            .position(|line| line.trim_end() == "---");
+        if let Some(closing) = closing {
+            for (_, line) in lines.by_ref().take(closing + 2) {
+                offset += line.len();
+            }
+            result.body_start = offset;
+        }
+    }
+
+    let mut in_fence = false;
+    let mut anchor: Option<String> = None;
+    let mut open: Option<(usize, usize, Option<String>)> = None;
+    for (index, line) in lines {
+        let start = offset;
+        offset += line.len();
+
+        if is_fence(line) {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        if is_marker(line, KEEP_START) {
+            // A nested opening marker is part of the section's text
+            if open.is_none() {
+                open = Some((start, index + 1, anchor.clone()));
+            }
+        } else if is_marker(line, KEEP_END) {
+            match open.take() {
+                Some((block_start, block_line, block_anchor)) => result.blocks.push(Block {
+                    anchor: block_anchor,
+                    span: block_start..offset,
+                    line: block_line,
+                }),
+                None => result.problems.push(UnpreservedBlock {
+                    line: index + 1,
+                    reason: UnpreservedReason::Unopened,
+                }),
+            }
+        } else if open.is_none() && is_heading(line) {
+            let heading = line.trim().to_string();
+            result.headings.push((start, heading.clone()));
+            anchor = Some(heading);
+        }
+    }
+
+    if let Some((_, line, _)) = open {
+        result.problems.push(UnpreservedBlock {
+            line,
+            reason: UnpreservedReason::Unclosed,
+        });
+    }
+    result
+}
+
+/// Returns the length of the blank lines at the start of `text`.
+fn blank_prefix_len(text: &str) -> usize {
+    text.len() - text.trim_start_matches(['\r', '\n']).len()
+}
+
+/// Removes the protected sections of `content`, along with the blank lines
+/// that follow them.
+fn strip_blocks(content: &str) -> String {
+    let blocks = scan(content).blocks;
+    if blocks.is_empty() {
+        return content.to_string();
+    }
+
+    let mut stripped = String::with_capacity(content.len());
+    let mut copied = 0;
+    for block in blocks {
+        stripped.push_str(&content[copied..block.span.start]);
+        copied = block.span.end + blank_prefix_len(&content[block.span.end..]);
+    }
+    stripped.push_str(&content[copied..]);
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEEP: &str = "<!-- biscuit:keep -->\nHand-written.\n<!-- /biscuit:keep -->";
+
+    #[test]
+    fn reinserts_sections_under_their_heading() {
+        let existing =
+            format!("# Tokio\n\nOld intro.\n\n## Install\n\nOld.\n\n{KEEP}\n\n## Usage\n\nOld.\n");
+        let regenerated = "# Tokio\n\nNew intro.\n\n## Usage\n\nNew.\n\n## Install\n\nNew.\n";
+
+        let preserved = preserve(&existing, regenerated);
+        assert_eq!(
+            preserved.content,
+            format!("# Tokio\n\nNew intro.\n\n## Usage\n\nNew.\n\n## Install\n\nNew.\n\n{KEEP}\n")
+        );
+        assert!(preserved.unpreserved.is_empty());
+    }
+
+    #[test]
+    fn preserving_is_idempotent() {
+        let document = format!(
+            "---\ntitle: x\n---\n{KEEP}\n\n# A\n\ntext\n\n{KEEP}\n\n## B\n\nbody\n\n{KEEP}\n"
+        );
+        let preserved = preserve(&document, &document);
+        assert_eq!(preserved.content, document);
+        assert!(preserved.unpreserved.is_empty());
+    }
+
+    #[test]
+    fn keeps_sections_verbatim_after_frontmatter() {
+        let existing = format!("---\nname: a\n---\n{KEEP}\n\n# Title\n");
+        let preserved = preserve(&existing, "---\nname: b\n---\n\n# Title\n\nNew.\n");
+        assert_eq!(
+            preserved.content,
+            format!("---\nname: b\n---\n\n{KEEP}\n\n# Title\n\nNew.\n")
+        );
+    }
+
+    #[test]
+    fn moves_sections_with_missing_anchor_to_the_end() {
+        let existing = format!("# A\n\n## Gone\n\n{KEEP}\n");
+        let preserved = preserve(&existing, "# A\n\nNew.");
+
+        assert_eq!(preserved.content, format!("# A\n\nNew.\n\n{KEEP}\n"));
+        assert_eq!(
+            preserved.unpreserved,
+            vec![UnpreservedBlock {
+                line: 5,
+                reason: UnpreservedReason::MissingAnchor("## Gone".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_unbalanced_markers() {
+        let existing = "# A\n\n<!-- /biscuit:keep -->\n\n<!-- biscuit:keep -->\nlost\n";
+        let preserved = preserve(existing, "# A\n\nNew.\n");
+
+        assert_eq!(preserved.content, "# A\n\nNew.\n");
+        let reasons: Vec<_> = preserved
+            .unpreserved
+            .iter()
+            .map(|block| (block.line, block.reason.clone()))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                (3, UnpreservedReason::Unopened),
+                (5, UnpreservedReason::Unclosed)
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_markers_in_code_and_generated_markers() {
+        let existing = "# A\n\n```md\n<!-- biscuit:keep -->\n```\n";
+        let regenerated = format!("# A\n\n{KEEP}\n\nNew.\n");
+
+        let preserved = preserve(existing, &regenerated);
+        assert_eq!(preserved.content, "# A\n\nNew.\n");
+        assert!(preserved.unpreserved.is_empty());
+        assert!(is_marker("<!--biscuit:keep  -->\n", KEEP_START));
+    }
+
+    #[tokio::test]
+    async fn write_preserving_carries_sections_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("overview.md");
+
+        assert!(write_preserving(&path, "# A\n").await.unwrap().is_empty());
+        std::fs::write(&path, format!("# A\n\n{KEEP}\n")).unwrap();
+        write_preserving(&path, "# A\n\nNew.\n").await.unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            format!("# A\n\nNew.\n\n{KEEP}\n")
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//! Mid-run failover of research tasks to the next model in their chain.
+//!
+//! [`FallbackChain::run`](super::FallbackChain::run) retries a single request.
+//! Research tasks are longer: an agent may make a dozen requests, stream into
+//! a file, and report its own outcome. When a task fails because its provider
+//! is rate limiting or erroring (HTTP 429 or 5xx), [`run_with_failover`]
+//! dispatches the whole task again on the next model in the task's chain,
+//! within the same run. Other failures (bad output, write errors,
+//! cancellation) are final.
+//!
+//! The failed attempt's report is held back from the observer, so observers
+//! see one outcome per task: the outcome of the attempt that ran last.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::PromptMetrics;
+use crate::models::ModelSpec;
+use crate::observer::{PhaseSummary, ResearchObserver, TaskOutcome, TaskReport, TaskStage};
+
+/// Phrases in provider errors that mean the provider, not the request, failed.
+const FAILURE_PHRASES: &[&str] = &[
+    "too many requests",
+    "rate limit",
+    "rate_limit",
+    "ratelimit",
+    "resource_exhausted",
+    "resource exhausted",
+    "quota",
+    "overloaded",
+    "internal server error",
+    "bad gateway",
+    "service unavailable",
+    "gateway timeout",
+];
+
+/// Words that introduce an HTTP status code in provider errors.
+const STATUS_CONTEXT: &[&str] = &["status", "code", "http"];
+
+/// Returns `true` if a task error means its provider is rate limiting or
+/// failing (HTTP 429 or 5xx), so the task may succeed on another provider.
+///
+/// Provider errors reach research tasks as text, so this looks for a 429/5xx
+/// status code (e.g. `status: 503`, `HTTP 502`, `"code": 429`) or the usual
+/// wording of those responses. Numbers without such context, like a count or
+/// a line number, don't count.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::providers::is_provider_failure;
+///
+/// assert!(is_provider_failure("ProviderError: HTTP 503 Service Unavailable"));
+/// assert!(is_provider_failure(r#"{"error":{"code":429,"status":"RESOURCE_EXHAUSTED"}}"#));
+/// assert!(!is_provider_failure("write failed: permission denied"));
+/// ```
+pub fn is_provider_failure(error: &str) -> bool {
+    let lower = error.to_lowercase();
+    FAILURE_PHRASES.iter().any(|phrase| lower.contains(phrase))
+        || status_codes(&lower).any(|code| code == 429 || (500..600).contains(&code))
+}
+
+/// Three-digit numbers in lowercased `text` that directly follow one of
+/// [`STATUS_CONTEXT`], skipping an HTTP version (`HTTP/1.1 503`) in between.
+fn status_codes(text: &str) -> impl Iterator<Item = u16> + '_ {
+    let words: Vec<&str> = text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    let is_number = |word: &str| word.bytes().all(|b| b.is_ascii_digit());
+
+    (0..words.len()).filter_map(move |i| {
+        let word = words[i];
+        if word.len() != 3 || !is_number(word) {
+            return None;
+        }
+        let context = words[..i]
+            .iter()
+            .rev()
+            .find(|word| !(word.len() == 1 && is_number(word)))?;
+        STATUS_CONTEXT
+            .contains(context)
+            .then(|| word.parse().ok())
+            .flatten()
+    })
+}
+
+/// Forwards every event to `inner` except a task failure caused by the
+/// provider, which it keeps so the task can be retried on another model.
+pub(crate) struct DeferringObserver {
+    inner: Arc<dyn ResearchObserver>,
+    failure: Mutex<Option<String>>,
+}
+
+impl DeferringObserver {
+    pub(crate) fn new(inner: Arc<dyn ResearchObserver>) -> Self {
+        Self {
+            inner,
+            failure: Mutex::new(None),
+        }
+    }
+
+    /// Returns the provider failure held back, if the task reported one.
+    pub(crate) fn take_failure(&self) -> Option<String> {
+        self.failure
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+    }
+}
+
+impl ResearchObserver for DeferringObserver {
+    fn on_task_start(&self, task: &str, stage: &TaskStage) {
+        self.inner.on_task_start(task, stage);
+    }
+
+    fn on_task_complete(&self, report: &TaskReport) {
+        if let TaskOutcome::Failed { error } = &report.outcome
+            && is_provider_failure(error)
+        {
+            *self.failure.lock().unwrap_or_else(PoisonError::into_inner) = Some(error.clone());
+            return;
+        }
+        self.inner.on_task_complete(report);
+    }
+
+    fn on_tool_call(&self, task: &str, tool: &str) {
+        self.inner.on_tool_call(task, tool);
+    }
+
+    fn on_token_usage(&self, task: &str, usage: &PromptMetrics) {
+        self.inner.on_token_usage(task, usage);
+    }
+
+    fn on_phase_complete(&self, summary: &PhaseSummary) {
+        self.inner.on_phase_complete(summary);
+    }
+
+    fn on_warning(&self, message: &str) {
+        self.inner.on_warning(message);
+    }
+
+    fn on_info(&self, message: &str) {
+        self.inner.on_info(message);
+    }
+}
+
+/// Runs `attempt` on the first model in `specs`, moving on to the next model
+/// whenever the attempt fails with a [provider failure](is_provider_failure).
+///
+/// `attempt` builds and runs the task on a model, reporting to the observer
+/// it is given. Each attempt that fails over counted itself as finished, so
+/// `counter` is decremented before the next attempt. The last model's
+/// attempt reports directly to `observer`, so its failure is final.
+///
+/// ## Returns
+///
+/// The result of the last attempt and the model it ran on.
+///
+/// ## Panics
+///
+/// Panics if `specs` is empty; [`ModelClients`](crate::models::ModelClients)
+/// only exists when every task has a configured model.
+pub(crate) async fn run_with_failover<T, F, Fut>(
+    task: &str,
+    specs: &[ModelSpec],
+    counter: &AtomicUsize,
+    observer: &Arc<dyn ResearchObserver>,
+    mut attempt: F,
+) -> (T, ModelSpec)
+where
+    F: FnMut(&ModelSpec, Arc<dyn ResearchObserver>) -> Fut,
+    Fut: Future<Output = T>,
+{
+    let (last, earlier) = specs
+        .split_last()
+        .expect("every task has at least one configured model");
+
+    for (index, spec) in earlier.iter().enumerate() {
+        let deferring = Arc::new(DeferringObserver::new(Arc::clone(observer)));
+        let attempt_observer: Arc<dyn ResearchObserver> = deferring.clone();
+        let result = attempt(spec, attempt_observer).await;
+
+        let Some(error) = deferring.take_failure() else {
+            return (result, spec.clone());
+        };
+        counter.fetch_sub(1, Ordering::SeqCst);
+        let message = format!(
+            "{} failed on {} ({}); retrying on {}",
+            task,
+            spec,
+            error,
+            specs[index + 1]
+        );
+        tracing::warn!("{}", message);
+        observer.on_warning(&message);
+    }
+
+    let result = attempt(last, Arc::clone(observer)).await;
+    (result, last.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl ResearchObserver for Recorder {
+        fn on_task_complete(&self, report: &TaskReport) {
+            let outcome = match &report.outcome {
+                TaskOutcome::Failed { error } => format!("failed: {}", error),
+                _ => "succeeded".to_string(),
+            };
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", report.task, outcome));
+        }
+
+        fn on_warning(&self, message: &str) {
+            self.0.lock().unwrap().push(format!("warning: {}", message));
+        }
+    }
+
+    /// Runs a fake task that finishes with `outcome`, counting and reporting
+    /// like the research tasks do.
+    fn finish(counter: &AtomicUsize, observer: &dyn ResearchObserver, outcome: TaskOutcome) {
+        let completed = counter.fetch_add(1, Ordering::SeqCst) + 1;
+        observer.on_task_complete(&TaskReport {
+            task: "overview".to_string(),
+            completed,
+            total: 1,
+            elapsed_secs: 1.0,
+            outcome,
+        });
+    }
+
+    fn specs() -> Vec<ModelSpec> {
+        vec![
+            ModelSpec::zai("glm-4.7"),
+            ModelSpec::gemini("gemini-3-flash-preview"),
+            ModelSpec::openai("gpt-5.2"),
+        ]
+    }
+
+    #[test]
+    fn provider_failures_are_recognized() {
+        for error in [
+            "CompletionError: HttpError: status code 429",
+            "ProviderError: 502 Bad Gateway",
+            "Anthropic API is overloaded",
+            "You exceeded your current quota",
+            "Rate limit reached for gpt-5.2",
+            r#"{"code": 500, "message": "internal"}"#,
+            "request failed with status: 503",
+            "HTTP/1.1 504",
+        ] {
+            assert!(is_provider_failure(error), "{error}");
+        }
+        for error in [
+            "write failed: No space left on device",
+            "ResponseError: invalid JSON at column 12",
+            "request took 5034ms",
+            "MaxDepthError: reached limit (404)",
+            "processed 500 documents before stopping",
+            "ResponseError: unexpected token at line 503",
+            "status code 404",
+        ] {
+            assert!(!is_provider_failure(error), "{error}");
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_over_until_an_attempt_succeeds() {
+        let recorder = Arc::new(Recorder::default());
+        let observer: Arc<dyn ResearchObserver> = recorder.clone();
+        let counter = AtomicUsize::new(0);
+        let finished = &counter;
+
+        let (tried, spec) = run_with_failover("overview", &specs(), &counter, &observer, {
+            let mut tried = Vec::new();
+            move |spec: &ModelSpec, observer: Arc<dyn ResearchObserver>| {
+                tried.push(spec.to_string());
+                let outcome = if spec.provider == crate::models::ModelProvider::Zai {
+                    TaskOutcome::Failed {
+                        error: "HTTP 503 Service Unavailable".to_string(),
+                    }
+                } else {
+                    TaskOutcome::Succeeded { metrics: None }
+                };
+                finish(finished, observer.as_ref(), outcome);
+                std::future::ready(tried.clone())
+            }
+        })
+        .await;
+
+        assert_eq!(tried, ["zai:glm-4.7", "gemini:gemini-3-flash-preview"]);
+        assert_eq!(spec, ModelSpec::gemini("gemini-3-flash-preview"));
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [
+                "warning: overview failed on zai:glm-4.7 (HTTP 503 Service Unavailable); \
+                 retrying on gemini:gemini-3-flash-preview",
+                "overview succeeded",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn other_failures_and_the_last_model_are_final() {
+        let recorder = Arc::new(Recorder::default());
+        let observer: Arc<dyn ResearchObserver> = recorder.clone();
+        let counter = AtomicUsize::new(0);
+
+        let (_, spec) =
+            run_with_failover("overview", &specs(), &counter, &observer, |_, observer| {
+                finish(
+                    &counter,
+                    observer.as_ref(),
+                    TaskOutcome::Failed {
+                        error: "write failed: permission denied".to_string(),
+                    },
+                );
+                std::future::ready(())
+            })
+            .await;
+        assert_eq!(spec, ModelSpec::zai("glm-4.7"));
+
+        let (_, spec) =
+            run_with_failover("overview", &specs(), &counter, &observer, |_, observer| {
+                finish(
+                    &counter,
+                    observer.as_ref(),
+                    TaskOutcome::Failed {
+                        error: "429 Too Many Requests".to_string(),
+                    },
+                );
+                std::future::ready(())
+            })
+            .await;
+        assert_eq!(spec, ModelSpec::openai("gpt-5.2"));
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+        let log = recorder.0.lock().unwrap();
+        assert_eq!(log.len(), 4);
+        assert_eq!(log[0], "overview failed: write failed: permission denied");
+        assert_eq!(log[3], "overview failed: 429 Too Many Requests");
+    }
+}
@@ -0,0 +1,244 @@
+//! Ordered (provider, model) pairs tried in sequence.
+
+use std::fmt;
+use std::future::Future;
+
+use thiserror::Error;
+
+/// A (provider, model) pair that failed while running a [`FallbackChain`].
+#[derive(Debug)]
+pub struct FallbackAttempt<P, E> {
+    /// The provider the request was sent to
+    pub provider: P,
+    /// The provider's model name
+    pub model: String,
+    /// Why the request failed
+    pub error: E,
+}
+
+/// Errors returned by [`FallbackChain::run`].
+#[derive(Error, Debug)]
+pub enum FallbackError<P, E>
+where
+    P: fmt::Debug + fmt::Display,
+    E: fmt::Debug + fmt::Display,
+{
+    #[error("No provider in the fallback chain is configured (skipped: {})", join(.skipped))]
+    NoneAvailable { skipped: Vec<P> },
+
+    #[error("Every provider in the fallback chain failed ({})", describe(.attempts))]
+    Exhausted {
+        attempts: Vec<FallbackAttempt<P, E>>,
+    },
+}
+
+fn join<P: fmt::Display>(providers: &[P]) -> String {
+    if providers.is_empty() {
+        return "none".to_string();
+    }
+    providers
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn describe<P: fmt::Display, E: fmt::Display>(attempts: &[FallbackAttempt<P, E>]) -> String {
+    attempts
+        .iter()
+        .map(|attempt| format!("{}:{}: {}", attempt.provider, attempt.model, attempt.error))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// An ordered list of (provider, model) pairs for one task.
+///
+/// The first pair whose provider is configured handles the request; if it
+/// fails, the next configured pair is tried, and so on.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::models::ModelProvider;
+/// use research_lib::providers::FallbackChain;
+///
+/// let chain = FallbackChain::new(ModelProvider::Zai, "glm-4.7")
+///     .with_fallback(ModelProvider::Gemini, "gemini-3-flash-preview");
+///
+/// // Without a Z.ai key the chain starts at Gemini
+/// let first = chain.first_available(|provider| *provider != ModelProvider::Zai);
+/// assert_eq!(first, Some((&ModelProvider::Gemini, "gemini-3-flash-preview")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FallbackChain<P> {
+    links: Vec<(P, String)>,
+}
+
+impl<P> FallbackChain<P> {
+    /// Creates a chain that starts with `model` on `provider`.
+    pub fn new(provider: P, model: impl Into<String>) -> Self {
+        Self {
+            links: vec![(provider, model.into())],
+        }
+    }
+
+    /// Appends `model` on `provider`, tried after every pair already in the chain.
+    ///
+    /// A pair already in the chain is not added again.
+    pub fn with_fallback(mut self, provider: P, model: impl Into<String>) -> Self
+    where
+        P: PartialEq,
+    {
+        let model = model.into();
+        if !self
+            .links
+            .iter()
+            .any(|(p, m)| *p == provider && *m == model)
+        {
+            self.links.push((provider, model));
+        }
+        self
+    }
+
+    /// The pairs in the order they are tried.
+    pub fn iter(&self) -> impl Iterator<Item = (&P, &str)> {
+        self.links
+            .iter()
+            .map(|(provider, model)| (provider, model.as_str()))
+    }
+
+    /// Number of pairs in the chain.
+    pub fn len(&self) -> usize {
+        self.links.len()
+    }
+
+    /// Whether the chain has no pairs; never true for a chain made with [`new`](Self::new).
+    pub fn is_empty(&self) -> bool {
+        self.links.is_empty()
+    }
+
+    /// The first pair whose provider passes `is_available`.
+    pub fn first_available<F>(&self, is_available: F) -> Option<(&P, &str)>
+    where
+        F: Fn(&P) -> bool,
+    {
+        self.iter().find(|(provider, _)| is_available(provider))
+    }
+
+    /// Runs `attempt` against each available pair in order until one succeeds.
+    ///
+    /// Pairs whose provider fails `is_available` (e.g. no API key) are skipped
+    /// without being attempted.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`FallbackError::NoneAvailable`] if no provider is available, or
+    /// [`FallbackError::Exhausted`] with every failure if all attempts fail.
+    pub async fn run<T, E, A, F, Fut>(
+        &self,
+        is_available: A,
+        mut attempt: F,
+    ) -> Result<T, FallbackError<P, E>>
+    where
+        P: Clone + fmt::Debug + fmt::Display,
+        E: fmt::Debug + fmt::Display,
+        A: Fn(&P) -> bool,
+        F: FnMut(P, String) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut skipped = Vec::new();
+        let mut attempts = Vec::new();
+
+        for (provider, model) in &self.links {
+            if !is_available(provider) {
+                tracing::debug!("Skipping {}:{} (not configured)", provider, model);
+                skipped.push(provider.clone());
+                continue;
+            }
+
+            match attempt(provider.clone(), model.clone()).await {
+                Ok(value) => return Ok(value),
+                Err(error) => {
+                    tracing::warn!("{}:{} failed: {}", provider, model, error);
+                    attempts.push(FallbackAttempt {
+                        provider: provider.clone(),
+                        model: model.clone(),
+                        error,
+                    });
+                }
+            }
+        }
+
+        if attempts.is_empty() {
+            Err(FallbackError::NoneAvailable { skipped })
+        } else {
+            Err(FallbackError::Exhausted { attempts })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn chain() -> FallbackChain<&'static str> {
+        FallbackChain::new("zai", "glm-4.7")
+            .with_fallback("gemini", "flash")
+            .with_fallback("openai", "gpt-5.2")
+            .with_fallback("gemini", "flash")
+    }
+
+    #[test]
+    fn duplicate_pairs_are_not_added() {
+        let chain = chain();
+        let pairs: Vec<_> = chain.iter().map(|(p, m)| (*p, m)).collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("zai", "glm-4.7"),
+                ("gemini", "flash"),
+                ("openai", "gpt-5.2")
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_skips_unavailable_and_falls_through_errors() {
+        let tried = RefCell::new(Vec::new());
+        let result = chain()
+            .run(
+                |provider| *provider != "zai",
+                |provider, model| {
+                    tried.borrow_mut().push(format!("{provider}:{model}"));
+                    async move {
+                        if provider == "gemini" {
+                            Err("rate limited")
+                        } else {
+                            Ok(model)
+                        }
+                    }
+                },
+            )
+            .await;
+
+        assert_eq!(result.unwrap(), "gpt-5.2");
+        assert_eq!(*tried.borrow(), vec!["gemini:flash", "openai:gpt-5.2"]);
+    }
+
+    #[tokio::test]
+    async fn run_reports_every_failure() {
+        let err = chain()
+            .run(|_| true, |_, _| async { Err::<(), _>("down") })
+            .await
+            .unwrap_err();
+        assert!(matches!(&err, FallbackError::Exhausted { attempts } if attempts.len() == 3));
+        assert!(err.to_string().contains("openai:gpt-5.2: down"));
+
+        let err = chain()
+            .run(|_| false, |_, _| async { Ok::<_, &str>(()) })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FallbackError::NoneAvailable { ref skipped } if skipped.len() == 3));
+    }
+}
@@ -0,0 +1,16 @@
+//! Provider selection shared by the research tasks.
+//!
+//! Each task declares an ordered [`FallbackChain`] of (provider, model) pairs;
+//! the chain skips providers without credentials and moves on to the next
+//! pair when a request fails. [`ModelConfig::chain`](crate::models::ModelConfig::chain)
+//! builds the chain for a [`ResearchTask`](crate::models::ResearchTask).
+//!
+//! Whole tasks fail over too: a task that fails because its provider is rate
+//! limiting or erroring runs again on the next model in its chain (see
+//! [`failover`]).
+
+pub mod failover;
+pub mod fallback;
+
+pub use failover::is_provider_failure;
+pub use fallback::{FallbackAttempt, FallbackChain, FallbackError};
@@ -113,14 +113,10 @@ pub fn get_git_root() -> Result<PathBuf> {
 
 /// Get the user's research library path.
 ///
-/// Uses `$RESEARCH_DIR/.research/library` if `RESEARCH_DIR` is set,
-/// otherwise falls back to `$HOME/.research/library`.
+/// Uses the `library` directory of the root found by
+/// [`ResearchPaths::from_env`](crate::paths::ResearchPaths::from_env).
 pub fn get_research_library_path() -> Result<PathBuf> {
-    let base = std::env::var("RESEARCH_DIR").unwrap_or_else(|_| {
-        std::env::var("HOME").expect("Neither RESEARCH_DIR nor HOME environment variable is set")
-    });
-
-    Ok(PathBuf::from(base).join(".research").join("library"))
+    Ok(crate::paths::ResearchPaths::from_env().library_dir())
 }
 
 /// Pull a topic from the user's research library to the current repository.
@@ -0,0 +1,309 @@
+//! Additional research questions.
+//!
+//! Besides the standard prompts, a research run answers any number of
+//! additional questions, each written to its own file. A [`Question`] is the
+//! question's text plus optional settings for that question alone:
+//!
+//! - **model**: answer with this model instead of the `questions` task's
+//!   (e.g. a strong model for a hard question, a cheap one for a lookup)
+//! - **tools**: whether the model may use the web research tools
+//! - **filename**: the answer's file name instead of `question_N.md`
+//!
+//! ```
+//! use research_lib::models::{ModelProvider, ModelSpec};
+//! use research_lib::question::Question;
+//!
+//! let questions = [
+//!     Question::from("Which MSRV does clap support?").with_tools(false),
+//!     Question::new("How does clap's derive API compare to the builder API?")
+//!         .with_model(ModelSpec::new(ModelProvider::OpenAi, "gpt-5.2"))
+//!         .with_filename("derive_vs_builder"),
+//! ];
+//!
+//! assert_eq!(questions[0].filename(1), "question_1.md");
+//! assert_eq!(questions[1].filename(2), "derive_vs_builder.md");
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::models::ModelSpec;
+use crate::utils::filename::{CORE_DOCUMENTS, extract_prompt_name};
+
+/// Errors from [`validate_questions()`].
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum QuestionError {
+    #[error("question {0} is empty")]
+    Empty(usize),
+
+    #[error("invalid question filename '{0}' (use a plain file name without directories)")]
+    InvalidFilename(String),
+
+    #[error("question filename '{0}' is reserved for research output")]
+    ReservedFilename(String),
+
+    #[error("several questions are written to '{0}'")]
+    DuplicateFilename(String),
+}
+
+/// An additional question to research.
+///
+/// Settings left unset follow the run's configuration. Deserializes from a
+/// plain string as well as an object, so question lists saved before the
+/// settings existed still load.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "QuestionRepr")]
+pub struct Question {
+    /// The question to answer
+    pub text: String,
+    /// The model to answer with; the `questions` task's model when unset or
+    /// when the provider has no API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<ModelSpec>,
+    /// Whether to use the web research tools; they're used whenever they're
+    /// available when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<bool>,
+    /// File name of the answer; `question_N.md` when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+}
+
+impl Question {
+    /// Creates a question with the run's default settings.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            model: None,
+            tools: None,
+            filename: None,
+        }
+    }
+
+    /// Parses a prompt in the `filename -> question` syntax, writing the
+    /// answer to that file (see [`extract_prompt_name`]); other prompts are
+    /// taken as the question's text.
+    pub fn from_prompt(prompt: &str) -> Self {
+        let (text, filename) = extract_prompt_name(prompt);
+        Self {
+            filename,
+            ..Self::new(text)
+        }
+    }
+
+    /// Answers the question with `model` instead of the `questions` task's model.
+    pub fn with_model(mut self, model: ModelSpec) -> Self {
+        self.model = Some(model);
+        self
+    }
+
+    /// Enables or disables the web research tools for this question.
+    pub fn with_tools(mut self, enabled: bool) -> Self {
+        self.tools = Some(enabled);
+        self
+    }
+
+    /// Writes the answer to `filename` (`.md` is added when missing).
+    pub fn with_filename(mut self, filename: impl Into<String>) -> Self {
+        let filename = filename.into();
+        self.filename = Some(if filename.ends_with(".md") {
+            filename
+        } else {
+            format!("{}.md", filename)
+        });
+        self
+    }
+
+    /// The file the answer is written to when it's question `num` of the run.
+    pub fn filename(&self, num: usize) -> String {
+        match &self.filename {
+            Some(filename) => filename.clone(),
+            None => format!("question_{}.md", num),
+        }
+    }
+
+    /// Whether to answer with the web research tools, given whether the run
+    /// has them.
+    pub fn uses_tools(&self, available: bool) -> bool {
+        available && self.tools.unwrap_or(true)
+    }
+}
+
+impl From<String> for Question {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&str> for Question {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl fmt::Display for Question {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// The serialized forms of a [`Question`].
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum QuestionRepr {
+    Text(String),
+    Full {
+        text: String,
+        #[serde(default)]
+        model: Option<ModelSpec>,
+        #[serde(default)]
+        tools: Option<bool>,
+        #[serde(default)]
+        filename: Option<String>,
+    },
+}
+
+impl From<QuestionRepr> for Question {
+    fn from(repr: QuestionRepr) -> Self {
+        match repr {
+            QuestionRepr::Text(text) => Self::new(text),
+            QuestionRepr::Full {
+                text,
+                model,
+                tools,
+                filename,
+            } => Self {
+                text,
+                model,
+                tools,
+                filename,
+            },
+        }
+    }
+}
+
+/// Labels the answer in `filename` in the Phase 2 context: by number for
+/// `question_N.md`, otherwise by file name without `.md`.
+pub(crate) fn answer_label(filename: &str) -> &str {
+    let stem = filename.strip_suffix(".md").unwrap_or(filename);
+    stem.strip_prefix("question_").unwrap_or(stem)
+}
+
+/// Checks that every question has text and that their answers go to
+/// distinct files in the topic directory.
+///
+/// Custom filenames must be plain file names, and can't take the name of a
+/// standard document (`overview.md`, `brief.md`, ...) or of another
+/// question's answer, including the numbered `question_N.md` files.
+///
+/// ## Errors
+///
+/// Returns the [`QuestionError`] for the first invalid question.
+pub fn validate_questions(questions: &[Question]) -> Result<(), QuestionError> {
+    let mut seen = HashSet::new();
+    for (i, question) in questions.iter().enumerate() {
+        if question.text.trim().is_empty() {
+            return Err(QuestionError::Empty(i + 1));
+        }
+
+        if let Some(filename) = &question.filename {
+            let stem = filename.strip_suffix(".md").unwrap_or(filename);
+            if stem.is_empty()
+                || stem.starts_with('.')
+                || filename.contains(['/', '\\'])
+                || filename.contains("..")
+            {
+                return Err(QuestionError::InvalidFilename(filename.clone()));
+            }
+            if CORE_DOCUMENTS.contains(&stem.to_lowercase().as_str()) {
+                return Err(QuestionError::ReservedFilename(filename.clone()));
+            }
+        }
+
+        let filename = question.filename(i + 1);
+        if !seen.insert(filename.clone()) {
+            return Err(QuestionError::DuplicateFilename(filename));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModelProvider;
+
+    #[test]
+    fn deserializes_plain_strings_and_objects() {
+        let json = r#"["Plain?", {"text": "Custom?", "tools": false, "filename": "custom.md",
+            "model": {"provider": "openai", "model": "gpt-5.2"}}]"#;
+
+        let questions: Vec<Question> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(questions[0], Question::new("Plain?"));
+        assert_eq!(
+            questions[1],
+            Question::new("Custom?")
+                .with_tools(false)
+                .with_filename("custom.md")
+                .with_model(ModelSpec::new(ModelProvider::OpenAi, "gpt-5.2"))
+        );
+        let round_trip: Vec<Question> =
+            serde_json::from_str(&serde_json::to_string(&questions).unwrap()).unwrap();
+        assert_eq!(round_trip, questions);
+    }
+
+    #[test]
+    fn tools_follow_availability() {
+        assert!(Question::new("Q?").uses_tools(true));
+        assert!(!Question::new("Q?").uses_tools(false));
+        assert!(!Question::new("Q?").with_tools(false).uses_tools(true));
+        assert!(!Question::new("Q?").with_tools(true).uses_tools(false));
+    }
+
+    #[test]
+    fn prompts_can_name_their_file() {
+        let named = Question::from_prompt("async-patterns -> What are the async patterns?");
+        assert_eq!(named.text, "What are the async patterns?");
+        assert_eq!(named.filename(1), "async-patterns.md");
+
+        let plain = Question::from_prompt("What are the best practices?");
+        assert_eq!(plain, Question::new("What are the best practices?"));
+    }
+
+    #[test]
+    fn validation_rejects_clashing_filenames() {
+        let valid = [
+            Question::new("First?"),
+            Question::new("Second?").with_filename("second"),
+        ];
+        assert_eq!(validate_questions(&valid), Ok(()));
+
+        let cases = [
+            (
+                Question::new("Q?").with_filename("../escape"),
+                QuestionError::InvalidFilename("../escape.md".to_string()),
+            ),
+            (
+                Question::new("Q?").with_filename("nested/answer.md"),
+                QuestionError::InvalidFilename("nested/answer.md".to_string()),
+            ),
+            (
+                Question::new("Q?").with_filename("overview"),
+                QuestionError::ReservedFilename("overview.md".to_string()),
+            ),
+            (
+                Question::new("Q?").with_filename("question_1"),
+                QuestionError::DuplicateFilename("question_1.md".to_string()),
+            ),
+            (Question::new("  "), QuestionError::Empty(2)),
+        ];
+        for (question, expected) in cases {
+            let questions = [Question::new("First?"), question];
+            assert_eq!(validate_questions(&questions), Err(expected));
+        }
+    }
+}
@@ -0,0 +1,610 @@
+//! Refreshing stale library research.
+//!
+//! Research goes out of date as libraries ship new releases, but most of a
+//! topic (the overview, similar libraries, use cases) changes slowly. A
+//! refresh re-runs only the changelog prompt and then Phase 2, so the skill,
+//! deep dive and brief are synthesized again from the new changelog and the
+//! existing documents.
+//!
+//! [`find_stale_topics()`] scans the research library for topics whose
+//! `metadata.json` was last updated longer ago than a maximum age, and
+//! [`refresh_stale()`] refreshes them one after another or, in batch, all at
+//! once through a shared [`ResearchScheduler`]. Running `research refresh`
+//! from cron keeps the library current.
+//!
+//! [`schedule_refresh()`] registers a recurring task in the `queue` history
+//! instead, running `research refresh <topic>` on a cadence whenever a
+//! `queue` TUI is open.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use research_lib::ResearchOptions;
+//! use research_lib::refresh::{find_stale_topics, refresh_stale};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let library = research_lib::tags::default_research_root().join("library");
+//! let stale = find_stale_topics(&library, Duration::from_secs(30 * 24 * 60 * 60)).await?;
+//! let results = refresh_stale(&stale, true, &ResearchOptions::default()).await;
+//! for (topic, error) in results.failed() {
+//!     eprintln!("{topic}: {error}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use queue_lib::{
+    ExecutionTarget, HistoryError, HistoryStore, JsonFileStore, ScheduleKind, ScheduledTask,
+};
+use serde::Serialize;
+use thiserror::Error;
+use tracing::{instrument, warn};
+
+use crate::checkpoint::Checkpoint;
+use crate::ecosystem::Ecosystem;
+use crate::models::ModelConfig;
+use crate::observer::{ConsoleObserver, ResearchObserver, TaskOutcome};
+use crate::question::Question;
+use crate::scheduler::ResearchScheduler;
+use crate::{
+    ManyResearchResult, ResearchError, ResearchMetadata, ResearchOptions, ResearchResult,
+    STANDARD_PROMPTS, TopicResearch, hooks,
+};
+
+pub use queue_lib::{Recurrence, parse_recurrence};
+
+/// Default maximum age, in days, before a topic is refreshed.
+pub const DEFAULT_MAX_AGE_DAYS: u64 = 30;
+
+/// The Phase 1 prompt a refresh runs again.
+const REFRESHED_PROMPT: &str = "changelog";
+
+/// Errors that can occur while looking for stale topics.
+#[derive(Debug, Error)]
+pub enum RefreshError {
+    /// The research library directory does not exist
+    #[error("Research library not found: {0}")]
+    LibraryNotFound(PathBuf),
+
+    /// The topic has no research in the library
+    #[error("No research found for '{0}'")]
+    TopicNotFound(String),
+
+    /// Failed to read the research library
+    #[error("Failed to read research library: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Failed to read or write the queue history
+    #[error("Failed to update the queue: {0}")]
+    Queue(#[from] HistoryError),
+}
+
+/// A topic whose research is older than the maximum age.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StaleTopic {
+    /// The topic (its directory name)
+    pub topic: String,
+    /// The topic's research directory
+    pub output_dir: PathBuf,
+    /// When the research was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl StaleTopic {
+    /// How long ago the research was last updated.
+    pub fn age(&self) -> chrono::Duration {
+        Utc::now() - self.updated_at
+    }
+}
+
+/// Finds the topics in `library_dir` last updated more than `max_age` ago.
+///
+/// Topics are read from `<library_dir>/<topic>/metadata.json`; directories
+/// without readable metadata are skipped. The oldest topics come first.
+///
+/// ## Errors
+///
+/// Returns [`RefreshError::LibraryNotFound`] if `library_dir` does not
+/// exist, or [`RefreshError::Io`] if it can't be read.
+#[instrument(skip(library_dir), fields(library_dir = %library_dir.display()))]
+pub async fn find_stale_topics(
+    library_dir: &Path,
+    max_age: Duration,
+) -> Result<Vec<StaleTopic>, RefreshError> {
+    if !library_dir.is_dir() {
+        return Err(RefreshError::LibraryNotFound(library_dir.to_path_buf()));
+    }
+
+    let cutoff = chrono::Duration::from_std(max_age)
+        .ok()
+        .and_then(|max_age| Utc::now().checked_sub_signed(max_age))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+
+    let mut stale = Vec::new();
+    for entry in std::fs::read_dir(library_dir)? {
+        let output_dir = entry?.path();
+        if !output_dir.is_dir() {
+            continue;
+        }
+        let Some(metadata) = ResearchMetadata::load(&output_dir).await else {
+            continue;
+        };
+        if metadata.updated_at >= cutoff {
+            continue;
+        }
+        let Some(topic) = output_dir.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        stale.push(StaleTopic {
+            topic: topic.to_string(),
+            updated_at: metadata.updated_at,
+            output_dir,
+        });
+    }
+
+    stale.sort_by(|a, b| a.updated_at.cmp(&b.updated_at).then(a.topic.cmp(&b.topic)));
+    Ok(stale)
+}
+
+/// Looks up the named `topics` in `library_dir`, whatever their age.
+///
+/// Topics are returned in the order given, ready for [`refresh_stale()`].
+///
+/// ## Errors
+///
+/// Returns [`RefreshError::TopicNotFound`] for the first topic without
+/// readable `metadata.json`.
+pub async fn find_topics(
+    library_dir: &Path,
+    topics: &[String],
+) -> Result<Vec<StaleTopic>, RefreshError> {
+    let mut found = Vec::with_capacity(topics.len());
+    for topic in topics {
+        let output_dir = library_dir.join(topic);
+        let metadata = ResearchMetadata::load(&output_dir)
+            .await
+            .ok_or_else(|| RefreshError::TopicNotFound(topic.clone()))?;
+        found.push(StaleTopic {
+            topic: topic.clone(),
+            updated_at: metadata.updated_at,
+            output_dir,
+        });
+    }
+    Ok(found)
+}
+
+/// The shell command a scheduled refresh of `topic` runs.
+pub fn refresh_command(topic: &str) -> String {
+    let plain = topic
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-_.@+/".contains(c));
+    if plain && !topic.is_empty() {
+        format!("research refresh {}", topic)
+    } else {
+        format!("research refresh '{}'", topic.replace('\'', r"'\''"))
+    }
+}
+
+/// Schedules `topic` to be refreshed on the given `recurrence`.
+///
+/// Registers a recurring background task in the default `queue` history
+/// (`~/.queue-history.jsonl`) that runs [`refresh_command()`]. See
+/// [`schedule_refresh_in()`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use research_lib::refresh::{Recurrence, schedule_refresh};
+///
+/// let task = schedule_refresh("clap", Recurrence::weekly())?;
+/// println!("First refresh at {}", task.scheduled_at);
+/// # Ok::<(), research_lib::refresh::RefreshError>(())
+/// ```
+///
+/// ## Errors
+///
+/// See [`schedule_refresh_in()`].
+pub fn schedule_refresh(
+    topic: &str,
+    recurrence: Recurrence,
+) -> Result<ScheduledTask, RefreshError> {
+    let library_dir = crate::tags::default_research_root().join("library");
+    schedule_refresh_in(
+        &JsonFileStore::default_path(),
+        &library_dir,
+        topic,
+        recurrence,
+        Utc::now(),
+    )
+}
+
+/// Schedules a refresh of the topic in `library_dir` through `store`.
+///
+/// The first refresh runs one interval after `now`; the `queue` TUI then
+/// schedules each following run when the previous one finishes. Scheduling a
+/// topic that already has a pending refresh updates that task's recurrence
+/// and next run instead of adding another.
+///
+/// ## Errors
+///
+/// Returns [`RefreshError::TopicNotFound`] if the topic has no
+/// `metadata.json` in `library_dir`, or [`RefreshError::Queue`] if the
+/// history can't be read or written.
+pub fn schedule_refresh_in(
+    store: &impl HistoryStore,
+    library_dir: &Path,
+    topic: &str,
+    recurrence: Recurrence,
+    now: DateTime<Utc>,
+) -> Result<ScheduledTask, RefreshError> {
+    if !library_dir.join(topic).join("metadata.json").is_file() {
+        return Err(RefreshError::TopicNotFound(topic.to_string()));
+    }
+
+    let command = refresh_command(topic);
+    let scheduled_at = now + recurrence.interval();
+    let tasks = store.load_all()?;
+
+    if let Some(existing) = tasks
+        .iter()
+        .find(|task| task.is_pending() && task.recurrence.is_some() && task.command == command)
+    {
+        let mut task = existing.clone().with_recurrence(recurrence);
+        task.scheduled_at = scheduled_at;
+        store.update(&task)?;
+        return Ok(task);
+    }
+
+    let id = tasks
+        .iter()
+        .map(|task| task.id)
+        .max()
+        .unwrap_or(0)
+        .saturating_add(1);
+    let task = ScheduledTask::with_schedule_kind(
+        id,
+        command,
+        scheduled_at,
+        ExecutionTarget::Background,
+        ScheduleKind::AfterDelay,
+    )
+    .with_recurrence(recurrence);
+    store.save(&task)?;
+    Ok(task)
+}
+
+/// The additional questions recorded in `metadata`, in order.
+///
+/// Questions numbered contiguously from `question_1.md` come first, matching
+/// the files a research run writes, followed by the questions answered in
+/// files of their own, which keep their file names.
+fn recorded_questions(metadata: &ResearchMetadata) -> Vec<Question> {
+    let mut numbered: Vec<(usize, &String)> = Vec::new();
+    let mut named: Vec<(&String, &String)> = Vec::new();
+    for (filename, prompt) in &metadata.additional_files {
+        let number = filename
+            .strip_prefix("question_")
+            .and_then(|rest| rest.strip_suffix(".md"))
+            .map(str::parse::<usize>);
+        match number {
+            Some(Ok(number)) => numbered.push((number, prompt)),
+            _ => named.push((filename, prompt)),
+        }
+    }
+    numbered.sort_by_key(|(number, _)| *number);
+    named.sort();
+
+    numbered
+        .into_iter()
+        .enumerate()
+        .take_while(|(i, (number, _))| *number == i + 1)
+        .map(|(_, (_, prompt))| Question::new(prompt.as_str()))
+        .chain(named.into_iter().map(|(filename, prompt)| {
+            Question::new(prompt.as_str()).with_filename(filename.as_str())
+        }))
+        .collect()
+}
+
+/// A checkpoint that marks every Phase 1 task except the changelog as
+/// completed, so the run keeps their documents.
+fn refresh_checkpoint(topic: &str, metadata: &ResearchMetadata) -> Checkpoint {
+    let library = metadata.library_info();
+    let ecosystem = Ecosystem::resolve(None, library.as_ref().map(|li| li.language.as_str()));
+    let questions = recorded_questions(metadata);
+    let question_count = questions.len();
+
+    let mut checkpoint = Checkpoint::new(topic, metadata.kind, library, ecosystem, questions);
+    let kept = STANDARD_PROMPTS
+        .iter()
+        .map(|(name, _, _)| name.to_string())
+        .filter(|name| name != REFRESHED_PROMPT)
+        .chain((1..=question_count).map(|i| format!("question_{}", i)));
+    for task in kept {
+        checkpoint.record(&task, &TaskOutcome::Succeeded { metrics: None });
+    }
+    checkpoint
+}
+
+/// Refreshes the research in `output_dir`.
+///
+/// Runs the changelog prompt again, then Phase 2 (skill, deep dive and
+/// brief) from the new changelog and the existing documents. The other
+/// Phase 1 documents are kept; any that are missing are researched again.
+/// The library, questions, tags and creation time recorded in
+/// `metadata.json` are preserved, and `updated_at` is set to now.
+///
+/// Models, streaming, the scheduler, the observer and completion hooks come
+/// from `options` as for [`research_with_options`](crate::research_with_options);
+/// its `kind` and `ecosystem` are ignored in favour of the topic's metadata.
+///
+/// ## Errors
+///
+/// Returns [`ResearchError::NoMetadata`] if `output_dir` has no
+/// `metadata.json`, or any error a research run can return.
+#[instrument(name = "refresh", skip(options), fields(output_dir = %output_dir.display()))]
+pub async fn refresh_topic(
+    output_dir: &Path,
+    options: &ResearchOptions,
+) -> Result<ResearchResult, ResearchError> {
+    dotenvy::dotenv().ok();
+
+    let metadata = ResearchMetadata::load(output_dir)
+        .await
+        .ok_or_else(|| ResearchError::NoMetadata(output_dir.to_path_buf()))?;
+    let topic = output_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let models = match options.models.clone() {
+        Some(models) => models,
+        None => ModelConfig::load()?,
+    };
+    let observer: Arc<dyn ResearchObserver> = options
+        .observer
+        .clone()
+        .unwrap_or_else(|| Arc::new(ConsoleObserver));
+    observer.on_info(&format!(
+        "Refreshing research for '{}' last updated {}",
+        topic,
+        metadata.updated_at.format("%Y-%m-%d %H:%M UTC")
+    ));
+
+    let result = crate::run_full_research_logged(
+        refresh_checkpoint(&topic, &metadata),
+        output_dir.to_path_buf(),
+        options.streaming.clone(),
+        models,
+        options.scheduler.clone(),
+        observer.clone(),
+    )
+    .await?;
+
+    // The run writes fresh metadata; carry over what it doesn't know about
+    if let Some(mut refreshed) = ResearchMetadata::load(output_dir).await {
+        refreshed.created_at = metadata.created_at;
+        refreshed.question_tags = metadata.question_tags;
+        for (filename, prompt) in metadata.additional_files {
+            refreshed.additional_files.entry(filename).or_insert(prompt);
+        }
+        if let Err(e) = refreshed.save(output_dir).await {
+            observer.on_warning(&format!("Failed to write metadata.json: {}", e));
+        }
+    }
+
+    for error in hooks::run_completion_hooks(&options.hooks, &result).await {
+        warn!(error = %error, "Completion hook failed");
+        observer.on_warning(&format!("Completion hook failed: {}", error));
+    }
+
+    Ok(result)
+}
+
+/// Refreshes each of the `stale` topics with [`refresh_topic()`].
+///
+/// Topics are refreshed one at a time, or all at once when `batch` is set.
+/// A batch shares one [`ResearchScheduler`] between its topics: the one in
+/// `options`, or [`ResearchScheduler::default`] when unset. A topic that
+/// fails doesn't stop the others; its error is kept in the returned
+/// [`ManyResearchResult`], which lists the topics in the order given.
+pub async fn refresh_stale(
+    stale: &[StaleTopic],
+    batch: bool,
+    options: &ResearchOptions,
+) -> ManyResearchResult {
+    let start_time = Instant::now();
+
+    let refresh = |stale: &StaleTopic, options: &ResearchOptions| {
+        let output_dir = stale.output_dir.clone();
+        let topic = stale.topic.clone();
+        let options = options.clone();
+        async move {
+            let result = refresh_topic(&output_dir, &options).await;
+            if let Err(error) = &result {
+                warn!(topic = %topic, error = %error, "Topic refresh failed");
+            }
+            TopicResearch { topic, result }
+        }
+    };
+
+    let topics = if batch {
+        let mut options = options.clone();
+        if options.scheduler.is_none() {
+            options.scheduler = Some(Arc::new(ResearchScheduler::default()));
+        }
+        join_all(stale.iter().map(|topic| refresh(topic, &options))).await
+    } else {
+        let mut topics = Vec::with_capacity(stale.len());
+        for topic in stale {
+            topics.push(refresh(topic, options).await);
+        }
+        topics
+    };
+
+    ManyResearchResult {
+        topics,
+        total_time_secs: start_time.elapsed().as_secs_f32(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LibraryInfo;
+    use tempfile::TempDir;
+
+    fn library_info() -> LibraryInfo {
+        LibraryInfo {
+            package_manager: "crates.io".to_string(),
+            language: "Rust".to_string(),
+            url: "https://crates.io/crates/clap".to_string(),
+            repository: None,
+            description: None,
+        }
+    }
+
+    async fn write_topic(library: &Path, topic: &str, age_days: i64) {
+        let dir = library.join(topic);
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut metadata = ResearchMetadata::new_library(Some(&library_info()));
+        // Without `when_to_use`, loading looks for it in SKILL.md and resaves
+        metadata.when_to_use = Some(format!("Use when working with {}", topic));
+        metadata.updated_at = Utc::now() - chrono::Duration::days(age_days);
+        metadata.save(&dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn finds_topics_older_than_max_age_oldest_first() {
+        let library = TempDir::new().unwrap();
+        write_topic(library.path(), "clap", 40).await;
+        write_topic(library.path(), "serde", 5).await;
+        write_topic(library.path(), "tokio", 90).await;
+        std::fs::create_dir(library.path().join("no-metadata")).unwrap();
+
+        let stale = find_stale_topics(library.path(), Duration::from_secs(30 * 24 * 60 * 60))
+            .await
+            .unwrap();
+
+        let topics: Vec<_> = stale.iter().map(|t| t.topic.as_str()).collect();
+        assert_eq!(topics, ["tokio", "clap"]);
+        assert_eq!(stale[1].output_dir, library.path().join("clap"));
+        assert!(stale[1].age() >= chrono::Duration::days(40));
+    }
+
+    #[tokio::test]
+    async fn missing_library_is_an_error() {
+        let library = TempDir::new().unwrap();
+        let missing = library.path().join("library");
+
+        let result = find_stale_topics(&missing, Duration::ZERO).await;
+
+        assert!(matches!(result, Err(RefreshError::LibraryNotFound(dir)) if dir == missing));
+    }
+
+    #[tokio::test]
+    async fn finds_named_topics_in_order() {
+        let library = TempDir::new().unwrap();
+        write_topic(library.path(), "clap", 40).await;
+        write_topic(library.path(), "serde", 5).await;
+
+        let topics = ["serde".to_string(), "clap".to_string()];
+        let found = find_topics(library.path(), &topics).await.unwrap();
+        let names: Vec<_> = found.iter().map(|t| t.topic.as_str()).collect();
+        assert_eq!(names, ["serde", "clap"]);
+
+        let result = find_topics(library.path(), &["tokio".to_string()]).await;
+        assert!(matches!(result, Err(RefreshError::TopicNotFound(topic)) if topic == "tokio"));
+    }
+
+    #[test]
+    fn refresh_commands_quote_unusual_topics() {
+        assert_eq!(refresh_command("clap"), "research refresh clap");
+        assert_eq!(
+            refresh_command("@tanstack/query"),
+            "research refresh @tanstack/query"
+        );
+        assert_eq!(refresh_command("it's ok"), r"research refresh 'it'\''s ok'");
+    }
+
+    #[tokio::test]
+    async fn scheduling_registers_one_recurring_task_per_topic() {
+        let library = TempDir::new().unwrap();
+        write_topic(library.path(), "clap", 1).await;
+        let store = JsonFileStore::new(library.path().join("queue.jsonl"));
+        store
+            .save(&ScheduledTask::new(
+                4,
+                "make".to_string(),
+                Utc::now(),
+                ExecutionTarget::Background,
+            ))
+            .unwrap();
+        let now = Utc::now();
+
+        let task =
+            schedule_refresh_in(&store, library.path(), "clap", Recurrence::weekly(), now).unwrap();
+        assert_eq!(task.id, 5);
+        assert_eq!(task.command, "research refresh clap");
+        assert_eq!(task.target, ExecutionTarget::Background);
+        assert_eq!(task.recurrence, Some(Recurrence::weekly()));
+        assert_eq!(task.scheduled_at, now + chrono::Duration::weeks(1));
+
+        let task =
+            schedule_refresh_in(&store, library.path(), "clap", Recurrence::daily(), now).unwrap();
+        assert_eq!(task.id, 5);
+        assert_eq!(task.scheduled_at, now + chrono::Duration::days(1));
+
+        let tasks = store.load_all().unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[1].recurrence, Some(Recurrence::daily()));
+
+        let result = schedule_refresh_in(&store, library.path(), "tokio", Recurrence::daily(), now);
+        assert!(matches!(result, Err(RefreshError::TopicNotFound(_))));
+    }
+
+    #[test]
+    fn checkpoint_keeps_everything_but_the_changelog() {
+        let mut metadata = ResearchMetadata::new_library(Some(&library_info()));
+        metadata.add_additional_file("question_2.md".to_string(), "Second?".to_string());
+        metadata.add_additional_file("question_1.md".to_string(), "First?".to_string());
+        metadata.add_additional_file("question_4.md".to_string(), "Orphan?".to_string());
+        metadata.add_additional_file("derive.md".to_string(), "Derive?".to_string());
+
+        let checkpoint = refresh_checkpoint("clap", &metadata);
+
+        assert_eq!(
+            checkpoint.questions,
+            [
+                Question::new("First?"),
+                Question::new("Second?"),
+                Question::new("Derive?").with_filename("derive.md"),
+            ]
+        );
+        assert_eq!(checkpoint.ecosystem, Ecosystem::Rust);
+        assert_eq!(
+            checkpoint
+                .library
+                .as_ref()
+                .map(|li| li.package_manager.as_str()),
+            Some("crates.io")
+        );
+        for task in [
+            "overview",
+            "similar_libraries",
+            "integration_partners",
+            "use_cases",
+            "question_1",
+            "question_2",
+            "question_3",
+        ] {
+            assert!(checkpoint.is_completed(task), "{} should be kept", task);
+        }
+        assert!(!checkpoint.is_completed("changelog"));
+        assert!(!checkpoint.is_completed("question_4"));
+    }
+}
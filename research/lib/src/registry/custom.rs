@@ -0,0 +1,619 @@
+//! Private and self-hosted package registries.
+//!
+//! Internal libraries are published to registries the built-in ones don't
+//! cover: an alternative Cargo registry, an npm registry such as Verdaccio, or
+//! a GitLab package registry. These are configured in `registries.toml`, read
+//! from `$RESEARCH_REGISTRIES` if set, otherwise from `registries.toml` (or
+//! `registries.json`) in the library root (see the [`paths`](crate::paths)
+//! module). Tokens aren't stored in the file; each registry names the
+//! environment variable holding its token.
+//!
+//! ```toml
+//! [[registry]]
+//! name = "acme-crates"
+//! kind = "cargo"
+//! url = "https://crates.acme.internal"
+//! token_env = "ACME_CRATES_TOKEN"
+//!
+//! [[registry]]
+//! name = "acme-npm"
+//! kind = "npm"
+//! url = "https://npm.acme.internal"
+//! token_env = "ACME_NPM_TOKEN"
+//!
+//! [[registry]]
+//! name = "acme-gitlab"
+//! kind = "gitlab"
+//! url = "https://gitlab.acme.internal"
+//! group = "platform"          # or project = "platform/tools"
+//! token_env = "ACME_GITLAB_TOKEN"
+//! ```
+//!
+//! Tokens are sent as `Authorization: <token>` to Cargo registries,
+//! `Authorization: Bearer <token>` to npm registries and
+//! `PRIVATE-TOKEN: <token>` to GitLab. Set `auth_header` to send the token
+//! as-is in another header instead.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use futures::future::BoxFuture;
+use reqwest::header::{AUTHORIZATION, HeaderName, HeaderValue};
+use reqwest::{Client as HttpClient, RequestBuilder, Url};
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::{CratesIoResponse, NpmResponse, Registry};
+use crate::LibraryInfo;
+
+/// Environment variable holding the path of a registry config file.
+pub const REGISTRIES_FILE_ENV: &str = "RESEARCH_REGISTRIES";
+
+/// Errors raised while loading a [`RegistryConfig`] or building its registries.
+#[derive(Error, Debug)]
+pub enum RegistryConfigError {
+    #[error("Failed to read registry config {path}: {source}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid TOML in registry config {path}: {source}")]
+    Toml {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+
+    #[error("Invalid JSON in registry config {path}: {source}")]
+    Json {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("Invalid URL '{url}' for registry '{registry}' (expected an http or https URL)")]
+    InvalidUrl { registry: String, url: String },
+
+    #[error("GitLab registry '{0}' needs exactly one of `project` or `group`")]
+    GitLabScope(String),
+
+    #[error("Registry '{registry}' reads its token from {var}, which is not set")]
+    MissingToken { registry: String, var: String },
+
+    #[error("Invalid auth header or token for registry '{0}'")]
+    InvalidAuth(String),
+}
+
+/// The API a custom registry speaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomRegistryKind {
+    /// An alternative Cargo registry serving the crates.io web API (e.g. Kellnr)
+    Cargo,
+    /// An npm registry (e.g. Verdaccio, Nexus or Artifactory)
+    Npm,
+    /// A GitLab project or group package registry
+    GitLab,
+}
+
+/// Registries configured in `registries.toml`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RegistryConfig {
+    /// The `[[registry]]` entries, in the order their matches are listed
+    #[serde(default, rename = "registry")]
+    pub registries: Vec<CustomRegistryConfig>,
+}
+
+/// One `[[registry]]` entry of a [`RegistryConfig`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomRegistryConfig {
+    /// Name stored as the package manager of libraries found in the registry
+    pub name: String,
+    pub kind: CustomRegistryKind,
+    /// Base URL of the registry, or of the GitLab instance
+    pub url: String,
+    /// GitLab project (numeric id or `group/project` path) to search
+    #[serde(default)]
+    pub project: Option<String>,
+    /// GitLab group (numeric id or path) to search, including subgroups
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Environment variable holding the registry's token
+    #[serde(default)]
+    pub token_env: Option<String>,
+    /// Header to send the token in as-is, instead of the kind's default
+    #[serde(default)]
+    pub auth_header: Option<String>,
+}
+
+impl RegistryConfig {
+    /// Loads the config from `$RESEARCH_REGISTRIES` or the default file.
+    ///
+    /// Without a file, no custom registries are configured.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn load() -> Result<Self, RegistryConfigError> {
+        let explicit = std::env::var_os(REGISTRIES_FILE_ENV).map(PathBuf::from);
+        match explicit.or_else(Self::default_path) {
+            Some(path) => Self::from_file(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Returns the first of `registries.toml` / `registries.json` that exists in the research directory.
+    pub fn default_path() -> Option<PathBuf> {
+        crate::paths::ResearchPaths::from_env().config_file("registries")
+    }
+
+    /// Reads a config file; `.json` files are parsed as JSON, anything else as TOML.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn from_file(path: &Path) -> Result<Self, RegistryConfigError> {
+        let content =
+            std::fs::read_to_string(path).map_err(|source| RegistryConfigError::Read {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        if is_json {
+            serde_json::from_str(&content).map_err(|source| RegistryConfigError::Json {
+                path: path.to_path_buf(),
+                source,
+            })
+        } else {
+            toml::from_str(&content).map_err(|source| RegistryConfigError::Toml {
+                path: path.to_path_buf(),
+                source,
+            })
+        }
+    }
+}
+
+/// The HTTP header carrying a registry's token.
+#[derive(Clone)]
+struct Auth {
+    header: HeaderName,
+    value: HeaderValue,
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Auth({}: <redacted>)", self.header)
+    }
+}
+
+/// Which GitLab package registry to search.
+#[derive(Debug, Clone)]
+enum GitLabScope {
+    Project(String),
+    Group(String),
+}
+
+/// A private or self-hosted registry built from a [`CustomRegistryConfig`].
+///
+/// Its token is never printed; the `Debug` output is redacted.
+#[derive(Debug, Clone)]
+pub struct CustomRegistry {
+    name: String,
+    kind: CustomRegistryKind,
+    url: Url,
+    scope: Option<GitLabScope>,
+    auth: Option<Auth>,
+}
+
+impl CustomRegistry {
+    /// Builds a registry from its config entry, reading the token variable
+    /// through `lookup`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the URL isn't http(s), a GitLab registry doesn't
+    /// name exactly one project or group, or the token variable is unset or
+    /// not a valid header value.
+    pub fn from_config<F>(
+        config: &CustomRegistryConfig,
+        lookup: F,
+    ) -> Result<Self, RegistryConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let url = Url::parse(&config.url)
+            .ok()
+            .filter(|url| matches!(url.scheme(), "http" | "https"))
+            .ok_or_else(|| RegistryConfigError::InvalidUrl {
+                registry: config.name.clone(),
+                url: config.url.clone(),
+            })?;
+
+        let scope = match (config.kind, &config.project, &config.group) {
+            (CustomRegistryKind::GitLab, Some(project), None) => {
+                Some(GitLabScope::Project(project.clone()))
+            }
+            (CustomRegistryKind::GitLab, None, Some(group)) => {
+                Some(GitLabScope::Group(group.clone()))
+            }
+            (CustomRegistryKind::GitLab, _, _) => {
+                return Err(RegistryConfigError::GitLabScope(config.name.clone()));
+            }
+            _ => None,
+        };
+
+        let auth = match &config.token_env {
+            Some(var) => {
+                let token = lookup(var)
+                    .filter(|token| !token.trim().is_empty())
+                    .ok_or_else(|| RegistryConfigError::MissingToken {
+                        registry: config.name.clone(),
+                        var: var.clone(),
+                    })?;
+                Some(Self::auth(config, token.trim())?)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            name: config.name.clone(),
+            kind: config.kind,
+            url,
+            scope,
+            auth,
+        })
+    }
+
+    fn auth(config: &CustomRegistryConfig, token: &str) -> Result<Auth, RegistryConfigError> {
+        let invalid = || RegistryConfigError::InvalidAuth(config.name.clone());
+        let (header, value) = match (&config.auth_header, config.kind) {
+            (Some(header), _) => (
+                HeaderName::try_from(header.as_str()).map_err(|_| invalid())?,
+                token.to_string(),
+            ),
+            (None, CustomRegistryKind::Cargo) => (AUTHORIZATION, token.to_string()),
+            (None, CustomRegistryKind::Npm) => (AUTHORIZATION, format!("Bearer {}", token)),
+            (None, CustomRegistryKind::GitLab) => {
+                (HeaderName::from_static("private-token"), token.to_string())
+            }
+        };
+        let mut value = HeaderValue::try_from(value).map_err(|_| invalid())?;
+        value.set_sensitive(true);
+        Ok(Auth { header, value })
+    }
+
+    /// The registry URL with `segments` appended to its path.
+    fn endpoint(&self, segments: &[&str]) -> Url {
+        let mut url = self.url.clone();
+        url.path_segments_mut()
+            .expect("registry URLs are http(s)")
+            .pop_if_empty()
+            .extend(segments);
+        url
+    }
+
+    /// Builds the lookup request for `name`.
+    fn request(&self, client: &HttpClient, name: &str) -> RequestBuilder {
+        let request = match (self.kind, &self.scope) {
+            (CustomRegistryKind::GitLab, Some(scope)) => {
+                let (kind, id) = match scope {
+                    GitLabScope::Project(id) => ("projects", id.as_str()),
+                    GitLabScope::Group(id) => ("groups", id.as_str()),
+                };
+                client
+                    .get(self.endpoint(&["api", "v4", kind, id, "packages"]))
+                    .query(&[("package_name", name)])
+            }
+            (CustomRegistryKind::Cargo, _) => {
+                client.get(self.endpoint(&["api", "v1", "crates", name]))
+            }
+            _ => client.get(self.endpoint(&[name])),
+        };
+        match &self.auth {
+            Some(auth) => request.header(auth.header.clone(), auth.value.clone()),
+            None => request,
+        }
+    }
+
+    async fn check(&self, client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+        let response = self.request(client, name).send().await.ok()?;
+
+        if !response.status().is_success() {
+            return None;
+        }
+
+        match self.kind {
+            CustomRegistryKind::Cargo => {
+                let data: CratesIoResponse = response.json().await.ok()?;
+                Some(self.cargo_info(name, data))
+            }
+            CustomRegistryKind::Npm => {
+                let data: NpmResponse = response.json().await.ok()?;
+                Some(self.npm_info(name, data))
+            }
+            CustomRegistryKind::GitLab => {
+                let packages: Vec<GitLabPackage> = response.json().await.ok()?;
+                self.gitlab_info(name, packages)
+            }
+        }
+    }
+
+    fn cargo_info(&self, name: &str, data: CratesIoResponse) -> LibraryInfo {
+        let krate = data.krate;
+        LibraryInfo {
+            package_manager: self.name.clone(),
+            language: "Rust".to_string(),
+            url: self.endpoint(&["api", "v1", "crates", name]).to_string(),
+            repository: krate.as_ref().and_then(|c| c.repository.clone()),
+            description: krate.and_then(|c| c.description),
+        }
+    }
+
+    fn npm_info(&self, name: &str, data: NpmResponse) -> LibraryInfo {
+        LibraryInfo {
+            package_manager: self.name.clone(),
+            language: "JavaScript/TypeScript".to_string(),
+            url: self.endpoint(&[name]).to_string(),
+            repository: data.repository_url(),
+            description: data.description,
+        }
+    }
+
+    /// Picks the package named exactly `name`; GitLab matches names by substring.
+    fn gitlab_info(&self, name: &str, packages: Vec<GitLabPackage>) -> Option<LibraryInfo> {
+        let package = packages.into_iter().find(|p| p.name == name)?;
+
+        let base = self.url.as_str().trim_end_matches('/');
+        let project_path = match &self.scope {
+            Some(GitLabScope::Project(project)) if !project.chars().all(|c| c.is_ascii_digit()) => {
+                Some(project.clone())
+            }
+            _ => package.project_path.clone(),
+        };
+        let url = package
+            .links
+            .and_then(|links| links.web_path)
+            .and_then(|path| self.url.join(&path).ok())
+            .map(|url| url.to_string())
+            .or_else(|| {
+                project_path
+                    .as_ref()
+                    .map(|path| format!("{}/{}/-/packages", base, path))
+            })
+            .unwrap_or_else(|| base.to_string());
+
+        Some(LibraryInfo {
+            package_manager: self.name.clone(),
+            language: gitlab_language(&package.package_type).to_string(),
+            url,
+            repository: project_path.map(|path| format!("{}/{}", base, path)),
+            description: None,
+        })
+    }
+}
+
+impl Registry for CustomRegistry {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(self.check(client, name))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// GitLab
+// ---------------------------------------------------------------------------
+
+/// A package from GitLab's project or group packages API
+#[derive(Debug, Deserialize)]
+struct GitLabPackage {
+    name: String,
+    package_type: String,
+    /// Only reported by the group API
+    #[serde(default)]
+    project_path: Option<String>,
+    #[serde(default, rename = "_links")]
+    links: Option<GitLabLinks>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabLinks {
+    web_path: Option<String>,
+}
+
+/// The language of a GitLab package type.
+fn gitlab_language(package_type: &str) -> &'static str {
+    match package_type {
+        "npm" => "JavaScript/TypeScript",
+        "pypi" => "Python",
+        "maven" => "Java",
+        "nuget" => "C#/.NET",
+        "composer" => "PHP",
+        "golang" => "Go",
+        "rubygems" => "Ruby",
+        "conan" => "C/C++",
+        _ => "Native",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(kind: CustomRegistryKind, url: &str) -> CustomRegistryConfig {
+        CustomRegistryConfig {
+            name: "acme".to_string(),
+            kind,
+            url: url.to_string(),
+            project: None,
+            group: None,
+            token_env: Some("ACME_TOKEN".to_string()),
+            auth_header: None,
+        }
+    }
+
+    fn token(var: &str) -> Option<String> {
+        (var == "ACME_TOKEN").then(|| "s3cret".to_string())
+    }
+
+    #[test]
+    fn test_parse_registry_config() {
+        let config: RegistryConfig = toml::from_str(
+            r#"
+            [[registry]]
+            name = "acme-crates"
+            kind = "cargo"
+            url = "https://crates.acme.internal"
+            token_env = "ACME_CRATES_TOKEN"
+
+            [[registry]]
+            name = "acme-gitlab"
+            kind = "gitlab"
+            url = "https://gitlab.acme.internal"
+            group = "platform"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.registries.len(), 2);
+        assert_eq!(config.registries[0].kind, CustomRegistryKind::Cargo);
+        assert_eq!(config.registries[1].kind, CustomRegistryKind::GitLab);
+        assert_eq!(config.registries[1].group.as_deref(), Some("platform"));
+        assert!(config.registries[1].token_env.is_none());
+    }
+
+    #[test]
+    fn test_cargo_request_sends_raw_token() {
+        let registry = CustomRegistry::from_config(
+            &config(CustomRegistryKind::Cargo, "https://crates.acme.internal/"),
+            token,
+        )
+        .unwrap();
+
+        let request = registry
+            .request(&HttpClient::new(), "widgets")
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://crates.acme.internal/api/v1/crates/widgets"
+        );
+        assert_eq!(request.headers()[AUTHORIZATION], "s3cret");
+        assert!(!format!("{:?}", registry).contains("s3cret"));
+    }
+
+    #[test]
+    fn test_npm_request_encodes_scoped_names() {
+        let registry = CustomRegistry::from_config(
+            &config(CustomRegistryKind::Npm, "https://npm.acme.internal/repo"),
+            token,
+        )
+        .unwrap();
+
+        let request = registry
+            .request(&HttpClient::new(), "@acme/ui")
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://npm.acme.internal/repo/@acme%2Fui"
+        );
+        assert_eq!(request.headers()[AUTHORIZATION], "Bearer s3cret");
+    }
+
+    #[test]
+    fn test_gitlab_request_and_custom_header() {
+        let mut gitlab = config(CustomRegistryKind::GitLab, "https://gitlab.acme.internal");
+        gitlab.project = Some("platform/tools".to_string());
+        let registry = CustomRegistry::from_config(&gitlab, token).unwrap();
+
+        let request = registry
+            .request(&HttpClient::new(), "widgets")
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://gitlab.acme.internal/api/v4/projects/platform%2Ftools/packages?package_name=widgets"
+        );
+        assert_eq!(request.headers()["private-token"], "s3cret");
+
+        gitlab.auth_header = Some("Job-Token".to_string());
+        let registry = CustomRegistry::from_config(&gitlab, token).unwrap();
+        let request = registry
+            .request(&HttpClient::new(), "widgets")
+            .build()
+            .unwrap();
+        assert_eq!(request.headers()["job-token"], "s3cret");
+        assert!(request.headers().get("private-token").is_none());
+    }
+
+    #[test]
+    fn test_from_config_rejects_invalid_entries() {
+        let gitlab = config(CustomRegistryKind::GitLab, "https://gitlab.acme.internal");
+        assert!(matches!(
+            CustomRegistry::from_config(&gitlab, token),
+            Err(RegistryConfigError::GitLabScope(_))
+        ));
+
+        let ftp = config(CustomRegistryKind::Npm, "ftp://npm.acme.internal");
+        assert!(matches!(
+            CustomRegistry::from_config(&ftp, token),
+            Err(RegistryConfigError::InvalidUrl { .. })
+        ));
+
+        let cargo = config(CustomRegistryKind::Cargo, "https://crates.acme.internal");
+        assert!(matches!(
+            CustomRegistry::from_config(&cargo, |_| None),
+            Err(RegistryConfigError::MissingToken { .. })
+        ));
+    }
+
+    #[test]
+    fn test_gitlab_response_requires_exact_name() {
+        let mut gitlab = config(CustomRegistryKind::GitLab, "https://gitlab.acme.internal");
+        gitlab.group = Some("platform".to_string());
+        gitlab.token_env = None;
+        let registry = CustomRegistry::from_config(&gitlab, token).unwrap();
+
+        let packages = || -> Vec<GitLabPackage> {
+            serde_json::from_value(serde_json::json!([
+                {
+                    "name": "widgets-legacy",
+                    "package_type": "npm",
+                    "project_path": "platform/legacy",
+                    "_links": {"web_path": "/platform/legacy/-/packages/7"}
+                },
+                {
+                    "name": "widgets",
+                    "package_type": "pypi",
+                    "project_path": "platform/widgets",
+                    "_links": {"web_path": "/platform/widgets/-/packages/42"}
+                }
+            ]))
+            .unwrap()
+        };
+
+        let info = registry.gitlab_info("widgets", packages()).unwrap();
+        assert_eq!(info.package_manager, "acme");
+        assert_eq!(info.language, "Python");
+        assert_eq!(
+            info.url,
+            "https://gitlab.acme.internal/platform/widgets/-/packages/42"
+        );
+        assert_eq!(
+            info.repository.as_deref(),
+            Some("https://gitlab.acme.internal/platform/widgets")
+        );
+        assert!(registry.gitlab_info("gadgets", packages()).is_none());
+    }
+}
@@ -0,0 +1,861 @@
+//! Package registries searched when resolving a library name.
+//!
+//! Each [`Registry`] looks a name up in one package registry and describes the
+//! match as a [`LibraryInfo`]. [`find_library`](crate::find_library) queries
+//! every registry in [`configured_registries`]: the private registries set up
+//! in `registries.toml` (see [`custom`]) followed by [`default_registries`].
+//! [`find_library_in`](crate::find_library_in) queries only the ones a caller
+//! passes, which is also how other registries are plugged in.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use research_lib::find_library_in;
+//! use research_lib::registry::{CratesIo, Registry, RubyGems};
+//!
+//! # async fn example() {
+//! let registries: Vec<Box<dyn Registry>> = vec![Box::new(CratesIo), Box::new(RubyGems)];
+//! for library in find_library_in(&registries, "rake").await {
+//!     println!("{} ({})", library.package_manager, library.url);
+//! }
+//! # }
+//! ```
+
+use futures::future::BoxFuture;
+use reqwest::Client as HttpClient;
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::LibraryInfo;
+
+pub mod custom;
+
+pub use custom::{
+    CustomRegistry, CustomRegistryConfig, CustomRegistryKind, REGISTRIES_FILE_ENV, RegistryConfig,
+    RegistryConfigError,
+};
+
+/// A package registry that can be searched for a library by name.
+///
+/// Lookups are best effort: network failures, error statuses and unexpected
+/// responses all count as "not found".
+pub trait Registry: Send + Sync {
+    /// The registry's name, as stored in [`LibraryInfo::package_manager`].
+    fn name(&self) -> &str;
+
+    /// Look `name` up, returning `None` when the registry has no such package.
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>>;
+}
+
+/// Returns all built-in registries, in the order their matches are listed.
+pub fn default_registries() -> Vec<Box<dyn Registry>> {
+    vec![
+        Box::new(CratesIo),
+        Box::new(Npm),
+        Box::new(PyPI),
+        Box::new(Packagist),
+        Box::new(LuaRocks),
+        Box::new(GoModules),
+        Box::new(RubyGems),
+        Box::new(MavenCentral),
+        Box::new(NuGet),
+        Box::new(Homebrew),
+    ]
+}
+
+/// Returns the registries configured in `registries.toml`, followed by the
+/// built-in ones.
+///
+/// Private registries come first, so an internal package that shares its name
+/// with a public one is listed ahead of it. A config file that can't be loaded,
+/// or an entry that can't be built (e.g. because its token variable is unset),
+/// is logged and skipped.
+pub fn configured_registries() -> Vec<Box<dyn Registry>> {
+    let mut registries: Vec<Box<dyn Registry>> = Vec::new();
+    match RegistryConfig::load() {
+        Ok(config) => {
+            for entry in &config.registries {
+                match CustomRegistry::from_config(entry, |var| std::env::var(var).ok()) {
+                    Ok(registry) => registries.push(Box::new(registry)),
+                    Err(e) => warn!("Skipping registry '{}': {}", entry.name, e),
+                }
+            }
+        }
+        Err(e) => warn!("Ignoring custom registries: {}", e),
+    }
+    registries.extend(default_registries());
+    registries
+}
+
+/// Returns `url` when it points at a source hosting service.
+///
+/// Registries that only report a homepage use this to avoid passing a
+/// project website off as the repository.
+fn repository_url(url: &str) -> Option<String> {
+    let host = url.split_once("://")?.1.split('/').next()?;
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    matches!(
+        host,
+        "github.com" | "gitlab.com" | "bitbucket.org" | "codeberg.org" | "sr.ht" | "git.sr.ht"
+    )
+    .then(|| url.trim_end_matches('/').to_string())
+}
+
+/// crates.io (Rust)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CratesIo;
+
+/// npm (JavaScript/TypeScript)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Npm;
+
+/// PyPI (Python)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PyPI;
+
+/// Packagist (PHP)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Packagist;
+
+/// LuaRocks (Lua)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LuaRocks;
+
+/// pkg.go.dev (Go)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GoModules;
+
+/// RubyGems (Ruby)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RubyGems;
+
+/// Maven Central (Java and other JVM languages)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MavenCentral;
+
+/// NuGet (.NET)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NuGet;
+
+/// Homebrew formulae (native libraries and tools)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Homebrew;
+
+// ---------------------------------------------------------------------------
+// crates.io
+// ---------------------------------------------------------------------------
+
+/// Response from crates.io API
+#[derive(Debug, Deserialize)]
+struct CratesIoResponse {
+    #[serde(rename = "crate")]
+    krate: Option<CratesIoCrate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CratesIoCrate {
+    description: Option<String>,
+    repository: Option<String>,
+}
+
+impl Registry for CratesIo {
+    fn name(&self) -> &'static str {
+        "crates.io"
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(check_crates_io(client, name))
+    }
+}
+
+async fn check_crates_io(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+    let url = format!("https://crates.io/api/v1/crates/{}", name);
+    let response = client.get(&url).send().await.ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let data: CratesIoResponse = response.json().await.ok()?;
+    let description = data.krate.as_ref().and_then(|c| c.description.clone());
+    let repository = data.krate.as_ref().and_then(|c| c.repository.clone());
+
+    Some(LibraryInfo {
+        package_manager: "crates.io".to_string(),
+        language: "Rust".to_string(),
+        url: format!("https://crates.io/crates/{}", name),
+        repository,
+        description,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// npm
+// ---------------------------------------------------------------------------
+
+/// Response from npm registry API
+#[derive(Debug, Deserialize)]
+struct NpmResponse {
+    description: Option<String>,
+    repository: Option<NpmRepository>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmRepository {
+    url: Option<String>,
+}
+
+impl NpmResponse {
+    /// The repository URL without the `git+` prefix npm allows.
+    fn repository_url(&self) -> Option<String> {
+        self.repository
+            .as_ref()
+            .and_then(|r| r.url.as_ref())
+            .map(|url| url.strip_prefix("git+").unwrap_or(url).to_string())
+    }
+}
+
+impl Registry for Npm {
+    fn name(&self) -> &'static str {
+        "npm"
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(check_npm(client, name))
+    }
+}
+
+async fn check_npm(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+    let url = format!("https://registry.npmjs.org/{}", name);
+    let response = client.get(&url).send().await.ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let data: NpmResponse = response.json().await.ok()?;
+
+    Some(LibraryInfo {
+        package_manager: "npm".to_string(),
+        language: "JavaScript/TypeScript".to_string(),
+        url: format!("https://www.npmjs.com/package/{}", name),
+        repository: data.repository_url(),
+        description: data.description,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// PyPI
+// ---------------------------------------------------------------------------
+
+/// Response from PyPI API
+#[derive(Debug, Deserialize)]
+struct PyPIResponse {
+    info: Option<PyPIInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PyPIInfo {
+    summary: Option<String>,
+    project_urls: Option<std::collections::HashMap<String, String>>,
+}
+
+impl Registry for PyPI {
+    fn name(&self) -> &'static str {
+        "PyPI"
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(check_pypi(client, name))
+    }
+}
+
+async fn check_pypi(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+    let url = format!("https://pypi.org/pypi/{}/json", name);
+    let response = client.get(&url).send().await.ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let data: PyPIResponse = response.json().await.ok()?;
+    let description = data.info.as_ref().and_then(|i| i.summary.clone());
+
+    // Extract repository URL from project_urls (try "Repository" first, then "Source")
+    let repository = data
+        .info
+        .as_ref()
+        .and_then(|i| i.project_urls.as_ref())
+        .and_then(|urls| {
+            urls.get("Repository")
+                .or_else(|| urls.get("Source"))
+                .cloned()
+        });
+
+    Some(LibraryInfo {
+        package_manager: "PyPI".to_string(),
+        language: "Python".to_string(),
+        url: format!("https://pypi.org/project/{}", name),
+        repository,
+        description,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Packagist
+// ---------------------------------------------------------------------------
+
+/// Response from Packagist API
+#[derive(Debug, Deserialize)]
+struct PackagistSearchResponse {
+    results: Option<Vec<PackagistResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PackagistResult {
+    name: String,
+    description: Option<String>,
+    url: Option<String>,
+}
+
+impl Registry for Packagist {
+    fn name(&self) -> &'static str {
+        "Packagist"
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(check_packagist(client, name))
+    }
+}
+
+async fn check_packagist(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+    // Packagist requires vendor/package format, so we search instead
+    let url = format!("https://packagist.org/search.json?q={}", name);
+    let response = client.get(&url).send().await.ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let data: PackagistSearchResponse = response.json().await.ok()?;
+    let results = data.results?;
+
+    // Look for an exact match in the package name
+    let matching = results.into_iter().find(|r| {
+        let package_name = r.name.split('/').next_back().unwrap_or(&r.name);
+        package_name == name
+    })?;
+
+    Some(LibraryInfo {
+        package_manager: "Packagist".to_string(),
+        language: "PHP".to_string(),
+        url: matching
+            .url
+            .unwrap_or_else(|| format!("https://packagist.org/packages/{}", matching.name)),
+        repository: None,
+        description: matching.description,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// LuaRocks
+// ---------------------------------------------------------------------------
+
+impl Registry for LuaRocks {
+    fn name(&self) -> &'static str {
+        "LuaRocks"
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(check_luarocks(client, name))
+    }
+}
+
+async fn check_luarocks(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+    // LuaRocks doesn't have a formal API, but we can check if the package page exists
+    let url = format!("https://luarocks.org/modules/{}", name);
+    let response = client.head(&url).send().await.ok()?;
+
+    if response.status().is_success() {
+        return Some(LibraryInfo {
+            package_manager: "LuaRocks".to_string(),
+            language: "Lua".to_string(),
+            url,
+            repository: None,
+            description: None,
+        });
+    }
+
+    // Try searching
+    let search_url = format!("https://luarocks.org/search?q={}", name);
+    let response = client.get(&search_url).send().await.ok()?;
+
+    if response.status().is_success() {
+        let body = response.text().await.ok()?;
+        // Simple check if the package name appears in search results
+        if body.contains(&format!("\"/{}/", name)) || body.contains(&format!(">{}<", name)) {
+            return Some(LibraryInfo {
+                package_manager: "LuaRocks".to_string(),
+                language: "Lua".to_string(),
+                url: format!("https://luarocks.org/modules/{}", name),
+                repository: None,
+                description: None,
+            });
+        }
+    }
+
+    None
+}
+
+// ---------------------------------------------------------------------------
+// pkg.go.dev
+// ---------------------------------------------------------------------------
+
+impl Registry for GoModules {
+    fn name(&self) -> &'static str {
+        "pkg.go.dev"
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(check_go(client, name))
+    }
+}
+
+async fn check_go(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+    // pkg.go.dev requires full module path, but we can search
+    // First try as a potential GitHub path
+    let common_prefixes = ["github.com/", "golang.org/x/", ""];
+
+    for prefix in common_prefixes {
+        let module = if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}{}", prefix, name)
+        };
+
+        let url = format!("https://pkg.go.dev/{}", module);
+        let response = client.head(&url).send().await.ok();
+
+        if let Some(resp) = response
+            && resp.status().is_success()
+        {
+            return Some(LibraryInfo {
+                package_manager: "pkg.go.dev".to_string(),
+                language: "Go".to_string(),
+                url,
+                repository: None,
+                description: None,
+            });
+        }
+    }
+
+    None
+}
+
+// ---------------------------------------------------------------------------
+// RubyGems
+// ---------------------------------------------------------------------------
+
+/// Response from the RubyGems gem API
+#[derive(Debug, Deserialize)]
+struct RubyGemsResponse {
+    name: String,
+    info: Option<String>,
+    project_uri: Option<String>,
+    homepage_uri: Option<String>,
+    source_code_uri: Option<String>,
+}
+
+impl RubyGemsResponse {
+    fn into_library_info(self) -> LibraryInfo {
+        let repository = self
+            .source_code_uri
+            .filter(|uri| !uri.is_empty())
+            .or_else(|| self.homepage_uri.as_deref().and_then(repository_url));
+
+        LibraryInfo {
+            package_manager: "RubyGems".to_string(),
+            language: "Ruby".to_string(),
+            url: self
+                .project_uri
+                .unwrap_or_else(|| format!("https://rubygems.org/gems/{}", self.name)),
+            repository,
+            description: self.info.filter(|info| !info.trim().is_empty()),
+        }
+    }
+}
+
+impl Registry for RubyGems {
+    fn name(&self) -> &'static str {
+        "RubyGems"
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(check_rubygems(client, name))
+    }
+}
+
+async fn check_rubygems(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+    let url = format!("https://rubygems.org/api/v1/gems/{}.json", name);
+    let response = client.get(&url).send().await.ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let data: RubyGemsResponse = response.json().await.ok()?;
+    Some(data.into_library_info())
+}
+
+// ---------------------------------------------------------------------------
+// Maven Central
+// ---------------------------------------------------------------------------
+
+/// Response from the Maven Central search API
+#[derive(Debug, Deserialize)]
+struct MavenSearchResponse {
+    response: MavenSearchResults,
+}
+
+#[derive(Debug, Deserialize)]
+struct MavenSearchResults {
+    docs: Vec<MavenArtifact>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MavenArtifact {
+    #[serde(rename = "g")]
+    group: String,
+    #[serde(rename = "a")]
+    artifact: String,
+}
+
+impl MavenSearchResponse {
+    /// The first artifact named exactly `name`, in search relevance order.
+    fn into_library_info(self, name: &str) -> Option<LibraryInfo> {
+        // The search also matches artifacts that merely contain the name
+        let artifact = self
+            .response
+            .docs
+            .into_iter()
+            .find(|doc| doc.artifact == name)?;
+
+        Some(LibraryInfo {
+            package_manager: "Maven Central".to_string(),
+            language: "Java".to_string(),
+            url: format!(
+                "https://central.sonatype.com/artifact/{}/{}",
+                artifact.group, artifact.artifact
+            ),
+            repository: None,
+            description: None,
+        })
+    }
+}
+
+impl Registry for MavenCentral {
+    fn name(&self) -> &'static str {
+        "Maven Central"
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(check_maven_central(client, name))
+    }
+}
+
+async fn check_maven_central(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+    let query = format!("a:\"{name}\"");
+    let response = client
+        .get("https://search.maven.org/solrsearch/select")
+        .query(&[("q", query.as_str()), ("rows", "20"), ("wt", "json")])
+        .send()
+        .await
+        .ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let data: MavenSearchResponse = response.json().await.ok()?;
+    data.into_library_info(name)
+}
+
+// ---------------------------------------------------------------------------
+// NuGet
+// ---------------------------------------------------------------------------
+
+/// Response from the NuGet search API
+#[derive(Debug, Deserialize)]
+struct NuGetSearchResponse {
+    data: Vec<NuGetPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NuGetPackage {
+    id: String,
+    description: Option<String>,
+    project_url: Option<String>,
+}
+
+impl NuGetSearchResponse {
+    /// The package whose ID is `name`; NuGet IDs are case-insensitive.
+    fn into_library_info(self, name: &str) -> Option<LibraryInfo> {
+        let package = self
+            .data
+            .into_iter()
+            .find(|package| package.id.eq_ignore_ascii_case(name))?;
+
+        Some(LibraryInfo {
+            package_manager: "NuGet".to_string(),
+            language: "C#/.NET".to_string(),
+            url: format!("https://www.nuget.org/packages/{}", package.id),
+            repository: package.project_url.as_deref().and_then(repository_url),
+            description: package
+                .description
+                .filter(|description| !description.trim().is_empty()),
+        })
+    }
+}
+
+impl Registry for NuGet {
+    fn name(&self) -> &'static str {
+        "NuGet"
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(check_nuget(client, name))
+    }
+}
+
+async fn check_nuget(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+    let query = format!("packageid:{name}");
+    let response = client
+        .get("https://azuresearch-usnc.nuget.org/query")
+        .query(&[("q", query.as_str()), ("take", "5")])
+        .send()
+        .await
+        .ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let data: NuGetSearchResponse = response.json().await.ok()?;
+    data.into_library_info(name)
+}
+
+// ---------------------------------------------------------------------------
+// Homebrew
+// ---------------------------------------------------------------------------
+
+/// Response from the Homebrew formula API
+#[derive(Debug, Deserialize)]
+struct HomebrewFormula {
+    name: String,
+    desc: Option<String>,
+    homepage: Option<String>,
+}
+
+impl HomebrewFormula {
+    fn into_library_info(self) -> LibraryInfo {
+        LibraryInfo {
+            package_manager: "Homebrew".to_string(),
+            // Formulae wrap software written in any language
+            language: "Native".to_string(),
+            url: format!("https://formulae.brew.sh/formula/{}", self.name),
+            repository: self.homepage.as_deref().and_then(repository_url),
+            description: self.desc,
+        }
+    }
+}
+
+impl Registry for Homebrew {
+    fn name(&self) -> &'static str {
+        "Homebrew"
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        name: &'a str,
+    ) -> BoxFuture<'a, Option<LibraryInfo>> {
+        Box::pin(check_homebrew(client, name))
+    }
+}
+
+async fn check_homebrew(client: &HttpClient, name: &str) -> Option<LibraryInfo> {
+    let url = format!("https://formulae.brew.sh/api/formula/{}.json", name);
+    let response = client.get(&url).send().await.ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let data: HomebrewFormula = response.json().await.ok()?;
+    Some(data.into_library_info())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_registries() {
+        let registries = default_registries();
+        let names: Vec<&str> = registries.iter().map(|r| r.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "crates.io",
+                "npm",
+                "PyPI",
+                "Packagist",
+                "LuaRocks",
+                "pkg.go.dev",
+                "RubyGems",
+                "Maven Central",
+                "NuGet",
+                "Homebrew"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repository_url() {
+        assert_eq!(
+            repository_url("https://github.com/rails/rails/"),
+            Some("https://github.com/rails/rails".to_string())
+        );
+        assert_eq!(
+            repository_url("https://www.gitlab.com/owner/repo"),
+            Some("https://www.gitlab.com/owner/repo".to_string())
+        );
+        assert_eq!(repository_url("https://rubyonrails.org"), None);
+        assert_eq!(repository_url("github.com/rails/rails"), None);
+    }
+
+    #[test]
+    fn test_rubygems_response() {
+        let data: RubyGemsResponse = serde_json::from_value(serde_json::json!({
+            "name": "rake",
+            "info": "Rake is a Make-like program implemented in Ruby.",
+            "project_uri": "https://rubygems.org/gems/rake",
+            "homepage_uri": "https://github.com/ruby/rake",
+            "source_code_uri": null
+        }))
+        .unwrap();
+
+        let info = data.into_library_info();
+        assert_eq!(info.package_manager, "RubyGems");
+        assert_eq!(info.language, "Ruby");
+        assert_eq!(info.url, "https://rubygems.org/gems/rake");
+        assert_eq!(
+            info.repository.as_deref(),
+            Some("https://github.com/ruby/rake")
+        );
+        assert_eq!(
+            info.description.as_deref(),
+            Some("Rake is a Make-like program implemented in Ruby.")
+        );
+    }
+
+    #[test]
+    fn test_maven_response_requires_exact_artifact() {
+        let data = || -> MavenSearchResponse {
+            serde_json::from_value(serde_json::json!({
+                "response": {
+                    "numFound": 2,
+                    "docs": [
+                        {"id": "com.google.guava:guava-testlib", "g": "com.google.guava", "a": "guava-testlib"},
+                        {"id": "com.google.guava:guava", "g": "com.google.guava", "a": "guava"}
+                    ]
+                }
+            }))
+            .unwrap()
+        };
+
+        let info = data().into_library_info("guava").unwrap();
+        assert_eq!(info.package_manager, "Maven Central");
+        assert_eq!(
+            info.url,
+            "https://central.sonatype.com/artifact/com.google.guava/guava"
+        );
+        assert!(data().into_library_info("gson").is_none());
+    }
+
+    #[test]
+    fn test_nuget_response_matches_case_insensitively() {
+        let data: NuGetSearchResponse = serde_json::from_value(serde_json::json!({
+            "totalHits": 1,
+            "data": [{
+                "id": "Newtonsoft.Json",
+                "version": "13.0.3",
+                "description": "Json.NET is a popular high-performance JSON framework for .NET",
+                "projectUrl": "https://www.newtonsoft.com/json"
+            }]
+        }))
+        .unwrap();
+
+        let info = data.into_library_info("newtonsoft.json").unwrap();
+        assert_eq!(info.package_manager, "NuGet");
+        assert_eq!(info.url, "https://www.nuget.org/packages/Newtonsoft.Json");
+        assert_eq!(info.repository, None);
+    }
+
+    #[test]
+    fn test_homebrew_formula() {
+        let data: HomebrewFormula = serde_json::from_value(serde_json::json!({
+            "name": "libgit2",
+            "full_name": "libgit2",
+            "desc": "C library of Git core methods that is re-entrant and linkable",
+            "homepage": "https://libgit2.github.com/"
+        }))
+        .unwrap();
+
+        let info = data.into_library_info();
+        assert_eq!(info.package_manager, "Homebrew");
+        assert_eq!(info.url, "https://formulae.brew.sh/formula/libgit2");
+        assert_eq!(info.repository, None);
+    }
+}
@@ -0,0 +1,594 @@
+//! Source repository context for grounding library research in real code.
+//!
+//! Models research a library from what they remember and what they can find
+//! on the web, which is often an older release. When the library's source is
+//! available, a full research run can analyze it with tree-hugger first and
+//! add a summary to its Phase 1 prompts: the exported symbols (with their
+//! signatures and doc summaries) and the files in its examples directories.
+//!
+//! The source comes from a [`RepositorySource`]: a local checkout, a git URL,
+//! or the repository URL the package registry lists for the library. Cloned
+//! repositories are kept in the library's `repositories` directory and
+//! updated by later runs.
+//!
+//! ```no_run
+//! use research_lib::ResearchOptions;
+//! use research_lib::repository::RepositorySource;
+//!
+//! let options = ResearchOptions::default()
+//!     .with_repository(RepositorySource::Path("../clap".into()));
+//! assert!(options.repository.is_some());
+//! ```
+
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use thiserror::Error;
+use tokio::process::Command;
+use tracing::{debug, instrument, warn};
+use tree_hugger_lib::{
+    ParallelOptions, ProgrammingLanguage, SymbolInfo, SymbolKind, TreeFile, TreeHuggerError,
+    TreePackage, TreePackageConfig, analyze_files,
+};
+use walkdir::WalkDir;
+
+use crate::LibraryInfo;
+
+/// Exported symbols listed in a prompt; the rest are only counted.
+pub const MAX_API_ITEMS: usize = 120;
+
+/// Example files listed in a prompt.
+pub const MAX_EXAMPLES: usize = 40;
+
+/// Sources that aren't part of the library's public surface.
+const NON_API_GLOBS: [&str; 10] = [
+    "**/tests/**",
+    "**/test/**",
+    "**/benches/**",
+    "**/examples/**",
+    "**/node_modules/**",
+    "**/vendor/**",
+    "**/*_test.go",
+    "**/test_*.py",
+    "**/*.test.*",
+    "**/*.spec.*",
+];
+
+/// Directory names searched for examples.
+const EXAMPLE_DIRS: [&str; 4] = ["examples", "example", "demos", "demo"];
+
+/// Where the repository to analyze comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepositorySource {
+    /// A local checkout
+    Path(PathBuf),
+    /// A git URL to clone
+    Url(String),
+    /// The repository URL the package registry lists for the library
+    Registry,
+}
+
+impl FromStr for RepositorySource {
+    type Err = Infallible;
+
+    /// Parses `registry` (or `auto`), a git URL, or a local path.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(match s {
+            "registry" | "auto" => RepositorySource::Registry,
+            _ if s.contains("://") || s.starts_with("git@") => RepositorySource::Url(s.to_string()),
+            _ => RepositorySource::Path(PathBuf::from(s)),
+        })
+    }
+}
+
+impl fmt::Display for RepositorySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepositorySource::Path(path) => write!(f, "{}", path.display()),
+            RepositorySource::Url(url) => f.write_str(url),
+            RepositorySource::Registry => f.write_str("the registry's repository URL"),
+        }
+    }
+}
+
+/// Errors that can occur while preparing repository context.
+#[derive(Debug, Error)]
+pub enum RepositoryError {
+    /// The registry lists no repository for the library.
+    #[error("The package registry lists no repository for this library")]
+    NoRepositoryUrl,
+
+    /// The local checkout doesn't exist.
+    #[error("Repository not found: {}", .0.display())]
+    NotFound(PathBuf),
+
+    /// `git clone` failed.
+    #[error("Failed to clone {url}: {message}")]
+    Clone { url: String, message: String },
+
+    /// tree-hugger couldn't analyze the sources.
+    #[error("Failed to analyze repository: {0}")]
+    Analysis(#[from] TreeHuggerError),
+
+    /// The analysis task panicked or was cancelled.
+    #[error("Repository analysis did not finish: {0}")]
+    Join(#[from] tokio::task::JoinError),
+}
+
+/// An exported symbol of the library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiItem {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Parameters and return type, e.g. `(path: &Path) -> Result<Self>`
+    pub signature: Option<String>,
+    /// First line of the symbol's doc comment
+    pub summary: Option<String>,
+}
+
+/// What a repository analysis found.
+#[derive(Debug, Clone)]
+pub struct RepositoryContext {
+    /// The package root that was analyzed
+    pub root: PathBuf,
+    pub language: ProgrammingLanguage,
+    /// Number of source files analyzed
+    pub source_files: usize,
+    /// Exported symbols by source file (relative to `root`), in file order
+    pub public_api: BTreeMap<PathBuf, Vec<ApiItem>>,
+    /// Files in the examples directories, relative to `root`
+    pub examples: Vec<PathBuf>,
+}
+
+impl RepositoryContext {
+    /// Analyzes the package in `dir` with tree-hugger.
+    ///
+    /// Tests, benchmarks, examples and vendored code don't count towards the
+    /// public API. Files tree-hugger can't parse are skipped.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `dir` isn't inside a git repository or has no
+    /// source files.
+    #[instrument(name = "analyze_repository")]
+    pub fn analyze(dir: &Path) -> Result<Self, RepositoryError> {
+        let config = TreePackageConfig {
+            language: None,
+            ignores: NON_API_GLOBS.iter().map(|glob| glob.to_string()).collect(),
+        };
+        let package = TreePackage::with_config(dir, config)?;
+        let root = package.root_dir.clone();
+
+        let exported = analyze_files(&package.source_files, &ParallelOptions::default(), |file| {
+            let symbols = TreeFile::new(file)
+                .and_then(|tree| tree.exported_symbols())
+                .inspect_err(|e| debug!(file = %file.display(), error = %e, "Skipping file"))
+                .unwrap_or_default();
+            let source = std::fs::read_to_string(file).unwrap_or_default();
+            Ok(symbols
+                .into_iter()
+                .filter(|symbol| is_public(symbol, &source))
+                .collect::<Vec<_>>())
+        })?;
+
+        let mut public_api = BTreeMap::new();
+        for (file, symbols) in package.source_files.iter().zip(exported) {
+            let items: Vec<_> = symbols.iter().map(ApiItem::from).collect();
+            if !items.is_empty() {
+                let relative = file.strip_prefix(&root).unwrap_or(file).to_path_buf();
+                public_api.insert(relative, items);
+            }
+        }
+
+        Ok(Self {
+            examples: find_examples(&root),
+            language: package.language,
+            source_files: package.source_files.len(),
+            public_api,
+            root,
+        })
+    }
+
+    /// Total number of exported symbols found.
+    pub fn api_len(&self) -> usize {
+        self.public_api.values().map(Vec::len).sum()
+    }
+
+    /// Renders the context as a Markdown section for a prompt.
+    ///
+    /// Files closest to the package root (usually the entry points) are
+    /// listed first; at most [`MAX_API_ITEMS`] symbols and [`MAX_EXAMPLES`]
+    /// example files are included.
+    pub fn to_prompt(&self) -> String {
+        let mut out = String::from("## Source Repository\n\n");
+        out.push_str(
+            "The library's source code was analyzed directly. Prefer these names and \
+             signatures over remembered ones, and base code examples on them.\n\n",
+        );
+        out.push_str(&format!("- Language: {}\n", self.language.name()));
+        out.push_str(&format!("- Source files: {}\n", self.source_files));
+
+        let total = self.api_len();
+        if total > 0 {
+            if total > MAX_API_ITEMS {
+                out.push_str(&format!(
+                    "\n### Public API (first {} of {} exported symbols)\n\n",
+                    MAX_API_ITEMS, total
+                ));
+            } else {
+                out.push_str(&format!(
+                    "\n### Public API ({} exported symbols)\n\n",
+                    total
+                ));
+            }
+
+            let mut files: Vec<_> = self.public_api.iter().collect();
+            files.sort_by_key(|(file, _)| file.components().count());
+            let mut remaining = MAX_API_ITEMS;
+            for (file, items) in files {
+                if remaining == 0 {
+                    break;
+                }
+                out.push_str(&format!("- `{}`\n", file.display()));
+                for item in items.iter().take(remaining) {
+                    out.push_str(&format!("  - {}\n", item));
+                }
+                remaining = remaining.saturating_sub(items.len());
+            }
+        }
+
+        if !self.examples.is_empty() {
+            out.push_str("\n### Examples\n\n");
+            for example in self.examples.iter().take(MAX_EXAMPLES) {
+                out.push_str(&format!("- `{}`\n", example.display()));
+            }
+            if self.examples.len() > MAX_EXAMPLES {
+                out.push_str(&format!(
+                    "- ... and {} more\n",
+                    self.examples.len() - MAX_EXAMPLES
+                ));
+            }
+        }
+
+        out
+    }
+}
+
+impl From<&SymbolInfo> for ApiItem {
+    fn from(symbol: &SymbolInfo) -> Self {
+        let signature = symbol.signature.as_ref().map(|sig| {
+            let params: Vec<_> = sig
+                .parameters
+                .iter()
+                .map(|param| match &param.type_annotation {
+                    Some(ty) => format!("{}: {}", param.name, ty),
+                    None => param.name.clone(),
+                })
+                .collect();
+            match &sig.return_type {
+                Some(ret) => format!("({}) -> {}", params.join(", "), ret),
+                None => format!("({})", params.join(", ")),
+            }
+        });
+        let summary = symbol.doc_comment.as_deref().and_then(|doc| {
+            doc.lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .map(str::to_string)
+        });
+
+        Self {
+            name: symbol.name.clone(),
+            kind: symbol.kind,
+            signature,
+            summary,
+        }
+    }
+}
+
+impl fmt::Display for ApiItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} `{}{}`",
+            self.kind,
+            self.name,
+            self.signature.as_deref().unwrap_or_default()
+        )?;
+        if let Some(summary) = &self.summary {
+            write!(f, " — {}", summary)?;
+        }
+        Ok(())
+    }
+}
+
+/// Lists the files in the examples directories at, or one level below, `root`.
+fn find_examples(root: &Path) -> Vec<PathBuf> {
+    let mut examples: Vec<_> = WalkDir::new(root)
+        .max_depth(2)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.')
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.file_type().is_dir()
+                && EXAMPLE_DIRS.contains(&entry.file_name().to_string_lossy().as_ref())
+        })
+        .flat_map(|dir| {
+            WalkDir::new(dir.path())
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|entry| entry.file_type().is_file())
+                .map(|entry| entry.path().to_path_buf())
+                .collect::<Vec<_>>()
+        })
+        .filter_map(|path| path.strip_prefix(root).ok().map(Path::to_path_buf))
+        .collect();
+    examples.sort();
+    examples
+}
+
+/// Returns where a clone of `url` is kept.
+///
+/// Clones live in the library's [`repositories_dir`](crate::paths::ResearchPaths::repositories_dir), in a
+/// directory named after the URL's host and path.
+pub fn clone_dir(url: &str) -> PathBuf {
+    let name = clone_url(url)
+        .split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .trim_start_matches("git@")
+        .trim_end_matches('/')
+        .trim_end_matches(".git")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>();
+    crate::paths::ResearchPaths::from_env()
+        .repositories_dir()
+        .join(name)
+}
+
+/// Whether an exported symbol is part of the library's public API.
+///
+/// tree-hugger counts every top-level Rust item as exported, so Rust items
+/// must also be declared `pub` (not `pub(crate)`) on their first line.
+fn is_public(symbol: &SymbolInfo, source: &str) -> bool {
+    if symbol.language != ProgrammingLanguage::Rust {
+        return true;
+    }
+    if matches!(symbol.kind, SymbolKind::Variable | SymbolKind::Parameter) {
+        return false;
+    }
+    source
+        .lines()
+        .nth(symbol.range.start_line.saturating_sub(1))
+        .is_some_and(|line| line.trim_start().starts_with("pub "))
+}
+
+/// Strips the `git+` prefix npm and PyPI put on repository URLs.
+fn clone_url(url: &str) -> &str {
+    url.trim().trim_start_matches("git+")
+}
+
+/// Clones `url` (shallowly), or updates an earlier clone, returning its path.
+async fn clone_repository(url: &str) -> Result<PathBuf, RepositoryError> {
+    let dir = clone_dir(url);
+    let clone_error = |message: String| RepositoryError::Clone {
+        url: url.to_string(),
+        message,
+    };
+
+    if dir.join(".git").is_dir() {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(&dir)
+            .args(["pull", "--ff-only", "--quiet"])
+            .output()
+            .await;
+        match output {
+            Ok(output) if output.status.success() => {}
+            Ok(output) => warn!(
+                url,
+                stderr = %String::from_utf8_lossy(&output.stderr).trim(),
+                "Failed to update repository clone; using it as is"
+            ),
+            Err(e) => warn!(url, error = %e, "Failed to update repository clone; using it as is"),
+        }
+        return Ok(dir);
+    }
+
+    if let Some(parent) = dir.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| clone_error(e.to_string()))?;
+    }
+    let output = Command::new("git")
+        .args(["clone", "--depth", "1", "--quiet", clone_url(url)])
+        .arg(&dir)
+        .output()
+        .await
+        .map_err(|e| clone_error(format!("Failed to run git: {}", e)))?;
+    if !output.status.success() {
+        return Err(clone_error(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(dir)
+}
+
+/// Finds or clones the repository `source` points at and analyzes it.
+///
+/// `library` supplies the URL for [`RepositorySource::Registry`].
+///
+/// ## Errors
+///
+/// Returns an error if the repository can't be found, cloned or analyzed.
+#[instrument(skip(library))]
+pub async fn repository_context(
+    source: &RepositorySource,
+    library: Option<&LibraryInfo>,
+) -> Result<RepositoryContext, RepositoryError> {
+    let dir = match source {
+        RepositorySource::Path(path) if path.is_dir() => path.clone(),
+        RepositorySource::Path(path) => return Err(RepositoryError::NotFound(path.clone())),
+        RepositorySource::Url(url) => clone_repository(url).await?,
+        RepositorySource::Registry => {
+            let url = library
+                .and_then(|li| li.repository.as_deref())
+                .ok_or(RepositoryError::NoRepositoryUrl)?;
+            clone_repository(url).await?
+        }
+    };
+
+    tokio::task::spawn_blocking(move || RepositoryContext::analyze(&dir)).await?
+}
+
+/// Returns true for the Phase 1 tasks whose prompts get repository context.
+///
+/// Similar libraries and the changelog are about other packages and past
+/// releases, so the current source doesn't help them.
+pub(crate) fn is_grounded(task: &str) -> bool {
+    matches!(task, "overview" | "integration_partners" | "use_cases")
+        || task.starts_with("question_")
+}
+
+/// Appends the rendered repository `context` to `task`'s prompt, if it's a
+/// task that uses it.
+pub(crate) fn ground_prompt(prompt: String, task: &str, context: Option<&str>) -> String {
+    match context {
+        Some(context) if is_grounded(task) => format!("{}\n\n{}", prompt.trim_end(), context),
+        _ => prompt,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> RepositoryContext {
+        let item = |name: &str, kind, signature: Option<&str>, summary: Option<&str>| ApiItem {
+            name: name.to_string(),
+            kind,
+            signature: signature.map(str::to_string),
+            summary: summary.map(str::to_string),
+        };
+        RepositoryContext {
+            root: PathBuf::from("/src/clap"),
+            language: ProgrammingLanguage::Rust,
+            source_files: 3,
+            public_api: BTreeMap::from([
+                (
+                    PathBuf::from("src/builder/command.rs"),
+                    vec![item(
+                        "Command",
+                        SymbolKind::Type,
+                        None,
+                        Some("A CLI command."),
+                    )],
+                ),
+                (
+                    PathBuf::from("src/lib.rs"),
+                    vec![item(
+                        "parse",
+                        SymbolKind::Function,
+                        Some("(args: I) -> Self"),
+                        None,
+                    )],
+                ),
+            ]),
+            examples: vec![PathBuf::from("examples/git.rs")],
+        }
+    }
+
+    #[test]
+    fn sources_parse_from_cli_values() {
+        let parse = |s: &str| s.parse::<RepositorySource>().unwrap();
+        assert_eq!(parse("registry"), RepositorySource::Registry);
+        assert_eq!(
+            parse("https://github.com/clap-rs/clap"),
+            RepositorySource::Url("https://github.com/clap-rs/clap".to_string())
+        );
+        assert_eq!(
+            parse("git@github.com:clap-rs/clap.git"),
+            RepositorySource::Url("git@github.com:clap-rs/clap.git".to_string())
+        );
+        assert_eq!(
+            parse("../clap"),
+            RepositorySource::Path(PathBuf::from("../clap"))
+        );
+    }
+
+    #[test]
+    fn clone_dirs_are_named_after_the_url() {
+        for url in [
+            "https://github.com/clap-rs/clap",
+            "git+https://github.com/clap-rs/clap.git",
+            "https://github.com/clap-rs/clap/",
+        ] {
+            assert_eq!(
+                clone_dir(url),
+                crate::paths::ResearchPaths::from_env()
+                    .repositories_dir()
+                    .join("github-com-clap-rs-clap"),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn prompt_lists_entry_points_first() {
+        let prompt = context().to_prompt();
+        assert!(prompt.starts_with("## Source Repository"));
+        assert!(prompt.contains("### Public API (2 exported symbols)"));
+        assert!(prompt.contains("  - function `parse(args: I) -> Self`\n"));
+        assert!(prompt.contains("  - type `Command` — A CLI command.\n"));
+        assert!(prompt.find("src/lib.rs").unwrap() < prompt.find("src/builder").unwrap());
+        assert!(prompt.contains("### Examples\n\n- `examples/git.rs`"));
+    }
+
+    #[test]
+    fn only_grounded_tasks_get_context() {
+        let ground = |task| ground_prompt("Prompt\n".to_string(), task, Some("## Source"));
+        assert_eq!(ground("overview"), "Prompt\n\n## Source");
+        assert_eq!(ground("question_2"), "Prompt\n\n## Source");
+        assert_eq!(ground("similar_libraries"), "Prompt\n");
+        assert_eq!(ground("changelog"), "Prompt\n");
+        assert_eq!(
+            ground_prompt("Prompt".to_string(), "overview", None),
+            "Prompt"
+        );
+    }
+
+    #[test]
+    fn analyze_lists_exported_symbols_and_examples() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        std::fs::create_dir(root.join(".git"))?;
+        std::fs::create_dir_all(root.join("src"))?;
+        std::fs::create_dir_all(root.join("examples"))?;
+        std::fs::create_dir_all(root.join("tests"))?;
+        std::fs::write(root.join("Cargo.toml"), "[package]\nname = \"demo\"\n")?;
+        std::fs::write(
+            root.join("src/lib.rs"),
+            "/// Says hello.\npub fn greet(name: &str) -> String {\n    format!(\"hi {name}\")\n}\n\nfn private() {}\n",
+        )?;
+        std::fs::write(root.join("examples/hello.rs"), "fn main() {}\n")?;
+        std::fs::write(root.join("tests/greet.rs"), "pub fn helper() {}\n")?;
+
+        let context = RepositoryContext::analyze(root)?;
+        assert_eq!(context.language, ProgrammingLanguage::Rust);
+        let names: Vec<_> = context
+            .public_api
+            .values()
+            .flatten()
+            .map(|item| item.name.as_str())
+            .collect();
+        assert_eq!(names, ["greet"]);
+        assert_eq!(context.examples, [PathBuf::from("examples/hello.rs")]);
+        Ok(())
+    }
+}
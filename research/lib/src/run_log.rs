@@ -0,0 +1,373 @@
+//! Machine-readable log of a research run.
+//!
+//! Every research run that runs tasks, including cancelled and resumed ones,
+//! writes a [`RunLog`] to `run_log.json` in its output directory: the run's
+//! [`ResearchResult`] plus, for each task that finished, its status,
+//! duration, token usage, model and error. Tools that ingest research runs
+//! can read it instead of parsing console output. The log covers the latest
+//! run only; tasks kept from an earlier run (an interrupted run's completed
+//! tasks, or an incremental run's existing documents) aren't listed. A run
+//! that fails with a [`ResearchError`](crate::ResearchError) leaves the
+//! previous log in place.
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "started_at": "2026-03-01T09:30:00Z",
+//!   "finished_at": "2026-03-01T09:32:10Z",
+//!   "result": { "topic": "clap", "succeeded": 7, "failed": 1, ... },
+//!   "tasks": [
+//!     {
+//!       "task": "overview",
+//!       "status": "succeeded",
+//!       "finished_at": "2026-03-01T09:31:02Z",
+//!       "duration_secs": 61.8,
+//!       "model": { "provider": "gemini", "model": "gemini-3-flash-preview" },
+//!       "metrics": { "input_tokens": 812, "output_tokens": 2406, "total_tokens": 3218, "elapsed_secs": 61.8 }
+//!     },
+//!     { "task": "changelog", "status": "failed", "finished_at": "...", "duration_secs": 12.1, "error": "rate limited" }
+//!   ]
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{ModelConfig, ModelProvider, ModelSpec, ResearchTask};
+use crate::observer::{PhaseSummary, ResearchObserver, TaskOutcome, TaskReport, TaskStage};
+use crate::question::Question;
+use crate::{PromptMetrics, ResearchResult};
+
+/// Name of the run log in a topic's output directory.
+pub const RUN_LOG_FILE: &str = "run_log.json";
+
+/// Version of the run log format, bumped on incompatible changes.
+pub const RUN_LOG_VERSION: u32 = 1;
+
+/// How a logged task ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    /// The output file was written.
+    Succeeded,
+    /// The agent ran out of tool calls and its output was synthesized from
+    /// what it had gathered.
+    Recovered,
+    Failed,
+    Cancelled,
+}
+
+/// One finished task of a research run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskLog {
+    /// Task name (`overview`, `question_2`, ...)
+    pub task: String,
+    pub status: TaskStatus,
+    pub finished_at: DateTime<Utc>,
+    /// Seconds from the task's start to its completion, when its start was
+    /// reported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<f32>,
+    /// The model the task ran on, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<ModelSpec>,
+    /// Token usage, when the task reports it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<PromptMetrics>,
+    /// Why the task failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Whether a cancelled task's partial output was left on disk
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub partial_output_kept: bool,
+}
+
+/// The log of a research run written to [`RUN_LOG_FILE`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunLog {
+    /// [`RUN_LOG_VERSION`] of the writer
+    pub version: u32,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub result: ResearchResult,
+    /// Finished tasks, in the order they finished
+    pub tasks: Vec<TaskLog>,
+}
+
+impl RunLog {
+    /// Returns the run log's path in `output_dir`.
+    pub fn path(output_dir: &Path) -> PathBuf {
+        output_dir.join(RUN_LOG_FILE)
+    }
+
+    /// Loads the run log in `output_dir`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the file can't be read or isn't a run log.
+    pub fn load(output_dir: &Path) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(Self::path(output_dir))?;
+        serde_json::from_str(&content)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Writes the run log to `output_dir`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, output_dir: &Path) -> std::io::Result<()> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(Self::path(output_dir), content)
+    }
+}
+
+impl TaskLog {
+    fn new(report: &TaskReport, duration_secs: Option<f32>, model: Option<ModelSpec>) -> Self {
+        let mut log = Self {
+            task: report.task.clone(),
+            status: TaskStatus::Succeeded,
+            finished_at: Utc::now(),
+            duration_secs,
+            model,
+            metrics: None,
+            error: None,
+            partial_output_kept: false,
+        };
+        match &report.outcome {
+            TaskOutcome::Succeeded { metrics } => log.metrics = metrics.clone(),
+            TaskOutcome::Recovered => log.status = TaskStatus::Recovered,
+            TaskOutcome::Failed { error } => {
+                log.status = TaskStatus::Failed;
+                log.error = Some(error.clone());
+            }
+            TaskOutcome::Cancelled {
+                partial_output_kept,
+            } => {
+                log.status = TaskStatus::Cancelled;
+                log.partial_output_kept = *partial_output_kept;
+            }
+        }
+        log
+    }
+}
+
+/// Forwards events to another observer, recording finished tasks for the
+/// run log.
+pub(crate) struct RunLogRecorder {
+    inner: Arc<dyn ResearchObserver>,
+    started_at: DateTime<Utc>,
+    models: HashMap<ResearchTask, ModelSpec>,
+    question_models: HashMap<String, ModelSpec>,
+    started: Mutex<HashMap<String, Instant>>,
+    tasks: Mutex<Vec<TaskLog>>,
+}
+
+impl RunLogRecorder {
+    /// Starts recording a run whose tasks use `models`, attributing each
+    /// task to the first model in its chain whose provider has an API key.
+    pub(crate) fn new(inner: Arc<dyn ResearchObserver>, models: &ModelConfig) -> Self {
+        Self {
+            inner,
+            started_at: Utc::now(),
+            models: ResearchTask::ALL
+                .into_iter()
+                .map(|task| {
+                    (
+                        task,
+                        models.resolve(task, ModelProvider::is_configured).clone(),
+                    )
+                })
+                .collect(),
+            question_models: HashMap::new(),
+            started: Mutex::new(HashMap::new()),
+            tasks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Attributes `question_N` tasks to the models of the questions numbered
+    /// `N`, for those that choose their own.
+    pub(crate) fn with_questions<'a>(
+        mut self,
+        questions: impl IntoIterator<Item = (usize, &'a Question)>,
+    ) -> Self {
+        self.question_models = questions
+            .into_iter()
+            .filter_map(|(num, question)| {
+                let spec = question.model.as_ref()?;
+                spec.provider
+                    .is_configured()
+                    .then(|| (format!("question_{}", num), spec.clone()))
+            })
+            .collect();
+        self
+    }
+
+    /// Writes the log of the finished run to the result's output directory.
+    pub(crate) fn finish(&self, result: &ResearchResult) {
+        let log = RunLog {
+            version: RUN_LOG_VERSION,
+            started_at: self.started_at,
+            finished_at: Utc::now(),
+            result: result.clone(),
+            tasks: self.tasks.lock().expect("run log lock poisoned").clone(),
+        };
+        if let Err(e) = log.save(&result.output_dir) {
+            self.inner
+                .on_warning(&format!("Failed to write {}: {}", RUN_LOG_FILE, e));
+        }
+    }
+
+    /// The model `task` runs on, if known.
+    fn model(&self, task: &str) -> Option<&ModelSpec> {
+        self.question_models
+            .get(task)
+            .or_else(|| ResearchTask::from_name(task).and_then(|task| self.models.get(&task)))
+    }
+}
+
+impl ResearchObserver for RunLogRecorder {
+    fn on_task_start(&self, task: &str, stage: &TaskStage) {
+        self.started
+            .lock()
+            .expect("run log lock poisoned")
+            .entry(task.to_string())
+            .or_insert_with(Instant::now);
+        self.inner.on_task_start(task, stage);
+    }
+
+    fn on_task_complete(&self, report: &TaskReport) {
+        let duration_secs = self
+            .started
+            .lock()
+            .expect("run log lock poisoned")
+            .remove(&report.task)
+            .map(|started| started.elapsed().as_secs_f32());
+        let log = TaskLog::new(report, duration_secs, self.model(&report.task).cloned());
+        self.tasks.lock().expect("run log lock poisoned").push(log);
+        self.inner.on_task_complete(report);
+    }
+
+    fn on_tool_call(&self, task: &str, tool: &str) {
+        self.inner.on_tool_call(task, tool);
+    }
+
+    fn on_token_usage(&self, task: &str, usage: &PromptMetrics) {
+        self.inner.on_token_usage(task, usage);
+    }
+
+    fn on_phase_complete(&self, summary: &PhaseSummary) {
+        self.inner.on_phase_complete(summary);
+    }
+
+    fn on_warning(&self, message: &str) {
+        self.inner.on_warning(message);
+    }
+
+    fn on_info(&self, message: &str) {
+        self.inner.on_info(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    struct Silent;
+
+    impl ResearchObserver for Silent {}
+
+    fn report(task: &str, outcome: TaskOutcome) -> TaskReport {
+        TaskReport {
+            task: task.to_string(),
+            completed: 1,
+            total: 3,
+            elapsed_secs: 1.0,
+            outcome,
+        }
+    }
+
+    fn result(output_dir: &Path) -> ResearchResult {
+        ResearchResult {
+            topic: "clap".to_string(),
+            output_dir: output_dir.to_path_buf(),
+            succeeded: 1,
+            failed: 1,
+            cancelled: false,
+            total_time_secs: 2.0,
+            total_input_tokens: 10,
+            total_output_tokens: 20,
+            total_tokens: 30,
+        }
+    }
+
+    #[test]
+    fn records_tasks_and_writes_the_log() {
+        let dir = tempdir().unwrap();
+        let models = ModelConfig::default();
+        let recorder = RunLogRecorder::new(Arc::new(Silent), &models);
+
+        recorder.on_task_start("overview", &TaskStage::Prompt);
+        recorder.on_task_complete(&report(
+            "overview",
+            TaskOutcome::Succeeded {
+                metrics: Some(PromptMetrics {
+                    input_tokens: 10,
+                    output_tokens: 20,
+                    total_tokens: 30,
+                    elapsed_secs: 1.0,
+                }),
+            },
+        ));
+        recorder.on_task_complete(&report(
+            "changelog",
+            TaskOutcome::Failed {
+                error: "rate limited".to_string(),
+            },
+        ));
+        recorder.finish(&result(dir.path()));
+
+        let log = RunLog::load(dir.path()).unwrap();
+        assert_eq!(log.version, RUN_LOG_VERSION);
+        assert_eq!(log.result.total_tokens, 30);
+        assert_eq!(log.tasks.len(), 2);
+
+        let overview = &log.tasks[0];
+        assert_eq!(overview.status, TaskStatus::Succeeded);
+        assert!(overview.duration_secs.is_some());
+        assert_eq!(overview.metrics.as_ref().unwrap().total_tokens, 30);
+
+        let changelog = &log.tasks[1];
+        assert_eq!(changelog.status, TaskStatus::Failed);
+        assert_eq!(changelog.error.as_deref(), Some("rate limited"));
+        assert!(changelog.duration_secs.is_none());
+        assert!(changelog.metrics.is_none());
+    }
+
+    #[test]
+    fn cancelled_tasks_serialize_compactly() {
+        let log = TaskLog::new(
+            &report(
+                "use_cases",
+                TaskOutcome::Cancelled {
+                    partial_output_kept: false,
+                },
+            ),
+            None,
+            None,
+        );
+        let json = serde_json::to_value(&log).unwrap();
+
+        assert_eq!(json["status"], "cancelled");
+        assert!(json.get("partial_output_kept").is_none());
+        assert!(json.get("error").is_none());
+        assert!(json.get("model").is_none());
+    }
+}
@@ -0,0 +1,293 @@
+//! Shared request scheduling for researching several topics at once.
+//!
+//! A research run fires a burst of model requests per phase. When many
+//! topics run together (see [`research_many`](crate::research_many)) those
+//! bursts add up, so every run sharing a [`ResearchScheduler`] draws from the
+//! same limits:
+//!
+//! - a global cap on model requests in flight, across all topics and providers
+//! - a per-provider cap, plus an optional minimum spacing between request
+//!   starts, so one provider's rate limit isn't exceeded while the others
+//!   still make progress
+//! - one Brave Search rate limiter, so the web searches of every topic stay
+//!   within the `BRAVE_PLAN` budget together
+//!
+//! ```
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! use research_lib::ResearchOptions;
+//! use research_lib::models::ModelProvider;
+//! use research_lib::scheduler::{ProviderLimit, ResearchScheduler};
+//!
+//! let scheduler = ResearchScheduler::new(6).with_provider_limit(
+//!     ModelProvider::Zai,
+//!     ProviderLimit::new(2).with_min_interval(Duration::from_millis(500)),
+//! );
+//! let options = ResearchOptions::default().with_scheduler(Arc::new(scheduler));
+//! assert!(options.scheduler.is_some());
+//! ```
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::sync::{Mutex, Semaphore};
+use tokio::time::Instant;
+use unchained_ai::rigging::tools::{BravePlan, BraveRateLimiter};
+
+use crate::models::ModelProvider;
+
+/// Model requests allowed in flight at once by [`ResearchScheduler::default`].
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 8;
+
+/// Requests allowed in flight per provider by [`ProviderLimit::default`].
+pub const DEFAULT_MAX_CONCURRENT_PER_PROVIDER: usize = 4;
+
+/// How hard a single provider may be driven.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderLimit {
+    /// Requests to the provider allowed in flight at once.
+    pub max_concurrent: usize,
+    /// Minimum time between the starts of two requests to the provider.
+    pub min_interval: Duration,
+}
+
+impl ProviderLimit {
+    /// Allows `max_concurrent` requests in flight (at least one), with no spacing.
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            max_concurrent: max_concurrent.max(1),
+            min_interval: Duration::ZERO,
+        }
+    }
+
+    /// Spaces request starts at least `interval` apart.
+    pub fn with_min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self
+    }
+}
+
+impl Default for ProviderLimit {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CONCURRENT_PER_PROVIDER)
+    }
+}
+
+/// Limits on model requests, shared by every research run it is given to.
+///
+/// Pass it to runs with
+/// [`ResearchOptions::with_scheduler`](crate::ResearchOptions::with_scheduler).
+/// Every provider starts with [`ProviderLimit::default`].
+#[derive(Debug)]
+pub struct ResearchScheduler {
+    requests: Semaphore,
+    max_concurrent_requests: usize,
+    providers: HashMap<ModelProvider, ProviderGate>,
+    search: BraveRateLimiter,
+}
+
+/// The permits and start spacing of one provider.
+#[derive(Debug)]
+struct ProviderGate {
+    limit: ProviderLimit,
+    permits: Semaphore,
+    next_start: Mutex<Instant>,
+}
+
+impl ProviderGate {
+    fn new(limit: ProviderLimit) -> Self {
+        Self {
+            limit,
+            permits: Semaphore::new(limit.max_concurrent),
+            next_start: Mutex::new(Instant::now()),
+        }
+    }
+}
+
+impl ResearchScheduler {
+    /// Allows `max_concurrent_requests` model requests in flight (at least one).
+    pub fn new(max_concurrent_requests: usize) -> Self {
+        let max_concurrent_requests = max_concurrent_requests.max(1);
+        Self {
+            requests: Semaphore::new(max_concurrent_requests),
+            max_concurrent_requests,
+            providers: ModelProvider::ALL
+                .into_iter()
+                .map(|provider| (provider, ProviderGate::new(ProviderLimit::default())))
+                .collect(),
+            search: BraveRateLimiter::new(
+                std::env::var("BRAVE_PLAN")
+                    .map(|plan| BravePlan::from_string(&plan))
+                    .unwrap_or_default(),
+            ),
+        }
+    }
+
+    /// Replaces the limit for `provider`.
+    pub fn with_provider_limit(mut self, provider: ModelProvider, limit: ProviderLimit) -> Self {
+        self.providers.insert(provider, ProviderGate::new(limit));
+        self
+    }
+
+    /// Replaces the Brave Search limiter, e.g. to size it for another plan
+    /// than `BRAVE_PLAN`.
+    pub fn with_search_limiter(mut self, limiter: BraveRateLimiter) -> Self {
+        self.search = limiter;
+        self
+    }
+
+    /// Returns the Brave Search limiter every run's search tool draws from.
+    pub fn search_limiter(&self) -> &BraveRateLimiter {
+        &self.search
+    }
+
+    /// Returns the global cap on model requests in flight.
+    pub fn max_concurrent_requests(&self) -> usize {
+        self.max_concurrent_requests
+    }
+
+    /// Returns the limit applied to `provider`.
+    pub fn provider_limit(&self, provider: ModelProvider) -> ProviderLimit {
+        self.providers[&provider].limit
+    }
+
+    /// Runs `request` once both `provider` and the global cap have room.
+    ///
+    /// The provider permit is taken first, so requests queued behind a busy
+    /// provider don't hold global permits other providers could use.
+    pub async fn run<F: Future>(&self, provider: ModelProvider, request: F) -> F::Output {
+        let gate = &self.providers[&provider];
+        let _provider_permit = gate
+            .permits
+            .acquire()
+            .await
+            .expect("scheduler semaphores are never closed");
+
+        if !gate.limit.min_interval.is_zero() {
+            let mut next_start = gate.next_start.lock().await;
+            tokio::time::sleep_until(*next_start).await;
+            *next_start = Instant::now() + gate.limit.min_interval;
+        }
+
+        let _request_permit = self
+            .requests
+            .acquire()
+            .await
+            .expect("scheduler semaphores are never closed");
+        request.await
+    }
+}
+
+impl Default for ResearchScheduler {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CONCURRENT_REQUESTS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future::join_all;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Runs `count` requests to each provider, returning the most seen in flight.
+    async fn peak_in_flight(
+        scheduler: &ResearchScheduler,
+        providers: &[ModelProvider],
+        count: usize,
+    ) -> usize {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let requests = providers.iter().flat_map(|&provider| {
+            (0..count).map({
+                let in_flight = in_flight.clone();
+                let peak = peak.clone();
+                move |_| {
+                    let in_flight = in_flight.clone();
+                    let peak = peak.clone();
+                    scheduler.run(provider, async move {
+                        let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak.fetch_max(now, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                    })
+                }
+            })
+        });
+        join_all(requests).await;
+        peak.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn limits_are_at_least_one() {
+        let scheduler = ResearchScheduler::new(0);
+        assert_eq!(scheduler.max_concurrent_requests(), 1);
+        assert_eq!(ProviderLimit::new(0).max_concurrent, 1);
+        assert_eq!(
+            scheduler.provider_limit(ModelProvider::Gemini),
+            ProviderLimit::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn global_limit_caps_requests_across_providers() {
+        let scheduler = ResearchScheduler::new(3);
+        let peak = peak_in_flight(
+            &scheduler,
+            &[ModelProvider::Gemini, ModelProvider::OpenAi],
+            4,
+        )
+        .await;
+        assert_eq!(peak, 3);
+    }
+
+    #[tokio::test]
+    async fn provider_limit_caps_only_that_provider() {
+        let scheduler = ResearchScheduler::new(10)
+            .with_provider_limit(ModelProvider::Zai, ProviderLimit::new(1));
+        assert_eq!(
+            peak_in_flight(&scheduler, &[ModelProvider::Zai], 4).await,
+            1
+        );
+        assert_eq!(
+            peak_in_flight(&scheduler, &[ModelProvider::OpenAi], 6).await,
+            DEFAULT_MAX_CONCURRENT_PER_PROVIDER
+        );
+    }
+
+    #[tokio::test]
+    async fn min_interval_spaces_request_starts() {
+        let interval = Duration::from_millis(20);
+        let scheduler = ResearchScheduler::new(10).with_provider_limit(
+            ModelProvider::Gemini,
+            ProviderLimit::new(10).with_min_interval(interval),
+        );
+
+        let start = Instant::now();
+        join_all((0..3).map(|_| scheduler.run(ModelProvider::Gemini, async {}))).await;
+        assert!(start.elapsed() >= interval * 2);
+    }
+
+    #[tokio::test]
+    async fn search_limiter_is_shared_by_clones() {
+        let scheduler =
+            ResearchScheduler::new(1).with_search_limiter(BraveRateLimiter::new(BravePlan::Base));
+        let (first, second) = (
+            scheduler.search_limiter().clone(),
+            scheduler.search_limiter().clone(),
+        );
+
+        // Base allows a burst of 20, so the 21st search waits for a refill
+        let start = Instant::now();
+        for _ in 0..10 {
+            first.acquire().await;
+            second.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(25));
+        first.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(25));
+    }
+}
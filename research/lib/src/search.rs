@@ -0,0 +1,498 @@
+//! Full-text search across the research library.
+//!
+//! [`search()`] finds the documents that cover a concept across every topic
+//! in the research library's `library` directory, ranked with BM25, with a snippet of
+//! the best-matching line of each document.
+//!
+//! Term counts for every markdown file inside a topic directory are kept in
+//! `.search-index.json` at the library root. Each search re-indexes only the
+//! files whose size or modification time changed since the last one and drops
+//! files that no longer exist, so searching stays cheap as the library grows.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use research_lib::search::search;
+//!
+//! for hit in search("connection pooling")? {
+//!     println!("{}/{} ({:.2})", hit.topic, hit.file.display(), hit.score);
+//!     println!("    {}", hit.snippet);
+//! }
+//! # Ok::<(), research_lib::search::SearchError>(())
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{debug, instrument, warn};
+use walkdir::WalkDir;
+
+/// Filename of the search index within the library directory.
+pub const INDEX_FILENAME: &str = ".search-index.json";
+
+/// Version of the index format; indexes written by other versions are rebuilt.
+const INDEX_VERSION: u32 = 1;
+
+/// Maximum length of a snippet, in characters.
+const SNIPPET_CHARS: usize = 160;
+
+/// BM25 term frequency saturation.
+const K1: f32 = 1.2;
+
+/// BM25 document length normalization.
+const B: f32 = 0.75;
+
+/// Words too common to be worth indexing.
+const STOPWORDS: &[&str] = &[
+    "an", "and", "are", "as", "at", "be", "by", "for", "from", "if", "in", "is", "it", "of", "on",
+    "or", "that", "the", "this", "to", "with",
+];
+
+/// Errors that can occur while searching the research library.
+#[derive(Debug, Error)]
+pub enum SearchError {
+    /// The research library directory was not found
+    #[error("Research library directory not found: {0}")]
+    LibraryNotFound(PathBuf),
+
+    /// Failed to walk the library
+    #[error("Search I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Options for [`search_with()`].
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    /// The research library directory (see [`ResearchPaths::library_dir`](crate::paths::ResearchPaths::library_dir))
+    pub library_dir: PathBuf,
+    /// Maximum number of hits returned
+    pub limit: usize,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            library_dir: crate::tags::default_research_root().join("library"),
+            limit: 10,
+        }
+    }
+}
+
+impl SearchOptions {
+    /// Search a different research library directory.
+    pub fn library_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.library_dir = dir.into();
+        self
+    }
+
+    /// Return at most `limit` hits.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+/// A document matching a search.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchHit {
+    /// The topic (directory name under `library/`)
+    pub topic: String,
+    /// The matching file, relative to the topic directory (e.g. `deep_dive.md`)
+    pub file: PathBuf,
+    /// BM25 relevance; higher is better
+    pub score: f32,
+    /// The line of the file matching the most query terms, shortened
+    pub snippet: String,
+}
+
+/// Term counts of one indexed file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexedFile {
+    topic: String,
+    modified: DateTime<Utc>,
+    size: u64,
+    /// Number of indexed terms in the file
+    length: u32,
+    terms: BTreeMap<String, u32>,
+}
+
+/// Term counts of every markdown file in the library, keyed by path
+/// relative to the library directory.
+#[derive(Debug, Serialize, Deserialize)]
+struct SearchIndex {
+    version: u32,
+    files: BTreeMap<PathBuf, IndexedFile>,
+}
+
+impl SearchIndex {
+    fn new() -> Self {
+        Self {
+            version: INDEX_VERSION,
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Loads the index, starting over when it's missing, unreadable or from
+    /// another index version.
+    fn load(library_dir: &Path) -> Self {
+        std::fs::read_to_string(library_dir.join(INDEX_FILENAME))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .filter(|index| index.version == INDEX_VERSION)
+            .unwrap_or_else(Self::new)
+    }
+
+    fn save(&self, library_dir: &Path) -> std::io::Result<()> {
+        let content = serde_json::to_string(self).map_err(std::io::Error::other)?;
+        std::fs::write(library_dir.join(INDEX_FILENAME), content)
+    }
+
+    /// Re-indexes new and changed files and drops deleted ones, returning
+    /// whether the index changed.
+    ///
+    /// Only markdown files inside topic directories are indexed; hidden files
+    /// and directories are skipped, as are files that aren't valid UTF-8.
+    fn refresh(&mut self, library_dir: &Path) -> Result<bool, SearchError> {
+        let mut seen = HashSet::new();
+        let mut changed = false;
+
+        let entries = WalkDir::new(library_dir)
+            .min_depth(2)
+            .into_iter()
+            .filter_entry(|entry| entry.depth() == 0 || !is_hidden(entry.path()));
+        for entry in entries {
+            let entry = entry.map_err(std::io::Error::from)?;
+            let path = entry.path();
+            if !entry.file_type().is_file() || path.extension().is_none_or(|ext| ext != "md") {
+                continue;
+            }
+            let Ok(relative) = path.strip_prefix(library_dir) else {
+                continue;
+            };
+            let Some(topic) = relative.iter().next() else {
+                continue;
+            };
+
+            let metadata = entry.metadata().map_err(std::io::Error::from)?;
+            let modified: DateTime<Utc> = metadata.modified()?.into();
+            let size = metadata.len();
+            seen.insert(relative.to_path_buf());
+
+            if self
+                .files
+                .get(relative)
+                .is_some_and(|file| file.modified == modified && file.size == size)
+            {
+                continue;
+            }
+
+            let Ok(content) = std::fs::read_to_string(path) else {
+                debug!("Skipping unreadable file {:?}", path);
+                continue;
+            };
+            let mut terms: BTreeMap<String, u32> = BTreeMap::new();
+            let mut length = 0;
+            for term in tokenize(&content) {
+                *terms.entry(term).or_default() += 1;
+                length += 1;
+            }
+
+            debug!("Indexed {:?} ({} terms)", relative, length);
+            self.files.insert(
+                relative.to_path_buf(),
+                IndexedFile {
+                    topic: topic.to_string_lossy().into_owned(),
+                    modified,
+                    size,
+                    length,
+                    terms,
+                },
+            );
+            changed = true;
+        }
+
+        let before = self.files.len();
+        self.files.retain(|path, _| seen.contains(path));
+        Ok(changed || self.files.len() != before)
+    }
+
+    /// Scores every file containing any of `terms` with BM25, best first.
+    fn rank(&self, terms: &BTreeSet<String>) -> Vec<(&Path, &IndexedFile, f32)> {
+        if self.files.is_empty() {
+            return Vec::new();
+        }
+
+        let count = self.files.len() as f32;
+        let total_length: u64 = self.files.values().map(|file| u64::from(file.length)).sum();
+        let average_length = (total_length as f32 / count).max(1.0);
+
+        let mut scores: BTreeMap<&Path, f32> = BTreeMap::new();
+        for term in terms {
+            let matches: Vec<(&PathBuf, &IndexedFile, u32)> = self
+                .files
+                .iter()
+                .filter_map(|(path, file)| file.terms.get(term).map(|&tf| (path, file, tf)))
+                .collect();
+            if matches.is_empty() {
+                continue;
+            }
+
+            let df = matches.len() as f32;
+            let idf = ((count - df + 0.5) / (df + 0.5) + 1.0).ln();
+            for (path, file, tf) in matches {
+                let tf = tf as f32;
+                let norm = 1.0 - B + B * file.length as f32 / average_length;
+                *scores.entry(path.as_path()).or_default() +=
+                    idf * tf * (K1 + 1.0) / (tf + K1 * norm);
+            }
+        }
+
+        let mut ranked: Vec<(&Path, &IndexedFile, f32)> = scores
+            .into_iter()
+            .map(|(path, score)| (path, &self.files[path], score))
+            .collect();
+        ranked.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| a.0.cmp(b.0)));
+        ranked
+    }
+}
+
+/// Searches the default research library for `query`.
+///
+/// See [`search_with()`].
+///
+/// ## Errors
+///
+/// Returns an error if the library directory does not exist or cannot be
+/// walked.
+pub fn search(query: &str) -> Result<Vec<SearchHit>, SearchError> {
+    search_with(&SearchOptions::default(), query)
+}
+
+/// Searches the research library for documents containing any word of
+/// `query`, best matches first.
+///
+/// Words are matched case-insensitively and in full (`pool` doesn't match
+/// `pooling`); documents containing more of the words, or containing them
+/// more often relative to their length, rank higher. The index is brought up
+/// to date first. A query without searchable words returns no hits.
+///
+/// ## Errors
+///
+/// Returns an error if the library directory does not exist or cannot be
+/// walked. Failing to write the index only logs a warning.
+#[instrument(
+    name = "search",
+    skip(options),
+    fields(library_dir = ?options.library_dir, limit = options.limit)
+)]
+pub fn search_with(options: &SearchOptions, query: &str) -> Result<Vec<SearchHit>, SearchError> {
+    let library_dir = &options.library_dir;
+    if !library_dir.is_dir() {
+        return Err(SearchError::LibraryNotFound(library_dir.clone()));
+    }
+
+    let mut index = SearchIndex::load(library_dir);
+    if index.refresh(library_dir)?
+        && let Err(e) = index.save(library_dir)
+    {
+        warn!("Failed to write search index: {}", e);
+    }
+
+    let terms: BTreeSet<String> = tokenize(query).collect();
+    if terms.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let hits = index
+        .rank(&terms)
+        .into_iter()
+        .take(options.limit)
+        .map(|(path, file, score)| {
+            let snippet = std::fs::read_to_string(library_dir.join(path))
+                .map(|content| snippet(&content, &terms))
+                .unwrap_or_default();
+            SearchHit {
+                topic: file.topic.clone(),
+                file: path.strip_prefix(&file.topic).unwrap_or(path).to_path_buf(),
+                score,
+                snippet,
+            }
+        })
+        .collect();
+    Ok(hits)
+}
+
+/// Splits text into lowercase words, dropping single characters and stopwords.
+///
+/// Underscores are kept so identifiers like `spawn_blocking` stay whole.
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|word| word.chars().nth(1).is_some())
+        .map(str::to_lowercase)
+        .filter(|word| !STOPWORDS.contains(&word.as_str()))
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+}
+
+/// Picks the line matching the most distinct query terms (the first, on
+/// ties), without its markdown markers, shortened around its first match.
+fn snippet(content: &str, terms: &BTreeSet<String>) -> String {
+    let mut best: Option<(&str, usize)> = None;
+    for line in content.lines() {
+        let line = line.trim().trim_start_matches(['#', '-', '*', '>', ' ']);
+        let matched: HashSet<String> = tokenize(line).filter(|w| terms.contains(w)).collect();
+        if !matched.is_empty() && best.is_none_or(|(_, count)| matched.len() > count) {
+            best = Some((line, matched.len()));
+        }
+    }
+    let Some((line, _)) = best else {
+        return String::new();
+    };
+
+    let chars: Vec<char> = line.chars().collect();
+    if chars.len() <= SNIPPET_CHARS {
+        return line.to_string();
+    }
+
+    // Start a third of the snippet before the first match when the lowercase
+    // line lines up with the original
+    let lower = line.to_lowercase();
+    let first_match = terms
+        .iter()
+        .filter_map(|term| lower.find(term.as_str()))
+        .min()
+        .filter(|_| lower.len() == line.len())
+        .map(|offset| line[..offset].chars().count())
+        .unwrap_or(0);
+    let start = first_match
+        .saturating_sub(SNIPPET_CHARS / 3)
+        .min(chars.len() - SNIPPET_CHARS);
+    let end = start + SNIPPET_CHARS;
+
+    let mut snippet: String = chars[start..end].iter().collect();
+    if start > 0 {
+        snippet.insert(0, '…');
+    }
+    if end < chars.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(library: &Path, file: &str, content: &str) {
+        let path = library.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn options(library: &Path) -> SearchOptions {
+        SearchOptions::default().library_dir(library)
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let words: Vec<String> = tokenize("The Tokio runtime: spawn_blocking & a C API!").collect();
+        assert_eq!(words, ["tokio", "runtime", "spawn_blocking", "api"]);
+    }
+
+    #[test]
+    fn test_search_ranks_documents_by_relevance() {
+        let temp = TempDir::new().unwrap();
+        let library = temp.path();
+        write(
+            library,
+            "deadpool/overview.md",
+            "# Deadpool\n\nDeadpool is a connection pool.\n\nConnection pooling for async connection managers.\n",
+        );
+        write(
+            library,
+            "tokio/deep_dive.md",
+            "# Tokio\n\nTokio is an async runtime with a connection example.\n",
+        );
+        write(
+            library,
+            "serde/overview.md",
+            "# Serde\n\nSerialization framework.\n",
+        );
+        write(library, "glossary.md", "connection connection connection\n");
+
+        let hits = search_with(&options(library), "Connection pool").unwrap();
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].topic, "deadpool");
+        assert_eq!(hits[0].file, PathBuf::from("overview.md"));
+        assert_eq!(hits[0].snippet, "Deadpool is a connection pool.");
+        assert_eq!(hits[1].topic, "tokio");
+        assert!(hits[0].score > hits[1].score);
+        assert!(library.join(INDEX_FILENAME).exists());
+
+        let limited = search_with(&options(library).limit(1), "connection").unwrap();
+        assert_eq!(limited.len(), 1);
+        assert!(search_with(&options(library), "the of").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_refreshes_changed_and_deleted_files() {
+        let temp = TempDir::new().unwrap();
+        let library = temp.path();
+        write(library, "tokio/overview.md", "Tokio is an async runtime.\n");
+        write(library, "tokio/question_1.md", "Retries with backoff.\n");
+        assert_eq!(search_with(&options(library), "backoff").unwrap().len(), 1);
+
+        write(
+            library,
+            "tokio/overview.md",
+            "Tokio is an async runtime with a work-stealing scheduler.\n",
+        );
+        std::fs::remove_file(library.join("tokio/question_1.md")).unwrap();
+
+        let hits = search_with(&options(library), "scheduler").unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].file, PathBuf::from("overview.md"));
+        assert!(
+            search_with(&options(library), "backoff")
+                .unwrap()
+                .is_empty()
+        );
+
+        let index = SearchIndex::load(library);
+        assert_eq!(
+            index.files.keys().collect::<Vec<_>>(),
+            [&PathBuf::from("tokio/overview.md")]
+        );
+    }
+
+    #[test]
+    fn test_search_requires_library() {
+        let temp = TempDir::new().unwrap();
+        let missing = temp.path().join("library");
+        assert!(matches!(
+            search_with(&options(&missing), "tokio"),
+            Err(SearchError::LibraryNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_snippet_shortens_long_lines_around_the_match() {
+        let terms: BTreeSet<String> = ["backpressure".to_string()].into();
+        let line = format!("{} backpressure {}", "lead ".repeat(60), "tail ".repeat(60));
+
+        let snippet = snippet(&line, &terms);
+
+        assert!(snippet.starts_with('…'));
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.contains("backpressure"));
+        assert_eq!(snippet.chars().count(), SNIPPET_CHARS + 2);
+    }
+}
@@ -0,0 +1,188 @@
+//! Streaming output for research prompt tasks.
+//!
+//! By default each prompt's response is written to its output file only once
+//! the model has finished, so a long prompt like `deep_dive` shows nothing for
+//! minutes and loses everything if the process dies. In streaming mode
+//! (see [`ResearchOptions::with_streaming`](crate::ResearchOptions::with_streaming))
+//! tokens are appended to the output `.md` file as they arrive and a
+//! [`PromptProgress`] event is emitted for each chunk.
+//!
+//! Streaming applies to plain completion prompts (Phase 1 prompts when web
+//! tools are unavailable, and the Phase 2 deep dive). Agent prompts that call
+//! tools still run to completion before writing.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tokio::fs::{self, File};
+use tokio::io::AsyncWriteExt;
+
+use crate::PromptMetrics;
+
+/// How many characters [`ProgressHandler::console`] waits between progress lines.
+const CONSOLE_PROGRESS_STEP: usize = 2_000;
+
+/// A progress event from a streaming prompt task.
+#[derive(Debug, Clone)]
+pub enum PromptProgress {
+    /// The task started streaming into `path`.
+    Started { task: String, path: PathBuf },
+
+    /// A chunk of `delta` characters was written; `chars` is the running total.
+    Chunk {
+        task: String,
+        delta: usize,
+        chars: usize,
+    },
+
+    /// The stream finished and the output file is complete.
+    Completed {
+        task: String,
+        chars: usize,
+        metrics: PromptMetrics,
+    },
+
+    /// The stream failed or was cancelled; the `chars` written so far are kept.
+    Failed {
+        task: String,
+        error: String,
+        chars: usize,
+    },
+}
+
+/// Receives [`PromptProgress`] events from streaming prompt tasks.
+///
+/// Handlers are called from the task's future, so they should return quickly.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::streaming::{ProgressHandler, PromptProgress};
+///
+/// let handler = ProgressHandler::new(|event: &PromptProgress| {
+///     if let PromptProgress::Chunk { task, chars, .. } = event {
+///         eprintln!("{task}: {chars} chars");
+///     }
+/// });
+/// # let _ = handler;
+/// ```
+#[derive(Clone)]
+pub struct ProgressHandler(Arc<dyn Fn(&PromptProgress) + Send + Sync>);
+
+impl ProgressHandler {
+    /// Wraps a callback.
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&PromptProgress) + Send + Sync + 'static,
+    {
+        Self(Arc::new(handler))
+    }
+
+    /// A handler that prints a line to stdout roughly every 2,000 characters.
+    ///
+    /// Start and finish lines are already printed by the prompt task itself.
+    pub fn console() -> Self {
+        Self::new(|event| {
+            if let PromptProgress::Chunk { task, delta, chars } = event
+                && (chars - delta) / CONSOLE_PROGRESS_STEP != chars / CONSOLE_PROGRESS_STEP
+            {
+                println!("  [{}] … {:.1}k chars", task, *chars as f32 / 1000.0);
+            }
+        })
+    }
+
+    /// Delivers `event` to the handler.
+    pub(crate) fn emit(&self, event: PromptProgress) {
+        (self.0)(&event);
+    }
+}
+
+impl fmt::Debug for ProgressHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressHandler(..)")
+    }
+}
+
+/// An output file that streamed text is appended to as it arrives.
+///
+/// Every chunk is flushed immediately so the file on disk always holds
+/// everything received so far.
+pub(crate) struct PartialOutput {
+    file: File,
+    chars: usize,
+}
+
+impl PartialOutput {
+    /// Creates (or truncates) the output file, creating parent directories as needed.
+    pub(crate) async fn create(path: &Path) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent()
+            && !parent.exists()
+        {
+            fs::create_dir_all(parent).await?;
+        }
+        Ok(Self {
+            file: File::create(path).await?,
+            chars: 0,
+        })
+    }
+
+    /// Appends `text` to the file and flushes it.
+    ///
+    /// ## Returns
+    ///
+    /// The number of characters written.
+    pub(crate) async fn push(&mut self, text: &str) -> std::io::Result<usize> {
+        self.file.write_all(text.as_bytes()).await?;
+        self.file.flush().await?;
+        let delta = text.chars().count();
+        self.chars += delta;
+        Ok(delta)
+    }
+
+    /// Returns the number of characters written so far.
+    pub(crate) fn chars(&self) -> usize {
+        self.chars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[tokio::test]
+    async fn partial_output_is_readable_after_each_chunk() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("deep-dive").join("clap.md");
+
+        let mut output = PartialOutput::create(&path).await.unwrap();
+        output.push("# Clap\n").await.unwrap();
+        assert_eq!(fs::read_to_string(&path).await.unwrap(), "# Clap\n");
+
+        assert_eq!(output.push("Ünïcode").await.unwrap(), 7);
+        assert_eq!(output.chars(), 14);
+        assert_eq!(fs::read_to_string(&path).await.unwrap(), "# Clap\nÜnïcode");
+    }
+
+    #[test]
+    fn handler_receives_emitted_events() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let handler = ProgressHandler::new(move |event: &PromptProgress| {
+            if let PromptProgress::Chunk { chars, .. } = event {
+                sink.lock().unwrap().push(*chars);
+            }
+        });
+
+        for chars in [10, 25] {
+            handler.emit(PromptProgress::Chunk {
+                task: "deep_dive".to_string(),
+                delta: 10,
+                chars,
+            });
+        }
+
+        assert_eq!(*seen.lock().unwrap(), vec![10, 25]);
+    }
+}
@@ -0,0 +1,447 @@
+//! Question tagging and cross-topic tag indexes.
+//!
+//! Additional questions can carry free-form tags (e.g. `reliability`). Tags are
+//! stored per question file in each topic's `metadata.json` and collected into
+//! `tags/<tag>.md` in the library root so that related answers
+//! across libraries can be found together.
+//!
+//! Topics themselves can also be tagged and filed under a category (see
+//! [`update_topic_tags`] and [`set_topic_category`]); `research list` filters
+//! on both kinds of tag.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use tracing::{debug, warn};
+
+use crate::{ResearchError, ResearchMetadata};
+
+/// Normalizes a user-supplied tag.
+///
+/// Tags are trimmed, lowercased, and have internal whitespace collapsed to `-`.
+/// Returns `None` for tags that are empty, start with `.`, or contain path
+/// separators, since every tag maps to a file under `tags/`.
+///
+/// ## Examples
+///
+/// ```
+/// use research_lib::tags::normalize_tag;
+///
+/// assert_eq!(normalize_tag(" Error Handling "), Some("error-handling".to_string()));
+/// assert_eq!(normalize_tag("../etc"), None);
+/// assert_eq!(normalize_tag("  "), None);
+/// ```
+pub fn normalize_tag(tag: &str) -> Option<String> {
+    let normalized = tag
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+
+    if normalized.is_empty() || normalized.starts_with('.') || normalized.contains(['/', '\\']) {
+        return None;
+    }
+
+    Some(normalized)
+}
+
+/// Returns the root of the research library.
+///
+/// See [`ResearchPaths::from_env`](crate::paths::ResearchPaths::from_env) for
+/// how the root is found.
+pub fn default_research_root() -> PathBuf {
+    crate::paths::ResearchPaths::from_env().root().to_path_buf()
+}
+
+/// A tagged question found in a topic's metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggedQuestion {
+    /// The topic (directory name under `library/`)
+    pub topic: String,
+    /// The question file within the topic (e.g. `question_1.md`)
+    pub filename: String,
+    /// The prompt that produced the question file
+    pub prompt: String,
+}
+
+/// The subset of `metadata.json` needed to build tag indexes.
+#[derive(Debug, Deserialize)]
+struct TaggedMetadata {
+    #[serde(default)]
+    additional_files: HashMap<String, String>,
+    #[serde(default)]
+    question_tags: HashMap<String, Vec<String>>,
+}
+
+/// Collects tagged questions from every topic under `library_dir`, grouped by tag.
+///
+/// Topics without a readable `metadata.json` are skipped. Questions within a
+/// tag are ordered by topic, then by filename.
+pub fn collect_tagged_questions(library_dir: &Path) -> BTreeMap<String, Vec<TaggedQuestion>> {
+    let mut by_tag: BTreeMap<String, Vec<TaggedQuestion>> = BTreeMap::new();
+
+    let Ok(entries) = std::fs::read_dir(library_dir) else {
+        debug!("No library directory at {:?}", library_dir);
+        return by_tag;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let topic = entry.file_name().to_string_lossy().to_string();
+        let metadata: TaggedMetadata = match std::fs::read_to_string(path.join("metadata.json"))
+            .ok()
+            .map(|content| serde_json::from_str(&content))
+        {
+            Some(Ok(metadata)) => metadata,
+            Some(Err(e)) => {
+                warn!(
+                    "Skipping tags for '{}': invalid metadata.json: {}",
+                    topic, e
+                );
+                continue;
+            }
+            None => continue,
+        };
+
+        for (filename, tags) in &metadata.question_tags {
+            let prompt = metadata
+                .additional_files
+                .get(filename)
+                .cloned()
+                .unwrap_or_default();
+            for tag in tags {
+                by_tag.entry(tag.clone()).or_default().push(TaggedQuestion {
+                    topic: topic.clone(),
+                    filename: filename.clone(),
+                    prompt: prompt.clone(),
+                });
+            }
+        }
+    }
+
+    for questions in by_tag.values_mut() {
+        questions.sort_by(|a, b| (&a.topic, &a.filename).cmp(&(&b.topic, &b.filename)));
+    }
+
+    by_tag
+}
+
+/// Renders the markdown index for a single tag.
+///
+/// Links are relative to the `tags/` directory and point into the sibling
+/// `library/` directory.
+pub fn render_tag_index(tag: &str, questions: &[TaggedQuestion]) -> String {
+    let mut out = format!("# {}\n\n", tag);
+    out.push_str(&format!(
+        "Questions tagged `{}` across the research library.\n",
+        tag
+    ));
+
+    let mut current_topic: Option<&str> = None;
+    for question in questions {
+        if current_topic != Some(question.topic.as_str()) {
+            out.push_str(&format!("\n## {}\n\n", question.topic));
+            current_topic = Some(question.topic.as_str());
+        }
+        let label = if question.prompt.is_empty() {
+            question.filename.as_str()
+        } else {
+            question.prompt.as_str()
+        };
+        out.push_str(&format!(
+            "- [{}](../library/{}/{})\n",
+            label, question.topic, question.filename
+        ));
+    }
+
+    out
+}
+
+/// Rewrites `tags/<tag>.md` for every tag used in the research library.
+///
+/// Reads topics from `<research_root>/library` and writes indexes to
+/// `<research_root>/tags`. Index files for tags no longer used by any
+/// question are removed. Returns the paths of the index files written.
+///
+/// ## Errors
+///
+/// Returns an error if the tags directory cannot be created or an index
+/// file cannot be written.
+pub fn write_tag_indexes(research_root: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let tags_dir = research_root.join("tags");
+    let by_tag = collect_tagged_questions(&research_root.join("library"));
+
+    if by_tag.is_empty() && !tags_dir.exists() {
+        return Ok(Vec::new());
+    }
+    std::fs::create_dir_all(&tags_dir)?;
+
+    // Remove stale indexes before writing the current set
+    let current: BTreeSet<String> = by_tag.keys().map(|tag| format!("{}.md", tag)).collect();
+    for entry in std::fs::read_dir(&tags_dir)?.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.ends_with(".md") && !current.contains(&name) {
+            debug!("Removing stale tag index: {}", name);
+            std::fs::remove_file(entry.path())?;
+        }
+    }
+
+    let mut written = Vec::with_capacity(by_tag.len());
+    for (tag, questions) in &by_tag {
+        let path = tags_dir.join(format!("{}.md", tag));
+        std::fs::write(&path, render_tag_index(tag, questions))?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// Tags researched questions of a topic and refreshes the cross-topic tag indexes.
+///
+/// Each question is matched against the prompts recorded in the topic's
+/// `metadata.json`; matching question files have their tags replaced with
+/// `tags`. Questions that were skipped during research (e.g. because they
+/// overlapped an existing question) are not tagged. Returns the number of
+/// question files tagged.
+///
+/// ## Errors
+///
+/// Returns an error if a tag is invalid, the topic has no metadata, or the
+/// metadata or tag indexes cannot be written.
+pub async fn tag_questions(
+    output_dir: &Path,
+    questions: &[String],
+    tags: &[String],
+) -> Result<usize, ResearchError> {
+    validate_tags(tags)?;
+    let mut metadata = load_metadata(output_dir).await?;
+
+    let filenames: Vec<String> = metadata
+        .additional_files
+        .iter()
+        .filter(|(_, prompt)| questions.contains(prompt))
+        .map(|(filename, _)| filename.clone())
+        .collect();
+
+    for filename in &filenames {
+        metadata.set_question_tags(filename, tags);
+    }
+
+    if !filenames.is_empty() {
+        save_metadata(&metadata, output_dir).await?;
+    }
+    write_tag_indexes(&default_research_root())
+        .map_err(|e| ResearchError::TaggingFailed(format!("failed to write tag indexes: {}", e)))?;
+
+    Ok(filenames.len())
+}
+
+/// Adds and removes tags on a researched topic, returning its tags afterwards.
+///
+/// Topic tags describe the topic as a whole (e.g. `async`, `networking`) and
+/// are what `research list --tag` matches alongside question tags. Tags in
+/// `remove` are removed after those in `add` are added; question tags are
+/// left untouched.
+///
+/// ## Errors
+///
+/// Returns an error if a tag is invalid, the topic has no metadata, or the
+/// metadata cannot be written.
+pub async fn update_topic_tags(
+    output_dir: &Path,
+    add: &[String],
+    remove: &[String],
+) -> Result<Vec<String>, ResearchError> {
+    validate_tags(add)?;
+    validate_tags(remove)?;
+    let mut metadata = load_metadata(output_dir).await?;
+
+    let added = metadata.add_tags(add);
+    let removed = metadata.remove_tags(remove);
+    if !added.is_empty() || !removed.is_empty() {
+        save_metadata(&metadata, output_dir).await?;
+    }
+
+    Ok(metadata.tags)
+}
+
+/// Sets or clears the category of a researched topic, returning the category
+/// as stored.
+///
+/// ## Errors
+///
+/// Returns an error if the category is invalid, the topic has no metadata,
+/// or the metadata cannot be written.
+pub async fn set_topic_category(
+    output_dir: &Path,
+    category: Option<&str>,
+) -> Result<Option<String>, ResearchError> {
+    if let Some(category) = category
+        && normalize_tag(category).is_none()
+    {
+        return Err(ResearchError::TaggingFailed(format!(
+            "invalid category '{}'",
+            category
+        )));
+    }
+    let mut metadata = load_metadata(output_dir).await?;
+
+    let previous = metadata.category.clone();
+    metadata.set_category(category);
+    if metadata.category != previous {
+        save_metadata(&metadata, output_dir).await?;
+    }
+
+    Ok(metadata.category)
+}
+
+fn validate_tags(tags: &[String]) -> Result<(), ResearchError> {
+    match tags.iter().find(|t| normalize_tag(t).is_none()) {
+        Some(invalid) => Err(ResearchError::TaggingFailed(format!(
+            "invalid tag '{}'",
+            invalid
+        ))),
+        None => Ok(()),
+    }
+}
+
+async fn load_metadata(output_dir: &Path) -> Result<ResearchMetadata, ResearchError> {
+    ResearchMetadata::load(output_dir).await.ok_or_else(|| {
+        ResearchError::TaggingFailed(format!("no metadata.json found in {:?}", output_dir))
+    })
+}
+
+async fn save_metadata(
+    metadata: &ResearchMetadata,
+    output_dir: &Path,
+) -> Result<(), ResearchError> {
+    metadata
+        .save(output_dir)
+        .await
+        .map_err(|e| ResearchError::TaggingFailed(format!("failed to save metadata.json: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_topic(root: &Path, topic: &str, metadata: &str) {
+        let dir = root.join("library").join(topic);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("metadata.json"), metadata).unwrap();
+    }
+
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(
+            normalize_tag("reliability"),
+            Some("reliability".to_string())
+        );
+        assert_eq!(
+            normalize_tag("  Error   Handling "),
+            Some("error-handling".to_string())
+        );
+        assert_eq!(normalize_tag(""), None);
+        assert_eq!(normalize_tag(".hidden"), None);
+        assert_eq!(normalize_tag("a/b"), None);
+        assert_eq!(normalize_tag("a\\b"), None);
+    }
+
+    #[test]
+    fn test_collect_tagged_questions_groups_across_topics() {
+        let temp = TempDir::new().unwrap();
+        write_topic(
+            temp.path(),
+            "tokio",
+            r#"{
+                "additional_files": {"question_1.md": "How does tokio handle retries?"},
+                "question_tags": {"question_1.md": ["reliability", "async"]}
+            }"#,
+        );
+        write_topic(
+            temp.path(),
+            "reqwest",
+            r#"{
+                "additional_files": {"question_2.md": "How are retries configured?"},
+                "question_tags": {"question_2.md": ["reliability"]}
+            }"#,
+        );
+        write_topic(temp.path(), "serde", r#"{"additional_files": {}}"#);
+
+        let by_tag = collect_tagged_questions(&temp.path().join("library"));
+
+        assert_eq!(
+            by_tag.keys().collect::<Vec<_>>(),
+            vec!["async", "reliability"]
+        );
+        let reliability = &by_tag["reliability"];
+        assert_eq!(reliability.len(), 2);
+        assert_eq!(reliability[0].topic, "reqwest");
+        assert_eq!(reliability[1].topic, "tokio");
+        assert_eq!(reliability[1].prompt, "How does tokio handle retries?");
+    }
+
+    #[test]
+    fn test_render_tag_index_links_into_library() {
+        let questions = vec![
+            TaggedQuestion {
+                topic: "tokio".to_string(),
+                filename: "question_1.md".to_string(),
+                prompt: "How does tokio handle retries?".to_string(),
+            },
+            TaggedQuestion {
+                topic: "tokio".to_string(),
+                filename: "question_2.md".to_string(),
+                prompt: String::new(),
+            },
+        ];
+
+        let index = render_tag_index("reliability", &questions);
+
+        assert!(index.starts_with("# reliability\n"));
+        assert_eq!(index.matches("## tokio").count(), 1);
+        assert!(
+            index.contains("- [How does tokio handle retries?](../library/tokio/question_1.md)")
+        );
+        assert!(index.contains("- [question_2.md](../library/tokio/question_2.md)"));
+    }
+
+    #[test]
+    fn test_write_tag_indexes_removes_stale_tags() {
+        let temp = TempDir::new().unwrap();
+        write_topic(
+            temp.path(),
+            "tokio",
+            r#"{
+                "additional_files": {"question_1.md": "Retries?"},
+                "question_tags": {"question_1.md": ["reliability"]}
+            }"#,
+        );
+        let tags_dir = temp.path().join("tags");
+        std::fs::create_dir_all(&tags_dir).unwrap();
+        std::fs::write(tags_dir.join("obsolete.md"), "# obsolete\n").unwrap();
+
+        let written = write_tag_indexes(temp.path()).unwrap();
+
+        assert_eq!(written, vec![tags_dir.join("reliability.md")]);
+        assert!(tags_dir.join("reliability.md").exists());
+        assert!(!tags_dir.join("obsolete.md").exists());
+    }
+
+    #[test]
+    fn test_write_tag_indexes_without_tags_creates_nothing() {
+        let temp = TempDir::new().unwrap();
+        write_topic(temp.path(), "serde", r#"{"additional_files": {}}"#);
+
+        let written = write_tag_indexes(temp.path()).unwrap();
+
+        assert!(written.is_empty());
+        assert!(!temp.path().join("tags").exists());
+    }
+}
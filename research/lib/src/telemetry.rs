@@ -0,0 +1,636 @@
+//! OpenTelemetry export for research runs (the `otel` feature).
+//!
+//! [`Telemetry::from_env`] sends a run's spans and metrics to an OTLP
+//! collector over HTTP, so LLM spend and latency show up in an existing
+//! observability stack. The exporters read the standard `OTEL_*` variables;
+//! setting `OTEL_EXPORTER_OTLP_ENDPOINT` (e.g. `http://localhost:4318`) turns
+//! export on.
+//!
+//! Spans come from the `tracing` instrumentation once
+//! [`Telemetry::tracing_layer`] is added to the subscriber: the `research`
+//! span of each run, and an `agent_task` span per agent with an event for
+//! every tool call. Metrics are recorded by [`TelemetryObserver`]:
+//!
+//! | Metric | Kind | Attributes |
+//! |--------|------|------------|
+//! | `research.tasks` | counter | `task`, `outcome` |
+//! | `research.task.duration` | histogram (s) | `task`, `outcome`, `provider`, `model` |
+//! | `research.tokens` | counter | `task`, `gen_ai.token.type` (`input`/`output`), `provider`, `model` |
+//! | `research.cost` | counter (USD) | `task`, `provider`, `model` |
+//! | `research.tool_calls` | counter | `task`, `tool` |
+//!
+//! `provider` and `model` are only set when the observer was given the run's
+//! [`ModelConfig`], and cost only for models with a [`TokenPrice`] (set
+//! directly or through [`PRICES_ENV`]). Agent tasks don't report token
+//! counts, so they add no tokens or cost.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use research_lib::models::ModelConfig;
+//! use research_lib::observer::ConsoleObserver;
+//! use research_lib::telemetry::{Telemetry, TokenPrice};
+//! use research_lib::{ResearchOptions, research_with_options};
+//! use tracing_subscriber::layer::SubscriberExt;
+//! use tracing_subscriber::util::SubscriberInitExt;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let telemetry = Telemetry::from_env()?.expect("OTEL_EXPORTER_OTLP_ENDPOINT not set");
+//!     tracing_subscriber::registry()
+//!         .with(telemetry.tracing_layer())
+//!         .init();
+//!
+//!     let models = ModelConfig::load()?;
+//!     let observer = telemetry
+//!         .observer(Arc::new(ConsoleObserver))
+//!         .with_models(&models)
+//!         .with_price("gpt-5.2", TokenPrice::per_million(1.75, 14.0));
+//!     let options = ResearchOptions::default()
+//!         .with_models(models)
+//!         .with_observer(Arc::new(observer));
+//!     research_with_options("clap", None, &[], false, false, &options).await?;
+//!
+//!     telemetry.shutdown()?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use opentelemetry::KeyValue;
+use opentelemetry::metrics::{Counter, Histogram, Meter, MeterProvider};
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{ExporterBuildError, MetricExporter, SpanExporter};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::error::OTelSdkError;
+use opentelemetry_sdk::metrics::SdkMeterProvider;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use thiserror::Error;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::PromptMetrics;
+use crate::models::{ModelConfig, ModelProvider, ModelSpec, ResearchTask};
+use crate::observer::{PhaseSummary, ResearchObserver, TaskOutcome, TaskReport, TaskStage};
+
+/// Variables that point the OTLP exporters at a collector.
+const ENDPOINT_VARS: [&str; 3] = [
+    "OTEL_EXPORTER_OTLP_ENDPOINT",
+    "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+    "OTEL_EXPORTER_OTLP_METRICS_ENDPOINT",
+];
+
+/// Service name reported when `OTEL_SERVICE_NAME` is not set.
+const SERVICE_NAME: &str = "research";
+
+/// Instrumentation scope for the tracer and meter.
+const SCOPE: &str = "research_lib";
+
+/// Environment variable listing model prices for `research.cost`, as
+/// comma-separated `<model>=<input>/<output>` entries in USD per million
+/// tokens (e.g. `gpt-5.2=1.75/14,gemini-3-flash-preview=0.5/3`).
+pub const PRICES_ENV: &str = "RESEARCH_PRICES";
+
+/// Errors raised while setting up or shutting down telemetry export.
+#[derive(Error, Debug)]
+pub enum TelemetryError {
+    #[error("Failed to build OTLP exporter: {0}")]
+    Exporter(#[from] ExporterBuildError),
+
+    #[error("Failed to flush telemetry: {0}")]
+    Shutdown(#[from] OTelSdkError),
+
+    #[error(
+        "Invalid price '{0}': expected <input>/<output> in USD per million tokens (e.g. 1.75/14)"
+    )]
+    InvalidPrice(String),
+}
+
+/// OTLP trace and metric pipelines for research runs.
+///
+/// Spans and metrics are exported in batches; call
+/// [`shutdown`](Self::shutdown) before exiting so the last batch is sent.
+#[derive(Debug)]
+pub struct Telemetry {
+    tracer_provider: SdkTracerProvider,
+    meter_provider: SdkMeterProvider,
+}
+
+impl Telemetry {
+    /// Sets up export if an `OTEL_EXPORTER_OTLP_*ENDPOINT` variable is set.
+    ///
+    /// Returns `Ok(None)` when none is, so telemetry stays off by default.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`TelemetryError::Exporter`] if an exporter can't be built
+    /// (e.g. the endpoint is not a valid URL).
+    pub fn from_env() -> Result<Option<Self>, TelemetryError> {
+        let configured = ENDPOINT_VARS
+            .iter()
+            .any(|var| std::env::var(var).is_ok_and(|value| !value.trim().is_empty()));
+        if !configured {
+            return Ok(None);
+        }
+        Self::new().map(Some)
+    }
+
+    /// Sets up OTLP/HTTP export configured by the `OTEL_*` variables, sending
+    /// to `http://localhost:4318` unless they say otherwise.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`TelemetryError::Exporter`] if an exporter can't be built.
+    pub fn new() -> Result<Self, TelemetryError> {
+        let resource = Resource::builder()
+            .with_service_name(
+                std::env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| SERVICE_NAME.to_string()),
+            )
+            .build();
+
+        let spans = SpanExporter::builder().with_http().build()?;
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_batch_exporter(spans)
+            .with_resource(resource.clone())
+            .build();
+
+        let metrics = MetricExporter::builder().with_http().build()?;
+        let meter_provider = SdkMeterProvider::builder()
+            .with_periodic_exporter(metrics)
+            .with_resource(resource)
+            .build();
+
+        Ok(Self {
+            tracer_provider,
+            meter_provider,
+        })
+    }
+
+    /// A `tracing` layer that exports spans and their events.
+    pub fn tracing_layer<S>(&self) -> OpenTelemetryLayer<S, SdkTracer>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        tracing_opentelemetry::layer().with_tracer(self.tracer_provider.tracer(SCOPE))
+    }
+
+    /// The meter research metrics are recorded with.
+    pub fn meter(&self) -> Meter {
+        self.meter_provider.meter(SCOPE)
+    }
+
+    /// An observer that records metrics and passes every event on to `inner`.
+    pub fn observer(&self, inner: Arc<dyn ResearchObserver>) -> TelemetryObserver {
+        TelemetryObserver::new(&self.meter(), inner)
+    }
+
+    /// Flushes pending spans and metrics and stops both pipelines.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`TelemetryError::Shutdown`] if either pipeline fails to flush;
+    /// both are shut down regardless.
+    pub fn shutdown(&self) -> Result<(), TelemetryError> {
+        let traces = self.tracer_provider.shutdown();
+        let metrics = self.meter_provider.shutdown();
+        traces?;
+        metrics?;
+        Ok(())
+    }
+}
+
+/// What a model charges for tokens, in US dollars.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenPrice {
+    /// Price of one million input tokens
+    pub input_per_million: f64,
+    /// Price of one million output tokens
+    pub output_per_million: f64,
+}
+
+impl TokenPrice {
+    /// A price per million input and output tokens, as providers list them.
+    pub fn per_million(input: f64, output: f64) -> Self {
+        Self {
+            input_per_million: input,
+            output_per_million: output,
+        }
+    }
+
+    /// What the tokens in `metrics` cost.
+    pub fn cost(&self, metrics: &PromptMetrics) -> f64 {
+        (metrics.input_tokens as f64 * self.input_per_million
+            + metrics.output_tokens as f64 * self.output_per_million)
+            / 1_000_000.0
+    }
+}
+
+impl FromStr for TokenPrice {
+    type Err = TelemetryError;
+
+    /// Parses `<input>/<output>` in USD per million tokens.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TelemetryError::InvalidPrice(s.to_string());
+        let (input, output) = s.split_once('/').ok_or_else(invalid)?;
+        let parse = |value: &str| {
+            value
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|price| price.is_finite() && *price >= 0.0)
+                .ok_or_else(invalid)
+        };
+        Ok(Self::per_million(parse(input)?, parse(output)?))
+    }
+}
+
+/// Records research metrics, then passes each event on to another observer.
+///
+/// See the [module docs](self) for the metrics it records.
+pub struct TelemetryObserver {
+    inner: Arc<dyn ResearchObserver>,
+    tasks: Counter<u64>,
+    duration: Histogram<f64>,
+    tokens: Counter<u64>,
+    cost: Counter<f64>,
+    tool_calls: Counter<u64>,
+    models: HashMap<ResearchTask, ModelSpec>,
+    prices: HashMap<String, TokenPrice>,
+    started: Mutex<HashMap<String, Instant>>,
+}
+
+impl TelemetryObserver {
+    /// Creates the instruments on `meter`.
+    pub fn new(meter: &Meter, inner: Arc<dyn ResearchObserver>) -> Self {
+        Self {
+            inner,
+            tasks: meter
+                .u64_counter("research.tasks")
+                .with_description("Research tasks finished, by outcome")
+                .build(),
+            duration: meter
+                .f64_histogram("research.task.duration")
+                .with_unit("s")
+                .with_description("Time from a task's start to its completion")
+                .build(),
+            tokens: meter
+                .u64_counter("research.tokens")
+                .with_unit("{token}")
+                .with_description("Tokens sent to and generated by models")
+                .build(),
+            cost: meter
+                .f64_counter("research.cost")
+                .with_unit("USD")
+                .with_description("Estimated model spend, for models with a known price")
+                .build(),
+            tool_calls: meter
+                .u64_counter("research.tool_calls")
+                .with_description("Tool calls made by research agents")
+                .build(),
+            models: HashMap::new(),
+            prices: HashMap::new(),
+            started: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Attributes each task's metrics to the model it runs on in `models`:
+    /// the first in its chain whose provider has an API key.
+    pub fn with_models(mut self, models: &ModelConfig) -> Self {
+        self.models = ResearchTask::ALL
+            .into_iter()
+            .map(|task| {
+                let spec = models.resolve(task, ModelProvider::is_configured);
+                (task, spec.clone())
+            })
+            .collect();
+        self
+    }
+
+    /// Records `research.cost` for tasks that run on `model`.
+    pub fn with_price(mut self, model: impl Into<String>, price: TokenPrice) -> Self {
+        self.prices.insert(model.into(), price);
+        self
+    }
+
+    /// Adds the prices listed in [`PRICES_ENV`], if it is set.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`TelemetryError::InvalidPrice`] for an entry that isn't
+    /// `<model>=<input>/<output>`.
+    pub fn with_prices_from_env(self) -> Result<Self, TelemetryError> {
+        match std::env::var(PRICES_ENV) {
+            Ok(prices) => self.with_prices(&prices),
+            Err(_) => Ok(self),
+        }
+    }
+
+    /// Adds prices from comma-separated `<model>=<input>/<output>` entries.
+    fn with_prices(self, prices: &str) -> Result<Self, TelemetryError> {
+        prices
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .try_fold(self, |observer, entry| {
+                let (model, price) = entry
+                    .split_once('=')
+                    .ok_or_else(|| TelemetryError::InvalidPrice(entry.to_string()))?;
+                Ok(observer.with_price(model.trim(), price.parse()?))
+            })
+    }
+
+    /// The model `task` runs on, if known.
+    fn model(&self, task: &str) -> Option<&ModelSpec> {
+        ResearchTask::from_name(task).and_then(|task| self.models.get(&task))
+    }
+
+    /// Attributes identifying `task` and its model.
+    fn task_attributes(&self, task: &str) -> Vec<KeyValue> {
+        let mut attributes = vec![KeyValue::new("task", task.to_string())];
+        if let Some(spec) = self.model(task) {
+            attributes.push(KeyValue::new("provider", spec.provider.to_string()));
+            attributes.push(KeyValue::new("model", spec.model.clone()));
+        }
+        attributes
+    }
+
+    fn record_tokens(&self, task: &str, metrics: &PromptMetrics) {
+        let attributes = self.task_attributes(task);
+        for (kind, count) in [
+            ("input", metrics.input_tokens),
+            ("output", metrics.output_tokens),
+        ] {
+            let mut attributes = attributes.clone();
+            attributes.push(KeyValue::new("gen_ai.token.type", kind));
+            self.tokens.add(count, &attributes);
+        }
+
+        let price = self
+            .model(task)
+            .and_then(|spec| self.prices.get(&spec.model));
+        if let Some(price) = price {
+            self.cost.add(price.cost(metrics), &attributes);
+        }
+    }
+}
+
+impl ResearchObserver for TelemetryObserver {
+    fn on_task_start(&self, task: &str, stage: &TaskStage) {
+        // Tasks report each stage; the duration runs from the first
+        self.started
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(task.to_string())
+            .or_insert_with(Instant::now);
+        self.inner.on_task_start(task, stage);
+    }
+
+    fn on_task_complete(&self, report: &TaskReport) {
+        let outcome = match &report.outcome {
+            TaskOutcome::Succeeded { metrics } => {
+                if let Some(metrics) = metrics {
+                    self.record_tokens(&report.task, metrics);
+                }
+                "succeeded"
+            }
+            TaskOutcome::Recovered => "recovered",
+            TaskOutcome::Failed { .. } => "failed",
+            TaskOutcome::Cancelled { .. } => "cancelled",
+        };
+
+        self.tasks.add(
+            1,
+            &[
+                KeyValue::new("task", report.task.clone()),
+                KeyValue::new("outcome", outcome),
+            ],
+        );
+
+        let started = self
+            .started
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&report.task);
+        if let Some(started) = started {
+            let mut attributes = self.task_attributes(&report.task);
+            attributes.push(KeyValue::new("outcome", outcome));
+            self.duration
+                .record(started.elapsed().as_secs_f64(), &attributes);
+        }
+
+        self.inner.on_task_complete(report);
+    }
+
+    fn on_tool_call(&self, task: &str, tool: &str) {
+        self.tool_calls.add(
+            1,
+            &[
+                KeyValue::new("task", task.to_string()),
+                KeyValue::new("tool", tool.to_string()),
+            ],
+        );
+        self.inner.on_tool_call(task, tool);
+    }
+
+    fn on_token_usage(&self, task: &str, usage: &PromptMetrics) {
+        self.inner.on_token_usage(task, usage);
+    }
+
+    fn on_phase_complete(&self, summary: &PhaseSummary) {
+        self.inner.on_phase_complete(summary);
+    }
+
+    fn on_warning(&self, message: &str) {
+        self.inner.on_warning(message);
+    }
+
+    fn on_info(&self, message: &str) {
+        self.inner.on_info(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry_sdk::metrics::data::{AggregatedMetrics, MetricData, ResourceMetrics};
+    use opentelemetry_sdk::metrics::{InMemoryMetricExporter, PeriodicReader};
+
+    #[derive(Default)]
+    struct Forwarded(Mutex<Vec<String>>);
+
+    impl ResearchObserver for Forwarded {
+        fn on_task_complete(&self, report: &TaskReport) {
+            self.0.lock().unwrap().push(report.task.clone());
+        }
+
+        fn on_tool_call(&self, _task: &str, tool: &str) {
+            self.0.lock().unwrap().push(tool.to_string());
+        }
+    }
+
+    fn report(task: &str, outcome: TaskOutcome) -> TaskReport {
+        TaskReport {
+            task: task.to_string(),
+            completed: 1,
+            total: 1,
+            elapsed_secs: 1.0,
+            outcome,
+        }
+    }
+
+    /// Sums the data points of the counter `name` whose attributes include `filter`.
+    fn counter_total(metrics: &[ResourceMetrics], name: &str, filter: &[(&str, &str)]) -> f64 {
+        let matches = |attributes: Vec<&KeyValue>| {
+            filter.iter().all(|(key, value)| {
+                attributes
+                    .iter()
+                    .any(|kv| kv.key.as_str() == *key && kv.value.as_str() == *value)
+            })
+        };
+        metrics
+            .iter()
+            .flat_map(|rm| rm.scope_metrics())
+            .flat_map(|sm| sm.metrics())
+            .filter(|metric| metric.name() == name)
+            .map(|metric| match metric.data() {
+                AggregatedMetrics::U64(MetricData::Sum(sum)) => sum
+                    .data_points()
+                    .filter(|dp| matches(dp.attributes().collect()))
+                    .map(|dp| dp.value() as f64)
+                    .sum(),
+                AggregatedMetrics::F64(MetricData::Sum(sum)) => sum
+                    .data_points()
+                    .filter(|dp| matches(dp.attributes().collect()))
+                    .map(|dp| dp.value())
+                    .sum(),
+                AggregatedMetrics::F64(MetricData::Histogram(histogram)) => histogram
+                    .data_points()
+                    .filter(|dp| matches(dp.attributes().collect()))
+                    .map(|dp| dp.count() as f64)
+                    .sum(),
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    #[test]
+    fn observer_records_task_metrics_and_forwards_events() {
+        let exporter = InMemoryMetricExporter::default();
+        let provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(exporter.clone()).build())
+            .build();
+        let forwarded = Arc::new(Forwarded::default());
+
+        let models = ModelConfig::default()
+            .with_model(ResearchTask::DeepDive, ModelSpec::ollama("qwen3:32b"));
+        let observer = TelemetryObserver::new(&provider.meter("test"), forwarded.clone())
+            .with_models(&models)
+            .with_price("qwen3:32b", TokenPrice::per_million(1.0, 4.0));
+
+        observer.on_task_start("deep_dive", &TaskStage::Prompt);
+        observer.on_task_complete(&report(
+            "deep_dive",
+            TaskOutcome::Succeeded {
+                metrics: Some(PromptMetrics {
+                    input_tokens: 500_000,
+                    output_tokens: 250_000,
+                    total_tokens: 750_000,
+                    elapsed_secs: 1.0,
+                }),
+            },
+        ));
+        observer.on_tool_call("overview", "brave_search");
+        observer.on_task_complete(&report(
+            "overview",
+            TaskOutcome::Failed {
+                error: "rate limited".to_string(),
+            },
+        ));
+
+        provider.force_flush().unwrap();
+        let metrics = exporter.get_finished_metrics().unwrap();
+
+        let deep_dive = [("task", "deep_dive")];
+        assert_eq!(
+            counter_total(
+                &metrics,
+                "research.tokens",
+                &[("gen_ai.token.type", "input")]
+            ),
+            500_000.0
+        );
+        assert_eq!(
+            counter_total(&metrics, "research.tokens", &[("model", "qwen3:32b")]),
+            750_000.0
+        );
+        assert_eq!(counter_total(&metrics, "research.cost", &deep_dive), 1.5);
+        assert_eq!(
+            counter_total(&metrics, "research.task.duration", &deep_dive),
+            1.0
+        );
+        assert_eq!(
+            counter_total(&metrics, "research.tasks", &[("outcome", "failed")]),
+            1.0
+        );
+        assert_eq!(
+            counter_total(&metrics, "research.tool_calls", &[("tool", "brave_search")]),
+            1.0
+        );
+
+        // Without a start event there is no duration to record
+        assert_eq!(
+            counter_total(&metrics, "research.task.duration", &[("task", "overview")]),
+            0.0
+        );
+        assert_eq!(
+            *forwarded.0.lock().unwrap(),
+            ["deep_dive", "brave_search", "overview"]
+        );
+    }
+
+    #[test]
+    fn prices_parse_from_model_entries() {
+        let meter = SdkMeterProvider::default().meter("test");
+        let observer = TelemetryObserver::new(&meter, Arc::new(Forwarded::default()))
+            .with_prices(" gpt-5.2=1.75/14, gemini-3-flash-preview = 0.5/3 ,")
+            .unwrap();
+        assert_eq!(
+            observer.prices["gpt-5.2"],
+            TokenPrice::per_million(1.75, 14.0)
+        );
+        assert_eq!(
+            observer.prices["gemini-3-flash-preview"],
+            TokenPrice::per_million(0.5, 3.0)
+        );
+
+        for invalid in ["gpt-5.2", "gpt-5.2=1.75", "gpt-5.2=-1/2", "gpt-5.2=free/2"] {
+            let observer = TelemetryObserver::new(&meter, Arc::new(Forwarded::default()));
+            assert!(
+                matches!(
+                    observer.with_prices(invalid),
+                    Err(TelemetryError::InvalidPrice(_))
+                ),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn token_price_is_per_million() {
+        let metrics = PromptMetrics {
+            input_tokens: 2_000,
+            output_tokens: 1_000,
+            total_tokens: 3_000,
+            elapsed_secs: 0.5,
+        };
+        let cost = TokenPrice::per_million(3.0, 15.0).cost(&metrics);
+        assert!((cost - 0.021).abs() < 1e-12);
+    }
+}
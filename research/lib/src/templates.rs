@@ -0,0 +1,136 @@
+//! Prompt templates with runtime overrides.
+//!
+//! Every prompt the pipeline sends is compiled into the crate, so the defaults
+//! always work. To tune a prompt without forking, point `RESEARCH_PROMPTS_DIR`
+//! at a directory laid out like the crate's `prompts/` directory: a file there
+//! replaces the embedded template at the same relative path, and anything
+//! missing falls back to the default.
+//!
+//! ```text
+//! $RESEARCH_PROMPTS_DIR/
+//! ├── overview.md                 # library overview
+//! ├── skill.md                    # Phase 2 skill synthesis
+//! ├── software/overview.md        # software overview
+//! └── ecosystem/rust/use_cases.md # Rust library use cases
+//! ```
+//!
+//! Overrides use the same `{{placeholder}}` variables as the templates they
+//! replace. Each file is read the first time its template is used and then
+//! cached for the rest of the process.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use tracing::{debug, warn};
+
+/// Environment variable holding the prompt override directory.
+pub const PROMPTS_DIR_ENV: &str = "RESEARCH_PROMPTS_DIR";
+
+/// Override files read so far, by path (`None` when the file doesn't exist).
+static OVERRIDES: OnceLock<Mutex<HashMap<PathBuf, Option<&'static str>>>> = OnceLock::new();
+
+/// An embedded prompt template that can be overridden from disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PromptTemplate {
+    path: &'static str,
+    embedded: &'static str,
+}
+
+impl PromptTemplate {
+    /// Creates a template for the embedded prompt at `path`, relative to the
+    /// prompts directory.
+    pub const fn new(path: &'static str, embedded: &'static str) -> Self {
+        Self { path, embedded }
+    }
+
+    /// The template's path relative to the prompts directory
+    /// (e.g., `software/overview.md`).
+    pub fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// The template compiled into the crate.
+    pub fn embedded(&self) -> &'static str {
+        self.embedded
+    }
+
+    /// Returns the template, read from [`prompts_dir()`] when it has an
+    /// override and the embedded default otherwise.
+    pub fn get(&self) -> &'static str {
+        match prompts_dir() {
+            Some(dir) => self.get_from(&dir),
+            None => self.embedded,
+        }
+    }
+
+    /// Returns the template's override in `dir`, falling back to the embedded
+    /// default.
+    pub fn get_from(&self, dir: &Path) -> &'static str {
+        let mut overrides = OVERRIDES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let content = *overrides
+            .entry(dir.join(self.path))
+            .or_insert_with_key(|path| load_override(path));
+        content.unwrap_or(self.embedded)
+    }
+}
+
+/// The prompt override directory, if `RESEARCH_PROMPTS_DIR` is set.
+pub fn prompts_dir() -> Option<PathBuf> {
+    std::env::var_os(PROMPTS_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Reads an override file, leaking it so it can be used like an embedded
+/// template. Each path is read at most once.
+fn load_override(path: &Path) -> Option<&'static str> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => {
+            debug!(path = %path.display(), "Using prompt override");
+            Some(Box::leak(content.into_boxed_str()))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => {
+            warn!(path = %path.display(), error = %e, "Ignoring unreadable prompt override");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const OVERVIEW: PromptTemplate = PromptTemplate::new("overview.md", "embedded {{topic}}");
+    const RUST_OVERVIEW: PromptTemplate =
+        PromptTemplate::new("ecosystem/rust/overview.md", "embedded rust {{topic}}");
+
+    #[test]
+    fn override_replaces_embedded_template() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("ecosystem/rust")).unwrap();
+        std::fs::write(
+            dir.path().join("ecosystem/rust/overview.md"),
+            "tuned {{topic}}",
+        )
+        .unwrap();
+
+        assert_eq!(RUST_OVERVIEW.get_from(dir.path()), "tuned {{topic}}");
+        // Only the template at the same relative path is replaced
+        assert_eq!(OVERVIEW.get_from(dir.path()), "embedded {{topic}}");
+    }
+
+    #[test]
+    fn missing_directory_falls_back_to_embedded() {
+        let dir = TempDir::new().unwrap();
+        let missing = dir.path().join("missing");
+
+        assert_eq!(OVERVIEW.get_from(&missing), OVERVIEW.embedded());
+        assert_eq!(OVERVIEW.path(), "overview.md");
+    }
+}
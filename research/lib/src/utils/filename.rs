@@ -227,7 +227,7 @@ pub fn resolve_collision(base_name: &str, existing_files: &[String]) -> String {
 ///
 /// These filenames are reserved for the core research pipeline outputs and
 /// will be rejected if a user attempts to use them via the `filename -> prompt` syntax.
-const CORE_DOCUMENTS: &[&str] = &[
+pub(crate) const CORE_DOCUMENTS: &[&str] = &[
     "overview",
     "similar_libraries",
     "integration_partners",
@@ -543,6 +543,62 @@ fn try_reconstruct_frontmatter(content: &str) -> Option<String> {
     Some(result)
 }
 
+/// Split SKILL.md content into its frontmatter and body.
+///
+/// Used to hand frontmatter that failed validation to a model on its own. When
+/// the `---` delimiters are missing or unclosed, everything before the first
+/// markdown heading is treated as the frontmatter.
+///
+/// # Examples
+///
+/// ```
+/// use research_lib::validation::frontmatter::split_skill_frontmatter;
+///
+/// let (frontmatter, body) = split_skill_frontmatter("name: chalk\n\n# chalk\n");
+/// assert_eq!(frontmatter, "name: chalk");
+/// assert_eq!(body, "# chalk\n");
+/// ```
+pub fn split_skill_frontmatter(content: &str) -> (String, String) {
+    if let Some(split) = extract_frontmatter(content) {
+        return split;
+    }
+
+    let content = content.trim_start();
+    let content = content
+        .strip_prefix("---")
+        .map(|rest| rest.split_once('\n').map_or("", |(_, rest)| rest))
+        .unwrap_or(content);
+
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') && !trimmed.contains(':') {
+            break;
+        }
+        offset += line.len();
+    }
+    (
+        content[..offset].trim_end().to_string(),
+        content[offset..].to_string(),
+    )
+}
+
+/// Put `frontmatter` back in front of `body`, between `---` delimiters.
+///
+/// Code fences and delimiters a model wrapped around the frontmatter are
+/// removed first.
+pub fn join_skill_frontmatter(frontmatter: &str, body: &str) -> String {
+    let mut yaml = frontmatter.trim();
+    if let Some(fenced) = yaml.strip_prefix("```") {
+        yaml = fenced.split_once('\n').map_or("", |(_, rest)| rest);
+        yaml = yaml.trim_end().strip_suffix("```").unwrap_or(yaml);
+    }
+    let yaml = yaml.trim();
+    let yaml = yaml.strip_prefix("---").unwrap_or(yaml);
+    let yaml = yaml.strip_suffix("---").unwrap_or(yaml);
+    format!("---\n{}\n---\n{}", yaml.trim(), body)
+}
+
 /// Parse and validate changelog.md frontmatter
 ///
 /// This function extracts the YAML frontmatter, parses it, validates required fields,
@@ -1732,4 +1788,35 @@ Use this skill when you need to **design or implement a Rust CLI**.
         assert_eq!(frontmatter.name, "chalk");
         assert!(frontmatter.description.contains("Chalk"));
     }
+
+    #[test]
+    fn test_split_skill_frontmatter_with_delimiters() {
+        let content = "---\nname: chalk\ndescription: bad: colon\n---\n# chalk\n\nBody\n";
+        let (frontmatter, body) = split_skill_frontmatter(content);
+        assert_eq!(frontmatter, "name: chalk\ndescription: bad: colon\n");
+        assert_eq!(body, "# chalk\n\nBody\n");
+    }
+
+    #[test]
+    fn test_split_skill_frontmatter_unclosed() {
+        let content = "---\n## name: chalk\ntools: \\[Read\\]\n\n# chalk\n\nBody\n";
+        let (frontmatter, body) = split_skill_frontmatter(content);
+        assert_eq!(frontmatter, "## name: chalk\ntools: \\[Read\\]");
+        assert_eq!(body, "# chalk\n\nBody\n");
+    }
+
+    #[test]
+    fn test_join_skill_frontmatter_strips_fences_and_delimiters() {
+        let fixed =
+            "```yaml\n---\nname: chalk\ndescription: \"Styles: terminal output\"\n---\n```\n";
+        let content = join_skill_frontmatter(fixed, "# chalk\n");
+        assert_eq!(
+            content,
+            "---\nname: chalk\ndescription: \"Styles: terminal output\"\n---\n# chalk\n"
+        );
+
+        let (frontmatter, body) = parse_and_validate_frontmatter(&content).unwrap();
+        assert_eq!(frontmatter.description, "Styles: terminal output");
+        assert_eq!(body, "# chalk\n");
+    }
 }
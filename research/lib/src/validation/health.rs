@@ -4,8 +4,12 @@
 //! scattered validation checks into a single, type-safe API. It validates that all required
 //! files are present and that the SKILL.md frontmatter is correctly formatted.
 
-use super::frontmatter::parse_and_validate_frontmatter;
+use super::frontmatter::{
+    parse_and_validate_changelog_frontmatter, parse_and_validate_frontmatter,
+};
 use crate::list::types::ResearchOutput;
+use crate::metadata::migration::get_schema_version;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -177,6 +181,10 @@ pub enum ValidationError {
         valid_types: Vec<String>,
     },
 
+    /// The research library directory was not found
+    #[error("Research library not found at path: {}", path.display())]
+    LibraryNotFound { path: PathBuf },
+
     /// File I/O error occurred
     #[error("I/O error: {0}")]
     IoError(#[from] std::io::Error),
@@ -321,6 +329,278 @@ fn validate_skill_frontmatter(topic_path: &Path) -> bool {
     }
 }
 
+/// Output files may be written up to this long after `metadata.json` records
+/// the update without the metadata counting as stale.
+const METADATA_GRACE: chrono::Duration = chrono::Duration::minutes(1);
+
+/// A file whose YAML frontmatter failed validation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvalidFrontmatter {
+    /// Path of the file, relative to the topic directory
+    pub file: String,
+    /// Why validation failed
+    pub error: String,
+}
+
+/// Why a topic's `metadata.json` is out of date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StaleMetadata {
+    /// There is no `metadata.json`
+    Missing,
+    /// `metadata.json` is not valid JSON
+    Unreadable,
+    /// `metadata.json` uses the v0 schema and needs migration
+    OutdatedSchema,
+    /// `metadata.json` has no `when_to_use` guidance
+    MissingWhenToUse,
+    /// An output file was modified after the metadata's `updated_at`
+    OlderThanOutputs,
+}
+
+/// Full audit of a research topic directory
+///
+/// Unlike [`ResearchHealth`], which answers "is this topic complete?", the
+/// report lists every problem found so a topic can be repaired: missing
+/// prompts and outputs, frontmatter that fails validation, files with no
+/// content, and metadata that no longer matches the files on disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthReport {
+    /// The topic name (its directory name)
+    pub topic: String,
+
+    /// The topic's directory
+    pub location: PathBuf,
+
+    /// True when no problems were found
+    pub ok: bool,
+
+    /// Missing Phase 1 prompt files (e.g., "Overview", "Use Cases")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_prompts: Vec<String>,
+
+    /// Missing Phase 2 output deliverables
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_outputs: Vec<ResearchOutput>,
+
+    /// SKILL.md or changelog.md files whose frontmatter is invalid
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invalid_frontmatter: Vec<InvalidFrontmatter>,
+
+    /// Markdown files that exist but contain only whitespace, relative to
+    /// the topic directory
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub empty_files: Vec<String>,
+
+    /// Problems with `metadata.json`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stale_metadata: Vec<StaleMetadata>,
+
+    /// Schema version for future evolution
+    #[serde(default = "default_version")]
+    pub version: u8,
+}
+
+impl HealthReport {
+    /// Audits the topic stored in `topic_path`
+    ///
+    /// The topic name is taken from the directory name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TopicNotFound` if `topic_path` is not a directory, or an I/O
+    /// error if it can't be read.
+    pub fn check(topic_path: &Path) -> Result<Self, ValidationError> {
+        if !topic_path.is_dir() {
+            return Err(ValidationError::TopicNotFound {
+                path: topic_path.to_path_buf(),
+            });
+        }
+        let topic = topic_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let missing_prompts = check_missing_prompts(topic_path);
+        let missing_outputs = check_missing_outputs(topic_path, &topic);
+        let invalid_frontmatter = check_frontmatter(topic_path);
+        let empty_files = check_empty_files(topic_path, &topic)?;
+        let stale_metadata = check_metadata(topic_path, &topic);
+
+        let ok = missing_prompts.is_empty()
+            && missing_outputs.is_empty()
+            && invalid_frontmatter.is_empty()
+            && empty_files.is_empty()
+            && stale_metadata.is_empty();
+
+        Ok(Self {
+            topic,
+            location: topic_path.to_path_buf(),
+            ok,
+            missing_prompts,
+            missing_outputs,
+            invalid_frontmatter,
+            empty_files,
+            stale_metadata,
+            version: 1,
+        })
+    }
+
+    /// Returns the total number of problems in the report
+    pub fn issue_count(&self) -> usize {
+        self.missing_prompts.len()
+            + self.missing_outputs.len()
+            + self.invalid_frontmatter.len()
+            + self.empty_files.len()
+            + self.stale_metadata.len()
+    }
+}
+
+/// Audit every topic in a research library directory
+///
+/// Each immediate subdirectory of `library_dir` (e.g.,
+/// `~/.research/library`) is treated as a topic. Symlinks are not followed.
+///
+/// # Returns
+///
+/// One [`HealthReport`] per topic, sorted by topic name.
+///
+/// # Errors
+///
+/// Returns `LibraryNotFound` if `library_dir` doesn't exist, or an I/O error
+/// if it or one of its topics can't be read.
+///
+/// # Examples
+///
+/// ```no_run
+/// use research_lib::validation::health::research_health_all;
+///
+/// let library = research_lib::tags::default_research_root().join("library");
+/// let reports = research_health_all(&library)?;
+/// for report in reports.iter().filter(|r| !r.ok) {
+///     println!("{}: {} issues", report.topic, report.issue_count());
+/// }
+/// println!("{}", serde_json::to_string_pretty(&reports)?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn research_health_all(library_dir: &Path) -> Result<Vec<HealthReport>, ValidationError> {
+    if !library_dir.is_dir() {
+        return Err(ValidationError::LibraryNotFound {
+            path: library_dir.to_path_buf(),
+        });
+    }
+
+    let mut reports = Vec::new();
+    for entry in std::fs::read_dir(library_dir)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        reports.push(HealthReport::check(&entry.path())?);
+    }
+
+    reports.sort_by(|a, b| a.topic.cmp(&b.topic));
+    Ok(reports)
+}
+
+/// Validate the frontmatter of SKILL.md and changelog.md, when present
+fn check_frontmatter(topic_path: &Path) -> Vec<InvalidFrontmatter> {
+    let mut invalid = Vec::new();
+
+    let skill = ResearchOutput::Skill.path_for("");
+    if let Ok(content) = std::fs::read_to_string(topic_path.join(&skill))
+        && let Err(e) = parse_and_validate_frontmatter(&content)
+    {
+        invalid.push(InvalidFrontmatter {
+            file: skill,
+            error: e.to_string(),
+        });
+    }
+
+    if let Ok(content) = std::fs::read_to_string(topic_path.join("changelog.md"))
+        && let Err(e) = parse_and_validate_changelog_frontmatter(&content)
+    {
+        invalid.push(InvalidFrontmatter {
+            file: "changelog.md".to_string(),
+            error: e.to_string(),
+        });
+    }
+
+    invalid
+}
+
+/// Find the prompt and output files that contain only whitespace
+///
+/// Checks every Markdown file in the topic directory (standard prompts and
+/// additional questions) plus the output deliverables.
+fn check_empty_files(topic_path: &Path, topic_name: &str) -> Result<Vec<String>, ValidationError> {
+    let mut candidates = Vec::new();
+    for entry in std::fs::read_dir(topic_path)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.ends_with(".md") && entry.file_type()?.is_file() {
+            candidates.push(name);
+        }
+    }
+    candidates.sort();
+    for output in [ResearchOutput::DeepDive, ResearchOutput::Skill] {
+        candidates.push(output.path_for(topic_name));
+    }
+
+    Ok(candidates
+        .into_iter()
+        .filter(|file| {
+            std::fs::read_to_string(topic_path.join(file))
+                .is_ok_and(|content| content.trim().is_empty())
+        })
+        .collect())
+}
+
+/// Check that `metadata.json` exists, is current and is newer than the outputs
+fn check_metadata(topic_path: &Path, topic_name: &str) -> Vec<StaleMetadata> {
+    let Ok(content) = std::fs::read_to_string(topic_path.join("metadata.json")) else {
+        return vec![StaleMetadata::Missing];
+    };
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return vec![StaleMetadata::Unreadable];
+    };
+
+    let mut stale = Vec::new();
+    if get_schema_version(&value) == 0 {
+        stale.push(StaleMetadata::OutdatedSchema);
+    }
+    if value.get("when_to_use").is_none_or(|v| v.is_null()) {
+        stale.push(StaleMetadata::MissingWhenToUse);
+    }
+
+    let updated_at = value
+        .get("updated_at")
+        .and_then(|v| v.as_str())
+        .and_then(|v| v.parse::<DateTime<Utc>>().ok());
+    if let Some(updated_at) = updated_at {
+        let newest_output = [
+            ResearchOutput::DeepDive,
+            ResearchOutput::Skill,
+            ResearchOutput::Brief,
+        ]
+        .iter()
+        .filter_map(|output| {
+            std::fs::metadata(topic_path.join(output.path_for(topic_name)))
+                .and_then(|m| m.modified())
+                .ok()
+        })
+        .max();
+        if newest_output
+            .is_some_and(|modified| DateTime::<Utc>::from(modified) > updated_at + METADATA_GRACE)
+        {
+            stale.push(StaleMetadata::OlderThanOutputs);
+        }
+    }
+
+    stale
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -683,4 +963,108 @@ Content
         assert!(!json.contains(r#""missingUnderlying""#));
         assert!(!json.contains(r#""missingDeliverables""#));
     }
+
+    /// Create a complete topic that passes every `HealthReport` check
+    fn create_healthy_topic(library: &Path, topic: &str) -> PathBuf {
+        let topic_path = library.join(topic);
+        fs::create_dir_all(&topic_path).unwrap();
+        create_all_prompts(&topic_path);
+        create_all_outputs(&topic_path, topic);
+        fs::write(
+            topic_path.join("changelog.md"),
+            "---\ncreated_at: 2024-12-30\nupdated_at: 2024-12-30\nlatest_version: \"1.0.0\"\nconfidence: low\nsources:\n  - llm_knowledge\n---\nBody\n",
+        )
+        .unwrap();
+        let metadata = serde_json::json!({
+            "schema_version": 1,
+            "when_to_use": "Use when testing",
+            "updated_at": Utc::now().to_rfc3339(),
+        });
+        fs::write(topic_path.join("metadata.json"), metadata.to_string()).unwrap();
+        topic_path
+    }
+
+    #[test]
+    fn test_health_report_healthy_topic() {
+        let temp = TempDir::new().unwrap();
+        let topic_path = create_healthy_topic(temp.path(), "good-lib");
+
+        let report = HealthReport::check(&topic_path).unwrap();
+
+        assert!(report.ok, "unexpected issues: {report:?}");
+        assert_eq!(report.topic, "good-lib");
+        assert_eq!(report.issue_count(), 0);
+    }
+
+    #[test]
+    fn test_health_report_lists_every_problem() {
+        let temp = TempDir::new().unwrap();
+        let topic_path = create_healthy_topic(temp.path(), "bad-lib");
+
+        fs::remove_file(topic_path.join("use_cases.md")).unwrap();
+        fs::remove_file(topic_path.join("brief.md")).unwrap();
+        fs::write(topic_path.join("changelog.md"), "No frontmatter").unwrap();
+        fs::write(topic_path.join("question_1.md"), "  \n").unwrap();
+        fs::write(
+            topic_path.join("metadata.json"),
+            r#"{"updated_at": "2020-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+
+        let report = HealthReport::check(&topic_path).unwrap();
+
+        assert!(!report.ok);
+        assert_eq!(report.missing_prompts, vec!["Use Cases".to_string()]);
+        assert_eq!(report.missing_outputs, vec![ResearchOutput::Brief]);
+        assert_eq!(report.invalid_frontmatter.len(), 1);
+        assert_eq!(report.invalid_frontmatter[0].file, "changelog.md");
+        assert_eq!(report.empty_files, vec!["question_1.md".to_string()]);
+        assert_eq!(
+            report.stale_metadata,
+            vec![
+                StaleMetadata::OutdatedSchema,
+                StaleMetadata::MissingWhenToUse,
+                StaleMetadata::OlderThanOutputs,
+            ]
+        );
+        assert_eq!(report.issue_count(), 7);
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains(r#""missingPrompts":["Use Cases"]"#));
+        assert!(json.contains(r#""emptyFiles":["question_1.md"]"#));
+        assert!(json.contains(r#""staleMetadata":["outdated_schema""#));
+    }
+
+    #[test]
+    fn test_health_report_missing_metadata() {
+        let temp = TempDir::new().unwrap();
+        let topic_path = create_healthy_topic(temp.path(), "no-meta");
+        fs::remove_file(topic_path.join("metadata.json")).unwrap();
+
+        let report = HealthReport::check(&topic_path).unwrap();
+
+        assert_eq!(report.stale_metadata, vec![StaleMetadata::Missing]);
+    }
+
+    #[test]
+    fn test_research_health_all_audits_every_topic() {
+        let temp = TempDir::new().unwrap();
+        create_healthy_topic(temp.path(), "zeta");
+        create_healthy_topic(temp.path(), "alpha");
+        fs::create_dir_all(temp.path().join("empty")).unwrap();
+        fs::write(temp.path().join("README.md"), "not a topic").unwrap();
+
+        let reports = research_health_all(temp.path()).unwrap();
+
+        let topics: Vec<_> = reports.iter().map(|r| r.topic.as_str()).collect();
+        assert_eq!(topics, ["alpha", "empty", "zeta"]);
+        assert!(reports[0].ok && reports[2].ok);
+        assert!(!reports[1].ok);
+
+        let result = research_health_all(&temp.path().join("missing"));
+        assert!(matches!(
+            result,
+            Err(ValidationError::LibraryNotFound { .. })
+        ));
+    }
 }
@@ -3,14 +3,18 @@
 //! This module provides validation functionality for research topics, including:
 //! - Frontmatter parsing and validation for SKILL.md files
 //! - Comprehensive health checking for research topic completeness
+//! - Health reports auditing every topic in a research library
 
 pub mod frontmatter;
 pub mod health;
 
 // Re-export commonly used types
 pub use frontmatter::{
-    ChangelogFrontmatter, FrontmatterError, SkillFrontmatter,
+    ChangelogFrontmatter, FrontmatterError, SkillFrontmatter, join_skill_frontmatter,
     parse_and_validate_changelog_frontmatter, parse_and_validate_frontmatter,
-    repair_skill_frontmatter,
+    repair_skill_frontmatter, split_skill_frontmatter,
+};
+pub use health::{
+    HealthReport, InvalidFrontmatter, ResearchHealth, ResearchType, StaleMetadata, ValidationError,
+    research_health, research_health_all,
 };
-pub use health::{ResearchHealth, ResearchType, ValidationError, research_health};
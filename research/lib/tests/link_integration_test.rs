@@ -3,7 +3,8 @@
 //! These tests verify the complete end-to-end workflow of the link command,
 //! including topic discovery, filtering, and symlink creation.
 
-use research_lib::link::{SkillAction, link};
+use research_lib::link::{SkillAction, link, link_in};
+use research_lib::paths::ResearchPaths;
 use serial_test::serial;
 use std::env;
 use std::fs;
@@ -1035,3 +1036,32 @@ async fn test_stale_doc_symlinks_also_removed() {
             .any(|s| s.contains("another-stale.md"))
     );
 }
+
+#[tokio::test]
+#[serial]
+async fn test_link_in_uses_injected_library_root() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().join("lib-root");
+    let library = root.join("library");
+    fs::create_dir_all(&library).unwrap();
+    let (claude_skills, _, _) = setup_temp_home_dirs(temp.path());
+
+    create_skill(&library, "clap", "library");
+
+    // Only HOME is set; the library root comes from the injected paths
+    unsafe {
+        env::remove_var("RESEARCH_DIR");
+        env::set_var("HOME", temp.path().join("home"));
+    }
+
+    let result = link_in(&ResearchPaths::new(&root), vec![], vec![], false, false).await;
+
+    unsafe {
+        env::remove_var("HOME");
+    }
+
+    let link_result = result.unwrap();
+    assert_eq!(link_result.total_processed(), 1);
+    assert_eq!(link_result.links[0].name, "clap");
+    assert!(claude_skills.join("clap").symlink_metadata().is_ok());
+}
@@ -0,0 +1,172 @@
+//! Content-encoding configuration for REST APIs.
+//!
+//! Generated clients negotiate compressed responses (gzip and brotli) and
+//! transparently decompress them. Request bodies can optionally be compressed
+//! once they reach a size threshold, which helps with large uploads to APIs
+//! that accept `Content-Encoding` on requests.
+//!
+//! Compression is configured per API via [`RestApi::compression`](crate::RestApi::compression)
+//! and can be overridden per endpoint via
+//! [`Endpoint::compression`](crate::Endpoint::compression).
+
+use serde::{Deserialize, Serialize};
+
+/// A content encoding supported by generated clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ContentEncoding {
+    /// gzip (RFC 1952), supported by nearly every HTTP server.
+    Gzip,
+    /// Brotli (RFC 7932), smaller output at the cost of slower compression.
+    Brotli,
+}
+
+impl ContentEncoding {
+    /// Returns the `Content-Encoding` header token for this encoding.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use schematic_define::ContentEncoding;
+    ///
+    /// assert_eq!(ContentEncoding::Gzip.as_str(), "gzip");
+    /// assert_eq!(ContentEncoding::Brotli.as_str(), "br");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Brotli => "br",
+        }
+    }
+}
+
+/// Request body compression settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestCompression {
+    /// Encoding applied to request bodies.
+    pub encoding: ContentEncoding,
+    /// Bodies smaller than this many bytes are sent uncompressed.
+    pub min_size: usize,
+}
+
+impl RequestCompression {
+    /// Returns true if a body of `body_len` bytes should be compressed.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use schematic_define::{ContentEncoding, RequestCompression};
+    ///
+    /// let config = RequestCompression { encoding: ContentEncoding::Gzip, min_size: 1024 };
+    /// assert!(!config.applies_to(512));
+    /// assert!(config.applies_to(4096));
+    /// ```
+    pub fn applies_to(&self, body_len: usize) -> bool {
+        body_len >= self.min_size
+    }
+}
+
+/// Content-encoding settings for an API or a single endpoint.
+///
+/// The default accepts compressed responses and sends request bodies
+/// uncompressed, which is safe for every server.
+///
+/// ## Examples
+///
+/// Compress request bodies of 8 KiB or more with gzip:
+///
+/// ```
+/// use schematic_define::{Compression, ContentEncoding};
+///
+/// let compression = Compression::default().compress_requests(ContentEncoding::Gzip, 8 * 1024);
+/// assert!(compression.decompress_responses);
+/// assert_eq!(compression.request.unwrap().min_size, 8192);
+/// ```
+///
+/// Opt an endpoint out of compression entirely (e.g., a streaming endpoint
+/// where the server's compression adds latency):
+///
+/// ```
+/// use schematic_define::Compression;
+///
+/// let compression = Compression::disabled();
+/// assert!(!compression.decompress_responses);
+/// assert!(compression.request.is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Compression {
+    /// Accept gzip/brotli responses and decompress them transparently.
+    ///
+    /// When `false`, requests are sent with `Accept-Encoding: identity` so the
+    /// server returns the body as-is.
+    pub decompress_responses: bool,
+    /// Request body compression (`None` sends bodies uncompressed).
+    pub request: Option<RequestCompression>,
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Self {
+            decompress_responses: true,
+            request: None,
+        }
+    }
+}
+
+impl Compression {
+    /// Disables both response decompression and request compression.
+    pub fn disabled() -> Self {
+        Self {
+            decompress_responses: false,
+            request: None,
+        }
+    }
+
+    /// Compresses request bodies of at least `min_size` bytes with `encoding`.
+    pub fn compress_requests(mut self, encoding: ContentEncoding, min_size: usize) -> Self {
+        self.request = Some(RequestCompression { encoding, min_size });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_decompresses_responses_only() {
+        let compression = Compression::default();
+        assert!(compression.decompress_responses);
+        assert!(compression.request.is_none());
+    }
+
+    #[test]
+    fn compress_requests_keeps_response_setting() {
+        let compression = Compression::disabled().compress_requests(ContentEncoding::Brotli, 0);
+        assert!(!compression.decompress_responses);
+        assert_eq!(
+            compression.request,
+            Some(RequestCompression {
+                encoding: ContentEncoding::Brotli,
+                min_size: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn applies_to_is_inclusive_of_min_size() {
+        let config = RequestCompression {
+            encoding: ContentEncoding::Gzip,
+            min_size: 100,
+        };
+        assert!(!config.applies_to(99));
+        assert!(config.applies_to(100));
+    }
+
+    #[test]
+    fn compression_serde_roundtrip() {
+        let compression = Compression::default().compress_requests(ContentEncoding::Gzip, 1024);
+        let json = serde_json::to_string(&compression).unwrap();
+        let parsed: Compression = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, compression);
+    }
+}
@@ -0,0 +1,120 @@
+//! Example payloads for endpoint definitions.
+//!
+//! Examples pair an endpoint with sample request and/or response bodies,
+//! ideally copied from the provider's documentation or captured from real
+//! traffic. `schematic-gen` turns each example into a contract test that
+//! checks the payload (de)serializes through the declared schema type, so
+//! drift between a definition and the real API fails at test time.
+
+/// A named example request and/or response body for an endpoint.
+///
+/// Payloads are raw JSON strings. A request example is only meaningful for
+/// endpoints with an [`ApiRequest::Json`](crate::ApiRequest::Json) body, and
+/// a response example only for [`ApiResponse::Json`](crate::ApiResponse::Json)
+/// responses.
+///
+/// ## Examples
+///
+/// ```
+/// use schematic_define::EndpointExample;
+///
+/// let example = EndpointExample::new("basic")
+///     .with_request(r#"{"model": "gpt-4", "messages": []}"#)
+///     .with_response(r#"{"id": "chatcmpl-123", "choices": []}"#);
+///
+/// assert_eq!(example.name, "basic");
+/// assert!(example.request.is_some());
+/// assert!(example.response.is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointExample {
+    /// Short identifier for this example (e.g., "basic", "with_tools").
+    ///
+    /// Used to name the generated contract test, so it should be unique
+    /// within the endpoint and contain only letters, digits, `_`, `-`, or spaces.
+    pub name: String,
+    /// Example JSON request body.
+    pub request: Option<String>,
+    /// Example JSON response body.
+    pub response: Option<String>,
+}
+
+impl EndpointExample {
+    /// Creates an example with no payloads.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use schematic_define::EndpointExample;
+    ///
+    /// let example = EndpointExample::new("empty");
+    /// assert!(example.request.is_none());
+    /// assert!(example.response.is_none());
+    /// ```
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            request: None,
+            response: None,
+        }
+    }
+
+    /// Creates an example with only a response body.
+    ///
+    /// This is the common case for GET endpoints.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use schematic_define::EndpointExample;
+    ///
+    /// let example = EndpointExample::response("list", r#"{"object": "list", "data": []}"#);
+    /// assert!(example.request.is_none());
+    /// ```
+    pub fn response(name: impl Into<String>, json: impl Into<String>) -> Self {
+        Self::new(name).with_response(json)
+    }
+
+    /// Sets the example request body.
+    pub fn with_request(mut self, json: impl Into<String>) -> Self {
+        self.request = Some(json.into());
+        self
+    }
+
+    /// Sets the example response body.
+    pub fn with_response(mut self, json: impl Into<String>) -> Self {
+        self.response = Some(json.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_creates_example_without_payloads() {
+        let example = EndpointExample::new("basic");
+        assert_eq!(example.name, "basic");
+        assert!(example.request.is_none());
+        assert!(example.response.is_none());
+    }
+
+    #[test]
+    fn builder_sets_payloads() {
+        let example = EndpointExample::new("create")
+            .with_request(r#"{"name": "a"}"#)
+            .with_response(r#"{"id": 1}"#);
+
+        assert_eq!(example.request.as_deref(), Some(r#"{"name": "a"}"#));
+        assert_eq!(example.response.as_deref(), Some(r#"{"id": 1}"#));
+    }
+
+    #[test]
+    fn response_shorthand_sets_only_response() {
+        let example = EndpointExample::response("list", "[]");
+        assert_eq!(example.name, "list");
+        assert!(example.request.is_none());
+        assert_eq!(example.response.as_deref(), Some("[]"));
+    }
+}
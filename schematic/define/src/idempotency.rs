@@ -0,0 +1,94 @@
+//! Idempotency key configuration for REST endpoints.
+//!
+//! APIs that create resources or trigger side effects (payments, messages,
+//! jobs) often accept an idempotency key header: the server remembers the
+//! key and replays the original result when a request is retried with it,
+//! instead of performing the operation twice.
+//!
+//! An endpoint opts in via [`Endpoint::idempotency`](crate::Endpoint::idempotency).
+//! Generated clients then send the header on every request to that endpoint,
+//! generating a fresh ULID when the caller didn't supply a key. With
+//! [`Idempotency::dedupe_in_flight`] set, an identical request issued while
+//! one is still in flight isn't sent at all: it waits for the in-flight
+//! request and gets a copy of its response.
+
+use serde::{Deserialize, Serialize};
+
+/// The header most APIs use for idempotency keys.
+pub const DEFAULT_IDEMPOTENCY_HEADER: &str = "Idempotency-Key";
+
+/// Idempotency settings for an endpoint.
+///
+/// ## Examples
+///
+/// Use the conventional `Idempotency-Key` header:
+///
+/// ```
+/// use schematic_define::Idempotency;
+///
+/// let idempotency = Idempotency::default();
+/// assert_eq!(idempotency.header, "Idempotency-Key");
+/// assert!(!idempotency.dedupe_in_flight);
+/// ```
+///
+/// Use a custom header and coalesce identical in-flight requests:
+///
+/// ```
+/// use schematic_define::Idempotency;
+///
+/// let idempotency = Idempotency::with_header("X-Request-Key").dedupe_in_flight();
+/// assert_eq!(idempotency.header, "X-Request-Key");
+/// assert!(idempotency.dedupe_in_flight);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Idempotency {
+    /// Name of the header carrying the idempotency key.
+    pub header: String,
+    /// Coalesce a request with an identical one (same method, URL and body)
+    /// that is still in flight: only the first is sent, and every caller
+    /// gets a copy of its response.
+    pub dedupe_in_flight: bool,
+}
+
+impl Default for Idempotency {
+    fn default() -> Self {
+        Self::with_header(DEFAULT_IDEMPOTENCY_HEADER)
+    }
+}
+
+impl Idempotency {
+    /// Creates settings that send keys in the given header.
+    pub fn with_header(header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            dedupe_in_flight: false,
+        }
+    }
+
+    /// Sends identical requests that are in flight at the same time only
+    /// once, sharing the response between their callers.
+    pub fn dedupe_in_flight(mut self) -> Self {
+        self.dedupe_in_flight = true;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_uses_conventional_header_without_dedupe() {
+        let idempotency = Idempotency::default();
+        assert_eq!(idempotency.header, DEFAULT_IDEMPOTENCY_HEADER);
+        assert!(!idempotency.dedupe_in_flight);
+    }
+
+    #[test]
+    fn idempotency_serde_roundtrip() {
+        let idempotency = Idempotency::with_header("X-Key").dedupe_in_flight();
+        let json = serde_json::to_string(&idempotency).unwrap();
+        let parsed: Idempotency = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, idempotency);
+    }
+}
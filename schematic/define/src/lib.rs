@@ -10,6 +10,7 @@
 //!
 //! - [`RestApi`] - A complete REST API definition with base URL, auth, and endpoints
 //! - [`Endpoint`] - A single API endpoint with method, path, and schemas
+//! - [`EndpointExample`] - Example request/response payloads used for contract tests
 //! - [`RestMethod`] - HTTP methods (GET, POST, PUT, etc.)
 //! - [`AuthStrategy`] - Authentication strategies (Bearer, API Key, Basic, None)
 //! - [`UpdateStrategy`] - Strategy for updating auth in API variants (NoChange, ChangeTo)
@@ -18,6 +19,9 @@
 //! - [`FormField`] - Form field definitions for multipart and URL-encoded requests
 //! - [`FormFieldKind`] - Form field type classification (Text, File, Files, Json)
 //! - [`Schema`] - Type information for request/response bodies
+//! - [`Compression`] - Response decompression and request compression settings
+//! - [`ContentEncoding`] - Supported content encodings (gzip, brotli)
+//! - [`Idempotency`] - Idempotency key header and in-flight deduplication settings
 //!
 //! ### WebSocket API Types
 //!
@@ -29,6 +33,10 @@
 //! - [`MessageSchema`] - Single message type with direction and schema
 //! - [`MessageDirection`] - Message flow direction (Client, Server, Bidirectional)
 //!
+//! ### Runtime Support
+//!
+//! - [`sse`] - Incremental Server-Sent Events parser shared by streaming clients
+//!
 //! ## Examples
 //!
 //! Define a simple API with bearer token authentication:
@@ -54,10 +62,15 @@
 //!             request: None,
 //!             response: ApiResponse::json_type("ListModelsResponse"),
 //!             headers: vec![],
+//!             examples: vec![],
+//!             compression: None,
+//!             idempotency: None,
 //!         },
 //!     ],
 //!     module_path: None,
 //!     request_suffix: None,
+//!     usage_docs: false,
+//!     compression: None,
 //! };
 //!
 //! assert_eq!(api.name, "OpenAI");
@@ -70,15 +83,22 @@
 //! which uses these primitives to define real-world APIs.
 
 pub mod auth;
+pub mod compression;
+pub mod example;
+pub mod idempotency;
 pub mod prelude;
 pub mod request;
 pub mod response;
 pub mod schema;
+pub mod sse;
 pub mod types;
 pub mod websocket;
 
 // Re-export main types at crate root
 pub use auth::{AuthStrategy, UpdateStrategy};
+pub use compression::{Compression, ContentEncoding, RequestCompression};
+pub use example::EndpointExample;
+pub use idempotency::{DEFAULT_IDEMPOTENCY_HEADER, Idempotency};
 pub use request::{ApiRequest, FormField, FormFieldKind};
 pub use response::ApiResponse;
 pub use schema::{Schema, SchemaObject};
@@ -21,6 +21,8 @@
 //!     endpoints: vec![],
 //!     module_path: None,
 //!     request_suffix: None,
+//!     usage_docs: false,
+//!     compression: None,
 //! };
 //! ```
 //!
@@ -41,6 +43,9 @@
 //! ```
 
 pub use crate::auth::{AuthStrategy, UpdateStrategy};
+pub use crate::compression::{Compression, ContentEncoding, RequestCompression};
+pub use crate::example::EndpointExample;
+pub use crate::idempotency::Idempotency;
 pub use crate::request::{ApiRequest, FormField, FormFieldKind};
 pub use crate::response::ApiResponse;
 pub use crate::schema::{Schema, SchemaObject};
@@ -729,6 +729,9 @@ mod tests {
                     request: Some(ApiRequest::json_type("CreateFolderRequest")),
                     response: ApiResponse::json_type("Folder"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 // FormData endpoint with file upload
                 Endpoint {
@@ -745,6 +748,9 @@ mod tests {
                     ])),
                     response: ApiResponse::json_type("File"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 // GET endpoint with no request body
                 Endpoint {
@@ -755,6 +761,9 @@ mod tests {
                     request: None,
                     response: ApiResponse::json_type("ListFilesResponse"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 // Binary download
                 Endpoint {
@@ -765,10 +774,15 @@ mod tests {
                     request: None,
                     response: ApiResponse::Binary,
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
             ],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         };
 
         assert_eq!(api.name, "FileService");
@@ -0,0 +1,277 @@
+//! Server-Sent Events parsing.
+//!
+//! Streaming endpoints (chat completions, text-to-speech progress, etc.)
+//! deliver their payloads as a `text/event-stream`. This module turns the raw
+//! bytes of such a stream into [`SseEvent`]s, following the
+//! [HTML event stream format](https://html.spec.whatwg.org/multipage/server-sent-events.html#event-stream-interpretation):
+//!
+//! - Lines end with `\n`, `\r\n` or a lone `\r`, even when a terminator is
+//!   split across chunks
+//! - `data:` lines accumulate until a blank line dispatches the event
+//! - `event:` names the event and `id:` sets the last event ID, which is
+//!   kept for later events
+//! - `retry:` sets the reconnection delay (see [`SseParser::retry`])
+//! - Lines starting with `:` are comments (typically keep-alives) and are
+//!   ignored, as are unknown fields and a leading byte order mark
+//!
+//! The parser is incremental and does no I/O, so it can be fed from any
+//! byte stream: a `reqwest` response, a WebSocket frame, or a test fixture.
+//!
+//! ## Examples
+//!
+//! ```
+//! use schematic_define::sse::SseParser;
+//!
+//! let mut parser = SseParser::default();
+//! assert!(parser.push(b"event: delta\ndata: {\"text\":").is_empty());
+//!
+//! let events = parser.push(b" \"Hi\"}\n\n");
+//! assert_eq!(events.len(), 1);
+//! assert_eq!(events[0].event.as_deref(), Some("delta"));
+//! assert_eq!(events[0].data, "{\"text\": \"Hi\"}");
+//! ```
+
+use std::time::Duration;
+
+/// UTF-8 byte order mark, ignored at the start of a stream.
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// A single Server-Sent Event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SseEvent {
+    /// The `event:` field, if any
+    pub event: Option<String>,
+    /// The `data:` lines joined with newlines
+    pub data: String,
+    /// The last event ID seen on the stream when this event was dispatched
+    pub id: Option<String>,
+}
+
+/// Incremental Server-Sent Events parser.
+///
+/// Feed it raw bytes as they arrive; complete events are returned as soon as
+/// their terminating blank line has been seen.
+#[derive(Debug, Default)]
+pub struct SseParser {
+    buffer: Vec<u8>,
+    started: bool,
+    after_cr: bool,
+    event: Option<String>,
+    data: Vec<String>,
+    last_event_id: Option<String>,
+    retry: Option<Duration>,
+}
+
+impl SseParser {
+    /// Adds bytes to the parser and returns the events they complete.
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<SseEvent> {
+        if bytes.is_empty() {
+            return Vec::new();
+        }
+
+        // A `\r` ending the previous chunk may be the first half of `\r\n`
+        let bytes = match (self.after_cr, bytes.strip_prefix(b"\n")) {
+            (true, Some(rest)) => rest,
+            _ => bytes,
+        };
+        self.after_cr = false;
+        self.buffer.extend_from_slice(bytes);
+
+        if !self.started {
+            if self.buffer.len() < BOM.len() && BOM.starts_with(&self.buffer) {
+                return Vec::new();
+            }
+            if self.buffer.starts_with(BOM) {
+                self.buffer.drain(..BOM.len());
+            }
+            self.started = true;
+        }
+
+        let mut events = Vec::new();
+        let mut start = 0;
+        while let Some(offset) = self.buffer[start..]
+            .iter()
+            .position(|&b| b == b'\n' || b == b'\r')
+        {
+            let end = start + offset;
+            let line = String::from_utf8_lossy(&self.buffer[start..end]).into_owned();
+            start = end + 1;
+            if self.buffer[end] == b'\r' {
+                match self.buffer.get(start) {
+                    Some(b'\n') => start += 1,
+                    Some(_) => {}
+                    None => self.after_cr = true,
+                }
+            }
+            if let Some(event) = self.process_line(&line) {
+                events.push(event);
+            }
+        }
+        self.buffer.drain(..start);
+
+        events
+    }
+
+    /// Flushes a trailing event that was not terminated by a blank line.
+    ///
+    /// Browsers discard such an event, but some servers omit the final blank
+    /// line, so it is returned rather than lost.
+    pub fn finish(&mut self) -> Option<SseEvent> {
+        if !self.buffer.is_empty() {
+            let line = String::from_utf8_lossy(&std::mem::take(&mut self.buffer)).into_owned();
+            self.process_line(&line);
+        }
+        self.after_cr = false;
+        self.dispatch()
+    }
+
+    /// The last event ID the server sent, to resume from when reconnecting
+    /// (the `Last-Event-ID` header).
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// How long the server asked clients to wait before reconnecting, if it
+    /// sent a `retry:` field.
+    pub fn retry(&self) -> Option<Duration> {
+        self.retry
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => self.data.push(value.to_string()),
+            // IDs containing NUL are ignored; an empty ID resets it
+            "id" if !value.contains('\0') => {
+                self.last_event_id = (!value.is_empty()).then(|| value.to_string());
+            }
+            "retry" if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+                if let Ok(millis) = value.parse() {
+                    self.retry = Some(Duration::from_millis(millis));
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        if self.data.is_empty() {
+            self.event = None;
+            return None;
+        }
+        Some(SseEvent {
+            event: self.event.take(),
+            data: std::mem::take(&mut self.data).join("\n"),
+            id: self.last_event_id.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_all(chunks: &[&[u8]]) -> Vec<SseEvent> {
+        let mut parser = SseParser::default();
+        let mut events: Vec<_> = chunks.iter().flat_map(|c| parser.push(c)).collect();
+        events.extend(parser.finish());
+        events
+    }
+
+    fn data(events: &[SseEvent]) -> Vec<&str> {
+        events.iter().map(|e| e.data.as_str()).collect()
+    }
+
+    #[test]
+    fn test_split_chunks_and_comments() {
+        let mut parser = SseParser::default();
+        assert!(parser.push(b"event: ping\r\ndata: {\"a\"").is_empty());
+        assert!(parser.push(b": 1}\n: comment\n").is_empty());
+
+        let events = parser.push(b"\ndata: second\ndata: line\n\n");
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event: Some("ping".to_string()),
+                    data: "{\"a\": 1}".to_string(),
+                    id: None,
+                },
+                SseEvent {
+                    event: None,
+                    data: "second\nline".to_string(),
+                    id: None,
+                },
+            ]
+        );
+
+        parser.push(b"data: trailing");
+        assert_eq!(parser.finish().unwrap().data, "trailing");
+    }
+
+    #[test]
+    fn test_line_endings() {
+        let events = parse_all(&[b"data: lf\n\ndata: cr\r\rdata: crlf\r\n\r\n"]);
+        assert_eq!(data(&events), ["lf", "cr", "crlf"]);
+
+        // A `\r\n` split across chunks is one line ending, not two
+        let events = parse_all(&[b"data: a\r", b"\ndata: b\r", b"\n\r", b"\n"]);
+        assert_eq!(data(&events), ["a\nb"]);
+    }
+
+    #[test]
+    fn test_ids_persist_until_reset() {
+        let events = parse_all(&[
+            b"id: 1\ndata: one\n\ndata: two\n\nid\ndata: three\n\nid: a\0b\ndata: four\n\n",
+        ]);
+        let ids: Vec<_> = events.iter().map(|e| e.id.as_deref()).collect();
+        assert_eq!(ids, [Some("1"), Some("1"), None, None]);
+    }
+
+    #[test]
+    fn test_retry_and_last_event_id() {
+        let mut parser = SseParser::default();
+        parser.push(b"retry: 2500\nid: 42\n\n");
+        assert_eq!(parser.retry(), Some(Duration::from_millis(2500)));
+        assert_eq!(parser.last_event_id(), Some("42"));
+
+        // Non-numeric values are ignored
+        parser.push(b"retry: 1s\nretry: -5\nretry:\n\n");
+        assert_eq!(parser.retry(), Some(Duration::from_millis(2500)));
+    }
+
+    #[test]
+    fn test_events_without_data_are_not_dispatched() {
+        let events = parse_all(&[b"event: ping\n\ndata\n\nevent: update\ndata:\n\n"]);
+        assert_eq!(
+            events,
+            [
+                SseEvent::default(),
+                SseEvent {
+                    event: Some("update".to_string()),
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_byte_order_mark_is_skipped() {
+        let events = parse_all(&[b"\xEF\xBB", b"\xBFdata: x\n\n"]);
+        assert_eq!(data(&events), ["x"]);
+
+        // Only at the start of the stream
+        let events = parse_all(&[b"data: x\n\n\xEF\xBB\xBFdata: y\n\n"]);
+        assert_eq!(events.len(), 1);
+    }
+}
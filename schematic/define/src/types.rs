@@ -10,6 +10,9 @@ use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, EnumString};
 
 use crate::auth::AuthStrategy;
+use crate::compression::Compression;
+use crate::example::EndpointExample;
+use crate::idempotency::Idempotency;
 use crate::request::ApiRequest;
 use crate::response::ApiResponse;
 
@@ -100,10 +103,15 @@ pub enum RestMethod {
 ///             request: None,
 ///             response: ApiResponse::json_type("HealthResponse"),
 ///             headers: vec![],
+///             examples: vec![],
+///             compression: None,
+///             idempotency: None,
 ///         },
 ///     ],
 ///     module_path: None,
 ///     request_suffix: None,
+///     usage_docs: false,
+///     compression: None,
 /// };
 ///
 /// assert_eq!(api.name, "SimpleApi");
@@ -159,6 +167,18 @@ pub struct RestApi {
     /// This allows APIs to customize the naming of request structs. For example,
     /// using "Params" would generate `ListModelsParams` instead of `ListModelsRequest`.
     pub request_suffix: Option<String>,
+    /// Whether to generate a `docs` module with Markdown usage documentation
+    /// for each endpoint (request constructor, sample call, authentication).
+    ///
+    /// The Markdown is exposed as string constants, so it can be rendered
+    /// with darkmatter or published alongside the client, and is also
+    /// attached to those constants as rustdoc.
+    pub usage_docs: bool,
+    /// Content-encoding settings for all endpoints (defaults to
+    /// [`Compression::default()`]: decompress responses, send bodies as-is).
+    ///
+    /// Individual endpoints can override this via [`Endpoint::compression`].
+    pub compression: Option<Compression>,
 }
 
 /// A single API endpoint definition.
@@ -186,6 +206,9 @@ pub struct RestApi {
 ///     request: None,
 ///     response: ApiResponse::json_type("User"),
 ///     headers: vec![],
+///     examples: vec![],
+///     compression: None,
+///     idempotency: None,
 /// };
 ///
 /// assert!(endpoint.path.contains("{user_id}"));
@@ -204,6 +227,9 @@ pub struct RestApi {
 ///     request: Some(ApiRequest::json_type("CreateUserRequest")),
 ///     response: ApiResponse::json_type("User"),
 ///     headers: vec![],
+///     examples: vec![],
+///     compression: None,
+///     idempotency: None,
 /// };
 ///
 /// assert!(endpoint.request.is_some());
@@ -225,6 +251,9 @@ pub struct RestApi {
 ///     ])),
 ///     response: ApiResponse::json_type("FileUploadResponse"),
 ///     headers: vec![],
+///     examples: vec![],
+///     compression: None,
+///     idempotency: None,
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -258,6 +287,22 @@ pub struct Endpoint {
     /// headers: vec![("anthropic-beta".to_string(), "message-batches-2024-09-24".to_string())]
     /// ```
     pub headers: Vec<(String, String)>,
+    /// Example request/response payloads for this endpoint.
+    ///
+    /// `schematic-gen` emits a contract test per example that checks the
+    /// payloads (de)serialize through the declared request and response types.
+    pub examples: Vec<EndpointExample>,
+    /// Endpoint-specific content-encoding settings.
+    ///
+    /// When set, replaces the API-level [`RestApi::compression`] for this
+    /// endpoint (e.g., to compress large upload bodies, or to disable
+    /// compression for a streaming endpoint).
+    pub compression: Option<Compression>,
+    /// Idempotency key settings (`None` sends no idempotency header).
+    ///
+    /// Set this for endpoints with side effects whose API accepts an
+    /// idempotency key, so retried requests aren't applied twice.
+    pub idempotency: Option<Idempotency>,
 }
 
 #[cfg(test)]
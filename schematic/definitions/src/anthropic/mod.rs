@@ -90,6 +90,9 @@ pub fn define_anthropic_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateMessageBody")),
                 response: ApiResponse::json_type("MessageResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             // Token counting for cost estimation
             Endpoint {
@@ -100,6 +103,9 @@ pub fn define_anthropic_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CountTokensBody")),
                 response: ApiResponse::json_type("CountTokensResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             // Model discovery
             Endpoint {
@@ -110,6 +116,9 @@ pub fn define_anthropic_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ListModelsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "RetrieveModel".to_string(),
@@ -119,10 +128,15 @@ pub fn define_anthropic_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ModelInfo"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
         ],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -91,6 +91,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateSpeechBody")),
                 response: ApiResponse::Binary,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "StreamSpeech".to_string(),
@@ -100,6 +103,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateSpeechBody")),
                 response: ApiResponse::Binary,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "CreateSpeechWithTimestamps".to_string(),
@@ -109,6 +115,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateSpeechBody")),
                 response: ApiResponse::json_type("SpeechWithTimestampsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "StreamSpeechWithTimestamps".to_string(),
@@ -118,6 +127,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateSpeechBody")),
                 response: ApiResponse::json_type("SpeechWithTimestampsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -131,6 +143,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ListVoicesResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetVoice".to_string(),
@@ -140,6 +155,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("VoiceResponseModel"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "DeleteVoice".to_string(),
@@ -149,6 +167,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -162,6 +183,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("VoiceSettings"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetVoiceSettings".to_string(),
@@ -171,6 +195,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("VoiceSettings"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "UpdateVoiceSettings".to_string(),
@@ -180,6 +207,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("VoiceSettings")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -193,6 +223,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::Binary,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "DeleteVoiceSample".to_string(),
@@ -202,6 +235,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "AddVoiceSample".to_string(),
@@ -217,6 +253,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 ])),
                 response: ApiResponse::json_type("AddSampleResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -230,6 +269,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ListSharedVoicesResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "AddSharedVoice".to_string(),
@@ -239,6 +281,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("AddSharedVoiceBody")),
                 response: ApiResponse::json_type("AddSharedVoiceResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -252,6 +297,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreatePvcVoiceBody")),
                 response: ApiResponse::json_type("AddSharedVoiceResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "UpdatePvcVoice".to_string(),
@@ -261,6 +309,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreatePvcVoiceBody")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "TrainPvcVoice".to_string(),
@@ -270,6 +321,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("TrainPvcVoiceBody")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -283,6 +337,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateSoundEffectBody")),
                 response: ApiResponse::Binary,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -296,6 +353,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<ModelInfo>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -309,6 +369,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("SingleUseTokenResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -322,6 +385,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("GetHistoryResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetHistoryItem".to_string(),
@@ -331,6 +397,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("SpeechHistoryItemResponseModel"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "DeleteHistoryItem".to_string(),
@@ -340,6 +409,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetHistoryItemAudio".to_string(),
@@ -349,6 +421,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::Binary,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "DownloadHistoryItems".to_string(),
@@ -358,6 +433,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("DownloadHistoryBody")),
                 response: ApiResponse::Binary,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -371,6 +449,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("UsageStatsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -384,6 +465,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("UserResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetUserSubscription".to_string(),
@@ -393,6 +477,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("SubscriptionModel"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -406,6 +493,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ResourceResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ShareResource".to_string(),
@@ -415,6 +505,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("ShareResourceBody")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "UnshareResource".to_string(),
@@ -424,6 +517,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("UnshareResourceBody")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "CopyResourceToWorkspace".to_string(),
@@ -433,6 +529,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CopyResourceBody")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -446,6 +545,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ListServiceAccountsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ListServiceAccountApiKeys".to_string(),
@@ -455,6 +557,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ListApiKeysResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "CreateApiKey".to_string(),
@@ -464,6 +569,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateApiKeyBody")),
                 response: ApiResponse::json_type("CreateApiKeyResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "UpdateApiKey".to_string(),
@@ -473,6 +581,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("UpdateApiKeyBody")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "DeleteApiKey".to_string(),
@@ -482,6 +593,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -495,6 +609,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ListWebhooksResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "CreateWebhook".to_string(),
@@ -504,6 +621,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateWebhookBody")),
                 response: ApiResponse::json_type("CreateWebhookResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "UpdateWebhook".to_string(),
@@ -513,6 +633,9 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: Some(ApiRequest::json_type("UpdateWebhookBody")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "DeleteWebhook".to_string(),
@@ -522,10 +645,15 @@ pub fn define_elevenlabs_rest_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
         ],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
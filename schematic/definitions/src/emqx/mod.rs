@@ -143,6 +143,8 @@ pub fn define_emqx_basic_api() -> RestApi {
         endpoints: build_common_endpoints(),
         module_path: Some("emqx".to_string()),
         request_suffix: Some("BasicRequest".to_string()),
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -196,6 +198,9 @@ pub fn define_emqx_bearer_api() -> RestApi {
             request: Some(ApiRequest::json_type("LoginBody")),
             response: ApiResponse::json_type("LoginResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // Logout endpoint
         Endpoint {
@@ -206,6 +211,9 @@ pub fn define_emqx_bearer_api() -> RestApi {
             request: None,
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
     ];
     endpoints.extend(build_common_endpoints());
@@ -222,6 +230,8 @@ pub fn define_emqx_bearer_api() -> RestApi {
         endpoints,
         module_path: Some("emqx".to_string()),
         request_suffix: Some("BearerRequest".to_string()),
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -239,6 +249,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListNodesResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "GetNode".to_string(),
@@ -248,6 +261,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("NodeInfo"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "GetCluster".to_string(),
@@ -257,6 +273,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ClusterStatus"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Client Endpoints
@@ -269,6 +288,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListClientsResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "GetClient".to_string(),
@@ -278,6 +300,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ClientInfo"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "DisconnectClient".to_string(),
@@ -287,6 +312,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "SubscribeClient".to_string(),
@@ -296,6 +324,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: Some(ApiRequest::json_type("SubscribeBody")),
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "UnsubscribeClient".to_string(),
@@ -305,6 +336,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: Some(ApiRequest::json_type("SubscribeBody")),
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Subscription Endpoints
@@ -317,6 +351,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListSubscriptionsResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Publishing Endpoints
@@ -329,6 +366,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: Some(ApiRequest::json_type("PublishBody")),
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "PublishBulk".to_string(),
@@ -338,6 +378,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: Some(ApiRequest::json_type("PublishBatchBody")),
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Rules Engine Endpoints
@@ -350,6 +393,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListRulesResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "CreateRule".to_string(),
@@ -359,6 +405,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: Some(ApiRequest::json_type("CreateRuleBody")),
             response: ApiResponse::json_type("RuleInfo"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "GetRule".to_string(),
@@ -368,6 +417,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("RuleInfo"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "UpdateRule".to_string(),
@@ -377,6 +429,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: Some(ApiRequest::json_type("CreateRuleBody")),
             response: ApiResponse::json_type("RuleInfo"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "DeleteRule".to_string(),
@@ -386,6 +441,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "TestRule".to_string(),
@@ -395,6 +453,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: Some(ApiRequest::json_type("TestRuleBody")),
             response: ApiResponse::json_type("TestRuleResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Authentication Endpoints
@@ -407,6 +468,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListAuthenticatorsResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "GetAuthenticator".to_string(),
@@ -416,6 +480,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("AuthenticatorInfo"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "ListAuthUsers".to_string(),
@@ -425,6 +492,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("Vec<AuthUser>"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "CreateAuthUser".to_string(),
@@ -434,6 +504,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: Some(ApiRequest::json_type("CreateAuthUserBody")),
             response: ApiResponse::json_type("AuthUser"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "DeleteAuthUser".to_string(),
@@ -443,6 +516,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Authorization Endpoints
@@ -455,6 +531,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListAuthzSourcesResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Listener Endpoints
@@ -467,6 +546,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListListenersResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "GetListener".to_string(),
@@ -476,6 +558,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListenerInfo"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Metrics & Stats Endpoints
@@ -488,6 +573,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListMetricsResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "ListStats".to_string(),
@@ -497,6 +585,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListStatsResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "GetPrometheus".to_string(),
@@ -506,6 +597,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::Text,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Topics Endpoints
@@ -518,6 +612,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListTopicsResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Retained Messages Endpoints
@@ -530,6 +627,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListRetainedResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "GetRetained".to_string(),
@@ -539,6 +639,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("RetainedMessage"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "DeleteRetained".to_string(),
@@ -548,6 +651,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Alarms Endpoints
@@ -560,6 +666,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListAlarmsResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         // =====================================================================
         // Banned Clients Endpoints
@@ -572,6 +681,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::json_type("ListBannedResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "CreateBan".to_string(),
@@ -581,6 +693,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: Some(ApiRequest::json_type("CreateBanBody")),
             response: ApiResponse::json_type("BanInfo"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
         Endpoint {
             id: "DeleteBan".to_string(),
@@ -590,6 +705,9 @@ fn build_common_endpoints() -> Vec<Endpoint> {
             request: None,
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         },
     ]
 }
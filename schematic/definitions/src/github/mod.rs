@@ -0,0 +1,304 @@
+//! GitHub REST API definition.
+//!
+//! This module defines the subset of the GitHub REST API the ecosystem uses
+//! to inspect repositories: repository details, releases, the README, issues,
+//! repository contents and the caller's rate limit.
+//!
+//! ## Endpoints
+//!
+//! ### Repositories (3)
+//! - `GetRepo` - GET /repos/{owner}/{repo}
+//! - `GetReadme` - GET /repos/{owner}/{repo}/readme
+//! - `GetContents` - GET /repos/{owner}/{repo}/contents/{path}
+//!
+//! ### Releases (3)
+//! - `ListReleases` - GET /repos/{owner}/{repo}/releases
+//! - `GetLatestRelease` - GET /repos/{owner}/{repo}/releases/latest
+//! - `GetReleaseByTag` - GET /repos/{owner}/{repo}/releases/tags/{tag}
+//!
+//! ### Issues (1)
+//! - `ListIssues` - GET /repos/{owner}/{repo}/issues
+//!
+//! ### Rate Limit (1)
+//! - `GetRateLimit` - GET /rate_limit
+//!
+//! ## Rate Limits
+//!
+//! GitHub allows 5,000 requests an hour with a token. Requests rejected by
+//! the rate limit fail with `SchematicError::RateLimited` in the generated
+//! client, carrying the time until the limit resets; `GetRateLimit` reports
+//! the remaining quota without using it.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use schematic_definitions::github::define_github_api;
+//!
+//! let api = define_github_api();
+//! assert_eq!(api.name, "GitHub");
+//! assert_eq!(api.endpoints.len(), 8);
+//! ```
+
+mod types;
+
+pub use types::*;
+
+use schematic_define::{ApiResponse, AuthStrategy, Endpoint, EndpointExample, RestApi, RestMethod};
+
+/// Creates the GitHub REST API definition.
+///
+/// Requests authenticate with a bearer token from `GITHUB_TOKEN` or
+/// `GH_TOKEN` and pin API version `2022-11-28`. GitHub rejects requests
+/// without a `User-Agent`, so one is sent by default.
+///
+/// ## Endpoints
+///
+/// - **Repositories**: 3 endpoints (get, readme, contents)
+/// - **Releases**: 3 endpoints (list, latest, by tag)
+/// - **Issues**: 1 endpoint (list)
+/// - **Rate Limit**: 1 endpoint (get)
+///
+/// ## Examples
+///
+/// ```rust
+/// use schematic_definitions::github::define_github_api;
+///
+/// let api = define_github_api();
+/// assert_eq!(api.base_url, "https://api.github.com");
+/// assert_eq!(api.module_path.as_deref(), Some("github"));
+/// ```
+pub fn define_github_api() -> RestApi {
+    RestApi {
+        name: "GitHub".to_string(),
+        description: "GitHub REST API for repository, release and issue inspection".to_string(),
+        base_url: "https://api.github.com".to_string(),
+        docs_url: Some("https://docs.github.com/en/rest".to_string()),
+        auth: AuthStrategy::BearerToken { header: None },
+        env_auth: vec!["GITHUB_TOKEN".to_string(), "GH_TOKEN".to_string()],
+        env_username: None,
+        headers: vec![
+            ("Accept".to_string(), "application/vnd.github+json".to_string()),
+            ("X-GitHub-Api-Version".to_string(), "2022-11-28".to_string()),
+            ("User-Agent".to_string(), "schematic".to_string()),
+        ],
+        endpoints: vec![
+            // =================================================================
+            // Repositories
+            // =================================================================
+            Endpoint {
+                id: "GetRepo".to_string(),
+                method: RestMethod::Get,
+                path: "/repos/{owner}/{repo}".to_string(),
+                description: "Gets a repository's details, including stars and open issue count".to_string(),
+                request: None,
+                response: ApiResponse::json_type("Repository"),
+                headers: vec![],
+                examples: vec![EndpointExample::response(
+                    "docs",
+                    r#"{"id": 46409563, "name": "clap", "full_name": "clap-rs/clap", "owner": {"login": "clap-rs", "id": 13536646, "avatar_url": "https://avatars.githubusercontent.com/u/13536646?v=4", "html_url": "https://github.com/clap-rs", "type": "Organization"}, "private": false, "html_url": "https://github.com/clap-rs/clap", "description": "A full featured, fast Command Line Argument Parser for Rust", "fork": false, "homepage": "docs.rs/clap", "language": "Rust", "stargazers_count": 15000, "forks_count": 1100, "open_issues_count": 350, "default_branch": "master", "topics": ["argument-parser", "cli", "rust"], "license": {"key": "apache-2.0", "name": "Apache License 2.0", "spdx_id": "Apache-2.0"}, "archived": false, "created_at": "2015-02-24T21:51:46Z", "updated_at": "2025-06-01T12:00:00Z", "pushed_at": "2025-06-01T11:00:00Z"}"#,
+                )],
+                compression: None,
+                idempotency: None,
+            },
+            Endpoint {
+                id: "GetReadme".to_string(),
+                method: RestMethod::Get,
+                path: "/repos/{owner}/{repo}/readme".to_string(),
+                description: "Gets the raw Markdown of a repository's preferred README".to_string(),
+                request: None,
+                response: ApiResponse::Text,
+                headers: vec![(
+                    "Accept".to_string(),
+                    "application/vnd.github.raw+json".to_string(),
+                )],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
+            },
+            Endpoint {
+                id: "GetContents".to_string(),
+                method: RestMethod::Get,
+                path: "/repos/{owner}/{repo}/contents/{path}".to_string(),
+                description: "Gets a file (with base64 content) or a directory listing from the default branch. Query params: ref".to_string(),
+                request: None,
+                response: ApiResponse::json_type("Contents"),
+                headers: vec![],
+                examples: vec![EndpointExample::response(
+                    "docs",
+                    r#"{"type": "file", "name": "CHANGELOG.md", "path": "CHANGELOG.md", "sha": "3d21ec53a331a6f037a91c368710b99387d012c1", "size": 20, "html_url": "https://github.com/clap-rs/clap/blob/master/CHANGELOG.md", "download_url": "https://raw.githubusercontent.com/clap-rs/clap/master/CHANGELOG.md", "content": "IyBDaGFuZ2Vsb2cKCiMjIDQuNQo=", "encoding": "base64"}"#,
+                )],
+                compression: None,
+                idempotency: None,
+            },
+            // =================================================================
+            // Releases
+            // =================================================================
+            Endpoint {
+                id: "ListReleases".to_string(),
+                method: RestMethod::Get,
+                path: "/repos/{owner}/{repo}/releases".to_string(),
+                description: "Lists published releases, newest first. Query params: per_page, page".to_string(),
+                request: None,
+                response: ApiResponse::json_type("Vec<Release>"),
+                headers: vec![],
+                examples: vec![EndpointExample::response(
+                    "docs",
+                    r#"[{"id": 1, "tag_name": "v4.5.0", "name": "v4.5.0", "body": "- Styled help output\n- New `ArgAction::Count`", "draft": false, "prerelease": false, "html_url": "https://github.com/clap-rs/clap/releases/tag/v4.5.0", "created_at": "2024-02-08T15:00:00Z", "published_at": "2024-02-08T15:10:00Z", "author": {"login": "epage", "id": 60961, "html_url": "https://github.com/epage", "type": "User"}}]"#,
+                )],
+                compression: None,
+                idempotency: None,
+            },
+            Endpoint {
+                id: "GetLatestRelease".to_string(),
+                method: RestMethod::Get,
+                path: "/repos/{owner}/{repo}/releases/latest".to_string(),
+                description: "Gets the latest published full release (not a draft or pre-release)".to_string(),
+                request: None,
+                response: ApiResponse::json_type("Release"),
+                headers: vec![],
+                examples: vec![EndpointExample::response(
+                    "docs",
+                    r#"{"id": 1, "tag_name": "v4.5.0", "name": "v4.5.0", "body": "- Styled help output", "draft": false, "prerelease": false, "html_url": "https://github.com/clap-rs/clap/releases/tag/v4.5.0", "created_at": "2024-02-08T15:00:00Z", "published_at": "2024-02-08T15:10:00Z"}"#,
+                )],
+                compression: None,
+                idempotency: None,
+            },
+            Endpoint {
+                id: "GetReleaseByTag".to_string(),
+                method: RestMethod::Get,
+                path: "/repos/{owner}/{repo}/releases/tags/{tag}".to_string(),
+                description: "Gets the release created from a tag".to_string(),
+                request: None,
+                response: ApiResponse::json_type("Release"),
+                headers: vec![],
+                examples: vec![EndpointExample::response(
+                    "docs",
+                    r#"{"id": 2, "tag_name": "v4.4.0", "name": null, "body": null, "draft": false, "prerelease": false, "html_url": "https://github.com/clap-rs/clap/releases/tag/v4.4.0", "created_at": "2023-08-24T15:00:00Z", "published_at": "2023-08-24T15:10:00Z"}"#,
+                )],
+                compression: None,
+                idempotency: None,
+            },
+            // =================================================================
+            // Issues
+            // =================================================================
+            Endpoint {
+                id: "ListIssues".to_string(),
+                method: RestMethod::Get,
+                path: "/repos/{owner}/{repo}/issues".to_string(),
+                description: "Lists open issues and pull requests, most recently created first. Query params: state, labels, sort, direction, since, per_page, page".to_string(),
+                request: None,
+                response: ApiResponse::json_type("Vec<Issue>"),
+                headers: vec![],
+                examples: vec![EndpointExample::response(
+                    "docs",
+                    r#"[{"id": 2001, "number": 5900, "title": "Panic when combining flags", "state": "open", "body": "Steps to reproduce...", "user": {"login": "octocat", "id": 583231, "html_url": "https://github.com/octocat", "type": "User"}, "labels": [{"id": 7, "name": "C-bug", "color": "d73a4a", "description": "Something isn't working"}], "comments": 3, "html_url": "https://github.com/clap-rs/clap/issues/5900", "created_at": "2025-05-01T10:00:00Z", "updated_at": "2025-05-02T10:00:00Z", "closed_at": null}]"#,
+                )],
+                compression: None,
+                idempotency: None,
+            },
+            // =================================================================
+            // Rate Limit
+            // =================================================================
+            Endpoint {
+                id: "GetRateLimit".to_string(),
+                method: RestMethod::Get,
+                path: "/rate_limit".to_string(),
+                description: "Gets the caller's remaining request quota; does not count against it".to_string(),
+                request: None,
+                response: ApiResponse::json_type("RateLimitResponse"),
+                headers: vec![],
+                examples: vec![EndpointExample::response(
+                    "docs",
+                    r#"{"resources": {"core": {"limit": 5000, "remaining": 4999, "reset": 1717243200, "used": 1}, "search": {"limit": 30, "remaining": 30, "reset": 1717239660, "used": 0}, "graphql": {"limit": 5000, "remaining": 5000, "reset": 1717243200, "used": 0}}, "rate": {"limit": 5000, "remaining": 4999, "reset": 1717243200, "used": 1}}"#,
+                )],
+                compression: None,
+                idempotency: None,
+            },
+        ],
+        // "GitHub" would otherwise infer the module "git" from its "Hub" suffix
+        module_path: Some("github".to_string()),
+        request_suffix: None,
+        usage_docs: true,
+        compression: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_has_correct_metadata() {
+        let api = define_github_api();
+
+        assert_eq!(api.name, "GitHub");
+        assert_eq!(api.base_url, "https://api.github.com");
+        assert!(api.docs_url.is_some());
+        assert_eq!(api.module_path.as_deref(), Some("github"));
+    }
+
+    #[test]
+    fn api_uses_bearer_auth_from_github_token() {
+        let api = define_github_api();
+
+        assert!(matches!(
+            api.auth,
+            AuthStrategy::BearerToken { header: None }
+        ));
+        assert_eq!(api.env_auth, vec!["GITHUB_TOKEN", "GH_TOKEN"]);
+    }
+
+    #[test]
+    fn api_sends_required_headers() {
+        let api = define_github_api();
+        let header = |name: &str| {
+            api.headers
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        assert_eq!(header("Accept"), Some("application/vnd.github+json"));
+        assert_eq!(header("X-GitHub-Api-Version"), Some("2022-11-28"));
+        assert!(header("User-Agent").is_some());
+    }
+
+    #[test]
+    fn endpoints_are_read_only() {
+        let api = define_github_api();
+
+        assert_eq!(api.endpoints.len(), 8);
+        for endpoint in &api.endpoints {
+            assert_eq!(endpoint.method, RestMethod::Get, "{}", endpoint.id);
+            assert!(endpoint.request.is_none(), "{}", endpoint.id);
+        }
+    }
+
+    #[test]
+    fn json_endpoints_have_response_examples() {
+        let api = define_github_api();
+
+        for endpoint in api.endpoints.iter().filter(|e| e.response.is_json()) {
+            assert!(
+                endpoint.examples.iter().any(|e| e.response.is_some()),
+                "{} should have a response example",
+                endpoint.id
+            );
+        }
+    }
+
+    #[test]
+    fn readme_is_requested_as_raw_markdown() {
+        let api = define_github_api();
+        let endpoint = api.endpoints.iter().find(|e| e.id == "GetReadme").unwrap();
+
+        assert!(matches!(endpoint.response, ApiResponse::Text));
+        assert_eq!(
+            endpoint.headers,
+            vec![(
+                "Accept".to_string(),
+                "application/vnd.github.raw+json".to_string()
+            )]
+        );
+    }
+}
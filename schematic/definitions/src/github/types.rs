@@ -0,0 +1,353 @@
+//! GitHub REST API types.
+//!
+//! This module contains the subset of GitHub's response types used by the
+//! ecosystem: repositories, releases, issues, repository contents and rate
+//! limits. Fields GitHub returns but nothing here needs are omitted; serde
+//! ignores them when deserializing.
+
+use serde::{Deserialize, Serialize};
+
+// =============================================================================
+// Shared Types
+// =============================================================================
+
+/// A GitHub user or organization, as embedded in other objects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    /// The account's login name.
+    pub login: String,
+    /// The account's numeric ID.
+    pub id: u64,
+    /// URL of the account's avatar image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// URL of the account's profile page.
+    pub html_url: String,
+    /// Account type: "User", "Organization" or "Bot".
+    #[serde(rename = "type")]
+    pub account_type: String,
+}
+
+/// A repository's license, as detected by GitHub.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct License {
+    /// Lowercase license key (e.g., "mit", "apache-2.0").
+    pub key: String,
+    /// Full license name.
+    pub name: String,
+    /// SPDX identifier (e.g., "MIT"), or "NOASSERTION" when unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spdx_id: Option<String>,
+}
+
+// =============================================================================
+// Repositories
+// =============================================================================
+
+/// A GitHub repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Repository {
+    /// The repository's numeric ID.
+    pub id: u64,
+    /// Repository name without the owner (e.g., "clap").
+    pub name: String,
+    /// Owner and name (e.g., "clap-rs/clap").
+    pub full_name: String,
+    /// The owning user or organization.
+    pub owner: User,
+    /// Whether the repository is private.
+    pub private: bool,
+    /// URL of the repository's page.
+    pub html_url: String,
+    /// Short description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the repository is a fork.
+    pub fork: bool,
+    /// Project homepage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    /// Primary language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Number of stars.
+    pub stargazers_count: u64,
+    /// Number of forks.
+    pub forks_count: u64,
+    /// Number of open issues, including open pull requests.
+    pub open_issues_count: u64,
+    /// The default branch (e.g., "main").
+    pub default_branch: String,
+    /// Repository topics.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<String>,
+    /// The detected license.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<License>,
+    /// Whether the repository is archived (read-only).
+    #[serde(default)]
+    pub archived: bool,
+    /// ISO 8601 timestamp of when the repository was created.
+    pub created_at: String,
+    /// ISO 8601 timestamp of the last change to the repository object.
+    pub updated_at: String,
+    /// ISO 8601 timestamp of the last push.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pushed_at: Option<String>,
+}
+
+// =============================================================================
+// Releases
+// =============================================================================
+
+/// A release published from a tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Release {
+    /// The release's numeric ID.
+    pub id: u64,
+    /// The tag the release was created from (e.g., "v4.5.0").
+    pub tag_name: String,
+    /// Release title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Release notes (Markdown).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Whether the release is an unpublished draft.
+    pub draft: bool,
+    /// Whether the release is marked as a pre-release.
+    pub prerelease: bool,
+    /// URL of the release page.
+    pub html_url: String,
+    /// ISO 8601 timestamp of when the release was created.
+    pub created_at: String,
+    /// ISO 8601 timestamp of when the release was published.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+    /// The user who created the release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+}
+
+// =============================================================================
+// Issues
+// =============================================================================
+
+/// Whether an issue is open or closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueState {
+    /// The issue is open.
+    Open,
+    /// The issue is closed.
+    Closed,
+}
+
+/// A label attached to an issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Label {
+    /// The label's numeric ID.
+    pub id: u64,
+    /// Label name (e.g., "bug").
+    pub name: String,
+    /// Hex color without the leading `#`.
+    pub color: String,
+    /// Label description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Links to the pull request an issue represents.
+///
+/// Present only on issues that are pull requests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuePullRequest {
+    /// URL of the pull request's page.
+    pub html_url: String,
+    /// ISO 8601 timestamp of when the pull request was merged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merged_at: Option<String>,
+}
+
+/// An issue or pull request.
+///
+/// GitHub's issues endpoints return pull requests too; use
+/// [`Issue::is_pull_request`] to tell them apart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Issue {
+    /// The issue's numeric ID.
+    pub id: u64,
+    /// The issue number within the repository.
+    pub number: u64,
+    /// Issue title.
+    pub title: String,
+    /// Whether the issue is open or closed.
+    pub state: IssueState,
+    /// Issue body (Markdown).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The user who opened the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Labels attached to the issue.
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    /// Number of comments.
+    pub comments: u64,
+    /// URL of the issue's page.
+    pub html_url: String,
+    /// ISO 8601 timestamp of when the issue was opened.
+    pub created_at: String,
+    /// ISO 8601 timestamp of the last update.
+    pub updated_at: String,
+    /// ISO 8601 timestamp of when the issue was closed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<String>,
+    /// Set when the issue is a pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pull_request: Option<IssuePullRequest>,
+}
+
+impl Issue {
+    /// Returns true if this "issue" is a pull request.
+    pub fn is_pull_request(&self) -> bool {
+        self.pull_request.is_some()
+    }
+}
+
+// =============================================================================
+// Contents
+// =============================================================================
+
+/// The kind of a repository content entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentType {
+    /// A regular file.
+    File,
+    /// A directory.
+    Dir,
+    /// A symbolic link.
+    Symlink,
+    /// A git submodule.
+    Submodule,
+}
+
+/// A file, directory, symlink or submodule in a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentEntry {
+    /// What kind of entry this is.
+    #[serde(rename = "type")]
+    pub content_type: ContentType,
+    /// File name.
+    pub name: String,
+    /// Path from the repository root.
+    pub path: String,
+    /// Git blob SHA.
+    pub sha: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// URL of the entry's page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// URL to download the raw file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_url: Option<String>,
+    /// File content, encoded as described by `encoding` (files only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Encoding of `content`, usually "base64".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+}
+
+/// Response from the Get Contents endpoint.
+///
+/// A file path returns the file with its content; a directory path returns
+/// its entries without content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Contents {
+    /// The entries of a directory.
+    Directory(Vec<ContentEntry>),
+    /// A single file, symlink or submodule.
+    File(Box<ContentEntry>),
+}
+
+// =============================================================================
+// Rate Limits
+// =============================================================================
+
+/// Quota for one category of requests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// Requests allowed per window.
+    pub limit: u64,
+    /// Requests left in the current window.
+    pub remaining: u64,
+    /// Unix timestamp (seconds) when the window resets.
+    pub reset: u64,
+    /// Requests made in the current window.
+    pub used: u64,
+}
+
+/// Quotas for the request categories the ecosystem uses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitResources {
+    /// The REST API (everything except search and GraphQL).
+    pub core: RateLimit,
+    /// The search API.
+    pub search: RateLimit,
+    /// The GraphQL API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graphql: Option<RateLimit>,
+}
+
+/// Response from the Get Rate Limit endpoint.
+///
+/// Checking the rate limit does not count against it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitResponse {
+    /// Quotas by request category.
+    pub resources: RateLimitResources,
+    /// The core REST API quota (same as `resources.core`).
+    pub rate: RateLimit,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issue_distinguishes_pull_requests() {
+        let json = r#"{
+            "id": 1, "number": 10, "title": "Add flag", "state": "open",
+            "comments": 0, "html_url": "https://github.com/o/r/pull/10",
+            "created_at": "2025-01-01T00:00:00Z", "updated_at": "2025-01-01T00:00:00Z",
+            "pull_request": {"html_url": "https://github.com/o/r/pull/10", "merged_at": null}
+        }"#;
+        let issue: Issue = serde_json::from_str(json).unwrap();
+
+        assert!(issue.is_pull_request());
+        assert_eq!(issue.state, IssueState::Open);
+        assert!(issue.labels.is_empty());
+    }
+
+    #[test]
+    fn contents_parses_files_and_directories() {
+        let file = r#"{"type": "file", "name": "README.md", "path": "README.md",
+            "sha": "abc", "size": 5, "content": "SGVsbG8=", "encoding": "base64"}"#;
+        let dir = r#"[{"type": "dir", "name": "src", "path": "src", "sha": "def", "size": 0}]"#;
+
+        let Contents::File(entry) = serde_json::from_str(file).unwrap() else {
+            panic!("expected a file");
+        };
+        assert_eq!(entry.content_type, ContentType::File);
+        assert_eq!(entry.content.as_deref(), Some("SGVsbG8="));
+
+        let Contents::Directory(entries) = serde_json::from_str(dir).unwrap() else {
+            panic!("expected a directory");
+        };
+        assert_eq!(entries[0].content_type, ContentType::Dir);
+    }
+}
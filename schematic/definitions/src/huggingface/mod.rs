@@ -107,6 +107,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<ModelInfo>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetModel".to_string(),
@@ -116,6 +119,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ModelInfo"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ListModelFiles".to_string(),
@@ -125,6 +131,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<RepoFile>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetModelFile".to_string(),
@@ -134,6 +143,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("FileMetadata"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ListModelCommits".to_string(),
@@ -143,6 +155,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<Commit>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetModelReadme".to_string(),
@@ -152,6 +167,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::Text,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ListModelDiscussions".to_string(),
@@ -161,6 +179,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("DiscussionList"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetModelCard".to_string(),
@@ -170,6 +191,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::Text,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -183,6 +207,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<DatasetInfo>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetDataset".to_string(),
@@ -192,6 +219,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("DatasetInfo"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ListDatasetFiles".to_string(),
@@ -201,6 +231,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<RepoFile>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetDatasetFile".to_string(),
@@ -210,6 +243,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("FileMetadata"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ListDatasetCommits".to_string(),
@@ -219,6 +255,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<Commit>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetDatasetReadme".to_string(),
@@ -228,6 +267,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::Text,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -241,6 +283,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<SpaceInfo>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetSpace".to_string(),
@@ -250,6 +295,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("SpaceInfo"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ListSpaceFiles".to_string(),
@@ -259,6 +307,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<RepoFile>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetSpaceFile".to_string(),
@@ -268,6 +319,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("FileMetadata"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -281,6 +335,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateRepoBody")),
                 response: ApiResponse::json_type("RepoUrl"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "DeleteRepo".to_string(),
@@ -290,6 +347,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: Some(ApiRequest::json_type("DeleteRepoBody")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "UpdateRepoSettings".to_string(),
@@ -299,6 +359,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: Some(ApiRequest::json_type("UpdateRepoSettingsBody")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "MoveRepo".to_string(),
@@ -308,6 +371,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: Some(ApiRequest::json_type("MoveRepoBody")),
                 response: ApiResponse::json_type("StatusResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
 
             // =================================================================
@@ -321,6 +387,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("UserInfo"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetUser".to_string(),
@@ -330,6 +399,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("UserInfo"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ListUserRepos".to_string(),
@@ -339,6 +411,9 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<RepoInfo>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetUserCollections".to_string(),
@@ -348,10 +423,15 @@ pub fn define_huggingface_hub_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Vec<Collection>"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
         ],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -11,6 +11,7 @@
 //! - [`huggingface`] - Hugging Face Hub API for model/dataset discovery
 //! - [`ollama`] - Ollama local LLM inference (native + OpenAI-compatible APIs)
 //! - [`emqx`] - EMQX Broker REST API (Basic Auth + Bearer Token variants)
+//! - [`github`] - GitHub REST API subset for repository, release and issue inspection
 //!
 //! ## Examples
 //!
@@ -73,10 +74,19 @@
 //! // Bearer API has login/logout plus all common endpoints
 //! assert!(bearer_api.endpoints.len() > basic_api.endpoints.len());
 //! ```
+//!
+//! ```
+//! use schematic_definitions::github::define_github_api;
+//!
+//! let api = define_github_api();
+//! assert_eq!(api.name, "GitHub");
+//! assert_eq!(api.endpoints.len(), 8);
+//! ```
 
 pub mod anthropic;
 pub mod elevenlabs;
 pub mod emqx;
+pub mod github;
 pub mod huggingface;
 pub mod ollama;
 pub mod openai;
@@ -86,6 +96,7 @@ pub mod prelude;
 pub use anthropic::define_anthropic_api;
 pub use elevenlabs::{define_elevenlabs_rest_api, define_elevenlabs_websocket_api};
 pub use emqx::{define_emqx_basic_api, define_emqx_bearer_api};
+pub use github::define_github_api;
 pub use huggingface::define_huggingface_hub_api;
 pub use ollama::{define_ollama_native_api, define_ollama_openai_api};
 pub use openai::define_openai_api;
@@ -80,6 +80,9 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: Some(ApiRequest::json_type("GenerateBody")),
                 response: ApiResponse::Binary, // Streaming NDJSON
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "Chat".to_string(),
@@ -90,6 +93,9 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: Some(ApiRequest::json_type("ChatBody")),
                 response: ApiResponse::Binary, // Streaming NDJSON
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "Embeddings".to_string(),
@@ -99,6 +105,9 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: Some(ApiRequest::json_type("EmbeddingsBody")),
                 response: ApiResponse::json_type("EmbeddingsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             // Model management endpoints
             Endpoint {
@@ -109,6 +118,9 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ListModelsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ShowModel".to_string(),
@@ -118,6 +130,9 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: Some(ApiRequest::json_type("ShowModelBody")),
                 response: ApiResponse::json_type("ShowModelResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "PullModel".to_string(),
@@ -129,6 +144,9 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: Some(ApiRequest::json_type("PullModelBody")),
                 response: ApiResponse::Binary, // Streaming progress
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "PushModel".to_string(),
@@ -139,6 +157,9 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: Some(ApiRequest::json_type("PushModelBody")),
                 response: ApiResponse::Binary, // Streaming progress
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "CopyModel".to_string(),
@@ -148,6 +169,9 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CopyModelBody")),
                 response: ApiResponse::Empty,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "DeleteModel".to_string(),
@@ -157,6 +181,9 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: Some(ApiRequest::json_type("DeleteModelBody")),
                 response: ApiResponse::Empty,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "CreateModel".to_string(),
@@ -167,6 +194,9 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateModelBody")),
                 response: ApiResponse::Binary, // Streaming progress
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ListRunningModels".to_string(),
@@ -176,10 +206,15 @@ pub fn define_ollama_native_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ListRunningModelsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
         ],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -226,6 +261,9 @@ pub fn define_ollama_openai_api() -> RestApi {
                 request: Some(ApiRequest::json_type("OpenAIChatCompletionRequest")),
                 response: ApiResponse::Binary, // SSE streaming
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "Completions".to_string(),
@@ -235,6 +273,9 @@ pub fn define_ollama_openai_api() -> RestApi {
                 request: Some(ApiRequest::json_type("OpenAICompletionRequest")),
                 response: ApiResponse::Binary, // SSE streaming
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "Embeddings".to_string(),
@@ -244,6 +285,9 @@ pub fn define_ollama_openai_api() -> RestApi {
                 request: Some(ApiRequest::json_type("OpenAIEmbeddingRequest")),
                 response: ApiResponse::json_type("OpenAIEmbeddingResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "ListModels".to_string(),
@@ -253,10 +297,15 @@ pub fn define_ollama_openai_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("OpenAIListModelsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
         ],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
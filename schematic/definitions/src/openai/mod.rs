@@ -7,7 +7,7 @@ mod types;
 
 pub use types::{DeleteModelResponse, ListModelsResponse, Model};
 
-use schematic_define::{ApiResponse, AuthStrategy, Endpoint, RestApi, RestMethod};
+use schematic_define::{ApiResponse, AuthStrategy, Endpoint, EndpointExample, RestApi, RestMethod};
 
 /// Creates the OpenAI API definition.
 ///
@@ -48,6 +48,12 @@ pub fn define_openai_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ListModelsResponse"),
                 headers: vec![],
+                examples: vec![EndpointExample::response(
+                    "docs",
+                    r#"{"object": "list", "data": [{"id": "gpt-4.1", "object": "model", "created": 1686935002, "owned_by": "openai"}]}"#,
+                )],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "RetrieveModel".to_string(),
@@ -57,6 +63,12 @@ pub fn define_openai_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Model"),
                 headers: vec![],
+                examples: vec![EndpointExample::response(
+                    "docs",
+                    r#"{"id": "gpt-4.1", "object": "model", "created": 1686935002, "owned_by": "openai"}"#,
+                )],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "DeleteModel".to_string(),
@@ -66,10 +78,18 @@ pub fn define_openai_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("DeleteModelResponse"),
                 headers: vec![],
+                examples: vec![EndpointExample::response(
+                    "docs",
+                    r#"{"id": "ft:gpt-4o-mini:acemeco:suffix:abc123", "object": "model", "deleted": true}"#,
+                )],
+                compression: None,
+                idempotency: None,
             },
         ],
         module_path: None,
         request_suffix: None,
+        usage_docs: true,
+        compression: None,
     }
 }
 
@@ -105,6 +125,19 @@ mod tests {
         assert_eq!(api.endpoints.len(), 3);
     }
 
+    #[test]
+    fn every_endpoint_has_response_example() {
+        let api = define_openai_api();
+
+        for endpoint in &api.endpoints {
+            assert!(
+                endpoint.examples.iter().any(|e| e.response.is_some()),
+                "{} should have a response example",
+                endpoint.id
+            );
+        }
+    }
+
     #[test]
     fn list_models_endpoint() {
         let api = define_openai_api();
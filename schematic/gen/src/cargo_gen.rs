@@ -21,17 +21,27 @@ description = "Generated REST API client code from schematic definitions"
 # This file was automatically generated by schematic-gen.
 # Do not edit manually - changes will be overwritten.
 
+[features]
+# Record responses to, and replay them from, cassette directories (shared::vcr)
+vcr = ["dep:base64", "biscuit-hash/xx_hash"]
+
 [dependencies]
+base64 = { version = "0.22", optional = true }
+biscuit-hash = { path = "{{BISCUIT_HASH_PATH}}", default-features = false, features = ["ulid"] }
+brotli = "9"
 bytes = "1"
-reqwest = { version = "0.12", default-features = false, features = ["json", "rustls-tls"] }
+flate2 = "1"
+http = "1"
+reqwest = { version = "0.12", default-features = false, features = ["json", "rustls-tls", "gzip", "brotli"] }
 schematic-define = { version = "0.1.0", path = "{{DEFINE_PATH}}" }
 schematic-definitions = { version = "0.1.0", path = "{{DEFINITIONS_PATH}}" }
 serde = { version = "1.0", features = ["derive"] }
 serde_json = "1.0"
 thiserror = "2.0"
-tokio = { version = "1.43", features = ["rt", "macros"] }
+tokio = { version = "1.43", features = ["rt", "macros", "sync"] }
 
 [dev-dependencies]
+tempfile = "3"
 wiremock = "0.6"
 "#;
 
@@ -43,8 +53,9 @@ wiremock = "0.6"
 /// ## Arguments
 ///
 /// * `workspace_root` - Optional path to the workspace root. When provided, uses
-///   absolute paths to the schematic-define and schematic-definitions packages.
-///   When `None`, uses the default relative paths (`../define`, `../definitions`).
+///   absolute paths to the schematic-define, schematic-definitions and
+///   biscuit-hash packages. When `None`, uses the default relative paths
+///   (`../define`, `../definitions`, `../../biscuit-hash/lib`).
 ///
 /// ## Examples
 ///
@@ -62,14 +73,23 @@ wiremock = "0.6"
 /// assert!(content.contains("path = \"/workspace/schematic/define\""));
 /// ```
 pub fn generate_cargo_toml(workspace_root: Option<&str>) -> String {
-    let (define_path, definitions_path) = match workspace_root {
-        Some(root) => (format!("{}/define", root), format!("{}/definitions", root)),
-        None => ("../define".to_string(), "../definitions".to_string()),
+    let (define_path, definitions_path, biscuit_hash_path) = match workspace_root {
+        Some(root) => (
+            format!("{}/define", root),
+            format!("{}/definitions", root),
+            format!("{}/../biscuit-hash/lib", root),
+        ),
+        None => (
+            "../define".to_string(),
+            "../definitions".to_string(),
+            "../../biscuit-hash/lib".to_string(),
+        ),
     };
 
     CARGO_TOML_TEMPLATE
         .replace("{{DEFINE_PATH}}", &define_path)
         .replace("{{DEFINITIONS_PATH}}", &definitions_path)
+        .replace("{{BISCUIT_HASH_PATH}}", &biscuit_hash_path)
 }
 
 /// Writes the Cargo.toml to the output directory.
@@ -202,6 +222,47 @@ mod tests {
         let features = reqwest.get("features").unwrap().as_array().unwrap();
         assert!(features.iter().any(|f| f.as_str() == Some("json")));
         assert!(features.iter().any(|f| f.as_str() == Some("rustls-tls")));
+        assert!(features.iter().any(|f| f.as_str() == Some("gzip")));
+        assert!(features.iter().any(|f| f.as_str() == Some("brotli")));
+    }
+
+    #[test]
+    fn generate_cargo_toml_includes_compression_encoders() {
+        let content = generate_cargo_toml(None);
+        let parsed: toml::Table = toml::from_str(&content).unwrap();
+
+        let deps = parsed.get("dependencies").unwrap().as_table().unwrap();
+        assert!(deps.contains_key("flate2"), "flate2 is required for gzip");
+        assert!(deps.contains_key("brotli"), "brotli is required for br");
+    }
+
+    #[test]
+    fn generate_cargo_toml_includes_biscuit_hash_with_ulid() {
+        let content = generate_cargo_toml(None);
+        let parsed: toml::Table = toml::from_str(&content).unwrap();
+
+        let deps = parsed.get("dependencies").unwrap().as_table().unwrap();
+        let biscuit_hash = deps.get("biscuit-hash").unwrap().as_table().unwrap();
+        assert_eq!(
+            biscuit_hash.get("path").unwrap().as_str(),
+            Some("../../biscuit-hash/lib")
+        );
+        let features = biscuit_hash.get("features").unwrap().as_array().unwrap();
+        assert!(features.iter().any(|f| f.as_str() == Some("ulid")));
+    }
+
+    #[test]
+    fn generate_cargo_toml_has_optional_vcr_feature() {
+        let content = generate_cargo_toml(None);
+        let parsed: toml::Table = toml::from_str(&content).unwrap();
+
+        let vcr = parsed["features"]["vcr"].as_array().unwrap();
+        let vcr: Vec<&str> = vcr.iter().filter_map(|f| f.as_str()).collect();
+        assert_eq!(vcr, ["dep:base64", "biscuit-hash/xx_hash"]);
+
+        let deps = parsed.get("dependencies").unwrap().as_table().unwrap();
+        let base64 = deps.get("base64").unwrap().as_table().unwrap();
+        assert_eq!(base64.get("optional").unwrap().as_bool(), Some(true));
     }
 
     #[test]
@@ -255,6 +316,7 @@ mod tests {
         let features = tokio.get("features").unwrap().as_array().unwrap();
         assert!(features.iter().any(|f| f.as_str() == Some("rt")));
         assert!(features.iter().any(|f| f.as_str() == Some("macros")));
+        assert!(features.iter().any(|f| f.as_str() == Some("sync")));
     }
 
     #[test]
@@ -384,6 +446,7 @@ mod tests {
 
         assert!(content.contains("path = \"/workspace/schematic/define\""));
         assert!(content.contains("path = \"/workspace/schematic/definitions\""));
+        assert!(content.contains("path = \"/workspace/schematic/../biscuit-hash/lib\""));
     }
 
     #[test]
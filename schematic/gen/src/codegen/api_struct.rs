@@ -7,6 +7,8 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use schematic_define::{AuthStrategy, RestApi};
 
+use super::compression::generate_compression_init;
+
 /// Generates the API struct for the given API definition.
 ///
 /// Creates a struct with:
@@ -15,6 +17,7 @@ use schematic_define::{AuthStrategy, RestApi};
 /// - `with_base_url()` constructor for custom base URLs
 /// - `with_client()` constructor for custom reqwest clients
 /// - `with_client_and_base_url()` constructor for both custom client and URL
+/// - `with_client_builder()` constructor for proxy, TLS and pool settings
 /// - `Default` trait implementation
 ///
 /// ## Examples
@@ -71,6 +74,9 @@ pub fn generate_api_struct(api: &RestApi) -> TokenStream {
     // Generate headers initialization
     let headers_init = generate_headers_init(&api.headers);
 
+    // Generate compression initialization (API default, before endpoint overrides)
+    let compression_init = generate_compression_init(&api.compression.unwrap_or_default());
+
     quote! {
         #[doc = #description]
         pub struct #struct_name {
@@ -84,6 +90,13 @@ pub fn generate_api_struct(api: &RestApi) -> TokenStream {
             env_username: Option<String>,
             /// Default HTTP headers to include with every request.
             headers: Vec<(String, String)>,
+            /// Content-encoding settings for endpoints without their own.
+            compression: schematic_define::Compression,
+            /// Requests in flight, for coalescing; shared with variants.
+            in_flight: InFlightRequests,
+            /// Cassette that records or replays responses.
+            #[cfg(feature = "vcr")]
+            cassette: Option<crate::shared::vcr::Cassette>,
         }
 
         impl #struct_name {
@@ -99,6 +112,10 @@ pub fn generate_api_struct(api: &RestApi) -> TokenStream {
                     auth_strategy: #auth_strategy_init,
                     env_username: #env_username_init,
                     headers: #headers_init,
+                    compression: #compression_init,
+                    in_flight: InFlightRequests::default(),
+                    #[cfg(feature = "vcr")]
+                    cassette: None,
                 }
             }
 
@@ -117,6 +134,10 @@ pub fn generate_api_struct(api: &RestApi) -> TokenStream {
                     auth_strategy: #auth_strategy_init,
                     env_username: #env_username_init,
                     headers: #headers_init,
+                    compression: #compression_init,
+                    in_flight: InFlightRequests::default(),
+                    #[cfg(feature = "vcr")]
+                    cassette: None,
                 }
             }
 
@@ -141,6 +162,10 @@ pub fn generate_api_struct(api: &RestApi) -> TokenStream {
                     auth_strategy: #auth_strategy_init,
                     env_username: #env_username_init,
                     headers: #headers_init,
+                    compression: #compression_init,
+                    in_flight: InFlightRequests::default(),
+                    #[cfg(feature = "vcr")]
+                    cassette: None,
                 }
             }
 
@@ -163,9 +188,40 @@ pub fn generate_api_struct(api: &RestApi) -> TokenStream {
                     auth_strategy: #auth_strategy_init,
                     env_username: #env_username_init,
                     headers: #headers_init,
+                    compression: #compression_init,
+                    in_flight: InFlightRequests::default(),
+                    #[cfg(feature = "vcr")]
+                    cassette: None,
                 }
             }
 
+            /// Creates a new API client whose HTTP client has the connection
+            /// settings of `builder` (proxy, root CAs, client certificate, DNS
+            /// overrides, pool sizing).
+            ///
+            /// For a custom base URL as well, pass `builder.build()?` to
+            /// `with_client_and_base_url()`.
+            ///
+            /// ## Errors
+            ///
+            /// Returns `SchematicError::ClientConfig` if a builder setting is invalid.
+            ///
+            /// ## Examples
+            ///
+            /// ```ignore
+            /// use schematic_schema::shared::ApiClientBuilder;
+            ///
+            /// let builder = ApiClientBuilder::new()
+            ///     .proxy("http://proxy.corp.example:3128")
+            ///     .root_certificate_pem(std::fs::read("/etc/ssl/corp-ca.pem")?);
+            /// let api = Api::with_client_builder(&builder)?;
+            /// ```
+            pub fn with_client_builder(
+                builder: &crate::shared::ApiClientBuilder,
+            ) -> Result<Self, SchematicError> {
+                Ok(Self::with_client(builder.build()?))
+            }
+
             /// Creates a variant of this API client with different configuration.
             ///
             /// This method clones the underlying HTTP client and allows customizing:
@@ -221,9 +277,52 @@ pub fn generate_api_struct(api: &RestApi) -> TokenStream {
                     auth_strategy,
                     env_username: self.env_username.clone(),
                     headers: self.headers.clone(),
+                    compression: self.compression,
+                    in_flight: self.in_flight.clone(),
+                    #[cfg(feature = "vcr")]
+                    cassette: self.cassette.clone(),
                 }
             }
 
+            /// Replaces the API-level compression settings for this client.
+            ///
+            /// Endpoints with their own compression settings in the definition
+            /// keep them.
+            ///
+            /// ## Examples
+            ///
+            /// ```ignore
+            /// use schematic_define::{Compression, ContentEncoding};
+            ///
+            /// // Gzip request bodies of 8 KiB or more
+            /// let api = Api::new().with_compression(
+            ///     Compression::default().compress_requests(ContentEncoding::Gzip, 8 * 1024),
+            /// );
+            /// ```
+            pub fn with_compression(mut self, compression: schematic_define::Compression) -> Self {
+                self.compression = compression;
+                self
+            }
+
+            /// Records responses to, or replays them from, a cassette.
+            ///
+            /// Replayed requests never reach the network and need no
+            /// credentials. Requires the `vcr` feature; see
+            /// `crate::shared::vcr`.
+            ///
+            /// ## Examples
+            ///
+            /// ```ignore
+            /// use schematic_schema::shared::vcr::{Cassette, VcrMode};
+            ///
+            /// let api = Api::new().with_cassette(Cassette::new("tests/cassettes/api", VcrMode::Auto));
+            /// ```
+            #[cfg(feature = "vcr")]
+            pub fn with_cassette(mut self, cassette: crate::shared::vcr::Cassette) -> Self {
+                self.cassette = Some(cassette);
+                self
+            }
+
             /// Returns a reference to the underlying HTTP client.
             ///
             /// Use this for custom requests that aren't covered by the generated methods,
@@ -312,6 +411,8 @@ mod tests {
             endpoints: vec![],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         }
     }
 
@@ -414,6 +515,19 @@ mod tests {
         assert!(code.contains("Self::BASE_URL.to_string()"));
     }
 
+    #[test]
+    fn generate_api_struct_has_feature_gated_cassette() {
+        let api = make_api("TestApi", "https://api.test.com", "Test API");
+        let tokens = generate_api_struct(&api);
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(code.contains("cassette: Option<crate::shared::vcr::Cassette>"));
+        assert!(code.contains("cassette: self.cassette.clone()"));
+        assert!(code.contains("pub fn with_cassette(mut self"));
+        // The field, its 4 constructor initializers, the variant copy and the builder
+        assert_eq!(code.matches("#[cfg(feature = \"vcr\")]").count(), 7);
+    }
+
     #[test]
     fn generate_api_struct_has_with_client_and_base_url_constructor() {
         let api = make_api("TestApi", "https://api.test.com", "Test API");
@@ -426,6 +540,17 @@ mod tests {
         assert!(code.contains("base_url: impl Into<String>"));
     }
 
+    #[test]
+    fn generate_api_struct_has_with_client_builder_constructor() {
+        let api = make_api("TestApi", "https://api.test.com", "Test API");
+        let tokens = generate_api_struct(&api);
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(code.contains("pub fn with_client_builder("));
+        assert!(code.contains("builder: &crate::shared::ApiClientBuilder"));
+        assert!(code.contains("Ok(Self::with_client(builder.build()?))"));
+    }
+
     #[test]
     fn generate_api_struct_with_bearer_auth() {
         let api = RestApi {
@@ -440,6 +565,8 @@ mod tests {
             endpoints: vec![],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         };
         let tokens = generate_api_struct(&api);
         let code = format_generated_code(&tokens).expect("Failed to format code");
@@ -464,6 +591,8 @@ mod tests {
             endpoints: vec![],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         };
         let tokens = generate_api_struct(&api);
         let code = format_generated_code(&tokens).expect("Failed to format code");
@@ -487,6 +616,8 @@ mod tests {
             endpoints: vec![],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         };
         let tokens = generate_api_struct(&api);
         let code = format_generated_code(&tokens).expect("Failed to format code");
@@ -619,6 +750,8 @@ mod tests {
             endpoints: vec![],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         };
         let tokens = generate_api_struct(&api);
         let code = format_generated_code(&tokens).expect("Failed to format code");
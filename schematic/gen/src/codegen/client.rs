@@ -10,6 +10,20 @@
 //! - `request_bytes()` - For binary responses (returns `bytes::Bytes`)
 //! - `request_text()` - For text responses (returns `String`)
 //! - `request_empty()` - For empty responses (returns `()`)
+//!
+//! ## Compression
+//!
+//! `build_and_send_request()` resolves the effective [`Compression`](schematic_define::Compression)
+//! (endpoint override, else the client's setting). Responses are decompressed by
+//! reqwest unless disabled, in which case `Accept-Encoding: identity` is sent.
+//! Request bodies at or above the configured size are compressed via the shared
+//! `compress_body()` helper and tagged with `Content-Encoding`.
+//!
+//! ## Recording and Replay
+//!
+//! With the generated crate's `vcr` feature, a client with a cassette (see
+//! [`vcr`](super::vcr)) checks it for a recorded response before applying
+//! authentication, and records live responses before their status is checked.
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -125,9 +139,26 @@ fn generate_build_request_method(
             request: impl Into<#request_enum>,
         ) -> Result<reqwest::Response, SchematicError> {
             let request = request.into();
+            // Endpoint-specific compression settings take precedence over the client's
+            let compression = request.compression().unwrap_or(self.compression);
+            let idempotency = request.idempotency();
             let (method, path, body, endpoint_headers) = request.into_parts()?;
             let url = format!("{}{}", self.base_url, path);
 
+            // Serve a recorded response before credentials are needed
+            #[cfg(feature = "vcr")]
+            let recording = match &self.cassette {
+                Some(cassette) => {
+                    let request =
+                        crate::shared::vcr::RecordedRequest::new(method, &path, body.as_deref());
+                    if let Some(interaction) = cassette.replay(&request)? {
+                        return interaction.into_response();
+                    }
+                    Some((cassette, request))
+                }
+                None => None,
+            };
+
             let mut req_builder = match method {
                 "GET" => self.client.get(&url),
                 "POST" => self.client.post(&url),
@@ -144,23 +175,70 @@ fn generate_build_request_method(
 
             // Merge API-level and endpoint-level headers
             let merged_headers = Self::merge_headers(&self.headers, &endpoint_headers);
+
+            // Ask for an uncompressed response unless a header already negotiates encoding
+            if !compression.decompress_responses
+                && !merged_headers
+                    .iter()
+                    .any(|(key, _)| key.eq_ignore_ascii_case("Accept-Encoding"))
+            {
+                req_builder = req_builder.header("Accept-Encoding", "identity");
+            }
+
+            // Send a fresh idempotency key unless the caller supplied one;
+            // only requests with a generated key are coalesced
+            let fingerprint = match &idempotency {
+                Some(config)
+                    if !merged_headers
+                        .iter()
+                        .any(|(key, _)| key.eq_ignore_ascii_case(&config.header)) =>
+                {
+                    req_builder = req_builder.header(config.header.as_str(), biscuit_hash::ulid());
+                    config
+                        .dedupe_in_flight
+                        .then(|| (method.to_string(), url.clone(), body.clone()))
+                }
+                _ => None,
+            };
+
             for (key, value) in merged_headers {
                 req_builder = req_builder.header(key.as_str(), value.as_str());
             }
 
-            // Add body if present
+            // Add body if present, compressing it when configured and large enough
             if let Some(body) = body {
-                req_builder = req_builder
-                    .header("Content-Type", "application/json")
-                    .body(body);
+                req_builder = req_builder.header("Content-Type", "application/json");
+                req_builder = match compression.request.filter(|c| c.applies_to(body.len())) {
+                    Some(config) => req_builder
+                        .header("Content-Encoding", config.encoding.as_str())
+                        .body(compress_body(body.as_bytes(), config.encoding)?),
+                    None => req_builder.body(body),
+                };
             }
 
-            let response = req_builder.send().await?;
+            let send = async {
+                let response = req_builder.send().await?;
+
+                // Record the response, errors included, before checking its status
+                #[cfg(feature = "vcr")]
+                let response = match recording {
+                    Some((cassette, request)) => cassette.record(&request, response).await?,
+                    None => response,
+                };
+                Ok::<_, SchematicError>(response)
+            };
+
+            // Identical requests already in flight share the first one's response
+            let response = match fingerprint {
+                Some(fingerprint) => self.in_flight.send(fingerprint, send).await?,
+                None => send.await?,
+            };
 
             if !response.status().is_success() {
                 let status = response.status().as_u16();
+                let headers = response.headers().clone();
                 let body = response.text().await.unwrap_or_default();
-                return Err(SchematicError::ApiError { status, body });
+                return Err(response_error(status, &headers, body));
             }
 
             Ok(response)
@@ -378,7 +456,7 @@ pub fn generate_convenience_methods(api: &RestApi, request_suffix: &str) -> Toke
 }
 
 /// Converts a CamelCase identifier to snake_case.
-fn to_snake_case(s: &str) -> String {
+pub(crate) fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
     for (i, c) in s.chars().enumerate() {
         if c.is_uppercase() {
@@ -467,9 +545,14 @@ mod tests {
                 request: None,
                 response: ApiResponse::json_type("ListItemsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             }],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         }
     }
 
@@ -486,6 +569,8 @@ mod tests {
             endpoints,
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         }
     }
 
@@ -508,9 +593,14 @@ mod tests {
                 request: None,
                 response: ApiResponse::json_type("ListItemsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             }],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         }
     }
 
@@ -547,7 +637,7 @@ mod tests {
 
         // Check error handling
         assert!(code.contains("SchematicError::UnsupportedMethod"));
-        assert!(code.contains("SchematicError::ApiError"));
+        assert!(code.contains("response_error(status, &headers, body)"));
 
         // Check body handling
         assert!(code.contains("if let Some(body) = body"));
@@ -749,6 +839,45 @@ mod tests {
         assert!(code.contains("eq_ignore_ascii_case"));
     }
 
+    #[test]
+    fn generate_request_method_resolves_compression() {
+        let api = make_api("CompressApi", AuthStrategy::None, vec![]);
+        let tokens = generate_request_method(&api);
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        // Endpoint override falls back to the client-level setting
+        assert!(code.contains("request.compression().unwrap_or(self.compression)"));
+        // Opting out of decompression sends identity
+        assert!(code.contains("!compression.decompress_responses"));
+        assert!(code.contains(r#"req_builder.header("Accept-Encoding", "identity")"#));
+    }
+
+    #[test]
+    fn generate_request_method_attaches_idempotency_keys() {
+        let api = make_api("IdempotentApi", AuthStrategy::None, vec![]);
+        let tokens = generate_request_method(&api);
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(code.contains("let idempotency = request.idempotency();"));
+        // Caller-supplied headers win over generated keys
+        assert!(code.contains(".any(|(key, _)| key.eq_ignore_ascii_case(&config.header))"));
+        assert!(code.contains("biscuit_hash::ulid()"));
+        // Only dedupe endpoints go through the in-flight registry
+        assert!(code.contains(".dedupe_in_flight"));
+        assert!(code.contains("self.in_flight.send(fingerprint, send).await?"));
+    }
+
+    #[test]
+    fn generate_request_method_compresses_large_bodies() {
+        let api = make_api("CompressApi", AuthStrategy::None, vec![]);
+        let tokens = generate_request_method(&api);
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(code.contains("c.applies_to(body.len())"));
+        assert!(code.contains(r#".header("Content-Encoding", config.encoding.as_str())"#));
+        assert!(code.contains("compress_body(body.as_bytes(), config.encoding)?"));
+    }
+
     // === New tests for response-type-specific methods ===
 
     #[test]
@@ -763,6 +892,9 @@ mod tests {
                 request: None,
                 response: ApiResponse::Binary,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             }],
         );
         let tokens = generate_request_method(&api);
@@ -807,6 +939,9 @@ mod tests {
                 request: None,
                 response: ApiResponse::Text,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             }],
         );
         let tokens = generate_request_method(&api);
@@ -845,6 +980,9 @@ mod tests {
                 request: None,
                 response: ApiResponse::Empty,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             }],
         );
         let tokens = generate_request_method(&api);
@@ -880,6 +1018,9 @@ mod tests {
                     request: None,
                     response: ApiResponse::json_type("ListItemsResponse"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 Endpoint {
                     id: "CreateSpeech".to_string(),
@@ -889,6 +1030,9 @@ mod tests {
                     request: None,
                     response: ApiResponse::Binary,
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
             ],
         );
@@ -930,6 +1074,9 @@ mod tests {
                     request: None,
                     response: ApiResponse::Binary,
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 Endpoint {
                     id: "GetVoiceSampleAudio".to_string(),
@@ -939,6 +1086,9 @@ mod tests {
                     request: None,
                     response: ApiResponse::Binary,
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
             ],
         );
@@ -0,0 +1,317 @@
+//! Contract test generation from endpoint examples.
+//!
+//! For every [`EndpointExample`] in an API definition, generates a `#[test]`
+//! that deserializes the example payload into the declared schema type,
+//! serializes it back, and checks that every non-null value in the example
+//! survived the round trip. A field present in the example but missing from
+//! the type (or a value that changes shape) fails the test, which surfaces
+//! drift between a definition and the real API.
+//!
+//! APIs without examples produce no contract test module.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use schematic_define::{ApiRequest, ApiResponse, Endpoint, EndpointExample, RestApi};
+
+use super::client::to_snake_case;
+
+/// Generates the `contract_tests` module for an API.
+///
+/// Returns an empty `TokenStream` if no endpoint defines examples.
+///
+/// Examples are expected to have been checked by
+/// [`validate_api`](crate::validation::validate_api); payloads for endpoints
+/// without a JSON request/response schema are skipped.
+///
+/// ## Examples
+///
+/// ```ignore
+/// // For endpoint "ListModels" with example "basic" (response only):
+/// #[cfg(test)]
+/// mod contract_tests {
+///     use super::*;
+///
+///     fn assert_example_roundtrip<T>(type_name: &str, example: &str) { ... }
+///
+///     #[test]
+///     fn list_models_basic_response() {
+///         assert_example_roundtrip::<ListModelsResponse>(
+///             "ListModelsResponse",
+///             r#"{"object": "list", "data": []}"#,
+///         );
+///     }
+/// }
+/// ```
+pub fn generate_contract_tests(api: &RestApi) -> TokenStream {
+    let tests: Vec<TokenStream> = api
+        .endpoints
+        .iter()
+        .flat_map(|endpoint| {
+            endpoint
+                .examples
+                .iter()
+                .flat_map(move |example| generate_example_tests(endpoint, example))
+        })
+        .collect();
+
+    if tests.is_empty() {
+        return TokenStream::new();
+    }
+
+    let helpers = generate_roundtrip_helpers();
+
+    quote! {
+        /// Contract tests generated from the endpoint examples in the API definition.
+        #[cfg(test)]
+        mod contract_tests {
+            use super::*;
+
+            #helpers
+
+            #(#tests)*
+        }
+    }
+}
+
+/// Generates the request and/or response test for a single example.
+fn generate_example_tests(endpoint: &Endpoint, example: &EndpointExample) -> Vec<TokenStream> {
+    let base_name = format!(
+        "{}_{}",
+        to_snake_case(&endpoint.id),
+        example_ident(&example.name)
+    );
+    let mut tests = Vec::new();
+
+    if let (Some(payload), Some(ApiRequest::Json(schema))) = (&example.request, &endpoint.request) {
+        tests.extend(generate_roundtrip_test(
+            &format!("{}_request", base_name),
+            &schema.type_name,
+            payload,
+        ));
+    }
+
+    if let (Some(payload), ApiResponse::Json(schema)) = (&example.response, &endpoint.response) {
+        tests.extend(generate_roundtrip_test(
+            &format!("{}_response", base_name),
+            &schema.type_name,
+            payload,
+        ));
+    }
+
+    tests
+}
+
+/// Generates one `#[test]` that round-trips `payload` through `type_name`.
+///
+/// Returns `None` if the type name can't be parsed as a Rust type.
+fn generate_roundtrip_test(test_name: &str, type_name: &str, payload: &str) -> Option<TokenStream> {
+    let ty: syn::Type = syn::parse_str(type_name).ok()?;
+    let test_ident = format_ident!("{}", test_name);
+    let payload_lit = raw_string_literal(payload);
+
+    Some(quote! {
+        #[test]
+        fn #test_ident() {
+            assert_example_roundtrip::<#ty>(#type_name, #payload_lit);
+        }
+    })
+}
+
+/// Generates the helper functions shared by all contract tests in a module.
+fn generate_roundtrip_helpers() -> TokenStream {
+    quote! {
+        /// Deserializes `example` into `T`, serializes it back, and checks that
+        /// every non-null value in the example survived the round trip.
+        fn assert_example_roundtrip<T>(type_name: &str, example: &str)
+        where
+            T: serde::de::DeserializeOwned + serde::Serialize,
+        {
+            let original: serde_json::Value = serde_json::from_str(example)
+                .unwrap_or_else(|e| panic!("Example for `{}` is not valid JSON: {}", type_name, e));
+            let parsed: T = serde_json::from_value(original.clone()).unwrap_or_else(|e| {
+                panic!("Example does not deserialize into `{}`: {}", type_name, e)
+            });
+            let roundtrip = serde_json::to_value(&parsed)
+                .unwrap_or_else(|e| panic!("`{}` failed to serialize: {}", type_name, e));
+
+            if let Err(path) = example_preserved(&original, &roundtrip, "$") {
+                panic!(
+                    "`{}` does not preserve the example value at `{}` (field missing from the type or changed during round trip)",
+                    type_name, path
+                );
+            }
+        }
+
+        /// Checks that `actual` contains every non-null value in `expected`.
+        ///
+        /// Returns the JSON path of the first value that was dropped or changed.
+        fn example_preserved(
+            expected: &serde_json::Value,
+            actual: &serde_json::Value,
+            path: &str,
+        ) -> Result<(), String> {
+            use serde_json::Value;
+
+            match (expected, actual) {
+                (Value::Null, _) => Ok(()),
+                (Value::Object(expected), Value::Object(actual)) => {
+                    for (key, value) in expected {
+                        let child = format!("{}.{}", path, key);
+                        match actual.get(key) {
+                            Some(actual_value) => example_preserved(value, actual_value, &child)?,
+                            // Types commonly skip serializing empty values
+                            None if is_empty_value(value) => {}
+                            None => return Err(child),
+                        }
+                    }
+                    Ok(())
+                }
+                (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+                    for (i, (e, a)) in expected.iter().zip(actual).enumerate() {
+                        example_preserved(e, a, &format!("{}[{}]", path, i))?;
+                    }
+                    Ok(())
+                }
+                // Integers may round-trip through float fields (and vice versa)
+                (Value::Number(e), Value::Number(a)) if e.as_f64() == a.as_f64() => Ok(()),
+                (expected, actual) if expected == actual => Ok(()),
+                _ => Err(path.to_string()),
+            }
+        }
+
+        /// Returns true for null, empty arrays, and empty objects.
+        fn is_empty_value(value: &serde_json::Value) -> bool {
+            match value {
+                serde_json::Value::Null => true,
+                serde_json::Value::Array(items) => items.is_empty(),
+                serde_json::Value::Object(map) => map.is_empty(),
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Converts an example name into a snake_case identifier fragment.
+///
+/// Spaces and hyphens become underscores; other characters are lowercased.
+pub(crate) fn example_ident(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| match c {
+            ' ' | '-' => '_',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+/// Builds a raw string literal (`r#"..."#`) for embedding JSON payloads.
+///
+/// Uses enough `#` characters that the payload can't terminate the literal.
+fn raw_string_literal(content: &str) -> TokenStream {
+    let mut hashes = String::from("#");
+    while content.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+    format!("r{hashes}\"{content}\"{hashes}")
+        .parse()
+        .expect("raw string literal is always valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{make_api_with_endpoint, make_endpoint};
+    use schematic_define::{AuthStrategy, RestMethod};
+
+    fn make_example_api(examples: Vec<EndpointExample>) -> RestApi {
+        let mut endpoint = make_endpoint(
+            "CreateUser",
+            RestMethod::Post,
+            "/users",
+            Some(ApiRequest::json_type("CreateUserBody")),
+        );
+        endpoint.response = ApiResponse::json_type("User");
+        endpoint.examples = examples;
+        make_api_with_endpoint("TestApi", AuthStrategy::None, vec![], endpoint)
+    }
+
+    #[test]
+    fn no_examples_generates_nothing() {
+        let api = make_example_api(vec![]);
+        assert!(generate_contract_tests(&api).is_empty());
+    }
+
+    #[test]
+    fn generates_request_and_response_tests() {
+        let api = make_example_api(vec![
+            EndpointExample::new("basic")
+                .with_request(r#"{"name": "Ada"}"#)
+                .with_response(r#"{"id": 1, "name": "Ada"}"#),
+        ]);
+
+        let code = generate_contract_tests(&api).to_string();
+
+        assert!(code.contains("mod contract_tests"));
+        assert!(code.contains("fn create_user_basic_request"));
+        assert!(code.contains("fn create_user_basic_response"));
+        assert!(code.contains("assert_example_roundtrip :: < CreateUserBody >"));
+        assert!(code.contains("assert_example_roundtrip :: < User >"));
+        assert!(code.contains(r###"r#"{"name": "Ada"}"#"###));
+    }
+
+    #[test]
+    fn response_only_example_skips_request_test() {
+        let api = make_example_api(vec![EndpointExample::response("found", r#"{"id": 1}"#)]);
+
+        let code = generate_contract_tests(&api).to_string();
+
+        assert!(code.contains("fn create_user_found_response"));
+        assert!(!code.contains("fn create_user_found_request"));
+    }
+
+    #[test]
+    fn generic_response_types_are_supported() {
+        let mut endpoint = make_endpoint("ListUsers", RestMethod::Get, "/users", None);
+        endpoint.response = ApiResponse::json_type("Vec<User>");
+        endpoint.examples = vec![EndpointExample::response("two users", "[{}, {}]")];
+        let api = make_api_with_endpoint("TestApi", AuthStrategy::None, vec![], endpoint);
+
+        let code = generate_contract_tests(&api).to_string();
+
+        assert!(code.contains("fn list_users_two_users_response"));
+        assert!(code.contains("assert_example_roundtrip :: < Vec < User > >"));
+    }
+
+    #[test]
+    fn generated_module_is_valid_rust() {
+        let api = make_example_api(vec![
+            EndpointExample::new("basic")
+                .with_request(r#"{"name": "Ada"}"#)
+                .with_response(r#"{"id": 1}"#),
+        ]);
+
+        let tokens = generate_contract_tests(&api);
+        let parsed: Result<syn::File, _> = syn::parse2(tokens);
+        assert!(
+            parsed.is_ok(),
+            "Generated code should parse: {:?}",
+            parsed.err()
+        );
+    }
+
+    #[test]
+    fn example_ident_normalizes_names() {
+        assert_eq!(example_ident("basic"), "basic");
+        assert_eq!(example_ident("With Tools"), "with_tools");
+        assert_eq!(example_ident("multi-turn"), "multi_turn");
+    }
+
+    #[test]
+    fn raw_string_literal_escapes_embedded_terminators() {
+        assert_eq!(raw_string_literal("{}").to_string(), r###"r#"{}"#"###);
+        assert_eq!(
+            raw_string_literal(r##"{"a": "\"#"}"##).to_string(),
+            r####"r##"{"a": "\"#"}"##"####
+        );
+    }
+}
@@ -38,8 +38,11 @@ pub fn generate_request_parts_type() -> TokenStream {
 /// - `Http`: HTTP request failures (network errors, timeouts)
 /// - `Json`: JSON deserialization failures
 /// - `ApiError`: API returned non-success status codes
+/// - `RateLimited`: API rejected the request because of a rate limit
 /// - `UnsupportedMethod`: Unknown HTTP method (should never occur with generated code)
 /// - `SerializationError`: Request body serialization failures
+/// - `ClientConfig`: Invalid `ApiClientBuilder` settings (proxy, certificates)
+/// - `Cassette`: Recording/replay failures (only with the `vcr` feature)
 ///
 /// ## Examples
 ///
@@ -85,6 +88,20 @@ pub fn generate_error_type() -> TokenStream {
                 body: String,
             },
 
+            /// API rejected the request because a rate limit was exceeded.
+            ///
+            /// Built by `response_error()` for `429` responses and for `403`
+            /// responses that report an exhausted rate limit.
+            #[error("Rate limited (status {status}): {body}")]
+            RateLimited {
+                /// HTTP status code from the response.
+                status: u16,
+                /// How long to wait before retrying, when the response said.
+                retry_after: Option<std::time::Duration>,
+                /// Response body text.
+                body: String,
+            },
+
             /// Unsupported HTTP method encountered.
             ///
             /// This error should never occur when using generated request types,
@@ -104,6 +121,17 @@ pub fn generate_error_type() -> TokenStream {
                 /// The environment variable names that were checked.
                 env_vars: Vec<String>,
             },
+
+            /// An `ApiClientBuilder` setting was rejected, e.g. a malformed
+            /// proxy URL or certificate.
+            #[error("Invalid client configuration: {0}")]
+            ClientConfig(String),
+
+            /// A cassette had no recording for a request, or could not be
+            /// read or written.
+            #[cfg(feature = "vcr")]
+            #[error("Cassette error: {0}")]
+            Cassette(String),
         }
     }
 }
@@ -143,6 +171,11 @@ mod tests {
             code.contains("MissingCredential {"),
             "Missing MissingCredential variant"
         );
+        assert!(
+            code.contains("ClientConfig("),
+            "Missing ClientConfig variant"
+        );
+        assert!(code.contains("Cassette("), "Missing Cassette variant");
     }
 
     #[test]
@@ -0,0 +1,168 @@
+//! Idempotency key code generation.
+//!
+//! Generates the pieces generated clients need for idempotent endpoints:
+//!
+//! - The shared `InFlightRequests` registry, which coalesces identical
+//!   in-flight requests so only the first one is sent
+//! - `schematic_define::Idempotency` literals for per-endpoint settings
+//!
+//! Keys are ULIDs from `biscuit_hash::ulid()`, which the generated
+//! `Cargo.toml` enables.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use schematic_define::Idempotency;
+
+/// Generates the shared `InFlightRequests` registry for `shared.rs`.
+///
+/// ## Examples
+///
+/// ```ignore
+/// let fingerprint = (method.to_string(), url.clone(), body.clone());
+/// let response = self.in_flight.send(fingerprint, req_builder.send()).await?;
+/// ```
+pub fn generate_in_flight_requests_type() -> TokenStream {
+    quote! {
+        /// Identifies a request by method, URL and body.
+        pub type RequestFingerprint = (String, String, Option<String>);
+
+        /// A response read into memory so every coalesced caller gets a copy.
+        #[derive(Debug)]
+        struct SharedResponse {
+            status: reqwest::StatusCode,
+            headers: reqwest::header::HeaderMap,
+            body: bytes::Bytes,
+        }
+
+        impl SharedResponse {
+            /// Reads the status, headers and body of a response.
+            async fn read(response: reqwest::Response) -> Result<Self, SchematicError> {
+                let status = response.status();
+                let headers = response.headers().clone();
+                let body = response.bytes().await?;
+                Ok(Self { status, headers, body })
+            }
+
+            /// Rebuilds a response from the buffered parts.
+            fn to_response(&self) -> reqwest::Response {
+                let mut response = http::Response::new(self.body.clone());
+                *response.status_mut() = self.status;
+                *response.headers_mut() = self.headers.clone();
+                reqwest::Response::from(response)
+            }
+        }
+
+        /// The response of an in-flight request, once it arrives.
+        type InFlightResponse = std::sync::Arc<tokio::sync::OnceCell<SharedResponse>>;
+
+        /// Requests that are currently in flight, keyed by fingerprint.
+        ///
+        /// Cloning is cheap and clones share the same registry, so every
+        /// client variant created from one client coalesces with the others.
+        #[derive(Debug, Clone, Default)]
+        pub struct InFlightRequests {
+            requests: std::sync::Arc<
+                std::sync::Mutex<std::collections::HashMap<RequestFingerprint, InFlightResponse>>,
+            >,
+        }
+
+        impl InFlightRequests {
+            /// Sends a request unless an identical one is already in flight.
+            ///
+            /// The first caller for a fingerprint runs `send`. Callers that
+            /// arrive while it's in flight drop their own `send` unpolled and
+            /// get a copy of its response, error statuses included. If the
+            /// request fails before a response arrives, its caller gets the
+            /// error and the next waiting caller sends its own request.
+            ///
+            /// The fingerprint is forgotten once the response arrives, so a
+            /// later identical call sends a new request.
+            ///
+            /// ## Errors
+            ///
+            /// Returns the error from `send`, or `SchematicError::Http` if the
+            /// response body can't be read.
+            pub async fn send<F>(
+                &self,
+                fingerprint: RequestFingerprint,
+                send: F,
+            ) -> Result<reqwest::Response, SchematicError>
+            where
+                F: std::future::Future<Output = Result<reqwest::Response, SchematicError>>,
+            {
+                let cell = self.lock().entry(fingerprint.clone()).or_default().clone();
+                let result = cell
+                    .get_or_try_init(|| async { SharedResponse::read(send.await?).await })
+                    .await
+                    .map(SharedResponse::to_response);
+
+                // Keep a failed request's entry while other callers still wait on it
+                let mut requests = self.lock();
+                let finished = cell.initialized() || std::sync::Arc::strong_count(&cell) == 2;
+                if finished
+                    && requests
+                        .get(&fingerprint)
+                        .is_some_and(|current| std::sync::Arc::ptr_eq(current, &cell))
+                {
+                    requests.remove(&fingerprint);
+                }
+                result
+            }
+
+            /// Returns whether a request with this fingerprint is in flight.
+            pub fn contains(&self, fingerprint: &RequestFingerprint) -> bool {
+                self.lock().contains_key(fingerprint)
+            }
+
+            fn lock(
+                &self,
+            ) -> std::sync::MutexGuard<'_, std::collections::HashMap<RequestFingerprint, InFlightResponse>>
+            {
+                self.requests
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+            }
+        }
+    }
+}
+
+/// Generates the initialization code for an `Idempotency` value.
+pub(crate) fn generate_idempotency_init(idempotency: &Idempotency) -> TokenStream {
+    let header = &idempotency.header;
+    let dedupe_in_flight = idempotency.dedupe_in_flight;
+
+    quote! {
+        schematic_define::Idempotency {
+            header: #header.to_string(),
+            dedupe_in_flight: #dedupe_in_flight,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::request_structs::validate_generated_code;
+
+    #[test]
+    fn in_flight_requests_type_is_valid_syntax() {
+        let tokens = generate_in_flight_requests_type();
+        assert!(validate_generated_code(&tokens).is_ok());
+    }
+
+    #[test]
+    fn in_flight_requests_share_one_response() {
+        let code = generate_in_flight_requests_type().to_string();
+        assert!(code.contains("tokio :: sync :: OnceCell < SharedResponse >"));
+        assert!(code.contains("get_or_try_init"));
+        assert!(code.contains("requests . remove (& fingerprint)"));
+    }
+
+    #[test]
+    fn idempotency_init_includes_header_and_dedupe() {
+        let idempotency = Idempotency::with_header("X-Request-Key").dedupe_in_flight();
+        let code = generate_idempotency_init(&idempotency).to_string();
+        assert!(code.contains("\"X-Request-Key\""));
+        assert!(code.contains("dedupe_in_flight : true"));
+    }
+}
@@ -8,9 +8,16 @@
 //!
 //! - [`api_struct`] - Generates the main API client struct with constructors
 //! - [`client`] - Generates the async `request()` method with auth handling
+//! - [`compression`] - Generates the request body compression helper
+//! - [`connection`] - Generates the `ApiClientBuilder` for proxy, TLS and pool settings
+//! - [`contract_tests`] - Generates contract tests from endpoint examples
 //! - [`error`] - Generates the `SchematicError` enum for runtime errors
+//! - [`idempotency`] - Generates the registry that coalesces identical in-flight requests
+//! - [`rate_limit`] - Generates the response error helper that detects rate limits
 //! - [`request_enum`] - Generates the unified request enum for all endpoints
 //! - [`request_structs`] - Generates per-endpoint request structs
+//! - [`usage_docs`] - Generates per-endpoint Markdown usage documentation
+//! - [`vcr`] - Generates the feature-gated request recording and replay module
 //!
 //! ## Code Generation Flow
 //!
@@ -19,6 +26,8 @@
 //! 3. The API struct is created via [`generate_api_struct`]
 //! 4. The request method is generated via [`generate_request_method`]
 //! 5. The error type is generated via [`generate_error_type`]
+//! 6. Endpoint examples become contract tests via [`generate_contract_tests`]
+//! 7. Opted-in APIs get a `docs` module via [`generate_usage_docs`]
 //!
 //! ## Output Format
 //!
@@ -30,17 +39,31 @@
 
 pub mod api_struct;
 pub mod client;
+pub mod compression;
+pub mod connection;
+pub mod contract_tests;
 pub mod error;
+pub mod idempotency;
 pub mod module_docs;
+pub mod rate_limit;
 pub mod request_enum;
 pub mod request_structs;
+pub mod usage_docs;
+pub mod vcr;
 
 pub use api_struct::generate_api_struct;
 pub use client::{generate_request_method, generate_request_method_with_suffix};
+pub use compression::generate_compress_body_fn;
+pub use connection::generate_client_builder_type;
+pub use contract_tests::generate_contract_tests;
 pub use error::{generate_error_type, generate_request_parts_type};
+pub use idempotency::generate_in_flight_requests_type;
 pub use module_docs::ModuleDocBuilder;
+pub use rate_limit::generate_response_error_fn;
 pub use request_enum::{generate_request_enum, generate_request_enum_with_suffix};
 pub use request_structs::{
     generate_request_struct, generate_request_struct_with_options,
     generate_request_struct_with_suffix,
 };
+pub use usage_docs::generate_usage_docs;
+pub use vcr::generate_vcr_module;
@@ -78,35 +78,8 @@ impl<'a> ModuleDocBuilder<'a> {
     }
 
     /// Generates the authentication section.
-    ///
-    /// Documents the authentication strategy and any environment variables
-    /// used for credentials.
     fn auth_section(&self) -> String {
-        let auth_desc = match &self.api.auth {
-            AuthStrategy::None => "No authentication required.".to_string(),
-            AuthStrategy::BearerToken { header } => {
-                let header_name = header.as_deref().unwrap_or("Authorization");
-                format!(
-                    "Uses Bearer token authentication via the `{}` header.",
-                    header_name
-                )
-            }
-            AuthStrategy::ApiKey { header } => {
-                format!("Uses API key authentication via the `{}` header.", header)
-            }
-            AuthStrategy::Basic => "Uses HTTP Basic authentication.".to_string(),
-        };
-
-        let env_info = if !self.api.env_auth.is_empty() {
-            format!(
-                " Set via environment variable: `{}`.",
-                self.api.env_auth.join("` or `")
-            )
-        } else {
-            String::new()
-        };
-
-        format!(" ## Authentication\n\n {}{}", auth_desc, env_info)
+        format!(" ## Authentication\n\n {}", auth_description(self.api))
     }
 
     /// Groups endpoints by their HTTP method.
@@ -184,6 +157,36 @@ impl<'a> ModuleDocBuilder<'a> {
     }
 }
 
+/// Describes an API's authentication strategy and any environment variables
+/// used for credentials.
+pub(crate) fn auth_description(api: &RestApi) -> String {
+    let auth_desc = match &api.auth {
+        AuthStrategy::None => "No authentication required.".to_string(),
+        AuthStrategy::BearerToken { header } => {
+            let header_name = header.as_deref().unwrap_or("Authorization");
+            format!(
+                "Uses Bearer token authentication via the `{}` header.",
+                header_name
+            )
+        }
+        AuthStrategy::ApiKey { header } => {
+            format!("Uses API key authentication via the `{}` header.", header)
+        }
+        AuthStrategy::Basic => "Uses HTTP Basic authentication.".to_string(),
+    };
+
+    let env_info = if !api.env_auth.is_empty() {
+        format!(
+            " Set via environment variable: `{}`.",
+            api.env_auth.join("` or `")
+        )
+    } else {
+        String::new()
+    };
+
+    format!("{}{}", auth_desc, env_info)
+}
+
 /// Converts a PascalCase string to snake_case.
 fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
@@ -223,9 +226,14 @@ mod tests {
                 request: None,
                 response: ApiResponse::json_type("ListItemsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             }],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         }
     }
 
@@ -338,6 +346,9 @@ mod tests {
             request: None,
             response: ApiResponse::json_type("CreateItemResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         });
         api.endpoints.push(Endpoint {
             id: "GetItem".to_string(),
@@ -347,6 +358,9 @@ mod tests {
             request: None,
             response: ApiResponse::json_type("Item"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         });
 
         let builder = ModuleDocBuilder::new(&api);
@@ -381,6 +395,9 @@ mod tests {
                 request: None,
                 response: ApiResponse::json_type("CreateItemResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
         );
 
@@ -403,6 +420,9 @@ mod tests {
             request: None,
             response: ApiResponse::json_type("CreateItemResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }];
 
         let builder = ModuleDocBuilder::new(&api);
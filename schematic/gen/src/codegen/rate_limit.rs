@@ -0,0 +1,91 @@
+//! Rate limit code generation.
+//!
+//! Generates the shared `response_error()` helper that turns a non-success
+//! response into a `SchematicError`. Responses rejected by a rate limit
+//! become `SchematicError::RateLimited` with the wait the server asked for,
+//! read from either:
+//!
+//! - `Retry-After` (delay in seconds), or
+//! - `X-RateLimit-Reset` (Unix time the window resets, as GitHub sends)
+//!
+//! A response counts as rate limited when its status is `429 Too Many
+//! Requests`, or `403 Forbidden` with `X-RateLimit-Remaining: 0` or a
+//! `Retry-After` header (GitHub's primary and secondary limits).
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the shared `response_error()` helper for `shared.rs`.
+///
+/// ## Examples
+///
+/// ```ignore
+/// let status = response.status().as_u16();
+/// let headers = response.headers().clone();
+/// let body = response.text().await.unwrap_or_default();
+/// return Err(response_error(status, &headers, body));
+/// ```
+pub fn generate_response_error_fn() -> TokenStream {
+    quote! {
+        /// Builds the error for a non-success response.
+        ///
+        /// A `429`, or a `403` with `X-RateLimit-Remaining: 0` or a
+        /// `Retry-After` header, becomes `SchematicError::RateLimited` with
+        /// the delay from `Retry-After` or `X-RateLimit-Reset`; any other
+        /// status becomes `SchematicError::ApiError`.
+        pub fn response_error(
+            status: u16,
+            headers: &reqwest::header::HeaderMap,
+            body: String,
+        ) -> SchematicError {
+            let header = |name: &str| {
+                headers
+                    .get(name)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.trim().parse::<u64>().ok())
+            };
+            let retry_after = header("retry-after").map(std::time::Duration::from_secs);
+            let exhausted = header("x-ratelimit-remaining") == Some(0);
+
+            if status != 429 && !(status == 403 && (exhausted || retry_after.is_some())) {
+                return SchematicError::ApiError { status, body };
+            }
+
+            let retry_after = retry_after.or_else(|| {
+                let reset = header("x-ratelimit-reset")?;
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .ok()?
+                    .as_secs();
+                Some(std::time::Duration::from_secs(reset.saturating_sub(now)))
+            });
+            SchematicError::RateLimited {
+                status,
+                retry_after,
+                body,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::request_structs::validate_generated_code;
+
+    #[test]
+    fn response_error_fn_is_valid_syntax() {
+        let tokens = generate_response_error_fn();
+        assert!(validate_generated_code(&tokens).is_ok());
+    }
+
+    #[test]
+    fn response_error_fn_reads_rate_limit_headers() {
+        let code = generate_response_error_fn().to_string();
+        assert!(code.contains(r#""retry-after""#));
+        assert!(code.contains(r#""x-ratelimit-remaining""#));
+        assert!(code.contains(r#""x-ratelimit-reset""#));
+        assert!(code.contains("SchematicError :: RateLimited"));
+        assert!(code.contains("SchematicError :: ApiError"));
+    }
+}
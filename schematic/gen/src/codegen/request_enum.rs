@@ -7,6 +7,9 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use schematic_define::RestApi;
 
+use super::compression::generate_compression_init;
+use super::idempotency::generate_idempotency_init;
+
 /// Default suffix for request struct names.
 const DEFAULT_REQUEST_SUFFIX: &str = "Request";
 
@@ -22,6 +25,8 @@ pub fn generate_request_enum(api: &RestApi) -> TokenStream {
 ///
 /// Creates an enum with one variant per endpoint, plus:
 /// - `into_parts()` method that delegates to inner request structs
+/// - `compression()` method returning endpoint-specific compression overrides
+/// - `idempotency()` method returning endpoint idempotency settings
 /// - `From<XxxRequest>` impl for each variant
 ///
 /// ## Arguments
@@ -70,6 +75,12 @@ pub fn generate_request_enum_with_suffix(api: &RestApi, suffix: &str) -> TokenSt
     // Generate into_parts match arms
     let match_arms = generate_match_arms(api);
 
+    // Generate compression override lookup
+    let compression_body = generate_compression_body(api);
+
+    // Generate idempotency settings lookup
+    let idempotency_body = generate_idempotency_body(api);
+
     // Generate individual From implementations
     let from_impls = generate_from_impls(api, &enum_name, suffix);
 
@@ -95,6 +106,17 @@ pub fn generate_request_enum_with_suffix(api: &RestApi, suffix: &str) -> TokenSt
                     #match_arms
                 }
             }
+
+            /// Returns the endpoint's compression settings, if the definition
+            /// overrides the API-level default for it.
+            pub fn compression(&self) -> Option<schematic_define::Compression> {
+                #compression_body
+            }
+
+            /// Returns the endpoint's idempotency settings, if it declares any.
+            pub fn idempotency(&self) -> Option<schematic_define::Idempotency> {
+                #idempotency_body
+            }
         }
 
         #from_impls
@@ -131,6 +153,71 @@ fn generate_match_arms(api: &RestApi) -> TokenStream {
     quote! { #(#arms)* }
 }
 
+/// Generates the body of the `compression()` method.
+///
+/// Only endpoints with an override get a match arm; when no endpoint has
+/// one, the method simply returns `None`.
+fn generate_compression_body(api: &RestApi) -> TokenStream {
+    let arms: Vec<TokenStream> = api
+        .endpoints
+        .iter()
+        .filter_map(|endpoint| {
+            let compression = endpoint.compression.as_ref()?;
+            let variant_name = format_ident!("{}", endpoint.id);
+            let init = generate_compression_init(compression);
+            Some(quote! {
+                Self::#variant_name(_) => Some(#init),
+            })
+        })
+        .collect();
+
+    generate_optional_match(api, arms)
+}
+
+/// Generates the body of the `idempotency()` method.
+///
+/// Only idempotent endpoints get a match arm; when there are none, the method
+/// simply returns `None`.
+fn generate_idempotency_body(api: &RestApi) -> TokenStream {
+    let arms: Vec<TokenStream> = api
+        .endpoints
+        .iter()
+        .filter_map(|endpoint| {
+            let idempotency = endpoint.idempotency.as_ref()?;
+            let variant_name = format_ident!("{}", endpoint.id);
+            let init = generate_idempotency_init(idempotency);
+            Some(quote! {
+                Self::#variant_name(_) => Some(#init),
+            })
+        })
+        .collect();
+
+    generate_optional_match(api, arms)
+}
+
+/// Wraps per-endpoint `Some(..)` arms in a match over `self`.
+///
+/// Adds a `_ => None` fallback unless every endpoint has an arm, and returns
+/// plain `None` when there are no arms at all.
+fn generate_optional_match(api: &RestApi, arms: Vec<TokenStream>) -> TokenStream {
+    if arms.is_empty() {
+        return quote! { None };
+    }
+
+    let fallback = if arms.len() < api.endpoints.len() {
+        quote! { _ => None, }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        match self {
+            #(#arms)*
+            #fallback
+        }
+    }
+}
+
 /// Generates individual `From` implementations for each request struct.
 fn generate_from_impls(api: &RestApi, enum_name: &proc_macro2::Ident, suffix: &str) -> TokenStream {
     let impls = api.endpoints.iter().map(|endpoint| {
@@ -153,7 +240,10 @@ fn generate_from_impls(api: &RestApi, enum_name: &proc_macro2::Ident, suffix: &s
 mod tests {
     use super::*;
     use crate::codegen::request_structs::{format_generated_code, validate_generated_code};
-    use schematic_define::{ApiRequest, ApiResponse, AuthStrategy, Endpoint, RestMethod};
+    use schematic_define::{
+        ApiRequest, ApiResponse, AuthStrategy, Compression, ContentEncoding, Endpoint, Idempotency,
+        RestMethod,
+    };
 
     fn make_api(name: &str, endpoints: Vec<Endpoint>) -> RestApi {
         RestApi {
@@ -168,6 +258,8 @@ mod tests {
             endpoints,
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         }
     }
 
@@ -185,6 +277,9 @@ mod tests {
             request,
             response: ApiResponse::json_type("TestResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }
     }
 
@@ -316,6 +411,9 @@ mod tests {
                 request: None,
                 response: ApiResponse::json_type("User"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             }],
         );
 
@@ -370,4 +468,104 @@ mod tests {
         let code = format_generated_code(&tokens).expect("Failed to format code");
         assert!(code.contains("pub enum EmptyRequest"));
     }
+
+    #[test]
+    fn compression_without_overrides_returns_none() {
+        let api = make_api(
+            "Plain",
+            vec![make_endpoint("ListItems", RestMethod::Get, "/items", None)],
+        );
+
+        let tokens = generate_request_enum(&api);
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(
+            code.contains("pub fn compression(&self) -> Option<schematic_define::Compression>")
+        );
+        assert!(!code.contains("Self::ListItems(_) =>"));
+    }
+
+    #[test]
+    fn compression_overrides_get_match_arms() {
+        let mut upload = make_endpoint(
+            "Upload",
+            RestMethod::Post,
+            "/upload",
+            Some(ApiRequest::json_type("UploadBody")),
+        );
+        upload.compression =
+            Some(Compression::default().compress_requests(ContentEncoding::Gzip, 1024));
+        let api = make_api(
+            "Storage",
+            vec![
+                make_endpoint("ListItems", RestMethod::Get, "/items", None),
+                upload,
+            ],
+        );
+
+        let tokens = generate_request_enum(&api);
+        assert!(validate_generated_code(&tokens).is_ok());
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(code.contains("Self::Upload(_) => {"));
+        assert!(code.contains("schematic_define::ContentEncoding::Gzip"));
+        assert!(code.contains("_ => None"));
+    }
+
+    #[test]
+    fn compression_overrides_on_every_endpoint_omit_fallback() {
+        let mut endpoint = make_endpoint("Stream", RestMethod::Get, "/stream", None);
+        endpoint.compression = Some(Compression::disabled());
+        let api = make_api("Streaming", vec![endpoint]);
+
+        let tokens = generate_request_enum(&api);
+        assert!(validate_generated_code(&tokens).is_ok());
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(code.contains("decompress_responses: false"));
+        assert!(!code.contains("_ => None"));
+    }
+
+    #[test]
+    fn idempotency_without_idempotent_endpoints_returns_none() {
+        let api = make_api(
+            "Plain",
+            vec![make_endpoint("ListItems", RestMethod::Get, "/items", None)],
+        );
+
+        let tokens = generate_request_enum(&api);
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(
+            code.contains("pub fn idempotency(&self) -> Option<schematic_define::Idempotency>")
+        );
+        assert!(!code.contains("Self::ListItems(_) =>"));
+    }
+
+    #[test]
+    fn idempotent_endpoints_get_match_arms() {
+        let mut charge = make_endpoint(
+            "CreateCharge",
+            RestMethod::Post,
+            "/charges",
+            Some(ApiRequest::json_type("CreateChargeBody")),
+        );
+        charge.idempotency = Some(Idempotency::default().dedupe_in_flight());
+        let api = make_api(
+            "Payments",
+            vec![
+                make_endpoint("ListCharges", RestMethod::Get, "/charges", None),
+                charge,
+            ],
+        );
+
+        let tokens = generate_request_enum(&api);
+        assert!(validate_generated_code(&tokens).is_ok());
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(code.contains("Self::CreateCharge(_) => {"));
+        assert!(code.contains(r#"header: "Idempotency-Key".to_string()"#));
+        assert!(code.contains("dedupe_in_flight: true"));
+        assert!(code.contains("_ => None"));
+    }
 }
@@ -32,6 +32,9 @@ const DEFAULT_REQUEST_SUFFIX: &str = "Request";
 ///     request: None,
 ///     headers: vec![],
 ///     ...
+///     examples: vec![],
+///     compression: None,
+///     idempotency: None,
 /// }
 ///
 /// // Generated struct:
@@ -96,6 +99,7 @@ pub fn generate_request_struct_with_options(
     // Generate struct fields
     let param_fields = generate_param_fields(&path_params);
     let body_field = generate_body_field(endpoint);
+    let idempotency_field = generate_idempotency_key_field(endpoint);
 
     // Generate derives (Default only if no body or body type implements Default)
     let derives = generate_derives(has_body);
@@ -104,7 +108,16 @@ pub fn generate_request_struct_with_options(
     let default_impl = generate_default_impl(&struct_name, &path_params, has_body);
 
     // Generate new() constructor for type-safe construction
-    let new_impl = generate_new_impl(&struct_name, &path_params, has_body, body_type_name);
+    let new_impl = generate_new_impl(
+        &struct_name,
+        &path_params,
+        has_body,
+        body_type_name,
+        endpoint.idempotency.is_some(),
+    );
+
+    // Generate with_idempotency_key() builder for idempotent endpoints
+    let idempotency_builder = generate_idempotency_key_builder(endpoint);
 
     // Generate into_parts method
     let into_parts = generate_into_parts(endpoint, &path_params, &method_str);
@@ -124,9 +137,13 @@ pub fn generate_request_struct_with_options(
         quote! {
             #param_fields
             #body_field
+            #idempotency_field
         }
     } else {
-        param_fields
+        quote! {
+            #param_fields
+            #idempotency_field
+        }
     };
 
     quote! {
@@ -141,6 +158,8 @@ pub fn generate_request_struct_with_options(
         #new_impl
 
         impl #struct_name {
+            #idempotency_builder
+
             #into_parts
         }
     }
@@ -272,6 +291,42 @@ fn generate_body_field(endpoint: &Endpoint) -> TokenStream {
     }
 }
 
+/// Generates the `idempotency_key` field for endpoints that declare idempotency.
+fn generate_idempotency_key_field(endpoint: &Endpoint) -> TokenStream {
+    match &endpoint.idempotency {
+        Some(idempotency) => {
+            let doc = format!(
+                " Key sent in the `{}` header; generated by the client when `None`.",
+                idempotency.header
+            );
+            quote! {
+                #[doc = #doc]
+                ///
+                /// Set the same key when retrying a request so the server can
+                /// recognize the retry instead of repeating the operation.
+                #[serde(skip)]
+                pub idempotency_key: Option<String>,
+            }
+        }
+        None => quote! {},
+    }
+}
+
+/// Generates the `with_idempotency_key()` builder for idempotent endpoints.
+fn generate_idempotency_key_builder(endpoint: &Endpoint) -> TokenStream {
+    if endpoint.idempotency.is_none() {
+        return quote! {};
+    }
+
+    quote! {
+        /// Sets the idempotency key sent with this request.
+        pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+            self.idempotency_key = Some(key.into());
+            self
+        }
+    }
+}
+
 /// Generates derive attributes for the struct.
 fn generate_derives(_has_body: bool) -> TokenStream {
     // Always derive Default - all field types (String, body types) implement Default
@@ -304,6 +359,7 @@ fn generate_new_impl(
     path_params: &[&str],
     has_body: bool,
     body_type: Option<&str>,
+    has_idempotency: bool,
 ) -> TokenStream {
     let params: Vec<_> = path_params
         .iter()
@@ -313,13 +369,16 @@ fn generate_new_impl(
         })
         .collect();
 
-    let field_inits: Vec<_> = path_params
+    let mut field_inits: Vec<_> = path_params
         .iter()
         .map(|p| {
             let name = format_ident!("{}", p);
             quote! { #name: #name.into() }
         })
         .collect();
+    if has_idempotency {
+        field_inits.push(quote! { idempotency_key: None });
+    }
 
     if has_body {
         let body_ty = format_ident!("{}", body_type.unwrap());
@@ -372,6 +431,21 @@ fn generate_into_parts(endpoint: &Endpoint, path_params: &[&str], method_str: &s
     // Generate headers initialization
     let headers_init = generate_endpoint_headers_init(&endpoint.headers);
 
+    // A caller-supplied idempotency key travels with the endpoint headers
+    let (headers_setup, headers_expr) = match &endpoint.idempotency {
+        Some(idempotency) => {
+            let header = &idempotency.header;
+            let setup = quote! {
+                let mut headers: Vec<(String, String)> = #headers_init;
+                if let Some(key) = self.idempotency_key {
+                    headers.push((#header.to_string(), key));
+                }
+            };
+            (setup, quote! { headers })
+        }
+        None => (quote! {}, headers_init),
+    };
+
     quote! {
         /// Converts the request into (method, path, body, headers) parts.
         ///
@@ -389,7 +463,8 @@ fn generate_into_parts(endpoint: &Endpoint, path_params: &[&str], method_str: &s
         /// fails to serialize to JSON.
         pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
             #path_format
-            Ok((#method_str, path, #body_expr, #headers_init))
+            #headers_setup
+            Ok((#method_str, path, #body_expr, #headers_expr))
         }
     }
 }
@@ -472,6 +547,9 @@ mod tests {
             request,
             response: ApiResponse::json_type("TestResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }
     }
 
@@ -900,4 +978,35 @@ mod tests {
             code
         );
     }
+
+    #[test]
+    fn idempotent_endpoint_gets_key_field_and_builder() {
+        let mut endpoint = make_endpoint(
+            "CreateCharge",
+            RestMethod::Post,
+            "/charges",
+            Some(ApiRequest::json_type("CreateChargeBody")),
+        );
+        endpoint.idempotency = Some(schematic_define::Idempotency::default());
+        let tokens = generate_request_struct(&endpoint);
+        assert!(validate_generated_code(&tokens).is_ok());
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(code.contains("#[serde(skip)]\n    pub idempotency_key: Option<String>"));
+        assert!(code.contains("idempotency_key: None"));
+        assert!(
+            code.contains("pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self")
+        );
+        assert!(code.contains(r#"headers.push(("Idempotency-Key".to_string(), key));"#));
+    }
+
+    #[test]
+    fn non_idempotent_endpoint_has_no_key_field() {
+        let endpoint = make_endpoint("ListCharges", RestMethod::Get, "/charges", None);
+        let tokens = generate_request_struct(&endpoint);
+        let code = format_generated_code(&tokens).expect("Failed to format code");
+
+        assert!(!code.contains("idempotency_key"));
+        assert!(code.contains("Ok((\"GET\", path, None, vec![]))"));
+    }
 }
@@ -0,0 +1,426 @@
+//! Usage documentation generation for API endpoints.
+//!
+//! When an API sets [`RestApi::usage_docs`], the generated module gets a
+//! `docs` submodule with one Markdown document per endpoint: what the
+//! endpoint does, the Rust signatures used to call it, a sample call, and
+//! the authentication it needs. Each document is a `&str` constant (so it
+//! can be rendered with darkmatter or written to disk) and is also attached
+//! to that constant as rustdoc, so the API docs are rebuilt from the same
+//! definition as the client every time the code is regenerated.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use schematic_define::{ApiRequest, ApiResponse, Endpoint, RestApi};
+
+use super::client::to_snake_case;
+use super::module_docs::auth_description;
+use crate::parser::extract_path_params;
+
+/// Generates the `docs` module for an API.
+///
+/// Returns an empty `TokenStream` unless `api.usage_docs` is set.
+///
+/// ## Arguments
+///
+/// * `api` - The REST API definition
+/// * `request_suffix` - Suffix used for the generated request structs
+/// * `module_path` - Module the client is generated into (e.g., "openai")
+///
+/// ## Examples
+///
+/// ```ignore
+/// // For an API with a single "ListModels" endpoint:
+/// pub mod docs {
+///     /// ## ListModels
+///     /// ...
+///     pub const LIST_MODELS: &str = "## ListModels\n\n...";
+///
+///     /// Every endpoint's usage documentation as `(endpoint id, Markdown)` pairs.
+///     pub const ENDPOINTS: &[(&str, &str)] = &[("ListModels", LIST_MODELS)];
+/// }
+/// ```
+pub fn generate_usage_docs(api: &RestApi, request_suffix: &str, module_path: &str) -> TokenStream {
+    if !api.usage_docs {
+        return TokenStream::new();
+    }
+
+    let mut constants = Vec::new();
+    let mut entries = Vec::new();
+
+    for endpoint in &api.endpoints {
+        let const_name = format_ident!("{}", to_snake_case(&endpoint.id).to_uppercase());
+        let markdown = endpoint_markdown(api, endpoint, request_suffix, module_path, "rust");
+        // Rustdoc would compile the sample as a doctest, so mark it `ignore`
+        let doc_lines: Vec<String> =
+            endpoint_markdown(api, endpoint, request_suffix, module_path, "ignore")
+                .lines()
+                .map(|line| format!(" {}", line))
+                .collect();
+        let id = &endpoint.id;
+
+        constants.push(quote! {
+            #(#[doc = #doc_lines])*
+            pub const #const_name: &str = #markdown;
+        });
+        entries.push(quote! { (#id, #const_name) });
+    }
+
+    let module_doc = format!(
+        " Markdown usage documentation for each `{}` endpoint.",
+        api.name
+    );
+
+    quote! {
+        #[doc = #module_doc]
+        ///
+        /// Generated from the API definition alongside the client, so it always
+        /// matches the generated request types.
+        pub mod docs {
+            #(#constants)*
+
+            /// Every endpoint's usage documentation as `(endpoint id, Markdown)` pairs,
+            /// in definition order.
+            pub const ENDPOINTS: &[(&str, &str)] = &[#(#entries),*];
+        }
+    }
+}
+
+/// Builds the Markdown usage document for one endpoint.
+///
+/// `fence` is the info string for code blocks: `rust` for the string
+/// constant, `ignore` for rustdoc.
+fn endpoint_markdown(
+    api: &RestApi,
+    endpoint: &Endpoint,
+    request_suffix: &str,
+    module_path: &str,
+    fence: &str,
+) -> String {
+    let struct_name = format!("{}{}", endpoint.id, request_suffix);
+    let path_params = extract_path_params(&endpoint.path);
+
+    let mut lines = vec![
+        format!("## {}", endpoint.id),
+        String::new(),
+        endpoint.description.clone(),
+        String::new(),
+        format!("`{} {}`", endpoint.method, endpoint.path),
+        String::new(),
+        format!("- **Request:** {}", request_summary(endpoint)),
+        format!("- **Response:** {}", response_summary(&endpoint.response)),
+        String::new(),
+        "### Signature".to_string(),
+        String::new(),
+        format!("```{}", fence),
+    ];
+    lines.extend(signature_lines(api, endpoint, &struct_name, &path_params));
+    lines.extend([
+        "```".to_string(),
+        String::new(),
+        "### Example".to_string(),
+        String::new(),
+        format!("```{}", fence),
+    ]);
+    lines.extend(example_lines(
+        api,
+        endpoint,
+        &struct_name,
+        &path_params,
+        module_path,
+    ));
+    lines.extend([
+        "```".to_string(),
+        String::new(),
+        "### Authentication".to_string(),
+        String::new(),
+        auth_description(api),
+    ]);
+
+    if !endpoint.headers.is_empty() {
+        let headers: Vec<String> = endpoint
+            .headers
+            .iter()
+            .map(|(key, value)| format!("`{}: {}`", key, value))
+            .collect();
+        lines.push(String::new());
+        lines.push(format!("Also sends {}.", headers.join(", ")));
+    }
+
+    if let Some(idempotency) = &endpoint.idempotency {
+        lines.push(String::new());
+        lines.push(format!(
+            "Sends an idempotency key in the `{}` header. A key is generated for each \
+             request unless one is set with `with_idempotency_key()`.",
+            idempotency.header
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Describes the request body.
+fn request_summary(endpoint: &Endpoint) -> String {
+    match &endpoint.request {
+        None => "no body".to_string(),
+        Some(ApiRequest::Json(schema)) => format!("`{}` (JSON)", schema.type_name),
+        Some(ApiRequest::FormData { .. }) => "multipart form data".to_string(),
+        Some(ApiRequest::UrlEncoded { .. }) => "URL-encoded form".to_string(),
+        Some(ApiRequest::Text { content_type }) => format!("text (`{}`)", content_type),
+        Some(ApiRequest::Binary { content_type }) => format!("binary (`{}`)", content_type),
+    }
+}
+
+/// Describes the response body.
+fn response_summary(response: &ApiResponse) -> String {
+    match response {
+        ApiResponse::Json(schema) => format!("`{}` (JSON)", schema.type_name),
+        ApiResponse::Text => "text".to_string(),
+        ApiResponse::Binary => "binary (`bytes::Bytes`)".to_string(),
+        ApiResponse::Empty => "empty".to_string(),
+    }
+}
+
+/// Returns the JSON body type name, if the endpoint has a typed body.
+fn json_body_type(endpoint: &Endpoint) -> Option<&str> {
+    match &endpoint.request {
+        Some(ApiRequest::Json(schema)) => Some(schema.type_name.as_str()),
+        _ => None,
+    }
+}
+
+/// Lists the signatures of the constructor and client method for an endpoint.
+fn signature_lines(
+    api: &RestApi,
+    endpoint: &Endpoint,
+    struct_name: &str,
+    path_params: &[&str],
+) -> Vec<String> {
+    let mut params: Vec<String> = path_params
+        .iter()
+        .map(|p| format!("{}: impl Into<String>", p))
+        .collect();
+    if let Some(body_ty) = json_body_type(endpoint) {
+        params.push(format!("body: {}", body_ty));
+    }
+
+    let mut lines = vec![format!("impl {} {{", struct_name)];
+    if params.is_empty() {
+        lines.push("    fn default() -> Self;".to_string());
+    } else {
+        lines.push(format!("    pub fn new({}) -> Self;", params.join(", ")));
+    }
+    if endpoint.idempotency.is_some() {
+        lines.push(
+            "    pub fn with_idempotency_key(self, key: impl Into<String>) -> Self;".to_string(),
+        );
+    }
+    lines.push("}".to_string());
+    lines.push(String::new());
+
+    lines.push(format!("impl {} {{", api.name));
+    let method = to_snake_case(&endpoint.id);
+    match &endpoint.response {
+        ApiResponse::Json(_) => lines.push(format!(
+            "    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<{}Request>) -> Result<T, SchematicError>;",
+            api.name
+        )),
+        ApiResponse::Text => lines.push(format!(
+            "    pub async fn {}(&self, request: {}) -> Result<String, SchematicError>;",
+            method, struct_name
+        )),
+        ApiResponse::Binary => lines.push(format!(
+            "    pub async fn {}(&self, request: {}) -> Result<bytes::Bytes, SchematicError>;",
+            method, struct_name
+        )),
+        ApiResponse::Empty => lines.push(format!(
+            "    pub async fn {}(&self, request: {}) -> Result<(), SchematicError>;",
+            method, struct_name
+        )),
+    }
+    lines.push("}".to_string());
+
+    lines
+}
+
+/// Builds a sample call for an endpoint.
+///
+/// Uses the first example request payload from the definition when there
+/// is one; otherwise shows the body built from `Default`.
+fn example_lines(
+    api: &RestApi,
+    endpoint: &Endpoint,
+    struct_name: &str,
+    path_params: &[&str],
+    module_path: &str,
+) -> Vec<String> {
+    let body_ty = json_body_type(endpoint);
+    let mut imports = vec![api.name.clone(), struct_name.to_string()];
+    if let Some(body_ty) = body_ty {
+        imports.push(body_ty.to_string());
+    }
+
+    let mut lines = vec![
+        format!(
+            "use schematic_schema::{}::{{{}}};",
+            module_path,
+            imports.join(", ")
+        ),
+        String::new(),
+        format!("let client = {}::new();", api.name),
+    ];
+
+    let mut args: Vec<String> = path_params
+        .iter()
+        .map(|p| format!("\"{}_value\"", p))
+        .collect();
+
+    if let Some(body_ty) = body_ty {
+        let payload = endpoint.examples.iter().find_map(|e| e.request.as_deref());
+        match payload {
+            Some(payload) => lines.push(format!(
+                "let body: {} = serde_json::from_str(r#\"{}\"#)?;",
+                body_ty, payload
+            )),
+            None => {
+                lines.push(format!("let body = {} {{", body_ty));
+                lines.push("    // ... set required fields ...".to_string());
+                lines.push("    ..Default::default()".to_string());
+                lines.push("};".to_string());
+            }
+        }
+        args.push("body".to_string());
+    }
+
+    if args.is_empty() {
+        lines.push(format!("let request = {}::default();", struct_name));
+    } else {
+        lines.push(format!(
+            "let request = {}::new({});",
+            struct_name,
+            args.join(", ")
+        ));
+    }
+
+    let method = to_snake_case(&endpoint.id);
+    match &endpoint.response {
+        ApiResponse::Json(schema) => lines.push(format!(
+            "let response: {} = client.request(request).await?;",
+            schema.type_name
+        )),
+        ApiResponse::Text => lines.push(format!("let text = client.{}(request).await?;", method)),
+        ApiResponse::Binary => {
+            lines.push(format!("let bytes = client.{}(request).await?;", method))
+        }
+        ApiResponse::Empty => lines.push(format!("client.{}(request).await?;", method)),
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schematic_define::{AuthStrategy, EndpointExample, Idempotency, RestMethod};
+
+    fn make_api() -> RestApi {
+        RestApi {
+            name: "TestApi".to_string(),
+            description: "A test API".to_string(),
+            base_url: "https://api.test.com".to_string(),
+            docs_url: None,
+            auth: AuthStrategy::BearerToken { header: None },
+            env_auth: vec!["TEST_API_KEY".to_string()],
+            env_username: None,
+            headers: vec![],
+            endpoints: vec![
+                Endpoint {
+                    id: "GetItem".to_string(),
+                    method: RestMethod::Get,
+                    path: "/items/{item_id}".to_string(),
+                    description: "Get an item".to_string(),
+                    request: None,
+                    response: ApiResponse::json_type("Item"),
+                    headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
+                },
+                Endpoint {
+                    id: "CreateItem".to_string(),
+                    method: RestMethod::Post,
+                    path: "/items".to_string(),
+                    description: "Create an item".to_string(),
+                    request: Some(ApiRequest::json_type("CreateItemBody")),
+                    response: ApiResponse::Empty,
+                    headers: vec![("X-Beta".to_string(), "items".to_string())],
+                    examples: vec![
+                        EndpointExample::new("basic").with_request(r#"{"name": "widget"}"#),
+                    ],
+                    compression: None,
+                    idempotency: Some(Idempotency::default()),
+                },
+            ],
+            module_path: None,
+            request_suffix: None,
+            usage_docs: true,
+            compression: None,
+        }
+    }
+
+    #[test]
+    fn disabled_api_generates_nothing() {
+        let mut api = make_api();
+        api.usage_docs = false;
+        assert!(generate_usage_docs(&api, "Request", "testapi").is_empty());
+    }
+
+    #[test]
+    fn generates_constant_per_endpoint_and_index() {
+        let api = make_api();
+        let code = generate_usage_docs(&api, "Request", "testapi").to_string();
+
+        assert!(code.contains("pub mod docs"));
+        assert!(code.contains("pub const GET_ITEM : & str"));
+        assert!(code.contains("pub const CREATE_ITEM : & str"));
+        assert!(code.contains("(\"GetItem\" , GET_ITEM)"));
+        assert!(code.contains("(\"CreateItem\" , CREATE_ITEM)"));
+    }
+
+    #[test]
+    fn markdown_shows_signature_and_sample_call() {
+        let api = make_api();
+        let md = endpoint_markdown(&api, &api.endpoints[0], "Request", "testapi", "rust");
+
+        assert!(md.starts_with("## GetItem\n\nGet an item"));
+        assert!(md.contains("`GET /items/{item_id}`"));
+        assert!(md.contains("pub fn new(item_id: impl Into<String>) -> Self;"));
+        assert!(md.contains("use schematic_schema::testapi::{TestApi, GetItemRequest};"));
+        assert!(md.contains("let request = GetItemRequest::new(\"item_id_value\");"));
+        assert!(md.contains("let response: Item = client.request(request).await?;"));
+        assert!(md.contains("`Authorization` header"));
+        assert!(md.contains("`TEST_API_KEY`"));
+    }
+
+    #[test]
+    fn markdown_uses_example_body_headers_and_idempotency() {
+        let api = make_api();
+        let md = endpoint_markdown(&api, &api.endpoints[1], "Request", "testapi", "rust");
+
+        assert!(md.contains("- **Request:** `CreateItemBody` (JSON)"));
+        assert!(md.contains(
+            r##"let body: CreateItemBody = serde_json::from_str(r#"{"name": "widget"}"#)?;"##
+        ));
+        assert!(md.contains("pub async fn create_item(&self, request: CreateItemRequest) -> Result<(), SchematicError>;"));
+        assert!(md.contains("client.create_item(request).await?;"));
+        assert!(md.contains("Also sends `X-Beta: items`."));
+        assert!(md.contains("`Idempotency-Key` header"));
+    }
+
+    #[test]
+    fn rustdoc_samples_are_ignored() {
+        let api = make_api();
+        let md = endpoint_markdown(&api, &api.endpoints[0], "Request", "testapi", "ignore");
+        assert!(md.contains("```ignore"));
+        assert!(!md.contains("```rust"));
+    }
+}
@@ -0,0 +1,392 @@
+//! Request/response recording and replay ("VCR") code generation.
+//!
+//! Generates the `shared::vcr` module, which lets a generated client record
+//! live API responses to a cassette directory and replay them later, so
+//! crates built on the generated clients can write deterministic
+//! integration tests:
+//!
+//! - Each request is keyed by a hash of its method, path (including the
+//!   query string) and body, so recordings work with any base URL.
+//! - Each interaction is stored as one JSON file, `<method>-<hash>.json`.
+//! - Request headers are never recorded, so credentials stay out of
+//!   cassettes; replayed requests don't need credentials either.
+//!
+//! The module and the client's `with_cassette()` builder are compiled only
+//! with the generated crate's `vcr` feature, which pulls in `base64`, `http`
+//! and the `xx_hash` feature of `biscuit-hash`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `vcr` module for `shared.rs`.
+///
+/// ## Examples
+///
+/// ```ignore
+/// let request = vcr::RecordedRequest::new(method, &path, body.as_deref());
+/// if let Some(interaction) = cassette.replay(&request)? {
+///     return interaction.into_response();
+/// }
+/// ```
+pub fn generate_vcr_module() -> TokenStream {
+    quote! {
+        /// Request/response recording and replay for deterministic tests.
+        ///
+        /// Attach a [`vcr::Cassette`] to a client with `with_cassette()`:
+        /// in [`vcr::VcrMode::Record`] every response is saved to the cassette
+        /// directory, in [`vcr::VcrMode::Replay`] responses come from the
+        /// cassette without touching the network, and
+        /// [`vcr::VcrMode::Auto`] replays what it has and records the rest.
+        ///
+        /// Requires the `vcr` feature.
+        #[cfg(feature = "vcr")]
+        pub mod vcr {
+            use std::path::{Path, PathBuf};
+
+            use base64::Engine;
+
+            use super::SchematicError;
+
+            /// Environment variable read by [`Cassette::from_env`]
+            /// (`replay`, `record` or `auto`).
+            pub const VCR_MODE_ENV: &str = "SCHEMATIC_VCR_MODE";
+
+            /// Response headers that are not recorded.
+            ///
+            /// Bodies are stored decoded, so encoding and length headers would
+            /// no longer match them, and cookies may hold session secrets.
+            const SKIPPED_HEADERS: &[&str] = &[
+                "content-encoding",
+                "content-length",
+                "set-cookie",
+                "transfer-encoding",
+            ];
+
+            /// How a [`Cassette`] treats requests.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+            pub enum VcrMode {
+                /// Serve recorded responses; requests without one fail.
+                #[default]
+                Replay,
+                /// Always call the API and (re-)record its responses.
+                Record,
+                /// Serve recorded responses and record requests without one.
+                Auto,
+            }
+
+            impl VcrMode {
+                /// Parses `replay`, `record` or `auto` (case-insensitive).
+                pub fn parse(value: &str) -> Option<Self> {
+                    match value.trim().to_ascii_lowercase().as_str() {
+                        "replay" => Some(Self::Replay),
+                        "record" => Some(Self::Record),
+                        "auto" => Some(Self::Auto),
+                        _ => None,
+                    }
+                }
+            }
+
+            /// The parts of a request that identify its recording.
+            #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+            pub struct RecordedRequest {
+                /// HTTP method (e.g., "GET").
+                pub method: String,
+                /// URL path and query string, without the base URL.
+                pub path: String,
+                /// JSON request body, if any.
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub body: Option<String>,
+            }
+
+            impl RecordedRequest {
+                /// Creates the recording key for a request.
+                pub fn new(method: &str, path: &str, body: Option<&str>) -> Self {
+                    Self {
+                        method: method.to_string(),
+                        path: path.to_string(),
+                        body: body.map(str::to_string),
+                    }
+                }
+
+                /// The cassette file stem for this request: the lowercase
+                /// method and a hash of the method, path and body.
+                pub fn key(&self) -> String {
+                    let hash = biscuit_hash::xx_hash(&format!(
+                        "{} {}\n{}",
+                        self.method,
+                        self.path,
+                        self.body.as_deref().unwrap_or_default()
+                    ));
+                    format!("{}-{:016x}", self.method.to_ascii_lowercase(), hash)
+                }
+            }
+
+            /// A recorded response.
+            #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+            pub struct RecordedResponse {
+                /// HTTP status code.
+                pub status: u16,
+                /// Response headers as (name, value) pairs.
+                #[serde(default)]
+                pub headers: Vec<(String, String)>,
+                /// Body, when it is valid UTF-8.
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub body: Option<String>,
+                /// Base64-encoded body, when it is binary.
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub body_base64: Option<String>,
+            }
+
+            impl RecordedResponse {
+                /// Creates a recorded response, storing binary bodies as base64.
+                pub fn new(status: u16, headers: Vec<(String, String)>, body: &[u8]) -> Self {
+                    let (body, body_base64) = match std::str::from_utf8(body) {
+                        Ok(text) => (Some(text.to_string()), None),
+                        Err(_) => (
+                            None,
+                            Some(base64::engine::general_purpose::STANDARD.encode(body)),
+                        ),
+                    };
+                    Self {
+                        status,
+                        headers,
+                        body,
+                        body_base64,
+                    }
+                }
+
+                /// Returns the body bytes.
+                ///
+                /// ## Errors
+                ///
+                /// Returns `SchematicError::Cassette` if `body_base64` is not
+                /// valid base64.
+                pub fn body_bytes(&self) -> Result<Vec<u8>, SchematicError> {
+                    match (&self.body, &self.body_base64) {
+                        (_, Some(encoded)) => base64::engine::general_purpose::STANDARD
+                            .decode(encoded)
+                            .map_err(|e| SchematicError::Cassette(format!("invalid body_base64: {}", e))),
+                        (Some(text), None) => Ok(text.as_bytes().to_vec()),
+                        (None, None) => Ok(Vec::new()),
+                    }
+                }
+            }
+
+            /// A request and the response the API gave, as stored in a
+            /// cassette file.
+            #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+            pub struct Interaction {
+                /// The request that was sent.
+                pub request: RecordedRequest,
+                /// The response that came back.
+                pub response: RecordedResponse,
+            }
+
+            impl Interaction {
+                /// Rebuilds the recorded response.
+                ///
+                /// A non-success status becomes the same error a live response
+                /// would (see `response_error()`).
+                ///
+                /// ## Errors
+                ///
+                /// Returns the API error for non-success responses, or
+                /// `SchematicError::Cassette` if the recording is malformed.
+                pub fn into_response(self) -> Result<reqwest::Response, SchematicError> {
+                    let response = self.to_http_response()?;
+                    if response.status().is_success() {
+                        return Ok(response);
+                    }
+                    let headers = response.headers().clone();
+                    let body = String::from_utf8_lossy(&self.response.body_bytes()?).into_owned();
+                    Err(super::response_error(self.response.status, &headers, body))
+                }
+
+                /// Rebuilds the recorded response without checking its status.
+                fn to_http_response(&self) -> Result<reqwest::Response, SchematicError> {
+                    let mut builder = http::Response::builder().status(self.response.status);
+                    for (name, value) in &self.response.headers {
+                        builder = builder.header(name.as_str(), value.as_str());
+                    }
+                    let response = builder
+                        .body(self.response.body_bytes()?)
+                        .map_err(|e| SchematicError::Cassette(format!("invalid recorded response: {}", e)))?;
+                    Ok(reqwest::Response::from(response))
+                }
+            }
+
+            /// A directory of recorded interactions, one JSON file per request.
+            ///
+            /// ## Examples
+            ///
+            /// ```ignore
+            /// use schematic_schema::shared::vcr::{Cassette, VcrMode};
+            ///
+            /// // Record once with SCHEMATIC_VCR_MODE=record, replay in CI
+            /// let cassette = Cassette::from_env("tests/cassettes/github");
+            /// let client = GitHub::new().with_cassette(cassette);
+            /// ```
+            #[derive(Debug, Clone)]
+            pub struct Cassette {
+                dir: PathBuf,
+                mode: VcrMode,
+            }
+
+            impl Cassette {
+                /// Creates a cassette stored in `dir`.
+                pub fn new(dir: impl Into<PathBuf>, mode: VcrMode) -> Self {
+                    Self {
+                        dir: dir.into(),
+                        mode,
+                    }
+                }
+
+                /// Creates a cassette stored in `dir`, with the mode taken from
+                /// the `SCHEMATIC_VCR_MODE` environment variable.
+                ///
+                /// Defaults to [`VcrMode::Replay`] when the variable is unset or
+                /// not a valid mode.
+                pub fn from_env(dir: impl Into<PathBuf>) -> Self {
+                    let mode = std::env::var(VCR_MODE_ENV)
+                        .ok()
+                        .and_then(|value| VcrMode::parse(&value))
+                        .unwrap_or_default();
+                    Self::new(dir, mode)
+                }
+
+                /// The directory holding the recordings.
+                pub fn dir(&self) -> &Path {
+                    &self.dir
+                }
+
+                /// How this cassette treats requests.
+                pub fn mode(&self) -> VcrMode {
+                    self.mode
+                }
+
+                /// The file a request's interaction is stored in.
+                pub fn path_for(&self, request: &RecordedRequest) -> PathBuf {
+                    self.dir.join(format!("{}.json", request.key()))
+                }
+
+                /// Loads the recorded interaction for a request, if any.
+                ///
+                /// ## Errors
+                ///
+                /// Returns `SchematicError::Cassette` if the file exists but
+                /// cannot be read or parsed.
+                pub fn load(&self, request: &RecordedRequest) -> Result<Option<Interaction>, SchematicError> {
+                    let path = self.path_for(request);
+                    let content = match std::fs::read_to_string(&path) {
+                        Ok(content) => content,
+                        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                        Err(e) => {
+                            return Err(SchematicError::Cassette(format!(
+                                "failed to read {}: {}",
+                                path.display(),
+                                e
+                            )));
+                        }
+                    };
+                    serde_json::from_str(&content).map(Some).map_err(|e| {
+                        SchematicError::Cassette(format!("invalid recording {}: {}", path.display(), e))
+                    })
+                }
+
+                /// Returns the recorded interaction to serve instead of calling
+                /// the API, or `None` if the request should go to the network.
+                ///
+                /// ## Errors
+                ///
+                /// Returns `SchematicError::Cassette` in replay mode when the
+                /// request has no recording, or if a recording is malformed.
+                pub fn replay(&self, request: &RecordedRequest) -> Result<Option<Interaction>, SchematicError> {
+                    match self.mode {
+                        VcrMode::Record => Ok(None),
+                        VcrMode::Auto => self.load(request),
+                        VcrMode::Replay => match self.load(request)? {
+                            Some(interaction) => Ok(Some(interaction)),
+                            None => Err(SchematicError::Cassette(format!(
+                                "no recording for {} {} in {}",
+                                request.method,
+                                request.path,
+                                self.dir.display()
+                            ))),
+                        },
+                    }
+                }
+
+                /// Records a live response and returns an equivalent one.
+                ///
+                /// The body is read in full (streaming responses are buffered)
+                /// and written to the cassette whatever the status code, so
+                /// error responses replay as errors.
+                ///
+                /// ## Errors
+                ///
+                /// Returns an error if the body cannot be read or the
+                /// recording cannot be written.
+                pub async fn record(
+                    &self,
+                    request: &RecordedRequest,
+                    response: reqwest::Response,
+                ) -> Result<reqwest::Response, SchematicError> {
+                    let status = response.status().as_u16();
+                    let headers = response
+                        .headers()
+                        .iter()
+                        .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+                        .filter_map(|(name, value)| {
+                            Some((name.to_string(), value.to_str().ok()?.to_string()))
+                        })
+                        .collect();
+                    let body = response.bytes().await?;
+                    let interaction = Interaction {
+                        request: request.clone(),
+                        response: RecordedResponse::new(status, headers, &body),
+                    };
+                    self.save(&interaction)?;
+                    interaction.to_http_response()
+                }
+
+                /// Writes an interaction to its cassette file.
+                fn save(&self, interaction: &Interaction) -> Result<(), SchematicError> {
+                    let path = self.path_for(&interaction.request);
+                    let content = serde_json::to_string_pretty(interaction)?;
+                    std::fs::create_dir_all(&self.dir)
+                        .and_then(|()| std::fs::write(&path, content))
+                        .map_err(|e| {
+                            SchematicError::Cassette(format!("failed to write {}: {}", path.display(), e))
+                        })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::request_structs::validate_generated_code;
+
+    #[test]
+    fn vcr_module_is_valid_syntax() {
+        let tokens = generate_vcr_module();
+        assert!(validate_generated_code(&tokens).is_ok());
+    }
+
+    #[test]
+    fn vcr_module_is_feature_gated() {
+        let code = generate_vcr_module().to_string();
+        assert!(code.starts_with("# [doc"));
+        assert!(code.contains("# [cfg (feature = \"vcr\")] pub mod vcr"));
+    }
+
+    #[test]
+    fn vcr_module_keys_requests_by_hash() {
+        let code = generate_vcr_module().to_string();
+        assert!(code.contains("biscuit_hash :: xx_hash"));
+        assert!(code.contains("\"SCHEMATIC_VCR_MODE\""));
+        assert!(code.contains("\"set-cookie\""));
+    }
+}
@@ -57,4 +57,18 @@ pub enum GeneratorError {
         /// Explanation of why the suffix is invalid.
         reason: String,
     },
+
+    /// Invalid endpoint example.
+    ///
+    /// Examples must have a usable name, at least one payload, valid JSON,
+    /// and a JSON schema on the side (request/response) they exemplify.
+    #[error("Invalid example '{example}' for endpoint '{endpoint_id}': {reason}")]
+    InvalidExample {
+        /// The endpoint ID the example belongs to.
+        endpoint_id: String,
+        /// The example name.
+        example: String,
+        /// Explanation of why the example is invalid.
+        reason: String,
+    },
 }
@@ -10,6 +10,7 @@ use colored::Colorize;
 use schematic_definitions::anthropic::define_anthropic_api;
 use schematic_definitions::elevenlabs::define_elevenlabs_rest_api;
 use schematic_definitions::emqx::{define_emqx_basic_api, define_emqx_bearer_api};
+use schematic_definitions::github::define_github_api;
 use schematic_definitions::huggingface::define_huggingface_hub_api;
 use schematic_definitions::ollama::{define_ollama_native_api, define_ollama_openai_api};
 use schematic_definitions::openai::define_openai_api;
@@ -19,7 +20,7 @@ use schematic_gen::output::{generate_and_write, generate_and_write_all};
 use schematic_gen::validate_api;
 
 /// List of available API names for error messages.
-const AVAILABLE_APIS: &str = "anthropic, openai, elevenlabs, huggingface, ollama-native, ollama-openai, emqx-basic, emqx-bearer, all";
+const AVAILABLE_APIS: &str = "anthropic, openai, elevenlabs, huggingface, github, ollama-native, ollama-openai, emqx-basic, emqx-bearer, all";
 
 /// Schematic code generator - transforms API definitions into typed Rust clients
 #[derive(Parser, Debug)]
@@ -79,6 +80,7 @@ fn resolve_api(name: &str) -> Result<schematic_define::RestApi, GeneratorError>
         "openai" => Ok(define_openai_api()),
         "elevenlabs" => Ok(define_elevenlabs_rest_api()),
         "huggingface" => Ok(define_huggingface_hub_api()),
+        "github" => Ok(define_github_api()),
         "ollama-native" => Ok(define_ollama_native_api()),
         "ollama-openai" => Ok(define_ollama_openai_api()),
         "emqx-basic" => Ok(define_emqx_basic_api()),
@@ -103,6 +105,7 @@ fn resolve_all_apis() -> Vec<schematic_define::RestApi> {
         define_openai_api(),
         define_elevenlabs_rest_api(),
         define_huggingface_hub_api(),
+        define_github_api(),
         // Note: Ollama APIs excluded from "all" - generate individually
         // define_ollama_native_api(),
         // define_ollama_openai_api(),
@@ -134,6 +137,7 @@ fn run_validation(api: &schematic_define::RestApi, verbose: u8) -> bool {
                 "{} No naming collisions detected",
                 "  [PASS]".green().bold()
             );
+            println!("{} Endpoint examples", "  [PASS]".green().bold());
             println!();
             println!(
                 "{} All validation checks passed for '{}'",
@@ -173,6 +177,24 @@ fn run_validation(api: &schematic_define::RestApi, verbose: u8) -> bool {
                         suggestion.green()
                     );
                 }
+                GeneratorError::InvalidExample {
+                    endpoint_id,
+                    example,
+                    reason,
+                } => {
+                    println!("{} Request suffix format", "  [PASS]".green().bold());
+                    println!(
+                        "{} No naming collisions detected",
+                        "  [PASS]".green().bold()
+                    );
+                    println!(
+                        "{} Example '{}' in endpoint '{}'",
+                        "  [FAIL]".red().bold(),
+                        example,
+                        endpoint_id
+                    );
+                    println!("         {}", reason);
+                }
                 _ => {
                     println!("{} {}", "  [FAIL]".red().bold(), err);
                 }
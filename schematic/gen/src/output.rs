@@ -17,19 +17,25 @@
 //! ## Safety Guarantees
 //!
 //! - **Validation**: All generated code is validated with `syn` before writing
-//! - **Formatting**: Output is formatted with `prettyplease` for consistent style
+//! - **Formatting**: Output is formatted with `prettyplease`, then `rustfmt` when
+//!   it's installed, so generated files pass `cargo fmt --check`
 //! - **Atomic writes**: Uses temp file + rename pattern to prevent partial writes
 
 use std::fs;
+use std::io::Write;
 use std::path::Path;
+use std::process::{Command, Stdio};
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use schematic_define::RestApi;
 
 use crate::codegen::{
-    ModuleDocBuilder, generate_api_struct, generate_error_type, generate_request_enum_with_suffix,
-    generate_request_method_with_suffix, generate_request_parts_type, generate_request_struct_with_options,
+    ModuleDocBuilder, generate_api_struct, generate_client_builder_type, generate_compress_body_fn,
+    generate_contract_tests, generate_error_type, generate_in_flight_requests_type,
+    generate_request_enum_with_suffix, generate_request_method_with_suffix,
+    generate_request_parts_type, generate_request_struct_with_options, generate_response_error_fn,
+    generate_usage_docs, generate_vcr_module,
 };
 use crate::errors::GeneratorError;
 use crate::inference::infer_module_path;
@@ -91,7 +97,13 @@ fn get_request_suffix(api: &RestApi) -> String {
 /// - Module documentation
 /// - Common error type used by all API clients
 /// - Common type aliases (e.g., `RequestParts`)
+/// - Request body compression helper (`compress_body`)
+/// - Non-success response helper that detects rate limits (`response_error`)
+/// - Registry that coalesces identical in-flight requests (`InFlightRequests`)
+/// - Connection-level client configuration (`ApiClientBuilder`)
+/// - Request recording and replay (`vcr`, behind the `vcr` feature)
 /// - Re-export of reqwest for downstream crates
+/// - Re-export of the Server-Sent Events parser for streaming endpoints
 ///
 /// ## Returns
 ///
@@ -100,6 +112,11 @@ pub fn assemble_shared_module() -> TokenStream {
     // Generate shared types
     let request_parts_type = generate_request_parts_type();
     let error_type = generate_error_type();
+    let compress_body_fn = generate_compress_body_fn();
+    let response_error_fn = generate_response_error_fn();
+    let in_flight_requests_type = generate_in_flight_requests_type();
+    let client_builder_type = generate_client_builder_type();
+    let vcr_module = generate_vcr_module();
 
     quote! {
         //! Shared types and utilities for generated API clients.
@@ -110,9 +127,26 @@ pub fn assemble_shared_module() -> TokenStream {
         /// reqwest as a direct dependency.
         pub use reqwest;
 
+        /// Re-export the Server-Sent Events parser for streaming endpoints.
+        ///
+        /// Endpoints that stream (e.g. chat completions with `stream: true`)
+        /// respond with a `text/event-stream`; feed the response bytes to an
+        /// [`sse::SseParser`] to get its events.
+        pub use schematic_define::sse;
+
         #request_parts_type
 
         #error_type
+
+        #compress_body_fn
+
+        #response_error_fn
+
+        #in_flight_requests_type
+
+        #client_builder_type
+
+        #vcr_module
     }
 }
 
@@ -126,6 +160,7 @@ pub fn assemble_shared_module() -> TokenStream {
 /// - Request enum (unifying all endpoints)
 /// - API client struct
 /// - Request method implementation
+/// - Contract tests for endpoint examples (if any)
 ///
 /// The error type (`SchematicError`) is imported from the shared module
 /// rather than being duplicated in each API module.
@@ -157,6 +192,12 @@ pub fn assemble_api_module(api: &RestApi) -> TokenStream {
     // Generate request method with the appropriate suffix
     let request_method = generate_request_method_with_suffix(api, &suffix);
 
+    // Generate contract tests from endpoint examples
+    let contract_tests = generate_contract_tests(api);
+
+    // Generate per-endpoint usage docs for APIs that opt in
+    let usage_docs = generate_usage_docs(api, &suffix, &api_name_lower);
+
     // Generate rich module documentation
     let module_docs = ModuleDocBuilder::new(api).build();
 
@@ -173,7 +214,7 @@ pub fn assemble_api_module(api: &RestApi) -> TokenStream {
         pub use schematic_definitions::#definitions_module::*;
 
         // Import shared types
-        use crate::shared::{RequestParts, SchematicError};
+        use crate::shared::{InFlightRequests, RequestParts, SchematicError, compress_body, response_error};
 
         #request_structs
 
@@ -182,6 +223,10 @@ pub fn assemble_api_module(api: &RestApi) -> TokenStream {
         #api_struct
 
         #request_method
+
+        #usage_docs
+
+        #contract_tests
     }
 }
 
@@ -296,7 +341,7 @@ pub fn assemble_prelude(apis: &[&RestApi]) -> TokenStream {
         //! ```
 
         // Shared types
-        pub use crate::shared::{RequestParts, SchematicError};
+        pub use crate::shared::{ApiClientBuilder, RequestParts, SchematicError};
 
         // API clients and request types
         #(#api_reexports)*
@@ -330,10 +375,13 @@ pub fn validate_code(tokens: &TokenStream) -> Result<syn::File, GeneratorError>
         .map_err(|e| GeneratorError::CodeGenError(format!("Generated code is invalid: {}", e)))
 }
 
-/// Formats generated code using prettyplease.
+/// Formats generated code using prettyplease, then rustfmt.
 ///
 /// Converts a parsed syn::File back to a nicely formatted string,
-/// prepending an auto-generated notice as a regular comment.
+/// prepending an auto-generated notice as a regular comment. prettyplease
+/// wraps long expressions differently from rustfmt, so its output is passed
+/// through `rustfmt` when it's installed, keeping generated files clean under
+/// `cargo fmt --check`.
 ///
 /// ## Arguments
 ///
@@ -343,7 +391,8 @@ pub fn validate_code(tokens: &TokenStream) -> Result<syn::File, GeneratorError>
 ///
 /// A formatted string representation of the code with auto-generated notice.
 pub fn format_code(file: &syn::File) -> String {
-    let formatted = prettyplease::unparse(file);
+    let unparsed = prettyplease::unparse(file);
+    let formatted = rustfmt(&unparsed).unwrap_or(unparsed);
     // Prepend auto-generated notice as regular comment
     format!(
         "// This code was automatically generated by schematic-gen. Do not edit manually.\n\n{}",
@@ -351,6 +400,25 @@ pub fn format_code(file: &syn::File) -> String {
     )
 }
 
+/// Formats code with `rustfmt`, returning `None` if it isn't installed or
+/// rejects the input.
+fn rustfmt(code: &str) -> Option<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2024", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    // rustfmt reads all of stdin before writing, so this can't deadlock
+    child.stdin.take()?.write_all(code.as_bytes()).ok()?;
+    let output = child.wait_with_output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
 /// Writes content to a file atomically using temp file + rename.
 ///
 /// This pattern ensures that:
@@ -540,9 +608,14 @@ mod tests {
                 request: None,
                 response: ApiResponse::json_type("ListItemsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             }],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         }
     }
 
@@ -565,6 +638,9 @@ mod tests {
                     request: None,
                     response: ApiResponse::json_type("ListModelsResponse"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 Endpoint {
                     id: "RetrieveModel".to_string(),
@@ -574,6 +650,9 @@ mod tests {
                     request: None,
                     response: ApiResponse::json_type("Model"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 Endpoint {
                     id: "CreateCompletion".to_string(),
@@ -583,10 +662,15 @@ mod tests {
                     request: Some(ApiRequest::json_type("CreateCompletionRequest")),
                     response: ApiResponse::json_type("Completion"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
             ],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         }
     }
 
@@ -719,11 +803,24 @@ mod tests {
         let formatted = format_code(&file);
 
         // All major elements should be present
-        assert!(formatted.contains("use crate::shared::{RequestParts, SchematicError}"));
+        assert!(formatted.contains(
+            "InFlightRequests, RequestParts, SchematicError, compress_body, response_error,"
+        ));
         assert!(formatted.contains("pub struct OpenAI"));
         assert!(formatted.contains("pub enum OpenAIRequest"));
     }
 
+    #[test]
+    fn format_code_output_is_rustfmt_clean() {
+        let file = validate_code(&assemble_shared_module()).unwrap();
+        let formatted = format_code(&file);
+
+        // Without rustfmt installed the prettyplease output is kept as-is
+        if let Some(reformatted) = rustfmt(&formatted) {
+            assert_eq!(reformatted, formatted);
+        }
+    }
+
     // === write_atomic tests ===
 
     #[test]
@@ -837,13 +934,16 @@ mod tests {
         // Should have all components
         assert!(content.contains("pub struct OpenAI"));
         assert!(content.contains("pub enum OpenAIRequest"));
-        assert!(content.contains("use crate::shared::{RequestParts, SchematicError}"));
+        assert!(content.contains(
+            "InFlightRequests, RequestParts, SchematicError, compress_body, response_error,"
+        ));
 
         // Check shared.rs exists and contains SchematicError and RequestParts
         let shared_path = temp_dir.path().join("shared.rs");
         let shared_content = fs::read_to_string(shared_path).unwrap();
         assert!(shared_content.contains("pub type RequestParts"));
         assert!(shared_content.contains("pub enum SchematicError"));
+        assert!(shared_content.contains("pub use schematic_define::sse;"));
 
         // Check lib.rs exists and has module declarations
         let lib_path = temp_dir.path().join("lib.rs");
@@ -926,9 +1026,14 @@ mod tests {
                     request: None,
                     response: ApiResponse::json_type("TestResponse"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 }],
                 module_path: None,
                 request_suffix: None,
+                usage_docs: false,
+                compression: None,
             };
 
             let temp_dir = TempDir::new().unwrap();
@@ -960,6 +1065,9 @@ mod tests {
                 request: None,
                 response: ApiResponse::json_type("Response"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             })
             .collect();
 
@@ -975,6 +1083,8 @@ mod tests {
             endpoints,
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         };
 
         let temp_dir = TempDir::new().unwrap();
@@ -1006,6 +1116,8 @@ mod tests {
             endpoints: vec![],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         };
 
         let temp_dir = TempDir::new().unwrap();
@@ -27,6 +27,8 @@ pub fn make_api(name: &str, base_url: &str, auth: AuthStrategy, env_auth: Vec<St
         endpoints: vec![],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -49,6 +51,8 @@ pub fn make_api_with_endpoint(
         endpoints: vec![endpoint],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -74,6 +78,9 @@ pub fn make_endpoint(
         request,
         response: ApiResponse::json_type("TestResponse"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     }
 }
 
@@ -96,9 +103,14 @@ pub fn make_simple_api() -> RestApi {
             request: None,
             response: ApiResponse::json_type("ListItemsResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -127,6 +139,9 @@ pub fn make_complex_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ListModelsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "RetrieveModel".to_string(),
@@ -136,6 +151,9 @@ pub fn make_complex_api() -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Model"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "CreateCompletion".to_string(),
@@ -145,10 +163,15 @@ pub fn make_complex_api() -> RestApi {
                 request: Some(ApiRequest::json_type("CreateCompletionRequest")),
                 response: ApiResponse::json_type("Completion"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
         ],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -9,6 +9,7 @@
 //! - **Naming collisions**: Ensures body type names don't conflict with
 //!   generated request struct names
 //! - **Request suffix format**: Validates the request suffix is alphanumeric
+//! - **Endpoint examples**: Ensures examples can become contract tests
 //!
 //! ## Examples
 //!
@@ -28,12 +29,18 @@
 //!     endpoints: vec![],
 //!     module_path: None,
 //!     request_suffix: None,
+//!     usage_docs: false,
+//!     compression: None,
 //! };
 //!
 //! assert!(validate_api(&api).is_ok());
 //! ```
 
-use schematic_define::{ApiRequest, RestApi};
+use std::collections::HashSet;
+
+use schematic_define::{ApiRequest, ApiResponse, Endpoint, RestApi};
+
+use crate::codegen::contract_tests::example_ident;
 
 use crate::errors::GeneratorError;
 
@@ -51,6 +58,10 @@ const DEFAULT_REQUEST_SUFFIX: &str = "Request";
 ///    checks that the body type name doesn't match the generated wrapper struct
 ///    name (`{endpoint_id}{suffix}`).
 ///
+/// 3. **Example validation**: Each endpoint example must have a unique name
+///    (letters, digits, `_`, `-`, spaces), at least one payload, valid JSON,
+///    and a JSON request/response schema for each payload it provides.
+///
 /// ## Examples
 ///
 /// Valid API passes validation:
@@ -77,10 +88,15 @@ const DEFAULT_REQUEST_SUFFIX: &str = "Request";
 ///             request: Some(ApiRequest::json_type("CreateUserBody")), // Different from CreateUserRequest
 ///             response: ApiResponse::json_type("User"),
 ///             headers: vec![],
+///             examples: vec![],
+///             compression: None,
+///             idempotency: None,
 ///         },
 ///     ],
 ///     module_path: None,
 ///     request_suffix: None,
+///     usage_docs: false,
+///     compression: None,
 /// };
 ///
 /// assert!(validate_api(&api).is_ok());
@@ -93,6 +109,9 @@ const DEFAULT_REQUEST_SUFFIX: &str = "Request";
 ///
 /// Returns `GeneratorError::NamingCollision` if a body type name matches
 /// the generated wrapper struct name.
+///
+/// Returns `GeneratorError::InvalidExample` if an endpoint example can't be
+/// turned into a contract test.
 pub fn validate_api(api: &RestApi) -> Result<(), GeneratorError> {
     // Get the effective suffix (custom or default)
     let suffix = api
@@ -137,13 +156,93 @@ pub fn validate_api(api: &RestApi) -> Result<(), GeneratorError> {
         }
     }
 
+    // Check 3: Validate endpoint examples
+    for endpoint in &api.endpoints {
+        validate_examples(endpoint)?;
+    }
+
+    Ok(())
+}
+
+/// Validates the examples attached to an endpoint.
+///
+/// ## Errors
+///
+/// Returns `GeneratorError::InvalidExample` describing the first problem found.
+fn validate_examples(endpoint: &Endpoint) -> Result<(), GeneratorError> {
+    let mut seen = HashSet::new();
+
+    for example in &endpoint.examples {
+        let invalid = |reason: String| GeneratorError::InvalidExample {
+            endpoint_id: endpoint.id.clone(),
+            example: example.name.clone(),
+            reason,
+        };
+
+        let name = example.name.trim();
+        if name.is_empty() {
+            return Err(invalid("example name cannot be empty".to_string()));
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | ' '))
+        {
+            return Err(invalid(
+                "example name must contain only letters, digits, '_', '-', or spaces".to_string(),
+            ));
+        }
+        if !seen.insert(example_ident(name)) {
+            return Err(invalid(
+                "duplicate example name for this endpoint".to_string(),
+            ));
+        }
+
+        if example.request.is_none() && example.response.is_none() {
+            return Err(invalid(
+                "example must include a request or response payload".to_string(),
+            ));
+        }
+
+        if let Some(payload) = &example.request {
+            let Some(ApiRequest::Json(schema)) = &endpoint.request else {
+                return Err(invalid(
+                    "request examples require a JSON request body".to_string(),
+                ));
+            };
+            validate_example_payload("request", &schema.type_name, payload).map_err(invalid)?;
+        }
+
+        if let Some(payload) = &example.response {
+            let ApiResponse::Json(schema) = &endpoint.response else {
+                return Err(invalid(
+                    "response examples require a JSON response".to_string(),
+                ));
+            };
+            validate_example_payload("response", &schema.type_name, payload).map_err(invalid)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that an example payload is valid JSON and its schema type is a valid Rust type.
+fn validate_example_payload(side: &str, type_name: &str, payload: &str) -> Result<(), String> {
+    if let Err(e) = serde_json::from_str::<serde_json::Value>(payload) {
+        return Err(format!("{} payload is not valid JSON: {}", side, e));
+    }
+    if syn::parse_str::<syn::Type>(type_name).is_err() {
+        return Err(format!(
+            "{} schema type '{}' is not a valid Rust type",
+            side, type_name
+        ));
+    }
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use schematic_define::{ApiResponse, AuthStrategy, Endpoint, RestMethod};
+    use schematic_define::{ApiResponse, AuthStrategy, Endpoint, EndpointExample, RestMethod};
 
     /// Helper to create a minimal API for testing.
     fn make_test_api() -> RestApi {
@@ -159,6 +258,8 @@ mod tests {
             endpoints: vec![],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         }
     }
 
@@ -172,6 +273,9 @@ mod tests {
             request: Some(ApiRequest::json_type(body_type)),
             response: ApiResponse::json_type("Response"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }
     }
 
@@ -185,6 +289,9 @@ mod tests {
             request: None,
             response: ApiResponse::json_type("Response"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }
     }
 
@@ -386,9 +493,133 @@ mod tests {
             request: Some(ApiRequest::form_data(vec![FormField::file("document")])),
             response: ApiResponse::json_type("UploadResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }];
 
         // FormData doesn't have a body type name, so no collision possible
         assert!(validate_api(&api).is_ok());
     }
+
+    /// Helper to validate a single endpoint carrying the given examples.
+    fn validate_with_examples(
+        mut endpoint: Endpoint,
+        examples: Vec<EndpointExample>,
+    ) -> Result<(), GeneratorError> {
+        endpoint.examples = examples;
+        let mut api = make_test_api();
+        api.endpoints = vec![endpoint];
+        validate_api(&api)
+    }
+
+    /// Extracts the reason from an `InvalidExample` error.
+    fn example_error_reason(result: Result<(), GeneratorError>) -> String {
+        match result.unwrap_err() {
+            GeneratorError::InvalidExample { reason, .. } => reason,
+            other => panic!("Expected InvalidExample, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn valid_examples_pass() {
+        let result = validate_with_examples(
+            make_endpoint_with_body("CreateUser", "CreateUserBody"),
+            vec![
+                EndpointExample::new("basic")
+                    .with_request(r#"{"name": "Ada"}"#)
+                    .with_response(r#"{"id": 1}"#),
+                EndpointExample::response("with-defaults", "{}"),
+            ],
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn example_with_invalid_json_is_rejected() {
+        let result = validate_with_examples(
+            make_endpoint_no_body("GetUser"),
+            vec![EndpointExample::response("broken", r#"{"id": "#)],
+        );
+
+        let reason = example_error_reason(result);
+        assert!(reason.contains("response payload is not valid JSON"));
+    }
+
+    #[test]
+    fn request_example_without_json_body_is_rejected() {
+        let result = validate_with_examples(
+            make_endpoint_no_body("GetUser"),
+            vec![EndpointExample::new("basic").with_request("{}")],
+        );
+
+        let reason = example_error_reason(result);
+        assert!(reason.contains("JSON request body"));
+    }
+
+    #[test]
+    fn response_example_without_json_response_is_rejected() {
+        let mut endpoint = make_endpoint_no_body("GetAudio");
+        endpoint.response = ApiResponse::Binary;
+
+        let result =
+            validate_with_examples(endpoint, vec![EndpointExample::response("basic", "{}")]);
+
+        let reason = example_error_reason(result);
+        assert!(reason.contains("JSON response"));
+    }
+
+    #[test]
+    fn example_without_payload_is_rejected() {
+        let result = validate_with_examples(
+            make_endpoint_no_body("GetUser"),
+            vec![EndpointExample::new("empty")],
+        );
+
+        let reason = example_error_reason(result);
+        assert!(reason.contains("request or response payload"));
+    }
+
+    #[test]
+    fn duplicate_example_names_are_rejected() {
+        let result = validate_with_examples(
+            make_endpoint_no_body("GetUser"),
+            vec![
+                EndpointExample::response("with tools", "{}"),
+                EndpointExample::response("with_tools", "{}"),
+            ],
+        );
+
+        let reason = example_error_reason(result);
+        assert!(reason.contains("duplicate"));
+    }
+
+    #[test]
+    fn example_names_must_be_identifier_friendly() {
+        for name in ["", "  ", "café", "a.b"] {
+            let result = validate_with_examples(
+                make_endpoint_no_body("GetUser"),
+                vec![EndpointExample::response(name, "{}")],
+            );
+
+            assert!(
+                matches!(result, Err(GeneratorError::InvalidExample { .. })),
+                "name {:?} should be rejected",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_example_error_names_endpoint_and_example() {
+        let result = validate_with_examples(
+            make_endpoint_no_body("GetUser"),
+            vec![EndpointExample::response("broken", "not json")],
+        );
+
+        let msg = result.unwrap_err().to_string();
+        assert!(msg.contains("GetUser"));
+        assert!(msg.contains("broken"));
+    }
 }
@@ -170,7 +170,9 @@ fn generated_files_exist_and_have_expected_structure() {
     assert!(api_content.contains("OpenAI"));
 
     // Should have all the generated components
-    assert!(api_content.contains("use crate::shared::{RequestParts, SchematicError}"));
+    assert!(api_content.contains(
+        "InFlightRequests, RequestParts, SchematicError, compress_body, response_error,"
+    ));
     assert!(api_content.contains("pub struct OpenAI"));
     assert!(api_content.contains("pub enum OpenAIRequest"));
 
@@ -214,9 +216,14 @@ fn generate_code_for_various_api_configurations() {
                 request: None,
                 response: ApiResponse::json_type("RootResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             }],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         },
         // API with all HTTP methods
         RestApi {
@@ -237,6 +244,9 @@ fn generate_code_for_various_api_configurations() {
                     request: None,
                     response: ApiResponse::json_type("Response"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 Endpoint {
                     id: "Post".to_string(),
@@ -246,6 +256,9 @@ fn generate_code_for_various_api_configurations() {
                     request: Some(ApiRequest::json_type("CreateRequest")),
                     response: ApiResponse::json_type("Response"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 Endpoint {
                     id: "Put".to_string(),
@@ -255,6 +268,9 @@ fn generate_code_for_various_api_configurations() {
                     request: Some(ApiRequest::json_type("UpdateRequest")),
                     response: ApiResponse::json_type("Response"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 Endpoint {
                     id: "Patch".to_string(),
@@ -264,6 +280,9 @@ fn generate_code_for_various_api_configurations() {
                     request: Some(ApiRequest::json_type("PatchRequest")),
                     response: ApiResponse::json_type("Response"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
                 Endpoint {
                     id: "Delete".to_string(),
@@ -273,10 +292,15 @@ fn generate_code_for_various_api_configurations() {
                     request: None,
                     response: ApiResponse::json_type("Response"),
                     headers: vec![],
+                    examples: vec![],
+                    compression: None,
+                    idempotency: None,
                 },
             ],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         },
         // API with multiple path parameters
         RestApi {
@@ -296,9 +320,14 @@ fn generate_code_for_various_api_configurations() {
                 request: None,
                 response: ApiResponse::json_type("Item"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             }],
             module_path: None,
             request_suffix: None,
+            usage_docs: false,
+            compression: None,
         },
     ];
 
@@ -378,9 +407,14 @@ fn binary_response_generates_request_bytes_method() {
             request: None,
             response: ApiResponse::Binary,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     };
 
     let temp_dir = TempDir::new().expect("Failed to create temp directory");
@@ -440,9 +474,14 @@ fn text_response_generates_request_text_method() {
             request: None,
             response: ApiResponse::Text,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     };
 
     let temp_dir = TempDir::new().expect("Failed to create temp directory");
@@ -489,9 +528,14 @@ fn empty_response_generates_request_empty_method() {
             request: None,
             response: ApiResponse::Empty,
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     };
 
     let temp_dir = TempDir::new().expect("Failed to create temp directory");
@@ -535,6 +579,9 @@ fn mixed_response_types_generate_all_methods() {
                 request: None,
                 response: ApiResponse::json_type("JsonResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetBinary".to_string(),
@@ -544,6 +591,9 @@ fn mixed_response_types_generate_all_methods() {
                 request: None,
                 response: ApiResponse::Binary,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetText".to_string(),
@@ -553,6 +603,9 @@ fn mixed_response_types_generate_all_methods() {
                 request: None,
                 response: ApiResponse::Text,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "DeleteItem".to_string(),
@@ -562,10 +615,15 @@ fn mixed_response_types_generate_all_methods() {
                 request: None,
                 response: ApiResponse::Empty,
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
         ],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     };
 
     let temp_dir = TempDir::new().expect("Failed to create temp directory");
@@ -36,6 +36,9 @@ fn make_api(name: &str, auth: AuthStrategy, env_auth: Vec<String>) -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("ItemsResponse"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
             Endpoint {
                 id: "GetItem".to_string(),
@@ -45,10 +48,15 @@ fn make_api(name: &str, auth: AuthStrategy, env_auth: Vec<String>) -> RestApi {
                 request: None,
                 response: ApiResponse::json_type("Item"),
                 headers: vec![],
+                examples: vec![],
+                compression: None,
+                idempotency: None,
             },
         ],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -72,9 +80,14 @@ fn make_basic_auth_api(name: &str, username_env: &str, password_env: &str) -> Re
             request: None,
             response: ApiResponse::json_type("ItemsResponse"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         }],
         module_path: None,
         request_suffix: None,
+        usage_docs: false,
+        compression: None,
     }
 }
 
@@ -390,6 +403,31 @@ fn client_checks_response_status() {
     );
 }
 
+#[test]
+fn client_replays_cassette_before_auth_and_records_before_status_check() {
+    let api = make_api(
+        "TestApi",
+        AuthStrategy::BearerToken { header: None },
+        vec!["TEST_TOKEN".to_string()],
+    );
+    let tokens = assemble_api_code(&api);
+    let code = format_tokens(&tokens);
+
+    let replay = code
+        .find("cassette.replay(&request)?")
+        .expect("Should replay");
+    let auth = code.find("MissingCredential").expect("Should apply auth");
+    let record = code
+        .find("cassette.record(&request, response)")
+        .expect("Should record");
+    let status = code
+        .find("response.status().is_success()")
+        .expect("Should check status");
+
+    assert!(replay < auth, "Replay must not require credentials");
+    assert!(record < status, "Error responses must be recorded too");
+}
+
 #[test]
 fn client_deserializes_json_response() {
     let api = make_api("TestApi", AuthStrategy::None, vec![]);
@@ -409,15 +447,15 @@ fn client_handles_api_error() {
     let tokens = assemble_api_code(&api);
     let code = format_tokens(&tokens);
 
-    // Check for ApiError pattern (may be formatted across lines)
+    // Non-success responses go through the shared rate-limit-aware helper
     assert!(
-        code.contains("SchematicError::ApiError"),
-        "Should return ApiError for non-success status\nGenerated code:\n{}",
+        code.contains("response_error(status, &headers, body)"),
+        "Should build the error for non-success status\nGenerated code:\n{}",
         code
     );
     assert!(
-        code.contains("status") && code.contains("body"),
-        "ApiError should have status and body fields\nGenerated code:\n{}",
+        code.contains("response.headers().clone()"),
+        "Should pass the response headers to response_error\nGenerated code:\n{}",
         code
     );
 }
@@ -29,6 +29,9 @@ fn single_path_param_struct_has_field() {
         request: None,
         response: ApiResponse::json_type("User"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -56,6 +59,9 @@ fn single_path_param_into_parts_format_string() {
         request: None,
         response: ApiResponse::json_type("User"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -85,6 +91,9 @@ fn multiple_path_params_struct_has_all_fields() {
         request: None,
         response: ApiResponse::json_type("Message"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -111,6 +120,9 @@ fn multiple_path_params_into_parts_format_string() {
         request: None,
         response: ApiResponse::json_type("Message"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -134,6 +146,9 @@ fn three_path_params_into_parts_format_string() {
         request: None,
         response: ApiResponse::json_type("Comments"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -174,6 +189,9 @@ fn no_path_params_no_fields() {
         request: None,
         response: ApiResponse::json_type("ListResponse"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -207,6 +225,9 @@ fn path_param_with_body_has_both_fields() {
         request: Some(ApiRequest::json_type("UpdateThreadBody")),
         response: ApiResponse::json_type("Thread"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -252,6 +273,9 @@ fn multiple_path_params_with_body() {
         request: Some(ApiRequest::json_type("CreateCommentBody")),
         response: ApiResponse::json_type("Comment"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -309,6 +333,9 @@ fn all_http_methods_generate_correct_method_string() {
             request: None,
             response: ApiResponse::json_type("Response"),
             headers: vec![],
+            examples: vec![],
+            compression: None,
+            idempotency: None,
         };
 
         let tokens = generate_request_struct(&endpoint);
@@ -338,6 +365,9 @@ fn consecutive_path_params() {
         request: None,
         response: ApiResponse::json_type("Response"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -363,6 +393,9 @@ fn path_param_at_start() {
         request: None,
         response: ApiResponse::json_type("Response"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -389,6 +422,9 @@ fn underscore_in_param_name() {
         request: None,
         response: ApiResponse::json_type("Profile"),
         headers: vec![],
+        examples: vec![],
+        compression: None,
+        idempotency: None,
     };
 
     let tokens = generate_request_struct(&endpoint);
@@ -32,9 +32,11 @@
      Ok(())
  }
  ```*/
-use serde::{Deserialize, Serialize};
+use crate::shared::{
+    InFlightRequests, RequestParts, SchematicError, compress_body, response_error,
+};
 pub use schematic_definitions::anthropic::*;
-use crate::shared::{RequestParts, SchematicError};
+use serde::{Deserialize, Serialize};
 /// Request for `CreateMessage` endpoint.
 ///
 /// ## Example
@@ -81,7 +83,7 @@ impl CreateMessageRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -133,7 +135,7 @@ impl CountTokensRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -187,7 +189,9 @@ pub struct RetrieveModelRequest {
 impl RetrieveModelRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(model_id: impl Into<String>) -> Self {
-        Self { model_id: model_id.into() }
+        Self {
+            model_id: model_id.into(),
+        }
     }
 }
 impl RetrieveModelRequest {
@@ -240,6 +244,15 @@ impl AnthropicRequest {
             Self::RetrieveModel(req) => req.into_parts(),
         }
     }
+    /// Returns the endpoint's compression settings, if the definition
+    /// overrides the API-level default for it.
+    pub fn compression(&self) -> Option<schematic_define::Compression> {
+        None
+    }
+    /// Returns the endpoint's idempotency settings, if it declares any.
+    pub fn idempotency(&self) -> Option<schematic_define::Idempotency> {
+        None
+    }
 }
 impl From<CreateMessageRequest> for AnthropicRequest {
     fn from(req: CreateMessageRequest) -> Self {
@@ -273,6 +286,13 @@ pub struct Anthropic {
     env_username: Option<String>,
     /// Default HTTP headers to include with every request.
     headers: Vec<(String, String)>,
+    /// Content-encoding settings for endpoints without their own.
+    compression: schematic_define::Compression,
+    /// Requests in flight, for coalescing; shared with variants.
+    in_flight: InFlightRequests,
+    /// Cassette that records or replays responses.
+    #[cfg(feature = "vcr")]
+    cassette: Option<crate::shared::vcr::Cassette>,
 }
 impl Anthropic {
     /// Base URL for the API.
@@ -288,6 +308,13 @@ impl Anthropic {
             },
             env_username: None,
             headers: vec![("anthropic-version".to_string(), "2023-06-01".to_string())],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a custom base URL.
@@ -307,6 +334,13 @@ impl Anthropic {
             },
             env_username: None,
             headers: vec![("anthropic-version".to_string(), "2023-06-01".to_string())],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a pre-configured reqwest client.
@@ -332,6 +366,13 @@ impl Anthropic {
             },
             env_username: None,
             headers: vec![("anthropic-version".to_string(), "2023-06-01".to_string())],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a pre-configured reqwest client and custom base URL.
@@ -345,10 +386,7 @@ impl Anthropic {
     ///     .unwrap();
     /// let api = Api::with_client_and_base_url(custom_client, "http://localhost:8080");
     /// ```
-    pub fn with_client_and_base_url(
-        client: reqwest::Client,
-        base_url: impl Into<String>,
-    ) -> Self {
+    pub fn with_client_and_base_url(client: reqwest::Client, base_url: impl Into<String>) -> Self {
         Self {
             client,
             base_url: base_url.into(),
@@ -358,8 +396,41 @@ impl Anthropic {
             },
             env_username: None,
             headers: vec![("anthropic-version".to_string(), "2023-06-01".to_string())],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
+    /// Creates a new API client whose HTTP client has the connection
+    /// settings of `builder` (proxy, root CAs, client certificate, DNS
+    /// overrides, pool sizing).
+    ///
+    /// For a custom base URL as well, pass `builder.build()?` to
+    /// `with_client_and_base_url()`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::ClientConfig` if a builder setting is invalid.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_schema::shared::ApiClientBuilder;
+    ///
+    /// let builder = ApiClientBuilder::new()
+    ///     .proxy("http://proxy.corp.example:3128")
+    ///     .root_certificate_pem(std::fs::read("/etc/ssl/corp-ca.pem")?);
+    /// let api = Api::with_client_builder(&builder)?;
+    /// ```
+    pub fn with_client_builder(
+        builder: &crate::shared::ApiClientBuilder,
+    ) -> Result<Self, SchematicError> {
+        Ok(Self::with_client(builder.build()?))
+    }
     /// Creates a variant of this API client with different configuration.
     ///
     /// This method clones the underlying HTTP client and allows customizing:
@@ -415,8 +486,49 @@ impl Anthropic {
             auth_strategy,
             env_username: self.env_username.clone(),
             headers: self.headers.clone(),
+            compression: self.compression,
+            in_flight: self.in_flight.clone(),
+            #[cfg(feature = "vcr")]
+            cassette: self.cassette.clone(),
         }
     }
+    /// Replaces the API-level compression settings for this client.
+    ///
+    /// Endpoints with their own compression settings in the definition
+    /// keep them.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_define::{Compression, ContentEncoding};
+    ///
+    /// // Gzip request bodies of 8 KiB or more
+    /// let api = Api::new().with_compression(
+    ///     Compression::default().compress_requests(ContentEncoding::Gzip, 8 * 1024),
+    /// );
+    /// ```
+    pub fn with_compression(mut self, compression: schematic_define::Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+    /// Records responses to, or replays them from, a cassette.
+    ///
+    /// Replayed requests never reach the network and need no
+    /// credentials. Requires the `vcr` feature; see
+    /// `crate::shared::vcr`.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_schema::shared::vcr::{Cassette, VcrMode};
+    ///
+    /// let api = Api::new().with_cassette(Cassette::new("tests/cassettes/api", VcrMode::Auto));
+    /// ```
+    #[cfg(feature = "vcr")]
+    pub fn with_cassette(mut self, cassette: crate::shared::vcr::Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
     /// Returns a reference to the underlying HTTP client.
     ///
     /// Use this for custom requests that aren't covered by the generated methods,
@@ -460,8 +572,22 @@ impl Anthropic {
         request: impl Into<AnthropicRequest>,
     ) -> Result<reqwest::Response, SchematicError> {
         let request = request.into();
+        let compression = request.compression().unwrap_or(self.compression);
+        let idempotency = request.idempotency();
         let (method, path, body, endpoint_headers) = request.into_parts()?;
         let url = format!("{}{}", self.base_url, path);
+        #[cfg(feature = "vcr")]
+        let recording = match &self.cassette {
+            Some(cassette) => {
+                let request =
+                    crate::shared::vcr::RecordedRequest::new(method, &path, body.as_deref());
+                if let Some(interaction) = cassette.replay(&request)? {
+                    return interaction.into_response();
+                }
+                Some((cassette, request))
+            }
+            None => None,
+        };
         let mut req_builder = match method {
             "GET" => self.client.get(&url),
             "POST" => self.client.post(&url),
@@ -483,8 +609,7 @@ impl Anthropic {
                     .ok_or_else(|| SchematicError::MissingCredential {
                         env_vars: self.env_auth.clone(),
                     })?;
-                req_builder = req_builder
-                    .header(header_name, format!("Bearer {}", token));
+                req_builder = req_builder.header(header_name, format!("Bearer {}", token));
             }
             schematic_define::AuthStrategy::ApiKey { header } => {
                 let key = self
@@ -503,34 +628,68 @@ impl Anthropic {
                     .first()
                     .map(String::as_str)
                     .unwrap_or("PASSWORD");
-                let username = std::env::var(username_env)
-                    .map_err(|_| SchematicError::MissingCredential {
+                let username =
+                    std::env::var(username_env).map_err(|_| SchematicError::MissingCredential {
                         env_vars: vec![username_env.to_string()],
                     })?;
-                let password = std::env::var(password_env)
-                    .map_err(|_| SchematicError::MissingCredential {
+                let password =
+                    std::env::var(password_env).map_err(|_| SchematicError::MissingCredential {
                         env_vars: vec![password_env.to_string()],
                     })?;
                 req_builder = req_builder.basic_auth(username, Some(password));
             }
         }
         let merged_headers = Self::merge_headers(&self.headers, &endpoint_headers);
+        if !compression.decompress_responses
+            && !merged_headers
+                .iter()
+                .any(|(key, _)| key.eq_ignore_ascii_case("Accept-Encoding"))
+        {
+            req_builder = req_builder.header("Accept-Encoding", "identity");
+        }
+        let fingerprint = match &idempotency {
+            Some(config)
+                if !merged_headers
+                    .iter()
+                    .any(|(key, _)| key.eq_ignore_ascii_case(&config.header)) =>
+            {
+                req_builder = req_builder.header(config.header.as_str(), biscuit_hash::ulid());
+                config
+                    .dedupe_in_flight
+                    .then(|| (method.to_string(), url.clone(), body.clone()))
+            }
+            _ => None,
+        };
         for (key, value) in merged_headers {
             req_builder = req_builder.header(key.as_str(), value.as_str());
         }
         if let Some(body) = body {
-            req_builder = req_builder
-                .header("Content-Type", "application/json")
-                .body(body);
+            req_builder = req_builder.header("Content-Type", "application/json");
+            req_builder = match compression.request.filter(|c| c.applies_to(body.len())) {
+                Some(config) => req_builder
+                    .header("Content-Encoding", config.encoding.as_str())
+                    .body(compress_body(body.as_bytes(), config.encoding)?),
+                None => req_builder.body(body),
+            };
         }
-        let response = req_builder.send().await?;
+        let send = async {
+            let response = req_builder.send().await?;
+            #[cfg(feature = "vcr")]
+            let response = match recording {
+                Some((cassette, request)) => cassette.record(&request, response).await?,
+                None => response,
+            };
+            Ok::<_, SchematicError>(response)
+        };
+        let response = match fingerprint {
+            Some(fingerprint) => self.in_flight.send(fingerprint, send).await?,
+            None => send.await?,
+        };
         if !response.status().is_success() {
             let status = response.status().as_u16();
+            let headers = response.headers().clone();
             let body = response.text().await.unwrap_or_default();
-            return Err(SchematicError::ApiError {
-                status,
-                body,
-            });
+            return Err(response_error(status, &headers, body));
         }
         Ok(response)
     }
@@ -74,9 +74,11 @@
      Ok(())
  }
  ```*/
-use serde::{Deserialize, Serialize};
+use crate::shared::{
+    InFlightRequests, RequestParts, SchematicError, compress_body, response_error,
+};
 pub use schematic_definitions::elevenlabs::*;
-use crate::shared::{RequestParts, SchematicError};
+use serde::{Deserialize, Serialize};
 /// Request for `CreateSpeech` endpoint.
 ///
 /// ## Example
@@ -128,7 +130,7 @@ impl CreateSpeechRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -185,7 +187,7 @@ impl StreamSpeechRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -242,7 +244,7 @@ impl CreateSpeechWithTimestampsRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -294,14 +296,15 @@ impl StreamSpeechWithTimestampsRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/v1/text-to-speech/{}/stream/with-timestamps", self.voice_id
+            "/v1/text-to-speech/{}/stream/with-timestamps",
+            self.voice_id
         );
         Ok((
             "POST",
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -355,7 +358,9 @@ pub struct GetVoiceRequest {
 impl GetVoiceRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(voice_id: impl Into<String>) -> Self {
-        Self { voice_id: voice_id.into() }
+        Self {
+            voice_id: voice_id.into(),
+        }
     }
 }
 impl GetVoiceRequest {
@@ -395,7 +400,9 @@ pub struct DeleteVoiceRequest {
 impl DeleteVoiceRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(voice_id: impl Into<String>) -> Self {
-        Self { voice_id: voice_id.into() }
+        Self {
+            voice_id: voice_id.into(),
+        }
     }
 }
 impl DeleteVoiceRequest {
@@ -466,7 +473,9 @@ pub struct GetVoiceSettingsRequest {
 impl GetVoiceSettingsRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(voice_id: impl Into<String>) -> Self {
-        Self { voice_id: voice_id.into() }
+        Self {
+            voice_id: voice_id.into(),
+        }
     }
 }
 impl GetVoiceSettingsRequest {
@@ -540,7 +549,7 @@ impl UpdateVoiceSettingsRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -588,7 +597,8 @@ impl GetVoiceSampleAudioRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/v1/voices/{}/samples/{}/audio", self.voice_id, self.sample_id
+            "/v1/voices/{}/samples/{}/audio",
+            self.voice_id, self.sample_id
         );
         Ok(("GET", path, None, vec![]))
     }
@@ -655,7 +665,9 @@ pub struct AddVoiceSampleRequest {
 impl AddVoiceSampleRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(voice_id: impl Into<String>) -> Self {
-        Self { voice_id: voice_id.into() }
+        Self {
+            voice_id: voice_id.into(),
+        }
     }
 }
 impl AddVoiceSampleRequest {
@@ -767,7 +779,7 @@ impl AddSharedVoiceRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -819,7 +831,7 @@ impl CreatePvcVoiceRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -876,7 +888,7 @@ impl UpdatePvcVoiceRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -933,7 +945,7 @@ impl TrainPvcVoiceRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -985,7 +997,7 @@ impl CreateSoundEffectRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1267,7 +1279,7 @@ impl DownloadHistoryItemsRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1459,7 +1471,7 @@ impl ShareResourceRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1516,7 +1528,7 @@ impl UnshareResourceRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1568,14 +1580,15 @@ impl CopyResourceToWorkspaceRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/v1/workspace/resources/{}/copy-to-workspace", self.resource_id
+            "/v1/workspace/resources/{}/copy-to-workspace",
+            self.resource_id
         );
         Ok((
             "POST",
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1651,7 +1664,8 @@ impl ListServiceAccountApiKeysRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/v1/service-accounts/{}/api-keys", self.service_account_user_id
+            "/v1/service-accounts/{}/api-keys",
+            self.service_account_user_id
         );
         Ok(("GET", path, None, vec![]))
     }
@@ -1678,10 +1692,7 @@ pub struct CreateApiKeyRequest {
 }
 impl CreateApiKeyRequest {
     /// Creates a new request with the required path parameters and body.
-    pub fn new(
-        service_account_user_id: impl Into<String>,
-        body: CreateApiKeyBody,
-    ) -> Self {
+    pub fn new(service_account_user_id: impl Into<String>, body: CreateApiKeyBody) -> Self {
         Self {
             service_account_user_id: service_account_user_id.into(),
             body,
@@ -1705,14 +1716,15 @@ impl CreateApiKeyRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/v1/service-accounts/{}/api-keys", self.service_account_user_id
+            "/v1/service-accounts/{}/api-keys",
+            self.service_account_user_id
         );
         Ok((
             "POST",
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1771,15 +1783,15 @@ impl UpdateApiKeyRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/v1/service-accounts/{}/api-keys/{}", self.service_account_user_id, self
-            .api_key_id
+            "/v1/service-accounts/{}/api-keys/{}",
+            self.service_account_user_id, self.api_key_id
         );
         Ok((
             "PATCH",
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1803,10 +1815,7 @@ pub struct DeleteApiKeyRequest {
 }
 impl DeleteApiKeyRequest {
     /// Creates a new request with the required path parameters.
-    pub fn new(
-        service_account_user_id: impl Into<String>,
-        api_key_id: impl Into<String>,
-    ) -> Self {
+    pub fn new(service_account_user_id: impl Into<String>, api_key_id: impl Into<String>) -> Self {
         Self {
             service_account_user_id: service_account_user_id.into(),
             api_key_id: api_key_id.into(),
@@ -1830,8 +1839,8 @@ impl DeleteApiKeyRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/v1/service-accounts/{}/api-keys/{}", self.service_account_user_id, self
-            .api_key_id
+            "/v1/service-accounts/{}/api-keys/{}",
+            self.service_account_user_id, self.api_key_id
         );
         Ok(("DELETE", path, None, vec![]))
     }
@@ -1913,7 +1922,7 @@ impl CreateWebhookRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1970,7 +1979,7 @@ impl UpdateWebhookRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -2162,6 +2171,15 @@ impl ElevenLabsRequest {
             Self::DeleteWebhook(req) => req.into_parts(),
         }
     }
+    /// Returns the endpoint's compression settings, if the definition
+    /// overrides the API-level default for it.
+    pub fn compression(&self) -> Option<schematic_define::Compression> {
+        None
+    }
+    /// Returns the endpoint's idempotency settings, if it declares any.
+    pub fn idempotency(&self) -> Option<schematic_define::Idempotency> {
+        None
+    }
 }
 impl From<CreateSpeechRequest> for ElevenLabsRequest {
     fn from(req: CreateSpeechRequest) -> Self {
@@ -2385,6 +2403,13 @@ pub struct ElevenLabs {
     env_username: Option<String>,
     /// Default HTTP headers to include with every request.
     headers: Vec<(String, String)>,
+    /// Content-encoding settings for endpoints without their own.
+    compression: schematic_define::Compression,
+    /// Requests in flight, for coalescing; shared with variants.
+    in_flight: InFlightRequests,
+    /// Cassette that records or replays responses.
+    #[cfg(feature = "vcr")]
+    cassette: Option<crate::shared::vcr::Cassette>,
 }
 impl ElevenLabs {
     /// Base URL for the API.
@@ -2395,13 +2420,21 @@ impl ElevenLabs {
             client: reqwest::Client::new(),
             base_url: Self::BASE_URL.to_string(),
             env_auth: vec![
-                "ELEVEN_LABS_API_KEY".to_string(), "ELEVENLABS_API_KEY".to_string()
+                "ELEVEN_LABS_API_KEY".to_string(),
+                "ELEVENLABS_API_KEY".to_string(),
             ],
             auth_strategy: schematic_define::AuthStrategy::ApiKey {
                 header: "xi-api-key".to_string(),
             },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a custom base URL.
@@ -2416,13 +2449,21 @@ impl ElevenLabs {
             client: reqwest::Client::new(),
             base_url: base_url.into(),
             env_auth: vec![
-                "ELEVEN_LABS_API_KEY".to_string(), "ELEVENLABS_API_KEY".to_string()
+                "ELEVEN_LABS_API_KEY".to_string(),
+                "ELEVENLABS_API_KEY".to_string(),
             ],
             auth_strategy: schematic_define::AuthStrategy::ApiKey {
                 header: "xi-api-key".to_string(),
             },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a pre-configured reqwest client.
@@ -2443,13 +2484,21 @@ impl ElevenLabs {
             client,
             base_url: Self::BASE_URL.to_string(),
             env_auth: vec![
-                "ELEVEN_LABS_API_KEY".to_string(), "ELEVENLABS_API_KEY".to_string()
+                "ELEVEN_LABS_API_KEY".to_string(),
+                "ELEVENLABS_API_KEY".to_string(),
             ],
             auth_strategy: schematic_define::AuthStrategy::ApiKey {
                 header: "xi-api-key".to_string(),
             },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a pre-configured reqwest client and custom base URL.
@@ -2463,23 +2512,54 @@ impl ElevenLabs {
     ///     .unwrap();
     /// let api = Api::with_client_and_base_url(custom_client, "http://localhost:8080");
     /// ```
-    pub fn with_client_and_base_url(
-        client: reqwest::Client,
-        base_url: impl Into<String>,
-    ) -> Self {
+    pub fn with_client_and_base_url(client: reqwest::Client, base_url: impl Into<String>) -> Self {
         Self {
             client,
             base_url: base_url.into(),
             env_auth: vec![
-                "ELEVEN_LABS_API_KEY".to_string(), "ELEVENLABS_API_KEY".to_string()
+                "ELEVEN_LABS_API_KEY".to_string(),
+                "ELEVENLABS_API_KEY".to_string(),
             ],
             auth_strategy: schematic_define::AuthStrategy::ApiKey {
                 header: "xi-api-key".to_string(),
             },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
+    /// Creates a new API client whose HTTP client has the connection
+    /// settings of `builder` (proxy, root CAs, client certificate, DNS
+    /// overrides, pool sizing).
+    ///
+    /// For a custom base URL as well, pass `builder.build()?` to
+    /// `with_client_and_base_url()`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::ClientConfig` if a builder setting is invalid.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_schema::shared::ApiClientBuilder;
+    ///
+    /// let builder = ApiClientBuilder::new()
+    ///     .proxy("http://proxy.corp.example:3128")
+    ///     .root_certificate_pem(std::fs::read("/etc/ssl/corp-ca.pem")?);
+    /// let api = Api::with_client_builder(&builder)?;
+    /// ```
+    pub fn with_client_builder(
+        builder: &crate::shared::ApiClientBuilder,
+    ) -> Result<Self, SchematicError> {
+        Ok(Self::with_client(builder.build()?))
+    }
     /// Creates a variant of this API client with different configuration.
     ///
     /// This method clones the underlying HTTP client and allows customizing:
@@ -2535,8 +2615,49 @@ impl ElevenLabs {
             auth_strategy,
             env_username: self.env_username.clone(),
             headers: self.headers.clone(),
+            compression: self.compression,
+            in_flight: self.in_flight.clone(),
+            #[cfg(feature = "vcr")]
+            cassette: self.cassette.clone(),
         }
     }
+    /// Replaces the API-level compression settings for this client.
+    ///
+    /// Endpoints with their own compression settings in the definition
+    /// keep them.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_define::{Compression, ContentEncoding};
+    ///
+    /// // Gzip request bodies of 8 KiB or more
+    /// let api = Api::new().with_compression(
+    ///     Compression::default().compress_requests(ContentEncoding::Gzip, 8 * 1024),
+    /// );
+    /// ```
+    pub fn with_compression(mut self, compression: schematic_define::Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+    /// Records responses to, or replays them from, a cassette.
+    ///
+    /// Replayed requests never reach the network and need no
+    /// credentials. Requires the `vcr` feature; see
+    /// `crate::shared::vcr`.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_schema::shared::vcr::{Cassette, VcrMode};
+    ///
+    /// let api = Api::new().with_cassette(Cassette::new("tests/cassettes/api", VcrMode::Auto));
+    /// ```
+    #[cfg(feature = "vcr")]
+    pub fn with_cassette(mut self, cassette: crate::shared::vcr::Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
     /// Returns a reference to the underlying HTTP client.
     ///
     /// Use this for custom requests that aren't covered by the generated methods,
@@ -2580,8 +2701,22 @@ impl ElevenLabs {
         request: impl Into<ElevenLabsRequest>,
     ) -> Result<reqwest::Response, SchematicError> {
         let request = request.into();
+        let compression = request.compression().unwrap_or(self.compression);
+        let idempotency = request.idempotency();
         let (method, path, body, endpoint_headers) = request.into_parts()?;
         let url = format!("{}{}", self.base_url, path);
+        #[cfg(feature = "vcr")]
+        let recording = match &self.cassette {
+            Some(cassette) => {
+                let request =
+                    crate::shared::vcr::RecordedRequest::new(method, &path, body.as_deref());
+                if let Some(interaction) = cassette.replay(&request)? {
+                    return interaction.into_response();
+                }
+                Some((cassette, request))
+            }
+            None => None,
+        };
         let mut req_builder = match method {
             "GET" => self.client.get(&url),
             "POST" => self.client.post(&url),
@@ -2603,8 +2738,7 @@ impl ElevenLabs {
                     .ok_or_else(|| SchematicError::MissingCredential {
                         env_vars: self.env_auth.clone(),
                     })?;
-                req_builder = req_builder
-                    .header(header_name, format!("Bearer {}", token));
+                req_builder = req_builder.header(header_name, format!("Bearer {}", token));
             }
             schematic_define::AuthStrategy::ApiKey { header } => {
                 let key = self
@@ -2623,34 +2757,68 @@ impl ElevenLabs {
                     .first()
                     .map(String::as_str)
                     .unwrap_or("PASSWORD");
-                let username = std::env::var(username_env)
-                    .map_err(|_| SchematicError::MissingCredential {
+                let username =
+                    std::env::var(username_env).map_err(|_| SchematicError::MissingCredential {
                         env_vars: vec![username_env.to_string()],
                     })?;
-                let password = std::env::var(password_env)
-                    .map_err(|_| SchematicError::MissingCredential {
+                let password =
+                    std::env::var(password_env).map_err(|_| SchematicError::MissingCredential {
                         env_vars: vec![password_env.to_string()],
                     })?;
                 req_builder = req_builder.basic_auth(username, Some(password));
             }
         }
         let merged_headers = Self::merge_headers(&self.headers, &endpoint_headers);
+        if !compression.decompress_responses
+            && !merged_headers
+                .iter()
+                .any(|(key, _)| key.eq_ignore_ascii_case("Accept-Encoding"))
+        {
+            req_builder = req_builder.header("Accept-Encoding", "identity");
+        }
+        let fingerprint = match &idempotency {
+            Some(config)
+                if !merged_headers
+                    .iter()
+                    .any(|(key, _)| key.eq_ignore_ascii_case(&config.header)) =>
+            {
+                req_builder = req_builder.header(config.header.as_str(), biscuit_hash::ulid());
+                config
+                    .dedupe_in_flight
+                    .then(|| (method.to_string(), url.clone(), body.clone()))
+            }
+            _ => None,
+        };
         for (key, value) in merged_headers {
             req_builder = req_builder.header(key.as_str(), value.as_str());
         }
         if let Some(body) = body {
-            req_builder = req_builder
-                .header("Content-Type", "application/json")
-                .body(body);
+            req_builder = req_builder.header("Content-Type", "application/json");
+            req_builder = match compression.request.filter(|c| c.applies_to(body.len())) {
+                Some(config) => req_builder
+                    .header("Content-Encoding", config.encoding.as_str())
+                    .body(compress_body(body.as_bytes(), config.encoding)?),
+                None => req_builder.body(body),
+            };
         }
-        let response = req_builder.send().await?;
+        let send = async {
+            let response = req_builder.send().await?;
+            #[cfg(feature = "vcr")]
+            let response = match recording {
+                Some((cassette, request)) => cassette.record(&request, response).await?,
+                None => response,
+            };
+            Ok::<_, SchematicError>(response)
+        };
+        let response = match fingerprint {
+            Some(fingerprint) => self.in_flight.send(fingerprint, send).await?,
+            None => send.await?,
+        };
         if !response.status().is_success() {
             let status = response.status().as_u16();
+            let headers = response.headers().clone();
             let body = response.text().await.unwrap_or_default();
-            return Err(SchematicError::ApiError {
-                status,
-                body,
-            });
+            return Err(response_error(status, &headers, body));
         }
         Ok(response)
     }
@@ -0,0 +1,1415 @@
+// This code was automatically generated by schematic-gen. Do not edit manually.
+
+/*! Generated API client for [GitHub](https://docs.github.com/en/rest).
+
+ GitHub REST API for repository, release and issue inspection*/
+//!
+/*! ## Authentication
+
+ Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.*/
+//!
+/*! ## Features
+
+ **GET**:
+ - `GetRepo` - Gets a repository's details, including stars and open issue count
+ - `GetReadme` - Gets the raw Markdown of a repository's preferred README
+ - `GetContents` - Gets a file (with base64 content) or a directory listing from the default branch. Query params: ref
+ - `ListReleases` - Lists published releases, newest first. Query params: per_page, page
+ - `GetLatestRelease` - Gets the latest published full release (not a draft or pre-release)
+ - `GetReleaseByTag` - Gets the release created from a tag
+ - `ListIssues` - Lists open issues and pull requests, most recently created first. Query params: state, labels, sort, direction, since, per_page, page
+ - `GetRateLimit` - Gets the caller's remaining request quota; does not count against it
+*/
+//!
+/*! ## Example
+
+ ```ignore
+ use schematic_schema::prelude::*;
+
+ #[tokio::main]
+ async fn main() -> Result<(), SchematicError> {
+     let client = GitHub::new();
+     let response = client.get_repo().await?;
+     println!("{:?}", response);
+     Ok(())
+ }
+ ```*/
+use crate::shared::{
+    InFlightRequests, RequestParts, SchematicError, compress_body, response_error,
+};
+pub use schematic_definitions::github::*;
+use serde::{Deserialize, Serialize};
+/// Request for `GetRepo` endpoint.
+///
+/// ## Example
+///
+/// ```ignore
+/// use schematic_schema::github::GetRepoRequest;
+///
+/// let request = GetRepoRequest::new("owner_value", "repo_value");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetRepoRequest {
+    /// Path parameter: owner
+    pub owner: String,
+    /// Path parameter: repo
+    pub repo: String,
+}
+impl GetRepoRequest {
+    /// Creates a new request with the required path parameters.
+    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+}
+impl GetRepoRequest {
+    /// Converts the request into (method, path, body, headers) parts.
+    ///
+    /// ## Returns
+    ///
+    /// A tuple of:
+    /// - HTTP method as a static string (e.g., "GET", "POST")
+    /// - Fully substituted path string
+    /// - Optional JSON body string
+    /// - Endpoint-specific headers as key-value pairs
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::SerializationError` if the request body
+    /// fails to serialize to JSON.
+    pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
+        let path = format!("/repos/{}/{}", self.owner, self.repo);
+        Ok(("GET", path, None, vec![]))
+    }
+}
+/// Request for `GetReadme` endpoint.
+///
+/// ## Example
+///
+/// ```ignore
+/// use schematic_schema::github::GetReadmeRequest;
+///
+/// let request = GetReadmeRequest::new("owner_value", "repo_value");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetReadmeRequest {
+    /// Path parameter: owner
+    pub owner: String,
+    /// Path parameter: repo
+    pub repo: String,
+}
+impl GetReadmeRequest {
+    /// Creates a new request with the required path parameters.
+    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+}
+impl GetReadmeRequest {
+    /// Converts the request into (method, path, body, headers) parts.
+    ///
+    /// ## Returns
+    ///
+    /// A tuple of:
+    /// - HTTP method as a static string (e.g., "GET", "POST")
+    /// - Fully substituted path string
+    /// - Optional JSON body string
+    /// - Endpoint-specific headers as key-value pairs
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::SerializationError` if the request body
+    /// fails to serialize to JSON.
+    pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
+        let path = format!("/repos/{}/{}/readme", self.owner, self.repo);
+        Ok((
+            "GET",
+            path,
+            None,
+            vec![(
+                "Accept".to_string(),
+                "application/vnd.github.raw+json".to_string(),
+            )],
+        ))
+    }
+}
+/// Request for `GetContents` endpoint.
+///
+/// ## Example
+///
+/// ```ignore
+/// use schematic_schema::github::GetContentsRequest;
+///
+/// let request = GetContentsRequest::new("owner_value", "repo_value", "path_value");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetContentsRequest {
+    /// Path parameter: owner
+    pub owner: String,
+    /// Path parameter: repo
+    pub repo: String,
+    /// Path parameter: path
+    pub path: String,
+}
+impl GetContentsRequest {
+    /// Creates a new request with the required path parameters.
+    pub fn new(owner: impl Into<String>, repo: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+            path: path.into(),
+        }
+    }
+}
+impl GetContentsRequest {
+    /// Converts the request into (method, path, body, headers) parts.
+    ///
+    /// ## Returns
+    ///
+    /// A tuple of:
+    /// - HTTP method as a static string (e.g., "GET", "POST")
+    /// - Fully substituted path string
+    /// - Optional JSON body string
+    /// - Endpoint-specific headers as key-value pairs
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::SerializationError` if the request body
+    /// fails to serialize to JSON.
+    pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
+        let path = format!("/repos/{}/{}/contents/{}", self.owner, self.repo, self.path);
+        Ok(("GET", path, None, vec![]))
+    }
+}
+/// Request for `ListReleases` endpoint.
+///
+/// ## Example
+///
+/// ```ignore
+/// use schematic_schema::github::ListReleasesRequest;
+///
+/// let request = ListReleasesRequest::new("owner_value", "repo_value");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListReleasesRequest {
+    /// Path parameter: owner
+    pub owner: String,
+    /// Path parameter: repo
+    pub repo: String,
+}
+impl ListReleasesRequest {
+    /// Creates a new request with the required path parameters.
+    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+}
+impl ListReleasesRequest {
+    /// Converts the request into (method, path, body, headers) parts.
+    ///
+    /// ## Returns
+    ///
+    /// A tuple of:
+    /// - HTTP method as a static string (e.g., "GET", "POST")
+    /// - Fully substituted path string
+    /// - Optional JSON body string
+    /// - Endpoint-specific headers as key-value pairs
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::SerializationError` if the request body
+    /// fails to serialize to JSON.
+    pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
+        let path = format!("/repos/{}/{}/releases", self.owner, self.repo);
+        Ok(("GET", path, None, vec![]))
+    }
+}
+/// Request for `GetLatestRelease` endpoint.
+///
+/// ## Example
+///
+/// ```ignore
+/// use schematic_schema::github::GetLatestReleaseRequest;
+///
+/// let request = GetLatestReleaseRequest::new("owner_value", "repo_value");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetLatestReleaseRequest {
+    /// Path parameter: owner
+    pub owner: String,
+    /// Path parameter: repo
+    pub repo: String,
+}
+impl GetLatestReleaseRequest {
+    /// Creates a new request with the required path parameters.
+    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+}
+impl GetLatestReleaseRequest {
+    /// Converts the request into (method, path, body, headers) parts.
+    ///
+    /// ## Returns
+    ///
+    /// A tuple of:
+    /// - HTTP method as a static string (e.g., "GET", "POST")
+    /// - Fully substituted path string
+    /// - Optional JSON body string
+    /// - Endpoint-specific headers as key-value pairs
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::SerializationError` if the request body
+    /// fails to serialize to JSON.
+    pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
+        let path = format!("/repos/{}/{}/releases/latest", self.owner, self.repo);
+        Ok(("GET", path, None, vec![]))
+    }
+}
+/// Request for `GetReleaseByTag` endpoint.
+///
+/// ## Example
+///
+/// ```ignore
+/// use schematic_schema::github::GetReleaseByTagRequest;
+///
+/// let request = GetReleaseByTagRequest::new("owner_value", "repo_value", "tag_value");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetReleaseByTagRequest {
+    /// Path parameter: owner
+    pub owner: String,
+    /// Path parameter: repo
+    pub repo: String,
+    /// Path parameter: tag
+    pub tag: String,
+}
+impl GetReleaseByTagRequest {
+    /// Creates a new request with the required path parameters.
+    pub fn new(owner: impl Into<String>, repo: impl Into<String>, tag: impl Into<String>) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+            tag: tag.into(),
+        }
+    }
+}
+impl GetReleaseByTagRequest {
+    /// Converts the request into (method, path, body, headers) parts.
+    ///
+    /// ## Returns
+    ///
+    /// A tuple of:
+    /// - HTTP method as a static string (e.g., "GET", "POST")
+    /// - Fully substituted path string
+    /// - Optional JSON body string
+    /// - Endpoint-specific headers as key-value pairs
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::SerializationError` if the request body
+    /// fails to serialize to JSON.
+    pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
+        let path = format!(
+            "/repos/{}/{}/releases/tags/{}",
+            self.owner, self.repo, self.tag
+        );
+        Ok(("GET", path, None, vec![]))
+    }
+}
+/// Request for `ListIssues` endpoint.
+///
+/// ## Example
+///
+/// ```ignore
+/// use schematic_schema::github::ListIssuesRequest;
+///
+/// let request = ListIssuesRequest::new("owner_value", "repo_value");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListIssuesRequest {
+    /// Path parameter: owner
+    pub owner: String,
+    /// Path parameter: repo
+    pub repo: String,
+}
+impl ListIssuesRequest {
+    /// Creates a new request with the required path parameters.
+    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+}
+impl ListIssuesRequest {
+    /// Converts the request into (method, path, body, headers) parts.
+    ///
+    /// ## Returns
+    ///
+    /// A tuple of:
+    /// - HTTP method as a static string (e.g., "GET", "POST")
+    /// - Fully substituted path string
+    /// - Optional JSON body string
+    /// - Endpoint-specific headers as key-value pairs
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::SerializationError` if the request body
+    /// fails to serialize to JSON.
+    pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
+        let path = format!("/repos/{}/{}/issues", self.owner, self.repo);
+        Ok(("GET", path, None, vec![]))
+    }
+}
+/// Request for `GetRateLimit` endpoint.
+///
+/// ## Example
+///
+/// ```ignore
+/// use schematic_schema::github::GetRateLimitRequest;
+///
+/// let request = GetRateLimitRequest::default();
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetRateLimitRequest {}
+impl GetRateLimitRequest {
+    /// Converts the request into (method, path, body, headers) parts.
+    ///
+    /// ## Returns
+    ///
+    /// A tuple of:
+    /// - HTTP method as a static string (e.g., "GET", "POST")
+    /// - Fully substituted path string
+    /// - Optional JSON body string
+    /// - Endpoint-specific headers as key-value pairs
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::SerializationError` if the request body
+    /// fails to serialize to JSON.
+    pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
+        let path = "/rate_limit".to_string();
+        Ok(("GET", path, None, vec![]))
+    }
+}
+/// Request enum for GitHub API.
+///
+/// Each variant wraps a strongly-typed request struct.
+pub enum GitHubRequest {
+    /// Gets a repository's details, including stars and open issue count
+    GetRepo(GetRepoRequest),
+    /// Gets the raw Markdown of a repository's preferred README
+    GetReadme(GetReadmeRequest),
+    /// Gets a file (with base64 content) or a directory listing from the default branch. Query params: ref
+    GetContents(GetContentsRequest),
+    /// Lists published releases, newest first. Query params: per_page, page
+    ListReleases(ListReleasesRequest),
+    /// Gets the latest published full release (not a draft or pre-release)
+    GetLatestRelease(GetLatestReleaseRequest),
+    /// Gets the release created from a tag
+    GetReleaseByTag(GetReleaseByTagRequest),
+    /// Lists open issues and pull requests, most recently created first. Query params: state, labels, sort, direction, since, per_page, page
+    ListIssues(ListIssuesRequest),
+    /// Gets the caller's remaining request quota; does not count against it
+    GetRateLimit(GetRateLimitRequest),
+}
+impl GitHubRequest {
+    /// Converts the request into (method, path, body, headers) parts.
+    ///
+    /// Delegates to the inner request struct's `into_parts()` method.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::SerializationError` if the request body
+    /// fails to serialize to JSON.
+    pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
+        match self {
+            Self::GetRepo(req) => req.into_parts(),
+            Self::GetReadme(req) => req.into_parts(),
+            Self::GetContents(req) => req.into_parts(),
+            Self::ListReleases(req) => req.into_parts(),
+            Self::GetLatestRelease(req) => req.into_parts(),
+            Self::GetReleaseByTag(req) => req.into_parts(),
+            Self::ListIssues(req) => req.into_parts(),
+            Self::GetRateLimit(req) => req.into_parts(),
+        }
+    }
+    /// Returns the endpoint's compression settings, if the definition
+    /// overrides the API-level default for it.
+    pub fn compression(&self) -> Option<schematic_define::Compression> {
+        None
+    }
+    /// Returns the endpoint's idempotency settings, if it declares any.
+    pub fn idempotency(&self) -> Option<schematic_define::Idempotency> {
+        None
+    }
+}
+impl From<GetRepoRequest> for GitHubRequest {
+    fn from(req: GetRepoRequest) -> Self {
+        Self::GetRepo(req)
+    }
+}
+impl From<GetReadmeRequest> for GitHubRequest {
+    fn from(req: GetReadmeRequest) -> Self {
+        Self::GetReadme(req)
+    }
+}
+impl From<GetContentsRequest> for GitHubRequest {
+    fn from(req: GetContentsRequest) -> Self {
+        Self::GetContents(req)
+    }
+}
+impl From<ListReleasesRequest> for GitHubRequest {
+    fn from(req: ListReleasesRequest) -> Self {
+        Self::ListReleases(req)
+    }
+}
+impl From<GetLatestReleaseRequest> for GitHubRequest {
+    fn from(req: GetLatestReleaseRequest) -> Self {
+        Self::GetLatestRelease(req)
+    }
+}
+impl From<GetReleaseByTagRequest> for GitHubRequest {
+    fn from(req: GetReleaseByTagRequest) -> Self {
+        Self::GetReleaseByTag(req)
+    }
+}
+impl From<ListIssuesRequest> for GitHubRequest {
+    fn from(req: ListIssuesRequest) -> Self {
+        Self::ListIssues(req)
+    }
+}
+impl From<GetRateLimitRequest> for GitHubRequest {
+    fn from(req: GetRateLimitRequest) -> Self {
+        Self::GetRateLimit(req)
+    }
+}
+/// GitHub REST API for repository, release and issue inspection client.
+pub struct GitHub {
+    client: reqwest::Client,
+    base_url: String,
+    /// Environment variable names for authentication credentials.
+    env_auth: Vec<String>,
+    /// Authentication strategy for this API client.
+    auth_strategy: schematic_define::AuthStrategy,
+    /// Environment variable for Basic auth username.
+    env_username: Option<String>,
+    /// Default HTTP headers to include with every request.
+    headers: Vec<(String, String)>,
+    /// Content-encoding settings for endpoints without their own.
+    compression: schematic_define::Compression,
+    /// Requests in flight, for coalescing; shared with variants.
+    in_flight: InFlightRequests,
+    /// Cassette that records or replays responses.
+    #[cfg(feature = "vcr")]
+    cassette: Option<crate::shared::vcr::Cassette>,
+}
+impl GitHub {
+    /// Base URL for the API.
+    pub const BASE_URL: &'static str = "https://api.github.com";
+    /// Creates a new API client with the default base URL.
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: Self::BASE_URL.to_string(),
+            env_auth: vec!["GITHUB_TOKEN".to_string(), "GH_TOKEN".to_string()],
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
+            env_username: None,
+            headers: vec![
+                (
+                    "Accept".to_string(),
+                    "application/vnd.github+json".to_string(),
+                ),
+                ("X-GitHub-Api-Version".to_string(), "2022-11-28".to_string()),
+                ("User-Agent".to_string(), "schematic".to_string()),
+            ],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
+        }
+    }
+    /// Creates a new API client with a custom base URL.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// let client = Api::with_base_url("http://localhost:8080/v1");
+    /// ```
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: base_url.into(),
+            env_auth: vec!["GITHUB_TOKEN".to_string(), "GH_TOKEN".to_string()],
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
+            env_username: None,
+            headers: vec![
+                (
+                    "Accept".to_string(),
+                    "application/vnd.github+json".to_string(),
+                ),
+                ("X-GitHub-Api-Version".to_string(), "2022-11-28".to_string()),
+                ("User-Agent".to_string(), "schematic".to_string()),
+            ],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
+        }
+    }
+    /// Creates a new API client with a pre-configured reqwest client.
+    ///
+    /// Use this when you need custom timeouts, connection pools, or middleware.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// let custom_client = reqwest::Client::builder()
+    ///     .timeout(std::time::Duration::from_secs(60))
+    ///     .build()
+    ///     .unwrap();
+    /// let api = Api::with_client(custom_client);
+    /// ```
+    pub fn with_client(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            base_url: Self::BASE_URL.to_string(),
+            env_auth: vec!["GITHUB_TOKEN".to_string(), "GH_TOKEN".to_string()],
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
+            env_username: None,
+            headers: vec![
+                (
+                    "Accept".to_string(),
+                    "application/vnd.github+json".to_string(),
+                ),
+                ("X-GitHub-Api-Version".to_string(), "2022-11-28".to_string()),
+                ("User-Agent".to_string(), "schematic".to_string()),
+            ],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
+        }
+    }
+    /// Creates a new API client with a pre-configured reqwest client and custom base URL.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// let custom_client = reqwest::Client::builder()
+    ///     .timeout(std::time::Duration::from_secs(60))
+    ///     .build()
+    ///     .unwrap();
+    /// let api = Api::with_client_and_base_url(custom_client, "http://localhost:8080");
+    /// ```
+    pub fn with_client_and_base_url(client: reqwest::Client, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+            env_auth: vec!["GITHUB_TOKEN".to_string(), "GH_TOKEN".to_string()],
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
+            env_username: None,
+            headers: vec![
+                (
+                    "Accept".to_string(),
+                    "application/vnd.github+json".to_string(),
+                ),
+                ("X-GitHub-Api-Version".to_string(), "2022-11-28".to_string()),
+                ("User-Agent".to_string(), "schematic".to_string()),
+            ],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
+        }
+    }
+    /// Creates a new API client whose HTTP client has the connection
+    /// settings of `builder` (proxy, root CAs, client certificate, DNS
+    /// overrides, pool sizing).
+    ///
+    /// For a custom base URL as well, pass `builder.build()?` to
+    /// `with_client_and_base_url()`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::ClientConfig` if a builder setting is invalid.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_schema::shared::ApiClientBuilder;
+    ///
+    /// let builder = ApiClientBuilder::new()
+    ///     .proxy("http://proxy.corp.example:3128")
+    ///     .root_certificate_pem(std::fs::read("/etc/ssl/corp-ca.pem")?);
+    /// let api = Api::with_client_builder(&builder)?;
+    /// ```
+    pub fn with_client_builder(
+        builder: &crate::shared::ApiClientBuilder,
+    ) -> Result<Self, SchematicError> {
+        Ok(Self::with_client(builder.build()?))
+    }
+    /// Creates a variant of this API client with different configuration.
+    ///
+    /// This method clones the underlying HTTP client and allows customizing:
+    /// - Base URL (for proxies, mock servers, or different environments)
+    /// - Authentication credentials (different env var names)
+    /// - Authentication strategy (via `UpdateStrategy`)
+    ///
+    /// ## Arguments
+    ///
+    /// * `base_url` - New base URL for this variant
+    /// * `env_auth` - New environment variable names for credentials
+    /// * `strategy` - How to update the auth strategy:
+    ///   - `UpdateStrategy::NoChange` - Keep current auth strategy
+    ///   - `UpdateStrategy::ChangeTo(auth)` - Use specified auth strategy
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_define::UpdateStrategy;
+    ///
+    /// let api = Api::new();
+    ///
+    /// // Create a variant pointing to a staging server
+    /// let staging = api.variant(
+    ///     "https://staging.api.com/v1",
+    ///     vec!["STAGING_API_KEY".to_string()],
+    ///     UpdateStrategy::NoChange,
+    /// );
+    ///
+    /// // Create a variant with different auth
+    /// let other = api.variant(
+    ///     "https://other.api.com/v1",
+    ///     vec!["OTHER_TOKEN".to_string()],
+    ///     UpdateStrategy::ChangeTo(schematic_define::AuthStrategy::ApiKey {
+    ///         header: "X-API-Key".to_string(),
+    ///     }),
+    /// );
+    /// ```
+    pub fn variant(
+        &self,
+        base_url: impl Into<String>,
+        env_auth: Vec<String>,
+        strategy: schematic_define::UpdateStrategy,
+    ) -> Self {
+        let auth_strategy = match strategy {
+            schematic_define::UpdateStrategy::NoChange => self.auth_strategy.clone(),
+            schematic_define::UpdateStrategy::ChangeTo(auth) => auth,
+        };
+        Self {
+            client: self.client.clone(),
+            base_url: base_url.into(),
+            env_auth,
+            auth_strategy,
+            env_username: self.env_username.clone(),
+            headers: self.headers.clone(),
+            compression: self.compression,
+            in_flight: self.in_flight.clone(),
+            #[cfg(feature = "vcr")]
+            cassette: self.cassette.clone(),
+        }
+    }
+    /// Replaces the API-level compression settings for this client.
+    ///
+    /// Endpoints with their own compression settings in the definition
+    /// keep them.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_define::{Compression, ContentEncoding};
+    ///
+    /// // Gzip request bodies of 8 KiB or more
+    /// let api = Api::new().with_compression(
+    ///     Compression::default().compress_requests(ContentEncoding::Gzip, 8 * 1024),
+    /// );
+    /// ```
+    pub fn with_compression(mut self, compression: schematic_define::Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+    /// Records responses to, or replays them from, a cassette.
+    ///
+    /// Replayed requests never reach the network and need no
+    /// credentials. Requires the `vcr` feature; see
+    /// `crate::shared::vcr`.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_schema::shared::vcr::{Cassette, VcrMode};
+    ///
+    /// let api = Api::new().with_cassette(Cassette::new("tests/cassettes/api", VcrMode::Auto));
+    /// ```
+    #[cfg(feature = "vcr")]
+    pub fn with_cassette(mut self, cassette: crate::shared::vcr::Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+    /// Returns a reference to the underlying HTTP client.
+    ///
+    /// Use this for custom requests that aren't covered by the generated methods,
+    /// such as paginated endpoints that require query parameters.
+    pub fn http_client(&self) -> &reqwest::Client {
+        &self.client
+    }
+    /// Returns the base URL for this API client.
+    pub fn api_base_url(&self) -> &str {
+        &self.base_url
+    }
+    /// Returns the API key header name and value for authentication.
+    ///
+    /// Returns `None` if the authentication strategy is not `ApiKey`
+    /// or if the API key environment variable is not set.
+    pub fn api_key_header(&self) -> Option<(String, String)> {
+        match &self.auth_strategy {
+            schematic_define::AuthStrategy::ApiKey { header } => {
+                for env_name in &self.env_auth {
+                    if let Ok(value) = std::env::var(env_name) {
+                        return Some((header.clone(), value));
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+impl Default for GitHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl GitHub {
+    /// Builds and sends an HTTP request, returning the raw response.
+    ///
+    /// This is an internal helper method used by the public request methods.
+    async fn build_and_send_request(
+        &self,
+        request: impl Into<GitHubRequest>,
+    ) -> Result<reqwest::Response, SchematicError> {
+        let request = request.into();
+        let compression = request.compression().unwrap_or(self.compression);
+        let idempotency = request.idempotency();
+        let (method, path, body, endpoint_headers) = request.into_parts()?;
+        let url = format!("{}{}", self.base_url, path);
+        #[cfg(feature = "vcr")]
+        let recording = match &self.cassette {
+            Some(cassette) => {
+                let request =
+                    crate::shared::vcr::RecordedRequest::new(method, &path, body.as_deref());
+                if let Some(interaction) = cassette.replay(&request)? {
+                    return interaction.into_response();
+                }
+                Some((cassette, request))
+            }
+            None => None,
+        };
+        let mut req_builder = match method {
+            "GET" => self.client.get(&url),
+            "POST" => self.client.post(&url),
+            "PUT" => self.client.put(&url),
+            "PATCH" => self.client.patch(&url),
+            "DELETE" => self.client.delete(&url),
+            "HEAD" => self.client.head(&url),
+            "OPTIONS" => self.client.request(reqwest::Method::OPTIONS, &url),
+            _ => return Err(SchematicError::UnsupportedMethod(method.to_string())),
+        };
+        match &self.auth_strategy {
+            schematic_define::AuthStrategy::None => {}
+            schematic_define::AuthStrategy::BearerToken { header } => {
+                let header_name = header.as_deref().unwrap_or("Authorization");
+                let token = self
+                    .env_auth
+                    .iter()
+                    .find_map(|var| std::env::var(var).ok())
+                    .ok_or_else(|| SchematicError::MissingCredential {
+                        env_vars: self.env_auth.clone(),
+                    })?;
+                req_builder = req_builder.header(header_name, format!("Bearer {}", token));
+            }
+            schematic_define::AuthStrategy::ApiKey { header } => {
+                let key = self
+                    .env_auth
+                    .iter()
+                    .find_map(|var| std::env::var(var).ok())
+                    .ok_or_else(|| SchematicError::MissingCredential {
+                        env_vars: self.env_auth.clone(),
+                    })?;
+                req_builder = req_builder.header(header.as_str(), key);
+            }
+            schematic_define::AuthStrategy::Basic => {
+                let username_env = self.env_username.as_deref().unwrap_or("USERNAME");
+                let password_env = self
+                    .env_auth
+                    .first()
+                    .map(String::as_str)
+                    .unwrap_or("PASSWORD");
+                let username =
+                    std::env::var(username_env).map_err(|_| SchematicError::MissingCredential {
+                        env_vars: vec![username_env.to_string()],
+                    })?;
+                let password =
+                    std::env::var(password_env).map_err(|_| SchematicError::MissingCredential {
+                        env_vars: vec![password_env.to_string()],
+                    })?;
+                req_builder = req_builder.basic_auth(username, Some(password));
+            }
+        }
+        let merged_headers = Self::merge_headers(&self.headers, &endpoint_headers);
+        if !compression.decompress_responses
+            && !merged_headers
+                .iter()
+                .any(|(key, _)| key.eq_ignore_ascii_case("Accept-Encoding"))
+        {
+            req_builder = req_builder.header("Accept-Encoding", "identity");
+        }
+        let fingerprint = match &idempotency {
+            Some(config)
+                if !merged_headers
+                    .iter()
+                    .any(|(key, _)| key.eq_ignore_ascii_case(&config.header)) =>
+            {
+                req_builder = req_builder.header(config.header.as_str(), biscuit_hash::ulid());
+                config
+                    .dedupe_in_flight
+                    .then(|| (method.to_string(), url.clone(), body.clone()))
+            }
+            _ => None,
+        };
+        for (key, value) in merged_headers {
+            req_builder = req_builder.header(key.as_str(), value.as_str());
+        }
+        if let Some(body) = body {
+            req_builder = req_builder.header("Content-Type", "application/json");
+            req_builder = match compression.request.filter(|c| c.applies_to(body.len())) {
+                Some(config) => req_builder
+                    .header("Content-Encoding", config.encoding.as_str())
+                    .body(compress_body(body.as_bytes(), config.encoding)?),
+                None => req_builder.body(body),
+            };
+        }
+        let send = async {
+            let response = req_builder.send().await?;
+            #[cfg(feature = "vcr")]
+            let response = match recording {
+                Some((cassette, request)) => cassette.record(&request, response).await?,
+                None => response,
+            };
+            Ok::<_, SchematicError>(response)
+        };
+        let response = match fingerprint {
+            Some(fingerprint) => self.in_flight.send(fingerprint, send).await?,
+            None => send.await?,
+        };
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = response.text().await.unwrap_or_default();
+            return Err(response_error(status, &headers, body));
+        }
+        Ok(response)
+    }
+    /// Merges API-level and endpoint-level headers.
+    ///
+    /// Endpoint headers override API headers for matching keys (case-insensitive).
+    /// Returns a new Vec with the merged headers.
+    fn merge_headers(
+        api_headers: &[(String, String)],
+        endpoint_headers: &[(String, String)],
+    ) -> Vec<(String, String)> {
+        let mut result: Vec<(String, String)> = Vec::new();
+        for (api_key, api_value) in api_headers {
+            let has_override = endpoint_headers
+                .iter()
+                .any(|(k, _)| k.eq_ignore_ascii_case(api_key));
+            if !has_override {
+                result.push((api_key.clone(), api_value.clone()));
+            }
+        }
+        for (key, value) in endpoint_headers {
+            result.push((key.clone(), value.clone()));
+        }
+        result
+    }
+    /// Executes an API request expecting a JSON response.
+    ///
+    /// Takes any request type that can be converted into the request enum
+    /// and returns the deserialized response.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if:
+    /// - The HTTP request fails (network error, timeout, etc.)
+    /// - The response indicates a non-success status code
+    /// - The response body cannot be deserialized as JSON
+    pub async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: impl Into<GitHubRequest>,
+    ) -> Result<T, SchematicError> {
+        let response = self.build_and_send_request(request).await?;
+        let result = response.json::<T>().await?;
+        Ok(result)
+    }
+    /// Executes an API request expecting a plain text response.
+    ///
+    /// Returns the response body as a String.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if:
+    /// - The HTTP request fails (network error, timeout, etc.)
+    /// - The response indicates a non-success status code
+    pub async fn request_text(
+        &self,
+        request: impl Into<GitHubRequest>,
+    ) -> Result<String, SchematicError> {
+        let response = self.build_and_send_request(request).await?;
+        let text = response.text().await?;
+        Ok(text)
+    }
+    /// Convenience method for the `GetReadme` endpoint.
+    ///
+    /// Gets the raw Markdown of a repository's preferred README
+    pub async fn get_readme(&self, request: GetReadmeRequest) -> Result<String, SchematicError> {
+        self.request_text(request).await
+    }
+}
+/// Markdown usage documentation for each `GitHub` endpoint.
+///
+/// Generated from the API definition alongside the client, so it always
+/// matches the generated request types.
+pub mod docs {
+    /// ## GetRepo
+    ///
+    /// Gets a repository's details, including stars and open issue count
+    ///
+    /// `GET /repos/{owner}/{repo}`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** `Repository` (JSON)
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl GetRepoRequest {
+    ///     pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self;
+    /// }
+    ///
+    /// impl GitHub {
+    ///     pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::github::{GitHub, GetRepoRequest};
+    ///
+    /// let client = GitHub::new();
+    /// let request = GetRepoRequest::new("owner_value", "repo_value");
+    /// let response: Repository = client.request(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.
+    pub const GET_REPO: &str = "## GetRepo\n\nGets a repository's details, including stars and open issue count\n\n`GET /repos/{owner}/{repo}`\n\n- **Request:** no body\n- **Response:** `Repository` (JSON)\n\n### Signature\n\n```rust\nimpl GetRepoRequest {\n    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self;\n}\n\nimpl GitHub {\n    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::github::{GitHub, GetRepoRequest};\n\nlet client = GitHub::new();\nlet request = GetRepoRequest::new(\"owner_value\", \"repo_value\");\nlet response: Repository = client.request(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.";
+    /// ## GetReadme
+    ///
+    /// Gets the raw Markdown of a repository's preferred README
+    ///
+    /// `GET /repos/{owner}/{repo}/readme`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** text
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl GetReadmeRequest {
+    ///     pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self;
+    /// }
+    ///
+    /// impl GitHub {
+    ///     pub async fn get_readme(&self, request: GetReadmeRequest) -> Result<String, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::github::{GitHub, GetReadmeRequest};
+    ///
+    /// let client = GitHub::new();
+    /// let request = GetReadmeRequest::new("owner_value", "repo_value");
+    /// let text = client.get_readme(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.
+    ///
+    /// Also sends `Accept: application/vnd.github.raw+json`.
+    pub const GET_README: &str = "## GetReadme\n\nGets the raw Markdown of a repository's preferred README\n\n`GET /repos/{owner}/{repo}/readme`\n\n- **Request:** no body\n- **Response:** text\n\n### Signature\n\n```rust\nimpl GetReadmeRequest {\n    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self;\n}\n\nimpl GitHub {\n    pub async fn get_readme(&self, request: GetReadmeRequest) -> Result<String, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::github::{GitHub, GetReadmeRequest};\n\nlet client = GitHub::new();\nlet request = GetReadmeRequest::new(\"owner_value\", \"repo_value\");\nlet text = client.get_readme(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.\n\nAlso sends `Accept: application/vnd.github.raw+json`.";
+    /// ## GetContents
+    ///
+    /// Gets a file (with base64 content) or a directory listing from the default branch. Query params: ref
+    ///
+    /// `GET /repos/{owner}/{repo}/contents/{path}`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** `Contents` (JSON)
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl GetContentsRequest {
+    ///     pub fn new(owner: impl Into<String>, repo: impl Into<String>, path: impl Into<String>) -> Self;
+    /// }
+    ///
+    /// impl GitHub {
+    ///     pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::github::{GitHub, GetContentsRequest};
+    ///
+    /// let client = GitHub::new();
+    /// let request = GetContentsRequest::new("owner_value", "repo_value", "path_value");
+    /// let response: Contents = client.request(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.
+    pub const GET_CONTENTS: &str = "## GetContents\n\nGets a file (with base64 content) or a directory listing from the default branch. Query params: ref\n\n`GET /repos/{owner}/{repo}/contents/{path}`\n\n- **Request:** no body\n- **Response:** `Contents` (JSON)\n\n### Signature\n\n```rust\nimpl GetContentsRequest {\n    pub fn new(owner: impl Into<String>, repo: impl Into<String>, path: impl Into<String>) -> Self;\n}\n\nimpl GitHub {\n    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::github::{GitHub, GetContentsRequest};\n\nlet client = GitHub::new();\nlet request = GetContentsRequest::new(\"owner_value\", \"repo_value\", \"path_value\");\nlet response: Contents = client.request(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.";
+    /// ## ListReleases
+    ///
+    /// Lists published releases, newest first. Query params: per_page, page
+    ///
+    /// `GET /repos/{owner}/{repo}/releases`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** `Vec<Release>` (JSON)
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl ListReleasesRequest {
+    ///     pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self;
+    /// }
+    ///
+    /// impl GitHub {
+    ///     pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::github::{GitHub, ListReleasesRequest};
+    ///
+    /// let client = GitHub::new();
+    /// let request = ListReleasesRequest::new("owner_value", "repo_value");
+    /// let response: Vec<Release> = client.request(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.
+    pub const LIST_RELEASES: &str = "## ListReleases\n\nLists published releases, newest first. Query params: per_page, page\n\n`GET /repos/{owner}/{repo}/releases`\n\n- **Request:** no body\n- **Response:** `Vec<Release>` (JSON)\n\n### Signature\n\n```rust\nimpl ListReleasesRequest {\n    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self;\n}\n\nimpl GitHub {\n    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::github::{GitHub, ListReleasesRequest};\n\nlet client = GitHub::new();\nlet request = ListReleasesRequest::new(\"owner_value\", \"repo_value\");\nlet response: Vec<Release> = client.request(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.";
+    /// ## GetLatestRelease
+    ///
+    /// Gets the latest published full release (not a draft or pre-release)
+    ///
+    /// `GET /repos/{owner}/{repo}/releases/latest`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** `Release` (JSON)
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl GetLatestReleaseRequest {
+    ///     pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self;
+    /// }
+    ///
+    /// impl GitHub {
+    ///     pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::github::{GitHub, GetLatestReleaseRequest};
+    ///
+    /// let client = GitHub::new();
+    /// let request = GetLatestReleaseRequest::new("owner_value", "repo_value");
+    /// let response: Release = client.request(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.
+    pub const GET_LATEST_RELEASE: &str = "## GetLatestRelease\n\nGets the latest published full release (not a draft or pre-release)\n\n`GET /repos/{owner}/{repo}/releases/latest`\n\n- **Request:** no body\n- **Response:** `Release` (JSON)\n\n### Signature\n\n```rust\nimpl GetLatestReleaseRequest {\n    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self;\n}\n\nimpl GitHub {\n    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::github::{GitHub, GetLatestReleaseRequest};\n\nlet client = GitHub::new();\nlet request = GetLatestReleaseRequest::new(\"owner_value\", \"repo_value\");\nlet response: Release = client.request(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.";
+    /// ## GetReleaseByTag
+    ///
+    /// Gets the release created from a tag
+    ///
+    /// `GET /repos/{owner}/{repo}/releases/tags/{tag}`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** `Release` (JSON)
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl GetReleaseByTagRequest {
+    ///     pub fn new(owner: impl Into<String>, repo: impl Into<String>, tag: impl Into<String>) -> Self;
+    /// }
+    ///
+    /// impl GitHub {
+    ///     pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::github::{GitHub, GetReleaseByTagRequest};
+    ///
+    /// let client = GitHub::new();
+    /// let request = GetReleaseByTagRequest::new("owner_value", "repo_value", "tag_value");
+    /// let response: Release = client.request(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.
+    pub const GET_RELEASE_BY_TAG: &str = "## GetReleaseByTag\n\nGets the release created from a tag\n\n`GET /repos/{owner}/{repo}/releases/tags/{tag}`\n\n- **Request:** no body\n- **Response:** `Release` (JSON)\n\n### Signature\n\n```rust\nimpl GetReleaseByTagRequest {\n    pub fn new(owner: impl Into<String>, repo: impl Into<String>, tag: impl Into<String>) -> Self;\n}\n\nimpl GitHub {\n    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::github::{GitHub, GetReleaseByTagRequest};\n\nlet client = GitHub::new();\nlet request = GetReleaseByTagRequest::new(\"owner_value\", \"repo_value\", \"tag_value\");\nlet response: Release = client.request(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.";
+    /// ## ListIssues
+    ///
+    /// Lists open issues and pull requests, most recently created first. Query params: state, labels, sort, direction, since, per_page, page
+    ///
+    /// `GET /repos/{owner}/{repo}/issues`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** `Vec<Issue>` (JSON)
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl ListIssuesRequest {
+    ///     pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self;
+    /// }
+    ///
+    /// impl GitHub {
+    ///     pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::github::{GitHub, ListIssuesRequest};
+    ///
+    /// let client = GitHub::new();
+    /// let request = ListIssuesRequest::new("owner_value", "repo_value");
+    /// let response: Vec<Issue> = client.request(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.
+    pub const LIST_ISSUES: &str = "## ListIssues\n\nLists open issues and pull requests, most recently created first. Query params: state, labels, sort, direction, since, per_page, page\n\n`GET /repos/{owner}/{repo}/issues`\n\n- **Request:** no body\n- **Response:** `Vec<Issue>` (JSON)\n\n### Signature\n\n```rust\nimpl ListIssuesRequest {\n    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self;\n}\n\nimpl GitHub {\n    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::github::{GitHub, ListIssuesRequest};\n\nlet client = GitHub::new();\nlet request = ListIssuesRequest::new(\"owner_value\", \"repo_value\");\nlet response: Vec<Issue> = client.request(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.";
+    /// ## GetRateLimit
+    ///
+    /// Gets the caller's remaining request quota; does not count against it
+    ///
+    /// `GET /rate_limit`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** `RateLimitResponse` (JSON)
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl GetRateLimitRequest {
+    ///     fn default() -> Self;
+    /// }
+    ///
+    /// impl GitHub {
+    ///     pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::github::{GitHub, GetRateLimitRequest};
+    ///
+    /// let client = GitHub::new();
+    /// let request = GetRateLimitRequest::default();
+    /// let response: RateLimitResponse = client.request(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.
+    pub const GET_RATE_LIMIT: &str = "## GetRateLimit\n\nGets the caller's remaining request quota; does not count against it\n\n`GET /rate_limit`\n\n- **Request:** no body\n- **Response:** `RateLimitResponse` (JSON)\n\n### Signature\n\n```rust\nimpl GetRateLimitRequest {\n    fn default() -> Self;\n}\n\nimpl GitHub {\n    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<GitHubRequest>) -> Result<T, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::github::{GitHub, GetRateLimitRequest};\n\nlet client = GitHub::new();\nlet request = GetRateLimitRequest::default();\nlet response: RateLimitResponse = client.request(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `GITHUB_TOKEN` or `GH_TOKEN`.";
+    /// Every endpoint's usage documentation as `(endpoint id, Markdown)` pairs,
+    /// in definition order.
+    pub const ENDPOINTS: &[(&str, &str)] = &[
+        ("GetRepo", GET_REPO),
+        ("GetReadme", GET_README),
+        ("GetContents", GET_CONTENTS),
+        ("ListReleases", LIST_RELEASES),
+        ("GetLatestRelease", GET_LATEST_RELEASE),
+        ("GetReleaseByTag", GET_RELEASE_BY_TAG),
+        ("ListIssues", LIST_ISSUES),
+        ("GetRateLimit", GET_RATE_LIMIT),
+    ];
+}
+/// Contract tests generated from the endpoint examples in the API definition.
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    /// Deserializes `example` into `T`, serializes it back, and checks that
+    /// every non-null value in the example survived the round trip.
+    fn assert_example_roundtrip<T>(type_name: &str, example: &str)
+    where
+        T: serde::de::DeserializeOwned + serde::Serialize,
+    {
+        let original: serde_json::Value = serde_json::from_str(example)
+            .unwrap_or_else(|e| panic!("Example for `{}` is not valid JSON: {}", type_name, e));
+        let parsed: T = serde_json::from_value(original.clone())
+            .unwrap_or_else(|e| panic!("Example does not deserialize into `{}`: {}", type_name, e));
+        let roundtrip = serde_json::to_value(&parsed)
+            .unwrap_or_else(|e| panic!("`{}` failed to serialize: {}", type_name, e));
+        if let Err(path) = example_preserved(&original, &roundtrip, "$") {
+            panic!(
+                "`{}` does not preserve the example value at `{}` (field missing from the type or changed during round trip)",
+                type_name, path
+            );
+        }
+    }
+    /// Checks that `actual` contains every non-null value in `expected`.
+    ///
+    /// Returns the JSON path of the first value that was dropped or changed.
+    fn example_preserved(
+        expected: &serde_json::Value,
+        actual: &serde_json::Value,
+        path: &str,
+    ) -> Result<(), String> {
+        use serde_json::Value;
+        match (expected, actual) {
+            (Value::Null, _) => Ok(()),
+            (Value::Object(expected), Value::Object(actual)) => {
+                for (key, value) in expected {
+                    let child = format!("{}.{}", path, key);
+                    match actual.get(key) {
+                        Some(actual_value) => example_preserved(value, actual_value, &child)?,
+                        None if is_empty_value(value) => {}
+                        None => return Err(child),
+                    }
+                }
+                Ok(())
+            }
+            (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+                for (i, (e, a)) in expected.iter().zip(actual).enumerate() {
+                    example_preserved(e, a, &format!("{}[{}]", path, i))?;
+                }
+                Ok(())
+            }
+            (Value::Number(e), Value::Number(a)) if e.as_f64() == a.as_f64() => Ok(()),
+            (expected, actual) if expected == actual => Ok(()),
+            _ => Err(path.to_string()),
+        }
+    }
+    /// Returns true for null, empty arrays, and empty objects.
+    fn is_empty_value(value: &serde_json::Value) -> bool {
+        match value {
+            serde_json::Value::Null => true,
+            serde_json::Value::Array(items) => items.is_empty(),
+            serde_json::Value::Object(map) => map.is_empty(),
+            _ => false,
+        }
+    }
+    #[test]
+    fn get_repo_docs_response() {
+        assert_example_roundtrip::<Repository>(
+            "Repository",
+            r#"{"id": 46409563, "name": "clap", "full_name": "clap-rs/clap", "owner": {"login": "clap-rs", "id": 13536646, "avatar_url": "https://avatars.githubusercontent.com/u/13536646?v=4", "html_url": "https://github.com/clap-rs", "type": "Organization"}, "private": false, "html_url": "https://github.com/clap-rs/clap", "description": "A full featured, fast Command Line Argument Parser for Rust", "fork": false, "homepage": "docs.rs/clap", "language": "Rust", "stargazers_count": 15000, "forks_count": 1100, "open_issues_count": 350, "default_branch": "master", "topics": ["argument-parser", "cli", "rust"], "license": {"key": "apache-2.0", "name": "Apache License 2.0", "spdx_id": "Apache-2.0"}, "archived": false, "created_at": "2015-02-24T21:51:46Z", "updated_at": "2025-06-01T12:00:00Z", "pushed_at": "2025-06-01T11:00:00Z"}"#,
+        );
+    }
+    #[test]
+    fn get_contents_docs_response() {
+        assert_example_roundtrip::<Contents>(
+            "Contents",
+            r#"{"type": "file", "name": "CHANGELOG.md", "path": "CHANGELOG.md", "sha": "3d21ec53a331a6f037a91c368710b99387d012c1", "size": 20, "html_url": "https://github.com/clap-rs/clap/blob/master/CHANGELOG.md", "download_url": "https://raw.githubusercontent.com/clap-rs/clap/master/CHANGELOG.md", "content": "IyBDaGFuZ2Vsb2cKCiMjIDQuNQo=", "encoding": "base64"}"#,
+        );
+    }
+    #[test]
+    fn list_releases_docs_response() {
+        assert_example_roundtrip::<Vec<Release>>(
+            "Vec<Release>",
+            r#"[{"id": 1, "tag_name": "v4.5.0", "name": "v4.5.0", "body": "- Styled help output\n- New `ArgAction::Count`", "draft": false, "prerelease": false, "html_url": "https://github.com/clap-rs/clap/releases/tag/v4.5.0", "created_at": "2024-02-08T15:00:00Z", "published_at": "2024-02-08T15:10:00Z", "author": {"login": "epage", "id": 60961, "html_url": "https://github.com/epage", "type": "User"}}]"#,
+        );
+    }
+    #[test]
+    fn get_latest_release_docs_response() {
+        assert_example_roundtrip::<Release>(
+            "Release",
+            r#"{"id": 1, "tag_name": "v4.5.0", "name": "v4.5.0", "body": "- Styled help output", "draft": false, "prerelease": false, "html_url": "https://github.com/clap-rs/clap/releases/tag/v4.5.0", "created_at": "2024-02-08T15:00:00Z", "published_at": "2024-02-08T15:10:00Z"}"#,
+        );
+    }
+    #[test]
+    fn get_release_by_tag_docs_response() {
+        assert_example_roundtrip::<Release>(
+            "Release",
+            r#"{"id": 2, "tag_name": "v4.4.0", "name": null, "body": null, "draft": false, "prerelease": false, "html_url": "https://github.com/clap-rs/clap/releases/tag/v4.4.0", "created_at": "2023-08-24T15:00:00Z", "published_at": "2023-08-24T15:10:00Z"}"#,
+        );
+    }
+    #[test]
+    fn list_issues_docs_response() {
+        assert_example_roundtrip::<Vec<Issue>>(
+            "Vec<Issue>",
+            r#"[{"id": 2001, "number": 5900, "title": "Panic when combining flags", "state": "open", "body": "Steps to reproduce...", "user": {"login": "octocat", "id": 583231, "html_url": "https://github.com/octocat", "type": "User"}, "labels": [{"id": 7, "name": "C-bug", "color": "d73a4a", "description": "Something isn't working"}], "comments": 3, "html_url": "https://github.com/clap-rs/clap/issues/5900", "created_at": "2025-05-01T10:00:00Z", "updated_at": "2025-05-02T10:00:00Z", "closed_at": null}]"#,
+        );
+    }
+    #[test]
+    fn get_rate_limit_docs_response() {
+        assert_example_roundtrip::<RateLimitResponse>(
+            "RateLimitResponse",
+            r#"{"resources": {"core": {"limit": 5000, "remaining": 4999, "reset": 1717243200, "used": 1}, "search": {"limit": 30, "remaining": 30, "reset": 1717239660, "used": 0}, "graphql": {"limit": 5000, "remaining": 5000, "reset": 1717243200, "used": 0}}, "rate": {"limit": 5000, "remaining": 4999, "reset": 1717243200, "used": 1}}"#,
+        );
+    }
+}
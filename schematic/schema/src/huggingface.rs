@@ -58,9 +58,11 @@
      Ok(())
  }
  ```*/
-use serde::{Deserialize, Serialize};
+use crate::shared::{
+    InFlightRequests, RequestParts, SchematicError, compress_body, response_error,
+};
 pub use schematic_definitions::huggingface::*;
-use crate::shared::{RequestParts, SchematicError};
+use serde::{Deserialize, Serialize};
 /// Request for `ListModels` endpoint.
 ///
 /// ## Example
@@ -109,7 +111,9 @@ pub struct GetModelRequest {
 impl GetModelRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(repo_id: impl Into<String>) -> Self {
-        Self { repo_id: repo_id.into() }
+        Self {
+            repo_id: repo_id.into(),
+        }
     }
 }
 impl GetModelRequest {
@@ -226,7 +230,8 @@ impl GetModelFileRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/models/{}/blob/{}/{}", self.repo_id, self.revision, self.path
+            "/models/{}/blob/{}/{}",
+            self.repo_id, self.revision, self.path
         );
         Ok(("GET", path, None, vec![]))
     }
@@ -318,7 +323,8 @@ impl GetModelReadmeRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/models/{}/resolve/{}/README.md", self.repo_id, self.revision
+            "/models/{}/resolve/{}/README.md",
+            self.repo_id, self.revision
         );
         Ok(("GET", path, None, vec![]))
     }
@@ -340,7 +346,9 @@ pub struct ListModelDiscussionsRequest {
 impl ListModelDiscussionsRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(repo_id: impl Into<String>) -> Self {
-        Self { repo_id: repo_id.into() }
+        Self {
+            repo_id: repo_id.into(),
+        }
     }
 }
 impl ListModelDiscussionsRequest {
@@ -405,7 +413,8 @@ impl GetModelCardRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/models/{}/resolve/{}/model_card.md", self.repo_id, self.revision
+            "/models/{}/resolve/{}/model_card.md",
+            self.repo_id, self.revision
         );
         Ok(("GET", path, None, vec![]))
     }
@@ -458,7 +467,9 @@ pub struct GetDatasetRequest {
 impl GetDatasetRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(repo_id: impl Into<String>) -> Self {
-        Self { repo_id: repo_id.into() }
+        Self {
+            repo_id: repo_id.into(),
+        }
     }
 }
 impl GetDatasetRequest {
@@ -575,7 +586,8 @@ impl GetDatasetFileRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/datasets/{}/blob/{}/{}", self.repo_id, self.revision, self.path
+            "/datasets/{}/blob/{}/{}",
+            self.repo_id, self.revision, self.path
         );
         Ok(("GET", path, None, vec![]))
     }
@@ -667,7 +679,8 @@ impl GetDatasetReadmeRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/datasets/{}/resolve/{}/README.md", self.repo_id, self.revision
+            "/datasets/{}/resolve/{}/README.md",
+            self.repo_id, self.revision
         );
         Ok(("GET", path, None, vec![]))
     }
@@ -720,7 +733,9 @@ pub struct GetSpaceRequest {
 impl GetSpaceRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(repo_id: impl Into<String>) -> Self {
-        Self { repo_id: repo_id.into() }
+        Self {
+            repo_id: repo_id.into(),
+        }
     }
 }
 impl GetSpaceRequest {
@@ -837,7 +852,8 @@ impl GetSpaceFileRequest {
     /// fails to serialize to JSON.
     pub fn into_parts(self) -> Result<RequestParts, SchematicError> {
         let path = format!(
-            "/spaces/{}/blob/{}/{}", self.repo_id, self.revision, self.path
+            "/spaces/{}/blob/{}/{}",
+            self.repo_id, self.revision, self.path
         );
         Ok(("GET", path, None, vec![]))
     }
@@ -888,7 +904,7 @@ impl CreateRepoRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -940,7 +956,7 @@ impl DeleteRepoRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1004,7 +1020,7 @@ impl UpdateRepoSettingsRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1056,7 +1072,7 @@ impl MoveRepoRequest {
             path,
             Some(
                 serde_json::to_string(&self.body)
-                    .map_err(|e| { SchematicError::SerializationError(e.to_string()) })?,
+                    .map_err(|e| SchematicError::SerializationError(e.to_string()))?,
             ),
             vec![],
         ))
@@ -1110,7 +1126,9 @@ pub struct GetUserRequest {
 impl GetUserRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(username: impl Into<String>) -> Self {
-        Self { username: username.into() }
+        Self {
+            username: username.into(),
+        }
     }
 }
 impl GetUserRequest {
@@ -1150,7 +1168,9 @@ pub struct ListUserReposRequest {
 impl ListUserReposRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(username: impl Into<String>) -> Self {
-        Self { username: username.into() }
+        Self {
+            username: username.into(),
+        }
     }
 }
 impl ListUserReposRequest {
@@ -1190,7 +1210,9 @@ pub struct GetUserCollectionsRequest {
 impl GetUserCollectionsRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(username: impl Into<String>) -> Self {
-        Self { username: username.into() }
+        Self {
+            username: username.into(),
+        }
     }
 }
 impl GetUserCollectionsRequest {
@@ -1309,6 +1331,15 @@ impl HuggingFaceHubRequest {
             Self::GetUserCollections(req) => req.into_parts(),
         }
     }
+    /// Returns the endpoint's compression settings, if the definition
+    /// overrides the API-level default for it.
+    pub fn compression(&self) -> Option<schematic_define::Compression> {
+        None
+    }
+    /// Returns the endpoint's idempotency settings, if it declares any.
+    pub fn idempotency(&self) -> Option<schematic_define::Idempotency> {
+        None
+    }
 }
 impl From<ListModelsRequest> for HuggingFaceHubRequest {
     fn from(req: ListModelsRequest) -> Self {
@@ -1452,6 +1483,13 @@ pub struct HuggingFaceHub {
     env_username: Option<String>,
     /// Default HTTP headers to include with every request.
     headers: Vec<(String, String)>,
+    /// Content-encoding settings for endpoints without their own.
+    compression: schematic_define::Compression,
+    /// Requests in flight, for coalescing; shared with variants.
+    in_flight: InFlightRequests,
+    /// Cassette that records or replays responses.
+    #[cfg(feature = "vcr")]
+    cassette: Option<crate::shared::vcr::Cassette>,
 }
 impl HuggingFaceHub {
     /// Base URL for the API.
@@ -1462,14 +1500,20 @@ impl HuggingFaceHub {
             client: reqwest::Client::new(),
             base_url: Self::BASE_URL.to_string(),
             env_auth: vec![
-                "HF_TOKEN".to_string(), "HUGGING_FACE_API_KEY".to_string(), "HF_API_KEY"
-                .to_string()
+                "HF_TOKEN".to_string(),
+                "HUGGING_FACE_API_KEY".to_string(),
+                "HF_API_KEY".to_string(),
             ],
-            auth_strategy: schematic_define::AuthStrategy::BearerToken {
-                header: None,
-            },
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a custom base URL.
@@ -1484,14 +1528,20 @@ impl HuggingFaceHub {
             client: reqwest::Client::new(),
             base_url: base_url.into(),
             env_auth: vec![
-                "HF_TOKEN".to_string(), "HUGGING_FACE_API_KEY".to_string(), "HF_API_KEY"
-                .to_string()
+                "HF_TOKEN".to_string(),
+                "HUGGING_FACE_API_KEY".to_string(),
+                "HF_API_KEY".to_string(),
             ],
-            auth_strategy: schematic_define::AuthStrategy::BearerToken {
-                header: None,
-            },
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a pre-configured reqwest client.
@@ -1512,14 +1562,20 @@ impl HuggingFaceHub {
             client,
             base_url: Self::BASE_URL.to_string(),
             env_auth: vec![
-                "HF_TOKEN".to_string(), "HUGGING_FACE_API_KEY".to_string(), "HF_API_KEY"
-                .to_string()
+                "HF_TOKEN".to_string(),
+                "HUGGING_FACE_API_KEY".to_string(),
+                "HF_API_KEY".to_string(),
             ],
-            auth_strategy: schematic_define::AuthStrategy::BearerToken {
-                header: None,
-            },
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a pre-configured reqwest client and custom base URL.
@@ -1533,24 +1589,53 @@ impl HuggingFaceHub {
     ///     .unwrap();
     /// let api = Api::with_client_and_base_url(custom_client, "http://localhost:8080");
     /// ```
-    pub fn with_client_and_base_url(
-        client: reqwest::Client,
-        base_url: impl Into<String>,
-    ) -> Self {
+    pub fn with_client_and_base_url(client: reqwest::Client, base_url: impl Into<String>) -> Self {
         Self {
             client,
             base_url: base_url.into(),
             env_auth: vec![
-                "HF_TOKEN".to_string(), "HUGGING_FACE_API_KEY".to_string(), "HF_API_KEY"
-                .to_string()
+                "HF_TOKEN".to_string(),
+                "HUGGING_FACE_API_KEY".to_string(),
+                "HF_API_KEY".to_string(),
             ],
-            auth_strategy: schematic_define::AuthStrategy::BearerToken {
-                header: None,
-            },
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
+    /// Creates a new API client whose HTTP client has the connection
+    /// settings of `builder` (proxy, root CAs, client certificate, DNS
+    /// overrides, pool sizing).
+    ///
+    /// For a custom base URL as well, pass `builder.build()?` to
+    /// `with_client_and_base_url()`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::ClientConfig` if a builder setting is invalid.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_schema::shared::ApiClientBuilder;
+    ///
+    /// let builder = ApiClientBuilder::new()
+    ///     .proxy("http://proxy.corp.example:3128")
+    ///     .root_certificate_pem(std::fs::read("/etc/ssl/corp-ca.pem")?);
+    /// let api = Api::with_client_builder(&builder)?;
+    /// ```
+    pub fn with_client_builder(
+        builder: &crate::shared::ApiClientBuilder,
+    ) -> Result<Self, SchematicError> {
+        Ok(Self::with_client(builder.build()?))
+    }
     /// Creates a variant of this API client with different configuration.
     ///
     /// This method clones the underlying HTTP client and allows customizing:
@@ -1606,8 +1691,49 @@ impl HuggingFaceHub {
             auth_strategy,
             env_username: self.env_username.clone(),
             headers: self.headers.clone(),
+            compression: self.compression,
+            in_flight: self.in_flight.clone(),
+            #[cfg(feature = "vcr")]
+            cassette: self.cassette.clone(),
         }
     }
+    /// Replaces the API-level compression settings for this client.
+    ///
+    /// Endpoints with their own compression settings in the definition
+    /// keep them.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_define::{Compression, ContentEncoding};
+    ///
+    /// // Gzip request bodies of 8 KiB or more
+    /// let api = Api::new().with_compression(
+    ///     Compression::default().compress_requests(ContentEncoding::Gzip, 8 * 1024),
+    /// );
+    /// ```
+    pub fn with_compression(mut self, compression: schematic_define::Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+    /// Records responses to, or replays them from, a cassette.
+    ///
+    /// Replayed requests never reach the network and need no
+    /// credentials. Requires the `vcr` feature; see
+    /// `crate::shared::vcr`.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_schema::shared::vcr::{Cassette, VcrMode};
+    ///
+    /// let api = Api::new().with_cassette(Cassette::new("tests/cassettes/api", VcrMode::Auto));
+    /// ```
+    #[cfg(feature = "vcr")]
+    pub fn with_cassette(mut self, cassette: crate::shared::vcr::Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
     /// Returns a reference to the underlying HTTP client.
     ///
     /// Use this for custom requests that aren't covered by the generated methods,
@@ -1651,8 +1777,22 @@ impl HuggingFaceHub {
         request: impl Into<HuggingFaceHubRequest>,
     ) -> Result<reqwest::Response, SchematicError> {
         let request = request.into();
+        let compression = request.compression().unwrap_or(self.compression);
+        let idempotency = request.idempotency();
         let (method, path, body, endpoint_headers) = request.into_parts()?;
         let url = format!("{}{}", self.base_url, path);
+        #[cfg(feature = "vcr")]
+        let recording = match &self.cassette {
+            Some(cassette) => {
+                let request =
+                    crate::shared::vcr::RecordedRequest::new(method, &path, body.as_deref());
+                if let Some(interaction) = cassette.replay(&request)? {
+                    return interaction.into_response();
+                }
+                Some((cassette, request))
+            }
+            None => None,
+        };
         let mut req_builder = match method {
             "GET" => self.client.get(&url),
             "POST" => self.client.post(&url),
@@ -1674,8 +1814,7 @@ impl HuggingFaceHub {
                     .ok_or_else(|| SchematicError::MissingCredential {
                         env_vars: self.env_auth.clone(),
                     })?;
-                req_builder = req_builder
-                    .header(header_name, format!("Bearer {}", token));
+                req_builder = req_builder.header(header_name, format!("Bearer {}", token));
             }
             schematic_define::AuthStrategy::ApiKey { header } => {
                 let key = self
@@ -1694,34 +1833,68 @@ impl HuggingFaceHub {
                     .first()
                     .map(String::as_str)
                     .unwrap_or("PASSWORD");
-                let username = std::env::var(username_env)
-                    .map_err(|_| SchematicError::MissingCredential {
+                let username =
+                    std::env::var(username_env).map_err(|_| SchematicError::MissingCredential {
                         env_vars: vec![username_env.to_string()],
                     })?;
-                let password = std::env::var(password_env)
-                    .map_err(|_| SchematicError::MissingCredential {
+                let password =
+                    std::env::var(password_env).map_err(|_| SchematicError::MissingCredential {
                         env_vars: vec![password_env.to_string()],
                     })?;
                 req_builder = req_builder.basic_auth(username, Some(password));
             }
         }
         let merged_headers = Self::merge_headers(&self.headers, &endpoint_headers);
+        if !compression.decompress_responses
+            && !merged_headers
+                .iter()
+                .any(|(key, _)| key.eq_ignore_ascii_case("Accept-Encoding"))
+        {
+            req_builder = req_builder.header("Accept-Encoding", "identity");
+        }
+        let fingerprint = match &idempotency {
+            Some(config)
+                if !merged_headers
+                    .iter()
+                    .any(|(key, _)| key.eq_ignore_ascii_case(&config.header)) =>
+            {
+                req_builder = req_builder.header(config.header.as_str(), biscuit_hash::ulid());
+                config
+                    .dedupe_in_flight
+                    .then(|| (method.to_string(), url.clone(), body.clone()))
+            }
+            _ => None,
+        };
         for (key, value) in merged_headers {
             req_builder = req_builder.header(key.as_str(), value.as_str());
         }
         if let Some(body) = body {
-            req_builder = req_builder
-                .header("Content-Type", "application/json")
-                .body(body);
+            req_builder = req_builder.header("Content-Type", "application/json");
+            req_builder = match compression.request.filter(|c| c.applies_to(body.len())) {
+                Some(config) => req_builder
+                    .header("Content-Encoding", config.encoding.as_str())
+                    .body(compress_body(body.as_bytes(), config.encoding)?),
+                None => req_builder.body(body),
+            };
         }
-        let response = req_builder.send().await?;
+        let send = async {
+            let response = req_builder.send().await?;
+            #[cfg(feature = "vcr")]
+            let response = match recording {
+                Some((cassette, request)) => cassette.record(&request, response).await?,
+                None => response,
+            };
+            Ok::<_, SchematicError>(response)
+        };
+        let response = match fingerprint {
+            Some(fingerprint) => self.in_flight.send(fingerprint, send).await?,
+            None => send.await?,
+        };
         if !response.status().is_success() {
             let status = response.status().as_u16();
+            let headers = response.headers().clone();
             let body = response.text().await.unwrap_or_default();
-            return Err(SchematicError::ApiError {
-                status,
-                body,
-            });
+            return Err(response_error(status, &headers, body));
         }
         Ok(response)
     }
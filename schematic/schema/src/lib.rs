@@ -14,9 +14,10 @@
 //! ```ignore
 //! use schematic_schema::prelude::*;
 //! ```
-pub mod shared;
-pub mod prelude;
 pub mod anthropic;
-pub mod openai;
 pub mod elevenlabs;
+pub mod github;
 pub mod huggingface;
+pub mod openai;
+pub mod prelude;
+pub mod shared;
@@ -31,9 +31,11 @@
      Ok(())
  }
  ```*/
-use serde::{Deserialize, Serialize};
+use crate::shared::{
+    InFlightRequests, RequestParts, SchematicError, compress_body, response_error,
+};
 pub use schematic_definitions::openai::*;
-use crate::shared::{RequestParts, SchematicError};
+use serde::{Deserialize, Serialize};
 /// Request for `ListModels` endpoint.
 ///
 /// ## Example
@@ -82,7 +84,9 @@ pub struct RetrieveModelRequest {
 impl RetrieveModelRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(model: impl Into<String>) -> Self {
-        Self { model: model.into() }
+        Self {
+            model: model.into(),
+        }
     }
 }
 impl RetrieveModelRequest {
@@ -122,7 +126,9 @@ pub struct DeleteModelRequest {
 impl DeleteModelRequest {
     /// Creates a new request with the required path parameters.
     pub fn new(model: impl Into<String>) -> Self {
-        Self { model: model.into() }
+        Self {
+            model: model.into(),
+        }
     }
 }
 impl DeleteModelRequest {
@@ -172,6 +178,15 @@ impl OpenAIRequest {
             Self::DeleteModel(req) => req.into_parts(),
         }
     }
+    /// Returns the endpoint's compression settings, if the definition
+    /// overrides the API-level default for it.
+    pub fn compression(&self) -> Option<schematic_define::Compression> {
+        None
+    }
+    /// Returns the endpoint's idempotency settings, if it declares any.
+    pub fn idempotency(&self) -> Option<schematic_define::Idempotency> {
+        None
+    }
 }
 impl From<ListModelsRequest> for OpenAIRequest {
     fn from(req: ListModelsRequest) -> Self {
@@ -200,6 +215,13 @@ pub struct OpenAI {
     env_username: Option<String>,
     /// Default HTTP headers to include with every request.
     headers: Vec<(String, String)>,
+    /// Content-encoding settings for endpoints without their own.
+    compression: schematic_define::Compression,
+    /// Requests in flight, for coalescing; shared with variants.
+    in_flight: InFlightRequests,
+    /// Cassette that records or replays responses.
+    #[cfg(feature = "vcr")]
+    cassette: Option<crate::shared::vcr::Cassette>,
 }
 impl OpenAI {
     /// Base URL for the API.
@@ -210,11 +232,16 @@ impl OpenAI {
             client: reqwest::Client::new(),
             base_url: Self::BASE_URL.to_string(),
             env_auth: vec!["OPENAI_API_KEY".to_string()],
-            auth_strategy: schematic_define::AuthStrategy::BearerToken {
-                header: None,
-            },
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a custom base URL.
@@ -229,11 +256,16 @@ impl OpenAI {
             client: reqwest::Client::new(),
             base_url: base_url.into(),
             env_auth: vec!["OPENAI_API_KEY".to_string()],
-            auth_strategy: schematic_define::AuthStrategy::BearerToken {
-                header: None,
-            },
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a pre-configured reqwest client.
@@ -254,11 +286,16 @@ impl OpenAI {
             client,
             base_url: Self::BASE_URL.to_string(),
             env_auth: vec!["OPENAI_API_KEY".to_string()],
-            auth_strategy: schematic_define::AuthStrategy::BearerToken {
-                header: None,
-            },
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
     /// Creates a new API client with a pre-configured reqwest client and custom base URL.
@@ -272,21 +309,49 @@ impl OpenAI {
     ///     .unwrap();
     /// let api = Api::with_client_and_base_url(custom_client, "http://localhost:8080");
     /// ```
-    pub fn with_client_and_base_url(
-        client: reqwest::Client,
-        base_url: impl Into<String>,
-    ) -> Self {
+    pub fn with_client_and_base_url(client: reqwest::Client, base_url: impl Into<String>) -> Self {
         Self {
             client,
             base_url: base_url.into(),
             env_auth: vec!["OPENAI_API_KEY".to_string()],
-            auth_strategy: schematic_define::AuthStrategy::BearerToken {
-                header: None,
-            },
+            auth_strategy: schematic_define::AuthStrategy::BearerToken { header: None },
             env_username: None,
             headers: vec![],
+            compression: schematic_define::Compression {
+                decompress_responses: true,
+                request: None,
+            },
+            in_flight: InFlightRequests::default(),
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
+    /// Creates a new API client whose HTTP client has the connection
+    /// settings of `builder` (proxy, root CAs, client certificate, DNS
+    /// overrides, pool sizing).
+    ///
+    /// For a custom base URL as well, pass `builder.build()?` to
+    /// `with_client_and_base_url()`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `SchematicError::ClientConfig` if a builder setting is invalid.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_schema::shared::ApiClientBuilder;
+    ///
+    /// let builder = ApiClientBuilder::new()
+    ///     .proxy("http://proxy.corp.example:3128")
+    ///     .root_certificate_pem(std::fs::read("/etc/ssl/corp-ca.pem")?);
+    /// let api = Api::with_client_builder(&builder)?;
+    /// ```
+    pub fn with_client_builder(
+        builder: &crate::shared::ApiClientBuilder,
+    ) -> Result<Self, SchematicError> {
+        Ok(Self::with_client(builder.build()?))
+    }
     /// Creates a variant of this API client with different configuration.
     ///
     /// This method clones the underlying HTTP client and allows customizing:
@@ -342,8 +407,49 @@ impl OpenAI {
             auth_strategy,
             env_username: self.env_username.clone(),
             headers: self.headers.clone(),
+            compression: self.compression,
+            in_flight: self.in_flight.clone(),
+            #[cfg(feature = "vcr")]
+            cassette: self.cassette.clone(),
         }
     }
+    /// Replaces the API-level compression settings for this client.
+    ///
+    /// Endpoints with their own compression settings in the definition
+    /// keep them.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_define::{Compression, ContentEncoding};
+    ///
+    /// // Gzip request bodies of 8 KiB or more
+    /// let api = Api::new().with_compression(
+    ///     Compression::default().compress_requests(ContentEncoding::Gzip, 8 * 1024),
+    /// );
+    /// ```
+    pub fn with_compression(mut self, compression: schematic_define::Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+    /// Records responses to, or replays them from, a cassette.
+    ///
+    /// Replayed requests never reach the network and need no
+    /// credentials. Requires the `vcr` feature; see
+    /// `crate::shared::vcr`.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// use schematic_schema::shared::vcr::{Cassette, VcrMode};
+    ///
+    /// let api = Api::new().with_cassette(Cassette::new("tests/cassettes/api", VcrMode::Auto));
+    /// ```
+    #[cfg(feature = "vcr")]
+    pub fn with_cassette(mut self, cassette: crate::shared::vcr::Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
     /// Returns a reference to the underlying HTTP client.
     ///
     /// Use this for custom requests that aren't covered by the generated methods,
@@ -387,8 +493,22 @@ impl OpenAI {
         request: impl Into<OpenAIRequest>,
     ) -> Result<reqwest::Response, SchematicError> {
         let request = request.into();
+        let compression = request.compression().unwrap_or(self.compression);
+        let idempotency = request.idempotency();
         let (method, path, body, endpoint_headers) = request.into_parts()?;
         let url = format!("{}{}", self.base_url, path);
+        #[cfg(feature = "vcr")]
+        let recording = match &self.cassette {
+            Some(cassette) => {
+                let request =
+                    crate::shared::vcr::RecordedRequest::new(method, &path, body.as_deref());
+                if let Some(interaction) = cassette.replay(&request)? {
+                    return interaction.into_response();
+                }
+                Some((cassette, request))
+            }
+            None => None,
+        };
         let mut req_builder = match method {
             "GET" => self.client.get(&url),
             "POST" => self.client.post(&url),
@@ -410,8 +530,7 @@ impl OpenAI {
                     .ok_or_else(|| SchematicError::MissingCredential {
                         env_vars: self.env_auth.clone(),
                     })?;
-                req_builder = req_builder
-                    .header(header_name, format!("Bearer {}", token));
+                req_builder = req_builder.header(header_name, format!("Bearer {}", token));
             }
             schematic_define::AuthStrategy::ApiKey { header } => {
                 let key = self
@@ -430,34 +549,68 @@ impl OpenAI {
                     .first()
                     .map(String::as_str)
                     .unwrap_or("PASSWORD");
-                let username = std::env::var(username_env)
-                    .map_err(|_| SchematicError::MissingCredential {
+                let username =
+                    std::env::var(username_env).map_err(|_| SchematicError::MissingCredential {
                         env_vars: vec![username_env.to_string()],
                     })?;
-                let password = std::env::var(password_env)
-                    .map_err(|_| SchematicError::MissingCredential {
+                let password =
+                    std::env::var(password_env).map_err(|_| SchematicError::MissingCredential {
                         env_vars: vec![password_env.to_string()],
                     })?;
                 req_builder = req_builder.basic_auth(username, Some(password));
             }
         }
         let merged_headers = Self::merge_headers(&self.headers, &endpoint_headers);
+        if !compression.decompress_responses
+            && !merged_headers
+                .iter()
+                .any(|(key, _)| key.eq_ignore_ascii_case("Accept-Encoding"))
+        {
+            req_builder = req_builder.header("Accept-Encoding", "identity");
+        }
+        let fingerprint = match &idempotency {
+            Some(config)
+                if !merged_headers
+                    .iter()
+                    .any(|(key, _)| key.eq_ignore_ascii_case(&config.header)) =>
+            {
+                req_builder = req_builder.header(config.header.as_str(), biscuit_hash::ulid());
+                config
+                    .dedupe_in_flight
+                    .then(|| (method.to_string(), url.clone(), body.clone()))
+            }
+            _ => None,
+        };
         for (key, value) in merged_headers {
             req_builder = req_builder.header(key.as_str(), value.as_str());
         }
         if let Some(body) = body {
-            req_builder = req_builder
-                .header("Content-Type", "application/json")
-                .body(body);
+            req_builder = req_builder.header("Content-Type", "application/json");
+            req_builder = match compression.request.filter(|c| c.applies_to(body.len())) {
+                Some(config) => req_builder
+                    .header("Content-Encoding", config.encoding.as_str())
+                    .body(compress_body(body.as_bytes(), config.encoding)?),
+                None => req_builder.body(body),
+            };
         }
-        let response = req_builder.send().await?;
+        let send = async {
+            let response = req_builder.send().await?;
+            #[cfg(feature = "vcr")]
+            let response = match recording {
+                Some((cassette, request)) => cassette.record(&request, response).await?,
+                None => response,
+            };
+            Ok::<_, SchematicError>(response)
+        };
+        let response = match fingerprint {
+            Some(fingerprint) => self.in_flight.send(fingerprint, send).await?,
+            None => send.await?,
+        };
         if !response.status().is_success() {
             let status = response.status().as_u16();
+            let headers = response.headers().clone();
             let body = response.text().await.unwrap_or_default();
-            return Err(SchematicError::ApiError {
-                status,
-                body,
-            });
+            return Err(response_error(status, &headers, body));
         }
         Ok(response)
     }
@@ -503,3 +656,208 @@ impl OpenAI {
         Ok(result)
     }
 }
+/// Markdown usage documentation for each `OpenAI` endpoint.
+///
+/// Generated from the API definition alongside the client, so it always
+/// matches the generated request types.
+pub mod docs {
+    /// ## ListModels
+    ///
+    /// Lists the currently available models
+    ///
+    /// `GET /models`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** `ListModelsResponse` (JSON)
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl ListModelsRequest {
+    ///     fn default() -> Self;
+    /// }
+    ///
+    /// impl OpenAI {
+    ///     pub async fn request<T: DeserializeOwned>(&self, request: impl Into<OpenAIRequest>) -> Result<T, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::openai::{OpenAI, ListModelsRequest};
+    ///
+    /// let client = OpenAI::new();
+    /// let request = ListModelsRequest::default();
+    /// let response: ListModelsResponse = client.request(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `OPENAI_API_KEY`.
+    pub const LIST_MODELS: &str = "## ListModels\n\nLists the currently available models\n\n`GET /models`\n\n- **Request:** no body\n- **Response:** `ListModelsResponse` (JSON)\n\n### Signature\n\n```rust\nimpl ListModelsRequest {\n    fn default() -> Self;\n}\n\nimpl OpenAI {\n    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<OpenAIRequest>) -> Result<T, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::openai::{OpenAI, ListModelsRequest};\n\nlet client = OpenAI::new();\nlet request = ListModelsRequest::default();\nlet response: ListModelsResponse = client.request(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `OPENAI_API_KEY`.";
+    /// ## RetrieveModel
+    ///
+    /// Retrieves a model instance
+    ///
+    /// `GET /models/{model}`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** `Model` (JSON)
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl RetrieveModelRequest {
+    ///     pub fn new(model: impl Into<String>) -> Self;
+    /// }
+    ///
+    /// impl OpenAI {
+    ///     pub async fn request<T: DeserializeOwned>(&self, request: impl Into<OpenAIRequest>) -> Result<T, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::openai::{OpenAI, RetrieveModelRequest};
+    ///
+    /// let client = OpenAI::new();
+    /// let request = RetrieveModelRequest::new("model_value");
+    /// let response: Model = client.request(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `OPENAI_API_KEY`.
+    pub const RETRIEVE_MODEL: &str = "## RetrieveModel\n\nRetrieves a model instance\n\n`GET /models/{model}`\n\n- **Request:** no body\n- **Response:** `Model` (JSON)\n\n### Signature\n\n```rust\nimpl RetrieveModelRequest {\n    pub fn new(model: impl Into<String>) -> Self;\n}\n\nimpl OpenAI {\n    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<OpenAIRequest>) -> Result<T, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::openai::{OpenAI, RetrieveModelRequest};\n\nlet client = OpenAI::new();\nlet request = RetrieveModelRequest::new(\"model_value\");\nlet response: Model = client.request(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `OPENAI_API_KEY`.";
+    /// ## DeleteModel
+    ///
+    /// Delete a fine-tuned model
+    ///
+    /// `DELETE /models/{model}`
+    ///
+    /// - **Request:** no body
+    /// - **Response:** `DeleteModelResponse` (JSON)
+    ///
+    /// ### Signature
+    ///
+    /// ```ignore
+    /// impl DeleteModelRequest {
+    ///     pub fn new(model: impl Into<String>) -> Self;
+    /// }
+    ///
+    /// impl OpenAI {
+    ///     pub async fn request<T: DeserializeOwned>(&self, request: impl Into<OpenAIRequest>) -> Result<T, SchematicError>;
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// use schematic_schema::openai::{OpenAI, DeleteModelRequest};
+    ///
+    /// let client = OpenAI::new();
+    /// let request = DeleteModelRequest::new("model_value");
+    /// let response: DeleteModelResponse = client.request(request).await?;
+    /// ```
+    ///
+    /// ### Authentication
+    ///
+    /// Uses Bearer token authentication via the `Authorization` header. Set via environment variable: `OPENAI_API_KEY`.
+    pub const DELETE_MODEL: &str = "## DeleteModel\n\nDelete a fine-tuned model\n\n`DELETE /models/{model}`\n\n- **Request:** no body\n- **Response:** `DeleteModelResponse` (JSON)\n\n### Signature\n\n```rust\nimpl DeleteModelRequest {\n    pub fn new(model: impl Into<String>) -> Self;\n}\n\nimpl OpenAI {\n    pub async fn request<T: DeserializeOwned>(&self, request: impl Into<OpenAIRequest>) -> Result<T, SchematicError>;\n}\n```\n\n### Example\n\n```rust\nuse schematic_schema::openai::{OpenAI, DeleteModelRequest};\n\nlet client = OpenAI::new();\nlet request = DeleteModelRequest::new(\"model_value\");\nlet response: DeleteModelResponse = client.request(request).await?;\n```\n\n### Authentication\n\nUses Bearer token authentication via the `Authorization` header. Set via environment variable: `OPENAI_API_KEY`.";
+    /// Every endpoint's usage documentation as `(endpoint id, Markdown)` pairs,
+    /// in definition order.
+    pub const ENDPOINTS: &[(&str, &str)] = &[
+        ("ListModels", LIST_MODELS),
+        ("RetrieveModel", RETRIEVE_MODEL),
+        ("DeleteModel", DELETE_MODEL),
+    ];
+}
+/// Contract tests generated from the endpoint examples in the API definition.
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    /// Deserializes `example` into `T`, serializes it back, and checks that
+    /// every non-null value in the example survived the round trip.
+    fn assert_example_roundtrip<T>(type_name: &str, example: &str)
+    where
+        T: serde::de::DeserializeOwned + serde::Serialize,
+    {
+        let original: serde_json::Value = serde_json::from_str(example)
+            .unwrap_or_else(|e| panic!("Example for `{}` is not valid JSON: {}", type_name, e));
+        let parsed: T = serde_json::from_value(original.clone())
+            .unwrap_or_else(|e| panic!("Example does not deserialize into `{}`: {}", type_name, e));
+        let roundtrip = serde_json::to_value(&parsed)
+            .unwrap_or_else(|e| panic!("`{}` failed to serialize: {}", type_name, e));
+        if let Err(path) = example_preserved(&original, &roundtrip, "$") {
+            panic!(
+                "`{}` does not preserve the example value at `{}` (field missing from the type or changed during round trip)",
+                type_name, path
+            );
+        }
+    }
+    /// Checks that `actual` contains every non-null value in `expected`.
+    ///
+    /// Returns the JSON path of the first value that was dropped or changed.
+    fn example_preserved(
+        expected: &serde_json::Value,
+        actual: &serde_json::Value,
+        path: &str,
+    ) -> Result<(), String> {
+        use serde_json::Value;
+        match (expected, actual) {
+            (Value::Null, _) => Ok(()),
+            (Value::Object(expected), Value::Object(actual)) => {
+                for (key, value) in expected {
+                    let child = format!("{}.{}", path, key);
+                    match actual.get(key) {
+                        Some(actual_value) => example_preserved(value, actual_value, &child)?,
+                        None if is_empty_value(value) => {}
+                        None => return Err(child),
+                    }
+                }
+                Ok(())
+            }
+            (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+                for (i, (e, a)) in expected.iter().zip(actual).enumerate() {
+                    example_preserved(e, a, &format!("{}[{}]", path, i))?;
+                }
+                Ok(())
+            }
+            (Value::Number(e), Value::Number(a)) if e.as_f64() == a.as_f64() => Ok(()),
+            (expected, actual) if expected == actual => Ok(()),
+            _ => Err(path.to_string()),
+        }
+    }
+    /// Returns true for null, empty arrays, and empty objects.
+    fn is_empty_value(value: &serde_json::Value) -> bool {
+        match value {
+            serde_json::Value::Null => true,
+            serde_json::Value::Array(items) => items.is_empty(),
+            serde_json::Value::Object(map) => map.is_empty(),
+            _ => false,
+        }
+    }
+    #[test]
+    fn list_models_docs_response() {
+        assert_example_roundtrip::<ListModelsResponse>(
+            "ListModelsResponse",
+            r#"{"object": "list", "data": [{"id": "gpt-4.1", "object": "model", "created": 1686935002, "owned_by": "openai"}]}"#,
+        );
+    }
+    #[test]
+    fn retrieve_model_docs_response() {
+        assert_example_roundtrip::<Model>(
+            "Model",
+            r#"{"id": "gpt-4.1", "object": "model", "created": 1686935002, "owned_by": "openai"}"#,
+        );
+    }
+    #[test]
+    fn delete_model_docs_response() {
+        assert_example_roundtrip::<DeleteModelResponse>(
+            "DeleteModelResponse",
+            r#"{"id": "ft:gpt-4o-mini:acemeco:suffix:abc123", "object": "model", "deleted": true}"#,
+        );
+    }
+}
@@ -22,8 +22,9 @@
 //!     Ok(())
 //! }
 //! ```
-pub use crate::shared::{RequestParts, SchematicError};
 pub use crate::anthropic::{Anthropic, AnthropicRequest};
-pub use crate::openai::{OpenAI, OpenAIRequest};
 pub use crate::elevenlabs::{ElevenLabs, ElevenLabsRequest};
+pub use crate::github::{GitHub, GitHubRequest};
 pub use crate::huggingface::{HuggingFaceHub, HuggingFaceHubRequest};
+pub use crate::openai::{OpenAI, OpenAIRequest};
+pub use crate::shared::{ApiClientBuilder, RequestParts, SchematicError};
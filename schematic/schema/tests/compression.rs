@@ -0,0 +1,241 @@
+//! Integration tests for content-encoding support in generated clients.
+//!
+//! These tests use wiremock to verify that generated clients decompress
+//! gzip/brotli responses and compress request bodies when configured.
+
+use std::io::{Read, Write};
+
+use schematic_define::{Compression, ContentEncoding};
+use schematic_schema::huggingface::{
+    CreateRepoBody, CreateRepoRequest, HuggingFaceHub, HuggingFaceHubRequest,
+};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+/// Helper to set up environment for tests.
+fn setup_test_env() {
+    // SAFETY: Tests run in isolation, setting env vars is safe here
+    unsafe {
+        std::env::set_var("HF_TOKEN", "test-token");
+    }
+}
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn gunzip(data: &[u8]) -> Vec<u8> {
+    let mut decoded = Vec::new();
+    flate2::read::GzDecoder::new(data)
+        .read_to_end(&mut decoded)
+        .unwrap();
+    decoded
+}
+
+fn brotli_compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = brotli::CompressorWriter::new(Vec::new(), 4096, 5, 22);
+    encoder.write_all(data).unwrap();
+    encoder.into_inner()
+}
+
+fn brotli_decompress(data: &[u8]) -> Vec<u8> {
+    let mut decoded = Vec::new();
+    brotli::Decompressor::new(data, 4096)
+        .read_to_end(&mut decoded)
+        .unwrap();
+    decoded
+}
+
+fn create_repo_request(name: &str) -> HuggingFaceHubRequest {
+    let body = CreateRepoBody {
+        name: name.to_string(),
+        ..Default::default()
+    };
+    HuggingFaceHubRequest::CreateRepo(CreateRepoRequest { body })
+}
+
+/// Returns the single request received by the mock server.
+async fn received_request(mock_server: &MockServer) -> Request {
+    let mut requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1, "Expected exactly one request");
+    requests.remove(0)
+}
+
+/// Test that gzip responses are transparently decompressed.
+#[tokio::test]
+async fn test_gzip_response_is_decompressed() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/whoami-v2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Encoding", "gzip")
+                .insert_header("Content-Type", "application/json")
+                .set_body_bytes(gzip(br#"{"type": "user", "name": "testuser"}"#)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = HuggingFaceHub::with_base_url(mock_server.uri());
+    let request = HuggingFaceHubRequest::WhoAmI(Default::default());
+    let result: serde_json::Value = client.request(request).await.unwrap();
+
+    assert_eq!(result["name"], "testuser");
+
+    let received = received_request(&mock_server).await;
+    let accept = received.headers.get("accept-encoding").unwrap();
+    assert!(accept.to_str().unwrap().contains("gzip"));
+    assert!(accept.to_str().unwrap().contains("br"));
+}
+
+/// Test that brotli responses are transparently decompressed.
+#[tokio::test]
+async fn test_brotli_response_is_decompressed() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/whoami-v2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Encoding", "br")
+                .insert_header("Content-Type", "application/json")
+                .set_body_bytes(brotli_compress(br#"{"type": "user", "name": "testuser"}"#)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = HuggingFaceHub::with_base_url(mock_server.uri());
+    let request = HuggingFaceHubRequest::WhoAmI(Default::default());
+    let result: serde_json::Value = client.request(request).await.unwrap();
+
+    assert_eq!(result["name"], "testuser");
+}
+
+/// Test that disabling decompression requests an identity encoding.
+#[tokio::test]
+async fn test_disabled_compression_requests_identity() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/whoami-v2"))
+        .and(header("Accept-Encoding", "identity"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "type": "user",
+            "name": "testuser"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        HuggingFaceHub::with_base_url(mock_server.uri()).with_compression(Compression::disabled());
+    let request = HuggingFaceHubRequest::WhoAmI(Default::default());
+    let result: Result<serde_json::Value, _> = client.request(request).await;
+
+    assert!(result.is_ok(), "Request failed: {:?}", result.err());
+}
+
+/// Test that request bodies are sent uncompressed by default.
+#[tokio::test]
+async fn test_request_body_uncompressed_by_default() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/create"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .mount(&mock_server)
+        .await;
+
+    let client = HuggingFaceHub::with_base_url(mock_server.uri());
+    let _: serde_json::Value = client
+        .request(create_repo_request("my-model"))
+        .await
+        .unwrap();
+
+    let received = received_request(&mock_server).await;
+    assert!(received.headers.get("content-encoding").is_none());
+    let body: serde_json::Value = serde_json::from_slice(&received.body).unwrap();
+    assert_eq!(body["name"], "my-model");
+}
+
+/// Test that request bodies at or above the threshold are gzip-compressed.
+#[tokio::test]
+async fn test_large_request_body_is_gzip_compressed() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/create"))
+        .and(header("Content-Encoding", "gzip"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .mount(&mock_server)
+        .await;
+
+    let client = HuggingFaceHub::with_base_url(mock_server.uri())
+        .with_compression(Compression::default().compress_requests(ContentEncoding::Gzip, 0));
+    let _: serde_json::Value = client
+        .request(create_repo_request("my-model"))
+        .await
+        .unwrap();
+
+    let received = received_request(&mock_server).await;
+    let body: serde_json::Value = serde_json::from_slice(&gunzip(&received.body)).unwrap();
+    assert_eq!(body["name"], "my-model");
+}
+
+/// Test that request bodies below the threshold are left uncompressed.
+#[tokio::test]
+async fn test_small_request_body_skips_compression() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/create"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .mount(&mock_server)
+        .await;
+
+    let client = HuggingFaceHub::with_base_url(mock_server.uri())
+        .with_compression(Compression::default().compress_requests(ContentEncoding::Gzip, 1024));
+    let _: serde_json::Value = client
+        .request(create_repo_request("my-model"))
+        .await
+        .unwrap();
+
+    let received = received_request(&mock_server).await;
+    assert!(received.headers.get("content-encoding").is_none());
+    let body: serde_json::Value = serde_json::from_slice(&received.body).unwrap();
+    assert_eq!(body["name"], "my-model");
+}
+
+/// Test that request bodies can be brotli-compressed.
+#[tokio::test]
+async fn test_request_body_brotli_compressed() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/create"))
+        .and(header("Content-Encoding", "br"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .mount(&mock_server)
+        .await;
+
+    let client = HuggingFaceHub::with_base_url(mock_server.uri())
+        .with_compression(Compression::default().compress_requests(ContentEncoding::Brotli, 0));
+    let _: serde_json::Value = client
+        .request(create_repo_request("my-model"))
+        .await
+        .unwrap();
+
+    let received = received_request(&mock_server).await;
+    let body: serde_json::Value =
+        serde_json::from_slice(&brotli_decompress(&received.body)).unwrap();
+    assert_eq!(body["name"], "my-model");
+}
@@ -0,0 +1,147 @@
+//! Integration tests for the in-flight request registry used by generated clients.
+//!
+//! No bundled API declares idempotent endpoints yet, so these tests exercise
+//! `InFlightRequests` directly against a wiremock server, the way
+//! `build_and_send_request()` uses it.
+
+use std::future::Future;
+use std::time::Duration;
+
+use schematic_schema::shared::reqwest;
+use schematic_schema::shared::{InFlightRequests, RequestFingerprint, SchematicError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const BODY: &str = r#"{"amount":100}"#;
+
+/// Mounts a `/v1/payments` mock that answers slowly enough for calls to overlap.
+async fn mount_payments(mock_server: &MockServer, status: u16, expected_requests: u64) {
+    Mock::given(method("POST"))
+        .and(path("/v1/payments"))
+        .respond_with(
+            ResponseTemplate::new(status)
+                .set_body_string(r#"{"id":"ch_1"}"#)
+                .set_delay(Duration::from_millis(200)),
+        )
+        .expect(expected_requests)
+        .mount(mock_server)
+        .await;
+}
+
+fn fingerprint(url: &str, body: &str) -> RequestFingerprint {
+    ("POST".to_string(), url.to_string(), Some(body.to_string()))
+}
+
+fn post(
+    client: &reqwest::Client,
+    url: &str,
+    body: &str,
+) -> impl Future<Output = Result<reqwest::Response, SchematicError>> + use<> {
+    let request = client.post(url).body(body.to_string()).send();
+    async move { Ok(request.await?) }
+}
+
+/// Test that two concurrent identical calls send one request.
+#[tokio::test]
+async fn test_concurrent_identical_requests_send_once() {
+    let mock_server = MockServer::start().await;
+    mount_payments(&mock_server, 200, 1).await;
+
+    let client = reqwest::Client::new();
+    let registry = InFlightRequests::default();
+    let url = format!("{}/v1/payments", mock_server.uri());
+
+    let (first, second) = tokio::join!(
+        registry.send(fingerprint(&url, BODY), post(&client, &url, BODY)),
+        registry.send(fingerprint(&url, BODY), post(&client, &url, BODY)),
+    );
+
+    assert_eq!(first.unwrap().text().await.unwrap(), r#"{"id":"ch_1"}"#);
+    assert_eq!(second.unwrap().text().await.unwrap(), r#"{"id":"ch_1"}"#);
+    mock_server.verify().await;
+}
+
+/// Test that coalesced callers all see an error status.
+#[tokio::test]
+async fn test_coalesced_requests_share_error_status() {
+    let mock_server = MockServer::start().await;
+    mount_payments(&mock_server, 503, 1).await;
+
+    let client = reqwest::Client::new();
+    let registry = InFlightRequests::default();
+    let url = format!("{}/v1/payments", mock_server.uri());
+
+    let (first, second) = tokio::join!(
+        registry.send(fingerprint(&url, BODY), post(&client, &url, BODY)),
+        registry.send(fingerprint(&url, BODY), post(&client, &url, BODY)),
+    );
+
+    assert_eq!(first.unwrap().status().as_u16(), 503);
+    assert_eq!(second.unwrap().status().as_u16(), 503);
+    mock_server.verify().await;
+}
+
+/// Test that requests with different bodies are each sent.
+#[tokio::test]
+async fn test_different_requests_are_not_coalesced() {
+    let mock_server = MockServer::start().await;
+    mount_payments(&mock_server, 200, 2).await;
+
+    let client = reqwest::Client::new();
+    let registry = InFlightRequests::default();
+    let url = format!("{}/v1/payments", mock_server.uri());
+    let other_body = r#"{"amount":5}"#;
+
+    let (first, second) = tokio::join!(
+        registry.send(fingerprint(&url, BODY), post(&client, &url, BODY)),
+        registry.send(
+            fingerprint(&url, other_body),
+            post(&client, &url, other_body)
+        ),
+    );
+
+    assert!(first.is_ok());
+    assert!(second.is_ok());
+    mock_server.verify().await;
+}
+
+/// Test that a request is forgotten once its response arrives.
+#[tokio::test]
+async fn test_completed_request_is_forgotten() {
+    let mock_server = MockServer::start().await;
+    mount_payments(&mock_server, 200, 2).await;
+
+    let client = reqwest::Client::new();
+    let registry = InFlightRequests::default();
+    let url = format!("{}/v1/payments", mock_server.uri());
+
+    for _ in 0..2 {
+        let response = registry
+            .send(fingerprint(&url, BODY), post(&client, &url, BODY))
+            .await;
+        assert!(response.is_ok());
+        assert!(!registry.contains(&fingerprint(&url, BODY)));
+    }
+    mock_server.verify().await;
+}
+
+/// Test that clones of a registry coalesce with each other.
+#[tokio::test]
+async fn test_cloned_registries_coalesce() {
+    let mock_server = MockServer::start().await;
+    mount_payments(&mock_server, 200, 1).await;
+
+    let client = reqwest::Client::new();
+    let registry = InFlightRequests::default();
+    let variant = registry.clone();
+    let url = format!("{}/v1/payments", mock_server.uri());
+
+    let (first, second) = tokio::join!(
+        registry.send(fingerprint(&url, BODY), post(&client, &url, BODY)),
+        variant.send(fingerprint(&url, BODY), post(&client, &url, BODY)),
+    );
+
+    assert!(first.is_ok());
+    assert!(second.is_ok());
+    mock_server.verify().await;
+}
@@ -0,0 +1,153 @@
+//! Integration tests for rate limit handling in generated clients.
+//!
+//! These tests use wiremock to verify that rate-limited responses surface as
+//! `SchematicError::RateLimited` with the delay the server asked for, using
+//! the GitHub client (which reports limits through `X-RateLimit-*` headers).
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use schematic_schema::github::{GetRateLimitRequest, GetRepoRequest, GitHub, RateLimitResponse};
+use schematic_schema::shared::SchematicError;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Helper to set up environment for tests.
+fn setup_test_env() {
+    // SAFETY: Tests run in isolation, setting env vars is safe here
+    unsafe {
+        std::env::set_var("GITHUB_TOKEN", "test-token");
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// An exhausted primary limit (403 + `X-RateLimit-Remaining: 0`) waits until
+/// `X-RateLimit-Reset`.
+#[tokio::test]
+async fn test_exhausted_primary_limit_is_rate_limited() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+    let reset = unix_now() + 120;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/clap-rs/clap"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .insert_header("X-RateLimit-Remaining", "0")
+                .insert_header("X-RateLimit-Reset", reset.to_string().as_str())
+                .set_body_string(r#"{"message": "API rate limit exceeded"}"#),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = GitHub::with_base_url(mock_server.uri());
+    let result: Result<serde_json::Value, _> =
+        client.request(GetRepoRequest::new("clap-rs", "clap")).await;
+
+    match result {
+        Err(SchematicError::RateLimited {
+            status,
+            retry_after,
+            body,
+        }) => {
+            assert_eq!(status, 403);
+            let retry_after = retry_after.expect("reset header should give a delay");
+            assert!(retry_after <= Duration::from_secs(120));
+            assert!(retry_after >= Duration::from_secs(110));
+            assert!(body.contains("rate limit exceeded"));
+        }
+        other => panic!("Expected RateLimited, got {other:?}"),
+    }
+}
+
+/// A secondary limit (429 + `Retry-After`) waits for the given seconds.
+#[tokio::test]
+async fn test_retry_after_is_used() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/clap-rs/clap"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "30"))
+        .mount(&mock_server)
+        .await;
+
+    let client = GitHub::with_base_url(mock_server.uri());
+    let result: Result<serde_json::Value, _> =
+        client.request(GetRepoRequest::new("clap-rs", "clap")).await;
+
+    assert!(
+        matches!(
+            result,
+            Err(SchematicError::RateLimited {
+                status: 429,
+                retry_after: Some(delay),
+                ..
+            }) if delay == Duration::from_secs(30)
+        ),
+        "Expected RateLimited with a 30s delay, got {result:?}"
+    );
+}
+
+/// A 403 that is not a rate limit (e.g. missing permissions) stays an API error.
+#[tokio::test]
+async fn test_plain_forbidden_is_api_error() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/clap-rs/clap"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .insert_header("X-RateLimit-Remaining", "4999")
+                .set_body_string("Resource not accessible by integration"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = GitHub::with_base_url(mock_server.uri());
+    let result: Result<serde_json::Value, _> =
+        client.request(GetRepoRequest::new("clap-rs", "clap")).await;
+
+    assert!(
+        matches!(result, Err(SchematicError::ApiError { status: 403, .. })),
+        "Expected ApiError, got {result:?}"
+    );
+}
+
+/// The GitHub client sends its default headers and parses the rate limit
+/// endpoint's response.
+#[tokio::test]
+async fn test_get_rate_limit() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/rate_limit"))
+        .and(header("Authorization", "Bearer test-token"))
+        .and(header("X-GitHub-Api-Version", "2022-11-28"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "resources": {
+                "core": {"limit": 5000, "remaining": 4999, "reset": 1735689600, "used": 1},
+                "search": {"limit": 30, "remaining": 30, "reset": 1735689600, "used": 0}
+            },
+            "rate": {"limit": 5000, "remaining": 4999, "reset": 1735689600, "used": 1}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = GitHub::with_base_url(mock_server.uri());
+    let response: RateLimitResponse = client
+        .request(GetRateLimitRequest::default())
+        .await
+        .expect("request should succeed");
+
+    assert_eq!(response.rate.remaining, 4999);
+    assert_eq!(response.resources.search.limit, 30);
+    assert!(response.resources.graphql.is_none());
+}
@@ -0,0 +1,26 @@
+//! Integration tests for the generated per-endpoint usage documentation.
+//!
+//! The OpenAI definition opts into `usage_docs`, so its module carries a
+//! `docs` submodule built from the same definition as the client.
+
+use schematic_schema::openai::docs;
+
+/// Test that every OpenAI endpoint has a document, in definition order.
+#[test]
+fn test_docs_cover_every_endpoint() {
+    let ids: Vec<&str> = docs::ENDPOINTS.iter().map(|(id, _)| *id).collect();
+    assert_eq!(ids, vec!["ListModels", "RetrieveModel", "DeleteModel"]);
+}
+
+/// Test that a document shows the constructor, sample call and auth.
+#[test]
+fn test_retrieve_model_doc_matches_client() {
+    let doc = docs::RETRIEVE_MODEL;
+
+    assert!(doc.starts_with("## RetrieveModel"));
+    assert!(doc.contains("`GET /models/{model}`"));
+    assert!(doc.contains("pub fn new(model: impl Into<String>) -> Self;"));
+    assert!(doc.contains("let request = RetrieveModelRequest::new(\"model_value\");"));
+    assert!(doc.contains("let response: Model = client.request(request).await?;"));
+    assert!(doc.contains("`OPENAI_API_KEY`"));
+}
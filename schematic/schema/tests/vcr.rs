@@ -0,0 +1,221 @@
+//! Integration tests for recording and replaying responses with a cassette.
+//!
+//! Responses are recorded from a wiremock server with the GitHub client and
+//! replayed by a client whose base URL is unreachable and whose credential
+//! variable is unset, proving replay never touches the network.
+//!
+//! Run with `cargo test -p schematic-schema --features vcr`.
+
+#![cfg(feature = "vcr")]
+
+use std::path::Path;
+use std::time::Duration;
+
+use schematic_define::UpdateStrategy;
+use schematic_schema::github::{GetRepoRequest, GitHub};
+use schematic_schema::shared::SchematicError;
+use schematic_schema::shared::vcr::{Cassette, RecordedRequest, RecordedResponse, VcrMode};
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TOKEN: &str = "vcr-test-token";
+
+/// Helper to set up environment for tests.
+fn setup_test_env() {
+    // SAFETY: Tests run in isolation, setting env vars is safe here
+    unsafe {
+        std::env::set_var("GITHUB_TOKEN", TOKEN);
+    }
+}
+
+/// A client that records into `dir` through the mock server.
+fn recording_client(server: &MockServer, dir: &Path, mode: VcrMode) -> GitHub {
+    GitHub::with_base_url(server.uri()).with_cassette(Cassette::new(dir, mode))
+}
+
+/// A client that can only succeed by replaying from `dir`.
+fn offline_client(dir: &Path) -> GitHub {
+    GitHub::new()
+        .variant(
+            "http://127.0.0.1:9",
+            vec!["SCHEMATIC_VCR_TEST_UNSET_TOKEN".to_string()],
+            UpdateStrategy::NoChange,
+        )
+        .with_cassette(Cassette::new(dir, VcrMode::Replay))
+}
+
+fn recordings(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+/// Test that a recorded response replays without network or credentials.
+#[tokio::test]
+async fn test_record_then_replay() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+    let cassette = TempDir::new().unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/repos/clap-rs/clap"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Set-Cookie", "session=secret")
+                .set_body_json(serde_json::json!({"full_name": "clap-rs/clap"})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let recorded: serde_json::Value =
+        recording_client(&mock_server, cassette.path(), VcrMode::Record)
+            .request(GetRepoRequest::new("clap-rs", "clap"))
+            .await
+            .unwrap();
+    assert_eq!(recorded["full_name"], "clap-rs/clap");
+
+    let files = recordings(cassette.path());
+    assert_eq!(files.len(), 1);
+    assert!(files[0].starts_with("get-") && files[0].ends_with(".json"));
+
+    let content = std::fs::read_to_string(cassette.path().join(&files[0])).unwrap();
+    assert!(content.contains("/repos/clap-rs/clap"));
+    assert!(!content.contains(TOKEN), "credentials must not be recorded");
+    assert!(!content.to_lowercase().contains("set-cookie"));
+
+    let replayed: serde_json::Value = offline_client(cassette.path())
+        .request(GetRepoRequest::new("clap-rs", "clap"))
+        .await
+        .unwrap();
+    assert_eq!(replayed, recorded);
+}
+
+/// Test that recorded error responses replay as the same error.
+#[tokio::test]
+async fn test_error_responses_replay_as_errors() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+    let cassette = TempDir::new().unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/repos/clap-rs/clap"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", "30")
+                .set_body_string("slow down"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let live: Result<serde_json::Value, _> =
+        recording_client(&mock_server, cassette.path(), VcrMode::Record)
+            .request(GetRepoRequest::new("clap-rs", "clap"))
+            .await;
+    assert!(matches!(live, Err(SchematicError::RateLimited { .. })));
+
+    let replayed: Result<serde_json::Value, _> = offline_client(cassette.path())
+        .request(GetRepoRequest::new("clap-rs", "clap"))
+        .await;
+    match replayed {
+        Err(SchematicError::RateLimited {
+            status,
+            retry_after,
+            body,
+        }) => {
+            assert_eq!(status, 429);
+            assert_eq!(retry_after, Some(Duration::from_secs(30)));
+            assert_eq!(body, "slow down");
+        }
+        other => panic!("Expected RateLimited, got {:?}", other),
+    }
+}
+
+/// Test that replay mode fails requests that were never recorded.
+#[tokio::test]
+async fn test_replay_without_recording_fails() {
+    let cassette = TempDir::new().unwrap();
+
+    let result: Result<serde_json::Value, _> = offline_client(cassette.path())
+        .request(GetRepoRequest::new("clap-rs", "clap"))
+        .await;
+
+    match result {
+        Err(SchematicError::Cassette(message)) => {
+            assert!(message.contains("GET /repos/clap-rs/clap"));
+        }
+        other => panic!("Expected Cassette error, got {:?}", other),
+    }
+}
+
+/// Test that auto mode records new requests and replays known ones.
+#[tokio::test]
+async fn test_auto_mode_records_once() {
+    setup_test_env();
+    let mock_server = MockServer::start().await;
+    let cassette = TempDir::new().unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/repos/tokio-rs/tokio"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": 1})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = recording_client(&mock_server, cassette.path(), VcrMode::Auto);
+    for _ in 0..3 {
+        let repo: serde_json::Value = client
+            .request(GetRepoRequest::new("tokio-rs", "tokio"))
+            .await
+            .unwrap();
+        assert_eq!(repo["id"], 1);
+    }
+    assert_eq!(recordings(cassette.path()).len(), 1);
+}
+
+/// Test that requests are keyed by method, path and body.
+#[test]
+fn test_request_keys() {
+    let get = RecordedRequest::new("GET", "/repos/a/b", None);
+    let key = get.key();
+    assert_eq!(key.len(), "get-".len() + 16);
+    assert_eq!(key, RecordedRequest::new("GET", "/repos/a/b", None).key());
+
+    assert_ne!(
+        key,
+        RecordedRequest::new("DELETE", "/repos/a/b", None).key()
+    );
+    assert_ne!(key, RecordedRequest::new("GET", "/repos/a/c", None).key());
+    assert_ne!(
+        RecordedRequest::new("POST", "/items", Some(r#"{"a":1}"#)).key(),
+        RecordedRequest::new("POST", "/items", Some(r#"{"a":2}"#)).key()
+    );
+}
+
+/// Test that binary bodies are stored as base64 and restored intact.
+#[test]
+fn test_binary_bodies_round_trip() {
+    let audio = [0xff, 0xfb, 0x00, 0x90];
+    let response = RecordedResponse::new(200, Vec::new(), &audio);
+    assert_eq!(response.body, None);
+    assert!(response.body_base64.is_some());
+    assert_eq!(response.body_bytes().unwrap(), audio);
+
+    let text = RecordedResponse::new(200, Vec::new(), b"hello");
+    assert_eq!(text.body.as_deref(), Some("hello"));
+    assert_eq!(text.body_bytes().unwrap(), b"hello");
+}
+
+/// Test parsing of the `SCHEMATIC_VCR_MODE` values.
+#[test]
+fn test_mode_parsing() {
+    assert_eq!(VcrMode::parse("record"), Some(VcrMode::Record));
+    assert_eq!(VcrMode::parse(" Replay "), Some(VcrMode::Replay));
+    assert_eq!(VcrMode::parse("AUTO"), Some(VcrMode::Auto));
+    assert_eq!(VcrMode::parse("rewind"), None);
+    assert_eq!(VcrMode::default(), VcrMode::Replay);
+}
@@ -1,10 +1,12 @@
 use clap::{CommandFactory, Parser, Subcommand};
 use clap_complete::CompleteEnv;
 use clap_complete::Shell;
+use sniff_lib::baseline::{self, Severity, SeverityRules};
 use sniff_lib::package::enrich_dependencies;
 use sniff_lib::programs::ProgramsInfo;
 use sniff_lib::services::{detect_services, ServiceState};
-use sniff_lib::{detect_with_config, SniffConfig, SniffResult};
+use sniff_lib::shell::detect_shell;
+use sniff_lib::{detect_with_config, RedactionLevel, SniffConfig, SniffResult};
 use std::path::PathBuf;
 
 mod output;
@@ -26,6 +28,10 @@ struct Cli {
     #[arg(long, global = true)]
     deep: bool,
 
+    /// Redact hostnames, addresses, git authors, and home paths (none, hash, mask)
+    #[arg(long, value_name = "LEVEL", default_value_t = RedactionLevel::None, global = true)]
+    redact: RedactionLevel,
+
     /// Increase output verbosity
     #[arg(short, long, action = clap::ArgAction::Count, global = true)]
     verbose: u8,
@@ -177,6 +183,47 @@ pub enum Commands {
         #[arg(long, value_enum, default_value = "running")]
         state: ServiceStateArg,
     },
+
+    // === Shell section ===
+    /// Show shell configuration (shell, frameworks, env vars, PATH, aliases)
+    Shell,
+
+    // === Baseline ===
+    /// Save an approved snapshot of this machine or check it for drift
+    Baseline {
+        #[command(subcommand)]
+        action: BaselineAction,
+    },
+}
+
+/// Actions for the baseline subcommand.
+#[derive(Subcommand, Debug, Clone)]
+pub enum BaselineAction {
+    /// Capture programs, OS release, and network setup as the approved baseline
+    Save {
+        /// Baseline file (default: ~/.config/sniff/baseline.json)
+        #[arg(long, value_name = "FILE")]
+        file: Option<PathBuf>,
+    },
+
+    /// Compare this machine against the saved baseline and report drift
+    Check {
+        /// Baseline file (default: ~/.config/sniff/baseline.json)
+        #[arg(long, value_name = "FILE")]
+        file: Option<PathBuf>,
+
+        /// Exit with status 1 when drift at or above this severity is found (info, warning, critical)
+        #[arg(long, value_name = "SEVERITY", default_value_t = Severity::Critical)]
+        fail_on: Severity,
+
+        /// Treat any change to this program as critical (repeatable)
+        #[arg(long = "critical", value_name = "PROGRAM")]
+        critical: Vec<String>,
+
+        /// Never report changes to this program (repeatable)
+        #[arg(long = "ignore", value_name = "PROGRAM")]
+        ignore: Vec<String>,
+    },
 }
 
 impl Commands {
@@ -212,6 +259,12 @@ impl Commands {
 
             // Services section
             Commands::Services { .. } => OutputFilter::Services,
+
+            // Shell section
+            Commands::Shell => OutputFilter::Shell,
+
+            // Baseline is handled before any section filtering
+            Commands::Baseline { .. } => OutputFilter::All,
         }
     }
 
@@ -311,6 +364,13 @@ SUBCOMMANDS:
     sniff services              Show running services (default)
     sniff services --state all  Show all services
 
+  Shell:
+    sniff shell                 Show shell, frameworks, env vars, PATH, and aliases
+
+  Baseline (drift detection):
+    sniff baseline save         Save programs, OS, and network as the baseline
+    sniff baseline check        Report drift since the baseline
+
 OUTPUT MODES:
   - No subcommand: JSON output (all data)
   - With subcommand: Text output by default, use --json for JSON
@@ -357,6 +417,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
+    // Handle baseline mode separately (doesn't use SniffResult)
+    if let Some(Commands::Baseline { action }) = &cli.command {
+        return run_baseline(action, cli.json);
+    }
+
     // Determine output filter based on subcommand
     let output_filter = cli
         .command
@@ -395,6 +460,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             return Ok(());
         }
+
+        // Handle shell mode separately (opt-in, not part of SniffResult)
+        if matches!(cmd, Commands::Shell) {
+            let mut shell = detect_shell();
+            shell.redact(cli.redact);
+            if cli.json {
+                output::print_shell_json(&shell)?;
+            } else {
+                output::print_shell_text(&shell, cli.verbose);
+            }
+            return Ok(());
+        }
     }
 
     // Canonicalize path if provided
@@ -414,6 +491,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         config = config.deep(true);
     }
 
+    config = config.redact(cli.redact);
+
     // Apply skip logic based on filter mode
     match output_filter {
         // Top-level section filters: skip all OTHER sections
@@ -431,7 +510,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         // Hardware detail filters: show only hardware section
         OutputFilter::Cpu | OutputFilter::Gpu | OutputFilter::Memory | OutputFilter::Storage => {
-            config = config.skip_os().skip_network().skip_filesystem();
+            config = config
+                .skip_os()
+                .skip_network()
+                .skip_filesystem()
+                .skip_power();
         }
         // Filesystem detail filters: show only filesystem section
         OutputFilter::Git | OutputFilter::Repo | OutputFilter::Language => {
@@ -441,7 +524,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         OutputFilter::All => {
             // No filtering - detect everything
         }
-        // Programs and Services filters are handled earlier in main, should not reach here
+        // Programs, Services and Shell filters are handled earlier in main, should not reach here
         OutputFilter::Programs
         | OutputFilter::Editors
         | OutputFilter::Utilities
@@ -450,8 +533,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         | OutputFilter::TtsClients
         | OutputFilter::TerminalApps
         | OutputFilter::HeadlessAudio
-        | OutputFilter::Services => {
-            unreachable!("Programs and Services mode should be handled before this point")
+        | OutputFilter::Services
+        | OutputFilter::Shell => {
+            unreachable!("Programs, Services and Shell mode should be handled before this point")
         }
     }
 
@@ -476,6 +560,69 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Runs `sniff baseline save` or `sniff baseline check`.
+///
+/// A check exits with status 1 when drift at or above `--fail-on` is found.
+fn run_baseline(action: &BaselineAction, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    match action {
+        BaselineAction::Save { file } => {
+            let path = file.clone().unwrap_or_else(baseline::default_path);
+            let snapshot = baseline::save(&path)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&snapshot)?);
+            } else {
+                println!(
+                    "Saved baseline of {} programs to {}",
+                    snapshot.programs.len(),
+                    path.display()
+                );
+            }
+        }
+        BaselineAction::Check {
+            file,
+            fail_on,
+            critical,
+            ignore,
+        } => {
+            let path = file.clone().unwrap_or_else(baseline::default_path);
+            let rules = critical
+                .iter()
+                .fold(SeverityRules::new(), |rules, program| {
+                    rules.critical_program(program.as_str())
+                });
+            let rules = ignore.iter().fold(rules, |rules, program| {
+                rules.ignore_program(program.as_str())
+            });
+            let report = baseline::check(&path, &rules)?;
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else if report.is_clean() {
+                println!(
+                    "No drift since baseline of {}",
+                    report.baseline_at.format("%Y-%m-%d %H:%M UTC")
+                );
+            } else {
+                println!(
+                    "Drift since baseline of {}:",
+                    report.baseline_at.format("%Y-%m-%d %H:%M UTC")
+                );
+                for drift in &report.drifts {
+                    println!("  {}", drift);
+                }
+            }
+
+            if report
+                .max_severity()
+                .is_some_and(|severity| severity >= *fail_on)
+            {
+                std::process::exit(1);
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Prints shell completions setup instructions.
 ///
 /// With dynamic completions, the shell sources a command that calls back to the CLI.
@@ -651,6 +798,64 @@ mod tests {
             let cli = parse_args(&["services"]).unwrap();
             assert!(matches!(cli.command, Some(Commands::Services { .. })));
         }
+
+        #[test]
+        fn shell_subcommand_parses() {
+            let cli = parse_args(&["shell"]).unwrap();
+            assert!(matches!(cli.command, Some(Commands::Shell)));
+        }
+
+        #[test]
+        fn baseline_save_subcommand_parses() {
+            let cli = parse_args(&["baseline", "save", "--file", "/tmp/baseline.json"]).unwrap();
+            match cli.command {
+                Some(Commands::Baseline {
+                    action: BaselineAction::Save { file },
+                }) => assert_eq!(file, Some(PathBuf::from("/tmp/baseline.json"))),
+                other => panic!("unexpected command: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn baseline_check_subcommand_parses() {
+            let cli = parse_args(&[
+                "baseline",
+                "check",
+                "--fail-on",
+                "warning",
+                "--critical",
+                "rustc",
+                "--critical",
+                "cargo",
+            ])
+            .unwrap();
+            match cli.command {
+                Some(Commands::Baseline {
+                    action:
+                        BaselineAction::Check {
+                            fail_on, critical, ..
+                        },
+                }) => {
+                    assert_eq!(fail_on, Severity::Warning);
+                    assert_eq!(critical, vec!["rustc", "cargo"]);
+                }
+                other => panic!("unexpected command: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn baseline_check_defaults_to_critical() {
+            let cli = parse_args(&["baseline", "check"]).unwrap();
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Baseline {
+                    action: BaselineAction::Check {
+                        fail_on: Severity::Critical,
+                        ..
+                    }
+                })
+            ));
+        }
     }
 
     mod to_output_filter {
@@ -756,6 +961,11 @@ mod tests {
             };
             assert_eq!(cmd.to_output_filter(), OutputFilter::Services);
         }
+
+        #[test]
+        fn shell_maps_to_shell_filter() {
+            assert_eq!(Commands::Shell.to_output_filter(), OutputFilter::Shell);
+        }
     }
 
     mod services_state_default {
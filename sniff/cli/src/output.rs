@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use biscuit_terminal::utils::symbols;
 use darkmatter_lib::markdown::Markdown;
 use darkmatter_lib::markdown::output::terminal::{TerminalOptions, for_terminal};
 use darkmatter_lib::render::link::Link;
@@ -8,6 +9,7 @@ use sniff_lib::filesystem::git::BehindStatus;
 use sniff_lib::hardware::NtpStatus;
 use sniff_lib::programs::ProgramsInfo;
 use sniff_lib::services::{Service, ServiceState, ServicesInfo};
+use sniff_lib::shell::ShellInfo;
 
 /// Filter mode for output - determines which subsection to display.
 ///
@@ -59,6 +61,8 @@ pub enum OutputFilter {
     HeadlessAudio,
     /// Show only system services (init system and service list)
     Services,
+    /// Show only the shell configuration inventory
+    Shell,
 }
 
 /// Format bytes into human-readable units (KB, MB, GB, TB)
@@ -134,6 +138,17 @@ fn format_simd_caps(simd: &sniff_lib::hardware::SimdCapabilities) -> Option<Stri
     }
 }
 
+/// Format a display as "name (WIDTHxHEIGHT @ RATE Hz)".
+fn format_display(display: &sniff_lib::hardware::DisplayInfo) -> String {
+    let mode = match (display.resolution(), display.refresh_rate_hz) {
+        (Some((w, h)), Some(hz)) => format!(" ({}x{} @ {:.0} Hz)", w, h, hz),
+        (Some((w, h)), None) => format!(" ({}x{})", w, h),
+        (None, Some(hz)) => format!(" ({:.0} Hz)", hz),
+        (None, None) => String::new(),
+    };
+    format!("{}{}", display.name, mode)
+}
+
 /// Format GPU capabilities into a comma-separated string.
 ///
 /// Returns `None` if no capabilities are detected.
@@ -285,7 +300,7 @@ pub fn print_text(result: &SniffResult, verbose: u8, filter: OutputFilter) {
                 print_language_section(langs, verbose);
             }
         }
-        // Programs and Services filters are handled separately in main.rs
+        // Programs, Services and Shell filters are handled separately in main.rs
         OutputFilter::Programs
         | OutputFilter::Editors
         | OutputFilter::Utilities
@@ -294,9 +309,10 @@ pub fn print_text(result: &SniffResult, verbose: u8, filter: OutputFilter) {
         | OutputFilter::TtsClients
         | OutputFilter::TerminalApps
         | OutputFilter::HeadlessAudio
-        | OutputFilter::Services => {
+        | OutputFilter::Services
+        | OutputFilter::Shell => {
             // These are handled separately, should not reach here
-            unreachable!("Programs and Services filters should be handled separately")
+            unreachable!("Programs, Services and Shell filters should be handled separately")
         }
     }
 }
@@ -316,6 +332,18 @@ fn print_os_section(os: &sniff_lib::OsInfo, verbose: u8) {
     println!("Kernel: {}", os.kernel);
     println!("Hostname: {}", os.hostname);
     println!("Uptime: {}", format_uptime(os.uptime_seconds));
+    if let Some(ref desktop) = os.desktop {
+        let mut parts = vec![desktop.session_type.to_string()];
+        if let Some(ref de) = desktop.desktop_environment {
+            parts.push(de.clone());
+        }
+        if verbose > 0
+            && let Some(ref wm) = desktop.window_manager
+        {
+            parts.push(format!("WM: {}", wm));
+        }
+        println!("Desktop: {}", parts.join(", "));
+    }
     println!();
 
     // Print package managers section if detected
@@ -499,6 +527,37 @@ fn print_hardware_section(
         println!();
     }
 
+    // Print connected displays if any
+    if !hardware.displays.is_empty() {
+        println!("Displays:");
+        for display in &hardware.displays {
+            println!("  {}", format_display(display));
+            if verbose > 0 {
+                if let Some(scale) = display.scale_factor {
+                    println!("    Scale: {}x", scale);
+                }
+                if let Some(hdr) = display.hdr {
+                    println!("    HDR: {}", if hdr { "yes" } else { "no" });
+                }
+                if display.is_primary {
+                    println!("    Primary: yes");
+                }
+                if display.is_builtin {
+                    println!("    Built-in: yes");
+                }
+            }
+        }
+        println!();
+    }
+
+    // Print power state if a battery or thermal data is available
+    if let Some(ref power) = hardware.power
+        && (!power.batteries.is_empty() || power.thermal_pressure.is_some())
+    {
+        print_power(power, verbose);
+        println!();
+    }
+
     println!("Storage:");
     for disk in &hardware.storage {
         let mount_str = relative_path(&disk.mount_point, repo_root);
@@ -568,6 +627,40 @@ fn print_network_section(network: &sniff_lib::NetworkInfo) {
     println!();
 }
 
+/// Print the power block (AC status, batteries, thermal pressure).
+fn print_power(power: &sniff_lib::hardware::PowerInfo, verbose: u8) {
+    let source = match power.on_ac_power {
+        Some(true) => "AC",
+        Some(false) => "battery",
+        None => "unknown",
+    };
+    println!("Power: {}", source);
+    for battery in &power.batteries {
+        let charge = battery
+            .charge_percent
+            .map(|p| format!("{:.0}%", p))
+            .unwrap_or_else(|| "?".to_string());
+        println!("  {}: {} ({})", battery.name, charge, battery.state);
+        if verbose > 0 {
+            if let Some(health) = battery.health_percent {
+                println!("    Health: {:.0}%", health);
+            }
+            if let Some(cycles) = battery.cycle_count {
+                println!("    Cycles: {}", cycles);
+            }
+        }
+    }
+    if let Some(pressure) = power.thermal_pressure {
+        match power.max_temperature_celsius {
+            Some(temp) if verbose > 0 => println!("  Thermal: {} ({:.0}°C)", pressure, temp),
+            _ => println!("  Thermal: {}", pressure),
+        }
+    }
+    if power.should_defer_heavy_work() {
+        println!("  Defer heavy work: yes");
+    }
+}
+
 // ============================================================================
 // Subsection print functions (for --cpu, --gpu, --memory, --storage filters)
 // ============================================================================
@@ -1173,7 +1266,7 @@ fn apply_filter_to_json(result: &SniffResult, filter: OutputFilter) -> serde_jso
                 json!({})
             }
         }
-        // Programs and Services filters are handled separately
+        // Programs, Services and Shell filters are handled separately
         OutputFilter::Programs
         | OutputFilter::Editors
         | OutputFilter::Utilities
@@ -1182,8 +1275,9 @@ fn apply_filter_to_json(result: &SniffResult, filter: OutputFilter) -> serde_jso
         | OutputFilter::TtsClients
         | OutputFilter::TerminalApps
         | OutputFilter::HeadlessAudio
-        | OutputFilter::Services => {
-            unreachable!("Programs and Services filters should be handled separately")
+        | OutputFilter::Services
+        | OutputFilter::Shell => {
+            unreachable!("Programs, Services and Shell filters should be handled separately")
         }
     }
 }
@@ -1418,7 +1512,11 @@ pub fn print_programs_markdown(programs: &ProgramsInfo, verbose: u8, filter: Out
     for entry in entries {
         let mut cells = vec![
             name_link(&entry.name, &entry.website),
-            if entry.installed { "✅".to_string() } else { "❌".to_string() },
+            if entry.installed {
+                symbols::INSTALLED.auto().to_string()
+            } else {
+                symbols::MISSING.auto().to_string()
+            },
         ];
 
         if verbose > 0 {
@@ -1807,6 +1905,72 @@ pub fn print_services_json(info: &ServicesInfo, state_filter: ServiceState) -> s
     Ok(())
 }
 
+// ==========================================================================
+// Shell output
+// ==========================================================================
+
+/// Print the shell configuration inventory as text.
+pub fn print_shell_text(info: &ShellInfo, verbose: u8) {
+    println!("=== Shell ===");
+    match info.shell_path {
+        Some(ref path) => println!("Shell: {} ({})", info.shell, path.display()),
+        None => println!("Shell: {}", info.shell),
+    }
+
+    if info.frameworks.is_empty() {
+        println!("Frameworks: (none)");
+    } else {
+        let names: Vec<String> = info.frameworks.iter().map(|f| f.to_string()).collect();
+        println!("Frameworks: {}", names.join(", "));
+    }
+    println!("Aliases: {}", info.alias_count);
+
+    println!();
+    println!("Config Files:");
+    if info.config_files.is_empty() {
+        println!("  (none)");
+    }
+    for file in &info.config_files {
+        println!("  {}", file.display());
+    }
+
+    if !info.env.is_empty() {
+        println!();
+        println!("Environment:");
+        for (key, value) in &info.env {
+            println!("  {}={}", key, value);
+        }
+    }
+
+    let missing = info.missing_path_entries().count();
+    let duplicates = info.duplicate_path_entries().count();
+    println!();
+    println!(
+        "PATH: {} entries ({} missing, {} duplicate)",
+        info.path.len(),
+        missing,
+        duplicates
+    );
+    // Show every entry at verbose level 1+, otherwise only the problems
+    for entry in &info.path {
+        let note = match (entry.exists, entry.duplicate) {
+            (false, _) => " [missing]",
+            (true, true) => " [duplicate]",
+            (true, false) if verbose > 0 => "",
+            (true, false) => continue,
+        };
+        println!("  {}{}", entry.path.display(), note);
+    }
+
+    println!();
+}
+
+/// Print the shell configuration inventory as JSON.
+pub fn print_shell_json(info: &ShellInfo) -> serde_json::Result<()> {
+    println!("{}", serde_json::to_string_pretty(info)?);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -883,6 +883,41 @@ fn test_services_state_stopped() {
         .stdout(predicate::str::contains("Stopped Services:"));
 }
 
+// ============================================================================
+// Shell Subcommand Tests
+// ============================================================================
+
+#[test]
+fn test_shell_subcommand_text_output() {
+    cargo_bin_cmd!("sniff")
+        .arg("shell")
+        .env("SHELL", "/bin/zsh")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("=== Shell ==="))
+        .stdout(predicate::str::contains("Shell: zsh"))
+        .stdout(predicate::str::contains("PATH:"));
+}
+
+#[test]
+fn test_shell_subcommand_json_output() {
+    cargo_bin_cmd!("sniff")
+        .args(["shell", "--json"])
+        .env("SHELL", "/usr/bin/fish")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"shell\": \"fish\""))
+        .stdout(predicate::str::contains("alias_count"));
+}
+
+#[test]
+fn test_shell_not_in_default_output() {
+    cargo_bin_cmd!("sniff")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("alias_count").not());
+}
+
 // ============================================================================
 // Deep Flag Tests
 // ============================================================================
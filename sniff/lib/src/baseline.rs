@@ -0,0 +1,1021 @@
+//! Baseline snapshots and environment drift detection.
+//!
+//! A [`Snapshot`] records the parts of a machine's setup that builds depend
+//! on: the OS release and kernel, every detected program with its path and
+//! version, and the IPv4 configuration of each network interface. Saving an
+//! approved snapshot as a baseline and checking the machine against it later
+//! reports [`Drift`] (new, removed, moved or upgraded programs, OS updates and
+//! network changes) graded by [`SeverityRules`], so setup drift is caught
+//! before it breaks a build.
+//!
+//! Loopback interfaces and IPv6 addresses are not recorded: temporary IPv6
+//! addresses rotate on their own and would be reported as drift on every
+//! check.
+//!
+//! ## Default Severities
+//!
+//! | Drift | Severity |
+//! |-------|----------|
+//! | Program removed | critical |
+//! | Major version change | critical |
+//! | Minor or unparseable version change | warning |
+//! | Patch version change | info |
+//! | Program moved to another path | warning |
+//! | Program added | info |
+//! | OS release changed | warning |
+//! | Kernel changed | info |
+//! | Primary interface changed | warning |
+//! | Interface added, removed, up/down or readdressed | info |
+//!
+//! Programs listed with [`SeverityRules::critical_program`] are critical on
+//! any change; programs listed with [`SeverityRules::ignore_program`] are
+//! never reported.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use sniff_lib::baseline::{self, Severity, SeverityRules};
+//!
+//! let path = baseline::default_path();
+//! baseline::save(&path).unwrap();
+//!
+//! // ...later, before a build
+//! let rules = SeverityRules::default().critical_program("rustc");
+//! let report = baseline::check(&path, &rules).unwrap();
+//! for drift in report.at_least(Severity::Warning) {
+//!     println!("{}", drift);
+//! }
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::net::Ipv4Addr;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+use crate::error::{Result, SniffError};
+use crate::network::{NetworkInfo, detect_network};
+use crate::os::{OsInfo, detect_os};
+use crate::programs::{ProgramDetector, ProgramMetadata, ProgramsInfo};
+
+/// OS release facts recorded in a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OsFingerprint {
+    /// Operating system name (e.g., "macOS", "Ubuntu")
+    pub name: String,
+    /// Operating system version
+    pub version: String,
+    /// Kernel version
+    pub kernel: String,
+}
+
+impl OsFingerprint {
+    fn release(&self) -> String {
+        format!("{} {}", self.name, self.version)
+    }
+}
+
+impl From<&OsInfo> for OsFingerprint {
+    fn from(os: &OsInfo) -> Self {
+        Self {
+            name: os.name.clone(),
+            version: os.version.clone(),
+            kernel: os.kernel.clone(),
+        }
+    }
+}
+
+/// A detected program recorded in a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramRecord {
+    /// Path to the program's binary
+    pub path: PathBuf,
+    /// Version reported by the program, if it could be determined
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// A non-loopback network interface recorded in a snapshot.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterfaceRecord {
+    /// Whether the interface was up
+    pub is_up: bool,
+    /// IPv4 addresses assigned to the interface
+    #[serde(default)]
+    pub ipv4: Vec<Ipv4Addr>,
+}
+
+/// Network configuration recorded in a snapshot.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkFingerprint {
+    /// Name of the primary interface
+    pub primary_interface: Option<String>,
+    /// Non-loopback interfaces by name
+    pub interfaces: BTreeMap<String, InterfaceRecord>,
+}
+
+impl From<&NetworkInfo> for NetworkFingerprint {
+    fn from(network: &NetworkInfo) -> Self {
+        let interfaces = network
+            .interfaces
+            .iter()
+            .filter(|iface| !iface.flags.is_loopback)
+            .map(|iface| {
+                let mut ipv4 = iface.ipv4_addresses.clone();
+                ipv4.sort();
+                let record = InterfaceRecord {
+                    is_up: iface.flags.is_up,
+                    ipv4,
+                };
+                (iface.name.clone(), record)
+            })
+            .collect();
+
+        Self {
+            primary_interface: network.primary_interface.clone(),
+            interfaces,
+        }
+    }
+}
+
+/// The parts of a machine's setup compared between baseline and check.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// When the snapshot was captured
+    pub captured_at: DateTime<Utc>,
+    /// OS release, if detected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub os: Option<OsFingerprint>,
+    /// Detected programs keyed by binary name
+    #[serde(default)]
+    pub programs: BTreeMap<String, ProgramRecord>,
+    /// Network configuration, if it could be enumerated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkFingerprint>,
+}
+
+impl Snapshot {
+    /// Captures the current machine.
+    ///
+    /// Runs the version command of every detected program, so this can take
+    /// a few seconds.
+    pub fn capture() -> Result<Self> {
+        let os = detect_os()?;
+        let network = detect_network()?;
+        let programs = ProgramsInfo::detect();
+        Ok(Self::from_parts(Some(&os), &programs, Some(&network)))
+    }
+
+    /// Builds a snapshot from previously detected information.
+    ///
+    /// Program versions are queried here, in parallel. Network information
+    /// is left out when interface enumeration was denied.
+    pub fn from_parts(
+        os: Option<&OsInfo>,
+        programs: &ProgramsInfo,
+        network: Option<&NetworkInfo>,
+    ) -> Self {
+        let mut records = BTreeMap::new();
+        collect_programs(&programs.editors, &mut records);
+        collect_programs(&programs.utilities, &mut records);
+        collect_programs(&programs.language_package_managers, &mut records);
+        collect_programs(&programs.os_package_managers, &mut records);
+        collect_programs(&programs.tts_clients, &mut records);
+        collect_programs(&programs.terminal_apps, &mut records);
+        collect_programs(&programs.headless_audio, &mut records);
+        collect_programs(&programs.ai_clients, &mut records);
+
+        Self {
+            captured_at: Utc::now(),
+            os: os.map(OsFingerprint::from),
+            programs: records,
+            network: network
+                .filter(|network| !network.permission_denied)
+                .map(NetworkFingerprint::from),
+        }
+    }
+
+    /// Reads a snapshot saved with [`Snapshot::save`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SniffError::BaselineNotFound`] if `path` does not exist and
+    /// [`SniffError::InvalidBaseline`] if it is not a snapshot.
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                SniffError::BaselineNotFound(path.to_path_buf())
+            } else {
+                SniffError::Io(e)
+            }
+        })?;
+        serde_json::from_str(&json).map_err(|e| SniffError::InvalidBaseline {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Writes the snapshot to `path` as JSON, creating parent directories.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| SniffError::InvalidBaseline {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Compares `current` against this snapshot as the baseline.
+    ///
+    /// Drift is ordered from most to least severe. OS and network drift are
+    /// only reported when both snapshots recorded them.
+    pub fn diff(&self, current: &Snapshot, rules: &SeverityRules) -> DriftReport {
+        let mut kinds = Vec::new();
+
+        if let (Some(before), Some(after)) = (&self.os, &current.os) {
+            if before.name != after.name || before.version != after.version {
+                kinds.push(DriftKind::OsReleaseChanged {
+                    from: before.release(),
+                    to: after.release(),
+                });
+            }
+            if before.kernel != after.kernel {
+                kinds.push(DriftKind::KernelChanged {
+                    from: before.kernel.clone(),
+                    to: after.kernel.clone(),
+                });
+            }
+        }
+
+        diff_programs(&self.programs, &current.programs, &mut kinds);
+
+        if let (Some(before), Some(after)) = (&self.network, &current.network) {
+            diff_network(before, after, &mut kinds);
+        }
+
+        let mut drifts: Vec<Drift> = kinds
+            .into_iter()
+            .filter_map(|kind| {
+                rules
+                    .severity(&kind)
+                    .map(|severity| Drift { severity, kind })
+            })
+            .collect();
+        drifts.sort_by_key(|drift| std::cmp::Reverse(drift.severity));
+
+        DriftReport {
+            baseline_at: self.captured_at,
+            checked_at: current.captured_at,
+            drifts,
+        }
+    }
+}
+
+fn collect_programs<D>(detector: &D, records: &mut BTreeMap<String, ProgramRecord>)
+where
+    D: ProgramDetector + Sync,
+    D::Program: Send + Sync,
+{
+    let found: Vec<(String, ProgramRecord)> = detector
+        .installed()
+        .into_par_iter()
+        .filter_map(|program| {
+            let path = detector.path(program)?;
+            let version = detector.version(program).ok();
+            Some((
+                program.binary_name().to_string(),
+                ProgramRecord { path, version },
+            ))
+        })
+        .collect();
+    records.extend(found);
+}
+
+fn diff_programs(
+    baseline: &BTreeMap<String, ProgramRecord>,
+    current: &BTreeMap<String, ProgramRecord>,
+    kinds: &mut Vec<DriftKind>,
+) {
+    for (program, before) in baseline {
+        let Some(after) = current.get(program) else {
+            kinds.push(DriftKind::ProgramRemoved {
+                program: program.clone(),
+            });
+            continue;
+        };
+        if before.version != after.version {
+            kinds.push(DriftKind::VersionChanged {
+                program: program.clone(),
+                change: VersionChange::between(before.version.as_deref(), after.version.as_deref()),
+                from: before.version.clone(),
+                to: after.version.clone(),
+            });
+        }
+        if before.path != after.path {
+            kinds.push(DriftKind::ProgramMoved {
+                program: program.clone(),
+                from: before.path.clone(),
+                to: after.path.clone(),
+            });
+        }
+    }
+
+    for (program, after) in current {
+        if !baseline.contains_key(program) {
+            kinds.push(DriftKind::ProgramAdded {
+                program: program.clone(),
+                version: after.version.clone(),
+            });
+        }
+    }
+}
+
+fn diff_network(
+    baseline: &NetworkFingerprint,
+    current: &NetworkFingerprint,
+    kinds: &mut Vec<DriftKind>,
+) {
+    if baseline.primary_interface != current.primary_interface {
+        kinds.push(DriftKind::PrimaryInterfaceChanged {
+            from: baseline.primary_interface.clone(),
+            to: current.primary_interface.clone(),
+        });
+    }
+
+    for (interface, before) in &baseline.interfaces {
+        let Some(after) = current.interfaces.get(interface) else {
+            kinds.push(DriftKind::InterfaceRemoved {
+                interface: interface.clone(),
+            });
+            continue;
+        };
+        if before.is_up != after.is_up {
+            kinds.push(DriftKind::InterfaceStateChanged {
+                interface: interface.clone(),
+                is_up: after.is_up,
+            });
+        }
+        if before.ipv4 != after.ipv4 {
+            kinds.push(DriftKind::AddressesChanged {
+                interface: interface.clone(),
+                from: before.ipv4.clone(),
+                to: after.ipv4.clone(),
+            });
+        }
+    }
+
+    for interface in current.interfaces.keys() {
+        if !baseline.interfaces.contains_key(interface) {
+            kinds.push(DriftKind::InterfaceAdded {
+                interface: interface.clone(),
+            });
+        }
+    }
+}
+
+/// How serious a piece of drift is.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    Display,
+    EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum Severity {
+    /// Worth knowing about, unlikely to break anything
+    #[default]
+    Info,
+    /// May change build behavior
+    Warning,
+    /// Likely to break builds
+    Critical,
+}
+
+/// Which version component changed between two program versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionChange {
+    /// The first numeric component changed
+    Major,
+    /// The second numeric component changed
+    Minor,
+    /// A later component or a suffix changed
+    Patch,
+    /// One of the versions is missing or has no numeric components
+    Unknown,
+}
+
+impl VersionChange {
+    /// Classifies the change from `from` to `to`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use sniff_lib::baseline::VersionChange;
+    ///
+    /// assert_eq!(VersionChange::between(Some("1.79.0"), Some("1.80.1")), VersionChange::Minor);
+    /// assert_eq!(VersionChange::between(Some("git version 2.43.0"), Some("3.0.0")), VersionChange::Major);
+    /// assert_eq!(VersionChange::between(None, Some("1.0.0")), VersionChange::Unknown);
+    /// ```
+    pub fn between(from: Option<&str>, to: Option<&str>) -> Self {
+        let (Some(from), Some(to)) = (from.and_then(numeric_parts), to.and_then(numeric_parts))
+        else {
+            return Self::Unknown;
+        };
+        match from.iter().zip(&to).position(|(a, b)| a != b) {
+            Some(0) => Self::Major,
+            Some(1) => Self::Minor,
+            _ => Self::Patch,
+        }
+    }
+}
+
+/// Extracts the leading dotted numeric components of a version string
+/// (`"ripgrep 14.1.0-rc1"` gives `[14, 1, 0]`).
+fn numeric_parts(version: &str) -> Option<Vec<u64>> {
+    let start = version.find(|c: char| c.is_ascii_digit())?;
+    let mut parts = Vec::new();
+    for part in version[start..].split('.') {
+        let digits = part.len() - part.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            break;
+        }
+        parts.push(part[..digits].parse().ok()?);
+        if digits < part.len() {
+            break;
+        }
+    }
+    Some(parts)
+}
+
+/// A single difference between the baseline and the current machine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum DriftKind {
+    /// A program was found that the baseline did not have
+    ProgramAdded {
+        program: String,
+        version: Option<String>,
+    },
+    /// A program in the baseline is no longer found
+    ProgramRemoved { program: String },
+    /// A program now resolves to a different binary
+    ProgramMoved {
+        program: String,
+        from: PathBuf,
+        to: PathBuf,
+    },
+    /// A program reports a different version
+    VersionChanged {
+        program: String,
+        from: Option<String>,
+        to: Option<String>,
+        change: VersionChange,
+    },
+    /// The OS name or version changed
+    OsReleaseChanged { from: String, to: String },
+    /// The kernel version changed
+    KernelChanged { from: String, to: String },
+    /// The primary network interface changed
+    PrimaryInterfaceChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    /// A network interface appeared
+    InterfaceAdded { interface: String },
+    /// A network interface disappeared
+    InterfaceRemoved { interface: String },
+    /// A network interface went up or down
+    InterfaceStateChanged { interface: String, is_up: bool },
+    /// A network interface's IPv4 addresses changed
+    AddressesChanged {
+        interface: String,
+        from: Vec<Ipv4Addr>,
+        to: Vec<Ipv4Addr>,
+    },
+}
+
+impl DriftKind {
+    /// The program this drift is about, if any.
+    pub fn program(&self) -> Option<&str> {
+        match self {
+            DriftKind::ProgramAdded { program, .. }
+            | DriftKind::ProgramRemoved { program }
+            | DriftKind::ProgramMoved { program, .. }
+            | DriftKind::VersionChanged { program, .. } => Some(program),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DriftKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn or_none(value: &Option<String>) -> &str {
+            value.as_deref().unwrap_or("none")
+        }
+        fn addresses(addrs: &[Ipv4Addr]) -> String {
+            if addrs.is_empty() {
+                "none".to_string()
+            } else {
+                addrs
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        }
+
+        match self {
+            DriftKind::ProgramAdded {
+                program,
+                version: Some(version),
+            } => write!(f, "{} was installed ({})", program, version),
+            DriftKind::ProgramAdded { program, .. } => write!(f, "{} was installed", program),
+            DriftKind::ProgramRemoved { program } => {
+                write!(f, "{} is no longer installed", program)
+            }
+            DriftKind::ProgramMoved { program, from, to } => write!(
+                f,
+                "{} moved from {} to {}",
+                program,
+                from.display(),
+                to.display()
+            ),
+            DriftKind::VersionChanged {
+                program, from, to, ..
+            } => write!(
+                f,
+                "{} changed version from {} to {}",
+                program,
+                or_none(from),
+                or_none(to)
+            ),
+            DriftKind::OsReleaseChanged { from, to } => {
+                write!(f, "OS changed from {} to {}", from, to)
+            }
+            DriftKind::KernelChanged { from, to } => {
+                write!(f, "kernel changed from {} to {}", from, to)
+            }
+            DriftKind::PrimaryInterfaceChanged { from, to } => write!(
+                f,
+                "primary network interface changed from {} to {}",
+                or_none(from),
+                or_none(to)
+            ),
+            DriftKind::InterfaceAdded { interface } => {
+                write!(f, "network interface {} appeared", interface)
+            }
+            DriftKind::InterfaceRemoved { interface } => {
+                write!(f, "network interface {} disappeared", interface)
+            }
+            DriftKind::InterfaceStateChanged { interface, is_up } => write!(
+                f,
+                "network interface {} is now {}",
+                interface,
+                if *is_up { "up" } else { "down" }
+            ),
+            DriftKind::AddressesChanged {
+                interface,
+                from,
+                to,
+            } => write!(
+                f,
+                "{} IPv4 addresses changed from {} to {}",
+                interface,
+                addresses(from),
+                addresses(to)
+            ),
+        }
+    }
+}
+
+/// A difference together with its graded severity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Drift {
+    pub severity: Severity,
+    #[serde(flatten)]
+    pub kind: DriftKind,
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.severity, self.kind)
+    }
+}
+
+/// Severity assigned to each kind of drift.
+///
+/// The defaults are listed in the [module documentation](self). Rules can be
+/// stored alongside a baseline; missing fields take their default.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SeverityRules {
+    pub program_added: Severity,
+    pub program_removed: Severity,
+    pub program_moved: Severity,
+    pub major_version: Severity,
+    pub minor_version: Severity,
+    pub patch_version: Severity,
+    /// Version changes that could not be classified
+    pub unknown_version: Severity,
+    pub os_release: Severity,
+    pub kernel: Severity,
+    pub primary_interface: Severity,
+    /// Interfaces appearing, disappearing, going up or down, or readdressed
+    pub interfaces: Severity,
+    /// Programs whose every change is critical
+    pub critical_programs: BTreeSet<String>,
+    /// Programs whose changes are not reported
+    pub ignored_programs: BTreeSet<String>,
+}
+
+impl Default for SeverityRules {
+    fn default() -> Self {
+        Self {
+            program_added: Severity::Info,
+            program_removed: Severity::Critical,
+            program_moved: Severity::Warning,
+            major_version: Severity::Critical,
+            minor_version: Severity::Warning,
+            patch_version: Severity::Info,
+            unknown_version: Severity::Warning,
+            os_release: Severity::Warning,
+            kernel: Severity::Info,
+            primary_interface: Severity::Warning,
+            interfaces: Severity::Info,
+            critical_programs: BTreeSet::new(),
+            ignored_programs: BTreeSet::new(),
+        }
+    }
+}
+
+impl SeverityRules {
+    /// Create rules with the default severities.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treat every change to `program` (by binary name) as critical.
+    pub fn critical_program(mut self, program: impl Into<String>) -> Self {
+        self.critical_programs.insert(program.into());
+        self
+    }
+
+    /// Never report changes to `program` (by binary name).
+    pub fn ignore_program(mut self, program: impl Into<String>) -> Self {
+        self.ignored_programs.insert(program.into());
+        self
+    }
+
+    /// Grades `kind`, returning `None` when it is ignored.
+    pub fn severity(&self, kind: &DriftKind) -> Option<Severity> {
+        if let Some(program) = kind.program() {
+            if self.ignored_programs.contains(program) {
+                return None;
+            }
+            if self.critical_programs.contains(program) {
+                return Some(Severity::Critical);
+            }
+        }
+
+        Some(match kind {
+            DriftKind::ProgramAdded { .. } => self.program_added,
+            DriftKind::ProgramRemoved { .. } => self.program_removed,
+            DriftKind::ProgramMoved { .. } => self.program_moved,
+            DriftKind::VersionChanged { change, .. } => match change {
+                VersionChange::Major => self.major_version,
+                VersionChange::Minor => self.minor_version,
+                VersionChange::Patch => self.patch_version,
+                VersionChange::Unknown => self.unknown_version,
+            },
+            DriftKind::OsReleaseChanged { .. } => self.os_release,
+            DriftKind::KernelChanged { .. } => self.kernel,
+            DriftKind::PrimaryInterfaceChanged { .. } => self.primary_interface,
+            DriftKind::InterfaceAdded { .. }
+            | DriftKind::InterfaceRemoved { .. }
+            | DriftKind::InterfaceStateChanged { .. }
+            | DriftKind::AddressesChanged { .. } => self.interfaces,
+        })
+    }
+}
+
+/// Result of checking the current machine against a baseline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriftReport {
+    /// When the baseline was captured
+    pub baseline_at: DateTime<Utc>,
+    /// When the current machine was captured
+    pub checked_at: DateTime<Utc>,
+    /// Differences, most severe first
+    pub drifts: Vec<Drift>,
+}
+
+impl DriftReport {
+    /// Returns true if nothing changed since the baseline.
+    pub fn is_clean(&self) -> bool {
+        self.drifts.is_empty()
+    }
+
+    /// The highest severity reported, if any drift was found.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.drifts.iter().map(|drift| drift.severity).max()
+    }
+
+    /// Drift at or above `severity`.
+    pub fn at_least(&self, severity: Severity) -> impl Iterator<Item = &Drift> {
+        self.drifts
+            .iter()
+            .filter(move |drift| drift.severity >= severity)
+    }
+}
+
+/// Default baseline location.
+///
+/// `$XDG_CONFIG_HOME/sniff/baseline.json`, falling back to
+/// `~/.config/sniff/baseline.json`.
+pub fn default_path() -> PathBuf {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .filter(|home| !home.is_empty())
+                .map(|home| PathBuf::from(home).join(".config"))
+        })
+        .unwrap_or_else(|| PathBuf::from("."));
+    config_dir.join("sniff").join("baseline.json")
+}
+
+/// Captures the current machine and saves it as the baseline at `path`.
+pub fn save(path: &Path) -> Result<Snapshot> {
+    let snapshot = Snapshot::capture()?;
+    snapshot.save(path)?;
+    Ok(snapshot)
+}
+
+/// Captures the current machine and compares it with the baseline at `path`.
+pub fn check(path: &Path, rules: &SeverityRules) -> Result<DriftReport> {
+    let baseline = Snapshot::load(path)?;
+    let current = Snapshot::capture()?;
+    Ok(baseline.diff(&current, rules))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(path: &str, version: Option<&str>) -> ProgramRecord {
+        ProgramRecord {
+            path: PathBuf::from(path),
+            version: version.map(str::to_string),
+        }
+    }
+
+    fn snapshot() -> Snapshot {
+        let mut interfaces = BTreeMap::new();
+        interfaces.insert(
+            "en0".to_string(),
+            InterfaceRecord {
+                is_up: true,
+                ipv4: vec![Ipv4Addr::new(192, 168, 1, 42)],
+            },
+        );
+
+        Snapshot {
+            captured_at: Utc::now(),
+            os: Some(OsFingerprint {
+                name: "macOS".to_string(),
+                version: "14.5".to_string(),
+                kernel: "23.5.0".to_string(),
+            }),
+            programs: BTreeMap::from([
+                ("git".to_string(), program("/usr/bin/git", Some("2.43.0"))),
+                ("rg".to_string(), program("/usr/bin/rg", Some("14.1.0"))),
+                (
+                    "node".to_string(),
+                    program("/usr/bin/node", Some("20.11.1")),
+                ),
+            ]),
+            network: Some(NetworkFingerprint {
+                primary_interface: Some("en0".to_string()),
+                interfaces,
+            }),
+        }
+    }
+
+    #[test]
+    fn identical_snapshots_have_no_drift() {
+        let baseline = snapshot();
+        let report = baseline.diff(&baseline.clone(), &SeverityRules::default());
+        assert!(report.is_clean());
+        assert_eq!(report.max_severity(), None);
+    }
+
+    #[test]
+    fn program_changes_are_graded() {
+        let baseline = snapshot();
+        let mut current = snapshot();
+        current.programs.remove("git");
+        current
+            .programs
+            .insert("rg".to_string(), program("/usr/bin/rg", Some("14.1.1")));
+        current.programs.insert(
+            "node".to_string(),
+            program("/opt/homebrew/bin/node", Some("22.0.0")),
+        );
+        current
+            .programs
+            .insert("bat".to_string(), program("/usr/bin/bat", None));
+
+        let report = baseline.diff(&current, &SeverityRules::default());
+        let graded: Vec<(Severity, Option<&str>)> = report
+            .drifts
+            .iter()
+            .map(|drift| (drift.severity, drift.kind.program()))
+            .collect();
+
+        assert_eq!(
+            graded,
+            vec![
+                (Severity::Critical, Some("git")),
+                (Severity::Critical, Some("node")),
+                (Severity::Warning, Some("node")),
+                (Severity::Info, Some("rg")),
+                (Severity::Info, Some("bat")),
+            ]
+        );
+        assert_eq!(report.max_severity(), Some(Severity::Critical));
+        assert_eq!(report.at_least(Severity::Warning).count(), 3);
+    }
+
+    #[test]
+    fn critical_and_ignored_programs_override_defaults() {
+        let baseline = snapshot();
+        let mut current = snapshot();
+        current
+            .programs
+            .insert("rg".to_string(), program("/usr/bin/rg", Some("14.1.1")));
+        current.programs.remove("git");
+
+        let rules = SeverityRules::new()
+            .critical_program("rg")
+            .ignore_program("git");
+        let report = baseline.diff(&current, &rules);
+
+        assert_eq!(report.drifts.len(), 1);
+        assert_eq!(report.drifts[0].severity, Severity::Critical);
+        assert_eq!(report.drifts[0].kind.program(), Some("rg"));
+    }
+
+    #[test]
+    fn os_and_network_changes_are_reported() {
+        let baseline = snapshot();
+        let mut current = snapshot();
+        if let Some(os) = current.os.as_mut() {
+            os.version = "15.0".to_string();
+            os.kernel = "24.0.0".to_string();
+        }
+        if let Some(network) = current.network.as_mut() {
+            network.primary_interface = Some("en1".to_string());
+            network.interfaces.insert(
+                "en1".to_string(),
+                InterfaceRecord {
+                    is_up: true,
+                    ipv4: vec![Ipv4Addr::new(10, 0, 0, 2)],
+                },
+            );
+            if let Some(en0) = network.interfaces.get_mut("en0") {
+                en0.is_up = false;
+                en0.ipv4.clear();
+            }
+        }
+
+        let report = baseline.diff(&current, &SeverityRules::default());
+        let messages: Vec<String> = report.drifts.iter().map(ToString::to_string).collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "[warning] OS changed from macOS 14.5 to macOS 15.0",
+                "[warning] primary network interface changed from en0 to en1",
+                "[info] kernel changed from 23.5.0 to 24.0.0",
+                "[info] network interface en0 is now down",
+                "[info] en0 IPv4 addresses changed from 192.168.1.42 to none",
+                "[info] network interface en1 appeared",
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_sections_are_not_compared() {
+        let baseline = snapshot();
+        let mut current = snapshot();
+        current.os = None;
+        current.network = None;
+
+        assert!(
+            baseline
+                .diff(&current, &SeverityRules::default())
+                .is_clean()
+        );
+    }
+
+    #[test]
+    fn version_change_classification() {
+        use VersionChange::*;
+        assert_eq!(VersionChange::between(Some("1.2.3"), Some("2.0.0")), Major);
+        assert_eq!(VersionChange::between(Some("1.2.3"), Some("1.3.0")), Minor);
+        assert_eq!(VersionChange::between(Some("1.2.3"), Some("1.2.4")), Patch);
+        assert_eq!(VersionChange::between(Some("1.2"), Some("1.2.1")), Patch);
+        assert_eq!(
+            VersionChange::between(Some("1.2.3-beta.1"), Some("1.2.3")),
+            Patch
+        );
+        assert_eq!(
+            VersionChange::between(Some("ripgrep 13.0.0"), Some("ripgrep 14.1.0")),
+            Major
+        );
+        assert_eq!(
+            VersionChange::between(Some("nightly"), Some("1.0")),
+            Unknown
+        );
+        assert_eq!(VersionChange::between(Some("1.0"), None), Unknown);
+    }
+
+    #[test]
+    fn numeric_parts_stop_at_suffixes() {
+        assert_eq!(numeric_parts("14.1.0-rc1"), Some(vec![14, 1, 0]));
+        assert_eq!(numeric_parts("v3.12.1rc1.2"), Some(vec![3, 12, 1]));
+        assert_eq!(numeric_parts("no digits"), None);
+    }
+
+    #[test]
+    fn severity_parses_case_insensitively() {
+        assert_eq!("Warning".parse::<Severity>().unwrap(), Severity::Warning);
+        assert_eq!(Severity::Critical.to_string(), "critical");
+        assert!(Severity::Critical > Severity::Warning);
+    }
+
+    #[test]
+    fn snapshot_round_trips_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("baseline.json");
+        let baseline = snapshot();
+
+        baseline.save(&path).unwrap();
+
+        assert_eq!(Snapshot::load(&path).unwrap(), baseline);
+    }
+
+    #[test]
+    fn load_reports_missing_and_invalid_baselines() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing.json");
+        assert!(matches!(
+            Snapshot::load(&missing),
+            Err(SniffError::BaselineNotFound(path)) if path == missing
+        ));
+
+        let invalid = dir.path().join("invalid.json");
+        fs::write(&invalid, "not json").unwrap();
+        assert!(matches!(
+            Snapshot::load(&invalid),
+            Err(SniffError::InvalidBaseline { .. })
+        ));
+    }
+
+    #[test]
+    fn drift_serializes_with_kind_tag() {
+        let drift = Drift {
+            severity: Severity::Critical,
+            kind: DriftKind::ProgramRemoved {
+                program: "git".to_string(),
+            },
+        };
+        let json = serde_json::to_value(&drift).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"severity": "critical", "kind": "program_removed", "program": "git"})
+        );
+        assert_eq!(serde_json::from_value::<Drift>(json).unwrap(), drift);
+    }
+}
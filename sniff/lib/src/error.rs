@@ -32,6 +32,14 @@ pub enum SniffError {
     #[error("Language detection failed: {0}")]
     LanguageDetection(String),
 
+    /// No baseline snapshot has been saved at the given path.
+    #[error("No baseline saved at {0}")]
+    BaselineNotFound(PathBuf),
+
+    /// The baseline file could not be parsed or written.
+    #[error("Invalid baseline {path}: {message}")]
+    InvalidBaseline { path: PathBuf, message: String },
+
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,540 @@
+//! Display (monitor) detection module.
+//!
+//! This module enumerates connected displays along with their resolution,
+//! refresh rate, scaling and HDR support. On Linux it reads the kernel's DRM
+//! connectors from `/sys/class/drm` (including EDID for HDR metadata) and
+//! refines the result with `xrandr` when an X11 display is available. On
+//! macOS it parses `system_profiler SPDisplaysDataType`. Other platforms
+//! return an empty result.
+
+// The Linux sysfs/xrandr helpers are exercised by tests on every platform
+#![cfg_attr(not(target_os = "linux"), allow(dead_code))]
+
+use serde::{Deserialize, Serialize};
+
+/// Information about a connected display.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DisplayInfo {
+    /// Connector or display name (e.g., "eDP-1", "HDMI-A-1", "Color LCD")
+    pub name: String,
+    /// Horizontal resolution in pixels
+    pub width: Option<u32>,
+    /// Vertical resolution in pixels
+    pub height: Option<u32>,
+    /// Refresh rate in Hz
+    pub refresh_rate_hz: Option<f64>,
+    /// UI scale factor (e.g., 2.0 for Retina/HiDPI)
+    pub scale_factor: Option<f64>,
+    /// Whether the display advertises HDR support.
+    /// `None` when it cannot be determined.
+    pub hdr: Option<bool>,
+    /// Whether this is the primary display
+    pub is_primary: bool,
+    /// Whether this is a built-in panel (laptop screen)
+    pub is_builtin: bool,
+}
+
+impl DisplayInfo {
+    /// Returns the resolution as `(width, height)` if both are known.
+    pub fn resolution(&self) -> Option<(u32, u32)> {
+        Some((self.width?, self.height?))
+    }
+}
+
+/// Detects all connected displays.
+///
+/// Returns an empty vector on headless systems (servers, containers, SSH
+/// sessions without a display) or when detection is unsupported.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use sniff_lib::hardware::detect_displays;
+///
+/// let displays = detect_displays();
+/// for display in &displays {
+///     if let Some((w, h)) = display.resolution() {
+///         println!("{}: {}x{}", display.name, w, h);
+///     }
+/// }
+/// ```
+#[cfg(target_os = "linux")]
+pub fn detect_displays() -> Vec<DisplayInfo> {
+    let mut displays = detect_drm_displays(std::path::Path::new("/sys/class/drm"));
+
+    // xrandr knows the *current* mode, refresh rate and primary output;
+    // DRM only exposes the preferred mode.
+    if std::env::var_os("DISPLAY").is_some()
+        && let Some(output) = crate::os::run_command_with_timeout("xrandr", &["--query"], 2)
+    {
+        merge_xrandr(&mut displays, parse_xrandr(&output));
+    }
+
+    if let Some(scale) = env_scale_factor() {
+        for display in &mut displays {
+            display.scale_factor.get_or_insert(scale);
+        }
+    }
+
+    displays
+}
+
+/// Detects all connected displays.
+///
+/// Uses `system_profiler SPDisplaysDataType -json` on macOS.
+#[cfg(target_os = "macos")]
+pub fn detect_displays() -> Vec<DisplayInfo> {
+    crate::os::run_command_with_timeout("system_profiler", &["SPDisplaysDataType", "-json"], 5)
+        .map(|json| parse_system_profiler(&json))
+        .unwrap_or_default()
+}
+
+/// Detects all connected displays.
+///
+/// Not yet supported on this platform; always returns an empty vector.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn detect_displays() -> Vec<DisplayInfo> {
+    Vec::new()
+}
+
+// ============================================================================
+// Linux: DRM sysfs
+// ============================================================================
+
+/// Reads connected displays from DRM connectors under `drm_root`.
+///
+/// Each connector directory (e.g., `card0-eDP-1`) has a `status` file
+/// ("connected"/"disconnected"), a `modes` file listing supported modes with
+/// the preferred one first, and an `edid` blob.
+fn detect_drm_displays(drm_root: &std::path::Path) -> Vec<DisplayInfo> {
+    let Ok(entries) = std::fs::read_dir(drm_root) else {
+        return Vec::new();
+    };
+
+    let mut displays: Vec<DisplayInfo> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let dir_name = entry.file_name().to_string_lossy().to_string();
+            // Connector directories look like "card0-HDMI-A-1"
+            let (_, connector) = dir_name.split_once('-')?;
+            if !dir_name.starts_with("card") {
+                return None;
+            }
+
+            let path = entry.path();
+            let status = std::fs::read_to_string(path.join("status")).ok()?;
+            if status.trim() != "connected" {
+                return None;
+            }
+
+            let (width, height) = std::fs::read_to_string(path.join("modes"))
+                .ok()
+                .and_then(|modes| modes.lines().next().and_then(parse_mode))
+                .unzip();
+            let hdr = std::fs::read(path.join("edid"))
+                .ok()
+                .filter(|edid| !edid.is_empty())
+                .map(|edid| edid_supports_hdr(&edid));
+
+            Some(DisplayInfo {
+                name: connector.to_string(),
+                width,
+                height,
+                hdr,
+                is_builtin: is_builtin_connector(connector),
+                ..Default::default()
+            })
+        })
+        .collect();
+
+    displays.sort_by(|a, b| a.name.cmp(&b.name));
+    displays
+}
+
+/// Parses a mode string like "1920x1080" (optionally suffixed, e.g. "1920x1080i").
+fn parse_mode(mode: &str) -> Option<(u32, u32)> {
+    let (width, height) = mode.trim().split_once('x')?;
+    let height: String = height.chars().take_while(char::is_ascii_digit).collect();
+    Some((width.parse().ok()?, height.parse().ok()?))
+}
+
+/// Returns true for connectors used by built-in laptop panels.
+fn is_builtin_connector(connector: &str) -> bool {
+    ["eDP", "LVDS", "DSI"]
+        .iter()
+        .any(|prefix| connector.starts_with(prefix))
+}
+
+/// Checks an EDID blob for an HDR Static Metadata data block.
+///
+/// HDR-capable displays advertise this block in a CTA-861 extension: within
+/// the extension's data block collection, a block with tag 7 ("use extended
+/// tag") whose first payload byte is extended tag 6.
+fn edid_supports_hdr(edid: &[u8]) -> bool {
+    const BLOCK_LEN: usize = 128;
+    const CTA_EXTENSION_TAG: u8 = 0x02;
+    const EXTENDED_TAG: u8 = 7;
+    const HDR_STATIC_METADATA: u8 = 6;
+
+    edid.chunks_exact(BLOCK_LEN)
+        .skip(1)
+        .filter(|block| block[0] == CTA_EXTENSION_TAG)
+        .any(|block| {
+            // Byte 2 is the offset of the detailed timing descriptors, which
+            // marks the end of the data block collection starting at byte 4.
+            let end = (block[2] as usize).min(BLOCK_LEN);
+            let mut offset = 4;
+            while offset < end {
+                let header = block[offset];
+                let tag = header >> 5;
+                let len = (header & 0x1f) as usize;
+                let extended_tag = block.get(offset + 1).copied();
+                if tag == EXTENDED_TAG && len > 0 && extended_tag == Some(HDR_STATIC_METADATA) {
+                    return true;
+                }
+                offset += len + 1;
+            }
+            false
+        })
+}
+
+// ============================================================================
+// Linux: xrandr
+// ============================================================================
+
+/// A connected output as reported by `xrandr --query`.
+#[derive(Debug, Clone, PartialEq)]
+struct XrandrOutput {
+    name: String,
+    width: Option<u32>,
+    height: Option<u32>,
+    refresh_rate_hz: Option<f64>,
+    is_primary: bool,
+}
+
+/// Parses the connected outputs from `xrandr --query` output.
+///
+/// Output headers look like `HDMI-1 connected primary 2560x1440+0+0 (...)`;
+/// the active mode's line contains a `*` after its refresh rate, e.g.
+/// `   2560x1440     59.95*+  144.00`.
+fn parse_xrandr(output: &str) -> Vec<XrandrOutput> {
+    let mut outputs: Vec<XrandrOutput> = Vec::new();
+
+    for line in output.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            let mut parts = line.split_whitespace();
+            let (Some(name), Some("connected")) = (parts.next(), parts.next()) else {
+                continue;
+            };
+            let rest: Vec<&str> = parts.collect();
+            let geometry = rest
+                .iter()
+                .find_map(|part| part.split('+').next().and_then(parse_mode));
+            outputs.push(XrandrOutput {
+                name: name.to_string(),
+                width: geometry.map(|(w, _)| w),
+                height: geometry.map(|(_, h)| h),
+                refresh_rate_hz: None,
+                is_primary: rest.contains(&"primary"),
+            });
+        } else if let Some(current) = outputs.last_mut()
+            && current.refresh_rate_hz.is_none()
+        {
+            let mut parts = line.split_whitespace();
+            let Some(mode) = parts.next() else { continue };
+            if let Some(rate) = parts.find(|rate| rate.contains('*')) {
+                current.refresh_rate_hz = rate.trim_end_matches(['*', '+']).parse().ok();
+                if current.width.is_none()
+                    && let Some((w, h)) = parse_mode(mode)
+                {
+                    current.width = Some(w);
+                    current.height = Some(h);
+                }
+            }
+        }
+    }
+
+    outputs
+}
+
+/// Normalizes connector names so DRM ("HDMI-A-1") and xrandr ("HDMI-1" or
+/// "HDMI-A-1") spellings of the same output compare equal.
+fn normalize_connector(name: &str) -> String {
+    name.replace("-A-", "-").to_ascii_lowercase()
+}
+
+/// Applies xrandr's current-mode information to DRM-detected displays.
+///
+/// Outputs xrandr reports that DRM did not (e.g., under a nested X server
+/// without sysfs access) are added as new displays.
+fn merge_xrandr(displays: &mut Vec<DisplayInfo>, outputs: Vec<XrandrOutput>) {
+    for output in outputs {
+        let key = normalize_connector(&output.name);
+        match displays
+            .iter_mut()
+            .find(|display| normalize_connector(&display.name) == key)
+        {
+            Some(display) => {
+                display.width = output.width.or(display.width);
+                display.height = output.height.or(display.height);
+                display.refresh_rate_hz = output.refresh_rate_hz;
+                display.is_primary = output.is_primary;
+            }
+            None => displays.push(DisplayInfo {
+                is_builtin: is_builtin_connector(&output.name),
+                name: output.name,
+                width: output.width,
+                height: output.height,
+                refresh_rate_hz: output.refresh_rate_hz,
+                is_primary: output.is_primary,
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+/// Reads a desktop-wide scale factor from toolkit environment variables.
+fn env_scale_factor() -> Option<f64> {
+    ["GDK_SCALE", "QT_SCALE_FACTOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .filter_map(|value| value.trim().parse::<f64>().ok())
+        .find(|scale| *scale > 0.0)
+}
+
+// ============================================================================
+// macOS: system_profiler
+// ============================================================================
+
+/// Parses `system_profiler SPDisplaysDataType -json` output.
+///
+/// Displays are nested under each GPU's `spdisplays_ndrvs` array. Relevant
+/// keys are `_spdisplays_pixels` ("3456 x 2234"), `_spdisplays_resolution`
+/// ("1728 x 1117 @ 120.00Hz"), `spdisplays_main` and
+/// `spdisplays_connection_type`.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_system_profiler(json: &str) -> Vec<DisplayInfo> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(json) else {
+        return Vec::new();
+    };
+
+    let parse_dimensions = |text: &str| -> Option<(u32, u32)> {
+        let mut numbers = text
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|part| !part.is_empty())
+            .map(|part| part.parse::<u32>().ok());
+        Some((numbers.next()??, numbers.next()??))
+    };
+
+    value["SPDisplaysDataType"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|gpu| gpu["spdisplays_ndrvs"].as_array())
+        .flatten()
+        .map(|display| {
+            let text = |key: &str| display[key].as_str().unwrap_or_default();
+            let pixels = parse_dimensions(text("_spdisplays_pixels"));
+            let resolution = text("_spdisplays_resolution");
+            let points = parse_dimensions(resolution);
+            let refresh_rate_hz = resolution
+                .split_once('@')
+                .and_then(|(_, rate)| rate.trim().trim_end_matches("Hz").parse().ok());
+            // Pixels vs points gives the backing scale factor (2.0 on Retina)
+            let scale_factor = match (pixels, points) {
+                (Some((pw, _)), Some((lw, _))) if lw > 0 => Some(f64::from(pw) / f64::from(lw)),
+                _ => None,
+            };
+
+            DisplayInfo {
+                name: text("_name").to_string(),
+                width: pixels.or(points).map(|(w, _)| w),
+                height: pixels.or(points).map(|(_, h)| h),
+                refresh_rate_hz,
+                scale_factor,
+                hdr: None,
+                is_primary: text("spdisplays_main") == "spdisplays_yes",
+                is_builtin: text("spdisplays_connection_type") == "spdisplays_internal",
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a two-block EDID whose CTA extension contains the given data blocks.
+    fn edid_with_cta_blocks(data_blocks: &[u8]) -> Vec<u8> {
+        let mut edid = vec![0u8; 256];
+        edid[128] = 0x02; // CTA-861 extension tag
+        edid[129] = 0x03; // revision
+        edid[130] = (4 + data_blocks.len()) as u8; // DTD offset
+        edid[132..132 + data_blocks.len()].copy_from_slice(data_blocks);
+        edid
+    }
+
+    #[test]
+    fn test_parse_mode() {
+        assert_eq!(parse_mode("1920x1080"), Some((1920, 1080)));
+        assert_eq!(parse_mode("1920x1080i\n"), Some((1920, 1080)));
+        assert_eq!(parse_mode("garbage"), None);
+    }
+
+    #[test]
+    fn test_builtin_connectors() {
+        assert!(is_builtin_connector("eDP-1"));
+        assert!(is_builtin_connector("LVDS-1"));
+        assert!(!is_builtin_connector("HDMI-A-1"));
+    }
+
+    #[test]
+    fn test_edid_hdr_static_metadata_detected() {
+        // Video data block (tag 2, len 2), then extended block (tag 7, len 3)
+        // with extended tag 6 (HDR static metadata)
+        let edid = edid_with_cta_blocks(&[0x42, 0x10, 0x04, 0xE3, 0x06, 0x0F, 0x01]);
+        assert!(edid_supports_hdr(&edid));
+    }
+
+    #[test]
+    fn test_edid_without_hdr_block() {
+        // Extended block with tag 0 (video capability) only
+        let edid = edid_with_cta_blocks(&[0x42, 0x10, 0x04, 0xE2, 0x00, 0x0F]);
+        assert!(!edid_supports_hdr(&edid));
+    }
+
+    #[test]
+    fn test_edid_base_block_only() {
+        assert!(!edid_supports_hdr(&[0u8; 128]));
+        assert!(!edid_supports_hdr(&[]));
+    }
+
+    #[test]
+    fn test_detect_drm_displays_reads_connected_connectors() {
+        let dir = tempfile::tempdir().unwrap();
+        let connected = dir.path().join("card0-eDP-1");
+        std::fs::create_dir(&connected).unwrap();
+        std::fs::write(connected.join("status"), "connected\n").unwrap();
+        std::fs::write(connected.join("modes"), "2560x1600\n1920x1200\n").unwrap();
+
+        let disconnected = dir.path().join("card0-HDMI-A-1");
+        std::fs::create_dir(&disconnected).unwrap();
+        std::fs::write(disconnected.join("status"), "disconnected\n").unwrap();
+
+        // Non-connector entries are ignored
+        std::fs::create_dir(dir.path().join("card0")).unwrap();
+        std::fs::create_dir(dir.path().join("renderD128")).unwrap();
+
+        let displays = detect_drm_displays(dir.path());
+        assert_eq!(displays.len(), 1);
+        assert_eq!(displays[0].name, "eDP-1");
+        assert_eq!(displays[0].resolution(), Some((2560, 1600)));
+        assert!(displays[0].is_builtin);
+        assert_eq!(displays[0].hdr, None);
+    }
+
+    #[test]
+    fn test_detect_drm_displays_missing_root() {
+        assert!(detect_drm_displays(std::path::Path::new("/nonexistent/drm")).is_empty());
+    }
+
+    const XRANDR_OUTPUT: &str = "\
+Screen 0: minimum 320 x 200, current 4480 x 1440, maximum 16384 x 16384
+eDP-1 connected 1920x1080+2560+0 (normal left inverted right x axis y axis) 344mm x 194mm
+   1920x1080     60.02*+  48.00
+   1680x1050     59.88
+HDMI-1 connected primary 2560x1440+0+0 (normal left inverted right x axis y axis) 597mm x 336mm
+   2560x1440     59.95 +  143.91*
+   1920x1080     60.00
+DP-1 disconnected (normal left inverted right x axis y axis)
+";
+
+    #[test]
+    fn test_parse_xrandr() {
+        let outputs = parse_xrandr(XRANDR_OUTPUT);
+        assert_eq!(outputs.len(), 2);
+
+        assert_eq!(outputs[0].name, "eDP-1");
+        assert_eq!(
+            (outputs[0].width, outputs[0].height),
+            (Some(1920), Some(1080))
+        );
+        assert_eq!(outputs[0].refresh_rate_hz, Some(60.02));
+        assert!(!outputs[0].is_primary);
+
+        assert_eq!(outputs[1].name, "HDMI-1");
+        assert_eq!(outputs[1].refresh_rate_hz, Some(143.91));
+        assert!(outputs[1].is_primary);
+    }
+
+    #[test]
+    fn test_merge_xrandr_matches_drm_connector_names() {
+        let mut displays = vec![DisplayInfo {
+            name: "HDMI-A-1".to_string(),
+            width: Some(3840),
+            height: Some(2160),
+            hdr: Some(true),
+            ..Default::default()
+        }];
+
+        merge_xrandr(&mut displays, parse_xrandr(XRANDR_OUTPUT));
+
+        assert_eq!(displays.len(), 2);
+        let hdmi = &displays[0];
+        // Current mode from xrandr replaces DRM's preferred mode
+        assert_eq!(hdmi.resolution(), Some((2560, 1440)));
+        assert!(hdmi.is_primary);
+        assert_eq!(hdmi.hdr, Some(true));
+        // eDP-1 was only reported by xrandr
+        assert_eq!(displays[1].name, "eDP-1");
+        assert!(displays[1].is_builtin);
+    }
+
+    #[test]
+    fn test_parse_system_profiler() {
+        let json = r#"{
+          "SPDisplaysDataType": [{
+            "sppci_model": "Apple M3 Max",
+            "spdisplays_ndrvs": [
+              {
+                "_name": "Color LCD",
+                "_spdisplays_pixels": "3456 x 2234",
+                "_spdisplays_resolution": "1728 x 1117 @ 120.00Hz",
+                "spdisplays_connection_type": "spdisplays_internal",
+                "spdisplays_main": "spdisplays_yes"
+              },
+              {
+                "_name": "LG UltraFine",
+                "_spdisplays_resolution": "2560 x 1440 @ 60.00Hz"
+              }
+            ]
+          }]
+        }"#;
+
+        let displays = parse_system_profiler(json);
+        assert_eq!(displays.len(), 2);
+
+        let builtin = &displays[0];
+        assert_eq!(builtin.name, "Color LCD");
+        assert_eq!(builtin.resolution(), Some((3456, 2234)));
+        assert_eq!(builtin.refresh_rate_hz, Some(120.0));
+        assert_eq!(builtin.scale_factor, Some(2.0));
+        assert!(builtin.is_primary);
+        assert!(builtin.is_builtin);
+
+        let external = &displays[1];
+        assert_eq!(external.resolution(), Some((2560, 1440)));
+        assert_eq!(external.scale_factor, None);
+        assert!(!external.is_primary);
+    }
+
+    #[test]
+    fn test_parse_system_profiler_invalid_json() {
+        assert!(parse_system_profiler("not json").is_empty());
+    }
+
+    #[test]
+    fn test_detect_displays_does_not_panic() {
+        let _ = detect_displays();
+    }
+}
@@ -4,13 +4,17 @@ use sysinfo::{CpuRefreshKind, DiskKind, Disks, MemoryRefreshKind, RefreshKind, S
 use crate::Result;
 
 mod cpu;
+mod display;
 mod gpu;
 mod memory;
+mod power;
 mod storage;
 
 pub use cpu::{CpuInfo, SimdCapabilities, detect_simd};
+pub use display::{DisplayInfo, detect_displays};
 pub use gpu::{GpuCapabilities, GpuDeviceType, GpuInfo, detect_gpus};
 pub use memory::MemoryInfo;
+pub use power::{BatteryInfo, BatteryState, PowerInfo, ThermalPressure, detect_power};
 pub use storage::{StorageInfo, StorageKind};
 
 // Re-export OS types from the dedicated os module for backward compatibility.
@@ -28,8 +32,8 @@ pub use crate::os::{
 
 /// Complete hardware information.
 ///
-/// Aggregates CPU, memory, storage, and GPU information detected
-/// from the current system. OS information is available separately
+/// Aggregates CPU, memory, storage, GPU, display, and power information
+/// detected from the current system. OS information is available separately
 /// via the top-level `os` field in `SniffResult`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct HardwareInfo {
@@ -41,13 +45,20 @@ pub struct HardwareInfo {
     pub storage: Vec<StorageInfo>,
     /// GPU devices
     pub gpu: Vec<GpuInfo>,
+    /// Connected displays (empty on headless systems)
+    #[serde(default)]
+    pub displays: Vec<DisplayInfo>,
+    /// Power source, battery, and thermal state
+    /// None when power detection is skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub power: Option<PowerInfo>,
 }
 
 /// Detects hardware information from the current system.
 ///
 /// This function gathers CPU specifications, memory statistics,
-/// storage information, and GPU devices. For OS information,
-/// use `detect_os()` separately.
+/// storage information, GPU devices, connected displays, and power state.
+/// For OS information, use `detect_os()` separately.
 ///
 /// ## Examples
 ///
@@ -65,6 +76,11 @@ pub struct HardwareInfo {
 /// Currently returns `Ok` in all cases, but future versions may return
 /// errors for system information gathering failures.
 pub fn detect_hardware() -> Result<HardwareInfo> {
+    detect_hardware_with_options(true)
+}
+
+/// Detects hardware information, optionally skipping power detection.
+pub(crate) fn detect_hardware_with_options(include_power: bool) -> Result<HardwareInfo> {
     let sys = System::new_with_specifics(
         RefreshKind::nothing()
             .with_cpu(CpuRefreshKind::everything())
@@ -127,12 +143,16 @@ pub fn detect_hardware() -> Result<HardwareInfo> {
         .collect();
 
     let gpu = detect_gpus();
+    let displays = detect_displays();
+    let power = include_power.then(detect_power);
 
     Ok(HardwareInfo {
         cpu,
         memory,
         storage,
         gpu,
+        displays,
+        power,
     })
 }
 
@@ -0,0 +1,613 @@
+//! Power source, battery and thermal state detection module.
+//!
+//! This module reports whether the system is running on AC power, the
+//! charge level and health of any batteries, and the current thermal
+//! pressure. On Linux it reads `/sys/class/power_supply` and
+//! `/sys/class/thermal`; on macOS it parses `pmset -g batt` and
+//! `pmset -g therm`. Other platforms return an empty result.
+//!
+//! Long-running jobs can use [`PowerInfo::should_defer_heavy_work`] to
+//! postpone expensive work while the machine is on battery or throttling.
+
+// The Linux sysfs helpers are exercised by tests on every platform
+#![cfg_attr(not(target_os = "linux"), allow(dead_code))]
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// Battery level (percent) below which heavy work should be deferred.
+const LOW_BATTERY_PERCENT: f32 = 20.0;
+
+/// Charging state of a battery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BatteryState {
+    /// Battery is charging
+    Charging,
+    /// Battery is discharging (running on battery power)
+    Discharging,
+    /// Battery is fully charged
+    Full,
+    /// Connected to power but not charging (e.g., charge limit reached)
+    NotCharging,
+    /// State could not be determined
+    #[default]
+    Unknown,
+}
+
+impl std::fmt::Display for BatteryState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatteryState::Charging => write!(f, "charging"),
+            BatteryState::Discharging => write!(f, "discharging"),
+            BatteryState::Full => write!(f, "full"),
+            BatteryState::NotCharging => write!(f, "not charging"),
+            BatteryState::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Thermal pressure level reported by the operating system.
+///
+/// Levels mirror the macOS thermal pressure states; on Linux they are
+/// derived from how close the hottest thermal zone is to its critical
+/// trip point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ThermalPressure {
+    /// No thermal constraints
+    Nominal,
+    /// Slightly elevated temperatures, no throttling
+    Fair,
+    /// High temperatures, performance is being throttled
+    Serious,
+    /// Critical temperatures, heavy throttling
+    Critical,
+}
+
+impl std::fmt::Display for ThermalPressure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThermalPressure::Nominal => write!(f, "nominal"),
+            ThermalPressure::Fair => write!(f, "fair"),
+            ThermalPressure::Serious => write!(f, "serious"),
+            ThermalPressure::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+/// Information about a single battery.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BatteryInfo {
+    /// Battery identifier (e.g., "BAT0", "InternalBattery-0")
+    pub name: String,
+    /// Current charge level in percent (0-100)
+    pub charge_percent: Option<f32>,
+    /// Full-charge capacity relative to design capacity in percent
+    pub health_percent: Option<f32>,
+    /// Number of charge cycles
+    pub cycle_count: Option<u32>,
+    /// Charging state
+    pub state: BatteryState,
+}
+
+/// Power source, battery and thermal information.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PowerInfo {
+    /// Whether the system is connected to AC (mains) power.
+    /// `None` when it cannot be determined.
+    pub on_ac_power: Option<bool>,
+    /// Installed batteries (empty on desktops and servers)
+    pub batteries: Vec<BatteryInfo>,
+    /// Current thermal pressure, where the OS exposes it
+    pub thermal_pressure: Option<ThermalPressure>,
+    /// Hottest thermal zone temperature in degrees Celsius (Linux only)
+    pub max_temperature_celsius: Option<f32>,
+}
+
+impl PowerInfo {
+    /// Returns true when running on battery power.
+    ///
+    /// Systems without batteries are never considered on battery.
+    pub fn on_battery(&self) -> bool {
+        match self.on_ac_power {
+            Some(on_ac) => !on_ac && !self.batteries.is_empty(),
+            None => self
+                .batteries
+                .iter()
+                .any(|b| b.state == BatteryState::Discharging),
+        }
+    }
+
+    /// Returns the lowest charge level across all batteries.
+    pub fn battery_percent(&self) -> Option<f32> {
+        self.batteries
+            .iter()
+            .filter_map(|b| b.charge_percent)
+            .reduce(f32::min)
+    }
+
+    /// Returns true when heavy work (builds, model runs, large downloads)
+    /// should be postponed.
+    ///
+    /// This is the case when running on battery below 20% charge, or when
+    /// the system reports serious or critical thermal pressure.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use sniff_lib::hardware::detect_power;
+    ///
+    /// if detect_power().should_defer_heavy_work() {
+    ///     println!("Deferring until the machine is plugged in and cool");
+    /// }
+    /// ```
+    pub fn should_defer_heavy_work(&self) -> bool {
+        let low_battery = self.on_battery()
+            && self
+                .battery_percent()
+                .is_some_and(|p| p < LOW_BATTERY_PERCENT);
+        let throttled = self
+            .thermal_pressure
+            .is_some_and(|t| t >= ThermalPressure::Serious);
+        low_battery || throttled
+    }
+}
+
+/// Detects power source, battery and thermal state.
+///
+/// Returns a default (empty) [`PowerInfo`] on unsupported platforms.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use sniff_lib::hardware::detect_power;
+///
+/// let power = detect_power();
+/// if power.on_battery() {
+///     println!("On battery: {:?}%", power.battery_percent());
+/// }
+/// ```
+#[cfg(target_os = "linux")]
+pub fn detect_power() -> PowerInfo {
+    let mut power = detect_power_supplies(Path::new("/sys/class/power_supply"));
+    let (pressure, max_temp) = detect_thermal_zones(Path::new("/sys/class/thermal"));
+    power.thermal_pressure = pressure;
+    power.max_temperature_celsius = max_temp;
+    power
+}
+
+/// Detects power source, battery and thermal state.
+///
+/// Returns a default (empty) [`PowerInfo`] on unsupported platforms.
+#[cfg(target_os = "macos")]
+pub fn detect_power() -> PowerInfo {
+    let mut power = crate::os::run_command_with_timeout("pmset", &["-g", "batt"], 2)
+        .map(|output| parse_pmset_batt(&output))
+        .unwrap_or_default();
+    power.thermal_pressure = crate::os::run_command_with_timeout("pmset", &["-g", "therm"], 2)
+        .and_then(|output| parse_pmset_therm(&output));
+    power
+}
+
+/// Detects power source, battery and thermal state.
+///
+/// Returns a default (empty) [`PowerInfo`] on unsupported platforms.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn detect_power() -> PowerInfo {
+    PowerInfo::default()
+}
+
+/// Reads a trimmed sysfs attribute.
+fn read_attr(dir: &Path, name: &str) -> Option<String> {
+    std::fs::read_to_string(dir.join(name))
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Reads a numeric sysfs attribute.
+fn read_num(dir: &Path, name: &str) -> Option<f64> {
+    read_attr(dir, name)?.parse().ok()
+}
+
+/// Reads batteries and AC adapters from a `power_supply` sysfs directory.
+fn detect_power_supplies(root: &Path) -> PowerInfo {
+    let mut power = PowerInfo::default();
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return power;
+    };
+
+    let mut entries: Vec<_> = entries.flatten().map(|e| e.path()).collect();
+    entries.sort();
+
+    for dir in entries {
+        match read_attr(&dir, "type").as_deref() {
+            Some("Mains" | "USB") => {
+                if let Some(online) = read_attr(&dir, "online") {
+                    let online = online == "1";
+                    power.on_ac_power = Some(power.on_ac_power.unwrap_or(false) || online);
+                }
+            }
+            Some("Battery") => {
+                // Peripheral batteries (mice, keyboards) report scope=Device
+                if read_attr(&dir, "scope").as_deref() == Some("Device") {
+                    continue;
+                }
+                power.batteries.push(parse_battery(&dir));
+            }
+            _ => {}
+        }
+    }
+
+    power
+}
+
+/// Parses a single sysfs battery directory.
+fn parse_battery(dir: &Path) -> BatteryInfo {
+    let name = dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let state = match read_attr(dir, "status").as_deref() {
+        Some("Charging") => BatteryState::Charging,
+        Some("Discharging") => BatteryState::Discharging,
+        Some("Full") => BatteryState::Full,
+        Some("Not charging") => BatteryState::NotCharging,
+        _ => BatteryState::Unknown,
+    };
+
+    // Batteries report either energy (µWh) or charge (µAh) counters
+    let ratio = |now: &str, full: &str| {
+        let now = read_num(dir, now)?;
+        let full = read_num(dir, full).filter(|&f| f > 0.0)?;
+        Some((now / full * 100.0).min(100.0) as f32)
+    };
+
+    let charge_percent = read_num(dir, "capacity")
+        .map(|c| c as f32)
+        .or_else(|| ratio("energy_now", "energy_full"))
+        .or_else(|| ratio("charge_now", "charge_full"));
+
+    let health_percent = ratio("energy_full", "energy_full_design")
+        .or_else(|| ratio("charge_full", "charge_full_design"));
+
+    // Some firmware reports 0 cycles when the counter is unsupported
+    let cycle_count = read_num(dir, "cycle_count")
+        .map(|c| c as u32)
+        .filter(|&c| c > 0);
+
+    BatteryInfo {
+        name,
+        charge_percent,
+        health_percent,
+        cycle_count,
+        state,
+    }
+}
+
+/// Reads thermal zones and derives a thermal pressure level.
+///
+/// Returns the pressure for the zone closest to its critical trip point
+/// together with the hottest zone temperature.
+fn detect_thermal_zones(root: &Path) -> (Option<ThermalPressure>, Option<f32>) {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return (None, None);
+    };
+
+    let mut pressure: Option<ThermalPressure> = None;
+    let mut max_temp: Option<f32> = None;
+
+    for dir in entries.flatten().map(|e| e.path()) {
+        let is_zone = dir
+            .file_name()
+            .is_some_and(|n| n.to_string_lossy().starts_with("thermal_zone"));
+        if !is_zone {
+            continue;
+        }
+        // Temperatures are reported in millidegrees Celsius
+        let Some(temp) = read_num(&dir, "temp").map(|t| t / 1000.0) else {
+            continue;
+        };
+        if temp <= 0.0 {
+            continue;
+        }
+        max_temp = Some(max_temp.map_or(temp as f32, |m| m.max(temp as f32)));
+
+        if let Some(critical) = critical_trip_point(&dir) {
+            let level = thermal_pressure_from_ratio(temp / critical);
+            pressure = Some(pressure.map_or(level, |p| p.max(level)));
+        }
+    }
+
+    (pressure, max_temp)
+}
+
+/// Finds the critical trip point temperature (°C) of a thermal zone.
+fn critical_trip_point(zone: &Path) -> Option<f64> {
+    (0..16).find_map(|i| {
+        let kind = read_attr(zone, &format!("trip_point_{i}_type"))?;
+        if kind != "critical" {
+            return None;
+        }
+        read_num(zone, &format!("trip_point_{i}_temp"))
+            .map(|t| t / 1000.0)
+            .filter(|&t| t > 0.0)
+    })
+}
+
+/// Maps a temperature/critical-temperature ratio to a pressure level.
+fn thermal_pressure_from_ratio(ratio: f64) -> ThermalPressure {
+    if ratio >= 0.95 {
+        ThermalPressure::Critical
+    } else if ratio >= 0.85 {
+        ThermalPressure::Serious
+    } else if ratio >= 0.7 {
+        ThermalPressure::Fair
+    } else {
+        ThermalPressure::Nominal
+    }
+}
+
+/// Parses `pmset -g batt` output.
+///
+/// ```text
+/// Now drawing from 'Battery Power'
+///  -InternalBattery-0 (id=4653155)    82%; discharging; 5:12 remaining present: true
+/// ```
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_pmset_batt(output: &str) -> PowerInfo {
+    let mut power = PowerInfo::default();
+
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("Now drawing from") {
+            power.on_ac_power = Some(rest.contains("AC Power"));
+            continue;
+        }
+        let Some(rest) = line.strip_prefix('-') else {
+            continue;
+        };
+
+        let name = rest
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let mut fields = rest
+            .split_once('\t')
+            .map(|(_, fields)| fields)
+            .unwrap_or(rest)
+            .split(';')
+            .map(str::trim);
+
+        let charge_percent = fields
+            .next()
+            .and_then(|f| f.strip_suffix('%'))
+            .and_then(|p| p.parse().ok());
+        let state = match fields.next() {
+            Some("charging") => BatteryState::Charging,
+            Some("discharging") => BatteryState::Discharging,
+            Some("charged") => BatteryState::Full,
+            Some("AC attached") => BatteryState::NotCharging,
+            _ => BatteryState::Unknown,
+        };
+
+        power.batteries.push(BatteryInfo {
+            name,
+            charge_percent,
+            state,
+            ..Default::default()
+        });
+    }
+
+    power
+}
+
+/// Parses `pmset -g therm` output.
+///
+/// macOS reports a CPU speed limit (percent) that drops below 100 while
+/// the system is throttling, and a "thermal warning level" notice.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_pmset_therm(output: &str) -> Option<ThermalPressure> {
+    let mut pressure = None;
+
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim() != "CPU_Speed_Limit" {
+                continue;
+            }
+            let Ok(limit) = value.trim().parse::<u32>() else {
+                continue;
+            };
+            let level = match limit {
+                100.. => ThermalPressure::Nominal,
+                80..=99 => ThermalPressure::Fair,
+                50..=79 => ThermalPressure::Serious,
+                _ => ThermalPressure::Critical,
+            };
+            pressure = Some(pressure.map_or(level, |p: ThermalPressure| p.max(level)));
+        } else if line.starts_with("Note: No thermal warning level has been recorded") {
+            pressure.get_or_insert(ThermalPressure::Nominal);
+        }
+    }
+
+    pressure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write_attrs(dir: &Path, attrs: &[(&str, &str)]) {
+        fs::create_dir_all(dir).unwrap();
+        for (name, value) in attrs {
+            fs::write(dir.join(name), format!("{value}\n")).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_detect_power_supplies_laptop() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_attrs(
+            &tmp.path().join("AC"),
+            &[("type", "Mains"), ("online", "0")],
+        );
+        write_attrs(
+            &tmp.path().join("BAT0"),
+            &[
+                ("type", "Battery"),
+                ("status", "Discharging"),
+                ("capacity", "42"),
+                ("energy_full", "45000000"),
+                ("energy_full_design", "50000000"),
+                ("cycle_count", "312"),
+            ],
+        );
+        write_attrs(
+            &tmp.path().join("hidpp_battery_0"),
+            &[("type", "Battery"), ("scope", "Device"), ("capacity", "90")],
+        );
+
+        let power = detect_power_supplies(tmp.path());
+        assert_eq!(power.on_ac_power, Some(false));
+        assert_eq!(power.batteries.len(), 1);
+
+        let battery = &power.batteries[0];
+        assert_eq!(battery.name, "BAT0");
+        assert_eq!(battery.state, BatteryState::Discharging);
+        assert_eq!(battery.charge_percent, Some(42.0));
+        assert_eq!(battery.health_percent, Some(90.0));
+        assert_eq!(battery.cycle_count, Some(312));
+        assert!(power.on_battery());
+    }
+
+    #[test]
+    fn test_battery_charge_from_charge_counters() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_attrs(
+            &tmp.path().join("BAT1"),
+            &[
+                ("type", "Battery"),
+                ("status", "Charging"),
+                ("charge_now", "1500000"),
+                ("charge_full", "3000000"),
+                ("cycle_count", "0"),
+            ],
+        );
+
+        let power = detect_power_supplies(tmp.path());
+        let battery = &power.batteries[0];
+        assert_eq!(battery.charge_percent, Some(50.0));
+        assert_eq!(battery.health_percent, None);
+        assert_eq!(battery.cycle_count, None);
+        assert_eq!(battery.state, BatteryState::Charging);
+    }
+
+    #[test]
+    fn test_missing_power_supply_dir() {
+        let power = detect_power_supplies(Path::new("/nonexistent/power_supply"));
+        assert_eq!(power, PowerInfo::default());
+        assert!(!power.on_battery());
+        assert!(!power.should_defer_heavy_work());
+    }
+
+    #[test]
+    fn test_detect_thermal_zones() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_attrs(
+            &tmp.path().join("thermal_zone0"),
+            &[
+                ("temp", "45000"),
+                ("trip_point_0_type", "passive"),
+                ("trip_point_0_temp", "80000"),
+                ("trip_point_1_type", "critical"),
+                ("trip_point_1_temp", "100000"),
+            ],
+        );
+        write_attrs(
+            &tmp.path().join("thermal_zone1"),
+            &[
+                ("temp", "88000"),
+                ("trip_point_0_type", "critical"),
+                ("trip_point_0_temp", "100000"),
+            ],
+        );
+        write_attrs(&tmp.path().join("cooling_device0"), &[("cur_state", "0")]);
+
+        let (pressure, max_temp) = detect_thermal_zones(tmp.path());
+        assert_eq!(pressure, Some(ThermalPressure::Serious));
+        assert_eq!(max_temp, Some(88.0));
+    }
+
+    #[test]
+    fn test_thermal_pressure_from_ratio() {
+        assert_eq!(thermal_pressure_from_ratio(0.5), ThermalPressure::Nominal);
+        assert_eq!(thermal_pressure_from_ratio(0.75), ThermalPressure::Fair);
+        assert_eq!(thermal_pressure_from_ratio(0.9), ThermalPressure::Serious);
+        assert_eq!(thermal_pressure_from_ratio(0.99), ThermalPressure::Critical);
+    }
+
+    #[test]
+    fn test_parse_pmset_batt() {
+        let output = "Now drawing from 'Battery Power'\n \
+            -InternalBattery-0 (id=4653155)\t15%; discharging; 0:52 remaining present: true\n";
+        let power = parse_pmset_batt(output);
+        assert_eq!(power.on_ac_power, Some(false));
+        assert_eq!(power.batteries.len(), 1);
+        assert_eq!(power.batteries[0].name, "InternalBattery-0");
+        assert_eq!(power.batteries[0].charge_percent, Some(15.0));
+        assert_eq!(power.batteries[0].state, BatteryState::Discharging);
+        assert!(power.should_defer_heavy_work());
+
+        let output = "Now drawing from 'AC Power'\n \
+            -InternalBattery-0 (id=4653155)\t100%; charged; 0:00 remaining present: true\n";
+        let power = parse_pmset_batt(output);
+        assert_eq!(power.on_ac_power, Some(true));
+        assert_eq!(power.batteries[0].state, BatteryState::Full);
+        assert!(!power.on_battery());
+    }
+
+    #[test]
+    fn test_parse_pmset_batt_desktop() {
+        let power = parse_pmset_batt("Now drawing from 'AC Power'\n");
+        assert_eq!(power.on_ac_power, Some(true));
+        assert!(power.batteries.is_empty());
+    }
+
+    #[test]
+    fn test_parse_pmset_therm() {
+        let nominal = "Note: No thermal warning level has been recorded\n\
+            Note: No performance warning level has been recorded\n";
+        assert_eq!(parse_pmset_therm(nominal), Some(ThermalPressure::Nominal));
+
+        let throttled = "CPU Power notify\n\tCPU_Scheduler_Limit \t= 100\n\
+            \tCPU_Available_CPUs \t= 8\n\tCPU_Speed_Limit \t= 62\n";
+        assert_eq!(parse_pmset_therm(throttled), Some(ThermalPressure::Serious));
+
+        assert_eq!(parse_pmset_therm(""), None);
+    }
+
+    #[test]
+    fn test_should_defer_heavy_work_when_hot() {
+        let power = PowerInfo {
+            on_ac_power: Some(true),
+            thermal_pressure: Some(ThermalPressure::Critical),
+            ..Default::default()
+        };
+        assert!(power.should_defer_heavy_work());
+    }
+
+    #[test]
+    fn test_thermal_pressure_ordering() {
+        assert!(ThermalPressure::Critical > ThermalPressure::Serious);
+        assert!(ThermalPressure::Fair > ThermalPressure::Nominal);
+    }
+
+    #[test]
+    fn test_detect_power_does_not_panic() {
+        let _ = detect_power();
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+pub mod baseline;
 pub mod error;
 pub mod filesystem;
 pub mod hardware;
@@ -8,13 +9,16 @@ pub mod network;
 pub mod os;
 pub mod package;
 pub mod programs;
+pub mod redact;
 pub mod services;
+pub mod shell;
 
 pub use error::{Result, SniffError};
 pub use filesystem::FilesystemInfo;
 pub use hardware::HardwareInfo;
 pub use network::NetworkInfo;
 pub use programs::{ProgramMetadata, ProgramsInfo};
+pub use redact::RedactionLevel;
 
 // Re-export key OS types from the os module for convenience.
 // The canonical path is `sniff_lib::os::*`.
@@ -67,6 +71,10 @@ pub struct SniffConfig {
     pub skip_network: bool,
     /// Skip filesystem detection
     pub skip_filesystem: bool,
+    /// Skip power, battery, and thermal detection (within hardware)
+    pub skip_power: bool,
+    /// Redaction applied to sensitive fields before the result is returned
+    pub redaction: RedactionLevel,
 }
 
 impl SniffConfig {
@@ -116,6 +124,20 @@ impl SniffConfig {
         self.skip_filesystem = true;
         self
     }
+
+    /// Skip power, battery, and thermal detection.
+    pub fn skip_power(mut self) -> Self {
+        self.skip_power = true;
+        self
+    }
+
+    /// Redact hostnames, addresses, authors, and home paths in the result.
+    ///
+    /// See the [`redact`] module for what each level does.
+    pub fn redact(mut self, level: RedactionLevel) -> Self {
+        self.redaction = level;
+        self
+    }
 }
 
 /// Detect system information with default configuration.
@@ -161,9 +183,13 @@ pub fn detect_with_config(config: SniffConfig) -> Result<SniffResult> {
     let hardware = if config.skip_hardware {
         None
     } else if config.include_cpu_usage {
-        Some(hardware::detect_hardware_with_usage()?)
+        let mut hardware = hardware::detect_hardware_with_usage()?;
+        if config.skip_power {
+            hardware.power = None;
+        }
+        Some(hardware)
     } else {
-        Some(hardware::detect_hardware()?)
+        Some(hardware::detect_hardware_with_options(!config.skip_power)?)
     };
 
     let network = if config.skip_network {
@@ -181,12 +207,15 @@ pub fn detect_with_config(config: SniffConfig) -> Result<SniffResult> {
         Some(filesystem::detect_filesystem(&base, config.deep)?)
     };
 
-    Ok(SniffResult {
+    let mut result = SniffResult {
         os,
         hardware,
         network,
         filesystem,
-    })
+    };
+    result.redact(config.redaction);
+
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -220,6 +249,18 @@ mod tests {
         assert!(result.filesystem.is_none());
     }
 
+    #[test]
+    fn test_skip_power_omits_power() {
+        let config = SniffConfig::new()
+            .skip_os()
+            .skip_network()
+            .skip_filesystem()
+            .skip_power();
+        let result = detect_with_config(config).unwrap();
+        let hardware = result.hardware.expect("hardware should be detected");
+        assert!(hardware.power.is_none());
+    }
+
     #[test]
     fn test_config_builder_pattern() {
         let config = SniffConfig::new()
@@ -0,0 +1,304 @@
+//! Desktop environment and window manager detection.
+//!
+//! This module identifies the graphical session on Linux (and other
+//! freedesktop-style Unix systems): the session type (X11, Wayland or a text
+//! console), the desktop environment, and the window manager or compositor.
+//! Detection relies on the environment variables set by display managers and
+//! compositors, falling back to scanning running processes for a known
+//! window manager.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Graphical session type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SessionType {
+    /// X11 (Xorg) session
+    X11,
+    /// Wayland session
+    Wayland,
+    /// Text console with no graphical session
+    Tty,
+    /// Session type could not be determined
+    #[default]
+    Unknown,
+}
+
+impl std::fmt::Display for SessionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionType::X11 => write!(f, "X11"),
+            SessionType::Wayland => write!(f, "Wayland"),
+            SessionType::Tty => write!(f, "TTY"),
+            SessionType::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Desktop environment and window manager information.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DesktopInfo {
+    /// Graphical session type
+    pub session_type: SessionType,
+    /// Desktop environment (e.g., "GNOME", "KDE", "XFCE")
+    pub desktop_environment: Option<String>,
+    /// Window manager or compositor (e.g., "Mutter", "KWin", "sway")
+    pub window_manager: Option<String>,
+}
+
+impl DesktopInfo {
+    /// Returns true when a graphical session is available.
+    ///
+    /// Useful for deciding whether opening a browser window or GUI
+    /// application is possible.
+    pub fn is_graphical(&self) -> bool {
+        matches!(self.session_type, SessionType::X11 | SessionType::Wayland)
+    }
+}
+
+/// Window managers recognised from environment variables they export.
+const WM_ENV_HINTS: &[(&str, &str)] = &[
+    ("HYPRLAND_INSTANCE_SIGNATURE", "Hyprland"),
+    ("SWAYSOCK", "sway"),
+    ("I3SOCK", "i3"),
+    ("NIRI_SOCKET", "niri"),
+];
+
+/// Known window manager/compositor process names and their display names.
+const WM_PROCESSES: &[(&str, &str)] = &[
+    ("kwin_wayland", "KWin"),
+    ("kwin_x11", "KWin"),
+    ("gnome-shell", "Mutter"),
+    ("mutter", "Mutter"),
+    ("xfwm4", "xfwm4"),
+    ("openbox", "Openbox"),
+    ("marco", "Marco"),
+    ("muffin", "Muffin"),
+    ("Hyprland", "Hyprland"),
+    ("sway", "sway"),
+    ("i3", "i3"),
+    ("bspwm", "bspwm"),
+    ("awesome", "awesome"),
+    ("dwm", "dwm"),
+    ("xmonad", "xmonad"),
+    ("qtile", "Qtile"),
+    ("labwc", "labwc"),
+    ("wayfire", "Wayfire"),
+    ("river", "river"),
+    ("niri", "niri"),
+    ("weston", "Weston"),
+];
+
+/// Detects the desktop environment and window manager.
+///
+/// Returns `None` on platforms without freedesktop-style sessions
+/// (Windows, macOS).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use sniff_lib::os::detect_desktop;
+///
+/// if let Some(desktop) = detect_desktop() {
+///     println!("Session: {}", desktop.session_type);
+///     if desktop.is_graphical() {
+///         println!("Can open GUI windows");
+///     }
+/// }
+/// ```
+#[cfg(all(unix, not(target_os = "macos")))]
+pub fn detect_desktop() -> Option<DesktopInfo> {
+    let env: HashMap<String, String> = std::env::vars().collect();
+    let mut desktop = desktop_from_env(&env);
+
+    if desktop.window_manager.is_none() && desktop.is_graphical() {
+        let system = sysinfo::System::new_with_specifics(
+            sysinfo::RefreshKind::nothing().with_processes(sysinfo::ProcessRefreshKind::nothing()),
+        );
+        let names: Vec<String> = system
+            .processes()
+            .values()
+            .map(|process| process.name().to_string_lossy().to_string())
+            .collect();
+        desktop.window_manager = window_manager_from_processes(&names);
+    }
+
+    Some(desktop)
+}
+
+/// Detects the desktop environment and window manager.
+///
+/// Returns `None` on platforms without freedesktop-style sessions
+/// (Windows, macOS).
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+pub fn detect_desktop() -> Option<DesktopInfo> {
+    None
+}
+
+/// Builds desktop information from session environment variables.
+///
+/// Uses `XDG_SESSION_TYPE` (falling back to `WAYLAND_DISPLAY`/`DISPLAY`),
+/// `XDG_CURRENT_DESKTOP` (falling back to `DESKTOP_SESSION`), and
+/// compositor-specific variables for the window manager.
+pub fn desktop_from_env(env: &HashMap<String, String>) -> DesktopInfo {
+    let get = |key: &str| env.get(key).map(|v| v.trim()).filter(|v| !v.is_empty());
+
+    let session_type = match get("XDG_SESSION_TYPE")
+        .map(str::to_ascii_lowercase)
+        .as_deref()
+    {
+        Some("wayland") => SessionType::Wayland,
+        Some("x11") => SessionType::X11,
+        Some("tty") => SessionType::Tty,
+        _ if get("WAYLAND_DISPLAY").is_some() => SessionType::Wayland,
+        _ if get("DISPLAY").is_some() => SessionType::X11,
+        _ => SessionType::Unknown,
+    };
+
+    // XDG_CURRENT_DESKTOP is a colon-separated list, e.g. "ubuntu:GNOME";
+    // the last entry is the most generic (and most recognisable) name.
+    let desktop_environment = get("XDG_CURRENT_DESKTOP")
+        .and_then(|value| value.split(':').rfind(|part| !part.is_empty()))
+        .or_else(|| get("DESKTOP_SESSION"))
+        .map(normalize_desktop_name);
+
+    let window_manager = WM_ENV_HINTS
+        .iter()
+        .find(|(var, _)| get(var).is_some())
+        .map(|(_, name)| name.to_string())
+        .or_else(|| {
+            desktop_environment
+                .as_deref()
+                .and_then(default_window_manager)
+                .map(str::to_string)
+        });
+
+    DesktopInfo {
+        session_type,
+        desktop_environment,
+        window_manager,
+    }
+}
+
+/// Normalizes common desktop environment identifiers to display names.
+fn normalize_desktop_name(name: &str) -> String {
+    match name.to_ascii_lowercase().as_str() {
+        "gnome" | "gnome-xorg" | "gnome-wayland" => "GNOME".to_string(),
+        "kde" | "plasma" | "plasmawayland" => "KDE".to_string(),
+        "xfce" | "xfce4" => "XFCE".to_string(),
+        "x-cinnamon" | "cinnamon" => "Cinnamon".to_string(),
+        "mate" => "MATE".to_string(),
+        "lxqt" => "LXQt".to_string(),
+        "lxde" => "LXDE".to_string(),
+        "unity" => "Unity".to_string(),
+        "budgie" | "budgie:gnome" => "Budgie".to_string(),
+        "pantheon" => "Pantheon".to_string(),
+        "cosmic" => "COSMIC".to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// Returns the window manager a desktop environment ships with.
+fn default_window_manager(desktop: &str) -> Option<&'static str> {
+    match desktop {
+        "GNOME" | "Unity" | "Pantheon" => Some("Mutter"),
+        "KDE" => Some("KWin"),
+        "XFCE" => Some("xfwm4"),
+        "Cinnamon" => Some("Muffin"),
+        "MATE" => Some("Marco"),
+        "LXDE" => Some("Openbox"),
+        _ => None,
+    }
+}
+
+/// Finds a known window manager among running process names.
+pub fn window_manager_from_processes(names: &[String]) -> Option<String> {
+    WM_PROCESSES
+        .iter()
+        .find(|(process, _)| names.iter().any(|name| name == process))
+        .map(|(_, display)| display.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_gnome_wayland_session() {
+        let info = desktop_from_env(&env(&[
+            ("XDG_SESSION_TYPE", "wayland"),
+            ("XDG_CURRENT_DESKTOP", "ubuntu:GNOME"),
+        ]));
+        assert_eq!(info.session_type, SessionType::Wayland);
+        assert_eq!(info.desktop_environment.as_deref(), Some("GNOME"));
+        assert_eq!(info.window_manager.as_deref(), Some("Mutter"));
+        assert!(info.is_graphical());
+    }
+
+    #[test]
+    fn test_sway_detected_from_socket() {
+        let info = desktop_from_env(&env(&[
+            ("WAYLAND_DISPLAY", "wayland-1"),
+            ("SWAYSOCK", "/run/user/1000/sway-ipc.sock"),
+        ]));
+        assert_eq!(info.session_type, SessionType::Wayland);
+        assert_eq!(info.desktop_environment, None);
+        assert_eq!(info.window_manager.as_deref(), Some("sway"));
+    }
+
+    #[test]
+    fn test_x11_from_display_fallback() {
+        let info = desktop_from_env(&env(&[("DISPLAY", ":0"), ("DESKTOP_SESSION", "xfce")]));
+        assert_eq!(info.session_type, SessionType::X11);
+        assert_eq!(info.desktop_environment.as_deref(), Some("XFCE"));
+        assert_eq!(info.window_manager.as_deref(), Some("xfwm4"));
+    }
+
+    #[test]
+    fn test_headless_session() {
+        let info = desktop_from_env(&env(&[("XDG_SESSION_TYPE", "tty")]));
+        assert_eq!(info.session_type, SessionType::Tty);
+        assert!(!info.is_graphical());
+
+        let info = desktop_from_env(&env(&[]));
+        assert_eq!(info.session_type, SessionType::Unknown);
+        assert_eq!(info, DesktopInfo::default());
+    }
+
+    #[test]
+    fn test_unknown_desktop_name_is_preserved() {
+        let info = desktop_from_env(&env(&[("XDG_CURRENT_DESKTOP", "Hyprland")]));
+        assert_eq!(info.desktop_environment.as_deref(), Some("Hyprland"));
+        assert_eq!(info.window_manager, None);
+    }
+
+    #[test]
+    fn test_window_manager_from_processes() {
+        let names = vec!["bash".to_string(), "kwin_wayland".to_string()];
+        assert_eq!(
+            window_manager_from_processes(&names).as_deref(),
+            Some("KWin")
+        );
+        assert_eq!(window_manager_from_processes(&["bash".to_string()]), None);
+    }
+
+    #[test]
+    fn test_session_type_display() {
+        assert_eq!(SessionType::X11.to_string(), "X11");
+        assert_eq!(SessionType::Wayland.to_string(), "Wayland");
+        assert_eq!(SessionType::Tty.to_string(), "TTY");
+    }
+
+    #[test]
+    fn test_detect_desktop_does_not_panic() {
+        let _ = detect_desktop();
+    }
+}
@@ -6,6 +6,7 @@
 //!
 //! ## Modules
 //!
+//! - [`desktop`] - Desktop environment and window manager detection
 //! - [`distro`] - Linux distribution detection and classification
 //! - [`locale`] - Locale detection from environment variables
 //! - [`time`] - Timezone and NTP status detection
@@ -17,12 +18,16 @@ use sysinfo::System;
 use crate::Result;
 
 // Submodules
+mod desktop;
 mod distro;
 mod locale;
 mod package_manager;
 mod time;
 
 // Re-export all public types for API stability
+pub use desktop::{
+    DesktopInfo, SessionType, desktop_from_env, detect_desktop, window_manager_from_processes,
+};
 pub use distro::{
     LinuxDistro, LinuxFamily, detect_linux_distro, infer_linux_family, parse_lsb_release_content,
     parse_os_release_content, parse_system_release_content,
@@ -35,6 +40,8 @@ pub use package_manager::{
     get_path_dirs,
 };
 pub use time::{NtpStatus, TimeInfo, detect_ntp_status, detect_timezone};
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub(crate) use time::run_command_with_timeout;
 
 // ============================================================================
 // OS Type Detection
@@ -122,7 +129,8 @@ pub fn detect_os_type() -> OsType {
 /// Operating system information.
 ///
 /// Contains details about the operating system, kernel version,
-/// architecture, hostname, package managers, locale, and timezone.
+/// architecture, hostname, package managers, locale, timezone, and
+/// desktop session.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OsInfo {
     /// Operating system type classification
@@ -151,12 +159,15 @@ pub struct OsInfo {
     pub locale: Option<LocaleInfo>,
     /// Timezone and time synchronization information
     pub time: Option<TimeInfo>,
+    /// Desktop environment and window manager
+    /// None on platforms without freedesktop-style sessions (Windows, macOS)
+    pub desktop: Option<DesktopInfo>,
 }
 
 /// Detects operating system information from the current system.
 ///
 /// This function gathers OS type, distribution details, package managers,
-/// locale, and timezone information.
+/// locale, timezone, and desktop session information.
 ///
 /// ## Examples
 ///
@@ -199,6 +210,9 @@ pub fn detect_os() -> Result<OsInfo> {
     // Detect timezone and time information
     let time = Some(detect_timezone());
 
+    // Detect desktop environment / window manager (Linux and BSD)
+    let desktop = detect_desktop();
+
     Ok(OsInfo {
         os_type,
         name: System::name().unwrap_or_default(),
@@ -212,6 +226,7 @@ pub fn detect_os() -> Result<OsInfo> {
         system_package_managers,
         locale,
         time,
+        desktop,
     })
 }
 
@@ -302,6 +317,11 @@ mod tests {
             system_package_managers: None,
             locale: None,
             time: None,
+            desktop: Some(DesktopInfo {
+                session_type: SessionType::Wayland,
+                desktop_environment: Some("GNOME".to_string()),
+                window_manager: Some("Mutter".to_string()),
+            }),
         };
 
         let json = serde_json::to_string(&os_info).expect("serialization should succeed");
@@ -314,5 +334,7 @@ mod tests {
         let distro = deserialized.linux_distro.as_ref().unwrap();
         assert_eq!(distro.id, "ubuntu");
         assert_eq!(distro.family, LinuxFamily::Debian);
+        let desktop = deserialized.desktop.as_ref().unwrap();
+        assert_eq!(desktop.session_type, SessionType::Wayland);
     }
 }
@@ -59,7 +59,7 @@ pub struct TimeInfo {
 ///
 /// `Some(String)` containing stdout if the command succeeds, `None` otherwise.
 /// Returns `None` for permission errors, timeouts, or any execution failure.
-#[allow(dead_code)] // Used only on Linux for NTP detection
+#[allow(dead_code)] // Used on Linux (NTP, xrandr) and macOS (display detection)
 pub(crate) fn run_command_with_timeout(
     cmd: &str,
     args: &[&str],
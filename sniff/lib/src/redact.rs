@@ -0,0 +1,409 @@
+//! Redaction of sensitive fields so sniff output can be shared.
+//!
+//! A [`SniffResult`] contains the hostname, IP and MAC addresses, git authors
+//! and remote URLs, and absolute paths that usually include the user's name.
+//! Redacting a result before serializing it makes a snapshot safe to attach
+//! to a bug report.
+//!
+//! - [`RedactionLevel::Hash`] replaces identifiers with short tokens
+//!   (e.g. `host-3f2a9c1b`), so equal values still match across a snapshot
+//! - [`RedactionLevel::Mask`] replaces identifiers with a fixed placeholder
+//!   (e.g. `<host>`)
+//!
+//! Hash tokens are keyed BLAKE3 hashes. Each [`Redactor`] draws a random key,
+//! so a token can't be reversed by hashing candidate values (every IPv4
+//! address, or every MAC from a vendor's prefix) and the same value gets a
+//! different token in each run. Use [`Redactor::with_key`] for tokens that
+//! stay stable across runs.
+//!
+//! At both levels paths under the home directory are rewritten to start with
+//! `~`, and non-loopback addresses in the per-interface lists are replaced
+//! with the unspecified address (`0.0.0.0` / `::`). Loopback addresses and
+//! interface names are kept.
+//!
+//! ## Examples
+//!
+//! ```
+//! use sniff_lib::redact::{RedactionLevel, Redactor};
+//!
+//! let redactor = Redactor::new(RedactionLevel::Hash);
+//! let token = redactor.text("host", "alice-laptop");
+//! assert!(token.starts_with("host-"));
+//! assert_eq!(token, redactor.text("host", "alice-laptop"));
+//!
+//! let masked = Redactor::new(RedactionLevel::Mask).text("host", "alice-laptop");
+//! assert_eq!(masked, "<host>");
+//! ```
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::{Path, PathBuf};
+
+use biscuit_hash::blake3_keyed_hash;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+use crate::SniffResult;
+use crate::filesystem::FilesystemInfo;
+use crate::hardware::HardwareInfo;
+use crate::network::NetworkInfo;
+use crate::os::OsInfo;
+
+/// How aggressively to redact sensitive fields.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Display, EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum RedactionLevel {
+    /// Leave all values as detected
+    #[default]
+    None,
+    /// Replace identifiers with stable hash tokens
+    Hash,
+    /// Replace identifiers with fixed placeholders
+    Mask,
+}
+
+/// Applies a [`RedactionLevel`] to individual values.
+#[derive(Clone)]
+pub struct Redactor {
+    level: RedactionLevel,
+    home: Option<PathBuf>,
+    key: [u8; 32],
+}
+
+impl std::fmt::Debug for Redactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The key would let anyone brute-force the hash tokens
+        f.debug_struct("Redactor")
+            .field("level", &self.level)
+            .field("home", &self.home)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Redactor {
+    /// Creates a redactor for `level`, using the current user's home directory
+    /// and a random hashing key.
+    pub fn new(level: RedactionLevel) -> Self {
+        let home = std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .filter(|home| !home.is_empty())
+            .map(PathBuf::from);
+        Self {
+            level,
+            home,
+            key: rand::random(),
+        }
+    }
+
+    /// Replaces the random hashing key, so hash tokens match those of other
+    /// redactors using the same key. Keep the key secret: anyone holding it
+    /// can test guesses against the tokens.
+    pub fn with_key(mut self, key: [u8; 32]) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// Overrides the home directory used to shorten paths.
+    pub fn with_home(mut self, home: impl Into<PathBuf>) -> Self {
+        self.home = Some(home.into());
+        self
+    }
+
+    /// Returns the redaction level.
+    pub fn level(&self) -> RedactionLevel {
+        self.level
+    }
+
+    /// Redacts an identifier, labelling the replacement with `kind`.
+    pub fn text(&self, kind: &str, value: &str) -> String {
+        match self.level {
+            RedactionLevel::None => value.to_string(),
+            RedactionLevel::Hash => {
+                let hash = blake3_keyed_hash(&self.key, value.as_bytes());
+                format!("{}-{}", kind, &hash[..8])
+            }
+            RedactionLevel::Mask => format!("<{}>", kind),
+        }
+    }
+
+    /// Rewrites a path under the home directory to start with `~`.
+    pub fn path(&self, path: &Path) -> PathBuf {
+        if self.level == RedactionLevel::None {
+            return path.to_path_buf();
+        }
+        match self
+            .home
+            .as_deref()
+            .and_then(|home| path.strip_prefix(home).ok())
+        {
+            Some(rest) => Path::new("~").join(rest),
+            None => path.to_path_buf(),
+        }
+    }
+
+    fn ipv4(&self, addr: Ipv4Addr) -> Ipv4Addr {
+        if self.level == RedactionLevel::None || addr.is_loopback() {
+            addr
+        } else {
+            Ipv4Addr::UNSPECIFIED
+        }
+    }
+
+    fn ipv6(&self, addr: Ipv6Addr) -> Ipv6Addr {
+        if self.level == RedactionLevel::None || addr.is_loopback() {
+            addr
+        } else {
+            Ipv6Addr::UNSPECIFIED
+        }
+    }
+
+    fn address(&self, kind: &str, address: &str) -> String {
+        let loopback = address
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|addr| addr.is_loopback());
+        if loopback {
+            address.to_string()
+        } else {
+            self.text(kind, address)
+        }
+    }
+}
+
+impl SniffResult {
+    /// Redacts sensitive fields in place.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use sniff_lib::detect;
+    /// use sniff_lib::redact::RedactionLevel;
+    ///
+    /// let mut result = detect().unwrap();
+    /// result.redact(RedactionLevel::Hash);
+    /// println!("{}", serde_json::to_string_pretty(&result).unwrap());
+    /// ```
+    pub fn redact(&mut self, level: RedactionLevel) {
+        self.redact_with(&Redactor::new(level));
+    }
+
+    /// Redacts sensitive fields in place using a configured [`Redactor`].
+    pub fn redact_with(&mut self, redactor: &Redactor) {
+        if redactor.level() == RedactionLevel::None {
+            return;
+        }
+        if let Some(os) = self.os.as_mut() {
+            redact_os(os, redactor);
+        }
+        if let Some(hardware) = self.hardware.as_mut() {
+            redact_hardware(hardware, redactor);
+        }
+        if let Some(network) = self.network.as_mut() {
+            redact_network(network, redactor);
+        }
+        if let Some(filesystem) = self.filesystem.as_mut() {
+            redact_filesystem(filesystem, redactor);
+        }
+    }
+}
+
+fn redact_os(os: &mut OsInfo, redactor: &Redactor) {
+    os.hostname = redactor.text("host", &os.hostname);
+}
+
+fn redact_hardware(hardware: &mut HardwareInfo, redactor: &Redactor) {
+    for disk in &mut hardware.storage {
+        disk.mount_point = redactor.path(&disk.mount_point);
+    }
+}
+
+fn redact_network(network: &mut NetworkInfo, redactor: &Redactor) {
+    for iface in &mut network.interfaces {
+        if let Some(mac) = iface.mac_address.as_mut() {
+            *mac = redactor.text("mac", mac);
+        }
+        for addr in &mut iface.ipv4_addresses {
+            *addr = redactor.ipv4(*addr);
+        }
+        for addr in &mut iface.ipv6_addresses {
+            *addr = redactor.ipv6(*addr);
+        }
+    }
+    for addr in &mut network.ip_addresses.v4 {
+        addr.address = redactor.address("ipv4", &addr.address);
+    }
+    for addr in &mut network.ip_addresses.v6 {
+        addr.address = redactor.address("ipv6", &addr.address);
+    }
+}
+
+fn redact_filesystem(filesystem: &mut FilesystemInfo, redactor: &Redactor) {
+    if let Some(languages) = filesystem.languages.as_mut() {
+        for stats in &mut languages.languages {
+            for file in &mut stats.files {
+                *file = redactor.path(file);
+            }
+        }
+    }
+
+    if let Some(git) = filesystem.git.as_mut() {
+        git.repo_root = redactor.path(&git.repo_root);
+        for commit in &mut git.recent {
+            commit.author = redactor.text("author", &commit.author);
+        }
+        for remote in &mut git.remotes {
+            if let Some(url) = remote.url.as_mut() {
+                *url = redactor.text("remote", url);
+            }
+        }
+        for worktree in git.worktrees.values_mut() {
+            worktree.filepath = redactor.path(&worktree.filepath);
+        }
+        for file in &mut git.status.dirty {
+            file.absolute_filepath = redactor.path(&file.absolute_filepath);
+        }
+        for file in &mut git.status.untracked {
+            file.absolute_filepath = redactor.path(&file.absolute_filepath);
+        }
+    }
+
+    if let Some(repo) = filesystem.repo.as_mut() {
+        repo.root = redactor.path(&repo.root);
+        for package in repo.packages.iter_mut().flatten() {
+            package.path = redactor.path(&package.path);
+        }
+    }
+
+    if let Some(formatting) = filesystem.formatting.as_mut() {
+        formatting.config_path = redactor.path(&formatting.config_path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::{IpAddresses, Ipv4Address, NetworkInterface};
+
+    fn network() -> NetworkInfo {
+        let mut iface = NetworkInterface::new("en0".to_string());
+        iface.mac_address = Some("00:1A:2B:3C:4D:5E".to_string());
+        iface.ipv4_addresses = vec![Ipv4Addr::new(192, 168, 1, 42), Ipv4Addr::LOCALHOST];
+
+        NetworkInfo {
+            interfaces: vec![iface],
+            primary_interface: Some("en0".to_string()),
+            ip_addresses: IpAddresses {
+                v4: vec![
+                    Ipv4Address {
+                        address: "192.168.1.42".to_string(),
+                        interface: "en0".to_string(),
+                    },
+                    Ipv4Address {
+                        address: "127.0.0.1".to_string(),
+                        interface: "lo0".to_string(),
+                    },
+                ],
+                v6: vec![],
+            },
+            permission_denied: false,
+        }
+    }
+
+    #[test]
+    fn level_parses_case_insensitively() {
+        assert_eq!(
+            "hash".parse::<RedactionLevel>().unwrap(),
+            RedactionLevel::Hash
+        );
+        assert_eq!(
+            "MASK".parse::<RedactionLevel>().unwrap(),
+            RedactionLevel::Mask
+        );
+        assert!("partial".parse::<RedactionLevel>().is_err());
+        assert_eq!(RedactionLevel::default(), RedactionLevel::None);
+    }
+
+    #[test]
+    fn hash_tokens_are_stable_and_distinct() {
+        let redactor = Redactor::new(RedactionLevel::Hash);
+        let a = redactor.text("host", "alice-laptop");
+        assert_eq!(a, redactor.text("host", "alice-laptop"));
+        assert_ne!(a, redactor.text("host", "bob-desktop"));
+        assert!(!a.contains("alice"));
+        assert_eq!(a.len(), "host-".len() + 8);
+    }
+
+    #[test]
+    fn hash_tokens_depend_on_the_redactor_key() {
+        let first = Redactor::new(RedactionLevel::Hash);
+        let second = Redactor::new(RedactionLevel::Hash);
+        assert_ne!(
+            first.text("ipv4", "192.168.1.42"),
+            second.text("ipv4", "192.168.1.42")
+        );
+        assert_ne!(
+            first.text("mac", "00:1A:2B:3C:4D:5E"),
+            second.text("mac", "00:1A:2B:3C:4D:5E")
+        );
+
+        let keyed = Redactor::new(RedactionLevel::Hash).with_key([7; 32]);
+        let same_key = Redactor::new(RedactionLevel::Hash).with_key([7; 32]);
+        assert_eq!(
+            keyed.text("host", "alice-laptop"),
+            same_key.text("host", "alice-laptop")
+        );
+        assert!(!format!("{:?}", keyed).contains("key"));
+    }
+
+    #[test]
+    fn paths_under_home_are_shortened() {
+        let redactor = Redactor::new(RedactionLevel::Mask).with_home("/home/alice");
+        assert_eq!(
+            redactor.path(Path::new("/home/alice/code/sniff")),
+            PathBuf::from("~/code/sniff")
+        );
+        assert_eq!(
+            redactor.path(Path::new("/opt/data")),
+            PathBuf::from("/opt/data")
+        );
+
+        let none = Redactor::new(RedactionLevel::None).with_home("/home/alice");
+        assert_eq!(
+            none.path(Path::new("/home/alice/code")),
+            PathBuf::from("/home/alice/code")
+        );
+    }
+
+    #[test]
+    fn network_redaction_keeps_loopback_and_interface_names() {
+        let mut network = network();
+        redact_network(&mut network, &Redactor::new(RedactionLevel::Mask));
+
+        let iface = &network.interfaces[0];
+        assert_eq!(iface.name, "en0");
+        assert_eq!(iface.mac_address.as_deref(), Some("<mac>"));
+        assert_eq!(
+            iface.ipv4_addresses,
+            vec![Ipv4Addr::UNSPECIFIED, Ipv4Addr::LOCALHOST]
+        );
+        assert_eq!(network.ip_addresses.v4[0].address, "<ipv4>");
+        assert_eq!(network.ip_addresses.v4[1].address, "127.0.0.1");
+    }
+
+    #[test]
+    fn redacted_result_serializes_without_identifiers() {
+        let mut result = SniffResult {
+            os: None,
+            hardware: None,
+            network: Some(network()),
+            filesystem: None,
+        };
+        result.redact(RedactionLevel::Hash);
+
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(!json.contains("192.168.1.42"));
+        assert!(!json.contains("00:1A:2B:3C:4D:5E"));
+        assert!(json.contains("ipv4-"));
+    }
+}
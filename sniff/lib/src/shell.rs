@@ -0,0 +1,503 @@
+//! Shell configuration inventory.
+//!
+//! Tools that work in one terminal and fail in another usually differ in
+//! their shell setup rather than in the tool itself: a missing `PATH` entry,
+//! a framework that wraps commands, or an alias shadowing a binary. This
+//! module summarizes that setup so two environments can be compared:
+//!
+//! - the login shell (from `SHELL`)
+//! - which of the shell's startup files exist
+//! - shell frameworks and prompt tools (oh-my-zsh, prezto, starship, ...)
+//! - a fixed set of environment variables that commonly change tool behavior
+//! - the `PATH` entries, flagging missing directories and duplicates
+//! - the number of aliases defined in the startup files
+//!
+//! The inventory is opt-in: it is not part of [`crate::detect`], because
+//! `PATH` entries and variable values often reveal usernames and project
+//! names. Use [`ShellInfo::redact`] before sharing it.
+//!
+//! Startup files are read but never executed, so aliases defined by a
+//! framework or in a sourced file are not counted.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use sniff_lib::shell::detect_shell;
+//!
+//! let shell = detect_shell();
+//! println!("Shell: {}", shell.shell);
+//! for framework in &shell.frameworks {
+//!     println!("Framework: {}", framework);
+//! }
+//! ```
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::redact::{RedactionLevel, Redactor};
+
+/// Environment variables reported by the inventory.
+///
+/// Only these variables are captured, so tokens and other secrets in the
+/// environment never end up in the output.
+pub const RELEVANT_ENV_VARS: &[&str] = &[
+    "SHELL",
+    "TERM",
+    "TERM_PROGRAM",
+    "COLORTERM",
+    "LANG",
+    "LC_ALL",
+    "EDITOR",
+    "VISUAL",
+    "PAGER",
+    "ZDOTDIR",
+    "XDG_CONFIG_HOME",
+    "XDG_DATA_HOME",
+    "CARGO_HOME",
+    "RUSTUP_HOME",
+    "GOPATH",
+    "NVM_DIR",
+    "PYENV_ROOT",
+    "VIRTUAL_ENV",
+    "CONDA_DEFAULT_ENV",
+    "JAVA_HOME",
+];
+
+/// The user's login shell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ShellKind {
+    Bash,
+    Zsh,
+    Fish,
+    Nushell,
+    PowerShell,
+    Elvish,
+    Xonsh,
+    Ksh,
+    Tcsh,
+    Dash,
+    Sh,
+    /// `SHELL` is unset or names an unrecognized shell
+    #[default]
+    Unknown,
+}
+
+impl ShellKind {
+    /// Identifies a shell from its executable path, e.g. `/usr/bin/zsh`.
+    pub fn from_path(path: &str) -> Self {
+        let name = Path::new(path)
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        match name {
+            "bash" => Self::Bash,
+            "zsh" => Self::Zsh,
+            "fish" => Self::Fish,
+            "nu" => Self::Nushell,
+            "pwsh" | "powershell" => Self::PowerShell,
+            "elvish" => Self::Elvish,
+            "xonsh" => Self::Xonsh,
+            "ksh" | "mksh" | "ksh93" => Self::Ksh,
+            "tcsh" | "csh" => Self::Tcsh,
+            "dash" => Self::Dash,
+            "sh" => Self::Sh,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Returns the startup files the shell reads, given the home, XDG config
+    /// and `ZDOTDIR` directories.
+    fn startup_files(self, home: &Path, config_home: &Path, zdotdir: &Path) -> Vec<PathBuf> {
+        match self {
+            Self::Bash => [
+                ".bashrc",
+                ".bash_profile",
+                ".bash_login",
+                ".profile",
+                ".bash_aliases",
+            ]
+            .iter()
+            .map(|name| home.join(name))
+            .collect(),
+            Self::Zsh => [".zshenv", ".zprofile", ".zshrc", ".zlogin"]
+                .iter()
+                .map(|name| zdotdir.join(name))
+                .collect(),
+            Self::Fish => vec![config_home.join("fish").join("config.fish")],
+            Self::Nushell => vec![
+                config_home.join("nushell").join("env.nu"),
+                config_home.join("nushell").join("config.nu"),
+            ],
+            Self::PowerShell => vec![
+                config_home
+                    .join("powershell")
+                    .join("Microsoft.PowerShell_profile.ps1"),
+            ],
+            Self::Elvish => vec![config_home.join("elvish").join("rc.elv")],
+            Self::Xonsh => vec![home.join(".xonshrc")],
+            Self::Ksh => vec![home.join(".kshrc"), home.join(".profile")],
+            Self::Tcsh => vec![home.join(".tcshrc"), home.join(".cshrc")],
+            Self::Dash | Self::Sh => vec![home.join(".profile")],
+            Self::Unknown => Vec::new(),
+        }
+    }
+}
+
+/// A shell framework, plugin manager or prompt tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum ShellFramework {
+    OhMyZsh,
+    Prezto,
+    Zinit,
+    Antidote,
+    Antigen,
+    OhMyBash,
+    BashIt,
+    OhMyFish,
+    Fisher,
+    Starship,
+    OhMyPosh,
+}
+
+/// One entry of the `PATH` variable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathEntry {
+    /// The directory as listed in `PATH`
+    pub path: PathBuf,
+    /// Whether the directory exists
+    pub exists: bool,
+    /// Whether the directory already appeared earlier in `PATH`
+    pub duplicate: bool,
+}
+
+/// Inventory of the user's shell configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShellInfo {
+    /// The login shell
+    pub shell: ShellKind,
+    /// The login shell's executable, from `SHELL`
+    pub shell_path: Option<PathBuf>,
+    /// Startup files of the login shell that exist
+    pub config_files: Vec<PathBuf>,
+    /// Frameworks and prompt tools found in the environment or home directory
+    pub frameworks: Vec<ShellFramework>,
+    /// Values of the [`RELEVANT_ENV_VARS`] that are set
+    pub env: BTreeMap<String, String>,
+    /// `PATH` entries in lookup order
+    pub path: Vec<PathEntry>,
+    /// Number of aliases defined in `config_files`
+    pub alias_count: usize,
+}
+
+impl ShellInfo {
+    /// Returns the `PATH` entries that don't exist.
+    pub fn missing_path_entries(&self) -> impl Iterator<Item = &PathEntry> {
+        self.path.iter().filter(|entry| !entry.exists)
+    }
+
+    /// Returns the `PATH` entries listed more than once.
+    pub fn duplicate_path_entries(&self) -> impl Iterator<Item = &PathEntry> {
+        self.path.iter().filter(|entry| entry.duplicate)
+    }
+
+    /// Shortens paths under the home directory, as for a redacted
+    /// [`SniffResult`](crate::SniffResult).
+    pub fn redact(&mut self, level: RedactionLevel) {
+        self.redact_with(&Redactor::new(level));
+    }
+
+    /// Redacts the inventory with an existing [`Redactor`].
+    pub fn redact_with(&mut self, redactor: &Redactor) {
+        if redactor.level() == RedactionLevel::None {
+            return;
+        }
+        if let Some(ref mut shell_path) = self.shell_path {
+            *shell_path = redactor.path(shell_path);
+        }
+        for file in &mut self.config_files {
+            *file = redactor.path(file);
+        }
+        for value in self.env.values_mut() {
+            *value = redactor
+                .path(Path::new(value.as_str()))
+                .display()
+                .to_string();
+        }
+        for entry in &mut self.path {
+            entry.path = redactor.path(&entry.path);
+        }
+    }
+}
+
+/// Inventories the current user's shell configuration.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use sniff_lib::shell::detect_shell;
+///
+/// let shell = detect_shell();
+/// for entry in shell.missing_path_entries() {
+///     println!("PATH entry does not exist: {}", entry.path.display());
+/// }
+/// ```
+pub fn detect_shell() -> ShellInfo {
+    let env: HashMap<String, String> = std::env::vars().collect();
+    let home = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from);
+    shell_from_env(&env, home.as_deref())
+}
+
+/// Builds the shell inventory from environment variables and a home
+/// directory.
+///
+/// Startup files and framework directories are looked up under `home`;
+/// without one only the environment is inspected.
+pub fn shell_from_env(env: &HashMap<String, String>, home: Option<&Path>) -> ShellInfo {
+    let get = |key: &str| env.get(key).map(|v| v.trim()).filter(|v| !v.is_empty());
+
+    let shell_path = get("SHELL").map(PathBuf::from);
+    let shell = get("SHELL").map(ShellKind::from_path).unwrap_or_default();
+
+    let config_files: Vec<PathBuf> = match home {
+        Some(home) => {
+            let config_home = get("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| home.join(".config"));
+            let zdotdir = get("ZDOTDIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| home.to_path_buf());
+            shell
+                .startup_files(home, &config_home, &zdotdir)
+                .into_iter()
+                .filter(|file| file.is_file())
+                .collect()
+        }
+        None => Vec::new(),
+    };
+
+    let alias_count = config_files
+        .iter()
+        .filter_map(|file| std::fs::read_to_string(file).ok())
+        .map(|contents| count_aliases(&contents))
+        .sum();
+
+    let env_values = RELEVANT_ENV_VARS
+        .iter()
+        .filter_map(|key| get(key).map(|value| (key.to_string(), value.to_string())))
+        .collect();
+
+    let path = get("PATH").map(parse_path).unwrap_or_default();
+
+    ShellInfo {
+        shell,
+        shell_path,
+        config_files,
+        frameworks: detect_frameworks(env, home),
+        env: env_values,
+        path,
+        alias_count,
+    }
+}
+
+/// Finds frameworks from the variables they export or the directories they
+/// install into.
+fn detect_frameworks(env: &HashMap<String, String>, home: Option<&Path>) -> Vec<ShellFramework> {
+    let has_var = |key: &str| env.get(key).is_some_and(|v| !v.trim().is_empty());
+    let has_file = |relative: &str| home.is_some_and(|home| home.join(relative).exists());
+
+    let checks: [(ShellFramework, bool); 11] = [
+        (
+            ShellFramework::OhMyZsh,
+            has_var("ZSH") || has_file(".oh-my-zsh"),
+        ),
+        (ShellFramework::Prezto, has_file(".zprezto")),
+        (
+            ShellFramework::Zinit,
+            has_var("ZINIT_HOME") || has_file(".local/share/zinit"),
+        ),
+        (ShellFramework::Antidote, has_file(".antidote")),
+        (
+            ShellFramework::Antigen,
+            has_var("ADOTDIR") || has_file(".antigen"),
+        ),
+        (
+            ShellFramework::OhMyBash,
+            has_var("OSH") || has_file(".oh-my-bash"),
+        ),
+        (
+            ShellFramework::BashIt,
+            has_var("BASH_IT") || has_file(".bash_it"),
+        ),
+        (
+            ShellFramework::OhMyFish,
+            has_var("OMF_PATH") || has_file(".local/share/omf"),
+        ),
+        (
+            ShellFramework::Fisher,
+            has_file(".config/fish/functions/fisher.fish"),
+        ),
+        (
+            ShellFramework::Starship,
+            has_var("STARSHIP_SHELL") || has_file(".config/starship.toml"),
+        ),
+        (ShellFramework::OhMyPosh, has_var("POSH_THEME")),
+    ];
+
+    checks
+        .into_iter()
+        .filter(|(_, found)| *found)
+        .map(|(framework, _)| framework)
+        .collect()
+}
+
+/// Splits a `PATH` value into entries, flagging missing and repeated ones.
+fn parse_path(value: &str) -> Vec<PathEntry> {
+    let mut seen = HashSet::new();
+    std::env::split_paths(value)
+        .filter(|path| !path.as_os_str().is_empty())
+        .map(|path| {
+            let duplicate = !seen.insert(path.clone());
+            PathEntry {
+                exists: path.is_dir(),
+                duplicate,
+                path,
+            }
+        })
+        .collect()
+}
+
+/// Counts alias definitions (`alias name=...` or fish's `alias name ...`)
+/// in a startup file.
+fn count_aliases(contents: &str) -> usize {
+    contents
+        .lines()
+        .map(str::trim_start)
+        .filter(|line| {
+            line.strip_prefix("alias")
+                .is_some_and(|rest| rest.starts_with(char::is_whitespace))
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn env(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_shell_kind_from_path() {
+        assert_eq!(ShellKind::from_path("/bin/zsh"), ShellKind::Zsh);
+        assert_eq!(ShellKind::from_path("/usr/local/bin/fish"), ShellKind::Fish);
+        assert_eq!(ShellKind::from_path("/usr/bin/pwsh"), ShellKind::PowerShell);
+        assert_eq!(ShellKind::from_path("/opt/weird-shell"), ShellKind::Unknown);
+        assert_eq!(ShellKind::PowerShell.to_string(), "powershell");
+    }
+
+    #[test]
+    fn test_zsh_inventory_from_home() {
+        let home = TempDir::new().unwrap();
+        std::fs::write(
+            home.path().join(".zshrc"),
+            "export ZSH=\"$HOME/.oh-my-zsh\"\nalias ll='ls -l'\n  alias gs='git status'\n# alias off=1\naliases=1\n",
+        )
+        .unwrap();
+        std::fs::create_dir(home.path().join(".oh-my-zsh")).unwrap();
+
+        let info = shell_from_env(
+            &env(&[
+                ("SHELL", "/bin/zsh"),
+                ("EDITOR", "nvim"),
+                ("GITHUB_TOKEN", "x"),
+            ]),
+            Some(home.path()),
+        );
+
+        assert_eq!(info.shell, ShellKind::Zsh);
+        assert_eq!(info.config_files, vec![home.path().join(".zshrc")]);
+        assert_eq!(info.frameworks, vec![ShellFramework::OhMyZsh]);
+        assert_eq!(info.alias_count, 2);
+        assert_eq!(info.env.get("EDITOR").map(String::as_str), Some("nvim"));
+        assert!(!info.env.contains_key("GITHUB_TOKEN"));
+    }
+
+    #[test]
+    fn test_path_entries_flag_missing_and_duplicates() {
+        let dir = TempDir::new().unwrap();
+        let existing = dir.path().display().to_string();
+        let missing = dir.path().join("missing").display().to_string();
+        let value = std::env::join_paths([&existing, &missing, &existing]).unwrap();
+
+        let info = shell_from_env(&env(&[("PATH", value.to_str().unwrap())]), None);
+
+        assert_eq!(info.path.len(), 3);
+        assert!(info.path[0].exists && !info.path[0].duplicate);
+        assert_eq!(info.missing_path_entries().count(), 1);
+        assert_eq!(
+            info.duplicate_path_entries()
+                .map(|entry| entry.path.as_path())
+                .collect::<Vec<_>>(),
+            vec![dir.path()]
+        );
+    }
+
+    #[test]
+    fn test_frameworks_from_env() {
+        let info = shell_from_env(
+            &env(&[
+                ("SHELL", "/bin/bash"),
+                ("STARSHIP_SHELL", "bash"),
+                ("OSH", "/x"),
+            ]),
+            None,
+        );
+        assert_eq!(
+            info.frameworks,
+            vec![ShellFramework::OhMyBash, ShellFramework::Starship]
+        );
+        assert_eq!(ShellFramework::OhMyBash.to_string(), "oh-my-bash");
+    }
+
+    #[test]
+    fn test_redact_shortens_home_paths() {
+        let mut info = ShellInfo {
+            shell_path: Some(PathBuf::from("/bin/zsh")),
+            config_files: vec![PathBuf::from("/home/alice/.zshrc")],
+            env: BTreeMap::from([("CARGO_HOME".to_string(), "/home/alice/.cargo".to_string())]),
+            path: vec![PathEntry {
+                path: PathBuf::from("/home/alice/.cargo/bin"),
+                exists: true,
+                duplicate: false,
+            }],
+            ..Default::default()
+        };
+
+        info.redact_with(&Redactor::new(RedactionLevel::Mask).with_home("/home/alice"));
+
+        assert_eq!(info.shell_path, Some(PathBuf::from("/bin/zsh")));
+        assert_eq!(info.config_files, vec![PathBuf::from("~/.zshrc")]);
+        assert_eq!(info.env["CARGO_HOME"], "~/.cargo");
+        assert_eq!(info.path[0].path, PathBuf::from("~/.cargo/bin"));
+    }
+
+    #[test]
+    fn test_detect_shell_does_not_panic() {
+        let _ = detect_shell();
+    }
+}
@@ -1,18 +1,26 @@
 use std::io::IsTerminal;
 use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
+use biscuit_terminal::components::picker::{Picker, PickerItem};
+use biscuit_terminal::components::progress_bar::ProgressBar;
+use biscuit_terminal::utils::symbols::{self, Icon, IconStyle};
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::{Generator, Shell};
 use ignore::WalkBuilder;
 use ignore::overrides::OverrideBuilder;
 use owo_colors::{OwoColorize, Style};
 use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use tree_hugger_lib::bloat::{
+    AttributedSymbol, SectionSize, SourceFileSize, attribute_symbols, sizes_by_file,
+};
 use tree_hugger_lib::{
-    Diagnostic, DiagnosticKind, DiagnosticSeverity, FieldInfo, FileSummary, FunctionSignature,
-    ImportSymbol, LintDiagnostic, PackageSummary, ParameterInfo, ProgrammingLanguage,
-    SourceContext, SymbolInfo, SymbolKind, SyntaxDiagnostic, TreeFile, TreeHuggerError,
-    TypeMetadata, VariantInfo,
+    BlameInfo, Diagnostic, DiagnosticKind, DiagnosticSeverity, FieldInfo, FileBlame, FileSummary,
+    FunctionSignature, ImportSymbol, LintDiagnostic, PackageSummary, ParallelOptions,
+    ParameterInfo, ProgrammingLanguage, SourceContext, SymbolIndex, SymbolInfo, SymbolKind,
+    SyntaxDiagnostic, TreeFile, TreeHuggerError, TypeMetadata, VariantInfo, analyze_binary,
+    analyze_files,
 };
 use serde::{Deserialize, Serialize};
 
@@ -39,6 +47,15 @@ struct Cli {
     #[arg(long, global = true)]
     plain: bool,
 
+    /// Number of files to analyze in parallel (defaults to one per CPU)
+    #[arg(long, short = 'j', value_name = "N", global = true)]
+    jobs: Option<usize>,
+
+    /// Annotate symbols and diagnostics with the author and age of the
+    /// commit that last modified them (requires git)
+    #[arg(long, global = true)]
+    blame: bool,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -100,6 +117,42 @@ struct LintArgs {
     syntax_only: bool,
 }
 
+/// Arguments for the find command
+#[derive(clap::Args, Debug, Clone)]
+struct FindArgs {
+    /// Fuzzy query to start with (use "" to browse everything)
+    #[arg(value_name = "QUERY")]
+    query: String,
+
+    /// Glob patterns for files to include (defaults to all supported files)
+    #[arg(value_name = "GLOB")]
+    inputs: Vec<String>,
+
+    /// Open the selected symbol in $VISUAL/$EDITOR at its line
+    #[arg(long, short = 'e', conflicts_with = "list")]
+    edit: bool,
+
+    /// Print ranked matches instead of prompting (default when not a TTY)
+    #[arg(long)]
+    list: bool,
+}
+
+/// Arguments for the bloat command
+#[derive(clap::Args, Debug, Clone)]
+struct BloatArgs {
+    /// Compiled binary to inspect (e.g. target/release/hug)
+    #[arg(value_name = "BINARY")]
+    binary: PathBuf,
+
+    /// Glob patterns for the source files symbols are mapped back to
+    #[arg(value_name = "GLOB")]
+    inputs: Vec<String>,
+
+    /// Number of sections, symbols and source files to show
+    #[arg(long, short = 'n', default_value_t = 20)]
+    top: usize,
+}
+
 /// Arguments for the completions command
 #[derive(clap::Args, Debug, Clone)]
 struct CompletionsArgs {
@@ -124,6 +177,10 @@ enum Command {
     Classes(ClassArgs),
     /// Run lint diagnostics on the file(s)
     Lint(LintArgs),
+    /// Fuzzy-find a symbol interactively and print or open its location
+    Find(FindArgs),
+    /// Show what takes up space in a compiled binary, mapped back to source files
+    Bloat(BloatArgs),
     /// Generate shell completions
     #[command(after_help = "\
 Examples:
@@ -153,6 +210,8 @@ impl Command {
             | Self::Imports(args) => &args.inputs,
             Self::Lint(args) => &args.inputs,
             Self::Classes(args) => &args.inputs,
+            Self::Find(args) => &args.inputs,
+            Self::Bloat(args) => &args.inputs,
             Self::Completions(_) => &[],
         }
     }
@@ -174,7 +233,7 @@ impl Command {
                 static_only: args.static_only,
                 instance_only: args.instance_only,
             }),
-            Self::Completions(_) => None,
+            Self::Completions(_) | Self::Find(_) | Self::Bloat(_) => None,
         }
     }
 }
@@ -250,6 +309,16 @@ impl OutputConfig {
     }
 }
 
+/// Global options shared by the commands that run outside the summary flow
+/// (`hug find` and `hug bloat`).
+struct RunContext<'a> {
+    language: Option<ProgrammingLanguage>,
+    jobs: Option<usize>,
+    format: OutputFormat,
+    config: &'a OutputConfig,
+    display_root: Option<&'a Path>,
+}
+
 /// Returns the color style for a symbol kind.
 fn style_for_kind(kind: SymbolKind) -> Style {
     match kind {
@@ -360,9 +429,29 @@ fn main() -> Result<(), TreeHuggerError> {
 
     let root_dir = current_dir()?;
     let display_root = find_repo_root(&root_dir);
+
+    let context = RunContext {
+        language,
+        jobs: cli.jobs,
+        format: output_format,
+        config: &output_config,
+        display_root: display_root.as_deref(),
+    };
+
+    if let Command::Bloat(args) = &cli.command {
+        return run_bloat(args, &root_dir, &cli.ignore, &context);
+    }
+
     let files = collect_files(&root_dir, inputs, &cli.ignore, language)?;
 
-    let command_kind = cli.command.kind().expect("completions already handled");
+    if let Command::Find(args) = &cli.command {
+        return run_find(args, &files, &context);
+    }
+
+    let command_kind = cli
+        .command
+        .kind()
+        .expect("completions, find and bloat already handled");
 
     // Handle classes command separately due to different output structure
     if let CommandKind::Classes {
@@ -371,21 +460,32 @@ fn main() -> Result<(), TreeHuggerError> {
         instance_only,
     } = &command_kind
     {
-        let mut all_class_summaries: Vec<(PathBuf, ProgrammingLanguage, Vec<ClassSummary>)> =
-            Vec::new();
-
-        for file in files {
-            let tree_file = TreeFile::with_language(&file, language)?;
-            let class_summaries = extract_class_summaries(
-                &tree_file,
-                name_filter.as_deref(),
-                *static_only,
-                *instance_only,
-            )?;
-            if !class_summaries.is_empty() {
-                all_class_summaries.push((tree_file.file.clone(), tree_file.language, class_summaries));
-            }
-        }
+        let all_class_summaries: Vec<(PathBuf, ProgrammingLanguage, Vec<ClassSummary>)> =
+            with_progress_bar(files.len(), cli.jobs, |options| {
+                analyze_files(&files, options, |file| {
+                    let tree_file = TreeFile::with_language(file, language)?;
+                    let mut class_summaries = extract_class_summaries(
+                        &tree_file,
+                        name_filter.as_deref(),
+                        *static_only,
+                        *instance_only,
+                    )?;
+                    if let Some(blame) = blame_for(file, cli.blame) {
+                        for summary in &mut class_summaries {
+                            let symbols = std::iter::once(&mut summary.class)
+                                .chain(summary.static_methods.iter_mut())
+                                .chain(summary.instance_methods.iter_mut());
+                            for symbol in symbols {
+                                symbol.blame = blame.for_range(&symbol.range).cloned();
+                            }
+                        }
+                    }
+                    Ok((tree_file.file.clone(), tree_file.language, class_summaries))
+                })
+            })?
+            .into_iter()
+            .filter(|(_, _, class_summaries)| !class_summaries.is_empty())
+            .collect();
 
         match output_format {
             OutputFormat::Json => {
@@ -407,12 +507,16 @@ fn main() -> Result<(), TreeHuggerError> {
         return Ok(());
     }
 
-    let mut summaries = Vec::new();
-    for file in files {
-        let tree_file = TreeFile::with_language(&file, language)?;
-        let summary = summarize_file(&tree_file, &command_kind)?;
-        summaries.push(summary);
-    }
+    let summaries = with_progress_bar(files.len(), cli.jobs, |options| {
+        analyze_files(&files, options, |file| {
+            let tree_file = TreeFile::with_language(file, language)?;
+            let mut summary = summarize_file(&tree_file, &command_kind)?;
+            if let Some(blame) = blame_for(file, cli.blame) {
+                blame.annotate(&mut summary);
+            }
+            Ok(summary)
+        })
+    })?;
 
     match output_format {
         OutputFormat::Json => {
@@ -443,6 +547,29 @@ fn main() -> Result<(), TreeHuggerError> {
     Ok(())
 }
 
+/// Blames `file` when `--blame` was passed.
+///
+/// Files git can't blame (untracked, or outside a repository) are left
+/// unannotated rather than failing the whole run.
+fn blame_for(file: &Path, enabled: bool) -> Option<FileBlame> {
+    if !enabled {
+        return None;
+    }
+    FileBlame::for_file(file).ok()
+}
+
+/// Formats blame as `author, 3 months ago`.
+fn format_blame(blame: &BlameInfo) -> String {
+    let age = match blame.age_days {
+        0 => "today".to_string(),
+        1 => "yesterday".to_string(),
+        days @ 2..=59 => format!("{days} days ago"),
+        days @ 60..=729 => format!("{} months ago", days / 30),
+        days => format!("{} years ago", days / 365),
+    };
+    format!("{}, {}", blame.author, age)
+}
+
 fn current_dir() -> Result<PathBuf, TreeHuggerError> {
     std::env::current_dir().map_err(|source| TreeHuggerError::Io {
         path: PathBuf::from("."),
@@ -450,6 +577,34 @@ fn current_dir() -> Result<PathBuf, TreeHuggerError> {
     })
 }
 
+/// Runs `analyze` with parallel options for `total` files and `jobs` threads,
+/// drawing a progress bar on stderr until it returns.
+fn with_progress_bar<T>(
+    total: usize,
+    jobs: Option<usize>,
+    analyze: impl FnOnce(&ParallelOptions) -> Result<T, TreeHuggerError>,
+) -> Result<T, TreeHuggerError> {
+    let bar = Arc::new(Mutex::new(ProgressBar::new(total).with_label("Analyzing")));
+    let mut options = ParallelOptions::default().with_progress({
+        let bar = Arc::clone(&bar);
+        move |progress| {
+            // Progress is cosmetic; a failed draw shouldn't fail the command
+            if let Ok(mut bar) = bar.lock() {
+                let _ = bar.update(progress.completed);
+            }
+        }
+    });
+    if let Some(jobs) = jobs {
+        options = options.with_jobs(jobs);
+    }
+
+    let result = analyze(&options);
+    if let Ok(mut bar) = bar.lock() {
+        let _ = bar.finish();
+    }
+    result
+}
+
 /// Prints shell completions to stdout.
 fn print_completions<G: Generator>(generator: G, cmd: &mut clap::Command) {
     clap_complete::generate(generator, cmd, cmd.get_name().to_string(), &mut std::io::stdout());
@@ -510,6 +665,342 @@ fn collect_files(
     Ok(files)
 }
 
+/// Runs `hug find`: fuzzy-picks a symbol and prints or opens its location.
+///
+/// Falls back to printing ranked matches when `--list`/`--json` is given or
+/// stdin/stderr is not a terminal.
+fn run_find(
+    args: &FindArgs,
+    files: &[PathBuf],
+    context: &RunContext<'_>,
+) -> Result<(), TreeHuggerError> {
+    let RunContext {
+        language,
+        jobs,
+        format,
+        config,
+        display_root,
+    } = *context;
+    let symbols = with_progress_bar(files.len(), jobs, |options| {
+        analyze_files(files, options, |file| {
+            // Without globs every file is collected; skip the ones we can't parse
+            if language.is_none() && ProgrammingLanguage::from_path(file).is_none() {
+                return Ok(Vec::new());
+            }
+
+            TreeFile::with_language(file, language)?.symbols()
+        })
+    })?;
+
+    let mut items = Vec::new();
+    for symbol in symbols.into_iter().flatten() {
+        if symbol.kind == SymbolKind::Parameter {
+            continue;
+        }
+        let detail = format!(
+            "{}  {}:{}",
+            symbol.kind,
+            display_path(&symbol.file, display_root),
+            symbol.range.start_line
+        );
+        items.push(PickerItem::new(symbol.name.clone(), symbol).with_detail(detail));
+    }
+
+    let picker = Picker::new(items)
+        .with_prompt("find> ")
+        .with_query(args.query.as_str());
+
+    let interactive = !args.list
+        && !matches!(format, OutputFormat::Json)
+        && std::io::stdin().is_terminal()
+        && std::io::stderr().is_terminal();
+
+    if !interactive {
+        let matches: Vec<&SymbolInfo> = picker
+            .matches(&args.query)
+            .into_iter()
+            .map(|m| &picker.items()[m.index].value)
+            .collect();
+
+        if matches!(format, OutputFormat::Json) {
+            let json = serde_json::to_string_pretty(&matches).map_err(|source| {
+                TreeHuggerError::Io {
+                    path: PathBuf::from("<stdout>"),
+                    source: std::io::Error::other(source),
+                }
+            })?;
+            println!("{json}");
+        } else {
+            for symbol in matches {
+                render_found_symbol(symbol, config, display_root);
+            }
+        }
+        return Ok(());
+    }
+
+    let selection = picker.run().map_err(|source| TreeHuggerError::Io {
+        path: PathBuf::from("<tty>"),
+        source: std::io::Error::other(source),
+    })?;
+
+    match selection {
+        Some(symbol) if args.edit => open_in_editor(&symbol.file, symbol.range.start_line),
+        Some(symbol) => {
+            render_found_symbol(&symbol, config, display_root);
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+/// JSON output of `hug bloat`.
+#[derive(Serialize)]
+struct BloatOutput<'a> {
+    binary: &'a Path,
+    file_size: u64,
+    /// Combined size of all sized symbols
+    symbols_size: u64,
+    /// Combined size of the symbols mapped to a source file
+    attributed_size: u64,
+    sections: &'a [SectionSize],
+    symbols: &'a [AttributedSymbol],
+    files: &'a [SourceFileSize],
+}
+
+/// Runs `hug bloat`: reports the largest sections and symbols of a binary.
+///
+/// Symbols are mapped back to source files through a [`SymbolIndex`] of the
+/// Rust sources (or `--language` sources) under the current directory, so
+/// the report also shows which files drive the binary's size.
+fn run_bloat(
+    args: &BloatArgs,
+    root_dir: &Path,
+    ignores: &[String],
+    context: &RunContext<'_>,
+) -> Result<(), TreeHuggerError> {
+    let RunContext {
+        language,
+        jobs,
+        format,
+        config,
+        display_root,
+    } = *context;
+    let report = analyze_binary(&args.binary)?;
+
+    let language = language.unwrap_or(ProgrammingLanguage::Rust);
+    let index = match collect_files(root_dir, &args.inputs, ignores, Some(language)) {
+        Ok(files) => with_progress_bar(files.len(), jobs, |options| {
+            SymbolIndex::build_with(&files, Some(language), options)
+        })?,
+        // Still report sizes when run outside the sources
+        Err(TreeHuggerError::NoSourceFiles { .. }) => SymbolIndex::default(),
+        Err(err) => return Err(err),
+    };
+
+    let symbols = attribute_symbols(&report, &index);
+    let files = sizes_by_file(&symbols);
+    let attributed_size: u64 = files.iter().map(|file| file.size).sum();
+
+    let top = |len: usize| len.min(args.top);
+    let sections = &report.sections[..top(report.sections.len())];
+    let largest = &symbols[..top(symbols.len())];
+    let largest_files = &files[..top(files.len())];
+
+    if matches!(format, OutputFormat::Json) {
+        let output = BloatOutput {
+            binary: &report.path,
+            file_size: report.file_size,
+            symbols_size: report.symbols_size(),
+            attributed_size,
+            sections,
+            symbols: largest,
+            files: largest_files,
+        };
+        let json = serde_json::to_string_pretty(&output).map_err(|source| TreeHuggerError::Io {
+            path: PathBuf::from("<stdout>"),
+            source: std::io::Error::other(source),
+        })?;
+        println!("{json}");
+        return Ok(());
+    }
+
+    let binary_display = display_path(&report.path, display_root);
+    if config.use_colors {
+        println!(
+            "{} ({})",
+            binary_display.bold(),
+            format_size(report.file_size).dimmed()
+        );
+    } else {
+        println!("{} ({})", binary_display, format_size(report.file_size));
+    }
+
+    render_bloat_heading("Sections", config);
+    for section in sections {
+        let share = section.size as f64 * 100.0 / report.file_size.max(1) as f64;
+        println!(
+            "  {:>10}  {:>5.1}%  {}",
+            format_size(section.size),
+            share,
+            section.name
+        );
+    }
+
+    render_bloat_heading("Largest symbols", config);
+    if largest.is_empty() {
+        println!("  (no symbol table; the binary may be stripped)");
+    }
+    for attributed in largest {
+        let size = format!("{:>10}", format_size(attributed.symbol.size));
+        let name = truncate_name(&attributed.symbol.name, 100);
+        let location = attributed.source.as_ref().map(|source| {
+            let text = format!(
+                "{}:{}",
+                display_path(&source.file, display_root),
+                source.line
+            );
+            if config.use_hyperlinks {
+                hyperlink(&source.file, source.line, &text)
+            } else {
+                text
+            }
+        });
+
+        match (config.use_colors, location) {
+            (true, Some(location)) => {
+                println!("  {}  {} {}", size.yellow(), name, location.dimmed())
+            }
+            (true, None) => println!("  {}  {}", size.yellow(), name.dimmed()),
+            (false, Some(location)) => println!("  {size}  {name} {location}"),
+            (false, None) => println!("  {size}  {name}"),
+        }
+    }
+
+    if !largest_files.is_empty() {
+        render_bloat_heading("Largest source files", config);
+        for file in largest_files {
+            let size = format!("{:>10}", format_size(file.size));
+            let path = display_path(&file.file, display_root);
+            let count = format!("({} symbols)", file.symbols);
+            if config.use_colors {
+                let path = if config.use_hyperlinks {
+                    hyperlink(&file.file, 1, &path)
+                } else {
+                    path
+                };
+                println!("  {}  {} {}", size.yellow(), path, count.dimmed());
+            } else {
+                println!("  {size}  {path} {count}");
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{} of {} in symbols mapped to {} source files",
+        format_size(attributed_size),
+        format_size(report.symbols_size()),
+        files.len()
+    );
+
+    Ok(())
+}
+
+/// Prints a section heading for `hug bloat` output.
+fn render_bloat_heading(title: &str, config: &OutputConfig) {
+    println!();
+    if config.use_colors {
+        println!("{}", title.bold());
+    } else {
+        println!("{title}");
+    }
+}
+
+/// Formats a byte count with binary units (e.g. `1.5 MiB`).
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Shortens long (mostly generic) symbol names to `max` characters.
+fn truncate_name(name: &str, max: usize) -> String {
+    if name.chars().count() <= max {
+        return name.to_string();
+    }
+    let truncated: String = name.chars().take(max.saturating_sub(1)).collect();
+    format!("{truncated}…")
+}
+
+/// Prints a symbol picked by `hug find` as `kind name path:line:column`.
+///
+/// Plain output prints only the location so it can be passed to other tools.
+fn render_found_symbol(symbol: &SymbolInfo, config: &OutputConfig, display_root: Option<&Path>) {
+    let location = format!(
+        "{}:{}:{}",
+        display_path(&symbol.file, display_root),
+        symbol.range.start_line,
+        symbol.range.start_column
+    );
+
+    if !config.use_colors {
+        println!("{location}");
+        return;
+    }
+
+    let location_display = if config.use_hyperlinks {
+        hyperlink(&symbol.file, symbol.range.start_line, &location)
+    } else {
+        location
+    };
+    println!(
+        "{} {} {}",
+        symbol.kind.to_string().style(style_for_kind(symbol.kind)),
+        symbol.name.bold(),
+        location_display.dimmed()
+    );
+}
+
+/// Opens a file at a line with `$VISUAL`, `$EDITOR`, or `vi`, as `<editor> +<line> <file>`.
+fn open_in_editor(path: &Path, line: usize) -> Result<(), TreeHuggerError> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    // Editors are often configured with arguments (e.g., `code --wait`)
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(format!("+{line}"))
+        .arg(path)
+        .status()
+        .map_err(|source| TreeHuggerError::Io {
+            path: PathBuf::from(program),
+            source,
+        })?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(TreeHuggerError::Io {
+            path: PathBuf::from(program),
+            source: std::io::Error::other(format!("editor exited with {status}")),
+        })
+    }
+}
+
 fn summarize_file(
     tree_file: &TreeFile,
     command: &CommandKind,
@@ -634,6 +1125,12 @@ fn render_symbols(symbols: &[SymbolInfo], config: &OutputConfig) {
         // Format symbol name with signature for functions/methods
         let name_with_sig = format_symbol_name(symbol, symbol.language);
 
+        let blame_suffix = symbol
+            .blame
+            .as_ref()
+            .map(|blame| format!(" ({})", format_blame(blame)))
+            .unwrap_or_default();
+
         // Extract visibility for functions/methods
         let visibility = symbol
             .signature
@@ -654,10 +1151,11 @@ fn render_symbols(symbols: &[SymbolInfo], config: &OutputConfig) {
             };
 
             println!(
-                "  - {} {} {}",
+                "  - {} {} {}{}",
                 kind_part,
                 name_with_sig.bold(),
-                location_display.dimmed()
+                location_display.dimmed(),
+                blame_suffix.dimmed()
             );
         } else {
             // Plain text: visibility + kind + name
@@ -665,7 +1163,10 @@ fn render_symbols(symbols: &[SymbolInfo], config: &OutputConfig) {
                 Some(vis) => format!("{} {}", vis, symbol.kind),
                 None => symbol.kind.to_string(),
             };
-            println!("  - {} {} {}", kind_part, name_with_sig, location_display);
+            println!(
+                "  - {} {} {}{}",
+                kind_part, name_with_sig, location_display, blame_suffix
+            );
         }
     }
 }
@@ -872,6 +1373,15 @@ fn style_for_severity(severity: DiagnosticSeverity) -> Style {
     }
 }
 
+/// Returns the shared status icon for a diagnostic severity.
+fn icon_for_severity(severity: DiagnosticSeverity) -> &'static Icon {
+    match severity {
+        DiagnosticSeverity::Error => &symbols::FAILURE,
+        DiagnosticSeverity::Warning => &symbols::WARNING,
+        DiagnosticSeverity::Info => &symbols::INFO,
+    }
+}
+
 /// Renders the source context with underline marker.
 fn render_source_context(context: &SourceContext, line_number: usize, config: &OutputConfig) {
     let line_num_width = line_number.to_string().len().max(4);
@@ -1011,20 +1521,47 @@ fn render_unified_diagnostic(diagnostic: &Diagnostic, file: &Path, config: &Outp
             DiagnosticKind::Semantic => Style::new().magenta(),
             DiagnosticKind::Syntax => Style::new().red(),
         };
+        // The ASCII fallbacks duplicate the severity label, so only show
+        // the icon when the terminal can render a real glyph.
+        let icon_style = IconStyle::current();
+        let icon = match icon_style {
+            IconStyle::Ascii => String::new(),
+            _ => format!(
+                "{} ",
+                icon_for_severity(diagnostic.severity)
+                    .glyph(icon_style)
+                    .style(severity_style)
+            ),
+        };
         println!(
-            "{} {}{}: {}",
+            "{}{} {}{}: {}",
+            icon,
             kind_label.style(kind_style),
             severity_label.style(severity_style),
             rule_display.dimmed(),
             diagnostic.message
         );
         println!("  {} {}", "-->".blue(), location_display);
+        if let Some(blame) = &diagnostic.blame {
+            println!(
+                "  {} {}",
+                "blame:".blue(),
+                format!("{} ({})", format_blame(blame), blame.short_commit()).dimmed()
+            );
+        }
     } else {
         println!(
             "{} {}{}: {}",
             kind_label, severity_label, rule_display, diagnostic.message
         );
         println!("  --> {}", location_display);
+        if let Some(blame) = &diagnostic.blame {
+            println!(
+                "  blame: {} ({})",
+                format_blame(blame),
+                blame.short_commit()
+            );
+        }
     }
 
     // Render source context if available
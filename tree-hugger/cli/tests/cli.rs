@@ -491,3 +491,129 @@ fn test_imports_rust_grouped_output() {
             "- use std::process::{Child, Command, Stdio}",
         ));
 }
+
+// ============================================================================
+// find command
+// ============================================================================
+
+#[test]
+fn test_find_lists_ranked_matches_when_not_a_tty() {
+    hug_cmd()
+        .args([
+            "find",
+            "grtmany",
+            "tree-hugger/lib/tests/fixtures/sample.rs",
+            "--plain",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "tree-hugger/lib/tests/fixtures/sample.rs:16:8\n",
+        ));
+}
+
+#[test]
+fn test_find_searches_kind_and_file() {
+    hug_cmd()
+        .args([
+            "find",
+            "greet function sample.rs",
+            "tree-hugger/lib/tests/fixtures/sample.rs",
+            "tree-hugger/lib/tests/fixtures/sample.ts",
+            "--list",
+            "--plain",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("sample.rs:8:"))
+        .stdout(predicate::str::contains("sample.ts").not());
+}
+
+#[test]
+fn test_find_json_output() {
+    hug_cmd()
+        .args([
+            "find",
+            "greet_many",
+            "tree-hugger/lib/tests/fixtures/sample.rs",
+            "--json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("["))
+        .stdout(predicate::str::contains("\"name\": \"greet_many\""));
+}
+
+#[test]
+fn test_find_edit_conflicts_with_list() {
+    hug_cmd()
+        .args(["find", "greet", "--edit", "--list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+// ============================================================================
+// bloat command
+// ============================================================================
+
+#[test]
+fn test_bloat_reports_sections_and_symbols() {
+    hug_cmd()
+        .args(["bloat", env!("CARGO_BIN_EXE_hug"), "--plain", "-n", "5"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Sections"))
+        .stdout(predicate::str::contains("Largest symbols"))
+        .stdout(predicate::str::contains("in symbols mapped to"));
+}
+
+#[test]
+fn test_bloat_maps_symbols_to_cli_sources() {
+    hug_cmd()
+        .args([
+            "bloat",
+            env!("CARGO_BIN_EXE_hug"),
+            "tree-hugger/cli/src/**",
+            "--json",
+            "-n",
+            "1000",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"attributed_size\""))
+        .stdout(predicate::str::contains("tree-hugger/cli/src/main.rs"));
+}
+
+#[test]
+fn test_bloat_rejects_non_binary() {
+    hug_cmd()
+        .args(["bloat", "tree-hugger/lib/tests/fixtures/sample.rs"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("BinaryParse"));
+}
+
+// ============================================================================
+// --jobs flag
+// ============================================================================
+
+#[test]
+fn test_jobs_flag_keeps_output_deterministic() {
+    let run = |jobs: &str| {
+        let output = hug_cmd()
+            .args([
+                "symbols",
+                "tree-hugger/lib/tests/fixtures/sample.*",
+                "--json",
+                "--jobs",
+                jobs,
+            ])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        output.stdout
+    };
+
+    assert_eq!(run("1"), run("8"));
+}
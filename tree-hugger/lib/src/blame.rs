@@ -0,0 +1,283 @@
+//! Git blame enrichment for symbols and diagnostics.
+//!
+//! [`FileBlame::for_file`] runs `git blame --porcelain` on a file and records
+//! who last touched each line. [`FileBlame::for_range`] then picks the most
+//! recent commit within a symbol's or diagnostic's range, and
+//! [`FileBlame::annotate`] fills in the `blame` field of everything in a
+//! [`FileSummary`], so lint reports can be grouped by owner or age.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::TreeHuggerError;
+use crate::shared::{CodeRange, FileSummary};
+
+/// The hash git blame reports for lines that are not committed yet.
+const UNCOMMITTED: &str = "0000000000000000000000000000000000000000";
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// The commit that last modified a line (or range of lines).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlameInfo {
+    /// Full hash of the commit.
+    pub commit: String,
+    /// Author name of the commit.
+    pub author: String,
+    /// Author email, without the surrounding `<>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+    /// When the commit was authored, in seconds since the Unix epoch.
+    pub timestamp: i64,
+    /// Whole days between `timestamp` and when the blame was taken.
+    pub age_days: u64,
+}
+
+impl BlameInfo {
+    /// The first seven characters of the commit hash.
+    pub fn short_commit(&self) -> &str {
+        &self.commit[..self.commit.len().min(7)]
+    }
+}
+
+/// Per-line blame for one file.
+#[derive(Debug, Clone, Default)]
+pub struct FileBlame {
+    commits: HashMap<String, BlameInfo>,
+    /// Commit hash for each line (index 0 is line 1); `None` when uncommitted.
+    lines: Vec<Option<String>>,
+}
+
+impl FileBlame {
+    /// Blames `path` with the `git` executable on the `PATH`.
+    ///
+    /// ## Errors
+    /// Returns an error if `git` cannot be run, or if git fails (e.g. the
+    /// file is untracked or not inside a repository).
+    pub fn for_file<P: AsRef<Path>>(path: P) -> Result<Self, TreeHuggerError> {
+        let path = path.as_ref();
+        let dir = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        let file_name = path.file_name().unwrap_or(path.as_os_str());
+
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["blame", "--porcelain", "--"])
+            .arg(file_name)
+            .output()
+            .map_err(|source| TreeHuggerError::Io {
+                path: path.to_path_buf(),
+                source,
+            })?;
+
+        if !output.status.success() {
+            return Err(TreeHuggerError::GitBlame {
+                path: path.to_path_buf(),
+                message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as i64)
+            .unwrap_or_default();
+
+        Ok(Self::parse_porcelain(
+            &String::from_utf8_lossy(&output.stdout),
+            now,
+        ))
+    }
+
+    /// Parses the output of `git blame --porcelain`.
+    ///
+    /// `now` (seconds since the Unix epoch) is used to compute
+    /// [`BlameInfo::age_days`].
+    pub fn parse_porcelain(output: &str, now: i64) -> Self {
+        let mut blame = Self::default();
+        let mut current: Option<(String, usize)> = None;
+
+        for line in output.lines() {
+            if line.starts_with('\t') {
+                // The line's content ends the entry.
+                if let Some((commit, final_line)) = current.take() {
+                    if blame.lines.len() < final_line {
+                        blame.lines.resize(final_line, None);
+                    }
+                    if commit != UNCOMMITTED {
+                        blame.lines[final_line - 1] = Some(commit);
+                    }
+                }
+                continue;
+            }
+
+            let Some((commit, _)) = &current else {
+                // Header: `<commit> <original line> <final line> [<group size>]`
+                let mut parts = line.split(' ');
+                let commit = parts.next().unwrap_or_default();
+                let final_line = parts.nth(1).and_then(|n| n.parse::<usize>().ok());
+                if let Some(final_line) = final_line.filter(|n| *n > 0) {
+                    blame
+                        .commits
+                        .entry(commit.to_string())
+                        .or_insert_with(|| BlameInfo {
+                            commit: commit.to_string(),
+                            author: String::new(),
+                            author_email: None,
+                            timestamp: 0,
+                            age_days: 0,
+                        });
+                    current = Some((commit.to_string(), final_line));
+                }
+                continue;
+            };
+
+            let Some(info) = blame.commits.get_mut(commit) else {
+                continue;
+            };
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            match key {
+                "author" => info.author = value.to_string(),
+                "author-mail" => {
+                    let email = value.trim_start_matches('<').trim_end_matches('>');
+                    info.author_email = (!email.is_empty()).then(|| email.to_string());
+                }
+                "author-time" => {
+                    info.timestamp = value.parse().unwrap_or_default();
+                    info.age_days = (now - info.timestamp).max(0) as u64 / SECONDS_PER_DAY as u64;
+                }
+                _ => {}
+            }
+        }
+
+        blame.commits.remove(UNCOMMITTED);
+        blame
+    }
+
+    /// Blame for a single line (1-based).
+    pub fn line(&self, line: usize) -> Option<&BlameInfo> {
+        let commit = self.lines.get(line.checked_sub(1)?)?.as_ref()?;
+        self.commits.get(commit)
+    }
+
+    /// The most recent commit touching any line in `range`.
+    ///
+    /// Returns `None` when every line in the range is uncommitted.
+    pub fn for_range(&self, range: &CodeRange) -> Option<&BlameInfo> {
+        (range.start_line..=range.end_line.max(range.start_line))
+            .filter_map(|line| self.line(line))
+            .max_by_key(|info| info.timestamp)
+    }
+
+    /// Fills in the `blame` field of every symbol and diagnostic in `summary`.
+    pub fn annotate(&self, summary: &mut FileSummary) {
+        let symbols = summary
+            .symbols
+            .iter_mut()
+            .chain(summary.exports.iter_mut())
+            .chain(summary.locals.iter_mut());
+        for symbol in symbols {
+            symbol.blame = self.for_range(&symbol.range).cloned();
+        }
+        for diagnostic in &mut summary.lint {
+            diagnostic.blame = self.for_range(&diagnostic.range).cloned();
+        }
+        for diagnostic in &mut summary.syntax {
+            diagnostic.blame = self.for_range(&diagnostic.range).cloned();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000 + 10 * SECONDS_PER_DAY;
+
+    const PORCELAIN: &str = "\
+1111111111111111111111111111111111111111 1 1 2
+author Ada Lovelace
+author-mail <ada@example.com>
+author-time 1700000000
+author-tz +0000
+summary first
+filename src/lib.rs
+\tfn one() {}
+1111111111111111111111111111111111111111 2 2
+filename src/lib.rs
+\tfn two() {}
+2222222222222222222222222222222222222222 3 3 1
+author Grace Hopper
+author-mail <grace@example.com>
+author-time 1700500000
+author-tz +0000
+summary second
+filename src/lib.rs
+\tfn three() {}
+0000000000000000000000000000000000000000 4 4 1
+author Not Committed Yet
+author-mail <not.committed.yet>
+author-time 1700800000
+author-tz +0000
+summary Version of src/lib.rs from src/lib.rs
+filename src/lib.rs
+\tfn four() {}
+";
+
+    fn range(start_line: usize, end_line: usize) -> CodeRange {
+        CodeRange {
+            start_line,
+            start_column: 1,
+            end_line,
+            end_column: 1,
+            start_byte: 0,
+            end_byte: 0,
+        }
+    }
+
+    #[test]
+    fn parses_commits_per_line() {
+        let blame = FileBlame::parse_porcelain(PORCELAIN, NOW);
+
+        let first = blame.line(1).expect("line 1 is committed");
+        assert_eq!(first.author, "Ada Lovelace");
+        assert_eq!(first.author_email.as_deref(), Some("ada@example.com"));
+        assert_eq!(first.age_days, 10);
+        assert_eq!(first.short_commit(), "1111111");
+        assert_eq!(blame.line(2), Some(first));
+        assert_eq!(
+            blame.line(3).map(|info| info.author.as_str()),
+            Some("Grace Hopper")
+        );
+    }
+
+    #[test]
+    fn uncommitted_and_missing_lines_have_no_blame() {
+        let blame = FileBlame::parse_porcelain(PORCELAIN, NOW);
+
+        assert!(blame.line(0).is_none());
+        assert!(blame.line(4).is_none());
+        assert!(blame.line(5).is_none());
+        assert!(blame.for_range(&range(4, 4)).is_none());
+    }
+
+    #[test]
+    fn range_uses_most_recent_commit() {
+        let blame = FileBlame::parse_porcelain(PORCELAIN, NOW);
+
+        let info = blame.for_range(&range(1, 4)).expect("range has commits");
+        assert_eq!(info.author, "Grace Hopper");
+        assert_eq!(
+            blame
+                .for_range(&range(2, 1))
+                .map(|info| info.author.as_str()),
+            Some("Ada Lovelace")
+        );
+    }
+}
@@ -0,0 +1,362 @@
+//! Binary size analysis for compiled artifacts.
+//!
+//! Reads the section and symbol tables of a compiled binary (ELF, Mach-O,
+//! PE, ...), demangles Rust symbol names, and maps the largest symbols back
+//! to the source files that define them through a [`SymbolIndex`].
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use object::{BinaryFormat, Object, ObjectSection, ObjectSymbol, SectionIndex};
+use serde::{Deserialize, Serialize};
+
+use crate::error::TreeHuggerError;
+use crate::package::symbol_index::SymbolIndex;
+
+/// The size of one section in a binary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionSize {
+    /// Section name (e.g. `.text`, `__TEXT,__text`).
+    pub name: String,
+    /// Size in bytes.
+    pub size: u64,
+}
+
+/// A code or data symbol found in a binary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BinarySymbol {
+    /// Demangled name, without the Rust hash suffix.
+    pub name: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// Name of the section holding the symbol.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+}
+
+/// Sections and symbols of a binary, each sorted largest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BinaryReport {
+    /// Path of the analyzed binary.
+    pub path: PathBuf,
+    /// Size of the binary on disk in bytes.
+    pub file_size: u64,
+    /// Non-empty sections.
+    pub sections: Vec<SectionSize>,
+    /// Code and data symbols with a known size. Empty for stripped binaries.
+    pub symbols: Vec<BinarySymbol>,
+}
+
+impl BinaryReport {
+    /// Returns the combined size of all symbols.
+    pub fn symbols_size(&self) -> u64 {
+        self.symbols.iter().map(|symbol| symbol.size).sum()
+    }
+}
+
+/// Where a binary symbol is defined in the source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolSource {
+    /// Source file defining the symbol.
+    pub file: PathBuf,
+    /// Line of the definition (1-based).
+    pub line: usize,
+}
+
+/// A binary symbol with the source location it was mapped to, if any.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttributedSymbol {
+    #[serde(flatten)]
+    pub symbol: BinarySymbol,
+    /// Defining source location; `None` for code from outside the indexed
+    /// sources (the standard library, dependencies, compiler glue).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<SymbolSource>,
+}
+
+/// The binary size attributed to one source file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceFileSize {
+    pub file: PathBuf,
+    /// Combined size of the file's symbols in bytes.
+    pub size: u64,
+    /// Number of binary symbols mapped to the file.
+    pub symbols: usize,
+}
+
+/// Reads the section and symbol sizes of a compiled binary.
+///
+/// Symbol sizes come from the symbol table; Mach-O, which doesn't record
+/// them, falls back to the distance to the next symbol in the section.
+///
+/// ## Errors
+/// Returns an error if the file cannot be read or is not a supported object format.
+pub fn analyze_binary<P: AsRef<Path>>(path: P) -> Result<BinaryReport, TreeHuggerError> {
+    let path = path.as_ref();
+    let data = std::fs::read(path).map_err(|source| TreeHuggerError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let file = object::File::parse(&*data).map_err(|source| TreeHuggerError::BinaryParse {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let mut sections: Vec<SectionSize> = file
+        .sections()
+        .filter(|section| section.size() > 0)
+        .map(|section| SectionSize {
+            name: section.name().unwrap_or("<unknown>").to_string(),
+            size: section.size(),
+        })
+        .collect();
+    sections.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+
+    Ok(BinaryReport {
+        path: path.to_path_buf(),
+        file_size: data.len() as u64,
+        sections,
+        symbols: read_symbols(&file),
+    })
+}
+
+/// Collects sized text and data symbols, demangled and sorted largest first.
+fn read_symbols(file: &object::File<'_>) -> Vec<BinarySymbol> {
+    struct RawSymbol<'data> {
+        name: &'data str,
+        address: u64,
+        size: u64,
+        section: Option<SectionIndex>,
+    }
+
+    let mut raw: Vec<RawSymbol<'_>> = file
+        .symbols()
+        .filter(|symbol| {
+            matches!(
+                symbol.kind(),
+                object::SymbolKind::Text | object::SymbolKind::Data
+            ) && symbol.is_definition()
+        })
+        .filter_map(|symbol| {
+            Some(RawSymbol {
+                name: symbol.name().ok().filter(|name| !name.is_empty())?,
+                address: symbol.address(),
+                size: symbol.size(),
+                section: symbol.section_index(),
+            })
+        })
+        .collect();
+
+    // Aliases share an address; count each address once
+    raw.sort_by_key(|symbol| (symbol.section.map(|index| index.0), symbol.address));
+    raw.dedup_by(|b, a| a.section == b.section && a.address == b.address);
+
+    // Mach-O records no symbol sizes: a symbol runs until the next one (or
+    // the end of its section). Elsewhere zero-size symbols are markers.
+    let infer_sizes = file.format() == BinaryFormat::MachO;
+    for i in 0..raw.len() {
+        if !infer_sizes || raw[i].size > 0 {
+            continue;
+        }
+        let end = match raw.get(i + 1) {
+            Some(next) if next.section == raw[i].section => Some(next.address),
+            _ => raw[i]
+                .section
+                .and_then(|index| file.section_by_index(index).ok())
+                .map(|section| section.address() + section.size()),
+        };
+        if let Some(end) = end {
+            raw[i].size = end.saturating_sub(raw[i].address);
+        }
+    }
+
+    let section_names: HashMap<SectionIndex, String> = file
+        .sections()
+        .filter_map(|section| Some((section.index(), section.name().ok()?.to_string())))
+        .collect();
+    let mut symbols: Vec<BinarySymbol> = raw
+        .into_iter()
+        .filter(|symbol| symbol.size > 0)
+        .map(|symbol| BinarySymbol {
+            name: demangle(symbol.name),
+            size: symbol.size,
+            section: symbol
+                .section
+                .and_then(|index| section_names.get(&index).cloned()),
+        })
+        .collect();
+
+    symbols.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+    symbols
+}
+
+/// Demangles a Rust symbol name without its hash; other names are returned as-is.
+pub fn demangle(name: &str) -> String {
+    match rustc_demangle::try_demangle(name) {
+        Ok(demangled) => format!("{demangled:#}"),
+        Err(_) => name.to_string(),
+    }
+}
+
+/// Splits a demangled symbol name into the path segments naming its definition.
+///
+/// Generic arguments, closure and shim segments are dropped, and trait impls
+/// resolve to the implementing type, so
+/// `<my_crate::Parser as core::fmt::Debug>::fmt` becomes
+/// `["my_crate", "Parser", "fmt"]` and
+/// `my_crate::run::<String>::{{closure}}` becomes `["my_crate", "run"]`.
+pub fn symbol_path(name: &str) -> Vec<String> {
+    let name = name.trim();
+    let qualified = match name.strip_prefix('<').and_then(|rest| {
+        let close = matching_angle(rest)?;
+        Some((&rest[..close], &rest[close + 1..]))
+    }) {
+        Some((inner, rest)) => {
+            let self_type = inner.split(" as ").next().unwrap_or(inner);
+            let self_type = self_type
+                .trim_start_matches(['&', '*'])
+                .trim_start_matches("mut ")
+                .trim_start_matches("const ")
+                .trim_start_matches("dyn ");
+            format!("{self_type}{rest}")
+        }
+        None => name.to_string(),
+    };
+
+    strip_generics(&qualified)
+        .split("::")
+        .map(str::trim)
+        .filter(|segment| {
+            !segment.is_empty() && !segment.starts_with('{') && !segment.starts_with('[')
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// Returns the index of the `>` closing an already-opened `<` in `text`.
+fn matching_angle(text: &str) -> Option<usize> {
+    let mut depth = 1usize;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Removes every `<...>` group (generic arguments) from a path.
+fn strip_generics(path: &str) -> String {
+    let mut result = String::with_capacity(path.len());
+    let mut depth = 0usize;
+    for ch in path.chars() {
+        match ch {
+            '<' => depth += 1,
+            '>' if depth > 0 => depth -= 1,
+            _ if depth == 0 => result.push(ch),
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Maps each symbol in the report to its defining source location.
+///
+/// Symbols are resolved by their [`symbol_path`] through the index; those
+/// with no matching definition keep a `None` source.
+pub fn attribute_symbols(report: &BinaryReport, index: &SymbolIndex) -> Vec<AttributedSymbol> {
+    report
+        .symbols
+        .iter()
+        .map(|symbol| AttributedSymbol {
+            source: index
+                .resolve(&symbol_path(&symbol.name))
+                .map(|definition| SymbolSource {
+                    file: definition.file.clone(),
+                    line: definition.range.start_line,
+                }),
+            symbol: symbol.clone(),
+        })
+        .collect()
+}
+
+/// Totals attributed symbol sizes per source file, largest first.
+pub fn sizes_by_file(symbols: &[AttributedSymbol]) -> Vec<SourceFileSize> {
+    let mut totals: HashMap<&Path, (u64, usize)> = HashMap::new();
+    for attributed in symbols {
+        if let Some(source) = &attributed.source {
+            let entry = totals.entry(source.file.as_path()).or_default();
+            entry.0 += attributed.symbol.size;
+            entry.1 += 1;
+        }
+    }
+
+    let mut files: Vec<SourceFileSize> = totals
+        .into_iter()
+        .map(|(file, (size, symbols))| SourceFileSize {
+            file: file.to_path_buf(),
+            size,
+            symbols,
+        })
+        .collect();
+    files.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.file.cmp(&b.file)));
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbol_path_splits_plain_paths() {
+        assert_eq!(
+            symbol_path("tree_hugger_lib::file::tree_file::TreeFile::symbols"),
+            vec![
+                "tree_hugger_lib",
+                "file",
+                "tree_file",
+                "TreeFile",
+                "symbols"
+            ]
+        );
+    }
+
+    #[test]
+    fn symbol_path_resolves_trait_impls_to_self_type() {
+        assert_eq!(
+            symbol_path("<my_crate::Parser as core::fmt::Debug>::fmt"),
+            vec!["my_crate", "Parser", "fmt"]
+        );
+        assert_eq!(
+            symbol_path("<&mut my_crate::Writer<W> as std::io::Write>::write"),
+            vec!["my_crate", "Writer", "write"]
+        );
+    }
+
+    #[test]
+    fn symbol_path_drops_generics_and_closures() {
+        assert_eq!(
+            symbol_path("my_crate::run::<alloc::string::String>::{{closure}}"),
+            vec!["my_crate", "run"]
+        );
+        assert_eq!(
+            symbol_path("core::ptr::drop_in_place<my_crate::Config>"),
+            vec!["core", "ptr", "drop_in_place"]
+        );
+    }
+
+    #[test]
+    fn demangle_strips_hash_and_keeps_foreign_names() {
+        assert_eq!(
+            demangle("_ZN8my_crate3run17h0123456789abcdefE"),
+            "my_crate::run"
+        );
+        assert_eq!(demangle("main"), "main");
+    }
+}
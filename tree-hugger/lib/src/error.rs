@@ -44,9 +44,22 @@ pub enum TreeHuggerError {
     #[error("Directory `{path}` is not inside a git repository")]
     GitRootNotFound { path: PathBuf },
 
+    #[error("git blame failed for `{path}`: {message}")]
+    GitBlame { path: PathBuf, message: String },
+
     #[error("No supported source files found in `{path}`")]
     NoSourceFiles { path: PathBuf },
 
     #[error("Ignore error: {0}")]
     Ignore(#[from] ignore::Error),
+
+    #[error("Failed to parse binary `{path}`: {source}")]
+    BinaryParse {
+        path: PathBuf,
+        #[source]
+        source: object::read::Error,
+    },
+
+    #[error("Failed to start worker threads: {0}")]
+    ThreadPool(#[from] rayon::ThreadPoolBuildError),
 }
@@ -6,7 +6,7 @@ use tree_sitter::{Node, Parser, QueryCursor, StreamingIterator};
 use crate::error::TreeHuggerError;
 use crate::queries::{QueryKind, format_rule_message, query_for, severity_for_rule};
 use crate::shared::{
-    CodeBlock, CodeRange, Diagnostic, DiagnosticSeverity, FieldInfo, FunctionSignature,
+    CodeBlock, CodeRange, Diagnostic, DiagnosticSeverity, DocComment, FieldInfo, FunctionSignature,
     ImportSymbol, LintDiagnostic, ParameterInfo, ProgrammingLanguage, ReferencedSymbol,
     SourceContext, SymbolInfo, SymbolKind, SyntaxDiagnostic, TypeMetadata, VariantInfo, Visibility,
 };
@@ -942,6 +942,7 @@ impl TreeFile {
                         severity: severity_for_rule(rule_id),
                         rule: Some(rule_id.to_string()),
                         context: Some(context),
+                        blame: None,
                     });
                 }
             }
@@ -1048,6 +1049,7 @@ impl TreeFile {
                         severity: severity_for_rule("dead-code"),
                         rule: Some("dead-code".to_string()),
                         context: Some(context),
+                        blame: None,
                     });
                 }
             }
@@ -1120,6 +1122,7 @@ impl TreeFile {
                 severity: severity_for_rule("undefined-symbol"),
                 rule: Some("undefined-symbol".to_string()),
                 context: Some(context),
+                blame: None,
             });
         }
 
@@ -1167,6 +1170,7 @@ impl TreeFile {
                 severity: severity_for_rule("unused-symbol"),
                 rule: Some("unused-symbol".to_string()),
                 context: Some(context),
+                blame: None,
             });
         }
 
@@ -1207,6 +1211,7 @@ impl TreeFile {
                 severity: severity_for_rule("unused-import"),
                 rule: Some("unused-import".to_string()),
                 context: Some(context),
+                blame: None,
             });
         }
 
@@ -1286,6 +1291,7 @@ impl TreeFile {
                 severity: severity_for_rule("undefined-module"),
                 rule: Some("undefined-module".to_string()),
                 context: Some(context),
+                blame: None,
             });
         }
 
@@ -1367,6 +1373,7 @@ impl TreeFile {
                     range,
                     severity: DiagnosticSeverity::Error,
                     context: Some(context),
+                    blame: None,
                 });
             }
 
@@ -1434,6 +1441,26 @@ impl TreeFile {
             .collect())
     }
 
+    /// Provides structured doc comments for every documented symbol in the file.
+    ///
+    /// Comments are parsed with the language's convention (rustdoc sections,
+    /// JSDoc tags, or Python docstrings) into a summary, description, and
+    /// normalized tags such as `param` and `returns`.
+    ///
+    /// ## Returns
+    /// Returns one entry per symbol that has a doc comment, in the same order
+    /// as [`symbols`](Self::symbols).
+    ///
+    /// ## Errors
+    /// Returns an error if query compilation fails.
+    pub fn doc_comments(&self) -> Result<Vec<DocComment>, TreeHuggerError> {
+        Ok(self
+            .symbols()?
+            .iter()
+            .filter_map(DocComment::from_symbol)
+            .collect())
+    }
+
     fn symbol_nodes(&self) -> Result<Vec<(SymbolInfo, Node<'_>)>, TreeHuggerError> {
         let query = query_for(self.language, QueryKind::Locals)?;
         let mut cursor = QueryCursor::new();
@@ -1509,6 +1536,7 @@ impl TreeFile {
                         doc_comment,
                         signature,
                         type_metadata,
+                        blame: None,
                     },
                     capture.node,
                 ));
@@ -3171,6 +3199,11 @@ fn extract_doc_comment(
     language: ProgrammingLanguage,
     source: &str,
 ) -> Option<String> {
+    // Python documents definitions with a docstring inside the body
+    if language == ProgrammingLanguage::Python {
+        return extract_python_docstring(node, source);
+    }
+
     // First try: look at direct preceding siblings
     if let Some(comments) = collect_doc_comments(node, language, source) {
         return Some(comments);
@@ -3198,6 +3231,37 @@ fn extract_doc_comment(
     None
 }
 
+/// Extracts the docstring of a Python function or class definition.
+///
+/// The docstring is the string literal that forms the first statement of the
+/// definition's body.
+fn extract_python_docstring(node: Node<'_>, source: &str) -> Option<String> {
+    let definition = if node.kind() == "decorated_definition" {
+        node.child_by_field_name("definition")?
+    } else {
+        node
+    };
+    let body = definition.child_by_field_name("body")?;
+
+    let mut cursor = body.walk();
+    let first_statement = body
+        .named_children(&mut cursor)
+        .find(|child| child.kind() != "comment")?;
+    if first_statement.kind() != "expression_statement" {
+        return None;
+    }
+
+    let string = first_statement.named_child(0)?;
+    if string.kind() != "string" {
+        return None;
+    }
+
+    let text = string.utf8_text(source.as_bytes()).ok()?;
+    // Raw and unicode prefixes (r"""...""") don't change the content
+    let text = text.trim_start_matches(['r', 'R', 'u', 'U']);
+    Some(clean_doc_comment(text, ProgrammingLanguage::Python))
+}
+
 /// Collects doc comments from preceding siblings of the given node.
 fn collect_doc_comments(
     node: Node<'_>,
@@ -3241,7 +3305,6 @@ fn collect_doc_comments(
 fn is_doc_comment_node(kind: &str, language: ProgrammingLanguage) -> bool {
     match language {
         ProgrammingLanguage::Rust => kind == "line_comment",
-        ProgrammingLanguage::Python => kind == "expression_statement", // docstrings
         ProgrammingLanguage::Go => kind == "comment",
         ProgrammingLanguage::JavaScript | ProgrammingLanguage::TypeScript => {
             kind == "comment" || kind == "jsx_text"
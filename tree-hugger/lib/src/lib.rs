@@ -1,16 +1,23 @@
+pub mod blame;
+pub mod bloat;
 pub mod builtins;
 pub mod dead_code;
 pub mod error;
 pub mod file;
 pub mod ignore_directives;
 pub mod package;
+pub mod parallel;
 pub mod queries;
 pub mod shared;
 
+pub use blame::{BlameInfo, FileBlame};
+pub use bloat::{BinaryReport, analyze_binary};
 pub use builtins::is_builtin;
 pub use dead_code::{find_dead_code_after, is_terminal_statement};
 pub use error::TreeHuggerError;
 pub use file::tree_file::TreeFile;
 pub use ignore_directives::IgnoreDirectives;
+pub use package::symbol_index::SymbolIndex;
 pub use package::tree_package::{TreePackage, TreePackageConfig};
+pub use parallel::{FileProgress, ParallelOptions, analyze_files};
 pub use shared::*;
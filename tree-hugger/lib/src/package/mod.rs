@@ -1,2 +1,4 @@
+/// Name-based symbol lookup across a package.
+pub mod symbol_index;
 /// Package discovery and aggregation utilities.
 pub mod tree_package;
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::TreeHuggerError;
+use crate::file::tree_file::TreeFile;
+use crate::package::tree_package::TreePackage;
+use crate::parallel::{ParallelOptions, analyze_files};
+use crate::shared::{ProgrammingLanguage, SymbolInfo, SymbolKind};
+
+/// An index of the symbols defined across a set of source files, keyed by name.
+///
+/// Used to map names found outside the sources (e.g. symbols in a compiled
+/// binary) back to the file and line that define them.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolIndex {
+    by_name: HashMap<String, Vec<SymbolInfo>>,
+    len: usize,
+}
+
+impl SymbolIndex {
+    /// Builds an index from the given source files.
+    ///
+    /// Parameters and fields are skipped since they never appear on their own
+    /// outside the source. Without a language override, files of unsupported
+    /// languages are skipped.
+    ///
+    /// ## Errors
+    /// Returns an error if a supported file cannot be read or parsed.
+    pub fn build(
+        files: &[PathBuf],
+        language: Option<ProgrammingLanguage>,
+    ) -> Result<Self, TreeHuggerError> {
+        Self::build_with(files, language, &ParallelOptions::default())
+    }
+
+    /// Builds an index like [`build`](Self::build), parsing files in parallel
+    /// as `options` directs.
+    ///
+    /// ## Errors
+    /// Returns an error if a supported file cannot be read or parsed.
+    pub fn build_with(
+        files: &[PathBuf],
+        language: Option<ProgrammingLanguage>,
+        options: &ParallelOptions,
+    ) -> Result<Self, TreeHuggerError> {
+        let symbols = analyze_files(files, options, |file| {
+            if language.is_none() && ProgrammingLanguage::from_path(file).is_none() {
+                return Ok(Vec::new());
+            }
+
+            TreeFile::with_language(file, language)?.symbols()
+        })?;
+
+        let mut index = Self::default();
+        for file_symbols in symbols {
+            index.extend(file_symbols);
+        }
+
+        Ok(index)
+    }
+
+    /// Adds symbols to the index.
+    pub fn extend(&mut self, symbols: impl IntoIterator<Item = SymbolInfo>) {
+        for symbol in symbols {
+            if matches!(symbol.kind, SymbolKind::Parameter | SymbolKind::Field) {
+                continue;
+            }
+            self.len += 1;
+            self.by_name
+                .entry(symbol.name.clone())
+                .or_default()
+                .push(symbol);
+        }
+    }
+
+    /// Returns every indexed symbol with the given name.
+    pub fn get(&self, name: &str) -> &[SymbolInfo] {
+        self.by_name.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Resolves a qualified path (e.g. `["my_crate", "parser", "parse"]`) to a symbol.
+    ///
+    /// Candidates share the last segment's name; the one whose file path
+    /// shares the most directory or file names with the leading segments
+    /// wins, so `my_crate::parser::parse` prefers `my-crate/src/parser.rs`.
+    /// Ties go to the first candidate indexed.
+    ///
+    /// ## Returns
+    /// Returns `None` when no symbol has the last segment's name.
+    pub fn resolve<S: AsRef<str>>(&self, path: &[S]) -> Option<&SymbolInfo> {
+        let (name, qualifiers) = path.split_last()?;
+        let candidates = self.get(name.as_ref());
+
+        let mut best: Option<(&SymbolInfo, usize)> = None;
+        for candidate in candidates {
+            let score = path_score(&candidate.file, qualifiers);
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((candidate, score));
+            }
+        }
+
+        best.map(|(symbol, _)| symbol)
+    }
+
+    /// Returns the number of indexed symbols.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no symbols are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl TreePackage {
+    /// Builds a [`SymbolIndex`] over the package's source files.
+    ///
+    /// ## Errors
+    /// Returns an error if a source file cannot be read or parsed.
+    pub fn symbol_index(&self) -> Result<SymbolIndex, TreeHuggerError> {
+        SymbolIndex::build(&self.source_files, Some(self.language))
+    }
+}
+
+/// Counts the qualifiers that name a directory or file stem in `file`.
+///
+/// Names are compared with `-` and `_` treated alike, since crate names use
+/// `_` in symbol paths and often `-` on disk.
+fn path_score<S: AsRef<str>>(file: &Path, qualifiers: &[S]) -> usize {
+    let components: Vec<String> = file
+        .with_extension("")
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(normalize(&part.to_string_lossy())),
+            _ => None,
+        })
+        .collect();
+
+    qualifiers
+        .iter()
+        .filter(|qualifier| components.contains(&normalize(qualifier.as_ref())))
+        .count()
+}
+
+fn normalize(name: &str) -> String {
+    name.replace('-', "_").to_ascii_lowercase()
+}
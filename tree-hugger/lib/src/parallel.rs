@@ -0,0 +1,112 @@
+//! Parallel per-file analysis.
+//!
+//! [`analyze_files`] runs an analysis over many files on a rayon thread pool
+//! and returns the results in the order the files were given, so output stays
+//! the same no matter which thread finishes first. A progress callback set
+//! with [`ParallelOptions::with_progress`] is told about every finished file,
+//! e.g. to draw a progress bar.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use crate::error::TreeHuggerError;
+
+/// Progress through a set of files, reported after each file finishes.
+#[derive(Debug, Clone, Copy)]
+pub struct FileProgress<'a> {
+    /// The file that just finished.
+    pub file: &'a Path,
+    /// Files finished so far, including this one.
+    pub completed: usize,
+    /// Files being analyzed.
+    pub total: usize,
+}
+
+/// Receives [`FileProgress`] updates; called from the worker threads.
+pub type ProgressCallback = dyn Fn(FileProgress<'_>) + Send + Sync;
+
+/// How [`analyze_files`] spreads work across threads.
+#[derive(Clone, Default)]
+pub struct ParallelOptions {
+    /// Worker threads to use; `None` uses rayon's global pool (one thread
+    /// per CPU).
+    pub jobs: Option<usize>,
+    /// Called after each file finishes.
+    pub progress: Option<Arc<ProgressCallback>>,
+}
+
+impl fmt::Debug for ParallelOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParallelOptions")
+            .field("jobs", &self.jobs)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+impl ParallelOptions {
+    /// Uses `jobs` worker threads; `1` analyzes the files one at a time.
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = Some(jobs.max(1));
+        self
+    }
+
+    /// Reports progress to `callback` after each file finishes.
+    pub fn with_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(FileProgress<'_>) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+}
+
+/// Runs `analyze` on every file in parallel, returning results in input order.
+///
+/// Every file is analyzed even if some fail, so the progress callback sees
+/// the full set; the error returned is the one for the earliest failing file.
+///
+/// ## Errors
+/// Returns the first error (in file order) returned by `analyze`, or an error
+/// if a thread pool with the requested number of jobs can't be created.
+pub fn analyze_files<T, F>(
+    files: &[PathBuf],
+    options: &ParallelOptions,
+    analyze: F,
+) -> Result<Vec<T>, TreeHuggerError>
+where
+    T: Send,
+    F: Fn(&Path) -> Result<T, TreeHuggerError> + Sync,
+{
+    let completed = AtomicUsize::new(0);
+    let run = || {
+        files
+            .par_iter()
+            .map(|file| {
+                let result = analyze(file);
+                if let Some(progress) = &options.progress {
+                    progress(FileProgress {
+                        file,
+                        completed: completed.fetch_add(1, Ordering::Relaxed) + 1,
+                        total: files.len(),
+                    });
+                }
+                result
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let results = match options.jobs {
+        Some(jobs) => rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build()?
+            .install(run),
+        None => run(),
+    };
+
+    results.into_iter().collect()
+}
@@ -0,0 +1,830 @@
+//! Structured documentation comments.
+//!
+//! [`TreeFile::doc_comments`](crate::TreeFile::doc_comments) pairs each documented
+//! symbol with a [`DocComment`] whose text is split into a summary, a free-form
+//! description, and tags. Tags are read from the convention of the language:
+//!
+//! - **rustdoc** sections (`## Arguments`, `## Returns`, `## Errors`, ...)
+//! - **JSDoc** tags (`@param`, `@returns`, `@throws`, ...) for JavaScript,
+//!   TypeScript, Java, PHP, C, C++, and Scala
+//! - **Python docstrings** in Google (`Args:`, `Returns:`) and reST
+//!   (`:param name:`, `:returns:`) styles
+//!
+//! Tag names are normalized so tooling can ask the same question of every
+//! language: parameters are always `param`, return values `returns`, and
+//! thrown/raised exceptions `throws`.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::symbol::{CodeRange, ProgrammingLanguage, SymbolInfo, SymbolKind};
+
+/// The documentation convention used to parse a doc comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocCommentStyle {
+    /// Markdown `///` comments with `# Section` headings.
+    Rustdoc,
+    /// `/** ... */` block comments with `@tag` lines.
+    JsDoc,
+    /// Python docstrings (Google and reST field styles).
+    Docstring,
+    /// Free-form comments without a tag convention (Go, Bash, ...).
+    Plain,
+}
+
+impl DocCommentStyle {
+    /// Returns the doc comment convention for a language.
+    pub fn for_language(language: ProgrammingLanguage) -> Self {
+        match language {
+            ProgrammingLanguage::Rust => Self::Rustdoc,
+            ProgrammingLanguage::JavaScript
+            | ProgrammingLanguage::TypeScript
+            | ProgrammingLanguage::Java
+            | ProgrammingLanguage::Php
+            | ProgrammingLanguage::C
+            | ProgrammingLanguage::Cpp
+            | ProgrammingLanguage::Scala => Self::JsDoc,
+            ProgrammingLanguage::Python => Self::Docstring,
+            _ => Self::Plain,
+        }
+    }
+}
+
+/// A tag parsed from a doc comment (e.g., `@param name - The name`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocTag {
+    /// Normalized tag name (`param`, `returns`, `throws`, `example`, ...).
+    pub name: String,
+    /// The tag's subject: the parameter name for `param`, the exception type
+    /// for `throws`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// Type annotation written in the tag (JSDoc `{string}`, docstring `name (str):`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_hint: Option<String>,
+    /// The tag's description text.
+    pub description: String,
+}
+
+impl DocTag {
+    fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            target: None,
+            type_hint: None,
+            description: String::new(),
+        }
+    }
+}
+
+/// The prose and tags of a doc comment, independent of any symbol.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ParsedDocComment {
+    /// The first paragraph of prose.
+    pub summary: String,
+    /// Prose following the summary, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Tags in the order they appear.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<DocTag>,
+}
+
+impl ParsedDocComment {
+    /// Parses cleaned doc comment text (comment markers already removed).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use tree_hugger_lib::{DocCommentStyle, ParsedDocComment};
+    ///
+    /// let doc = ParsedDocComment::parse(
+    ///     "Greets a person.\n@param {string} name - Who to greet\n@returns The greeting",
+    ///     DocCommentStyle::JsDoc,
+    /// );
+    /// assert_eq!(doc.summary, "Greets a person.");
+    /// assert_eq!(doc.tags[0].target.as_deref(), Some("name"));
+    /// assert_eq!(doc.tags[0].type_hint.as_deref(), Some("string"));
+    /// assert_eq!(doc.tags[1].name, "returns");
+    /// ```
+    pub fn parse(text: &str, style: DocCommentStyle) -> Self {
+        let (prose, mut tags) = match style {
+            DocCommentStyle::Rustdoc => parse_rustdoc(text),
+            DocCommentStyle::JsDoc => parse_jsdoc(text),
+            DocCommentStyle::Docstring => parse_docstring(text),
+            DocCommentStyle::Plain => (text.lines().collect(), Vec::new()),
+        };
+
+        for tag in &mut tags {
+            tag.description = tag.description.trim().to_string();
+        }
+
+        let (summary, description) = split_prose(&prose);
+        Self {
+            summary,
+            description,
+            tags,
+        }
+    }
+}
+
+/// A doc comment attached to a symbol, parsed into summary, description, and tags.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocComment {
+    /// Name of the documented symbol.
+    pub symbol: String,
+    /// Kind of the documented symbol.
+    pub kind: SymbolKind,
+    /// Range of the documented symbol's name.
+    pub range: CodeRange,
+    /// Language of the source file.
+    pub language: ProgrammingLanguage,
+    /// Path of the source file.
+    pub file: PathBuf,
+    /// The convention used to parse the comment.
+    pub style: DocCommentStyle,
+    /// The cleaned comment text with comment markers removed.
+    pub text: String,
+    /// The first paragraph of prose.
+    pub summary: String,
+    /// Prose following the summary, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Tags in the order they appear.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<DocTag>,
+}
+
+impl DocComment {
+    /// Builds a structured doc comment from a symbol's extracted doc text.
+    ///
+    /// ## Returns
+    /// Returns `None` if the symbol has no doc comment.
+    pub fn from_symbol(symbol: &SymbolInfo) -> Option<Self> {
+        let text = symbol.doc_comment.as_ref()?;
+        let style = DocCommentStyle::for_language(symbol.language);
+        let parsed = ParsedDocComment::parse(text, style);
+
+        Some(Self {
+            symbol: symbol.name.clone(),
+            kind: symbol.kind,
+            range: symbol.range.clone(),
+            language: symbol.language,
+            file: symbol.file.clone(),
+            style,
+            text: text.clone(),
+            summary: parsed.summary,
+            description: parsed.description,
+            tags: parsed.tags,
+        })
+    }
+
+    /// Returns the tags with the given normalized name.
+    pub fn tags_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a DocTag> {
+        self.tags.iter().filter(move |tag| tag.name == name)
+    }
+
+    /// Returns the documented parameters.
+    pub fn params(&self) -> impl Iterator<Item = &DocTag> {
+        self.tags_named("param")
+    }
+
+    /// Returns the documentation for the named parameter.
+    pub fn param(&self, name: &str) -> Option<&DocTag> {
+        self.params()
+            .find(|tag| tag.target.as_deref() == Some(name))
+    }
+
+    /// Returns the documentation for the return value.
+    pub fn returns(&self) -> Option<&DocTag> {
+        self.tags_named("returns").next()
+    }
+}
+
+/// Maps tag and section names onto a shared vocabulary.
+fn normalize_tag_name(name: &str) -> String {
+    let lower = name.trim().to_ascii_lowercase();
+    let normalized = match lower.as_str() {
+        "arg" | "args" | "argument" | "arguments" | "param" | "params" | "parameter"
+        | "parameters" => "param",
+        "return" | "returns" => "returns",
+        "exception" | "exceptions" | "throw" | "throws" | "raise" | "raises" => "throws",
+        "yield" | "yields" => "yields",
+        "example" | "examples" => "example",
+        "note" | "notes" => "note",
+        other => other,
+    };
+    normalized.to_string()
+}
+
+/// Appends a continuation line to a tag description.
+fn append_description(tag: &mut DocTag, line: &str, separator: char) {
+    if tag.description.is_empty() {
+        tag.description.push_str(line.trim());
+    } else {
+        tag.description.push(separator);
+        tag.description.push_str(line);
+    }
+}
+
+/// Splits prose lines into the first paragraph and the remainder.
+fn split_prose(lines: &[&str]) -> (String, Option<String>) {
+    let mut lines = lines
+        .iter()
+        .map(|line| line.trim())
+        .skip_while(|line| line.is_empty());
+
+    let summary = lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let description = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+
+    (summary, (!description.is_empty()).then_some(description))
+}
+
+/// Splits off the first whitespace-delimited word.
+fn split_word(text: &str) -> (&str, &str) {
+    let text = text.trim_start();
+    text.split_once(char::is_whitespace).unwrap_or((text, ""))
+}
+
+// ============================================================================
+// JSDoc
+// ============================================================================
+
+fn parse_jsdoc(text: &str) -> (Vec<&str>, Vec<DocTag>) {
+    let mut prose = Vec::new();
+    let mut tags: Vec<DocTag> = Vec::new();
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if let Some(tag) = trimmed.strip_prefix('@').and_then(parse_jsdoc_tag) {
+            tags.push(tag);
+            continue;
+        }
+
+        match tags.last_mut() {
+            // Examples are code, so keep their line structure
+            Some(tag) if tag.name == "example" => append_description(tag, line, '\n'),
+            Some(tag) if !trimmed.is_empty() => append_description(tag, trimmed, ' '),
+            Some(_) => {}
+            None => prose.push(trimmed),
+        }
+    }
+
+    (prose, tags)
+}
+
+/// Parses the text following `@` on a tag line.
+fn parse_jsdoc_tag(text: &str) -> Option<DocTag> {
+    let (name, rest) = split_word(text);
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return None;
+    }
+
+    let mut tag = DocTag::new(normalize_tag_name(name));
+    let mut rest = rest.trim_start();
+
+    if rest.starts_with('{')
+        && let Some(end) = matching_brace(rest)
+    {
+        tag.type_hint = Some(rest[1..end].trim().to_string());
+        rest = rest[end + 1..].trim_start();
+    }
+
+    if matches!(
+        tag.name.as_str(),
+        "param" | "property" | "prop" | "template"
+    ) {
+        let (target, after) = split_word(rest);
+        if !target.is_empty() {
+            tag.target = Some(clean_jsdoc_param_name(target));
+            rest = after;
+        }
+    }
+
+    let rest = rest.trim_start();
+    tag.description = rest
+        .strip_prefix('-')
+        .map(str::trim_start)
+        .unwrap_or(rest)
+        .to_string();
+
+    Some(tag)
+}
+
+/// Returns the byte index of the brace closing the one at the start of `text`.
+fn matching_brace(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Strips optional-parameter syntax: `[name=default]` becomes `name`.
+fn clean_jsdoc_param_name(name: &str) -> String {
+    let name = name.trim_start_matches('[').trim_end_matches(']');
+    name.split('=').next().unwrap_or(name).to_string()
+}
+
+// ============================================================================
+// rustdoc
+// ============================================================================
+
+fn parse_rustdoc(text: &str) -> (Vec<&str>, Vec<DocTag>) {
+    let mut prose = Vec::new();
+    let mut tags = Vec::new();
+    let mut section: Option<(String, Vec<&str>)> = None;
+    let mut in_code_block = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        // `#` lines inside code blocks are hidden doctest lines, not headings
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if !in_code_block && let Some(heading) = markdown_heading(trimmed) {
+            if let Some((name, lines)) = section.take() {
+                push_rustdoc_section(&name, &lines, &mut tags);
+            }
+            section = Some((normalize_tag_name(heading), Vec::new()));
+            continue;
+        }
+
+        match &mut section {
+            Some((_, lines)) => lines.push(line),
+            None => prose.push(line),
+        }
+    }
+
+    if let Some((name, lines)) = section {
+        push_rustdoc_section(&name, &lines, &mut tags);
+    }
+
+    (prose, tags)
+}
+
+/// Returns the text of a markdown ATX heading (`## Returns` -> `Returns`).
+fn markdown_heading(line: &str) -> Option<&str> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    line[level..]
+        .strip_prefix(' ')
+        .map(str::trim)
+        .filter(|heading| !heading.is_empty())
+}
+
+/// Converts a rustdoc section into tags.
+///
+/// `Arguments` sections yield one `param` tag per list item; other sections
+/// yield a single tag holding the section text.
+fn push_rustdoc_section(name: &str, lines: &[&str], tags: &mut Vec<DocTag>) {
+    if name != "param" {
+        let mut tag = DocTag::new(name);
+        tag.description = lines.join("\n");
+        tags.push(tag);
+        return;
+    }
+
+    let mut current: Option<DocTag> = None;
+    for line in lines {
+        let trimmed = line.trim();
+        let item = trimmed
+            .strip_prefix("* ")
+            .or_else(|| trimmed.strip_prefix("- "));
+
+        if let Some(item) = item {
+            tags.extend(current.take());
+            current = Some(parse_rustdoc_param(item));
+        } else if let Some(tag) = current.as_mut()
+            && !trimmed.is_empty()
+        {
+            append_description(tag, trimmed, ' ');
+        }
+    }
+    tags.extend(current);
+}
+
+/// Parses an argument list item such as `` `name` - The name ``.
+fn parse_rustdoc_param(item: &str) -> DocTag {
+    let item = item.trim();
+    let (name, rest) = match item.strip_prefix('`').and_then(|s| s.split_once('`')) {
+        Some((name, rest)) => (name, rest),
+        None => item
+            .split_once(|c: char| c == ':' || c.is_whitespace())
+            .unwrap_or((item, "")),
+    };
+
+    let rest = rest.trim_start();
+    let description = rest
+        .strip_prefix('-')
+        .or_else(|| rest.strip_prefix(':'))
+        .unwrap_or(rest);
+
+    let mut tag = DocTag::new("param");
+    tag.target = Some(name.trim().to_string());
+    tag.description = description.trim().to_string();
+    tag
+}
+
+// ============================================================================
+// Python docstrings
+// ============================================================================
+
+/// Google-style section headers recognized in docstrings.
+const DOCSTRING_SECTIONS: &[&str] = &[
+    "Args",
+    "Arguments",
+    "Parameters",
+    "Params",
+    "Keyword Args",
+    "Keyword Arguments",
+    "Returns",
+    "Return",
+    "Yields",
+    "Yield",
+    "Raises",
+    "Examples",
+    "Example",
+    "Note",
+    "Notes",
+    "Attributes",
+    "Todo",
+    "Warning",
+    "Warnings",
+    "See Also",
+];
+
+/// The docstring construct that indented lines continue.
+enum DocstringSection {
+    /// Prose; indented lines are ordinary text.
+    None,
+    /// A list of `name (type): description` items (`Args:`, `Raises:`).
+    Items {
+        indent: usize,
+        item_indent: Option<usize>,
+        name: String,
+    },
+    /// A free-form section (`Returns:`, `Examples:`) or reST field.
+    Block { indent: usize, separator: char },
+}
+
+fn parse_docstring(text: &str) -> (Vec<&str>, Vec<DocTag>) {
+    let mut prose = Vec::new();
+    let mut tags: Vec<DocTag> = Vec::new();
+    let mut section = DocstringSection::None;
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        let indent = line.len() - line.trim_start().len();
+
+        // Section content continues until a non-blank line dedents to the header
+        match &mut section {
+            DocstringSection::Items {
+                indent: header,
+                item_indent,
+                name,
+            } if trimmed.is_empty() || indent > *header => {
+                if trimmed.is_empty() {
+                    continue;
+                }
+                let item_indent = *item_indent.get_or_insert(indent);
+                match tags.last_mut() {
+                    Some(tag) if indent > item_indent => append_description(tag, trimmed, ' '),
+                    _ => tags.push(parse_docstring_item(name, trimmed)),
+                }
+                continue;
+            }
+            DocstringSection::Block {
+                indent: header,
+                separator,
+            } if trimmed.is_empty() || indent > *header => {
+                if let Some(tag) = tags.last_mut()
+                    && (!trimmed.is_empty() || *separator == '\n')
+                {
+                    append_description(tag, trimmed, *separator);
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        section = DocstringSection::None;
+
+        let header = trimmed
+            .strip_suffix(':')
+            .filter(|name| DOCSTRING_SECTIONS.contains(name));
+        if let Some(header) = header {
+            let name = normalize_tag_name(header);
+            section = if name == "param" || name == "throws" || name == "attributes" {
+                DocstringSection::Items {
+                    indent,
+                    item_indent: None,
+                    name,
+                }
+            } else {
+                tags.push(DocTag::new(name));
+                DocstringSection::Block {
+                    indent,
+                    separator: '\n',
+                }
+            };
+            continue;
+        }
+
+        if parse_rest_field(trimmed, &mut tags) {
+            section = DocstringSection::Block {
+                indent,
+                separator: ' ',
+            };
+            continue;
+        }
+
+        prose.push(trimmed);
+    }
+
+    (prose, tags)
+}
+
+/// Parses a Google-style item such as `name (str): The name`.
+fn parse_docstring_item(section: &str, item: &str) -> DocTag {
+    let (head, description) = item.split_once(':').unwrap_or((item, ""));
+    let (target, type_hint) = match head.split_once('(') {
+        Some((target, type_hint)) => (target, Some(type_hint.trim_end().trim_end_matches(')'))),
+        None => (head, None),
+    };
+
+    let mut tag = DocTag::new(section);
+    // `*args` and `**kwargs` are documented with their stars
+    tag.target = Some(target.trim().trim_start_matches('*').to_string());
+    tag.type_hint = type_hint.map(|t| t.trim().to_string());
+    tag.description = description.trim().to_string();
+    tag
+}
+
+/// Parses a reST field list entry (`:param str name: The name`).
+///
+/// `:type:` and `:rtype:` fields annotate existing tags instead of adding new ones.
+///
+/// ## Returns
+/// Returns `true` if the line was a field.
+fn parse_rest_field(line: &str, tags: &mut Vec<DocTag>) -> bool {
+    let Some((field, description)) = line.strip_prefix(':').and_then(|rest| rest.split_once(':'))
+    else {
+        return false;
+    };
+    let words: Vec<&str> = field.split_whitespace().collect();
+    let Some(first) = words.first() else {
+        return false;
+    };
+    let description = description.trim().to_string();
+
+    match *first {
+        "type" => {
+            if let Some(target) = words.get(1)
+                && let Some(tag) = tags
+                    .iter_mut()
+                    .find(|tag| tag.name == "param" && tag.target.as_deref() == Some(target))
+            {
+                tag.type_hint = Some(description);
+            }
+            return true;
+        }
+        "rtype" => {
+            match tags.iter_mut().find(|tag| tag.name == "returns") {
+                Some(tag) => tag.type_hint = Some(description),
+                None => {
+                    let mut tag = DocTag::new("returns");
+                    tag.type_hint = Some(description);
+                    tags.push(tag);
+                }
+            }
+            return true;
+        }
+        _ => {}
+    }
+
+    let mut tag = DocTag::new(normalize_tag_name(first));
+    match words.as_slice() {
+        [_, type_hint, target] if tag.name == "param" => {
+            tag.type_hint = Some((*type_hint).to_string());
+            tag.target = Some((*target).to_string());
+        }
+        [_, target, ..] => tag.target = Some((*target).to_string()),
+        _ => {}
+    }
+    tag.description = description;
+    tags.push(tag);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str, style: DocCommentStyle) -> ParsedDocComment {
+        ParsedDocComment::parse(text, style)
+    }
+
+    #[test]
+    fn jsdoc_tags_with_types_and_separators() {
+        let doc = parse(
+            "Greets a person by name.\n\
+             @param {string} name - The name of the person\n\
+             @param [greeting=Hello] The greeting to use\n\
+             @return {string} A formatted\n\
+             greeting string\n\
+             @throws {TypeError} If name is empty",
+            DocCommentStyle::JsDoc,
+        );
+
+        assert_eq!(doc.summary, "Greets a person by name.");
+        assert_eq!(doc.description, None);
+        assert_eq!(doc.tags.len(), 4);
+
+        assert_eq!(doc.tags[0].name, "param");
+        assert_eq!(doc.tags[0].target.as_deref(), Some("name"));
+        assert_eq!(doc.tags[0].type_hint.as_deref(), Some("string"));
+        assert_eq!(doc.tags[0].description, "The name of the person");
+
+        assert_eq!(doc.tags[1].target.as_deref(), Some("greeting"));
+        assert_eq!(doc.tags[1].description, "The greeting to use");
+
+        assert_eq!(doc.tags[2].name, "returns");
+        assert_eq!(doc.tags[2].description, "A formatted greeting string");
+
+        assert_eq!(doc.tags[3].name, "throws");
+        assert_eq!(doc.tags[3].type_hint.as_deref(), Some("TypeError"));
+        assert_eq!(doc.tags[3].target, None);
+    }
+
+    #[test]
+    fn jsdoc_nested_braces_in_type() {
+        let doc = parse(
+            "@param {{ id: number }} options - Lookup options",
+            DocCommentStyle::JsDoc,
+        );
+        assert_eq!(doc.summary, "");
+        assert_eq!(doc.tags[0].type_hint.as_deref(), Some("{ id: number }"));
+        assert_eq!(doc.tags[0].target.as_deref(), Some("options"));
+    }
+
+    #[test]
+    fn jsdoc_example_keeps_lines() {
+        let doc = parse(
+            "Adds numbers.\n@example\nadd(1, 2);\nadd(3, 4);",
+            DocCommentStyle::JsDoc,
+        );
+        assert_eq!(doc.tags[0].name, "example");
+        assert_eq!(doc.tags[0].description, "add(1, 2);\nadd(3, 4);");
+    }
+
+    #[test]
+    fn rustdoc_sections() {
+        let doc = parse(
+            "Greets a person by name.\n\
+             \n\
+             Uses the default greeting.\n\
+             \n\
+             ## Arguments\n\
+             * `name` - The name of the person\n\
+             to greet\n\
+             - `loud`: Whether to shout\n\
+             \n\
+             ## Returns\n\
+             A formatted greeting string.\n\
+             \n\
+             ## Errors\n\
+             Returns an error if `name` is empty.",
+            DocCommentStyle::Rustdoc,
+        );
+
+        assert_eq!(doc.summary, "Greets a person by name.");
+        assert_eq!(
+            doc.description.as_deref(),
+            Some("Uses the default greeting.")
+        );
+
+        let names: Vec<_> = doc.tags.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["param", "param", "returns", "errors"]);
+        assert_eq!(doc.tags[0].target.as_deref(), Some("name"));
+        assert_eq!(doc.tags[0].description, "The name of the person to greet");
+        assert_eq!(doc.tags[1].target.as_deref(), Some("loud"));
+        assert_eq!(doc.tags[1].description, "Whether to shout");
+        assert_eq!(doc.tags[2].description, "A formatted greeting string.");
+        assert_eq!(
+            doc.tags[3].description,
+            "Returns an error if `name` is empty."
+        );
+    }
+
+    #[test]
+    fn rustdoc_ignores_hidden_doctest_lines() {
+        let doc = parse(
+            "Parses input.\n\
+             \n\
+             # Examples\n\
+             ```\n\
+             # use crate::parse;\n\
+             parse(\"x\");\n\
+             ```",
+            DocCommentStyle::Rustdoc,
+        );
+
+        assert_eq!(doc.tags.len(), 1);
+        assert_eq!(doc.tags[0].name, "example");
+        assert!(doc.tags[0].description.contains("# use crate::parse;"));
+    }
+
+    #[test]
+    fn google_docstring_sections() {
+        let doc = parse(
+            "Greet a person by name.\n\
+             \n\
+             \x20   Args:\n\
+             \x20       name (str): The name of the person.\n\
+             \x20           Defaults to \"World\".\n\
+             \x20       *extra: Additional names.\n\
+             \n\
+             \x20   Returns:\n\
+             \x20       A formatted greeting string.\n\
+             \n\
+             \x20   Raises:\n\
+             \x20       ValueError: If name is empty.",
+            DocCommentStyle::Docstring,
+        );
+
+        assert_eq!(doc.summary, "Greet a person by name.");
+        let names: Vec<_> = doc.tags.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["param", "param", "returns", "throws"]);
+
+        assert_eq!(doc.tags[0].target.as_deref(), Some("name"));
+        assert_eq!(doc.tags[0].type_hint.as_deref(), Some("str"));
+        assert_eq!(
+            doc.tags[0].description,
+            "The name of the person. Defaults to \"World\"."
+        );
+        assert_eq!(doc.tags[1].target.as_deref(), Some("extra"));
+        assert_eq!(doc.tags[2].description, "A formatted greeting string.");
+        assert_eq!(doc.tags[3].target.as_deref(), Some("ValueError"));
+    }
+
+    #[test]
+    fn rest_docstring_fields() {
+        let doc = parse(
+            "Greet a person.\n\
+             \n\
+             \x20   :param name: The name\n\
+             \x20       of the person.\n\
+             \x20   :type name: str\n\
+             \x20   :param int count: Repetitions.\n\
+             \x20   :returns: The greeting.\n\
+             \x20   :rtype: str\n\
+             \x20   :raises ValueError: If name is empty.",
+            DocCommentStyle::Docstring,
+        );
+
+        assert_eq!(doc.summary, "Greet a person.");
+        assert_eq!(doc.tags.len(), 4);
+        assert_eq!(doc.tags[0].target.as_deref(), Some("name"));
+        assert_eq!(doc.tags[0].type_hint.as_deref(), Some("str"));
+        assert_eq!(doc.tags[0].description, "The name of the person.");
+        assert_eq!(doc.tags[1].target.as_deref(), Some("count"));
+        assert_eq!(doc.tags[1].type_hint.as_deref(), Some("int"));
+        assert_eq!(doc.tags[2].name, "returns");
+        assert_eq!(doc.tags[2].type_hint.as_deref(), Some("str"));
+        assert_eq!(doc.tags[3].name, "throws");
+        assert_eq!(doc.tags[3].target.as_deref(), Some("ValueError"));
+    }
+
+    #[test]
+    fn plain_comments_have_no_tags() {
+        let doc = parse(
+            "Greet returns a greeting.\n@param is not special here",
+            DocCommentStyle::Plain,
+        );
+        assert_eq!(
+            doc.summary,
+            "Greet returns a greeting. @param is not special here"
+        );
+        assert!(doc.tags.is_empty());
+    }
+}
@@ -1,4 +1,6 @@
 /// Shared types used across tree-hugger modules.
+pub mod doc_comment;
 pub mod symbol;
 
+pub use doc_comment::*;
 pub use symbol::*;
@@ -4,6 +4,8 @@ use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 use tree_sitter::Language;
 
+use crate::blame::BlameInfo;
+
 /// Programming languages supported by tree-hugger.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ProgrammingLanguage {
@@ -531,6 +533,9 @@ pub struct SymbolInfo {
     /// Type metadata (fields, variants, etc.) for type-like symbols.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_metadata: Option<TypeMetadata>,
+    /// The commit that last modified this symbol, when blame was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blame: Option<BlameInfo>,
 }
 
 /// An imported symbol reference.
@@ -590,6 +595,9 @@ pub struct LintDiagnostic {
     /// Source context for displaying the diagnostic with visual markers.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub context: Option<SourceContext>,
+    /// The commit that last modified the flagged code, when blame was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blame: Option<BlameInfo>,
 }
 
 /// A syntax diagnostic derived from parse errors.
@@ -601,6 +609,9 @@ pub struct SyntaxDiagnostic {
     /// Source context for displaying the diagnostic with visual markers.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub context: Option<SourceContext>,
+    /// The commit that last modified the flagged code, when blame was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blame: Option<BlameInfo>,
 }
 
 /// Categorizes the source of a diagnostic.
@@ -646,6 +657,9 @@ pub struct Diagnostic {
     /// Source context for displaying the diagnostic with visual markers.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub context: Option<SourceContext>,
+    /// The commit that last modified the flagged code, when blame was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blame: Option<BlameInfo>,
 }
 
 impl Diagnostic {
@@ -671,6 +685,7 @@ impl Diagnostic {
             severity: lint.severity,
             rule: lint.rule,
             context: lint.context,
+            blame: lint.blame,
         }
     }
 
@@ -683,6 +698,7 @@ impl Diagnostic {
             severity: syntax.severity,
             rule: None,
             context: syntax.context,
+            blame: syntax.blame,
         }
     }
 }
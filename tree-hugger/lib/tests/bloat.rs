@@ -0,0 +1,94 @@
+use std::fs;
+use std::path::PathBuf;
+
+use tempfile::TempDir;
+use tree_hugger_lib::bloat::{BinarySymbol, attribute_symbols, sizes_by_file};
+use tree_hugger_lib::{BinaryReport, TreeHuggerError, TreePackage, analyze_binary};
+
+#[test]
+fn analyzes_the_test_binary() -> Result<(), Box<dyn std::error::Error>> {
+    let report = analyze_binary(std::env::current_exe()?)?;
+
+    assert!(report.file_size > 0);
+    assert!(!report.sections.is_empty());
+    assert!(
+        report
+            .symbols
+            .windows(2)
+            .all(|pair| pair[0].size >= pair[1].size),
+        "symbols should be sorted largest first"
+    );
+    assert!(
+        report
+            .symbols
+            .iter()
+            .any(|symbol| symbol.name.ends_with("analyzes_the_test_binary")),
+        "expected the demangled test function among the symbols"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn rejects_files_that_are_not_binaries() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sample.rs");
+    let result = analyze_binary(&path);
+    assert!(matches!(result, Err(TreeHuggerError::BinaryParse { .. })));
+}
+
+#[test]
+fn maps_symbols_to_defining_source_files() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let root = temp_dir.path();
+
+    fs::create_dir(root.join(".git"))?;
+    fs::create_dir_all(root.join("src/render"))?;
+    fs::write(root.join("Cargo.toml"), "[package]\nname = \"sample\"\n")?;
+    fs::write(
+        root.join("src/lib.rs"),
+        "pub mod parser;\npub mod render;\n",
+    )?;
+    fs::write(root.join("src/parser.rs"), "pub fn parse() {}\n")?;
+    fs::write(root.join("src/render/mod.rs"), "\npub fn parse() {}\n")?;
+
+    let index = TreePackage::new(root)?.symbol_index()?;
+    let report = BinaryReport {
+        path: PathBuf::from("sample"),
+        file_size: 1024,
+        sections: Vec::new(),
+        symbols: vec![
+            symbol("sample::render::parse", 300),
+            symbol("sample::parser::parse::{{closure}}", 200),
+            symbol("core::fmt::write", 100),
+        ],
+    };
+
+    let attributed = attribute_symbols(&report, &index);
+    let sources: Vec<Option<&str>> = attributed
+        .iter()
+        .map(|symbol| {
+            symbol
+                .source
+                .as_ref()
+                .map(|source| source.file.file_name().unwrap().to_str().unwrap())
+        })
+        .collect();
+    assert_eq!(sources, vec![Some("mod.rs"), Some("parser.rs"), None]);
+    assert_eq!(attributed[0].source.as_ref().unwrap().line, 2);
+
+    let files = sizes_by_file(&attributed);
+    assert_eq!(files.len(), 2);
+    assert!(files[0].file.ends_with("src/render/mod.rs"));
+    assert_eq!((files[0].size, files[0].symbols), (300, 1));
+    assert_eq!(files[1].size, 200);
+
+    Ok(())
+}
+
+fn symbol(name: &str, size: u64) -> BinarySymbol {
+    BinarySymbol {
+        name: name.to_string(),
+        size,
+        section: Some(".text".to_string()),
+    }
+}
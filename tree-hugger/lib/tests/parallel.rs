@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tree_hugger_lib::{ParallelOptions, SymbolIndex, TreeFile, TreeHuggerError, analyze_files};
+
+fn fixtures(names: &[&str]) -> Vec<PathBuf> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    names.iter().map(|name| dir.join(name)).collect()
+}
+
+#[test]
+fn results_keep_input_order_for_any_job_count() -> Result<(), TreeHuggerError> {
+    let files = fixtures(&[
+        "sample.rs",
+        "sample.py",
+        "sample.go",
+        "sample.ts",
+        "sample.c",
+    ]);
+    let names = |options: &ParallelOptions| {
+        analyze_files(&files, options, |file| {
+            let symbols = TreeFile::new(file)?.symbols()?;
+            Ok(symbols
+                .into_iter()
+                .map(|symbol| symbol.name)
+                .collect::<Vec<_>>())
+        })
+    };
+
+    let sequential = names(&ParallelOptions::default().with_jobs(1))?;
+    assert_eq!(sequential.len(), files.len());
+    assert_eq!(names(&ParallelOptions::default().with_jobs(4))?, sequential);
+    assert_eq!(names(&ParallelOptions::default())?, sequential);
+
+    Ok(())
+}
+
+#[test]
+fn progress_is_reported_for_every_file() -> Result<(), TreeHuggerError> {
+    let files = fixtures(&["sample.rs", "sample.py", "sample.go"]);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let options = ParallelOptions::default().with_jobs(2).with_progress({
+        let calls = Arc::clone(&calls);
+        move |progress| {
+            assert_eq!(progress.total, 3);
+            assert!((1..=3).contains(&progress.completed));
+            calls.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    SymbolIndex::build_with(&files, None, &options)?;
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+#[test]
+fn the_earliest_failing_file_wins() {
+    let mut files = fixtures(&["sample.rs"]);
+    files.push(PathBuf::from("missing-first.rs"));
+    files.push(PathBuf::from("missing-second.rs"));
+
+    let result = analyze_files(&files, &ParallelOptions::default().with_jobs(3), |file| {
+        TreeFile::new(file)
+    });
+    match result {
+        Err(TreeHuggerError::Io { path, .. }) => {
+            assert_eq!(path, PathBuf::from("missing-first.rs"))
+        }
+        other => panic!("expected an IO error, got {other:?}"),
+    }
+}
@@ -2,7 +2,7 @@ use std::fs;
 use std::path::PathBuf;
 
 use tempfile::TempDir;
-use tree_hugger_lib::{ProgrammingLanguage, TreeFile, TreeHuggerError};
+use tree_hugger_lib::{DocCommentStyle, ProgrammingLanguage, TreeFile, TreeHuggerError};
 
 #[test]
 fn parses_all_fixtures() -> Result<(), TreeHuggerError> {
@@ -362,6 +362,137 @@ fn extracts_go_doc_comments() -> Result<(), TreeHuggerError> {
     Ok(())
 }
 
+// ============================================================================
+// Structured doc comments
+// ============================================================================
+
+#[test]
+fn parses_rustdoc_sections_into_tags() -> Result<(), TreeHuggerError> {
+    let tree_file = TreeFile::new(fixture_path("sample.rs"))?;
+    let docs = tree_file.doc_comments()?;
+
+    let greet = docs
+        .iter()
+        .find(|d| d.symbol == "greet" && d.kind == tree_hugger_lib::SymbolKind::Function)
+        .expect("should find greet doc comment");
+
+    assert_eq!(greet.style, DocCommentStyle::Rustdoc);
+    assert_eq!(greet.summary, "Greets a person by name.");
+    let name = greet.param("name").expect("should document name");
+    assert_eq!(name.description, "The name of the person to greet");
+    assert_eq!(
+        greet.returns().map(|t| t.description.as_str()),
+        Some("A formatted greeting string.")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parses_jsdoc_tags() -> Result<(), TreeHuggerError> {
+    let tree_file = TreeFile::new(fixture_path("sample.ts"))?;
+    let docs = tree_file.doc_comments()?;
+
+    let greet = docs
+        .iter()
+        .find(|d| d.symbol == "greet" && d.kind == tree_hugger_lib::SymbolKind::Function)
+        .expect("should find greet doc comment");
+
+    assert_eq!(greet.style, DocCommentStyle::JsDoc);
+    assert_eq!(greet.summary, "Greets a person by name.");
+    assert_eq!(greet.params().count(), 1);
+    assert_eq!(
+        greet.param("name").map(|t| t.description.as_str()),
+        Some("The name of the person to greet")
+    );
+    assert_eq!(
+        greet.returns().map(|t| t.description.as_str()),
+        Some("A formatted greeting string")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn extracts_python_docstrings() -> Result<(), TreeHuggerError> {
+    // Regression test: docstrings live in the definition body, not before it
+    let tree_file = TreeFile::new(fixture_path("sample.py"))?;
+    let docs = tree_file.doc_comments()?;
+
+    let greet = docs
+        .iter()
+        .find(|d| d.symbol == "greet" && d.kind == tree_hugger_lib::SymbolKind::Function)
+        .expect("should find greet docstring");
+
+    assert_eq!(greet.style, DocCommentStyle::Docstring);
+    assert_eq!(greet.summary, "Greet a person by name.");
+    assert_eq!(
+        greet.param("name").map(|t| t.description.as_str()),
+        Some("The name of the person to greet. Defaults to \"World\".")
+    );
+    assert_eq!(
+        greet.returns().map(|t| t.description.as_str()),
+        Some("A formatted greeting string.")
+    );
+
+    let greet_many = docs
+        .iter()
+        .find(|d| d.symbol == "greet_many")
+        .expect("should find greet_many docstring");
+    assert!(
+        greet_many.param("names").is_some(),
+        "*names should be documented as names"
+    );
+
+    let greeter = docs
+        .iter()
+        .find(|d| d.symbol == "Greeter")
+        .expect("should find class docstring");
+    assert_eq!(greeter.summary, "A class that can generate greetings.");
+    assert!(greeter.tags.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn doc_comments_skip_undocumented_symbols() -> Result<(), TreeHuggerError> {
+    let dir = TempDir::new().unwrap();
+    let path = create_temp_file(
+        &dir,
+        "undocumented.js",
+        r#"/**
+ * Adds two numbers.
+ * @param {number} a - First operand
+ * @param {number} b - Second operand
+ */
+function add(a, b) {
+    return a + b;
+}
+
+function subtract(a, b) {
+    return a - b;
+}
+"#,
+    );
+
+    let tree_file = TreeFile::new(&path)?;
+    let docs = tree_file.doc_comments()?;
+
+    assert!(docs.iter().all(|d| d.symbol != "subtract"));
+    let add = docs
+        .iter()
+        .find(|d| d.symbol == "add")
+        .expect("should find add doc comment");
+    let params: Vec<_> = add.params().filter_map(|t| t.target.as_deref()).collect();
+    assert_eq!(params, vec!["a", "b"]);
+    assert_eq!(
+        add.param("b").and_then(|t| t.type_hint.as_deref()),
+        Some("number")
+    );
+
+    Ok(())
+}
+
 // ============================================================================
 // Regression tests for generics support
 // ============================================================================
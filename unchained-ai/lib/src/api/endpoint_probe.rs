@@ -0,0 +1,713 @@
+//! Capability probing for OpenAI-compatible endpoints
+//!
+//! A `/v1/models` listing says which models an endpoint serves but not what
+//! they can actually do. [`EndpointProber`] sends a few tiny chat completion
+//! requests to each model to find out:
+//!
+//! - **max context** - read from the model listing when advertised, otherwise
+//!   from the error a server returns when asked for far too many tokens
+//! - **tool calls** - whether a `tools` request produces a tool call
+//! - **JSON mode** - whether `response_format: json_object` returns valid JSON
+//!
+//! Each model gets a [`ModelCapabilities::score`] from 0 to 100, and the
+//! [`EndpointReport`] for a base URL can be cached with a [`ToolCache`] so an
+//! endpoint is only probed once per TTL. Model selection can then prefer the
+//! highest scoring models via [`EndpointReport::ranked`].
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use unchained_ai::api::endpoint_probe::EndpointProber;
+//! use unchained_ai::rigging::tools::ToolCache;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let prober = EndpointProber::new("http://localhost:8000/v1")
+//!     .with_cache(ToolCache::new("/tmp/probe-cache"));
+//!
+//! let report = prober.report().await?;
+//! for model in report.ranked() {
+//!     println!("{} scored {}", model.model, model.score);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, Instant};
+
+use futures::stream::{self, StreamExt};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tracing::{debug, info};
+
+use crate::rigging::providers::provider_errors::ProviderError;
+use crate::rigging::tools::ToolCache;
+
+/// Cache namespace for endpoint reports
+const CACHE_NAMESPACE: &str = "endpoint_probe";
+
+/// Completion tokens requested by the tool and JSON probes
+const PROBE_MAX_TOKENS: u64 = 64;
+
+/// A `max_tokens` no model accepts, used to provoke a context length error
+const OVERSIZED_MAX_TOKENS: u64 = 100_000_000;
+
+/// Number of models probed at the same time
+const PROBE_CONCURRENCY: usize = 4;
+
+/// Context window which earns no context points (4k)
+const MIN_SCORED_CONTEXT: f64 = 4_096.0;
+
+/// Context window which earns full context points (128k)
+const MAX_SCORED_CONTEXT: f64 = 131_072.0;
+
+/// Phrases servers put in front of the context limit in their error messages
+const CONTEXT_LIMIT_MARKERS: &[&str] = &[
+    "maximum context length is",
+    "maximum context length of",
+    "context length of",
+    "context window of",
+    "context window is",
+    "max_model_len",
+    "available context size",
+];
+
+/// Whether a model supports a feature, as far as probing could tell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Support {
+    /// The probe request succeeded and the feature worked
+    Supported,
+    /// The server rejected the request, or ignored the feature
+    Unsupported,
+    /// The probe was inconclusive (server error, timeout, or the model
+    /// accepted the request but chose not to use the feature)
+    Unknown,
+}
+
+impl Support {
+    /// Points earned out of `max`: all when supported, half when unknown.
+    fn points(self, max: u8) -> u8 {
+        match self {
+            Self::Supported => max,
+            Self::Unknown => max / 2,
+            Self::Unsupported => 0,
+        }
+    }
+}
+
+/// The capabilities probed for one model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelCapabilities {
+    /// Model ID, as listed by the endpoint
+    pub model: String,
+    /// Maximum context window in tokens, if it could be determined
+    pub max_context: Option<u64>,
+    /// Whether the model makes tool calls
+    pub tool_calls: Support,
+    /// Whether the model honors `response_format: json_object`
+    pub json_mode: Support,
+    /// Round trip time of the fastest probe request, in milliseconds
+    pub latency_ms: Option<u64>,
+    /// Overall capability score from 0 to 100 (see [`capability_score`])
+    pub score: u8,
+}
+
+/// Capabilities of every probed model behind one base URL.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndpointReport {
+    /// The base URL that was probed
+    pub base_url: String,
+    /// One entry per probed model, in listing order
+    pub models: Vec<ModelCapabilities>,
+}
+
+impl EndpointReport {
+    /// Models ordered from highest to lowest score (ties by model ID).
+    pub fn ranked(&self) -> Vec<&ModelCapabilities> {
+        let mut ranked: Vec<_> = self.models.iter().collect();
+        ranked.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.model.cmp(&b.model)));
+        ranked
+    }
+
+    /// Capabilities for `model`, if it was probed.
+    pub fn get(&self, model: &str) -> Option<&ModelCapabilities> {
+        self.models.iter().find(|caps| caps.model == model)
+    }
+}
+
+/// A model from the endpoint's `/models` listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListedModel {
+    pub id: String,
+    /// Context window, when the listing advertises one (OpenRouter, vLLM, ...)
+    pub context_length: Option<u64>,
+}
+
+/// Probes an OpenAI-compatible endpoint for the capabilities of its models.
+#[derive(Debug, Clone)]
+pub struct EndpointProber {
+    base_url: String,
+    api_key: Option<String>,
+    models: Option<Vec<String>>,
+    cache: Option<ToolCache>,
+    http: Client,
+}
+
+impl EndpointProber {
+    /// Creates a prober for `base_url`, including its version segment
+    /// (e.g. `https://api.openai.com/v1`).
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            api_key: None,
+            models: None,
+            cache: None,
+            http: Client::new(),
+        }
+    }
+
+    /// Sends `api_key` as a bearer token.
+    #[must_use]
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into()).filter(|key| !key.is_empty());
+        self
+    }
+
+    /// Probes only these models instead of everything the endpoint lists.
+    ///
+    /// Each model costs three small completion requests, so large aggregators
+    /// should be limited to the models you intend to use.
+    #[must_use]
+    pub fn with_models<S: Into<String>>(mut self, models: impl IntoIterator<Item = S>) -> Self {
+        self.models = Some(models.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Caches reports per base URL in `cache`, for as long as its TTL.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ToolCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Uses `client` for all requests (e.g. to set timeouts or a proxy).
+    #[must_use]
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.http = client;
+        self
+    }
+
+    /// The base URL being probed.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the capability report for this endpoint, probing it unless a
+    /// fresh report is cached.
+    ///
+    /// ## Errors
+    ///
+    /// - `ProviderError::AuthenticationFailed` - the endpoint rejected the API key
+    /// - `ProviderError::RateLimitExceeded` - the endpoint returned 429
+    /// - `ProviderError::ProbeFailed` - the model listing could not be fetched
+    ///
+    /// Failures of individual probe requests don't fail the report; the
+    /// capability is recorded as [`Support::Unknown`] instead.
+    #[tracing::instrument(skip(self), fields(base_url = %self.base_url))]
+    pub async fn report(&self) -> Result<EndpointReport, ProviderError> {
+        let cache_key = self.cache_key();
+        if let Some(cache) = &self.cache
+            && let Some(report) = cache
+                .get::<EndpointReport>(CACHE_NAMESPACE, &cache_key)
+                .await
+        {
+            return Ok(report);
+        }
+
+        let listed = self.list_models().await?;
+        let targets: Vec<ListedModel> = match &self.models {
+            Some(wanted) => wanted
+                .iter()
+                .map(|id| {
+                    listed
+                        .iter()
+                        .find(|model| &model.id == id)
+                        .cloned()
+                        .unwrap_or_else(|| ListedModel {
+                            id: id.clone(),
+                            context_length: None,
+                        })
+                })
+                .collect(),
+            None => listed,
+        };
+
+        info!("Probing {} models at {}", targets.len(), self.base_url);
+
+        let results: Vec<Result<ModelCapabilities, ProviderError>> = stream::iter(targets)
+            .map(|model| async move { self.probe_model(&model.id, model.context_length).await })
+            .buffered(PROBE_CONCURRENCY)
+            .collect()
+            .await;
+        let models = results.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+        let report = EndpointReport {
+            base_url: self.base_url.clone(),
+            models,
+        };
+        if let Some(cache) = &self.cache {
+            cache.put(CACHE_NAMESPACE, &cache_key, &report).await;
+        }
+
+        Ok(report)
+    }
+
+    /// Fetches the endpoint's model listing.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the request fails or the endpoint responds with a
+    /// non-success status.
+    pub async fn list_models(&self) -> Result<Vec<ListedModel>, ProviderError> {
+        let url = format!("{}/models", self.base_url);
+        let response = self.authorize(self.http.get(&url)).send().await?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        match status.as_u16() {
+            401 | 403 => return Err(self.auth_failed()),
+            429 => return Err(self.rate_limited()),
+            _ if !status.is_success() => {
+                return Err(ProviderError::ProbeFailed {
+                    base_url: self.base_url.clone(),
+                    message: format!("HTTP {}: {}", status.as_u16(), body.trim()),
+                });
+            }
+            _ => {}
+        }
+
+        Ok(parse_model_listing(&serde_json::from_str(&body)?))
+    }
+
+    /// Probes a single model.
+    ///
+    /// `advertised_context` (from the model listing) is used as the max
+    /// context when present; otherwise it is read from a context length error.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error only for authentication failures and rate limiting.
+    pub async fn probe_model(
+        &self,
+        model: &str,
+        advertised_context: Option<u64>,
+    ) -> Result<ModelCapabilities, ProviderError> {
+        debug!("Probing {} at {}", model, self.base_url);
+
+        let tools = self
+            .complete(json!({
+                "model": model,
+                "max_tokens": PROBE_MAX_TOKENS,
+                "messages": [{ "role": "user", "content": "Call the `ping` tool." }],
+                "tools": [{
+                    "type": "function",
+                    "function": {
+                        "name": "ping",
+                        "description": "Checks that tool calling works.",
+                        "parameters": { "type": "object", "properties": {} }
+                    }
+                }]
+            }))
+            .await?;
+        let tool_calls = match &tools.outcome {
+            ProbeOutcome::Success(body) => {
+                let called = body
+                    .pointer("/choices/0/message/tool_calls")
+                    .and_then(Value::as_array)
+                    .is_some_and(|calls| !calls.is_empty());
+                if called {
+                    Support::Supported
+                } else {
+                    Support::Unknown
+                }
+            }
+            ProbeOutcome::Rejected(_) => Support::Unsupported,
+            ProbeOutcome::Failed => Support::Unknown,
+        };
+
+        let json_probe = self
+            .complete(json!({
+                "model": model,
+                "max_tokens": PROBE_MAX_TOKENS,
+                "messages": [{
+                    "role": "user",
+                    "content": "Reply with a JSON object whose key \"ok\" is set to true."
+                }],
+                "response_format": { "type": "json_object" }
+            }))
+            .await?;
+        let json_mode = match &json_probe.outcome {
+            ProbeOutcome::Success(body) => {
+                let is_object = body
+                    .pointer("/choices/0/message/content")
+                    .and_then(Value::as_str)
+                    .and_then(|content| serde_json::from_str::<Value>(content.trim()).ok())
+                    .is_some_and(|value| value.is_object());
+                if is_object {
+                    Support::Supported
+                } else {
+                    Support::Unsupported
+                }
+            }
+            ProbeOutcome::Rejected(_) => Support::Unsupported,
+            ProbeOutcome::Failed => Support::Unknown,
+        };
+
+        let max_context = match advertised_context {
+            Some(context) => Some(context),
+            None => {
+                let probe = self
+                    .complete(json!({
+                        "model": model,
+                        "max_tokens": OVERSIZED_MAX_TOKENS,
+                        "messages": [{ "role": "user", "content": "Reply with the word ok." }]
+                    }))
+                    .await?;
+                match &probe.outcome {
+                    ProbeOutcome::Rejected(message) => context_limit_from_error(message),
+                    ProbeOutcome::Success(_) | ProbeOutcome::Failed => None,
+                }
+            }
+        };
+
+        let latency_ms = [tools.latency, json_probe.latency]
+            .into_iter()
+            .flatten()
+            .min()
+            .map(|latency| latency.as_millis() as u64);
+
+        Ok(ModelCapabilities {
+            model: model.to_string(),
+            max_context,
+            tool_calls,
+            json_mode,
+            latency_ms,
+            score: capability_score(max_context, tool_calls, json_mode),
+        })
+    }
+
+    /// Sends one chat completion probe.
+    async fn complete(&self, body: Value) -> Result<ProbeResponse, ProviderError> {
+        let url = format!("{}/chat/completions", self.base_url);
+        let request = self
+            .authorize(self.http.post(&url))
+            .header("Content-Type", "application/json")
+            .body(serde_json::to_vec(&body)?);
+
+        let started = Instant::now();
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                debug!("Probe request to {} failed: {}", url, e);
+                return Ok(ProbeResponse {
+                    outcome: ProbeOutcome::Failed,
+                    latency: None,
+                });
+            }
+        };
+        let latency = started.elapsed();
+
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        let outcome = match status.as_u16() {
+            401 | 403 => return Err(self.auth_failed()),
+            429 => return Err(self.rate_limited()),
+            400 | 404 | 422 => ProbeOutcome::Rejected(text),
+            _ if status.is_success() => match serde_json::from_str(&text) {
+                Ok(body) => ProbeOutcome::Success(body),
+                Err(_) => ProbeOutcome::Failed,
+            },
+            _ => ProbeOutcome::Failed,
+        };
+
+        Ok(ProbeResponse {
+            latency: matches!(outcome, ProbeOutcome::Success(_)).then_some(latency),
+            outcome,
+        })
+    }
+
+    fn authorize(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.api_key {
+            Some(key) => request.header("Authorization", format!("Bearer {}", key)),
+            None => request,
+        }
+    }
+
+    /// Reports are cached per base URL, and per model selection when limited.
+    fn cache_key(&self) -> String {
+        match &self.models {
+            Some(models) => format!("{}#{}", self.base_url, models.join(",")),
+            None => self.base_url.clone(),
+        }
+    }
+
+    fn auth_failed(&self) -> ProviderError {
+        ProviderError::AuthenticationFailed {
+            provider: self.base_url.clone(),
+        }
+    }
+
+    fn rate_limited(&self) -> ProviderError {
+        ProviderError::RateLimitExceeded {
+            provider: self.base_url.clone(),
+        }
+    }
+}
+
+/// How a single probe request went.
+#[derive(Debug)]
+enum ProbeOutcome {
+    /// 2xx with a JSON body
+    Success(Value),
+    /// The server rejected the request as invalid; holds the error body
+    Rejected(String),
+    /// Network error, server error or unreadable response
+    Failed,
+}
+
+#[derive(Debug)]
+struct ProbeResponse {
+    outcome: ProbeOutcome,
+    /// Round trip time, recorded for successful requests only
+    latency: Option<Duration>,
+}
+
+/// Extracts models (and any advertised context length) from a `/models` body.
+fn parse_model_listing(body: &Value) -> Vec<ListedModel> {
+    let entries = body
+        .get("data")
+        .or_else(|| body.get("models"))
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let id = entry
+                .get("id")
+                .or_else(|| entry.get("name"))
+                .and_then(Value::as_str)?;
+            let context_length = ["context_length", "context_window", "max_model_len"]
+                .iter()
+                .find_map(|key| entry.get(*key).and_then(Value::as_u64))
+                .or_else(|| {
+                    entry
+                        .pointer("/top_provider/context_length")
+                        .and_then(Value::as_u64)
+                });
+            Some(ListedModel {
+                id: id.strip_prefix("models/").unwrap_or(id).to_string(),
+                context_length,
+            })
+        })
+        .collect()
+}
+
+/// Reads the context limit out of a "context length exceeded" error message.
+///
+/// Recognizes the wording used by OpenAI, vLLM, llama.cpp and similar servers,
+/// e.g. `This model's maximum context length is 8192 tokens`.
+fn context_limit_from_error(message: &str) -> Option<u64> {
+    let message = message.to_lowercase();
+
+    CONTEXT_LIMIT_MARKERS.iter().find_map(|marker| {
+        let start = message.find(marker)? + marker.len();
+        let rest = message[start..].trim_start_matches(|c: char| !c.is_ascii_digit());
+        // Only accept a number that directly follows the marker
+        if message.len() - start - rest.len() > 4 {
+            return None;
+        }
+        let digits: String = rest
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == ',')
+            .filter(char::is_ascii_digit)
+            .collect();
+        digits.parse().ok().filter(|limit| *limit >= 256)
+    })
+}
+
+/// Scores a model from 0 to 100.
+///
+/// Tool calls are worth 40 points, JSON mode 20 and the context window 40,
+/// scaled logarithmically from 4k (no points) to 128k tokens (full points).
+/// Unknown support and an unknown context window earn half points.
+pub fn capability_score(max_context: Option<u64>, tool_calls: Support, json_mode: Support) -> u8 {
+    let context_points = match max_context {
+        Some(context) => {
+            let range = (MAX_SCORED_CONTEXT / MIN_SCORED_CONTEXT).log2();
+            let position = (context as f64 / MIN_SCORED_CONTEXT).log2() / range;
+            (40.0 * position.clamp(0.0, 1.0)).round() as u8
+        }
+        None => 20,
+    };
+
+    tool_calls.points(40) + json_mode.points(20) + context_points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn completion(message: Value) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(json!({ "choices": [{ "message": message }] }))
+    }
+
+    async fn mock_endpoint() -> MockServer {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/models"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{ "id": "small" }, { "id": "large", "context_length": 200000 }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(body_partial_json(json!({ "model": "large", "tools": [] })))
+            .respond_with(completion(json!({ "tool_calls": [{ "id": "1" }] })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(body_partial_json(json!({ "tools": [] })))
+            .respond_with(ResponseTemplate::new(400).set_body_string("tools are not supported"))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(body_partial_json(
+                json!({ "response_format": { "type": "json_object" } }),
+            ))
+            .respond_with(completion(json!({ "content": "{\"ok\": true}" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(body_partial_json(json!({ "max_tokens": OVERSIZED_MAX_TOKENS })))
+            .respond_with(ResponseTemplate::new(400).set_body_string(
+                "This model's maximum context length is 8192 tokens. However, you requested 100000010 tokens.",
+            ))
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    #[tokio::test]
+    async fn test_report_probes_each_model() {
+        let server = mock_endpoint().await;
+        let prober = EndpointProber::new(format!("{}/v1", server.uri()));
+
+        let report = prober.report().await.unwrap();
+
+        let small = report.get("small").unwrap();
+        assert_eq!(small.tool_calls, Support::Unsupported);
+        assert_eq!(small.json_mode, Support::Supported);
+        assert_eq!(small.max_context, Some(8192));
+
+        let large = report.get("large").unwrap();
+        assert_eq!(large.tool_calls, Support::Supported);
+        assert_eq!(large.max_context, Some(200_000));
+        assert_eq!(large.score, 100);
+
+        let ranked: Vec<_> = report.ranked().iter().map(|m| m.model.as_str()).collect();
+        assert_eq!(ranked, vec!["large", "small"]);
+    }
+
+    #[tokio::test]
+    async fn test_report_is_cached_per_base_url() {
+        let server = mock_endpoint().await;
+        let dir = tempfile::tempdir().unwrap();
+        let prober = EndpointProber::new(format!("{}/v1", server.uri()))
+            .with_models(["large"])
+            .with_cache(ToolCache::new(dir.path()));
+
+        let first = prober.report().await.unwrap();
+        let requests = server.received_requests().await.unwrap().len();
+        let second = prober.report().await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(server.received_requests().await.unwrap().len(), requests);
+    }
+
+    #[tokio::test]
+    async fn test_auth_failure_fails_the_report() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/models"))
+            .and(header("Authorization", "Bearer bad-key"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let prober = EndpointProber::new(format!("{}/v1/", server.uri())).with_api_key("bad-key");
+
+        assert!(matches!(
+            prober.report().await,
+            Err(ProviderError::AuthenticationFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_context_limit_from_error() {
+        assert_eq!(
+            context_limit_from_error(
+                "This model's maximum context length is 128,000 tokens. However, you requested 5 tokens"
+            ),
+            Some(128_000)
+        );
+        assert_eq!(
+            context_limit_from_error("requested tokens exceed context window of 32768"),
+            Some(32_768)
+        );
+        assert_eq!(
+            context_limit_from_error("max_tokens must be at most 4096"),
+            None
+        );
+        assert_eq!(
+            context_limit_from_error("context length of the model was exceeded by 100000000"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_model_listing() {
+        let listing = parse_model_listing(&json!({
+            "models": [
+                { "name": "models/gemini-2.0-flash" },
+                { "id": "qwen", "max_model_len": 32768 },
+                { "id": "router", "top_provider": { "context_length": 65536 } },
+                { "object": "model" }
+            ]
+        }));
+
+        assert_eq!(listing.len(), 3);
+        assert_eq!(listing[0].id, "gemini-2.0-flash");
+        assert_eq!(listing[1].context_length, Some(32_768));
+        assert_eq!(listing[2].context_length, Some(65_536));
+    }
+
+    #[test]
+    fn test_capability_score() {
+        use Support::*;
+
+        assert_eq!(capability_score(Some(131_072), Supported, Supported), 100);
+        assert_eq!(capability_score(Some(4_096), Unsupported, Unsupported), 0);
+        assert_eq!(capability_score(None, Unknown, Unknown), 50);
+        assert_eq!(capability_score(Some(16_384), Supported, Unsupported), 56);
+    }
+}
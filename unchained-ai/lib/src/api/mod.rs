@@ -1,2 +1,3 @@
 pub mod auth;
+pub mod endpoint_probe;
 pub mod openai_api;
@@ -2,5 +2,6 @@ pub mod client_adaptors;
 pub mod models;
 mod provider;
 pub mod provider_errors;
+pub mod streaming;
 
 pub use provider::*;
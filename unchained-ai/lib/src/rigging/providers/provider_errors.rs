@@ -48,4 +48,10 @@ pub enum ProviderError {
 
     #[error("Failed to build client for {provider}: {reason}")]
     ClientBuildFailed { provider: String, reason: String },
+
+    #[error("Streaming failed for {provider}: {message}")]
+    StreamFailed { provider: String, message: String },
+
+    #[error("Probing {base_url} failed: {message}")]
+    ProbeFailed { base_url: String, message: String },
 }
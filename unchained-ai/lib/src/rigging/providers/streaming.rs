@@ -0,0 +1,1113 @@
+//! Provider-agnostic streaming completions.
+//!
+//! Every provider streams completions over Server-Sent Events, but each uses
+//! its own payload shape. This module hides those differences behind a single
+//! [`StreamEvent`] type so callers can display model output as it arrives,
+//! regardless of provider:
+//!
+//! - [`StreamEvent::TextDelta`] - a chunk of generated text
+//! - [`StreamEvent::ToolCallDelta`] - a fragment of a tool call (name and/or JSON arguments)
+//! - [`StreamEvent::Usage`] - token usage, reported once near the end of the stream
+//! - [`StreamEvent::Done`] - the stream finished, with the provider's stop reason
+//!
+//! Three wire formats are supported (see [`StreamFormat`]): the OpenAI-compatible
+//! chat completions API (used by most providers), Anthropic's Messages API, and
+//! Gemini's `streamGenerateContent`.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use unchained_ai::rigging::providers::Provider;
+//! use unchained_ai::rigging::providers::streaming::{
+//!     StreamEvent, StreamRequest, StreamingClient,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = StreamingClient::from_env(Provider::Anthropic)?;
+//! let request = StreamRequest::new("claude-sonnet-4-5", "Write a haiku about Rust");
+//!
+//! let mut stream = client.stream(&request).await?;
+//! while let Some(event) = stream.next().await {
+//!     if let StreamEvent::TextDelta(text) = event? {
+//!         print!("{}", text);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, VecDeque};
+use std::pin::Pin;
+
+use futures::stream::{self, Stream, StreamExt};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tracing::debug;
+
+pub use schematic_define::sse::{SseEvent, SseParser};
+
+use crate::api::auth::ApiAuthMethod;
+use crate::rigging::providers::Provider;
+use crate::rigging::providers::provider_errors::ProviderError;
+
+/// Default `max_tokens` for providers that require one (Anthropic).
+const DEFAULT_MAX_TOKENS: u32 = 4096;
+
+/// Anthropic API version sent with every request.
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// A boxed stream of [`StreamEvent`]s.
+pub type CompletionStream = Pin<Box<dyn Stream<Item = Result<StreamEvent, ProviderError>> + Send>>;
+
+/// A single event in a streamed completion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamEvent {
+    /// A chunk of generated text
+    TextDelta(String),
+    /// A fragment of a tool call.
+    ///
+    /// `index` identifies the call within the response; `id` and `name` are
+    /// usually only present on the first fragment, while `arguments` carries
+    /// the next piece of the JSON arguments (possibly empty).
+    ToolCallDelta {
+        index: usize,
+        id: Option<String>,
+        name: Option<String>,
+        arguments: String,
+    },
+    /// Token usage for the completion
+    Usage(StreamUsage),
+    /// The stream finished
+    Done {
+        /// Provider-specific stop reason (e.g. `stop`, `end_turn`, `tool_use`)
+        stop_reason: Option<String>,
+    },
+}
+
+/// Token usage reported by a streamed completion.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamUsage {
+    pub input_tokens: Option<u64>,
+    pub output_tokens: Option<u64>,
+}
+
+/// The wire format a provider uses for streaming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamFormat {
+    /// OpenAI-compatible `chat/completions` with `stream: true`
+    OpenAiCompatible,
+    /// Anthropic Messages API (`/v1/messages`)
+    Anthropic,
+    /// Gemini `streamGenerateContent?alt=sse`
+    Gemini,
+}
+
+impl StreamFormat {
+    /// Returns the streaming format used by `provider`.
+    pub fn for_provider(provider: Provider) -> Self {
+        match provider {
+            Provider::Anthropic => StreamFormat::Anthropic,
+            Provider::Gemini => StreamFormat::Gemini,
+            _ => StreamFormat::OpenAiCompatible,
+        }
+    }
+}
+
+/// A prompt to stream a completion for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamRequest {
+    /// Model ID without the provider prefix (e.g. `gpt-4o`)
+    pub model: String,
+    /// Optional system prompt
+    pub system: Option<String>,
+    /// The user prompt
+    pub prompt: String,
+    /// Maximum number of tokens to generate
+    pub max_tokens: Option<u32>,
+    /// Sampling temperature
+    pub temperature: Option<f32>,
+}
+
+impl StreamRequest {
+    /// Creates a request for `prompt` against `model`.
+    pub fn new(model: impl Into<String>, prompt: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            system: None,
+            prompt: prompt.into(),
+            max_tokens: None,
+            temperature: None,
+        }
+    }
+
+    /// Sets the system prompt.
+    pub fn with_system(mut self, system: impl Into<String>) -> Self {
+        self.system = Some(system.into());
+        self
+    }
+
+    /// Sets the maximum number of tokens to generate.
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Sets the sampling temperature.
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Builds the JSON request body for `format`.
+    pub fn to_body(&self, format: StreamFormat) -> Value {
+        match format {
+            StreamFormat::OpenAiCompatible => {
+                let mut messages = Vec::new();
+                if let Some(system) = &self.system {
+                    messages.push(json!({"role": "system", "content": system}));
+                }
+                messages.push(json!({"role": "user", "content": self.prompt}));
+
+                let mut body = json!({
+                    "model": self.model,
+                    "messages": messages,
+                    "stream": true,
+                    "stream_options": {"include_usage": true},
+                });
+                if let Some(max_tokens) = self.max_tokens {
+                    body["max_tokens"] = json!(max_tokens);
+                }
+                if let Some(temperature) = self.temperature {
+                    body["temperature"] = json!(temperature);
+                }
+                body
+            }
+            StreamFormat::Anthropic => {
+                let mut body = json!({
+                    "model": self.model,
+                    "max_tokens": self.max_tokens.unwrap_or(DEFAULT_MAX_TOKENS),
+                    "messages": [{"role": "user", "content": self.prompt}],
+                    "stream": true,
+                });
+                if let Some(system) = &self.system {
+                    body["system"] = json!(system);
+                }
+                if let Some(temperature) = self.temperature {
+                    body["temperature"] = json!(temperature);
+                }
+                body
+            }
+            StreamFormat::Gemini => {
+                let mut body = json!({
+                    "contents": [{"role": "user", "parts": [{"text": self.prompt}]}],
+                });
+                if let Some(system) = &self.system {
+                    body["systemInstruction"] = json!({"parts": [{"text": system}]});
+                }
+                let mut config = serde_json::Map::new();
+                if let Some(max_tokens) = self.max_tokens {
+                    config.insert("maxOutputTokens".to_string(), json!(max_tokens));
+                }
+                if let Some(temperature) = self.temperature {
+                    config.insert("temperature".to_string(), json!(temperature));
+                }
+                if !config.is_empty() {
+                    body["generationConfig"] = Value::Object(config);
+                }
+                body
+            }
+        }
+    }
+}
+
+/// Streams completions from a single provider.
+#[derive(Debug, Clone)]
+pub struct StreamingClient {
+    provider: Provider,
+    api_key: String,
+    base_url: String,
+    http: Client,
+}
+
+impl StreamingClient {
+    /// Creates a client for `provider` using `api_key` (empty for local providers).
+    pub fn new(provider: Provider, api_key: impl Into<String>) -> Self {
+        Self {
+            provider,
+            api_key: api_key.into(),
+            base_url: default_base_url(provider).to_string(),
+            http: Client::new(),
+        }
+    }
+
+    /// Creates a client for `provider`, reading the API key from its environment variables.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `ProviderError::MissingApiKey` if the provider needs a key and
+    /// none of its environment variables are set.
+    pub fn from_env(provider: Provider) -> Result<Self, ProviderError> {
+        let config = provider.config();
+        let api_key = config
+            .env_vars
+            .iter()
+            .find_map(|var| std::env::var(var).ok().filter(|key| !key.is_empty()));
+
+        match api_key {
+            Some(key) => Ok(Self::new(provider, key)),
+            None if config.is_local => Ok(Self::new(provider, "")),
+            None => Err(ProviderError::MissingApiKey {
+                provider: provider_name(provider),
+                env_vars: config.env_vars.iter().map(|v| v.to_string()).collect(),
+            }),
+        }
+    }
+
+    /// Overrides the API base URL (e.g. for a proxy or tests).
+    ///
+    /// The URL must include any version segment the provider's paths expect,
+    /// as the default does (see [`Self::endpoint`]).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Returns the provider this client talks to.
+    pub fn provider(&self) -> Provider {
+        self.provider
+    }
+
+    /// Returns the streaming endpoint URL for `model` (without credentials).
+    pub fn endpoint(&self, model: &str) -> String {
+        match StreamFormat::for_provider(self.provider) {
+            StreamFormat::OpenAiCompatible => format!("{}/chat/completions", self.base_url),
+            StreamFormat::Anthropic => format!("{}/messages", self.base_url),
+            StreamFormat::Gemini => format!(
+                "{}/models/{}:streamGenerateContent?alt=sse",
+                self.base_url, model
+            ),
+        }
+    }
+
+    /// Starts streaming a completion.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the request cannot be sent or the provider rejects
+    /// it (authentication, rate limiting, or any other non-success status).
+    /// Errors that occur mid-stream are yielded as items of the stream.
+    #[tracing::instrument(skip(self, request), fields(provider = ?self.provider, model = %request.model))]
+    pub async fn stream(&self, request: &StreamRequest) -> Result<CompletionStream, ProviderError> {
+        let format = StreamFormat::for_provider(self.provider);
+        let name = provider_name(self.provider);
+        let body = serde_json::to_vec(&request.to_body(format))?;
+
+        let mut url = self.endpoint(&request.model);
+        if let ApiAuthMethod::QueryParam(param) = &self.provider.config().auth_method {
+            url = format!("{}&{}={}", url, param, self.api_key);
+        }
+
+        let mut builder = self
+            .http
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "text/event-stream")
+            .body(body);
+
+        match &self.provider.config().auth_method {
+            ApiAuthMethod::BearerToken => {
+                builder = builder.header("Authorization", format!("Bearer {}", self.api_key));
+            }
+            ApiAuthMethod::ApiKey(header) => {
+                builder = builder.header(header.as_str(), &self.api_key);
+            }
+            // Providers without a configured auth method still accept a bearer key
+            ApiAuthMethod::None if !self.api_key.is_empty() => {
+                builder = builder.header("Authorization", format!("Bearer {}", self.api_key));
+            }
+            ApiAuthMethod::None | ApiAuthMethod::QueryParam(_) => {}
+        }
+        if format == StreamFormat::Anthropic {
+            builder = builder.header("anthropic-version", ANTHROPIC_VERSION);
+        }
+
+        debug!("Starting streamed completion from {}", name);
+        let response = builder.send().await?;
+
+        let status = response.status();
+        match status.as_u16() {
+            401 | 403 => return Err(ProviderError::AuthenticationFailed { provider: name }),
+            429 => return Err(ProviderError::RateLimitExceeded { provider: name }),
+            _ if !status.is_success() => {
+                let message = response.text().await.unwrap_or_default();
+                return Err(ProviderError::StreamFailed {
+                    provider: name,
+                    message: format!("HTTP {}: {}", status.as_u16(), message.trim()),
+                });
+            }
+            _ => {}
+        }
+
+        Ok(decode_stream(
+            response.bytes_stream(),
+            StreamDecoder::new(format, name),
+        ))
+    }
+}
+
+/// Returns the streaming base URL for `provider`, including its version segment.
+fn default_base_url(provider: Provider) -> &'static str {
+    match provider {
+        Provider::Gemini => "https://generativelanguage.googleapis.com/v1beta",
+        Provider::HuggingFace => "https://router.huggingface.co/v1",
+        Provider::Anthropic => "https://api.anthropic.com/v1",
+        Provider::Deepseek => "https://api.deepseek.com/v1",
+        Provider::Groq => "https://api.groq.com/openai/v1",
+        Provider::Mistral => "https://api.mistral.ai/v1",
+        Provider::Ollama => "http://localhost:11434/v1",
+        Provider::OpenAi => "https://api.openai.com/v1",
+        Provider::OpenRouter => "https://openrouter.ai/api/v1",
+        Provider::ZenMux => "https://zenmux.ai/api/v1",
+        // These base URLs already carry their version segment
+        Provider::MoonshotAi | Provider::Xai | Provider::Zai => provider.base_url(),
+    }
+}
+
+fn provider_name(provider: Provider) -> String {
+    format!("{:?}", provider).to_lowercase()
+}
+
+/// Turns a byte stream of SSE data into a [`CompletionStream`].
+fn decode_stream<S, B>(bytes: S, decoder: StreamDecoder) -> CompletionStream
+where
+    S: Stream<Item = Result<B, reqwest::Error>> + Send + 'static,
+    B: AsRef<[u8]> + Send + 'static,
+{
+    struct State<S> {
+        bytes: Pin<Box<S>>,
+        parser: SseParser,
+        decoder: StreamDecoder,
+        pending: VecDeque<Result<StreamEvent, ProviderError>>,
+        finished: bool,
+    }
+
+    let state = State {
+        bytes: Box::pin(bytes),
+        parser: SseParser::default(),
+        decoder,
+        pending: VecDeque::new(),
+        finished: false,
+    };
+
+    Box::pin(stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.pending.pop_front() {
+                return Some((item, state));
+            }
+            if state.finished {
+                return None;
+            }
+
+            match state.bytes.next().await {
+                Some(Ok(chunk)) => {
+                    for event in state.parser.push(chunk.as_ref()) {
+                        match state.decoder.decode(&event) {
+                            Ok(events) => state.pending.extend(events.into_iter().map(Ok)),
+                            Err(e) => {
+                                state.pending.push_back(Err(e));
+                                state.finished = true;
+                                break;
+                            }
+                        }
+                    }
+                }
+                Some(Err(e)) => {
+                    state.pending.push_back(Err(e.into()));
+                    state.finished = true;
+                }
+                None => {
+                    let events = state
+                        .parser
+                        .finish()
+                        .into_iter()
+                        .map(|event| state.decoder.decode(&event))
+                        .collect::<Result<Vec<_>, _>>();
+                    match events {
+                        Ok(events) => {
+                            state.pending.extend(events.into_iter().flatten().map(Ok));
+                            state
+                                .pending
+                                .extend(state.decoder.finish().into_iter().map(Ok));
+                        }
+                        Err(e) => state.pending.push_back(Err(e)),
+                    }
+                    state.finished = true;
+                }
+            }
+        }
+    }))
+}
+
+/// Converts provider-specific SSE payloads into [`StreamEvent`]s.
+#[derive(Debug)]
+pub struct StreamDecoder {
+    format: StreamFormat,
+    provider: String,
+    stop_reason: Option<String>,
+    usage: StreamUsage,
+    usage_seen: bool,
+    tool_calls: usize,
+    done: bool,
+}
+
+impl StreamDecoder {
+    /// Creates a decoder for `format`; `provider` is used in error messages.
+    pub fn new(format: StreamFormat, provider: impl Into<String>) -> Self {
+        Self {
+            format,
+            provider: provider.into(),
+            stop_reason: None,
+            usage: StreamUsage::default(),
+            usage_seen: false,
+            tool_calls: 0,
+            done: false,
+        }
+    }
+
+    /// Decodes one SSE event.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `ProviderError::StreamFailed` if the provider reports an error
+    /// mid-stream or sends a payload that is not valid JSON.
+    pub fn decode(&mut self, event: &SseEvent) -> Result<Vec<StreamEvent>, ProviderError> {
+        if self.done {
+            return Ok(Vec::new());
+        }
+        if event.data.trim() == "[DONE]" {
+            return Ok(self.finish());
+        }
+
+        let payload: Value =
+            serde_json::from_str(&event.data).map_err(|e| ProviderError::StreamFailed {
+                provider: self.provider.clone(),
+                message: format!("invalid event payload: {}", e),
+            })?;
+        if let Some(error) = payload.get("error") {
+            let message = error
+                .get("message")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| error.to_string());
+            return Err(ProviderError::StreamFailed {
+                provider: self.provider.clone(),
+                message,
+            });
+        }
+
+        Ok(match self.format {
+            StreamFormat::OpenAiCompatible => self.decode_openai(payload),
+            StreamFormat::Anthropic => self.decode_anthropic(payload),
+            StreamFormat::Gemini => self.decode_gemini(payload),
+        })
+    }
+
+    /// Ends the stream, emitting any pending usage and the final `Done` event.
+    ///
+    /// Returns nothing if the stream was already finished.
+    pub fn finish(&mut self) -> Vec<StreamEvent> {
+        if self.done {
+            return Vec::new();
+        }
+        self.done = true;
+
+        let mut events = Vec::new();
+        // Gemini repeats cumulative usage on every chunk; report it once at the end
+        if self.format == StreamFormat::Gemini && self.usage_seen {
+            events.push(StreamEvent::Usage(self.usage));
+        }
+        events.push(StreamEvent::Done {
+            stop_reason: self.stop_reason.take(),
+        });
+        events
+    }
+
+    fn decode_openai(&mut self, payload: Value) -> Vec<StreamEvent> {
+        let chunk: OpenAiChunk = serde_json::from_value(payload).unwrap_or_default();
+        let mut events = Vec::new();
+
+        for choice in chunk.choices {
+            if let Some(content) = choice.delta.content.filter(|c| !c.is_empty()) {
+                events.push(StreamEvent::TextDelta(content));
+            }
+            for call in choice.delta.tool_calls {
+                events.push(StreamEvent::ToolCallDelta {
+                    index: call.index,
+                    id: call.id,
+                    name: call.function.name,
+                    arguments: call.function.arguments.unwrap_or_default(),
+                });
+            }
+            if let Some(reason) = choice.finish_reason {
+                self.stop_reason = Some(reason);
+            }
+        }
+
+        if let Some(usage) = chunk.usage {
+            events.push(StreamEvent::Usage(StreamUsage {
+                input_tokens: usage.prompt_tokens,
+                output_tokens: usage.completion_tokens,
+            }));
+        }
+
+        events
+    }
+
+    fn decode_anthropic(&mut self, payload: Value) -> Vec<StreamEvent> {
+        let kind = payload.get("type").and_then(Value::as_str).unwrap_or("");
+        let index = payload
+            .get("index")
+            .and_then(Value::as_u64)
+            .unwrap_or_default() as usize;
+
+        match kind {
+            "message_start" => {
+                let usage = &payload["message"]["usage"];
+                self.usage.input_tokens = usage["input_tokens"].as_u64();
+                self.usage.output_tokens = usage["output_tokens"].as_u64();
+                Vec::new()
+            }
+            "content_block_start" => {
+                let block = &payload["content_block"];
+                match block["type"].as_str() {
+                    Some("tool_use") => vec![StreamEvent::ToolCallDelta {
+                        index,
+                        id: block["id"].as_str().map(str::to_string),
+                        name: block["name"].as_str().map(str::to_string),
+                        arguments: String::new(),
+                    }],
+                    Some("text") => block["text"]
+                        .as_str()
+                        .filter(|t| !t.is_empty())
+                        .map(|t| vec![StreamEvent::TextDelta(t.to_string())])
+                        .unwrap_or_default(),
+                    _ => Vec::new(),
+                }
+            }
+            "content_block_delta" => {
+                let delta = &payload["delta"];
+                match delta["type"].as_str() {
+                    Some("text_delta") => delta["text"]
+                        .as_str()
+                        .map(|t| vec![StreamEvent::TextDelta(t.to_string())])
+                        .unwrap_or_default(),
+                    Some("input_json_delta") => vec![StreamEvent::ToolCallDelta {
+                        index,
+                        id: None,
+                        name: None,
+                        arguments: delta["partial_json"].as_str().unwrap_or("").to_string(),
+                    }],
+                    _ => Vec::new(),
+                }
+            }
+            "message_delta" => {
+                if let Some(reason) = payload["delta"]["stop_reason"].as_str() {
+                    self.stop_reason = Some(reason.to_string());
+                }
+                match payload["usage"]["output_tokens"].as_u64() {
+                    Some(output) => {
+                        self.usage.output_tokens = Some(output);
+                        vec![StreamEvent::Usage(self.usage)]
+                    }
+                    None => Vec::new(),
+                }
+            }
+            "message_stop" => self.finish(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn decode_gemini(&mut self, payload: Value) -> Vec<StreamEvent> {
+        let chunk: GeminiChunk = serde_json::from_value(payload).unwrap_or_default();
+        let mut events = Vec::new();
+
+        for candidate in chunk.candidates {
+            for part in candidate.content.map(|c| c.parts).unwrap_or_default() {
+                if let Some(text) = part.text.filter(|t| !t.is_empty()) {
+                    events.push(StreamEvent::TextDelta(text));
+                }
+                if let Some(call) = part.function_call {
+                    events.push(StreamEvent::ToolCallDelta {
+                        index: self.tool_calls,
+                        id: None,
+                        name: Some(call.name),
+                        arguments: call.args.map(|a| a.to_string()).unwrap_or_default(),
+                    });
+                    self.tool_calls += 1;
+                }
+            }
+            if let Some(reason) = candidate.finish_reason {
+                self.stop_reason = Some(reason);
+            }
+        }
+
+        if let Some(usage) = chunk.usage_metadata {
+            self.usage = StreamUsage {
+                input_tokens: usage.prompt_token_count,
+                output_tokens: usage.candidates_token_count,
+            };
+            self.usage_seen = true;
+        }
+
+        events
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OpenAiChunk {
+    #[serde(default)]
+    choices: Vec<OpenAiChoice>,
+    usage: Option<OpenAiUsage>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OpenAiChoice {
+    #[serde(default)]
+    delta: OpenAiDelta,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OpenAiDelta {
+    content: Option<String>,
+    #[serde(default)]
+    tool_calls: Vec<OpenAiToolCall>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiToolCall {
+    #[serde(default)]
+    index: usize,
+    id: Option<String>,
+    #[serde(default)]
+    function: OpenAiFunction,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OpenAiFunction {
+    name: Option<String>,
+    arguments: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiUsage {
+    prompt_tokens: Option<u64>,
+    completion_tokens: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiChunk {
+    #[serde(default)]
+    candidates: Vec<GeminiCandidate>,
+    usage_metadata: Option<GeminiUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiCandidate {
+    content: Option<GeminiContent>,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeminiContent {
+    #[serde(default)]
+    parts: Vec<GeminiPart>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiPart {
+    text: Option<String>,
+    function_call: Option<GeminiFunctionCall>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeminiFunctionCall {
+    name: String,
+    args: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiUsage {
+    prompt_token_count: Option<u64>,
+    candidates_token_count: Option<u64>,
+}
+
+/// A tool call assembled from [`StreamEvent::ToolCallDelta`] fragments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolCall {
+    pub id: Option<String>,
+    pub name: String,
+    /// The JSON arguments as streamed (may be empty)
+    pub arguments: String,
+}
+
+/// Collects streamed events into the final completion.
+///
+/// Useful for callers that display deltas as they arrive but also need the
+/// complete text, tool calls, and usage afterwards.
+///
+/// ## Examples
+///
+/// ```
+/// use unchained_ai::rigging::providers::streaming::{CompletionAccumulator, StreamEvent};
+///
+/// let mut acc = CompletionAccumulator::default();
+/// acc.push(&StreamEvent::TextDelta("Hello, ".to_string()));
+/// acc.push(&StreamEvent::TextDelta("world".to_string()));
+/// acc.push(&StreamEvent::Done { stop_reason: Some("stop".to_string()) });
+///
+/// assert_eq!(acc.text, "Hello, world");
+/// assert!(acc.done);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompletionAccumulator {
+    /// All text deltas concatenated
+    pub text: String,
+    /// Tool calls ordered by their index
+    pub tool_calls: BTreeMap<usize, ToolCall>,
+    /// The last reported usage
+    pub usage: Option<StreamUsage>,
+    /// The stop reason from the `Done` event
+    pub stop_reason: Option<String>,
+    /// Whether the `Done` event has been seen
+    pub done: bool,
+}
+
+impl CompletionAccumulator {
+    /// Applies one event.
+    pub fn push(&mut self, event: &StreamEvent) {
+        match event {
+            StreamEvent::TextDelta(text) => self.text.push_str(text),
+            StreamEvent::ToolCallDelta {
+                index,
+                id,
+                name,
+                arguments,
+            } => {
+                let call = self.tool_calls.entry(*index).or_default();
+                if id.is_some() {
+                    call.id = id.clone();
+                }
+                if let Some(name) = name {
+                    call.name.push_str(name);
+                }
+                call.arguments.push_str(arguments);
+            }
+            StreamEvent::Usage(usage) => self.usage = Some(*usage),
+            StreamEvent::Done { stop_reason } => {
+                self.stop_reason = stop_reason.clone();
+                self.done = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn decode_all(format: StreamFormat, sse: &str) -> Vec<StreamEvent> {
+        let mut parser = SseParser::default();
+        let mut decoder = StreamDecoder::new(format, "test");
+        let mut events = Vec::new();
+        for event in parser
+            .push(sse.as_bytes())
+            .into_iter()
+            .chain(parser.finish())
+        {
+            events.extend(decoder.decode(&event).unwrap());
+        }
+        events.extend(decoder.finish());
+        events
+    }
+
+    fn sse_response(body: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200)
+            .insert_header("Content-Type", "text/event-stream")
+            .set_body_string(body.to_string())
+    }
+
+    #[test]
+    fn test_decode_openai_text_tools_and_usage() {
+        let sse = concat!(
+            "data: {\"choices\":[{\"delta\":{\"role\":\"assistant\",\"content\":\"Hel\"}}]}\n\n",
+            "data: {\"choices\":[{\"delta\":{\"content\":\"lo\"}}]}\n\n",
+            "data: {\"choices\":[{\"delta\":{\"tool_calls\":[{\"index\":0,\"id\":\"call_1\",\"function\":{\"name\":\"search\",\"arguments\":\"\"}}]}}]}\n\n",
+            "data: {\"choices\":[{\"delta\":{\"tool_calls\":[{\"index\":0,\"function\":{\"arguments\":\"{\\\"q\\\":1}\"}}]},\"finish_reason\":\"tool_calls\"}]}\n\n",
+            "data: {\"choices\":[],\"usage\":{\"prompt_tokens\":5,\"completion_tokens\":7}}\n\n",
+            "data: [DONE]\n\n",
+        );
+
+        let events = decode_all(StreamFormat::OpenAiCompatible, sse);
+
+        assert_eq!(events[0], StreamEvent::TextDelta("Hel".to_string()));
+        assert_eq!(events[1], StreamEvent::TextDelta("lo".to_string()));
+        assert_eq!(
+            events[2],
+            StreamEvent::ToolCallDelta {
+                index: 0,
+                id: Some("call_1".to_string()),
+                name: Some("search".to_string()),
+                arguments: String::new(),
+            }
+        );
+        assert_eq!(
+            events[4],
+            StreamEvent::Usage(StreamUsage {
+                input_tokens: Some(5),
+                output_tokens: Some(7),
+            })
+        );
+        assert_eq!(
+            events[5],
+            StreamEvent::Done {
+                stop_reason: Some("tool_calls".to_string())
+            }
+        );
+        assert_eq!(events.len(), 6);
+
+        let mut acc = CompletionAccumulator::default();
+        events.iter().for_each(|e| acc.push(e));
+        assert_eq!(acc.text, "Hello");
+        assert_eq!(acc.tool_calls[&0].name, "search");
+        assert_eq!(acc.tool_calls[&0].arguments, "{\"q\":1}");
+    }
+
+    #[test]
+    fn test_decode_anthropic_events() {
+        let sse = concat!(
+            "event: message_start\n",
+            "data: {\"type\":\"message_start\",\"message\":{\"usage\":{\"input_tokens\":12,\"output_tokens\":1}}}\n\n",
+            "event: content_block_start\n",
+            "data: {\"type\":\"content_block_start\",\"index\":0,\"content_block\":{\"type\":\"text\",\"text\":\"\"}}\n\n",
+            "event: content_block_delta\n",
+            "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Hi\"}}\n\n",
+            "event: content_block_start\n",
+            "data: {\"type\":\"content_block_start\",\"index\":1,\"content_block\":{\"type\":\"tool_use\",\"id\":\"toolu_1\",\"name\":\"get_weather\",\"input\":{}}}\n\n",
+            "event: content_block_delta\n",
+            "data: {\"type\":\"content_block_delta\",\"index\":1,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"{\\\"city\\\":\"}}\n\n",
+            "event: ping\n",
+            "data: {\"type\":\"ping\"}\n\n",
+            "event: message_delta\n",
+            "data: {\"type\":\"message_delta\",\"delta\":{\"stop_reason\":\"tool_use\"},\"usage\":{\"output_tokens\":30}}\n\n",
+            "event: message_stop\n",
+            "data: {\"type\":\"message_stop\"}\n\n",
+        );
+
+        let events = decode_all(StreamFormat::Anthropic, sse);
+
+        assert_eq!(
+            events,
+            vec![
+                StreamEvent::TextDelta("Hi".to_string()),
+                StreamEvent::ToolCallDelta {
+                    index: 1,
+                    id: Some("toolu_1".to_string()),
+                    name: Some("get_weather".to_string()),
+                    arguments: String::new(),
+                },
+                StreamEvent::ToolCallDelta {
+                    index: 1,
+                    id: None,
+                    name: None,
+                    arguments: "{\"city\":".to_string(),
+                },
+                StreamEvent::Usage(StreamUsage {
+                    input_tokens: Some(12),
+                    output_tokens: Some(30),
+                }),
+                StreamEvent::Done {
+                    stop_reason: Some("tool_use".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_gemini_chunks() {
+        let sse = concat!(
+            "data: {\"candidates\":[{\"content\":{\"parts\":[{\"text\":\"Hello\"}],\"role\":\"model\"}}],\"usageMetadata\":{\"promptTokenCount\":4,\"candidatesTokenCount\":1}}\n\n",
+            "data: {\"candidates\":[{\"content\":{\"parts\":[{\"functionCall\":{\"name\":\"lookup\",\"args\":{\"id\":7}}}]},\"finishReason\":\"STOP\"}],\"usageMetadata\":{\"promptTokenCount\":4,\"candidatesTokenCount\":9}}\n\n",
+        );
+
+        let events = decode_all(StreamFormat::Gemini, sse);
+
+        assert_eq!(
+            events,
+            vec![
+                StreamEvent::TextDelta("Hello".to_string()),
+                StreamEvent::ToolCallDelta {
+                    index: 0,
+                    id: None,
+                    name: Some("lookup".to_string()),
+                    arguments: "{\"id\":7}".to_string(),
+                },
+                StreamEvent::Usage(StreamUsage {
+                    input_tokens: Some(4),
+                    output_tokens: Some(9),
+                }),
+                StreamEvent::Done {
+                    stop_reason: Some("STOP".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_error_event() {
+        let mut decoder = StreamDecoder::new(StreamFormat::Anthropic, "anthropic");
+        let event = SseEvent {
+            event: Some("error".to_string()),
+            data: r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#
+                .to_string(),
+            ..Default::default()
+        };
+
+        let err = decoder.decode(&event).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Streaming failed for anthropic: Overloaded"
+        );
+    }
+
+    #[test]
+    fn test_request_bodies() {
+        let request = StreamRequest::new("m", "hi")
+            .with_system("be brief")
+            .with_max_tokens(100);
+
+        let openai = request.to_body(StreamFormat::OpenAiCompatible);
+        assert_eq!(openai["messages"][0]["role"], "system");
+        assert_eq!(openai["messages"][1]["content"], "hi");
+        assert_eq!(openai["stream"], true);
+        assert_eq!(openai["max_tokens"], 100);
+
+        let anthropic = request.to_body(StreamFormat::Anthropic);
+        assert_eq!(anthropic["system"], "be brief");
+        assert_eq!(anthropic["max_tokens"], 100);
+        assert_eq!(
+            StreamRequest::new("m", "hi").to_body(StreamFormat::Anthropic)["max_tokens"],
+            DEFAULT_MAX_TOKENS
+        );
+
+        let gemini = request.to_body(StreamFormat::Gemini);
+        assert_eq!(gemini["systemInstruction"]["parts"][0]["text"], "be brief");
+        assert_eq!(gemini["generationConfig"]["maxOutputTokens"], 100);
+        assert!(gemini.get("model").is_none());
+    }
+
+    #[test]
+    fn test_endpoints() {
+        let openai = StreamingClient::new(Provider::OpenAi, "k");
+        assert_eq!(
+            openai.endpoint("gpt-4o"),
+            "https://api.openai.com/v1/chat/completions"
+        );
+        let zai = StreamingClient::new(Provider::Zai, "k");
+        assert_eq!(
+            zai.endpoint("glm-4"),
+            "https://open.bigmodel.cn/api/paas/v4/chat/completions"
+        );
+        let gemini = StreamingClient::new(Provider::Gemini, "k");
+        assert_eq!(
+            gemini.endpoint("gemini-2.5-flash"),
+            "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.5-flash:streamGenerateContent?alt=sse"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_openai_compatible() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(header("Authorization", "Bearer test-key"))
+            .and(body_partial_json(json!({"model": "gpt-4o", "stream": true})))
+            .respond_with(sse_response(concat!(
+                "data: {\"choices\":[{\"delta\":{\"content\":\"Hi\"},\"finish_reason\":\"stop\"}]}\n\n",
+                "data: [DONE]\n\n",
+            )))
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            StreamingClient::new(Provider::OpenAi, "test-key").with_base_url(mock_server.uri());
+        let events: Vec<StreamEvent> = client
+            .stream(&StreamRequest::new("gpt-4o", "hello"))
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(
+            events,
+            vec![
+                StreamEvent::TextDelta("Hi".to_string()),
+                StreamEvent::Done {
+                    stop_reason: Some("stop".to_string())
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_anthropic_headers() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/messages"))
+            .and(header("x-api-key", "test-key"))
+            .and(header("anthropic-version", ANTHROPIC_VERSION))
+            .respond_with(sse_response(
+                "event: message_stop\ndata: {\"type\":\"message_stop\"}\n\n",
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            StreamingClient::new(Provider::Anthropic, "test-key").with_base_url(mock_server.uri());
+        let events: Vec<_> = client
+            .stream(&StreamRequest::new("claude", "hello"))
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Ok(StreamEvent::Done { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_stream_maps_http_errors() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&mock_server)
+            .await;
+
+        let client = StreamingClient::new(Provider::Groq, "bad").with_base_url(mock_server.uri());
+        let result = client.stream(&StreamRequest::new("llama", "hello")).await;
+
+        assert!(matches!(
+            result,
+            Err(ProviderError::AuthenticationFailed { .. })
+        ));
+    }
+}
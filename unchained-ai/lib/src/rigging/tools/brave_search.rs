@@ -14,6 +14,17 @@
 //! - `base`: 20 requests per second
 //! - `pro`: 50 requests per second
 //!
+//! Limits are enforced by a token bucket ([`BraveRateLimiter`]) shared by every
+//! clone of the tool, so parallel agents handed clones of one tool stay within
+//! the plan together. Requests rejected with `429 Too Many Requests` pause all
+//! of them until the API's retry window has passed, then retry.
+//!
+//! ## Caching
+//!
+//! With a [`ToolCache`] attached via [`BraveSearchTool::with_cache`], results
+//! are stored on disk keyed by the search arguments and reused until the
+//! cache's TTL expires, without spending any of the plan's request budget.
+//!
 //! ## Example
 //!
 //! ```rust,ignore
@@ -33,15 +44,17 @@
 //! }
 //! ```
 
-use reqwest::Client;
+use super::cache::ToolCache;
+use reqwest::{Client, RequestBuilder, StatusCode};
 use rig::completion::ToolDefinition;
 use rig::tool::Tool;
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use thiserror::Error;
 use tokio::sync::Mutex;
+use tokio::time::Instant;
 use tracing::{Span, debug, info, instrument, warn};
 
 /// Brave Search API plan tier, determines rate limiting.
@@ -83,38 +96,100 @@ impl BravePlan {
             Self::Pro => 50,
         }
     }
+
+    /// Get the number of requests that may be sent back-to-back.
+    ///
+    /// Requests beyond the burst are spaced at the plan's rate.
+    pub fn burst(&self) -> u32 {
+        match self {
+            Self::Free => 1,
+            Self::Base | Self::Pro => self.requests_per_second(),
+        }
+    }
 }
 
-/// Shared rate limiter for Brave Search requests.
-#[derive(Clone)]
-struct RateLimiter {
-    last_request: Arc<Mutex<Option<Instant>>>,
-    min_interval: Duration,
+/// Token-bucket rate limiter for Brave Search requests.
+///
+/// The bucket holds up to [`BravePlan::burst`] tokens and refills at the
+/// plan's requests per second; each request takes one token. Clones share
+/// the same bucket, so every agent holding a clone of a [`BraveSearchTool`]
+/// draws from a single budget.
+///
+/// When the API answers with `429 Too Many Requests`, [`pause_for`](Self::pause_for)
+/// empties the bucket and holds back every clone until the API's retry window
+/// has passed.
+#[derive(Debug, Clone)]
+pub struct BraveRateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+    capacity: f64,
+    refill_per_second: f64,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+    paused_until: Option<Instant>,
 }
 
-impl RateLimiter {
-    fn new(plan: BravePlan) -> Self {
+impl BraveRateLimiter {
+    /// Create a full bucket sized for `plan`.
+    pub fn new(plan: BravePlan) -> Self {
+        let capacity = f64::from(plan.burst());
         Self {
-            last_request: Arc::new(Mutex::new(None)),
-            min_interval: plan.min_interval(),
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: capacity,
+                updated: Instant::now(),
+                paused_until: None,
+            })),
+            capacity,
+            refill_per_second: f64::from(plan.requests_per_second()),
         }
     }
 
-    /// Wait if necessary to respect the rate limit, then mark this request.
-    async fn acquire(&self) {
-        let mut last = self.last_request.lock().await;
-        if let Some(last_time) = *last {
-            let elapsed = last_time.elapsed();
-            if elapsed < self.min_interval {
-                let wait_time = self.min_interval - elapsed;
-                debug!(
-                    wait_ms = wait_time.as_millis() as u64,
-                    "Rate limiting: waiting before next request"
-                );
-                tokio::time::sleep(wait_time).await;
-            }
+    /// Wait until a token is available, then take it.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                let now = Instant::now();
+
+                match bucket.paused_until {
+                    Some(until) if until > now => until - now,
+                    _ => {
+                        bucket.paused_until = None;
+                        let refilled = now.duration_since(bucket.updated).as_secs_f64()
+                            * self.refill_per_second;
+                        bucket.tokens = (bucket.tokens + refilled).min(self.capacity);
+                        bucket.updated = now;
+
+                        if bucket.tokens >= 1.0 {
+                            bucket.tokens -= 1.0;
+                            return;
+                        }
+                        Duration::from_secs_f64((1.0 - bucket.tokens) / self.refill_per_second)
+                    }
+                }
+            };
+
+            debug!(
+                wait_ms = wait.as_millis() as u64,
+                "Rate limiting: waiting before next request"
+            );
+            tokio::time::sleep(wait).await;
         }
-        *last = Some(Instant::now());
+    }
+
+    /// Empty the bucket and block every clone for at least `duration`.
+    pub async fn pause_for(&self, duration: Duration) {
+        let mut bucket = self.bucket.lock().await;
+        let until = Instant::now() + duration;
+        let until = bucket
+            .paused_until
+            .map_or(until, |current| current.max(until));
+        bucket.paused_until = Some(until);
+        bucket.tokens = 0.0;
+        bucket.updated = until;
     }
 }
 
@@ -282,29 +357,59 @@ pub enum BraveSearchError {
     ConfigError(String),
 }
 
+/// How many times a request rejected with `429 Too Many Requests` is retried.
+const MAX_RATE_LIMIT_RETRIES: u32 = 2;
+
+/// How long the API asks us to wait before retrying.
+///
+/// Reads `Retry-After`, falling back to the first (per-second) window of
+/// Brave's `X-RateLimit-Reset` header.
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let seconds = |name: &str| {
+        headers
+            .get(name)?
+            .to_str()
+            .ok()?
+            .split(',')
+            .next()?
+            .trim()
+            .parse::<u64>()
+            .ok()
+    };
+
+    seconds("retry-after")
+        .or_else(|| seconds("x-ratelimit-reset"))
+        .map(Duration::from_secs)
+}
+
 /// Brave Search tool for rig-core agents.
 ///
 /// This tool enables AI agents to search the web using the Brave Search API.
 /// It returns structured search results with titles, URLs, and snippets.
 ///
 /// The tool implements rate limiting based on the configured plan tier.
-/// When cloned, all instances share the same rate limiter to ensure
-/// the rate limit is respected across concurrent usage.
+/// When cloned, all instances share the same [`BraveRateLimiter`] to ensure
+/// the rate limit is respected across concurrent usage; separately created
+/// tools can share one via [`with_rate_limiter`](Self::with_rate_limiter).
+/// Requests rejected with `429 Too Many Requests` are retried after the
+/// API's retry window.
 #[derive(Clone)]
 pub struct BraveSearchTool {
     config: BraveSearchConfig,
     client: Client,
-    rate_limiter: RateLimiter,
+    rate_limiter: BraveRateLimiter,
+    cache: Option<ToolCache>,
 }
 
 impl BraveSearchTool {
     /// Create a new Brave Search tool with the given configuration.
     pub fn new(config: BraveSearchConfig) -> Self {
-        let rate_limiter = RateLimiter::new(config.plan);
+        let rate_limiter = BraveRateLimiter::new(config.plan);
         Self {
             config,
             client: Client::new(),
             rate_limiter,
+            cache: None,
         }
     }
 
@@ -317,17 +422,70 @@ impl BraveSearchTool {
         Self::new(BraveSearchConfig::from_env())
     }
 
+    /// Use `rate_limiter` instead of this tool's own limiter.
+    ///
+    /// Tools sharing a limiter draw from the same request budget.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, rate_limiter: BraveRateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// The rate limiter shared by clones of this tool.
+    pub fn rate_limiter(&self) -> &BraveRateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Reuse results stored in `cache` and store new ones there.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ToolCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Create a tool with a custom HTTP client (useful for testing).
     #[cfg(test)]
     pub fn with_client(config: BraveSearchConfig, client: Client) -> Self {
-        let rate_limiter = RateLimiter::new(config.plan);
+        let rate_limiter = BraveRateLimiter::new(config.plan);
         Self {
             config,
             client,
             rate_limiter,
+            cache: None,
         }
     }
 
+    /// Build the search request for `args`.
+    fn request(&self, args: &SearchArgs, count: u32, offset: u32) -> RequestBuilder {
+        let mut request = self
+            .client
+            .get(&self.config.endpoint)
+            .header("Accept", "application/json")
+            .header("Accept-Encoding", "gzip")
+            .header("X-Subscription-Token", &self.config.api_key)
+            .query(&[
+                ("q", args.query.as_str()),
+                ("count", &count.to_string()),
+                ("offset", &offset.to_string()),
+            ]);
+
+        // Add optional parameters
+        if let Some(ref country) = args.country {
+            request = request.query(&[("country", country.as_str())]);
+        }
+        if let Some(ref lang) = args.search_lang {
+            request = request.query(&[("search_lang", lang.as_str())]);
+        }
+        if let Some(ref safesearch) = args.safesearch {
+            request = request.query(&[("safesearch", safesearch.as_str())]);
+        }
+        if let Some(ref freshness) = args.freshness {
+            request = request.query(&[("freshness", freshness.as_str())]);
+        }
+
+        request
+    }
+
     /// Perform a web search with the given arguments.
     #[instrument(
         name = "brave_search",
@@ -352,12 +510,23 @@ impl BraveSearchTool {
             ));
         }
 
-        // Acquire rate limit before making request
-        self.rate_limiter.acquire().await;
-
         let count = args.count.unwrap_or(10).clamp(1, 20);
         let offset = args.offset.unwrap_or(0);
 
+        let cache_key = serde_json::to_string(args)
+            .map_err(|e| BraveSearchError::ConfigError(e.to_string()))?;
+        if let Some(cache) = &self.cache
+            && let Some(results) = cache
+                .get::<Vec<SearchResultOutput>>(Self::NAME, &cache_key)
+                .await
+        {
+            info!(
+                tool.results_count = results.len(),
+                "Search served from cache"
+            );
+            return Ok(results);
+        }
+
         debug!(
             country = ?args.country,
             freshness = ?args.freshness,
@@ -365,46 +534,42 @@ impl BraveSearchTool {
             "Executing search"
         );
 
-        let mut request = self
-            .client
-            .get(&self.config.endpoint)
-            .header("Accept", "application/json")
-            .header("Accept-Encoding", "gzip")
-            .header("X-Subscription-Token", &self.config.api_key)
-            .query(&[
-                ("q", args.query.as_str()),
-                ("count", &count.to_string()),
-                ("offset", &offset.to_string()),
-            ]);
-
-        // Add optional parameters
-        if let Some(ref country) = args.country {
-            request = request.query(&[("country", country.as_str())]);
-        }
-        if let Some(ref lang) = args.search_lang {
-            request = request.query(&[("search_lang", lang.as_str())]);
-        }
-        if let Some(ref safesearch) = args.safesearch {
-            request = request.query(&[("safesearch", safesearch.as_str())]);
-        }
-        if let Some(ref freshness) = args.freshness {
-            request = request.query(&[("freshness", freshness.as_str())]);
-        }
-
-        let response = request.send().await;
-
-        match &response {
-            Ok(resp) => {
-                let status = resp.status().as_u16();
-                Span::current().record("http.status_code", status);
-                debug!(http.status_code = status, "Received API response");
+        let mut retries = 0;
+        let response = loop {
+            // Acquire rate limit before making request
+            self.rate_limiter.acquire().await;
+
+            let response = self.request(args, count, offset).send().await;
+
+            match &response {
+                Ok(resp) => {
+                    let status = resp.status().as_u16();
+                    Span::current().record("http.status_code", status);
+                    debug!(http.status_code = status, "Received API response");
+                }
+                Err(e) => {
+                    warn!(error = %e, "Search request failed");
+                }
             }
-            Err(e) => {
-                warn!(error = %e, "Search request failed");
+
+            let response = response?;
+
+            if response.status() == StatusCode::TOO_MANY_REQUESTS
+                && retries < MAX_RATE_LIMIT_RETRIES
+            {
+                let wait =
+                    retry_after(response.headers()).unwrap_or(self.config.plan.min_interval());
+                retries += 1;
+                warn!(
+                    wait_ms = wait.as_millis() as u64,
+                    retries, "Rate limited by Brave API, backing off"
+                );
+                self.rate_limiter.pause_for(wait).await;
+                continue;
             }
-        }
 
-        let response = response?;
+            break response;
+        };
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -435,6 +600,10 @@ impl BraveSearchTool {
             })
             .unwrap_or_default();
 
+        if let Some(cache) = &self.cache {
+            cache.put(Self::NAME, &cache_key, &results).await;
+        }
+
         let elapsed = start.elapsed();
 
         if results.is_empty() {
@@ -459,6 +628,7 @@ impl std::fmt::Debug for BraveSearchTool {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("BraveSearchTool")
             .field("endpoint", &self.config.endpoint)
+            .field("cache", &self.cache.as_ref().map(ToolCache::dir))
             .finish_non_exhaustive()
     }
 }
@@ -592,6 +762,56 @@ mod tests {
         assert_eq!(BravePlan::Pro.requests_per_second(), 50);
     }
 
+    #[test]
+    fn test_brave_plan_burst() {
+        assert_eq!(BravePlan::Free.burst(), 1);
+        assert_eq!(BravePlan::Base.burst(), 20);
+        assert_eq!(BravePlan::Pro.burst(), 50);
+    }
+
+    // ===========================================
+    // Tests for BraveRateLimiter
+    // ===========================================
+
+    #[tokio::test]
+    async fn test_rate_limiter_allows_burst_then_spaces_requests() {
+        let limiter = BraveRateLimiter::new(BravePlan::Pro);
+        let start = Instant::now();
+        for _ in 0..BravePlan::Pro.burst() {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(15));
+
+        // Clones share the bucket, so the next token has to be refilled
+        limiter.clone().acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(15));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_pause_blocks_all_clones() {
+        let limiter = BraveRateLimiter::new(BravePlan::Pro);
+        let other = limiter.clone();
+        let start = Instant::now();
+
+        limiter.pause_for(Duration::from_millis(60)).await;
+        other.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(60));
+    }
+
+    #[test]
+    fn test_retry_after_headers() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1, 1419704"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(1)));
+
+        headers.insert("retry-after", HeaderValue::from_static("3"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(3)));
+    }
+
     // ===========================================
     // Tests for SearchArgs
     // ===========================================
@@ -985,4 +1205,82 @@ mod tests {
         // Assert warning was emitted
         assert!(logs_contain("API returned error"));
     }
+
+    #[tokio::test]
+    async fn test_rate_limited_request_is_retried() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "web": {
+                    "results": [
+                        {"title": "Retried", "url": "https://example.com/", "description": "ok"}
+                    ]
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = BraveSearchConfig::new("test-key")
+            .with_endpoint(format!("{}/search", mock_server.uri()))
+            .with_plan(BravePlan::Pro);
+        let tool = BraveSearchTool::new(config);
+
+        let args = SearchArgs {
+            query: "test".to_string(),
+            ..Default::default()
+        };
+
+        let results = tool.call(args).await.unwrap();
+        assert_eq!(results[0].title, "Retried");
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cached_search_skips_api() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "web": {
+                    "results": [
+                        {"title": "Cached", "url": "https://example.com/", "description": "ok"}
+                    ]
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let config = BraveSearchConfig::new("test-key")
+            .with_endpoint(format!("{}/search", mock_server.uri()))
+            .with_plan(BravePlan::Pro);
+        let tool = BraveSearchTool::new(config).with_cache(ToolCache::new(dir.path()));
+
+        let args = SearchArgs {
+            query: "test".to_string(),
+            ..Default::default()
+        };
+
+        let first = tool.call(args.clone()).await.unwrap();
+        let second = tool.call(args).await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(second[0].title, "Cached");
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+    }
 }
@@ -0,0 +1,209 @@
+//! On-disk cache for web tool results.
+//!
+//! [`ToolCache`] stores each result as a JSON file named after the xxHash of
+//! its key (a search query, page URL or crate item), so repeated lookups are
+//! served from disk until their TTL expires instead of hitting the network.
+//!
+//! ## Layout
+//!
+//! ```text
+//! <dir>/
+//! ├── brave_search/<hash>.json
+//! ├── docs_rs/<hash>.json
+//! ├── github_repo/<hash>.json
+//! └── screen_scrape/<hash>.json
+//! ```
+//!
+//! The cache is best effort: unreadable, corrupt or expired entries count as
+//! misses, and failed writes are logged and ignored.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//! use unchained_ai::rigging::tools::{BraveSearchTool, ScreenScrapeTool, ToolCache};
+//!
+//! let cache = ToolCache::new("/tmp/tool-cache").with_ttl(Duration::from_secs(3600));
+//! let search = BraveSearchTool::from_env().with_cache(cache.clone());
+//! let scraper = ScreenScrapeTool::new().with_cache(cache);
+//! ```
+
+use biscuit_hash::xx_hash;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, warn};
+
+/// A cached value together with the key it was stored under.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry<T> {
+    /// The full key, checked on read to rule out hash collisions
+    key: String,
+    /// When the entry was written, in milliseconds since the Unix epoch
+    stored_at: u64,
+    value: T,
+}
+
+/// On-disk cache of tool results with a time-to-live.
+#[derive(Debug, Clone)]
+pub struct ToolCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl ToolCache {
+    /// How long entries stay fresh unless configured otherwise (24 hours).
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// Create a cache stored under `dir` with the default TTL.
+    ///
+    /// The directory is created on the first write.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl: Self::DEFAULT_TTL,
+        }
+    }
+
+    /// Set how long entries stay fresh.
+    #[must_use]
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// The directory entries are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// How long entries stay fresh.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// The file holding the entry for `key` in `namespace`.
+    fn path(&self, namespace: &str, key: &str) -> PathBuf {
+        self.dir
+            .join(namespace)
+            .join(format!("{:016x}.json", xx_hash(key)))
+    }
+
+    /// Look up a fresh entry for `key` in `namespace`.
+    pub async fn get<T: DeserializeOwned>(&self, namespace: &str, key: &str) -> Option<T> {
+        let path = self.path(namespace, key);
+        let bytes = tokio::fs::read(&path).await.ok()?;
+        let entry: CacheEntry<T> = match serde_json::from_slice(&bytes) {
+            Ok(entry) => entry,
+            Err(e) => {
+                debug!(path = %path.display(), error = %e, "Ignoring unreadable cache entry");
+                return None;
+            }
+        };
+
+        if entry.key != key {
+            return None;
+        }
+        let age = Duration::from_millis(now_millis().saturating_sub(entry.stored_at));
+        if age >= self.ttl {
+            debug!(
+                namespace,
+                key,
+                age_secs = age.as_secs(),
+                "Cache entry expired"
+            );
+            return None;
+        }
+
+        debug!(namespace, key, "Cache hit");
+        Some(entry.value)
+    }
+
+    /// Store `value` for `key` in `namespace`, replacing any previous entry.
+    pub async fn put<T: Serialize>(&self, namespace: &str, key: &str, value: &T) {
+        let path = self.path(namespace, key);
+        let entry = CacheEntry {
+            key: key.to_string(),
+            stored_at: now_millis(),
+            value,
+        };
+
+        let result = async {
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            let json = serde_json::to_vec(&entry)?;
+            tokio::fs::write(&path, json).await
+        }
+        .await;
+
+        if let Err(e) = result {
+            warn!(path = %path.display(), error = %e, "Failed to write cache entry");
+        }
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_put_then_get_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ToolCache::new(dir.path());
+
+        cache
+            .put("brave_search", "rust", &vec!["a".to_string()])
+            .await;
+        let value: Option<Vec<String>> = cache.get("brave_search", "rust").await;
+
+        assert_eq!(value, Some(vec!["a".to_string()]));
+        assert!(dir.path().join("brave_search").is_dir());
+    }
+
+    #[tokio::test]
+    async fn test_missing_key_is_a_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ToolCache::new(dir.path());
+
+        cache.put("brave_search", "rust", &1u32).await;
+
+        assert_eq!(cache.get::<u32>("brave_search", "go").await, None);
+        assert_eq!(cache.get::<u32>("screen_scrape", "rust").await, None);
+    }
+
+    #[tokio::test]
+    async fn test_expired_entry_is_a_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ToolCache::new(dir.path()).with_ttl(Duration::ZERO);
+
+        cache.put("brave_search", "rust", &1u32).await;
+
+        assert_eq!(cache.get::<u32>("brave_search", "rust").await, None);
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_entry_is_a_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ToolCache::new(dir.path());
+        let path = cache.path("brave_search", "rust");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "not json").unwrap();
+
+        assert_eq!(cache.get::<u32>("brave_search", "rust").await, None);
+    }
+
+    #[test]
+    fn test_default_ttl() {
+        let cache = ToolCache::new("/tmp/cache");
+        assert_eq!(cache.ttl(), ToolCache::DEFAULT_TTL);
+        assert_eq!(cache.dir(), Path::new("/tmp/cache"));
+    }
+}
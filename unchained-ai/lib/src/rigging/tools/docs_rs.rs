@@ -0,0 +1,1050 @@
+//! docs.rs Tool for rig-core agents
+//!
+//! Fetches the rustdoc pages that [docs.rs](https://docs.rs) hosts for a crate
+//! and condenses them into markdown: the item declaration, its documentation,
+//! method signatures and, for crates and modules, the items they contain.
+//!
+//! Agents researching Rust libraries use this to quote real API signatures
+//! instead of inventing them.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use unchained_ai::rigging::tools::{DocsArgs, DocsRsTool};
+//! use rig::tool::Tool;
+//!
+//! let tool = DocsRsTool::new();
+//! let args = DocsArgs {
+//!     crate_name: "tokio".to_string(),
+//!     item: Some("sync::Mutex".to_string()),
+//!     ..Default::default()
+//! };
+//!
+//! let page = tool.call(args).await?;
+//! println!("{}", page.declaration.unwrap_or_default());
+//! ```
+
+use super::cache::ToolCache;
+use reqwest::{Client, StatusCode};
+use rig::completion::ToolDefinition;
+use rig::tool::Tool;
+use scraper::{ElementRef, Html, Node, Selector};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use thiserror::Error;
+use tracing::{Span, debug, info, instrument, warn};
+
+/// Documentation beyond this many characters is truncated.
+const MAX_DOCS_CHARS: usize = 12_000;
+
+/// Maximum number of method signatures returned.
+const MAX_SIGNATURES: usize = 80;
+
+/// Maximum number of module items returned.
+const MAX_ITEMS: usize = 200;
+
+/// Input parameters for the docs.rs tool.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DocsArgs {
+    /// Crate name as published on crates.io
+    pub crate_name: String,
+
+    /// Item path within the crate (e.g., `sync::Mutex`); the crate root when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<String>,
+
+    /// Crate version (default: latest)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// An item listed on a crate or module page.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DocsItem {
+    /// Item kind as rustdoc labels it (`struct`, `fn`, `trait`, `mod`, ...)
+    pub kind: String,
+    /// Item name
+    pub name: String,
+    /// First line of the item's documentation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+/// A condensed rustdoc page returned by the tool.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DocsPage {
+    /// Crate name
+    pub crate_name: String,
+    /// Documented crate version, when the page shows it
+    pub version: Option<String>,
+    /// Item path within the crate; `None` for the crate root
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<String>,
+    /// Page URL
+    pub url: String,
+    /// Page heading (e.g., `Struct tokio::sync::Mutex`)
+    pub title: String,
+    /// Item declaration as Rust source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub declaration: Option<String>,
+    /// Item documentation as markdown (possibly truncated)
+    pub docs: String,
+    /// Signatures of inherent and trait methods
+    pub signatures: Vec<String>,
+    /// Items contained in a crate or module
+    pub items: Vec<DocsItem>,
+}
+
+/// Errors that can occur while reading docs.rs.
+#[derive(Debug, Error)]
+pub enum DocsRsError {
+    /// The crate name, version or item path is malformed
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    HttpError(#[from] reqwest::Error),
+
+    /// The crate or version is not on docs.rs
+    #[error("Crate not found on docs.rs: {0}")]
+    NotFound(String),
+
+    /// The crate exists but has no item at the requested path
+    #[error("No item `{item}` in crate {crate_name}")]
+    ItemNotFound {
+        /// Crate name
+        crate_name: String,
+        /// Requested item path
+        item: String,
+    },
+
+    /// docs.rs served a page without rustdoc content (e.g., a failed build)
+    #[error("docs.rs has no documentation for {0}")]
+    MissingDocs(String),
+
+    /// docs.rs returned an error response
+    #[error("docs.rs error (status {status}): {message}")]
+    ApiError {
+        /// HTTP status code
+        status: u16,
+        /// Response body
+        message: String,
+    },
+}
+
+/// Truncate `text` to at most `max_chars` characters, marking the cut.
+fn truncate(text: String, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((cut, _)) => format!("{}\n\n[... truncated]", &text[..cut]),
+        None => text,
+    }
+}
+
+/// Whether `name` is a valid crates.io crate name.
+fn is_crate_name(name: &str) -> bool {
+    name.len() <= 64
+        && name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Whether `version` is a version or version requirement docs.rs can resolve.
+fn is_version(version: &str) -> bool {
+    !version.is_empty()
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ".-+~^=*".contains(c))
+}
+
+/// Normalize an item path to `module::Item` form relative to the crate root.
+///
+/// Accepts `crate_ident::path::Item`, `::path::Item` and `path::Item`.
+fn normalize_item_path(item: &str, crate_ident: &str) -> Result<String, DocsRsError> {
+    let path = item.trim().trim_start_matches("::");
+    let path = path
+        .strip_prefix(crate_ident)
+        .and_then(|rest| rest.strip_prefix("::"))
+        .unwrap_or(if path == crate_ident { "" } else { path });
+
+    let valid = path.split("::").all(|segment| {
+        !segment.is_empty()
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    });
+    if !path.is_empty() && !valid {
+        return Err(DocsRsError::InvalidArgument(format!(
+            "not a Rust item path: {item}"
+        )));
+    }
+    Ok(path.to_string())
+}
+
+/// Parse a CSS selector that is known to be valid.
+fn selector(css: &str) -> Selector {
+    Selector::parse(css).expect("static selector is valid")
+}
+
+/// Whether an element is rustdoc chrome rather than content.
+fn is_chrome(element: &ElementRef<'_>) -> bool {
+    let value = element.value();
+    matches!(
+        value.name(),
+        "button" | "script" | "style" | "rustdoc-toolbar"
+    ) || value.classes().any(|class| {
+        matches!(
+            class,
+            "doc-anchor" | "anchor" | "out-of-band" | "src" | "rightside" | "tooltip"
+        )
+    })
+}
+
+/// Collapse runs of whitespace into single spaces.
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Render the inline content of an element as markdown.
+fn inline_markdown(element: ElementRef<'_>) -> String {
+    let mut out = String::new();
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(text),
+            Node::Element(_) => {
+                let Some(child) = ElementRef::wrap(child) else {
+                    continue;
+                };
+                if is_chrome(&child) {
+                    continue;
+                }
+                match child.value().name() {
+                    "code" => out.push_str(&format!("`{}`", child.text().collect::<String>())),
+                    "strong" | "b" => out.push_str(&format!("**{}**", inline_markdown(child))),
+                    "em" | "i" => out.push_str(&format!("*{}*", inline_markdown(child))),
+                    "br" => out.push(' '),
+                    _ => out.push_str(&inline_markdown(child)),
+                }
+            }
+            _ => {}
+        }
+    }
+    collapse_whitespace(&out)
+}
+
+/// Plain text of an element with rustdoc chrome removed.
+fn plain_text(element: ElementRef<'_>) -> String {
+    let mut out = String::new();
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(text),
+            Node::Element(_) => {
+                if let Some(child) = ElementRef::wrap(child)
+                    && !is_chrome(&child)
+                {
+                    out.push_str(&plain_text(child));
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Render a rustdoc docblock as markdown, appending to `out`.
+fn block_markdown(element: ElementRef<'_>, out: &mut String) {
+    for child in element.children() {
+        let Some(child) = ElementRef::wrap(child) else {
+            if let Node::Text(text) = child.value() {
+                let text = collapse_whitespace(text);
+                if !text.is_empty() {
+                    out.push_str(&format!("{text}\n\n"));
+                }
+            }
+            continue;
+        };
+        if is_chrome(&child) {
+            continue;
+        }
+
+        let name = child.value().name();
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = name[1..].parse::<usize>().unwrap_or(2);
+                out.push_str(&format!(
+                    "{} {}\n\n",
+                    "#".repeat(level),
+                    inline_markdown(child)
+                ));
+            }
+            "p" => {
+                let text = inline_markdown(child);
+                if !text.is_empty() {
+                    out.push_str(&format!("{text}\n\n"));
+                }
+            }
+            "pre" => {
+                let language = if child.value().classes().any(|class| class == "rust") {
+                    "rust"
+                } else {
+                    ""
+                };
+                let code = plain_text(child);
+                out.push_str(&format!("```{language}\n{}\n```\n\n", code.trim_end()));
+            }
+            "ul" | "ol" => {
+                let items = child
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .filter(|li| li.value().name() == "li");
+                for (i, li) in items.enumerate() {
+                    let marker = if name == "ol" {
+                        format!("{}.", i + 1)
+                    } else {
+                        "-".to_string()
+                    };
+                    out.push_str(&format!("{marker} {}\n", inline_markdown(li)));
+                }
+                out.push('\n');
+            }
+            "blockquote" => {
+                out.push_str(&format!("> {}\n\n", inline_markdown(child)));
+            }
+            "tr" => {
+                let cells: Vec<String> = child
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .map(inline_markdown)
+                    .collect();
+                out.push_str(&format!("| {} |\n", cells.join(" | ")));
+            }
+            "table" | "thead" | "tbody" => {
+                block_markdown(child, out);
+                if name == "table" {
+                    out.push('\n');
+                }
+            }
+            _ => block_markdown(child, out),
+        }
+    }
+}
+
+/// Extract the items listed in a crate or module page's item tables.
+fn extract_items(document: &Html) -> Vec<DocsItem> {
+    let entry = |link: ElementRef<'_>, summary: Option<ElementRef<'_>>| DocsItem {
+        kind: link.value().classes().next().unwrap_or("item").to_string(),
+        name: collapse_whitespace(&plain_text(link)),
+        summary: summary
+            .map(inline_markdown)
+            .filter(|summary| !summary.is_empty()),
+    };
+
+    // Current rustdoc: <dl class="item-table"><dt><a>..</a></dt><dd>..</dd>
+    let mut items: Vec<DocsItem> = document
+        .select(&selector("dl.item-table > dt"))
+        .filter_map(|dt| {
+            let link = dt.select(&selector("a")).next()?;
+            let summary = dt
+                .next_siblings()
+                .filter_map(ElementRef::wrap)
+                .next()
+                .filter(|sibling| sibling.value().name() == "dd");
+            Some(entry(link, summary))
+        })
+        .collect();
+
+    // Older rustdoc: rows with .item-name and .desc cells
+    if items.is_empty() {
+        items = document
+            .select(&selector(".item-table .item-name"))
+            .filter_map(|name| {
+                let link = name.select(&selector("a")).next()?;
+                let summary = name
+                    .next_siblings()
+                    .filter_map(ElementRef::wrap)
+                    .find(|sibling| sibling.value().classes().any(|class| class == "desc"));
+                Some(entry(link, summary))
+            })
+            .collect();
+    }
+
+    items.truncate(MAX_ITEMS);
+    items
+}
+
+/// Condense a rustdoc page into a [`DocsPage`].
+///
+/// Returns `None` when the HTML has no rustdoc main content.
+fn parse_page(html: &str, crate_name: &str, item: Option<String>, url: String) -> Option<DocsPage> {
+    let document = Html::parse_document(html);
+    let main = document.select(&selector("#main-content")).next()?;
+
+    let title = main
+        .select(&selector("h1"))
+        .next()
+        .map(|h1| collapse_whitespace(&plain_text(h1)))
+        .unwrap_or_else(|| crate_name.to_string());
+
+    let version = document
+        .select(&selector(".sidebar-crate .version, .sidebar .version"))
+        .next()
+        .map(|version| collapse_whitespace(&plain_text(version)))
+        .filter(|version| !version.is_empty());
+
+    let declaration = main
+        .select(&selector(".item-decl"))
+        .next()
+        .map(|decl| plain_text(decl).trim().to_string())
+        .filter(|decl| !decl.is_empty());
+
+    let mut docs = String::new();
+    if let Some(docblock) = main
+        .select(&selector("details.top-doc > .docblock"))
+        .next()
+        .or_else(|| main.select(&selector("#main-content > .docblock")).next())
+    {
+        block_markdown(docblock, &mut docs);
+    }
+
+    let signatures = main
+        .select(&selector(
+            "#implementations-list .code-header, \
+             #required-methods .code-header, \
+             #provided-methods .code-header",
+        ))
+        .map(|header| collapse_whitespace(&plain_text(header)))
+        .filter(|signature| !signature.is_empty())
+        .take(MAX_SIGNATURES)
+        .collect();
+
+    Some(DocsPage {
+        crate_name: crate_name.to_string(),
+        version,
+        item,
+        url,
+        title,
+        declaration,
+        docs: truncate(docs.trim_end().to_string(), MAX_DOCS_CHARS),
+        signatures,
+        items: extract_items(&document),
+    })
+}
+
+/// Find the page for `path` in a crate's `all.html` item list.
+///
+/// Prefers an exact path match; a bare name matches the first item with that
+/// name in any module. Returns the page's path relative to the crate root.
+fn find_item_page(all_items_html: &str, path: &str) -> Option<(String, String)> {
+    let document = Html::parse_document(all_items_html);
+    let entries: Vec<(String, String)> = document
+        .select(&selector(".all-items a[href]"))
+        .filter_map(|link| {
+            let href = link.value().attr("href")?;
+            Some((collapse_whitespace(&plain_text(link)), href.to_string()))
+        })
+        .collect();
+
+    let exact = entries.iter().find(|(name, _)| name == path);
+    let by_name = || {
+        (!path.contains("::"))
+            .then(|| {
+                entries
+                    .iter()
+                    .find(|(name, _)| name.rsplit("::").next() == Some(path))
+            })
+            .flatten()
+    };
+    exact.or_else(by_name).cloned()
+}
+
+/// docs.rs documentation tool for rig-core agents.
+///
+/// Gives agents the documented API of a Rust crate (declarations, method
+/// signatures and item docs) so answers cite signatures that actually exist.
+#[derive(Clone)]
+pub struct DocsRsTool {
+    base_url: String,
+    client: Client,
+    cache: Option<ToolCache>,
+}
+
+impl DocsRsTool {
+    /// Create a new docs.rs tool.
+    pub fn new() -> Self {
+        Self {
+            base_url: "https://docs.rs".to_string(),
+            client: Client::new(),
+            cache: None,
+        }
+    }
+
+    /// Set a custom base URL (useful for testing or docs.rs mirrors).
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Reuse results stored in `cache` and store new ones there.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ToolCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Fetch a page, returning `Ok(None)` for `404 Not Found`.
+    async fn fetch(&self, url: &str) -> Result<Option<String>, DocsRsError> {
+        let response = self
+            .client
+            .get(url)
+            .header("User-Agent", "unchained-ai")
+            .header("Accept", "text/html")
+            .timeout(Duration::from_secs(15))
+            .send()
+            .await?;
+        let status = response.status();
+        Span::current().record("http.status_code", status.as_u16());
+
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unable to read error response".to_string());
+            warn!(status = status.as_u16(), "docs.rs returned error");
+            return Err(DocsRsError::ApiError {
+                status: status.as_u16(),
+                message: truncate(message, 500),
+            });
+        }
+
+        Ok(Some(response.text().await?))
+    }
+
+    /// Read the documentation page described by `args`.
+    #[instrument(
+        name = "docs_rs",
+        skip(self, args),
+        fields(
+            tool.name = "docs_rs",
+            tool.crate_name = %args.crate_name,
+            tool.item = args.item.as_deref().unwrap_or(""),
+            otel.kind = "client"
+        )
+    )]
+    async fn read(&self, args: &DocsArgs) -> Result<DocsPage, DocsRsError> {
+        let start = std::time::Instant::now();
+
+        let crate_name = args.crate_name.trim();
+        if !is_crate_name(crate_name) {
+            return Err(DocsRsError::InvalidArgument(format!(
+                "not a crate name: {}",
+                args.crate_name
+            )));
+        }
+        let version = args
+            .version
+            .as_deref()
+            .map(str::trim)
+            .filter(|version| !version.is_empty())
+            .unwrap_or("latest");
+        if !is_version(version) {
+            return Err(DocsRsError::InvalidArgument(format!(
+                "not a version: {version}"
+            )));
+        }
+        let crate_ident = crate_name.replace('-', "_");
+        let path = match args.item.as_deref() {
+            Some(item) => normalize_item_path(item, &crate_ident)?,
+            None => String::new(),
+        };
+
+        let cache_key = format!("{crate_name}@{version} {path}");
+        if let Some(cache) = &self.cache
+            && let Some(page) = cache.get::<DocsPage>(Self::NAME, &cache_key).await
+        {
+            info!("Documentation served from cache");
+            return Ok(page);
+        }
+
+        let root = format!(
+            "{}/{crate_name}/{version}/{crate_ident}",
+            self.base_url.trim_end_matches('/')
+        );
+        let not_found = || DocsRsError::NotFound(format!("{crate_name}@{version}"));
+
+        let (url, html, item) = if path.is_empty() {
+            let url = format!("{root}/");
+            let html = self.fetch(&url).await?.ok_or_else(not_found)?;
+            (url, html, None)
+        } else {
+            debug!(%path, "Resolving item");
+            let all_items = self
+                .fetch(&format!("{root}/all.html"))
+                .await?
+                .ok_or_else(not_found)?;
+            let item_not_found = || DocsRsError::ItemNotFound {
+                crate_name: crate_name.to_string(),
+                item: path.clone(),
+            };
+
+            match find_item_page(&all_items, &path) {
+                Some((item, href)) => {
+                    let url = format!("{root}/{}", href.trim_start_matches("./"));
+                    let html = self.fetch(&url).await?.ok_or_else(item_not_found)?;
+                    (url, html, Some(item))
+                }
+                // all.html doesn't list modules
+                None => {
+                    let url = format!("{root}/{}/index.html", path.replace("::", "/"));
+                    let html = self.fetch(&url).await?.ok_or_else(item_not_found)?;
+                    (url, html, Some(path.clone()))
+                }
+            }
+        };
+
+        let page = parse_page(&html, crate_name, item, url).ok_or_else(|| {
+            warn!("Page has no rustdoc content");
+            DocsRsError::MissingDocs(format!("{crate_name}@{version}"))
+        })?;
+
+        if let Some(cache) = &self.cache {
+            cache.put(Self::NAME, &cache_key, &page).await;
+        }
+
+        info!(
+            tool.signatures_count = page.signatures.len(),
+            tool.items_count = page.items.len(),
+            tool.duration_ms = start.elapsed().as_millis() as u64,
+            "Documentation read"
+        );
+
+        Ok(page)
+    }
+}
+
+impl Default for DocsRsTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for DocsRsTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DocsRsTool")
+            .field("base_url", &self.base_url)
+            .field("cache", &self.cache.as_ref().map(ToolCache::dir))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Tool for DocsRsTool {
+    const NAME: &'static str = "docs_rs";
+
+    type Error = DocsRsError;
+    type Args = DocsArgs;
+    type Output = DocsPage;
+
+    async fn definition(&self, _prompt: String) -> ToolDefinition {
+        ToolDefinition {
+            name: "docs_rs".to_string(),
+            description: "Read a Rust crate's API documentation from docs.rs. Returns the item's \
+                declaration, its documentation as markdown, method signatures and, for crates \
+                and modules, the items they contain. Start with the crate root to see what it \
+                exports, then request specific items. Use this tool whenever you need exact \
+                type names or function signatures for a Rust crate."
+                .to_string(),
+            parameters: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "crate_name": {
+                        "type": "string",
+                        "description": "Crate name as published on crates.io (e.g., tokio)"
+                    },
+                    "item": {
+                        "type": "string",
+                        "description": "Item path within the crate (e.g., sync::Mutex or Mutex). Omit for the crate root."
+                    },
+                    "version": {
+                        "type": "string",
+                        "description": "Crate version (default: latest)"
+                    }
+                },
+                "required": ["crate_name"]
+            }),
+        }
+    }
+
+    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
+        self.read(&args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const CRATE_ROOT_HTML: &str = r##"<html><body>
+        <nav class="sidebar"><div class="sidebar-crate"><h2><a href="../tokio/index.html">tokio</a><span class="version">1.44.0</span></h2></div></nav>
+        <main><section id="main-content" class="content">
+          <div class="main-heading"><h1>Crate <span>tokio</span><button id="copy-path">Copy item path</button></h1>
+            <rustdoc-toolbar></rustdoc-toolbar><span class="out-of-band"><a class="src" href="../src/tokio/lib.rs.html">Source</a></span></div>
+          <details class="toggle top-doc" open><summary class="hideme"><span>Expand description</span></summary>
+            <div class="docblock">
+              <p>A runtime for writing <strong>reliable</strong> applications with <code>async</code>.</p>
+              <h2 id="a-tour"><a class="doc-anchor" href="#a-tour">§</a>A Tour</h2>
+              <ul><li>Tools for <a href="sync/index.html">synchronization</a></li><li>Timers</li></ul>
+              <div class="example-wrap"><pre class="rust rust-example-rendered"><code><span class="kw">let </span>rt = Runtime::new();</code></pre></div>
+            </div>
+          </details>
+          <h2 id="modules" class="section-header">Modules<a href="#modules" class="anchor">§</a></h2>
+          <dl class="item-table">
+            <dt><a class="mod" href="sync/index.html" title="mod tokio::sync">sync</a></dt><dd>Synchronization primitives.</dd>
+            <dt><a class="macro" href="macro.select.html">select</a></dt>
+          </dl>
+        </section></main></body></html>"##;
+
+    const ALL_ITEMS_HTML: &str = r##"<html><body><section id="main-content">
+        <h3 id="structs">Structs</h3>
+        <ul class="all-items">
+          <li><a href="runtime/struct.Runtime.html">runtime::Runtime</a></li>
+          <li><a href="sync/struct.Mutex.html">sync::Mutex</a></li>
+        </ul></section></body></html>"##;
+
+    const MUTEX_HTML: &str = r##"<html><body><section id="main-content">
+        <h1>Struct <a class="mod" href="../index.html">tokio</a>::<wbr><a class="mod" href="index.html">sync</a>::<wbr><span class="struct">Mutex</span></h1>
+        <pre class="rust item-decl"><code>pub struct Mutex&lt;T: ?<a class="trait" href="#">Sized</a>&gt; { <span class="comment">/* private fields */</span> }</code></pre>
+        <details class="toggle top-doc" open><div class="docblock"><p>An asynchronous <code>Mutex</code>-like type.</p></div></details>
+        <div id="implementations-list">
+          <details class="toggle implementors-toggle" open><summary><section class="impl"><a class="src rightside" href="#">Source</a><h3 class="code-header">impl&lt;T: ?Sized&gt; Mutex&lt;T&gt;</h3></section></summary>
+            <div class="impl-items">
+              <section id="method.lock" class="method"><a class="src rightside" href="#">Source</a><h4 class="code-header">pub async fn <a class="fn" href="#method.lock">lock</a>(&amp;self) -&gt; MutexGuard&lt;'_, T&gt;</h4></section>
+            </div>
+          </details>
+        </div>
+        <div id="trait-implementations-list"><section class="impl"><h3 class="code-header">impl&lt;T&gt; Debug for Mutex&lt;T&gt;</h3></section></div>
+        </section></body></html>"##;
+
+    fn tool_for(server: &MockServer) -> DocsRsTool {
+        DocsRsTool::new().with_base_url(server.uri())
+    }
+
+    async fn mock_page(server: &MockServer, page: &str, html: &str) {
+        Mock::given(method("GET"))
+            .and(path(page))
+            .respond_with(ResponseTemplate::new(200).set_body_string(html))
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn test_is_crate_name() {
+        assert!(is_crate_name("tokio"));
+        assert!(is_crate_name("serde_json"));
+        assert!(is_crate_name("biscuit-hash"));
+        assert!(!is_crate_name(""));
+        assert!(!is_crate_name("1password"));
+        assert!(!is_crate_name("../etc"));
+        assert!(!is_crate_name("tokio sync"));
+    }
+
+    #[test]
+    fn test_is_version() {
+        assert!(is_version("latest"));
+        assert!(is_version("1.44.0"));
+        assert!(is_version("^1.2"));
+        assert!(is_version("1.0.0-beta.1+build"));
+        assert!(!is_version(""));
+        assert!(!is_version("1.0/../.."));
+    }
+
+    #[test]
+    fn test_normalize_item_path() {
+        assert_eq!(
+            normalize_item_path("tokio::sync::Mutex", "tokio").unwrap(),
+            "sync::Mutex"
+        );
+        assert_eq!(
+            normalize_item_path("::sync::Mutex", "tokio").unwrap(),
+            "sync::Mutex"
+        );
+        assert_eq!(normalize_item_path("Mutex", "tokio").unwrap(), "Mutex");
+        assert_eq!(normalize_item_path("tokio", "tokio").unwrap(), "");
+        assert_eq!(
+            normalize_item_path("tokio_util::codec", "tokio").unwrap(),
+            "tokio_util::codec"
+        );
+        assert!(normalize_item_path("sync/Mutex", "tokio").is_err());
+        assert!(normalize_item_path("sync::", "tokio").is_err());
+    }
+
+    #[test]
+    fn test_parse_crate_root() {
+        let page = parse_page(
+            CRATE_ROOT_HTML,
+            "tokio",
+            None,
+            "https://docs.rs/tokio/latest/tokio/".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(page.title, "Crate tokio");
+        assert_eq!(page.version.as_deref(), Some("1.44.0"));
+        assert_eq!(page.declaration, None);
+        assert!(
+            page.docs
+                .starts_with("A runtime for writing **reliable** applications with `async`.")
+        );
+        assert!(
+            page.docs
+                .contains("## A Tour\n\n- Tools for synchronization\n- Timers")
+        );
+        assert!(page.docs.contains("```rust\nlet rt = Runtime::new();\n```"));
+        assert_eq!(
+            page.items,
+            vec![
+                DocsItem {
+                    kind: "mod".to_string(),
+                    name: "sync".to_string(),
+                    summary: Some("Synchronization primitives.".to_string()),
+                },
+                DocsItem {
+                    kind: "macro".to_string(),
+                    name: "select".to_string(),
+                    summary: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_item_page() {
+        let page = parse_page(MUTEX_HTML, "tokio", None, String::new()).unwrap();
+
+        assert_eq!(page.title, "Struct tokio::sync::Mutex");
+        assert_eq!(
+            page.declaration.as_deref(),
+            Some("pub struct Mutex<T: ?Sized> { /* private fields */ }")
+        );
+        assert_eq!(page.docs, "An asynchronous `Mutex`-like type.");
+        assert_eq!(
+            page.signatures,
+            vec![
+                "impl<T: ?Sized> Mutex<T>",
+                "pub async fn lock(&self) -> MutexGuard<'_, T>"
+            ]
+        );
+        assert!(page.items.is_empty());
+    }
+
+    #[test]
+    fn test_parse_page_without_rustdoc_content() {
+        assert!(
+            parse_page(
+                "<html><body>Build failed</body></html>",
+                "x",
+                None,
+                String::new()
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_find_item_page() {
+        assert_eq!(
+            find_item_page(ALL_ITEMS_HTML, "sync::Mutex"),
+            Some((
+                "sync::Mutex".to_string(),
+                "sync/struct.Mutex.html".to_string()
+            ))
+        );
+        assert_eq!(
+            find_item_page(ALL_ITEMS_HTML, "Runtime").map(|(name, _)| name),
+            Some("runtime::Runtime".to_string())
+        );
+        assert_eq!(find_item_page(ALL_ITEMS_HTML, "net::Mutex"), None);
+        assert_eq!(find_item_page(ALL_ITEMS_HTML, "sync"), None);
+    }
+
+    #[test]
+    fn test_tool_default() {
+        let tool = DocsRsTool::default();
+        assert_eq!(tool.base_url, "https://docs.rs");
+        assert!(format!("{:?}", tool).contains("DocsRsTool"));
+    }
+
+    #[tokio::test]
+    async fn test_tool_definition() {
+        let definition = DocsRsTool::new().definition(String::new()).await;
+        assert_eq!(definition.name, "docs_rs");
+        assert_eq!(definition.parameters["required"][0], "crate_name");
+    }
+
+    #[tokio::test]
+    async fn test_read_crate_root_with_mock() {
+        let server = MockServer::start().await;
+        mock_page(&server, "/tokio/latest/tokio/", CRATE_ROOT_HTML).await;
+
+        let page = tool_for(&server)
+            .call(DocsArgs {
+                crate_name: "tokio".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(page.url, format!("{}/tokio/latest/tokio/", server.uri()));
+        assert_eq!(page.item, None);
+        assert_eq!(page.items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_item_with_mock() {
+        let server = MockServer::start().await;
+        mock_page(&server, "/tokio/1.44.0/tokio/all.html", ALL_ITEMS_HTML).await;
+        mock_page(
+            &server,
+            "/tokio/1.44.0/tokio/sync/struct.Mutex.html",
+            MUTEX_HTML,
+        )
+        .await;
+
+        let page = tool_for(&server)
+            .call(DocsArgs {
+                crate_name: "tokio".to_string(),
+                item: Some("tokio::sync::Mutex".to_string()),
+                version: Some("1.44.0".to_string()),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(page.item.as_deref(), Some("sync::Mutex"));
+        assert_eq!(page.signatures.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_module_falls_back_to_index() {
+        let server = MockServer::start().await;
+        mock_page(&server, "/tokio/latest/tokio/all.html", ALL_ITEMS_HTML).await;
+        mock_page(
+            &server,
+            "/tokio/latest/tokio/sync/index.html",
+            CRATE_ROOT_HTML,
+        )
+        .await;
+
+        let page = tool_for(&server)
+            .call(DocsArgs {
+                crate_name: "tokio".to_string(),
+                item: Some("sync".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(page.item.as_deref(), Some("sync"));
+        assert!(page.url.ends_with("/sync/index.html"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_item_error() {
+        let server = MockServer::start().await;
+        mock_page(&server, "/tokio/latest/tokio/all.html", ALL_ITEMS_HTML).await;
+
+        let err = tool_for(&server)
+            .call(DocsArgs {
+                crate_name: "tokio".to_string(),
+                item: Some("net::Nope".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, DocsRsError::ItemNotFound { ref item, .. } if item == "net::Nope"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_crate_error() {
+        let server = MockServer::start().await;
+
+        let err = tool_for(&server)
+            .call(DocsArgs {
+                crate_name: "no-such-crate".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, DocsRsError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_failed_build_error() {
+        let server = MockServer::start().await;
+        mock_page(
+            &server,
+            "/broken/latest/broken/",
+            "<html><body>Build failed</body></html>",
+        )
+        .await;
+
+        let err = tool_for(&server)
+            .call(DocsArgs {
+                crate_name: "broken".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, DocsRsError::MissingDocs(_)));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_arguments_skip_network() {
+        let server = MockServer::start().await;
+        let tool = tool_for(&server);
+
+        let bad_crate = tool
+            .call(DocsArgs {
+                crate_name: "../etc".to_string(),
+                ..Default::default()
+            })
+            .await;
+        let bad_version = tool
+            .call(DocsArgs {
+                crate_name: "tokio".to_string(),
+                version: Some("1/../..".to_string()),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(matches!(bad_crate, Err(DocsRsError::InvalidArgument(_))));
+        assert!(matches!(bad_version, Err(DocsRsError::InvalidArgument(_))));
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cached_page_skips_network() {
+        let server = MockServer::start().await;
+        mock_page(&server, "/tokio/latest/tokio/", CRATE_ROOT_HTML).await;
+        let dir = tempfile::tempdir().unwrap();
+        let tool = tool_for(&server).with_cache(ToolCache::new(dir.path()));
+        let args = DocsArgs {
+            crate_name: "tokio".to_string(),
+            ..Default::default()
+        };
+
+        let first = tool.call(args.clone()).await.unwrap();
+        let second = tool.call(args).await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,814 @@
+//! GitHub Repository Tool for rig-core agents
+//!
+//! Fetches first-party facts about a GitHub repository from the
+//! [GitHub REST API](https://docs.github.com/en/rest): description, stars,
+//! forks, open issues, license, the README and recent release notes.
+//!
+//! ## Authentication
+//!
+//! Unauthenticated requests are limited to 60 per hour. Set the `GITHUB_TOKEN`
+//! environment variable (read by [`GitHubRepoConfig::from_env`]) to raise the
+//! limit to 5,000 per hour.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use unchained_ai::rigging::tools::{GitHubRepoTool, RepoArgs};
+//! use rig::tool::Tool;
+//!
+//! let tool = GitHubRepoTool::from_env();
+//! let args = RepoArgs {
+//!     repository: "https://github.com/tokio-rs/tokio".to_string(),
+//!     ..Default::default()
+//! };
+//!
+//! let repo = tool.call(args).await?;
+//! println!("{} stars, {} open issues", repo.stars, repo.open_issues);
+//! ```
+
+use super::cache::ToolCache;
+use reqwest::{Client, RequestBuilder, StatusCode};
+use rig::completion::ToolDefinition;
+use rig::tool::Tool;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::time::Duration;
+use thiserror::Error;
+use tracing::{Span, debug, info, instrument, warn};
+
+/// Default number of releases returned.
+const DEFAULT_RELEASES: u32 = 5;
+
+/// Maximum number of releases returned.
+const MAX_RELEASES: u32 = 20;
+
+/// README content beyond this many characters is truncated.
+const MAX_README_CHARS: usize = 20_000;
+
+/// Release notes beyond this many characters are truncated.
+const MAX_RELEASE_NOTES_CHARS: usize = 4_000;
+
+/// Configuration for the GitHub API client.
+#[derive(Debug, Clone)]
+pub struct GitHubRepoConfig {
+    /// Personal access token, if any
+    pub token: Option<String>,
+    /// API base URL
+    pub api_base: String,
+}
+
+impl GitHubRepoConfig {
+    /// Create configuration from environment variables.
+    ///
+    /// Reads `GITHUB_TOKEN` (optional) for authentication.
+    pub fn from_env() -> Self {
+        Self {
+            token: env::var("GITHUB_TOKEN")
+                .ok()
+                .filter(|t| !t.trim().is_empty()),
+            ..Self::default()
+        }
+    }
+
+    /// Authenticate requests with `token`.
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Set a custom API base URL (useful for testing or GitHub Enterprise).
+    #[must_use]
+    pub fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into();
+        self
+    }
+}
+
+impl Default for GitHubRepoConfig {
+    fn default() -> Self {
+        Self {
+            token: None,
+            api_base: "https://api.github.com".to_string(),
+        }
+    }
+}
+
+/// Input parameters for the GitHub repository tool.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RepoArgs {
+    /// Repository URL or `owner/repo`
+    pub repository: String,
+
+    /// Include the README (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_readme: Option<bool>,
+
+    /// Number of recent releases to include (0-20, default: 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub releases: Option<u32>,
+}
+
+/// GitHub API repository response.
+#[derive(Debug, Deserialize)]
+struct RepoResponse {
+    full_name: String,
+    html_url: String,
+    description: Option<String>,
+    homepage: Option<String>,
+    stargazers_count: u64,
+    forks_count: u64,
+    open_issues_count: u64,
+    license: Option<LicenseResponse>,
+    default_branch: String,
+    #[serde(default)]
+    topics: Vec<String>,
+    #[serde(default)]
+    archived: bool,
+    pushed_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LicenseResponse {
+    spdx_id: Option<String>,
+    name: String,
+}
+
+/// GitHub API release response.
+#[derive(Debug, Deserialize)]
+struct ReleaseResponse {
+    tag_name: String,
+    name: Option<String>,
+    body: Option<String>,
+    published_at: Option<String>,
+    prerelease: bool,
+    draft: bool,
+}
+
+/// A release of the repository.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReleaseSummary {
+    /// Git tag of the release
+    pub tag: String,
+    /// Release title, if different from the tag
+    pub name: Option<String>,
+    /// Publication timestamp (RFC 3339)
+    pub published_at: Option<String>,
+    /// Whether the release is marked as a prerelease
+    pub prerelease: bool,
+    /// Release notes (markdown, possibly truncated)
+    pub notes: Option<String>,
+}
+
+/// Facts about a repository returned by the tool.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RepoInfo {
+    /// `owner/repo`
+    pub full_name: String,
+    /// Repository web page
+    pub url: String,
+    /// Repository description
+    pub description: Option<String>,
+    /// Project homepage
+    pub homepage: Option<String>,
+    /// Number of stars
+    pub stars: u64,
+    /// Number of forks
+    pub forks: u64,
+    /// Open issues (GitHub counts open pull requests as issues)
+    pub open_issues: u64,
+    /// License SPDX identifier or name
+    pub license: Option<String>,
+    /// Default branch
+    pub default_branch: String,
+    /// Repository topics
+    pub topics: Vec<String>,
+    /// Whether the repository is archived
+    pub archived: bool,
+    /// Time of the last push (RFC 3339)
+    pub pushed_at: Option<String>,
+    /// README content (markdown, possibly truncated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readme: Option<String>,
+    /// Most recent published releases, newest first
+    pub releases: Vec<ReleaseSummary>,
+}
+
+/// Errors that can occur while inspecting a repository.
+#[derive(Debug, Error)]
+pub enum GitHubRepoError {
+    /// The repository could not be identified
+    #[error("Not a GitHub repository: {0}")]
+    InvalidRepository(String),
+
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    HttpError(#[from] reqwest::Error),
+
+    /// The repository does not exist or is private
+    #[error("Repository not found: {0}")]
+    NotFound(String),
+
+    /// The API rate limit was exhausted
+    #[error("GitHub API rate limit exceeded (set GITHUB_TOKEN to raise it)")]
+    RateLimited,
+
+    /// API returned an error response
+    #[error("API error (status {status}): {message}")]
+    ApiError {
+        /// HTTP status code
+        status: u16,
+        /// Error message from the API
+        message: String,
+    },
+
+    /// Failed to parse the API response
+    #[error("Failed to parse response: {0}")]
+    ParseError(String),
+}
+
+/// Split a repository URL or `owner/repo` into owner and name.
+///
+/// Accepts `owner/repo`, `https://github.com/owner/repo` (with or without
+/// `.git`, a trailing path such as `/tree/main`, or a `git+` prefix) and
+/// `git@github.com:owner/repo.git`. Returns `None` for other hosts.
+pub fn parse_github_repository(repository: &str) -> Option<(String, String)> {
+    let repository = repository.trim().trim_start_matches("git+");
+
+    let path = if let Some(rest) = repository.strip_prefix("git@github.com:") {
+        rest
+    } else if let Some((_, rest)) = repository.split_once("://") {
+        let (host, path) = rest.split_once('/')?;
+        let host = host.rsplit('@').next()?;
+        if !host.eq_ignore_ascii_case("github.com") && !host.eq_ignore_ascii_case("www.github.com")
+        {
+            return None;
+        }
+        path
+    } else if repository.contains(':') || repository.starts_with('/') {
+        return None;
+    } else {
+        repository.strip_prefix("github.com/").unwrap_or(repository)
+    };
+
+    let mut parts = path.split('/').filter(|part| !part.is_empty());
+    let owner = parts.next()?;
+    let name = parts.next()?.trim_end_matches(".git");
+    let name = name.split(['?', '#']).next()?;
+    if owner.is_empty() || name.is_empty() {
+        return None;
+    }
+    Some((owner.to_string(), name.to_string()))
+}
+
+/// Truncate `text` to at most `max_chars` characters, marking the cut.
+fn truncate(text: String, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((cut, _)) => format!("{}\n\n[... truncated]", &text[..cut]),
+        None => text,
+    }
+}
+
+/// GitHub repository inspection tool for rig-core agents.
+///
+/// Gives agents first-party data about a repository (popularity, maintenance
+/// activity, README and release notes) instead of relying on web search
+/// results about it.
+#[derive(Clone)]
+pub struct GitHubRepoTool {
+    config: GitHubRepoConfig,
+    client: Client,
+    cache: Option<ToolCache>,
+}
+
+impl GitHubRepoTool {
+    /// Create a new GitHub repository tool with the given configuration.
+    pub fn new(config: GitHubRepoConfig) -> Self {
+        Self {
+            config,
+            client: Client::new(),
+            cache: None,
+        }
+    }
+
+    /// Create a new GitHub repository tool from environment variables.
+    pub fn from_env() -> Self {
+        Self::new(GitHubRepoConfig::from_env())
+    }
+
+    /// Reuse results stored in `cache` and store new ones there.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ToolCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Build a GET request for an API path.
+    fn request(&self, path: &str, accept: &str) -> RequestBuilder {
+        let mut request = self
+            .client
+            .get(format!(
+                "{}{}",
+                self.config.api_base.trim_end_matches('/'),
+                path
+            ))
+            .header("User-Agent", "unchained-ai")
+            .header("Accept", accept)
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .timeout(Duration::from_secs(15));
+
+        if let Some(ref token) = self.config.token {
+            request = request.header("Authorization", format!("Bearer {}", token));
+        }
+
+        request
+    }
+
+    /// Send a request, mapping GitHub's error statuses.
+    ///
+    /// Returns `Ok(None)` for `404 Not Found`.
+    async fn send(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<Option<reqwest::Response>, GitHubRepoError> {
+        let response = request.send().await?;
+        let status = response.status();
+        Span::current().record("http.status_code", status.as_u16());
+
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let rate_limited = status == StatusCode::TOO_MANY_REQUESTS
+            || (status == StatusCode::FORBIDDEN
+                && response
+                    .headers()
+                    .get("x-ratelimit-remaining")
+                    .and_then(|v| v.to_str().ok())
+                    == Some("0"));
+        if rate_limited {
+            warn!("GitHub API rate limit exceeded");
+            return Err(GitHubRepoError::RateLimited);
+        }
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unable to read error response".to_string());
+            warn!(status = status.as_u16(), %message, "GitHub API returned error");
+            return Err(GitHubRepoError::ApiError {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(Some(response))
+    }
+
+    /// Fetch and parse a JSON API resource.
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> Result<Option<T>, GitHubRepoError> {
+        let Some(response) = self
+            .send(self.request(path, "application/vnd.github+json"))
+            .await?
+        else {
+            return Ok(None);
+        };
+        response
+            .json()
+            .await
+            .map(Some)
+            .map_err(|e| GitHubRepoError::ParseError(e.to_string()))
+    }
+
+    /// Inspect the repository described by `args`.
+    #[instrument(
+        name = "github_repo",
+        skip(self, args),
+        fields(
+            tool.name = "github_repo",
+            tool.repository = %args.repository,
+            otel.kind = "client"
+        )
+    )]
+    async fn inspect(&self, args: &RepoArgs) -> Result<RepoInfo, GitHubRepoError> {
+        let start = std::time::Instant::now();
+
+        let (owner, name) = parse_github_repository(&args.repository).ok_or_else(|| {
+            warn!(repository = %args.repository, "Not a GitHub repository");
+            GitHubRepoError::InvalidRepository(args.repository.clone())
+        })?;
+        let include_readme = args.include_readme.unwrap_or(true);
+        let release_count = args.releases.unwrap_or(DEFAULT_RELEASES).min(MAX_RELEASES);
+
+        let cache_key = format!("{owner}/{name} readme={include_readme} releases={release_count}");
+        if let Some(cache) = &self.cache
+            && let Some(info) = cache.get::<RepoInfo>(Self::NAME, &cache_key).await
+        {
+            info!("Repository served from cache");
+            return Ok(info);
+        }
+
+        debug!(%owner, %name, include_readme, release_count, "Inspecting repository");
+
+        let repo: RepoResponse = self
+            .get_json(&format!("/repos/{owner}/{name}"))
+            .await?
+            .ok_or_else(|| GitHubRepoError::NotFound(format!("{owner}/{name}")))?;
+
+        let readme = if include_readme {
+            let request = self.request(
+                &format!("/repos/{owner}/{name}/readme"),
+                "application/vnd.github.raw+json",
+            );
+            match self.send(request).await? {
+                Some(response) => Some(truncate(response.text().await?, MAX_README_CHARS)),
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let releases = if release_count > 0 {
+            // Ask for a few extra so skipped drafts don't shrink the result
+            let releases: Vec<ReleaseResponse> = self
+                .get_json(&format!(
+                    "/repos/{owner}/{name}/releases?per_page={}",
+                    (release_count + 5).min(100)
+                ))
+                .await?
+                .unwrap_or_default();
+            releases
+                .into_iter()
+                .filter(|release| !release.draft)
+                .take(release_count as usize)
+                .map(|release| ReleaseSummary {
+                    name: release
+                        .name
+                        .filter(|name| !name.trim().is_empty() && *name != release.tag_name),
+                    tag: release.tag_name,
+                    published_at: release.published_at,
+                    prerelease: release.prerelease,
+                    notes: release
+                        .body
+                        .filter(|body| !body.trim().is_empty())
+                        .map(|body| truncate(body, MAX_RELEASE_NOTES_CHARS)),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let info = RepoInfo {
+            full_name: repo.full_name,
+            url: repo.html_url,
+            description: repo.description,
+            homepage: repo.homepage.filter(|homepage| !homepage.is_empty()),
+            stars: repo.stargazers_count,
+            forks: repo.forks_count,
+            open_issues: repo.open_issues_count,
+            license: repo.license.map(|license| match license.spdx_id {
+                Some(spdx) if spdx != "NOASSERTION" => spdx,
+                _ => license.name,
+            }),
+            default_branch: repo.default_branch,
+            topics: repo.topics,
+            archived: repo.archived,
+            pushed_at: repo.pushed_at,
+            readme,
+            releases,
+        };
+
+        if let Some(cache) = &self.cache {
+            cache.put(Self::NAME, &cache_key, &info).await;
+        }
+
+        info!(
+            tool.stars = info.stars,
+            tool.releases_count = info.releases.len(),
+            tool.duration_ms = start.elapsed().as_millis() as u64,
+            "Repository inspected"
+        );
+
+        Ok(info)
+    }
+}
+
+impl std::fmt::Debug for GitHubRepoTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GitHubRepoTool")
+            .field("api_base", &self.config.api_base)
+            .field("authenticated", &self.config.token.is_some())
+            .field("cache", &self.cache.as_ref().map(ToolCache::dir))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Tool for GitHubRepoTool {
+    const NAME: &'static str = "github_repo";
+
+    type Error = GitHubRepoError;
+    type Args = RepoArgs;
+    type Output = RepoInfo;
+
+    async fn definition(&self, _prompt: String) -> ToolDefinition {
+        ToolDefinition {
+            name: "github_repo".to_string(),
+            description: "Inspect a GitHub repository. Returns its description, stars, forks, \
+                open issue count, license, last push time, README and recent release notes. \
+                Use this tool for first-party information about a project hosted on GitHub, \
+                such as its purpose, popularity, maintenance activity and recent changes."
+                .to_string(),
+            parameters: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "repository": {
+                        "type": "string",
+                        "description": "Repository URL (e.g., https://github.com/tokio-rs/tokio) or owner/repo"
+                    },
+                    "include_readme": {
+                        "type": "boolean",
+                        "description": "Include the README content (default: true)"
+                    },
+                    "releases": {
+                        "type": "integer",
+                        "description": "Number of recent releases to include (0-20, default: 5)",
+                        "minimum": 0,
+                        "maximum": 20
+                    }
+                },
+                "required": ["repository"]
+            }),
+        }
+    }
+
+    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
+        self.inspect(&args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn repo_json() -> serde_json::Value {
+        serde_json::json!({
+            "full_name": "tokio-rs/tokio",
+            "html_url": "https://github.com/tokio-rs/tokio",
+            "description": "A runtime for writing reliable asynchronous applications",
+            "homepage": "https://tokio.rs",
+            "stargazers_count": 28000,
+            "forks_count": 2500,
+            "open_issues_count": 300,
+            "license": {"spdx_id": "MIT", "name": "MIT License"},
+            "default_branch": "master",
+            "topics": ["async", "rust"],
+            "archived": false,
+            "pushed_at": "2025-01-01T00:00:00Z"
+        })
+    }
+
+    async fn mock_repo(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/repos/tokio-rs/tokio"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(repo_json()))
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/tokio-rs/tokio/readme"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("# Tokio\n\nAsync runtime."))
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/tokio-rs/tokio/releases"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"tag_name": "tokio-1.44.0", "name": "Tokio v1.44.0", "body": "New stuff",
+                 "published_at": "2025-03-01T00:00:00Z", "prerelease": false, "draft": true},
+                {"tag_name": "tokio-1.43.0", "name": "tokio-1.43.0", "body": "",
+                 "published_at": "2025-01-01T00:00:00Z", "prerelease": false, "draft": false}
+            ])))
+            .mount(server)
+            .await;
+    }
+
+    fn tool_for(server: &MockServer) -> GitHubRepoTool {
+        GitHubRepoTool::new(GitHubRepoConfig::default().with_api_base(server.uri()))
+    }
+
+    #[test]
+    fn test_parse_github_repository() {
+        let expected = Some(("tokio-rs".to_string(), "tokio".to_string()));
+        assert_eq!(parse_github_repository("tokio-rs/tokio"), expected);
+        assert_eq!(
+            parse_github_repository("https://github.com/tokio-rs/tokio"),
+            expected
+        );
+        assert_eq!(
+            parse_github_repository("https://github.com/tokio-rs/tokio.git"),
+            expected
+        );
+        assert_eq!(
+            parse_github_repository("https://github.com/tokio-rs/tokio/tree/master/tokio"),
+            expected
+        );
+        assert_eq!(
+            parse_github_repository("git+https://github.com/tokio-rs/tokio.git"),
+            expected
+        );
+        assert_eq!(
+            parse_github_repository("git@github.com:tokio-rs/tokio.git"),
+            expected
+        );
+        assert_eq!(
+            parse_github_repository("github.com/tokio-rs/tokio"),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_github_repository_rejects_other_hosts() {
+        assert_eq!(
+            parse_github_repository("https://gitlab.com/owner/repo"),
+            None
+        );
+        assert_eq!(parse_github_repository("https://github.com/owner"), None);
+        assert_eq!(parse_github_repository("tokio"), None);
+        assert_eq!(parse_github_repository(""), None);
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("short".to_string(), 10), "short");
+        assert_eq!(
+            truncate("héllo wörld".to_string(), 5),
+            "héllo\n\n[... truncated]"
+        );
+    }
+
+    #[test]
+    fn test_config_from_default() {
+        let config = GitHubRepoConfig::default().with_token("secret");
+        assert_eq!(config.api_base, "https://api.github.com");
+        assert_eq!(config.token.as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn test_tool_debug_hides_token() {
+        let tool = GitHubRepoTool::new(GitHubRepoConfig::default().with_token("secret"));
+        let debug = format!("{:?}", tool);
+        assert!(debug.contains("GitHubRepoTool"));
+        assert!(!debug.contains("secret"));
+    }
+
+    #[tokio::test]
+    async fn test_tool_definition() {
+        let tool = GitHubRepoTool::new(GitHubRepoConfig::default());
+        let def = tool.definition(String::new()).await;
+        assert_eq!(def.name, "github_repo");
+        assert_eq!(def.parameters["required"][0], "repository");
+    }
+
+    #[tokio::test]
+    async fn test_inspect_repository_with_mock() {
+        let server = MockServer::start().await;
+        mock_repo(&server).await;
+
+        let info = tool_for(&server)
+            .call(RepoArgs {
+                repository: "https://github.com/tokio-rs/tokio".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(info.full_name, "tokio-rs/tokio");
+        assert_eq!(info.stars, 28000);
+        assert_eq!(info.open_issues, 300);
+        assert_eq!(info.license.as_deref(), Some("MIT"));
+        assert_eq!(info.readme.as_deref(), Some("# Tokio\n\nAsync runtime."));
+        // Drafts are skipped and names equal to the tag dropped
+        assert_eq!(info.releases.len(), 1);
+        assert_eq!(info.releases[0].tag, "tokio-1.43.0");
+        assert_eq!(info.releases[0].name, None);
+        assert_eq!(info.releases[0].notes, None);
+    }
+
+    #[tokio::test]
+    async fn test_inspect_skips_readme_and_releases_when_not_requested() {
+        let server = MockServer::start().await;
+        mock_repo(&server).await;
+
+        let info = tool_for(&server)
+            .call(RepoArgs {
+                repository: "tokio-rs/tokio".to_string(),
+                include_readme: Some(false),
+                releases: Some(0),
+            })
+            .await
+            .unwrap();
+
+        assert!(info.readme.is_none());
+        assert!(info.releases.is_empty());
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sends_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/tokio-rs/tokio"))
+            .and(header("Authorization", "Bearer secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(repo_json()))
+            .mount(&server)
+            .await;
+
+        let tool = GitHubRepoTool::new(
+            GitHubRepoConfig::default()
+                .with_api_base(server.uri())
+                .with_token("secret"),
+        );
+        let info = tool
+            .call(RepoArgs {
+                repository: "tokio-rs/tokio".to_string(),
+                include_readme: Some(false),
+                releases: Some(0),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(info.full_name, "tokio-rs/tokio");
+    }
+
+    #[tokio::test]
+    async fn test_missing_repository_is_not_found() {
+        let server = MockServer::start().await;
+
+        let err = tool_for(&server)
+            .call(RepoArgs {
+                repository: "owner/missing".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, GitHubRepoError::NotFound(ref repo) if repo == "owner/missing"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(403).insert_header("x-ratelimit-remaining", "0"))
+            .mount(&server)
+            .await;
+
+        let err = tool_for(&server)
+            .call(RepoArgs {
+                repository: "tokio-rs/tokio".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, GitHubRepoError::RateLimited));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_repository_error() {
+        let tool = GitHubRepoTool::new(GitHubRepoConfig::default());
+        let err = tool
+            .call(RepoArgs {
+                repository: "https://gitlab.com/owner/repo".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, GitHubRepoError::InvalidRepository(_)));
+    }
+
+    #[tokio::test]
+    async fn test_cached_repository_skips_api() {
+        let server = MockServer::start().await;
+        mock_repo(&server).await;
+        let dir = tempfile::tempdir().unwrap();
+        let tool = tool_for(&server).with_cache(ToolCache::new(dir.path()));
+        let args = RepoArgs {
+            repository: "tokio-rs/tokio".to_string(),
+            ..Default::default()
+        };
+
+        let first = tool.call(args.clone()).await.unwrap();
+        let requests = server.received_requests().await.unwrap().len();
+        let second = tool.call(args).await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(server.received_requests().await.unwrap().len(), requests);
+    }
+}
@@ -4,6 +4,11 @@
 //!
 //! - [`BraveSearchTool`] - Web search using the Brave Search API
 //! - [`ScreenScrapeTool`] - Web page content extraction and scraping
+//! - [`GitHubRepoTool`] - Repository facts, README and release notes from GitHub
+//! - [`DocsRsTool`] - Rust crate API documentation from docs.rs
+//!
+//! All tools can share a [`ToolCache`] so repeated lookups are read from disk
+//! instead of re-fetched.
 //!
 //! ## Usage with rig-core agents
 //!
@@ -23,11 +28,20 @@
 //! ```
 
 mod brave_search;
+mod cache;
+mod docs_rs;
+mod github_repo;
 mod screen_scrape;
 
 pub use brave_search::{
-    BravePlan, BraveSearchConfig, BraveSearchError, BraveSearchTool, SearchArgs,
-    SearchResultOutput,
+    BravePlan, BraveRateLimiter, BraveSearchConfig, BraveSearchError, BraveSearchTool,
+    SearchArgs, SearchResultOutput,
+};
+pub use cache::ToolCache;
+pub use docs_rs::{DocsArgs, DocsItem, DocsPage, DocsRsError, DocsRsTool};
+pub use github_repo::{
+    GitHubRepoConfig, GitHubRepoError, GitHubRepoTool, ReleaseSummary, RepoArgs, RepoInfo,
+    parse_github_repository,
 };
 pub use screen_scrape::{
     LinkInfo, OutputFormat, ProxyMode, ScrapeAction, ScrapeArgs, ScrapeError, ScrapeMetadata,
@@ -10,6 +10,7 @@
 //! - Tag filtering (include/exclude specific HTML tags)
 //! - Mobile/Desktop user agent switching
 //! - Custom headers and request configuration
+//! - Optional on-disk caching of fetched pages ([`ScreenScrapeTool::with_cache`])
 //!
 //! ## Example
 //!
@@ -29,6 +30,7 @@
 //! println!("Content: {:?}", result.content);
 //! ```
 
+use super::cache::ToolCache;
 use reqwest::Client;
 use rig::completion::ToolDefinition;
 use rig::tool::Tool;
@@ -364,6 +366,15 @@ pub struct ScreenScrapeTool {
     #[allow(dead_code)]
     client: Client,
     default_timeout: Duration,
+    cache: Option<ToolCache>,
+}
+
+/// A fetched page before any content processing, as stored in the cache.
+#[derive(Debug, Serialize, Deserialize)]
+struct FetchedPage {
+    status_code: u16,
+    content_type: Option<String>,
+    html: String,
 }
 
 impl ScreenScrapeTool {
@@ -372,6 +383,7 @@ impl ScreenScrapeTool {
         Self {
             client: Client::new(),
             default_timeout: Duration::from_secs(30),
+            cache: None,
         }
     }
 
@@ -382,12 +394,23 @@ impl ScreenScrapeTool {
         self
     }
 
+    /// Reuse pages stored in `cache` and store newly fetched ones there.
+    ///
+    /// Pages are keyed by URL and user agent; only successful responses are
+    /// cached.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ToolCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Create a tool with a custom HTTP client (useful for testing).
     #[cfg(test)]
     pub fn with_client(client: Client) -> Self {
         Self {
             client,
             default_timeout: Duration::from_secs(30),
+            cache: None,
         }
     }
 
@@ -557,43 +580,13 @@ impl ScreenScrapeTool {
         result
     }
 
-    /// Perform the scrape operation.
-    #[instrument(
-        name = "screen_scrape",
-        skip(self, args),
-        fields(
-            tool.name = "screen_scrape",
-            tool.url = %args.url,
-            tool.formats = ?args.formats,
-            otel.kind = "client"
-        )
-    )]
-    async fn scrape(&self, args: ScrapeArgs) -> Result<ScrapeOutput, ScrapeError> {
-        let start_time = std::time::Instant::now();
-
-        debug!(
-            only_main_content = args.only_main_content,
-            mobile = args.mobile,
-            timeout = args.timeout,
-            "Starting page scrape"
-        );
-
-        // Validate URL
-        let parsed_url = url::Url::parse(&args.url).map_err(|e| {
-            warn!(error = %e, url = %args.url, "Invalid URL");
-            ScrapeError::InvalidUrl(e.to_string())
-        })?;
-
-        if !["http", "https"].contains(&parsed_url.scheme()) {
-            warn!(scheme = %parsed_url.scheme(), "Unsupported URL scheme");
-            return Err(ScrapeError::InvalidUrl(
-                "Only HTTP and HTTPS URLs are supported".to_string(),
-            ));
-        }
-
-        // Build client and request
-        let client = self.build_request_client(&args)?;
-        let user_agent = self.get_user_agent(&args);
+    /// Fetch the raw page for `args`.
+    async fn fetch_page(
+        &self,
+        args: &ScrapeArgs,
+        user_agent: String,
+    ) -> Result<FetchedPage, ScrapeError> {
+        let client = self.build_request_client(args)?;
 
         let mut request = client
             .get(args.url.clone())
@@ -638,7 +631,77 @@ impl ScreenScrapeTool {
             .and_then(|v| v.to_str().ok())
             .map(String::from);
 
-        let html_content = response.text().await?;
+        let html = response.text().await?;
+
+        Ok(FetchedPage {
+            status_code,
+            content_type,
+            html,
+        })
+    }
+
+    /// Perform the scrape operation.
+    #[instrument(
+        name = "screen_scrape",
+        skip(self, args),
+        fields(
+            tool.name = "screen_scrape",
+            tool.url = %args.url,
+            tool.formats = ?args.formats,
+            otel.kind = "client"
+        )
+    )]
+    async fn scrape(&self, args: ScrapeArgs) -> Result<ScrapeOutput, ScrapeError> {
+        let start_time = std::time::Instant::now();
+
+        debug!(
+            only_main_content = args.only_main_content,
+            mobile = args.mobile,
+            timeout = args.timeout,
+            "Starting page scrape"
+        );
+
+        // Validate URL
+        let parsed_url = url::Url::parse(&args.url).map_err(|e| {
+            warn!(error = %e, url = %args.url, "Invalid URL");
+            ScrapeError::InvalidUrl(e.to_string())
+        })?;
+
+        if !["http", "https"].contains(&parsed_url.scheme()) {
+            warn!(scheme = %parsed_url.scheme(), "Unsupported URL scheme");
+            return Err(ScrapeError::InvalidUrl(
+                "Only HTTP and HTTPS URLs are supported".to_string(),
+            ));
+        }
+
+        let user_agent = self.get_user_agent(&args);
+        let cache_key = format!("{} {}", user_agent, args.url);
+        let cached = match &self.cache {
+            Some(cache) => cache.get::<FetchedPage>(Self::NAME, &cache_key).await,
+            None => None,
+        };
+
+        let page = match cached {
+            Some(page) => {
+                debug!("Page served from cache");
+                page
+            }
+            None => {
+                let page = self.fetch_page(&args, user_agent).await?;
+                if let Some(cache) = &self.cache
+                    && (200..300).contains(&page.status_code)
+                {
+                    cache.put(Self::NAME, &cache_key, &page).await;
+                }
+                page
+            }
+        };
+
+        let FetchedPage {
+            status_code,
+            content_type,
+            html: html_content,
+        } = page;
         let content_length = html_content.len();
 
         debug!(content_length, "Retrieved page content");
@@ -730,6 +793,7 @@ impl std::fmt::Debug for ScreenScrapeTool {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ScreenScrapeTool")
             .field("default_timeout", &self.default_timeout)
+            .field("cache", &self.cache.as_ref().map(ToolCache::dir))
             .finish_non_exhaustive()
     }
 }
@@ -1242,6 +1306,54 @@ mod tests {
         assert_eq!(links[0].url, "https://example.com");
     }
 
+    #[tokio::test]
+    async fn test_cached_scrape_skips_fetch() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("<html><body><p>Cached page</p></body></html>")
+                    .insert_header("content-type", "text/html"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let tool = ScreenScrapeTool::new().with_cache(ToolCache::new(dir.path()));
+
+        let first = tool
+            .call(ScrapeArgs {
+                url: mock_server.uri(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let second = tool
+            .call(ScrapeArgs {
+                url: mock_server.uri(),
+                formats: vec![OutputFormat::PlainText],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(first.status_code, 200);
+        assert_eq!(second.status_code, 200);
+        assert_eq!(second.metadata.content_type, first.metadata.content_type);
+        assert!(
+            second.content["plain_text"]
+                .as_str()
+                .unwrap()
+                .contains("Cached page")
+        );
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+    }
+
     #[tokio::test]
     async fn test_scrape_with_main_content_extraction() {
         use wiremock::matchers::{method, path};